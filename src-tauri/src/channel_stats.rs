@@ -0,0 +1,853 @@
+use chrono::{Local, TimeZone, Timelike};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tauri::State;
+
+use crate::export::{escape_html, local_time};
+use crate::history::{casefold, HistoryState};
+use crate::irc::{ctcp_action, is_ctcp, strip_formatting};
+
+/// Messages read per query while catching up
+const CHUNK: i64 = 5000;
+/// Per-day caps on what is kept, so busy days don't bloat the cache
+const MAX_DAY_WORDS: usize = 1000;
+const MAX_DAY_URLS: usize = 200;
+const MAX_LONGEST: usize = 10;
+/// Words shorter than this are too common to be interesting
+const MIN_WORD_LEN: usize = 4;
+/// Lines eligible as a talker's random quote
+const QUOTE_LEN: std::ops::RangeInclusive<usize> = 20..=200;
+
+const TOP_TALKERS: usize = 25;
+const TOP_WORDS: usize = 25;
+const TOP_URLS: usize = 15;
+const TOP_OTHER: usize = 5;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Quote {
+    nick: String,
+    text: String,
+    time: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct Talker {
+    nick: String,
+    lines: u64,
+    words: u64,
+    actions: u64,
+    quote: Option<Quote>,
+    /// Random quotes keep the eligible line with the lowest rank, which
+    /// samples uniformly and lets days merge by taking the minimum
+    quote_rank: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct Count {
+    nick: String,
+    count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct UrlCount {
+    count: u64,
+    /// Who shared it first
+    nick: String,
+    time: i64,
+}
+
+/// Aggregates for one channel on one local day, cached in `stats_days`
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+struct DayStats {
+    talkers: HashMap<String, Talker>,
+    hours: [u64; 24],
+    words: HashMap<String, u64>,
+    longest: Vec<Quote>,
+    urls: HashMap<String, UrlCount>,
+    kicks_given: HashMap<String, Count>,
+    kicks_received: HashMap<String, Count>,
+    ops_given: HashMap<String, Count>,
+    deops_given: HashMap<String, Count>,
+}
+
+fn bump(map: &mut HashMap<String, Count>, nick: &str, by: u64) {
+    let entry = map.entry(casefold(nick)).or_default();
+    entry.nick = nick.to_string();
+    entry.count += by;
+}
+
+/// Cheap, stable pseudo-random rank for a message id (splitmix64)
+fn rank(id: i64) -> u64 {
+    let mut z = (id as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn keep_longest(longest: &mut Vec<Quote>) {
+    longest.sort_by_key(|quote| Reverse(quote.text.chars().count()));
+    longest.truncate(MAX_LONGEST);
+}
+
+fn keep_top<T>(map: &mut HashMap<String, T>, limit: usize, count: impl Fn(&T) -> u64) {
+    if map.len() <= limit {
+        return;
+    }
+    let mut entries: Vec<_> = map.drain().collect();
+    entries.sort_by_key(|(_, value)| Reverse(count(value)));
+    entries.truncate(limit);
+    map.extend(entries);
+}
+
+fn is_url(word: &str) -> bool {
+    word.starts_with("http://") || word.starts_with("https://") || word.starts_with("www.")
+}
+
+impl DayStats {
+    fn add(&mut self, id: i64, time: i64, command: &str, sender: &str, text: &str) {
+        match command {
+            "PRIVMSG" => {
+                if is_ctcp(text) {
+                    return;
+                }
+                let (action, text) = match ctcp_action(text) {
+                    Some(action) => (true, action),
+                    None => (false, text),
+                };
+                self.add_line(id, time, sender, &strip_formatting(text), action);
+            }
+            "KICK" => {
+                let victim = text.split(' ').next().unwrap_or("");
+                bump(&mut self.kicks_given, sender, 1);
+                bump(&mut self.kicks_received, victim, 1);
+            }
+            "MODE" => {
+                let modes = text.split(' ').next().unwrap_or("");
+                let mut adding = true;
+                for c in modes.chars() {
+                    match c {
+                        '+' => adding = true,
+                        '-' => adding = false,
+                        'o' if adding => bump(&mut self.ops_given, sender, 1),
+                        'o' => bump(&mut self.deops_given, sender, 1),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn add_line(&mut self, id: i64, time: i64, sender: &str, text: &str, action: bool) {
+        if let Some(local) = Local.timestamp_millis_opt(time).single() {
+            self.hours[local.hour() as usize] += 1;
+        }
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let talker = self.talkers.entry(casefold(sender)).or_default();
+        talker.nick = sender.to_string();
+        talker.lines += 1;
+        talker.words += words.len() as u64;
+        if action {
+            talker.actions += 1;
+        }
+
+        let quote = Quote {
+            nick: sender.to_string(),
+            text: text.to_string(),
+            time,
+        };
+        let rank = rank(id);
+        if !action
+            && QUOTE_LEN.contains(&text.chars().count())
+            && (talker.quote.is_none() || rank < talker.quote_rank)
+        {
+            talker.quote = Some(quote.clone());
+            talker.quote_rank = rank;
+        }
+
+        for word in words {
+            if is_url(word) {
+                let url = self
+                    .urls
+                    .entry(word.to_string())
+                    .or_insert_with(|| UrlCount {
+                        count: 0,
+                        nick: sender.to_string(),
+                        time,
+                    });
+                url.count += 1;
+                continue;
+            }
+            let word = word
+                .trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase();
+            if word.chars().count() >= MIN_WORD_LEN {
+                *self.words.entry(word).or_default() += 1;
+            }
+        }
+
+        if self.longest.len() < MAX_LONGEST
+            || self
+                .longest
+                .last()
+                .is_some_and(|l| l.text.chars().count() < text.chars().count())
+        {
+            self.longest.push(quote);
+            keep_longest(&mut self.longest);
+        }
+    }
+
+    fn trim(&mut self) {
+        keep_top(&mut self.words, MAX_DAY_WORDS, |count| *count);
+        keep_top(&mut self.urls, MAX_DAY_URLS, |url| url.count);
+    }
+
+    fn merge(&mut self, other: DayStats) {
+        for (key, talker) in other.talkers {
+            let entry = self.talkers.entry(key).or_default();
+            entry.nick = talker.nick;
+            entry.lines += talker.lines;
+            entry.words += talker.words;
+            entry.actions += talker.actions;
+            if talker.quote.is_some()
+                && (entry.quote.is_none() || talker.quote_rank < entry.quote_rank)
+            {
+                entry.quote = talker.quote;
+                entry.quote_rank = talker.quote_rank;
+            }
+        }
+        for (hour, count) in other.hours.iter().enumerate() {
+            self.hours[hour] += count;
+        }
+        for (word, count) in other.words {
+            *self.words.entry(word).or_default() += count;
+        }
+        self.longest.extend(other.longest);
+        keep_longest(&mut self.longest);
+        for (url, count) in other.urls {
+            let entry = self.urls.entry(url).or_insert_with(|| UrlCount {
+                time: i64::MAX,
+                ..Default::default()
+            });
+            entry.count += count.count;
+            if count.time < entry.time {
+                entry.nick = count.nick;
+                entry.time = count.time;
+            }
+        }
+        let merge_counts = |into: &mut HashMap<String, Count>, from: HashMap<String, Count>| {
+            for count in from.into_values() {
+                bump(into, &count.nick, count.count);
+            }
+        };
+        merge_counts(&mut self.kicks_given, other.kicks_given);
+        merge_counts(&mut self.kicks_received, other.kicks_received);
+        merge_counts(&mut self.ops_given, other.ops_given);
+        merge_counts(&mut self.deops_given, other.deops_given);
+    }
+}
+
+fn local_day(millis: i64) -> String {
+    Local
+        .timestamp_millis_opt(millis)
+        .single()
+        .map(|t| t.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+fn load_day(
+    conn: &Connection,
+    network: &str,
+    target_key: &str,
+    day: &str,
+) -> rusqlite::Result<DayStats> {
+    let data: Option<String> = conn
+        .query_row(
+            "SELECT data FROM stats_days WHERE network = ?1 AND target_key = ?2 AND day = ?3",
+            params![network, target_key, day],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(data
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default())
+}
+
+/// Fold messages stored since the last report into the per-day cache
+fn catch_up(conn: &mut Connection, network: &str, target_key: &str) -> rusqlite::Result<()> {
+    let mut last_id: i64 = conn
+        .query_row(
+            "SELECT last_id FROM stats_progress WHERE network = ?1 AND target_key = ?2",
+            params![network, target_key],
+            |row| row.get(0),
+        )
+        .optional()?
+        .unwrap_or(0);
+
+    loop {
+        let rows: Vec<(i64, i64, String, String, String)> = {
+            let mut stmt = conn.prepare_cached(
+                "SELECT id, time, command, sender, text FROM messages
+                 WHERE network = ?1 AND target_key = ?2 AND id > ?3
+                   AND sender IS NOT NULL AND command IN ('PRIVMSG', 'KICK', 'MODE')
+                 ORDER BY id LIMIT ?4",
+            )?;
+            let rows = stmt
+                .query_map(params![network, target_key, last_id, CHUNK], |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+                    ))
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            rows
+        };
+        let Some(&(chunk_last, ..)) = rows.last() else {
+            return Ok(());
+        };
+
+        let mut days: BTreeMap<String, DayStats> = BTreeMap::new();
+        for (id, time, command, sender, text) in &rows {
+            let day = local_day(*time);
+            if !days.contains_key(&day) {
+                let stats = load_day(conn, network, target_key, &day)?;
+                days.insert(day.clone(), stats);
+            }
+            if let Some(stats) = days.get_mut(&day) {
+                stats.add(*id, *time, command, sender, text);
+            }
+        }
+
+        let tx = conn.transaction()?;
+        for (day, mut stats) in days {
+            stats.trim();
+            tx.execute(
+                "INSERT OR REPLACE INTO stats_days (network, target_key, day, data) VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    network,
+                    target_key,
+                    day,
+                    serde_json::to_string(&stats).unwrap_or_else(|_| "{}".to_string())
+                ],
+            )?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO stats_progress (network, target_key, last_id) VALUES (?1, ?2, ?3)",
+            params![network, target_key, chunk_last],
+        )?;
+        tx.commit()?;
+        last_id = chunk_last;
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TalkerReport {
+    nick: String,
+    lines: u64,
+    words: u64,
+    words_per_line: f64,
+    actions: u64,
+    quote: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WordReport {
+    word: String,
+    count: u64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlReport {
+    url: String,
+    count: u64,
+    nick: String,
+    time: i64,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct NickCount {
+    nick: String,
+    count: u64,
+}
+
+/// pisg-style statistics for one channel
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelReport {
+    network: String,
+    target: String,
+    /// First and last local day with activity in the period
+    first_day: Option<String>,
+    last_day: Option<String>,
+    days: usize,
+    total_lines: u64,
+    talkers: Vec<TalkerReport>,
+    /// Lines per local hour of day, 0-23
+    hours: [u64; 24],
+    words: Vec<WordReport>,
+    longest_lines: Vec<Quote>,
+    urls: Vec<UrlReport>,
+    kicks_given: Vec<NickCount>,
+    kicks_received: Vec<NickCount>,
+    ops_given: Vec<NickCount>,
+    deops_given: Vec<NickCount>,
+}
+
+fn top_counts(map: HashMap<String, Count>) -> Vec<NickCount> {
+    let mut counts: Vec<NickCount> = map
+        .into_values()
+        .map(|c| NickCount {
+            nick: c.nick,
+            count: c.count,
+        })
+        .collect();
+    counts.sort_by_key(|count| Reverse(count.count));
+    counts.truncate(TOP_OTHER);
+    counts
+}
+
+fn build_report(
+    conn: &Connection,
+    network: &str,
+    target: &str,
+    from: Option<i64>,
+    to: Option<i64>,
+) -> rusqlite::Result<ChannelReport> {
+    let mut stmt = conn.prepare(
+        "SELECT day, data FROM stats_days
+         WHERE network = ?1 AND target_key = ?2
+           AND (?3 IS NULL OR day >= ?3) AND (?4 IS NULL OR day <= ?4)
+         ORDER BY day",
+    )?;
+    let rows = stmt
+        .query_map(
+            params![
+                network,
+                casefold(target),
+                from.map(local_day),
+                to.map(local_day)
+            ],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        )?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let days = rows.len();
+    let first_day = rows.first().map(|(day, _)| day.clone());
+    let last_day = rows.last().map(|(day, _)| day.clone());
+    let mut total = DayStats::default();
+    for (_, data) in rows {
+        if let Ok(stats) = serde_json::from_str::<DayStats>(&data) {
+            total.merge(stats);
+        }
+    }
+
+    let mut talkers: Vec<Talker> = total.talkers.into_values().collect();
+    talkers.sort_by_key(|talker| Reverse(talker.lines));
+    let total_lines = talkers.iter().map(|t| t.lines).sum();
+    let nicks: HashSet<String> = talkers.iter().map(|t| casefold(&t.nick)).collect();
+    talkers.truncate(TOP_TALKERS);
+
+    let mut words: Vec<WordReport> = total
+        .words
+        .into_iter()
+        .filter(|(word, _)| !nicks.contains(word))
+        .map(|(word, count)| WordReport { word, count })
+        .collect();
+    words.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+    words.truncate(TOP_WORDS);
+
+    let mut urls: Vec<UrlReport> = total
+        .urls
+        .into_iter()
+        .map(|(url, count)| UrlReport {
+            url,
+            count: count.count,
+            nick: count.nick,
+            time: count.time,
+        })
+        .collect();
+    urls.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| b.time.cmp(&a.time)));
+    urls.truncate(TOP_URLS);
+
+    total.longest.truncate(TOP_OTHER);
+
+    Ok(ChannelReport {
+        network: network.to_string(),
+        target: target.to_string(),
+        first_day,
+        last_day,
+        days,
+        total_lines,
+        talkers: talkers
+            .into_iter()
+            .map(|t| TalkerReport {
+                words_per_line: if t.lines > 0 {
+                    t.words as f64 / t.lines as f64
+                } else {
+                    0.0
+                },
+                nick: t.nick,
+                lines: t.lines,
+                words: t.words,
+                actions: t.actions,
+                quote: t.quote.map(|q| q.text),
+            })
+            .collect(),
+        hours: total.hours,
+        words,
+        longest_lines: total.longest,
+        urls,
+        kicks_given: top_counts(total.kicks_given),
+        kicks_received: top_counts(total.kicks_received),
+        ops_given: top_counts(total.ops_given),
+        deops_given: top_counts(total.deops_given),
+    })
+}
+
+fn html_table(out: &mut String, title: &str, headers: &[&str], rows: Vec<Vec<String>>) {
+    if rows.is_empty() {
+        return;
+    }
+    out.push_str("<h2>");
+    escape_html(title, out);
+    out.push_str("</h2>\n<table>\n<tr>");
+    for header in headers {
+        out.push_str("<th>");
+        escape_html(header, out);
+        out.push_str("</th>");
+    }
+    out.push_str("</tr>\n");
+    for row in rows {
+        out.push_str("<tr>");
+        for cell in row {
+            out.push_str("<td>");
+            escape_html(&cell, out);
+            out.push_str("</td>");
+        }
+        out.push_str("</tr>\n");
+    }
+    out.push_str("</table>\n");
+}
+
+fn counts_rows(counts: &[NickCount]) -> Vec<Vec<String>> {
+    counts
+        .iter()
+        .map(|c| vec![c.nick.clone(), c.count.to_string()])
+        .collect()
+}
+
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body { background: #313338; color: #dbdee1; font-family: system-ui, sans-serif; font-size: 14px; margin: 1.5em; }
+h1 { font-size: 1.4em; }
+h2 { font-size: 1.1em; margin-top: 1.5em; }
+table { border-collapse: collapse; }
+th, td { text-align: left; padding: 3px 10px; border-bottom: 1px solid #404249; vertical-align: top; }
+th { color: #949ba4; }
+.hours { display: flex; align-items: flex-end; gap: 3px; height: 120px; }
+.hours div { width: 22px; background: #5865f2; }
+.labels { display: flex; gap: 3px; color: #949ba4; font-size: 11px; }
+.labels span { width: 22px; text-align: center; }
+</style>
+</head>
+<body>
+"#;
+
+fn render_html(report: &ChannelReport) -> String {
+    let mut title = String::new();
+    escape_html(
+        &format!("Statistics for {} on {}", report.target, report.network),
+        &mut title,
+    );
+    let mut out = HTML_HEAD.replace("{title}", &title);
+    out.push_str(&format!("<h1>{}</h1>\n<p>", title));
+    escape_html(
+        &format!(
+            "{} lines over {} days ({} to {})",
+            report.total_lines,
+            report.days,
+            report.first_day.as_deref().unwrap_or("-"),
+            report.last_day.as_deref().unwrap_or("-")
+        ),
+        &mut out,
+    );
+    out.push_str("</p>\n");
+
+    out.push_str("<h2>Activity by hour</h2>\n<div class=\"hours\">");
+    let busiest = report.hours.iter().copied().max().unwrap_or(0).max(1);
+    for (hour, count) in report.hours.iter().enumerate() {
+        out.push_str(&format!(
+            "<div style=\"height:{}%\" title=\"{}:00 - {} lines\"></div>",
+            count * 100 / busiest,
+            hour,
+            count
+        ));
+    }
+    out.push_str("</div>\n<div class=\"labels\">");
+    for hour in 0..24 {
+        out.push_str(&format!("<span>{}</span>", hour));
+    }
+    out.push_str("</div>\n");
+
+    html_table(
+        &mut out,
+        "Top talkers",
+        &["Nick", "Lines", "Words", "Words per line", "Random quote"],
+        report
+            .talkers
+            .iter()
+            .map(|t| {
+                vec![
+                    t.nick.clone(),
+                    t.lines.to_string(),
+                    t.words.to_string(),
+                    format!("{:.1}", t.words_per_line),
+                    t.quote.clone().unwrap_or_default(),
+                ]
+            })
+            .collect(),
+    );
+    html_table(
+        &mut out,
+        "Most used words",
+        &["Word", "Uses"],
+        report
+            .words
+            .iter()
+            .map(|w| vec![w.word.clone(), w.count.to_string()])
+            .collect(),
+    );
+    html_table(
+        &mut out,
+        "Longest lines",
+        &["Time", "Nick", "Line"],
+        report
+            .longest_lines
+            .iter()
+            .map(|q| vec![local_time(q.time), q.nick.clone(), q.text.clone()])
+            .collect(),
+    );
+    html_table(
+        &mut out,
+        "Most shared URLs",
+        &["URL", "Times", "First shared by"],
+        report
+            .urls
+            .iter()
+            .map(|u| vec![u.url.clone(), u.count.to_string(), u.nick.clone()])
+            .collect(),
+    );
+    html_table(
+        &mut out,
+        "Most kicks given",
+        &["Nick", "Kicks"],
+        counts_rows(&report.kicks_given),
+    );
+    html_table(
+        &mut out,
+        "Most kicked",
+        &["Nick", "Kicked"],
+        counts_rows(&report.kicks_received),
+    );
+    html_table(
+        &mut out,
+        "Most ops given",
+        &["Nick", "Ops"],
+        counts_rows(&report.ops_given),
+    );
+    html_table(
+        &mut out,
+        "Most ops taken",
+        &["Nick", "Deops"],
+        counts_rows(&report.deops_given),
+    );
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    Html,
+    Json,
+}
+
+/// Generate a pisg-style statistics report for a channel from stored history.
+///
+/// Aggregates are cached per day, so only messages stored since the last
+/// report are analysed. `from` and `to` (milliseconds since the Unix epoch)
+/// select whole local days. Returns the report as an HTML document or JSON.
+#[tauri::command]
+pub async fn channel_stats_report(
+    network: String,
+    target: String,
+    from: Option<i64>,
+    to: Option<i64>,
+    format: ReportFormat,
+    history: State<'_, HistoryState>,
+) -> Result<String, String> {
+    let report = history
+        .0
+        .with_conn(move |conn| {
+            catch_up(conn, &network, &casefold(&target))?;
+            build_report(conn, &network, &target, from, to)
+        })
+        .await?;
+    match format {
+        ReportFormat::Html => Ok(render_html(&report)),
+        ReportFormat::Json => serde_json::to_string(&report).map_err(|e| e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::{insert_messages, prune, HistoryStore, NewMessage};
+
+    fn day(lines: &[(&str, &str, &str)]) -> DayStats {
+        let mut stats = DayStats::default();
+        for (id, (command, sender, text)) in lines.iter().enumerate() {
+            stats.add(id as i64, 0, command, sender, text);
+        }
+        stats
+    }
+
+    #[test]
+    fn counts_lines_words_and_events() {
+        let stats = day(&[
+            ("PRIVMSG", "Alice", "hello there everyone"),
+            ("PRIVMSG", "alice", "\x01ACTION waves hello\x01"),
+            ("PRIVMSG", "alice", "\x01VERSION\x01"),
+            ("PRIVMSG", "bob", "see https://example.com HELLO"),
+            ("KICK", "op", "bob flooding"),
+            ("MODE", "op", "+oo-o alice bob carol"),
+        ]);
+        let alice = &stats.talkers["alice"];
+        assert_eq!((alice.lines, alice.words, alice.actions), (2, 5, 1));
+        assert_eq!(stats.talkers["bob"].lines, 1);
+        assert_eq!(stats.words["hello"], 3);
+        assert!(!stats.words.contains_key("the"));
+        assert_eq!(stats.urls["https://example.com"].nick, "bob");
+        assert_eq!(stats.kicks_given["op"].count, 1);
+        assert_eq!(stats.kicks_received["bob"].count, 1);
+        assert_eq!(stats.ops_given["op"].count, 2);
+        assert_eq!(stats.deops_given["op"].count, 1);
+        assert_eq!(stats.hours.iter().sum::<u64>(), 3);
+    }
+
+    #[test]
+    fn merging_days_adds_up() {
+        let mut first = day(&[("PRIVMSG", "alice", "first link https://a.example")]);
+        first.urls.get_mut("https://a.example").unwrap().time = 10;
+        let mut second = day(&[
+            ("PRIVMSG", "ALICE", "again https://a.example"),
+            ("PRIVMSG", "bob", "mine https://a.example"),
+        ]);
+        for url in second.urls.values_mut() {
+            url.time = 20;
+        }
+        first.merge(second);
+        assert_eq!(first.talkers["alice"].lines, 2);
+        assert_eq!(first.talkers["alice"].nick, "ALICE");
+        let url = &first.urls["https://a.example"];
+        assert_eq!((url.count, url.nick.as_str(), url.time), (3, "alice", 10));
+    }
+
+    #[test]
+    fn report_catches_up_incrementally() {
+        let dir = std::env::temp_dir().join(format!("channel-stats-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = HistoryStore::open(&dir.join("history.sqlite3"), false).unwrap();
+        let line = |time: i64, sender: &str, text: &str| NewMessage {
+            network: "libera".to_string(),
+            target: Some("#Chan".to_string()),
+            sender: Some(sender.to_string()),
+            command: "PRIVMSG".to_string(),
+            text: Some(text.to_string()),
+            msgid: None,
+            time,
+            tags: "{}".to_string(),
+            outgoing: false,
+            import_key: None,
+        };
+        let day_ms = 24 * 60 * 60 * 1000;
+        let mut conn = store.lock_conn().unwrap();
+        insert_messages(
+            &mut conn,
+            &[line(0, "alice", "one"), line(day_ms * 3, "bob", "two")],
+        )
+        .unwrap();
+        catch_up(&mut conn, "libera", "#chan").unwrap();
+        insert_messages(&mut conn, &[line(day_ms * 3 + 1, "bob", "three")]).unwrap();
+        catch_up(&mut conn, "libera", "#chan").unwrap();
+
+        let report = build_report(&conn, "libera", "#chan", None, None).unwrap();
+        assert_eq!(report.total_lines, 3);
+        assert_eq!(report.days, 2);
+        assert_eq!(report.talkers[0].nick, "bob");
+        assert_eq!(report.talkers[0].lines, 2);
+        assert!(render_html(&report).contains("<td>bob</td><td>2</td>"));
+
+        drop(conn);
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn retention_drops_cached_stats() {
+        let dir = std::env::temp_dir().join(format!("channel-stats-prune-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let store = HistoryStore::open(&dir.join("history.sqlite3"), false).unwrap();
+        let line = |time: i64, text: &str| NewMessage {
+            network: "libera".to_string(),
+            target: Some("#chan".to_string()),
+            sender: Some("alice".to_string()),
+            command: "PRIVMSG".to_string(),
+            text: Some(text.to_string()),
+            msgid: None,
+            time,
+            tags: "{}".to_string(),
+            outgoing: false,
+            import_key: None,
+        };
+        let now = crate::stats::now_millis() as i64;
+        let mut conn = store.lock_conn().unwrap();
+        insert_messages(
+            &mut conn,
+            &[line(0, "old https://old.example"), line(now, "new")],
+        )
+        .unwrap();
+        catch_up(&mut conn, "libera", "#chan").unwrap();
+        conn.execute(
+            "INSERT INTO meta (key, value) VALUES ('retention_days', '30')",
+            [],
+        )
+        .unwrap();
+        assert_eq!(prune(&conn).unwrap(), 1);
+        catch_up(&mut conn, "libera", "#chan").unwrap();
+
+        let report = build_report(&conn, "libera", "#chan", None, None).unwrap();
+        assert_eq!(report.total_lines, 1);
+        assert_eq!(report.days, 1);
+        assert!(report.urls.is_empty());
+
+        drop(conn);
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
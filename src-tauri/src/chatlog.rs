@@ -0,0 +1,676 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Local, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use tauri::State;
+
+use crate::history::{casefold, NewMessage};
+use crate::irc::{ctcp_action, is_ctcp, strip_formatting};
+use crate::util::{load_json, save_json};
+use crate::vault::Vault;
+
+/// Open files kept around before all handles are closed again
+const MAX_OPEN_FILES: usize = 64;
+/// Lines held in memory while the app is locked
+const MAX_PENDING: usize = 50_000;
+/// Suffix of logs encrypted by the app lock; each line is one sealed record
+const ENCRYPTED_SUFFIX: &str = ".enc";
+
+/// Line layout of the written logs
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStyle {
+    /// Tab separated `time<TAB>prefix<TAB>message`, as written by weechat
+    Weechat,
+    /// `time <nick> message` with `-!-` for events, as written by irssi
+    Irssi,
+}
+
+/// What `{date}` expands to in the path template
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRotation {
+    Daily,
+    Monthly,
+}
+
+impl LogRotation {
+    fn date_format(self) -> &'static str {
+        match self {
+            LogRotation::Daily => "%Y-%m-%d",
+            LogRotation::Monthly => "%Y-%m",
+        }
+    }
+}
+
+/// Plain-text chat log settings, stored as JSON in the app config directory
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChatLogConfig {
+    enabled: bool,
+    /// Root directory; defaults to `logs` in the app data directory
+    directory: Option<String>,
+    /// Relative path with `{network}`, `{channel}`, `{date}` and strftime codes
+    path_template: String,
+    timestamp_format: String,
+    style: LogStyle,
+    rotation: LogRotation,
+    strip_formatting: bool,
+    /// Targets not to log, per network, case-folded
+    excluded_targets: HashMap<String, Vec<String>>,
+    /// Networks for which nothing is persisted at all
+    incognito_networks: Vec<String>,
+}
+
+impl Default for ChatLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: None,
+            path_template: "{network}/{channel}/{date}.log".to_string(),
+            timestamp_format: "%Y-%m-%d %H:%M:%S".to_string(),
+            style: LogStyle::Weechat,
+            rotation: LogRotation::Monthly,
+            strip_formatting: true,
+            excluded_targets: HashMap::new(),
+            incognito_networks: Vec::new(),
+        }
+    }
+}
+
+impl ChatLogConfig {
+    fn validate(&self) -> Result<(), String> {
+        let invalid =
+            |format: &str| StrftimeItems::new(format).any(|item| matches!(item, Item::Error));
+        if invalid(&self.path_template) {
+            return Err(format!("Invalid path template: {}", self.path_template));
+        }
+        if invalid(&self.timestamp_format) {
+            return Err(format!(
+                "Invalid timestamp format: {}",
+                self.timestamp_format
+            ));
+        }
+        if self.path_template.trim().is_empty() {
+            return Err("The path template must not be empty".to_string());
+        }
+        Ok(())
+    }
+
+    fn is_excluded(&self, network: &str, target: &str) -> bool {
+        let target = casefold(target);
+        self.excluded_targets
+            .get(network)
+            .is_some_and(|targets| targets.contains(&target))
+    }
+}
+
+/// Make a network or target name safe to use as one path component
+pub(crate) fn path_component(name: &str) -> String {
+    let name = name.split_once("://").map_or(name, |(_, rest)| rest);
+    let cleaned: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '\0' => '_',
+            c => c,
+        })
+        .collect();
+    if cleaned.is_empty() || cleaned.starts_with('.') {
+        format!("_{}", cleaned)
+    } else {
+        cleaned
+    }
+}
+
+enum WriterCommand {
+    Append(PathBuf, String),
+    /// Close every file so the next line opens it again
+    Close,
+    /// Close every file, report back and wait until the receiver is
+    /// disconnected, so logs can be rewritten meanwhile
+    Pause(mpsc::Sender<()>, mpsc::Receiver<()>),
+}
+
+/// Append a line, opening the file if needed
+fn append_line(
+    files: &mut HashMap<PathBuf, BufWriter<File>>,
+    path: &Path,
+    line: &str,
+) -> std::io::Result<()> {
+    if !files.contains_key(path) {
+        if files.len() >= MAX_OPEN_FILES {
+            files.clear();
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        files.insert(path.to_path_buf(), BufWriter::new(file));
+    }
+    let file = files.get_mut(path).expect("file was just opened");
+    writeln!(file, "{}", line)
+}
+
+/// Background thread that owns the open files, flushing them whenever the
+/// queue runs dry so the logs are complete even if the app crashes
+fn run_writer(rx: mpsc::Receiver<WriterCommand>) {
+    let mut files = HashMap::new();
+    while let Ok(first) = rx.recv() {
+        for command in std::iter::once(first).chain(rx.try_iter()) {
+            match command {
+                WriterCommand::Append(path, line) => {
+                    if let Err(e) = append_line(&mut files, &path, &line) {
+                        log::warn!("Chat log write error in {}: {}", path.display(), e);
+                    }
+                }
+                WriterCommand::Close => files.clear(),
+                WriterCommand::Pause(paused, resume) => {
+                    files.clear();
+                    let _ = paused.send(());
+                    let _ = resume.recv();
+                }
+            }
+        }
+        for (path, file) in files.iter_mut() {
+            if let Err(e) = file.flush() {
+                log::warn!("Chat log write error in {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+/// Writes messages to one text file per network and target.
+///
+/// Lines go through a channel to a dedicated writer thread, so the socket
+/// tasks never wait on the disk. With the app lock on, every line is
+/// encrypted on its own and written as base64 to a `.enc` file; while
+/// locked, lines wait in memory.
+pub struct ChatLogger {
+    config: RwLock<ChatLogConfig>,
+    config_path: PathBuf,
+    default_dir: PathBuf,
+    writer: Mutex<mpsc::Sender<WriterCommand>>,
+    vault: Arc<Vault>,
+    pending: Mutex<Vec<(PathBuf, String)>>,
+}
+
+impl ChatLogger {
+    /// Load the settings from `config_path`, falling back to defaults
+    pub fn load(config_path: PathBuf, default_dir: PathBuf, vault: Arc<Vault>) -> Self {
+        let config = load_json(&config_path);
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || run_writer(rx));
+        Self {
+            config: RwLock::new(config),
+            config_path,
+            default_dir,
+            writer: Mutex::new(tx),
+            vault,
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn send(&self, command: WriterCommand) {
+        let writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writer.send(command);
+    }
+
+    fn config(&self) -> ChatLogConfig {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ChatLogConfig)) -> Result<(), String> {
+        let mut config = self.config();
+        f(&mut config);
+        config.validate()?;
+        save_json(&self.config_path, &config, "log settings")?;
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+        // Paths may have changed; reopen files on the next write
+        self.send(WriterCommand::Close);
+        Ok(())
+    }
+
+    /// Whether nothing at all may be persisted for this network
+    pub fn is_incognito(&self, network: &str) -> bool {
+        let config = self.config.read().unwrap_or_else(|e| e.into_inner());
+        config.incognito_networks.iter().any(|n| n == network)
+    }
+
+    /// Log a message to the file of `channel`, which may differ from its target
+    /// for events like QUIT and NICK that are shown in every shared channel
+    pub fn write(&self, message: &NewMessage, channel: &str) {
+        let config = self.config.read().unwrap_or_else(|e| e.into_inner());
+        if !config.enabled
+            || config.incognito_networks.contains(&message.network)
+            || config.is_excluded(&message.network, channel)
+        {
+            return;
+        }
+        let Some(time) = Local.timestamp_millis_opt(message.time).single() else {
+            return;
+        };
+        let Some(line) = format_line(&config, message, &time) else {
+            return;
+        };
+
+        let Some(relative) = relative_path(&config, &message.network, channel, &time) else {
+            return;
+        };
+        let base = self.base_dir(&config);
+        drop(config);
+
+        self.append(&base.join(relative), &line);
+    }
+
+    fn base_dir(&self, config: &ChatLogConfig) -> PathBuf {
+        config
+            .directory
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.default_dir.clone())
+    }
+
+    fn append(&self, path: &Path, line: &str) {
+        if !self.vault.is_enabled() {
+            return self.send(WriterCommand::Append(path.to_path_buf(), line.to_string()));
+        }
+        match self.vault.seal(line.as_bytes()) {
+            Some(sealed) => self.send(WriterCommand::Append(
+                encrypted_path(path),
+                BASE64.encode(sealed),
+            )),
+            None => {
+                let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+                if pending.len() >= MAX_PENDING {
+                    pending.remove(0);
+                }
+                pending.push((path.to_path_buf(), line.to_string()));
+            }
+        }
+    }
+
+    /// Write the lines held while the app was locked
+    pub fn flush_pending(&self) {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap_or_else(|e| e.into_inner()));
+        for (path, line) in pending {
+            self.append(&path, &line);
+        }
+    }
+
+    /// Encrypt every log in the log directory when the app lock is turned on,
+    /// or decrypt them when it is turned off. Needs the vault unlocked.
+    ///
+    /// The directory may be shared with other files, so only those named the
+    /// way the path template names logs are touched.
+    pub fn convert(&self, vault: &Vault, encrypt: bool) -> Result<(), String> {
+        let config = self.config();
+        let base = self.base_dir(&config);
+        let depth = template_patterns(&config.path_template).len();
+        // Pause the writer so no line is written while a log is rewritten;
+        // it resumes when `_resume` is dropped
+        let (paused, wait) = mpsc::channel();
+        let (_resume, resume) = mpsc::channel();
+        self.send(WriterCommand::Pause(paused, resume));
+        let _ = wait.recv();
+
+        let mut dirs = vec![(base.clone(), 1)];
+        while let Some((dir, level)) = dirs.pop() {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(format!("Failed to read {}: {}", dir.display(), e)),
+            };
+            for path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+                if path.is_dir() {
+                    if level < depth {
+                        dirs.push((path, level + 1));
+                    }
+                    continue;
+                }
+                let name = path.to_string_lossy();
+                let is_encrypted = name.ends_with(ENCRYPTED_SUFFIX);
+                if is_encrypted == encrypt || !path.is_file() {
+                    continue;
+                }
+                let plain = if is_encrypted {
+                    PathBuf::from(&name[..name.len() - ENCRYPTED_SUFFIX.len()])
+                } else {
+                    path.clone()
+                };
+                let is_log = plain
+                    .strip_prefix(&base)
+                    .is_ok_and(|relative| matches_template(&config.path_template, relative));
+                if !is_log {
+                    continue;
+                }
+                convert_file(vault, &path, encrypt)
+                    .map_err(|e| format!("Failed to convert {}: {}", path.display(), e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_os_string();
+    encrypted.push(ENCRYPTED_SUFFIX);
+    PathBuf::from(encrypted)
+}
+
+/// Rewrite one log as encrypted or plaintext. A log that already exists in
+/// the other form is merged, older lines first.
+fn convert_file(vault: &Vault, path: &Path, encrypt: bool) -> Result<(), String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidData => {
+            log::warn!("Not converting {}, which isn't a text file", path.display());
+            return Ok(());
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+    let mut converted = String::new();
+    for line in content.lines() {
+        let line = if encrypt {
+            let sealed = vault.seal(line.as_bytes()).ok_or("The app is locked")?;
+            BASE64.encode(sealed)
+        } else {
+            let sealed = BASE64.decode(line).map_err(|e| e.to_string())?;
+            String::from_utf8_lossy(&vault.open(&sealed)?).into_owned()
+        };
+        converted.push_str(&line);
+        converted.push('\n');
+    }
+
+    let target = if encrypt {
+        encrypted_path(path)
+    } else {
+        let name = path.to_string_lossy();
+        PathBuf::from(&name[..name.len() - ENCRYPTED_SUFFIX.len()])
+    };
+    let existing = match fs::read_to_string(&target) {
+        Ok(existing) => existing,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.to_string()),
+    };
+    // Plaintext lines predate encrypted ones
+    let merged = if encrypt {
+        converted + &existing
+    } else {
+        existing + &converted
+    };
+    let tmp = target.with_extension("tmp");
+    fs::write(&tmp, merged)
+        .and_then(|()| fs::rename(&tmp, &target))
+        .and_then(|()| fs::remove_file(path))
+        .map_err(|e| e.to_string())
+}
+
+/// One path component of the template, with None for each placeholder or
+/// strftime code
+fn template_patterns(template: &str) -> Vec<Vec<Option<char>>> {
+    template
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut pattern = Vec::new();
+            let mut rest = part;
+            while let Some(c) = rest.chars().next() {
+                if let Some(after) = ["{network}", "{channel}", "{date}"]
+                    .iter()
+                    .find_map(|placeholder| rest.strip_prefix(placeholder))
+                {
+                    pattern.push(None);
+                    rest = after;
+                    continue;
+                }
+                rest = &rest[c.len_utf8()..];
+                if c != '%' {
+                    pattern.push(Some(c));
+                } else if let Some(after) = rest.strip_prefix('%') {
+                    pattern.push(Some('%'));
+                    rest = after;
+                } else {
+                    // Flags and padding up to the conversion letter
+                    let end = rest
+                        .find(|c: char| c.is_ascii_alphabetic())
+                        .map_or(rest.len(), |i| i + 1);
+                    pattern.push(None);
+                    rest = &rest[end..];
+                }
+            }
+            pattern
+        })
+        .collect()
+}
+
+/// Whether the path template could have produced `relative`
+fn matches_template(template: &str, relative: &Path) -> bool {
+    fn matches(pattern: &[Option<char>], name: &[char]) -> bool {
+        match pattern.split_first() {
+            None => name.is_empty(),
+            Some((Some(c), rest)) => name.first() == Some(c) && matches(rest, &name[1..]),
+            Some((None, rest)) => (1..=name.len()).any(|n| matches(rest, &name[n..])),
+        }
+    }
+    let patterns = template_patterns(template);
+    let components: Vec<Vec<char>> = relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().chars().collect())
+        .collect();
+    components.len() == patterns.len()
+        && patterns
+            .iter()
+            .zip(&components)
+            .all(|(pattern, name)| matches(pattern, name))
+}
+
+/// Expand the path template for a message sent at `time`
+fn relative_path(
+    config: &ChatLogConfig,
+    network: &str,
+    channel: &str,
+    time: &chrono::DateTime<Local>,
+) -> Option<String> {
+    let mut relative = String::new();
+    let template = config
+        .path_template
+        .replace("{date}", config.rotation.date_format());
+    write!(relative, "{}", time.format(&template)).ok()?;
+    Some(
+        relative
+            .replace("{network}", &path_component(network))
+            .replace("{channel}", &path_component(&casefold(channel))),
+    )
+}
+
+/// Render a message in the configured log style
+fn format_line(
+    config: &ChatLogConfig,
+    message: &NewMessage,
+    time: &chrono::DateTime<Local>,
+) -> Option<String> {
+    let mut timestamp = String::new();
+    write!(timestamp, "{}", time.format(&config.timestamp_format)).ok()?;
+    let nick = message.sender.as_deref().unwrap_or("*");
+    let target = message.target.as_deref().unwrap_or("");
+    let raw_text = message.text.as_deref().unwrap_or("");
+    let text = if config.strip_formatting {
+        strip_formatting(raw_text)
+    } else {
+        raw_text.to_string()
+    };
+    // KICK stores "victim reason", MODE stores "modes args"
+    let (first, rest) = text.split_once(' ').unwrap_or((text.as_str(), ""));
+
+    let line = match (config.style, message.command.as_str()) {
+        (_, "PRIVMSG" | "NOTICE") if is_ctcp(raw_text) => return None,
+        (LogStyle::Weechat, "PRIVMSG") => match ctcp_action(&text) {
+            Some(action) => format!("{}\t *\t{} {}", timestamp, nick, action),
+            None => format!("{}\t{}\t{}", timestamp, nick, text),
+        },
+        (LogStyle::Weechat, "NOTICE") => format!("{}\t--\tNotice({}): {}", timestamp, nick, text),
+        (LogStyle::Weechat, "JOIN") => {
+            format!("{}\t-->\t{} has joined {}", timestamp, nick, target)
+        }
+        (LogStyle::Weechat, "PART") => format!(
+            "{}\t<--\t{} has left {} ({})",
+            timestamp, nick, target, text
+        ),
+        (LogStyle::Weechat, "QUIT") => format!("{}\t<--\t{} has quit ({})", timestamp, nick, text),
+        (LogStyle::Weechat, "KICK") => format!(
+            "{}\t<--\t{} has kicked {} ({})",
+            timestamp, nick, first, rest
+        ),
+        (LogStyle::Weechat, "NICK") => {
+            format!("{}\t--\t{} is now known as {}", timestamp, nick, text)
+        }
+        (LogStyle::Weechat, "TOPIC") => {
+            format!(
+                "{}\t--\t{} has changed topic for {} to \"{}\"",
+                timestamp, nick, target, text
+            )
+        }
+        (LogStyle::Weechat, "MODE") => {
+            format!("{}\t--\tMode {} [{}] by {}", timestamp, target, text, nick)
+        }
+        (LogStyle::Irssi, "PRIVMSG") => match ctcp_action(&text) {
+            Some(action) => format!("{}  * {} {}", timestamp, nick, action),
+            None => format!("{} <{}> {}", timestamp, nick, text),
+        },
+        (LogStyle::Irssi, "NOTICE") => format!("{} -{}- {}", timestamp, nick, text),
+        (LogStyle::Irssi, "JOIN") => format!("{} -!- {} has joined {}", timestamp, nick, target),
+        (LogStyle::Irssi, "PART") => {
+            format!("{} -!- {} has left {} [{}]", timestamp, nick, target, text)
+        }
+        (LogStyle::Irssi, "QUIT") => format!("{} -!- {} has quit [{}]", timestamp, nick, text),
+        (LogStyle::Irssi, "KICK") => {
+            format!(
+                "{} -!- {} was kicked from {} by {} [{}]",
+                timestamp, first, target, nick, rest
+            )
+        }
+        (LogStyle::Irssi, "NICK") => format!("{} -!- {} is now known as {}", timestamp, nick, text),
+        (LogStyle::Irssi, "TOPIC") => {
+            format!(
+                "{} -!- {} changed the topic of {} to: {}",
+                timestamp, nick, target, text
+            )
+        }
+        (LogStyle::Irssi, "MODE") => {
+            format!("{} -!- mode/{} [{}] by {}", timestamp, target, text, nick)
+        }
+        _ => return None,
+    };
+    Some(line)
+}
+
+/// Shared plain-text chat logger
+pub struct ChatLogState(pub(crate) Arc<ChatLogger>);
+
+#[tauri::command]
+pub async fn chat_log_get_config(
+    chat_log: State<'_, ChatLogState>,
+) -> Result<ChatLogConfig, String> {
+    Ok(chat_log.0.config())
+}
+
+#[tauri::command]
+pub async fn chat_log_set_config(
+    config: ChatLogConfig,
+    chat_log: State<'_, ChatLogState>,
+) -> Result<(), String> {
+    chat_log.0.update(|current| *current = config)
+}
+
+/// Turn logging of a single channel or query on or off
+#[tauri::command]
+pub async fn chat_log_set_target_enabled(
+    network: String,
+    target: String,
+    enabled: bool,
+    chat_log: State<'_, ChatLogState>,
+) -> Result<(), String> {
+    let target = casefold(&target);
+    chat_log.0.update(|config| {
+        let excluded = config.excluded_targets.entry(network).or_default();
+        excluded.retain(|t| *t != target);
+        if !enabled {
+            excluded.push(target);
+        }
+        config
+            .excluded_targets
+            .retain(|_, targets| !targets.is_empty());
+    })
+}
+
+/// Mark a network as incognito: no chat logs and no history are written for it
+#[tauri::command]
+pub async fn set_network_incognito(
+    network: String,
+    incognito: bool,
+    chat_log: State<'_, ChatLogState>,
+) -> Result<(), String> {
+    chat_log.0.update(|config| {
+        config.incognito_networks.retain(|n| *n != network);
+        if incognito {
+            config.incognito_networks.push(network);
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_template_follows_rotation() {
+        let time = Local.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+        let mut config = ChatLogConfig::default();
+        assert_eq!(
+            relative_path(&config, "ircs://irc.libera.chat:6697", "#Rust", &time).as_deref(),
+            Some("irc.libera.chat_6697/#rust/2024-03.log")
+        );
+        config.rotation = LogRotation::Daily;
+        assert_eq!(
+            relative_path(&config, "irc.libera.chat", "#rust", &time).as_deref(),
+            Some("irc.libera.chat/#rust/2024-03-09.log")
+        );
+    }
+
+    #[test]
+    fn path_components_stay_inside_the_log_directory() {
+        assert_eq!(path_component("../etc"), "_.._etc");
+        assert_eq!(path_component("a/b\\c"), "a_b_c");
+        assert_eq!(path_component(""), "_");
+    }
+
+    #[test]
+    fn only_template_paths_are_logs() {
+        let template = ChatLogConfig::default().path_template;
+        let is_log = |path: &str| matches_template(&template, Path::new(path));
+        assert!(is_log("irc.libera.chat_6697/#rust/2024-03.log"));
+        assert!(is_log("irc.libera.chat/#rust/2024-03-09.log"));
+        assert!(!is_log("irc.libera.chat/#rust/2024-03.txt"));
+        assert!(!is_log("irc.libera.chat/#rust/.log"));
+        assert!(!is_log("notes.log"));
+        assert!(!is_log("Documents/work/old/report.log"));
+
+        let template = "%Y/{network}-{channel}.%m.txt";
+        assert!(matches_template(
+            template,
+            Path::new("2024/libera-#rust.03.txt")
+        ));
+        assert!(!matches_template(template, Path::new("2024/libera.03.txt")));
+        assert!(matches_template("100%%/{channel}", Path::new("100%/#rust")));
+    }
+}
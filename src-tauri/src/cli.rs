@@ -0,0 +1,260 @@
+use log::LevelFilter;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tauri::State;
+
+pub const USAGE: &str = "\
+Usage: obsidianirc [OPTIONS] [IRC-URL...]
+
+Arguments:
+  [IRC-URL...]             irc://, ircs:// or web client links to open
+
+Options:
+      --profile <NAME>     Use a separate profile
+      --data-dir <PATH>    Keep all data in PATH (portable mode)
+      --connect <NETWORK>  Connect to a saved network, may be repeated
+      --minimized          Start minimized
+      --no-autoconnect     Don't connect to saved networks on startup
+      --log-level <LEVEL>  off, error, warn, info, debug or trace
+  -h, --help               Print help
+  -V, --version            Print version";
+
+/// Options the app was started with, handed to the frontend by `startup_config`
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupOptions {
+    /// Links to open; they reach the frontend as "open-irc-url" events
+    #[serde(skip)]
+    pub urls: Vec<String>,
+    pub profile: Option<String>,
+    pub data_dir: Option<PathBuf>,
+    /// Saved networks to connect to, by name or host
+    pub connect: Vec<String>,
+    pub minimized: bool,
+    pub no_autoconnect: bool,
+    #[serde(skip)]
+    pub log_level: Option<LevelFilter>,
+}
+
+/// What the binary should do with its arguments
+#[derive(Debug)]
+pub enum Command {
+    Run(StartupOptions),
+    /// Print this and exit (`--help`, `--version`)
+    Print(String),
+}
+
+/// Parse the arguments after the program name. Relative paths are resolved
+/// against `cwd`, which is the other process' directory for forwarded ones.
+pub fn parse<I, S>(args: I, cwd: &Path) -> Result<Command, String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut options = StartupOptions::default();
+    let mut args = args.into_iter().map(Into::into);
+    let mut only_urls = false;
+
+    while let Some(arg) = args.next() {
+        if only_urls || !arg.starts_with('-') {
+            options.urls.push(arg);
+            continue;
+        }
+        // macOS adds a process serial number when launched from Finder
+        if arg.starts_with("-psn_") {
+            continue;
+        }
+
+        let (name, inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let mut value = |name: &str| {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("{} needs a value", name))
+        };
+
+        match name {
+            "--" => only_urls = true,
+            "-h" | "--help" => return Ok(Command::Print(USAGE.to_string())),
+            "-V" | "--version" => {
+                return Ok(Command::Print(format!(
+                    "obsidianirc {}",
+                    env!("CARGO_PKG_VERSION")
+                )))
+            }
+            "--profile" => {
+                let profile = value(name)?;
+                validate_profile(&profile)?;
+                options.profile = Some(profile);
+            }
+            "--data-dir" => {
+                let dir = value(name)?;
+                if dir.is_empty() {
+                    return Err("--data-dir needs a value".to_string());
+                }
+                options.data_dir = Some(cwd.join(dir));
+            }
+            "--connect" => {
+                let network = value(name)?;
+                if network.trim().is_empty() {
+                    return Err("--connect needs a value".to_string());
+                }
+                options.connect.push(network);
+            }
+            "--log-level" => {
+                let level = value(name)?;
+                options.log_level = Some(
+                    LevelFilter::from_str(&level)
+                        .map_err(|_| format!("Unknown log level: {}", level))?,
+                );
+            }
+            "--minimized" | "--no-autoconnect" if inline_value.is_some() => {
+                return Err(format!("{} doesn't take a value", name));
+            }
+            "--minimized" => options.minimized = true,
+            "--no-autoconnect" => options.no_autoconnect = true,
+            _ => return Err(format!("Unknown option: {}", name)),
+        }
+    }
+
+    Ok(Command::Run(options))
+}
+
+/// Profile names become directory names
+pub(crate) fn validate_profile(profile: &str) -> Result<(), String> {
+    let valid = !profile.is_empty()
+        && profile.len() <= 64
+        && !profile.starts_with('.')
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid profile name: {:?} (use letters, digits, '-', '_' and '.')",
+            profile
+        ))
+    }
+}
+
+pub struct StartupState(pub StartupOptions);
+
+#[tauri::command]
+pub fn startup_config(state: State<'_, StartupState>) -> StartupOptions {
+    state.0.clone()
+}
+
+/// Act on the command line of a second instance that found us already running
+#[cfg(desktop)]
+pub fn forward(app: &tauri::AppHandle, options: StartupOptions) {
+    use tauri::Emitter;
+
+    if !options.minimized {
+        crate::deeplink::focus_main_window(app);
+    }
+    crate::deeplink::open_links(
+        app,
+        options
+            .urls
+            .iter()
+            .filter(|url| !crate::deeplink::delivered_by_plugin(url)),
+    );
+    if !options.connect.is_empty() {
+        let _ = app.emit("cli-args", &options);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<StartupOptions, String> {
+        match parse(args.iter().copied(), Path::new("/home/me"))? {
+            Command::Run(options) => Ok(options),
+            Command::Print(text) => Err(format!("unexpected print: {}", text)),
+        }
+    }
+
+    #[test]
+    fn collects_urls_and_options() {
+        let options = run(&[
+            "ircs://irc.libera.chat/#rust",
+            "--profile",
+            "work",
+            "--connect=Libera",
+            "--connect",
+            "OFTC",
+            "--minimized",
+            "--no-autoconnect",
+            "--log-level",
+            "debug",
+        ])
+        .unwrap();
+        assert_eq!(options.urls, vec!["ircs://irc.libera.chat/#rust"]);
+        assert_eq!(options.profile.as_deref(), Some("work"));
+        assert_eq!(options.connect, vec!["Libera", "OFTC"]);
+        assert!(options.minimized);
+        assert!(options.no_autoconnect);
+        assert_eq!(options.log_level, Some(LevelFilter::Debug));
+    }
+
+    #[test]
+    fn resolves_data_dir_against_cwd() {
+        let options = run(&["--data-dir", "portable"]).unwrap();
+        assert_eq!(options.data_dir, Some(PathBuf::from("/home/me/portable")));
+        let options = run(&["--data-dir=/srv/irc"]).unwrap();
+        assert_eq!(options.data_dir, Some(PathBuf::from("/srv/irc")));
+    }
+
+    #[test]
+    fn double_dash_ends_options() {
+        let options = run(&["--", "--minimized"]).unwrap();
+        assert_eq!(options.urls, vec!["--minimized"]);
+        assert!(!options.minimized);
+    }
+
+    #[test]
+    fn skips_macos_process_serial_number() {
+        let options = run(&["-psn_0_12345"]).unwrap();
+        assert!(options.urls.is_empty());
+    }
+
+    #[test]
+    fn help_and_version_print() {
+        for arg in ["-h", "--help", "-V", "--version"] {
+            assert!(matches!(
+                parse([arg], Path::new("/")),
+                Ok(Command::Print(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for args in [
+            &["--profile"][..],
+            &["--profile", "../etc"],
+            &["--profile", ".hidden"],
+            &["--data-dir="],
+            &["--connect", " "],
+            &["--log-level", "loud"],
+            &["--minimized=yes"],
+            &["--frobnicate"],
+        ] {
+            assert!(run(args).is_err(), "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn validates_profile_names() {
+        assert!(validate_profile("work-2.test_a").is_ok());
+        assert!(validate_profile("").is_err());
+        assert!(validate_profile("a/b").is_err());
+        assert!(validate_profile(&"x".repeat(65)).is_err());
+    }
+}
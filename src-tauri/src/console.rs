@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::State;
+use tokio::sync::{watch, Mutex};
+
+use crate::socket::SocketState;
+use crate::tap::TapState;
+
+/// Filter and pause settings of one raw console window
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ConsoleSettings {
+    paused: bool,
+    /// Commands or numerics to show; entries starting with `-` are hidden instead
+    commands: Vec<String>,
+}
+
+impl ConsoleSettings {
+    fn shows(&self, command: &str) -> bool {
+        if self.paused {
+            return false;
+        }
+        let mut included = None;
+        for filter in &self.commands {
+            if let Some(excluded) = filter.strip_prefix('-') {
+                if excluded == command {
+                    return false;
+                }
+            } else {
+                included = Some(included.unwrap_or(false) || filter == command);
+            }
+        }
+        included.unwrap_or(true)
+    }
+}
+
+/// Settings channels of the open raw console windows, keyed by window label
+pub struct ConsoleState(pub(crate) Arc<Mutex<HashMap<String, watch::Sender<ConsoleSettings>>>>);
+
+/// Open a window mirroring the raw traffic of one connection, or of all of them.
+/// Returns the window label used by the filter and pause commands.
+#[tauri::command]
+pub async fn open_raw_console(
+    client_id: Option<String>,
+    sockets: State<'_, SocketState>,
+    taps: State<'_, TapState>,
+    consoles: State<'_, ConsoleState>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    open_raw_console_impl(client_id, &sockets, &taps, &consoles, app_handle).await
+}
+
+#[cfg(desktop)]
+async fn open_raw_console_impl(
+    client_id: Option<String>,
+    sockets: &SocketState,
+    taps: &TapState,
+    consoles: &ConsoleState,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    use tauri::{Emitter, Manager, WebviewUrl, WebviewWindowBuilder, WindowEvent};
+    use tokio::sync::broadcast::error::RecvError;
+    use tokio::sync::oneshot;
+
+    let network = match client_id {
+        Some(client_id) => Some(sockets.network_of(client_id).await),
+        None => None,
+    };
+    let label = match &network {
+        Some(network) => format!(
+            "raw-console-{}",
+            network
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                })
+                .collect::<String>()
+        ),
+        None => "raw-console".to_string(),
+    };
+
+    // Only one console per target; bring the existing one forward
+    if let Some(window) = app_handle.get_webview_window(&label) {
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+        return Ok(label);
+    }
+
+    let mut traffic = match &network {
+        Some(network) => taps.subscribe(network).await,
+        None => taps.subscribe_all(),
+    };
+    let (settings_tx, settings_rx) = watch::channel(ConsoleSettings::default());
+    consoles.0.lock().await.insert(label.clone(), settings_tx);
+
+    let title = format!(
+        "Raw traffic - {}",
+        network.as_deref().unwrap_or("all connections")
+    );
+    let url = format!("index.html?rawConsole={}", label);
+    let window = WebviewWindowBuilder::new(&app_handle, &label, WebviewUrl::App(url.into()))
+        .title(title)
+        .inner_size(900.0, 600.0)
+        .build();
+    let window = match window {
+        Ok(window) => window,
+        Err(e) => {
+            consoles.0.lock().await.remove(&label);
+            return Err(format!("Failed to open raw console: {}", e));
+        }
+    };
+
+    // The window event handler is Fn, so the one-shot sender has to be taken out of a slot
+    let (closed_tx, mut closed_rx) = oneshot::channel::<()>();
+    let closed_tx = std::sync::Mutex::new(Some(closed_tx));
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            if let Some(tx) = closed_tx.lock().ok().and_then(|mut tx| tx.take()) {
+                let _ = tx.send(());
+            }
+        }
+    });
+
+    let consoles = consoles.0.clone();
+    let target = label.clone();
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = &mut closed_rx => break,
+                received = traffic.recv() => match received {
+                    Ok(event) => {
+                        let shows = settings_rx.borrow().shows(&event.command);
+                        if shows {
+                            let _ = app_handle.emit_to(target.as_str(), "raw-traffic", &event);
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        let _ = app_handle.emit_to(target.as_str(), "raw-traffic-lagged", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+        consoles.lock().await.remove(&target);
+    });
+
+    Ok(label)
+}
+
+#[cfg(mobile)]
+async fn open_raw_console_impl(
+    _client_id: Option<String>,
+    _sockets: &SocketState,
+    _taps: &TapState,
+    _consoles: &ConsoleState,
+    _app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    Err("The raw console is only available on desktop".to_string())
+}
+
+/// Only show the given commands or numerics in a raw console; `-CMD` hides one
+#[tauri::command]
+pub async fn set_raw_console_filter(
+    label: String,
+    commands: Vec<String>,
+    consoles: State<'_, ConsoleState>,
+) -> Result<(), String> {
+    let consoles = consoles.0.lock().await;
+    let settings = consoles
+        .get(&label)
+        .ok_or_else(|| format!("No raw console found: {}", label))?;
+    settings.send_modify(|settings| {
+        settings.commands = commands
+            .iter()
+            .map(|c| c.trim().to_ascii_uppercase())
+            .filter(|c| !c.is_empty())
+            .collect();
+    });
+    Ok(())
+}
+
+/// Pause or resume a raw console; traffic seen while paused is not shown
+#[tauri::command]
+pub async fn set_raw_console_paused(
+    label: String,
+    paused: bool,
+    consoles: State<'_, ConsoleState>,
+) -> Result<(), String> {
+    let consoles = consoles.0.lock().await;
+    let settings = consoles
+        .get(&label)
+        .ok_or_else(|| format!("No raw console found: {}", label))?;
+    settings.send_modify(|settings| settings.paused = paused);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(commands: &[&str]) -> ConsoleSettings {
+        ConsoleSettings {
+            paused: false,
+            commands: commands.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn filters_commands() {
+        assert!(settings(&[]).shows("PRIVMSG"));
+        assert!(settings(&["PRIVMSG", "NOTICE"]).shows("NOTICE"));
+        assert!(!settings(&["PRIVMSG"]).shows("PING"));
+        assert!(!settings(&["-PING"]).shows("PING"));
+        assert!(settings(&["-PING"]).shows("PONG"));
+        assert!(!settings(&["PING", "-PING"]).shows("PING"));
+    }
+
+    #[test]
+    fn paused_shows_nothing() {
+        let mut paused = settings(&[]);
+        paused.paused = true;
+        assert!(!paused.shows("PRIVMSG"));
+    }
+}
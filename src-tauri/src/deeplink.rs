@@ -0,0 +1,179 @@
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::irc_url::{percent_decode, IrcUrl};
+
+/// Host of the web client, whose links the desktop app can open too
+const WEB_CLIENT_HOST: &str = "obsidianirc.github.io";
+
+/// An IRC link the app was asked to open
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenIrcUrl {
+    /// The link as it was received
+    source: String,
+    #[serde(flatten)]
+    url: IrcUrl,
+    tls: bool,
+}
+
+/// Links received before the frontend was ready to handle them
+#[derive(Default)]
+pub struct DeepLinkState(Mutex<DeepLinkQueue>);
+
+#[derive(Default)]
+struct DeepLinkQueue {
+    ready: bool,
+    pending: Vec<OpenIrcUrl>,
+}
+
+/// Parse an `irc://`/`ircs://` link or a web client link carrying one.
+///
+/// Web client links put the IRC URL in a `url` query parameter
+/// (`https://obsidianirc.github.io/?url=ircs%3A%2F%2F...`) or directly after
+/// the host (`https://obsidianirc.github.io/ircs://irc.libera.chat/#chan`).
+pub fn parse_link(link: &str) -> Option<IrcUrl> {
+    // Links copied out of chat text often pick up trailing punctuation
+    let link = link.trim().trim_end_matches([')', ',', '.', ';', ':']);
+    let lower = link.to_ascii_lowercase();
+    if lower.starts_with("irc://") || lower.starts_with("ircs://") || lower.starts_with("irc6://") {
+        return IrcUrl::parse(link).ok();
+    }
+
+    let rest = lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"))?;
+    let after_host = rest.strip_prefix(WEB_CLIENT_HOST)?;
+    if !(after_host.is_empty() || after_host.starts_with(['/', '?', '#'])) {
+        return None;
+    }
+    let rest = &link[link.len() - rest.len() + WEB_CLIENT_HOST.len()..];
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    // Check the `url` parameter first, an unencoded value contains `://` too
+    if let Some((path, query)) = rest.split_once('?') {
+        if !path.contains("://") {
+            if let Some(value) = query.split('&').find_map(|pair| pair.strip_prefix("url=")) {
+                return IrcUrl::parse(&percent_decode(value)).ok();
+            }
+        }
+    }
+    if rest.contains("://") {
+        return IrcUrl::parse(rest.trim_start_matches(['?', '#'])).ok();
+    }
+    None
+}
+
+/// Whether the deep link plugin already delivers this command line argument;
+/// it picks up our registered schemes from the arguments on Linux and Windows
+pub fn delivered_by_plugin(arg: &str) -> bool {
+    let lower = arg.to_ascii_lowercase();
+    cfg!(any(target_os = "linux", windows))
+        && (lower.starts_with("irc://") || lower.starts_with("ircs://"))
+}
+
+/// Bring the main window to the front
+pub fn focus_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Hand links to the frontend, or keep them until it calls `deep_link_ready`.
+/// Anything that isn't an IRC link is ignored.
+pub fn open_links<I, S>(app: &AppHandle, links: I)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let parsed: Vec<OpenIrcUrl> = links
+        .into_iter()
+        .filter_map(|link| {
+            let source = link.as_ref().to_string();
+            parse_link(&source).map(|url| OpenIrcUrl {
+                tls: url.scheme.is_tls(),
+                source,
+                url,
+            })
+        })
+        .collect();
+    if parsed.is_empty() {
+        return;
+    }
+
+    focus_main_window(app);
+    let state = app.state::<DeepLinkState>();
+    let mut queue = state.0.lock().unwrap_or_else(|e| e.into_inner());
+    if !queue.ready {
+        queue.pending.extend(parsed);
+        return;
+    }
+    for link in parsed {
+        let _ = app.emit("open-irc-url", link);
+    }
+}
+
+/// Called once the frontend listens for "open-irc-url"; returns the links
+/// that arrived before that, including the one the app was started with.
+#[tauri::command]
+pub fn deep_link_ready(state: State<'_, DeepLinkState>) -> Vec<OpenIrcUrl> {
+    let mut queue = state.0.lock().unwrap_or_else(|e| e.into_inner());
+    queue.ready = true;
+    std::mem::take(&mut queue.pending)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direct_irc_links() {
+        let url = parse_link("irc://irc.example.net/#chan").unwrap();
+        assert_eq!(url.host, "irc.example.net");
+        assert_eq!(url.port, 6667);
+        assert_eq!(url.channels, ["#chan"]);
+
+        let url = parse_link("ircs://irc.libera.chat:7000/#chan).").unwrap();
+        assert!(url.scheme.is_tls());
+        assert_eq!(url.port, 7000);
+        assert_eq!(url.channels, ["#chan"]);
+    }
+
+    #[test]
+    fn web_client_path_form() {
+        let url = parse_link("https://obsidianirc.github.io/ircs://irc.libera.chat/#chan").unwrap();
+        assert_eq!(url.host, "irc.libera.chat");
+        assert_eq!(url.channels, ["#chan"]);
+    }
+
+    #[test]
+    fn web_client_url_parameter() {
+        let url =
+            parse_link("https://obsidianirc.github.io/?url=ircs%3A%2F%2Firc.libera.chat%2F%23chan")
+                .unwrap();
+        assert_eq!(url.host, "irc.libera.chat");
+        assert_eq!(url.channels, ["#chan"]);
+
+        let url =
+            parse_link("https://obsidianirc.github.io/?url=ircs://irc.libera.chat/#chan").unwrap();
+        assert_eq!(url.host, "irc.libera.chat");
+        assert_eq!(url.channels, ["#chan"]);
+
+        let url = parse_link("http://obsidianirc.github.io?lang=en&url=irc://example.net").unwrap();
+        assert_eq!(url.host, "example.net");
+        assert!(parse_link("https://obsidianirc.github.io/?lang=en").is_none());
+    }
+
+    #[test]
+    fn foreign_hosts_are_ignored() {
+        assert!(parse_link("https://evil.example/?url=irc://example.net").is_none());
+        assert!(parse_link("https://evil.example/ircs://example.net").is_none());
+        assert!(
+            parse_link("https://obsidianirc.github.io.evil.example/?url=irc://example.net")
+                .is_none()
+        );
+        assert!(parse_link("ftp://obsidianirc.github.io/?url=irc://example.net").is_none());
+    }
+}
@@ -0,0 +1,395 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::Notify;
+
+use crate::fetch_policy::{error_message, FetchPolicyState};
+use crate::irc_url::percent_decode;
+
+/// Largest file `download_image` will fetch
+const MAX_DOWNLOAD_SIZE: u64 = 100 * 1024 * 1024;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest wait for the next chunk of the body
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+const MAX_FILENAME_LEN: usize = 120;
+
+/// Cancel signals of downloads in progress, by the frontend's download id
+#[derive(Default)]
+pub struct DownloadState(Mutex<HashMap<String, Arc<Notify>>>);
+
+impl DownloadState {
+    fn downloads(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Notify>>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Sent as "download-progress" while the body comes in
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DownloadProgress {
+    download_id: String,
+    received: u64,
+    /// From Content-Length, when the server sent one
+    total: Option<u64>,
+}
+
+/// A fetched media file, ready to be saved
+pub(crate) struct Downloaded {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Media type and extension of a file, from its first bytes
+pub(crate) fn sniff(bytes: &[u8]) -> Option<(&'static str, &'static str)> {
+    let starts = |magic: &[u8]| bytes.starts_with(magic);
+    let at = |offset: usize, magic: &[u8]| bytes.get(offset..offset + magic.len()) == Some(magic);
+    if starts(b"\xFF\xD8\xFF") {
+        Some(("image/jpeg", "jpg"))
+    } else if starts(b"\x89PNG\r\n\x1A\n") {
+        Some(("image/png", "png"))
+    } else if starts(b"GIF87a") || starts(b"GIF89a") {
+        Some(("image/gif", "gif"))
+    } else if starts(b"RIFF") && at(8, b"WEBP") {
+        Some(("image/webp", "webp"))
+    } else if starts(b"BM") {
+        Some(("image/bmp", "bmp"))
+    } else if at(4, b"ftypavif") || at(4, b"ftypavis") {
+        Some(("image/avif", "avif"))
+    } else if at(4, b"ftypheic") || at(4, b"ftypheix") || at(4, b"ftypmif1") {
+        Some(("image/heic", "heic"))
+    } else if at(4, b"ftypqt") {
+        Some(("video/quicktime", "mov"))
+    } else if at(4, b"ftyp") {
+        Some(("video/mp4", "mp4"))
+    } else if starts(b"\x1A\x45\xDF\xA3") {
+        Some(("video/webm", "webm"))
+    } else if starts(b"OggS") {
+        Some(("audio/ogg", "ogg"))
+    } else if starts(b"ID3") || starts(b"\xFF\xFB") || starts(b"\xFF\xF3") {
+        Some(("audio/mpeg", "mp3"))
+    } else {
+        None
+    }
+}
+
+/// Extensions that already fit a sniffed type
+fn extension_matches(extension: &str, sniffed: &str) -> bool {
+    let extension = extension.to_ascii_lowercase();
+    extension == sniffed
+        || matches!(
+            (sniffed, extension.as_str()),
+            ("jpg", "jpeg" | "jpe" | "jfif") | ("heic", "heif") | ("mp4", "m4v" | "m4a")
+        )
+}
+
+/// The `filename*` (RFC 5987) or `filename` parameter of a
+/// Content-Disposition header
+pub(crate) fn disposition_filename(header: &str) -> Option<String> {
+    let mut plain = None;
+    for param in header.split(';').skip(1) {
+        let Some((name, value)) = param.trim().split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "filename*" => {
+                // charset'language'percent-encoded; a malformed one leaves `filename`
+                if let Some(encoded) = value.splitn(3, '\'').nth(2) {
+                    return Some(percent_decode(encoded));
+                }
+            }
+            "filename" => {
+                plain = Some(value.trim_matches('"').replace("\\\"", "\""));
+            }
+            _ => {}
+        }
+    }
+    plain
+}
+
+/// The last path segment of a URL
+pub(crate) fn url_filename(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.rsplit('/')
+        .next()
+        .filter(|s| !s.is_empty())
+        .map(percent_decode)
+}
+
+/// A server-chosen name made safe to offer in a save dialog: no directories,
+/// no characters Windows rejects, no leading dots and a bounded length
+pub(crate) fn sanitize_filename(name: &str) -> String {
+    let name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    let cleaned: String = name
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let cleaned = cleaned
+        .trim()
+        .trim_start_matches('.')
+        .trim_end_matches(['.', ' ']);
+    let mut name: String = cleaned.chars().take(MAX_FILENAME_LEN).collect();
+    let stem = name.split('.').next().unwrap_or("").to_ascii_uppercase();
+    let reserved = matches!(stem.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+        || ((stem.starts_with("COM") || stem.starts_with("LPT"))
+            && stem.len() == 4
+            && stem.as_bytes()[3].is_ascii_digit());
+    if name.is_empty() || reserved {
+        name = format!("download{}", name);
+    }
+    name
+}
+
+/// `name` with the extension of the sniffed type
+pub(crate) fn with_extension(name: &str, extension: &str) -> String {
+    match name.rsplit_once('.') {
+        Some((_, current)) if extension_matches(current, extension) => name.to_string(),
+        Some((stem, current))
+            if current.len() <= 4
+                && current.chars().all(|c| c.is_ascii_alphanumeric())
+                && !stem.is_empty() =>
+        {
+            format!("{}.{}", stem, extension)
+        }
+        _ => format!("{}.{}", name, extension),
+    }
+}
+
+async fn fetch(app: &AppHandle, url: &str, download_id: &str) -> Result<Downloaded, String> {
+    let policy = app.state::<FetchPolicyState>().0.clone();
+    let url = policy.check_url(url)?;
+    let client = policy
+        .client_builder(&[])
+        .connect_timeout(CONNECT_TIMEOUT)
+        .read_timeout(READ_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let mut response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| error_message(&e))?;
+    // Name the file after where redirects ended up
+    let response_url = response.url().clone();
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("The server answered {}", status));
+    }
+    let total = response.content_length();
+    if total.is_some_and(|len| len > MAX_DOWNLOAD_SIZE) {
+        return Err(format!(
+            "The file is larger than {} MB",
+            MAX_DOWNLOAD_SIZE / 1024 / 1024
+        ));
+    }
+    let header = |name: reqwest::header::HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let disposition = header(reqwest::header::CONTENT_DISPOSITION);
+    let content_type = header(reqwest::header::CONTENT_TYPE);
+
+    let mut bytes = Vec::with_capacity(total.unwrap_or(0) as usize);
+    let mut last_progress = Instant::now();
+    while let Some(chunk) = response.chunk().await.map_err(|e| error_message(&e))? {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() as u64 > MAX_DOWNLOAD_SIZE {
+            return Err(format!(
+                "The file is larger than {} MB",
+                MAX_DOWNLOAD_SIZE / 1024 / 1024
+            ));
+        }
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            last_progress = Instant::now();
+            let _ = app.emit(
+                "download-progress",
+                DownloadProgress {
+                    download_id: download_id.to_string(),
+                    received: bytes.len() as u64,
+                    total,
+                },
+            );
+        }
+    }
+    let _ = app.emit(
+        "download-progress",
+        DownloadProgress {
+            download_id: download_id.to_string(),
+            received: bytes.len() as u64,
+            total,
+        },
+    );
+    if bytes.is_empty() {
+        return Err("Downloaded file is empty".to_string());
+    }
+
+    // Trust the bytes over the headers: error pages come back as text/html
+    // with a 200 from some hosts, and CDNs mislabel WebP as JPEG
+    let Some((_, extension)) = sniff(&bytes) else {
+        let content_type = content_type.unwrap_or_default();
+        return Err(format!(
+            "Not a media file ({})",
+            content_type.split(';').next().unwrap_or("unknown type")
+        ));
+    };
+    let name = disposition
+        .as_deref()
+        .and_then(disposition_filename)
+        .or_else(|| url_filename(response_url.as_str()))
+        .unwrap_or_default();
+    let filename = with_extension(&sanitize_filename(&name), extension);
+    Ok(Downloaded { filename, bytes })
+}
+
+/// Fetch a media file with size, time and type checks. `download_id` names
+/// it in "download-progress" events and for `download_image_cancel`.
+pub(crate) async fn fetch_media(
+    app: &AppHandle,
+    url: &str,
+    download_id: Option<String>,
+) -> Result<Downloaded, String> {
+    let state = app.state::<DownloadState>();
+    let download_id = download_id.unwrap_or_default();
+    let cancel = Arc::new(Notify::new());
+    if !download_id.is_empty() {
+        state
+            .downloads()
+            .insert(download_id.clone(), cancel.clone());
+    }
+    let result = tokio::select! {
+        result = fetch(app, url, &download_id) => result,
+        _ = cancel.notified() => Err("The download was cancelled".to_string()),
+    };
+    if !download_id.is_empty() {
+        state.downloads().remove(&download_id);
+    }
+    result
+}
+
+/// Stop a `download_image` that is still fetching; it fails with "cancelled"
+#[tauri::command]
+pub fn download_image_cancel(download_id: String, downloads: State<'_, DownloadState>) {
+    if let Some(cancel) = downloads.downloads().remove(&download_id) {
+        cancel.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffs_media_types() {
+        let ftyp = |brand: &[u8]| [b"\0\0\0\x18ftyp".as_slice(), brand].concat();
+        assert_eq!(sniff(b"\xFF\xD8\xFF\xE0"), Some(("image/jpeg", "jpg")));
+        assert_eq!(sniff(b"\x89PNG\r\n\x1A\n"), Some(("image/png", "png")));
+        assert_eq!(sniff(b"GIF89a"), Some(("image/gif", "gif")));
+        assert_eq!(sniff(b"RIFF\0\0\0\0WEBPVP8 "), Some(("image/webp", "webp")));
+        assert_eq!(sniff(&ftyp(b"avif")), Some(("image/avif", "avif")));
+        assert_eq!(sniff(&ftyp(b"heic")), Some(("image/heic", "heic")));
+        assert_eq!(sniff(&ftyp(b"qt  ")), Some(("video/quicktime", "mov")));
+        assert_eq!(sniff(&ftyp(b"isom")), Some(("video/mp4", "mp4")));
+        assert_eq!(sniff(b"\x1A\x45\xDF\xA3"), Some(("video/webm", "webm")));
+        assert_eq!(sniff(b"ID3\x04"), Some(("audio/mpeg", "mp3")));
+
+        assert_eq!(sniff(b"RIFF\0\0\0\0WAVEfmt "), None);
+        assert_eq!(sniff(b"<!doctype html>"), None);
+        assert_eq!(sniff(b"RIFF"), None);
+        assert_eq!(sniff(b""), None);
+    }
+
+    #[test]
+    fn content_disposition_names() {
+        assert_eq!(
+            disposition_filename("attachment; filename=\"cat.png\"").as_deref(),
+            Some("cat.png")
+        );
+        assert_eq!(
+            disposition_filename("inline; FILENAME=cat.png").as_deref(),
+            Some("cat.png")
+        );
+        assert_eq!(
+            disposition_filename(r#"attachment; filename="say \"hi\".txt""#).as_deref(),
+            Some("say \"hi\".txt")
+        );
+        // RFC 5987 names win wherever they are
+        assert_eq!(
+            disposition_filename(
+                "attachment; filename=\"naive.png\"; filename*=UTF-8''na%C3%AFve%20cat.png"
+            )
+            .as_deref(),
+            Some("na\u{ef}ve cat.png")
+        );
+        assert_eq!(
+            disposition_filename("attachment; filename*=UTF-8'en'b.png; filename=a.png").as_deref(),
+            Some("b.png")
+        );
+        assert_eq!(
+            disposition_filename("attachment; filename*=b.png; filename=a.png").as_deref(),
+            Some("a.png")
+        );
+        assert_eq!(disposition_filename("attachment"), None);
+        assert_eq!(disposition_filename("inline; size=12"), None);
+    }
+
+    #[test]
+    fn url_names() {
+        assert_eq!(
+            url_filename("https://example.com/a/b%20c.png?w=100#top").as_deref(),
+            Some("b c.png")
+        );
+        assert_eq!(url_filename("https://example.com/dir/"), None);
+    }
+
+    #[test]
+    fn sanitized_names_stay_in_the_directory() {
+        assert_eq!(sanitize_filename("../../etc/passwd"), "passwd");
+        assert_eq!(sanitize_filename("..\\..\\boot.ini"), "boot.ini");
+        assert_eq!(sanitize_filename("/tmp/"), "download");
+        assert_eq!(sanitize_filename(".bashrc"), "bashrc");
+        assert_eq!(sanitize_filename("..."), "download");
+        assert_eq!(sanitize_filename(""), "download");
+        assert_eq!(sanitize_filename(" notes.txt. . "), "notes.txt");
+        assert_eq!(
+            sanitize_filename("a<b>:c|d?*\"e\u{0}.png"),
+            "a_b__c_d___e_.png"
+        );
+        assert_eq!(sanitize_filename(&"x".repeat(500)).len(), MAX_FILENAME_LEN);
+    }
+
+    #[test]
+    fn reserved_windows_names() {
+        assert_eq!(sanitize_filename("CON"), "downloadCON");
+        assert_eq!(sanitize_filename("nul.txt"), "downloadnul.txt");
+        assert_eq!(sanitize_filename("com1.png"), "downloadcom1.png");
+        assert_eq!(sanitize_filename("LPT9"), "downloadLPT9");
+        assert_eq!(sanitize_filename("COM10.png"), "COM10.png");
+        assert_eq!(sanitize_filename("console.log"), "console.log");
+    }
+
+    #[test]
+    fn extensions_follow_the_content() {
+        assert_eq!(with_extension("photo.jpeg", "jpg"), "photo.jpeg");
+        assert_eq!(with_extension("clip.MOV", "mov"), "clip.MOV");
+        assert_eq!(with_extension("photo.txt", "png"), "photo.png");
+        assert_eq!(with_extension("photo", "png"), "photo.png");
+        assert_eq!(with_extension("archive.tar.gz", "png"), "archive.tar.png");
+        assert_eq!(
+            with_extension("report.final version", "pdf"),
+            "report.final version.pdf"
+        );
+        assert_eq!(with_extension("bashrc.", "png"), "bashrc.png");
+    }
+}
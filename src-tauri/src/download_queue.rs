@@ -0,0 +1,787 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::Notify;
+
+use crate::download::{disposition_filename, sanitize_filename, sniff, url_filename};
+use crate::fetch_policy::{error_message, FetchPolicyState};
+use crate::stats::now_millis;
+use crate::util::{blocking, load_json, save_json};
+
+const DEFAULT_MAX_CONCURRENT: usize = 3;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest wait for the next chunk of the body
+const READ_TIMEOUT: Duration = Duration::from_secs(60);
+/// Tries per start or resume; each retry picks up where the last one stopped
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_secs(3);
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+/// How often the queue file is rewritten while bytes come in
+const PERSIST_INTERVAL: Duration = Duration::from_secs(5);
+/// Finished downloads kept in the list
+const MAX_FINISHED: usize = 200;
+
+/// Tells apart entries queued in the same millisecond
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DownloadConfig {
+    /// Save here without asking; None shows a dialog per download
+    download_dir: Option<String>,
+    max_concurrent: usize,
+}
+
+impl Default for DownloadConfig {
+    fn default() -> Self {
+        Self {
+            download_dir: None,
+            max_concurrent: DEFAULT_MAX_CONCURRENT,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum DownloadStatus {
+    Queued,
+    Downloading,
+    Paused,
+    Failed,
+    Done,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadEntry {
+    id: String,
+    url: String,
+    /// Folder the file goes to when no path was chosen up front
+    dir: PathBuf,
+    /// Final location; picked in `dir` once the server has named the file
+    path: Option<PathBuf>,
+    filename: Option<String>,
+    status: DownloadStatus,
+    received: u64,
+    total: Option<u64>,
+    /// ETag or Last-Modified of the first response, sent as If-Range so a
+    /// resume never splices two versions of a file
+    validator: Option<String>,
+    error: Option<String>,
+    created_at: u64,
+}
+
+/// Sent as "download-removed" when an entry leaves the list
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct DownloadRemoved {
+    id: String,
+}
+
+/// Why a transfer ended without the file
+enum TransferError {
+    /// Paused or cancelled; the entry already says which
+    Stopped,
+    Failed(String),
+}
+
+impl From<String> for TransferError {
+    fn from(message: String) -> Self {
+        Self::Failed(message)
+    }
+}
+
+/// Downloads of attachments, kept across restarts.
+///
+/// Bytes go to `<id>.part` in the partial folder and are moved to the
+/// destination when complete, so an interrupted download resumes with an
+/// HTTP Range request from the size of its part file.
+pub struct DownloadManager {
+    app: AppHandle,
+    config: RwLock<DownloadConfig>,
+    config_path: PathBuf,
+    queue_path: PathBuf,
+    partial_dir: PathBuf,
+    entries: Mutex<Vec<DownloadEntry>>,
+    /// Stop signals of running transfers, by entry id. A transfer leaves
+    /// the map itself once stopped, so it is never started twice.
+    running: Mutex<HashMap<String, Arc<Notify>>>,
+}
+
+fn unique_path(dir: &Path, filename: &str) -> PathBuf {
+    let candidate = dir.join(filename);
+    if !candidate.exists() {
+        return candidate;
+    }
+    let (stem, extension) = match filename.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() => (stem, format!(".{}", extension)),
+        _ => (filename, String::new()),
+    };
+    (1..)
+        .map(|n| dir.join(format!("{} ({}){}", stem, n, extension)))
+        .find(|path| !path.exists())
+        .unwrap_or(candidate)
+}
+
+/// Move a finished part file into place, copying across file systems
+fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    fs::copy(from, to)?;
+    fs::remove_file(from)
+}
+
+/// The sniffed extension for names that have none
+fn extension_from_content(part: &Path, filename: &str) -> String {
+    if filename
+        .rsplit_once('.')
+        .is_some_and(|(stem, _)| !stem.is_empty())
+    {
+        return filename.to_string();
+    }
+    let mut head = [0u8; 16];
+    let read = File::open(part)
+        .and_then(|mut file| file.read(&mut head))
+        .unwrap_or(0);
+    match sniff(&head[..read]) {
+        Some((_, extension)) => format!("{}.{}", filename, extension),
+        None => filename.to_string(),
+    }
+}
+
+impl DownloadManager {
+    /// Load the settings from `config_path` and the queue from `data_dir`.
+    /// Downloads that were running when the app quit are queued again.
+    pub fn load(app: AppHandle, config_path: PathBuf, data_dir: &Path) -> Self {
+        let config = load_json(&config_path);
+        let queue_path = data_dir.join("download-queue.json");
+        let mut entries: Vec<DownloadEntry> = load_json(&queue_path);
+        for entry in &mut entries {
+            if entry.status == DownloadStatus::Downloading {
+                entry.status = DownloadStatus::Queued;
+            }
+        }
+        Self {
+            app,
+            config: RwLock::new(config),
+            config_path,
+            queue_path,
+            partial_dir: data_dir.join("downloads"),
+            entries: Mutex::new(entries),
+            running: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn config(&self) -> DownloadConfig {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn set_config(&self, config: DownloadConfig) -> Result<(), String> {
+        if config.max_concurrent == 0 {
+            return Err("At least one download must be allowed at a time".to_string());
+        }
+        if let Some(dir) = &config.download_dir {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Can't use {} as the download folder: {}", dir, e))?;
+        }
+        save_json(&self.config_path, &config, "download settings")?;
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+        Ok(())
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, Vec<DownloadEntry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn running(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Notify>>> {
+        self.running.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn persist(&self, entries: &[DownloadEntry]) {
+        let result = serde_json::to_string(entries)
+            .map_err(|e| e.to_string())
+            .and_then(|json| fs::write(&self.queue_path, json).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            log::warn!("Failed to save the download queue: {}", e);
+        }
+    }
+
+    fn part_path(&self, id: &str) -> PathBuf {
+        self.partial_dir.join(format!("{}.part", id))
+    }
+
+    fn entry(&self, id: &str) -> Option<DownloadEntry> {
+        self.entries().iter().find(|e| e.id == id).cloned()
+    }
+
+    /// Change an entry, tell the frontend and optionally save the queue
+    fn update(
+        &self,
+        id: &str,
+        persist: bool,
+        f: impl FnOnce(&mut DownloadEntry),
+    ) -> Option<DownloadEntry> {
+        let mut entries = self.entries();
+        let entry = entries.iter_mut().find(|e| e.id == id)?;
+        f(entry);
+        let entry = entry.clone();
+        if persist {
+            self.persist(&entries);
+        }
+        drop(entries);
+        let _ = self.app.emit("download-updated", &entry);
+        Some(entry)
+    }
+
+    fn add(&self, new: Vec<DownloadEntry>) {
+        let mut entries = self.entries();
+        entries.extend(new.iter().cloned());
+        let finished = entries
+            .iter()
+            .filter(|e| e.status == DownloadStatus::Done)
+            .count();
+        if finished > MAX_FINISHED {
+            let mut excess = finished - MAX_FINISHED;
+            entries.retain(|e| {
+                let drop = excess > 0 && e.status == DownloadStatus::Done;
+                if drop {
+                    excess -= 1;
+                }
+                !drop
+            });
+        }
+        self.persist(&entries);
+        drop(entries);
+        for entry in &new {
+            let _ = self.app.emit("download-updated", entry);
+        }
+    }
+
+    /// Start queued downloads while there is room
+    fn pump(self: &Arc<Self>) {
+        let max = self.config().max_concurrent.max(1);
+        loop {
+            let mut running = self.running();
+            if running.len() >= max {
+                return;
+            }
+            let next = self
+                .entries()
+                .iter()
+                .find(|e| e.status == DownloadStatus::Queued && !running.contains_key(&e.id))
+                .map(|e| e.id.clone());
+            let Some(id) = next else {
+                return;
+            };
+            let stop = Arc::new(Notify::new());
+            running.insert(id.clone(), stop.clone());
+            drop(running);
+            self.update(&id, true, |e| {
+                e.status = DownloadStatus::Downloading;
+                e.error = None;
+            });
+            let manager = self.clone();
+            tauri::async_runtime::spawn(async move { manager.run(id, stop).await });
+        }
+    }
+
+    /// Save the queue from a blocking thread
+    async fn save(self: &Arc<Self>) {
+        let manager = self.clone();
+        let _ = blocking(move || manager.persist(&manager.entries())).await;
+    }
+
+    async fn run(self: Arc<Self>, id: String, stop: Arc<Notify>) {
+        let mut attempt = 1;
+        let result = loop {
+            let result = self.transfer(&id, &stop).await;
+            match result {
+                Err(TransferError::Failed(e)) if attempt < MAX_ATTEMPTS => {
+                    log::info!("Download {} failed, retrying: {}", id, e);
+                    attempt += 1;
+                    tokio::select! {
+                        _ = tokio::time::sleep(RETRY_DELAY * attempt) => {}
+                        _ = stop.notified() => break Err(TransferError::Stopped),
+                    }
+                }
+                result => break result,
+            }
+        };
+        self.running().remove(&id);
+        // Cancelled: the part file is closed now, so it can go
+        if self.entry(&id).is_none() {
+            let part = self.part_path(&id);
+            let _ = blocking(move || fs::remove_file(part)).await;
+            self.pump();
+            return;
+        }
+        match result {
+            Ok(path) => {
+                self.update(&id, true, |e| {
+                    e.status = DownloadStatus::Done;
+                    e.path = Some(path);
+                    e.error = None;
+                });
+            }
+            Err(TransferError::Failed(error)) => {
+                self.update(&id, true, |e| {
+                    if e.status == DownloadStatus::Downloading {
+                        e.status = DownloadStatus::Failed;
+                        e.error = Some(error);
+                    }
+                });
+            }
+            Err(TransferError::Stopped) => {}
+        }
+        self.pump();
+    }
+
+    /// Fetch the rest of a download and move it into place. Stops between
+    /// writes when `stop` is notified, so the part file is closed on return.
+    async fn transfer(self: &Arc<Self>, id: &str, stop: &Notify) -> Result<PathBuf, TransferError> {
+        let entry = self
+            .entry(id)
+            .ok_or_else(|| "The download is gone".to_string())?;
+        let part = self.part_path(id);
+        let offset = {
+            let part = part.clone();
+            blocking(move || fs::metadata(part).map(|m| m.len()).unwrap_or(0)).await?
+        };
+
+        let policy = self.app.state::<FetchPolicyState>().0.clone();
+        let url = policy.check_url(&entry.url)?;
+        let client = policy
+            .client_builder(&[])
+            .connect_timeout(CONNECT_TIMEOUT)
+            .read_timeout(READ_TIMEOUT)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let mut request = client.get(url);
+        if offset > 0 {
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+            if let Some(validator) = &entry.validator {
+                request = request.header(reqwest::header::IF_RANGE, validator);
+            }
+        }
+        let mut response = tokio::select! {
+            response = request.send() => response.map_err(|e| error_message(&e))?,
+            _ = stop.notified() => return Err(TransferError::Stopped),
+        };
+        let status = response.status();
+        if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            // The file changed or shrank; the retry starts over
+            let part = part.clone();
+            let _ = blocking(move || fs::remove_file(part)).await;
+            return Err("The server refused to resume the download"
+                .to_string()
+                .into());
+        }
+        if !status.is_success() {
+            return Err(format!("The server answered {}", status).into());
+        }
+        // A 200 to a range request sends the whole file again
+        let resumed = offset > 0 && status == reqwest::StatusCode::PARTIAL_CONTENT;
+        let start = if resumed { offset } else { 0 };
+        let total = response.content_length().map(|len| len + start);
+
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let validator = header(reqwest::header::ETAG)
+            .filter(|etag| !etag.starts_with("W/"))
+            .or_else(|| header(reqwest::header::LAST_MODIFIED));
+        let filename = entry.filename.clone().unwrap_or_else(|| {
+            let name = header(reqwest::header::CONTENT_DISPOSITION)
+                .as_deref()
+                .and_then(disposition_filename)
+                .or_else(|| url_filename(response.url().as_str()))
+                .unwrap_or_default();
+            sanitize_filename(&name)
+        });
+        self.update(id, false, |e| {
+            e.filename = Some(filename.clone());
+            e.total = total;
+            e.received = start;
+            if !resumed {
+                e.validator = validator;
+            }
+        });
+        self.save().await;
+
+        let write_error = {
+            let part = part.clone();
+            move |e: std::io::Error| format!("Failed to write {}: {}", part.display(), e)
+        };
+        let mut file = {
+            let partial_dir = self.partial_dir.clone();
+            let part = part.clone();
+            blocking(move || {
+                fs::create_dir_all(&partial_dir)?;
+                if resumed {
+                    OpenOptions::new().append(true).open(&part)
+                } else {
+                    File::create(&part)
+                }
+            })
+            .await?
+            .map_err(&write_error)?
+        };
+
+        let mut received = start;
+        let mut last_progress = Instant::now();
+        let mut last_persist = Instant::now();
+        loop {
+            let chunk = tokio::select! {
+                chunk = response.chunk() => chunk.map_err(|e| error_message(&e))?,
+                _ = stop.notified() => return Err(TransferError::Stopped),
+            };
+            let Some(chunk) = chunk else {
+                break;
+            };
+            received += chunk.len() as u64;
+            file = blocking(move || file.write_all(&chunk).map(|()| file))
+                .await?
+                .map_err(&write_error)?;
+            if last_progress.elapsed() >= PROGRESS_INTERVAL {
+                last_progress = Instant::now();
+                self.update(id, false, |e| e.received = received);
+                if last_persist.elapsed() >= PERSIST_INTERVAL {
+                    last_persist = Instant::now();
+                    self.save().await;
+                }
+            }
+        }
+        blocking(move || file.flush())
+            .await?
+            .map_err(&write_error)?;
+        self.update(id, false, |e| e.received = received);
+        self.save().await;
+        if total.is_some_and(|total| received < total) {
+            return Err("The connection closed before the download finished"
+                .to_string()
+                .into());
+        }
+
+        let path = blocking(move || {
+            let path = match entry.path {
+                Some(path) => path,
+                None => {
+                    fs::create_dir_all(&entry.dir)
+                        .map_err(|e| format!("Failed to create {}: {}", entry.dir.display(), e))?;
+                    unique_path(&entry.dir, &extension_from_content(&part, &filename))
+                }
+            };
+            move_file(&part, &path)
+                .map_err(|e| format!("Failed to save {}: {}", path.display(), e))?;
+            Ok::<_, String>(path)
+        })
+        .await??;
+        Ok(path)
+    }
+
+    /// Signal a running transfer to stop. Returns whether one was running.
+    fn stop(&self, id: &str) -> bool {
+        match self.running().get(id) {
+            Some(stop) => {
+                stop.notify_one();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Where new downloads go
+enum Target {
+    Dir(PathBuf),
+    /// A single download saved under a name the user picked
+    File(PathBuf),
+}
+
+/// Ask where to save, unless a download folder is set. None when the user
+/// closes the dialog.
+async fn choose_target(
+    app: &AppHandle,
+    config: &DownloadConfig,
+    urls: &[String],
+) -> Result<Option<Target>, String> {
+    if let Some(dir) = &config.download_dir {
+        return Ok(Some(Target::Dir(PathBuf::from(dir))));
+    }
+    #[cfg(desktop)]
+    {
+        let _ = app;
+        let dialog = rfd::AsyncFileDialog::new();
+        if let [url] = urls {
+            let name = sanitize_filename(&url_filename(url).unwrap_or_default());
+            let file = dialog.set_file_name(&name).save_file().await;
+            return Ok(file.map(|file| Target::File(file.path().to_path_buf())));
+        }
+        let folder = dialog.pick_folder().await;
+        Ok(folder.map(|folder| Target::Dir(folder.path().to_path_buf())))
+    }
+    #[cfg(mobile)]
+    {
+        let _ = urls;
+        let dir = app.path().download_dir().map_err(|e| e.to_string())?;
+        Ok(Some(Target::Dir(dir)))
+    }
+}
+
+pub struct DownloadManagerState(pub Arc<DownloadManager>);
+
+impl DownloadManagerState {
+    /// Resume the downloads left over from the last run
+    pub fn start(&self) {
+        self.0.pump();
+    }
+}
+
+/// Queue downloads of `urls`. Asks for a file (one URL) or a folder (a
+/// batch) unless a download folder is set; returns nothing when the user
+/// closes the dialog.
+#[tauri::command]
+pub async fn download_enqueue(
+    app: AppHandle,
+    urls: Vec<String>,
+    downloads: State<'_, DownloadManagerState>,
+    policy: State<'_, FetchPolicyState>,
+) -> Result<Vec<DownloadEntry>, String> {
+    let mut seen = HashSet::new();
+    let urls: Vec<String> = urls
+        .into_iter()
+        .filter(|url| !url.is_empty() && seen.insert(url.clone()))
+        .collect();
+    for url in &urls {
+        policy.0.check_url(url)?;
+    }
+    if urls.is_empty() {
+        return Ok(Vec::new());
+    }
+    let manager = downloads.0.clone();
+    let Some(target) = choose_target(&app, &manager.config(), &urls).await? else {
+        return Ok(Vec::new());
+    };
+    let created_at = now_millis();
+    let entries: Vec<DownloadEntry> = urls
+        .into_iter()
+        .map(|url| {
+            let (dir, path) = match &target {
+                Target::Dir(dir) => (dir.clone(), None),
+                Target::File(path) => (
+                    path.parent().map(Path::to_path_buf).unwrap_or_default(),
+                    Some(path.clone()),
+                ),
+            };
+            DownloadEntry {
+                id: format!(
+                    "{:x}-{:x}",
+                    created_at,
+                    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+                ),
+                url,
+                filename: path
+                    .as_ref()
+                    .and_then(|p| p.file_name())
+                    .map(|name| name.to_string_lossy().into_owned()),
+                dir,
+                path,
+                status: DownloadStatus::Queued,
+                received: 0,
+                total: None,
+                validator: None,
+                error: None,
+                created_at,
+            }
+        })
+        .collect();
+    manager.add(entries.clone());
+    manager.pump();
+    Ok(entries)
+}
+
+#[tauri::command]
+pub async fn download_list(
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<Vec<DownloadEntry>, String> {
+    Ok(downloads.0.entries().clone())
+}
+
+/// Stop a queued or running download, keeping what has arrived
+#[tauri::command]
+pub async fn download_pause(
+    id: String,
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<(), String> {
+    let manager = &downloads.0;
+    manager
+        .update(&id, true, |e| {
+            if matches!(
+                e.status,
+                DownloadStatus::Queued | DownloadStatus::Downloading
+            ) {
+                e.status = DownloadStatus::Paused;
+            }
+        })
+        .ok_or("No such download")?;
+    manager.stop(&id);
+    Ok(())
+}
+
+/// Queue a paused or failed download again; it continues where it stopped
+#[tauri::command]
+pub async fn download_resume(
+    id: String,
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<(), String> {
+    let manager = &downloads.0;
+    manager
+        .update(&id, true, |e| {
+            if matches!(e.status, DownloadStatus::Paused | DownloadStatus::Failed) {
+                e.status = DownloadStatus::Queued;
+                e.error = None;
+            }
+        })
+        .ok_or("No such download")?;
+    manager.pump();
+    Ok(())
+}
+
+/// Stop a download and drop it from the list with its partial data. Saved
+/// files stay on disk.
+#[tauri::command]
+pub async fn download_cancel(
+    id: String,
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<(), String> {
+    let manager = &downloads.0;
+    {
+        let mut entries = manager.entries();
+        entries.retain(|e| e.id != id);
+        manager.persist(&entries);
+    }
+    // A running transfer may still hold the part file; it removes the file
+    // itself once it has stopped and sees the entry gone
+    if !manager.stop(&id) {
+        let part = manager.part_path(&id);
+        let _ = blocking(move || fs::remove_file(part)).await;
+    }
+    let _ = manager.app.emit("download-removed", DownloadRemoved { id });
+    manager.pump();
+    Ok(())
+}
+
+/// Show a finished download in the file manager, or its folder otherwise
+#[tauri::command]
+pub async fn download_reveal(
+    id: String,
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<(), String> {
+    let entry = downloads.0.entry(&id).ok_or("No such download")?;
+    #[cfg(desktop)]
+    {
+        use tauri_plugin_opener::OpenerExt;
+
+        let opener = downloads.0.app.opener();
+        match entry.path.filter(|path| path.exists()) {
+            Some(path) => opener.reveal_item_in_dir(path),
+            None => opener.open_path(entry.dir.to_string_lossy(), None::<&str>),
+        }
+        .map_err(|e| e.to_string())
+    }
+    #[cfg(mobile)]
+    {
+        let _ = entry;
+        Err("Revealing files isn't supported on this platform".to_string())
+    }
+}
+
+#[tauri::command]
+pub async fn download_get_config(
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<DownloadConfig, String> {
+    Ok(downloads.0.config())
+}
+
+#[tauri::command]
+pub async fn download_set_config(
+    config: DownloadConfig,
+    downloads: State<'_, DownloadManagerState>,
+) -> Result<(), String> {
+    downloads.0.set_config(config)?;
+    downloads.0.pump();
+    Ok(())
+}
+
+/// Pick a download folder in a dialog; None when the user closes it
+#[tauri::command]
+pub async fn download_choose_folder() -> Result<Option<String>, String> {
+    #[cfg(desktop)]
+    {
+        let folder = rfd::AsyncFileDialog::new().pick_folder().await;
+        Ok(folder.map(|folder| folder.path().to_string_lossy().into_owned()))
+    }
+    #[cfg(mobile)]
+    {
+        Err("Choosing a folder isn't supported on this platform".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_paths_number_taken_names() {
+        let dir = std::env::temp_dir().join(format!("download-queue-names-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(unique_path(&dir, "photo.png"), dir.join("photo.png"));
+
+        fs::write(dir.join("photo.png"), b"").unwrap();
+        assert_eq!(unique_path(&dir, "photo.png"), dir.join("photo (1).png"));
+        fs::write(dir.join("photo (1).png"), b"").unwrap();
+        assert_eq!(unique_path(&dir, "photo.png"), dir.join("photo (2).png"));
+
+        fs::write(dir.join("README"), b"").unwrap();
+        assert_eq!(unique_path(&dir, "README"), dir.join("README (1)"));
+        fs::write(dir.join(".bashrc"), b"").unwrap();
+        assert_eq!(unique_path(&dir, ".bashrc"), dir.join(".bashrc (1)"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn extensions_come_from_the_part_file() {
+        let dir = std::env::temp_dir().join(format!("download-queue-sniff-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let png = dir.join("png.part");
+        fs::write(&png, b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR").unwrap();
+        let text = dir.join("text.part");
+        fs::write(&text, b"just some text").unwrap();
+
+        assert_eq!(extension_from_content(&png, "image"), "image.png");
+        assert_eq!(extension_from_content(&png, "picture.jpg"), "picture.jpg");
+        assert_eq!(extension_from_content(&png, ".hidden"), ".hidden.png");
+        assert_eq!(extension_from_content(&text, "notes"), "notes");
+        assert_eq!(
+            extension_from_content(&dir.join("missing.part"), "gone"),
+            "gone"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
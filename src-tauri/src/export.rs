@@ -0,0 +1,693 @@
+use chrono::{Local, TimeZone};
+use rusqlite::{params, Connection};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tauri::State;
+
+use crate::chatlog::path_component;
+use crate::history::{casefold, HistoryState, HistoryStore, StoredMessage, COLUMNS};
+use crate::irc::{ctcp_action, is_ctcp, strip_formatting};
+
+/// mIRC colours 0-98, as in `ircColors` on the TS side; 99 means the default colour
+const PALETTE: [&str; 99] = [
+    "#FFFFFF", "#000000", "#00007F", "#009300", "#FF0000", "#7F0000", "#9C009C", "#FC7F00",
+    "#FFFF00", "#00FC00", "#009393", "#00FFFF", "#0000FC", "#FF00FF", "#7F7F7F", "#D2D2D2",
+    "#470000", "#472100", "#474700", "#324700", "#004700", "#00472C", "#004747", "#002747",
+    "#000047", "#2E0047", "#470047", "#47002A", "#740000", "#743A00", "#747400", "#517400",
+    "#007400", "#007449", "#007474", "#004074", "#000074", "#4B0074", "#740074", "#740045",
+    "#B50000", "#B56300", "#B5B500", "#7DB500", "#00B500", "#00B571", "#00B5B5", "#0063B5",
+    "#0000B5", "#7500B5", "#B500B5", "#B5006B", "#FF000B", "#FF8C00", "#FFFF0B", "#B2FF00",
+    "#00FF00", "#00FFA0", "#00FFFB", "#008CFF", "#0000FF", "#A500FF", "#FF00FB", "#FF0098",
+    "#FF5959", "#FFB459", "#FFFF71", "#CFFF60", "#6FFF6F", "#65FFC9", "#6DFFFF", "#59B4FF",
+    "#5959FF", "#C459FF", "#FF66FF", "#FF59BC", "#FF9C9C", "#FFD39C", "#FFFF9C", "#E2FF9C",
+    "#9CFF9C", "#9CFFDB", "#9CFFFF", "#9CD3FF", "#9C9CFF", "#DC9CFF", "#FF9CFF", "#FF94D3",
+    "#00000A", "#131313", "#282828", "#363636", "#4D4D4D", "#656565", "#818181", "#9F9F9F",
+    "#BCBCBC", "#E2E2E2", "#FFFFFF",
+];
+
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Formats a conversation can be exported to
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Html,
+    Jsonl,
+    Text,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Jsonl => "jsonl",
+            ExportFormat::Text => "txt",
+        }
+    }
+}
+
+/// What to export
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRequest {
+    network: String,
+    target: String,
+    /// Inclusive lower bound, milliseconds since the Unix epoch
+    from: Option<i64>,
+    /// Inclusive upper bound, milliseconds since the Unix epoch
+    to: Option<i64>,
+    format: ExportFormat,
+    /// Nick colours known to the UI (e.g. from metadata), keyed by nick
+    #[serde(default)]
+    nick_colors: HashMap<String, String>,
+}
+
+/// How a stored message reads in a transcript
+enum Entry<'a> {
+    Message { nick: &'a str, text: &'a str },
+    Action { nick: &'a str, text: &'a str },
+    Notice { nick: &'a str, text: &'a str },
+    Event(String),
+}
+
+fn entry(message: &StoredMessage) -> Option<Entry<'_>> {
+    let nick = message.sender.as_deref().unwrap_or("*");
+    let text = message.text.as_deref().unwrap_or("");
+    let reason = |text: &str| {
+        if text.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", strip_formatting(text))
+        }
+    };
+    Some(match message.command.as_str() {
+        "PRIVMSG" => match ctcp_action(text) {
+            Some(action) => Entry::Action { nick, text: action },
+            None if is_ctcp(text) => return None,
+            None => Entry::Message { nick, text },
+        },
+        "NOTICE" => Entry::Notice { nick, text },
+        "JOIN" => Entry::Event(format!("{} has joined", nick)),
+        "PART" => Entry::Event(format!("{} has left{}", nick, reason(text))),
+        "QUIT" => Entry::Event(format!("{} has quit{}", nick, reason(text))),
+        "KICK" => {
+            let (victim, why) = text.split_once(' ').unwrap_or((text, ""));
+            Entry::Event(format!("{} has kicked {}{}", nick, victim, reason(why)))
+        }
+        "NICK" => Entry::Event(format!("{} is now known as {}", nick, text)),
+        "TOPIC" => Entry::Event(format!(
+            "{} changed the topic to: {}",
+            nick,
+            strip_formatting(text)
+        )),
+        "MODE" => Entry::Event(format!("{} sets mode {}", nick, text)),
+        _ => return None,
+    })
+}
+
+pub(crate) fn local_time(millis: i64) -> String {
+    Local
+        .timestamp_millis_opt(millis)
+        .single()
+        .map(|t| t.format(TIME_FORMAT).to_string())
+        .unwrap_or_default()
+}
+
+pub(crate) fn escape_html(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Formatting in effect at a point of an mIRC-formatted line
+#[derive(Default, Clone, PartialEq, Eq)]
+struct Style {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+    monospace: bool,
+    reverse: bool,
+    fg: Option<String>,
+    bg: Option<String>,
+}
+
+impl Style {
+    fn css(&self) -> String {
+        let mut css = String::new();
+        let (fg, bg) = if self.reverse {
+            (
+                Some(self.bg.as_deref().unwrap_or("var(--bg)")),
+                Some(self.fg.as_deref().unwrap_or("var(--fg)")),
+            )
+        } else {
+            (self.fg.as_deref(), self.bg.as_deref())
+        };
+        if let Some(fg) = fg {
+            css.push_str(&format!("color:{};", fg));
+        }
+        if let Some(bg) = bg {
+            css.push_str(&format!("background:{};", bg));
+        }
+        if self.bold {
+            css.push_str("font-weight:bold;");
+        }
+        if self.italic {
+            css.push_str("font-style:italic;");
+        }
+        match (self.underline, self.strikethrough) {
+            (true, true) => css.push_str("text-decoration:underline line-through;"),
+            (true, false) => css.push_str("text-decoration:underline;"),
+            (false, true) => css.push_str("text-decoration:line-through;"),
+            (false, false) => {}
+        }
+        if self.monospace {
+            css.push_str("font-family:monospace;");
+        }
+        css
+    }
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+/// Read up to `width` characters matching `is_digit`
+fn take_digits(chars: &mut Chars, width: usize, is_digit: fn(&char) -> bool) -> String {
+    let mut digits = String::new();
+    while digits.len() < width {
+        match chars.next_if(is_digit) {
+            Some(c) => digits.push(c),
+            None => break,
+        }
+    }
+    digits
+}
+
+/// Read a colour code's `fg[,bg]` arguments; `None` when there are none
+fn take_colours(
+    chars: &mut Chars,
+    width: usize,
+    is_digit: fn(&char) -> bool,
+) -> Option<(String, Option<String>)> {
+    let fg = take_digits(chars, width, is_digit);
+    if fg.is_empty() {
+        return None;
+    }
+    let mut lookahead = chars.clone();
+    if lookahead.next() == Some(',') && lookahead.peek().is_some_and(is_digit) {
+        chars.next();
+        return Some((fg, Some(take_digits(chars, width, is_digit))));
+    }
+    Some((fg, None))
+}
+
+fn palette_colour(code: &str) -> Option<String> {
+    code.parse::<usize>()
+        .ok()
+        .and_then(|index| PALETTE.get(index))
+        .map(|colour| colour.to_string())
+}
+
+/// Render mIRC formatting codes as inline-styled HTML
+fn format_html(text: &str, out: &mut String) {
+    let mut style = Style::default();
+    let mut run = String::new();
+    let flush = |run: &mut String, style: &Style, out: &mut String| {
+        if run.is_empty() {
+            return;
+        }
+        let css = style.css();
+        if css.is_empty() {
+            escape_html(run, out);
+        } else {
+            out.push_str(&format!("<span style=\"{}\">", css));
+            escape_html(run, out);
+            out.push_str("</span>");
+        }
+        run.clear();
+    };
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut next = style.clone();
+        match c {
+            '\x02' => next.bold = !next.bold,
+            '\x1d' => next.italic = !next.italic,
+            '\x1f' => next.underline = !next.underline,
+            '\x1e' => next.strikethrough = !next.strikethrough,
+            '\x11' => next.monospace = !next.monospace,
+            '\x16' => next.reverse = !next.reverse,
+            '\x0f' => next = Style::default(),
+            '\x03' => match take_colours(&mut chars, 2, char::is_ascii_digit) {
+                Some((fg, bg)) => {
+                    next.fg = palette_colour(&fg);
+                    if let Some(bg) = bg {
+                        next.bg = palette_colour(&bg);
+                    }
+                }
+                None => (next.fg, next.bg) = (None, None),
+            },
+            '\x04' => match take_colours(&mut chars, 6, char::is_ascii_hexdigit) {
+                Some((fg, bg)) => {
+                    next.fg = (fg.len() == 6).then(|| format!("#{}", fg));
+                    if let Some(bg) = bg {
+                        next.bg = (bg.len() == 6).then(|| format!("#{}", bg));
+                    }
+                }
+                None => (next.fg, next.bg) = (None, None),
+            },
+            c => {
+                run.push(c);
+                continue;
+            }
+        }
+        if next != style {
+            flush(&mut run, &style, out);
+            style = next;
+        }
+    }
+    flush(&mut run, &style, out);
+}
+
+/// Whether a colour from the UI is a plain `#rgb`, `#rrggbb` or `hsl(...)`
+/// value that is safe to put in a style attribute
+fn is_safe_colour(colour: &str) -> bool {
+    if let Some(hex) = colour.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    colour
+        .strip_prefix("hsl(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .is_some_and(|args| {
+            args.chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '.' | '%' | ',' | ' '))
+        })
+}
+
+/// Colour for a nick: the one the UI knows, or a stable hue derived from the nick
+fn nick_colour(nick: &str, known: &HashMap<String, String>) -> String {
+    if let Some(colour) = known.get(&casefold(nick)) {
+        if is_safe_colour(colour) {
+            return colour.clone();
+        }
+    }
+    let hash = casefold(nick)
+        .bytes()
+        .fold(5381u32, |hash, b| hash.wrapping_mul(33) ^ u32::from(b));
+    format!("hsl({}, 60%, 65%)", hash % 360)
+}
+
+const HTML_HEAD: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+:root { --bg: #313338; --fg: #dbdee1; }
+body { background: var(--bg); color: var(--fg); font-family: system-ui, sans-serif; font-size: 14px; margin: 1.5em; }
+h1 { font-size: 1.2em; }
+.line { padding: 1px 0; white-space: pre-wrap; word-break: break-word; }
+.time { color: #80848e; font-family: monospace; margin-right: 0.5em; }
+.nick { font-weight: bold; }
+.event { color: #949ba4; font-style: italic; }
+.notice .nick::before, .notice .nick::after { content: "-"; }
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+"#;
+
+/// Writes one transcript line at a time
+struct Exporter<'a, W: Write> {
+    out: W,
+    format: ExportFormat,
+    nick_colors: &'a HashMap<String, String>,
+}
+
+impl<W: Write> Exporter<'_, W> {
+    fn begin(&mut self, request: &ExportRequest) -> std::io::Result<()> {
+        if self.format == ExportFormat::Html {
+            let mut title = String::new();
+            escape_html(
+                &format!("{} on {}", request.target, request.network),
+                &mut title,
+            );
+            self.out
+                .write_all(HTML_HEAD.replace("{title}", &title).as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, message: &StoredMessage) -> std::io::Result<bool> {
+        if self.format == ExportFormat::Jsonl {
+            serde_json::to_writer(&mut self.out, message)?;
+            self.out.write_all(b"\n")?;
+            return Ok(true);
+        }
+        let Some(entry) = entry(message) else {
+            return Ok(false);
+        };
+        let time = local_time(message.time);
+        let line = match self.format {
+            ExportFormat::Html => self.html_line(&time, &entry),
+            _ => {
+                let body = match entry {
+                    Entry::Message { nick, text } => {
+                        format!("<{}> {}", nick, strip_formatting(text))
+                    }
+                    Entry::Action { nick, text } => {
+                        format!("* {} {}", nick, strip_formatting(text))
+                    }
+                    Entry::Notice { nick, text } => {
+                        format!("-{}- {}", nick, strip_formatting(text))
+                    }
+                    Entry::Event(event) => format!("*** {}", event),
+                };
+                format!("[{}] {}\n", time, body)
+            }
+        };
+        self.out.write_all(line.as_bytes())?;
+        Ok(true)
+    }
+
+    fn html_line(&self, time: &str, entry: &Entry) -> String {
+        let mut line = String::new();
+        let nick_span = |line: &mut String, nick: &str| {
+            line.push_str(&format!(
+                "<span class=\"nick\" style=\"color:{}\">",
+                nick_colour(nick, self.nick_colors)
+            ));
+            escape_html(nick, line);
+            line.push_str("</span> ");
+        };
+        let class = match entry {
+            Entry::Event(_) => "line event",
+            Entry::Notice { .. } => "line notice",
+            _ => "line",
+        };
+        line.push_str(&format!(
+            "<div class=\"{}\"><span class=\"time\">{}</span>",
+            class, time
+        ));
+        match entry {
+            Entry::Message { nick, text } | Entry::Notice { nick, text } => {
+                nick_span(&mut line, nick);
+                format_html(text, &mut line);
+            }
+            Entry::Action { nick, text } => {
+                line.push_str("* ");
+                nick_span(&mut line, nick);
+                format_html(text, &mut line);
+            }
+            Entry::Event(event) => escape_html(event, &mut line),
+        }
+        line.push_str("</div>\n");
+        line
+    }
+
+    fn finish(mut self) -> std::io::Result<()> {
+        if self.format == ExportFormat::Html {
+            self.out.write_all(b"</body>\n</html>\n")?;
+        }
+        self.out.flush()
+    }
+}
+
+/// Stream a conversation from the store into a file, row by row.
+/// Returns the number of messages written.
+fn export_to_file(conn: &Connection, request: &ExportRequest, path: &Path) -> Result<u64, String> {
+    let file =
+        File::create(path).map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let nick_colors = request
+        .nick_colors
+        .iter()
+        .map(|(nick, colour)| (casefold(nick), colour.clone()))
+        .collect();
+    let mut exporter = Exporter {
+        out: BufWriter::new(file),
+        format: request.format,
+        nick_colors: &nick_colors,
+    };
+    let io_error = |e: std::io::Error| format!("Failed to write export: {}", e);
+    let db_error = |e: rusqlite::Error| format!("History error: {}", e);
+
+    exporter.begin(request).map_err(io_error)?;
+    let mut stmt = conn
+        .prepare(&format!(
+            "SELECT {} FROM messages m
+             WHERE m.network = ?1 AND m.target_key = ?2
+               AND (?3 IS NULL OR m.time >= ?3)
+               AND (?4 IS NULL OR m.time <= ?4)
+             ORDER BY m.time, m.id",
+            COLUMNS
+        ))
+        .map_err(db_error)?;
+    let rows = stmt
+        .query_map(
+            params![
+                request.network,
+                casefold(&request.target),
+                request.from,
+                request.to
+            ],
+            StoredMessage::from_row,
+        )
+        .map_err(db_error)?;
+    let mut written = 0;
+    for row in rows {
+        if exporter.write(&row.map_err(db_error)?).map_err(io_error)? {
+            written += 1;
+        }
+    }
+    exporter.finish().map_err(io_error)?;
+    Ok(written)
+}
+
+/// Run an export on a blocking thread
+async fn export_blocking(
+    store: Arc<HistoryStore>,
+    request: ExportRequest,
+    path: PathBuf,
+) -> Result<u64, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let conn = store.lock_conn()?;
+        export_to_file(&conn, &request, &path)
+    })
+    .await
+    .map_err(|e| format!("Export task failed: {}", e))?
+}
+
+fn suggested_filename(request: &ExportRequest) -> String {
+    format!(
+        "{}-{}.{}",
+        path_component(&request.network),
+        path_component(&request.target),
+        request.format.extension()
+    )
+}
+
+/// Export a conversation to HTML, JSON Lines or plain text.
+/// Returns the number of exported messages, or `None` if the user cancelled.
+#[tauri::command]
+pub async fn history_export(
+    app: tauri::AppHandle,
+    request: ExportRequest,
+    history: State<'_, HistoryState>,
+) -> Result<Option<u64>, String> {
+    // Fail before asking where to save
+    if history.0.is_locked() {
+        return Err("The app is locked".to_string());
+    }
+    export_impl(app, history.0.clone(), request).await
+}
+
+#[cfg(desktop)]
+async fn export_impl(
+    _app: tauri::AppHandle,
+    store: Arc<HistoryStore>,
+    request: ExportRequest,
+) -> Result<Option<u64>, String> {
+    let Some(file) = rfd::AsyncFileDialog::new()
+        .set_file_name(suggested_filename(&request))
+        .save_file()
+        .await
+    else {
+        return Ok(None);
+    };
+    export_blocking(store, request, file.path().to_path_buf())
+        .await
+        .map(Some)
+}
+
+#[cfg(target_os = "ios")]
+async fn export_impl(
+    _app: tauri::AppHandle,
+    store: Arc<HistoryStore>,
+    request: ExportRequest,
+) -> Result<Option<u64>, String> {
+    // Write to the temp directory; iOS cleans this up automatically.
+    let tmp_path = std::env::temp_dir().join(suggested_filename(&request));
+    let written = export_blocking(store, request, tmp_path.clone()).await?;
+
+    if let Some(path_str) = tmp_path.to_str() {
+        tauri_plugin_share_sheet::share_file(path_str);
+    }
+    Ok(Some(written))
+}
+
+#[cfg(target_os = "android")]
+async fn export_impl(
+    app: tauri::AppHandle,
+    store: Arc<HistoryStore>,
+    request: ExportRequest,
+) -> Result<Option<u64>, String> {
+    use tauri_plugin_dialog::DialogExt;
+
+    let (tx, rx) = tokio::sync::oneshot::channel::<Option<tauri_plugin_dialog::FilePath>>();
+    app.dialog()
+        .file()
+        .set_file_name(suggested_filename(&request))
+        .save_file(move |path| {
+            let _ = tx.send(path);
+        });
+
+    match rx.await {
+        Ok(Some(tauri_plugin_dialog::FilePath::Path(path))) => {
+            export_blocking(store, request, path).await.map(Some)
+        }
+        Ok(Some(tauri_plugin_dialog::FilePath::Url(uri))) => {
+            // content:// URIs can only be written through the ContentResolver,
+            // so export to a temp file first and copy it over.
+            let tmp_path = std::env::temp_dir().join(suggested_filename(&request));
+            let written = export_blocking(store, request, tmp_path.clone()).await?;
+            let uri_str = uri.to_string();
+            tokio::task::spawn_blocking(move || {
+                let bytes = std::fs::read(&tmp_path).map_err(|e| e.to_string())?;
+                let _ = std::fs::remove_file(&tmp_path);
+                crate::write_bytes_to_content_uri(&bytes, &uri_str)
+            })
+            .await
+            .map_err(|e| format!("Thread join error: {e}"))??;
+            Ok(Some(written))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn html(text: &str) -> String {
+        let mut out = String::new();
+        format_html(text, &mut out);
+        out
+    }
+
+    fn message(sender: &str, command: &str, text: &str) -> StoredMessage {
+        StoredMessage {
+            id: 1,
+            network: "libera".to_string(),
+            target: Some("#chan".to_string()),
+            sender: Some(sender.to_string()),
+            command: command.to_string(),
+            text: Some(text.to_string()),
+            msgid: None,
+            time: 0,
+            tags: serde_json::Value::Null,
+            outgoing: false,
+        }
+    }
+
+    fn export(
+        format: ExportFormat,
+        nick_colors: &HashMap<String, String>,
+        message: &StoredMessage,
+    ) -> String {
+        let mut exporter = Exporter {
+            out: Vec::new(),
+            format,
+            nick_colors,
+        };
+        exporter.write(message).unwrap();
+        String::from_utf8(exporter.out).unwrap()
+    }
+
+    #[test]
+    fn mirc_formatting_becomes_inline_styles() {
+        assert_eq!(html("plain <b>"), "plain &lt;b&gt;");
+        assert_eq!(
+            html("\x02bold\x02 normal"),
+            "<span style=\"font-weight:bold;\">bold</span> normal"
+        );
+        assert_eq!(
+            html("\x034,12red on blue\x03 none"),
+            "<span style=\"color:#FF0000;background:#0000FC;\">red on blue</span> none"
+        );
+        assert_eq!(
+            html("\x04ff8800orange\x0f"),
+            "<span style=\"color:#ff8800;\">orange</span>"
+        );
+        // A comma without a digit after it is text
+        assert_eq!(html("\x033,x"), "<span style=\"color:#009300;\">,x</span>");
+    }
+
+    #[test]
+    fn hostile_nick_colours_are_replaced() {
+        let alice = message("alice", "PRIVMSG", "hi");
+        let mut colours = HashMap::new();
+        colours.insert("alice".to_string(), "#abc".to_string());
+        assert!(export(ExportFormat::Html, &colours, &alice).contains("style=\"color:#abc\""));
+
+        colours.insert(
+            "alice".to_string(),
+            "red\"><script>alert(1)</script>".to_string(),
+        );
+        let line = export(ExportFormat::Html, &colours, &alice);
+        assert!(!line.contains("<script>"));
+        assert!(line.contains(&format!(
+            "style=\"color:{}\"",
+            nick_colour("alice", &HashMap::new())
+        )));
+
+        for hostile in ["hsl(1,2%,3%);background:url(x)", "#abcd", "expression(x)"] {
+            assert!(!is_safe_colour(hostile), "{}", hostile);
+        }
+        assert!(is_safe_colour("hsl(120, 60%, 65%)"));
+    }
+
+    #[test]
+    fn text_transcript_strips_formatting() {
+        let none = HashMap::new();
+        let line = export(
+            ExportFormat::Text,
+            &none,
+            &message("bob", "PRIVMSG", "\x01ACTION \x02waves\x01"),
+        );
+        assert!(line.ends_with("] * bob waves\n"));
+        let line = export(
+            ExportFormat::Text,
+            &none,
+            &message("bob", "KICK", "carol spam"),
+        );
+        assert!(line.ends_with("] *** bob has kicked carol (spam)\n"));
+        assert_eq!(
+            export(
+                ExportFormat::Text,
+                &none,
+                &message("bob", "PRIVMSG", "\x01VERSION\x01")
+            ),
+            ""
+        );
+    }
+}
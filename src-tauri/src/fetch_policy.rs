@@ -0,0 +1,478 @@
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use tauri::State;
+
+use crate::util::{load_json, save_json};
+
+/// Redirects a policy client follows before giving up
+const MAX_REDIRECTS: usize = 5;
+
+/// Hosts and networks the user lets backend fetches reach despite the
+/// policy, e.g. an intranet filehost
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FetchPolicyConfig {
+    /// Host names (`files.corp`, `*.corp`), addresses or CIDR ranges
+    allowed_hosts: Vec<String>,
+}
+
+/// One entry of the allowlist
+enum Allowed {
+    Host(String),
+    /// `*.example` matches subdomains and `example` itself
+    Domain(String),
+    Network(IpAddr, u8),
+}
+
+impl Allowed {
+    fn parse(entry: &str) -> Result<Self, String> {
+        let entry = entry.trim().to_ascii_lowercase();
+        if let Some((addr, len)) = entry.split_once('/') {
+            let addr: IpAddr = addr
+                .parse()
+                .map_err(|_| format!("Invalid network: {}", entry))?;
+            let max = if addr.is_ipv4() { 32 } else { 128 };
+            let len: u8 = len
+                .parse()
+                .ok()
+                .filter(|len| *len <= max)
+                .ok_or_else(|| format!("Invalid prefix length: {}", entry))?;
+            return Ok(Self::Network(addr, len));
+        }
+        if let Ok(addr) = entry.trim_matches(['[', ']']).parse::<IpAddr>() {
+            let len = if addr.is_ipv4() { 32 } else { 128 };
+            return Ok(Self::Network(addr, len));
+        }
+        if let Some(domain) = entry.strip_prefix("*.") {
+            return Ok(Self::Domain(domain.to_string()));
+        }
+        let valid = !entry.is_empty()
+            && entry
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'));
+        if !valid {
+            return Err(format!("Invalid host: {}", entry));
+        }
+        Ok(Self::Host(entry))
+    }
+
+    fn matches_host(&self, host: &str) -> bool {
+        match self {
+            Self::Host(name) => name == host,
+            Self::Domain(domain) => {
+                host == domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|s| s.ends_with('.'))
+            }
+            Self::Network(..) => false,
+        }
+    }
+
+    fn matches_ip(&self, ip: IpAddr) -> bool {
+        let Self::Network(network, len) = *self else {
+            return false;
+        };
+        match (network, canonical(ip)) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                prefix_matches(&network.octets(), &ip.octets(), len)
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                prefix_matches(&network.octets(), &ip.octets(), len)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn prefix_matches(network: &[u8], ip: &[u8], len: u8) -> bool {
+    let full = (len / 8) as usize;
+    let rest = len % 8;
+    if network[..full] != ip[..full] {
+        return false;
+    }
+    rest == 0 || {
+        let mask = 0xFFu8 << (8 - rest);
+        network[full] & mask == ip[full] & mask
+    }
+}
+
+/// IPv4 addresses hidden in IPv6 ones (mapped, NAT64, 6to4, Teredo) as the
+/// IPv4 address
+fn canonical(ip: IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return IpAddr::V4(v4);
+            }
+            let o = v6.octets();
+            if o[..12] == [0, 0x64, 0xFF, 0x9B, 0, 0, 0, 0, 0, 0, 0, 0] {
+                return IpAddr::V4(Ipv4Addr::new(o[12], o[13], o[14], o[15]));
+            }
+            // 6to4: 2002:aabb:ccdd::/48
+            if o[..2] == [0x20, 0x02] {
+                return IpAddr::V4(Ipv4Addr::new(o[2], o[3], o[4], o[5]));
+            }
+            // Teredo: 2001:0::/32 with the client address inverted at the end
+            if o[..4] == [0x20, 0x01, 0, 0] {
+                return IpAddr::V4(Ipv4Addr::new(!o[12], !o[13], !o[14], !o[15]));
+            }
+            ip
+        }
+        IpAddr::V4(_) => ip,
+    }
+}
+
+/// Addresses remote content must not make the app reach: this machine, the
+/// local network, link-local (including cloud metadata services at
+/// 169.254.169.254 and fd00:ec2::254) and non-unicast ranges
+fn is_blocked(ip: IpAddr) -> bool {
+    match canonical(ip) {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || a == 0
+                // Shared address space (carrier NAT, Alibaba metadata)
+                || (a == 100 && (64..128).contains(&b))
+                || (a == 192 && b == 0 && c == 0)
+                // Benchmarking
+                || (a == 198 && (18..20).contains(&b))
+                || a >= 240
+        }
+        IpAddr::V6(ip) => {
+            let first = ip.segments()[0];
+            ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_multicast()
+                // Unique local
+                || (first & 0xFE00) == 0xFC00
+                // Link-local
+                || (first & 0xFFC0) == 0xFE80
+                // Deprecated site-local
+                || (first & 0xFFC0) == 0xFEC0
+        }
+    }
+}
+
+/// Decides which hosts backend HTTP fetches of remote content may reach.
+///
+/// Clients built by `client_builder` resolve names through the policy, so
+/// every connection is checked against the addresses it actually goes to,
+/// redirects included; URLs with literal addresses are checked before the
+/// request and on each redirect.
+pub struct FetchPolicy {
+    config: RwLock<FetchPolicyConfig>,
+    allowed: RwLock<Vec<Allowed>>,
+    config_path: PathBuf,
+}
+
+impl FetchPolicy {
+    /// Load the allowlist from `config_path`, falling back to none
+    pub fn load(config_path: PathBuf) -> Self {
+        let config: FetchPolicyConfig = load_json(&config_path);
+        let allowed = config
+            .allowed_hosts
+            .iter()
+            .filter_map(|entry| Allowed::parse(entry).ok())
+            .collect();
+        Self {
+            config: RwLock::new(config),
+            allowed: RwLock::new(allowed),
+            config_path,
+        }
+    }
+
+    fn config(&self) -> FetchPolicyConfig {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn update(&self, config: FetchPolicyConfig) -> Result<(), String> {
+        let allowed = config
+            .allowed_hosts
+            .iter()
+            .map(|entry| Allowed::parse(entry))
+            .collect::<Result<Vec<_>, _>>()?;
+        save_json(&self.config_path, &config, "fetch settings")?;
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+        *self.allowed.write().unwrap_or_else(|e| e.into_inner()) = allowed;
+        Ok(())
+    }
+
+    fn host_allowed(&self, host: &str, trusted: &[String]) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        trusted.iter().any(|t| t.eq_ignore_ascii_case(&host))
+            || self
+                .allowed
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .any(|allowed| allowed.matches_host(&host))
+    }
+
+    fn ip_allowed(&self, ip: IpAddr) -> bool {
+        !is_blocked(ip)
+            || self
+                .allowed
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .any(|allowed| allowed.matches_ip(ip))
+    }
+
+    /// Reject URLs that aren't http(s) or name a blocked address directly
+    fn check(&self, url: &reqwest::Url, trusted: &[String]) -> Result<(), String> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("Unsupported URL scheme: {}", url.scheme()));
+        }
+        let host = url.host_str().ok_or("The URL has no host")?;
+        if self.host_allowed(host, trusted) {
+            return Ok(());
+        }
+        match host.trim_matches(['[', ']']).parse::<IpAddr>() {
+            Ok(ip) if !self.ip_allowed(ip) => Err(blocked_message(host)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check a URL before handing it to a policy client
+    pub(crate) fn check_url(&self, url: &str) -> Result<reqwest::Url, String> {
+        let url = reqwest::Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+        self.check(&url, &[])?;
+        Ok(url)
+    }
+
+    /// A client builder bound to the policy. `trusted` names hosts the user
+    /// configured themselves (an OAuth issuer), which skip the checks.
+    ///
+    /// System proxies are off: a proxy would resolve names on our behalf
+    /// and defeat the address checks.
+    pub(crate) fn client_builder(self: &Arc<Self>, trusted: &[&str]) -> reqwest::ClientBuilder {
+        let trusted: Arc<Vec<String>> = Arc::new(
+            trusted
+                .iter()
+                .map(|host| host.to_ascii_lowercase())
+                .collect(),
+        );
+        let policy = self.clone();
+        let redirect_trusted = trusted.clone();
+        let redirect = reqwest::redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                return attempt.error(format!("More than {} redirects", MAX_REDIRECTS));
+            }
+            match policy.check(attempt.url(), &redirect_trusted) {
+                Ok(()) => attempt.follow(),
+                Err(e) => attempt.error(e),
+            }
+        });
+        reqwest::Client::builder()
+            .no_proxy()
+            .redirect(redirect)
+            .dns_resolver(Arc::new(PolicyResolver {
+                policy: self.clone(),
+                trusted,
+            }))
+    }
+}
+
+fn blocked_message(host: &str) -> String {
+    format!(
+        "{} is a local or private address; add it to the allowed hosts to fetch from it",
+        host
+    )
+}
+
+/// Resolves names and drops addresses the policy blocks
+struct PolicyResolver {
+    policy: Arc<FetchPolicy>,
+    trusted: Arc<Vec<String>>,
+}
+
+impl reqwest::dns::Resolve for PolicyResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let policy = self.policy.clone();
+        let trusted = self.trusted.clone();
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if policy.host_allowed(&host, &trusted) {
+                return Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs);
+            }
+            // One blocked address taints the name: a rebinding host could
+            // otherwise hand out a public and a private one
+            if addrs.iter().any(|addr| !policy.ip_allowed(addr.ip())) {
+                return Err(blocked_message(&host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// A reqwest error with its causes, which carry the policy's messages
+pub(crate) fn error_message(error: &reqwest::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        let cause_text = cause.to_string();
+        if !message.contains(&cause_text) {
+            message = format!("{}: {}", message, cause_text);
+        }
+        source = cause.source();
+    }
+    message
+}
+
+pub struct FetchPolicyState(pub Arc<FetchPolicy>);
+
+#[tauri::command]
+pub async fn fetch_policy_get(
+    policy: State<'_, FetchPolicyState>,
+) -> Result<FetchPolicyConfig, String> {
+    Ok(policy.0.config())
+}
+
+#[tauri::command]
+pub async fn fetch_policy_set(
+    config: FetchPolicyConfig,
+    policy: State<'_, FetchPolicyState>,
+) -> Result<(), String> {
+    policy.0.update(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(addr: &str) -> IpAddr {
+        addr.parse().unwrap()
+    }
+
+    #[test]
+    fn blocks_local_and_special_addresses() {
+        for addr in [
+            "0.0.0.0",
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.100.100.200",
+            "192.0.0.1",
+            "198.18.0.1",
+            "224.0.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fd00:ec2::254",
+            "fe80::1",
+            "fec0::1",
+            "ff02::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::a00:1",
+            // 6to4 wrapping 127.0.0.1 and 169.254.169.254
+            "2002:7f00:1::",
+            "2002:a9fe:a9fe::1",
+            // Teredo with the client 192.168.1.1, inverted
+            "2001:0:4136:e378:8000:63bf:3f57:fefe",
+        ] {
+            assert!(is_blocked(ip(addr)), "{} should be blocked", addr);
+        }
+    }
+
+    #[test]
+    fn allows_public_addresses() {
+        for addr in [
+            "1.1.1.1",
+            "93.184.216.34",
+            "100.128.0.1",
+            "2606:4700:4700::1111",
+            "::ffff:1.1.1.1",
+            "2002:101:101::1",
+            // Teredo with the client 1.1.1.1, inverted
+            "2001:0:4136:e378:8000:63bf:fefe:fefe",
+        ] {
+            assert!(!is_blocked(ip(addr)), "{} should be allowed", addr);
+        }
+    }
+
+    #[test]
+    fn prefixes() {
+        for (network, addr, len, expected) in [
+            ([10, 0, 0, 0], [10, 255, 1, 2], 8, true),
+            ([10, 0, 0, 0], [11, 0, 0, 0], 8, false),
+            ([192, 168, 0, 0], [192, 168, 1, 1], 23, true),
+            ([192, 168, 0, 0], [192, 168, 2, 1], 23, false),
+            ([1, 2, 3, 4], [1, 2, 3, 4], 32, true),
+            ([1, 2, 3, 4], [1, 2, 3, 5], 32, false),
+            ([1, 2, 3, 4], [9, 9, 9, 9], 0, true),
+        ] {
+            assert_eq!(
+                prefix_matches(&network, &addr, len),
+                expected,
+                "{:?}/{} vs {:?}",
+                network,
+                len,
+                addr
+            );
+        }
+    }
+
+    #[test]
+    fn parses_allowlist_entries() {
+        for entry in [
+            "files.corp",
+            "*.corp",
+            "10.0.0.0/8",
+            "fd00::/8",
+            "192.168.1.5",
+            "[fd00::1]",
+        ] {
+            assert!(Allowed::parse(entry).is_ok(), "{}", entry);
+        }
+        for entry in ["", "10.0.0.0/33", "fd00::/129", "host/8", "bad host", "a:b"] {
+            assert!(Allowed::parse(entry).is_err(), "{}", entry);
+        }
+        assert!(matches!(
+            Allowed::parse("[fd00::1]").unwrap(),
+            Allowed::Network(IpAddr::V6(_), 128)
+        ));
+    }
+
+    #[test]
+    fn matches_hosts_and_networks() {
+        for (entry, host, expected) in [
+            ("files.corp", "files.corp", true),
+            ("FILES.corp", "files.corp", true),
+            ("files.corp", "a.files.corp", false),
+            ("*.corp", "corp", true),
+            ("*.corp", "files.corp", true),
+            ("*.corp", "a.b.corp", true),
+            ("*.corp", "notcorp", false),
+            ("10.0.0.0/8", "10.0.0.1", false),
+        ] {
+            let allowed = Allowed::parse(entry).unwrap();
+            assert_eq!(
+                allowed.matches_host(host),
+                expected,
+                "{} vs {}",
+                entry,
+                host
+            );
+        }
+        let network = Allowed::parse("10.0.0.0/8").unwrap();
+        assert!(network.matches_ip(ip("10.2.3.4")));
+        assert!(network.matches_ip(ip("::ffff:10.2.3.4")));
+        assert!(network.matches_ip(ip("2002:a02:304::")));
+        assert!(!network.matches_ip(ip("11.0.0.1")));
+    }
+}
@@ -0,0 +1,1015 @@
+use rusqlite::{params, Connection, DatabaseName, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tauri::State;
+
+use crate::chatlog::ChatLogger;
+use crate::irc::{is_channel, Message};
+use crate::stats::now_millis;
+
+/// How often the writer thread applies the retention policy when idle
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// Most rows written in one transaction
+const MAX_BATCH: usize = 500;
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// Schema migrations, applied in order and tracked with `PRAGMA user_version`
+const MIGRATIONS: &[&str] = &[
+    r#"
+CREATE TABLE messages (
+    id INTEGER PRIMARY KEY,
+    network TEXT NOT NULL,
+    target TEXT,
+    target_key TEXT,
+    sender TEXT,
+    sender_key TEXT,
+    command TEXT NOT NULL,
+    text TEXT,
+    msgid TEXT,
+    time INTEGER NOT NULL,
+    tags TEXT NOT NULL DEFAULT '{}',
+    outgoing INTEGER NOT NULL DEFAULT 0
+);
+CREATE UNIQUE INDEX messages_msgid ON messages(network, msgid) WHERE msgid IS NOT NULL;
+CREATE INDEX messages_target ON messages(network, target_key, time);
+CREATE INDEX messages_time ON messages(time);
+CREATE VIRTUAL TABLE messages_fts USING fts5(text, content='messages', content_rowid='id');
+CREATE TRIGGER messages_ai AFTER INSERT ON messages WHEN new.text IS NOT NULL BEGIN
+    INSERT INTO messages_fts(rowid, text) VALUES (new.id, new.text);
+END;
+CREATE TRIGGER messages_ad AFTER DELETE ON messages WHEN old.text IS NOT NULL BEGIN
+    INSERT INTO messages_fts(messages_fts, rowid, text) VALUES ('delete', old.id, old.text);
+END;
+CREATE TABLE meta (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+"#,
+    r#"
+ALTER TABLE messages ADD COLUMN import_key TEXT;
+CREATE UNIQUE INDEX messages_import_key ON messages(import_key) WHERE import_key IS NOT NULL;
+"#,
+    r#"
+CREATE TABLE stats_days (
+    network TEXT NOT NULL,
+    target_key TEXT NOT NULL,
+    day TEXT NOT NULL,
+    data TEXT NOT NULL,
+    PRIMARY KEY (network, target_key, day)
+);
+CREATE TABLE stats_progress (
+    network TEXT NOT NULL,
+    target_key TEXT NOT NULL,
+    last_id INTEGER NOT NULL,
+    PRIMARY KEY (network, target_key)
+);
+"#,
+];
+
+pub(crate) const COLUMNS: &str =
+    "m.id, m.network, m.target, m.sender, m.command, m.text, m.msgid, m.time, m.tags, m.outgoing";
+
+/// A message about to be written to the store
+#[derive(Debug, Clone)]
+pub struct NewMessage {
+    pub network: String,
+    pub target: Option<String>,
+    pub sender: Option<String>,
+    pub command: String,
+    pub text: Option<String>,
+    pub msgid: Option<String>,
+    /// Milliseconds since the Unix epoch
+    pub time: i64,
+    /// Message tags as a JSON object
+    pub tags: String,
+    pub outgoing: bool,
+    /// Stable identity of an imported line, so re-imports are skipped
+    pub import_key: Option<String>,
+}
+
+/// A message read back from the store, as sent to TS
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredMessage {
+    pub(crate) id: i64,
+    pub(crate) network: String,
+    pub(crate) target: Option<String>,
+    pub(crate) sender: Option<String>,
+    pub(crate) command: String,
+    pub(crate) text: Option<String>,
+    pub(crate) msgid: Option<String>,
+    pub(crate) time: i64,
+    pub(crate) tags: serde_json::Value,
+    pub(crate) outgoing: bool,
+}
+
+impl StoredMessage {
+    pub(crate) fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        let tags: String = row.get(8)?;
+        Ok(Self {
+            id: row.get(0)?,
+            network: row.get(1)?,
+            target: row.get(2)?,
+            sender: row.get(3)?,
+            command: row.get(4)?,
+            text: row.get(5)?,
+            msgid: row.get(6)?,
+            time: row.get(7)?,
+            tags: serde_json::from_str(&tags).unwrap_or_default(),
+            outgoing: row.get(9)?,
+        })
+    }
+}
+
+/// Lower-cased key used to match targets and nicks regardless of case
+pub(crate) fn casefold(name: &str) -> String {
+    name.to_ascii_lowercase()
+}
+
+/// Insert messages in one transaction, skipping ones already stored.
+/// Returns how many were new.
+pub(crate) fn insert_messages(
+    conn: &mut Connection,
+    messages: &[NewMessage],
+) -> rusqlite::Result<usize> {
+    let tx = conn.transaction()?;
+    let mut inserted = 0;
+    {
+        let mut stmt = tx.prepare_cached(
+            "INSERT OR IGNORE INTO messages
+                (network, target, target_key, sender, sender_key, command, text, msgid, time, tags, outgoing, import_key)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        )?;
+        for m in messages {
+            inserted += stmt.execute(params![
+                m.network,
+                m.target,
+                m.target.as_deref().map(casefold),
+                m.sender,
+                m.sender.as_deref().map(casefold),
+                m.command,
+                m.text,
+                m.msgid,
+                m.time,
+                m.tags,
+                m.outgoing,
+                m.import_key,
+            ])?;
+        }
+    }
+    tx.commit()?;
+    Ok(inserted)
+}
+
+fn migrate(conn: &mut Connection) -> rusqlite::Result<()> {
+    let version: usize = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration)?;
+        tx.pragma_update(None, "user_version", index + 1)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+fn configure(conn: &Connection) -> rusqlite::Result<()> {
+    conn.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))?;
+    conn.pragma_update(None, "synchronous", "NORMAL")?;
+    conn.busy_timeout(Duration::from_secs(5))
+}
+
+/// Copy the database into a new file encrypted with `key`, or plaintext
+fn export_to(conn: &Connection, path: &Path, key: Option<&str>) -> rusqlite::Result<()> {
+    let key = key.map(|key| format!("x'{}'", key)).unwrap_or_default();
+    conn.execute(
+        "ATTACH DATABASE ?1 AS rekeyed KEY ?2",
+        params![path.to_string_lossy(), key],
+    )?;
+    let exported = conn
+        .query_row("SELECT sqlcipher_export('rekeyed')", [], |_| Ok(()))
+        .and_then(|()| {
+            conn.pragma_update(
+                Some(DatabaseName::Attached("rekeyed")),
+                "user_version",
+                MIGRATIONS.len(),
+            )
+        });
+    conn.execute("DETACH DATABASE rekeyed", [])?;
+    exported
+}
+
+fn retention_days(conn: &Connection) -> rusqlite::Result<Option<u32>> {
+    let value: Option<String> = conn
+        .query_row(
+            "SELECT value FROM meta WHERE key = 'retention_days'",
+            [],
+            |row| row.get(0),
+        )
+        .optional()?;
+    Ok(value.and_then(|v| v.parse().ok()))
+}
+
+/// Delete messages older than the retention period, if one is set. The
+/// statistics cache keeps quotes and URLs verbatim, so it is dropped too and
+/// rebuilt from what is left on the next report.
+pub(crate) fn prune(conn: &Connection) -> rusqlite::Result<usize> {
+    let Some(days) = retention_days(conn)? else {
+        return Ok(0);
+    };
+    let cutoff = now_millis() as i64 - i64::from(days) * 24 * 60 * 60 * 1000;
+    let deleted = conn.execute("DELETE FROM messages WHERE time < ?1", [cutoff])?;
+    if deleted > 0 {
+        conn.execute("DELETE FROM stats_days", [])?;
+        conn.execute("DELETE FROM stats_progress", [])?;
+    }
+    Ok(deleted)
+}
+
+/// Messages held by the writer while the history is locked
+const MAX_PENDING: usize = 50_000;
+
+enum WriterCommand {
+    Record(NewMessage),
+    /// Switch to another connection, or to none to hold messages in memory;
+    /// the sender is told once the old connection is closed
+    Open(Option<Connection>, mpsc::Sender<()>),
+}
+
+/// Write a batch, or hold it until a connection is back
+fn write_or_hold(
+    conn: &mut Option<Connection>,
+    pending: &mut Vec<NewMessage>,
+    batch: Vec<NewMessage>,
+) {
+    match conn {
+        Some(conn) => {
+            if let Err(e) = insert_messages(conn, &batch) {
+                log::error!("History write error: {}", e);
+            }
+        }
+        None => {
+            pending.extend(batch);
+            if pending.len() > MAX_PENDING {
+                let excess = pending.len() - MAX_PENDING;
+                pending.drain(..excess);
+            }
+        }
+    }
+}
+
+/// Background thread that batches live traffic into transactions
+fn run_writer(mut conn: Option<Connection>, rx: mpsc::Receiver<WriterCommand>) {
+    let mut last_prune = Instant::now();
+    let mut pending = Vec::new();
+    loop {
+        if last_prune.elapsed() >= PRUNE_INTERVAL {
+            if let Some(conn) = &conn {
+                if let Err(e) = prune(conn) {
+                    log::warn!("History prune error: {}", e);
+                }
+            }
+            last_prune = Instant::now();
+        }
+        let mut next = match rx.recv_timeout(PRUNE_INTERVAL) {
+            Ok(command) => Some(command),
+            Err(mpsc::RecvTimeoutError::Timeout) => None,
+            Err(mpsc::RecvTimeoutError::Disconnected) => break,
+        };
+        while let Some(command) = next.take() {
+            match command {
+                WriterCommand::Record(first) => {
+                    let mut batch = vec![first];
+                    while batch.len() < MAX_BATCH {
+                        match rx.try_recv() {
+                            Ok(WriterCommand::Record(message)) => batch.push(message),
+                            Ok(other) => {
+                                next = Some(other);
+                                break;
+                            }
+                            Err(_) => break,
+                        }
+                    }
+                    write_or_hold(&mut conn, &mut pending, batch);
+                }
+                WriterCommand::Open(new_conn, done) => {
+                    conn = new_conn;
+                    if conn.is_some() && !pending.is_empty() {
+                        let held = std::mem::take(&mut pending);
+                        write_or_hold(&mut conn, &mut pending, held);
+                    }
+                    let _ = done.send(());
+                }
+            }
+        }
+    }
+}
+
+/// Open the database file, keyed with a SQLCipher raw key in hex when encrypted
+fn open_file(path: &Path, key: Option<&str>) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    if let Some(key) = key {
+        conn.pragma_update(None, "key", format!("x'{}'", key))?;
+    }
+    configure(&conn)?;
+    Ok(conn)
+}
+
+/// SQLite message history with full-text search.
+///
+/// Live traffic goes through a channel to a dedicated writer connection;
+/// queries, deletes and imports use a second connection. With the app lock
+/// on, the file is encrypted with SQLCipher; while locked, the writer holds
+/// new messages in memory and queries fail.
+pub struct HistoryStore {
+    path: PathBuf,
+    writer: Mutex<mpsc::Sender<WriterCommand>>,
+    /// The query connection; None while locked
+    conn: Arc<Mutex<Option<Connection>>>,
+}
+
+/// The unlocked query connection, held until dropped
+pub(crate) struct ConnGuard<'a>(MutexGuard<'a, Option<Connection>>);
+
+impl Deref for ConnGuard<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.0.as_ref().expect("checked by lock_conn")
+    }
+}
+
+impl DerefMut for ConnGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.0.as_mut().expect("checked by lock_conn")
+    }
+}
+
+impl HistoryStore {
+    /// Open the history at `path`, or start locked until `unlock` is called
+    pub fn open(path: &Path, locked: bool) -> rusqlite::Result<Self> {
+        let (tx, rx) = mpsc::channel();
+        let (conn, writer_conn) = if locked {
+            (None, None)
+        } else {
+            let mut conn = open_file(path, None)?;
+            migrate(&mut conn)?;
+            prune(&conn)?;
+            (Some(conn), Some(open_file(path, None)?))
+        };
+        std::thread::spawn(move || run_writer(writer_conn, rx));
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: Mutex::new(tx),
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    /// Queue a message for writing without blocking the caller
+    pub fn record(&self, message: NewMessage) {
+        let writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+        let _ = writer.send(WriterCommand::Record(message));
+    }
+
+    /// Hand the writer a connection and wait until it switched
+    fn reopen_writer(&self, conn: Option<Connection>) {
+        let (done, wait) = mpsc::channel();
+        {
+            let writer = self.writer.lock().unwrap_or_else(|e| e.into_inner());
+            let _ = writer.send(WriterCommand::Open(conn, done));
+        }
+        let _ = wait.recv();
+    }
+
+    /// Close the file; new messages are held in memory until `unlock`
+    pub(crate) fn lock(&self) -> rusqlite::Result<()> {
+        self.reopen_writer(None);
+        *self.query_conn() = None;
+        Ok(())
+    }
+
+    /// Open the file again with `key` and write the held messages
+    pub(crate) fn unlock(&self, key: Option<&str>) -> rusqlite::Result<()> {
+        let mut conn = open_file(&self.path, key)?;
+        migrate(&mut conn)?;
+        prune(&conn)?;
+        let writer_conn = open_file(&self.path, key)?;
+        *self.query_conn() = Some(conn);
+        self.reopen_writer(Some(writer_conn));
+        Ok(())
+    }
+
+    /// Re-encrypt the file from `current` to `new`, where None is plaintext.
+    /// Live messages are held while the copy is made.
+    pub(crate) fn rekey(&self, current: Option<&str>, new: Option<&str>) -> Result<(), String> {
+        let tmp = self.path.with_extension("sqlite3-rekey");
+        let _ = fs::remove_file(&tmp);
+        self.reopen_writer(None);
+
+        let swapped = {
+            let mut conn = self.query_conn();
+            let exported = match conn.as_ref() {
+                Some(conn) => export_to(conn, &tmp, new).map_err(|e| e.to_string()),
+                None => Err("The app is locked".to_string()),
+            };
+            // Close the file before it is replaced
+            exported.map(|()| *conn = None)
+        };
+        let result = swapped.and_then(|()| {
+            for suffix in ["-wal", "-shm"] {
+                let mut side = self.path.clone().into_os_string();
+                side.push(suffix);
+                let _ = fs::remove_file(side);
+            }
+            fs::rename(&tmp, &self.path).map_err(|e| e.to_string())
+        });
+
+        match result {
+            Ok(()) => self.unlock(new).map_err(|e| e.to_string()),
+            Err(e) => {
+                let _ = fs::remove_file(&tmp);
+                self.unlock(current).map_err(|e| e.to_string())?;
+                Err(e)
+            }
+        }
+    }
+
+    pub(crate) fn is_locked(&self) -> bool {
+        self.query_conn().is_none()
+    }
+
+    fn query_conn(&self) -> MutexGuard<'_, Option<Connection>> {
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Lock the query connection from code already on a blocking thread.
+    /// Fails while the app is locked.
+    pub(crate) fn lock_conn(&self) -> Result<ConnGuard<'_>, String> {
+        let conn = self.query_conn();
+        if conn.is_none() {
+            return Err("The app is locked".to_string());
+        }
+        Ok(ConnGuard(conn))
+    }
+
+    /// Run a query on a blocking thread
+    pub(crate) async fn with_conn<T, F>(&self, f: F) -> Result<T, String>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let conn = self.conn.clone();
+        tauri::async_runtime::spawn_blocking(move || {
+            let mut conn = conn.lock().unwrap_or_else(|e| e.into_inner());
+            let conn = conn.as_mut().ok_or("The app is locked")?;
+            f(conn).map_err(|e| format!("History error: {}", e))
+        })
+        .await
+        .map_err(|e| format!("History task failed: {}", e))?
+    }
+}
+
+/// Shared history store
+pub struct HistoryState(pub(crate) Arc<HistoryStore>);
+
+/// What a feed remembers about its connection
+#[derive(Default)]
+struct FeedState {
+    nick: Option<String>,
+    /// Case-folded channel name to the case-folded nicks in it
+    channels: HashMap<String, HashSet<String>>,
+    /// References of open batches replaying old history
+    playback_batches: HashSet<String>,
+}
+
+impl FeedState {
+    fn is_own_nick(&self, nick: &str) -> bool {
+        self.nick
+            .as_deref()
+            .is_some_and(|own| own.eq_ignore_ascii_case(nick))
+    }
+
+    /// Channels we share with a nick, as case-folded names
+    fn channels_of(&self, nick: &str) -> Vec<String> {
+        let nick = casefold(nick);
+        self.channels
+            .iter()
+            .filter(|(_, members)| members.contains(&nick))
+            .map(|(channel, _)| channel.clone())
+            .collect()
+    }
+
+    /// Follow nick, membership and batch changes
+    fn update(&mut self, message: &Message) {
+        let source = message.source_nick().unwrap_or_default();
+        match message.command.as_str() {
+            "001" => self.nick = message.param(0).map(str::to_string),
+            "JOIN" => {
+                if let Some(channel) = message.param(0) {
+                    let members = self.channels.entry(casefold(channel)).or_default();
+                    members.insert(casefold(source));
+                }
+            }
+            // RPL_NAMREPLY: <me> <symbol> <channel> :<names>
+            "353" => {
+                if let (Some(channel), Some(names)) = (message.param(2), message.param(3)) {
+                    let members = self.channels.entry(casefold(channel)).or_default();
+                    for name in names.split(' ').filter(|n| !n.is_empty()) {
+                        let name = name.trim_start_matches(['~', '&', '@', '%', '+', '!', '.']);
+                        let nick = name.split('!').next().unwrap_or(name);
+                        members.insert(casefold(nick));
+                    }
+                }
+            }
+            "PART" | "KICK" => {
+                let nick = if message.command == "KICK" {
+                    message.param(1)
+                } else {
+                    Some(source)
+                };
+                if let (Some(channel), Some(nick)) = (message.param(0), nick) {
+                    if self.is_own_nick(nick) {
+                        self.channels.remove(&casefold(channel));
+                    } else if let Some(members) = self.channels.get_mut(&casefold(channel)) {
+                        members.remove(&casefold(nick));
+                    }
+                }
+            }
+            "QUIT" => {
+                let nick = casefold(source);
+                for members in self.channels.values_mut() {
+                    members.remove(&nick);
+                }
+            }
+            "NICK" => {
+                if let Some(new) = message.param(0) {
+                    if self.is_own_nick(source) {
+                        self.nick = Some(new.to_string());
+                    }
+                    let (old, new) = (casefold(source), casefold(new));
+                    for members in self.channels.values_mut() {
+                        if members.remove(&old) {
+                            members.insert(new.clone());
+                        }
+                    }
+                }
+            }
+            "BATCH" => {
+                if let Some(reference) = message.param(0) {
+                    if let Some(reference) = reference.strip_prefix('-') {
+                        self.playback_batches.remove(reference);
+                    } else if let Some(reference) = reference.strip_prefix('+') {
+                        if matches!(message.param(1), Some("chathistory" | "znc.in/playback")) {
+                            self.playback_batches.insert(reference.to_string());
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Feeds one connection's traffic into the history store and chat logs.
+///
+/// Tracks our own nick so private messages are filed under the other party,
+/// channel membership so QUIT and NICK land in the right channel logs, and
+/// whether echo-message is on so outgoing messages aren't stored twice.
+pub struct HistoryFeed {
+    network: String,
+    store: Arc<HistoryStore>,
+    chat_log: Arc<ChatLogger>,
+    state: Mutex<FeedState>,
+    echo_message: AtomicBool,
+}
+
+impl HistoryFeed {
+    pub fn new(network: &str, store: Arc<HistoryStore>, chat_log: Arc<ChatLogger>) -> Self {
+        Self {
+            network: network.to_string(),
+            store,
+            chat_log,
+            state: Mutex::new(FeedState::default()),
+            echo_message: AtomicBool::new(false),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, FeedState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn inbound(&self, data: &[u8]) {
+        let line = String::from_utf8_lossy(data);
+        let Some(message) = Message::parse(&line) else {
+            return;
+        };
+
+        if message.command == "CAP" && message.param(1) == Some("ACK") {
+            let caps = message.params.last().map(String::as_str).unwrap_or("");
+            for cap in caps.split(' ') {
+                match cap {
+                    "echo-message" => self.echo_message.store(true, Ordering::Relaxed),
+                    "-echo-message" => self.echo_message.store(false, Ordering::Relaxed),
+                    _ => {}
+                }
+            }
+        }
+
+        let (entry, channels, playback) = {
+            let mut state = self.state();
+            let entry = self.entry(&state, &message, false);
+            // QUIT and NICK go to every channel the user was in before the change
+            let channels = match (&entry, message.command.as_str()) {
+                (Some(_), "QUIT" | "NICK") => {
+                    state.channels_of(message.source_nick().unwrap_or_default())
+                }
+                (Some(entry), _) => entry.target.iter().cloned().collect(),
+                (None, _) => Vec::new(),
+            };
+            let playback = message
+                .tags
+                .get("batch")
+                .is_some_and(|batch| state.playback_batches.contains(batch));
+            state.update(&message);
+            (entry, channels, playback)
+        };
+
+        if let Some(entry) = entry {
+            self.deliver(entry, &channels, playback);
+        }
+    }
+
+    pub fn outbound(&self, data: &[u8]) {
+        // With echo-message the server sends our messages back with msgid and time
+        if self.echo_message.load(Ordering::Relaxed) {
+            return;
+        }
+        let line = String::from_utf8_lossy(data);
+        let Some(message) = Message::parse(&line) else {
+            return;
+        };
+        // Membership changes are always echoed, only messages need recording here
+        if message.command != "PRIVMSG" && message.command != "NOTICE" {
+            return;
+        }
+        let entry = self.entry(&self.state(), &message, true);
+        if let Some(entry) = entry {
+            let channels: Vec<String> = entry.target.iter().cloned().collect();
+            self.deliver(entry, &channels, false);
+        }
+    }
+
+    /// Hand a message to the chat logs and the store, unless the network is incognito.
+    /// Replayed history only goes to the store, which drops duplicates by msgid.
+    fn deliver(&self, entry: NewMessage, channels: &[String], playback: bool) {
+        if self.chat_log.is_incognito(&self.network) {
+            return;
+        }
+        if !playback {
+            for channel in channels {
+                self.chat_log.write(&entry, channel);
+            }
+        }
+        self.store.record(entry);
+    }
+
+    fn entry(&self, state: &FeedState, message: &Message, outgoing: bool) -> Option<NewMessage> {
+        let source = message.source_nick();
+        // Our own messages also arrive from the server with echo-message and
+        // in replayed history, and belong to the conversation they were sent to
+        let outgoing = outgoing
+            || (matches!(message.command.as_str(), "PRIVMSG" | "NOTICE")
+                && source.is_some_and(|nick| state.is_own_nick(nick)));
+        let sender = match source {
+            Some(nick) => Some(nick.to_string()),
+            None if outgoing => state.nick.clone(),
+            None => None,
+        };
+        let rest = |from: usize| {
+            let params = message.params.get(from..).unwrap_or_default();
+            (!params.is_empty()).then(|| params.join(" "))
+        };
+
+        let (target, text) = match message.command.as_str() {
+            "PRIVMSG" | "NOTICE" => {
+                let target = message.param(0)?;
+                if target == "*" {
+                    return None;
+                }
+                // A private message to us belongs to the conversation with its sender
+                let target = if outgoing || is_channel(target) {
+                    target.to_string()
+                } else {
+                    sender.clone()?
+                };
+                (Some(target), rest(1))
+            }
+            // extended-join adds account and realname, which aren't message text
+            "JOIN" => (message.param(0).map(str::to_string), None),
+            "PART" | "KICK" | "TOPIC" => (message.param(0).map(str::to_string), rest(1)),
+            "MODE" => {
+                let target = message.param(0)?;
+                if !is_channel(target) {
+                    return None;
+                }
+                (Some(target.to_string()), rest(1))
+            }
+            "QUIT" | "NICK" => (None, rest(0)),
+            _ => return None,
+        };
+
+        let time = message
+            .tags
+            .get("time")
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|t| t.timestamp_millis())
+            .unwrap_or_else(|| now_millis() as i64);
+
+        Some(NewMessage {
+            network: self.network.clone(),
+            target,
+            sender,
+            command: message.command.clone(),
+            text,
+            msgid: message.tags.get("msgid").cloned(),
+            time,
+            tags: serde_json::to_string(&message.tags).unwrap_or_else(|_| "{}".to_string()),
+            outgoing,
+            import_key: None,
+        })
+    }
+}
+
+/// Position in a conversation to page backwards from
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct HistoryCursor {
+    time: i64,
+    id: i64,
+}
+
+/// Optional filters for a global search
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryFilters {
+    network: Option<String>,
+    target: Option<String>,
+    sender: Option<String>,
+    /// Inclusive lower bound, milliseconds since the Unix epoch
+    from: Option<i64>,
+    /// Inclusive upper bound, milliseconds since the Unix epoch
+    to: Option<i64>,
+}
+
+/// Turn user input into an FTS5 query that matches all words literally
+fn fts_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Get a page of a conversation's history, oldest first.
+/// Pass the `time` and `id` of the oldest message already shown to get older ones.
+#[tauri::command]
+pub async fn history_page(
+    network: String,
+    target: String,
+    before: Option<HistoryCursor>,
+    limit: Option<u32>,
+    history: State<'_, HistoryState>,
+) -> Result<Vec<StoredMessage>, String> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    history
+        .0
+        .with_conn(move |conn| {
+            let mut stmt = conn.prepare_cached(&format!(
+                "SELECT {} FROM messages m
+                 WHERE m.network = ?1 AND m.target_key = ?2
+                   AND (?3 IS NULL OR m.time < ?3 OR (m.time = ?3 AND m.id < ?4))
+                 ORDER BY m.time DESC, m.id DESC
+                 LIMIT ?5",
+                COLUMNS
+            ))?;
+            let mut messages = stmt
+                .query_map(
+                    params![
+                        network,
+                        casefold(&target),
+                        before.map(|c| c.time),
+                        before.map(|c| c.id),
+                        limit
+                    ],
+                    StoredMessage::from_row,
+                )?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            messages.reverse();
+            Ok(messages)
+        })
+        .await
+}
+
+/// Search all stored messages, newest first
+#[tauri::command]
+pub async fn history_search(
+    query: String,
+    filters: Option<HistoryFilters>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    history: State<'_, HistoryState>,
+) -> Result<Vec<StoredMessage>, String> {
+    let filters = filters.unwrap_or_default();
+    let query = fts_query(&query);
+    history
+        .0
+        .with_conn(move |conn| {
+            let conditions = "(?2 IS NULL OR m.network = ?2)
+                AND (?3 IS NULL OR m.target_key = ?3)
+                AND (?4 IS NULL OR m.sender_key = ?4)
+                AND (?5 IS NULL OR m.time >= ?5)
+                AND (?6 IS NULL OR m.time <= ?6)";
+            // Without search words this is a plain filtered listing
+            let sql = if query.is_empty() {
+                format!(
+                    "SELECT {} FROM messages m
+                     WHERE ?1 = '' AND {}
+                     ORDER BY m.time DESC, m.id DESC LIMIT ?7 OFFSET ?8",
+                    COLUMNS, conditions
+                )
+            } else {
+                format!(
+                    "SELECT {} FROM messages_fts JOIN messages m ON m.id = messages_fts.rowid
+                     WHERE messages_fts MATCH ?1 AND {}
+                     ORDER BY m.time DESC, m.id DESC LIMIT ?7 OFFSET ?8",
+                    COLUMNS, conditions
+                )
+            };
+            let mut stmt = conn.prepare_cached(&sql)?;
+            let messages = stmt
+                .query_map(
+                    params![
+                        query,
+                        filters.network,
+                        filters.target.as_deref().map(casefold),
+                        filters.sender.as_deref().map(casefold),
+                        filters.from,
+                        filters.to,
+                        limit.unwrap_or(DEFAULT_PAGE_SIZE),
+                        offset.unwrap_or(0)
+                    ],
+                    StoredMessage::from_row,
+                )?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            Ok(messages)
+        })
+        .await
+}
+
+/// Delete stored messages; every argument narrows what is deleted.
+/// Channel statistics of the affected conversations are rebuilt on their next report.
+/// Returns the number of deleted messages.
+#[tauri::command]
+pub async fn history_delete(
+    network: Option<String>,
+    target: Option<String>,
+    before: Option<i64>,
+    history: State<'_, HistoryState>,
+) -> Result<usize, String> {
+    history
+        .0
+        .with_conn(move |conn| {
+            let target_key = target.as_deref().map(casefold);
+            let tx = conn.transaction()?;
+            let deleted = tx.execute(
+                "DELETE FROM messages
+                 WHERE (?1 IS NULL OR network = ?1)
+                   AND (?2 IS NULL OR target_key = ?2)
+                   AND (?3 IS NULL OR time < ?3)",
+                params![network, target_key, before],
+            )?;
+            if deleted > 0 {
+                for table in ["stats_days", "stats_progress"] {
+                    tx.execute(
+                        &format!(
+                            "DELETE FROM {} WHERE (?1 IS NULL OR network = ?1)
+                               AND (?2 IS NULL OR target_key = ?2)",
+                            table
+                        ),
+                        params![network, target_key],
+                    )?;
+                }
+            }
+            tx.commit()?;
+            Ok(deleted)
+        })
+        .await
+}
+
+/// Get the retention period in days; `None` keeps history forever
+#[tauri::command]
+pub async fn history_get_retention(
+    history: State<'_, HistoryState>,
+) -> Result<Option<u32>, String> {
+    history.0.with_conn(|conn| retention_days(conn)).await
+}
+
+/// Set the retention period in days and prune immediately; `None` keeps history forever
+#[tauri::command]
+pub async fn history_set_retention(
+    days: Option<u32>,
+    history: State<'_, HistoryState>,
+) -> Result<usize, String> {
+    history
+        .0
+        .with_conn(move |conn| {
+            match days {
+                Some(days) => conn.execute(
+                    "INSERT OR REPLACE INTO meta (key, value) VALUES ('retention_days', ?1)",
+                    [days.to_string()],
+                )?,
+                None => conn.execute("DELETE FROM meta WHERE key = 'retention_days'", [])?,
+            };
+            prune(conn)
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault::Vault;
+
+    fn feed() -> HistoryFeed {
+        let missing = Path::new("/nonexistent");
+        let store = HistoryStore::open(&missing.join("history.sqlite3"), true).unwrap();
+        let chat_log = ChatLogger::load(
+            missing.join("chat-logs.json"),
+            missing.join("logs"),
+            Arc::new(Vault::load(missing)),
+        );
+        HistoryFeed::new("irc.example.net", Arc::new(store), Arc::new(chat_log))
+    }
+
+    fn entry(line: &str, outgoing: bool) -> NewMessage {
+        let state = FeedState {
+            nick: Some("Me".to_string()),
+            ..FeedState::default()
+        };
+        feed()
+            .entry(&state, &Message::parse(line).unwrap(), outgoing)
+            .unwrap()
+    }
+
+    #[test]
+    fn private_messages_belong_to_the_other_party() {
+        let received = entry(":bob!u@h PRIVMSG me :hi", false);
+        assert_eq!(received.target.as_deref(), Some("bob"));
+        assert_eq!(received.sender.as_deref(), Some("bob"));
+        assert!(!received.outgoing);
+
+        let sent = entry("PRIVMSG bob :hi", true);
+        assert_eq!(sent.target.as_deref(), Some("bob"));
+        assert_eq!(sent.sender.as_deref(), Some("Me"));
+        assert!(sent.outgoing);
+    }
+
+    #[test]
+    fn echoed_own_messages_are_outgoing() {
+        let echoed = entry("@msgid=abc :me!u@h PRIVMSG bob :hi", false);
+        assert_eq!(echoed.target.as_deref(), Some("bob"));
+        assert_eq!(echoed.sender.as_deref(), Some("me"));
+        assert!(echoed.outgoing);
+
+        let channel = entry(":ME!u@h NOTICE #chan :hello", false);
+        assert_eq!(channel.target.as_deref(), Some("#chan"));
+        assert!(channel.outgoing);
+
+        // Joining is an event, not something we sent
+        assert!(!entry(":me!u@h JOIN #chan", false).outgoing);
+    }
+
+    #[test]
+    fn queries_fail_while_locked() {
+        let dir = std::env::temp_dir().join(format!("history-lock-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let store = HistoryStore::open(&dir.join("history.sqlite3"), false).unwrap();
+        assert!(store.lock_conn().is_ok());
+
+        store.lock().unwrap();
+        assert!(store.is_locked());
+        assert_eq!(
+            store.lock_conn().err().as_deref(),
+            Some("The app is locked")
+        );
+        let queried = tauri::async_runtime::block_on(store.with_conn(|_| Ok(())));
+        assert_eq!(queried.err().as_deref(), Some("The app is locked"));
+
+        store.unlock(None).unwrap();
+        assert!(store.lock_conn().is_ok());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
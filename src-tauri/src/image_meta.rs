@@ -0,0 +1,884 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::State;
+
+use crate::download::sniff;
+use crate::upload::UploadState;
+
+/// Largest image the metadata commands will read into memory
+const MAX_IMAGE_SIZE: u64 = 200 * 1024 * 1024;
+const EXIF_HEADER: &[u8] = b"Exif\0\0";
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1A\n";
+/// An EXIF block with an empty IFD0, written over EXIF items in HEIF files
+const EMPTY_TIFF: &[u8] = b"MM\0\x2A\0\0\0\x08\0\0\0\0\0\0";
+
+/// What privacy-relevant metadata an image carries, for warning before
+/// it's sent
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataReport {
+    /// "jpeg", "png", "webp", "heif"; None for anything else
+    format: Option<&'static str>,
+    /// Whether `strip` can clean the file
+    supported: bool,
+    /// EXIF beyond the orientation tag
+    exif: bool,
+    gps: bool,
+    xmp: bool,
+    iptc: bool,
+    /// Free-form comments and text chunks
+    comments: bool,
+    camera: Option<String>,
+    taken_at: Option<String>,
+    orientation: Option<u16>,
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Jpeg,
+    Png,
+    WebP,
+    Heif,
+}
+
+impl Format {
+    fn of(bytes: &[u8]) -> Option<Self> {
+        match sniff(bytes)?.1 {
+            "jpg" => Some(Self::Jpeg),
+            "png" => Some(Self::Png),
+            "webp" => Some(Self::WebP),
+            "heic" | "avif" => Some(Self::Heif),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpeg",
+            Self::Png => "png",
+            Self::WebP => "webp",
+            Self::Heif => "heif",
+        }
+    }
+}
+
+fn malformed() -> String {
+    "The image is malformed".to_string()
+}
+
+/// Bounds-checked big-endian reads over part of a file
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or_else(malformed)?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// An unsigned integer of 0, 4 or 8 bytes, as ISOBMFF sizes them
+    fn uint(&mut self, size: u8) -> Result<u64, String> {
+        match size {
+            0 => Ok(0),
+            4 => Ok(self.u32()? as u64),
+            8 => Ok(u64::from_be_bytes(self.take(8)?.try_into().unwrap())),
+            _ => Err(malformed()),
+        }
+    }
+}
+
+/// One IFD entry; `value_pos` is where the value or its offset sits
+struct IfdEntry {
+    tag: u16,
+    kind: u16,
+    count: u32,
+    value_pos: usize,
+}
+
+/// A TIFF structure as found in EXIF blocks
+struct Tiff<'a> {
+    data: &'a [u8],
+    little: bool,
+}
+
+impl Tiff<'_> {
+    fn u16(&self, at: usize) -> Option<u16> {
+        let bytes: [u8; 2] = self.data.get(at..at + 2)?.try_into().ok()?;
+        Some(if self.little {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32(&self, at: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.data.get(at..at + 4)?.try_into().ok()?;
+        Some(if self.little {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn entries(&self, ifd: usize) -> Vec<IfdEntry> {
+        let count = self.u16(ifd).unwrap_or(0) as usize;
+        (0..count.min(512))
+            .map_while(|i| {
+                let at = ifd + 2 + i * 12;
+                Some(IfdEntry {
+                    tag: self.u16(at)?,
+                    kind: self.u16(at + 2)?,
+                    count: self.u32(at + 4)?,
+                    value_pos: at + 8,
+                })
+            })
+            .collect()
+    }
+
+    fn ascii(&self, entry: &IfdEntry) -> Option<String> {
+        if entry.kind != 2 {
+            return None;
+        }
+        let len = entry.count as usize;
+        let start = if len <= 4 {
+            entry.value_pos
+        } else {
+            self.u32(entry.value_pos)? as usize
+        };
+        let text = String::from_utf8_lossy(self.data.get(start..start + len)?);
+        let text = text.trim_end_matches('\0').trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+}
+
+/// Note what an EXIF block (a TIFF structure) holds
+fn note_exif(report: &mut MetadataReport, tiff: &[u8]) {
+    let tiff = tiff.strip_prefix(EXIF_HEADER).unwrap_or(tiff);
+    let little = match tiff.get(..2) {
+        Some(b"II") => true,
+        Some(b"MM") => false,
+        _ => return,
+    };
+    let tiff = Tiff { data: tiff, little };
+    let Some(ifd0) = tiff.u32(4).filter(|_| tiff.u16(2) == Some(42)) else {
+        return;
+    };
+    let (mut make, mut model, mut exif_ifd) = (None, None, None);
+    for entry in tiff.entries(ifd0 as usize) {
+        if entry.tag != 0x0112 {
+            report.exif = true;
+        }
+        match entry.tag {
+            0x0112 => {
+                report.orientation = tiff.u16(entry.value_pos).filter(|o| (1..=8).contains(o))
+            }
+            0x010F => make = tiff.ascii(&entry),
+            0x0110 => model = tiff.ascii(&entry),
+            0x8769 => exif_ifd = tiff.u32(entry.value_pos),
+            0x8825 => {
+                report.gps |= tiff
+                    .u32(entry.value_pos)
+                    .and_then(|ifd| tiff.u16(ifd as usize))
+                    .is_some_and(|count| count > 0)
+            }
+            _ => {}
+        }
+    }
+    if let Some(ifd) = exif_ifd {
+        for entry in tiff.entries(ifd as usize) {
+            // DateTimeOriginal
+            if entry.tag == 0x9003 {
+                report.taken_at = tiff.ascii(&entry);
+            }
+        }
+    }
+    report.camera = match (make, model) {
+        (Some(make), Some(model)) if model.starts_with(&make) => Some(model),
+        (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+        (make, model) => make.or(model),
+    };
+}
+
+/// A big-endian TIFF block holding only the orientation tag
+fn orientation_exif(orientation: u16) -> Vec<u8> {
+    let mut tiff = b"MM\0\x2A\0\0\0\x08\0\x01\x01\x12\0\x03\0\0\0\x01".to_vec();
+    tiff.extend_from_slice(&orientation.to_be_bytes());
+    tiff.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    tiff
+}
+
+/// Orientation worth keeping: anything but the default
+fn kept_orientation(report: &MetadataReport) -> Option<u16> {
+    report.orientation.filter(|o| *o != 1)
+}
+
+/// Drop APP1 EXIF/XMP, APP13 IPTC, comments, vendor APP segments and
+/// anything after the end of the image (the extra pictures of MPF files,
+/// which carry their own EXIF); keep JFIF, ICC profiles and Adobe color
+/// info
+fn strip_jpeg(bytes: &[u8], report: &mut MetadataReport) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(&bytes[..2]);
+    let mut exif_at = 2;
+    let mut pos = 2;
+    while pos < bytes.len() {
+        if bytes[pos] != 0xFF || pos + 1 >= bytes.len() {
+            return Err(malformed());
+        }
+        let marker = bytes[pos + 1];
+        match marker {
+            // Fill byte before a marker
+            0xFF => {
+                pos += 1;
+                continue;
+            }
+            0xD9 => {
+                out.extend_from_slice(&[0xFF, 0xD9]);
+                break;
+            }
+            0x01 | 0xD0..=0xD7 => {
+                out.extend_from_slice(&bytes[pos..pos + 2]);
+                pos += 2;
+                continue;
+            }
+            _ => {}
+        }
+        let len = Reader {
+            data: bytes,
+            pos: pos + 2,
+        }
+        .u16()? as usize;
+        let end = pos + 2 + len;
+        let payload = bytes.get(pos + 4..end).ok_or_else(malformed)?;
+        let drop = match marker {
+            0xE1 if payload.starts_with(EXIF_HEADER) => {
+                note_exif(report, payload);
+                true
+            }
+            0xE1 if payload.starts_with(b"http://ns.adobe.com/") => {
+                report.xmp = true;
+                true
+            }
+            0xE2 => payload.starts_with(b"MPF\0"),
+            0xED => {
+                report.iptc = true;
+                true
+            }
+            0xFE => {
+                report.comments = true;
+                true
+            }
+            0xE0 | 0xE1 | 0xEE => false,
+            0xE3..=0xEF => true,
+            _ => false,
+        };
+        if !drop {
+            out.extend_from_slice(&bytes[pos..end]);
+            if marker == 0xE0 && exif_at == 2 {
+                exif_at = out.len();
+            }
+        }
+        pos = end;
+        if marker == 0xDA {
+            // Entropy-coded data runs to the next marker that isn't a
+            // stuffed byte or a restart
+            let start = pos;
+            while pos + 1 < bytes.len()
+                && !(bytes[pos] == 0xFF
+                    && bytes[pos + 1] != 0
+                    && !(0xD0..=0xD7).contains(&bytes[pos + 1]))
+            {
+                pos += 1;
+            }
+            if pos + 1 >= bytes.len() {
+                // Truncated: keep what there is, as viewers do
+                out.extend_from_slice(&bytes[start..]);
+                break;
+            }
+            out.extend_from_slice(&bytes[start..pos]);
+        }
+    }
+    if let Some(orientation) = kept_orientation(report) {
+        let tiff = orientation_exif(orientation);
+        let mut segment = vec![0xFF, 0xE1];
+        segment.extend_from_slice(&((2 + EXIF_HEADER.len() + tiff.len()) as u16).to_be_bytes());
+        segment.extend_from_slice(EXIF_HEADER);
+        segment.extend_from_slice(&tiff);
+        out.splice(exif_at..exif_at, segment);
+    }
+    Ok(out)
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8], data: &[u8]) {
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    out.extend_from_slice(&crc.finalize().to_be_bytes());
+}
+
+/// Drop eXIf, text chunks (XMP lives in iTXt) and tIME
+fn strip_png(bytes: &[u8], report: &mut MetadataReport) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(PNG_SIGNATURE);
+    let mut reader = Reader {
+        data: bytes,
+        pos: PNG_SIGNATURE.len(),
+    };
+    let mut seen_data = false;
+    while reader.pos < bytes.len() {
+        let start = reader.pos;
+        let len = reader.u32()? as usize;
+        let kind = reader.take(4)?;
+        let data = reader.take(len)?;
+        reader.take(4)?;
+        let drop = match kind {
+            b"eXIf" => {
+                note_exif(report, data);
+                true
+            }
+            b"tEXt" | b"zTXt" | b"iTXt" => {
+                if data.starts_with(b"XML:com.adobe.xmp\0") {
+                    report.xmp = true;
+                } else {
+                    report.comments = true;
+                }
+                true
+            }
+            b"tIME" => true,
+            _ => false,
+        };
+        // eXIf has to come before the image data
+        if kind == b"IDAT" && !seen_data {
+            seen_data = true;
+            if let Some(orientation) = kept_orientation(report) {
+                png_chunk(&mut out, b"eXIf", &orientation_exif(orientation));
+            }
+        }
+        if !drop {
+            out.extend_from_slice(&bytes[start..reader.pos]);
+        }
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    Ok(out)
+}
+
+/// Drop the EXIF and XMP chunks and their VP8X flags
+fn strip_webp(bytes: &[u8], report: &mut MetadataReport) -> Result<Vec<u8>, String> {
+    const EXIF_FLAG: u8 = 0x08;
+    const XMP_FLAG: u8 = 0x04;
+    let riff_len = u32::from_le_bytes(bytes.get(4..8).ok_or_else(malformed)?.try_into().unwrap());
+    let end = (8 + riff_len as usize).min(bytes.len());
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(&bytes[..12]);
+    let mut flags_at = None;
+    let mut pos = 12;
+    while pos + 8 <= end {
+        let kind = &bytes[pos..pos + 4];
+        let len = u32::from_le_bytes(bytes[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        let padded = (pos + 8 + len + (len & 1)).min(end);
+        let data = bytes.get(pos + 8..pos + 8 + len).ok_or_else(malformed)?;
+        match kind {
+            b"EXIF" => note_exif(report, data),
+            b"XMP " => report.xmp = true,
+            _ => {
+                if kind == b"VP8X" {
+                    flags_at = Some(out.len() + 8);
+                }
+                out.extend_from_slice(&bytes[pos..padded]);
+            }
+        }
+        pos = padded;
+    }
+    if let Some(flags_at) = flags_at {
+        out[flags_at] &= !(EXIF_FLAG | XMP_FLAG);
+        if let Some(orientation) = kept_orientation(report) {
+            let tiff = orientation_exif(orientation);
+            out.extend_from_slice(b"EXIF");
+            out.extend_from_slice(&(tiff.len() as u32).to_le_bytes());
+            out.extend_from_slice(&tiff);
+            if tiff.len() % 2 == 1 {
+                out.push(0);
+            }
+            out[flags_at] |= EXIF_FLAG;
+        }
+    }
+    let riff_len = (out.len() - 8) as u32;
+    out[4..8].copy_from_slice(&riff_len.to_le_bytes());
+    Ok(out)
+}
+
+/// Child boxes of an ISOBMFF box as (type, content start, end)
+fn isobmff_boxes(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<Vec<([u8; 4], usize, usize)>, String> {
+    let mut boxes = Vec::new();
+    let mut reader = Reader {
+        data: &bytes[..end],
+        pos: start,
+    };
+    while reader.pos + 8 <= end {
+        let box_start = reader.pos;
+        let size = reader.u32()? as u64;
+        let kind: [u8; 4] = reader.take(4)?.try_into().unwrap();
+        let size = match size {
+            0 => (end - box_start) as u64,
+            1 => reader.uint(8)?,
+            size => size,
+        };
+        let box_end = usize::try_from(size)
+            .ok()
+            .and_then(|size| box_start.checked_add(size))
+            .filter(|box_end| *box_end <= end && *box_end >= reader.pos)
+            .ok_or_else(malformed)?;
+        boxes.push((kind, reader.pos, box_end));
+        reader.pos = box_end;
+    }
+    Ok(boxes)
+}
+
+/// File ranges of each item in an `iloc` box
+fn heif_item_ranges(
+    bytes: &[u8],
+    iloc: (usize, usize),
+    idat: Option<usize>,
+) -> Result<HashMap<u32, Vec<(usize, usize)>>, String> {
+    let mut reader = Reader {
+        data: &bytes[..iloc.1],
+        pos: iloc.0,
+    };
+    let version = reader.u8()?;
+    reader.take(3)?;
+    let sizes = reader.u8()?;
+    let (offset_size, length_size) = (sizes >> 4, sizes & 0x0F);
+    let sizes = reader.u8()?;
+    let base_size = sizes >> 4;
+    let index_size = if version >= 1 { sizes & 0x0F } else { 0 };
+    let count = if version < 2 {
+        reader.u16()? as u32
+    } else {
+        reader.u32()?
+    };
+    let mut items: HashMap<u32, Vec<(usize, usize)>> = HashMap::new();
+    for _ in 0..count {
+        let id = if version < 2 {
+            reader.u16()? as u32
+        } else {
+            reader.u32()?
+        };
+        let method = if version >= 1 {
+            reader.u16()? & 0x0F
+        } else {
+            0
+        };
+        reader.u16()?;
+        let base = reader.uint(base_size)?;
+        let extents = reader.u16()?;
+        for _ in 0..extents {
+            reader.uint(index_size)?;
+            let offset = reader.uint(offset_size)?;
+            let length = reader.uint(length_size)?;
+            // Offsets are into the file, or into the idat box
+            let origin = match (method, idat) {
+                (0, _) => 0,
+                (1, Some(idat)) => idat as u64,
+                _ => continue,
+            };
+            let start = origin + base + offset;
+            let end = if length == 0 {
+                bytes.len() as u64
+            } else {
+                start + length
+            };
+            if end > bytes.len() as u64 || start > end {
+                return Err(malformed());
+            }
+            items
+                .entry(id)
+                .or_default()
+                .push((start as usize, end as usize));
+        }
+    }
+    Ok(items)
+}
+
+/// Blank the EXIF and XMP items in place. HEIF keeps orientation in the
+/// irot/imir properties, so nothing needs to be carried over, and
+/// overwriting rather than removing leaves every offset valid.
+fn strip_heif(bytes: &[u8], report: &mut MetadataReport) -> Result<Vec<u8>, String> {
+    let top = isobmff_boxes(bytes, 0, bytes.len())?;
+    let Some(&(_, meta_start, meta_end)) = top.iter().find(|(kind, ..)| kind == b"meta") else {
+        return Ok(bytes.to_vec());
+    };
+    let children = isobmff_boxes(bytes, meta_start + 4, meta_end)?;
+    let child = |name: &[u8; 4]| {
+        children
+            .iter()
+            .find(|(kind, ..)| kind == name)
+            .map(|&(_, start, end)| (start, end))
+    };
+
+    let (mut exif_items, mut xmp_items) = (Vec::new(), Vec::new());
+    if let Some((iinf_start, iinf_end)) = child(b"iinf") {
+        let entries_at = iinf_start + if bytes[iinf_start] == 0 { 6 } else { 8 };
+        for (kind, start, end) in isobmff_boxes(bytes, entries_at, iinf_end)? {
+            if &kind != b"infe" {
+                continue;
+            }
+            let mut reader = Reader {
+                data: &bytes[..end],
+                pos: start,
+            };
+            let version = reader.u8()?;
+            if version < 2 {
+                continue;
+            }
+            reader.take(3)?;
+            let id = if version == 2 {
+                reader.u16()? as u32
+            } else {
+                reader.u32()?
+            };
+            reader.u16()?;
+            match reader.take(4)? {
+                b"Exif" => exif_items.push(id),
+                b"mime" => {
+                    let rest = &bytes[reader.pos..end];
+                    let content_type = rest
+                        .split(|b| *b == 0)
+                        .nth(1)
+                        .map(String::from_utf8_lossy)
+                        .unwrap_or_default();
+                    if content_type.contains("rdf+xml") || content_type.contains("xmp") {
+                        xmp_items.push(id);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    if exif_items.is_empty() && xmp_items.is_empty() {
+        return Ok(bytes.to_vec());
+    }
+    let Some(iloc) = child(b"iloc") else {
+        return Err(malformed());
+    };
+    let ranges = heif_item_ranges(bytes, iloc, child(b"idat").map(|(start, _)| start))?;
+
+    let mut out = bytes.to_vec();
+    for id in exif_items {
+        let Some(extents) = ranges.get(&id) else {
+            continue;
+        };
+        let data: Vec<u8> = extents
+            .iter()
+            .flat_map(|&(start, end)| bytes[start..end].iter().copied())
+            .collect();
+        // A 32-bit offset to the TIFF header comes first
+        if let Some(skip) = data
+            .get(..4)
+            .map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+        {
+            if let Some(tiff) = data.get(4 + skip as usize..) {
+                note_exif(report, tiff);
+            }
+        }
+        let mut blank = vec![0u8; data.len()];
+        let header: Vec<u8> = [&[0, 0, 0, 0][..], EMPTY_TIFF].concat();
+        if blank.len() >= header.len() {
+            blank[..header.len()].copy_from_slice(&header);
+        }
+        let mut blank = blank.into_iter();
+        for &(start, end) in extents {
+            for byte in &mut out[start..end] {
+                *byte = blank.next().unwrap_or(0);
+            }
+        }
+    }
+    for id in xmp_items {
+        for &(start, end) in ranges.get(&id).into_iter().flatten() {
+            // Already blanked items don't count
+            report.xmp |= bytes[start..end].iter().any(|b| !b.is_ascii_whitespace());
+            out[start..end].fill(b' ');
+        }
+    }
+    Ok(out)
+}
+
+fn scan(bytes: &[u8], report: &mut MetadataReport) -> Result<Option<Vec<u8>>, String> {
+    let Some(format) = Format::of(bytes) else {
+        return Ok(None);
+    };
+    report.format = Some(format.name());
+    let stripped = match format {
+        Format::Jpeg => strip_jpeg(bytes, report),
+        Format::Png => strip_png(bytes, report),
+        Format::WebP => strip_webp(bytes, report),
+        Format::Heif => strip_heif(bytes, report),
+    }?;
+    report.supported = true;
+    Ok(Some(stripped))
+}
+
+/// Whether `strip` handles files starting with `head`
+pub(crate) fn is_supported(head: &[u8]) -> bool {
+    Format::of(head).is_some()
+}
+
+/// What metadata an image carries
+pub(crate) fn inspect(bytes: &[u8]) -> MetadataReport {
+    let mut report = MetadataReport::default();
+    let _ = scan(bytes, &mut report);
+    report
+}
+
+/// The image without EXIF, XMP, IPTC and comments, its orientation kept;
+/// None for formats it doesn't handle
+pub(crate) fn strip(bytes: &[u8]) -> Result<Option<Vec<u8>>, String> {
+    scan(bytes, &mut MetadataReport::default())
+}
+
+pub(crate) async fn read_image(path: &Path) -> Result<Vec<u8>, String> {
+    let metadata = tokio::fs::metadata(path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if metadata.len() > MAX_IMAGE_SIZE {
+        return Err(format!(
+            "The image is larger than {} MB",
+            MAX_IMAGE_SIZE / 1024 / 1024
+        ));
+    }
+    tokio::fs::read(path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// Report the metadata of an image handed out for upload
+#[tauri::command]
+pub async fn image_metadata(
+    handle: String,
+    uploads: State<'_, UploadState>,
+) -> Result<MetadataReport, String> {
+    let bytes = read_image(&uploads.resolve(&handle)?).await?;
+    tauri::async_runtime::spawn_blocking(move || inspect(&bytes))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Big-endian EXIF with the camera make, orientation 6 and a GPS IFD
+    fn exif() -> Vec<u8> {
+        let mut tiff = b"MM\0\x2A\0\0\0\x08\0\x03".to_vec();
+        // Make, ASCII "Canon" at 50
+        tiff.extend_from_slice(&[0x01, 0x0F, 0, 2, 0, 0, 0, 6, 0, 0, 0, 50]);
+        // Orientation 6
+        tiff.extend_from_slice(&[0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0]);
+        // GPS IFD at 56
+        tiff.extend_from_slice(&[0x88, 0x25, 0, 4, 0, 0, 0, 1, 0, 0, 0, 56]);
+        tiff.extend_from_slice(&[0, 0, 0, 0]);
+        tiff.extend_from_slice(b"Canon\0");
+        // GPSLatitudeRef "N"
+        tiff.extend_from_slice(&[0, 1, 0, 0x01, 0, 2, 0, 0, 0, 2, b'N', 0, 0, 0]);
+        tiff.extend_from_slice(&[0, 0, 0, 0]);
+        tiff
+    }
+
+    fn assert_found(report: &MetadataReport) {
+        assert!(report.exif && report.gps);
+        assert_eq!(report.camera.as_deref(), Some("Canon"));
+    }
+
+    /// Strip `bytes`, check the result is clean and stripping it again
+    /// changes nothing
+    fn round_trip(bytes: &[u8]) -> (MetadataReport, Vec<u8>) {
+        let stripped = strip(bytes).unwrap().unwrap();
+        let report = inspect(&stripped);
+        assert!(report.supported);
+        assert!(!report.exif && !report.gps && !report.xmp && !report.iptc && !report.comments);
+        assert_eq!(report.camera, None);
+        assert_eq!(strip(&stripped).unwrap().unwrap(), stripped);
+        (report, stripped)
+    }
+
+    fn jpeg_segment(marker: u8, payload: &[u8]) -> Vec<u8> {
+        let mut segment = vec![0xFF, marker];
+        segment.extend_from_slice(&((payload.len() + 2) as u16).to_be_bytes());
+        segment.extend_from_slice(payload);
+        segment
+    }
+
+    #[test]
+    fn jpeg_round_trip() {
+        let jfif = jpeg_segment(0xE0, b"JFIF\0\x01\x02\0\0\x01\0\x01\0\0");
+        let scan = [0x12, 0x34, 0xFF, 0x00, 0x56, 0xFF, 0xD0, 0x78];
+        let mut jpeg = vec![0xFF, 0xD8];
+        jpeg.extend_from_slice(&jfif);
+        jpeg.extend_from_slice(&jpeg_segment(0xE1, &[EXIF_HEADER, &exif()].concat()));
+        jpeg.extend_from_slice(&jpeg_segment(0xE1, b"http://ns.adobe.com/xap/1.0/\0<x/>"));
+        jpeg.extend_from_slice(&jpeg_segment(0xED, b"Photoshop 3.0\0"));
+        jpeg.extend_from_slice(&jpeg_segment(0xFE, b"a comment"));
+        jpeg.extend_from_slice(&jpeg_segment(0xDA, &[1, 1, 0, 0, 0x3F, 0]));
+        jpeg.extend_from_slice(&scan);
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+
+        let found = inspect(&jpeg);
+        assert_found(&found);
+        assert!(found.xmp && found.iptc && found.comments);
+        assert_eq!(found.orientation, Some(6));
+
+        let (report, stripped) = round_trip(&jpeg);
+        assert_eq!(report.orientation, Some(6));
+        assert_eq!(&stripped[2..2 + jfif.len()], &jfif[..]);
+        assert!(stripped.ends_with(&[&scan[..], &[0xFF, 0xD9]].concat()));
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let mut png = PNG_SIGNATURE.to_vec();
+        png_chunk(&mut png, b"IHDR", &[0, 0, 0, 1, 0, 0, 0, 1, 8, 0, 0, 0, 0]);
+        png_chunk(&mut png, b"eXIf", &exif());
+        png_chunk(&mut png, b"tEXt", b"Comment\0hello");
+        png_chunk(&mut png, b"iTXt", b"XML:com.adobe.xmp\0\0\0\0\0<x/>");
+        png_chunk(&mut png, b"tIME", &[7, 232, 1, 1, 0, 0, 0]);
+        png_chunk(&mut png, b"IDAT", &[1, 2, 3]);
+        png_chunk(&mut png, b"IEND", &[]);
+
+        let found = inspect(&png);
+        assert_found(&found);
+        assert!(found.xmp && found.comments);
+
+        let (report, stripped) = round_trip(&png);
+        assert_eq!(report.orientation, Some(6));
+        let exif_at = stripped.windows(4).position(|w| w == b"eXIf").unwrap();
+        let idat_at = stripped.windows(4).position(|w| w == b"IDAT").unwrap();
+        assert!(exif_at < idat_at);
+        assert!(!stripped.windows(4).any(|w| w == b"tIME"));
+    }
+
+    fn webp_chunk(out: &mut Vec<u8>, kind: &[u8], data: &[u8]) {
+        out.extend_from_slice(kind);
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            out.push(0);
+        }
+    }
+
+    #[test]
+    fn webp_round_trip() {
+        let mut webp = b"RIFF\0\0\0\0WEBP".to_vec();
+        webp_chunk(&mut webp, b"VP8X", &[0x0C, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        webp_chunk(&mut webp, b"VP8L", &[0x2F, 0, 0, 0, 0]);
+        webp_chunk(&mut webp, b"EXIF", &exif());
+        webp_chunk(&mut webp, b"XMP ", b"<x/>");
+        let riff_len = (webp.len() - 8) as u32;
+        webp[4..8].copy_from_slice(&riff_len.to_le_bytes());
+
+        let found = inspect(&webp);
+        assert_found(&found);
+        assert!(found.xmp);
+
+        let (report, stripped) = round_trip(&webp);
+        assert_eq!(report.orientation, Some(6));
+        // The EXIF flag stays for the orientation; XMP goes
+        assert_eq!(stripped[20], 0x08);
+        let riff_len = u32::from_le_bytes(stripped[4..8].try_into().unwrap());
+        assert_eq!(riff_len as usize, stripped.len() - 8);
+    }
+
+    fn isobmff_box(kind: &[u8], content: &[u8]) -> Vec<u8> {
+        let mut out = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(content);
+        out
+    }
+
+    #[test]
+    fn heif_round_trip() {
+        let exif_item = [&[0, 0, 0, 0][..], &exif()].concat();
+        let xmp_item = b"<x:xmpmeta/>".to_vec();
+        let ftyp = isobmff_box(b"ftyp", b"heic\0\0\0\0");
+        let infe = |id: u8, kind: &[u8], rest: &[u8]| {
+            isobmff_box(
+                b"infe",
+                &[&[2, 0, 0, 0, 0, id, 0, 0][..], kind, rest].concat(),
+            )
+        };
+        let iinf = isobmff_box(
+            b"iinf",
+            &[
+                &[0, 0, 0, 0, 0, 2][..],
+                &infe(1, b"Exif", b"\0"),
+                &infe(2, b"mime", b"\0application/rdf+xml\0"),
+            ]
+            .concat(),
+        );
+        let meta_len = 8 + 4 + iinf.len() + 8 + 8 + 2 * 14;
+        let mdat_data = ftyp.len() + meta_len + 8;
+        let mut iloc = vec![0, 0, 0, 0, 0x44, 0, 0, 2];
+        for (id, offset, len) in [
+            (1u16, mdat_data, exif_item.len()),
+            (2, mdat_data + exif_item.len(), xmp_item.len()),
+        ] {
+            iloc.extend_from_slice(&id.to_be_bytes());
+            iloc.extend_from_slice(&[0, 0, 0, 1]);
+            iloc.extend_from_slice(&(offset as u32).to_be_bytes());
+            iloc.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+        let meta = isobmff_box(
+            b"meta",
+            &[&[0, 0, 0, 0][..], &iinf, &isobmff_box(b"iloc", &iloc)].concat(),
+        );
+        assert_eq!(meta.len(), meta_len);
+        let heif = [
+            ftyp,
+            meta,
+            isobmff_box(b"mdat", &[exif_item, xmp_item].concat()),
+        ]
+        .concat();
+
+        let found = inspect(&heif);
+        assert_found(&found);
+        assert!(found.xmp);
+
+        let (_, stripped) = round_trip(&heif);
+        assert_eq!(stripped.len(), heif.len());
+    }
+
+    #[test]
+    fn rejects_truncated_images() {
+        let mut jpeg = vec![0xFF, 0xD8];
+        jpeg.extend_from_slice(&jpeg_segment(0xE1, &[EXIF_HEADER, &exif()].concat()));
+        jpeg.truncate(20);
+        assert!(strip(&jpeg).is_err());
+        assert_eq!(strip(b"plain text").unwrap(), None);
+    }
+}
@@ -0,0 +1,577 @@
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPDecoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::{AnimationDecoder, Delay, DynamicImage, Frame, ImageDecoder, ImageReader, RgbaImage};
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use tauri::State;
+use tokio::io::AsyncReadExt;
+
+use crate::download::{sniff, with_extension};
+use crate::image_meta::read_image;
+use crate::upload::UploadState;
+
+const DEFAULT_QUALITY: u8 = 85;
+/// Quality re-encoding lowers to before it downscales instead
+const MIN_QUALITY: u8 = 60;
+const QUALITY_STEP: u8 = 10;
+/// Downscale per attempt while the result is still over the limit
+const SCALE_STEP: f64 = 0.8;
+const MAX_ATTEMPTS: usize = 10;
+/// Longest side below which it stops downscaling
+const MIN_DIMENSION: u32 = 320;
+/// Decoded frames an animation may take up before it's treated as a still
+const MAX_FRAMES_BYTES: usize = 512 * 1024 * 1024;
+/// 1 (best) to 30 (fastest) for GIF quantization
+const GIF_SPEED: i32 = 10;
+
+/// When and how to shrink an image before upload
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ShrinkOptions {
+    /// The filehost's limit; larger files are re-encoded until they fit
+    max_bytes: Option<u64>,
+    /// Longest side in pixels
+    max_dimension: Option<u32>,
+    /// JPEG and WebP quality, 1-100
+    quality: Option<u8>,
+}
+
+/// What `image_shrink` did
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ShrinkReport {
+    /// Handle of the file to upload: a resized copy, or the original when
+    /// unchanged
+    path: String,
+    changed: bool,
+    before_bytes: u64,
+    after_bytes: u64,
+    before_width: u32,
+    before_height: u32,
+    after_width: u32,
+    after_height: u32,
+    /// Whether the result is still animated
+    animated: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Source {
+    Jpeg,
+    Png,
+    WebP,
+    Gif,
+}
+
+impl Source {
+    fn of(bytes: &[u8]) -> Option<Self> {
+        match sniff(bytes)?.1 {
+            "jpg" => Some(Self::Jpeg),
+            "png" => Some(Self::Png),
+            "webp" => Some(Self::WebP),
+            "gif" => Some(Self::Gif),
+            _ => None,
+        }
+    }
+}
+
+/// A re-encoded image
+pub(crate) struct Shrunk {
+    pub bytes: Vec<u8>,
+    pub extension: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub animated: bool,
+}
+
+/// The size that fits `max` on the longest side, keeping the aspect ratio
+fn fit(width: u32, height: u32, max: u32) -> (u32, u32) {
+    let longest = width.max(height);
+    if longest <= max || max == 0 {
+        return (width, height);
+    }
+    scale(width, height, max as f64 / longest as f64)
+}
+
+fn scale(width: u32, height: u32, factor: f64) -> (u32, u32) {
+    (
+        ((width as f64 * factor).round() as u32).max(1),
+        ((height as f64 * factor).round() as u32).max(1),
+    )
+}
+
+fn image_error(error: image::ImageError) -> String {
+    format!("Couldn't process the image: {}", error)
+}
+
+/// The first frame, turned upright: the EXIF orientation doesn't survive
+/// re-encoding
+fn decode_still(bytes: &[u8]) -> Result<DynamicImage, String> {
+    let mut decoder = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?
+        .into_decoder()
+        .map_err(image_error)?;
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder).map_err(image_error)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+/// The composited frames of an animated GIF or WebP; None for stills and
+/// animations too large to hold in memory
+fn decode_frames(bytes: &[u8], source: Source) -> Result<Option<Vec<Frame>>, String> {
+    let frames = match source {
+        Source::Gif => GifDecoder::new(Cursor::new(bytes))
+            .map_err(image_error)?
+            .into_frames(),
+        Source::WebP => {
+            let decoder = WebPDecoder::new(Cursor::new(bytes)).map_err(image_error)?;
+            if !decoder.has_animation() {
+                return Ok(None);
+            }
+            decoder.into_frames()
+        }
+        Source::Jpeg | Source::Png => return Ok(None),
+    };
+    let mut collected = Vec::new();
+    let mut total = 0;
+    for frame in frames {
+        let frame = frame.map_err(image_error)?;
+        total += frame.buffer().len();
+        if total > MAX_FRAMES_BYTES {
+            return Ok(None);
+        }
+        collected.push(frame);
+    }
+    Ok((collected.len() > 1).then_some(collected))
+}
+
+/// JPEG can't hold transparency; WebP keeps it
+fn is_transparent(image: &DynamicImage) -> bool {
+    image.color().has_alpha() && image.to_rgba8().pixels().any(|p| p[3] < 255)
+}
+
+fn encode_still(image: &DynamicImage, extension: &str, quality: u8) -> Result<Vec<u8>, String> {
+    if extension == "webp" {
+        let rgba = image.to_rgba8();
+        let encoded = webp::Encoder::from_rgba(&rgba, rgba.width(), rgba.height())
+            .encode_simple(false, quality as f32)
+            .map_err(|e| format!("Couldn't encode the image: {:?}", e))?;
+        return Ok(encoded.to_vec());
+    }
+    let mut out = Vec::new();
+    image
+        .to_rgb8()
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut out, quality))
+        .map_err(image_error)?;
+    Ok(out)
+}
+
+fn encode_animation(
+    frames: &[Frame],
+    extension: &str,
+    (width, height): (u32, u32),
+    quality: u8,
+) -> Result<Vec<u8>, String> {
+    let resized: Vec<(RgbaImage, u32)> = frames
+        .iter()
+        .map(|frame| {
+            let (numerator, denominator) = frame.delay().numer_denom_ms();
+            (
+                image::imageops::resize(frame.buffer(), width, height, FilterType::Triangle),
+                numerator / denominator.max(1),
+            )
+        })
+        .collect();
+    if extension == "gif" {
+        let mut out = Vec::new();
+        {
+            let mut encoder = GifEncoder::new_with_speed(&mut out, GIF_SPEED);
+            encoder.set_repeat(Repeat::Infinite).map_err(image_error)?;
+            encoder
+                .encode_frames(resized.into_iter().map(|(buffer, delay)| {
+                    Frame::from_parts(buffer, 0, 0, Delay::from_numer_denom_ms(delay, 1))
+                }))
+                .map_err(image_error)?;
+        }
+        return Ok(out);
+    }
+    let mut config =
+        webp::WebPConfig::new().map_err(|_| "Couldn't set up the WebP encoder".to_string())?;
+    config.quality = quality as f32;
+    let mut encoder = webp::AnimEncoder::new(width, height, &config);
+    encoder.set_loop_count(0);
+    // Frames are placed by their start time
+    let mut timestamp = 0;
+    for (buffer, delay) in &resized {
+        encoder.add_frame(webp::AnimFrame::from_rgba(buffer, width, height, timestamp));
+        timestamp += *delay as i32;
+    }
+    let encoded = encoder
+        .try_encode()
+        .map_err(|e| format!("Couldn't encode the animation: {:?}", e))?;
+    Ok(encoded.to_vec())
+}
+
+fn fits(bytes: &[u8], max_bytes: Option<u64>) -> bool {
+    !matches!(max_bytes, Some(max) if bytes.len() as u64 > max)
+}
+
+fn too_large_message(max_bytes: Option<u64>) -> String {
+    format!(
+        "Couldn't shrink the image below {:.1} MB",
+        max_bytes.unwrap_or(0) as f64 / 1024.0 / 1024.0
+    )
+}
+
+/// Downscale and re-encode an image that is over `max_bytes` or
+/// `max_dimension`. Animations stay animated when they can be made to fit
+/// and become a still of their first frame otherwise. None when nothing
+/// needs doing or the format isn't one it handles.
+pub(crate) fn shrink(bytes: &[u8], options: &ShrinkOptions) -> Result<Option<Shrunk>, String> {
+    let Some(source) = Source::of(bytes) else {
+        return Ok(None);
+    };
+    let max_bytes = options.max_bytes.filter(|max| *max > 0);
+    let max_dimension = options.max_dimension.unwrap_or(0);
+    let too_large = !fits(bytes, max_bytes);
+    let quality = options.quality.unwrap_or(DEFAULT_QUALITY).clamp(1, 100);
+
+    if let Some(frames) = decode_frames(bytes, source)? {
+        let (width, height) = frames[0].buffer().dimensions();
+        let mut size = fit(width, height, max_dimension);
+        if !too_large && size == (width, height) {
+            return Ok(None);
+        }
+        let extension = if source == Source::Gif { "gif" } else { "webp" };
+        for _ in 0..MAX_ATTEMPTS {
+            let encoded = encode_animation(&frames, extension, size, quality)?;
+            if fits(&encoded, max_bytes) {
+                return Ok(Some(Shrunk {
+                    bytes: encoded,
+                    extension,
+                    width: size.0,
+                    height: size.1,
+                    animated: true,
+                }));
+            }
+            if size.0.max(size.1) <= MIN_DIMENSION {
+                break;
+            }
+            size = scale(size.0, size.1, SCALE_STEP);
+        }
+    }
+
+    let image = decode_still(bytes)?;
+    let (width, height) = (image.width(), image.height());
+    let mut size = fit(width, height, max_dimension);
+    if !too_large && size == (width, height) {
+        return Ok(None);
+    }
+    let extension = if is_transparent(&image) || (source == Source::WebP) {
+        "webp"
+    } else {
+        "jpg"
+    };
+    let mut quality = quality;
+    let mut resized = image.resize_exact(size.0, size.1, FilterType::Lanczos3);
+    for _ in 0..MAX_ATTEMPTS {
+        let encoded = encode_still(&resized, extension, quality)?;
+        if fits(&encoded, max_bytes) {
+            return Ok(Some(Shrunk {
+                bytes: encoded,
+                extension,
+                width: size.0,
+                height: size.1,
+                animated: false,
+            }));
+        }
+        if quality > MIN_QUALITY {
+            quality = quality.saturating_sub(QUALITY_STEP).max(MIN_QUALITY);
+        } else if size.0.max(size.1) > MIN_DIMENSION {
+            size = scale(size.0, size.1, SCALE_STEP);
+            resized = image.resize_exact(size.0, size.1, FilterType::Lanczos3);
+        } else {
+            break;
+        }
+    }
+    Err(too_large_message(max_bytes))
+}
+
+/// A still of the first frame at most `max_dimension` on the longest side,
+/// as JPEG or, with transparency, WebP
+pub(crate) fn thumbnail(bytes: &[u8], max_dimension: u32, quality: u8) -> Result<Shrunk, String> {
+    let image = decode_still(bytes)?;
+    let (width, height) = fit(image.width(), image.height(), max_dimension);
+    let extension = if is_transparent(&image) {
+        "webp"
+    } else {
+        "jpg"
+    };
+    let resized = image.resize_exact(width, height, FilterType::Triangle);
+    Ok(Shrunk {
+        bytes: encode_still(&resized, extension, quality)?,
+        extension,
+        width,
+        height,
+        animated: false,
+    })
+}
+
+/// Downscale and re-encode an image on disk when it's over the filehost's
+/// limit or the user's maximum dimension. The result goes to the upload
+/// staging area under a new handle, which replaces `handle`; other files
+/// and images that already fit come back as is.
+#[tauri::command]
+pub async fn image_shrink(
+    handle: String,
+    options: ShrinkOptions,
+    uploads: State<'_, UploadState>,
+) -> Result<ShrinkReport, String> {
+    let source = &uploads.resolve(&handle)?;
+    let mut head = [0u8; 32];
+    let head_len = tokio::fs::File::open(source)
+        .await
+        .map_err(|e| format!("Failed to open {}: {}", source.display(), e))?
+        .read(&mut head)
+        .await
+        .map_err(|e| e.to_string())?;
+    let before_bytes = tokio::fs::metadata(source)
+        .await
+        .map_err(|e| e.to_string())?
+        .len();
+    let mut report = ShrinkReport {
+        path: handle.clone(),
+        changed: false,
+        before_bytes,
+        after_bytes: before_bytes,
+        before_width: 0,
+        before_height: 0,
+        after_width: 0,
+        after_height: 0,
+        animated: false,
+    };
+    if Source::of(&head[..head_len]).is_none() {
+        return Ok(report);
+    }
+
+    let bytes = read_image(source).await?;
+    let (dimensions, shrunk) = tauri::async_runtime::spawn_blocking(move || {
+        let dimensions = ImageReader::new(Cursor::new(&bytes))
+            .with_guessed_format()
+            .ok()
+            .and_then(|reader| reader.into_dimensions().ok())
+            .unwrap_or((0, 0));
+        shrink(&bytes, &options).map(|shrunk| (dimensions, shrunk))
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    (report.before_width, report.before_height) = dimensions;
+    (report.after_width, report.after_height) = dimensions;
+    let Some(shrunk) = shrunk else {
+        return Ok(report);
+    };
+
+    let filename = source
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let staged = uploads.stage_path(&with_extension(&filename, shrunk.extension))?;
+    tokio::fs::write(&staged, &shrunk.bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", staged.display(), e))?;
+    uploads.release(&handle);
+    report.path = uploads.register(staged);
+    report.changed = true;
+    report.after_bytes = shrunk.bytes.len() as u64;
+    report.after_width = shrunk.width;
+    report.after_height = shrunk.height;
+    report.animated = shrunk.animated;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::codecs::png::PngEncoder;
+    use image::{ImageEncoder, Rgba};
+
+    /// Pixels that compress badly, so sizes respond to quality and scale
+    fn noise(width: u32, height: u32, seed: u32, alpha: u8) -> RgbaImage {
+        let mut state = seed.wrapping_mul(2654435761).max(1);
+        RgbaImage::from_fn(width, height, |_, _| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let [r, g, b, _] = state.to_le_bytes();
+            Rgba([r, g, b, alpha])
+        })
+    }
+
+    fn png(image: &RgbaImage) -> Vec<u8> {
+        let mut out = Vec::new();
+        PngEncoder::new(&mut out)
+            .write_image(
+                image,
+                image.width(),
+                image.height(),
+                image::ExtendedColorType::Rgba8,
+            )
+            .unwrap();
+        out
+    }
+
+    fn gif(frames: &[RgbaImage]) -> Vec<u8> {
+        let mut out = Vec::new();
+        {
+            let mut encoder = GifEncoder::new_with_speed(&mut out, GIF_SPEED);
+            encoder.set_repeat(Repeat::Infinite).unwrap();
+            encoder
+                .encode_frames(frames.iter().map(|frame| {
+                    Frame::from_parts(frame.clone(), 0, 0, Delay::from_numer_denom_ms(100, 1))
+                }))
+                .unwrap();
+        }
+        out
+    }
+
+    fn options(max_bytes: Option<u64>, max_dimension: Option<u32>) -> ShrinkOptions {
+        ShrinkOptions {
+            max_bytes,
+            max_dimension,
+            quality: None,
+        }
+    }
+
+    #[test]
+    fn fit_keeps_the_aspect_ratio() {
+        assert_eq!(fit(4000, 3000, 1000), (1000, 750));
+        assert_eq!(fit(3000, 4000, 1000), (750, 1000));
+        assert_eq!(fit(800, 600, 1000), (800, 600));
+        assert_eq!(fit(800, 600, 0), (800, 600));
+        assert_eq!(fit(10, 4000, 100), (1, 100));
+    }
+
+    #[test]
+    fn images_that_fit_are_left_alone() {
+        let bytes = png(&noise(64, 48, 1, 255));
+        assert!(shrink(&bytes, &options(None, None)).unwrap().is_none());
+        assert!(shrink(&bytes, &options(Some(10 * 1024 * 1024), Some(64)))
+            .unwrap()
+            .is_none());
+        assert!(shrink(b"not an image", &options(Some(1), Some(1)))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn transparency_picks_webp() {
+        let opaque = shrink(&png(&noise(400, 200, 2, 255)), &options(None, Some(100)))
+            .unwrap()
+            .unwrap();
+        assert_eq!((opaque.width, opaque.height), (100, 50));
+        assert_eq!(opaque.extension, "jpg");
+        assert_eq!(sniff(&opaque.bytes).unwrap().1, "jpg");
+        assert!(!opaque.animated);
+
+        let transparent = shrink(&png(&noise(400, 200, 3, 128)), &options(None, Some(100)))
+            .unwrap()
+            .unwrap();
+        assert_eq!((transparent.width, transparent.height), (100, 50));
+        assert_eq!(transparent.extension, "webp");
+        assert_eq!(sniff(&transparent.bytes).unwrap().1, "webp");
+        let decoded = decode_still(&transparent.bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (100, 50));
+        assert!(is_transparent(&decoded));
+    }
+
+    #[test]
+    fn byte_limit_lowers_quality_before_scaling() {
+        let source = noise(500, 500, 4, 255);
+        let bytes = png(&source);
+        let image = DynamicImage::ImageRgba8(source);
+        let best = encode_still(&image, "jpg", DEFAULT_QUALITY).unwrap().len() as u64;
+        let worst = encode_still(&image, "jpg", MIN_QUALITY).unwrap().len() as u64;
+        assert!(worst < best && best < bytes.len() as u64);
+
+        // Lower quality is enough, so the size stays
+        let shrunk = shrink(&bytes, &options(Some(best - 1), None))
+            .unwrap()
+            .unwrap();
+        assert_eq!((shrunk.width, shrunk.height), (500, 500));
+        assert_eq!(shrunk.extension, "jpg");
+        assert!((shrunk.bytes.len() as u64) < best);
+
+        // Below what the lowest quality gives, it scales down
+        let shrunk = shrink(&bytes, &options(Some(worst - 1), None))
+            .unwrap()
+            .unwrap();
+        assert_eq!((shrunk.width, shrunk.height), (400, 400));
+        assert!((shrunk.bytes.len() as u64) < worst);
+
+        // It gives up at the smallest size
+        assert_eq!(
+            shrink(&bytes, &options(Some(1024), None)).err(),
+            Some(too_large_message(Some(1024)))
+        );
+    }
+
+    #[test]
+    fn animations_stay_animated() {
+        let frames = [noise(200, 100, 5, 255), noise(200, 100, 6, 255)];
+        let shrunk = shrink(&gif(&frames), &options(None, Some(100)))
+            .unwrap()
+            .unwrap();
+        assert!(shrunk.animated);
+        assert_eq!(shrunk.extension, "gif");
+        assert_eq!((shrunk.width, shrunk.height), (100, 50));
+        let decoded = decode_frames(&shrunk.bytes, Source::Gif).unwrap().unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].buffer().dimensions(), (100, 50));
+    }
+
+    #[test]
+    fn animations_over_the_byte_limit_scale_down() {
+        let frames = [noise(400, 400, 7, 255), noise(400, 400, 8, 255)];
+        let bytes = gif(&frames);
+        let decoded = decode_frames(&bytes, Source::Gif).unwrap().unwrap();
+        let full = encode_animation(&decoded, "gif", (400, 400), DEFAULT_QUALITY)
+            .unwrap()
+            .len() as u64;
+
+        let shrunk = shrink(&bytes, &options(Some(full - 1), None))
+            .unwrap()
+            .unwrap();
+        assert!(shrunk.animated);
+        assert_eq!(shrunk.extension, "gif");
+        assert_eq!((shrunk.width, shrunk.height), (320, 320));
+        assert!((shrunk.bytes.len() as u64) < full);
+        assert_eq!(
+            decode_frames(&shrunk.bytes, Source::Gif)
+                .unwrap()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn animated_webp_stays_webp() {
+        let frames = [noise(200, 100, 9, 255), noise(200, 100, 10, 255)];
+        let decoded: Vec<Frame> = frames
+            .iter()
+            .map(|frame| Frame::from_parts(frame.clone(), 0, 0, Delay::from_numer_denom_ms(100, 1)))
+            .collect();
+        let bytes = encode_animation(&decoded, "webp", (200, 100), DEFAULT_QUALITY).unwrap();
+
+        let shrunk = shrink(&bytes, &options(None, Some(100))).unwrap().unwrap();
+        assert!(shrunk.animated);
+        assert_eq!(shrunk.extension, "webp");
+        assert_eq!((shrunk.width, shrunk.height), (100, 50));
+        let decoder = WebPDecoder::new(Cursor::new(&shrunk.bytes)).unwrap();
+        assert!(decoder.has_animation());
+    }
+}
@@ -0,0 +1,848 @@
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use tauri::{Emitter, State};
+
+use crate::history::{insert_messages, HistoryState, HistoryStore, NewMessage};
+use crate::util::hex;
+
+/// Lines inserted per transaction
+const BATCH_SIZE: usize = 1000;
+/// Unparseable lines included in the summary as examples
+const MAX_SAMPLES: usize = 20;
+
+/// Log formats the importer understands
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Weechat,
+    Irssi,
+    Znc,
+    Hexchat,
+}
+
+/// One message recovered from a log line
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedLine {
+    time: NaiveDateTime,
+    command: &'static str,
+    sender: Option<String>,
+    text: Option<String>,
+}
+
+enum Line {
+    Message(ParsedLine),
+    /// Log metadata such as "Log opened" or date changes
+    Skip,
+    Unparseable,
+}
+
+fn message(time: NaiveDateTime, command: &'static str, sender: &str, text: Option<&str>) -> Line {
+    Line::Message(ParsedLine {
+        time,
+        command,
+        sender: Some(strip_mode(sender).to_string()),
+        text: text.filter(|t| !t.is_empty()).map(str::to_string),
+    })
+}
+
+fn action(time: NaiveDateTime, nick_and_text: &str) -> Line {
+    let (nick, text) = nick_and_text.split_once(' ').unwrap_or((nick_and_text, ""));
+    message(
+        time,
+        "PRIVMSG",
+        nick,
+        Some(&format!("\x01ACTION {}\x01", text)),
+    )
+}
+
+/// Drop channel status prefixes and padding clients add to nicks
+fn strip_mode(nick: &str) -> &str {
+    nick.trim()
+        .trim_start_matches(['~', '&', '@', '%', '+', '!'])
+}
+
+/// Text inside the last pair of the given brackets at the end of a line
+fn trailing_group(text: &str, open: char, close: char) -> Option<&str> {
+    let inner = text.strip_suffix(close)?;
+    let start = inner.rfind(open)?;
+    Some(&inner[start + open.len_utf8()..])
+}
+
+/// Shared handling of "<nick> has joined/left/quit/kicked" style events
+fn membership_event(time: NaiveDateTime, text: &str, open: char, close: char) -> Line {
+    let (nick, rest) = text.split_once(' ').unwrap_or((text, ""));
+    let reason = trailing_group(rest, open, close);
+    if rest.contains(" has joined ") || rest.starts_with("has joined ") {
+        message(time, "JOIN", nick, None)
+    } else if rest.contains("has left ") {
+        message(time, "PART", nick, reason)
+    } else if rest.contains("has quit") {
+        message(time, "QUIT", nick, reason)
+    } else if let Some(after) = rest.strip_prefix("has kicked ") {
+        let victim = after.split(' ').next().unwrap_or("");
+        message(
+            time,
+            "KICK",
+            nick,
+            Some(&format!("{} {}", victim, reason.unwrap_or(""))),
+        )
+    } else {
+        Line::Skip
+    }
+}
+
+/// weechat: `YYYY-MM-DD HH:MM:SS<TAB>prefix<TAB>message`
+fn parse_weechat(line: &str) -> Line {
+    let mut parts = line.splitn(3, '\t');
+    let (Some(stamp), Some(prefix), Some(text)) = (parts.next(), parts.next(), parts.next()) else {
+        return Line::Unparseable;
+    };
+    let Ok(time) = NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d %H:%M:%S") else {
+        return Line::Unparseable;
+    };
+
+    match prefix.trim() {
+        "-->" | "<--" => membership_event(time, text, '(', ')'),
+        "--" => {
+            if let Some((old, new)) = text.split_once(" is now known as ") {
+                message(time, "NICK", old, Some(new))
+            } else if let Some((nick, rest)) = text.split_once(" has changed topic for ") {
+                let topic = rest
+                    .rsplit_once(" to \"")
+                    .map(|(_, t)| t.trim_end_matches('"'));
+                message(time, "TOPIC", nick, topic)
+            } else if let Some(rest) = text.strip_prefix("Mode ") {
+                // Mode #chan [+o nick] by op
+                match (rest.split_once(" ["), rest.rsplit_once("] by ")) {
+                    (Some(_), Some((modes, op))) => {
+                        let modes = modes.split_once('[').map_or(modes, |(_, m)| m);
+                        message(time, "MODE", op, Some(modes))
+                    }
+                    _ => Line::Skip,
+                }
+            } else if let Some(rest) = text.strip_prefix("Notice(") {
+                match rest.split_once("): ") {
+                    Some((nick, notice)) => message(time, "NOTICE", nick, Some(notice)),
+                    None => Line::Skip,
+                }
+            } else {
+                Line::Skip
+            }
+        }
+        "*" => action(time, text),
+        "" | "=!=" | "-" => Line::Skip,
+        nick => message(time, "PRIVMSG", nick, Some(text)),
+    }
+}
+
+/// irssi default theme: `HH:MM[:SS] <nick> message`, dated by "Log opened" and "Day changed"
+#[derive(Default)]
+struct IrssiParser {
+    date: Option<NaiveDate>,
+}
+
+impl IrssiParser {
+    fn parse(&mut self, line: &str) -> Line {
+        if let Some(rest) = line.strip_prefix("--- Log opened ") {
+            let rest = rest.split_whitespace().collect::<Vec<_>>().join(" ");
+            self.date = NaiveDateTime::parse_from_str(&rest, "%a %b %d %H:%M:%S %Y")
+                .ok()
+                .map(|t| t.date());
+            return Line::Skip;
+        }
+        if let Some(rest) = line.strip_prefix("--- Day changed ") {
+            let rest = rest.split_whitespace().collect::<Vec<_>>().join(" ");
+            self.date = NaiveDate::parse_from_str(&rest, "%a %b %d %Y")
+                .ok()
+                .or(self.date);
+            return Line::Skip;
+        }
+        if line.starts_with("--- ") {
+            return Line::Skip;
+        }
+
+        let Some((stamp, rest)) = line.split_once(' ') else {
+            return Line::Unparseable;
+        };
+        let (Some(date), Some(clock)) = (self.date, parse_clock(stamp)) else {
+            return Line::Unparseable;
+        };
+        let time = date.and_time(clock);
+
+        if let Some(event) = rest.strip_prefix("-!- ") {
+            return irssi_event(time, event);
+        }
+        if let Some(rest) = rest.trim_start().strip_prefix("* ") {
+            return action(time, rest);
+        }
+        if let Some(rest) = rest.strip_prefix('<') {
+            return match rest.split_once("> ") {
+                Some((nick, text)) => message(time, "PRIVMSG", nick, Some(text)),
+                None => Line::Unparseable,
+            };
+        }
+        if let Some(rest) = rest.strip_prefix('-') {
+            // -nick(user@host)- or -nick:#chan- notices
+            return match rest.split_once("- ") {
+                Some((nick, text)) => {
+                    let nick = nick.split(['(', ':']).next().unwrap_or(nick);
+                    message(time, "NOTICE", nick, Some(text))
+                }
+                None => Line::Unparseable,
+            };
+        }
+        Line::Unparseable
+    }
+}
+
+fn irssi_event(time: NaiveDateTime, event: &str) -> Line {
+    if let Some((old, new)) = event.split_once(" is now known as ") {
+        return message(time, "NICK", old, Some(new));
+    }
+    if let Some((victim, rest)) = event.split_once(" was kicked from ") {
+        let op = rest
+            .split_once(" by ")
+            .map(|(_, op)| op.split(' ').next().unwrap_or(op));
+        let reason = trailing_group(rest, '[', ']').unwrap_or("");
+        return match op {
+            Some(op) => message(time, "KICK", op, Some(&format!("{} {}", victim, reason))),
+            None => Line::Skip,
+        };
+    }
+    if let Some((nick, rest)) = event.split_once(" changed the topic of ") {
+        let topic = rest.split_once(" to: ").map(|(_, t)| t);
+        return message(time, "TOPIC", nick, topic);
+    }
+    if let Some(rest) = event.strip_prefix("mode/") {
+        // mode/#chan [+o nick] by op
+        return match (rest.split_once(" ["), rest.rsplit_once("] by ")) {
+            (Some((_, modes)), Some((_, op))) => {
+                let modes = modes.rsplit_once("] by ").map_or(modes, |(m, _)| m);
+                message(time, "MODE", op, Some(modes))
+            }
+            _ => Line::Skip,
+        };
+    }
+    // nick [user@host] has joined #chan
+    let text = match event.split_once(" [") {
+        Some((nick, rest)) => match rest.split_once("] ") {
+            Some((_, rest)) => format!("{} {}", nick, rest),
+            None => event.to_string(),
+        },
+        None => event.to_string(),
+    };
+    membership_event(time, &text, '[', ']')
+}
+
+/// ZNC log module: `[HH:MM:SS] <nick> message`, dated by the file name
+struct ZncParser {
+    date: Option<NaiveDate>,
+}
+
+impl ZncParser {
+    fn for_file(path: &Path) -> Self {
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        // New layout names files YYYY-MM-DD.log, the old one network_#chan_YYYYMMDD.log
+        let date = NaiveDate::parse_from_str(stem, "%Y-%m-%d")
+            .ok()
+            .or_else(|| {
+                let (_, suffix) = stem.rsplit_once('_')?;
+                NaiveDate::parse_from_str(suffix, "%Y%m%d").ok()
+            });
+        Self { date }
+    }
+
+    fn parse(&self, line: &str) -> Line {
+        let Some(rest) = line.strip_prefix('[') else {
+            return Line::Unparseable;
+        };
+        let Some((stamp, rest)) = rest.split_once("] ") else {
+            return Line::Unparseable;
+        };
+        let (Some(date), Some(clock)) = (self.date, parse_clock(stamp)) else {
+            return Line::Unparseable;
+        };
+        let time = date.and_time(clock);
+
+        if let Some(event) = rest.strip_prefix("*** ") {
+            return znc_event(time, event);
+        }
+        if let Some(rest) = rest.strip_prefix("* ") {
+            return action(time, rest);
+        }
+        if let Some(rest) = rest.strip_prefix('<') {
+            return match rest.split_once("> ") {
+                Some((nick, text)) => message(time, "PRIVMSG", nick, Some(text)),
+                None => Line::Unparseable,
+            };
+        }
+        if let Some(rest) = rest.strip_prefix('-') {
+            return match rest.split_once("- ") {
+                Some((nick, text)) => message(time, "NOTICE", nick, Some(text)),
+                None => Line::Unparseable,
+            };
+        }
+        Line::Unparseable
+    }
+}
+
+fn znc_event(time: NaiveDateTime, event: &str) -> Line {
+    let nick_of = |rest: &str| rest.split(' ').next().unwrap_or("").to_string();
+    if let Some(rest) = event.strip_prefix("Joins: ") {
+        return message(time, "JOIN", &nick_of(rest), None);
+    }
+    if let Some(rest) = event.strip_prefix("Parts: ") {
+        return message(time, "PART", &nick_of(rest), trailing_group(rest, '(', ')'));
+    }
+    if let Some(rest) = event.strip_prefix("Quits: ") {
+        return message(time, "QUIT", &nick_of(rest), trailing_group(rest, '(', ')'));
+    }
+    if let Some((old, new)) = event.split_once(" is now known as ") {
+        return message(time, "NICK", old, Some(new));
+    }
+    if let Some((victim, rest)) = event.split_once(" was kicked by ") {
+        let reason = trailing_group(rest, '(', ')').unwrap_or("");
+        return message(
+            time,
+            "KICK",
+            &nick_of(rest),
+            Some(&format!("{} {}", victim, reason)),
+        );
+    }
+    if let Some((nick, topic)) = event.split_once(" changes topic to ") {
+        let topic = topic.trim_start_matches('\'').trim_end_matches('\'');
+        return message(time, "TOPIC", nick, Some(topic));
+    }
+    if let Some((op, modes)) = event.split_once(" sets mode: ") {
+        return message(time, "MODE", op, Some(modes));
+    }
+    Line::Skip
+}
+
+/// HexChat: `Mon DD HH:MM:SS prefix<TAB>message`, with the year from "BEGIN LOGGING"
+#[derive(Default)]
+struct HexchatParser {
+    year: Option<i32>,
+}
+
+impl HexchatParser {
+    fn parse(&mut self, line: &str) -> Line {
+        if let Some(rest) = line.strip_prefix("**** ") {
+            if let Some(stamp) = rest.strip_prefix("BEGIN LOGGING AT ") {
+                let stamp = stamp.split_whitespace().collect::<Vec<_>>().join(" ");
+                if let Ok(time) = NaiveDateTime::parse_from_str(&stamp, "%a %b %d %H:%M:%S %Y") {
+                    self.year = Some(chrono::Datelike::year(&time));
+                }
+            }
+            return Line::Skip;
+        }
+
+        let Some((head, text)) = line.split_once('\t') else {
+            return Line::Unparseable;
+        };
+        // The first three fields are the timestamp (days may be space padded), then the prefix
+        let fields: Vec<&str> = head.split_whitespace().collect();
+        let Some(year) = self.year else {
+            return Line::Unparseable;
+        };
+        let (stamp, prefix) = match fields.as_slice() {
+            [month, day, clock, prefix, ..] => {
+                (format!("{} {} {} {}", year, month, day, clock), *prefix)
+            }
+            _ => return Line::Unparseable,
+        };
+        let Ok(time) = NaiveDateTime::parse_from_str(&stamp, "%Y %b %d %H:%M:%S") else {
+            return Line::Unparseable;
+        };
+
+        match prefix {
+            "-->" | "<--" => {
+                // "op has kicked victim from #chan (reason)" reads the same as weechat's
+                membership_event(time, text, '(', ')')
+            }
+            "*" => {
+                if let Some((old, new)) = text.split_once(" is now known as ") {
+                    message(time, "NICK", old, Some(new))
+                } else if let Some((nick, topic)) = text.split_once(" has changed the topic to: ") {
+                    message(time, "TOPIC", nick, Some(topic))
+                } else if text.contains(" gives ")
+                    || text.contains(" removes ")
+                    || text.contains(" sets ")
+                {
+                    Line::Skip
+                } else {
+                    action(time, text)
+                }
+            }
+            "-" | "---" | "=!=" => Line::Skip,
+            prefix if prefix.starts_with('<') && prefix.ends_with('>') => {
+                message(time, "PRIVMSG", &prefix[1..prefix.len() - 1], Some(text))
+            }
+            prefix if prefix.starts_with('-') && prefix.ends_with('-') && prefix.len() > 2 => {
+                message(time, "NOTICE", &prefix[1..prefix.len() - 1], Some(text))
+            }
+            _ => Line::Unparseable,
+        }
+    }
+}
+
+fn parse_clock(stamp: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(stamp, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(stamp, "%H:%M"))
+        .ok()
+}
+
+/// Guess a file's format from its name and first lines
+fn detect_format(path: &Path) -> Option<ImportFormat> {
+    if path.extension().is_some_and(|e| e == "weechatlog") {
+        return Some(ImportFormat::Weechat);
+    }
+    let file = File::open(path).ok()?;
+    for line in BufReader::new(file).lines().take(20).map_while(Result::ok) {
+        if line.starts_with("**** BEGIN LOGGING AT") {
+            return Some(ImportFormat::Hexchat);
+        }
+        if line.starts_with("--- Log opened") {
+            return Some(ImportFormat::Irssi);
+        }
+        if line.starts_with('[') && line.get(9..11) == Some("] ") {
+            return Some(ImportFormat::Znc);
+        }
+        if line.get(4..5) == Some("-") && line.get(10..11) == Some(" ") && line.contains('\t') {
+            return Some(ImportFormat::Weechat);
+        }
+    }
+    None
+}
+
+/// Network and target a file belongs to, from the naming scheme of its format
+fn conversation_of(path: &Path, format: ImportFormat) -> Option<(String, String)> {
+    let name = |p: Option<&Path>| {
+        p.and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .map(str::to_string)
+    };
+    let stem = path.file_stem()?.to_str()?;
+    match format {
+        // irc.<network>.<target>.weechatlog; server and core buffers have no target
+        ImportFormat::Weechat => {
+            let rest = stem.strip_prefix("irc.")?;
+            if rest.starts_with("server.") {
+                return None;
+            }
+            let (network, target) = rest.split_once('.')?;
+            Some((network.to_string(), target.to_string()))
+        }
+        // <network>/<target>.log
+        ImportFormat::Irssi | ImportFormat::Hexchat => {
+            let network = name(path.parent())?;
+            if stem.eq_ignore_ascii_case("server") || stem.eq_ignore_ascii_case(&network) {
+                return None;
+            }
+            Some((network, stem.to_string()))
+        }
+        // <network>/<target>/YYYY-MM-DD.log or <network>_<target>_YYYYMMDD.log
+        ImportFormat::Znc => {
+            if NaiveDate::parse_from_str(stem, "%Y-%m-%d").is_ok() {
+                let target = name(path.parent())?;
+                let network = name(path.parent().and_then(Path::parent))?;
+                Some((network, target))
+            } else {
+                let (rest, _) = stem.rsplit_once('_')?;
+                let (network, target) = rest.split_once('_')?;
+                Some((network.to_string(), target.to_string()))
+            }
+        }
+    }
+}
+
+/// Every regular file under the given paths
+fn collect_files(paths: &[String]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut pending: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+    while let Some(path) = pending.pop() {
+        if path.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.filter_map(Result::ok).map(|e| e.path()));
+            }
+        } else if path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+/// Identity of an imported line. The file name and line number keep lines
+/// that repeat within the log's timestamp resolution apart, while staying the
+/// same when the logs are moved and imported again.
+fn import_key(message: &NewMessage, file_name: &str, line_number: usize) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        file_name,
+        &line_number.to_string(),
+        message.network.as_str(),
+        message.target.as_deref().unwrap_or(""),
+        &message.time.to_string(),
+        message.sender.as_deref().unwrap_or(""),
+        message.command.as_str(),
+        message.text.as_deref().unwrap_or(""),
+    ] {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    hex(&hasher.finalize())
+}
+
+/// Message count for one conversation found in the imported logs
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationCount {
+    network: String,
+    target: String,
+    messages: usize,
+}
+
+/// What an import found, and on a real run what it stored
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportSummary {
+    dry_run: bool,
+    files: usize,
+    /// Files that weren't recognised as a log of a channel or query
+    skipped_files: Vec<String>,
+    messages: usize,
+    /// Messages stored; the rest were already in the history
+    imported: usize,
+    unparseable: usize,
+    unparseable_samples: Vec<String>,
+    conversations: Vec<ConversationCount>,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ImportProgress {
+    file: String,
+    files_done: usize,
+    files_total: usize,
+    messages: usize,
+}
+
+/// Parse every file and, unless this is a dry run, store the messages
+fn run_import(
+    store: &HistoryStore,
+    paths: &[String],
+    format: Option<ImportFormat>,
+    networks: &HashMap<String, String>,
+    dry_run: bool,
+    on_progress: impl Fn(ImportProgress),
+) -> Result<ImportSummary, String> {
+    let files = collect_files(paths);
+    let mut summary = ImportSummary {
+        dry_run,
+        files: files.len(),
+        ..Default::default()
+    };
+    let mut counts: BTreeMap<(String, String), usize> = BTreeMap::new();
+    let mut batch = Vec::with_capacity(BATCH_SIZE);
+
+    let flush = |batch: &mut Vec<NewMessage>, summary: &mut ImportSummary| -> Result<(), String> {
+        if !dry_run && !batch.is_empty() {
+            let mut conn = store.lock_conn()?;
+            summary.imported +=
+                insert_messages(&mut conn, batch).map_err(|e| format!("History error: {}", e))?;
+        }
+        batch.clear();
+        Ok(())
+    };
+
+    for (index, path) in files.iter().enumerate() {
+        let display = path.to_string_lossy().into_owned();
+        let Some(file_format) = format.or_else(|| detect_format(path)) else {
+            summary.skipped_files.push(display);
+            continue;
+        };
+        let Some((network, target)) = conversation_of(path, file_format) else {
+            summary.skipped_files.push(display);
+            continue;
+        };
+        let network = networks.get(&network).cloned().unwrap_or(network);
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file = File::open(path).map_err(|e| format!("Failed to open {}: {}", display, e))?;
+
+        let mut irssi = IrssiParser::default();
+        let mut hexchat = HexchatParser::default();
+        let znc = ZncParser::for_file(path);
+        let lines = BufReader::new(file).split(b'\n').map_while(Result::ok);
+        for (line_number, line) in lines.enumerate() {
+            // Old logs are often not UTF-8; keep what can be decoded
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\r', '\n']);
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parsed = match file_format {
+                ImportFormat::Weechat => parse_weechat(line),
+                ImportFormat::Irssi => irssi.parse(line),
+                ImportFormat::Znc => znc.parse(line),
+                ImportFormat::Hexchat => hexchat.parse(line),
+            };
+            let parsed = match parsed {
+                Line::Message(parsed) => parsed,
+                Line::Skip => continue,
+                Line::Unparseable => {
+                    summary.unparseable += 1;
+                    if summary.unparseable_samples.len() < MAX_SAMPLES {
+                        summary
+                            .unparseable_samples
+                            .push(format!("{}: {}", display, line));
+                    }
+                    continue;
+                }
+            };
+            // Logs are written in local time; skip times that don't exist locally (DST gaps)
+            let Some(time) = Local.from_local_datetime(&parsed.time).earliest() else {
+                summary.unparseable += 1;
+                continue;
+            };
+
+            summary.messages += 1;
+            *counts.entry((network.clone(), target.clone())).or_default() += 1;
+            let mut entry = NewMessage {
+                network: network.clone(),
+                target: Some(target.clone()),
+                sender: parsed.sender,
+                command: parsed.command.to_string(),
+                text: parsed.text,
+                msgid: None,
+                time: time.timestamp_millis(),
+                tags: "{}".to_string(),
+                outgoing: false,
+                import_key: None,
+            };
+            entry.import_key = Some(import_key(&entry, &file_name, line_number));
+            batch.push(entry);
+            if batch.len() >= BATCH_SIZE {
+                flush(&mut batch, &mut summary)?;
+            }
+        }
+        flush(&mut batch, &mut summary)?;
+
+        on_progress(ImportProgress {
+            file: display,
+            files_done: index + 1,
+            files_total: files.len(),
+            messages: summary.messages,
+        });
+    }
+
+    summary.conversations = counts
+        .into_iter()
+        .map(|((network, target), messages)| ConversationCount {
+            network,
+            target,
+            messages,
+        })
+        .collect();
+    Ok(summary)
+}
+
+/// Import weechat, irssi, ZNC or HexChat logs into the message history.
+///
+/// `paths` may be files or directories. `networks` maps network names found
+/// in the logs to the network keys used by live connections. Run with
+/// `dry_run` first to get the summary without storing anything; re-running
+/// a real import skips lines that were already imported.
+#[tauri::command]
+pub async fn history_import(
+    paths: Vec<String>,
+    format: Option<ImportFormat>,
+    networks: Option<HashMap<String, String>>,
+    dry_run: bool,
+    history: State<'_, HistoryState>,
+    app_handle: tauri::AppHandle,
+) -> Result<ImportSummary, String> {
+    // A dry run would otherwise succeed without touching the database
+    if history.0.is_locked() {
+        return Err("The app is locked".to_string());
+    }
+    let store = history.0.clone();
+    let networks = networks.unwrap_or_default();
+    tauri::async_runtime::spawn_blocking(move || {
+        run_import(&store, &paths, format, &networks, dry_run, |progress| {
+            let _ = app_handle.emit("history-import-progress", progress);
+        })
+    })
+    .await
+    .map_err(|e| format!("Import task failed: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(date: (i32, u32, u32), clock: (u32, u32, u32)) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(date.0, date.1, date.2)
+            .unwrap()
+            .and_hms_opt(clock.0, clock.1, clock.2)
+            .unwrap()
+    }
+
+    fn parsed(line: Line) -> (NaiveDateTime, &'static str, String, Option<String>) {
+        match line {
+            Line::Message(m) => (m.time, m.command, m.sender.unwrap(), m.text),
+            Line::Skip => panic!("line was skipped"),
+            Line::Unparseable => panic!("line was unparseable"),
+        }
+    }
+
+    fn text(t: &str) -> Option<String> {
+        Some(t.to_string())
+    }
+
+    #[test]
+    fn weechat() {
+        let time = at((2024, 3, 9), (14, 5, 7));
+        assert_eq!(
+            parsed(parse_weechat("2024-03-09 14:05:07\t@alice\thello there")),
+            (time, "PRIVMSG", "alice".to_string(), text("hello there"))
+        );
+        assert_eq!(
+            parsed(parse_weechat("2024-03-09 14:05:07\t *\tbob waves")),
+            (
+                time,
+                "PRIVMSG",
+                "bob".to_string(),
+                text("\x01ACTION waves\x01")
+            )
+        );
+        assert_eq!(
+            parsed(parse_weechat(
+                "2024-03-09 14:05:07\t<--\tbob (~b@host) has quit (Ping timeout)"
+            )),
+            (time, "QUIT", "bob".to_string(), text("Ping timeout"))
+        );
+        assert_eq!(
+            parsed(parse_weechat(
+                "2024-03-09 14:05:07\t--\tMode #chan [+o bob] by alice"
+            )),
+            (time, "MODE", "alice".to_string(), text("+o bob"))
+        );
+        assert!(matches!(parse_weechat("garbage"), Line::Unparseable));
+    }
+
+    #[test]
+    fn irssi() {
+        let mut parser = IrssiParser::default();
+        assert!(matches!(
+            parser.parse("12:00 <alice> too early"),
+            Line::Unparseable
+        ));
+        assert!(matches!(
+            parser.parse("--- Log opened Sat Mar 09 14:00:00 2024"),
+            Line::Skip
+        ));
+        assert_eq!(
+            parsed(parser.parse("14:05 <@alice> hi")),
+            (
+                at((2024, 3, 9), (14, 5, 0)),
+                "PRIVMSG",
+                "alice".to_string(),
+                text("hi")
+            )
+        );
+        assert_eq!(
+            parsed(parser.parse("14:06 -!- bob [~b@host] has joined #chan")),
+            (
+                at((2024, 3, 9), (14, 6, 0)),
+                "JOIN",
+                "bob".to_string(),
+                None
+            )
+        );
+        assert!(matches!(
+            parser.parse("--- Day changed Sun Mar 10 2024"),
+            Line::Skip
+        ));
+        assert_eq!(
+            parsed(parser.parse("00:01 -!- bob was kicked from #chan by alice [bye]")),
+            (
+                at((2024, 3, 10), (0, 1, 0)),
+                "KICK",
+                "alice".to_string(),
+                text("bob bye")
+            )
+        );
+    }
+
+    #[test]
+    fn znc() {
+        let parser = ZncParser::for_file(Path::new("libera/#chan/2024-03-09.log"));
+        let time = at((2024, 3, 9), (14, 5, 7));
+        assert_eq!(
+            parsed(parser.parse("[14:05:07] <alice> hi")),
+            (time, "PRIVMSG", "alice".to_string(), text("hi"))
+        );
+        assert_eq!(
+            parsed(parser.parse("[14:05:07] *** Parts: bob (~b@host) (gone)")),
+            (time, "PART", "bob".to_string(), text("gone"))
+        );
+        assert_eq!(
+            parsed(parser.parse("[14:05:07] *** alice sets mode: +o bob")),
+            (time, "MODE", "alice".to_string(), text("+o bob"))
+        );
+
+        let old_layout = ZncParser::for_file(Path::new("libera_#chan_20240309.log"));
+        assert_eq!(parsed(old_layout.parse("[14:05:07] <alice> hi")).0, time);
+    }
+
+    #[test]
+    fn hexchat() {
+        let mut parser = HexchatParser::default();
+        assert!(matches!(
+            parser.parse("**** BEGIN LOGGING AT Sat Mar  9 14:00:00 2024"),
+            Line::Skip
+        ));
+        let time = at((2024, 3, 9), (14, 5, 7));
+        assert_eq!(
+            parsed(parser.parse("Mar  9 14:05:07 <alice>\thi")),
+            (time, "PRIVMSG", "alice".to_string(), text("hi"))
+        );
+        assert_eq!(
+            parsed(parser.parse("Mar 09 14:05:07 -->\tbob (~b@host) has joined #chan")),
+            (time, "JOIN", "bob".to_string(), None)
+        );
+        assert_eq!(
+            parsed(parser.parse("Mar 09 14:05:07 *\tbob is now known as bobby")),
+            (time, "NICK", "bob".to_string(), text("bobby"))
+        );
+    }
+
+    #[test]
+    fn repeated_lines_get_their_own_key() {
+        let message = NewMessage {
+            network: "libera".to_string(),
+            target: Some("#chan".to_string()),
+            sender: Some("alice".to_string()),
+            command: "PRIVMSG".to_string(),
+            text: text("lol"),
+            msgid: None,
+            time: 0,
+            tags: "{}".to_string(),
+            outgoing: false,
+            import_key: None,
+        };
+        let key = import_key(&message, "#chan.log", 3);
+        assert_eq!(key, import_key(&message, "#chan.log", 3));
+        assert_ne!(key, import_key(&message, "#chan.log", 4));
+        assert_ne!(key, import_key(&message, "#other.log", 3));
+    }
+}
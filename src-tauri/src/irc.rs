@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+/// A parsed IRC protocol line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Message {
+    pub tags: BTreeMap<String, String>,
+    pub source: Option<String>,
+    pub command: String,
+    pub params: Vec<String>,
+}
+
+impl Message {
+    /// Parse one line, with or without its trailing \r\n
+    pub fn parse(line: &str) -> Option<Message> {
+        let mut rest = line.trim_end_matches(['\r', '\n']);
+        let mut message = Message::default();
+
+        if let Some(stripped) = rest.strip_prefix('@') {
+            let (tags, remainder) = stripped.split_once(' ')?;
+            for tag in tags.split(';').filter(|t| !t.is_empty()) {
+                let (key, value) = tag.split_once('=').unwrap_or((tag, ""));
+                message.tags.insert(key.to_string(), unescape_tag(value));
+            }
+            rest = remainder.trim_start_matches(' ');
+        }
+
+        if let Some(stripped) = rest.strip_prefix(':') {
+            let (source, remainder) = stripped.split_once(' ')?;
+            message.source = Some(source.to_string());
+            rest = remainder.trim_start_matches(' ');
+        }
+
+        let (command, mut params) = rest.split_once(' ').unwrap_or((rest, ""));
+        if command.is_empty() {
+            return None;
+        }
+        message.command = command.to_ascii_uppercase();
+
+        loop {
+            params = params.trim_start_matches(' ');
+            if params.is_empty() {
+                break;
+            }
+            if let Some(trailing) = params.strip_prefix(':') {
+                message.params.push(trailing.to_string());
+                break;
+            }
+            match params.split_once(' ') {
+                Some((param, remainder)) => {
+                    message.params.push(param.to_string());
+                    params = remainder;
+                }
+                None => {
+                    message.params.push(params.to_string());
+                    break;
+                }
+            }
+        }
+
+        Some(message)
+    }
+
+    /// Nickname part of the source, if any
+    pub fn source_nick(&self) -> Option<&str> {
+        self.source
+            .as_deref()
+            .map(|source| source.split(['!', '@']).next().unwrap_or(source))
+    }
+
+    pub fn param(&self, index: usize) -> Option<&str> {
+        self.params.get(index).map(String::as_str)
+    }
+}
+
+/// Undo the IRCv3 message-tags value escaping
+fn unescape_tag(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(':') => out.push(';'),
+            Some('s') => out.push(' '),
+            Some('r') => out.push('\r'),
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+/// Whether a target names a channel rather than a user
+pub fn is_channel(target: &str) -> bool {
+    target.starts_with(['#', '&', '!', '+'])
+}
+
+type Chars<'a> = std::iter::Peekable<std::str::Chars<'a>>;
+
+/// Skip a colour code's `fg[,bg]` arguments of up to `width` characters each
+fn skip_colour(chars: &mut Chars, width: usize, is_digit: fn(&char) -> bool) {
+    let skip = |chars: &mut Chars| {
+        for _ in 0..width {
+            if chars.next_if(is_digit).is_none() {
+                break;
+            }
+        }
+    };
+    if !chars.peek().is_some_and(is_digit) {
+        return;
+    }
+    skip(chars);
+    let mut lookahead = chars.clone();
+    if lookahead.next() == Some(',') && lookahead.peek().is_some_and(is_digit) {
+        chars.next();
+        skip(chars);
+    }
+}
+
+/// Remove mIRC bold, colour, italic, underline, strikethrough, monospace and reset codes
+pub fn strip_formatting(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x02' | '\x0f' | '\x11' | '\x16' | '\x1d' | '\x1e' | '\x1f' => {}
+            '\x03' => skip_colour(&mut chars, 2, char::is_ascii_digit),
+            // Hex colours take six digits per colour
+            '\x04' => skip_colour(&mut chars, 6, char::is_ascii_hexdigit),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// The text of a CTCP ACTION (`/me`), if the message is one
+pub fn ctcp_action(text: &str) -> Option<&str> {
+    let inner = text.strip_prefix('\x01')?;
+    let inner = inner.strip_suffix('\x01').unwrap_or(inner);
+    inner
+        .strip_prefix("ACTION ")
+        .or_else(|| (inner == "ACTION").then_some(""))
+}
+
+/// Whether the text is a CTCP request or reply other than ACTION
+pub fn is_ctcp(text: &str) -> bool {
+    text.starts_with('\x01') && ctcp_action(text).is_none()
+}
@@ -1,30 +1,98 @@
 use std::{collections::HashMap, sync::Arc};
+use tauri::Manager;
 use tokio::sync::Mutex;
 
+mod channel_stats;
+mod chatlog;
+pub mod cli;
+mod console;
+mod deeplink;
+mod download;
+mod download_queue;
+mod export;
+mod fetch_policy;
+mod history;
+mod image_meta;
+mod image_resize;
+mod import;
+mod irc;
+mod irc_url;
+mod link_preview;
+mod oauth;
+mod oauth_tokens;
+mod profile;
+mod rawlog;
+mod secrets;
 mod socket;
+mod stats;
+mod tap;
+mod upload;
+mod util;
+mod vault;
 
+use channel_stats::channel_stats_report;
+use chatlog::{
+    chat_log_get_config, chat_log_set_config, chat_log_set_target_enabled, set_network_incognito,
+    ChatLogState, ChatLogger,
+};
+use cli::{startup_config, StartupOptions, StartupState};
+use console::{open_raw_console, set_raw_console_filter, set_raw_console_paused, ConsoleState};
+use deeplink::{deep_link_ready, DeepLinkState};
+use download::{download_image_cancel, DownloadState};
+use download_queue::{
+    download_cancel, download_choose_folder, download_enqueue, download_get_config, download_list,
+    download_pause, download_resume, download_reveal, download_set_config, DownloadManager,
+    DownloadManagerState,
+};
+use export::history_export;
+use fetch_policy::{fetch_policy_get, fetch_policy_set, FetchPolicy, FetchPolicyState};
+use history::{
+    history_delete, history_get_retention, history_page, history_search, history_set_retention,
+    HistoryState, HistoryStore,
+};
+use image_meta::image_metadata;
+use image_resize::image_shrink;
+use import::history_import;
+use irc_url::parse_irc_url;
+use link_preview::{
+    link_preview_fetch, link_preview_get_config, link_preview_set_config, LinkPreviewState,
+    LinkPreviews,
+};
+use oauth::{
+    oauth_loopback_cancel, oauth_loopback_listen, oauth_loopback_wait, OAuthLoopbackState,
+};
+use oauth_tokens::{oauth_access_token, OAuthTokenState};
+use profile::{
+    profile_create, profile_current, profile_delete, profile_launch, profile_list,
+    profile_set_ask_at_startup, ProfileState,
+};
+use rawlog::{open_raw_log_folder, start_raw_log, stop_raw_log, RawLogState};
+use secrets::{secret_delete, secret_get, secret_migrate, secret_set, SecretState, SecretStore};
 use socket::{connect, disconnect, listen, send, SocketState};
+use stats::{all_connection_stats, connection_stats, StatsState};
+use tap::TapState;
+use upload::{upload_cancel, upload_file, upload_pick_files, upload_stage, UploadState};
+use vault::{
+    vault_activity, vault_change_passphrase, vault_disable, vault_enable, vault_lock,
+    vault_set_auto_lock, vault_status, vault_unlock, Vault, VaultState,
+};
 
 #[tauri::command]
-async fn download_image(app: tauri::AppHandle, url: String) -> Result<String, String> {
-    download_image_impl(app, url).await
-}
-
-fn extract_filename(url: &str) -> String {
-    url.split('/')
-        .next_back()
-        .and_then(|s| s.split('?').next())
-        .filter(|s| !s.is_empty())
-        .unwrap_or("image")
-        .to_string()
+async fn download_image(
+    app: tauri::AppHandle,
+    url: String,
+    download_id: Option<String>,
+) -> Result<String, String> {
+    let downloaded = download::fetch_media(&app, &url, download_id).await?;
+    download_image_impl(app, downloaded.filename, downloaded.bytes).await
 }
 
 #[cfg(desktop)]
-async fn download_image_impl(_app: tauri::AppHandle, url: String) -> Result<String, String> {
-    let response = reqwest::get(&url).await.map_err(|e| e.to_string())?;
-    let filename = extract_filename(&url);
-    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
-
+async fn download_image_impl(
+    _app: tauri::AppHandle,
+    filename: String,
+    bytes: Vec<u8>,
+) -> Result<String, String> {
     if let Some(file) = rfd::AsyncFileDialog::new()
         .set_file_name(&filename)
         .save_file()
@@ -36,11 +104,11 @@ async fn download_image_impl(_app: tauri::AppHandle, url: String) -> Result<Stri
 }
 
 #[cfg(target_os = "ios")]
-async fn download_image_impl(_app: tauri::AppHandle, url: String) -> Result<String, String> {
-    let response = reqwest::get(&url).await.map_err(|e| e.to_string())?;
-    let filename = extract_filename(&url);
-    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
-
+async fn download_image_impl(
+    _app: tauri::AppHandle,
+    filename: String,
+    bytes: Vec<u8>,
+) -> Result<String, String> {
     // Write to the temp directory; iOS cleans this up automatically.
     let tmp_path = std::env::temp_dir().join(&filename);
     std::fs::write(&tmp_path, &bytes).map_err(|e| e.to_string())?;
@@ -53,17 +121,13 @@ async fn download_image_impl(_app: tauri::AppHandle, url: String) -> Result<Stri
 }
 
 #[cfg(target_os = "android")]
-async fn download_image_impl(app: tauri::AppHandle, url: String) -> Result<String, String> {
+async fn download_image_impl(
+    app: tauri::AppHandle,
+    filename: String,
+    bytes_vec: Vec<u8>,
+) -> Result<String, String> {
     use tauri_plugin_dialog::DialogExt;
 
-    let response = reqwest::get(&url).await.map_err(|e| e.to_string())?;
-    let filename = extract_filename(&url);
-    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
-
-    if bytes.is_empty() {
-        return Err("Downloaded file is empty".to_string());
-    }
-
     // Show Android save-file picker (Intent.ACTION_CREATE_DOCUMENT).
     let (tx, rx) = tokio::sync::oneshot::channel::<Option<tauri_plugin_dialog::FilePath>>();
     app.dialog()
@@ -74,8 +138,6 @@ async fn download_image_impl(app: tauri::AppHandle, url: String) -> Result<Strin
         });
 
     if let Ok(Some(dest)) = rx.await {
-        // Convert bytes::Bytes to Vec so it can be moved into spawn_blocking.
-        let bytes_vec = bytes.to_vec();
         match dest {
             tauri_plugin_dialog::FilePath::Path(path) => {
                 // Rare on Android but handle it — run on a blocking thread.
@@ -103,19 +165,16 @@ async fn download_image_impl(app: tauri::AppHandle, url: String) -> Result<Strin
     Ok(String::new())
 }
 
+/// The content resolver and the parsed `uri_str`, for reading or writing a
+/// content:// URI through Android's ContentResolver
 #[cfg(target_os = "android")]
-fn write_bytes_to_content_uri(bytes: &[u8], uri_str: &str) -> Result<(), String> {
-    use jni::{
-        objects::{JObject, JValue},
-        JavaVM,
-    };
+fn content_uri<'local>(
+    env: &mut jni::JNIEnv<'local>,
+    uri_str: &str,
+) -> Result<(jni::objects::JObject<'local>, jni::objects::JObject<'local>), String> {
+    use jni::objects::{JObject, JValue};
 
     let ctx = ndk_context::android_context();
-    // SAFETY: pointers come from the Android runtime and are valid for the app lifetime.
-    let vm = unsafe { JavaVM::from_raw(ctx.vm().cast()) }.map_err(|e| e.to_string())?;
-    // attach_current_thread is idempotent: if the thread is already attached it just
-    // returns the existing env and the guard won't detach on drop.
-    let mut env = vm.attach_current_thread().map_err(|e| e.to_string())?;
     let activity = unsafe { JObject::from_raw(ctx.context().cast()) };
 
     let resolver = env
@@ -140,6 +199,26 @@ fn write_bytes_to_content_uri(bytes: &[u8], uri_str: &str) -> Result<(), String>
         .map_err(|e| e.to_string())?
         .l()
         .map_err(|e| e.to_string())?;
+    Ok((resolver, uri_obj))
+}
+
+/// The Java VM the app runs in
+#[cfg(target_os = "android")]
+fn java_vm() -> Result<jni::JavaVM, String> {
+    let ctx = ndk_context::android_context();
+    // SAFETY: pointers come from the Android runtime and are valid for the app lifetime.
+    unsafe { jni::JavaVM::from_raw(ctx.vm().cast()) }.map_err(|e| e.to_string())
+}
+
+#[cfg(target_os = "android")]
+fn write_bytes_to_content_uri(bytes: &[u8], uri_str: &str) -> Result<(), String> {
+    use jni::objects::JValue;
+
+    let vm = java_vm()?;
+    // attach_current_thread is idempotent: if the thread is already attached it just
+    // returns the existing env and the guard won't detach on drop.
+    let mut env = vm.attach_current_thread().map_err(|e| e.to_string())?;
+    let (resolver, uri_obj) = content_uri(&mut env, uri_str)?;
 
     // Use "wt" mode (write + truncate) — the single-arg overload defaults to "w" which
     // does not work with all Android storage providers. "wt" is explicit and reliable.
@@ -192,16 +271,163 @@ fn write_bytes_to_content_uri(bytes: &[u8], uri_str: &str) -> Result<(), String>
     Ok(())
 }
 
+/// The display name of a content:// URI, as the document picker shows it
+#[cfg(target_os = "android")]
+fn content_uri_name(uri_str: &str) -> Option<String> {
+    use jni::objects::{JObject, JString, JValue};
+
+    let vm = java_vm().ok()?;
+    let mut env = vm.attach_current_thread().ok()?;
+    let (resolver, uri_obj) = content_uri(&mut env, uri_str).ok()?;
+    let null = JObject::null();
+    let cursor = env
+        .call_method(
+            &resolver,
+            "query",
+            "(Landroid/net/Uri;[Ljava/lang/String;Ljava/lang/String;[Ljava/lang/String;Ljava/lang/String;)Landroid/database/Cursor;",
+            &[
+                JValue::Object(&uri_obj),
+                JValue::Object(&null),
+                JValue::Object(&null),
+                JValue::Object(&null),
+                JValue::Object(&null),
+            ],
+        )
+        .ok()?
+        .l()
+        .ok()?;
+    if cursor.is_null() {
+        return None;
+    }
+
+    let mut read_name = || -> Option<String> {
+        if !env
+            .call_method(&cursor, "moveToFirst", "()Z", &[])
+            .ok()?
+            .z()
+            .ok()?
+        {
+            return None;
+        }
+        // OpenableColumns.DISPLAY_NAME
+        let column = env.new_string("_display_name").ok()?;
+        let index = env
+            .call_method(
+                &cursor,
+                "getColumnIndex",
+                "(Ljava/lang/String;)I",
+                &[JValue::Object(&*column)],
+            )
+            .ok()?
+            .i()
+            .ok()?;
+        if index < 0 {
+            return None;
+        }
+        let name = env
+            .call_method(
+                &cursor,
+                "getString",
+                "(I)Ljava/lang/String;",
+                &[JValue::Int(index)],
+            )
+            .ok()?
+            .l()
+            .ok()?;
+        if name.is_null() {
+            return None;
+        }
+        let name = JString::from(name);
+        let name: String = env.get_string(&name).ok()?.into();
+        Some(name)
+    };
+    let name = read_name();
+    let _ = env.call_method(&cursor, "close", "()V", &[]);
+    name
+}
+
+/// Copy the document behind a content:// URI to `path`
+#[cfg(target_os = "android")]
+fn copy_content_uri(uri_str: &str, path: &std::path::Path) -> Result<(), String> {
+    use jni::objects::JValue;
+    use std::io::Write;
+
+    let vm = java_vm()?;
+    let mut env = vm.attach_current_thread().map_err(|e| e.to_string())?;
+    let (resolver, uri_obj) = content_uri(&mut env, uri_str)?;
+    let stream = env
+        .call_method(
+            &resolver,
+            "openInputStream",
+            "(Landroid/net/Uri;)Ljava/io/InputStream;",
+            &[JValue::Object(&uri_obj)],
+        )
+        .map_err(|e| e.to_string())?
+        .l()
+        .map_err(|e| e.to_string())?;
+    if stream.is_null() {
+        return Err(
+            "openInputStream returned null — URI may be invalid or access denied".to_string(),
+        );
+    }
+
+    // Read in 64 KB chunks, like the writes above, so large videos never
+    // sit in the Java heap or in memory here
+    const CHUNK: usize = 65_536;
+    let mut copy = || -> Result<(), String> {
+        let mut file = std::fs::File::create(path)
+            .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+        let array = env
+            .new_byte_array(CHUNK as i32)
+            .map_err(|e| e.to_string())?;
+        let mut chunk = vec![0i8; CHUNK];
+        loop {
+            let read = env
+                .call_method(&stream, "read", "([B)I", &[JValue::Object(&array)])
+                .map_err(|e| e.to_string())?
+                .i()
+                .map_err(|e| e.to_string())?;
+            if read < 0 {
+                return Ok(());
+            }
+            let read = read as usize;
+            env.get_byte_array_region(&array, 0, &mut chunk[..read])
+                .map_err(|e| e.to_string())?;
+            let bytes: Vec<u8> = chunk[..read].iter().map(|&b| b as u8).collect();
+            file.write_all(&bytes)
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        }
+    };
+    let result = copy();
+    let _ = env.call_method(&stream, "close", "()V", &[]);
+    result.map_err(|e| format!("Read failed: {e}"))
+}
+
 // use tauri_plugin_deep_link::DeepLinkExt;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    run_with(StartupOptions::default());
+}
+
+/// Start the app with options from the command line
+pub fn run_with(options: StartupOptions) {
+    let selection = profile::Selection::from_options(&options);
+    let mut context = tauri::generate_context!();
+    let base_identifier = context.config().identifier.clone();
+    context.config_mut().identifier = selection.identifier(&base_identifier);
+
     let mut builder = tauri::Builder::default();
 
     #[cfg(desktop)]
     {
-        builder = builder.plugin(tauri_plugin_single_instance::init(|_app, argv, _cwd| {
-            println!("a new app instance was opened with {argv:?} and the deep link event was already triggered");
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+            // The second instance already rejected arguments it couldn't parse
+            match cli::parse(argv.into_iter().skip(1), std::path::Path::new(&cwd)) {
+                Ok(cli::Command::Run(options)) => cli::forward(app, options),
+                Ok(cli::Command::Print(_)) => {}
+                Err(e) => log::warn!("Ignoring arguments of a second instance: {}", e),
+            }
         }));
     }
 
@@ -212,12 +438,9 @@ pub fn run() {
 
     #[cfg(any(target_os = "ios", target_os = "android"))]
     {
-        builder = builder.plugin(tauri_plugin_haptics::init());
-    }
-
-    #[cfg(target_os = "android")]
-    {
-        builder = builder.plugin(tauri_plugin_dialog::init());
+        builder = builder
+            .plugin(tauri_plugin_haptics::init())
+            .plugin(tauri_plugin_dialog::init());
     }
 
     builder
@@ -225,26 +448,210 @@ pub fn run() {
         .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_notification::init())
-        .setup(|app| {
-            if cfg!(debug_assertions) {
+        .setup(move |app| {
+            let options = app.state::<StartupState>().0.clone();
+            let profile = selection.resolve(app.handle(), &base_identifier)?;
+            if cfg!(debug_assertions) || options.log_level.is_some() {
+                use tauri_plugin_log::{Target, TargetKind};
                 app.handle().plugin(
                     tauri_plugin_log::Builder::default()
-                        .level(log::LevelFilter::Info)
+                        .level(options.log_level.unwrap_or(log::LevelFilter::Info))
+                        .targets([
+                            Target::new(TargetKind::Stdout),
+                            Target::new(TargetKind::Folder {
+                                path: profile.log_dir.clone(),
+                                file_name: None,
+                            }),
+                        ])
                         .build(),
                 )?;
             }
+
+            // The main window is created here so it gets the profile's webview storage
+            let window_config = app
+                .config()
+                .app
+                .windows
+                .first()
+                .cloned()
+                .ok_or("No window configured")?;
+            let mut window =
+                tauri::WebviewWindowBuilder::from_config(app.handle(), &window_config)?;
+            if let Some(dir) = &profile.webview_dir {
+                window = window
+                    .data_directory(dir.clone())
+                    .title(format!("{} ({})", window_config.title, profile.name));
+                #[cfg(target_os = "macos")]
+                {
+                    window = window.data_store_identifier(profile::data_store_identifier(dir));
+                }
+            }
+            let window = window.build()?;
+            if options.minimized {
+                window.minimize()?;
+            }
+            #[cfg(desktop)]
+            {
+                let handle = app.handle().clone();
+                window.on_window_event(move |event| upload::on_window_event(&handle, event));
+            }
+
             // Register deep links at runtime for Linux and Windows (debug)
             // This enables AppImage support and development testing
             // Note: macOS doesn't support runtime registration
+            // Other profiles leave the registration to the default one
             #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
-            {
+            if selection.is_default() {
                 use tauri_plugin_deep_link::DeepLinkExt;
                 app.deep_link().register_all()?;
             }
+
+            // Links opened while running, and the one we may have been started with
+            {
+                use tauri_plugin_deep_link::DeepLinkExt;
+                let handle = app.handle().clone();
+                app.deep_link().on_open_url(move |event| {
+                    deeplink::open_links(&handle, event.urls());
+                });
+                if let Some(urls) = app.deep_link().get_current()? {
+                    deeplink::open_links(app.handle(), urls);
+                }
+                deeplink::open_links(
+                    app.handle(),
+                    options
+                        .urls
+                        .iter()
+                        .filter(|url| !deeplink::delivered_by_plugin(url)),
+                );
+            }
+
+            // With the app lock on, everything starts locked until the
+            // frontend unlocks it
+            let vault = Arc::new(Vault::load(&profile.config_dir));
+            let history = HistoryStore::open(
+                &profile.data_dir.join("history.sqlite3"),
+                vault.is_enabled(),
+            )?;
+            app.manage(HistoryState(Arc::new(history)));
+            let chat_log = ChatLogger::load(
+                profile.config_dir.join("chat-logs.json"),
+                profile.data_dir.join("logs"),
+                vault.clone(),
+            );
+            app.manage(ChatLogState(Arc::new(chat_log)));
+            app.manage(SecretState(Arc::new(SecretStore::new(
+                app.config().identifier.clone(),
+                &profile.data_dir,
+                profile.portable,
+                vault.clone(),
+            ))));
+            let link_previews = Arc::new(LinkPreviews::load(
+                profile.config_dir.join("link-previews.json"),
+                profile.data_dir.join("link-previews"),
+                vault.clone(),
+            ));
+            let pruned = link_previews.clone();
+            tauri::async_runtime::spawn_blocking(move || pruned.prune());
+            app.manage(LinkPreviewState(link_previews));
+            app.manage(VaultState(vault));
+            app.manage(FetchPolicyState(Arc::new(FetchPolicy::load(
+                profile.config_dir.join("fetch-policy.json"),
+            ))));
+            let downloads = DownloadManagerState(Arc::new(DownloadManager::load(
+                app.handle().clone(),
+                profile.config_dir.join("downloads.json"),
+                &profile.data_dir,
+            )));
+            downloads.start();
+            app.manage(downloads);
+            app.manage(UploadState::new(profile.data_dir.join("upload-staging")));
+            vault::spawn_auto_lock(app.handle().clone());
+            app.manage(ProfileState(profile));
             Ok(())
         })
         .manage(SocketState(Arc::new(Mutex::new(HashMap::new()))))
-        .invoke_handler(tauri::generate_handler![connect, disconnect, listen, send, download_image])
-        .run(tauri::generate_context!())
+        .manage(StatsState(Arc::new(Mutex::new(HashMap::new()))))
+        .manage(RawLogState(Arc::new(Mutex::new(HashMap::new()))))
+        .manage(TapState::default())
+        .manage(ConsoleState(Arc::new(Mutex::new(HashMap::new()))))
+        .manage(DeepLinkState::default())
+        .manage(StartupState(options))
+        .manage(OAuthLoopbackState::default())
+        .manage(OAuthTokenState::default())
+        .manage(DownloadState::default())
+        .invoke_handler(tauri::generate_handler![
+            connect,
+            disconnect,
+            listen,
+            send,
+            download_image,
+            download_image_cancel,
+            fetch_policy_get,
+            fetch_policy_set,
+            download_enqueue,
+            download_list,
+            download_pause,
+            download_resume,
+            download_cancel,
+            download_reveal,
+            download_get_config,
+            download_set_config,
+            download_choose_folder,
+            upload_file,
+            upload_cancel,
+            upload_pick_files,
+            upload_stage,
+            image_metadata,
+            image_shrink,
+            link_preview_fetch,
+            link_preview_get_config,
+            link_preview_set_config,
+            connection_stats,
+            all_connection_stats,
+            start_raw_log,
+            stop_raw_log,
+            open_raw_log_folder,
+            open_raw_console,
+            set_raw_console_filter,
+            set_raw_console_paused,
+            history_page,
+            history_search,
+            history_delete,
+            history_get_retention,
+            history_set_retention,
+            history_import,
+            history_export,
+            channel_stats_report,
+            parse_irc_url,
+            deep_link_ready,
+            startup_config,
+            profile_current,
+            profile_list,
+            profile_create,
+            profile_delete,
+            profile_set_ask_at_startup,
+            profile_launch,
+            secret_set,
+            secret_get,
+            secret_delete,
+            secret_migrate,
+            vault_status,
+            vault_enable,
+            vault_disable,
+            vault_lock,
+            vault_unlock,
+            vault_change_passphrase,
+            vault_set_auto_lock,
+            vault_activity,
+            oauth_loopback_listen,
+            oauth_loopback_wait,
+            oauth_loopback_cancel,
+            oauth_access_token,
+            chat_log_get_config,
+            chat_log_set_config,
+            chat_log_set_target_enabled,
+            set_network_incognito
+        ])
+        .run(context)
         .expect("error while running tauri application");
 }
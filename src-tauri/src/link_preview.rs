@@ -0,0 +1,936 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::Duration;
+use tauri::State;
+
+use crate::download::sniff;
+use crate::fetch_policy::{error_message, FetchPolicy, FetchPolicyState};
+use crate::image_resize::thumbnail;
+use crate::stats::now_millis;
+use crate::util::{hex, load_json, save_json};
+use crate::vault::Vault;
+
+/// Everything for one preview, page, oEmbed and image, must fit in this
+const FETCH_BUDGET: Duration = Duration::from_secs(10);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_REDIRECTS: usize = 5;
+/// Bytes of a page read for its metadata; the head comes first
+const MAX_PAGE_SIZE: usize = 512 * 1024;
+const MAX_OEMBED_SIZE: usize = 64 * 1024;
+const MAX_IMAGE_SIZE: usize = 5 * 1024 * 1024;
+/// Longest side of the preview image
+const IMAGE_DIMENSION: u32 = 400;
+const IMAGE_QUALITY: u8 = 80;
+const MAX_TITLE_LEN: usize = 200;
+const MAX_DESCRIPTION_LEN: usize = 500;
+const PREVIEW_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+/// Failures are only kept in memory, and not for long: the site may just
+/// have been down
+const FAILURE_TTL: Duration = Duration::from_secs(10 * 60);
+const MAX_MEMORY_ENTRIES: usize = 256;
+const USER_AGENT: &str = concat!("ObsidianIRC/", env!("CARGO_PKG_VERSION"), " (link preview)");
+
+/// What the frontend shows under a message with a link
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkPreview {
+    /// Where redirects ended up
+    url: String,
+    title: Option<String>,
+    description: Option<String>,
+    site_name: Option<String>,
+    /// The downsized image as a data URL, so the webview never loads
+    /// anything from the site
+    image: Option<String>,
+}
+
+/// Domains link previews are never fetched from
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LinkPreviewConfig {
+    /// `example.com` also covers its subdomains
+    denied_domains: Vec<String>,
+}
+
+/// A denylist entry in the form it's matched in
+fn normalize_domain(entry: &str) -> Result<String, String> {
+    let domain = entry
+        .trim()
+        .trim_start_matches("*.")
+        .trim_matches('.')
+        .to_ascii_lowercase();
+    let valid = domain.trim_matches(['[', ']']).parse::<IpAddr>().is_ok()
+        || (!domain.is_empty()
+            && domain
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_')));
+    if !valid {
+        return Err(format!("Invalid domain: {}", entry.trim()));
+    }
+    Ok(domain)
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct CacheEntry {
+    fetched_at: u64,
+    /// None when the page had nothing to show
+    preview: Option<LinkPreview>,
+    error: Option<String>,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        let ttl = if self.error.is_some() {
+            FAILURE_TTL
+        } else {
+            PREVIEW_TTL
+        };
+        now_millis().saturating_sub(self.fetched_at) < ttl.as_millis() as u64
+    }
+
+    fn result(self) -> Result<Option<LinkPreview>, String> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.preview),
+        }
+    }
+}
+
+fn digest(url: &str) -> String {
+    hex(&Sha256::digest(url.as_bytes()))
+}
+
+/// HMAC-SHA256 of `url`, so sealed cache files don't reveal which URLs were
+/// previewed to anyone who can hash a guess
+fn keyed_digest(key: &[u8], url: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(url.as_bytes());
+    hex(&mac.finalize().into_bytes())
+}
+
+/// The denylist and the preview cache. Previews are cached on disk, one
+/// file per URL named by its hash, or sealed and named by a keyed hash while
+/// the app lock is on; failures only in memory.
+pub struct LinkPreviews {
+    config: RwLock<LinkPreviewConfig>,
+    config_path: PathBuf,
+    cache_dir: PathBuf,
+    memory: Mutex<HashMap<String, CacheEntry>>,
+    vault: Arc<Vault>,
+}
+
+impl LinkPreviews {
+    /// Load the denylist from `config_path`, falling back to none
+    pub fn load(config_path: PathBuf, cache_dir: PathBuf, vault: Arc<Vault>) -> Self {
+        Self {
+            config: RwLock::new(load_json(&config_path)),
+            config_path,
+            cache_dir,
+            memory: Mutex::new(HashMap::new()),
+            vault,
+        }
+    }
+
+    fn config(&self) -> LinkPreviewConfig {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn update(&self, config: LinkPreviewConfig) -> Result<(), String> {
+        let mut denied_domains = Vec::new();
+        for entry in &config.denied_domains {
+            let domain = normalize_domain(entry)?;
+            if !denied_domains.contains(&domain) {
+                denied_domains.push(domain);
+            }
+        }
+        let config = LinkPreviewConfig { denied_domains };
+        save_json(&self.config_path, &config, "link preview settings")?;
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+        Ok(())
+    }
+
+    /// Reject URLs on a denied domain
+    fn check_host(&self, url: &reqwest::Url) -> Result<(), String> {
+        let host = url
+            .host_str()
+            .ok_or("The URL has no host")?
+            .trim_end_matches('.')
+            .to_ascii_lowercase();
+        let denied = self
+            .config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .denied_domains
+            .iter()
+            .any(|domain| {
+                host == *domain
+                    || host
+                        .strip_suffix(domain.as_str())
+                        .is_some_and(|s| s.ends_with('.'))
+            });
+        if denied {
+            return Err(format!("Link previews are turned off for {}", host));
+        }
+        Ok(())
+    }
+
+    fn memory(&self) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+        self.memory.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// None while locked, when the file name can't be derived
+    fn cache_path(&self, url: &str, encrypted: bool) -> Option<PathBuf> {
+        let name = if encrypted {
+            let key = self.vault.subkey("link-preview")?;
+            format!("{}.enc", keyed_digest(key.as_bytes(), url))
+        } else {
+            format!("{}.json", digest(url))
+        };
+        Some(self.cache_dir.join(name))
+    }
+
+    fn cached(&self, url: &str) -> Option<CacheEntry> {
+        if let Some(entry) = self.memory().get(url).filter(|entry| entry.is_fresh()) {
+            return Some(entry.clone());
+        }
+        let encrypted = self.vault.is_enabled();
+        let bytes = fs::read(self.cache_path(url, encrypted)?).ok()?;
+        let json = if encrypted {
+            self.vault.open(&bytes).ok()?
+        } else {
+            bytes
+        };
+        let entry: CacheEntry = serde_json::from_slice(&json).ok()?;
+        entry.is_fresh().then(|| {
+            self.remember(url, entry.clone());
+            entry
+        })
+    }
+
+    fn remember(&self, url: &str, entry: CacheEntry) {
+        let mut memory = self.memory();
+        if memory.len() >= MAX_MEMORY_ENTRIES {
+            memory.retain(|_, entry| entry.is_fresh());
+            if memory.len() >= MAX_MEMORY_ENTRIES {
+                memory.clear();
+            }
+        }
+        memory.insert(url.to_string(), entry);
+    }
+
+    fn store(&self, url: &str, result: &Result<Option<LinkPreview>, String>) {
+        let entry = CacheEntry {
+            fetched_at: now_millis(),
+            preview: result.as_ref().ok().cloned().flatten(),
+            error: result.as_ref().err().cloned(),
+        };
+        self.remember(url, entry.clone());
+        if entry.error.is_some() {
+            return;
+        }
+        let Ok(json) = serde_json::to_vec(&entry) else {
+            return;
+        };
+        let encrypted = self.vault.is_enabled();
+        // While locked nothing may be written; the memory copy has to do
+        let bytes = if encrypted {
+            match self.vault.seal(&json) {
+                Some(sealed) => sealed,
+                None => return,
+            }
+        } else {
+            json
+        };
+        let Some(path) = self.cache_path(url, encrypted) else {
+            return;
+        };
+        if let Err(e) = fs::create_dir_all(&self.cache_dir).and_then(|()| fs::write(path, bytes)) {
+            log::warn!("Failed to cache the link preview: {}", e);
+        }
+    }
+
+    /// Remove cached previews past their lifetime, and those in the form the
+    /// app lock setting no longer uses
+    pub fn prune(&self) {
+        let Ok(entries) = fs::read_dir(&self.cache_dir) else {
+            return;
+        };
+        let stale_extension = if self.vault.is_enabled() {
+            "json"
+        } else {
+            "enc"
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let expired = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > PREVIEW_TTL);
+            let stale = path.extension().is_some_and(|ext| ext == stale_extension);
+            if expired || stale {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+}
+
+/// A GET that follows redirects itself, so every hop, the first one
+/// included, is checked against the denylist as well as the fetch policy.
+/// The policy's resolver only sees host names; addresses written in the
+/// URL are caught here.
+async fn get(
+    client: &reqwest::Client,
+    policy: &FetchPolicy,
+    previews: &LinkPreviews,
+    mut url: reqwest::Url,
+    accept: &str,
+) -> Result<reqwest::Response, String> {
+    for _ in 0..=MAX_REDIRECTS {
+        url = policy.check_url(url.as_str())?;
+        previews.check_host(&url)?;
+        let response = client
+            .get(url.clone())
+            .header(reqwest::header::ACCEPT, accept)
+            .send()
+            .await
+            .map_err(|e| error_message(&e))?;
+        if !response.status().is_redirection() {
+            return Ok(response);
+        }
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or("The server redirected without a location")?;
+        url = url
+            .join(location)
+            .map_err(|e| format!("Invalid redirect: {}", e))?;
+    }
+    Err(format!("More than {} redirects", MAX_REDIRECTS))
+}
+
+/// Up to `limit` bytes of the body. With `truncate` a longer body is cut
+/// short rather than rejected.
+async fn read_body(
+    mut response: reqwest::Response,
+    limit: usize,
+    truncate: bool,
+) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| error_message(&e))? {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() > limit {
+            if !truncate {
+                return Err(format!("The response is larger than {} KB", limit / 1024));
+            }
+            bytes.truncate(limit);
+            break;
+        }
+    }
+    Ok(bytes)
+}
+
+/// The page as text: UTF-8 unless the headers or a meta tag say Latin-1
+fn decode_page(bytes: &[u8], content_type: &str) -> String {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(2048)]).to_ascii_lowercase();
+    let charset = content_type
+        .split_once("charset=")
+        .or_else(|| head.split_once("charset="))
+        .map(|(_, charset)| charset.trim_start_matches(['"', '\'']))
+        .unwrap_or("");
+    if ["iso-8859-1", "latin1", "windows-1252"]
+        .iter()
+        .any(|latin| charset.starts_with(latin))
+    {
+        bytes.iter().map(|&b| b as char).collect()
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Character references in attribute values and the title
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| {
+                let c = match &rest[1..1 + end] {
+                    "amp" => '&',
+                    "lt" => '<',
+                    "gt" => '>',
+                    "quot" => '"',
+                    "apos" => '\'',
+                    "nbsp" => ' ',
+                    "hellip" => '…',
+                    "ndash" => '–',
+                    "mdash" => '—',
+                    "lsquo" => '‘',
+                    "rsquo" => '’',
+                    "ldquo" => '“',
+                    "rdquo" => '”',
+                    entity => {
+                        let number = entity.strip_prefix('#')?;
+                        let code = match number.strip_prefix(['x', 'X']) {
+                            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                            None => number.parse().ok()?,
+                        };
+                        char::from_u32(code)?
+                    }
+                };
+                Some((c, end + 2))
+            });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Whitespace collapsed and cut to `max` characters; None when empty
+fn clean_text(text: &str, max: usize) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() <= max {
+        return Some(text);
+    }
+    let cut: String = text.chars().take(max - 1).collect();
+    Some(format!("{}…", cut.trim_end()))
+}
+
+/// The attributes of a tag, names lowercased and values decoded; the first
+/// of a repeated name wins
+fn attributes(tag: &str) -> HashMap<String, String> {
+    let mut attrs = HashMap::new();
+    let mut rest = tag;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+        if rest.is_empty() {
+            break;
+        }
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let mut value = "";
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &after[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    value = &body[..end];
+                    rest = body.get(end + 1..).unwrap_or("");
+                }
+                _ => {
+                    let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    value = &after[..end];
+                    rest = &after[end..];
+                }
+            }
+        }
+        if !name.is_empty() {
+            attrs.entry(name).or_insert_with(|| decode_entities(value));
+        }
+    }
+    attrs
+}
+
+/// Index of the `>` closing the tag opened at `start`, skipping quoted values
+fn tag_end(html: &str, start: usize) -> Option<usize> {
+    let mut quote = None;
+    for (i, b) in html.bytes().enumerate().skip(start + 1) {
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None if b == b'"' || b == b'\'' => quote = Some(b),
+            None if b == b'>' => return Some(i),
+            None => {}
+        }
+    }
+    None
+}
+
+/// What the head of a page says about it
+#[derive(Default)]
+struct PageMeta {
+    /// `<meta>` contents by lowercased `property` or `name`
+    meta: HashMap<String, String>,
+    title: Option<String>,
+    /// The JSON oEmbed endpoint the page links to
+    oembed: Option<String>,
+}
+
+impl PageMeta {
+    /// The first of `keys` with content
+    fn first(&self, keys: &[&str], max: usize) -> Option<String> {
+        keys.iter()
+            .filter_map(|key| self.meta.get(*key))
+            .find_map(|value| clean_text(value, max))
+    }
+}
+
+/// Scan the tags up to the body for meta, link and title; a page is rarely
+/// well-formed enough for anything stricter
+fn parse_head(html: &str) -> PageMeta {
+    // ASCII lowercasing keeps byte offsets, so both can be indexed alike
+    let lower = html.to_ascii_lowercase();
+    let mut page = PageMeta::default();
+    let mut pos = 0;
+    while let Some(offset) = lower[pos..].find('<') {
+        let start = pos + offset;
+        let rest = &lower[start + 1..];
+        if rest.starts_with("!--") {
+            pos = rest
+                .find("-->")
+                .map_or(lower.len(), |end| start + 1 + end + 3);
+            continue;
+        }
+        let name_len = rest
+            .char_indices()
+            .find(|&(i, c)| !(c.is_ascii_alphanumeric() || (i == 0 && matches!(c, '/' | '!'))))
+            .map_or(rest.len(), |(i, _)| i);
+        let name = &rest[..name_len];
+        if name.is_empty() {
+            pos = start + 1;
+            continue;
+        }
+        let Some(end) = tag_end(&lower, start) else {
+            break;
+        };
+        let tag = &html[start + 1 + name_len..end];
+        pos = end + 1;
+        match name {
+            "/head" | "body" => break,
+            "meta" => {
+                let attrs = attributes(tag);
+                let key = attrs.get("property").or_else(|| attrs.get("name"));
+                if let (Some(key), Some(content)) = (key, attrs.get("content")) {
+                    page.meta
+                        .entry(key.to_ascii_lowercase())
+                        .or_insert_with(|| content.clone());
+                }
+            }
+            "link" => {
+                let attrs = attributes(tag);
+                let oembed = attrs
+                    .get("type")
+                    .is_some_and(|t| t.eq_ignore_ascii_case("application/json+oembed"));
+                if oembed && page.oembed.is_none() {
+                    page.oembed = attrs.get("href").cloned();
+                }
+            }
+            "title" | "script" | "style" => {
+                let close = lower[pos..]
+                    .find(&format!("</{}", name))
+                    .map_or(lower.len(), |n| pos + n);
+                if name == "title" && page.title.is_none() {
+                    page.title = clean_text(&decode_entities(&html[pos..close]), MAX_TITLE_LEN);
+                }
+                pos = close;
+            }
+            _ => {}
+        }
+    }
+    page
+}
+
+#[derive(Deserialize, Default)]
+struct OEmbed {
+    title: Option<String>,
+    provider_name: Option<String>,
+    thumbnail_url: Option<String>,
+}
+
+async fn fetch_oembed(
+    client: &reqwest::Client,
+    policy: &FetchPolicy,
+    previews: &LinkPreviews,
+    endpoint: reqwest::Url,
+) -> Option<OEmbed> {
+    let response = get(client, policy, previews, endpoint, "application/json")
+        .await
+        .ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let bytes = read_body(response, MAX_OEMBED_SIZE, false).await.ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+/// The preview image, downsized and re-encoded as a data URL
+async fn fetch_image(
+    client: &reqwest::Client,
+    policy: &FetchPolicy,
+    previews: &LinkPreviews,
+    url: reqwest::Url,
+) -> Result<String, String> {
+    let response = get(client, policy, previews, url, "image/*").await?;
+    if !response.status().is_success() {
+        return Err(format!("The server answered {}", response.status()));
+    }
+    if response
+        .content_length()
+        .is_some_and(|len| len > MAX_IMAGE_SIZE as u64)
+    {
+        return Err("The image is too large".to_string());
+    }
+    let bytes = read_body(response, MAX_IMAGE_SIZE, false).await?;
+    if !sniff(&bytes).is_some_and(|(mime, _)| mime.starts_with("image/")) {
+        return Err("Not an image".to_string());
+    }
+    let image = tauri::async_runtime::spawn_blocking(move || {
+        thumbnail(&bytes, IMAGE_DIMENSION, IMAGE_QUALITY)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    let mime = if image.extension == "webp" {
+        "image/webp"
+    } else {
+        "image/jpeg"
+    };
+    Ok(format!(
+        "data:{};base64,{}",
+        mime,
+        BASE64.encode(&image.bytes)
+    ))
+}
+
+/// The preview a page's tags give, OpenGraph before Twitter Card before
+/// oEmbed, and the URL of its image, which is still to be fetched
+fn describe(
+    page: &PageMeta,
+    oembed: &OEmbed,
+    page_url: &reqwest::Url,
+) -> (LinkPreview, Option<reqwest::Url>) {
+    let title = page
+        .first(&["og:title", "twitter:title"], MAX_TITLE_LEN)
+        .or_else(|| clean_text(oembed.title.as_deref()?, MAX_TITLE_LEN))
+        .or(page.title.clone());
+    let description = page.first(
+        &["og:description", "twitter:description", "description"],
+        MAX_DESCRIPTION_LEN,
+    );
+    let site_name = page
+        .first(&["og:site_name"], MAX_TITLE_LEN)
+        .or_else(|| clean_text(oembed.provider_name.as_deref()?, MAX_TITLE_LEN));
+    let image_url = [
+        "og:image:secure_url",
+        "og:image",
+        "og:image:url",
+        "twitter:image",
+        "twitter:image:src",
+    ]
+    .iter()
+    .find_map(|key| page.meta.get(*key).filter(|url| !url.trim().is_empty()))
+    .or(oembed.thumbnail_url.as_ref())
+    .and_then(|url| page_url.join(url.trim()).ok());
+    let preview = LinkPreview {
+        url: page_url.to_string(),
+        title,
+        description,
+        site_name,
+        image: None,
+    };
+    (preview, image_url)
+}
+
+async fn fetch_preview(
+    policy: &FetchPolicy,
+    previews: &LinkPreviews,
+    client: reqwest::Client,
+    url: reqwest::Url,
+) -> Result<Option<LinkPreview>, String> {
+    let response = get(
+        &client,
+        policy,
+        previews,
+        url,
+        "text/html,application/xhtml+xml;q=0.9,*/*;q=0.1",
+    )
+    .await?;
+    let page_url = response.url().clone();
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("The server answered {}", status));
+    }
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let essence = content_type.split(';').next().unwrap_or("").trim();
+    if !matches!(essence, "" | "text/html" | "application/xhtml+xml") {
+        return Err(format!("Not a web page ({})", essence));
+    }
+    let bytes = read_body(response, MAX_PAGE_SIZE, true).await?;
+    if sniff(&bytes).is_some() {
+        return Err("Not a web page".to_string());
+    }
+    let page = parse_head(&decode_page(&bytes, &content_type));
+
+    let oembed = match page.oembed.as_deref().map(|href| page_url.join(href)) {
+        Some(Ok(endpoint)) => fetch_oembed(&client, policy, previews, endpoint).await,
+        _ => None,
+    }
+    .unwrap_or_default();
+
+    let (mut preview, image_url) = describe(&page, &oembed, &page_url);
+    // A preview without its image is still worth showing
+    preview.image = match image_url {
+        Some(image_url) => fetch_image(&client, policy, previews, image_url).await.ok(),
+        None => None,
+    };
+
+    if preview.title.is_none() && preview.description.is_none() && preview.image.is_none() {
+        return Ok(None);
+    }
+    Ok(Some(preview))
+}
+
+pub struct LinkPreviewState(pub Arc<LinkPreviews>);
+
+/// Fetch the OpenGraph, Twitter Card or oEmbed preview of a page, so that
+/// previews don't depend on CORS and the webview never loads anything from
+/// the linked site. Results are cached by URL; None when the page has
+/// nothing to show.
+#[tauri::command]
+pub async fn link_preview_fetch(
+    url: String,
+    policy: State<'_, FetchPolicyState>,
+    previews: State<'_, LinkPreviewState>,
+) -> Result<Option<LinkPreview>, String> {
+    let policy = policy.0.clone();
+    let previews = previews.0.clone();
+    let mut url = policy.check_url(&url)?;
+    url.set_fragment(None);
+    previews.check_host(&url)?;
+    let key = url.to_string();
+    if let Some(entry) = previews.cached(&key) {
+        return entry.result();
+    }
+
+    let client = policy
+        .client_builder(&[])
+        .redirect(reqwest::redirect::Policy::none())
+        .connect_timeout(CONNECT_TIMEOUT)
+        .user_agent(USER_AGENT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let result = tokio::time::timeout(FETCH_BUDGET, fetch_preview(&policy, &previews, client, url))
+        .await
+        .unwrap_or_else(|_| Err("The page took too long to load".to_string()));
+    previews.store(&key, &result);
+    result
+}
+
+#[tauri::command]
+pub async fn link_preview_get_config(
+    previews: State<'_, LinkPreviewState>,
+) -> Result<LinkPreviewConfig, String> {
+    Ok(previews.0.config())
+}
+
+#[tauri::command]
+pub async fn link_preview_set_config(
+    config: LinkPreviewConfig,
+    previews: State<'_, LinkPreviewState>,
+) -> Result<(), String> {
+    previews.0.update(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyed_digest_matches_hmac_sha256() {
+        // RFC 4231 test cases 2 and 6
+        assert_eq!(
+            keyed_digest(b"Jefe", "what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            keyed_digest(
+                &[0xaa; 131],
+                "Test Using Larger Than Block-Size Key - Hash Key First"
+            ),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+        assert_ne!(keyed_digest(b"key", "https://a/"), digest("https://a/"));
+    }
+
+    #[test]
+    fn entities_are_decoded() {
+        assert_eq!(
+            decode_entities(
+                "Fish &amp; chips &lt;b&gt; &quot;x&quot; &apos;y&#39; &#x263A;&hellip;"
+            ),
+            "Fish & chips <b> \"x\" 'y' ☺…"
+        );
+        // Unknown, unterminated and invalid references stay as written
+        assert_eq!(
+            decode_entities("&bogus; AT&T &#xZZ; &#1114112; &"),
+            "&bogus; AT&T &#xZZ; &#1114112; &"
+        );
+    }
+
+    #[test]
+    fn tag_end_skips_quoted_brackets() {
+        let html = r#"<meta content="a > b" name='x>y'>after"#;
+        assert_eq!(tag_end(html, 0), Some(html.len() - "after".len() - 1));
+        assert_eq!(tag_end(r#"<meta content="a > b"#, 0), None);
+    }
+
+    #[test]
+    fn attributes_are_lowercased_and_decoded() {
+        let attrs = attributes(
+            r#" Property="og:title" CONTENT='Fish &amp; chips' data-x=bare checked name=a name=b /"#,
+        );
+        assert_eq!(attrs["property"], "og:title");
+        assert_eq!(attrs["content"], "Fish & chips");
+        assert_eq!(attrs["data-x"], "bare");
+        assert_eq!(attrs["checked"], "");
+        assert_eq!(attrs["name"], "a");
+        assert_eq!(attrs.len(), 5);
+    }
+
+    #[test]
+    fn head_tags_are_read_up_to_the_body() {
+        let page = parse_head(
+            r#"<!DOCTYPE html><html><head>
+            <!-- <meta property="og:title" content="commented out"> -->
+            <script>document.write("<meta property='og:title' content='scripted'>")</script>
+            <title>Cats &amp; dogs</title>
+            <META Property="OG:Title" content="Greater > lesser">
+            <meta property="og:title" content="second">
+            <meta name="description" content="About">
+            <link rel="alternate" type="Application/JSON+oEmbed" href="/oembed?url=a&amp;format=json">
+            <link rel="alternate" type="application/json+oembed" href="/other">
+            </head><body><meta property="og:image" content="/in-body.png"></body>"#,
+        );
+        assert_eq!(page.meta["og:title"], "Greater > lesser");
+        assert_eq!(page.meta["description"], "About");
+        assert!(!page.meta.contains_key("og:image"));
+        assert_eq!(page.title.as_deref(), Some("Cats & dogs"));
+        assert_eq!(page.oembed.as_deref(), Some("/oembed?url=a&format=json"));
+    }
+
+    #[test]
+    fn opengraph_comes_before_twitter_and_oembed() {
+        let page_url = reqwest::Url::parse("https://example.com/a/post").unwrap();
+        let oembed = OEmbed {
+            title: Some("oEmbed title".to_string()),
+            provider_name: Some("Provider".to_string()),
+            thumbnail_url: Some("https://cdn.example.com/thumb.jpg".to_string()),
+        };
+        let describe_html =
+            |html: &str, oembed: &OEmbed| describe(&parse_head(html), oembed, &page_url);
+
+        let (preview, image) = describe_html(
+            r#"<title>Page</title>
+            <meta name="twitter:title" content="Twitter title">
+            <meta property="og:title" content="OG title">
+            <meta property="og:site_name" content="Site">
+            <meta name="twitter:image" content="/twitter.png">
+            <meta property="og:image" content="og.png">
+            <meta property="og:image:secure_url" content=" ">"#,
+            &oembed,
+        );
+        assert_eq!(preview.title.as_deref(), Some("OG title"));
+        assert_eq!(preview.site_name.as_deref(), Some("Site"));
+        assert_eq!(image.unwrap().as_str(), "https://example.com/a/og.png");
+
+        let (preview, image) = describe_html(
+            r#"<title>Page</title>
+            <meta name="twitter:title" content="Twitter title">
+            <meta name="twitter:image" content="/twitter.png">"#,
+            &oembed,
+        );
+        assert_eq!(preview.title.as_deref(), Some("Twitter title"));
+        assert_eq!(preview.site_name.as_deref(), Some("Provider"));
+        assert_eq!(image.unwrap().as_str(), "https://example.com/twitter.png");
+
+        let (preview, image) = describe_html("<title>Page</title>", &oembed);
+        assert_eq!(preview.title.as_deref(), Some("oEmbed title"));
+        assert_eq!(image.unwrap().as_str(), "https://cdn.example.com/thumb.jpg");
+
+        let (preview, image) = describe_html("<title>Page</title>", &OEmbed::default());
+        assert_eq!(preview.title.as_deref(), Some("Page"));
+        assert_eq!(preview.site_name, None);
+        assert_eq!(image, None);
+    }
+
+    #[tokio::test]
+    async fn oembed_links_to_literal_addresses_are_refused() {
+        let dir = std::env::temp_dir().join(format!("link-preview-ssrf-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let policy = Arc::new(FetchPolicy::load(dir.join("fetch.json")));
+        let previews = LinkPreviews::load(
+            dir.join("link-previews.json"),
+            dir.join("cache"),
+            Arc::new(Vault::load(&dir)),
+        );
+        let client = policy
+            .client_builder(&[])
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        let page_url = reqwest::Url::parse("https://example.com/post").unwrap();
+        for href in [
+            "http://169.254.169.254/latest/meta-data/",
+            "http://192.168.1.1/oembed?format=json",
+            "//[::1]:8080/oembed",
+        ] {
+            let html = format!(
+                r#"<head><link rel="alternate" type="application/json+oembed" href="{}"></head>"#,
+                href
+            );
+            let endpoint = page_url.join(&parse_head(&html).oembed.unwrap()).unwrap();
+            let error = get(
+                &client,
+                &policy,
+                &previews,
+                endpoint.clone(),
+                "application/json",
+            )
+            .await
+            .unwrap_err();
+            assert!(error.contains("local or private address"), "{}", error);
+            assert!(fetch_oembed(&client, &policy, &previews, endpoint)
+                .await
+                .is_none());
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,6 +1,43 @@
 // Prevents additional console window on Windows in release, DO NOT REMOVE!!
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+use app_lib::cli::{self, Command};
+
 fn main() {
-    app_lib::run();
+    let args = std::env::args_os()
+        .skip(1)
+        .map(|arg| arg.to_string_lossy().into_owned());
+    let cwd = std::env::current_dir().unwrap_or_default();
+    match cli::parse(args, &cwd) {
+        Ok(Command::Run(options)) => app_lib::run_with(options),
+        Ok(Command::Print(text)) => {
+            attach_parent_console();
+            println!("{}", text);
+        }
+        Err(e) => {
+            attach_parent_console();
+            eprintln!("obsidianirc: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Release builds use the GUI subsystem and start without a console, so
+/// borrow the one of the shell that started us for help and errors
+#[cfg(windows)]
+fn attach_parent_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+
+    // Fails harmlessly when there is no parent console, e.g. from Explorer
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
 }
+
+#[cfg(not(windows))]
+fn attach_parent_console() {}
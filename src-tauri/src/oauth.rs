@@ -0,0 +1,310 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+
+use crate::irc_url::percent_decode;
+
+/// Path the identity provider redirects to on the loopback listener
+const CALLBACK_PATH: &str = "/callback";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+/// Time a browser gets to send its request line and headers
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REQUEST_SIZE: usize = 16 * 1024;
+
+const DONE_PAGE: &str = "<!doctype html><meta charset=utf-8><title>Signed in</title>\
+<body style=\"font-family:sans-serif;text-align:center;margin-top:4em\">\
+<h1>You're signed in</h1><p>You can close this tab and return to ObsidianIRC.</p>";
+
+const FAILED_PAGE: &str = "<!doctype html><meta charset=utf-8><title>Sign-in failed</title>\
+<body style=\"font-family:sans-serif;text-align:center;margin-top:4em\">\
+<h1>Sign-in failed</h1><p>Close this tab and check ObsidianIRC for details.</p>";
+
+/// A login waiting for its redirect
+struct PendingLogin {
+    /// Taken by `oauth_loopback_wait`
+    listener: Option<TcpListener>,
+    cancel: Arc<Notify>,
+}
+
+/// Loopback listeners of logins in progress, by the frontend's login id
+#[derive(Default)]
+pub struct OAuthLoopbackState(Mutex<HashMap<String, PendingLogin>>);
+
+impl OAuthLoopbackState {
+    fn logins(&self) -> std::sync::MutexGuard<'_, HashMap<String, PendingLogin>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// What the identity provider sent to the redirect URI
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoopbackResult {
+    code: String,
+    state: String,
+}
+
+/// Parameters of a redirect to the callback path
+#[derive(Default)]
+struct Callback {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// The query of a `GET /callback?...` request line, or None for other requests
+fn parse_request_line(line: &str) -> Option<Callback> {
+    let mut parts = line.split(' ');
+    if parts.next()? != "GET" {
+        return None;
+    }
+    let target = parts.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    if path != CALLBACK_PATH {
+        return None;
+    }
+    let mut callback = Callback::default();
+    for pair in query.split('&') {
+        let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = percent_decode(&value.replace('+', " "));
+        match name {
+            "code" => callback.code = Some(value),
+            "state" => callback.state = Some(value),
+            "error" => callback.error = Some(value),
+            "error_description" => callback.error_description = Some(value),
+            _ => {}
+        }
+    }
+    Some(callback)
+}
+
+/// Read up to the end of the request headers and return the request line
+async fn read_request_line(stream: &mut TcpStream) -> Option<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+    let read = async {
+        while !buf.windows(4).any(|w| w == b"\r\n\r\n") && buf.len() < MAX_REQUEST_SIZE {
+            let n = stream.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+        }
+        Some(())
+    };
+    tokio::time::timeout(REQUEST_TIMEOUT, read).await.ok()??;
+    let text = String::from_utf8_lossy(&buf);
+    text.lines().next().map(str::to_string)
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\n\
+Cache-Control: no-store\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Serve the listener until a redirect carrying `state` arrives. Requests
+/// for other paths (favicons) and with a foreign state are turned away.
+async fn accept_redirect(listener: TcpListener, state: &str) -> Result<LoopbackResult, String> {
+    loop {
+        let (mut stream, _) = listener
+            .accept()
+            .await
+            .map_err(|e| format!("OAuth listener failed: {}", e))?;
+        let Some(line) = read_request_line(&mut stream).await else {
+            continue;
+        };
+        let Some(callback) = parse_request_line(&line) else {
+            respond(&mut stream, "404 Not Found", "").await;
+            continue;
+        };
+        if callback.state.as_deref() != Some(state) {
+            respond(&mut stream, "400 Bad Request", FAILED_PAGE).await;
+            continue;
+        }
+        if let Some(error) = callback.error {
+            respond(&mut stream, "200 OK", FAILED_PAGE).await;
+            return Err(match callback.error_description {
+                Some(description) => format!("{}: {}", error, description),
+                None => error,
+            });
+        }
+        let Some(code) = callback.code else {
+            respond(&mut stream, "200 OK", FAILED_PAGE).await;
+            return Err("OAuth callback returned no code".to_string());
+        };
+        respond(&mut stream, "200 OK", DONE_PAGE).await;
+        return Ok(LoopbackResult {
+            code,
+            state: state.to_string(),
+        });
+    }
+}
+
+/// Start listening for a login's redirect on a random loopback port
+/// (RFC 8252 section 7.3). Returns the redirect URI to put in the
+/// authorize URL.
+#[tauri::command]
+pub async fn oauth_loopback_listen(
+    login_id: String,
+    logins: State<'_, OAuthLoopbackState>,
+) -> Result<String, String> {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .map_err(|e| format!("Failed to start the OAuth listener: {}", e))?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    logins.logins().insert(
+        login_id,
+        PendingLogin {
+            listener: Some(listener),
+            cancel: Arc::new(Notify::new()),
+        },
+    );
+    Ok(format!("http://127.0.0.1:{}{}", port, CALLBACK_PATH))
+}
+
+/// Open `authorize_url` in the browser and wait for the redirect to the
+/// listener of `oauth_loopback_listen`
+#[tauri::command]
+pub async fn oauth_loopback_wait(
+    app: AppHandle,
+    login_id: String,
+    authorize_url: String,
+    state: String,
+    timeout_secs: Option<u64>,
+    logins: State<'_, OAuthLoopbackState>,
+) -> Result<LoopbackResult, String> {
+    let (listener, cancel) = {
+        let mut logins = logins.logins();
+        let login = logins.get_mut(&login_id).ok_or("The login was cancelled")?;
+        let listener = login
+            .listener
+            .take()
+            .ok_or("The login is already waiting")?;
+        (listener, login.cancel.clone())
+    };
+
+    if let Err(e) = app.opener().open_url(&authorize_url, None::<&str>) {
+        logins.logins().remove(&login_id);
+        return Err(format!("Failed to open the browser: {}", e));
+    }
+
+    let timeout = timeout_secs.map_or(DEFAULT_TIMEOUT, Duration::from_secs);
+    let result = tokio::select! {
+        result = tokio::time::timeout(timeout, accept_redirect(listener, &state)) => {
+            result.unwrap_or_else(|_| Err("The login timed out".to_string()))
+        }
+        _ = cancel.notified() => Err("The login was cancelled".to_string()),
+    };
+    logins.logins().remove(&login_id);
+    result
+}
+
+/// Stop waiting for a login; its `oauth_loopback_wait` fails with "cancelled"
+#[tauri::command]
+pub fn oauth_loopback_cancel(login_id: String, logins: State<'_, OAuthLoopbackState>) {
+    if let Some(login) = logins.logins().remove(&login_id) {
+        login.cancel.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_query_is_decoded() {
+        let callback =
+            parse_request_line("GET /callback?code=a%2Fb+c%2Bd&state=xyz&scope=openid HTTP/1.1")
+                .unwrap();
+        assert_eq!(callback.code.as_deref(), Some("a/b c+d"));
+        assert_eq!(callback.state.as_deref(), Some("xyz"));
+        assert!(callback.error.is_none());
+
+        let callback = parse_request_line(
+            "GET /callback?error=access_denied&error_description=User+said+%22no%22&state=xyz HTTP/1.1",
+        )
+        .unwrap();
+        assert_eq!(callback.error.as_deref(), Some("access_denied"));
+        assert_eq!(
+            callback.error_description.as_deref(),
+            Some("User said \"no\"")
+        );
+        assert!(callback.code.is_none());
+
+        let callback = parse_request_line("GET /callback HTTP/1.1").unwrap();
+        assert!(callback.code.is_none() && callback.state.is_none());
+    }
+
+    #[test]
+    fn other_requests_are_not_callbacks() {
+        assert!(parse_request_line("GET /favicon.ico HTTP/1.1").is_none());
+        assert!(parse_request_line("GET /callbacks?code=1 HTTP/1.1").is_none());
+        assert!(parse_request_line("GET /callback/extra?code=1 HTTP/1.1").is_none());
+        assert!(parse_request_line("POST /callback?code=1 HTTP/1.1").is_none());
+        assert!(parse_request_line("").is_none());
+    }
+
+    /// Send a request to the listener and return the response's status line
+    async fn request(port: u16, target: &str) -> String {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n", target);
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    #[tokio::test]
+    async fn redirect_needs_the_state() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let redirect = tokio::spawn(async move { accept_redirect(listener, "xyz").await });
+
+        assert_eq!(
+            request(port, "/favicon.ico").await,
+            "HTTP/1.1 404 Not Found"
+        );
+        assert_eq!(
+            request(port, "/callback?code=stolen&state=abc").await,
+            "HTTP/1.1 400 Bad Request"
+        );
+        assert_eq!(
+            request(port, "/callback?code=c0de&state=xyz").await,
+            "HTTP/1.1 200 OK"
+        );
+        let result = redirect.await.unwrap().unwrap();
+        assert_eq!(result.code, "c0de");
+        assert_eq!(result.state, "xyz");
+    }
+
+    #[tokio::test]
+    async fn redirect_reports_provider_errors() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let redirect = tokio::spawn(async move { accept_redirect(listener, "xyz").await });
+
+        request(
+            port,
+            "/callback?error=access_denied&error_description=Cancelled&state=xyz",
+        )
+        .await;
+        assert_eq!(
+            redirect.await.unwrap().unwrap_err(),
+            "access_denied: Cancelled"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
+use tokio::sync::Mutex;
+
+use crate::fetch_policy::{error_message, FetchPolicy, FetchPolicyState};
+use crate::secrets::SecretState;
+use crate::stats::now_millis;
+use crate::util::{blocking, hex};
+
+/// Secret store service holding refresh tokens, by server id
+const SECRET_SERVICE: &str = "oauth";
+/// Refresh access tokens that expire within this many seconds
+const EXPIRY_MARGIN_SECS: i64 = 60;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Identity provider of a server, as configured in the frontend
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthProvider {
+    issuer: String,
+    client_id: String,
+    scopes: Option<String>,
+    /// Set for providers without OIDC discovery
+    token_endpoint: Option<String>,
+}
+
+/// Tokens the frontend holds for a server
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentTokens {
+    access_token: Option<String>,
+    id_token: Option<String>,
+    refresh_token: Option<String>,
+    /// Seconds since the Unix epoch
+    expires_at: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FreshTokens {
+    access_token: String,
+    id_token: Option<String>,
+    expires_at: Option<i64>,
+    scope: Option<String>,
+    /// False when the current tokens were still good
+    refreshed: bool,
+}
+
+/// Sent as "oauth-relogin-required" when a server's tokens can't be renewed
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ReloginRequired {
+    server_id: String,
+    reason: String,
+}
+
+/// A refresh token kept in the secret store. Providers that rotate refresh
+/// tokens make the frontend's copy stale, so the stored one is used as long
+/// as the frontend still hands over the token it was derived from (`seed`);
+/// a different token means the user signed in again.
+#[derive(Serialize, Deserialize)]
+struct StoredRefresh {
+    refresh_token: String,
+    /// SHA-256 of the refresh token the frontend last handed over
+    seed: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    id_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+    scope: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenError {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OidcMetadata {
+    token_endpoint: String,
+}
+
+/// Keeps SASL bearer tokens fresh by redeeming refresh tokens at the
+/// provider's token endpoint
+#[derive(Default)]
+pub struct OAuthTokenState {
+    /// Token endpoints found by OIDC discovery, by issuer
+    discovery: Mutex<HashMap<String, String>>,
+    /// Held while refreshing so a rotating refresh token is redeemed once
+    refreshing: Mutex<()>,
+}
+
+fn digest(token: &str) -> String {
+    hex(&Sha256::digest(token.as_bytes()))
+}
+
+/// A client for `url` under the fetch policy. The user configured the
+/// provider, so its host may be on the intranet; redirects elsewhere are
+/// still checked.
+fn client(policy: &Arc<FetchPolicy>, url: &str) -> Result<reqwest::Client, String> {
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default();
+    policy
+        .client_builder(&[&host])
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+impl OAuthTokenState {
+    /// The token endpoint, from OIDC discovery unless configured
+    async fn token_endpoint(
+        &self,
+        policy: &Arc<FetchPolicy>,
+        provider: &OAuthProvider,
+    ) -> Result<String, String> {
+        if let Some(endpoint) = provider.token_endpoint.as_ref().filter(|e| !e.is_empty()) {
+            return Ok(endpoint.clone());
+        }
+        let issuer = provider.issuer.trim_end_matches('/').to_string();
+        if let Some(endpoint) = self.discovery.lock().await.get(&issuer) {
+            return Ok(endpoint.clone());
+        }
+        let url = format!("{}/.well-known/openid-configuration", issuer);
+        let response = client(policy, &url)?
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| format!("OIDC discovery failed at {}: {}", url, error_message(&e)))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "OIDC discovery failed ({}) at {}",
+                response.status(),
+                url
+            ));
+        }
+        let body = response.text().await.map_err(|e| e.to_string())?;
+        let metadata: OidcMetadata = serde_json::from_str(&body)
+            .map_err(|_| "OIDC metadata missing endpoints".to_string())?;
+        self.discovery
+            .lock()
+            .await
+            .insert(issuer, metadata.token_endpoint.clone());
+        Ok(metadata.token_endpoint)
+    }
+}
+
+/// Why a refresh failed
+enum RefreshError {
+    /// The provider rejected the refresh token; only a new login helps
+    Rejected(String),
+    /// Network or provider trouble; worth trying again later
+    Failed(String),
+}
+
+async fn redeem(
+    policy: &Arc<FetchPolicy>,
+    endpoint: &str,
+    provider: &OAuthProvider,
+    refresh_token: &str,
+) -> Result<TokenResponse, RefreshError> {
+    let mut form = vec![
+        ("grant_type", "refresh_token"),
+        ("refresh_token", refresh_token),
+        ("client_id", provider.client_id.as_str()),
+    ];
+    if let Some(scopes) = provider.scopes.as_deref().filter(|s| !s.trim().is_empty()) {
+        form.push(("scope", scopes));
+    }
+    let response = client(policy, endpoint)
+        .map_err(RefreshError::Failed)?
+        .post(endpoint)
+        .header("Accept", "application/json")
+        .form(&form)
+        .send()
+        .await
+        .map_err(|e| {
+            RefreshError::Failed(format!("Token refresh failed: {}", error_message(&e)))
+        })?;
+    let status = response.status();
+    let body = response
+        .text()
+        .await
+        .map_err(|e| RefreshError::Failed(e.to_string()))?;
+    if status.is_success() {
+        return serde_json::from_str(&body)
+            .map_err(|e| RefreshError::Failed(format!("Invalid token response: {}", e)));
+    }
+    // RFC 6749 section 5.2; invalid_grant covers expired and revoked tokens
+    match serde_json::from_str::<TokenError>(&body) {
+        Ok(error)
+            if matches!(
+                error.error.as_str(),
+                "invalid_grant" | "invalid_client" | "unauthorized_client"
+            ) =>
+        {
+            Err(RefreshError::Rejected(match error.error_description {
+                Some(description) => format!("{}: {}", error.error, description),
+                None => error.error,
+            }))
+        }
+        _ => Err(RefreshError::Failed(format!(
+            "Token refresh failed ({})",
+            status
+        ))),
+    }
+}
+
+fn relogin_required(app: &AppHandle, server_id: &str, reason: String) -> String {
+    let _ = app.emit(
+        "oauth-relogin-required",
+        ReloginRequired {
+            server_id: server_id.to_string(),
+            reason: reason.clone(),
+        },
+    );
+    reason
+}
+
+/// A bearer token for SASL that is good for at least another minute.
+///
+/// Returns the current tokens while they last; otherwise redeems the
+/// refresh token, keeping the rotated one in the secret store. Emits
+/// "oauth-relogin-required" when the provider won't issue new tokens.
+#[tauri::command]
+pub async fn oauth_access_token(
+    app: AppHandle,
+    server_id: String,
+    provider: OAuthProvider,
+    current: CurrentTokens,
+    tokens: State<'_, OAuthTokenState>,
+    secrets: State<'_, SecretState>,
+    policy: State<'_, FetchPolicyState>,
+) -> Result<FreshTokens, String> {
+    let now = (now_millis() / 1000) as i64;
+    if let (Some(access_token), Some(expires_at)) = (&current.access_token, current.expires_at) {
+        if expires_at > now + EXPIRY_MARGIN_SECS {
+            return Ok(FreshTokens {
+                access_token: access_token.clone(),
+                id_token: current.id_token,
+                expires_at: current.expires_at,
+                scope: None,
+                refreshed: false,
+            });
+        }
+    }
+
+    let _refreshing = tokens.refreshing.lock().await;
+    let store = secrets.0.clone();
+    let account = server_id.clone();
+    // A record the store can't read (the app is locked, or it was sealed
+    // with another key) counts as missing; the frontend's token still works
+    let stored = blocking(move || store.get(SECRET_SERVICE, &account).ok().flatten())
+        .await?
+        .and_then(|json| serde_json::from_str::<StoredRefresh>(&json).ok());
+
+    let Some(seed) = current.refresh_token.as_deref().filter(|t| !t.is_empty()) else {
+        return Err(relogin_required(
+            &app,
+            &server_id,
+            "The sign-in has expired and there is no refresh token".to_string(),
+        ));
+    };
+    let seed = digest(seed);
+    let refresh_token = match stored {
+        Some(stored) if stored.seed == seed => stored.refresh_token,
+        _ => current.refresh_token.clone().unwrap_or_default(),
+    };
+
+    let endpoint = tokens.token_endpoint(&policy.0, &provider).await?;
+    let response = match redeem(&policy.0, &endpoint, &provider, &refresh_token).await {
+        Ok(response) => response,
+        Err(RefreshError::Rejected(reason)) => {
+            let store = secrets.0.clone();
+            let account = server_id.clone();
+            let deleted = blocking(move || store.delete(SECRET_SERVICE, &account))
+                .await
+                .and_then(|deleted| deleted);
+            if let Err(e) = deleted {
+                log::warn!("Failed to forget the refresh token: {}", e);
+            }
+            return Err(relogin_required(&app, &server_id, reason));
+        }
+        Err(RefreshError::Failed(e)) => return Err(e),
+    };
+
+    let stored = StoredRefresh {
+        refresh_token: response.refresh_token.unwrap_or(refresh_token),
+        seed,
+    };
+    let json = serde_json::to_string(&stored).map_err(|e| e.to_string())?;
+    // The tokens were redeemed already, so they are returned even if the
+    // rotated refresh token can't be kept
+    let store = secrets.0.clone();
+    let saved = blocking(move || store.set(SECRET_SERVICE, &server_id, &json))
+        .await
+        .and_then(|saved| saved);
+    if let Err(e) = saved {
+        log::warn!("Failed to keep the refresh token: {}", e);
+    }
+
+    Ok(FreshTokens {
+        access_token: response.access_token,
+        id_token: response.id_token,
+        expires_at: response.expires_in.map(|secs| now + secs),
+        scope: response.scope,
+        refreshed: true,
+    })
+}
@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime, State};
+
+use crate::cli::{validate_profile, StartupOptions};
+use crate::util::{hex, load_json, save_json};
+
+/// Name the frontend shows for the profile used when none is given
+const DEFAULT_PROFILE: &str = "default";
+
+/// A file with this name next to the executable turns on portable mode
+const PORTABLE_MARKER: &str = "portable";
+
+/// Which profile this process runs, decided before the app is built because
+/// it changes the app identifier, and with it the single-instance identity
+#[derive(Clone, Debug)]
+pub struct Selection {
+    /// None for the default profile
+    name: Option<String>,
+    /// Root of all data in portable mode
+    portable_root: Option<PathBuf>,
+    /// Portable mode came from `--data-dir` rather than the marker file
+    explicit_data_dir: bool,
+}
+
+impl Selection {
+    pub fn from_options(options: &StartupOptions) -> Self {
+        Selection {
+            name: options
+                .profile
+                .clone()
+                .filter(|name| name != DEFAULT_PROFILE),
+            portable_root: options.data_dir.clone().or_else(portable_root_from_marker),
+            explicit_data_dir: options.data_dir.is_some(),
+        }
+    }
+
+    /// The default profile outside portable mode, which owns the system-wide
+    /// deep link registration and the data from before profiles existed
+    pub fn is_default(&self) -> bool {
+        self.name.is_none() && self.portable_root.is_none()
+    }
+
+    /// App identifier for this selection. The default profile keeps the bundle
+    /// identifier; every other one runs as its own single instance.
+    pub fn identifier(&self, base: &str) -> String {
+        let mut identifier = base.to_string();
+        if let Some(root) = &self.portable_root {
+            let digest = Sha256::digest(root.to_string_lossy().as_bytes());
+            identifier.push_str(".portable-");
+            identifier.push_str(&hex(&digest[..4]));
+        }
+        if let Some(name) = &self.name {
+            identifier.push_str(".profile-");
+            identifier.extend(name.chars().map(
+                |c| {
+                    if c.is_ascii_alphanumeric() {
+                        c
+                    } else {
+                        '-'
+                    }
+                },
+            ));
+        }
+        identifier
+    }
+
+    /// Work out where this profile keeps its data. `base_identifier` is the
+    /// bundle identifier from before `identifier` replaced it.
+    pub fn resolve<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        base_identifier: &str,
+    ) -> tauri::Result<Profile> {
+        let path = app.path();
+        let base = match (&self.portable_root, &self.name) {
+            (Some(root), _) => root.clone(),
+            (None, None) => path.app_data_dir()?,
+            // The identifier was changed, so find the default profile's
+            // directory from the bundle identifier
+            (None, Some(_)) => path.data_dir()?.join(base_identifier),
+        };
+
+        let mut profile = if self.is_default() {
+            // Where everything lived before profiles, so nothing moves
+            Profile {
+                name: DEFAULT_PROFILE.to_string(),
+                portable: false,
+                data_dir: path.app_data_dir()?,
+                config_dir: path.app_config_dir()?,
+                log_dir: path.app_log_dir()?,
+                certs_dir: PathBuf::new(),
+                webview_dir: None,
+                base_dir: base.clone(),
+                launch_data_dir: None,
+            }
+        } else {
+            let root = match &self.name {
+                Some(name) => base.join("profiles").join(name),
+                None => base.clone(),
+            };
+            Profile {
+                name: self
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+                portable: self.portable_root.is_some(),
+                config_dir: root.join("config"),
+                log_dir: root.join("app-logs"),
+                certs_dir: PathBuf::new(),
+                webview_dir: Some(root.join("webview")),
+                data_dir: root,
+                base_dir: base.clone(),
+                launch_data_dir: self
+                    .portable_root
+                    .clone()
+                    .filter(|_| self.explicit_data_dir),
+            }
+        };
+        profile.certs_dir = profile.data_dir.join("certs");
+        for dir in [&profile.data_dir, &profile.config_dir, &profile.certs_dir] {
+            fs::create_dir_all(dir)?;
+        }
+        Ok(profile)
+    }
+}
+
+/// WKWebView ignores the data directory and keys storage by a 16-byte
+/// identifier instead (macOS 14+), so derive a stable one from the directory
+pub fn data_store_identifier(webview_dir: &Path) -> [u8; 16] {
+    let digest = Sha256::digest(webview_dir.to_string_lossy().as_bytes());
+    let mut identifier = [0; 16];
+    identifier.copy_from_slice(&digest[..16]);
+    identifier
+}
+
+#[cfg(desktop)]
+fn portable_root_from_marker() -> Option<PathBuf> {
+    let exe = std::env::current_exe().ok()?;
+    let dir = exe.parent()?;
+    dir.join(PORTABLE_MARKER)
+        .is_file()
+        .then(|| dir.join("data"))
+}
+
+#[cfg(mobile)]
+fn portable_root_from_marker() -> Option<PathBuf> {
+    None
+}
+
+/// Where the running profile keeps its data
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub name: String,
+    pub portable: bool,
+    pub data_dir: PathBuf,
+    pub config_dir: PathBuf,
+    pub log_dir: PathBuf,
+    /// Client certificates for SASL EXTERNAL and friends
+    pub certs_dir: PathBuf,
+    /// Webview storage; None keeps the platform default of the default profile
+    #[serde(skip)]
+    pub webview_dir: Option<PathBuf>,
+    /// Holds the profile list and the other profiles
+    #[serde(skip)]
+    base_dir: PathBuf,
+    /// `--data-dir` to pass on when launching another profile
+    #[serde(skip)]
+    launch_data_dir: Option<PathBuf>,
+}
+
+impl Profile {
+    fn profiles_dir(&self) -> PathBuf {
+        self.base_dir.join("profiles")
+    }
+
+    fn settings_path(&self) -> PathBuf {
+        self.base_dir.join("profiles.json")
+    }
+
+    fn settings(&self) -> ProfileSettings {
+        load_json(&self.settings_path())
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct ProfileSettings {
+    /// Show the profile picker when started without `--profile`
+    ask_at_startup: bool,
+}
+
+pub struct ProfileState(pub Profile);
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileList {
+    current: String,
+    /// The default profile first, then the others by name
+    profiles: Vec<String>,
+    ask_at_startup: bool,
+}
+
+#[tauri::command]
+pub fn profile_current(profile: State<'_, ProfileState>) -> Profile {
+    profile.0.clone()
+}
+
+#[tauri::command]
+pub fn profile_list(profile: State<'_, ProfileState>) -> Result<ProfileList, String> {
+    let profile = &profile.0;
+    let mut names: Vec<String> = match fs::read_dir(profile.profiles_dir()) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|name| validate_profile(name).is_ok())
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(format!("Failed to list profiles: {}", e)),
+    };
+    names.sort_by_key(|name| name.to_lowercase());
+    names.insert(0, DEFAULT_PROFILE.to_string());
+    Ok(ProfileList {
+        current: profile.name.clone(),
+        profiles: names,
+        ask_at_startup: profile.settings().ask_at_startup,
+    })
+}
+
+#[tauri::command]
+pub fn profile_create(profile: State<'_, ProfileState>, name: String) -> Result<(), String> {
+    validate_profile(&name)?;
+    if name == DEFAULT_PROFILE {
+        return Err("The default profile always exists".to_string());
+    }
+    let dir = profile.0.profiles_dir().join(&name);
+    if dir.exists() {
+        return Err(format!("Profile {} already exists", name));
+    }
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create profile: {}", e))
+}
+
+/// Delete a profile with all its networks, logs, history and credentials
+#[tauri::command]
+pub fn profile_delete(profile: State<'_, ProfileState>, name: String) -> Result<(), String> {
+    validate_profile(&name)?;
+    if name == DEFAULT_PROFILE {
+        return Err("The default profile can't be deleted".to_string());
+    }
+    if name == profile.0.name {
+        return Err("Switch to another profile before deleting this one".to_string());
+    }
+    let dir = profile.0.profiles_dir().join(&name);
+    fs::remove_dir_all(&dir).map_err(|e| format!("Failed to delete profile: {}", e))
+}
+
+#[tauri::command]
+pub fn profile_set_ask_at_startup(
+    profile: State<'_, ProfileState>,
+    ask: bool,
+) -> Result<(), String> {
+    let profile = &profile.0;
+    let mut settings = profile.settings();
+    settings.ask_at_startup = ask;
+    save_json(&profile.settings_path(), &settings, "profile settings")
+}
+
+/// Start another profile. With `replace` this one quits once the other is
+/// running, otherwise both run side by side.
+#[cfg(desktop)]
+#[tauri::command]
+pub fn profile_launch(
+    app: AppHandle,
+    profile: State<'_, ProfileState>,
+    name: String,
+    replace: bool,
+) -> Result<(), String> {
+    validate_profile(&name)?;
+    let profile = &profile.0;
+    if name == profile.name {
+        return Ok(());
+    }
+    if name != DEFAULT_PROFILE && !profile.profiles_dir().join(&name).is_dir() {
+        return Err(format!("Profile {} doesn't exist", name));
+    }
+
+    let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    let mut command = std::process::Command::new(exe);
+    if name != DEFAULT_PROFILE {
+        command.arg("--profile").arg(&name);
+    }
+    if let Some(dir) = &profile.launch_data_dir {
+        command.arg("--data-dir").arg(dir);
+    }
+    command
+        .spawn()
+        .map_err(|e| format!("Failed to start profile {}: {}", name, e))?;
+    if replace {
+        app.exit(0);
+    }
+    Ok(())
+}
+
+#[cfg(mobile)]
+#[tauri::command]
+pub fn profile_launch(name: String, replace: bool) -> Result<(), String> {
+    let _ = (name, replace);
+    Err("Profiles can't be switched on mobile".to_string())
+}
@@ -0,0 +1,404 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use tauri::{Manager, State};
+use tauri_plugin_opener::OpenerExt;
+use tokio::sync::Mutex;
+
+use crate::profile::ProfileState;
+use crate::socket::SocketState;
+use crate::vault::VaultState;
+
+const DEFAULT_MAX_FILE_SIZE: u64 = 5 * 1024 * 1024;
+const DEFAULT_MAX_TOTAL_SIZE: u64 = 50 * 1024 * 1024;
+const REDACTED: &str = "<redacted>";
+
+/// SASL mechanism names are safe to log, everything else after AUTHENTICATE is a payload
+const SASL_MECHANISMS: &[&str] = &[
+    "PLAIN",
+    "EXTERNAL",
+    "ANONYMOUS",
+    "SCRAM-SHA-1",
+    "SCRAM-SHA-256",
+    "SCRAM-SHA-512",
+    "OAUTHBEARER",
+    "IRCV3BEARER",
+    "ECDSA-NIST256P-CHALLENGE",
+    "TOTP",
+    "WEBAUTHN",
+    "2FA-REQUIRED",
+];
+
+/// Direction of a logged line, relative to the client
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    In,
+    Out,
+}
+
+impl Direction {
+    fn marker(self) -> &'static str {
+        match self {
+            Direction::In => "<<",
+            Direction::Out => ">>",
+        }
+    }
+}
+
+/// A line waiting for the writer thread
+struct RawLine {
+    time: chrono::DateTime<chrono::Utc>,
+    direction: Direction,
+    line: String,
+}
+
+/// Size-rotated log file: `raw.log`, then `raw.1.log` (newest) to `raw.N.log` (oldest)
+struct RawLogWriter {
+    dir: PathBuf,
+    file: BufWriter<File>,
+    size: u64,
+    max_file_size: u64,
+    max_files: u64,
+}
+
+impl RawLogWriter {
+    fn open(dir: PathBuf, max_file_size: u64, max_total_size: u64) -> std::io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        let path = dir.join("raw.log");
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            dir,
+            file: BufWriter::new(file),
+            size,
+            max_file_size: max_file_size.max(1),
+            max_files: (max_total_size / max_file_size.max(1)).max(1),
+        })
+    }
+
+    fn rotated_path(&self, index: u64) -> PathBuf {
+        if index == 0 {
+            self.dir.join("raw.log")
+        } else {
+            self.dir.join(format!("raw.{}.log", index))
+        }
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.file.flush()?;
+        let _ = fs::remove_file(self.rotated_path(self.max_files - 1));
+        for index in (0..self.max_files - 1).rev() {
+            let from = self.rotated_path(index);
+            if from.exists() {
+                fs::rename(&from, self.rotated_path(index + 1))?;
+            }
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(self.rotated_path(0))?;
+        self.file = BufWriter::new(file);
+        self.size = 0;
+        Ok(())
+    }
+
+    fn write_line(&mut self, line: &RawLine) -> std::io::Result<()> {
+        let entry = format!(
+            "{} {} {}\n",
+            line.time.format("%Y-%m-%dT%H:%M:%S%.6fZ"),
+            line.direction.marker(),
+            redact(&line.line)
+        );
+        if self.size > 0 && self.size + entry.len() as u64 > self.max_file_size {
+            self.rotate()?;
+        }
+        self.file.write_all(entry.as_bytes())?;
+        self.size += entry.len() as u64;
+        Ok(())
+    }
+}
+
+/// Write lines until the log is switched off, flushing whenever the queue
+/// runs dry so the log is complete even if the app crashes
+fn run_writer(mut writer: RawLogWriter, rx: mpsc::Receiver<RawLine>) {
+    for line in rx.iter() {
+        let written = writer
+            .write_line(&line)
+            .and_then(|()| rx.try_iter().try_for_each(|line| writer.write_line(&line)))
+            .and_then(|()| writer.file.flush());
+        if let Err(e) = written {
+            log::warn!("Raw log write error: {}", e);
+            return;
+        }
+    }
+}
+
+/// Raw traffic log for one logical network, shared across its reconnects.
+///
+/// Lines go through a channel to a writer thread, so the socket tasks never
+/// wait on the disk.
+#[derive(Default)]
+pub struct RawLog {
+    writer: std::sync::Mutex<Option<mpsc::Sender<RawLine>>>,
+}
+
+impl RawLog {
+    /// Queue a line if logging is enabled; a failing log is switched off
+    pub fn record(&self, direction: Direction, data: &[u8]) {
+        let mut writer = match self.writer.lock() {
+            Ok(writer) => writer,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(tx) = writer.as_ref() {
+            let line = String::from_utf8_lossy(data);
+            let line = RawLine {
+                time: chrono::Utc::now(),
+                direction,
+                line: line.trim_end_matches(['\r', '\n']).to_string(),
+            };
+            if tx.send(line).is_err() {
+                *writer = None;
+            }
+        }
+    }
+
+    /// Switch logging off
+    pub(crate) fn stop(&self) {
+        self.set(None);
+    }
+
+    /// Start writing to `log` on a new thread, or switch logging off.
+    /// A previous writer finishes its queued lines and exits.
+    fn set(&self, log: Option<RawLogWriter>) {
+        let tx = log.map(|log| {
+            let (tx, rx) = mpsc::channel();
+            std::thread::spawn(move || run_writer(log, rx));
+            tx
+        });
+        let mut writer = match self.writer.lock() {
+            Ok(writer) => writer,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        *writer = tx;
+    }
+}
+
+/// Raw logs keyed by logical network
+pub struct RawLogState(pub(crate) Arc<Mutex<HashMap<String, Arc<RawLog>>>>);
+
+impl RawLogState {
+    /// Get the raw log for a network, creating a disabled one if needed
+    pub async fn for_network(&self, network: &str) -> Arc<RawLog> {
+        let mut logs = self.0.lock().await;
+        logs.entry(network.to_string()).or_default().clone()
+    }
+}
+
+/// Split a raw IRC line into tags and source, command, and parameters
+pub(crate) fn split_command(line: &str) -> (&str, &str, &str) {
+    // Skip tags and source to find where the command starts
+    let mut command_start = 0;
+    for prefix in ['@', ':'] {
+        if line[command_start..].starts_with(prefix) {
+            match line[command_start..].find(' ') {
+                Some(pos) => command_start += pos + 1,
+                None => return (line, "", ""),
+            }
+        }
+    }
+    let (head, rest) = line.split_at(command_start);
+    let (command, params) = rest.split_once(' ').unwrap_or((rest, ""));
+    (head, command, params)
+}
+
+/// Replace credentials in a raw IRC line with a placeholder
+pub fn redact(line: &str) -> String {
+    let (head, command, params) = split_command(line);
+
+    let redacted_params = match command.to_ascii_uppercase().as_str() {
+        "PASS" if !params.is_empty() => Some(REDACTED.to_string()),
+        "OPER" => params
+            .split_once(' ')
+            .map(|(name, _)| format!("{} {}", name, REDACTED)),
+        "AUTHENTICATE" => {
+            let payload = params.trim_start_matches(':');
+            let safe = payload.is_empty()
+                || payload == "+"
+                || payload == "*"
+                || SASL_MECHANISMS.contains(&payload.to_ascii_uppercase().as_str());
+            (!safe).then(|| REDACTED.to_string())
+        }
+        "PRIVMSG" | "NOTICE" => params.split_once(' ').and_then(|(target, text)| {
+            let nick = target.split('@').next().unwrap_or(target);
+            if !nick.eq_ignore_ascii_case("NickServ") {
+                return None;
+            }
+            redact_nickserv(text.trim_start_matches(':'))
+                .map(|text| format!("{} :{}", target, text))
+        }),
+        "NICKSERV" | "NS" => redact_nickserv(params.trim_start_matches(':')),
+        // EXTJWT replies carry a signed token as the last parameter
+        "EXTJWT" => params
+            .rsplit_once(' ')
+            .map(|(before, _)| format!("{} {}", before, REDACTED)),
+        _ => None,
+    };
+
+    match redacted_params {
+        Some(params) => format!("{}{} {}", head, command, params),
+        None => line.to_string(),
+    }
+}
+
+/// Redact the password in a NickServ IDENTIFY or REGISTER command
+fn redact_nickserv(text: &str) -> Option<String> {
+    let (verb, _) = text.split_once(' ')?;
+    if verb.eq_ignore_ascii_case("IDENTIFY") || verb.eq_ignore_ascii_case("REGISTER") {
+        Some(format!("{} {}", verb, REDACTED))
+    } else {
+        None
+    }
+}
+
+/// Directory that holds the raw logs of one network
+fn log_dir(app_handle: &tauri::AppHandle, network: &str) -> PathBuf {
+    let base = app_handle.state::<ProfileState>().0.log_dir.join("raw");
+    let name = network.split_once("://").map_or(network, |(_, rest)| rest);
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    base.join(name)
+}
+
+/// Start writing a redacted raw traffic log for a connection.
+/// Returns the directory the log is written to.
+///
+/// Raw logs stay plaintext for debugging, so they are refused with the app lock on.
+#[tauri::command]
+pub async fn start_raw_log(
+    client_id: String,
+    max_file_size: Option<u64>,
+    max_total_size: Option<u64>,
+    sockets: State<'_, SocketState>,
+    raw_logs: State<'_, RawLogState>,
+    vault: State<'_, VaultState>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    if vault.0.is_enabled() {
+        return Err("Raw logs are not available while the app lock is on".to_string());
+    }
+    let network = sockets.network_of(client_id).await;
+    let dir = log_dir(&app_handle, &network);
+    let writer = RawLogWriter::open(
+        dir.clone(),
+        max_file_size.unwrap_or(DEFAULT_MAX_FILE_SIZE),
+        max_total_size.unwrap_or(DEFAULT_MAX_TOTAL_SIZE),
+    )
+    .map_err(|e| format!("Failed to open raw log in {}: {}", dir.display(), e))?;
+
+    raw_logs.for_network(&network).await.set(Some(writer));
+    Ok(dir.to_string_lossy().into_owned())
+}
+
+/// Stop the raw traffic log for a connection
+#[tauri::command]
+pub async fn stop_raw_log(
+    client_id: String,
+    sockets: State<'_, SocketState>,
+    raw_logs: State<'_, RawLogState>,
+) -> Result<(), String> {
+    let network = sockets.network_of(client_id).await;
+    let logs = raw_logs.0.lock().await;
+    if let Some(log) = logs.get(&network) {
+        log.set(None);
+    }
+    Ok(())
+}
+
+/// Open the raw log folder of a connection, or of all connections, in the file manager
+#[tauri::command]
+pub async fn open_raw_log_folder(
+    client_id: Option<String>,
+    sockets: State<'_, SocketState>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let dir = match client_id {
+        Some(client_id) => log_dir(&app_handle, &sockets.network_of(client_id).await),
+        None => log_dir(&app_handle, ""),
+    };
+    open_folder(&app_handle, &dir)
+}
+
+fn open_folder(app_handle: &tauri::AppHandle, dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    app_handle
+        .opener()
+        .open_path(dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| format!("Failed to open {}: {}", dir.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_skips_tags_and_source() {
+        assert_eq!(split_command("PING :x"), ("", "PING", ":x"));
+        assert_eq!(
+            split_command("@time=1 :nick!u@h PRIVMSG #c :hi"),
+            ("@time=1 :nick!u@h ", "PRIVMSG", "#c :hi")
+        );
+        assert_eq!(split_command(":server 001"), (":server ", "001", ""));
+        assert_eq!(split_command(":lonely"), (":lonely", "", ""));
+    }
+
+    #[test]
+    fn redacts_passwords() {
+        assert_eq!(redact("PASS hunter2"), "PASS <redacted>");
+        assert_eq!(redact("OPER admin hunter2"), "OPER admin <redacted>");
+        assert_eq!(
+            redact("PRIVMSG NickServ :IDENTIFY me hunter2"),
+            "PRIVMSG NickServ :IDENTIFY <redacted>"
+        );
+        assert_eq!(
+            redact("privmsg nickserv@services.net :register hunter2 me@example.com"),
+            "privmsg nickserv@services.net :register <redacted>"
+        );
+        assert_eq!(redact("NS IDENTIFY hunter2"), "NS IDENTIFY <redacted>");
+        assert_eq!(
+            redact(":irc.example.net EXTJWT #chan * eyJhbGciOi"),
+            ":irc.example.net EXTJWT #chan * <redacted>"
+        );
+    }
+
+    #[test]
+    fn keeps_sasl_mechanisms_and_plain_traffic() {
+        assert_eq!(redact("AUTHENTICATE PLAIN"), "AUTHENTICATE PLAIN");
+        assert_eq!(redact("AUTHENTICATE +"), "AUTHENTICATE +");
+        assert_eq!(
+            redact("AUTHENTICATE bWUAbWUAaHVudGVyMg=="),
+            "AUTHENTICATE <redacted>"
+        );
+        assert_eq!(
+            redact("PRIVMSG #chan :IDENTIFY me"),
+            "PRIVMSG #chan :IDENTIFY me"
+        );
+        assert_eq!(
+            redact("PRIVMSG NickServ :INFO me"),
+            "PRIVMSG NickServ :INFO me"
+        );
+        assert_eq!(redact("PASS"), "PASS");
+    }
+}
@@ -0,0 +1,661 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use tauri::State;
+
+use crate::util::blocking;
+use crate::vault::Vault;
+
+const NONCE_LEN: usize = 24;
+/// Prefix of secrets encrypted with the app lock before they are stored
+const VAULT_PREFIX: &str = "vault:";
+
+/// Where secrets end up, decided on first use because probing the secret
+/// service can block
+enum Backend {
+    /// Secret Service, macOS Keychain or Windows Credential Manager
+    #[cfg(desktop)]
+    Keyring,
+    /// Encrypted file, for Linux sessions without a secret service, mobile and
+    /// portable profiles
+    File(SecretFile),
+}
+
+impl Backend {
+    fn name(&self) -> &'static str {
+        match self {
+            #[cfg(desktop)]
+            Backend::Keyring => "keyring",
+            Backend::File(_) => "file",
+        }
+    }
+}
+
+/// Passwords and tokens, kept out of the webview's localStorage.
+///
+/// With the app lock on, secrets are also encrypted with the vault before
+/// they reach the backend. While locked they can't be read, and changes wait
+/// in memory.
+///
+/// The keychain can't list what we stored in it, so the service and account
+/// of every secret are kept in an index next to the profile data; turning the
+/// app lock on or off re-stores each of them.
+pub struct SecretStore {
+    /// App identifier; keeps profiles apart in the shared OS keychain
+    namespace: String,
+    data_dir: PathBuf,
+    /// Keep everything in the data directory, so it can move between machines
+    portable: bool,
+    backend: OnceLock<Backend>,
+    vault: Arc<Vault>,
+    /// Service, account and new secret (None to delete) of changes made while locked
+    pending: Mutex<Vec<(String, String, Option<String>)>>,
+    /// Service and account of the stored secrets, loaded on first use
+    index: Mutex<Option<BTreeSet<(String, String)>>>,
+}
+
+impl SecretStore {
+    pub fn new(namespace: String, data_dir: &Path, portable: bool, vault: Arc<Vault>) -> Self {
+        SecretStore {
+            namespace,
+            data_dir: data_dir.to_path_buf(),
+            portable,
+            backend: OnceLock::new(),
+            vault,
+            pending: Mutex::new(Vec::new()),
+            index: Mutex::new(None),
+        }
+    }
+
+    fn backend(&self) -> &Backend {
+        self.backend.get_or_init(|| {
+            if self.portable {
+                log::info!("Portable profile, keeping secrets in an encrypted file");
+                return Backend::File(SecretFile::new(&self.data_dir, false));
+            }
+            #[cfg(desktop)]
+            if keyring_available(&self.namespace) {
+                return Backend::Keyring;
+            }
+            log::info!("No OS keychain available, keeping secrets in an encrypted file");
+            Backend::File(SecretFile::new(&self.data_dir, true))
+        })
+    }
+
+    #[cfg(desktop)]
+    fn entry(&self, service: &str, account: &str) -> Result<keyring::Entry, String> {
+        keyring::Entry::new(&format!("{}:{}", self.namespace, service), account)
+            .map_err(|e| format!("Keychain error: {}", e))
+    }
+
+    pub fn get(&self, service: &str, account: &str) -> Result<Option<String>, String> {
+        if self.vault.is_locked() {
+            return Err("The app is locked".to_string());
+        }
+        let pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((_, _, secret)) = pending
+            .iter()
+            .rev()
+            .find(|(s, a, _)| s == service && a == account)
+        {
+            return Ok(secret.clone());
+        }
+        drop(pending);
+
+        let Some(stored) = self.get_stored(service, account)? else {
+            return Ok(None);
+        };
+        match stored.strip_prefix(VAULT_PREFIX) {
+            Some(sealed) => self.unseal(sealed).map(Some),
+            // Stored before the app lock was turned on
+            None => Ok(Some(stored)),
+        }
+    }
+
+    fn unseal(&self, sealed: &str) -> Result<String, String> {
+        let sealed = BASE64
+            .decode(sealed)
+            .map_err(|_| "A stored secret is damaged".to_string())?;
+        let secret = self.vault.open(&sealed)?;
+        String::from_utf8(secret).map_err(|_| "A stored secret is damaged".to_string())
+    }
+
+    pub fn set(&self, service: &str, account: &str, secret: &str) -> Result<(), String> {
+        if !self.vault.is_enabled() {
+            return self.set_stored(service, account, secret);
+        }
+        match self.vault.seal(secret.as_bytes()) {
+            Some(sealed) => self.set_stored(
+                service,
+                account,
+                &format!("{}{}", VAULT_PREFIX, BASE64.encode(sealed)),
+            ),
+            None => {
+                self.hold(service, account, Some(secret));
+                Ok(())
+            }
+        }
+    }
+
+    pub fn delete(&self, service: &str, account: &str) -> Result<(), String> {
+        if self.vault.is_locked() {
+            self.hold(service, account, None);
+            return Ok(());
+        }
+        self.delete_stored(service, account)
+    }
+
+    fn hold(&self, service: &str, account: &str, secret: Option<&str>) {
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        pending.retain(|(s, a, _)| s != service || a != account);
+        pending.push((
+            service.to_string(),
+            account.to_string(),
+            secret.map(str::to_string),
+        ));
+    }
+
+    /// Store the changes made while the app was locked
+    pub fn flush_pending(&self) {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap_or_else(|e| e.into_inner()));
+        for (service, account, secret) in pending {
+            let stored = match &secret {
+                Some(secret) => self.set(&service, &account, secret),
+                None => self.delete(&service, &account),
+            };
+            if let Err(e) = stored {
+                log::error!("Failed to store secret {}/{}: {}", service, account, e);
+            }
+        }
+    }
+
+    /// Encrypt every stored secret with the vault, or decrypt them again.
+    /// The vault has to be unlocked.
+    pub fn convert(&self, encrypt: bool) -> Result<(), String> {
+        let mut keys = self.indexed();
+        match self.backend() {
+            #[cfg(desktop)]
+            Backend::Keyring => {}
+            Backend::File(file) => keys.extend(file.keys()?),
+        }
+        for (service, account) in keys {
+            let Some(stored) = self.get_stored(&service, &account)? else {
+                continue;
+            };
+            let converted = match (encrypt, stored.strip_prefix(VAULT_PREFIX)) {
+                (true, None) => {
+                    let sealed = self
+                        .vault
+                        .seal(stored.as_bytes())
+                        .ok_or("The app is locked")?;
+                    format!("{}{}", VAULT_PREFIX, BASE64.encode(sealed))
+                }
+                (false, Some(sealed)) => self.unseal(sealed)?,
+                _ => continue,
+            };
+            self.set_stored(&service, &account, &converted)
+                .map_err(|e| format!("Failed to convert secret {}/{}: {}", service, account, e))?;
+        }
+        Ok(())
+    }
+
+    fn indexed(&self) -> BTreeSet<(String, String)> {
+        let mut index = self.index.lock().unwrap_or_else(|e| e.into_inner());
+        index.get_or_insert_with(|| self.load_index()).clone()
+    }
+
+    fn index_path(&self) -> PathBuf {
+        self.data_dir.join("secrets-index.json")
+    }
+
+    fn load_index(&self) -> BTreeSet<(String, String)> {
+        fs::read(self.index_path())
+            .ok()
+            .and_then(|json| serde_json::from_slice(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Add or remove a secret in the index; only a missed re-store on the
+    /// next app lock change depends on it, so failures are just logged
+    fn update_index(&self, service: &str, account: &str, stored: bool) {
+        let mut index = self.index.lock().unwrap_or_else(|e| e.into_inner());
+        let index = index.get_or_insert_with(|| self.load_index());
+        let key = (service.to_string(), account.to_string());
+        let changed = if stored {
+            index.insert(key)
+        } else {
+            index.remove(&key)
+        };
+        if !changed {
+            return;
+        }
+        let saved = serde_json::to_vec(&*index)
+            .map_err(|e| e.to_string())
+            .and_then(|json| write_private(&self.index_path(), &json).map_err(|e| e.to_string()));
+        if let Err(e) = saved {
+            log::warn!("Failed to save the secrets index: {}", e);
+        }
+    }
+
+    fn get_stored(&self, service: &str, account: &str) -> Result<Option<String>, String> {
+        let stored = match self.backend() {
+            #[cfg(desktop)]
+            Backend::Keyring => match self.entry(service, account)?.get_password() {
+                Ok(secret) => Ok(Some(secret)),
+                Err(keyring::Error::NoEntry) => Ok(None),
+                Err(e) => Err(format!("Keychain error: {}", e)),
+            },
+            Backend::File(file) => file.get(service, account),
+        }?;
+        // Secrets stored before the index existed join it once they are read
+        if stored.is_some() {
+            self.update_index(service, account, true);
+        }
+        Ok(stored)
+    }
+
+    fn set_stored(&self, service: &str, account: &str, secret: &str) -> Result<(), String> {
+        match self.backend() {
+            #[cfg(desktop)]
+            Backend::Keyring => self
+                .entry(service, account)?
+                .set_password(secret)
+                .map_err(|e| format!("Keychain error: {}", e)),
+            Backend::File(file) => file.set(service, account, Some(secret)),
+        }?;
+        self.update_index(service, account, true);
+        Ok(())
+    }
+
+    fn delete_stored(&self, service: &str, account: &str) -> Result<(), String> {
+        match self.backend() {
+            #[cfg(desktop)]
+            Backend::Keyring => match self.entry(service, account)?.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+                Err(e) => Err(format!("Keychain error: {}", e)),
+            },
+            Backend::File(file) => file.set(service, account, None),
+        }?;
+        self.update_index(service, account, false);
+        Ok(())
+    }
+}
+
+/// Whether the platform keychain answers. On Linux this fails without a
+/// running secret service (headless sessions, minimal window managers).
+#[cfg(desktop)]
+fn keyring_available(namespace: &str) -> bool {
+    let probe = keyring::Entry::new(&format!("{}:probe", namespace), "probe")
+        .and_then(|entry| entry.get_password());
+    match probe {
+        Ok(_) | Err(keyring::Error::NoEntry) => true,
+        Err(e) => {
+            log::warn!("OS keychain unavailable: {}", e);
+            false
+        }
+    }
+}
+
+/// Secrets encrypted with XChaCha20-Poly1305 in the profile directory.
+///
+/// The key lives in a file readable only by the user, mixed with the machine
+/// id so a copied profile doesn't decrypt elsewhere; portable profiles leave
+/// the machine id out so they keep working on the next machine. This keeps
+/// secrets out of plain sight and out of backups of the webview storage, but
+/// it is no stronger than the user's file permissions.
+struct SecretFile {
+    path: PathBuf,
+    key_path: PathBuf,
+    bind_to_machine: bool,
+    /// Decrypted contents, loaded on first use
+    entries: Mutex<Option<BTreeMap<String, String>>>,
+}
+
+impl SecretFile {
+    fn new(data_dir: &Path, bind_to_machine: bool) -> Self {
+        SecretFile {
+            path: data_dir.join("secrets.bin"),
+            key_path: data_dir.join("secrets.key"),
+            bind_to_machine,
+            entries: Mutex::new(None),
+        }
+    }
+
+    fn entry_key(service: &str, account: &str) -> String {
+        format!("{}\n{}", service, account)
+    }
+
+    fn get(&self, service: &str, account: &str) -> Result<Option<String>, String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entries = self.loaded(&mut entries)?;
+        Ok(entries.get(&Self::entry_key(service, account)).cloned())
+    }
+
+    /// Service and account of every entry
+    fn keys(&self) -> Result<Vec<(String, String)>, String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entries = self.loaded(&mut entries)?;
+        Ok(entries
+            .keys()
+            .filter_map(|key| key.split_once('\n'))
+            .map(|(service, account)| (service.to_string(), account.to_string()))
+            .collect())
+    }
+
+    fn set(&self, service: &str, account: &str, secret: Option<&str>) -> Result<(), String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let entries = self.loaded(&mut entries)?;
+        let key = Self::entry_key(service, account);
+        match secret {
+            Some(secret) => {
+                entries.insert(key, secret.to_string());
+            }
+            None => {
+                if entries.remove(&key).is_none() {
+                    return Ok(());
+                }
+            }
+        }
+        self.save(entries)
+    }
+
+    fn loaded<'a>(
+        &self,
+        entries: &'a mut Option<BTreeMap<String, String>>,
+    ) -> Result<&'a mut BTreeMap<String, String>, String> {
+        if entries.is_none() {
+            *entries = Some(self.load()?);
+        }
+        Ok(entries.get_or_insert_with(BTreeMap::new))
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, String>, String> {
+        let data = match fs::read(&self.path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => return Err(format!("Failed to read secrets: {}", e)),
+        };
+        if data.len() < NONCE_LEN {
+            return Err("The secrets file is damaged".to_string());
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let nonce = XNonce::from_slice(nonce);
+        let mut plaintext = self
+            .cipher(self.bind_to_machine)?
+            .decrypt(nonce, ciphertext);
+        if plaintext.is_err() && !self.bind_to_machine {
+            // Written before the profile was portable; saved unbound from now on
+            plaintext = self.cipher(true)?.decrypt(nonce, ciphertext);
+        }
+        let plaintext = plaintext.map_err(|_| {
+            "Failed to decrypt secrets; was the profile copied from another machine?"
+        })?;
+        serde_json::from_slice(&plaintext)
+            .map_err(|e| format!("The secrets file is damaged: {}", e))
+    }
+
+    fn save(&self, entries: &BTreeMap<String, String>) -> Result<(), String> {
+        let plaintext = serde_json::to_vec(entries).map_err(|e| e.to_string())?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher(self.bind_to_machine)?
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| "Failed to encrypt secrets".to_string())?;
+        let mut data = nonce.to_vec();
+        data.extend_from_slice(&ciphertext);
+        write_private(&self.path, &data).map_err(|e| format!("Failed to save secrets: {}", e))
+    }
+
+    fn cipher(&self, bind_to_machine: bool) -> Result<XChaCha20Poly1305, String> {
+        let file_key = match fs::read(&self.key_path) {
+            Ok(key) => key,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let key = XChaCha20Poly1305::generate_key(&mut OsRng).to_vec();
+                write_private(&self.key_path, &key)
+                    .map_err(|e| format!("Failed to create the secrets key: {}", e))?;
+                key
+            }
+            Err(e) => return Err(format!("Failed to read the secrets key: {}", e)),
+        };
+        let mut hasher = Sha256::new();
+        hasher.update(&file_key);
+        if bind_to_machine {
+            hasher.update(machine_id());
+        }
+        XChaCha20Poly1305::new_from_slice(&hasher.finalize()).map_err(|e| e.to_string())
+    }
+}
+
+fn machine_id() -> Vec<u8> {
+    ["/etc/machine-id", "/var/lib/dbus/machine-id"]
+        .iter()
+        .find_map(|path| fs::read(path).ok())
+        .map(|id| String::from_utf8_lossy(&id).trim().as_bytes().to_vec())
+        .unwrap_or_default()
+}
+
+/// Replace a file with contents only the user can read
+pub(crate) fn write_private(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("tmp");
+    {
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        std::io::Write::write_all(&mut options.open(&tmp)?, data)?;
+    }
+    fs::rename(&tmp, path)
+}
+
+pub struct SecretState(pub Arc<SecretStore>);
+
+#[tauri::command]
+pub async fn secret_set(
+    service: String,
+    account: String,
+    secret: String,
+    secrets: State<'_, SecretState>,
+) -> Result<(), String> {
+    let store = secrets.0.clone();
+    blocking(move || store.set(&service, &account, &secret)).await?
+}
+
+#[tauri::command]
+pub async fn secret_get(
+    service: String,
+    account: String,
+    secrets: State<'_, SecretState>,
+) -> Result<Option<String>, String> {
+    let store = secrets.0.clone();
+    blocking(move || store.get(&service, &account)).await?
+}
+
+#[tauri::command]
+pub async fn secret_delete(
+    service: String,
+    account: String,
+    secrets: State<'_, SecretState>,
+) -> Result<(), String> {
+    let store = secrets.0.clone();
+    blocking(move || store.delete(&service, &account)).await?
+}
+
+/// A plaintext secret found in the webview's storage
+#[derive(Deserialize)]
+pub struct PlaintextSecret {
+    service: String,
+    account: String,
+    secret: String,
+}
+
+#[derive(Serialize)]
+pub struct SecretKey {
+    service: String,
+    account: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationReport {
+    /// "keyring" or "file"
+    backend: &'static str,
+    /// Stored and read back; the frontend may drop its plaintext copy
+    migrated: Vec<SecretKey>,
+    errors: Vec<String>,
+}
+
+/// Move plaintext secrets into the store. Each one is read back before it is
+/// reported as migrated, so the caller only deletes what is safe to delete.
+#[tauri::command]
+pub async fn secret_migrate(
+    secrets: Vec<PlaintextSecret>,
+    store: State<'_, SecretState>,
+) -> Result<MigrationReport, String> {
+    let store = store.0.clone();
+    blocking(move || {
+        let mut report = MigrationReport {
+            backend: store.backend().name(),
+            migrated: Vec::new(),
+            errors: Vec::new(),
+        };
+        for entry in secrets {
+            let stored = store
+                .set(&entry.service, &entry.account, &entry.secret)
+                .and_then(|()| store.get(&entry.service, &entry.account));
+            match stored {
+                Ok(Some(secret)) if secret == entry.secret => report.migrated.push(SecretKey {
+                    service: entry.service,
+                    account: entry.account,
+                }),
+                Ok(_) => report.errors.push(format!(
+                    "{}/{}: the stored secret didn't read back",
+                    entry.service, entry.account
+                )),
+                Err(e) => report
+                    .errors
+                    .push(format!("{}/{}: {}", entry.service, entry.account, e)),
+            }
+        }
+        report
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("secrets-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn file_round_trip() {
+        let dir = temp_dir("round-trip");
+        let file = SecretFile::new(&dir, true);
+        assert_eq!(file.get("server", "libera").unwrap(), None);
+        file.set("server", "libera", Some("hunter2")).unwrap();
+        file.set("oauth", "libera", Some("{\"token\":1}")).unwrap();
+        assert!(!fs::read(&file.path)
+            .unwrap()
+            .windows(7)
+            .any(|w| w == b"hunter2"));
+
+        let reopened = SecretFile::new(&dir, true);
+        assert_eq!(
+            reopened.get("server", "libera").unwrap().as_deref(),
+            Some("hunter2")
+        );
+        assert_eq!(
+            reopened.get("oauth", "libera").unwrap().as_deref(),
+            Some("{\"token\":1}")
+        );
+        reopened.set("server", "libera", None).unwrap();
+        assert_eq!(
+            SecretFile::new(&dir, true).get("server", "libera").unwrap(),
+            None
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn damaged_file_is_an_error() {
+        let dir = temp_dir("damaged");
+        SecretFile::new(&dir, true)
+            .set("server", "libera", Some("hunter2"))
+            .unwrap();
+        let path = dir.join("secrets.bin");
+        let mut data = fs::read(&path).unwrap();
+        *data.last_mut().unwrap() ^= 1;
+        fs::write(&path, data).unwrap();
+        assert!(SecretFile::new(&dir, true).get("server", "libera").is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn app_lock_reseals_stored_secrets() {
+        let dir = temp_dir("reseal");
+        let vault = Arc::new(Vault::load(&dir));
+        let store = SecretStore::new("test".to_string(), &dir, true, vault.clone());
+        store.set("server", "libera", "hunter2").unwrap();
+        store.set("oauth", "libera", "refresh").unwrap();
+
+        vault.enable_for_tests();
+        store.convert(true).unwrap();
+        let stored = store.get_stored("oauth", "libera").unwrap().unwrap();
+        assert!(stored.starts_with(VAULT_PREFIX));
+        assert_eq!(
+            store.get("oauth", "libera").unwrap().as_deref(),
+            Some("refresh")
+        );
+
+        store.convert(false).unwrap();
+        for (service, secret) in [("server", "hunter2"), ("oauth", "refresh")] {
+            let stored = store.get_stored(service, "libera").unwrap();
+            assert_eq!(stored.as_deref(), Some(secret));
+        }
+        assert_eq!(store.indexed().len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn portable_file_moves_to_unbound_key() {
+        let dir = temp_dir("portable");
+        SecretFile::new(&dir, true)
+            .set("server", "libera", Some("hunter2"))
+            .unwrap();
+        let portable = SecretFile::new(&dir, false);
+        assert_eq!(
+            portable.get("server", "libera").unwrap().as_deref(),
+            Some("hunter2")
+        );
+        portable.set("server", "oftc", Some("swordfish")).unwrap();
+
+        // Saved without the machine id, so only the file key is needed now
+        let data = fs::read(dir.join("secrets.bin")).unwrap();
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let plaintext = portable
+            .cipher(false)
+            .unwrap()
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .unwrap();
+        let entries: BTreeMap<String, String> = serde_json::from_slice(&plaintext).unwrap();
+        assert_eq!(entries.len(), 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
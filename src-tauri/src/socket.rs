@@ -1,11 +1,26 @@
 use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tauri::{Emitter, State};
+use tauri::{Emitter, Manager, State};
 use tokio::net::TcpStream;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+#[cfg(unix)]
+use tokio::net::UnixStream;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::sync::{Mutex, mpsc, oneshot};
 use tokio::task;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+use tokio_tungstenite::WebSocketStream;
+
+use crate::chatlog::ChatLogState;
+use crate::history::{HistoryFeed, HistoryState};
+use crate::irc_url::{IrcUrl, Scheme};
+use crate::rawlog::{Direction, RawLog, RawLogState};
+use crate::stats::{StatsState, TrafficStats};
+use crate::tap::{Tap, TapState};
 
 // Platform-specific TLS imports
 #[cfg(not(target_os = "android"))]
@@ -22,16 +37,41 @@ use std::sync::Arc as StdArc;
 #[cfg(target_os = "android")]
 use webpki_roots;
 
+/// Longest inbound line we buffer before truncating it.
+/// Comfortably above the IRCv3 limit of 8191 bytes of tags plus 512 of message.
+const MAX_LINE_BYTES: usize = 16 * 1024;
+
 /// Connection handle for managing write operations and shutdown
 #[derive(Debug)]
 pub struct ConnectionHandle {
     write_tx: mpsc::Sender<String>,
     shutdown_tx: Option<oneshot::Sender<()>>,
+    /// Logical network this connection belongs to, used to key its stats
+    pub(crate) network: String,
+    stats: Arc<TrafficStats>,
+}
+
+impl ConnectionHandle {
+    /// Number of lines waiting in the write queue
+    pub(crate) fn queue_depth(&self) -> u64 {
+        (self.write_tx.max_capacity() - self.write_tx.capacity()) as u64
+    }
 }
 
 /// Socket state to manage multiple connections
 pub struct SocketState(pub(crate) Arc<Mutex<HashMap<String, ConnectionHandle>>>);
 
+impl SocketState {
+    /// Resolve a client id to its network, or treat it as a network key already
+    pub(crate) async fn network_of(&self, client_id: String) -> String {
+        let connections = self.0.lock().await;
+        connections
+            .get(&client_id)
+            .map(|handle| handle.network.clone())
+            .unwrap_or(client_id)
+    }
+}
+
 /// Payload we send back to TS whenever we receive data
 #[derive(Serialize, Clone)]
 struct ReceivedPayload {
@@ -51,32 +91,82 @@ struct MessageData {
     data: Vec<u8>,
 }
 
+/// Everything besides TS that sees a connection's traffic
+#[derive(Clone)]
+struct Observers {
+    stats: Arc<TrafficStats>,
+    raw_log: Arc<RawLog>,
+    tap: Tap,
+    history: Arc<HistoryFeed>,
+}
+
+impl Observers {
+    /// A complete line arrived from the server
+    fn inbound(&self, data: &[u8]) {
+        self.raw_log.record(Direction::In, data);
+        self.tap.mirror(Direction::In, data);
+        self.history.inbound(data);
+    }
+
+    /// A line was written to the server
+    fn outbound(&self, data: &[u8]) {
+        self.stats.record_line_out(data.len());
+        self.raw_log.record(Direction::Out, data);
+        self.tap.mirror(Direction::Out, data);
+        self.history.outbound(data);
+    }
+}
+
+/// Emit one complete line to TS, counting it as received or dropped
+fn emit_line(app_handle: &tauri::AppHandle, client_id: &str, data: Vec<u8>, observers: &Observers) {
+    observers.inbound(&data);
+    let result = app_handle.emit("tcp-message", ReceivedPayload {
+        id: client_id.to_string(),
+        event: MessageEvent {
+            message: Some(MessageData { data }),
+            error: None,
+            connected: None,
+        },
+    });
+    if result.is_ok() {
+        observers.stats.record_line_in();
+    } else {
+        observers.stats.record_dropped();
+    }
+}
+
+/// Empty the buffer, keeping a trailing \r that may pair with the next read's \n
+fn clear_keeping_cr(buffer: &mut Vec<u8>) {
+    let keep_cr = buffer.last() == Some(&b'\r');
+    buffer.clear();
+    if keep_cr {
+        buffer.push(b'\r');
+    }
+}
+
 /// Read task for handling incoming data from the socket
 async fn read_task<R>(
     client_id: String,
     mut reader: R,
     app_handle: tauri::AppHandle,
     state: Arc<Mutex<HashMap<String, ConnectionHandle>>>,
+    observers: Observers,
 ) where
     R: AsyncReadExt + Unpin,
 {
+    let stats = &observers.stats;
     let mut read_buf = vec![0u8; 4096];
     let mut line_buffer = Vec::new();
+    // Set while skipping the remainder of a line that was truncated
+    let mut discarding = false;
 
     loop {
         match reader.read(&mut read_buf).await {
             Ok(0) => {
                 // Connection closed by server
                 // Emit any remaining partial data as a final message
-                if !line_buffer.is_empty() {
-                    let _ = app_handle.emit("tcp-message", ReceivedPayload {
-                        id: client_id.clone(),
-                        event: MessageEvent {
-                            message: Some(MessageData { data: line_buffer.clone() }),
-                            error: None,
-                            connected: None,
-                        },
-                    });
+                if !line_buffer.is_empty() && !discarding {
+                    emit_line(&app_handle, &client_id, line_buffer.clone(), &observers);
                 }
 
                 let _ = app_handle.emit("tcp-message", ReceivedPayload {
@@ -94,9 +184,22 @@ async fn read_task<R>(
                 break;
             }
             Ok(n) => {
+                stats.record_read(n);
+
                 // Append new data to line buffer
                 line_buffer.extend_from_slice(&read_buf[..n]);
 
+                // Skip the tail of an overlong line up to its terminator
+                if discarding {
+                    if let Some(pos) = line_buffer.windows(2).position(|w| w == b"\r\n") {
+                        line_buffer.drain(..pos + 2);
+                        discarding = false;
+                    } else {
+                        clear_keeping_cr(&mut line_buffer);
+                        continue;
+                    }
+                }
+
                 // Extract complete lines (ending with \r\n)
                 loop {
                     if let Some(pos) = line_buffer.windows(2).position(|w| w == b"\r\n") {
@@ -107,19 +210,22 @@ async fn read_task<R>(
                         line_buffer.drain(..pos + 2);
 
                         // Emit the complete line
-                        let _ = app_handle.emit("tcp-message", ReceivedPayload {
-                            id: client_id.clone(),
-                            event: MessageEvent {
-                                message: Some(MessageData { data: line_data }),
-                                error: None,
-                                connected: None,
-                            },
-                        });
+                        emit_line(&app_handle, &client_id, line_data, &observers);
                     } else {
                         // No complete line found, wait for more data
                         break;
                     }
                 }
+
+                // A server that never sends \r\n must not grow the buffer forever
+                if line_buffer.len() > MAX_LINE_BYTES {
+                    let mut line_data = line_buffer[..MAX_LINE_BYTES].to_vec();
+                    line_data.extend_from_slice(b"\r\n");
+                    clear_keeping_cr(&mut line_buffer);
+                    discarding = true;
+                    stats.record_truncated();
+                    emit_line(&app_handle, &client_id, line_data, &observers);
+                }
             }
             Err(e) => {
                 // Read error - emit error event and stop
@@ -146,9 +252,11 @@ async fn write_task<W>(
     mut writer: W,
     mut write_rx: mpsc::Receiver<String>,
     mut shutdown_rx: oneshot::Receiver<()>,
+    observers: Observers,
 ) where
     W: AsyncWriteExt + Unpin,
 {
+    let stats = &observers.stats;
     loop {
         tokio::select! {
             // Handle write commands
@@ -162,6 +270,7 @@ async fn write_task<W>(
 
                 if let Err(e) = writer.write_all(data_with_crlf.as_bytes()).await {
                     eprintln!("Write error: {}", e);
+                    stats.record_dropped();
                     break;
                 }
 
@@ -169,6 +278,8 @@ async fn write_task<W>(
                     eprintln!("Flush error: {}", e);
                     break;
                 }
+
+                observers.outbound(data_with_crlf.as_bytes());
             }
             // Handle shutdown signal
             _ = &mut shutdown_rx => {
@@ -177,121 +288,322 @@ async fn write_task<W>(
             }
         }
     }
+
+    // Anything still queued will never reach the server
+    write_rx.close();
+    while write_rx.try_recv().is_ok() {
+        stats.record_dropped();
+    }
+}
+
+/// Read task for IRC over WebSocket, where each frame carries one line
+async fn websocket_read_task<S>(
+    client_id: String,
+    mut stream: SplitStream<WebSocketStream<S>>,
+    app_handle: tauri::AppHandle,
+    state: Arc<Mutex<HashMap<String, ConnectionHandle>>>,
+    observers: Observers,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let stats = &observers.stats;
+    let mut error = None;
+
+    while let Some(frame) = stream.next().await {
+        let data = match frame {
+            Ok(WsMessage::Text(text)) => text.as_bytes().to_vec(),
+            Ok(WsMessage::Binary(data)) => data,
+            Ok(WsMessage::Close(_)) => break,
+            // Pings are answered by tungstenite itself
+            Ok(_) => continue,
+            Err(e) => {
+                error = Some(format!("Read error: {}", e));
+                break;
+            }
+        };
+        stats.record_read(data.len());
+
+        // Frames carry lines without terminators; TS expects them like TCP lines
+        let mut line = data;
+        while matches!(line.last(), Some(b'\r' | b'\n')) {
+            line.pop();
+        }
+        if line.len() > MAX_LINE_BYTES {
+            line.truncate(MAX_LINE_BYTES);
+            stats.record_truncated();
+        }
+        line.extend_from_slice(b"\r\n");
+        emit_line(&app_handle, &client_id, line, &observers);
+    }
+
+    let _ = app_handle.emit("tcp-message", ReceivedPayload {
+        id: client_id.clone(),
+        event: MessageEvent {
+            message: None,
+            error,
+            connected: Some(false),
+        },
+    });
+
+    // Remove connection from state
+    let mut connections = state.lock().await;
+    connections.remove(&client_id);
+}
+
+/// Write task for IRC over WebSocket, sending one frame per line
+async fn websocket_write_task<S>(
+    mut sink: SplitSink<WebSocketStream<S>, WsMessage>,
+    mut write_rx: mpsc::Receiver<String>,
+    mut shutdown_rx: oneshot::Receiver<()>,
+    observers: Observers,
+    binary: bool,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let stats = &observers.stats;
+    loop {
+        tokio::select! {
+            Some(data) = write_rx.recv() => {
+                let line = data.trim_end_matches(['\r', '\n']);
+                let frame = if binary {
+                    WsMessage::Binary(line.as_bytes().to_vec())
+                } else {
+                    WsMessage::Text(line.to_string())
+                };
+
+                if let Err(e) = sink.send(frame).await {
+                    eprintln!("Write error: {}", e);
+                    stats.record_dropped();
+                    break;
+                }
+
+                observers.outbound(format!("{}\r\n", line).as_bytes());
+            }
+            _ = &mut shutdown_rx => {
+                let _ = sink.close().await;
+                break;
+            }
+        }
+    }
+
+    // Anything still queued will never reach the server
+    write_rx.close();
+    while write_rx.try_recv().is_ok() {
+        stats.record_dropped();
+    }
+}
+
+/// Everything the read and write tasks of a new connection need
+struct Tasks {
+    client_id: String,
+    app_handle: tauri::AppHandle,
+    state: Arc<Mutex<HashMap<String, ConnectionHandle>>>,
+    observers: Observers,
+    write_rx: mpsc::Receiver<String>,
+    shutdown_rx: oneshot::Receiver<()>,
+}
+
+impl Tasks {
+    /// Run the line-based read and write tasks over a byte stream
+    fn spawn_stream<S>(self, stream: S)
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let Tasks { client_id, app_handle, state, observers, write_rx, shutdown_rx } = self;
+        let (reader, writer) = tokio::io::split(stream);
+        let observers_read = observers.clone();
+        task::spawn(async move {
+            read_task(client_id, reader, app_handle, state, observers_read).await;
+        });
+        task::spawn(async move {
+            write_task(writer, write_rx, shutdown_rx, observers).await;
+        });
+    }
+
+    /// Do the WebSocket handshake over a stream, then run frame-based tasks
+    async fn spawn_websocket<S>(self, stream: S, url: &str) -> Result<(), String>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
+        let mut request = url
+            .into_client_request()
+            .map_err(|e| format!("Invalid WebSocket URL {}: {}", url, e))?;
+        request.headers_mut().insert(
+            "Sec-WebSocket-Protocol",
+            HeaderValue::from_static("text.ircv3.net, binary.ircv3.net"),
+        );
+        let (websocket, response) = tokio_tungstenite::client_async(request, stream)
+            .await
+            .map_err(|e| format!("WebSocket handshake failed: {}", e))?;
+        let binary = response
+            .headers()
+            .get("Sec-WebSocket-Protocol")
+            .is_some_and(|protocol| protocol == "binary.ircv3.net");
+
+        let Tasks { client_id, app_handle, state, observers, write_rx, shutdown_rx } = self;
+        let (sink, stream) = websocket.split();
+        let observers_read = observers.clone();
+        task::spawn(async move {
+            websocket_read_task(client_id, stream, app_handle, state, observers_read).await;
+        });
+        task::spawn(async move {
+            websocket_write_task(sink, write_rx, shutdown_rx, observers, binary).await;
+        });
+        Ok(())
+    }
+}
+
+/// TLS handshake with the platform's TLS stack
+#[cfg(not(target_os = "android"))]
+async fn tls_connect(
+    host: &str,
+    tcp_stream: TcpStream,
+) -> Result<tokio_native_tls::TlsStream<TcpStream>, String> {
+    let connector = TlsConnector::from(
+        NativeTlsConnector::builder()
+            .build()
+            .map_err(|e| format!("Failed to create TLS connector: {}", e))?
+    );
+
+    connector
+        .connect(host, tcp_stream)
+        .await
+        .map_err(|e| format!("TLS handshake failed: {}", e))
+}
+
+/// TLS handshake with rustls and the webpki roots
+#[cfg(target_os = "android")]
+async fn tls_connect(
+    host: &str,
+    tcp_stream: TcpStream,
+) -> Result<tokio_rustls::client::TlsStream<TcpStream>, String> {
+    // Create rustls config with webpki roots
+    let root_store = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(root_store)
+        .with_no_client_auth();
+
+    let connector = TlsConnector::from(StdArc::new(config));
+
+    let server_name = ServerName::try_from(host.to_string())
+        .map_err(|_| format!("Invalid DNS name: {}", host))?;
+
+    connector
+        .connect(server_name, tcp_stream)
+        .await
+        .map_err(|e| format!("TLS handshake failed: {}", e))
+}
+
+/// A freshly opened socket, before any TLS or WebSocket handshake
+enum Connected {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+/// Open a Unix domain socket, explaining the usual failures
+#[cfg(unix)]
+async fn unix_connect(path: &str) -> Result<UnixStream, String> {
+    UnixStream::connect(path).await.map_err(|e| match e.kind() {
+        std::io::ErrorKind::PermissionDenied => format!(
+            "Permission denied for socket {}: your user needs write access to it",
+            path
+        ),
+        std::io::ErrorKind::NotFound => format!("Socket {} does not exist", path),
+        std::io::ErrorKind::ConnectionRefused => {
+            format!("Nothing is listening on socket {}", path)
+        }
+        _ => format!("Failed to connect to socket {}: {}", path, e),
+    })
 }
 
 /// Connect to IRC server with real TCP/TLS implementation
+///
+/// `network` identifies the logical network across reconnects; it defaults
+/// to the address. `ws://` and `wss://` addresses speak IRC over WebSocket,
+/// `unix:///path/to/socket` connects to a local Unix domain socket.
 #[tauri::command]
 pub async fn connect(
     client_id: String,
     address: String,
+    network: Option<String>,
     state: State<'_, SocketState>,
     app_handle: tauri::AppHandle,
 ) -> Result<(), String> {
     // Parse the address to determine protocol and extract host:port
-    let (use_tls, host, port) = parse_address(&address)?;
+    let url = parse_address(&address)?;
+    let (scheme, host, port) = (url.scheme, url.host.as_str(), url.port);
+    // Never key stats and logs by an address that may carry a password
+    let network = network.unwrap_or_else(|| url.server_address());
+
+    // Create the TCP or Unix socket connection
+    let stream = match scheme {
+        #[cfg(unix)]
+        Scheme::Unix => Connected::Unix(unix_connect(&url.path).await?),
+        #[cfg(not(unix))]
+        Scheme::Unix => {
+            return Err("Unix sockets are not supported on this platform".to_string());
+        }
+        _ => Connected::Tcp(
+            TcpStream::connect((host, port))
+                .await
+                .map_err(|e| format!("Failed to connect to {}: {}", url.server_address(), e))?,
+        ),
+    };
 
-    // Create TCP connection
-    let tcp_stream = TcpStream::connect(format!("{}:{}", host, port))
-        .await
-        .map_err(|e| format!("Failed to connect to {}:{}: {}", host, port, e))?;
+    let stats = app_handle.state::<StatsState>().for_network(&network).await;
+    let observers = Observers {
+        stats: stats.clone(),
+        raw_log: app_handle.state::<RawLogState>().for_network(&network).await,
+        tap: app_handle.state::<TapState>().tap(&client_id, &network).await,
+        history: Arc::new(HistoryFeed::new(
+            &network,
+            app_handle.state::<HistoryState>().0.clone(),
+            app_handle.state::<ChatLogState>().0.clone(),
+        )),
+    };
 
     // Create channels for write operations
     let (write_tx, write_rx) = mpsc::channel::<String>(100);
     let (shutdown_tx, shutdown_rx) = oneshot::channel();
 
-    // Handle TLS if needed
-    if use_tls {
-        // Create TLS connection based on platform
-        #[cfg(not(target_os = "android"))]
-        {
-            let connector = TlsConnector::from(
-                NativeTlsConnector::builder()
-                    .build()
-                    .map_err(|e| format!("Failed to create TLS connector: {}", e))?
-            );
-
-            let tls_stream = connector
-                .connect(&host, tcp_stream)
-                .await
-                .map_err(|e| format!("TLS handshake failed: {}", e))?;
-
-            // Split the TLS stream using tokio::io::split
-            let (reader, writer) = tokio::io::split(tls_stream);
-
-            // Spawn read task
-            let client_id_read = client_id.clone();
-            let app_handle_read = app_handle.clone();
-            let state_clone = state.0.clone();
-            task::spawn(async move {
-                read_task(client_id_read, reader, app_handle_read, state_clone).await;
-            });
-
-            // Spawn write task
-            task::spawn(async move {
-                write_task(writer, write_rx, shutdown_rx).await;
-            });
+    let tasks = Tasks {
+        client_id: client_id.clone(),
+        app_handle: app_handle.clone(),
+        state: state.0.clone(),
+        observers,
+        write_rx,
+        shutdown_rx,
+    };
+    // The handshake runs over our own TLS stream, so WebSocket gets the same TLS setup
+    let ws_url = format!("{}{}", url.server_address(), url.path);
+    match (scheme, stream) {
+        #[cfg(unix)]
+        (_, Connected::Unix(unix_stream)) => tasks.spawn_stream(unix_stream),
+        (Scheme::Ircs, Connected::Tcp(tcp_stream)) => {
+            tasks.spawn_stream(tls_connect(host, tcp_stream).await?)
         }
-
-        #[cfg(target_os = "android")]
-        {
-            // Create rustls config with webpki roots
-            let root_store = rustls::RootCertStore {
-                roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
-            };
-
-            let config = rustls::ClientConfig::builder()
-                .with_root_certificates(root_store)
-                .with_no_client_auth();
-
-            let connector = TlsConnector::from(StdArc::new(config));
-
-            let server_name = ServerName::try_from(host.clone())
-                .map_err(|_| format!("Invalid DNS name: {}", host))?;
-
-            let tls_stream = connector
-                .connect(server_name, tcp_stream)
-                .await
-                .map_err(|e| format!("TLS handshake failed: {}", e))?;
-
-            // Split the TLS stream using tokio::io::split
-            let (reader, writer) = tokio::io::split(tls_stream);
-
-            // Spawn read task
-            let client_id_read = client_id.clone();
-            let app_handle_read = app_handle.clone();
-            let state_clone = state.0.clone();
-            task::spawn(async move {
-                read_task(client_id_read, reader, app_handle_read, state_clone).await;
-            });
-
-            // Spawn write task
-            task::spawn(async move {
-                write_task(writer, write_rx, shutdown_rx).await;
-            });
+        (Scheme::Ws, Connected::Tcp(tcp_stream)) => {
+            tasks.spawn_websocket(tcp_stream, &ws_url).await?
         }
-    } else {
-        // Plain TCP - use into_split for owned halves
-        let (reader, writer) = tcp_stream.into_split();
-
-        // Spawn read task
-        let client_id_read = client_id.clone();
-        let app_handle_read = app_handle.clone();
-        let state_clone = state.0.clone();
-        task::spawn(async move {
-            read_task(client_id_read, reader, app_handle_read, state_clone).await;
-        });
-
-        // Spawn write task
-        task::spawn(async move {
-            write_task(writer, write_rx, shutdown_rx).await;
-        });
+        (Scheme::Wss, Connected::Tcp(tcp_stream)) => {
+            let tls_stream = tls_connect(host, tcp_stream).await?;
+            tasks.spawn_websocket(tls_stream, &ws_url).await?
+        }
+        (_, Connected::Tcp(tcp_stream)) => tasks.spawn_stream(tcp_stream),
     }
+    stats.record_connect();
 
     // Store the connection handle
     let mut connections = state.0.lock().await;
     connections.insert(client_id.clone(), ConnectionHandle {
         write_tx,
         shutdown_tx: Some(shutdown_tx),
+        network,
+        stats,
     });
 
     // Emit connected event
@@ -307,33 +619,12 @@ pub async fn connect(
     Ok(())
 }
 
-/// Parse address string to extract protocol, host, and port
-fn parse_address(address: &str) -> Result<(bool, String, u16), String> {
-    if let Some(stripped) = address.strip_prefix("ircs://") {
-        let (host, port) = parse_host_port(stripped, 6697)?;
-        Ok((true, host, port))
-    } else if let Some(stripped) = address.strip_prefix("irc://") {
-        let (host, port) = parse_host_port(stripped, 6667)?;
-        Ok((false, host, port))
+/// Parse an address; one without a scheme is taken as plain IRC
+fn parse_address(address: &str) -> Result<IrcUrl, String> {
+    if address.contains("://") {
+        IrcUrl::parse(address)
     } else {
-        // Assume plain IRC if no protocol specified
-        let (host, port) = parse_host_port(address, 6667)?;
-        Ok((false, host, port))
-    }
-}
-
-/// Parse host:port string with default port fallback
-fn parse_host_port(host_port: &str, default_port: u16) -> Result<(String, u16), String> {
-    if let Some((host, port_str)) = host_port.rsplit_once(':') {
-        // Check if this is actually a valid port number
-        if let Ok(port) = port_str.parse::<u16>() {
-            Ok((host.to_string(), port))
-        } else {
-            // If port parsing fails, treat the whole thing as hostname
-            Ok((host_port.to_string(), default_port))
-        }
-    } else {
-        Ok((host_port.to_string(), default_port))
+        IrcUrl::parse(&format!("irc://{}", address))
     }
 }
 
@@ -371,18 +662,18 @@ pub async fn send(
     state: State<'_, SocketState>,
 ) -> Result<(), String> {
     // Extract write_tx without holding the mutex across .await
-    let write_tx = {
+    let handle = {
         let connections = state.0.lock().await;
         connections
             .get(&client_id)
-            .map(|handle| handle.write_tx.clone())
+            .map(|handle| (handle.write_tx.clone(), handle.stats.clone()))
     };
 
-    if let Some(write_tx) = write_tx {
-        write_tx
-            .send(data)
-            .await
-            .map_err(|e| format!("Failed to send data: {}", e))?;
+    if let Some((write_tx, stats)) = handle {
+        write_tx.send(data).await.map_err(|e| {
+            stats.record_dropped();
+            format!("Failed to send data: {}", e)
+        })?;
         Ok(())
     } else {
         Err(format!("No connection found for client_id: {}", client_id))
@@ -0,0 +1,201 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::State;
+use tokio::sync::Mutex;
+
+use crate::socket::SocketState;
+
+/// Traffic counters for one logical network.
+///
+/// A single instance is shared by every connection made to the same network,
+/// so the numbers keep accumulating across reconnects.
+#[derive(Debug, Default)]
+pub struct TrafficStats {
+    bytes_in: AtomicU64,
+    bytes_out: AtomicU64,
+    lines_in: AtomicU64,
+    lines_out: AtomicU64,
+    lines_dropped: AtomicU64,
+    lines_truncated: AtomicU64,
+    connected_at: AtomicU64,
+    connects: AtomicU64,
+    last_activity: AtomicU64,
+}
+
+/// Milliseconds since the Unix epoch
+pub(crate) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+impl TrafficStats {
+    /// Record a (re)connect; the first one is not counted as a reconnect
+    pub fn record_connect(&self) {
+        self.connected_at.store(now_millis(), Ordering::Relaxed);
+        self.connects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record raw bytes read from the socket
+    pub fn record_read(&self, bytes: usize) {
+        self.bytes_in.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.last_activity.store(now_millis(), Ordering::Relaxed);
+    }
+
+    /// Record a complete line delivered to the frontend
+    pub fn record_line_in(&self) {
+        self.lines_in.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a line written to the socket
+    pub fn record_line_out(&self, bytes: usize) {
+        self.bytes_out.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.lines_out.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_dropped(&self) {
+        self.lines_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_truncated(&self) {
+        self.lines_truncated.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self, network: &str, write_queue_depth: Option<u64>) -> StatsSnapshot {
+        let connected_at = self.connected_at.load(Ordering::Relaxed);
+        let last_activity = self.last_activity.load(Ordering::Relaxed);
+        StatsSnapshot {
+            network: network.to_string(),
+            connected: write_queue_depth.is_some(),
+            bytes_in: self.bytes_in.load(Ordering::Relaxed),
+            bytes_out: self.bytes_out.load(Ordering::Relaxed),
+            lines_in: self.lines_in.load(Ordering::Relaxed),
+            lines_out: self.lines_out.load(Ordering::Relaxed),
+            lines_dropped: self.lines_dropped.load(Ordering::Relaxed),
+            lines_truncated: self.lines_truncated.load(Ordering::Relaxed),
+            connected_at: (connected_at > 0).then_some(connected_at),
+            reconnects: self.connects.load(Ordering::Relaxed).saturating_sub(1),
+            last_activity: (last_activity > 0).then_some(last_activity),
+            write_queue_depth: write_queue_depth.unwrap_or(0),
+        }
+    }
+}
+
+/// Point-in-time copy of a network's counters, as sent to TS
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSnapshot {
+    network: String,
+    connected: bool,
+    bytes_in: u64,
+    bytes_out: u64,
+    lines_in: u64,
+    lines_out: u64,
+    lines_dropped: u64,
+    lines_truncated: u64,
+    connected_at: Option<u64>,
+    reconnects: u64,
+    last_activity: Option<u64>,
+    write_queue_depth: u64,
+}
+
+impl StatsSnapshot {
+    fn accumulate(&mut self, other: &StatsSnapshot) {
+        self.connected |= other.connected;
+        self.bytes_in += other.bytes_in;
+        self.bytes_out += other.bytes_out;
+        self.lines_in += other.lines_in;
+        self.lines_out += other.lines_out;
+        self.lines_dropped += other.lines_dropped;
+        self.lines_truncated += other.lines_truncated;
+        self.reconnects += other.reconnects;
+        self.write_queue_depth += other.write_queue_depth;
+        self.connected_at = match (self.connected_at, other.connected_at) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.last_activity = self.last_activity.max(other.last_activity);
+    }
+}
+
+/// Stats for every connection plus their sum
+#[derive(Serialize, Clone)]
+pub struct AllStats {
+    connections: Vec<StatsSnapshot>,
+    total: StatsSnapshot,
+}
+
+/// Traffic stats keyed by logical network, kept after disconnects
+pub struct StatsState(pub(crate) Arc<Mutex<HashMap<String, Arc<TrafficStats>>>>);
+
+impl StatsState {
+    /// Get the counters for a network, creating them on first connect
+    pub async fn for_network(&self, network: &str) -> Arc<TrafficStats> {
+        let mut stats = self.0.lock().await;
+        stats
+            .entry(network.to_string())
+            .or_insert_with(|| Arc::new(TrafficStats::default()))
+            .clone()
+    }
+}
+
+/// Get traffic stats for a connection, by client id or network key
+#[tauri::command]
+pub async fn connection_stats(
+    client_id: String,
+    sockets: State<'_, SocketState>,
+    stats: State<'_, StatsState>,
+) -> Result<StatsSnapshot, String> {
+    let live = {
+        let connections = sockets.0.lock().await;
+        connections
+            .get(&client_id)
+            .map(|handle| (handle.network.clone(), handle.queue_depth()))
+    };
+    let (network, depth) = match live {
+        Some((network, depth)) => (network, Some(depth)),
+        None => (client_id, None),
+    };
+
+    let stats = stats.0.lock().await;
+    stats
+        .get(&network)
+        .map(|s| s.snapshot(&network, depth))
+        .ok_or_else(|| format!("No stats found for: {}", network))
+}
+
+/// Get traffic stats for every known network plus an aggregate
+#[tauri::command]
+pub async fn all_connection_stats(
+    sockets: State<'_, SocketState>,
+    stats: State<'_, StatsState>,
+) -> Result<AllStats, String> {
+    let mut depths: HashMap<String, u64> = HashMap::new();
+    {
+        let connections = sockets.0.lock().await;
+        for handle in connections.values() {
+            *depths.entry(handle.network.clone()).or_default() += handle.queue_depth();
+        }
+    }
+
+    let stats = stats.0.lock().await;
+    let mut connections: Vec<StatsSnapshot> = stats
+        .iter()
+        .map(|(network, s)| s.snapshot(network, depths.get(network).copied()))
+        .collect();
+    connections.sort_by(|a, b| a.network.cmp(&b.network));
+
+    let mut total = StatsSnapshot {
+        network: "*".to_string(),
+        ..Default::default()
+    };
+    for snapshot in &connections {
+        total.accumulate(snapshot);
+    }
+
+    Ok(AllStats { connections, total })
+}
@@ -0,0 +1,139 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use tokio::sync::{broadcast, Mutex};
+
+use crate::rawlog::{redact, split_command, Direction};
+use crate::stats::now_millis;
+
+/// How many lines a slow subscriber may fall behind before it starts skipping
+const TAP_CAPACITY: usize = 1024;
+
+/// One mirrored line of raw traffic
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TapEvent {
+    client_id: String,
+    network: String,
+    direction: Direction,
+    timestamp: u64,
+    /// Upper-cased command or numeric, for filtering
+    pub(crate) command: String,
+    line: String,
+}
+
+/// Mirror of a connection's traffic for debugging subscribers.
+///
+/// Sending is skipped entirely when nobody is subscribed, so the main
+/// delivery path only pays for a receiver count check.
+#[derive(Clone)]
+pub struct Tap {
+    client_id: String,
+    network: String,
+    connection: broadcast::Sender<TapEvent>,
+    all: broadcast::Sender<TapEvent>,
+}
+
+impl Tap {
+    pub fn mirror(&self, direction: Direction, data: &[u8]) {
+        if self.connection.receiver_count() == 0 && self.all.receiver_count() == 0 {
+            return;
+        }
+
+        let line = String::from_utf8_lossy(data);
+        let line = line.trim_end_matches(['\r', '\n']);
+        let (_, command, _) = split_command(line);
+        let event = TapEvent {
+            client_id: self.client_id.clone(),
+            network: self.network.clone(),
+            direction,
+            timestamp: now_millis(),
+            command: command.to_ascii_uppercase(),
+            line: redact(line),
+        };
+
+        let _ = self.connection.send(event.clone());
+        let _ = self.all.send(event);
+    }
+}
+
+/// Broadcast channels per logical network plus one carrying every connection
+pub struct TapState {
+    all: broadcast::Sender<TapEvent>,
+    networks: Mutex<HashMap<String, broadcast::Sender<TapEvent>>>,
+}
+
+impl Default for TapState {
+    fn default() -> Self {
+        Self {
+            all: broadcast::channel(TAP_CAPACITY).0,
+            networks: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl TapState {
+    async fn sender(&self, network: &str) -> broadcast::Sender<TapEvent> {
+        let mut networks = self.networks.lock().await;
+        networks
+            .entry(network.to_string())
+            .or_insert_with(|| broadcast::channel(TAP_CAPACITY).0)
+            .clone()
+    }
+
+    /// Create the tap for a new connection
+    pub async fn tap(&self, client_id: &str, network: &str) -> Tap {
+        Tap {
+            client_id: client_id.to_string(),
+            network: network.to_string(),
+            connection: self.sender(network).await,
+            all: self.all.clone(),
+        }
+    }
+
+    /// Subscribe to one network's traffic, including its future reconnects
+    pub async fn subscribe(&self, network: &str) -> broadcast::Receiver<TapEvent> {
+        self.sender(network).await.subscribe()
+    }
+
+    /// Subscribe to the traffic of every connection
+    pub fn subscribe_all(&self) -> broadcast::Receiver<TapEvent> {
+        self.all.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tap() -> (Tap, broadcast::Receiver<TapEvent>) {
+        let (connection, rx) = broadcast::channel(TAP_CAPACITY);
+        let tap = Tap {
+            client_id: "client".to_string(),
+            network: "irc.example.net".to_string(),
+            connection,
+            all: broadcast::channel(TAP_CAPACITY).0,
+        };
+        (tap, rx)
+    }
+
+    #[test]
+    fn mirrors_redacted_lines() {
+        let (tap, mut rx) = tap();
+        tap.mirror(Direction::Out, b"pass hunter2\r\n");
+        let event = rx.try_recv().unwrap();
+        assert_eq!(event.command, "PASS");
+        assert_eq!(event.line, "pass <redacted>");
+        assert_eq!(event.direction, Direction::Out);
+
+        tap.mirror(Direction::In, b"@msgid=1 :srv 001 me :Welcome\r\n");
+        assert_eq!(rx.try_recv().unwrap().command, "001");
+    }
+
+    #[test]
+    fn skips_work_without_subscribers() {
+        let (tap, rx) = tap();
+        drop(rx);
+        tap.mirror(Direction::In, b"PING :x\r\n");
+        assert_eq!(tap.connection.len(), 0);
+    }
+}
@@ -0,0 +1,500 @@
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::ipc::{InvokeBody, Request};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt};
+use tokio::sync::Notify;
+
+use crate::download::{sanitize_filename, sniff};
+use crate::fetch_policy::{error_message, FetchPolicyState};
+use crate::image_meta;
+use crate::irc_url::percent_decode;
+use crate::stats::now_millis;
+#[cfg(target_os = "android")]
+use crate::util::blocking;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest wait for the upload policy, which is a small JSON document
+const INFO_TIMEOUT: Duration = Duration::from_secs(15);
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+const CHUNK_SIZE: usize = 64 * 1024;
+
+pub struct UploadState {
+    /// Cancel signals of uploads in progress, by the frontend's upload id
+    uploads: Mutex<HashMap<String, Arc<Notify>>>,
+    /// Files the frontend may read and upload, by handle. Handles come from
+    /// the file dialog, window drops and staging, so no command takes a
+    /// path from the webview.
+    files: Mutex<HashMap<String, PathBuf>>,
+    /// Copies made for uploading: resized images and files picked in the
+    /// webview. Each is removed once uploaded, and the folder at startup.
+    staging_dir: PathBuf,
+}
+
+impl UploadState {
+    pub fn new(staging_dir: PathBuf) -> Self {
+        let _ = std::fs::remove_dir_all(&staging_dir);
+        Self {
+            uploads: Mutex::new(HashMap::new()),
+            files: Mutex::new(HashMap::new()),
+            staging_dir,
+        }
+    }
+
+    fn uploads(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Notify>>> {
+        self.uploads.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn files(&self) -> std::sync::MutexGuard<'_, HashMap<String, PathBuf>> {
+        self.files.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// A handle for `path`. It ends in the file name, which the frontend
+    /// shows and checks the extension of.
+    pub(crate) fn register(&self, path: PathBuf) -> String {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "upload".to_string());
+        let handle = format!(
+            "{:x}-{:x}/{}",
+            now_millis(),
+            NEXT.fetch_add(1, Ordering::Relaxed),
+            filename
+        );
+        self.files().insert(handle.clone(), path);
+        handle
+    }
+
+    /// The file behind a handle
+    pub(crate) fn resolve(&self, handle: &str) -> Result<PathBuf, String> {
+        self.files()
+            .get(handle)
+            .cloned()
+            .ok_or_else(|| "The file is no longer available for upload".to_string())
+    }
+
+    /// Forget a handle, removing the file if it's a staged copy
+    pub(crate) fn release(&self, handle: &str) {
+        if let Some(path) = self.files().remove(handle) {
+            self.discard(&path);
+        }
+    }
+
+    /// A fresh path for a staged copy named `filename`
+    pub(crate) fn stage_path(&self, filename: &str) -> Result<PathBuf, String> {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let dir = self.staging_dir.join(format!(
+            "{}-{}",
+            now_millis(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        let filename = if filename.trim().is_empty() {
+            "upload"
+        } else {
+            filename
+        };
+        Ok(dir.join(sanitize_filename(filename)))
+    }
+
+    /// Remove a staged copy; files elsewhere are the user's and stay
+    fn discard(&self, path: &Path) {
+        if path.starts_with(&self.staging_dir) {
+            if let Some(dir) = path.parent() {
+                let _ = std::fs::remove_dir_all(dir);
+            }
+        }
+    }
+}
+
+/// The filehost's upload policy from GET /upload/info, as in mediaUpload.ts
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct UploadInfo {
+    /// Bytes; 0 for no limit
+    max_size: u64,
+    /// With the leading dot (".png"); empty for any
+    allowed_extensions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct UploadResponse {
+    saved_url: Option<String>,
+}
+
+/// Sent as "upload-progress" while the file goes up
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct UploadProgress {
+    upload_id: String,
+    sent: u64,
+    total: u64,
+}
+
+/// Counts the bytes handed to the request body and reports them now and then
+struct Progress {
+    app: AppHandle,
+    upload_id: String,
+    total: u64,
+    /// Bytes sent and when they were last reported
+    sent: Mutex<(u64, Instant)>,
+}
+
+impl Progress {
+    fn advance(&self, bytes: u64) {
+        let mut sent = self.sent.lock().unwrap_or_else(|e| e.into_inner());
+        sent.0 += bytes;
+        if sent.1.elapsed() >= PROGRESS_INTERVAL || sent.0 >= self.total {
+            sent.1 = Instant::now();
+            self.emit(sent.0);
+        }
+    }
+
+    fn emit(&self, sent: u64) {
+        let _ = self.app.emit(
+            "upload-progress",
+            UploadProgress {
+                upload_id: self.upload_id.clone(),
+                sent: sent.min(self.total),
+                total: self.total,
+            },
+        );
+    }
+}
+
+/// The policy, or None when the filehost has no /upload/info (older
+/// deployments), in which case the server alone decides
+async fn fetch_info(client: &reqwest::Client, base: &str) -> Option<UploadInfo> {
+    let response = client
+        .get(format!("{}/upload/info", base))
+        .header(reqwest::header::ACCEPT, "application/json")
+        .timeout(INFO_TIMEOUT)
+        .send()
+        .await
+        .ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    serde_json::from_str(&response.text().await.ok()?).ok()
+}
+
+/// The same checks as `validateFileAgainstInfo`, with its messages
+fn validate(filename: &str, size: u64, info: &UploadInfo) -> Result<(), String> {
+    if info.max_size > 0 && size > info.max_size {
+        return Err(format!(
+            "File is too large ({:.1} MB > {:.0} MB).",
+            size as f64 / 1024.0 / 1024.0,
+            info.max_size as f64 / 1024.0 / 1024.0
+        ));
+    }
+    if !info.allowed_extensions.is_empty() {
+        let extension = filename
+            .rfind('.')
+            .map(|dot| filename[dot..].to_lowercase())
+            .unwrap_or_default();
+        if !info
+            .allowed_extensions
+            .iter()
+            .any(|allowed| allowed.to_lowercase() == extension)
+        {
+            let shown = if extension.is_empty() {
+                "(none)"
+            } else {
+                extension.as_str()
+            };
+            return Err(format!("File type \"{}\" is not allowed.", shown));
+        }
+    }
+    Ok(())
+}
+
+/// A multipart part that reads the file as the request goes out
+fn file_part(
+    source: impl AsyncRead + Unpin + Send + 'static,
+    filename: &str,
+    mime: &str,
+    size: u64,
+    progress: Arc<Progress>,
+) -> Result<reqwest::multipart::Part, String> {
+    let chunks = stream::unfold(source, move |mut source| {
+        let progress = progress.clone();
+        async move {
+            let mut buf = vec![0u8; CHUNK_SIZE];
+            match source.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(n) => {
+                    buf.truncate(n);
+                    progress.advance(n as u64);
+                    Some((Ok(buf), source))
+                }
+                Err(e) => Some((Err(e), source)),
+            }
+        }
+    });
+    reqwest::multipart::Part::stream_with_length(reqwest::Body::wrap_stream(chunks), size)
+        .file_name(filename.to_string())
+        .mime_str(mime)
+        .map_err(|e| e.to_string())
+}
+
+async fn upload(
+    app: &AppHandle,
+    upload_id: &str,
+    path: &Path,
+    filehost_url: &str,
+    bearer_token: &str,
+    strip_metadata: bool,
+) -> Result<String, String> {
+    let base = filehost_url.trim_end_matches('/');
+    let policy = app.state::<FetchPolicyState>().0.clone();
+    let url = policy.check_url(&format!("{}/upload", base))?;
+    let client = policy
+        .client_builder(&[])
+        .connect_timeout(CONNECT_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let metadata =
+        std::fs::metadata(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if !metadata.is_file() {
+        return Err(format!("{} is not a file", path.display()));
+    }
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "upload".to_string());
+
+    let mut file = tokio::fs::File::open(path)
+        .await
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut head = [0u8; 32];
+    let head_len = file.read(&mut head).await.map_err(|e| e.to_string())?;
+    let head = &head[..head_len];
+    let mime = sniff(head)
+        .map(|(mime, _)| mime)
+        .unwrap_or("application/octet-stream");
+
+    // Cleaned images go up from memory; everything else streams from disk
+    let stripped = if strip_metadata && image_meta::is_supported(head) {
+        let bytes = image_meta::read_image(path).await?;
+        tauri::async_runtime::spawn_blocking(move || image_meta::strip(&bytes))
+            .await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| format!("Couldn't remove the image metadata: {}", e))?
+    } else {
+        None
+    };
+    let size = stripped
+        .as_ref()
+        .map_or(metadata.len(), |bytes| bytes.len() as u64);
+
+    let info = fetch_info(&client, base).await;
+    if let Some(info) = &info {
+        validate(&filename, size, info)?;
+    }
+
+    // Backends with /upload/info read "file"; older ones only know the
+    // legacy "image" field, and get it instead of both so the file goes up
+    // once either way
+    let field = if info.is_some() { "file" } else { "image" };
+    let progress = Arc::new(Progress {
+        app: app.clone(),
+        upload_id: upload_id.to_string(),
+        total: size,
+        sent: Mutex::new((0, Instant::now())),
+    });
+    progress.emit(0);
+    let part = match stripped {
+        Some(bytes) => file_part(Cursor::new(bytes), &filename, mime, size, progress)?,
+        None => {
+            file.rewind().await.map_err(|e| e.to_string())?;
+            file_part(file, &filename, mime, size, progress)?
+        }
+    };
+    let form = reqwest::multipart::Form::new().part(field, part);
+
+    let response = client
+        .post(url)
+        .bearer_auth(bearer_token)
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|e| error_message(&e))?;
+    let status = response.status();
+    let body = response.text().await.map_err(|e| error_message(&e))?;
+    if !status.is_success() {
+        return Err(if body.trim().is_empty() {
+            status.to_string()
+        } else {
+            body
+        });
+    }
+    let response: UploadResponse =
+        serde_json::from_str(&body).map_err(|_| "Backend returned invalid JSON".to_string())?;
+    let saved_url = response
+        .saved_url
+        .ok_or("Backend response did not include saved_url")?;
+    Ok(format!("{}{}", base, saved_url))
+}
+
+/// Stream a local file to the server's filehost with its EXTJWT token,
+/// checking it against the filehost's /upload/info policy first. Reports
+/// "upload-progress" events under `upload_id` and returns the absolute URL
+/// of the saved file. With `strip_metadata`, JPEG, PNG, WebP and HEIF
+/// images lose their EXIF, XMP and IPTC metadata on the way. The handle is
+/// used up, and a staged copy removed, either way.
+#[tauri::command]
+pub async fn upload_file(
+    app: AppHandle,
+    upload_id: String,
+    handle: String,
+    filehost_url: String,
+    bearer_token: String,
+    strip_metadata: bool,
+    uploads: State<'_, UploadState>,
+) -> Result<String, String> {
+    let path = uploads.resolve(&handle)?;
+    let cancel = Arc::new(Notify::new());
+    uploads.uploads().insert(upload_id.clone(), cancel.clone());
+    let result = tokio::select! {
+        result = upload(
+            &app,
+            &upload_id,
+            &path,
+            &filehost_url,
+            &bearer_token,
+            strip_metadata,
+        ) => result,
+        _ = cancel.notified() => Err("Upload cancelled".to_string()),
+    };
+    uploads.uploads().remove(&upload_id);
+    uploads.release(&handle);
+    result
+}
+
+/// Stop an `upload_file` in progress; it fails with "Upload cancelled"
+#[tauri::command]
+pub fn upload_cancel(upload_id: String, uploads: State<'_, UploadState>) {
+    if let Some(cancel) = uploads.uploads().remove(&upload_id) {
+        cancel.notify_one();
+    }
+}
+
+/// Copy a file picked in the webview to the staging area and return its
+/// handle, so it can go through `image_shrink` and `upload_file` like one on
+/// disk. The contents are the raw request body and the name is the
+/// percent-encoded `x-file-name` header.
+#[tauri::command]
+pub async fn upload_stage(
+    request: Request<'_>,
+    uploads: State<'_, UploadState>,
+) -> Result<String, String> {
+    let InvokeBody::Raw(bytes) = request.body() else {
+        return Err("Expected the file contents".to_string());
+    };
+    let filename = request
+        .headers()
+        .get("x-file-name")
+        .and_then(|name| name.to_str().ok())
+        .map(percent_decode)
+        .unwrap_or_default();
+    let path = uploads.stage_path(&filename)?;
+    tokio::fs::write(&path, bytes)
+        .await
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    Ok(uploads.register(path))
+}
+
+/// Copy a document picked on Android, which comes as a content:// URI, to
+/// the staging area
+#[cfg(target_os = "android")]
+async fn stage_content_uri(uploads: &UploadState, uri: String) -> Result<PathBuf, String> {
+    let name_uri = uri.clone();
+    let name = blocking(move || crate::content_uri_name(&name_uri))
+        .await?
+        .unwrap_or_default();
+    let path = uploads.stage_path(&name)?;
+    let target = path.clone();
+    let copied = blocking(move || crate::copy_content_uri(&uri, &target))
+        .await
+        .and_then(|copied| copied);
+    if let Err(e) = copied {
+        uploads.discard(&path);
+        return Err(e);
+    }
+    Ok(path)
+}
+
+/// Pick files to upload with the native file dialog; returns their handles.
+/// On Android the picked documents are copied to the staging area first.
+#[tauri::command]
+pub async fn upload_pick_files(
+    app: AppHandle,
+    uploads: State<'_, UploadState>,
+) -> Result<Vec<String>, String> {
+    #[cfg(desktop)]
+    {
+        let _ = app;
+        let files = rfd::AsyncFileDialog::new().pick_files().await;
+        Ok(files
+            .unwrap_or_default()
+            .iter()
+            .map(|file| uploads.register(file.path().to_path_buf()))
+            .collect())
+    }
+    #[cfg(mobile)]
+    {
+        use tauri_plugin_dialog::{DialogExt, FilePath};
+
+        let (tx, rx) = tokio::sync::oneshot::channel::<Option<Vec<FilePath>>>();
+        app.dialog().file().pick_files(move |files| {
+            let _ = tx.send(files);
+        });
+        let mut handles = Vec::new();
+        for file in rx.await.ok().flatten().unwrap_or_default() {
+            let path = match file {
+                #[cfg(target_os = "android")]
+                FilePath::Url(uri) if uri.scheme() == "content" => {
+                    stage_content_uri(&uploads, uri.to_string()).await
+                }
+                file => file.into_path().map_err(|e| e.to_string()),
+            };
+            match path {
+                Ok(path) => handles.push(uploads.register(path)),
+                Err(e) => {
+                    for handle in &handles {
+                        uploads.release(handle);
+                    }
+                    return Err(format!("Failed to open the picked file: {}", e));
+                }
+            }
+        }
+        Ok(handles)
+    }
+}
+
+/// Hand files dropped on the window to the frontend as upload handles, in an
+/// "upload-files-dropped" event
+#[cfg(desktop)]
+pub fn on_window_event(app: &AppHandle, event: &tauri::WindowEvent) {
+    let tauri::WindowEvent::DragDrop(tauri::DragDropEvent::Drop { paths, .. }) = event else {
+        return;
+    };
+    let uploads = app.state::<UploadState>();
+    let handles: Vec<String> = paths
+        .iter()
+        .map(|path| uploads.register(path.clone()))
+        .collect();
+    if !handles.is_empty() {
+        let _ = app.emit("upload-files-dropped", handles);
+    }
+}
@@ -0,0 +1,65 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Run blocking work (files, the keychain, key derivation) off the async
+/// runtime
+pub(crate) async fn blocking<T, F>(f: F) -> Result<T, String>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    tauri::async_runtime::spawn_blocking(f)
+        .await
+        .map_err(|e| format!("Background task failed: {}", e))
+}
+
+/// Lowercase hex digits of `bytes`
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Read a JSON settings file; a missing or unreadable one gives the defaults
+pub(crate) fn load_json<T: DeserializeOwned + Default>(path: &Path) -> T {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Write a JSON settings file, creating its directory. `what` names the
+/// settings in the error message.
+pub(crate) fn save_json<T: Serialize>(path: &Path, value: &T, what: &str) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, json).map_err(|e| format!("Failed to save {}: {}", what, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_digits() {
+        assert_eq!(hex(&[0x00, 0x0f, 0xa5, 0xff]), "000fa5ff");
+        assert_eq!(hex(&[]), "");
+    }
+
+    #[test]
+    fn json_settings_round_trip() {
+        let dir = std::env::temp_dir().join(format!("util-json-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("nested").join("settings.json");
+        assert_eq!(load_json::<Vec<String>>(&path), Vec::<String>::new());
+
+        save_json(&path, &vec!["a".to_string()], "test settings").unwrap();
+        assert_eq!(load_json::<Vec<String>>(&path), ["a"]);
+
+        fs::write(&path, "{ not json").unwrap();
+        assert_eq!(load_json::<Vec<String>>(&path), Vec::<String>::new());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,621 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, State};
+use zeroize::Zeroizing;
+
+use crate::chatlog::ChatLogState;
+use crate::history::HistoryState;
+use crate::rawlog::RawLogState;
+use crate::secrets::{write_private, SecretState};
+use crate::util::{blocking, hex, load_json};
+
+const NONCE_LEN: usize = 24;
+const SALT_LEN: usize = 16;
+const MIN_PASSPHRASE_LEN: usize = 8;
+/// Argon2id cost: 64 MiB, three passes, one lane
+#[cfg(not(test))]
+const M_COST: u32 = 64 * 1024;
+#[cfg(not(test))]
+const T_COST: u32 = 3;
+/// Tests use the cheapest cost Argon2 accepts, not seconds per key
+#[cfg(test)]
+const M_COST: u32 = Params::MIN_M_COST;
+#[cfg(test)]
+const T_COST: u32 = Params::MIN_T_COST;
+const P_COST: u32 = 1;
+/// How often the idle timer is checked
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Settings of an enabled vault, stored in `vault.json`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+struct VaultConfig {
+    salt: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    /// Nonce and ciphertext of the data key, encrypted with the passphrase key
+    wrapped_key: String,
+    /// Lock after this long without user activity; None never locks
+    auto_lock_minutes: Option<u32>,
+}
+
+/// Encrypts what the app keeps on disk with a key that only exists in memory
+/// while unlocked.
+///
+/// A random data key encrypts the data. The passphrase, stretched with
+/// Argon2id, only wraps the data key, so changing it doesn't rewrite anything.
+pub struct Vault {
+    path: PathBuf,
+    /// None while the vault is disabled
+    config: Mutex<Option<VaultConfig>>,
+    /// The data key; None while locked
+    key: Mutex<Option<Zeroizing<[u8; 32]>>>,
+    last_activity: Mutex<Instant>,
+}
+
+impl Vault {
+    /// Read the vault settings from `config_dir`. An enabled vault starts locked.
+    pub fn load(config_dir: &Path) -> Self {
+        let path = config_dir.join("vault.json");
+        let config = load_json(&path);
+        Vault {
+            path,
+            config: Mutex::new(config),
+            key: Mutex::new(None),
+            last_activity: Mutex::new(Instant::now()),
+        }
+    }
+
+    fn config(&self) -> Option<VaultConfig> {
+        self.config
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn save_config(&self, config: Option<VaultConfig>) -> Result<(), String> {
+        match &config {
+            Some(config) => {
+                let json = serde_json::to_vec_pretty(config).map_err(|e| e.to_string())?;
+                write_private(&self.path, &json)
+                    .map_err(|e| format!("Failed to save the vault: {}", e))?;
+            }
+            None => match fs::remove_file(&self.path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(format!("Failed to remove the vault: {}", e)),
+            },
+        }
+        *self.config.lock().unwrap_or_else(|e| e.into_inner()) = config;
+        Ok(())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// Enabled and without its key, so nothing may be written
+    pub fn is_locked(&self) -> bool {
+        self.is_enabled() && self.key.lock().unwrap_or_else(|e| e.into_inner()).is_none()
+    }
+
+    fn cipher(&self) -> Option<XChaCha20Poly1305> {
+        let key = self.key.lock().unwrap_or_else(|e| e.into_inner());
+        key.as_ref()
+            .map(|key| XChaCha20Poly1305::new_from_slice(&key[..]).expect("key is 32 bytes"))
+    }
+
+    /// Encrypt with the data key as nonce followed by ciphertext.
+    /// None while locked or disabled.
+    pub fn seal(&self, plaintext: &[u8]) -> Option<Vec<u8>> {
+        let cipher = self.cipher()?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher.encrypt(&nonce, plaintext).ok()?;
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Some(sealed)
+    }
+
+    /// Decrypt what `seal` produced
+    pub fn open(&self, sealed: &[u8]) -> Result<Vec<u8>, String> {
+        let cipher = self.cipher().ok_or("The app is locked")?;
+        if sealed.len() < NONCE_LEN {
+            return Err("Encrypted data is damaged".to_string());
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        cipher
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "Encrypted data is damaged".to_string())
+    }
+
+    /// A key for one purpose derived from the data key, as hex.
+    /// None while locked or disabled.
+    pub fn subkey(&self, purpose: &str) -> Option<Zeroizing<String>> {
+        let key = self.key.lock().unwrap_or_else(|e| e.into_inner());
+        let key = key.as_ref()?;
+        let mut hasher = Sha256::new();
+        hasher.update(purpose.as_bytes());
+        hasher.update(&key[..]);
+        let digest = hasher.finalize();
+        Some(Zeroizing::new(hex(&digest)))
+    }
+
+    /// Turn the vault on with a fresh data key, leaving it unlocked
+    fn create(&self, passphrase: &str) -> Result<(), String> {
+        if self.is_enabled() {
+            return Err("The app lock is already on".to_string());
+        }
+        check_passphrase(passphrase)?;
+        let key = Zeroizing::new(<[u8; 32]>::from(XChaCha20Poly1305::generate_key(
+            &mut OsRng,
+        )));
+        let config = wrap_key(&key, passphrase, None)?;
+        self.save_config(Some(config))?;
+        *self.key.lock().unwrap_or_else(|e| e.into_inner()) = Some(key);
+        self.touch();
+        Ok(())
+    }
+
+    /// Check a passphrase and keep the data key it unwraps
+    fn unlock(&self, passphrase: &str) -> Result<(), String> {
+        let config = self.config().ok_or("The app lock is off")?;
+        let key = unwrap_key(&config, passphrase)?;
+        *self.key.lock().unwrap_or_else(|e| e.into_inner()) = Some(key);
+        self.touch();
+        Ok(())
+    }
+
+    /// Forget the data key. Returns whether it was unlocked.
+    fn lock(&self) -> bool {
+        self.key
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .is_some()
+    }
+
+    /// Turn the vault off, once everything was decrypted with the key
+    fn remove(&self) -> Result<(), String> {
+        self.save_config(None)?;
+        self.lock();
+        Ok(())
+    }
+
+    fn change_passphrase(&self, current: &str, new: &str) -> Result<(), String> {
+        let config = self.config().ok_or("The app lock is off")?;
+        check_passphrase(new)?;
+        let key = unwrap_key(&config, current)?;
+        let config = wrap_key(&key, new, config.auto_lock_minutes)?;
+        self.save_config(Some(config))
+    }
+
+    fn set_auto_lock(&self, minutes: Option<u32>) -> Result<(), String> {
+        let mut config = self.config().ok_or("The app lock is off")?;
+        config.auto_lock_minutes = minutes.filter(|minutes| *minutes > 0);
+        self.save_config(Some(config))
+    }
+
+    /// Note user activity, which postpones the auto-lock
+    pub fn touch(&self) {
+        *self.last_activity.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    /// Unlocked and idle for longer than the auto-lock delay
+    fn idle_expired(&self) -> bool {
+        let Some(minutes) = self.config().and_then(|config| config.auto_lock_minutes) else {
+            return false;
+        };
+        let idle = self
+            .last_activity
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .elapsed();
+        !self.is_locked() && idle >= Duration::from_secs(u64::from(minutes) * 60)
+    }
+
+    /// Turn the vault on for tests of the stores that use it
+    #[cfg(test)]
+    pub(crate) fn enable_for_tests(&self) {
+        self.create("correct horse battery").unwrap();
+    }
+
+    fn status(&self) -> VaultStatus {
+        let config = self.config();
+        VaultStatus {
+            enabled: config.is_some(),
+            locked: self.is_locked(),
+            auto_lock_minutes: config.and_then(|config| config.auto_lock_minutes),
+        }
+    }
+}
+
+fn check_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        return Err(format!(
+            "The passphrase needs at least {} characters",
+            MIN_PASSPHRASE_LEN
+        ));
+    }
+    Ok(())
+}
+
+/// Stretch a passphrase into a 32 byte key with Argon2id
+fn derive(
+    passphrase: &str,
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> Result<Zeroizing<[u8; 32]>, String> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(32))
+        .map_err(|e| format!("Invalid vault parameters: {}", e))?;
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key[..])
+        .map_err(|e| format!("Failed to derive the vault key: {}", e))?;
+    Ok(key)
+}
+
+/// Encrypt the data key with a passphrase and a new salt
+fn wrap_key(
+    key: &[u8; 32],
+    passphrase: &str,
+    auto_lock_minutes: Option<u32>,
+) -> Result<VaultConfig, String> {
+    let salt: [u8; SALT_LEN] = rand_bytes();
+    let kek = derive(passphrase, &salt, M_COST, T_COST, P_COST)?;
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = XChaCha20Poly1305::new_from_slice(&kek[..])
+        .expect("key is 32 bytes")
+        .encrypt(&nonce, &key[..])
+        .map_err(|_| "Failed to encrypt the vault key".to_string())?;
+    let mut wrapped = nonce.to_vec();
+    wrapped.extend_from_slice(&ciphertext);
+    Ok(VaultConfig {
+        salt: BASE64.encode(salt),
+        m_cost: M_COST,
+        t_cost: T_COST,
+        p_cost: P_COST,
+        wrapped_key: BASE64.encode(wrapped),
+        auto_lock_minutes,
+    })
+}
+
+/// Recover the data key; fails on a wrong passphrase
+fn unwrap_key(config: &VaultConfig, passphrase: &str) -> Result<Zeroizing<[u8; 32]>, String> {
+    let damaged = |_| "The vault file is damaged".to_string();
+    let salt = BASE64.decode(&config.salt).map_err(damaged)?;
+    let wrapped = BASE64.decode(&config.wrapped_key).map_err(damaged)?;
+    if wrapped.len() < NONCE_LEN {
+        return Err("The vault file is damaged".to_string());
+    }
+    let kek = derive(
+        passphrase,
+        &salt,
+        config.m_cost,
+        config.t_cost,
+        config.p_cost,
+    )?;
+    let (nonce, ciphertext) = wrapped.split_at(NONCE_LEN);
+    let key = Zeroizing::new(
+        XChaCha20Poly1305::new_from_slice(&kek[..])
+            .expect("key is 32 bytes")
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "Wrong passphrase".to_string())?,
+    );
+    let key: [u8; 32] = key[..]
+        .try_into()
+        .map_err(|_| "The vault file is damaged".to_string())?;
+    Ok(Zeroizing::new(key))
+}
+
+fn rand_bytes<const N: usize>() -> [u8; N] {
+    use chacha20poly1305::aead::rand_core::RngCore;
+    let mut bytes = [0u8; N];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+pub struct VaultState(pub Arc<Vault>);
+
+#[derive(Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultStatus {
+    enabled: bool,
+    locked: bool,
+    auto_lock_minutes: Option<u32>,
+}
+
+/// Lock the vault and switch every store to holding its writes in memory.
+/// Connections stay up; the frontend covers itself with the lock screen.
+pub fn lock_all(app: &AppHandle) {
+    let vault = &app.state::<VaultState>().0;
+    if !vault.is_enabled() {
+        return;
+    }
+    if let Err(e) = app.state::<HistoryState>().0.lock() {
+        log::error!("Failed to close the history database: {}", e);
+    }
+    if vault.lock() {
+        let _ = app.emit("vault-locked", vault.status());
+    }
+}
+
+/// Reopen the stores with the data key and write what piled up while locked
+fn unlock_all(app: &AppHandle) -> Result<(), String> {
+    let vault = &app.state::<VaultState>().0;
+    let key = vault.subkey("history").ok_or("The app is locked")?;
+    app.state::<HistoryState>()
+        .0
+        .unlock(Some(key.as_str()))
+        .map_err(|e| format!("Failed to open the history database: {}", e))?;
+    app.state::<ChatLogState>().0.flush_pending();
+    app.state::<SecretState>().0.flush_pending();
+    let _ = app.emit("vault-unlocked", vault.status());
+    Ok(())
+}
+
+/// Lock after the configured idle time
+pub fn spawn_auto_lock(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(IDLE_CHECK_INTERVAL).await;
+            if app.state::<VaultState>().0.idle_expired() {
+                log::info!("Locking the app after inactivity");
+                let app = app.clone();
+                let _ = tauri::async_runtime::spawn_blocking(move || lock_all(&app)).await;
+            }
+        }
+    });
+}
+
+#[tauri::command]
+pub fn vault_status(vault: State<'_, VaultState>) -> VaultStatus {
+    vault.0.status()
+}
+
+/// Turn the app lock on and encrypt the history, chat logs and stored
+/// secrets. Raw logs, which are meant for debugging, are stopped instead.
+#[tauri::command]
+pub async fn vault_enable(app: AppHandle, passphrase: String) -> Result<VaultStatus, String> {
+    for log in app.state::<RawLogState>().0.lock().await.values() {
+        log.stop();
+    }
+    blocking(move || {
+        let vault = &app.state::<VaultState>().0;
+        vault.create(&passphrase)?;
+        let key = vault.subkey("history").ok_or("The app is locked")?;
+        let history = &app.state::<HistoryState>().0;
+        if let Err(e) = history.rekey(None, Some(key.as_str())) {
+            vault.remove()?;
+            return Err(format!("Failed to encrypt the history database: {}", e));
+        }
+        let chat_log = &app.state::<ChatLogState>().0;
+        let secrets = &app.state::<SecretState>().0;
+        let converted = chat_log
+            .convert(vault, true)
+            .and_then(|()| secrets.convert(true));
+        if let Err(e) = converted {
+            // Leave the data as it was
+            let _ = secrets.convert(false);
+            let _ = chat_log.convert(vault, false);
+            let _ = history.rekey(Some(key.as_str()), None);
+            vault.remove()?;
+            return Err(e);
+        }
+        Ok(vault.status())
+    })
+    .await?
+}
+
+/// Turn the app lock off and decrypt everything again
+#[tauri::command]
+pub async fn vault_disable(app: AppHandle, passphrase: String) -> Result<VaultStatus, String> {
+    blocking(move || {
+        let vault = &app.state::<VaultState>().0;
+        let was_locked = vault.is_locked();
+        vault.unlock(&passphrase)?;
+        if was_locked {
+            unlock_all(&app)?;
+        }
+        let key = vault.subkey("history").ok_or("The app is locked")?;
+        app.state::<HistoryState>()
+            .0
+            .rekey(Some(key.as_str()), None)
+            .map_err(|e| format!("Failed to decrypt the history database: {}", e))?;
+        app.state::<ChatLogState>().0.convert(vault, false)?;
+        let secrets = &app.state::<SecretState>().0;
+        secrets.flush_pending();
+        secrets.convert(false)?;
+        vault.remove()?;
+        Ok(vault.status())
+    })
+    .await?
+}
+
+#[tauri::command]
+pub async fn vault_lock(app: AppHandle) -> Result<(), String> {
+    blocking(move || lock_all(&app)).await
+}
+
+#[tauri::command]
+pub async fn vault_unlock(app: AppHandle, passphrase: String) -> Result<VaultStatus, String> {
+    blocking(move || {
+        let vault = &app.state::<VaultState>().0;
+        if !vault.is_locked() {
+            return Ok(vault.status());
+        }
+        vault.unlock(&passphrase)?;
+        if let Err(e) = unlock_all(&app) {
+            vault.lock();
+            return Err(e);
+        }
+        Ok(vault.status())
+    })
+    .await?
+}
+
+#[tauri::command]
+pub async fn vault_change_passphrase(
+    app: AppHandle,
+    current: String,
+    passphrase: String,
+) -> Result<(), String> {
+    blocking(move || {
+        app.state::<VaultState>()
+            .0
+            .change_passphrase(&current, &passphrase)
+    })
+    .await?
+}
+
+#[tauri::command]
+pub fn vault_set_auto_lock(
+    vault: State<'_, VaultState>,
+    minutes: Option<u32>,
+) -> Result<VaultStatus, String> {
+    vault.0.set_auto_lock(minutes)?;
+    vault.0.touch();
+    Ok(vault.0.status())
+}
+
+/// Called by the frontend, throttled, while the user is interacting with it
+#[tauri::command]
+pub fn vault_activity(vault: State<'_, VaultState>) {
+    vault.0.touch();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vault-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn wrapped_key_needs_the_passphrase() {
+        let key = [7u8; 32];
+        let config = wrap_key(&key, "correct horse", Some(5)).unwrap();
+        assert_eq!(config.auto_lock_minutes, Some(5));
+        assert_eq!(*unwrap_key(&config, "correct horse").unwrap(), key);
+        assert_eq!(
+            unwrap_key(&config, "wrong horse").unwrap_err(),
+            "Wrong passphrase"
+        );
+
+        // A new salt each time
+        let again = wrap_key(&key, "correct horse", None).unwrap();
+        assert_ne!(again.salt, config.salt);
+        assert_ne!(again.wrapped_key, config.wrapped_key);
+    }
+
+    #[test]
+    fn seal_round_trip_and_tampering() {
+        let dir = temp_dir("seal");
+        let vault = Vault::load(&dir);
+        assert!(vault.seal(b"hello").is_none());
+        vault.enable_for_tests();
+
+        let sealed = vault.seal(b"hello").unwrap();
+        assert_ne!(&sealed[NONCE_LEN..], b"hello");
+        assert_eq!(vault.open(&sealed).unwrap(), b"hello");
+        assert_ne!(vault.seal(b"hello").unwrap(), sealed);
+
+        let mut tampered = sealed.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            vault.open(&tampered).unwrap_err(),
+            "Encrypted data is damaged"
+        );
+        assert!(vault.open(&sealed[..NONCE_LEN - 1]).is_err());
+
+        assert!(vault.lock());
+        assert!(vault.is_locked());
+        assert!(vault.seal(b"hello").is_none());
+        assert_eq!(vault.open(&sealed).unwrap_err(), "The app is locked");
+
+        // Enabled vaults start locked
+        let reloaded = Vault::load(&dir);
+        assert!(reloaded.is_enabled() && reloaded.is_locked());
+        reloaded.unlock("correct horse battery").unwrap();
+        assert_eq!(reloaded.open(&sealed).unwrap(), b"hello");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn changing_the_passphrase_keeps_the_data_key() {
+        let dir = temp_dir("change");
+        let vault = Vault::load(&dir);
+        vault.enable_for_tests();
+        let sealed = vault.seal(b"hello").unwrap();
+        let subkey = vault.subkey("history").unwrap();
+
+        assert_eq!(
+            vault
+                .change_passphrase("wrong horse", "new passphrase")
+                .unwrap_err(),
+            "Wrong passphrase"
+        );
+        assert!(vault
+            .change_passphrase("correct horse battery", "short")
+            .is_err());
+        vault
+            .change_passphrase("correct horse battery", "new passphrase")
+            .unwrap();
+
+        vault.lock();
+        assert_eq!(
+            vault.unlock("correct horse battery").unwrap_err(),
+            "Wrong passphrase"
+        );
+        vault.unlock("new passphrase").unwrap();
+        assert_eq!(vault.open(&sealed).unwrap(), b"hello");
+        assert_eq!(vault.subkey("history").unwrap(), subkey);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn idle_expiry() {
+        let dir = temp_dir("idle");
+        let vault = Vault::load(&dir);
+        vault.enable_for_tests();
+        let idle = |seconds| {
+            *vault.last_activity.lock().unwrap() = Instant::now() - Duration::from_secs(seconds);
+        };
+
+        idle(24 * 60 * 60);
+        assert!(!vault.idle_expired());
+
+        vault.set_auto_lock(Some(5)).unwrap();
+        idle(4 * 60);
+        assert!(!vault.idle_expired());
+        idle(5 * 60);
+        assert!(vault.idle_expired());
+        vault.touch();
+        assert!(!vault.idle_expired());
+
+        idle(5 * 60);
+        vault.lock();
+        assert!(!vault.idle_expired());
+
+        vault.set_auto_lock(Some(0)).unwrap();
+        assert_eq!(vault.status().auto_lock_minutes, None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
-import { onOpenUrl } from "@tauri-apps/plugin-deep-link";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import {
   isPermissionGranted,
   requestPermission,
@@ -10,10 +11,14 @@ import AppLayout from "./components/layout/AppLayout";
 import { ServerNoticesPopup } from "./components/message/ServerNoticesPopup";
 import PrivacyPolicy from "./components/PrivacyPolicy";
 import AddServerModal from "./components/ui/AddServerModal";
+import AppLockModal from "./components/ui/AppLockModal";
 import ChannelListModal from "./components/ui/ChannelListModal";
+import DownloadsModal from "./components/ui/DownloadsModal";
 import { EditServerModal } from "./components/ui/EditServerModal";
 import LinkSecurityWarningModal from "./components/ui/LinkSecurityWarningModal";
 import LoadingOverlay from "./components/ui/LoadingOverlay";
+import LockScreen from "./components/ui/LockScreen";
+import ProfilePickerModal from "./components/ui/ProfilePickerModal";
 import QuickActions from "./components/ui/QuickActions";
 import { TicTacToeModal } from "./components/ui/TicTacToeModal";
 import { TotpStepUpModal } from "./components/ui/TotpStepUpModal";
@@ -24,11 +29,30 @@ import { useChannelTabSwitching } from "./hooks/useChannelTabSwitching";
 import { useConnectionResilience } from "./hooks/useConnectionResilience";
 import { useKeyboardResize } from "./hooks/useKeyboardResize";
 import ircClient from "./lib/ircClient";
-import { parseIrcUrl } from "./lib/ircUrlParser";
+import { resolveIrcUrl } from "./lib/ircUrlParser";
 import { isTauri } from "./lib/platformUtils";
-import useStore, { loadSavedServers } from "./store";
+import { shouldAskForProfile } from "./lib/profiles";
+import { initSecrets } from "./lib/secrets";
+import { loadStartupConfig, type StartupConfig } from "./lib/startupConfig";
+import { getVaultStatus, reportActivity } from "./lib/vault";
+import useStore, {
+  isSavedNetwork,
+  loadSavedServers,
+  saveServersToLocalStorage,
+} from "./store";
 import type { ConnectionDetails } from "./store/types";
 
+/** An IRC link forwarded by the backend (deep link, second instance, CLI) */
+interface OpenIrcUrl {
+  source: string;
+  scheme: "irc" | "ircs" | "ws" | "wss" | "unix";
+  host: string;
+  port: number;
+  nick: string | null;
+  channels: string[];
+  tls: boolean;
+}
+
 const askPermissions = async () => {
   // Do you have permission to send a notification?
   let permissionGranted = await isPermissionGranted();
@@ -83,6 +107,9 @@ const App: React.FC = () => {
     toggleAddServerModal,
     toggleEditServerModal,
     toggleQuickActions,
+    toggleProfilePicker,
+    toggleAppLockSettings,
+    toggleDownloads,
     toggleTwoFactorSettings,
     ui: {
       isAddServerModalOpen,
@@ -91,6 +118,9 @@ const App: React.FC = () => {
       isEditServerModalOpen,
       isSettingsModalOpen,
       isQuickActionsOpen,
+      isProfilePickerOpen,
+      isAppLockSettingsOpen,
+      isDownloadsOpen,
       isUserProfileModalOpen,
       isTwoFactorSettingsOpen,
       editServerId,
@@ -149,8 +179,8 @@ const App: React.FC = () => {
     e.preventDefault();
   };
 
-  const handleIrcLinkClick = (url: string) => {
-    const parsed = parseIrcUrl(url, "user");
+  const handleIrcLinkClick = async (url: string) => {
+    const parsed = await resolveIrcUrl(url, "user");
     toggleAddServerModal(true, {
       name: parsed.host,
       host: parsed.host,
@@ -160,6 +190,45 @@ const App: React.FC = () => {
     });
   };
 
+  // Connect to saved networks named on the command line; unknown ones open
+  // the connect modal instead
+  const connectToNetworks = (networks: string[]) => {
+    const saved = loadSavedServers();
+    const isSaved = (network: string) =>
+      saved.some((server) => isSavedNetwork(server, network));
+    connectToSavedServers(networks.filter(isSaved));
+    const unknown = networks.find((network) => !isSaved(network));
+    if (unknown) {
+      toggleAddServerModal(true, {
+        name: unknown,
+        host: unknown,
+        port: "6697",
+        nickname: "user",
+        useWebSocket: false,
+      });
+    }
+  };
+
+  const connectToNetworksRef = useRef(connectToNetworks);
+  connectToNetworksRef.current = connectToNetworks;
+  const deferredAutoconnect = useRef<(() => void) | null>(null);
+
+  const closeProfilePicker = () => {
+    toggleProfilePicker(false);
+    deferredAutoconnect.current?.();
+    deferredAutoconnect.current = null;
+  };
+
+  // The app lock covers everything; startup waits for the first unlock
+  // because the keychain can't be read before
+  const [isLocked, setIsLocked] = useState(false);
+  const startupUnlock = useRef<(() => void) | null>(null);
+  const handleUnlocked = () => {
+    setIsLocked(false);
+    startupUnlock.current?.();
+    startupUnlock.current = null;
+  };
+
   // Initialize keyboard resize handling for mobile platforms
   useKeyboardResize();
   useConnectionResilience();
@@ -171,9 +240,81 @@ const App: React.FC = () => {
     if (hasInitialized.current) return;
     hasInitialized.current = true;
     initializeEnvSettings(toggleAddServerModal, joinChannel);
-    connectToSavedServers();
+    const unlocked = getVaultStatus()
+      .catch(() => null)
+      .then((status) => {
+        if (!status?.locked) return;
+        setIsLocked(true);
+        return new Promise<void>((resolve) => {
+          startupUnlock.current = resolve;
+        });
+      });
+    Promise.all([
+      loadStartupConfig(),
+      unlocked.then(() =>
+        initSecrets(loadSavedServers, saveServersToLocalStorage),
+      ),
+    ]).then(async ([config]) => {
+      const autoconnect = () => {
+        if (!config?.noAutoconnect) {
+          connectToSavedServers();
+        }
+        if (config?.connect.length) {
+          connectToNetworksRef.current(config.connect);
+        }
+      };
+      // Picking another profile restarts the app, so hold off connecting
+      if (config && !config.profile && (await shouldAskForProfile())) {
+        deferredAutoconnect.current = autoconnect;
+        toggleProfilePicker(true);
+      } else {
+        autoconnect();
+      }
+    });
   }, [connectToSavedServers, joinChannel, toggleAddServerModal]);
 
+  // A second launch with --connect forwards its networks to us
+  useEffect(() => {
+    if (!isTauri()) return;
+    const unlisten = listen<StartupConfig>("cli-args", (event) =>
+      connectToNetworksRef.current(event.payload.connect),
+    );
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Lock screen events from the vault, including the idle auto-lock, and
+  // OAuth sessions that need a new sign-in
+  useEffect(() => {
+    if (!isTauri()) return;
+    const unlisteners = [
+      listen("vault-locked", () => setIsLocked(true)),
+      listen("vault-unlocked", () => setIsLocked(false)),
+      listen<{ serverId: string; reason: string }>(
+        "oauth-relogin-required",
+        ({ payload }) =>
+          useStore.getState().addGlobalNotification({
+            type: "warn",
+            command: "AUTHENTICATE",
+            code: "OAUTH_RELOGIN",
+            message: `Sign in again to renew your OAuth session: ${payload.reason}`,
+            serverId: payload.serverId,
+          }),
+      ),
+    ];
+    const onActivity = () => reportActivity();
+    for (const type of ["keydown", "pointerdown", "wheel"]) {
+      window.addEventListener(type, onActivity, { passive: true });
+    }
+    return () => {
+      for (const unlisten of unlisteners) unlisten.then((fn) => fn());
+      for (const type of ["keydown", "pointerdown", "wheel"]) {
+        window.removeEventListener(type, onActivity);
+      }
+    };
+  }, []);
+
   // When the server list is hidden and all saved-server connections fail, the user
   // has no other way to open the login modal, so we open it automatically.
   useEffect(() => {
@@ -215,40 +356,36 @@ const App: React.FC = () => {
     toggleAddServerModal,
   ]);
 
-  // Handle deeplinks
+  // Handle deeplinks; the backend parses them and queues any that arrive
+  // before we listen
   useEffect(() => {
     let unlisten: (() => void) | null = null;
 
+    const openIrcUrl = (link: OpenIrcUrl) => {
+      console.log("Deep link received:", link.source);
+      // Open the connect modal with pre-filled details
+      toggleAddServerModal(true, {
+        name: link.host || "IRC Server",
+        host: link.host,
+        port: link.port.toString(),
+        nickname: link.nick || "user",
+        useWebSocket: link.scheme === "ws" || link.scheme === "wss",
+      });
+    };
+
     const setupDeepLinkHandler = async () => {
       if (!isTauri()) {
         return;
       }
 
       try {
-        // Register handler for when app is already running; store unlisten for cleanup
-        unlisten = await onOpenUrl((urls) => {
-          console.log("Deep link received:", urls);
-
-          for (const url of urls) {
-            if (url.startsWith("irc://") || url.startsWith("ircs://")) {
-              try {
-                // Parse the IRC URL
-                const parsed = parseIrcUrl(url);
-
-                // Open the connect modal with pre-filled details
-                toggleAddServerModal(true, {
-                  name: parsed.host || "IRC Server",
-                  host: parsed.host,
-                  port: parsed.port.toString(),
-                  nickname: parsed.nick || "user",
-                  useWebSocket: false,
-                });
-              } catch (error) {
-                console.error("Failed to parse IRC URL:", error);
-              }
-            }
-          }
-        });
+        unlisten = await listen<OpenIrcUrl>("open-irc-url", (event) =>
+          openIrcUrl(event.payload),
+        );
+        const pending = await invoke<OpenIrcUrl[]>("deep_link_ready");
+        for (const link of pending) {
+          openIrcUrl(link);
+        }
       } catch (error) {
         console.error("Failed to setup deep link handler:", error);
       }
@@ -322,6 +459,15 @@ const App: React.FC = () => {
               <TicTacToeModal />
               {isSettingsModalOpen && <UserSettings />}
               {isQuickActionsOpen && <QuickActions />}
+              {isProfilePickerOpen && (
+                <ProfilePickerModal onClose={closeProfilePicker} />
+              )}
+              {isAppLockSettingsOpen && (
+                <AppLockModal onClose={() => toggleAppLockSettings(false)} />
+              )}
+              {isDownloadsOpen && (
+                <DownloadsModal onClose={() => toggleDownloads(false)} />
+              )}
               {isChannelListModalOpen && <ChannelListModal />}
               <LinkSecurityWarningModal />
               {userProfileModalState?.isOpen && (
@@ -342,6 +488,7 @@ const App: React.FC = () => {
                 />
               )}
               {isConnecting && <LoadingOverlay />}
+              {isLocked && <LockScreen onUnlocked={handleUnlocked} />}
             </>
           }
         />
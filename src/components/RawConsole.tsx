@@ -0,0 +1,130 @@
+import { Trans, useLingui } from "@lingui/react/macro";
+import { invoke } from "@tauri-apps/api/core";
+import { getCurrentWebviewWindow } from "@tauri-apps/api/webviewWindow";
+import type * as React from "react";
+import { useEffect, useRef, useState } from "react";
+
+// Oldest lines are dropped past this so a busy network can't exhaust memory
+const MAX_LINES = 5000;
+
+interface RawTrafficEvent {
+  clientId: string;
+  network: string;
+  direction: "in" | "out";
+  timestamp: number;
+  command: string;
+  line: string;
+}
+
+const formatLine = (event: RawTrafficEvent) => {
+  const time = new Date(event.timestamp).toISOString().slice(11, 23);
+  const marker = event.direction === "in" ? "<<" : ">>";
+  return `${time} [${event.network}] ${marker} ${event.line}`;
+};
+
+const RawConsole: React.FC<{ label: string }> = ({ label }) => {
+  const { t } = useLingui();
+  const [lines, setLines] = useState<RawTrafficEvent[]>([]);
+  const [filter, setFilter] = useState("");
+  const [paused, setPaused] = useState(false);
+  const [skipped, setSkipped] = useState(0);
+  const bottomRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    const consoleWindow = getCurrentWebviewWindow();
+    const unlisten = Promise.all([
+      consoleWindow.listen<RawTrafficEvent>("raw-traffic", ({ payload }) => {
+        setLines((prev) => [...prev, payload].slice(-MAX_LINES));
+      }),
+      consoleWindow.listen<number>("raw-traffic-lagged", ({ payload }) => {
+        setSkipped((prev) => prev + payload);
+      }),
+    ]);
+    return () => {
+      unlisten.then((fns) => {
+        for (const fn of fns) fn();
+      });
+    };
+  }, []);
+
+  useEffect(() => {
+    if (lines.length > 0) bottomRef.current?.scrollIntoView();
+  }, [lines]);
+
+  const applyFilter = (value: string) => {
+    setFilter(value);
+    invoke("set_raw_console_filter", {
+      label,
+      commands: value.split(/[\s,]+/),
+    }).catch(console.error);
+  };
+
+  const togglePaused = () => {
+    const next = !paused;
+    setPaused(next);
+    invoke("set_raw_console_paused", { label, paused: next }).catch(
+      console.error,
+    );
+  };
+
+  const copyAll = () => {
+    navigator.clipboard.writeText(lines.map(formatLine).join("\n"));
+  };
+
+  return (
+    <div className="h-screen flex flex-col bg-discord-dark-200 text-discord-text-normal">
+      <div className="flex items-center gap-2 p-2 border-b border-discord-dark-400">
+        <input
+          className="flex-1 px-2 py-1 rounded bg-discord-dark-400 text-sm"
+          placeholder={t`Filter commands, e.g. PRIVMSG 001 -PING`}
+          value={filter}
+          onChange={(e) => applyFilter(e.target.value)}
+        />
+        <button
+          className="px-3 py-1 rounded bg-discord-dark-400 hover:bg-discord-dark-300 text-sm"
+          onClick={togglePaused}
+        >
+          {paused ? <Trans>Resume</Trans> : <Trans>Pause</Trans>}
+        </button>
+        <button
+          className="px-3 py-1 rounded bg-discord-dark-400 hover:bg-discord-dark-300 text-sm"
+          onClick={copyAll}
+        >
+          <Trans>Copy</Trans>
+        </button>
+        <button
+          className="px-3 py-1 rounded bg-discord-dark-400 hover:bg-discord-dark-300 text-sm"
+          onClick={() => {
+            setLines([]);
+            setSkipped(0);
+          }}
+        >
+          <Trans>Clear</Trans>
+        </button>
+      </div>
+      {skipped > 0 && (
+        <div className="px-2 py-1 text-xs text-discord-text-muted">
+          <Trans>{skipped} lines skipped because the console fell behind</Trans>
+        </div>
+      )}
+      <div className="flex-1 overflow-y-auto p-2 font-mono text-xs select-text whitespace-pre-wrap break-all">
+        {lines.map((event, i) => (
+          <div
+            // biome-ignore lint/suspicious/noArrayIndexKey: lines are append-only
+            key={i}
+            className={
+              event.direction === "in"
+                ? "text-discord-text-normal"
+                : "text-discord-text-muted"
+            }
+          >
+            {formatLine(event)}
+          </div>
+        ))}
+        <div ref={bottomRef} />
+      </div>
+    </div>
+  );
+};
+
+export default RawConsole;
@@ -1,4 +1,5 @@
 import { Trans, useLingui } from "@lingui/react/macro";
+import { listen } from "@tauri-apps/api/event";
 import type { EmojiClickData } from "emoji-picker-react";
 import type * as React from "react";
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
@@ -22,11 +23,20 @@ import {
   emojiClickValue,
   packEntriesForPicker,
 } from "../../lib/customEmojiPicker";
+import {
+  imagesWithMetadata,
+  isStrippableImage,
+} from "../../lib/imageMetadata";
+import { isShrinkableImage, shrinkImage } from "../../lib/imageShrink";
 import ircClient from "../../lib/ircClient";
-import { parseIrcUrl } from "../../lib/ircUrlParser";
+import { resolveIrcUrl } from "../../lib/ircUrlParser";
 import {
   fetchUploadInfo,
+  pathFileName,
+  pickUploadFiles,
+  stageUploadFile,
   uploadFile,
+  uploadFilePath,
   validateFileAgainstInfo,
 } from "../../lib/mediaUpload";
 import {
@@ -34,7 +44,12 @@ import {
   getPreviewStyles,
   isValidFormattingType,
 } from "../../lib/messageFormatter";
-import { isMobileDevice, isTauriMobile } from "../../lib/platformUtils";
+import {
+  isMobileDevice,
+  isTauri,
+  isTauriDesktop,
+  isTauriMobile,
+} from "../../lib/platformUtils";
 import useStore from "../../store";
 import type { Message as MessageType, User } from "../../types";
 import { MessageItem } from "../message/MessageItem";
@@ -163,8 +178,9 @@ export const ChatArea: React.FC<{
     previewUrl: null,
   });
   // Multimedia uploads in flight.  Each job tracks one file's
-  // XHR-driven progress; once they all settle we send a single
-  // PRIVMSG with the URLs joined.
+  // progress (XHR for webview files, backend events for paths on
+  // disk); once they all settle we send a single PRIVMSG with the
+  // URLs joined.
   const [uploadJobs, setUploadJobs] = useState<UploadJob[]>([]);
   const uploadAbortsRef = useRef<Map<string, AbortController>>(new Map());
   const [isServerNoticesPoppedOut, setIsServerNoticesPoppedOut] =
@@ -500,11 +516,14 @@ export const ChatArea: React.FC<{
   const isMobileInput = isMobileDevice();
 
   const handleIrcLinkClick = useCallback(
-    (rawUrl: string) => {
+    async (rawUrl: string) => {
       const ircCurrentUser = selectedServerId
         ? ircClient.getCurrentUser(selectedServerId)
         : null;
-      const parsed = parseIrcUrl(rawUrl, ircCurrentUser?.username || "user");
+      const parsed = await resolveIrcUrl(
+        rawUrl,
+        ircCurrentUser?.username || "user",
+      );
 
       // Open the connect modal with pre-filled server details
       toggleAddServerModal(true, {
@@ -1010,8 +1029,11 @@ export const ChatArea: React.FC<{
   // per batch -- the backend accepts the same token across many
   // uploads), kicks off N uploads in parallel, surfaces per-file
   // progress, and on full success sends a single PRIVMSG containing
-  // all the resulting URLs separated by spaces.
-  const handleFilesUpload = async (files: File[]) => {
+  // all the resulting URLs separated by spaces.  Strings are paths on
+  // disk (Tauri native picker or drag-and-drop) that the backend
+  // streams up itself.  On Tauri, images are resized first when they
+  // are over the server's limit or the user's maximum size.
+  const handleFilesUpload = async (files: Array<File | string>) => {
     if (!selectedServer?.filehost || !selectedServerId || files.length === 0) {
       return;
     }
@@ -1019,6 +1041,45 @@ export const ChatArea: React.FC<{
     const target = selectedChannel?.name ?? selectedPrivateChat?.username;
     if (!target) return;
 
+    // On Tauri, images picked in the webview are copied to disk so the
+    // backend can process them like picked or dropped paths.  Photos
+    // often carry GPS coordinates; the backend strips image metadata
+    // as it uploads them.
+    let sources = files;
+    let stripMetadata = false;
+    if (isTauri()) {
+      sources = await Promise.all(
+        files.map((f) =>
+          typeof f !== "string" &&
+          (isStrippableImage(f) || isShrinkableImage(f))
+            ? stageUploadFile(f).catch(() => f)
+            : f,
+        ),
+      );
+      const mode = useStore.getState().globalSettings.imageMetadataMode;
+      if (mode === "always") {
+        stripMetadata = true;
+      } else if (mode === "ask") {
+        const found = await imagesWithMetadata(
+          sources.filter((f): f is string => typeof f === "string"),
+        );
+        if (found.length > 0) {
+          const kinds = new Set<string>();
+          for (const report of found) {
+            if (report.gps) kinds.add(t`location data`);
+            if (report.camera) kinds.add(t`camera details`);
+            if (report.takenAt) kinds.add(t`the date taken`);
+          }
+          const details = kinds.size > 0 ? [...kinds].join(", ") : t`metadata`;
+          stripMetadata = window.confirm(
+            found.length === 1
+              ? t`This image contains ${details}. Remove it before uploading?`
+              : t`${found.length} images contain ${details}. Remove it before uploading?`,
+          );
+        }
+      }
+    }
+
     // Pre-flight: pull the policy so we can reject obviously-bad
     // files before pushing bytes.
     const info = await fetchUploadInfo(filehostUrl);
@@ -1049,13 +1110,27 @@ export const ChatArea: React.FC<{
 
     // Build the job list in one go so the progress strip appears
     // immediately, before any network roundtrip.
-    const initialJobs: UploadJob[] = files.map((f) => ({
-      id: uuidv4(),
-      file: f,
-      loaded: 0,
-      total: f.size,
-      status: "pending",
-    }));
+    const initialJobs: UploadJob[] = sources.map((f) =>
+      typeof f === "string"
+        ? {
+            id: uuidv4(),
+            name: pathFileName(f),
+            size: 0,
+            path: f,
+            loaded: 0,
+            total: 0,
+            status: "pending",
+          }
+        : {
+            id: uuidv4(),
+            name: f.name,
+            size: f.size,
+            file: f,
+            loaded: 0,
+            total: f.size,
+            status: "pending",
+          },
+    );
     setUploadJobs((prev) => [...prev, ...initialJobs]);
 
     const updateJob = (id: string, patch: Partial<UploadJob>) =>
@@ -1067,8 +1142,11 @@ export const ChatArea: React.FC<{
     const results = await Promise.all(
       initialJobs.map(async (job) => {
         // Cheap client-side validation -- saves a server round-trip
-        // and gives the user immediate feedback.
-        const validationError = validateFileAgainstInfo(job.file, info);
+        // and gives the user immediate feedback.  The backend runs the
+        // same checks for paths.
+        const validationError = job.file
+          ? validateFileAgainstInfo(job.file, info)
+          : null;
         if (validationError) {
           updateJob(job.id, {
             status: "failed",
@@ -1078,20 +1156,41 @@ export const ChatArea: React.FC<{
         }
         const ac = new AbortController();
         uploadAbortsRef.current.set(job.id, ac);
-        updateJob(job.id, { status: "uploading" });
         try {
-          const url = await uploadFile(job.file, {
+          let path = job.path;
+          if (path && isShrinkableImage(path)) {
+            updateJob(job.id, { status: "processing" });
+            const { uploadMaxDimension, uploadImageQuality } =
+              useStore.getState().globalSettings;
+            const report = await shrinkImage(path, {
+              maxBytes: info?.max_size || null,
+              maxDimension: uploadMaxDimension || null,
+              quality: uploadImageQuality,
+            });
+            if (report.changed) {
+              path = report.path;
+              updateJob(job.id, {
+                size: report.afterBytes,
+                originalSize: report.beforeBytes,
+              });
+            }
+          }
+          updateJob(job.id, { status: "uploading" });
+          const opts = {
             filehostUrl,
             bearerToken: jwtToken as string,
             signal: ac.signal,
-            onProgress: (loaded, total) => updateJob(job.id, { loaded, total }),
-          });
-          updateJob(job.id, {
-            status: "done",
-            loaded: job.file.size,
-            total: job.file.size,
-            url,
-          });
+            stripMetadata,
+            onProgress: (loaded: number, total: number) =>
+              updateJob(job.id, { loaded, total }),
+          };
+          let url: string;
+          if (job.file) {
+            url = await uploadFile(job.file, opts);
+          } else {
+            url = await uploadFilePath(path as string, opts);
+          }
+          updateJob(job.id, { status: "done", url });
           uploadAbortsRef.current.delete(job.id);
           return url;
         } catch (err) {
@@ -1139,6 +1238,29 @@ export const ChatArea: React.FC<{
     }, 1500);
   };
 
+  // Files dropped on the desktop window arrive from the backend as
+  // upload handles rather than as DOM drop events.  The ref keeps the
+  // listener on the current channel without resubscribing on every render.
+  const handleFilesUploadRef = useRef(handleFilesUpload);
+  handleFilesUploadRef.current = handleFilesUpload;
+  useEffect(() => {
+    if (!isTauriDesktop()) return;
+    let unlisten: (() => void) | undefined;
+    let disposed = false;
+    listen<string[]>("upload-files-dropped", ({ payload }) => {
+      if (payload.length > 0) {
+        handleFilesUploadRef.current(payload);
+      }
+    }).then((fn) => {
+      if (disposed) fn();
+      else unlisten = fn;
+    });
+    return () => {
+      disposed = true;
+      unlisten?.();
+    };
+  }, []);
+
   const cancelUploadJob = (id: string) => {
     const ac = uploadAbortsRef.current.get(id);
     if (ac) ac.abort();
@@ -2214,6 +2336,20 @@ export const ChatArea: React.FC<{
                     <button
                       className="w-full text-left px-4 py-2 text-discord-text-normal hover:bg-discord-dark-300 rounded-lg flex items-center"
                       onClick={() => {
+                        // In the app the native dialog hands us files
+                        // the backend streams from disk, so nothing is
+                        // read into the webview.
+                        if (isTauri()) {
+                          setShowPlusMenu(false);
+                          pickUploadFiles()
+                            .then((paths) => {
+                              if (paths.length > 0) handleFilesUpload(paths);
+                            })
+                            .catch((error) =>
+                              console.error("File picker failed:", error),
+                            );
+                          return;
+                        }
                         // Multi-file picker: images, videos, audio.
                         // The backend's allowlist is the source of
                         // truth, but we hint accept= so the OS file
@@ -12,6 +12,9 @@ interface LinkPreviewProps {
   title?: string;
   snippet?: string;
   imageUrl?: string;
+  siteName?: string;
+  /** The link previewed; the first one in the message otherwise */
+  url?: string;
   theme: string;
   messageContent: string;
   serverId?: string;
@@ -21,6 +24,8 @@ export const LinkPreview: React.FC<LinkPreviewProps> = ({
   title,
   snippet,
   imageUrl,
+  siteName,
+  url,
   theme,
   messageContent,
   serverId,
@@ -45,11 +50,14 @@ export const LinkPreview: React.FC<LinkPreviewProps> = ({
   const cleanContent = stripIrcFormatting(messageContent).replace(/\*\*/g, "");
   const urlRegex = /\b(?:https?):\/\/[^\s<>"']+/i;
   const match = cleanContent.match(urlRegex);
-  const firstUrl = match ? match[0] : undefined;
+  const firstUrl = url ?? (match ? match[0] : undefined);
 
-  // Check if image is from a trusted source (server filehost or globally configured trusted URLs)
+  // Check if image is from a trusted source (server filehost or globally configured trusted URLs).
+  // Images the backend fetched arrive as data URLs and reach no host.
   const isTrustedImage =
-    imageUrl && isUrlFromTrustedSource(imageUrl, server?.filehost);
+    imageUrl &&
+    (imageUrl.startsWith("data:image/") ||
+      isUrlFromTrustedSource(imageUrl, server?.filehost));
   // Show image if it's from a trusted source and safe media is enabled, or if external content is allowed
   const shouldShowImage =
     imageUrl && ((isTrustedImage && showSafeMedia) || showExternalContent);
@@ -111,6 +119,13 @@ export const LinkPreview: React.FC<LinkPreviewProps> = ({
           )}
           {(title || snippet) && (
             <div className="flex-grow pl-[40px] pr-2 pt-2 pb-2 min-w-0">
+              {siteName && (
+                <div
+                  className={`text-${theme}-text-muted mb-0.5 truncate text-[10px] uppercase`}
+                >
+                  {siteName}
+                </div>
+              )}
               {title && (
                 <div
                   className={`font-semibold text-${theme}-text mb-1 line-clamp-2 text-xs`}
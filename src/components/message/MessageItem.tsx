@@ -1,6 +1,7 @@
 import { Plural, Trans, useLingui } from "@lingui/react/macro";
 import type * as React from "react";
 import { memo, useCallback, useMemo, useRef, useState } from "react";
+import { useLinkPreview } from "../../hooks/useLinkPreview";
 import { useLongPress } from "../../hooks/useLongPress";
 import { useMediaQuery } from "../../hooks/useMediaQuery";
 import { renderWithCustomEmoji, useEmojiResolver } from "../../lib/customEmoji";
@@ -13,11 +14,13 @@ import {
 import {
   canShowMedia,
   extractMediaFromMessage,
+  extractMediaFromText,
   type MediaEntry,
   type MediaType,
   mediaLevelToSettings,
 } from "../../lib/mediaUtils";
 import { stripIrcFormatting } from "../../lib/messageFormatter";
+import { isTauri } from "../../lib/platformUtils";
 import useStore, { loadSavedMetadata } from "../../store";
 import type { MessageType, PrivateChat, User } from "../../types";
 import MessageBottomSheet from "../mobile/MessageBottomSheet";
@@ -340,6 +343,9 @@ export const MessageItem = memo((props: MessageItemProps) => {
   const enableMarkdownRendering = useStore(
     useCallback((state) => state.globalSettings.enableMarkdownRendering, []),
   );
+  const fetchLinkPreviews = useStore(
+    useCallback((state) => state.globalSettings.fetchLinkPreviews, []),
+  );
   const openMedia = useStore(useCallback((state) => state.openMedia, []));
   const canRedact =
     !isSystem &&
@@ -438,6 +444,27 @@ export const MessageItem = memo((props: MessageItemProps) => {
     });
   }, []);
 
+  // Links the server didn't preview and that didn't turn out to be media
+  // get a preview fetched by the backend
+  const hasServerPreview = !!(
+    message.linkPreviewTitle ||
+    message.linkPreviewSnippet ||
+    message.linkPreviewMeta
+  );
+  const fetchedPreviewUrl = useMemo(() => {
+    if (!fetchLinkPreviews || hasServerPreview || !isTauri()) return null;
+    const entry = extractMediaFromText(message.content).find(
+      (e) => e.type === null && !resolvedProbeTypes.has(e.url),
+    );
+    return entry?.url ?? null;
+  }, [
+    fetchLinkPreviews,
+    hasServerPreview,
+    message.content,
+    resolvedProbeTypes,
+  ]);
+  const fetchedPreview = useLinkPreview(fetchedPreviewUrl);
+
   const firstOpenableMedia = mediaEntries.find(
     (e) => e.type !== null || resolvedProbeTypes.has(e.url),
   );
@@ -873,9 +900,7 @@ export const MessageItem = memo((props: MessageItemProps) => {
               })()}
 
             {/* Render link preview if available */}
-            {(message.linkPreviewTitle ||
-              message.linkPreviewSnippet ||
-              message.linkPreviewMeta) && (
+            {hasServerPreview && (
               <LinkPreview
                 title={message.linkPreviewTitle}
                 snippet={message.linkPreviewSnippet}
@@ -885,6 +910,18 @@ export const MessageItem = memo((props: MessageItemProps) => {
                 serverId={serverId}
               />
             )}
+            {fetchedPreview && fetchedPreviewUrl && (
+              <LinkPreview
+                title={fetchedPreview.title ?? undefined}
+                snippet={fetchedPreview.description ?? undefined}
+                imageUrl={fetchedPreview.image ?? undefined}
+                siteName={fetchedPreview.siteName ?? undefined}
+                url={fetchedPreviewUrl}
+                theme={theme}
+                messageContent={message.content}
+                serverId={serverId}
+              />
+            )}
           </div>
 
           <ReactionsWithActions
@@ -5,7 +5,7 @@ import { FaQuestionCircle } from "react-icons/fa";
 import BaseModal from "../../lib/modal/BaseModal";
 import { Button, ModalBody, ModalFooter } from "../../lib/modal/components";
 import { getBuiltinOAuthConfig } from "../../lib/oauth";
-import { isTauri } from "../../lib/platformUtils";
+import { isTauri, isTauriDesktop } from "../../lib/platformUtils";
 import useStore from "../../store";
 import type { ServerOAuthConfig } from "../../types";
 import { OAuthSection } from "./OAuthSection";
@@ -112,7 +112,9 @@ export const AddServerModal: React.FC = () => {
 
     try {
       let finalHost = serverHost;
-      if (isTauri()) {
+      if (isTauriDesktop() && serverHost.trim().startsWith("unix://")) {
+        finalHost = serverHost.trim();
+      } else if (isTauri()) {
         const port = Number.parseInt(serverPort, 10);
         const cleanHost = serverHost.replace(
           /^(https?|wss|ircs?|irc):\/\//,
@@ -197,6 +199,14 @@ export const AddServerModal: React.FC = () => {
                   }`}
                   disabled={disableServerConnectionInfo}
                 />
+                {isTauriDesktop() && !disableServerConnectionInfo && (
+                  <p className="text-discord-text-muted text-xs mt-1">
+                    <Trans>
+                      Use unix:///path/to/socket to connect to a local bouncer
+                      or server on Linux and macOS
+                    </Trans>
+                  </p>
+                )}
               </div>
 
               <div className="mb-4 flex items-end gap-4">
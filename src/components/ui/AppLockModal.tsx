@@ -0,0 +1,240 @@
+// Settings of the app lock: turning it on or off, the passphrase and the
+// idle time before it locks by itself.
+
+import type { MessageDescriptor } from "@lingui/core";
+import { msg } from "@lingui/core/macro";
+import { Trans, useLingui } from "@lingui/react/macro";
+import type React from "react";
+import { useEffect, useState } from "react";
+import {
+  changeVaultPassphrase,
+  disableVault,
+  enableVault,
+  getVaultStatus,
+  lockVault,
+  setAutoLock,
+  type VaultStatus,
+} from "../../lib/vault";
+
+interface Props {
+  onClose: () => void;
+}
+
+const AUTO_LOCK_OPTIONS: {
+  minutes: number | null;
+  label: MessageDescriptor;
+}[] = [
+  { minutes: null, label: msg`Never` },
+  { minutes: 5, label: msg`After 5 minutes` },
+  { minutes: 15, label: msg`After 15 minutes` },
+  { minutes: 30, label: msg`After 30 minutes` },
+  { minutes: 60, label: msg`After 1 hour` },
+];
+
+const inputClass =
+  "w-full px-3 py-2 rounded bg-discord-dark-400 text-white text-sm";
+
+export const AppLockModal: React.FC<Props> = ({ onClose }) => {
+  const { t, i18n } = useLingui();
+  const [status, setStatus] = useState<VaultStatus | null>(null);
+  const [current, setCurrent] = useState("");
+  const [passphrase, setPassphrase] = useState("");
+  const [confirm, setConfirm] = useState("");
+  const [busy, setBusy] = useState(false);
+  const [err, setErr] = useState<string | null>(null);
+  const [notice, setNotice] = useState<string | null>(null);
+
+  useEffect(() => {
+    getVaultStatus()
+      .then(setStatus)
+      .catch((error) => setErr(String(error)));
+  }, []);
+
+  const run = async (action: () => Promise<void>) => {
+    setErr(null);
+    setNotice(null);
+    setBusy(true);
+    try {
+      await action();
+      setCurrent("");
+      setPassphrase("");
+      setConfirm("");
+    } catch (error) {
+      setErr(String(error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const mismatch = passphrase !== confirm;
+
+  const enable = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (mismatch) return;
+    run(async () => {
+      setStatus(await enableVault(passphrase));
+      setNotice(t`The app lock is on. Raw logs were stopped.`);
+    });
+  };
+
+  const disable = () =>
+    run(async () => {
+      setStatus(await disableVault(current));
+      setNotice(t`The app lock is off.`);
+    });
+
+  const change = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (mismatch) return;
+    run(async () => {
+      await changeVaultPassphrase(current, passphrase);
+      setNotice(t`Passphrase changed.`);
+    });
+  };
+
+  const changeAutoLock = (value: string) =>
+    run(async () => {
+      setStatus(await setAutoLock(value ? Number(value) : null));
+    });
+
+  const lockNow = () =>
+    run(async () => {
+      await lockVault();
+      onClose();
+    });
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-70 flex items-center justify-center z-50">
+      <div className="bg-discord-dark-200 rounded-lg w-full max-w-md p-5">
+        <div className="flex justify-between items-center mb-4">
+          <h2 className="text-lg font-semibold text-white">
+            <Trans>App lock</Trans>
+          </h2>
+          <button
+            type="button"
+            onClick={onClose}
+            className="text-discord-text-muted hover:text-white"
+          >
+            ✕
+          </button>
+        </div>
+
+        <p className="text-discord-text-muted text-xs mb-4">
+          <Trans>
+            Encrypts saved passwords, chat logs and message history with a
+            passphrase. While locked you stay connected, but nothing new is
+            saved until you unlock. A forgotten passphrase can't be recovered.
+          </Trans>
+        </p>
+
+        {status && !status.enabled && (
+          <form onSubmit={enable} className="space-y-2">
+            <input
+              type="password"
+              value={passphrase}
+              onChange={(e) => setPassphrase(e.target.value)}
+              placeholder={t`Passphrase (at least 8 characters)`}
+              className={inputClass}
+            />
+            <input
+              type="password"
+              value={confirm}
+              onChange={(e) => setConfirm(e.target.value)}
+              placeholder={t`Repeat passphrase`}
+              className={inputClass}
+            />
+            <button
+              type="submit"
+              disabled={busy || !passphrase || mismatch}
+              className="w-full px-3 py-2 rounded bg-discord-green text-white text-sm font-medium disabled:opacity-50"
+            >
+              <Trans>Turn on app lock</Trans>
+            </button>
+          </form>
+        )}
+
+        {status?.enabled && (
+          <div className="space-y-4">
+            <div className="flex items-center gap-2">
+              <label htmlFor="auto-lock" className="flex-1 text-sm text-white">
+                <Trans>Lock when idle</Trans>
+              </label>
+              <select
+                id="auto-lock"
+                value={status.autoLockMinutes ?? ""}
+                disabled={busy}
+                onChange={(e) => changeAutoLock(e.target.value)}
+                className="px-2 py-1 rounded bg-discord-dark-400 text-white text-sm"
+              >
+                {AUTO_LOCK_OPTIONS.map(({ minutes, label }) => (
+                  <option key={minutes ?? "never"} value={minutes ?? ""}>
+                    {i18n._(label)}
+                  </option>
+                ))}
+              </select>
+              <button
+                type="button"
+                disabled={busy}
+                onClick={lockNow}
+                className="px-3 py-1 rounded bg-discord-blue text-white text-sm disabled:opacity-50"
+              >
+                <Trans>Lock now</Trans>
+              </button>
+            </div>
+
+            <form onSubmit={change} className="space-y-2">
+              <input
+                type="password"
+                value={current}
+                onChange={(e) => setCurrent(e.target.value)}
+                placeholder={t`Current passphrase`}
+                className={inputClass}
+              />
+              <input
+                type="password"
+                value={passphrase}
+                onChange={(e) => setPassphrase(e.target.value)}
+                placeholder={t`New passphrase`}
+                className={inputClass}
+              />
+              <input
+                type="password"
+                value={confirm}
+                onChange={(e) => setConfirm(e.target.value)}
+                placeholder={t`Repeat new passphrase`}
+                className={inputClass}
+              />
+              <div className="flex gap-2">
+                <button
+                  type="submit"
+                  disabled={busy || !current || !passphrase || mismatch}
+                  className="flex-1 px-3 py-2 rounded bg-discord-blue text-white text-sm font-medium disabled:opacity-50"
+                >
+                  <Trans>Change passphrase</Trans>
+                </button>
+                <button
+                  type="button"
+                  disabled={busy || !current}
+                  onClick={disable}
+                  className="px-3 py-2 rounded text-discord-red text-sm disabled:opacity-50"
+                >
+                  <Trans>Turn off</Trans>
+                </button>
+              </div>
+            </form>
+          </div>
+        )}
+
+        {busy && (
+          <p className="text-discord-text-muted text-xs mt-3">
+            <Trans>Working…</Trans>
+          </p>
+        )}
+        {notice && <p className="text-discord-green text-xs mt-3">{notice}</p>}
+        {err && <p className="text-discord-red text-xs mt-3">{err}</p>}
+      </div>
+    </div>
+  );
+};
+
+export default AppLockModal;
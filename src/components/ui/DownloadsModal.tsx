@@ -0,0 +1,288 @@
+// Downloads of the desktop download manager: progress, pause/resume, cancel
+// and the download folder.
+
+import { t } from "@lingui/core/macro";
+import { Trans } from "@lingui/react/macro";
+import { listen } from "@tauri-apps/api/event";
+import type React from "react";
+import { useEffect, useState } from "react";
+import { FaFolderOpen, FaPause, FaPlay, FaRedo, FaTimes } from "react-icons/fa";
+import {
+  cancelDownload,
+  chooseDownloadFolder,
+  type DownloadConfig,
+  type DownloadEntry,
+  getDownloadConfig,
+  listDownloads,
+  pauseDownload,
+  resumeDownload,
+  revealDownload,
+  setDownloadConfig,
+} from "../../lib/downloads";
+
+interface Props {
+  onClose: () => void;
+}
+
+const CONCURRENCY_OPTIONS = [1, 2, 3, 4, 6, 8];
+
+function formatBytes(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(0)} KB`;
+  if (bytes < 1024 * 1024 * 1024) {
+    return `${(bytes / 1024 / 1024).toFixed(1)} MB`;
+  }
+  return `${(bytes / 1024 / 1024 / 1024).toFixed(2)} GB`;
+}
+
+function describe(entry: DownloadEntry): string {
+  const size = entry.total
+    ? `${formatBytes(entry.received)} / ${formatBytes(entry.total)}`
+    : formatBytes(entry.received);
+  switch (entry.status) {
+    case "queued":
+      return t`Waiting`;
+    case "downloading":
+      return size;
+    case "paused":
+      return t`Paused · ${size}`;
+    case "failed": {
+      const error = entry.error ?? t`unknown error`;
+      return t`Failed: ${error}`;
+    }
+    case "done":
+      return entry.path ?? t`Done`;
+  }
+}
+
+export const DownloadsModal: React.FC<Props> = ({ onClose }) => {
+  const [entries, setEntries] = useState<DownloadEntry[]>([]);
+  const [config, setConfig] = useState<DownloadConfig | null>(null);
+  const [err, setErr] = useState<string | null>(null);
+
+  useEffect(() => {
+    listDownloads()
+      .then(setEntries)
+      .catch((error) => setErr(String(error)));
+    getDownloadConfig()
+      .then(setConfig)
+      .catch((error) => setErr(String(error)));
+    const unlisteners = [
+      listen<DownloadEntry>("download-updated", ({ payload }) =>
+        setEntries((current) =>
+          current.some((e) => e.id === payload.id)
+            ? current.map((e) => (e.id === payload.id ? payload : e))
+            : [...current, payload],
+        ),
+      ),
+      listen<{ id: string }>("download-removed", ({ payload }) =>
+        setEntries((current) => current.filter((e) => e.id !== payload.id)),
+      ),
+    ];
+    return () => {
+      for (const unlisten of unlisteners) unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const run = (action: () => Promise<void>) => {
+    setErr(null);
+    action().catch((error) => setErr(String(error)));
+  };
+
+  const saveConfig = (next: DownloadConfig) =>
+    run(async () => {
+      await setDownloadConfig(next);
+      setConfig(next);
+    });
+
+  const chooseFolder = () =>
+    run(async () => {
+      const folder = await chooseDownloadFolder();
+      if (folder && config) {
+        await setDownloadConfig({ ...config, downloadDir: folder });
+        setConfig({ ...config, downloadDir: folder });
+      }
+    });
+
+  const clearFinished = () =>
+    run(async () => {
+      for (const entry of entries) {
+        if (entry.status === "done") await cancelDownload(entry.id);
+      }
+    });
+
+  const sorted = [...entries].sort((a, b) => b.createdAt - a.createdAt);
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-70 flex items-center justify-center z-50">
+      <div className="bg-discord-dark-200 rounded-lg w-full max-w-lg p-5 max-h-[80vh] flex flex-col">
+        <div className="flex justify-between items-center mb-4">
+          <h2 className="text-lg font-semibold text-white">
+            <Trans>Downloads</Trans>
+          </h2>
+          <button
+            type="button"
+            onClick={onClose}
+            className="text-discord-text-muted hover:text-white"
+          >
+            ✕
+          </button>
+        </div>
+
+        {config && (
+          <div className="space-y-2 mb-4 text-sm">
+            <div className="flex items-center gap-2">
+              <span className="flex-1 text-white truncate">
+                {config.downloadDir ?? t`Ask where to save each download`}
+              </span>
+              <button
+                type="button"
+                onClick={chooseFolder}
+                className="px-3 py-1 rounded bg-discord-blue text-white text-sm"
+              >
+                <Trans>Choose folder</Trans>
+              </button>
+              {config.downloadDir && (
+                <button
+                  type="button"
+                  onClick={() => saveConfig({ ...config, downloadDir: null })}
+                  className="px-3 py-1 rounded text-discord-text-muted hover:text-white text-sm"
+                >
+                  <Trans>Always ask</Trans>
+                </button>
+              )}
+            </div>
+            <div className="flex items-center gap-2">
+              <label htmlFor="download-concurrency" className="flex-1 text-white">
+                <Trans>Simultaneous downloads</Trans>
+              </label>
+              <select
+                id="download-concurrency"
+                value={config.maxConcurrent}
+                onChange={(e) =>
+                  saveConfig({
+                    ...config,
+                    maxConcurrent: Number(e.target.value),
+                  })
+                }
+                className="px-2 py-1 rounded bg-discord-dark-400 text-white text-sm"
+              >
+                {CONCURRENCY_OPTIONS.map((n) => (
+                  <option key={n} value={n}>
+                    {n}
+                  </option>
+                ))}
+              </select>
+            </div>
+          </div>
+        )}
+
+        <div className="flex-1 overflow-y-auto space-y-2">
+          {sorted.length === 0 && (
+            <p className="text-discord-text-muted text-sm">
+              <Trans>No downloads yet.</Trans>
+            </p>
+          )}
+          {sorted.map((entry) => {
+            const percent =
+              entry.total && entry.status !== "done"
+                ? Math.floor((entry.received / entry.total) * 100)
+                : null;
+            return (
+              <div
+                key={entry.id}
+                className="bg-discord-dark-400 rounded px-3 py-2 text-sm"
+              >
+                <div className="flex items-center gap-2">
+                  <span
+                    className="flex-1 text-white truncate"
+                    title={entry.url}
+                  >
+                    {entry.filename ?? entry.url}
+                  </span>
+                  {entry.status === "downloading" ||
+                  entry.status === "queued" ? (
+                    <button
+                      type="button"
+                      title={t`Pause`}
+                      onClick={() => run(() => pauseDownload(entry.id))}
+                      className="text-discord-text-muted hover:text-white"
+                    >
+                      <FaPause className="w-3 h-3" />
+                    </button>
+                  ) : entry.status === "paused" ? (
+                    <button
+                      type="button"
+                      title={t`Resume`}
+                      onClick={() => run(() => resumeDownload(entry.id))}
+                      className="text-discord-text-muted hover:text-white"
+                    >
+                      <FaPlay className="w-3 h-3" />
+                    </button>
+                  ) : entry.status === "failed" ? (
+                    <button
+                      type="button"
+                      title={t`Retry`}
+                      onClick={() => run(() => resumeDownload(entry.id))}
+                      className="text-discord-text-muted hover:text-white"
+                    >
+                      <FaRedo className="w-3 h-3" />
+                    </button>
+                  ) : null}
+                  <button
+                    type="button"
+                    title={t`Show in folder`}
+                    onClick={() => run(() => revealDownload(entry.id))}
+                    className="text-discord-text-muted hover:text-white"
+                  >
+                    <FaFolderOpen className="w-3 h-3" />
+                  </button>
+                  <button
+                    type="button"
+                    title={
+                      entry.status === "done" ? t`Remove from list` : t`Cancel`
+                    }
+                    onClick={() => run(() => cancelDownload(entry.id))}
+                    className="text-discord-text-muted hover:text-discord-red"
+                  >
+                    <FaTimes className="w-3 h-3" />
+                  </button>
+                </div>
+                {percent !== null && (
+                  <div className="h-1 mt-2 rounded bg-discord-dark-500 overflow-hidden">
+                    <div
+                      className="h-full bg-discord-blue"
+                      style={{ width: `${percent}%` }}
+                    />
+                  </div>
+                )}
+                <p
+                  className={`text-xs mt-1 truncate ${
+                    entry.status === "failed"
+                      ? "text-discord-red"
+                      : "text-discord-text-muted"
+                  }`}
+                >
+                  {describe(entry)}
+                </p>
+              </div>
+            );
+          })}
+        </div>
+
+        {entries.some((e) => e.status === "done") && (
+          <button
+            type="button"
+            onClick={clearFinished}
+            className="mt-3 self-end text-xs text-discord-text-muted hover:text-white"
+          >
+            <Trans>Clear finished</Trans>
+          </button>
+        )}
+        {err && <p className="text-discord-red text-xs mt-3">{err}</p>}
+      </div>
+    </div>
+  );
+};
+
+export default DownloadsModal;
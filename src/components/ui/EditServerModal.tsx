@@ -15,6 +15,7 @@ import {
 import { useMediaQuery } from "../../hooks/useMediaQuery";
 import { useModalBehavior } from "../../hooks/useModalBehavior";
 import { getBuiltinOAuthConfig } from "../../lib/oauth";
+import { isTauri } from "../../lib/platformUtils";
 import useStore, { loadSavedServers } from "../../store";
 import type { ServerConfig, ServerOAuthConfig } from "../../types";
 import ChangePasswordModal from "./ChangePasswordModal";
@@ -57,6 +58,9 @@ export const EditServerModal: React.FC<EditServerModalProps> = ({
     serverConfig?.port?.toString() || server?.port?.toString() || "443",
   );
   const [nickname, setNickname] = useState(serverConfig?.nickname || "");
+  const [nativeWebSocket, setNativeWebSocket] = useState(
+    serverConfig?.nativeWebSocket || false,
+  );
   const [password, setPassword] = useState("");
 
   const [saslAccountName, setSaslAccountName] = useState(
@@ -150,6 +154,7 @@ export const EditServerModal: React.FC<EditServerModalProps> = ({
         host: serverHost.trim(),
         port: Number.parseInt(serverPort, 10),
         nickname: nickname.trim(),
+        nativeWebSocket,
         password: password.trim() || undefined,
         saslAccountName: finalSaslAccountName || undefined,
         // The existing storage format keeps SASL passwords base64-encoded.
@@ -236,6 +241,27 @@ export const EditServerModal: React.FC<EditServerModalProps> = ({
           className={inputClass}
         />
       </div>
+      {isTauri() && !/^(ircs?|unix):\/\//.test(serverHost.trim()) && (
+        <div className="flex items-center space-x-2">
+          <input
+            type="checkbox"
+            id="nativeWebSocket"
+            checked={nativeWebSocket}
+            onChange={() => setNativeWebSocket(!nativeWebSocket)}
+            className="accent-discord-accent rounded"
+          />
+          <label
+            htmlFor="nativeWebSocket"
+            className="text-discord-text-muted text-sm flex items-center"
+          >
+            Use the native WebSocket transport{" "}
+            <FaQuestionCircle
+              title="Connect through the app's own socket layer instead of the webview. Takes effect on the next connect."
+              className="inline-block text-discord-text-muted cursor-help text-xs ml-1"
+            />
+          </label>
+        </div>
+      )}
     </div>
   );
 
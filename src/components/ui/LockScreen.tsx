@@ -0,0 +1,68 @@
+// Covers the app while the app lock is engaged. Connections keep running
+// underneath; unlocking writes out what was held in memory meanwhile.
+
+import { Trans, useLingui } from "@lingui/react/macro";
+import type React from "react";
+import { useState } from "react";
+import { unlockVault } from "../../lib/vault";
+
+interface Props {
+  onUnlocked: () => void;
+}
+
+export const LockScreen: React.FC<Props> = ({ onUnlocked }) => {
+  const { t } = useLingui();
+  const [passphrase, setPassphrase] = useState("");
+  const [busy, setBusy] = useState(false);
+  const [err, setErr] = useState<string | null>(null);
+
+  const unlock = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!passphrase) return;
+    setErr(null);
+    setBusy(true);
+    try {
+      await unlockVault(passphrase);
+      setPassphrase("");
+      onUnlocked();
+    } catch (error) {
+      setErr(String(error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 bg-discord-dark-300 flex items-center justify-center z-[100]">
+      <form
+        onSubmit={unlock}
+        className="bg-discord-dark-200 rounded-lg w-full max-w-sm p-6"
+      >
+        <h2 className="text-lg font-semibold text-white mb-1">
+          <Trans>ObsidianIRC is locked</Trans>
+        </h2>
+        <p className="text-discord-text-muted text-xs mb-4">
+          <Trans>Enter your passphrase to continue.</Trans>
+        </p>
+        <input
+          type="password"
+          autoFocus
+          value={passphrase}
+          onChange={(e) => setPassphrase(e.target.value)}
+          placeholder={t`Passphrase`}
+          className="w-full px-3 py-2 rounded bg-discord-dark-400 text-white text-sm mb-3"
+        />
+        <button
+          type="submit"
+          disabled={busy || !passphrase}
+          className="w-full px-3 py-2 rounded bg-discord-blue text-white text-sm font-medium disabled:opacity-50"
+        >
+          {busy ? t`Unlocking…` : t`Unlock`}
+        </button>
+        {err && <p className="text-discord-red text-xs mt-3">{err}</p>}
+      </form>
+    </div>
+  );
+};
+
+export default LockScreen;
@@ -411,6 +411,9 @@ export function MediaViewerModal({
   >([]);
   const [currentIndex, setCurrentIndex] = useState(-1);
   const [isDownloading, setIsDownloading] = useState(false);
+  // Percentage of the running download, when the size is known
+  const [downloadPercent, setDownloadPercent] = useState<number | null>(null);
+  const downloadIdRef = useRef<string | null>(null);
   const [savedMessage, setSavedMessage] = useState("");
   const [failedUrls, setFailedUrls] = useState<Set<string>>(new Set());
   const [showComments, setShowComments] = useState(false);
@@ -1066,10 +1069,32 @@ export function MediaViewerModal({
   };
 
   const handleDownload = async () => {
+    const { invoke } = await import("@tauri-apps/api/core");
+    // A second click while fetching cancels
+    if (downloadIdRef.current) {
+      invoke("download_image_cancel", {
+        downloadId: downloadIdRef.current,
+      }).catch(() => {});
+      return;
+    }
+    const { listen } = await import("@tauri-apps/api/event");
+    const downloadId = crypto.randomUUID();
+    downloadIdRef.current = downloadId;
     setIsDownloading(true);
+    setDownloadPercent(null);
+    const unlisten = await listen<{
+      downloadId: string;
+      received: number;
+      total: number | null;
+    }>("download-progress", ({ payload }) => {
+      if (payload.downloadId !== downloadId || !payload.total) return;
+      setDownloadPercent(Math.floor((payload.received / payload.total) * 100));
+    });
     try {
-      const { invoke } = await import("@tauri-apps/api/core");
-      const msg = await invoke<string>("download_image", { url: currentUrl });
+      const msg = await invoke<string>("download_image", {
+        url: currentUrl,
+        downloadId,
+      });
       const toast = msg || t`Saved`;
       setSavedMessage(toast);
       setTimeout(() => setSavedMessage(""), 4000);
@@ -1078,7 +1103,10 @@ export function MediaViewerModal({
       setSavedMessage(t`Save failed: ${msg}`);
       setTimeout(() => setSavedMessage(""), 6000);
     } finally {
+      unlisten();
+      downloadIdRef.current = null;
       setIsDownloading(false);
+      setDownloadPercent(null);
     }
   };
 
@@ -1275,13 +1303,23 @@ export function MediaViewerModal({
                       <button
                         type="button"
                         onClick={handleDownload}
-                        disabled={isDownloading}
-                        title={t`Download`}
-                        aria-label={t`Download`}
-                        className="p-1.5 rounded-full hover:bg-white/10 disabled:opacity-30 disabled:cursor-not-allowed transition-colors"
+                        title={
+                          isDownloading ? t`Cancel download` : t`Download`
+                        }
+                        aria-label={
+                          isDownloading ? t`Cancel download` : t`Download`
+                        }
+                        className="flex items-center gap-1 p-1.5 rounded-full hover:bg-white/10 transition-colors"
                       >
                         {isDownloading ? (
-                          <FaSpinner className="w-3.5 h-3.5 animate-spin" />
+                          <>
+                            <FaSpinner className="w-3.5 h-3.5 animate-spin" />
+                            {downloadPercent !== null && (
+                              <span className="text-[10px] tabular-nums">
+                                {downloadPercent}%
+                              </span>
+                            )}
+                          </>
                         ) : (
                           <FaDownload className="w-3.5 h-3.5" />
                         )}
@@ -1,6 +1,12 @@
+import { Trans } from "@lingui/react/macro";
 import type React from "react";
 import { useState } from "react";
-import { beginOauthLogin, OAUTH_PRESETS } from "../../lib/oauth";
+import {
+  beginOauthLogin,
+  cancelOauthLogin,
+  OAUTH_PRESETS,
+} from "../../lib/oauth";
+import { isTauri } from "../../lib/platformUtils";
 import type { ServerOAuthConfig } from "../../types";
 import { TextInput } from "./TextInput";
 
@@ -253,6 +259,15 @@ export const OAuthSection: React.FC<OAuthSectionProps> = ({
                 ? `Re-sign in with ${locked.providerLabel}`
                 : `Sign in with ${locked.providerLabel}`}
           </button>
+          {signingIn && isTauri() && (
+            <button
+              type="button"
+              onClick={cancelOauthLogin}
+              className="px-3 py-1 text-sm rounded font-medium bg-gray-600 text-gray-300 hover:bg-gray-500"
+            >
+              <Trans>Cancel</Trans>
+            </button>
+          )}
           {accessToken && (
             <button
               type="button"
@@ -467,6 +482,15 @@ export const OAuthSection: React.FC<OAuthSectionProps> = ({
                   ? `Re-sign in with ${providerLabel || "OAuth"}`
                   : `Sign in with ${providerLabel || "OAuth"}`}
             </button>
+            {signingIn && isTauri() && (
+              <button
+                type="button"
+                onClick={cancelOauthLogin}
+                className="px-3 py-1 text-sm rounded font-medium bg-gray-600 text-gray-300 hover:bg-gray-500"
+              >
+                <Trans>Cancel</Trans>
+              </button>
+            )}
             {accessToken && (
               <button
                 type="button"
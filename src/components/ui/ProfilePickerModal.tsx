@@ -0,0 +1,192 @@
+// Picks the profile to run at startup. Profiles are separate app instances
+// with their own storage, so choosing another one restarts into it.
+
+import { Trans, useLingui } from "@lingui/react/macro";
+import type React from "react";
+import { useEffect, useState } from "react";
+import {
+  createProfile,
+  deleteProfile,
+  launchProfile,
+  listProfiles,
+  type ProfileList,
+  setAskAtStartup,
+} from "../../lib/profiles";
+
+interface Props {
+  onClose: () => void;
+}
+
+export const ProfilePickerModal: React.FC<Props> = ({ onClose }) => {
+  const { t } = useLingui();
+  const [list, setList] = useState<ProfileList>({
+    current: "default",
+    profiles: [],
+    askAtStartup: false,
+  });
+  const [newName, setNewName] = useState("");
+  const [busy, setBusy] = useState(false);
+  const [err, setErr] = useState<string | null>(null);
+
+  useEffect(() => {
+    listProfiles()
+      .then(setList)
+      .catch((error) => setErr(String(error)));
+  }, []);
+
+  const run = async (action: () => Promise<void>) => {
+    setErr(null);
+    setBusy(true);
+    try {
+      await action();
+    } catch (error) {
+      setErr(String(error));
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const open = (name: string, replace: boolean) => {
+    if (name === list.current) {
+      onClose();
+      return;
+    }
+    run(async () => {
+      await launchProfile(name, replace);
+      if (!replace) onClose();
+    });
+  };
+
+  const create = (e: React.FormEvent) => {
+    e.preventDefault();
+    const name = newName.trim();
+    if (!name) return;
+    run(async () => {
+      await createProfile(name);
+      setList((l) => ({
+        ...l,
+        profiles: [...l.profiles, name],
+      }));
+      setNewName("");
+    });
+  };
+
+  const remove = (name: string) => {
+    if (
+      !window.confirm(
+        t`Delete profile "${name}" with all its networks, logs and history?`,
+      )
+    )
+      return;
+    run(async () => {
+      await deleteProfile(name);
+      setList((l) => ({
+        ...l,
+        profiles: l.profiles.filter((p) => p !== name),
+      }));
+    });
+  };
+
+  const toggleAsk = (ask: boolean) =>
+    run(async () => {
+      await setAskAtStartup(ask);
+      setList((l) => ({ ...l, askAtStartup: ask }));
+    });
+
+  return (
+    <div className="fixed inset-0 bg-black bg-opacity-70 flex items-center justify-center z-50">
+      <div className="bg-discord-dark-200 rounded-lg w-full max-w-md p-5">
+        <div className="flex justify-between items-center mb-4">
+          <h2 className="text-lg font-semibold text-white">
+            <Trans>Choose a profile</Trans>
+          </h2>
+          <button
+            type="button"
+            onClick={onClose}
+            className="text-discord-text-muted hover:text-white"
+          >
+            ✕
+          </button>
+        </div>
+
+        <ul className="space-y-2 mb-4">
+          {list.profiles.map((name) => (
+            <li
+              key={name}
+              className="flex items-center gap-2 px-3 py-2 rounded bg-discord-dark-400"
+            >
+              <span className="flex-1 text-white text-sm truncate">
+                {name}
+                {name === list.current && (
+                  <span className="text-discord-text-muted">
+                    {" "}
+                    <Trans>(current)</Trans>
+                  </span>
+                )}
+              </span>
+              <button
+                type="button"
+                disabled={busy}
+                onClick={() => open(name, true)}
+                className="px-2 py-1 rounded bg-discord-blue text-white text-xs disabled:opacity-50"
+              >
+                <Trans>Open</Trans>
+              </button>
+              {name !== list.current && (
+                <button
+                  type="button"
+                  disabled={busy}
+                  onClick={() => open(name, false)}
+                  title={t`Run this profile next to the current one`}
+                  className="px-2 py-1 rounded bg-discord-dark-300 text-white text-xs disabled:opacity-50"
+                >
+                  <Trans>Side by side</Trans>
+                </button>
+              )}
+              {name !== "default" && name !== list.current && (
+                <button
+                  type="button"
+                  disabled={busy}
+                  onClick={() => remove(name)}
+                  className="px-2 py-1 rounded text-discord-red text-xs disabled:opacity-50"
+                >
+                  <Trans>Delete</Trans>
+                </button>
+              )}
+            </li>
+          ))}
+        </ul>
+
+        <form onSubmit={create} className="flex gap-2 mb-3">
+          <input
+            value={newName}
+            onChange={(e) => setNewName(e.target.value)}
+            placeholder={t`New profile name`}
+            className="flex-1 px-3 py-2 rounded bg-discord-dark-400 text-white text-sm"
+          />
+          <button
+            type="submit"
+            disabled={busy || !newName.trim()}
+            className="px-3 py-2 rounded bg-discord-green text-white text-sm font-medium disabled:opacity-50"
+          >
+            <Trans>Create</Trans>
+          </button>
+        </form>
+
+        <label className="flex items-center gap-2 text-xs text-discord-text-muted">
+          <input
+            type="checkbox"
+            checked={list.askAtStartup}
+            disabled={busy}
+            onChange={(e) => toggleAsk(e.target.checked)}
+          />
+          <Trans>Ask which profile to use at startup</Trans>
+        </label>
+
+        {err && <p className="text-discord-red text-xs mt-3">{err}</p>}
+      </div>
+    </div>
+  );
+};
+
+export default ProfilePickerModal;
@@ -11,10 +11,12 @@ import {
   FaUser,
 } from "react-icons/fa";
 import { useJoinAndSelectChannel } from "../../hooks/useJoinAndSelectChannel";
+import { enqueueDownloads, mediaUrlsOf } from "../../lib/downloads";
 import { fuzzyMatch } from "../../lib/fuzzySearch";
 import ircClient from "../../lib/ircClient";
 import { settingsRegistry } from "../../lib/settings";
 import type { SettingSearchResult } from "../../lib/settings/types";
+import { lockVault } from "../../lib/vault";
 import useStore from "../../store";
 import type { Channel, PrivateChat } from "../../types";
 import { buildQuickActionContext } from "./QuickActions/context";
@@ -55,6 +57,9 @@ const QuickActions: React.FC = () => {
     setTopicModalRequest,
     setProfileViewRequest,
     toggleUserProfileModal,
+    toggleProfilePicker,
+    toggleAppLockSettings,
+    toggleDownloads,
     setChannelSettingsRequest,
     setInviteUserRequest,
   } = useStore();
@@ -486,15 +491,50 @@ const QuickActions: React.FC = () => {
             toggleUserProfileModal(true);
           }
           break;
+        case "open-profile-picker":
+          toggleProfilePicker(true);
+          break;
+        case "open-app-lock":
+          toggleAppLockSettings(true);
+          break;
+        case "lock-app":
+          lockVault().catch((error) =>
+            console.error("Failed to lock the app:", error),
+          );
+          break;
+        case "open-downloads":
+          toggleDownloads(true);
+          break;
+        case "save-channel-media":
+          if (data.serverId && data.channelId) {
+            const state = useStore.getState();
+            const urls = mediaUrlsOf(
+              state.messages[`${data.serverId}-${data.channelId}`] ?? [],
+              servers.find((s) => s.id === data.serverId)?.filehost,
+            );
+            if (urls.length === 0) break;
+            enqueueDownloads(urls)
+              .then((queued) => {
+                if (queued.length > 0) toggleDownloads(true);
+              })
+              .catch((error) =>
+                console.error("Failed to queue downloads:", error),
+              );
+          }
+          break;
       }
     },
     [
+      servers,
       setChannelSettingsRequest,
       setInviteUserRequest,
       toggleChannelListModal,
       setTopicModalRequest,
       setProfileViewRequest,
       toggleUserProfileModal,
+      toggleProfilePicker,
+      toggleAppLockSettings,
+      toggleDownloads,
     ],
   );
 
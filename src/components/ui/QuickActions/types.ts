@@ -14,7 +14,12 @@ export type UIModalAction =
   | "open-topic-modal"
   | "open-user-profile"
   | "open-rename-channel"
-  | "open-server-channels";
+  | "open-server-channels"
+  | "open-profile-picker"
+  | "open-app-lock"
+  | "lock-app"
+  | "open-downloads"
+  | "save-channel-media";
 
 export interface UIActionData {
   action: UIToggleAction | UIModalAction;
@@ -3,14 +3,18 @@ import { t } from "@lingui/core/macro";
 import type React from "react";
 import {
   FaBell,
+  FaDownload,
   FaEdit,
   FaHashtag,
   FaInfoCircle,
   FaList,
+  FaLock,
   FaPenAlt,
   FaThumbtack,
+  FaUserCircle,
   FaUserPlus,
 } from "react-icons/fa";
+import { isTauri } from "../../../lib/platformUtils";
 import type {
   QuickActionContext,
   QuickActionResultType,
@@ -154,6 +158,66 @@ export function getUIActions(): UIActionConfig[] {
       availability: (ctx) =>
         ctx.selectedChannel !== null && !!ctx.selectedChannel.topic,
     },
+    {
+      id: "open-profile-picker",
+      type: "ui-modal",
+      title: t`Switch Profile`,
+      description: t`Open another profile or run one side by side`,
+      keywords: ["profile", "switch", "identity", "portable", "work"],
+      score: 70,
+      icon: <FaUserCircle className="w-4 h-4" />,
+      badge: t`Modal`,
+      data: { action: "open-profile-picker" },
+      availability: () => isTauri(),
+    },
+    {
+      id: "open-app-lock",
+      type: "ui-modal",
+      title: t`App Lock Settings`,
+      description: t`Encrypt saved data with a passphrase`,
+      keywords: ["lock", "passphrase", "encrypt", "vault", "security"],
+      score: 70,
+      icon: <FaLock className="w-4 h-4" />,
+      badge: t`Modal`,
+      data: { action: "open-app-lock" },
+      availability: () => isTauri(),
+    },
+    {
+      id: "lock-app",
+      type: "ui-modal",
+      title: t`Lock App`,
+      description: t`Lock now; connections stay up`,
+      keywords: ["lock", "away", "vault", "security"],
+      score: 70,
+      icon: <FaLock className="w-4 h-4" />,
+      badge: t`Action`,
+      data: { action: "lock-app" },
+      availability: () => isTauri(),
+    },
+    {
+      id: "open-downloads",
+      type: "ui-modal",
+      title: t`Downloads`,
+      description: t`Pause, resume and find downloaded files`,
+      keywords: ["download", "files", "save", "queue"],
+      score: 70,
+      icon: <FaDownload className="w-4 h-4" />,
+      badge: t`Modal`,
+      data: { action: "open-downloads" },
+      availability: () => isTauri(),
+    },
+    {
+      id: "save-channel-media",
+      type: "ui-modal",
+      title: t`Save All Media in Channel`,
+      description: t`Download every image, video and file linked here`,
+      keywords: ["download", "save", "media", "images", "files", "all"],
+      score: 70,
+      icon: <FaDownload className="w-4 h-4" />,
+      badge: t`Action`,
+      data: { action: "save-channel-media" },
+      availability: (ctx) => isTauri() && ctx.selectedChannel !== null,
+    },
     {
       id: "open-user-profile",
       type: "ui-modal",
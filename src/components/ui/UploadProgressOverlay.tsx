@@ -1,17 +1,26 @@
 // Progress strip rendered above the chat input while files are
 // uploading.  Each row shows the file name, a determinate bar driven
-// by XHR's upload-progress event, and a "done" / "failed" mark when
-// the upload settles.
+// by XHR's (or the Tauri backend's) upload-progress events, and a
+// "done" / "failed" mark when the upload settles.  Images the backend
+// resized first also show their size before and after.
 
+import { plural, t } from "@lingui/core/macro";
 import type React from "react";
 import { FaCheck, FaFile, FaSpinner, FaTimes } from "react-icons/fa";
 
 export interface UploadJob {
   id: string;
-  file: File;
+  name: string;
+  size: number;
+  /** Picked in the webview; uploaded with XHR. */
+  file?: File;
+  /** On disk (native picker, drag-and-drop); streamed by the backend. */
+  path?: string;
+  /** Size before the image was resized for upload. */
+  originalSize?: number;
   loaded: number;
   total: number;
-  status: "pending" | "uploading" | "done" | "failed";
+  status: "pending" | "processing" | "uploading" | "done" | "failed";
   error?: string;
   url?: string;
 }
@@ -29,13 +38,15 @@ function fmtSize(bytes: number): string {
 
 export const UploadProgressOverlay: React.FC<Props> = ({ jobs, onCancel }) => {
   if (jobs.length === 0) return null;
+  const count = jobs.length;
   return (
     <div className="absolute bottom-full left-0 right-0 mb-2 px-2 z-20">
       <div className="bg-discord-dark-300 rounded-md shadow-lg border border-discord-dark-200 p-2 space-y-2">
         <div className="text-xs text-discord-text-muted font-semibold uppercase tracking-wide px-1">
-          {jobs.length === 1
-            ? "Uploading 1 file"
-            : `Uploading ${jobs.length} files`}
+          {plural(count, {
+            one: "Uploading 1 file",
+            other: `Uploading ${count} files`,
+          })}
         </div>
         {jobs.map((job) => {
           const pct =
@@ -52,21 +63,23 @@ export const UploadProgressOverlay: React.FC<Props> = ({ jobs, onCancel }) => {
                 )}
                 <FaFile className="text-discord-text-muted flex-shrink-0" />
                 <span className="truncate flex-1 text-white text-xs">
-                  {job.file.name}
+                  {job.name}
                 </span>
                 <span className="text-xs text-discord-text-muted whitespace-nowrap">
                   {job.status === "failed"
-                    ? "failed"
+                    ? t`failed`
                     : job.status === "done"
-                      ? "done"
-                      : `${fmtSize(job.loaded)} / ${fmtSize(job.total || job.file.size)}`}
+                      ? t`done`
+                      : job.status === "processing"
+                        ? t`resizing`
+                        : `${fmtSize(job.loaded)} / ${fmtSize(job.total || job.size)}`}
                 </span>
                 {onCancel && job.status !== "done" && (
                   <button
                     type="button"
                     onClick={() => onCancel(job.id)}
                     className="text-discord-text-muted hover:text-white text-xs"
-                    aria-label="Cancel upload"
+                    aria-label={t`Cancel upload`}
                   >
                     ✕
                   </button>
@@ -84,6 +97,11 @@ export const UploadProgressOverlay: React.FC<Props> = ({ jobs, onCancel }) => {
                   />
                 </div>
               )}
+              {job.originalSize !== undefined && (
+                <p className="text-xs text-discord-text-muted mt-1">
+                  {t`Resized ${fmtSize(job.originalSize)} → ${fmtSize(job.size)}`}
+                </p>
+              )}
               {job.error && (
                 <p className="text-xs text-discord-red mt-1">{job.error}</p>
               )}
@@ -0,0 +1,28 @@
+import { t } from "@lingui/core/macro";
+import type React from "react";
+import { getFetchPolicy, setFetchPolicy } from "../../../lib/fetchPolicy";
+import type { SettingComponentProps } from "../../../lib/settings/types";
+import { HostListField } from "./HostListField";
+
+const loadAllowedHosts = () =>
+  getFetchPolicy().then((config) => config.allowedHosts);
+
+const saveAllowedHosts = (allowedHosts: string[]) =>
+  setFetchPolicy({ allowedHosts });
+
+/**
+ * Custom component for the hosts downloads and previews may reach on the
+ * local network. The list lives on the Rust side, not in the settings store.
+ */
+export const AllowedHostsField: React.FC<SettingComponentProps> = ({
+  disabled,
+}) => (
+  <HostListField
+    disabled={disabled}
+    load={loadAllowedHosts}
+    save={saveAllowedHosts}
+    placeholder={t`files.corp, *.intranet, 192.168.1.0/24`}
+  />
+);
+
+export default AllowedHostsField;
@@ -0,0 +1,121 @@
+import { XMarkIcon } from "@heroicons/react/24/solid";
+import { t } from "@lingui/core/macro";
+import type React from "react";
+import { useEffect, useState } from "react";
+import { TextInput } from "../TextInput";
+
+interface HostListFieldProps {
+  disabled?: boolean;
+  /** Read the list; keep the identity stable, it's an effect dependency */
+  load: () => Promise<string[]>;
+  save: (hosts: string[]) => Promise<void>;
+  placeholder: string;
+}
+
+/**
+ * An editable list of hosts kept on the Rust side rather than in the
+ * settings store; the backend validates entries as they are saved.
+ */
+export const HostListField: React.FC<HostListFieldProps> = ({
+  disabled,
+  load,
+  save: saveHosts,
+  placeholder,
+}) => {
+  const [hosts, setHosts] = useState<string[]>([]);
+  const [newHost, setNewHost] = useState("");
+  const [error, setError] = useState("");
+
+  useEffect(() => {
+    load()
+      .then(setHosts)
+      .catch((err) => setError(String(err)));
+  }, [load]);
+
+  const save = async (next: string[]) => {
+    try {
+      await saveHosts(next);
+      setHosts(next);
+      setError("");
+      return true;
+    } catch (err) {
+      setError(String(err));
+      return false;
+    }
+  };
+
+  const handleAddHost = async () => {
+    const trimmed = newHost.trim().toLowerCase();
+    if (!trimmed) return;
+    if (hosts.includes(trimmed)) {
+      setError(t`Host already exists`);
+      return;
+    }
+    if (await save([...hosts, trimmed])) setNewHost("");
+  };
+
+  const handleKeyPress = (e: React.KeyboardEvent) => {
+    if (e.key === "Enter") {
+      e.preventDefault();
+      handleAddHost();
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <div className="space-y-2">
+        <div className="flex space-x-2">
+          <TextInput
+            value={newHost}
+            onChange={(e: React.ChangeEvent<HTMLInputElement>) => {
+              setNewHost(e.target.value);
+              setError("");
+            }}
+            onKeyPress={handleKeyPress}
+            disabled={disabled}
+            placeholder={placeholder}
+            className={`flex-1 rounded border px-3 py-2 text-discord-text-normal placeholder-discord-text-muted focus:outline-none disabled:opacity-50 ${
+              error
+                ? "border-red-500 bg-red-900/20 focus:border-red-400"
+                : "border-discord-button-secondary-default bg-discord-input-bg focus:border-discord-text-link"
+            }`}
+          />
+          <button
+            type="button"
+            onClick={handleAddHost}
+            disabled={disabled || !newHost.trim()}
+            className="rounded bg-discord-button-success-default px-4 py-2 text-white hover:bg-discord-button-success-hover disabled:opacity-50 disabled:cursor-not-allowed"
+          >
+            {t`Add`}
+          </button>
+        </div>
+        {error && <p className="text-red-400 text-xs">{error}</p>}
+      </div>
+      {hosts.length > 0 && (
+        <div className="space-y-1 bg-discord-dark-400 rounded p-3">
+          {hosts.map((host) => (
+            <div
+              key={host}
+              className="flex items-center justify-between bg-discord-dark-500 rounded px-3 py-2"
+            >
+              <code className="text-discord-text-normal text-sm font-mono">
+                {host}
+              </code>
+              <button
+                type="button"
+                onClick={() => save(hosts.filter((h) => h !== host))}
+                disabled={disabled}
+                className="text-red-400 hover:text-red-300 disabled:opacity-50"
+                title={t`Remove host`}
+              >
+                <XMarkIcon className="w-4 h-4" />
+              </button>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
+
+export default HostListField;
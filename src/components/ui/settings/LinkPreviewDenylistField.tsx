@@ -0,0 +1,31 @@
+import { t } from "@lingui/core/macro";
+import type React from "react";
+import {
+  getLinkPreviewConfig,
+  setLinkPreviewConfig,
+} from "../../../lib/linkPreview";
+import type { SettingComponentProps } from "../../../lib/settings/types";
+import { HostListField } from "./HostListField";
+
+const loadDeniedDomains = () =>
+  getLinkPreviewConfig().then((config) => config.deniedDomains);
+
+const saveDeniedDomains = (deniedDomains: string[]) =>
+  setLinkPreviewConfig({ deniedDomains });
+
+/**
+ * Custom component for the domains link previews are never fetched from.
+ * The list lives on the Rust side, not in the settings store.
+ */
+export const LinkPreviewDenylistField: React.FC<SettingComponentProps> = ({
+  disabled,
+}) => (
+  <HostListField
+    disabled={disabled}
+    load={loadDeniedDomains}
+    save={saveDeniedDomains}
+    placeholder={t`example.com, tracker.example`}
+  />
+);
+
+export default LinkPreviewDenylistField;
@@ -0,0 +1,24 @@
+import { useEffect, useState } from "react";
+import { fetchLinkPreview, type LinkPreviewData } from "../lib/linkPreview";
+
+/**
+ * The backend-fetched preview of `url`: null while it loads, when there
+ * is none, or when `url` is null.
+ */
+export function useLinkPreview(url: string | null): LinkPreviewData | null {
+  const [preview, setPreview] = useState<LinkPreviewData | null>(null);
+
+  useEffect(() => {
+    setPreview(null);
+    if (!url) return;
+    let cancelled = false;
+    fetchLinkPreview(url).then((data) => {
+      if (!cancelled) setPreview(data);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [url]);
+
+  return preview;
+}
@@ -0,0 +1,74 @@
+// Desktop download manager. The Rust side keeps the queue across restarts,
+// resumes interrupted downloads and saves to the download folder when one is
+// set, asking for a location otherwise.
+
+import { invoke } from "@tauri-apps/api/core";
+import type { Message } from "../types";
+import { isUrlFromFilehost } from "./ircUtils";
+import { detectMediaType, extractMediaFromMessage } from "./mediaUtils";
+
+export type DownloadStatus =
+  | "queued"
+  | "downloading"
+  | "paused"
+  | "failed"
+  | "done";
+
+export interface DownloadEntry {
+  id: string;
+  url: string;
+  dir: string;
+  path: string | null;
+  filename: string | null;
+  status: DownloadStatus;
+  received: number;
+  total: number | null;
+  error: string | null;
+  createdAt: number;
+}
+
+export interface DownloadConfig {
+  /** Save here without asking; null shows a dialog */
+  downloadDir: string | null;
+  maxConcurrent: number;
+}
+
+export const enqueueDownloads = (urls: string[]) =>
+  invoke<DownloadEntry[]>("download_enqueue", { urls });
+
+export const listDownloads = () => invoke<DownloadEntry[]>("download_list");
+
+export const pauseDownload = (id: string) =>
+  invoke<void>("download_pause", { id });
+
+export const resumeDownload = (id: string) =>
+  invoke<void>("download_resume", { id });
+
+export const cancelDownload = (id: string) =>
+  invoke<void>("download_cancel", { id });
+
+export const revealDownload = (id: string) =>
+  invoke<void>("download_reveal", { id });
+
+export const getDownloadConfig = () =>
+  invoke<DownloadConfig>("download_get_config");
+
+export const setDownloadConfig = (config: DownloadConfig) =>
+  invoke<void>("download_set_config", { config });
+
+export const chooseDownloadFolder = () =>
+  invoke<string | null>("download_choose_folder");
+
+/** Links in `messages` worth saving: media, PDFs and filehost uploads */
+export function mediaUrlsOf(messages: Message[], filehost?: string): string[] {
+  const urls = new Set<string>();
+  for (const message of messages) {
+    for (const { url } of extractMediaFromMessage(message)) {
+      const type = detectMediaType(url);
+      if (type === "embed") continue;
+      const fromFilehost = !!filehost && isUrlFromFilehost(url, filehost);
+      if (type || fromFilehost) urls.add(url);
+    }
+  }
+  return [...urls];
+}
@@ -0,0 +1,16 @@
+// Desktop: which hosts the Rust side may fetch from when a URL comes from
+// remote content. Loopback, link-local and private addresses are blocked
+// unless listed here.
+
+import { invoke } from "@tauri-apps/api/core";
+
+export interface FetchPolicyConfig {
+  /** Host names (`files.corp`, `*.corp`), addresses or CIDR ranges */
+  allowedHosts: string[];
+}
+
+export const getFetchPolicy = () =>
+  invoke<FetchPolicyConfig>("fetch_policy_get");
+
+export const setFetchPolicy = (config: FetchPolicyConfig) =>
+  invoke<void>("fetch_policy_set", { config });
@@ -0,0 +1,54 @@
+// Image metadata (EXIF, XMP, IPTC) removal before upload.  The Rust
+// side rewrites JPEG, PNG, WebP and HEIC containers without
+// re-encoding, so pixels are untouched; only the orientation survives.
+
+import { invoke } from "@tauri-apps/api/core";
+
+export interface ImageMetadataReport {
+  format: "jpeg" | "png" | "webp" | "heif" | null;
+  /** Whether the backend can strip this file. */
+  supported: boolean;
+  /** EXIF beyond the orientation tag. */
+  exif: boolean;
+  gps: boolean;
+  xmp: boolean;
+  iptc: boolean;
+  comments: boolean;
+  camera: string | null;
+  takenAt: string | null;
+  orientation: number | null;
+}
+
+const IMAGE_EXTENSION = /\.(jpe?g|jfif|png|webp|hei[cf]|avif)$/i;
+
+/** Upload sources worth checking: files in the webview or upload handles. */
+export function isStrippableImage(source: File | string): boolean {
+  return typeof source === "string"
+    ? IMAGE_EXTENSION.test(source)
+    : IMAGE_EXTENSION.test(source.name) ||
+        /^image\/(jpeg|png|webp|hei[cf]|avif)$/.test(source.type);
+}
+
+export const imageMetadata = (handle: string) =>
+  invoke<ImageMetadataReport>("image_metadata", { handle });
+
+export const hasMetadata = (report: ImageMetadataReport) =>
+  report.exif || report.gps || report.xmp || report.iptc || report.comments;
+
+/**
+ * Reports of the images in an upload batch that carry metadata.
+ * Files the backend can't read are skipped rather than failing the
+ * batch; the upload itself reports those.
+ */
+export async function imagesWithMetadata(
+  handles: string[],
+): Promise<ImageMetadataReport[]> {
+  const reports = await Promise.all(
+    handles
+      .filter(isStrippableImage)
+      .map((handle) => imageMetadata(handle).catch(() => null)),
+  );
+  return reports.filter(
+    (report): report is ImageMetadataReport => !!report && hasMetadata(report),
+  );
+}
@@ -0,0 +1,41 @@
+// Resizing and re-encoding images before upload (Tauri only).  The
+// backend downscales images that are over the filehost's size limit
+// or the user's maximum dimension and re-encodes them as JPEG, or as
+// WebP when they have transparency; animated GIF and WebP stay
+// animated when they can be made to fit.
+
+import { invoke } from "@tauri-apps/api/core";
+
+export interface ShrinkOptions {
+  /** The filehost's limit in bytes; null or 0 for none. */
+  maxBytes?: number | null;
+  /** Longest side in pixels; null or 0 for none. */
+  maxDimension?: number | null;
+  /** JPEG and WebP quality, 1-100. */
+  quality?: number;
+}
+
+export interface ShrinkReport {
+  /** Handle of the file to upload: a resized copy, or the original. */
+  path: string;
+  changed: boolean;
+  beforeBytes: number;
+  afterBytes: number;
+  beforeWidth: number;
+  beforeHeight: number;
+  afterWidth: number;
+  afterHeight: number;
+  animated: boolean;
+}
+
+const SHRINKABLE_EXTENSION = /\.(jpe?g|jfif|png|webp|gif)$/i;
+
+export function isShrinkableImage(source: File | string): boolean {
+  return typeof source === "string"
+    ? SHRINKABLE_EXTENSION.test(source)
+    : SHRINKABLE_EXTENSION.test(source.name) ||
+        /^image\/(jpeg|png|webp|gif)$/.test(source.type);
+}
+
+export const shrinkImage = (handle: string, options: ShrinkOptions) =>
+  invoke<ShrinkReport>("image_shrink", { handle, options });
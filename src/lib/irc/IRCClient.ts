@@ -12,7 +12,6 @@ import type {
   Server,
   User,
 } from "../../types";
-import { parseIrcUrl } from "../ircUrlParser";
 import { isChannelTarget, parseMessageTags } from "../ircUtils";
 import { createSocket, type ISocket } from "../socket";
 import { IRC_DISPATCH } from "./handlers";
@@ -530,6 +529,7 @@ export class IRCClient implements IRCClientContext {
       password?: string;
       saslAccountName?: string;
       saslPassword?: string;
+      nativeWebSocket?: boolean;
     }
   > = new Map();
   private lastWakeReconnect: Map<string, number> = new Map();
@@ -617,6 +617,7 @@ export class IRCClient implements IRCClientContext {
     _saslPassword?: string,
     serverId?: string,
     oauthBearerEnabled?: boolean,
+    nativeWebSocket?: boolean,
   ): Promise<Server> {
     const connectionKey = `${host}:${port}`;
 
@@ -638,18 +639,19 @@ export class IRCClient implements IRCClientContext {
 
     // Create a new connection promise and store it
     const connectionPromise = new Promise<Server>((resolve, reject) => {
-      let protocol: "wss" | "ircs" | "irc" = "wss";
       let actualHost = host;
       let actualPort = port;
       let actualPath = "";
+      let nativeUrl: string | undefined;
 
-      if (host.startsWith("irc://") || host.startsWith("ircs://")) {
-        // Parse the IRC URL using centralized parser (Android-compatible)
-        const parsed = parseIrcUrl(host);
-
-        protocol = parsed.scheme;
-        actualHost = parsed.host;
-        actualPort = parsed.port;
+      if (
+        host.startsWith("irc://") ||
+        host.startsWith("ircs://") ||
+        host.startsWith("unix://")
+      ) {
+        // The native layer parses these itself, so ports and socket paths
+        // follow the same rules as links and the command line
+        nativeUrl = host;
       } else if (host.startsWith("wss://")) {
         // Use URL constructor to preserve path/query (e.g. wss://host/websocket?token=...)
         try {
@@ -678,8 +680,16 @@ export class IRCClient implements IRCClientContext {
         }
       }
 
-      const url = `${protocol}://${actualHost}:${actualPort}${actualPath}`;
-      const socket = createSocket(url);
+      const url =
+        nativeUrl ?? `wss://${actualHost}:${actualPort}${actualPath}`;
+      // Internal reconnects keep the transport the server was opened with
+      const useNativeWebSocket =
+        nativeWebSocket ??
+        (serverId
+          ? this.serverConnectParams.get(serverId)?.nativeWebSocket
+          : undefined) ??
+        false;
+      const socket = createSocket(url, useNativeWebSocket);
 
       // Create server object immediately and add to servers map
       // Use provided name, default to actualHost if name is empty
@@ -724,6 +734,7 @@ export class IRCClient implements IRCClientContext {
         password,
         saslAccountName: _saslAccountName,
         saslPassword: _saslPassword,
+        nativeWebSocket: useNativeWebSocket,
       });
       // Enable SASL if we have either PLAIN credentials or an OAuth bearer
       // path. OAuth path is signaled by the caller; tokens themselves are
@@ -3,6 +3,9 @@
  * Supports extracting server details, channels, and connection parameters
  */
 
+import { invoke } from "@tauri-apps/api/core";
+import { isTauri } from "./platformUtils";
+
 export interface ParsedIrcUrl {
   host: string;
   port: number;
@@ -98,6 +101,53 @@ export function parseIrcUrl(url: string, defaultNick = "user"): ParsedIrcUrl {
   };
 }
 
+/** IRC URL as parsed by the native `parse_irc_url` command */
+interface NativeIrcUrl {
+  scheme: "irc" | "ircs" | "ws" | "wss" | "unix";
+  host: string;
+  port: number;
+  channels: string[];
+  nick: string | null;
+  password: string | null;
+}
+
+/**
+ * Parses an IRC URL with the same parser the native connect path uses, so
+ * default ports and targets match what connecting to the URL would do.
+ * Falls back to {@link parseIrcUrl} in the browser or when the native
+ * parser rejects the URL.
+ *
+ * @param url - The IRC URL to parse (irc:// or ircs://)
+ * @param defaultNick - Default nickname to use if none specified in URL
+ * @returns Parsed IRC connection details
+ */
+export async function resolveIrcUrl(
+  url: string,
+  defaultNick = "user",
+): Promise<ParsedIrcUrl> {
+  if (!isTauri()) {
+    return parseIrcUrl(url, defaultNick);
+  }
+  // Strip punctuation that chat text tends to leave after a link
+  const sanitizedUrl = url.trim().replace(/[),.;:]+$/, "");
+  try {
+    const parsed = await invoke<NativeIrcUrl>("parse_irc_url", {
+      url: sanitizedUrl,
+    });
+    return {
+      host: parsed.host,
+      port: parsed.port,
+      scheme:
+        parsed.scheme === "ircs" || parsed.scheme === "wss" ? "ircs" : "irc",
+      channels: parsed.channels,
+      nick: parsed.nick || defaultNick,
+      password: parsed.password || undefined,
+    };
+  } catch {
+    return parseIrcUrl(url, defaultNick);
+  }
+}
+
 /**
  * Normalizes a channel name by ensuring it has the correct prefix
  * Adds '#' prefix if the channel doesn't start with a valid IRC channel prefix
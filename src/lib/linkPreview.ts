@@ -0,0 +1,55 @@
+// Desktop and mobile: link previews fetched by the Rust side, which
+// parses OpenGraph, Twitter Card and oEmbed metadata and hands back the
+// preview image as a data URL, so the webview neither runs into CORS
+// nor loads anything from the linked site.  The backend caches results
+// on disk; this module only dedupes requests within a session.
+
+import { invoke } from "@tauri-apps/api/core";
+
+export interface LinkPreviewData {
+  /** Where redirects ended up */
+  url: string;
+  title: string | null;
+  description: string | null;
+  siteName: string | null;
+  /** Downsized preview image as a data URL */
+  image: string | null;
+}
+
+export interface LinkPreviewConfig {
+  /** Domains never fetched for previews; subdomains included */
+  deniedDomains: string[];
+}
+
+const requests = new Map<string, Promise<LinkPreviewData | null>>();
+
+async function load(url: string): Promise<LinkPreviewData | null> {
+  try {
+    return await invoke<LinkPreviewData | null>("link_preview_fetch", {
+      url,
+    });
+  } catch {
+    // Blocked, denied or not a web page; the backend remembers failures
+    // for a while, so asking again later is cheap
+    requests.delete(url);
+    return null;
+  }
+}
+
+/** The preview of `url`, or null when there is none to show. */
+export function fetchLinkPreview(
+  url: string,
+): Promise<LinkPreviewData | null> {
+  let request = requests.get(url);
+  if (!request) {
+    request = load(url);
+    requests.set(url, request);
+  }
+  return request;
+}
+
+export const getLinkPreviewConfig = () =>
+  invoke<LinkPreviewConfig>("link_preview_get_config");
+
+export const setLinkPreviewConfig = (config: LinkPreviewConfig) =>
+  invoke<void>("link_preview_set_config", { config });
@@ -7,6 +7,17 @@
 // uploadFile() resolves with the absolute saved URL on success and
 // rejects with a `string` describing the failure (string -- not Error
 // -- so the UI can show server text verbatim without unwrapping).
+//
+// On Tauri, uploadFilePath() does the same for a file on disk: the
+// Rust side streams it from disk, so picked or dropped files never
+// have to be read into the webview.  Files are named by opaque handles
+// the backend hands out for picked, dropped and staged files; it takes
+// no paths from the webview.  stageUploadFile() copies a webview File
+// to disk first so images can be processed the same way.
+
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+import { v4 as uuidv4 } from "uuid";
 
 export interface UploadInfo {
   max_size: number;
@@ -44,6 +55,8 @@ export interface UploadOptions {
   bearerToken: string;
   onProgress?: (loaded: number, total: number) => void;
   signal?: AbortSignal;
+  /** uploadFilePath() only: have the backend remove image metadata. */
+  stripMetadata?: boolean;
 }
 
 /**
@@ -103,6 +116,69 @@ export function uploadFile(file: File, opts: UploadOptions): Promise<string> {
   });
 }
 
+/**
+ * Upload a local file by its handle through the Rust backend, which
+ * checks it against /upload/info and streams it up.  Same contract as
+ * uploadFile(): resolves with the absolute URL, rejects with a string.
+ * The handle can't be used again afterwards.
+ */
+export async function uploadFilePath(
+  handle: string,
+  opts: UploadOptions,
+): Promise<string> {
+  if (opts.signal?.aborted) return Promise.reject("Upload cancelled");
+  const uploadId = uuidv4();
+  const unlisten = await listen<{
+    uploadId: string;
+    sent: number;
+    total: number;
+  }>("upload-progress", ({ payload }) => {
+    if (payload.uploadId === uploadId) {
+      opts.onProgress?.(payload.sent, payload.total);
+    }
+  });
+  const onAbort = () => {
+    invoke("upload_cancel", { uploadId }).catch(() => {});
+  };
+  try {
+    opts.signal?.addEventListener("abort", onAbort);
+    return await invoke<string>("upload_file", {
+      uploadId,
+      handle,
+      filehostUrl: opts.filehostUrl,
+      bearerToken: opts.bearerToken,
+      stripMetadata: opts.stripMetadata ?? false,
+    });
+  } finally {
+    opts.signal?.removeEventListener("abort", onAbort);
+    unlisten();
+  }
+}
+
+/**
+ * Copy a webview File into the backend's staging area and resolve
+ * with its handle, for image_shrink and uploadFilePath().  The copy is
+ * removed once it has been uploaded.
+ */
+export async function stageUploadFile(file: File): Promise<string> {
+  return invoke<string>(
+    "upload_stage",
+    new Uint8Array(await file.arrayBuffer()),
+    { headers: { "x-file-name": encodeURIComponent(file.name) } },
+  );
+}
+
+/**
+ * Pick files with the native dialog (Tauri) and resolve with their
+ * handles; empty if dismissed.
+ */
+export const pickUploadFiles = () => invoke<string[]>("upload_pick_files");
+
+/** The file name part of an upload handle or local path, for display. */
+export function pathFileName(path: string): string {
+  return path.split(/[\\/]/).pop() || path;
+}
+
 /**
  * Best-effort client-side validation against fetchUploadInfo() output.
  * Returns null when the file is OK; otherwise a human-readable reason
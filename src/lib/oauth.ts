@@ -11,9 +11,14 @@
 //
 // The redirect URI must be registered with the IdP. If the caller doesn't
 // override it we use `<origin>/oauth/callback`, which the SPA serves via
-// the OAuthCallback component.
+// the OAuthCallback component. The desktop app has no such origin; it opens
+// the system browser and receives the redirect on a one-shot listener at
+// http://127.0.0.1:<random port>/callback instead (RFC 8252 loopback
+// redirect), which IdPs accept for native clients on any port.
 
+import { invoke } from "@tauri-apps/api/core";
 import type { ServerOAuthConfig } from "../types";
+import { isTauri } from "./platformUtils";
 
 export interface OidcMetadata {
   issuer: string;
@@ -144,42 +149,87 @@ export interface OAuthCallbackMessage {
   errorDescription?: string;
 }
 
-// Run the full authorization-code-with-PKCE dance via a popup. Returns the
-// tokens. Caller is responsible for storing them on the server config.
-export async function beginOauthLogin(
-  cfg: Pick<
-    ServerOAuthConfig,
-    | "issuer"
-    | "clientId"
-    | "scopes"
-    | "redirectUri"
-    | "authorizeEndpoint"
-    | "tokenEndpoint"
-  >,
-): Promise<OAuthLoginResult> {
-  // GitHub and other non-OIDC providers don't publish a discovery
-  // document; admin supplies authorize/token URLs directly.
-  const meta: OidcMetadata =
-    cfg.authorizeEndpoint && cfg.tokenEndpoint
-      ? {
-          issuer: cfg.issuer,
-          authorization_endpoint: cfg.authorizeEndpoint,
-          token_endpoint: cfg.tokenEndpoint,
-        }
-      : await discoverOidc(cfg.issuer);
-  const codeVerifier = generateCodeVerifier();
-  const codeChallenge = await deriveCodeChallenge(codeVerifier);
-  const state = generateCodeVerifier();
-  const scope = cfg.scopes?.trim() || "openid";
-  const redirectUri = cfg.redirectUri?.trim() || defaultRedirectUri();
-  const url = buildAuthorizeUrl(meta, {
-    clientId: cfg.clientId,
-    redirectUri,
-    scope,
-    state,
-    codeChallenge,
+// Id of the desktop login waiting on its loopback listener, for cancelling
+let loopbackLoginId: string | null = null;
+
+interface AuthorizationCode {
+  code: string;
+  redirectUri: string;
+}
+
+// Desktop: open the system browser and wait for the redirect to the loopback
+// listener in the Rust backend.
+async function waitForLoopbackCode(
+  authorizeUrl: (redirectUri: string) => string,
+  state: string,
+): Promise<AuthorizationCode> {
+  const loginId = generateCodeVerifier();
+  loopbackLoginId = loginId;
+  try {
+    const redirectUri = await invoke<string>("oauth_loopback_listen", {
+      loginId,
+    });
+    const { code } = await invoke<{ code: string; state: string }>(
+      "oauth_loopback_wait",
+      { loginId, authorizeUrl: authorizeUrl(redirectUri), state },
+    );
+    return { code, redirectUri };
+  } finally {
+    if (loopbackLoginId === loginId) loopbackLoginId = null;
+  }
+}
+
+// Give up on a desktop login still waiting for the browser. No-op on the web,
+// where closing the popup does the same.
+export function cancelOauthLogin(): void {
+  if (!loopbackLoginId) return;
+  invoke("oauth_loopback_cancel", { loginId: loopbackLoginId }).catch(
+    () => {},
+  );
+}
+
+export interface FreshOauthTokens {
+  accessToken: string;
+  idToken?: string;
+  /** Seconds since the epoch */
+  expiresAt?: number;
+  scope?: string;
+  /** False when the held tokens were still good */
+  refreshed: boolean;
+}
+
+// Desktop: a bearer token valid for at least another minute. The Rust side
+// redeems the refresh token when the held one is about to expire and emits
+// "oauth-relogin-required" when the provider refuses.
+export function freshOauthTokens(
+  serverId: string,
+  cfg: ServerOAuthConfig,
+): Promise<FreshOauthTokens> {
+  return invoke<FreshOauthTokens>("oauth_access_token", {
+    serverId,
+    provider: {
+      issuer: cfg.issuer,
+      clientId: cfg.clientId,
+      scopes: cfg.scopes,
+      tokenEndpoint: cfg.tokenEndpoint,
+    },
+    current: {
+      accessToken: cfg.accessToken,
+      idToken: cfg.idToken,
+      refreshToken: cfg.refreshToken,
+      expiresAt: cfg.tokenExpiresAt,
+    },
   });
+}
 
+// Web: run the authorize step in a popup that posts the code back from
+// /oauth/callback.
+async function waitForPopupCode(
+  authorizeUrl: (redirectUri: string) => string,
+  state: string,
+  redirectUri: string,
+): Promise<AuthorizationCode> {
+  const url = authorizeUrl(redirectUri);
   const left = window.screenX + (window.outerWidth - POPUP_W) / 2;
   const top = window.screenY + (window.outerHeight - POPUP_H) / 2;
   const popup = window.open(
@@ -237,6 +287,54 @@ export async function beginOauthLogin(
     }, 500);
   });
 
+  return { code, redirectUri };
+}
+
+// Run the full authorization-code-with-PKCE dance, in a popup on the web
+// and in the system browser on desktop. Returns the tokens. Caller is
+// responsible for storing them on the server config.
+export async function beginOauthLogin(
+  cfg: Pick<
+    ServerOAuthConfig,
+    | "issuer"
+    | "clientId"
+    | "scopes"
+    | "redirectUri"
+    | "authorizeEndpoint"
+    | "tokenEndpoint"
+  >,
+): Promise<OAuthLoginResult> {
+  // GitHub and other non-OIDC providers don't publish a discovery
+  // document; admin supplies authorize/token URLs directly.
+  const meta: OidcMetadata =
+    cfg.authorizeEndpoint && cfg.tokenEndpoint
+      ? {
+          issuer: cfg.issuer,
+          authorization_endpoint: cfg.authorizeEndpoint,
+          token_endpoint: cfg.tokenEndpoint,
+        }
+      : await discoverOidc(cfg.issuer);
+  const codeVerifier = generateCodeVerifier();
+  const codeChallenge = await deriveCodeChallenge(codeVerifier);
+  const state = generateCodeVerifier();
+  const scope = cfg.scopes?.trim() || "openid";
+  const authorizeUrl = (redirectUri: string) =>
+    buildAuthorizeUrl(meta, {
+      clientId: cfg.clientId,
+      redirectUri,
+      scope,
+      state,
+      codeChallenge,
+    });
+
+  const { code, redirectUri } = isTauri()
+    ? await waitForLoopbackCode(authorizeUrl, state)
+    : await waitForPopupCode(
+        authorizeUrl,
+        state,
+        cfg.redirectUri?.trim() || defaultRedirectUri(),
+      );
+
   const tokens = await exchangeCodeForToken({
     meta,
     clientId: cfg.clientId,
@@ -0,0 +1,35 @@
+import { invoke } from "@tauri-apps/api/core";
+
+/** Profiles of the desktop app, each with its own networks and storage */
+export interface ProfileList {
+  current: string;
+  /** "default" first, then the others by name */
+  profiles: string[];
+  askAtStartup: boolean;
+}
+
+export const listProfiles = () => invoke<ProfileList>("profile_list");
+
+export const createProfile = (name: string) =>
+  invoke<void>("profile_create", { name });
+
+export const deleteProfile = (name: string) =>
+  invoke<void>("profile_delete", { name });
+
+export const setAskAtStartup = (ask: boolean) =>
+  invoke<void>("profile_set_ask_at_startup", { ask });
+
+/** Start another profile; `replace` quits this one */
+export const launchProfile = (name: string, replace: boolean) =>
+  invoke<void>("profile_launch", { name, replace });
+
+/** Whether to show the profile picker before connecting anywhere */
+export async function shouldAskForProfile(): Promise<boolean> {
+  try {
+    const list = await listProfiles();
+    return list.askAtStartup && list.profiles.length > 1;
+  } catch (error) {
+    console.error("Failed to list profiles:", error);
+    return false;
+  }
+}
@@ -0,0 +1,175 @@
+// Keeps server passwords and OAuth tokens in the OS keychain in the desktop
+// app. localStorage only holds the rest of each ServerConfig; the secrets are
+// loaded once at startup and merged back in by storage.servers.load.
+
+import { invoke } from "@tauri-apps/api/core";
+import type { ServerConfig } from "../types";
+import { isTauri } from "./platformUtils";
+
+const SERVICE = "server";
+
+const SERVER_FIELDS = ["password", "saslPassword", "operPassword"] as const;
+const OAUTH_FIELDS = ["accessToken", "idToken", "refreshToken"] as const;
+
+interface ServerSecrets {
+  password?: string;
+  saslPassword?: string;
+  operPassword?: string;
+  oauth?: { accessToken?: string; idToken?: string; refreshToken?: string };
+}
+
+interface MigrationReport {
+  backend: "keyring" | "file";
+  migrated: { service: string; account: string }[];
+  errors: string[];
+}
+
+// Serialized secrets by server id, or null while the keychain isn't in use
+let cache: Map<string, string> | null = null;
+
+export const secretsInKeychain = () => cache !== null;
+
+export const setSecret = (service: string, account: string, secret: string) =>
+  invoke<void>("secret_set", { service, account, secret });
+
+export const getSecret = (service: string, account: string) =>
+  invoke<string | null>("secret_get", { service, account });
+
+export const deleteSecret = (service: string, account: string) =>
+  invoke<void>("secret_delete", { service, account });
+
+function secretsOf(server: ServerConfig): ServerSecrets {
+  const secrets: ServerSecrets = {};
+  for (const field of SERVER_FIELDS) {
+    if (server[field]) secrets[field] = server[field];
+  }
+  if (server.oauth) {
+    for (const field of OAUTH_FIELDS) {
+      if (server.oauth[field]) {
+        secrets.oauth = { ...secrets.oauth, [field]: server.oauth[field] };
+      }
+    }
+  }
+  return secrets;
+}
+
+const isEmpty = (secrets: ServerSecrets) => Object.keys(secrets).length === 0;
+
+function withoutSecrets(server: ServerConfig): ServerConfig {
+  const stripped = { ...server };
+  for (const field of SERVER_FIELDS) delete stripped[field];
+  if (stripped.oauth) {
+    stripped.oauth = { ...stripped.oauth };
+    for (const field of OAUTH_FIELDS) delete stripped.oauth[field];
+  }
+  return stripped;
+}
+
+/** Fill in the secrets of servers read from localStorage */
+export function mergeSecrets(servers: ServerConfig[]): ServerConfig[] {
+  if (!cache) return servers;
+  return servers.map((server) => {
+    const json = cache?.get(server.id);
+    if (!json) return server;
+    const secrets = JSON.parse(json) as ServerSecrets;
+    return {
+      ...secrets,
+      ...server,
+      oauth: server.oauth && { ...secrets.oauth, ...server.oauth },
+    };
+  });
+}
+
+/**
+ * Store changed secrets in the keychain and return the servers without them,
+ * ready for localStorage. Servers that are gone lose their secrets too.
+ */
+export function extractSecrets(servers: ServerConfig[]): ServerConfig[] {
+  if (!cache) return servers;
+  const known = cache;
+  for (const server of servers) {
+    const secrets = secretsOf(server);
+    const json = isEmpty(secrets) ? undefined : JSON.stringify(secrets);
+    if (json === known.get(server.id)) continue;
+    // Objects loaded before the keychain was read have no secret fields at
+    // all; only a field that is present but empty clears a secret
+    const cleared =
+      SERVER_FIELDS.some((field) => field in server) ||
+      OAUTH_FIELDS.some((field) => server.oauth && field in server.oauth);
+    if (!json && !cleared) continue;
+    if (json) {
+      known.set(server.id, json);
+      setSecret(SERVICE, server.id, json).catch((error) =>
+        console.error("Failed to store server secrets:", error),
+      );
+    } else {
+      known.delete(server.id);
+      deleteSecret(SERVICE, server.id).catch((error) =>
+        console.error("Failed to delete server secrets:", error),
+      );
+    }
+  }
+  const ids = new Set(servers.map((server) => server.id));
+  for (const id of [...known.keys()]) {
+    if (ids.has(id)) continue;
+    known.delete(id);
+    deleteSecret(SERVICE, id).catch((error) =>
+      console.error("Failed to delete server secrets:", error),
+    );
+    // Refresh token kept by the token manager
+    deleteSecret("oauth", id).catch(() => {});
+  }
+  return servers.map(withoutSecrets);
+}
+
+/**
+ * Move plaintext secrets from localStorage into the keychain and load the
+ * ones stored there. Until this finishes, and for good if the keychain
+ * fails, servers stay in localStorage as they are.
+ */
+export async function initSecrets(
+  load: () => ServerConfig[],
+  save: (servers: ServerConfig[]) => void,
+): Promise<void> {
+  if (!isTauri() || cache) return;
+  const servers = load();
+  const plaintext = servers
+    .map((server) => ({ server, secrets: secretsOf(server) }))
+    .filter(({ secrets }) => !isEmpty(secrets));
+
+  try {
+    const loaded = new Map<string, string>();
+    for (const server of servers) {
+      const json = await getSecret(SERVICE, server.id);
+      if (json) loaded.set(server.id, json);
+    }
+
+    // Plaintext wins over what the keychain has: it's what the user last saved
+    const report = await invoke<MigrationReport>("secret_migrate", {
+      secrets: plaintext.map(({ server, secrets }) => ({
+        service: SERVICE,
+        account: server.id,
+        secret: JSON.stringify({
+          ...JSON.parse(loaded.get(server.id) ?? "{}"),
+          ...secrets,
+        }),
+      })),
+    });
+    if (report.errors.length > 0) {
+      console.error("Keeping secrets in localStorage:", report.errors);
+      return;
+    }
+    for (const { account } of report.migrated) {
+      const json = await getSecret(SERVICE, account);
+      if (json) loaded.set(account, json);
+    }
+
+    cache = loaded;
+    save(mergeSecrets(servers));
+  } catch (error) {
+    console.error(
+      "Keychain unavailable, keeping secrets in localStorage:",
+      error,
+    );
+  }
+}
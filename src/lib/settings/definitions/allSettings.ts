@@ -1,6 +1,9 @@
 import { msg } from "@lingui/core/macro";
+import { AllowedHostsField } from "../../../components/ui/settings/AllowedHostsField";
 import { CustomMentionsField } from "../../../components/ui/settings/CustomMentionsField";
 import { IgnoreListField } from "../../../components/ui/settings/IgnoreListField";
+import { LinkPreviewDenylistField } from "../../../components/ui/settings/LinkPreviewDenylistField";
+import { isTauri } from "../../platformUtils";
 import settingsRegistry from "../registry";
 import type { SettingDefinition } from "../types";
 
@@ -395,6 +398,105 @@ const preferenceSettings: SettingDefinition[] = [
     priority: 11,
     customComponent: IgnoreListField,
   },
+  {
+    id: "preferences.allowedFetchHosts",
+    key: "allowedFetchHosts",
+    category: "preferences",
+    subcategory: "Privacy",
+    title: msg`Local Network Hosts`,
+    description: msg`Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.`,
+    type: "custom",
+    defaultValue: [],
+    hidden: !isTauri(),
+    searchKeywords: ["intranet", "local", "private", "allowlist", "download"],
+    priority: 12,
+    customComponent: AllowedHostsField,
+  },
+  {
+    id: "preferences.imageMetadataMode",
+    key: "imageMetadataMode",
+    category: "preferences",
+    subcategory: "Privacy",
+    title: msg`Remove Photo Metadata`,
+    description: msg`Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them`,
+    type: "select",
+    defaultValue: "always",
+    options: [
+      { value: "always", label: "Always" },
+      { value: "ask", label: "Ask when an image has metadata" },
+      { value: "never", label: "Never" },
+    ],
+    hidden: !isTauri(),
+    searchKeywords: ["exif", "gps", "location", "metadata", "photo", "upload"],
+    priority: 13,
+  },
+  {
+    id: "preferences.uploadMaxDimension",
+    key: "uploadMaxDimension",
+    category: "preferences",
+    subcategory: "Uploads",
+    title: msg`Maximum Image Size`,
+    description: msg`Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.`,
+    type: "number",
+    defaultValue: 0,
+    min: 0,
+    step: 100,
+    unit: "px",
+    validation: { min: 0 },
+    hidden: !isTauri(),
+    searchKeywords: ["resize", "dimension", "downscale", "photo", "upload"],
+    priority: 14,
+  },
+  {
+    id: "preferences.uploadImageQuality",
+    key: "uploadImageQuality",
+    category: "preferences",
+    subcategory: "Uploads",
+    title: msg`Image Quality`,
+    description: msg`JPEG and WebP quality for images that are resized or too large for the server`,
+    type: "range",
+    defaultValue: 85,
+    min: 50,
+    max: 100,
+    step: 5,
+    unit: "%",
+    hidden: !isTauri(),
+    searchKeywords: ["quality", "compress", "jpeg", "webp", "upload"],
+    priority: 15,
+  },
+  {
+    id: "preferences.fetchLinkPreviews",
+    key: "fetchLinkPreviews",
+    category: "preferences",
+    subcategory: "Privacy",
+    title: msg`Fetch Link Previews`,
+    description: msg`Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.`,
+    type: "toggle",
+    defaultValue: false,
+    hidden: !isTauri(),
+    searchKeywords: ["link", "preview", "opengraph", "embed", "url"],
+    priority: 16,
+  },
+  {
+    id: "preferences.linkPreviewDenylist",
+    key: "linkPreviewDenylist",
+    category: "preferences",
+    subcategory: "Privacy",
+    title: msg`Link Preview Blocklist`,
+    description: msg`Domains that link previews are never fetched from, including their subdomains`,
+    type: "custom",
+    defaultValue: [],
+    hidden: !isTauri(),
+    searchKeywords: ["link", "preview", "block", "deny", "domain"],
+    priority: 17,
+    customComponent: LinkPreviewDenylistField,
+    dependencies: [
+      {
+        settingId: "preferences.fetchLinkPreviews",
+        condition: (value) => value === true,
+      },
+    ],
+  },
 ];
 
 /**
@@ -2,6 +2,7 @@
 
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
+import { isTauri } from "./platformUtils";
 
 export interface ISocket {
   onopen: (() => void) | null;
@@ -161,11 +162,22 @@ export class WebSocketWrapper implements ISocket {
   }
 }
 
-export function createSocket(url: string): ISocket {
-  if (url.startsWith("wss://")) {
-    return new WebSocketWrapper(url);
+export function createSocket(
+  url: string,
+  nativeWebSocket = false,
+): ISocket {
+  if (url.startsWith("wss://") || url.startsWith("ws://")) {
+    // Servers can opt in to the native socket layer, which speaks IRC over
+    // WebSocket with the same TLS stack as raw connections
+    return isTauri() && nativeWebSocket
+      ? new TCPSocket(url)
+      : new WebSocketWrapper(url);
   }
-  if (url.startsWith("irc://") || url.startsWith("ircs://")) {
+  if (
+    url.startsWith("irc://") ||
+    url.startsWith("ircs://") ||
+    url.startsWith("unix://")
+  ) {
     return new TCPSocket(url);
   }
   throw new Error("Unsupported socket protocol");
@@ -0,0 +1,26 @@
+import { invoke } from "@tauri-apps/api/core";
+import { isTauri } from "./platformUtils";
+
+/** Command line options of the desktop app (see `obsidianirc --help`) */
+export interface StartupConfig {
+  profile: string | null;
+  /** Set in portable mode */
+  dataDir: string | null;
+  /** Saved networks to connect to, by name or host */
+  connect: string[];
+  minimized: boolean;
+  noAutoconnect: boolean;
+}
+
+/** The options the app was started with, or null outside the desktop app */
+export async function loadStartupConfig(): Promise<StartupConfig | null> {
+  if (!isTauri()) {
+    return null;
+  }
+  try {
+    return await invoke<StartupConfig>("startup_config");
+  } catch (error) {
+    console.error("Failed to load startup config:", error);
+    return null;
+  }
+}
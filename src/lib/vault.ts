@@ -0,0 +1,48 @@
+// App lock of the desktop app. The Rust side encrypts secrets, chat logs and
+// the history database with a key derived from the user's passphrase; while
+// locked, connections keep running but nothing is written in cleartext.
+
+import { invoke } from "@tauri-apps/api/core";
+import { isTauri } from "./platformUtils";
+
+export interface VaultStatus {
+  enabled: boolean;
+  locked: boolean;
+  /** Minutes without activity before locking, or null to never lock */
+  autoLockMinutes: number | null;
+}
+
+/** How often activity is reported while the user is busy */
+const ACTIVITY_INTERVAL_MS = 30_000;
+
+export const getVaultStatus = (): Promise<VaultStatus> =>
+  isTauri()
+    ? invoke<VaultStatus>("vault_status")
+    : Promise.resolve({ enabled: false, locked: false, autoLockMinutes: null });
+
+export const enableVault = (passphrase: string) =>
+  invoke<VaultStatus>("vault_enable", { passphrase });
+
+export const disableVault = (passphrase: string) =>
+  invoke<VaultStatus>("vault_disable", { passphrase });
+
+export const lockVault = () => invoke<void>("vault_lock");
+
+export const unlockVault = (passphrase: string) =>
+  invoke<VaultStatus>("vault_unlock", { passphrase });
+
+export const changeVaultPassphrase = (current: string, passphrase: string) =>
+  invoke<void>("vault_change_passphrase", { current, passphrase });
+
+export const setAutoLock = (minutes: number | null) =>
+  invoke<VaultStatus>("vault_set_auto_lock", { minutes });
+
+let lastActivity = 0;
+
+/** Postpone the auto-lock; cheap to call on every input event */
+export function reportActivity() {
+  const now = Date.now();
+  if (now - lastActivity < ACTIVITY_INTERVAL_MS) return;
+  lastActivity = now;
+  invoke<void>("vault_activity").catch(() => {});
+}
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Neplatný formát vzoru. Použijte formát nick!user@host (jsou povoleny zástupné znaky *)\"],\"+6NQQA\":[\"Obecný podpůrný kanál\"],\"+6NyRG\":[\"Klient\"],\"+K0AvT\":[\"Odpojit\"],\"+cyFdH\":[\"Výchozí zpráva při označení nepřítomnosti\"],\"+mVPqU\":[\"Zobrazovat Markdown formátování ve zprávách\"],\"+vqCJH\":[\"Uživatelské jméno vašeho účtu pro ověření\"],\"+yPBXI\":[\"Vybrat soubor\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Nízká bezpečnost připojení (Úroveň \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Uživatelé mimo kanál nemohou odesílat zprávy do něj\"],\"/6BzZF\":[\"Přepnout seznam členů\"],\"/TNOPk\":[\"Uživatel je nepřítomen\"],\"/XQgft\":[\"Objevovat\"],\"/cF7Rs\":[\"Hlasitost\"],\"/dqduX\":[\"Další stránka\"],\"/fc3q4\":[\"Veškerý obsah\"],\"/kISDh\":[\"Povolit zvuky upozornění\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Zvuk\"],\"/rfkZe\":[\"Přehrávat zvuky pro zmínky a zprávy\"],\"0/0ZGA\":[\"Maska názvu kanálu\"],\"0D6j7U\":[\"Zjistit více o vlastních pravidlech →\"],\"0XsHcR\":[\"Vyhodit uživatele\"],\"0ZpE//\":[\"Seřadit podle uživatelů\"],\"0bEPwz\":[\"Nastavit nepřítomnost\"],\"0dGkPt\":[\"Rozbalit seznam kanálů\"],\"0gS7M5\":[\"Zobrazované jméno\"],\"0kS+M8\":[\"PříkladSÍŤ\"],\"0rgoY7\":[\"Připojovat se pouze k serverům, které si vyberete\"],\"0wdd7X\":[\"Připojit se\"],\"0wkVYx\":[\"Soukromé zprávy\"],\"111uHX\":[\"Náhled odkazu\"],\"196EG4\":[\"Smazat soukromý chat\"],\"1DSr1i\":[\"Zaregistrovat účet\"],\"1O/24y\":[\"Přepnout seznam kanálů\"],\"1VPJJ2\":[\"Varování o externím odkazu\"],\"1ZC/dv\":[\"Žádné nepřečtené zmínky ani zprávy\"],\"1pO1zi\":[\"Název serveru je povinný\"],\"1uwfzQ\":[\"Zobrazit téma kanálu\"],\"268g7c\":[\"Zadejte zobrazované jméno\"],\"2FOFq1\":[\"Operátoři serveru v síti by potenciálně mohli číst vaše zprávy\"],\"2FYpfJ\":[\"Více\"],\"2HF1Y2\":[[\"inviter\"],\" pozval \",[\"target\"],\" k připojení do \",[\"channel\"]],\"2I70QL\":[\"Zobrazit informace o profilu uživatele\"],\"2QYdmE\":[\"Uživatelé:\"],\"2QpEjG\":[\"odešel\"],\"2YE223\":[\"Zpráva #\",[\"0\"],\" (Enter pro nový řádek, Shift+Enter pro odeslání)\"],\"2bimFY\":[\"Použít heslo serveru\"],\"2iTmdZ\":[\"Místní úložiště:\"],\"2odkwe\":[\"Přísný - agresivnější ochrana\"],\"2uDhbA\":[\"Zadejte uživatelské jméno pro pozvání\"],\"2ygf/L\":[\"← Zpět\"],\"2zEgxj\":[\"Hledat GIFy...\"],\"3RdPhl\":[\"Přejmenovat kanál\"],\"3THokf\":[\"Uživatel s hlasem\"],\"3TSz9S\":[\"Minimalizovat\"],\"3jBDvM\":[\"Zobrazovaný název kanálu\"],\"3ryuFU\":[\"Volitelné zprávy o pádu pro zlepšení aplikace\"],\"3uBF/8\":[\"Zavřít prohlížeč\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Zadejte název účtu...\"],\"4/Rr0R\":[\"Pozvat uživatele do aktuálního kanálu\"],\"4EZrJN\":[\"Pravidla\"],\"4JJtW9\":[\"#přetečení\"],\"4NqeT4\":[\"Profil floodingu (+F)\"],\"4RZQRK\":[\"Co teď děláš?\"],\"4hfTrB\":[\"Přezdívka\"],\"4n99LO\":[\"Již v \",[\"0\"]],\"4t6vMV\":[\"Automaticky přepnout na jeden řádek pro krátké zprávy\"],\"4vsHmf\":[\"Čas (min)\"],\"5+INAX\":[\"Zvýrazňovat zprávy, které vás zmiňují\"],\"5R5Pv/\":[\"Jméno operátora\"],\"678PKt\":[\"Název sítě\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Heslo nutné pro vstup do kanálu. Nechte prázdné pro odstranění klíče.\"],\"6HhMs3\":[\"Zpráva při odpojení\"],\"6V3Ea3\":[\"Zkopírováno\"],\"6lGV3K\":[\"Zobrazit méně\"],\"6yFOEi\":[\"Zadejte heslo opera...\"],\"7+IHTZ\":[\"Žádný soubor nevybrán\"],\"73hrRi\":[\"nick!user@host (např. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Odeslat soukromou zprávu\"],\"7U1W7c\":[\"Velmi uvolněný\"],\"7Y1YQj\":[\"Skutečné jméno:\"],\"7YHArF\":[\"— otevřít v prohlížeči\"],\"7fjnVl\":[\"Hledat uživatele...\"],\"7jL88x\":[\"Smazat tuto zprávu? Tuto akci nelze vrátit zpět.\"],\"7nGhhM\":[\"Na co myslíte?\"],\"7sEpu1\":[\"Členové — \",[\"0\"]],\"7sNhEz\":[\"Uživatelské jméno\"],\"8H0Q+x\":[\"Zjistit více o profilech →\"],\"8Phu0A\":[\"Zobrazovat, když uživatelé mění přezdívky\"],\"8XTG9e\":[\"Zadejte heslo operátora\"],\"8XsV2J\":[\"Zkusit odeslat znovu\"],\"8ZsakT\":[\"Heslo\"],\"8kR84m\":[\"Chystáte se otevřít externí odkaz:\"],\"8lCgih\":[\"Odebrat pravidlo\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"připojil se\"],\"few\":[\"připojil se \",[\"joinCount\"],\"×\"],\"many\":[\"připojil se \",[\"joinCount\"],\"×\"],\"other\":[\"připojil se \",[\"joinCount\"],\"×\"]}]],\"9BMLnJ\":[\"Znovu připojit k serveru\"],\"9OEgyT\":[\"Přidat reakci\"],\"9PQ8m2\":[\"G-Line (globální ban)\"],\"9Qs99X\":[\"E-mail:\"],\"9QupBP\":[\"Odebrat vzor\"],\"9bG48P\":[\"Odesílání\"],\"9f5f0u\":[\"Otázky ohledně soukromí? Kontaktujte nás:\"],\"9unqs3\":[\"Nepřítomen:\"],\"9v3hwv\":[\"Nebyly nalezeny žádné servery.\"],\"9zb2WA\":[\"Připojování\"],\"A1taO8\":[\"Hledat\"],\"A2adVi\":[\"Odesílat oznámení o psaní\"],\"A9Rhec\":[\"Název kanálu\"],\"AWOSPo\":[\"Přiblížit\"],\"AXSpEQ\":[\"Operátor při připojení\"],\"AeXO77\":[\"Účet\"],\"AhNP40\":[\"Přetočit\"],\"Ai2U7L\":[\"Hostitel\"],\"AjBQnf\":[\"Změněna přezdívka\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Zrušit odpověď\"],\"ApSx0O\":[\"Nalezeno \",[\"0\"],\" zpráv odpovídajících \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Žádné výsledky nenalezeny\"],\"AyNqAB\":[\"Zobrazit všechny události serveru v chatu\"],\"B/QqGw\":[\"Pryč od klávesnice\"],\"B8AaMI\":[\"Toto pole je povinné\"],\"BA2c49\":[\"Server nepodporuje pokročilé filtrování LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" a \",[\"3\"],\" dalších píší...\"],\"BGul2A\":[\"Máte neuložené změny. Opravdu chcete zavřít bez uložení?\"],\"BIf9fi\":[\"Vaše stavová zpráva\"],\"BZz3md\":[\"Vaše osobní webová stránka\"],\"Bgm/H7\":[\"Povolit zadávání více řádků textu\"],\"BiQIl1\":[\"Připnout tuto soukromou konverzaci\"],\"BlNZZ2\":[\"Klikněte pro přechod na zprávu\"],\"Bowq3c\":[\"Téma kanálu mohou měnit pouze operátoři\"],\"Btozzp\":[\"Platnost tohoto obrázku vypršela\"],\"Bycfjm\":[\"Celkem: \",[\"0\"]],\"C6IBQc\":[\"Kopírovat celý JSON\"],\"C9L9wL\":[\"Sběr dat\"],\"CDq4wC\":[\"Moderovat uživatele\"],\"CHVRxG\":[\"Zpráva @\",[\"0\"],\" (Shift+Enter pro nový řádek)\"],\"CN9zdR\":[\"Jméno a heslo operátora jsou povinné\"],\"CW3sYa\":[\"Přidat reakci \",[\"emoji\"]],\"CaAkqd\":[\"Zobrazit odchody\"],\"CbvaYj\":[\"Ban podle přezdívky\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Vybrat kanál\"],\"CsekCi\":[\"Normální\"],\"D+NlUC\":[\"Systém\"],\"D28t6+\":[\"se připojil a odpojil\"],\"DB8zMK\":[\"Použít\"],\"DBcWHr\":[\"Vlastní soubor zvuku oznámení\"],\"DTy9Xw\":[\"Náhledy médií\"],\"Dj4pSr\":[\"Zvolte bezpečné heslo\"],\"Du+zn+\":[\"Hledám...\"],\"Du2T2f\":[\"Nastavení nenalezeno\"],\"DwsSVQ\":[\"Použít filtry a obnovit\"],\"E3W/zd\":[\"Výchozí přezdívka\"],\"E6nRW7\":[\"Kopírovat URL\"],\"E703RG\":[\"Režimy:\"],\"EAeu1Z\":[\"Odeslat pozvánku\"],\"EFKJQT\":[\"Nastavení\"],\"EGPQBv\":[\"Vlastní pravidla floodingu (+f)\"],\"ELik0r\":[\"Zobrazit úplné zásady ochrany soukromí\"],\"EPbeC2\":[\"Zobrazit nebo upravit téma kanálu\"],\"EQCDNT\":[\"Zadejte uživatelské jméno opera...\"],\"EUvulZ\":[\"Nalezena 1 zpráva odpovídající \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Další obrázek\"],\"EdQY6l\":[\"Žádné\"],\"EnqLYU\":[\"Hledat servery...\"],\"F0OKMc\":[\"Upravit server\"],\"F6Int2\":[\"Povolit zvýraznění\"],\"FDoLyE\":[\"Max. uživatelů\"],\"FUU/hZ\":[\"Kontrolujte, kolik externích médií se načítá v chatu.\"],\"Fdp03t\":[\"zap\"],\"FfPWR0\":[\"Modální okno\"],\"FjkaiT\":[\"Oddálit\"],\"FlqOE9\":[\"Co to znamená:\"],\"FolHNl\":[\"Spravujte svůj účet a ověřování\"],\"Fp2Dif\":[\"Opustit server\"],\"G5KmCc\":[\"GZ-Line (globální Z-Line)\"],\"GDs0lz\":[\"<0>Riziko:</0> Citlivé informace (zprávy, soukromé konverzace, přihlašovací údaje) mohou být přístupné správcům sítě nebo útočníkům mezi IRC servery.\"],\"GR+2I3\":[\"Přidat masku pozvánky (např. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Zavřít vyskočená serverová oznámení\"],\"GlHnXw\":[\"Změna přezdívky se nezdařila: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Náhled:\"],\"GtmO8/\":[\"od\"],\"GtuHUQ\":[\"Přejmenovat tento kanál na serveru. Nový název uvidí všichni uživatelé.\"],\"GuGfFX\":[\"Přepnout hledání\"],\"GxkJXS\":[\"Nahrávám...\"],\"GzbwnK\":[\"Připojil se ke kanálu\"],\"GzsUDB\":[\"Rozšířený profil\"],\"H/PnT8\":[\"Vložit emoji\"],\"H6Izzl\":[\"Váš preferovaný kód barvy\"],\"H9jIv+\":[\"Zobrazit připojení/odchody\"],\"HAKBY9\":[\"Nahrát soubory\"],\"HdE1If\":[\"Kanál\"],\"Hk4AW9\":[\"Vaše preferované zobrazované jméno\"],\"HmHDk7\":[\"Vybrat člena\"],\"HrQzPU\":[\"Kanály na \",[\"networkName\"]],\"I2tXQ5\":[\"Zpráva @\",[\"0\"],\" (Enter pro nový řádek, Shift+Enter pro odeslání)\"],\"I6bw/h\":[\"Zabanovat uživatele\"],\"I92Z+b\":[\"Povolit upozornění\"],\"I9D72S\":[\"Opravdu chcete tuto zprávu smazat? Tuto akci nelze vrátit zpět.\"],\"IA+1wo\":[\"Zobrazovat, když jsou uživatelé vyhozeni z kanálů\"],\"IDwkJx\":[\"IRC operátor\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Uložit změny\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" a \",[\"2\"],\" píší...\"],\"IgrLD/\":[\"Pauza\"],\"Im6JED\":[\"ŠEPOT\"],\"ImOQa9\":[\"Odpovědět\"],\"IoHMnl\":[\"Maximální hodnota je \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Připojování...\"],\"J5T9NW\":[\"Informace o uživateli\"],\"J8Y5+z\":[\"Jejda! Síť se rozdělila! ⚠️\"],\"JBHkBA\":[\"Opustil kanál\"],\"JCwL0Q\":[\"Zadejte důvod (volitelné)\"],\"JFciKP\":[\"Přepnout\"],\"JXGkhG\":[\"Změnit název kanálu (pouze operátoři)\"],\"JcD7qf\":[\"Více akcí\"],\"JdkA+c\":[\"Tajný (+s)\"],\"Jmu12l\":[\"Kanály serveru\"],\"JvQ++s\":[\"Povolit Markdown\"],\"K2jwh/\":[\"Data WHOIS nejsou k dispozici\"],\"KAXSwC\":[\"Hlas\"],\"KDfTdX\":[\"Smazat zprávu\"],\"KKBlUU\":[\"Vložit\"],\"KM0pLb\":[\"Vítejte v kanálu!\"],\"KR6W2h\":[\"Přestat ignorovat uživatele\"],\"KV+Bi1\":[\"Pouze na pozvání (+i)\"],\"KdCtwE\":[\"Kolik sekund sledovat floodingovou aktivitu před resetováním čítačů\"],\"Kkezga\":[\"Heslo serveru\"],\"KsiQ/8\":[\"Uživatelé musí být pozváni k připojení do kanálu\"],\"L+gB/D\":[\"Informace o kanálu\"],\"LC1a7n\":[\"IRC server oznámil, že jeho meziservery mají nízkou úroveň zabezpečení. To znamená, že když jsou vaše zprávy přeposílány mezi IRC servery v síti, nemusí být správně šifrovány nebo SSL/TLS certifikáty nemusí být správně ověřovány.\"],\"LNfLR5\":[\"Zobrazit vykopnutí\"],\"LQb0W/\":[\"Zobrazit všechny události\"],\"LU7/yA\":[\"Alternativní název pro zobrazení v rozhraní. Může obsahovat mezery, emoji a speciální znaky. Skutečný název kanálu (\",[\"channelName\"],\") bude nadále používán pro IRC příkazy.\"],\"LUb9O7\":[\"Je vyžadován platný port serveru\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Zásady ochrany soukromí\"],\"LcuSDR\":[\"Spravujte informace profilu a metadata\"],\"LqLS9B\":[\"Zobrazit změny přezdívek\"],\"LsDQt2\":[\"Nastavení kanálu\"],\"LtI9AS\":[\"Vlastník\"],\"LuNhhL\":[\"reagoval na tuto zprávu\"],\"M/AZNG\":[\"URL vašeho avatara\"],\"M/WIer\":[\"Odeslat zprávu\"],\"M8er/5\":[\"Název:\"],\"MHk+7g\":[\"Předchozí obrázek\"],\"MRorGe\":[\"Soukromá zpráva uživateli\"],\"MVbSGP\":[\"Časové okno (sekundy)\"],\"MkpcsT\":[\"Vaše zprávy a nastavení jsou uloženy lokálně na vašem zařízení\"],\"N/hDSy\":[\"Označit jako bot - obvykle 'on' nebo prázdné\"],\"N7TQbE\":[\"Pozvat uživatele do \",[\"channelName\"]],\"NCca/o\":[\"Zadejte výchozí přezdívku...\"],\"Nqs6B9\":[\"Zobrazuje veškerá externí média. Libovolná URL může způsobit požadavek na neznámý server.\"],\"Nt+9O7\":[\"Použít WebSocket místo surového TCP\"],\"NxIHzc\":[\"Odpojit uživatele\"],\"O+v/cL\":[\"Procházet všechny kanály na serveru\"],\"ODwSCk\":[\"Odeslat GIF\"],\"OGQ5kK\":[\"Konfigurovat zvuky upozornění a zvýraznění\"],\"OIPt1Z\":[\"Zobrazit nebo skrýt boční panel se seznamem členů\"],\"OKSNq/\":[\"Velmi přísný\"],\"ONWvwQ\":[\"Nahrát\"],\"OVKoQO\":[\"Heslo vašeho účtu pro ověření\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Přinášíme IRC do budoucnosti\"],\"OhCpra\":[\"Nastavit téma…\"],\"OkltoQ\":[\"Zabanovat \",[\"username\"],\" podle přezdívky (zabrání opětovnému připojení se stejným nickem)\"],\"P+t/Te\":[\"Žádné další údaje\"],\"P42Wcc\":[\"Bezpečné\"],\"PD38l0\":[\"Náhled avatara kanálu\"],\"PD9mEt\":[\"Napište zprávu...\"],\"PPqfdA\":[\"Otevřít nastavení konfigurace kanálu\"],\"PSCjfZ\":[\"Téma, které bude zobrazeno pro tento kanál. Téma mohou vidět všichni uživatelé.\"],\"PZCecv\":[\"Náhled PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1×\"],\"few\":[[\"c\"],\"×\"],\"many\":[[\"c\"],\"×\"],\"other\":[[\"c\"],\"×\"]}]],\"PguS2C\":[\"Přidat masku výjimky (např. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Zobrazeno \",[\"displayedChannelsCount\"],\" z \",[\"0\"],\" kanálů\"],\"PqhVlJ\":[\"Zabanovat uživatele (podle masky hostitele)\"],\"Q+chwU\":[\"Uživatelské jméno:\"],\"Q6hhn8\":[\"Předvolby\"],\"QF4a34\":[\"Zadejte prosím uživatelské jméno\"],\"QGqSZ2\":[\"Barva a formátování\"],\"QJQd1J\":[\"Upravit profil\"],\"QSzGDE\":[\"Nečinný\"],\"QUlny5\":[\"Vítejte v \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Číst více\"],\"QuSkCF\":[\"Filtrovat kanály...\"],\"QwUrDZ\":[\"změnil téma na: \",[\"topic\"]],\"R0UH07\":[\"Obrázek \",[\"0\"],\" z \",[\"1\"]],\"R7SsBE\":[\"Ztlumit\"],\"R8rf1X\":[\"Klikněte pro nastavení tématu\"],\"RArB3D\":[\"byl vyhozen z \",[\"channelName\"],\" uživatelem \",[\"username\"]],\"RI3cWd\":[\"Objevte svět IRC s ObsidianIRC\"],\"RMMaN5\":[\"Moderovaný (+m)\"],\"RWw9Lg\":[\"Zavřít okno\"],\"RZ2BuZ\":[\"Registrace účtu \",[\"account\"],\" vyžaduje ověření: \",[\"message\"]],\"RySp6q\":[\"Skrýt komentáře\"],\"SPKQTd\":[\"Přezdívka je povinná\"],\"SPVjfj\":[\"Výchozí bude 'bez důvodu', pokud ponecháte prázdné\"],\"SQKPvQ\":[\"Pozvat uživatele\"],\"SkZcl+\":[\"Vyberte předdefinovaný profil ochrany před floodem. Tyto profily poskytují vyvážená nastavení ochrany pro různé případy použití.\"],\"Slr+3C\":[\"Min. uživatelů\"],\"Spnlre\":[\"Pozval jste \",[\"target\"],\" k připojení do \",[\"channel\"]],\"T/ckN5\":[\"Otevřít v prohlížeči\"],\"T91vKp\":[\"Přehrát\"],\"TV2Wdu\":[\"Zjistěte, jak nakládáme s vašimi daty a chráníme vaše soukromí.\"],\"TgFpwD\":[\"Používám...\"],\"TkzSFB\":[\"Žádné změny\"],\"TtserG\":[\"Zadejte skutečné jméno\"],\"Ttz9J1\":[\"Zadejte heslo...\"],\"Tz0i8g\":[\"Nastavení\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"React\"],\"UE4KO5\":[\"*kanál*\"],\"UGT5vp\":[\"Uložit nastavení\"],\"UV5hLB\":[\"Nenalezeny žádné zákazy\"],\"Uaj3Nd\":[\"Stavové zprávy\"],\"Ue3uny\":[\"Výchozí (bez profilu)\"],\"UkARhe\":[\"Normální - standardní ochrana\"],\"Umn7Cj\":[\"Zatím žádné komentáře. Buďte první!\"],\"UtUIRh\":[[\"0\"],\" starších zpráv\"],\"UwzP+U\":[\"Zabezpečené připojení\"],\"V0/A4O\":[\"Vlastník kanálu\"],\"V4qgxE\":[\"Vytvořeno před (min. zpět)\"],\"V8yTm6\":[\"Vymazat hledání\"],\"VJMMyz\":[\"ObsidianIRC - Přinášíme IRC do budoucnosti\"],\"VJScHU\":[\"Důvod\"],\"VLsmVV\":[\"Ztlumit upozornění\"],\"VbyRUy\":[\"Komentáře\"],\"Vmx0mQ\":[\"Nastaveno:\"],\"VqnIZz\":[\"Zobrazit naše zásady ochrany soukromí a práci s daty\"],\"VrMygG\":[\"Minimální délka je \",[\"0\"]],\"VrnTui\":[\"Vaše zájmena, zobrazená ve vašem profilu\"],\"W8E3qn\":[\"Ověřený účet\"],\"WAakm9\":[\"Smazat kanál\"],\"WFxTHC\":[\"Přidat masku banu (např. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Hostitel serveru je povinný\"],\"WRYdXW\":[\"Pozice zvuku\"],\"WUOH5B\":[\"Ignorovat uživatele\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Zobrazit 1 další položku\"],\"few\":[\"Zobrazit \",[\"1\"],\" další položky\"],\"many\":[\"Zobrazit \",[\"1\"],\" dalších položek\"],\"other\":[\"Zobrazit \",[\"1\"],\" dalších položek\"]}]],\"Weq9zb\":[\"Obecné\"],\"Wfj7Sk\":[\"Ztlumit nebo zapnout zvuky upozornění\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profil uživatele\"],\"X6S3lt\":[\"Hledat nastavení, kanály, servery...\"],\"XEHan5\":[\"Přesto pokračovat\"],\"XI1+wb\":[\"Neplatný formát\"],\"XIXeuC\":[\"Zpráva @\",[\"0\"]],\"XMS+k4\":[\"Začít soukromou zprávu\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Odepnout soukromou konverzaci\"],\"Xm/s+u\":[\"Zobrazení\"],\"Xp2n93\":[\"Zobrazuje média z důvěryhodného file hostu vašeho serveru. Nejsou prováděny žádné požadavky na externí služby.\"],\"XvjC4F\":[\"Ukládám...\"],\"Y/qryO\":[\"Nebyly nalezeni žádní uživatelé odpovídající vašemu vyhledávání\"],\"YAqRpI\":[\"Registrace účtu \",[\"account\"],\" proběhla úspěšně: \",[\"message\"]],\"YEfzvP\":[\"Chráněné téma (+t)\"],\"YQOn6a\":[\"Sbalit seznam členů\"],\"YRCoE9\":[\"Operátor kanálu\"],\"YURQaF\":[\"Zobrazit profil\"],\"YdBSvr\":[\"Ovládat zobrazení médií a externího obsahu\"],\"Yj6U3V\":[\"Bez centrálního serveru:\"],\"YjvpGx\":[\"Zájmena\"],\"YqH4l4\":[\"Bez klíče\"],\"YyUPpV\":[\"Účet:\"],\"ZJSWfw\":[\"Zpráva zobrazená při odpojení od serveru\"],\"ZR1dJ4\":[\"Pozvánky\"],\"ZdWg0V\":[\"Otevřít v prohlížeči\"],\"ZhRBbl\":[\"Hledat zprávy…\"],\"Zmcu3y\":[\"Pokročilé filtry\"],\"a2/8e5\":[\"Téma nastaveno po (min)\"],\"aHKcKc\":[\"Předchozí stránka\"],\"aJTbXX\":[\"Heslo operátora\"],\"aQryQv\":[\"Vzor již existuje\"],\"aW9pLN\":[\"Maximální počet uživatelů povolených v kanálu. Nechte prázdné pro žádný limit.\"],\"ah4fmZ\":[\"Zobrazuje také náhledy z YouTube, Vimeo, SoundCloud a podobných známých služeb.\"],\"aifXak\":[\"V tomto kanálu nejsou žádná média\"],\"ap2zBz\":[\"Uvolněný\"],\"az8lvo\":[\"Vypnuto\"],\"azXSNo\":[\"Rozbalit seznam členů\"],\"azdliB\":[\"Přihlásit se k účtu\"],\"b26wlF\":[\"ona/její\"],\"bD/+Ei\":[\"Přísný\"],\"bQ6BJn\":[\"Nakonfigurujte podrobná pravidla ochrany proti floodingu. Každé pravidlo určuje, jaký typ aktivity sledovat a jakou akci provést při překročení prahů.\"],\"beV7+y\":[\"Uživatel obdrží pozvánku k připojení do \",[\"channelName\"],\".\"],\"bk84cH\":[\"Zpráva o nepřítomnosti\"],\"bkHdLj\":[\"Přidat IRC server\"],\"bmQLn5\":[\"Přidat pravidlo\"],\"bwRvnp\":[\"Akce\"],\"c8+EVZ\":[\"Ověřený účet\"],\"cGYUlD\":[\"Nejsou načteny žádné náhledy médií.\"],\"cLF98o\":[\"Zobrazit komentáře (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Žádní uživatelé nejsou k dispozici\"],\"cSgpoS\":[\"Připnout soukromou konverzaci\"],\"cde3ce\":[\"Zpráva <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopírovat formátovaný výstup\"],\"cl/A5J\":[\"Vítejte v \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Smazat\"],\"coPLXT\":[\"Neukládáme vaši IRC komunikaci na našich serverech\"],\"crYH/6\":[\"Přehrávač SoundCloud\"],\"d3sis4\":[\"Přidat server\"],\"d9aN5k\":[\"Odebrat \",[\"username\"],\" z kanálu\"],\"dEgA5A\":[\"Zrušit\"],\"dGi1We\":[\"Odepnout tuto soukromou konverzaci\"],\"dJVuyC\":[\"opustil \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"do\"],\"dXqxlh\":[\"<0>⚠️ Bezpečnostní riziko!</0> Toto připojení může být zranitelné vůči odposlechu nebo útokům man-in-the-middle.\"],\"da9Q/R\":[\"Změněny módy kanálu\"],\"dhJN3N\":[\"Zobrazit komentáře\"],\"dj2xTE\":[\"Odmítnout oznámení\"],\"dpCzmC\":[\"Nastavení ochrany proti floodingu\"],\"e9dQpT\":[\"Chcete otevřít tento odkaz v nové záložce?\"],\"ePK91l\":[\"Upravit\"],\"eYBDuB\":[\"Nahrajte obrázek nebo zadejte URL s volitelnou substitucí \",[\"size\"],\" pro dynamické velikosti\"],\"edBbee\":[\"Zabanovat \",[\"username\"],\" podle masky hostitele (zabrání opětovnému připojení ze stejné IP/hostitele)\"],\"ekfzWq\":[\"Nastavení uživatele\"],\"elPDWs\":[\"Přizpůsobte si IRC klienta\"],\"eu2osY\":[\"<0>💡 Doporučení:</0> Pokračujte pouze pokud důvěřujete tomuto serveru a rozumíte rizikům. Vyhněte se sdílení citlivých informací nebo hesel přes toto připojení.\"],\"euEhbr\":[\"Klikněte pro připojení k \",[\"channel\"]],\"ez3vLd\":[\"Povolit víceřádkové zadávání\"],\"f0J5Ki\":[\"Komunikace mezi servery může používat nešifrovaná připojení\"],\"f9BHJk\":[\"Varovat uživatele\"],\"fDOLLd\":[\"Nebyly nalezeny žádné kanály.\"],\"ffzDkB\":[\"Anonymní analytika:\"],\"fq1GF9\":[\"Zobrazit při odpojení uživatelů ze serveru\"],\"gEF57C\":[\"Tento server podporuje pouze jeden typ připojení\"],\"gJuLUI\":[\"Seznam ignorovaných\"],\"gNzMrk\":[\"Aktuální avatar\"],\"gjPWyO\":[\"Zadejte přezdívku...\"],\"gz6UQ3\":[\"Maximalizovat\"],\"h6razj\":[\"Maska vyloučení názvu kanálu\"],\"hG6jnw\":[\"Téma není nastaveno\"],\"hG89Ed\":[\"Obrázek\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"např. 100:1440\"],\"hehnjM\":[\"Množství\"],\"hzdLuQ\":[\"Mluvit mohou pouze uživatelé s hlasem nebo vyšší hodností\"],\"i0qMbr\":[\"Domů\"],\"iDNBZe\":[\"Oznámení\"],\"iH8pgl\":[\"Zpět\"],\"iL9SZg\":[\"Zabanovat uživatele (podle přezdívky)\"],\"iNt+3c\":[\"Zpět na obrázek\"],\"iQvi+a\":[\"Neupozorňovat mě na nízkou bezpečnost připojení pro tento server\"],\"iSLIjg\":[\"Připojit\"],\"iWXkHH\":[\"Polooperátor\"],\"iZeTtp\":[\"Hostitel serveru\"],\"idD8Ev\":[\"Uloženo\"],\"iivqkW\":[\"Přihlášen\"],\"ij+Elv\":[\"Náhled obrázku\"],\"ilIWp7\":[\"Přepnout oznámení\"],\"iuaqvB\":[\"Použijte * pro zástupné znaky. Příklady: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Ban podle masky hostitele\"],\"jA4uoI\":[\"Téma:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Důvod (volitelné)\"],\"jUV7CU\":[\"Nahrát avatar\"],\"jW5Uwh\":[\"Kontrolujte načítání externích médií. Vypnuto / Bezpečné / Důvěryhodné zdroje / Veškerý obsah.\"],\"jXzms5\":[\"Možnosti přílohy\"],\"jZlrte\":[\"Barva\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#nový-název-kanálu\"],\"k112DD\":[\"Načíst starší zprávy\"],\"k3ID0F\":[\"Filtrovat členy…\"],\"k65gsE\":[\"Podrobný přehled\"],\"k7Zgob\":[\"Zrušit připojení\"],\"kAVx5h\":[\"Nenalezeny žádné pozvánky\"],\"kCLEPU\":[\"Připojeno k\"],\"kF5LKb\":[\"Ignorované vzory:\"],\"kGeOx/\":[\"Připojit se k \",[\"0\"]],\"kITKr8\":[\"Načítám režimy kanálu...\"],\"kPpPsw\":[\"Jste IRC operátor\"],\"kWJmRL\":[\"Vy\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Kopírovat JSON\"],\"krViRy\":[\"Klikněte pro kopírování jako JSON\"],\"ks71ra\":[\"Výjimky\"],\"kw4lRv\":[\"Polooperátor kanálu\"],\"kxgIRq\":[\"Vyberte nebo přidejte kanál pro začátek.\"],\"ky6dWe\":[\"Náhled avatara\"],\"l+GxCv\":[\"Načítám kanály...\"],\"l+IUVW\":[\"Ověření účtu \",[\"account\"],\" proběhlo úspěšně: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"znovu se připojil\"],\"few\":[\"znovu se připojil \",[\"reconnectCount\"],\"×\"],\"many\":[\"znovu se připojil \",[\"reconnectCount\"],\"×\"],\"other\":[\"znovu se připojil \",[\"reconnectCount\"],\"×\"]}]],\"l5jmzx\":[[\"0\"],\" a \",[\"1\"],\" píší...\"],\"lHy8N5\":[\"Načítám více kanálů...\"],\"lbpf14\":[\"Připojit se k \",[\"value\"]],\"lfFsZ4\":[\"Kanály\"],\"lkNdiH\":[\"Název účtu\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Nahrát obrázek\"],\"loQxaJ\":[\"Jsem zpět\"],\"lvfaxv\":[\"DOMŮ\"],\"m16xKo\":[\"Přidat\"],\"m8flAk\":[\"Náhled (ještě nenahrán)\"],\"mEPxTp\":[\"<0>⚠️ Buďte opatrní!</0> Otevírejte pouze odkazy z důvěryhodných zdrojů. Škodlivé odkazy mohou ohrozit vaši bezpečnost nebo soukromí.\"],\"mHGdhG\":[\"Informace o serveru\"],\"mHS8lb\":[\"Zpráva #\",[\"0\"]],\"mMYBD9\":[\"Široký - širší rozsah ochrany\"],\"mTGsPd\":[\"Téma kanálu\"],\"mU8j6O\":[\"Žádné externí zprávy (+n)\"],\"mZp8FL\":[\"Automatický návrat na jeden řádek\"],\"mdQu8G\":[\"VašePřezdívka\"],\"miSSBQ\":[\"Komentáře (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Uživatel je ověřen\"],\"mwtcGl\":[\"Zavřít komentáře\"],\"mzI/c+\":[\"Stáhnout\"],\"n3fGRk\":[\"nastaveno \",[\"0\"]],\"nE9jsU\":[\"Uvolněný - méně agresivní ochrana\"],\"nNflMD\":[\"Opustit kanál\"],\"nPXkBi\":[\"Načítám data WHOIS...\"],\"nQnxxF\":[\"Zpráva #\",[\"0\"],\" (Shift+Enter pro nový řádek)\"],\"nWMRxa\":[\"Odepnout\"],\"nkC032\":[\"Žádný profil floodingu\"],\"o69z4d\":[\"Odeslat varovnou zprávu uživateli \",[\"username\"]],\"o9ylQi\":[\"Hledejte GIFy pro začátek\"],\"oFGkER\":[\"Oznámení serveru\"],\"oOi11l\":[\"Přejít dolů\"],\"oQEzQR\":[\"Nová DM\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Útoky man-in-the-middle na serverová připojení jsou možné\"],\"oeqmmJ\":[\"Důvěryhodné zdroje\"],\"ovBPCi\":[\"Výchozí\"],\"p0Z69r\":[\"Vzor nemůže být prázdný\"],\"p1KgtK\":[\"Nepodařilo se načíst zvuk\"],\"p59pEv\":[\"Další podrobnosti\"],\"p7sRI6\":[\"Informovat ostatní, když píšete\"],\"pBm1od\":[\"Tajný kanál\"],\"pNmiXx\":[\"Vaše výchozí přezdívka pro všechny servery\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Heslo účtu\"],\"peNE68\":[\"Trvalý\"],\"plhHQt\":[\"Žádná data\"],\"pm6+q5\":[\"Bezpečnostní upozornění\"],\"pn5qSs\":[\"Další informace\"],\"q0cR4S\":[\"je nyní znám jako **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Kanál se nebude zobrazovat v příkazech LIST nebo NAMES\"],\"qLpTm/\":[\"Odebrat reakci \",[\"emoji\"]],\"qVkGWK\":[\"Připnout\"],\"qY8wNa\":[\"Domovská stránka\"],\"qb0xJ7\":[\"Použijte zástupné znaky: * odpovídá libovolné sekvenci, ? odpovídá libovolnému jednomu znaku. Příklady: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Klíč kanálu (+k)\"],\"qtoOYG\":[\"Bez omezení\"],\"r1W2AS\":[\"Obrázek z file hostu\"],\"rIPR2O\":[\"Téma nastaveno před (min)\"],\"rMMSYo\":[\"Maximální délka je \",[\"0\"]],\"rWtzQe\":[\"Síť se rozdělila a znovu připojila. ✅\"],\"rYG2u6\":[\"Prosím čekejte...\"],\"rdUucN\":[\"Náhled\"],\"rjGI/Q\":[\"Soukromí\"],\"rk8iDX\":[\"Načítám GIFy...\"],\"rn6SBY\":[\"Zrušit ztlumení\"],\"s/UKqq\":[\"Byl vykopnut z kanálu\"],\"s8cATI\":[\"se připojil k \",[\"channelName\"]],\"sCO9ue\":[\"Připojení k <0>\",[\"serverName\"],\"</0> má následující bezpečnostní problémy:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"je nyní znám jako **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" vás pozval k připojení do \",[\"channel\"]],\"sby+1/\":[\"Klikněte pro kopírování\"],\"sfN25C\":[\"Vaše skutečné nebo celé jméno\"],\"sliuzR\":[\"Otevřít odkaz\"],\"sqrO9R\":[\"Vlastní zmínky\"],\"sr6RdJ\":[\"Víceřádkové na Shift+Enter\"],\"swrCpB\":[\"Kanál byl přejmenován z \",[\"oldName\"],\" na \",[\"newName\"],\" uživatelem \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Pokročilé\"],\"t/YqKh\":[\"Odebrat\"],\"t47eHD\":[\"Váš jedinečný identifikátor na tomto serveru\"],\"tAkAh0\":[\"URL s volitelnou substitucí \",[\"size\"],\" pro dynamické velikosti. Příklad: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Zobrazit nebo skrýt boční panel se seznamem kanálů\"],\"tfDRzk\":[\"Uložit\"],\"tiBsJk\":[\"opustil \",[\"channelName\"]],\"tt4/UD\":[\"se odpojil (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Přezdívka {nick} je již používána, zkouším s {newNick}\"],\"u0a8B4\":[\"Ověřit jako IRC operátor pro administrativní přístup\"],\"u0rWFU\":[\"Vytvořeno po (min. zpět)\"],\"u72w3t\":[\"Uživatelé a vzory k ignorování\"],\"u7jc2L\":[\"se odpojil\"],\"uAQUqI\":[\"Stav\"],\"uB85T3\":[\"Uložení selhalo: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC servery:\"],\"usSSr/\":[\"Úroveň přiblížení\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Použijte Shift+Enter pro nový řádek (Enter odešle)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Bez tématu\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Jazyk\"],\"vaHYxN\":[\"Skutečné jméno\"],\"vhjbKr\":[\"Nepřítomen\"],\"w4NYox\":[\"klient \",[\"title\"]],\"w8xQRx\":[\"Neplatná hodnota\"],\"wFjjxZ\":[\"byl vyhozen z \",[\"channelName\"],\" uživatelem \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nenalezeny žádné výjimky zákazu\"],\"wPrGnM\":[\"Správce kanálu\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Zobrazovat, když uživatelé vstupují nebo opouštějí kanály\"],\"whqZ9r\":[\"Další slova nebo fráze ke zvýraznění\"],\"wm7RV4\":[\"Zvuk oznámení\"],\"wz/Yoq\":[\"Vaše zprávy mohou být zachyceny při přeposílání mezi servery\"],\"xCJdfg\":[\"Vymazat\"],\"xUHRTR\":[\"Automaticky ověřit jako operátor při připojení\"],\"xWHwwQ\":[\"Bany\"],\"xYilR2\":[\"Média\"],\"xceQrO\":[\"Jsou podporovány pouze zabezpečené websocket připojení\"],\"xdtXa+\":[\"název-kanálu\"],\"xfXC7q\":[\"Textové kanály\"],\"xlCYOE\":[\"Načítám více zpráv...\"],\"xlhswE\":[\"Minimální hodnota je \",[\"0\"]],\"xq97Ci\":[\"Přidat slovo nebo frázi...\"],\"xuRqRq\":[\"Limit klientů (+l)\"],\"xwF+7J\":[[\"0\"],\" píše...\"],\"yNeucF\":[\"Tento server nepodporuje rozšířená metadata profilu (rozšíření IRCv3 METADATA). Další pole jako avatar, zobrazované jméno a stav nejsou k dispozici.\"],\"yPlrca\":[\"Avatar kanálu\"],\"yQE2r9\":[\"Načítání\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Uživatelské jméno operátora\"],\"yYOzWD\":[\"logy\"],\"yfx9Re\":[\"Heslo IRC operátora\"],\"ygCKqB\":[\"Zastavit\"],\"ymDxJx\":[\"Uživatelské jméno IRC operátora\"],\"yrpRsQ\":[\"Seřadit podle názvu\"],\"yz7wBu\":[\"Zavřít\"],\"zJw+jA\":[\"nastavuje režim: \",[\"0\"]],\"zebeLu\":[\"Zadejte uživatelské jméno operátora\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Neplatný formát vzoru. Použijte formát nick!user@host (jsou povoleny zástupné znaky *)\"],\"+6NQQA\":[\"Obecný podpůrný kanál\"],\"+6NyRG\":[\"Klient\"],\"+K0AvT\":[\"Odpojit\"],\"+cyFdH\":[\"Výchozí zpráva při označení nepřítomnosti\"],\"+mVPqU\":[\"Zobrazovat Markdown formátování ve zprávách\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Uživatelské jméno vašeho účtu pro ověření\"],\"+yPBXI\":[\"Vybrat soubor\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Nízká bezpečnost připojení (Úroveň \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Uživatelé mimo kanál nemohou odesílat zprávy do něj\"],\"/6BzZF\":[\"Přepnout seznam členů\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Uživatel je nepřítomen\"],\"/XQgft\":[\"Objevovat\"],\"/cF7Rs\":[\"Hlasitost\"],\"/dqduX\":[\"Další stránka\"],\"/fc3q4\":[\"Veškerý obsah\"],\"/kISDh\":[\"Povolit zvuky upozornění\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Zvuk\"],\"/rfkZe\":[\"Přehrávat zvuky pro zmínky a zprávy\"],\"0/0ZGA\":[\"Maska názvu kanálu\"],\"0D6j7U\":[\"Zjistit více o vlastních pravidlech →\"],\"0XsHcR\":[\"Vyhodit uživatele\"],\"0ZpE//\":[\"Seřadit podle uživatelů\"],\"0bEPwz\":[\"Nastavit nepřítomnost\"],\"0dGkPt\":[\"Rozbalit seznam kanálů\"],\"0gS7M5\":[\"Zobrazované jméno\"],\"0kS+M8\":[\"PříkladSÍŤ\"],\"0rgoY7\":[\"Připojovat se pouze k serverům, které si vyberete\"],\"0wdd7X\":[\"Připojit se\"],\"0wkVYx\":[\"Soukromé zprávy\"],\"111uHX\":[\"Náhled odkazu\"],\"196EG4\":[\"Smazat soukromý chat\"],\"1DSr1i\":[\"Zaregistrovat účet\"],\"1O/24y\":[\"Přepnout seznam kanálů\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Varování o externím odkazu\"],\"1ZC/dv\":[\"Žádné nepřečtené zmínky ani zprávy\"],\"1pO1zi\":[\"Název serveru je povinný\"],\"1uwfzQ\":[\"Zobrazit téma kanálu\"],\"268g7c\":[\"Zadejte zobrazované jméno\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Operátoři serveru v síti by potenciálně mohli číst vaše zprávy\"],\"2FYpfJ\":[\"Více\"],\"2HF1Y2\":[[\"inviter\"],\" pozval \",[\"target\"],\" k připojení do \",[\"channel\"]],\"2I70QL\":[\"Zobrazit informace o profilu uživatele\"],\"2QYdmE\":[\"Uživatelé:\"],\"2QpEjG\":[\"odešel\"],\"2YE223\":[\"Zpráva #\",[\"0\"],\" (Enter pro nový řádek, Shift+Enter pro odeslání)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Použít heslo serveru\"],\"2iTmdZ\":[\"Místní úložiště:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Přísný - agresivnější ochrana\"],\"2uDhbA\":[\"Zadejte uživatelské jméno pro pozvání\"],\"2ygf/L\":[\"← Zpět\"],\"2zEgxj\":[\"Hledat GIFy...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Přejmenovat kanál\"],\"3THokf\":[\"Uživatel s hlasem\"],\"3TSz9S\":[\"Minimalizovat\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Zobrazovaný název kanálu\"],\"3ryuFU\":[\"Volitelné zprávy o pádu pro zlepšení aplikace\"],\"3uBF/8\":[\"Zavřít prohlížeč\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Zadejte název účtu...\"],\"4/Rr0R\":[\"Pozvat uživatele do aktuálního kanálu\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Pravidla\"],\"4JJtW9\":[\"#přetečení\"],\"4NqeT4\":[\"Profil floodingu (+F)\"],\"4RZQRK\":[\"Co teď děláš?\"],\"4hfTrB\":[\"Přezdívka\"],\"4n99LO\":[\"Již v \",[\"0\"]],\"4t6vMV\":[\"Automaticky přepnout na jeden řádek pro krátké zprávy\"],\"4vsHmf\":[\"Čas (min)\"],\"5+INAX\":[\"Zvýrazňovat zprávy, které vás zmiňují\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Jméno operátora\"],\"678PKt\":[\"Název sítě\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Heslo nutné pro vstup do kanálu. Nechte prázdné pro odstranění klíče.\"],\"6HhMs3\":[\"Zpráva při odpojení\"],\"6V3Ea3\":[\"Zkopírováno\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Zobrazit méně\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Zadejte heslo opera...\"],\"7+IHTZ\":[\"Žádný soubor nevybrán\"],\"73hrRi\":[\"nick!user@host (např. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Odeslat soukromou zprávu\"],\"7U1W7c\":[\"Velmi uvolněný\"],\"7Y1YQj\":[\"Skutečné jméno:\"],\"7YHArF\":[\"— otevřít v prohlížeči\"],\"7fjnVl\":[\"Hledat uživatele...\"],\"7jL88x\":[\"Smazat tuto zprávu? Tuto akci nelze vrátit zpět.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Na co myslíte?\"],\"7sEpu1\":[\"Členové — \",[\"0\"]],\"7sNhEz\":[\"Uživatelské jméno\"],\"8H0Q+x\":[\"Zjistit více o profilech →\"],\"8Phu0A\":[\"Zobrazovat, když uživatelé mění přezdívky\"],\"8XTG9e\":[\"Zadejte heslo operátora\"],\"8XsV2J\":[\"Zkusit odeslat znovu\"],\"8ZsakT\":[\"Heslo\"],\"8kR84m\":[\"Chystáte se otevřít externí odkaz:\"],\"8lCgih\":[\"Odebrat pravidlo\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"připojil se\"],\"few\":[\"připojil se \",[\"joinCount\"],\"×\"],\"many\":[\"připojil se \",[\"joinCount\"],\"×\"],\"other\":[\"připojil se \",[\"joinCount\"],\"×\"]}]],\"9BMLnJ\":[\"Znovu připojit k serveru\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Přidat reakci\"],\"9PQ8m2\":[\"G-Line (globální ban)\"],\"9Qs99X\":[\"E-mail:\"],\"9QupBP\":[\"Odebrat vzor\"],\"9bG48P\":[\"Odesílání\"],\"9f5f0u\":[\"Otázky ohledně soukromí? Kontaktujte nás:\"],\"9unqs3\":[\"Nepřítomen:\"],\"9v3hwv\":[\"Nebyly nalezeny žádné servery.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Připojování\"],\"A1taO8\":[\"Hledat\"],\"A2adVi\":[\"Odesílat oznámení o psaní\"],\"A9Rhec\":[\"Název kanálu\"],\"AWOSPo\":[\"Přiblížit\"],\"AXSpEQ\":[\"Operátor při připojení\"],\"AeXO77\":[\"Účet\"],\"AhNP40\":[\"Přetočit\"],\"Ai2U7L\":[\"Hostitel\"],\"AjBQnf\":[\"Změněna přezdívka\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Zrušit odpověď\"],\"ApSx0O\":[\"Nalezeno \",[\"0\"],\" zpráv odpovídajících \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Žádné výsledky nenalezeny\"],\"AyNqAB\":[\"Zobrazit všechny události serveru v chatu\"],\"B/QqGw\":[\"Pryč od klávesnice\"],\"B8AaMI\":[\"Toto pole je povinné\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Server nepodporuje pokročilé filtrování LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" a \",[\"3\"],\" dalších píší...\"],\"BGul2A\":[\"Máte neuložené změny. Opravdu chcete zavřít bez uložení?\"],\"BIf9fi\":[\"Vaše stavová zpráva\"],\"BZz3md\":[\"Vaše osobní webová stránka\"],\"Bgm/H7\":[\"Povolit zadávání více řádků textu\"],\"BiQIl1\":[\"Připnout tuto soukromou konverzaci\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Klikněte pro přechod na zprávu\"],\"Bowq3c\":[\"Téma kanálu mohou měnit pouze operátoři\"],\"Btozzp\":[\"Platnost tohoto obrázku vypršela\"],\"Bycfjm\":[\"Celkem: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Kopírovat celý JSON\"],\"C9L9wL\":[\"Sběr dat\"],\"CDq4wC\":[\"Moderovat uživatele\"],\"CHVRxG\":[\"Zpráva @\",[\"0\"],\" (Shift+Enter pro nový řádek)\"],\"CN9zdR\":[\"Jméno a heslo operátora jsou povinné\"],\"CW3sYa\":[\"Přidat reakci \",[\"emoji\"]],\"CaAkqd\":[\"Zobrazit odchody\"],\"CbvaYj\":[\"Ban podle přezdívky\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Vybrat kanál\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normální\"],\"D+NlUC\":[\"Systém\"],\"D28t6+\":[\"se připojil a odpojil\"],\"DB8zMK\":[\"Použít\"],\"DBcWHr\":[\"Vlastní soubor zvuku oznámení\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Náhledy médií\"],\"Dj4pSr\":[\"Zvolte bezpečné heslo\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Hledám...\"],\"Du2T2f\":[\"Nastavení nenalezeno\"],\"DwsSVQ\":[\"Použít filtry a obnovit\"],\"E3W/zd\":[\"Výchozí přezdívka\"],\"E6nRW7\":[\"Kopírovat URL\"],\"E703RG\":[\"Režimy:\"],\"EAeu1Z\":[\"Odeslat pozvánku\"],\"EFKJQT\":[\"Nastavení\"],\"EGPQBv\":[\"Vlastní pravidla floodingu (+f)\"],\"ELik0r\":[\"Zobrazit úplné zásady ochrany soukromí\"],\"EPbeC2\":[\"Zobrazit nebo upravit téma kanálu\"],\"EQCDNT\":[\"Zadejte uživatelské jméno opera...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Nalezena 1 zpráva odpovídající \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Další obrázek\"],\"EdQY6l\":[\"Žádné\"],\"EnqLYU\":[\"Hledat servery...\"],\"F0OKMc\":[\"Upravit server\"],\"F6Int2\":[\"Povolit zvýraznění\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Max. uživatelů\"],\"FUU/hZ\":[\"Kontrolujte, kolik externích médií se načítá v chatu.\"],\"Fdp03t\":[\"zap\"],\"FfPWR0\":[\"Modální okno\"],\"FjkaiT\":[\"Oddálit\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Co to znamená:\"],\"FolHNl\":[\"Spravujte svůj účet a ověřování\"],\"Fp2Dif\":[\"Opustit server\"],\"G5KmCc\":[\"GZ-Line (globální Z-Line)\"],\"GDs0lz\":[\"<0>Riziko:</0> Citlivé informace (zprávy, soukromé konverzace, přihlašovací údaje) mohou být přístupné správcům sítě nebo útočníkům mezi IRC servery.\"],\"GR+2I3\":[\"Přidat masku pozvánky (např. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Zavřít vyskočená serverová oznámení\"],\"GlHnXw\":[\"Změna přezdívky se nezdařila: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Náhled:\"],\"GtmO8/\":[\"od\"],\"GtuHUQ\":[\"Přejmenovat tento kanál na serveru. Nový název uvidí všichni uživatelé.\"],\"GuGfFX\":[\"Přepnout hledání\"],\"GxkJXS\":[\"Nahrávám...\"],\"GzbwnK\":[\"Připojil se ke kanálu\"],\"GzsUDB\":[\"Rozšířený profil\"],\"H/PnT8\":[\"Vložit emoji\"],\"H6Izzl\":[\"Váš preferovaný kód barvy\"],\"H9jIv+\":[\"Zobrazit připojení/odchody\"],\"HAKBY9\":[\"Nahrát soubory\"],\"HdE1If\":[\"Kanál\"],\"Hk4AW9\":[\"Vaše preferované zobrazované jméno\"],\"HmHDk7\":[\"Vybrat člena\"],\"HrQzPU\":[\"Kanály na \",[\"networkName\"]],\"I2tXQ5\":[\"Zpráva @\",[\"0\"],\" (Enter pro nový řádek, Shift+Enter pro odeslání)\"],\"I6bw/h\":[\"Zabanovat uživatele\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Povolit upozornění\"],\"I9D72S\":[\"Opravdu chcete tuto zprávu smazat? Tuto akci nelze vrátit zpět.\"],\"IA+1wo\":[\"Zobrazovat, když jsou uživatelé vyhozeni z kanálů\"],\"IDwkJx\":[\"IRC operátor\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Uložit změny\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" a \",[\"2\"],\" píší...\"],\"IgrLD/\":[\"Pauza\"],\"Im6JED\":[\"ŠEPOT\"],\"ImOQa9\":[\"Odpovědět\"],\"IoHMnl\":[\"Maximální hodnota je \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Připojování...\"],\"J5T9NW\":[\"Informace o uživateli\"],\"J8Y5+z\":[\"Jejda! Síť se rozdělila! ⚠️\"],\"JBHkBA\":[\"Opustil kanál\"],\"JCwL0Q\":[\"Zadejte důvod (volitelné)\"],\"JFciKP\":[\"Přepnout\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Změnit název kanálu (pouze operátoři)\"],\"JcD7qf\":[\"Více akcí\"],\"JdkA+c\":[\"Tajný (+s)\"],\"Jmu12l\":[\"Kanály serveru\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Povolit Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Data WHOIS nejsou k dispozici\"],\"KAXSwC\":[\"Hlas\"],\"KDfTdX\":[\"Smazat zprávu\"],\"KKBlUU\":[\"Vložit\"],\"KM0pLb\":[\"Vítejte v kanálu!\"],\"KR6W2h\":[\"Přestat ignorovat uživatele\"],\"KV+Bi1\":[\"Pouze na pozvání (+i)\"],\"KdCtwE\":[\"Kolik sekund sledovat floodingovou aktivitu před resetováním čítačů\"],\"Kkezga\":[\"Heslo serveru\"],\"KsiQ/8\":[\"Uživatelé musí být pozváni k připojení do kanálu\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Informace o kanálu\"],\"LC1a7n\":[\"IRC server oznámil, že jeho meziservery mají nízkou úroveň zabezpečení. To znamená, že když jsou vaše zprávy přeposílány mezi IRC servery v síti, nemusí být správně šifrovány nebo SSL/TLS certifikáty nemusí být správně ověřovány.\"],\"LNfLR5\":[\"Zobrazit vykopnutí\"],\"LQb0W/\":[\"Zobrazit všechny události\"],\"LU7/yA\":[\"Alternativní název pro zobrazení v rozhraní. Může obsahovat mezery, emoji a speciální znaky. Skutečný název kanálu (\",[\"channelName\"],\") bude nadále používán pro IRC příkazy.\"],\"LUb9O7\":[\"Je vyžadován platný port serveru\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Zásady ochrany soukromí\"],\"LcuSDR\":[\"Spravujte informace profilu a metadata\"],\"LqLS9B\":[\"Zobrazit změny přezdívek\"],\"LsDQt2\":[\"Nastavení kanálu\"],\"LtI9AS\":[\"Vlastník\"],\"LuNhhL\":[\"reagoval na tuto zprávu\"],\"M/AZNG\":[\"URL vašeho avatara\"],\"M/WIer\":[\"Odeslat zprávu\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Název:\"],\"MHk+7g\":[\"Předchozí obrázek\"],\"MRorGe\":[\"Soukromá zpráva uživateli\"],\"MVbSGP\":[\"Časové okno (sekundy)\"],\"MkpcsT\":[\"Vaše zprávy a nastavení jsou uloženy lokálně na vašem zařízení\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Označit jako bot - obvykle 'on' nebo prázdné\"],\"N7TQbE\":[\"Pozvat uživatele do \",[\"channelName\"]],\"NCca/o\":[\"Zadejte výchozí přezdívku...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Zobrazuje veškerá externí média. Libovolná URL může způsobit požadavek na neznámý server.\"],\"Nt+9O7\":[\"Použít WebSocket místo surového TCP\"],\"NxIHzc\":[\"Odpojit uživatele\"],\"O+v/cL\":[\"Procházet všechny kanály na serveru\"],\"ODwSCk\":[\"Odeslat GIF\"],\"OGQ5kK\":[\"Konfigurovat zvuky upozornění a zvýraznění\"],\"OIPt1Z\":[\"Zobrazit nebo skrýt boční panel se seznamem členů\"],\"OKSNq/\":[\"Velmi přísný\"],\"ONWvwQ\":[\"Nahrát\"],\"OVKoQO\":[\"Heslo vašeho účtu pro ověření\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Přinášíme IRC do budoucnosti\"],\"OhCpra\":[\"Nastavit téma…\"],\"OkltoQ\":[\"Zabanovat \",[\"username\"],\" podle přezdívky (zabrání opětovnému připojení se stejným nickem)\"],\"P+t/Te\":[\"Žádné další údaje\"],\"P42Wcc\":[\"Bezpečné\"],\"PD38l0\":[\"Náhled avatara kanálu\"],\"PD9mEt\":[\"Napište zprávu...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Otevřít nastavení konfigurace kanálu\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Téma, které bude zobrazeno pro tento kanál. Téma mohou vidět všichni uživatelé.\"],\"PZCecv\":[\"Náhled PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1×\"],\"few\":[[\"c\"],\"×\"],\"many\":[[\"c\"],\"×\"],\"other\":[[\"c\"],\"×\"]}]],\"PguS2C\":[\"Přidat masku výjimky (např. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Zobrazeno \",[\"displayedChannelsCount\"],\" z \",[\"0\"],\" kanálů\"],\"PqhVlJ\":[\"Zabanovat uživatele (podle masky hostitele)\"],\"Q+chwU\":[\"Uživatelské jméno:\"],\"Q6hhn8\":[\"Předvolby\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Zadejte prosím uživatelské jméno\"],\"QGqSZ2\":[\"Barva a formátování\"],\"QJQd1J\":[\"Upravit profil\"],\"QSzGDE\":[\"Nečinný\"],\"QUlny5\":[\"Vítejte v \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Číst více\"],\"QuSkCF\":[\"Filtrovat kanály...\"],\"QwUrDZ\":[\"změnil téma na: \",[\"topic\"]],\"R0UH07\":[\"Obrázek \",[\"0\"],\" z \",[\"1\"]],\"R7SsBE\":[\"Ztlumit\"],\"R8rf1X\":[\"Klikněte pro nastavení tématu\"],\"RArB3D\":[\"byl vyhozen z \",[\"channelName\"],\" uživatelem \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Objevte svět IRC s ObsidianIRC\"],\"RMMaN5\":[\"Moderovaný (+m)\"],\"RWw9Lg\":[\"Zavřít okno\"],\"RZ2BuZ\":[\"Registrace účtu \",[\"account\"],\" vyžaduje ověření: \",[\"message\"]],\"RySp6q\":[\"Skrýt komentáře\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Přezdívka je povinná\"],\"SPVjfj\":[\"Výchozí bude 'bez důvodu', pokud ponecháte prázdné\"],\"SQKPvQ\":[\"Pozvat uživatele\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Vyberte předdefinovaný profil ochrany před floodem. Tyto profily poskytují vyvážená nastavení ochrany pro různé případy použití.\"],\"Slr+3C\":[\"Min. uživatelů\"],\"Spnlre\":[\"Pozval jste \",[\"target\"],\" k připojení do \",[\"channel\"]],\"T/ckN5\":[\"Otevřít v prohlížeči\"],\"T91vKp\":[\"Přehrát\"],\"TV2Wdu\":[\"Zjistěte, jak nakládáme s vašimi daty a chráníme vaše soukromí.\"],\"TgFpwD\":[\"Používám...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Žádné změny\"],\"TtserG\":[\"Zadejte skutečné jméno\"],\"Ttz9J1\":[\"Zadejte heslo...\"],\"Tz0i8g\":[\"Nastavení\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"React\"],\"UE4KO5\":[\"*kanál*\"],\"UGT5vp\":[\"Uložit nastavení\"],\"UV5hLB\":[\"Nenalezeny žádné zákazy\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Stavové zprávy\"],\"Ue3uny\":[\"Výchozí (bez profilu)\"],\"UkARhe\":[\"Normální - standardní ochrana\"],\"Umn7Cj\":[\"Zatím žádné komentáře. Buďte první!\"],\"UtUIRh\":[[\"0\"],\" starších zpráv\"],\"UwzP+U\":[\"Zabezpečené připojení\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Vlastník kanálu\"],\"V4qgxE\":[\"Vytvořeno před (min. zpět)\"],\"V8yTm6\":[\"Vymazat hledání\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - Přinášíme IRC do budoucnosti\"],\"VJScHU\":[\"Důvod\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Ztlumit upozornění\"],\"VbyRUy\":[\"Komentáře\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Nastaveno:\"],\"VqnIZz\":[\"Zobrazit naše zásady ochrany soukromí a práci s daty\"],\"VrMygG\":[\"Minimální délka je \",[\"0\"]],\"VrnTui\":[\"Vaše zájmena, zobrazená ve vašem profilu\"],\"W8E3qn\":[\"Ověřený účet\"],\"WAakm9\":[\"Smazat kanál\"],\"WFxTHC\":[\"Přidat masku banu (např. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Hostitel serveru je povinný\"],\"WRYdXW\":[\"Pozice zvuku\"],\"WUOH5B\":[\"Ignorovat uživatele\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Zobrazit 1 další položku\"],\"few\":[\"Zobrazit \",[\"1\"],\" další položky\"],\"many\":[\"Zobrazit \",[\"1\"],\" dalších položek\"],\"other\":[\"Zobrazit \",[\"1\"],\" dalších položek\"]}]],\"Weq9zb\":[\"Obecné\"],\"Wfj7Sk\":[\"Ztlumit nebo zapnout zvuky upozornění\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profil uživatele\"],\"X6S3lt\":[\"Hledat nastavení, kanály, servery...\"],\"XEHan5\":[\"Přesto pokračovat\"],\"XI1+wb\":[\"Neplatný formát\"],\"XIXeuC\":[\"Zpráva @\",[\"0\"]],\"XMS+k4\":[\"Začít soukromou zprávu\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Odepnout soukromou konverzaci\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Zobrazení\"],\"Xp2n93\":[\"Zobrazuje média z důvěryhodného file hostu vašeho serveru. Nejsou prováděny žádné požadavky na externí služby.\"],\"XvjC4F\":[\"Ukládám...\"],\"Y/qryO\":[\"Nebyly nalezeni žádní uživatelé odpovídající vašemu vyhledávání\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Registrace účtu \",[\"account\"],\" proběhla úspěšně: \",[\"message\"]],\"YEfzvP\":[\"Chráněné téma (+t)\"],\"YQOn6a\":[\"Sbalit seznam členů\"],\"YRCoE9\":[\"Operátor kanálu\"],\"YURQaF\":[\"Zobrazit profil\"],\"YdBSvr\":[\"Ovládat zobrazení médií a externího obsahu\"],\"Yj6U3V\":[\"Bez centrálního serveru:\"],\"YjvpGx\":[\"Zájmena\"],\"YqH4l4\":[\"Bez klíče\"],\"YyUPpV\":[\"Účet:\"],\"ZJSWfw\":[\"Zpráva zobrazená při odpojení od serveru\"],\"ZR1dJ4\":[\"Pozvánky\"],\"ZdWg0V\":[\"Otevřít v prohlížeči\"],\"ZhRBbl\":[\"Hledat zprávy…\"],\"Zmcu3y\":[\"Pokročilé filtry\"],\"a2/8e5\":[\"Téma nastaveno po (min)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Předchozí stránka\"],\"aJTbXX\":[\"Heslo operátora\"],\"aQryQv\":[\"Vzor již existuje\"],\"aW9pLN\":[\"Maximální počet uživatelů povolených v kanálu. Nechte prázdné pro žádný limit.\"],\"ah4fmZ\":[\"Zobrazuje také náhledy z YouTube, Vimeo, SoundCloud a podobných známých služeb.\"],\"aifXak\":[\"V tomto kanálu nejsou žádná média\"],\"ap2zBz\":[\"Uvolněný\"],\"az8lvo\":[\"Vypnuto\"],\"azXSNo\":[\"Rozbalit seznam členů\"],\"azdliB\":[\"Přihlásit se k účtu\"],\"b26wlF\":[\"ona/její\"],\"bD/+Ei\":[\"Přísný\"],\"bQ6BJn\":[\"Nakonfigurujte podrobná pravidla ochrany proti floodingu. Každé pravidlo určuje, jaký typ aktivity sledovat a jakou akci provést při překročení prahů.\"],\"beV7+y\":[\"Uživatel obdrží pozvánku k připojení do \",[\"channelName\"],\".\"],\"bk84cH\":[\"Zpráva o nepřítomnosti\"],\"bkHdLj\":[\"Přidat IRC server\"],\"bmQLn5\":[\"Přidat pravidlo\"],\"bwRvnp\":[\"Akce\"],\"c8+EVZ\":[\"Ověřený účet\"],\"cGYUlD\":[\"Nejsou načteny žádné náhledy médií.\"],\"cLF98o\":[\"Zobrazit komentáře (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Žádní uživatelé nejsou k dispozici\"],\"cSgpoS\":[\"Připnout soukromou konverzaci\"],\"cde3ce\":[\"Zpráva <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopírovat formátovaný výstup\"],\"cl/A5J\":[\"Vítejte v \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Smazat\"],\"coPLXT\":[\"Neukládáme vaši IRC komunikaci na našich serverech\"],\"crYH/6\":[\"Přehrávač SoundCloud\"],\"d3sis4\":[\"Přidat server\"],\"d9aN5k\":[\"Odebrat \",[\"username\"],\" z kanálu\"],\"dEgA5A\":[\"Zrušit\"],\"dGi1We\":[\"Odepnout tuto soukromou konverzaci\"],\"dJVuyC\":[\"opustil \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"do\"],\"dXqxlh\":[\"<0>⚠️ Bezpečnostní riziko!</0> Toto připojení může být zranitelné vůči odposlechu nebo útokům man-in-the-middle.\"],\"da9Q/R\":[\"Změněny módy kanálu\"],\"dhJN3N\":[\"Zobrazit komentáře\"],\"dj2xTE\":[\"Odmítnout oznámení\"],\"dpCzmC\":[\"Nastavení ochrany proti floodingu\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Chcete otevřít tento odkaz v nové záložce?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Upravit\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Nahrajte obrázek nebo zadejte URL s volitelnou substitucí \",[\"size\"],\" pro dynamické velikosti\"],\"edBbee\":[\"Zabanovat \",[\"username\"],\" podle masky hostitele (zabrání opětovnému připojení ze stejné IP/hostitele)\"],\"ekfzWq\":[\"Nastavení uživatele\"],\"elPDWs\":[\"Přizpůsobte si IRC klienta\"],\"eu2osY\":[\"<0>💡 Doporučení:</0> Pokračujte pouze pokud důvěřujete tomuto serveru a rozumíte rizikům. Vyhněte se sdílení citlivých informací nebo hesel přes toto připojení.\"],\"euEhbr\":[\"Klikněte pro připojení k \",[\"channel\"]],\"ez3vLd\":[\"Povolit víceřádkové zadávání\"],\"f0J5Ki\":[\"Komunikace mezi servery může používat nešifrovaná připojení\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Varovat uživatele\"],\"fDOLLd\":[\"Nebyly nalezeny žádné kanály.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonymní analytika:\"],\"fq1GF9\":[\"Zobrazit při odpojení uživatelů ze serveru\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Tento server podporuje pouze jeden typ připojení\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Seznam ignorovaných\"],\"gNzMrk\":[\"Aktuální avatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Zadejte přezdívku...\"],\"gz6UQ3\":[\"Maximalizovat\"],\"h6razj\":[\"Maska vyloučení názvu kanálu\"],\"hG6jnw\":[\"Téma není nastaveno\"],\"hG89Ed\":[\"Obrázek\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"např. 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Množství\"],\"hzdLuQ\":[\"Mluvit mohou pouze uživatelé s hlasem nebo vyšší hodností\"],\"i0qMbr\":[\"Domů\"],\"iDNBZe\":[\"Oznámení\"],\"iH8pgl\":[\"Zpět\"],\"iL9SZg\":[\"Zabanovat uživatele (podle přezdívky)\"],\"iNt+3c\":[\"Zpět na obrázek\"],\"iQvi+a\":[\"Neupozorňovat mě na nízkou bezpečnost připojení pro tento server\"],\"iSLIjg\":[\"Připojit\"],\"iWXkHH\":[\"Polooperátor\"],\"iZeTtp\":[\"Hostitel serveru\"],\"idD8Ev\":[\"Uloženo\"],\"iivqkW\":[\"Přihlášen\"],\"ij+Elv\":[\"Náhled obrázku\"],\"ilIWp7\":[\"Přepnout oznámení\"],\"iuaqvB\":[\"Použijte * pro zástupné znaky. Příklady: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Ban podle masky hostitele\"],\"jA4uoI\":[\"Téma:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Důvod (volitelné)\"],\"jUV7CU\":[\"Nahrát avatar\"],\"jW5Uwh\":[\"Kontrolujte načítání externích médií. Vypnuto / Bezpečné / Důvěryhodné zdroje / Veškerý obsah.\"],\"jXzms5\":[\"Možnosti přílohy\"],\"jZlrte\":[\"Barva\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#nový-název-kanálu\"],\"k112DD\":[\"Načíst starší zprávy\"],\"k3ID0F\":[\"Filtrovat členy…\"],\"k65gsE\":[\"Podrobný přehled\"],\"k7Zgob\":[\"Zrušit připojení\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Nenalezeny žádné pozvánky\"],\"kCLEPU\":[\"Připojeno k\"],\"kF5LKb\":[\"Ignorované vzory:\"],\"kGeOx/\":[\"Připojit se k \",[\"0\"]],\"kITKr8\":[\"Načítám režimy kanálu...\"],\"kPpPsw\":[\"Jste IRC operátor\"],\"kWJmRL\":[\"Vy\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Kopírovat JSON\"],\"krViRy\":[\"Klikněte pro kopírování jako JSON\"],\"ks71ra\":[\"Výjimky\"],\"kw4lRv\":[\"Polooperátor kanálu\"],\"kxgIRq\":[\"Vyberte nebo přidejte kanál pro začátek.\"],\"ky6dWe\":[\"Náhled avatara\"],\"l+GxCv\":[\"Načítám kanály...\"],\"l+IUVW\":[\"Ověření účtu \",[\"account\"],\" proběhlo úspěšně: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"znovu se připojil\"],\"few\":[\"znovu se připojil \",[\"reconnectCount\"],\"×\"],\"many\":[\"znovu se připojil \",[\"reconnectCount\"],\"×\"],\"other\":[\"znovu se připojil \",[\"reconnectCount\"],\"×\"]}]],\"l5jmzx\":[[\"0\"],\" a \",[\"1\"],\" píší...\"],\"lHy8N5\":[\"Načítám více kanálů...\"],\"lbpf14\":[\"Připojit se k \",[\"value\"]],\"lfFsZ4\":[\"Kanály\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Název účtu\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Nahrát obrázek\"],\"loQxaJ\":[\"Jsem zpět\"],\"lvfaxv\":[\"DOMŮ\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Přidat\"],\"m8flAk\":[\"Náhled (ještě nenahrán)\"],\"mEPxTp\":[\"<0>⚠️ Buďte opatrní!</0> Otevírejte pouze odkazy z důvěryhodných zdrojů. Škodlivé odkazy mohou ohrozit vaši bezpečnost nebo soukromí.\"],\"mHGdhG\":[\"Informace o serveru\"],\"mHS8lb\":[\"Zpráva #\",[\"0\"]],\"mMYBD9\":[\"Široký - širší rozsah ochrany\"],\"mTGsPd\":[\"Téma kanálu\"],\"mU8j6O\":[\"Žádné externí zprávy (+n)\"],\"mZp8FL\":[\"Automatický návrat na jeden řádek\"],\"mdQu8G\":[\"VašePřezdívka\"],\"miSSBQ\":[\"Komentáře (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Uživatel je ověřen\"],\"mwtcGl\":[\"Zavřít komentáře\"],\"mzI/c+\":[\"Stáhnout\"],\"n3fGRk\":[\"nastaveno \",[\"0\"]],\"nE9jsU\":[\"Uvolněný - méně agresivní ochrana\"],\"nNflMD\":[\"Opustit kanál\"],\"nPXkBi\":[\"Načítám data WHOIS...\"],\"nQnxxF\":[\"Zpráva #\",[\"0\"],\" (Shift+Enter pro nový řádek)\"],\"nWMRxa\":[\"Odepnout\"],\"nkC032\":[\"Žádný profil floodingu\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Odeslat varovnou zprávu uživateli \",[\"username\"]],\"o9ylQi\":[\"Hledejte GIFy pro začátek\"],\"oFGkER\":[\"Oznámení serveru\"],\"oOi11l\":[\"Přejít dolů\"],\"oQEzQR\":[\"Nová DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Útoky man-in-the-middle na serverová připojení jsou možné\"],\"oeqmmJ\":[\"Důvěryhodné zdroje\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Výchozí\"],\"p0Z69r\":[\"Vzor nemůže být prázdný\"],\"p1KgtK\":[\"Nepodařilo se načíst zvuk\"],\"p59pEv\":[\"Další podrobnosti\"],\"p7sRI6\":[\"Informovat ostatní, když píšete\"],\"pBm1od\":[\"Tajný kanál\"],\"pNmiXx\":[\"Vaše výchozí přezdívka pro všechny servery\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Heslo účtu\"],\"peNE68\":[\"Trvalý\"],\"plhHQt\":[\"Žádná data\"],\"pm6+q5\":[\"Bezpečnostní upozornění\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Další informace\"],\"q0cR4S\":[\"je nyní znám jako **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Kanál se nebude zobrazovat v příkazech LIST nebo NAMES\"],\"qLpTm/\":[\"Odebrat reakci \",[\"emoji\"]],\"qVkGWK\":[\"Připnout\"],\"qY8wNa\":[\"Domovská stránka\"],\"qb0xJ7\":[\"Použijte zástupné znaky: * odpovídá libovolné sekvenci, ? odpovídá libovolnému jednomu znaku. Příklady: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Klíč kanálu (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Bez omezení\"],\"r1W2AS\":[\"Obrázek z file hostu\"],\"rIPR2O\":[\"Téma nastaveno před (min)\"],\"rMMSYo\":[\"Maximální délka je \",[\"0\"]],\"rWtzQe\":[\"Síť se rozdělila a znovu připojila. ✅\"],\"rYG2u6\":[\"Prosím čekejte...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Náhled\"],\"rjGI/Q\":[\"Soukromí\"],\"rk8iDX\":[\"Načítám GIFy...\"],\"rn6SBY\":[\"Zrušit ztlumení\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Byl vykopnut z kanálu\"],\"s8cATI\":[\"se připojil k \",[\"channelName\"]],\"sCO9ue\":[\"Připojení k <0>\",[\"serverName\"],\"</0> má následující bezpečnostní problémy:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"je nyní znám jako **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" vás pozval k připojení do \",[\"channel\"]],\"sby+1/\":[\"Klikněte pro kopírování\"],\"sfN25C\":[\"Vaše skutečné nebo celé jméno\"],\"sliuzR\":[\"Otevřít odkaz\"],\"sqrO9R\":[\"Vlastní zmínky\"],\"sr6RdJ\":[\"Víceřádkové na Shift+Enter\"],\"swrCpB\":[\"Kanál byl přejmenován z \",[\"oldName\"],\" na \",[\"newName\"],\" uživatelem \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Pokročilé\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Odebrat\"],\"t47eHD\":[\"Váš jedinečný identifikátor na tomto serveru\"],\"tAkAh0\":[\"URL s volitelnou substitucí \",[\"size\"],\" pro dynamické velikosti. Příklad: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Zobrazit nebo skrýt boční panel se seznamem kanálů\"],\"tfDRzk\":[\"Uložit\"],\"tiBsJk\":[\"opustil \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"se odpojil (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Přezdívka {nick} je již používána, zkouším s {newNick}\"],\"u0a8B4\":[\"Ověřit jako IRC operátor pro administrativní přístup\"],\"u0rWFU\":[\"Vytvořeno po (min. zpět)\"],\"u72w3t\":[\"Uživatelé a vzory k ignorování\"],\"u7jc2L\":[\"se odpojil\"],\"uAQUqI\":[\"Stav\"],\"uB85T3\":[\"Uložení selhalo: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC servery:\"],\"usSSr/\":[\"Úroveň přiblížení\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Použijte Shift+Enter pro nový řádek (Enter odešle)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Bez tématu\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Jazyk\"],\"vaHYxN\":[\"Skutečné jméno\"],\"vhjbKr\":[\"Nepřítomen\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[\"klient \",[\"title\"]],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Neplatná hodnota\"],\"wFjjxZ\":[\"byl vyhozen z \",[\"channelName\"],\" uživatelem \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nenalezeny žádné výjimky zákazu\"],\"wPrGnM\":[\"Správce kanálu\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Zobrazovat, když uživatelé vstupují nebo opouštějí kanály\"],\"whqZ9r\":[\"Další slova nebo fráze ke zvýraznění\"],\"wm7RV4\":[\"Zvuk oznámení\"],\"wz/Yoq\":[\"Vaše zprávy mohou být zachyceny při přeposílání mezi servery\"],\"xCJdfg\":[\"Vymazat\"],\"xUHRTR\":[\"Automaticky ověřit jako operátor při připojení\"],\"xWHwwQ\":[\"Bany\"],\"xYilR2\":[\"Média\"],\"xceQrO\":[\"Jsou podporovány pouze zabezpečené websocket připojení\"],\"xdtXa+\":[\"název-kanálu\"],\"xfXC7q\":[\"Textové kanály\"],\"xlCYOE\":[\"Načítám více zpráv...\"],\"xlhswE\":[\"Minimální hodnota je \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Přidat slovo nebo frázi...\"],\"xuRqRq\":[\"Limit klientů (+l)\"],\"xwF+7J\":[[\"0\"],\" píše...\"],\"yNeucF\":[\"Tento server nepodporuje rozšířená metadata profilu (rozšíření IRCv3 METADATA). Další pole jako avatar, zobrazované jméno a stav nejsou k dispozici.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Avatar kanálu\"],\"yQE2r9\":[\"Načítání\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Uživatelské jméno operátora\"],\"yYOzWD\":[\"logy\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"Heslo IRC operátora\"],\"ygCKqB\":[\"Zastavit\"],\"ymDxJx\":[\"Uživatelské jméno IRC operátora\"],\"yrpRsQ\":[\"Seřadit podle názvu\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Zavřít\"],\"zJw+jA\":[\"nastavuje režim: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Zadejte uživatelské jméno operátora\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Ungültiges Musterformat. Verwenden Sie nick!user@host (Platzhalter * erlaubt)\"],\"+6NQQA\":[\"Allgemeiner Support-Kanal\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Trennen\"],\"+cyFdH\":[\"Standardnachricht beim Als-abwesend-markieren\"],\"+mVPqU\":[\"Markdown-Formatierung in Nachrichten rendern\"],\"+vqCJH\":[\"Ihr Kontobenutzername zur Authentifizierung\"],\"+yPBXI\":[\"Datei auswählen\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Geringe Verbindungssicherheit (Stufe \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Externe Benutzer können keine Nachrichten senden\"],\"/6BzZF\":[\"Mitgliederliste umschalten\"],\"/TNOPk\":[\"Benutzer ist abwesend\"],\"/XQgft\":[\"Entdecken\"],\"/cF7Rs\":[\"Lautstärke\"],\"/dqduX\":[\"Nächste Seite\"],\"/fc3q4\":[\"Alle Inhalte\"],\"/kISDh\":[\"Benachrichtigungstöne aktivieren\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Töne bei Erwähnungen und Nachrichten abspielen\"],\"0/0ZGA\":[\"Kanalname-Maske\"],\"0D6j7U\":[\"Mehr über benutzerdefinierte Regeln erfahren →\"],\"0XsHcR\":[\"Benutzer rauswerfen\"],\"0ZpE//\":[\"Nach Benutzern sortieren\"],\"0bEPwz\":[\"Als abwesend setzen\"],\"0dGkPt\":[\"Kanalliste ausklappen\"],\"0gS7M5\":[\"Anzeigename\"],\"0kS+M8\":[\"BeispielNET\"],\"0rgoY7\":[\"Nur mit ausgewählten Servern verbinden\"],\"0wdd7X\":[\"Beitreten\"],\"0wkVYx\":[\"Privatnachrichten\"],\"111uHX\":[\"Link-Vorschau\"],\"196EG4\":[\"Privatnachricht löschen\"],\"1DSr1i\":[\"Konto registrieren\"],\"1O/24y\":[\"Kanalliste umschalten\"],\"1VPJJ2\":[\"Warnung: Externer Link\"],\"1ZC/dv\":[\"Keine ungelesenen Erwähnungen oder Nachrichten\"],\"1pO1zi\":[\"Servername ist erforderlich\"],\"1uwfzQ\":[\"Kanalthema anzeigen\"],\"268g7c\":[\"Anzeigenamen eingeben\"],\"2FOFq1\":[\"Server-Operatoren im Netzwerk könnten deine Nachrichten lesen\"],\"2FYpfJ\":[\"Mehr\"],\"2HF1Y2\":[[\"inviter\"],\" hat \",[\"target\"],\" eingeladen, \",[\"channel\"],\" beizutreten\"],\"2I70QL\":[\"Benutzerprofilinformationen anzeigen\"],\"2QYdmE\":[\"Benutzer:\"],\"2QpEjG\":[\"hat verlassen\"],\"2YE223\":[\"Nachricht an #\",[\"0\"],\" (Enter für neue Zeile, Shift+Enter zum Senden)\"],\"2bimFY\":[\"Server-Passwort verwenden\"],\"2iTmdZ\":[\"Lokaler Speicher:\"],\"2odkwe\":[\"Streng – Aggressiverer Schutz\"],\"2uDhbA\":[\"Benutzername zum Einladen eingeben\"],\"2ygf/L\":[\"← Zurück\"],\"2zEgxj\":[\"GIFs suchen...\"],\"3RdPhl\":[\"Kanal umbenennen\"],\"3THokf\":[\"Benutzer mit Sprachrecht\"],\"3TSz9S\":[\"Minimieren\"],\"3jBDvM\":[\"Kanal-Anzeigename\"],\"3ryuFU\":[\"Optionale Absturzberichte zur App-Verbesserung\"],\"3uBF/8\":[\"Ansicht schließen\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Kontoname eingeben...\"],\"4/Rr0R\":[\"Benutzer in den aktuellen Kanal einladen\"],\"4EZrJN\":[\"Regeln\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flood-Profil (+F)\"],\"4RZQRK\":[\"Was machst du gerade?\"],\"4hfTrB\":[\"Nickname\"],\"4n99LO\":[\"Bereits in \",[\"0\"]],\"4t6vMV\":[\"Kurze Nachrichten automatisch einzeilig darstellen\"],\"4vsHmf\":[\"Zeit (Min)\"],\"5+INAX\":[\"Nachrichten hervorheben, die Sie erwähnen\"],\"5R5Pv/\":[\"Oper Name\"],\"678PKt\":[\"Netzwerkname\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Passwort zum Beitreten erforderlich. Leer lassen, um den Schlüssel zu entfernen.\"],\"6HhMs3\":[\"Abgangsnachricht\"],\"6V3Ea3\":[\"Kopiert\"],\"6lGV3K\":[\"Weniger anzeigen\"],\"6yFOEi\":[\"Oper-Passwort eingeben...\"],\"7+IHTZ\":[\"Keine Datei ausgewählt\"],\"73hrRi\":[\"nick!user@host (z.B. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Privatnachricht senden\"],\"7U1W7c\":[\"Sehr locker\"],\"7Y1YQj\":[\"Echter Name:\"],\"7YHArF\":[\"— im Viewer öffnen\"],\"7fjnVl\":[\"Benutzer suchen...\"],\"7jL88x\":[\"Diese Nachricht löschen? Dies kann nicht rückgängig gemacht werden.\"],\"7nGhhM\":[\"Was denkst du gerade?\"],\"7sEpu1\":[\"Mitglieder — \",[\"0\"]],\"7sNhEz\":[\"Benutzername\"],\"8H0Q+x\":[\"Mehr über Profile erfahren →\"],\"8Phu0A\":[\"Anzeigen, wenn Benutzer ihren Nickname ändern\"],\"8XTG9e\":[\"oper-Passwort eingeben\"],\"8XsV2J\":[\"Erneut senden\"],\"8ZsakT\":[\"Passwort\"],\"8kR84m\":[\"Du bist dabei, einen externen Link zu öffnen:\"],\"8lCgih\":[\"Regel entfernen\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"beigetreten\"],\"other\":[[\"joinCount\"],\"-mal beigetreten\"]}]],\"9BMLnJ\":[\"Erneut mit Server verbinden\"],\"9OEgyT\":[\"Reaktion hinzufügen\"],\"9PQ8m2\":[\"G-Line (globaler Ban)\"],\"9Qs99X\":[\"E-Mail:\"],\"9QupBP\":[\"Muster entfernen\"],\"9bG48P\":[\"Wird gesendet\"],\"9f5f0u\":[\"Fragen zum Datenschutz? Kontaktieren Sie uns:\"],\"9unqs3\":[\"Abwesend:\"],\"9v3hwv\":[\"Keine Server gefunden.\"],\"9zb2WA\":[\"Verbinden...\"],\"A1taO8\":[\"Suchen\"],\"A2adVi\":[\"Tipp-Benachrichtigungen senden\"],\"A9Rhec\":[\"Kanalname\"],\"AWOSPo\":[\"Vergrößern\"],\"AXSpEQ\":[\"Oper beim Verbinden\"],\"AeXO77\":[\"Konto\"],\"AhNP40\":[\"Vor-/Zurückspulen\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Nickname geändert\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Antwort abbrechen\"],\"ApSx0O\":[[\"0\"],\" Nachrichten gefunden, die zu \\\"\",[\"searchQuery\"],\"\\\" passen\"],\"AxPAXW\":[\"Keine Ergebnisse gefunden\"],\"AyNqAB\":[\"Alle Serverereignisse im Chat anzeigen\"],\"B/QqGw\":[\"Nicht am Rechner\"],\"B8AaMI\":[\"Dieses Feld ist erforderlich\"],\"BA2c49\":[\"Server unterstützt keine erweiterte LIST-Filterung\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" und \",[\"3\"],\" weitere tippen...\"],\"BGul2A\":[\"Du hast ungespeicherte Änderungen. Möchtest du wirklich schließen, ohne zu speichern?\"],\"BIf9fi\":[\"Ihre Statusnachricht\"],\"BZz3md\":[\"Ihre persönliche Website\"],\"Bgm/H7\":[\"Mehrzeilige Texteingabe erlauben\"],\"BiQIl1\":[\"Dieses Privatgespräch anheften\"],\"BlNZZ2\":[\"Klicken, um zur Nachricht zu springen\"],\"Bowq3c\":[\"Nur Operatoren können das Kanalthema ändern\"],\"Btozzp\":[\"Dieses Bild ist abgelaufen\"],\"Bycfjm\":[\"Gesamt: \",[\"0\"]],\"C6IBQc\":[\"Gesamtes JSON kopieren\"],\"C9L9wL\":[\"Datenerfassung\"],\"CDq4wC\":[\"Benutzer moderieren\"],\"CHVRxG\":[\"Nachricht an @\",[\"0\"],\" (Shift+Enter für neue Zeile)\"],\"CN9zdR\":[\"Oper-Name und Passwort sind erforderlich\"],\"CW3sYa\":[\"Reaktion \",[\"emoji\"],\" hinzufügen\"],\"CaAkqd\":[\"Verbindungstrennungen anzeigen\"],\"CbvaYj\":[\"Nach Nickname sperren\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Kanal auswählen\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"ist beigetreten und gegangen\"],\"DB8zMK\":[\"Anwenden\"],\"DBcWHr\":[\"Benutzerdefinierte Benachrichtigungstondatei\"],\"DTy9Xw\":[\"Medienvorschauen\"],\"Dj4pSr\":[\"Sicheres Passwort wählen\"],\"Du+zn+\":[\"Suche...\"],\"Du2T2f\":[\"Einstellung nicht gefunden\"],\"DwsSVQ\":[\"Filter anwenden & Aktualisieren\"],\"E3W/zd\":[\"Standard-Nickname\"],\"E6nRW7\":[\"URL kopieren\"],\"E703RG\":[\"Modi:\"],\"EAeu1Z\":[\"Einladung senden\"],\"EFKJQT\":[\"Einstellung\"],\"EGPQBv\":[\"Benutzerdefinierte Flood-Regeln (+f)\"],\"ELik0r\":[\"Vollständige Datenschutzrichtlinie anzeigen\"],\"EPbeC2\":[\"Kanalthema anzeigen oder bearbeiten\"],\"EQCDNT\":[\"Oper-Benutzernamen eingeben...\"],\"EUvulZ\":[\"1 Nachricht gefunden, die zu \\\"\",[\"searchQuery\"],\"\\\" passt\"],\"EatZYJ\":[\"Nächstes Bild\"],\"EdQY6l\":[\"Keine\"],\"EnqLYU\":[\"Server suchen...\"],\"F0OKMc\":[\"Server bearbeiten\"],\"F6Int2\":[\"Hervorhebungen aktivieren\"],\"FDoLyE\":[\"Max. Benutzer\"],\"FUU/hZ\":[\"Steuert, wie viele externe Medien im Chat geladen werden.\"],\"Fdp03t\":[\"an\"],\"FfPWR0\":[\"Modal\"],\"FjkaiT\":[\"Verkleinern\"],\"FlqOE9\":[\"Was das bedeutet:\"],\"FolHNl\":[\"Konto und Authentifizierung verwalten\"],\"Fp2Dif\":[\"Den Server verlassen\"],\"G5KmCc\":[\"GZ-Line (globale Z-Line)\"],\"GDs0lz\":[\"<0>Risiko:</0> Sensible Informationen (Nachrichten, private Gespräche, Authentifizierungsdaten) könnten Netzwerkadministratoren oder Angreifern zwischen IRC-Servern zugänglich sein.\"],\"GR+2I3\":[\"Einladungs-Maske hinzufügen (z.B. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Server-Hinweise schließen\"],\"GlHnXw\":[\"Nicknamewechsel fehlgeschlagen: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Vorschau:\"],\"GtmO8/\":[\"von\"],\"GtuHUQ\":[\"Diesen Kanal auf dem Server umbenennen. Alle Benutzer sehen den neuen Namen.\"],\"GuGfFX\":[\"Suche umschalten\"],\"GxkJXS\":[\"Wird hochgeladen...\"],\"GzbwnK\":[\"Dem Kanal beigetreten\"],\"GzsUDB\":[\"Erweitertes Profil\"],\"H/PnT8\":[\"Emoji einfügen\"],\"H6Izzl\":[\"Ihr bevorzugter Farbcode\"],\"H9jIv+\":[\"Beitritte/Abgänge anzeigen\"],\"HAKBY9\":[\"Dateien hochladen\"],\"HdE1If\":[\"Kanal\"],\"Hk4AW9\":[\"Ihr bevorzugter Anzeigename\"],\"HmHDk7\":[\"Mitglied auswählen\"],\"HrQzPU\":[\"Kanäle auf \",[\"networkName\"]],\"I2tXQ5\":[\"Nachricht an @\",[\"0\"],\" (Enter für neue Zeile, Shift+Enter zum Senden)\"],\"I6bw/h\":[\"Benutzer sperren\"],\"I92Z+b\":[\"Benachrichtigungen aktivieren\"],\"I9D72S\":[\"Bist du sicher, dass du diese Nachricht löschen möchtest? Diese Aktion kann nicht rückgängig gemacht werden.\"],\"IA+1wo\":[\"Anzeigen, wenn Benutzer aus Kanälen gekickt werden\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Änderungen speichern\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" und \",[\"2\"],\" tippen...\"],\"IgrLD/\":[\"Pause\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Antworten\"],\"IoHMnl\":[\"Maximalwert ist \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Verbinde...\"],\"J5T9NW\":[\"Benutzerinformationen\"],\"J8Y5+z\":[\"Ups! Netz-Split! ⚠️\"],\"JBHkBA\":[\"Den Kanal verlassen\"],\"JCwL0Q\":[\"Grund eingeben (optional)\"],\"JFciKP\":[\"Umschalten\"],\"JXGkhG\":[\"Kanalnamen ändern (nur Operatoren)\"],\"JcD7qf\":[\"Weitere Aktionen\"],\"JdkA+c\":[\"Geheim (+s)\"],\"Jmu12l\":[\"Serverkanäle\"],\"JvQ++s\":[\"Markdown aktivieren\"],\"K2jwh/\":[\"Keine WHOIS-Daten verfügbar\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Nachricht löschen\"],\"KKBlUU\":[\"Einbetten\"],\"KM0pLb\":[\"Willkommen im Kanal!\"],\"KR6W2h\":[\"Benutzer nicht mehr ignorieren\"],\"KV+Bi1\":[\"Nur auf Einladung (+i)\"],\"KdCtwE\":[\"Wie viele Sekunden Flood-Aktivität überwacht wird, bevor die Zähler zurückgesetzt werden\"],\"Kkezga\":[\"Server-Passwort\"],\"KsiQ/8\":[\"Benutzer müssen eingeladen werden\"],\"L+gB/D\":[\"Kanalinformationen\"],\"LC1a7n\":[\"Der IRC-Server hat gemeldet, dass seine Server-zu-Server-Verbindungen ein niedriges Sicherheitsniveau aufweisen. Das bedeutet, dass deine Nachrichten beim Weiterleiten zwischen IRC-Servern im Netzwerk möglicherweise nicht ordnungsgemäß verschlüsselt sind oder die SSL/TLS-Zertifikate nicht korrekt validiert werden.\"],\"LNfLR5\":[\"Kicks anzeigen\"],\"LQb0W/\":[\"Alle Ereignisse anzeigen\"],\"LU7/yA\":[\"Alternativer Anzeigename. Kann Leerzeichen, Emojis und Sonderzeichen enthalten. Der echte Kanalname (\",[\"channelName\"],\") wird weiterhin für IRC-Befehle verwendet.\"],\"LUb9O7\":[\"Ein gültiger Server-Port ist erforderlich\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Datenschutzrichtlinie\"],\"LcuSDR\":[\"Profilinformationen und Metadaten verwalten\"],\"LqLS9B\":[\"Nickwechsel anzeigen\"],\"LsDQt2\":[\"Kanaleinstellungen\"],\"LtI9AS\":[\"Eigentümer\"],\"LuNhhL\":[\"hat auf diese Nachricht reagiert\"],\"M/AZNG\":[\"URL zu Ihrem Avatar-Bild\"],\"M/WIer\":[\"Nachricht senden\"],\"M8er/5\":[\"Name:\"],\"MHk+7g\":[\"Vorheriges Bild\"],\"MRorGe\":[\"Benutzer anschreiben\"],\"MVbSGP\":[\"Zeitfenster (Sekunden)\"],\"MkpcsT\":[\"Ihre Nachrichten und Einstellungen werden lokal gespeichert\"],\"N/hDSy\":[\"Als Bot markieren – normalerweise 'on' oder leer\"],\"N7TQbE\":[\"Benutzer zu \",[\"channelName\"],\" einladen\"],\"NCca/o\":[\"Standard-Spitznamen eingeben...\"],\"Nqs6B9\":[\"Zeigt alle externen Medien. Jede URL kann eine Anfrage an einen unbekannten Server auslösen.\"],\"Nt+9O7\":[\"WebSocket statt rohem TCP verwenden\"],\"NxIHzc\":[\"Benutzer trennen\"],\"O+v/cL\":[\"Alle Kanäle auf dem Server durchsuchen\"],\"ODwSCk\":[\"GIF senden\"],\"OGQ5kK\":[\"Benachrichtigungstöne und Hervorhebungen konfigurieren\"],\"OIPt1Z\":[\"Seitenleiste der Mitgliederliste ein- oder ausblenden\"],\"OKSNq/\":[\"Sehr streng\"],\"ONWvwQ\":[\"Hochladen\"],\"OVKoQO\":[\"Ihr Kontopasswort zur Authentifizierung\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - IRC in die Zukunft bringen\"],\"OhCpra\":[\"Thema setzen…\"],\"OkltoQ\":[[\"username\"],\" per Nickname sperren (verhindert erneutes Beitreten mit demselben Nick)\"],\"P+t/Te\":[\"Keine weiteren Daten\"],\"P42Wcc\":[\"Sicher\"],\"PD38l0\":[\"Kanal-Avatar-Vorschau\"],\"PD9mEt\":[\"Nachricht eingeben...\"],\"PPqfdA\":[\"Kanaleinstellungen öffnen\"],\"PSCjfZ\":[\"Das Thema für diesen Kanal. Alle Benutzer können es sehen.\"],\"PZCecv\":[\"PDF-Vorschau\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 Mal\"],\"other\":[[\"c\"],\" Mal\"]}]],\"PguS2C\":[\"Ausnahme-Maske hinzufügen (z.B. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[[\"displayedChannelsCount\"],\" von \",[\"0\"],\" Kanälen angezeigt\"],\"PqhVlJ\":[\"Benutzer sperren (per Hostmask)\"],\"Q+chwU\":[\"Benutzername:\"],\"Q6hhn8\":[\"Einstellungen\"],\"QF4a34\":[\"Bitte gib einen Benutzernamen ein\"],\"QGqSZ2\":[\"Farbe & Formatierung\"],\"QJQd1J\":[\"Profil bearbeiten\"],\"QSzGDE\":[\"Inaktiv\"],\"QUlny5\":[\"Willkommen bei \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Mehr lesen\"],\"QuSkCF\":[\"Kanäle filtern...\"],\"QwUrDZ\":[\"hat das Thema geändert zu: \",[\"topic\"]],\"R0UH07\":[\"Bild \",[\"0\"],\" von \",[\"1\"]],\"R7SsBE\":[\"Stumm schalten\"],\"R8rf1X\":[\"Klicken, um das Thema zu setzen\"],\"RArB3D\":[\"wurde von \",[\"username\"],\" aus \",[\"channelName\"],\" gekickt\"],\"RI3cWd\":[\"Entdecke die Welt von IRC mit ObsidianIRC\"],\"RMMaN5\":[\"Moderiert (+m)\"],\"RWw9Lg\":[\"Fenster schließen\"],\"RZ2BuZ\":[\"Kontoregistrierung für \",[\"account\"],\" erfordert Verifizierung: \",[\"message\"]],\"RySp6q\":[\"Kommentare ausblenden\"],\"SPKQTd\":[\"Nickname ist erforderlich\"],\"SPVjfj\":[\"Standardmäßig 'kein Grund', wenn leer gelassen\"],\"SQKPvQ\":[\"Benutzer einladen\"],\"SkZcl+\":[\"Wähle ein vordefiniertes Flood-Schutzprofil. Diese Profile bieten ausgewogene Schutzeinstellungen für verschiedene Anwendungsfälle.\"],\"Slr+3C\":[\"Min. Benutzer\"],\"Spnlre\":[\"Du hast \",[\"target\"],\" eingeladen, \",[\"channel\"],\" beizutreten\"],\"T/ckN5\":[\"Im Viewer öffnen\"],\"T91vKp\":[\"Abspielen\"],\"TV2Wdu\":[\"Erfahren Sie, wie wir Ihre Daten verwalten und Ihre Privatsphäre schützen.\"],\"TgFpwD\":[\"Wird angewendet...\"],\"TkzSFB\":[\"Keine Änderungen\"],\"TtserG\":[\"Echten Namen eingeben\"],\"Ttz9J1\":[\"Passwort eingeben...\"],\"Tz0i8g\":[\"Einstellungen\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagieren\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Einstellungen speichern\"],\"UV5hLB\":[\"Keine Sperren gefunden\"],\"Uaj3Nd\":[\"Statusnachrichten\"],\"Ue3uny\":[\"Standard (kein Profil)\"],\"UkARhe\":[\"Normal – Standardschutz\"],\"Umn7Cj\":[\"Noch keine Kommentare. Sei der Erste!\"],\"UtUIRh\":[[\"0\"],\" ältere Nachrichten\"],\"UwzP+U\":[\"Sichere Verbindung\"],\"V0/A4O\":[\"Kanalbesitzer\"],\"V4qgxE\":[\"Erstellt vor (Min.)\"],\"V8yTm6\":[\"Suche löschen\"],\"VJMMyz\":[\"ObsidianIRC - IRC in die Zukunft bringen\"],\"VJScHU\":[\"Grund\"],\"VLsmVV\":[\"Benachrichtigungen stummschalten\"],\"VbyRUy\":[\"Kommentare\"],\"Vmx0mQ\":[\"Gesetzt von:\"],\"VqnIZz\":[\"Datenschutzrichtlinie und Datenpraktiken anzeigen\"],\"VrMygG\":[\"Mindestlänge ist \",[\"0\"]],\"VrnTui\":[\"Ihre Pronomen, im Profil angezeigt\"],\"W8E3qn\":[\"Authentifiziertes Konto\"],\"WAakm9\":[\"Kanal löschen\"],\"WFxTHC\":[\"Bann-Maske hinzufügen (z.B. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Server-Host ist erforderlich\"],\"WRYdXW\":[\"Audioposition\"],\"WUOH5B\":[\"Benutzer ignorieren\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"1 weiteres Element anzeigen\"],\"other\":[[\"1\"],\" weitere Elemente anzeigen\"]}]],\"Weq9zb\":[\"Allgemein\"],\"Wfj7Sk\":[\"Benachrichtigungstöne stummschalten oder aktivieren\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Benutzerprofil\"],\"X6S3lt\":[\"Einstellungen, Kanäle, Server suchen...\"],\"XEHan5\":[\"Trotzdem fortfahren\"],\"XI1+wb\":[\"Ungültiges Format\"],\"XIXeuC\":[\"Nachricht an @\",[\"0\"]],\"XMS+k4\":[\"Privatnachricht starten\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Privatnachricht loslösen\"],\"Xm/s+u\":[\"Anzeige\"],\"Xp2n93\":[\"Zeigt Medien vom vertrauenswürdigen Datei-Host deines Servers. Es werden keine Anfragen an externe Dienste gestellt.\"],\"XvjC4F\":[\"Wird gespeichert...\"],\"Y/qryO\":[\"Keine Benutzer gefunden, die deiner Suche entsprechen\"],\"YAqRpI\":[\"Kontoregistrierung für \",[\"account\"],\" erfolgreich: \",[\"message\"]],\"YEfzvP\":[\"Geschütztes Thema (+t)\"],\"YQOn6a\":[\"Mitgliederliste einklappen\"],\"YRCoE9\":[\"Kanal-Operator\"],\"YURQaF\":[\"Profil anzeigen\"],\"YdBSvr\":[\"Medienanzeige und externe Inhalte steuern\"],\"Yj6U3V\":[\"Kein zentraler Server:\"],\"YjvpGx\":[\"Pronomen\"],\"YqH4l4\":[\"Kein Schlüssel\"],\"YyUPpV\":[\"Konto:\"],\"ZJSWfw\":[\"Nachricht beim Trennen vom Server\"],\"ZR1dJ4\":[\"Einladungen\"],\"ZdWg0V\":[\"Im Browser öffnen\"],\"ZhRBbl\":[\"Nachrichten suchen…\"],\"Zmcu3y\":[\"Erweiterte Filter\"],\"a2/8e5\":[\"Thema gesetzt nach (Min.)\"],\"aHKcKc\":[\"Vorherige Seite\"],\"aJTbXX\":[\"Oper Password\"],\"aQryQv\":[\"Muster existiert bereits\"],\"aW9pLN\":[\"Maximale Anzahl der zugelassenen Benutzer. Leer lassen für kein Limit.\"],\"ah4fmZ\":[\"Zeigt auch Vorschauen von YouTube, Vimeo, SoundCloud und ähnlichen bekannten Diensten.\"],\"aifXak\":[\"Keine Medien in diesem Kanal\"],\"ap2zBz\":[\"Locker\"],\"az8lvo\":[\"Aus\"],\"azXSNo\":[\"Mitgliederliste ausklappen\"],\"azdliB\":[\"Bei einem Konto anmelden\"],\"b26wlF\":[\"sie/ihr\"],\"bD/+Ei\":[\"Streng\"],\"bQ6BJn\":[\"Detaillierte Flood-Schutzregeln konfigurieren. Jede Regel legt fest, welche Aktivitäten überwacht werden sollen und welche Maßnahmen bei Überschreitung der Schwellenwerte ergriffen werden.\"],\"beV7+y\":[\"Der Benutzer erhält eine Einladung, \",[\"channelName\"],\" beizutreten.\"],\"bk84cH\":[\"Abwesenheitsnachricht\"],\"bkHdLj\":[\"IRC-Server hinzufügen\"],\"bmQLn5\":[\"Regel hinzufügen\"],\"bwRvnp\":[\"Aktion\"],\"c8+EVZ\":[\"Verifiziertes Konto\"],\"cGYUlD\":[\"Es werden keine Medienvorschauen geladen.\"],\"cLF98o\":[\"Kommentare anzeigen (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Keine Benutzer verfügbar\"],\"cSgpoS\":[\"Privatnachricht anheften\"],\"cde3ce\":[\"Nachricht an <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Formatierte Ausgabe kopieren\"],\"cl/A5J\":[\"Willkommen bei \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Löschen\"],\"coPLXT\":[\"Wir speichern Ihre IRC-Kommunikation nicht auf unseren Servern\"],\"crYH/6\":[\"SoundCloud-Player\"],\"d3sis4\":[\"Server hinzufügen\"],\"d9aN5k\":[[\"username\"],\" aus dem Kanal entfernen\"],\"dEgA5A\":[\"Abbrechen\"],\"dGi1We\":[\"Dieses Privatgespräch loslösen\"],\"dJVuyC\":[\"hat \",[\"channelName\"],\" verlassen (\",[\"reason\"],\")\"],\"dMtLDE\":[\"an\"],\"dXqxlh\":[\"<0>⚠️ Sicherheitsrisiko!</0> Diese Verbindung könnte anfällig für Abhören oder Man-in-the-Middle-Angriffe sein.\"],\"da9Q/R\":[\"Kanalmodi geändert\"],\"dhJN3N\":[\"Kommentare anzeigen\"],\"dj2xTE\":[\"Benachrichtigung schließen\"],\"dpCzmC\":[\"Flood-Schutz-Einstellungen\"],\"e9dQpT\":[\"Möchtest du diesen Link in einem neuen Tab öffnen?\"],\"ePK91l\":[\"Bearbeiten\"],\"eYBDuB\":[\"Bild hochladen oder URL mit optionaler \",[\"size\"],\"-Substitution angeben\"],\"edBbee\":[[\"username\"],\" per hostmask sperren (verhindert erneutes Beitreten von derselben IP/Host)\"],\"ekfzWq\":[\"Benutzereinstellungen\"],\"elPDWs\":[\"IRC-Client-Erfahrung anpassen\"],\"eu2osY\":[\"<0>💡 Empfehlung:</0> Fahre nur fort, wenn du diesem Server vertraust und die Risiken kennst. Teile keine sensiblen Informationen oder Passwörter über diese Verbindung.\"],\"euEhbr\":[\"Klicke, um \",[\"channel\"],\" beizutreten\"],\"ez3vLd\":[\"Mehrzeilige Eingabe aktivieren\"],\"f0J5Ki\":[\"Die Server-zu-Server-Kommunikation verwendet möglicherweise unverschlüsselte Verbindungen\"],\"f9BHJk\":[\"Benutzer warnen\"],\"fDOLLd\":[\"Keine Kanäle gefunden.\"],\"ffzDkB\":[\"Anonyme Analysen:\"],\"fq1GF9\":[\"Anzeigen, wenn Benutzer die Verbindung trennen\"],\"gEF57C\":[\"Dieser Server unterstützt nur einen Verbindungstyp\"],\"gJuLUI\":[\"Ignorierliste\"],\"gNzMrk\":[\"Aktueller Avatar\"],\"gjPWyO\":[\"Spitznamen eingeben...\"],\"gz6UQ3\":[\"Maximieren\"],\"h6razj\":[\"Kanalname-Maske ausschließen\"],\"hG6jnw\":[\"Kein Thema gesetzt\"],\"hG89Ed\":[\"Bild\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"z.B. 100:1440\"],\"hehnjM\":[\"Anzahl\"],\"hzdLuQ\":[\"Nur Benutzer mit Voice oder höher können sprechen\"],\"i0qMbr\":[\"Startseite\"],\"iDNBZe\":[\"Benachrichtigungen\"],\"iH8pgl\":[\"Zurück\"],\"iL9SZg\":[\"Benutzer sperren (per Nickname)\"],\"iNt+3c\":[\"Zurück zum Bild\"],\"iQvi+a\":[\"Nicht mehr vor geringer Verbindungssicherheit für diesen Server warnen\"],\"iSLIjg\":[\"Verbinden\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Server-Host\"],\"idD8Ev\":[\"Gespeichert\"],\"iivqkW\":[\"Angemeldet seit\"],\"ij+Elv\":[\"Bildvorschau\"],\"ilIWp7\":[\"Benachrichtigungen umschalten\"],\"iuaqvB\":[\"* als Platzhalter verwenden. Beispiele: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Nach Hostmaske sperren\"],\"jA4uoI\":[\"Thema:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Grund (optional)\"],\"jUV7CU\":[\"Avatar hochladen\"],\"jW5Uwh\":[\"Steuert, wie viele externe Medien geladen werden. Aus / Sicher / Vertrauenswürdige / Alle Inhalte.\"],\"jXzms5\":[\"Anhangsoptionen\"],\"jZlrte\":[\"Farbe\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Ältere Nachrichten laden\"],\"k3ID0F\":[\"Mitglieder filtern…\"],\"k65gsE\":[\"Vertieft ansehen\"],\"k7Zgob\":[\"Verbindung abbrechen\"],\"kAVx5h\":[\"Keine Einladungen gefunden\"],\"kCLEPU\":[\"Verbunden mit\"],\"kF5LKb\":[\"Ignorierte Muster:\"],\"kGeOx/\":[[\"0\"],\" beitreten\"],\"kITKr8\":[\"Kanal-Modi werden geladen...\"],\"kPpPsw\":[\"Du bist ein IRC Operator\"],\"kWJmRL\":[\"Du\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"JSON kopieren\"],\"krViRy\":[\"Klicken zum Kopieren als JSON\"],\"ks71ra\":[\"Ausnahmen\"],\"kw4lRv\":[\"Kanal-Halboperator\"],\"kxgIRq\":[\"Kanal auswählen oder hinzufügen, um zu beginnen.\"],\"ky6dWe\":[\"Avatar-Vorschau\"],\"l+GxCv\":[\"Kanäle werden geladen...\"],\"l+IUVW\":[\"Kontoverifizierung für \",[\"account\"],\" erfolgreich: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"erneut verbunden\"],\"other\":[[\"reconnectCount\"],\"-mal erneut verbunden\"]}]],\"l5jmzx\":[[\"0\"],\" und \",[\"1\"],\" tippen...\"],\"lHy8N5\":[\"Weitere Kanäle werden geladen...\"],\"lbpf14\":[[\"value\"],\" beitreten\"],\"lfFsZ4\":[\"Kanäle\"],\"lkNdiH\":[\"Kontoname\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Bild hochladen\"],\"loQxaJ\":[\"Ich bin zurück\"],\"lvfaxv\":[\"STARTSEITE\"],\"m16xKo\":[\"Hinzufügen\"],\"m8flAk\":[\"Vorschau (noch nicht hochgeladen)\"],\"mEPxTp\":[\"<0>⚠️ Vorsicht!</0> Öffne nur Links aus vertrauenswürdigen Quellen. Bösartige Links können deine Sicherheit oder Privatsphäre gefährden.\"],\"mHGdhG\":[\"Serverinformationen\"],\"mHS8lb\":[\"Nachricht an #\",[\"0\"]],\"mMYBD9\":[\"Weit – Breiterer Schutzbereich\"],\"mTGsPd\":[\"Kanalthema\"],\"mU8j6O\":[\"Keine externen Nachrichten (+n)\"],\"mZp8FL\":[\"Automatisch auf einzeilig wechseln\"],\"mdQu8G\":[\"DeinNickname\"],\"miSSBQ\":[\"Kommentare (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Benutzer ist authentifiziert\"],\"mwtcGl\":[\"Kommentare schließen\"],\"mzI/c+\":[\"Herunterladen\"],\"n3fGRk\":[\"gesetzt von \",[\"0\"]],\"nE9jsU\":[\"Entspannt – Weniger aggressiver Schutz\"],\"nNflMD\":[\"Kanal verlassen\"],\"nPXkBi\":[\"WHOIS-Daten werden geladen...\"],\"nQnxxF\":[\"Nachricht an #\",[\"0\"],\" (Shift+Enter für neue Zeile)\"],\"nWMRxa\":[\"Loslösen\"],\"nkC032\":[\"Kein Flood-Profil\"],\"o69z4d\":[\"Warnmeldung an \",[\"username\"],\" senden\"],\"o9ylQi\":[\"GIFs suchen, um zu beginnen\"],\"oFGkER\":[\"Server-Hinweise\"],\"oOi11l\":[\"Nach unten scrollen\"],\"oQEzQR\":[\"Neue Direktnachricht\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Man-in-the-Middle-Angriffe auf Server-Verbindungen sind möglich\"],\"oeqmmJ\":[\"Vertrauenswürdige Quellen\"],\"ovBPCi\":[\"Standard\"],\"p0Z69r\":[\"Muster darf nicht leer sein\"],\"p1KgtK\":[\"Audio konnte nicht geladen werden\"],\"p59pEv\":[\"Weitere Details\"],\"p7sRI6\":[\"Anderen mitteilen, wenn Sie tippen\"],\"pBm1od\":[\"Geheimer Kanal\"],\"pNmiXx\":[\"Ihr Standard-Nickname für alle Server\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Kontopasswort\"],\"peNE68\":[\"Dauerhaft\"],\"plhHQt\":[\"Keine Daten\"],\"pm6+q5\":[\"Sicherheitswarnung\"],\"pn5qSs\":[\"Weitere Informationen\"],\"q0cR4S\":[\"ist jetzt bekannt als **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Kanal erscheint nicht in LIST- oder NAMES-Befehlen\"],\"qLpTm/\":[\"Reaktion \",[\"emoji\"],\" entfernen\"],\"qVkGWK\":[\"Anheften\"],\"qY8wNa\":[\"Homepage\"],\"qb0xJ7\":[\"Platzhalter: * beliebige Zeichen, ? ein einzelnes Zeichen. Beispiele: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanalschlüssel (+k)\"],\"qtoOYG\":[\"Kein Limit\"],\"r1W2AS\":[\"Dateiserver-Bild\"],\"rIPR2O\":[\"Thema gesetzt vor (Min.)\"],\"rMMSYo\":[\"Maximale Länge ist \",[\"0\"]],\"rWtzQe\":[\"Das Netzwerk hat sich geteilt und wieder verbunden. ✅\"],\"rYG2u6\":[\"Bitte warten...\"],\"rdUucN\":[\"Vorschau\"],\"rjGI/Q\":[\"Datenschutz\"],\"rk8iDX\":[\"GIFs werden geladen...\"],\"rn6SBY\":[\"Ton einschalten\"],\"s/UKqq\":[\"Wurde aus dem Kanal geworfen\"],\"s8cATI\":[\"ist \",[\"channelName\"],\" beigetreten\"],\"sCO9ue\":[\"Die Verbindung zu <0>\",[\"serverName\"],\"</0> hat folgende Sicherheitsbedenken:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"ist jetzt bekannt als **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" hat dich eingeladen, \",[\"channel\"],\" beizutreten\"],\"sby+1/\":[\"Zum Kopieren klicken\"],\"sfN25C\":[\"Ihr echter oder vollständiger Name\"],\"sliuzR\":[\"Link öffnen\"],\"sqrO9R\":[\"Benutzerdefinierte Erwähnungen\"],\"sr6RdJ\":[\"Mehrzeilig mit Shift+Enter\"],\"swrCpB\":[\"Der Kanal wurde von \",[\"oldName\"],\" in \",[\"newName\"],\" umbenannt von \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Erweitert\"],\"t/YqKh\":[\"Entfernen\"],\"t47eHD\":[\"Ihr eindeutiger Bezeichner auf diesem Server\"],\"tAkAh0\":[\"URL mit optionaler \",[\"size\"],\"-Substitution. Beispiel: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Seitenleiste der Kanalliste ein- oder ausblenden\"],\"tfDRzk\":[\"Speichern\"],\"tiBsJk\":[\"hat \",[\"channelName\"],\" verlassen\"],\"tt4/UD\":[\"hat sich abgemeldet (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Nickname {nick} bereits vergeben, versuche es mit {newNick}\"],\"u0a8B4\":[\"Als IRC-Operator für Verwaltungszugriff authentifizieren\"],\"u0rWFU\":[\"Erstellt nach (Min.)\"],\"u72w3t\":[\"Zu ignorierende Benutzer und Muster\"],\"u7jc2L\":[\"hat sich abgemeldet\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Speichern fehlgeschlagen: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-Server:\"],\"usSSr/\":[\"Zoomstufe\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Shift+Enter für neue Zeilen (Enter sendet)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Kein Thema\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Sprache\"],\"vaHYxN\":[\"Echter Name\"],\"vhjbKr\":[\"Abwesend\"],\"w4NYox\":[[\"title\"],\" Client\"],\"w8xQRx\":[\"Ungültiger Wert\"],\"wFjjxZ\":[\"wurde von \",[\"username\"],\" aus \",[\"channelName\"],\" gekickt (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Keine Bann-Ausnahmen gefunden\"],\"wPrGnM\":[\"Kanal-Administrator\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Anzeigen, wenn Benutzer Kanäle betreten oder verlassen\"],\"whqZ9r\":[\"Weitere Wörter oder Phrasen zum Hervorheben\"],\"wm7RV4\":[\"Benachrichtigungston\"],\"wz/Yoq\":[\"Deine Nachrichten könnten abgefangen werden, wenn sie zwischen Servern weitergeleitet werden\"],\"xCJdfg\":[\"Leeren\"],\"xUHRTR\":[\"Beim Verbinden automatisch als Operator authentifizieren\"],\"xWHwwQ\":[\"Sperren\"],\"xYilR2\":[\"Medien\"],\"xceQrO\":[\"Nur sichere Websockets werden unterstützt\"],\"xdtXa+\":[\"Kanalname\"],\"xfXC7q\":[\"Textkanäle\"],\"xlCYOE\":[\"Weitere Nachrichten werden geladen...\"],\"xlhswE\":[\"Mindestwert ist \",[\"0\"]],\"xq97Ci\":[\"Wort oder Phrase hinzufügen...\"],\"xuRqRq\":[\"Client-Limit (+l)\"],\"xwF+7J\":[[\"0\"],\" tippt...\"],\"yNeucF\":[\"Dieser Server unterstützt keine erweiterten Profilmetadaten (IRCv3 METADATA). Felder wie Avatar, Anzeigename und Status sind nicht verfügbar.\"],\"yPlrca\":[\"Kanal-Avatar\"],\"yQE2r9\":[\"Laden\"],\"ySU+JY\":[\"deine@email.de\"],\"yTX1Rt\":[\"Oper-Benutzername\"],\"yYOzWD\":[\"Protokolle\"],\"yfx9Re\":[\"IRC-Operatorpasswort\"],\"ygCKqB\":[\"Stopp\"],\"ymDxJx\":[\"IRC-Operatorbenutzername\"],\"yrpRsQ\":[\"Nach Name sortieren\"],\"yz7wBu\":[\"Schließen\"],\"zJw+jA\":[\"setzt Modus: \",[\"0\"]],\"zebeLu\":[\"oper-Benutzername eingeben\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Ungültiges Musterformat. Verwenden Sie nick!user@host (Platzhalter * erlaubt)\"],\"+6NQQA\":[\"Allgemeiner Support-Kanal\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Trennen\"],\"+cyFdH\":[\"Standardnachricht beim Als-abwesend-markieren\"],\"+mVPqU\":[\"Markdown-Formatierung in Nachrichten rendern\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Ihr Kontobenutzername zur Authentifizierung\"],\"+yPBXI\":[\"Datei auswählen\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Geringe Verbindungssicherheit (Stufe \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Externe Benutzer können keine Nachrichten senden\"],\"/6BzZF\":[\"Mitgliederliste umschalten\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Benutzer ist abwesend\"],\"/XQgft\":[\"Entdecken\"],\"/cF7Rs\":[\"Lautstärke\"],\"/dqduX\":[\"Nächste Seite\"],\"/fc3q4\":[\"Alle Inhalte\"],\"/kISDh\":[\"Benachrichtigungstöne aktivieren\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Töne bei Erwähnungen und Nachrichten abspielen\"],\"0/0ZGA\":[\"Kanalname-Maske\"],\"0D6j7U\":[\"Mehr über benutzerdefinierte Regeln erfahren →\"],\"0XsHcR\":[\"Benutzer rauswerfen\"],\"0ZpE//\":[\"Nach Benutzern sortieren\"],\"0bEPwz\":[\"Als abwesend setzen\"],\"0dGkPt\":[\"Kanalliste ausklappen\"],\"0gS7M5\":[\"Anzeigename\"],\"0kS+M8\":[\"BeispielNET\"],\"0rgoY7\":[\"Nur mit ausgewählten Servern verbinden\"],\"0wdd7X\":[\"Beitreten\"],\"0wkVYx\":[\"Privatnachrichten\"],\"111uHX\":[\"Link-Vorschau\"],\"196EG4\":[\"Privatnachricht löschen\"],\"1DSr1i\":[\"Konto registrieren\"],\"1O/24y\":[\"Kanalliste umschalten\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Warnung: Externer Link\"],\"1ZC/dv\":[\"Keine ungelesenen Erwähnungen oder Nachrichten\"],\"1pO1zi\":[\"Servername ist erforderlich\"],\"1uwfzQ\":[\"Kanalthema anzeigen\"],\"268g7c\":[\"Anzeigenamen eingeben\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Server-Operatoren im Netzwerk könnten deine Nachrichten lesen\"],\"2FYpfJ\":[\"Mehr\"],\"2HF1Y2\":[[\"inviter\"],\" hat \",[\"target\"],\" eingeladen, \",[\"channel\"],\" beizutreten\"],\"2I70QL\":[\"Benutzerprofilinformationen anzeigen\"],\"2QYdmE\":[\"Benutzer:\"],\"2QpEjG\":[\"hat verlassen\"],\"2YE223\":[\"Nachricht an #\",[\"0\"],\" (Enter für neue Zeile, Shift+Enter zum Senden)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Server-Passwort verwenden\"],\"2iTmdZ\":[\"Lokaler Speicher:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Streng – Aggressiverer Schutz\"],\"2uDhbA\":[\"Benutzername zum Einladen eingeben\"],\"2ygf/L\":[\"← Zurück\"],\"2zEgxj\":[\"GIFs suchen...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Kanal umbenennen\"],\"3THokf\":[\"Benutzer mit Sprachrecht\"],\"3TSz9S\":[\"Minimieren\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Kanal-Anzeigename\"],\"3ryuFU\":[\"Optionale Absturzberichte zur App-Verbesserung\"],\"3uBF/8\":[\"Ansicht schließen\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Kontoname eingeben...\"],\"4/Rr0R\":[\"Benutzer in den aktuellen Kanal einladen\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Regeln\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flood-Profil (+F)\"],\"4RZQRK\":[\"Was machst du gerade?\"],\"4hfTrB\":[\"Nickname\"],\"4n99LO\":[\"Bereits in \",[\"0\"]],\"4t6vMV\":[\"Kurze Nachrichten automatisch einzeilig darstellen\"],\"4vsHmf\":[\"Zeit (Min)\"],\"5+INAX\":[\"Nachrichten hervorheben, die Sie erwähnen\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper Name\"],\"678PKt\":[\"Netzwerkname\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Passwort zum Beitreten erforderlich. Leer lassen, um den Schlüssel zu entfernen.\"],\"6HhMs3\":[\"Abgangsnachricht\"],\"6V3Ea3\":[\"Kopiert\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Weniger anzeigen\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Oper-Passwort eingeben...\"],\"7+IHTZ\":[\"Keine Datei ausgewählt\"],\"73hrRi\":[\"nick!user@host (z.B. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Privatnachricht senden\"],\"7U1W7c\":[\"Sehr locker\"],\"7Y1YQj\":[\"Echter Name:\"],\"7YHArF\":[\"— im Viewer öffnen\"],\"7fjnVl\":[\"Benutzer suchen...\"],\"7jL88x\":[\"Diese Nachricht löschen? Dies kann nicht rückgängig gemacht werden.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Was denkst du gerade?\"],\"7sEpu1\":[\"Mitglieder — \",[\"0\"]],\"7sNhEz\":[\"Benutzername\"],\"8H0Q+x\":[\"Mehr über Profile erfahren →\"],\"8Phu0A\":[\"Anzeigen, wenn Benutzer ihren Nickname ändern\"],\"8XTG9e\":[\"oper-Passwort eingeben\"],\"8XsV2J\":[\"Erneut senden\"],\"8ZsakT\":[\"Passwort\"],\"8kR84m\":[\"Du bist dabei, einen externen Link zu öffnen:\"],\"8lCgih\":[\"Regel entfernen\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"beigetreten\"],\"other\":[[\"joinCount\"],\"-mal beigetreten\"]}]],\"9BMLnJ\":[\"Erneut mit Server verbinden\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Reaktion hinzufügen\"],\"9PQ8m2\":[\"G-Line (globaler Ban)\"],\"9Qs99X\":[\"E-Mail:\"],\"9QupBP\":[\"Muster entfernen\"],\"9bG48P\":[\"Wird gesendet\"],\"9f5f0u\":[\"Fragen zum Datenschutz? Kontaktieren Sie uns:\"],\"9unqs3\":[\"Abwesend:\"],\"9v3hwv\":[\"Keine Server gefunden.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Verbinden...\"],\"A1taO8\":[\"Suchen\"],\"A2adVi\":[\"Tipp-Benachrichtigungen senden\"],\"A9Rhec\":[\"Kanalname\"],\"AWOSPo\":[\"Vergrößern\"],\"AXSpEQ\":[\"Oper beim Verbinden\"],\"AeXO77\":[\"Konto\"],\"AhNP40\":[\"Vor-/Zurückspulen\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Nickname geändert\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Antwort abbrechen\"],\"ApSx0O\":[[\"0\"],\" Nachrichten gefunden, die zu \\\"\",[\"searchQuery\"],\"\\\" passen\"],\"AxPAXW\":[\"Keine Ergebnisse gefunden\"],\"AyNqAB\":[\"Alle Serverereignisse im Chat anzeigen\"],\"B/QqGw\":[\"Nicht am Rechner\"],\"B8AaMI\":[\"Dieses Feld ist erforderlich\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Server unterstützt keine erweiterte LIST-Filterung\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" und \",[\"3\"],\" weitere tippen...\"],\"BGul2A\":[\"Du hast ungespeicherte Änderungen. Möchtest du wirklich schließen, ohne zu speichern?\"],\"BIf9fi\":[\"Ihre Statusnachricht\"],\"BZz3md\":[\"Ihre persönliche Website\"],\"Bgm/H7\":[\"Mehrzeilige Texteingabe erlauben\"],\"BiQIl1\":[\"Dieses Privatgespräch anheften\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Klicken, um zur Nachricht zu springen\"],\"Bowq3c\":[\"Nur Operatoren können das Kanalthema ändern\"],\"Btozzp\":[\"Dieses Bild ist abgelaufen\"],\"Bycfjm\":[\"Gesamt: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Gesamtes JSON kopieren\"],\"C9L9wL\":[\"Datenerfassung\"],\"CDq4wC\":[\"Benutzer moderieren\"],\"CHVRxG\":[\"Nachricht an @\",[\"0\"],\" (Shift+Enter für neue Zeile)\"],\"CN9zdR\":[\"Oper-Name und Passwort sind erforderlich\"],\"CW3sYa\":[\"Reaktion \",[\"emoji\"],\" hinzufügen\"],\"CaAkqd\":[\"Verbindungstrennungen anzeigen\"],\"CbvaYj\":[\"Nach Nickname sperren\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Kanal auswählen\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"ist beigetreten und gegangen\"],\"DB8zMK\":[\"Anwenden\"],\"DBcWHr\":[\"Benutzerdefinierte Benachrichtigungstondatei\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Medienvorschauen\"],\"Dj4pSr\":[\"Sicheres Passwort wählen\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Suche...\"],\"Du2T2f\":[\"Einstellung nicht gefunden\"],\"DwsSVQ\":[\"Filter anwenden & Aktualisieren\"],\"E3W/zd\":[\"Standard-Nickname\"],\"E6nRW7\":[\"URL kopieren\"],\"E703RG\":[\"Modi:\"],\"EAeu1Z\":[\"Einladung senden\"],\"EFKJQT\":[\"Einstellung\"],\"EGPQBv\":[\"Benutzerdefinierte Flood-Regeln (+f)\"],\"ELik0r\":[\"Vollständige Datenschutzrichtlinie anzeigen\"],\"EPbeC2\":[\"Kanalthema anzeigen oder bearbeiten\"],\"EQCDNT\":[\"Oper-Benutzernamen eingeben...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"1 Nachricht gefunden, die zu \\\"\",[\"searchQuery\"],\"\\\" passt\"],\"EatZYJ\":[\"Nächstes Bild\"],\"EdQY6l\":[\"Keine\"],\"EnqLYU\":[\"Server suchen...\"],\"F0OKMc\":[\"Server bearbeiten\"],\"F6Int2\":[\"Hervorhebungen aktivieren\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Max. Benutzer\"],\"FUU/hZ\":[\"Steuert, wie viele externe Medien im Chat geladen werden.\"],\"Fdp03t\":[\"an\"],\"FfPWR0\":[\"Modal\"],\"FjkaiT\":[\"Verkleinern\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Was das bedeutet:\"],\"FolHNl\":[\"Konto und Authentifizierung verwalten\"],\"Fp2Dif\":[\"Den Server verlassen\"],\"G5KmCc\":[\"GZ-Line (globale Z-Line)\"],\"GDs0lz\":[\"<0>Risiko:</0> Sensible Informationen (Nachrichten, private Gespräche, Authentifizierungsdaten) könnten Netzwerkadministratoren oder Angreifern zwischen IRC-Servern zugänglich sein.\"],\"GR+2I3\":[\"Einladungs-Maske hinzufügen (z.B. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Server-Hinweise schließen\"],\"GlHnXw\":[\"Nicknamewechsel fehlgeschlagen: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Vorschau:\"],\"GtmO8/\":[\"von\"],\"GtuHUQ\":[\"Diesen Kanal auf dem Server umbenennen. Alle Benutzer sehen den neuen Namen.\"],\"GuGfFX\":[\"Suche umschalten\"],\"GxkJXS\":[\"Wird hochgeladen...\"],\"GzbwnK\":[\"Dem Kanal beigetreten\"],\"GzsUDB\":[\"Erweitertes Profil\"],\"H/PnT8\":[\"Emoji einfügen\"],\"H6Izzl\":[\"Ihr bevorzugter Farbcode\"],\"H9jIv+\":[\"Beitritte/Abgänge anzeigen\"],\"HAKBY9\":[\"Dateien hochladen\"],\"HdE1If\":[\"Kanal\"],\"Hk4AW9\":[\"Ihr bevorzugter Anzeigename\"],\"HmHDk7\":[\"Mitglied auswählen\"],\"HrQzPU\":[\"Kanäle auf \",[\"networkName\"]],\"I2tXQ5\":[\"Nachricht an @\",[\"0\"],\" (Enter für neue Zeile, Shift+Enter zum Senden)\"],\"I6bw/h\":[\"Benutzer sperren\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Benachrichtigungen aktivieren\"],\"I9D72S\":[\"Bist du sicher, dass du diese Nachricht löschen möchtest? Diese Aktion kann nicht rückgängig gemacht werden.\"],\"IA+1wo\":[\"Anzeigen, wenn Benutzer aus Kanälen gekickt werden\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Änderungen speichern\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" und \",[\"2\"],\" tippen...\"],\"IgrLD/\":[\"Pause\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Antworten\"],\"IoHMnl\":[\"Maximalwert ist \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Verbinde...\"],\"J5T9NW\":[\"Benutzerinformationen\"],\"J8Y5+z\":[\"Ups! Netz-Split! ⚠️\"],\"JBHkBA\":[\"Den Kanal verlassen\"],\"JCwL0Q\":[\"Grund eingeben (optional)\"],\"JFciKP\":[\"Umschalten\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Kanalnamen ändern (nur Operatoren)\"],\"JcD7qf\":[\"Weitere Aktionen\"],\"JdkA+c\":[\"Geheim (+s)\"],\"Jmu12l\":[\"Serverkanäle\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Markdown aktivieren\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Keine WHOIS-Daten verfügbar\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Nachricht löschen\"],\"KKBlUU\":[\"Einbetten\"],\"KM0pLb\":[\"Willkommen im Kanal!\"],\"KR6W2h\":[\"Benutzer nicht mehr ignorieren\"],\"KV+Bi1\":[\"Nur auf Einladung (+i)\"],\"KdCtwE\":[\"Wie viele Sekunden Flood-Aktivität überwacht wird, bevor die Zähler zurückgesetzt werden\"],\"Kkezga\":[\"Server-Passwort\"],\"KsiQ/8\":[\"Benutzer müssen eingeladen werden\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Kanalinformationen\"],\"LC1a7n\":[\"Der IRC-Server hat gemeldet, dass seine Server-zu-Server-Verbindungen ein niedriges Sicherheitsniveau aufweisen. Das bedeutet, dass deine Nachrichten beim Weiterleiten zwischen IRC-Servern im Netzwerk möglicherweise nicht ordnungsgemäß verschlüsselt sind oder die SSL/TLS-Zertifikate nicht korrekt validiert werden.\"],\"LNfLR5\":[\"Kicks anzeigen\"],\"LQb0W/\":[\"Alle Ereignisse anzeigen\"],\"LU7/yA\":[\"Alternativer Anzeigename. Kann Leerzeichen, Emojis und Sonderzeichen enthalten. Der echte Kanalname (\",[\"channelName\"],\") wird weiterhin für IRC-Befehle verwendet.\"],\"LUb9O7\":[\"Ein gültiger Server-Port ist erforderlich\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Datenschutzrichtlinie\"],\"LcuSDR\":[\"Profilinformationen und Metadaten verwalten\"],\"LqLS9B\":[\"Nickwechsel anzeigen\"],\"LsDQt2\":[\"Kanaleinstellungen\"],\"LtI9AS\":[\"Eigentümer\"],\"LuNhhL\":[\"hat auf diese Nachricht reagiert\"],\"M/AZNG\":[\"URL zu Ihrem Avatar-Bild\"],\"M/WIer\":[\"Nachricht senden\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Name:\"],\"MHk+7g\":[\"Vorheriges Bild\"],\"MRorGe\":[\"Benutzer anschreiben\"],\"MVbSGP\":[\"Zeitfenster (Sekunden)\"],\"MkpcsT\":[\"Ihre Nachrichten und Einstellungen werden lokal gespeichert\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Als Bot markieren – normalerweise 'on' oder leer\"],\"N7TQbE\":[\"Benutzer zu \",[\"channelName\"],\" einladen\"],\"NCca/o\":[\"Standard-Spitznamen eingeben...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Zeigt alle externen Medien. Jede URL kann eine Anfrage an einen unbekannten Server auslösen.\"],\"Nt+9O7\":[\"WebSocket statt rohem TCP verwenden\"],\"NxIHzc\":[\"Benutzer trennen\"],\"O+v/cL\":[\"Alle Kanäle auf dem Server durchsuchen\"],\"ODwSCk\":[\"GIF senden\"],\"OGQ5kK\":[\"Benachrichtigungstöne und Hervorhebungen konfigurieren\"],\"OIPt1Z\":[\"Seitenleiste der Mitgliederliste ein- oder ausblenden\"],\"OKSNq/\":[\"Sehr streng\"],\"ONWvwQ\":[\"Hochladen\"],\"OVKoQO\":[\"Ihr Kontopasswort zur Authentifizierung\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - IRC in die Zukunft bringen\"],\"OhCpra\":[\"Thema setzen…\"],\"OkltoQ\":[[\"username\"],\" per Nickname sperren (verhindert erneutes Beitreten mit demselben Nick)\"],\"P+t/Te\":[\"Keine weiteren Daten\"],\"P42Wcc\":[\"Sicher\"],\"PD38l0\":[\"Kanal-Avatar-Vorschau\"],\"PD9mEt\":[\"Nachricht eingeben...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Kanaleinstellungen öffnen\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Das Thema für diesen Kanal. Alle Benutzer können es sehen.\"],\"PZCecv\":[\"PDF-Vorschau\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 Mal\"],\"other\":[[\"c\"],\" Mal\"]}]],\"PguS2C\":[\"Ausnahme-Maske hinzufügen (z.B. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[[\"displayedChannelsCount\"],\" von \",[\"0\"],\" Kanälen angezeigt\"],\"PqhVlJ\":[\"Benutzer sperren (per Hostmask)\"],\"Q+chwU\":[\"Benutzername:\"],\"Q6hhn8\":[\"Einstellungen\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Bitte gib einen Benutzernamen ein\"],\"QGqSZ2\":[\"Farbe & Formatierung\"],\"QJQd1J\":[\"Profil bearbeiten\"],\"QSzGDE\":[\"Inaktiv\"],\"QUlny5\":[\"Willkommen bei \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Mehr lesen\"],\"QuSkCF\":[\"Kanäle filtern...\"],\"QwUrDZ\":[\"hat das Thema geändert zu: \",[\"topic\"]],\"R0UH07\":[\"Bild \",[\"0\"],\" von \",[\"1\"]],\"R7SsBE\":[\"Stumm schalten\"],\"R8rf1X\":[\"Klicken, um das Thema zu setzen\"],\"RArB3D\":[\"wurde von \",[\"username\"],\" aus \",[\"channelName\"],\" gekickt\"],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Entdecke die Welt von IRC mit ObsidianIRC\"],\"RMMaN5\":[\"Moderiert (+m)\"],\"RWw9Lg\":[\"Fenster schließen\"],\"RZ2BuZ\":[\"Kontoregistrierung für \",[\"account\"],\" erfordert Verifizierung: \",[\"message\"]],\"RySp6q\":[\"Kommentare ausblenden\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Nickname ist erforderlich\"],\"SPVjfj\":[\"Standardmäßig 'kein Grund', wenn leer gelassen\"],\"SQKPvQ\":[\"Benutzer einladen\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Wähle ein vordefiniertes Flood-Schutzprofil. Diese Profile bieten ausgewogene Schutzeinstellungen für verschiedene Anwendungsfälle.\"],\"Slr+3C\":[\"Min. Benutzer\"],\"Spnlre\":[\"Du hast \",[\"target\"],\" eingeladen, \",[\"channel\"],\" beizutreten\"],\"T/ckN5\":[\"Im Viewer öffnen\"],\"T91vKp\":[\"Abspielen\"],\"TV2Wdu\":[\"Erfahren Sie, wie wir Ihre Daten verwalten und Ihre Privatsphäre schützen.\"],\"TgFpwD\":[\"Wird angewendet...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Keine Änderungen\"],\"TtserG\":[\"Echten Namen eingeben\"],\"Ttz9J1\":[\"Passwort eingeben...\"],\"Tz0i8g\":[\"Einstellungen\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagieren\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Einstellungen speichern\"],\"UV5hLB\":[\"Keine Sperren gefunden\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Statusnachrichten\"],\"Ue3uny\":[\"Standard (kein Profil)\"],\"UkARhe\":[\"Normal – Standardschutz\"],\"Umn7Cj\":[\"Noch keine Kommentare. Sei der Erste!\"],\"UtUIRh\":[[\"0\"],\" ältere Nachrichten\"],\"UwzP+U\":[\"Sichere Verbindung\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Kanalbesitzer\"],\"V4qgxE\":[\"Erstellt vor (Min.)\"],\"V8yTm6\":[\"Suche löschen\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - IRC in die Zukunft bringen\"],\"VJScHU\":[\"Grund\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Benachrichtigungen stummschalten\"],\"VbyRUy\":[\"Kommentare\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Gesetzt von:\"],\"VqnIZz\":[\"Datenschutzrichtlinie und Datenpraktiken anzeigen\"],\"VrMygG\":[\"Mindestlänge ist \",[\"0\"]],\"VrnTui\":[\"Ihre Pronomen, im Profil angezeigt\"],\"W8E3qn\":[\"Authentifiziertes Konto\"],\"WAakm9\":[\"Kanal löschen\"],\"WFxTHC\":[\"Bann-Maske hinzufügen (z.B. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Server-Host ist erforderlich\"],\"WRYdXW\":[\"Audioposition\"],\"WUOH5B\":[\"Benutzer ignorieren\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"1 weiteres Element anzeigen\"],\"other\":[[\"1\"],\" weitere Elemente anzeigen\"]}]],\"Weq9zb\":[\"Allgemein\"],\"Wfj7Sk\":[\"Benachrichtigungstöne stummschalten oder aktivieren\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Benutzerprofil\"],\"X6S3lt\":[\"Einstellungen, Kanäle, Server suchen...\"],\"XEHan5\":[\"Trotzdem fortfahren\"],\"XI1+wb\":[\"Ungültiges Format\"],\"XIXeuC\":[\"Nachricht an @\",[\"0\"]],\"XMS+k4\":[\"Privatnachricht starten\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Privatnachricht loslösen\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Anzeige\"],\"Xp2n93\":[\"Zeigt Medien vom vertrauenswürdigen Datei-Host deines Servers. Es werden keine Anfragen an externe Dienste gestellt.\"],\"XvjC4F\":[\"Wird gespeichert...\"],\"Y/qryO\":[\"Keine Benutzer gefunden, die deiner Suche entsprechen\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Kontoregistrierung für \",[\"account\"],\" erfolgreich: \",[\"message\"]],\"YEfzvP\":[\"Geschütztes Thema (+t)\"],\"YQOn6a\":[\"Mitgliederliste einklappen\"],\"YRCoE9\":[\"Kanal-Operator\"],\"YURQaF\":[\"Profil anzeigen\"],\"YdBSvr\":[\"Medienanzeige und externe Inhalte steuern\"],\"Yj6U3V\":[\"Kein zentraler Server:\"],\"YjvpGx\":[\"Pronomen\"],\"YqH4l4\":[\"Kein Schlüssel\"],\"YyUPpV\":[\"Konto:\"],\"ZJSWfw\":[\"Nachricht beim Trennen vom Server\"],\"ZR1dJ4\":[\"Einladungen\"],\"ZdWg0V\":[\"Im Browser öffnen\"],\"ZhRBbl\":[\"Nachrichten suchen…\"],\"Zmcu3y\":[\"Erweiterte Filter\"],\"a2/8e5\":[\"Thema gesetzt nach (Min.)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Vorherige Seite\"],\"aJTbXX\":[\"Oper Password\"],\"aQryQv\":[\"Muster existiert bereits\"],\"aW9pLN\":[\"Maximale Anzahl der zugelassenen Benutzer. Leer lassen für kein Limit.\"],\"ah4fmZ\":[\"Zeigt auch Vorschauen von YouTube, Vimeo, SoundCloud und ähnlichen bekannten Diensten.\"],\"aifXak\":[\"Keine Medien in diesem Kanal\"],\"ap2zBz\":[\"Locker\"],\"az8lvo\":[\"Aus\"],\"azXSNo\":[\"Mitgliederliste ausklappen\"],\"azdliB\":[\"Bei einem Konto anmelden\"],\"b26wlF\":[\"sie/ihr\"],\"bD/+Ei\":[\"Streng\"],\"bQ6BJn\":[\"Detaillierte Flood-Schutzregeln konfigurieren. Jede Regel legt fest, welche Aktivitäten überwacht werden sollen und welche Maßnahmen bei Überschreitung der Schwellenwerte ergriffen werden.\"],\"beV7+y\":[\"Der Benutzer erhält eine Einladung, \",[\"channelName\"],\" beizutreten.\"],\"bk84cH\":[\"Abwesenheitsnachricht\"],\"bkHdLj\":[\"IRC-Server hinzufügen\"],\"bmQLn5\":[\"Regel hinzufügen\"],\"bwRvnp\":[\"Aktion\"],\"c8+EVZ\":[\"Verifiziertes Konto\"],\"cGYUlD\":[\"Es werden keine Medienvorschauen geladen.\"],\"cLF98o\":[\"Kommentare anzeigen (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Keine Benutzer verfügbar\"],\"cSgpoS\":[\"Privatnachricht anheften\"],\"cde3ce\":[\"Nachricht an <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Formatierte Ausgabe kopieren\"],\"cl/A5J\":[\"Willkommen bei \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Löschen\"],\"coPLXT\":[\"Wir speichern Ihre IRC-Kommunikation nicht auf unseren Servern\"],\"crYH/6\":[\"SoundCloud-Player\"],\"d3sis4\":[\"Server hinzufügen\"],\"d9aN5k\":[[\"username\"],\" aus dem Kanal entfernen\"],\"dEgA5A\":[\"Abbrechen\"],\"dGi1We\":[\"Dieses Privatgespräch loslösen\"],\"dJVuyC\":[\"hat \",[\"channelName\"],\" verlassen (\",[\"reason\"],\")\"],\"dMtLDE\":[\"an\"],\"dXqxlh\":[\"<0>⚠️ Sicherheitsrisiko!</0> Diese Verbindung könnte anfällig für Abhören oder Man-in-the-Middle-Angriffe sein.\"],\"da9Q/R\":[\"Kanalmodi geändert\"],\"dhJN3N\":[\"Kommentare anzeigen\"],\"dj2xTE\":[\"Benachrichtigung schließen\"],\"dpCzmC\":[\"Flood-Schutz-Einstellungen\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Möchtest du diesen Link in einem neuen Tab öffnen?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Bearbeiten\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Bild hochladen oder URL mit optionaler \",[\"size\"],\"-Substitution angeben\"],\"edBbee\":[[\"username\"],\" per hostmask sperren (verhindert erneutes Beitreten von derselben IP/Host)\"],\"ekfzWq\":[\"Benutzereinstellungen\"],\"elPDWs\":[\"IRC-Client-Erfahrung anpassen\"],\"eu2osY\":[\"<0>💡 Empfehlung:</0> Fahre nur fort, wenn du diesem Server vertraust und die Risiken kennst. Teile keine sensiblen Informationen oder Passwörter über diese Verbindung.\"],\"euEhbr\":[\"Klicke, um \",[\"channel\"],\" beizutreten\"],\"ez3vLd\":[\"Mehrzeilige Eingabe aktivieren\"],\"f0J5Ki\":[\"Die Server-zu-Server-Kommunikation verwendet möglicherweise unverschlüsselte Verbindungen\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Benutzer warnen\"],\"fDOLLd\":[\"Keine Kanäle gefunden.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonyme Analysen:\"],\"fq1GF9\":[\"Anzeigen, wenn Benutzer die Verbindung trennen\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Dieser Server unterstützt nur einen Verbindungstyp\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Ignorierliste\"],\"gNzMrk\":[\"Aktueller Avatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Spitznamen eingeben...\"],\"gz6UQ3\":[\"Maximieren\"],\"h6razj\":[\"Kanalname-Maske ausschließen\"],\"hG6jnw\":[\"Kein Thema gesetzt\"],\"hG89Ed\":[\"Bild\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"z.B. 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Anzahl\"],\"hzdLuQ\":[\"Nur Benutzer mit Voice oder höher können sprechen\"],\"i0qMbr\":[\"Startseite\"],\"iDNBZe\":[\"Benachrichtigungen\"],\"iH8pgl\":[\"Zurück\"],\"iL9SZg\":[\"Benutzer sperren (per Nickname)\"],\"iNt+3c\":[\"Zurück zum Bild\"],\"iQvi+a\":[\"Nicht mehr vor geringer Verbindungssicherheit für diesen Server warnen\"],\"iSLIjg\":[\"Verbinden\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Server-Host\"],\"idD8Ev\":[\"Gespeichert\"],\"iivqkW\":[\"Angemeldet seit\"],\"ij+Elv\":[\"Bildvorschau\"],\"ilIWp7\":[\"Benachrichtigungen umschalten\"],\"iuaqvB\":[\"* als Platzhalter verwenden. Beispiele: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Nach Hostmaske sperren\"],\"jA4uoI\":[\"Thema:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Grund (optional)\"],\"jUV7CU\":[\"Avatar hochladen\"],\"jW5Uwh\":[\"Steuert, wie viele externe Medien geladen werden. Aus / Sicher / Vertrauenswürdige / Alle Inhalte.\"],\"jXzms5\":[\"Anhangsoptionen\"],\"jZlrte\":[\"Farbe\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Ältere Nachrichten laden\"],\"k3ID0F\":[\"Mitglieder filtern…\"],\"k65gsE\":[\"Vertieft ansehen\"],\"k7Zgob\":[\"Verbindung abbrechen\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Keine Einladungen gefunden\"],\"kCLEPU\":[\"Verbunden mit\"],\"kF5LKb\":[\"Ignorierte Muster:\"],\"kGeOx/\":[[\"0\"],\" beitreten\"],\"kITKr8\":[\"Kanal-Modi werden geladen...\"],\"kPpPsw\":[\"Du bist ein IRC Operator\"],\"kWJmRL\":[\"Du\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"JSON kopieren\"],\"krViRy\":[\"Klicken zum Kopieren als JSON\"],\"ks71ra\":[\"Ausnahmen\"],\"kw4lRv\":[\"Kanal-Halboperator\"],\"kxgIRq\":[\"Kanal auswählen oder hinzufügen, um zu beginnen.\"],\"ky6dWe\":[\"Avatar-Vorschau\"],\"l+GxCv\":[\"Kanäle werden geladen...\"],\"l+IUVW\":[\"Kontoverifizierung für \",[\"account\"],\" erfolgreich: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"erneut verbunden\"],\"other\":[[\"reconnectCount\"],\"-mal erneut verbunden\"]}]],\"l5jmzx\":[[\"0\"],\" und \",[\"1\"],\" tippen...\"],\"lHy8N5\":[\"Weitere Kanäle werden geladen...\"],\"lbpf14\":[[\"value\"],\" beitreten\"],\"lfFsZ4\":[\"Kanäle\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Kontoname\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Bild hochladen\"],\"loQxaJ\":[\"Ich bin zurück\"],\"lvfaxv\":[\"STARTSEITE\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Hinzufügen\"],\"m8flAk\":[\"Vorschau (noch nicht hochgeladen)\"],\"mEPxTp\":[\"<0>⚠️ Vorsicht!</0> Öffne nur Links aus vertrauenswürdigen Quellen. Bösartige Links können deine Sicherheit oder Privatsphäre gefährden.\"],\"mHGdhG\":[\"Serverinformationen\"],\"mHS8lb\":[\"Nachricht an #\",[\"0\"]],\"mMYBD9\":[\"Weit – Breiterer Schutzbereich\"],\"mTGsPd\":[\"Kanalthema\"],\"mU8j6O\":[\"Keine externen Nachrichten (+n)\"],\"mZp8FL\":[\"Automatisch auf einzeilig wechseln\"],\"mdQu8G\":[\"DeinNickname\"],\"miSSBQ\":[\"Kommentare (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Benutzer ist authentifiziert\"],\"mwtcGl\":[\"Kommentare schließen\"],\"mzI/c+\":[\"Herunterladen\"],\"n3fGRk\":[\"gesetzt von \",[\"0\"]],\"nE9jsU\":[\"Entspannt – Weniger aggressiver Schutz\"],\"nNflMD\":[\"Kanal verlassen\"],\"nPXkBi\":[\"WHOIS-Daten werden geladen...\"],\"nQnxxF\":[\"Nachricht an #\",[\"0\"],\" (Shift+Enter für neue Zeile)\"],\"nWMRxa\":[\"Loslösen\"],\"nkC032\":[\"Kein Flood-Profil\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Warnmeldung an \",[\"username\"],\" senden\"],\"o9ylQi\":[\"GIFs suchen, um zu beginnen\"],\"oFGkER\":[\"Server-Hinweise\"],\"oOi11l\":[\"Nach unten scrollen\"],\"oQEzQR\":[\"Neue Direktnachricht\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Man-in-the-Middle-Angriffe auf Server-Verbindungen sind möglich\"],\"oeqmmJ\":[\"Vertrauenswürdige Quellen\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Standard\"],\"p0Z69r\":[\"Muster darf nicht leer sein\"],\"p1KgtK\":[\"Audio konnte nicht geladen werden\"],\"p59pEv\":[\"Weitere Details\"],\"p7sRI6\":[\"Anderen mitteilen, wenn Sie tippen\"],\"pBm1od\":[\"Geheimer Kanal\"],\"pNmiXx\":[\"Ihr Standard-Nickname für alle Server\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Kontopasswort\"],\"peNE68\":[\"Dauerhaft\"],\"plhHQt\":[\"Keine Daten\"],\"pm6+q5\":[\"Sicherheitswarnung\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Weitere Informationen\"],\"q0cR4S\":[\"ist jetzt bekannt als **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Kanal erscheint nicht in LIST- oder NAMES-Befehlen\"],\"qLpTm/\":[\"Reaktion \",[\"emoji\"],\" entfernen\"],\"qVkGWK\":[\"Anheften\"],\"qY8wNa\":[\"Homepage\"],\"qb0xJ7\":[\"Platzhalter: * beliebige Zeichen, ? ein einzelnes Zeichen. Beispiele: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanalschlüssel (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Kein Limit\"],\"r1W2AS\":[\"Dateiserver-Bild\"],\"rIPR2O\":[\"Thema gesetzt vor (Min.)\"],\"rMMSYo\":[\"Maximale Länge ist \",[\"0\"]],\"rWtzQe\":[\"Das Netzwerk hat sich geteilt und wieder verbunden. ✅\"],\"rYG2u6\":[\"Bitte warten...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Vorschau\"],\"rjGI/Q\":[\"Datenschutz\"],\"rk8iDX\":[\"GIFs werden geladen...\"],\"rn6SBY\":[\"Ton einschalten\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Wurde aus dem Kanal geworfen\"],\"s8cATI\":[\"ist \",[\"channelName\"],\" beigetreten\"],\"sCO9ue\":[\"Die Verbindung zu <0>\",[\"serverName\"],\"</0> hat folgende Sicherheitsbedenken:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"ist jetzt bekannt als **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" hat dich eingeladen, \",[\"channel\"],\" beizutreten\"],\"sby+1/\":[\"Zum Kopieren klicken\"],\"sfN25C\":[\"Ihr echter oder vollständiger Name\"],\"sliuzR\":[\"Link öffnen\"],\"sqrO9R\":[\"Benutzerdefinierte Erwähnungen\"],\"sr6RdJ\":[\"Mehrzeilig mit Shift+Enter\"],\"swrCpB\":[\"Der Kanal wurde von \",[\"oldName\"],\" in \",[\"newName\"],\" umbenannt von \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Erweitert\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Entfernen\"],\"t47eHD\":[\"Ihr eindeutiger Bezeichner auf diesem Server\"],\"tAkAh0\":[\"URL mit optionaler \",[\"size\"],\"-Substitution. Beispiel: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Seitenleiste der Kanalliste ein- oder ausblenden\"],\"tfDRzk\":[\"Speichern\"],\"tiBsJk\":[\"hat \",[\"channelName\"],\" verlassen\"],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"hat sich abgemeldet (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Nickname {nick} bereits vergeben, versuche es mit {newNick}\"],\"u0a8B4\":[\"Als IRC-Operator für Verwaltungszugriff authentifizieren\"],\"u0rWFU\":[\"Erstellt nach (Min.)\"],\"u72w3t\":[\"Zu ignorierende Benutzer und Muster\"],\"u7jc2L\":[\"hat sich abgemeldet\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Speichern fehlgeschlagen: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-Server:\"],\"usSSr/\":[\"Zoomstufe\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Shift+Enter für neue Zeilen (Enter sendet)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Kein Thema\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Sprache\"],\"vaHYxN\":[\"Echter Name\"],\"vhjbKr\":[\"Abwesend\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" Client\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Ungültiger Wert\"],\"wFjjxZ\":[\"wurde von \",[\"username\"],\" aus \",[\"channelName\"],\" gekickt (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Keine Bann-Ausnahmen gefunden\"],\"wPrGnM\":[\"Kanal-Administrator\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Anzeigen, wenn Benutzer Kanäle betreten oder verlassen\"],\"whqZ9r\":[\"Weitere Wörter oder Phrasen zum Hervorheben\"],\"wm7RV4\":[\"Benachrichtigungston\"],\"wz/Yoq\":[\"Deine Nachrichten könnten abgefangen werden, wenn sie zwischen Servern weitergeleitet werden\"],\"xCJdfg\":[\"Leeren\"],\"xUHRTR\":[\"Beim Verbinden automatisch als Operator authentifizieren\"],\"xWHwwQ\":[\"Sperren\"],\"xYilR2\":[\"Medien\"],\"xceQrO\":[\"Nur sichere Websockets werden unterstützt\"],\"xdtXa+\":[\"Kanalname\"],\"xfXC7q\":[\"Textkanäle\"],\"xlCYOE\":[\"Weitere Nachrichten werden geladen...\"],\"xlhswE\":[\"Mindestwert ist \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Wort oder Phrase hinzufügen...\"],\"xuRqRq\":[\"Client-Limit (+l)\"],\"xwF+7J\":[[\"0\"],\" tippt...\"],\"yNeucF\":[\"Dieser Server unterstützt keine erweiterten Profilmetadaten (IRCv3 METADATA). Felder wie Avatar, Anzeigename und Status sind nicht verfügbar.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Kanal-Avatar\"],\"yQE2r9\":[\"Laden\"],\"ySU+JY\":[\"deine@email.de\"],\"yTX1Rt\":[\"Oper-Benutzername\"],\"yYOzWD\":[\"Protokolle\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC-Operatorpasswort\"],\"ygCKqB\":[\"Stopp\"],\"ymDxJx\":[\"IRC-Operatorbenutzername\"],\"yrpRsQ\":[\"Nach Name sortieren\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Schließen\"],\"zJw+jA\":[\"setzt Modus: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"oper-Benutzername eingeben\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Invalid pattern format. Use nick!user@host format (wildcards * allowed)\"],\"+6NQQA\":[\"General Support Channel\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Disconnect\"],\"+cyFdH\":[\"Default message when marking yourself as away\"],\"+mVPqU\":[\"Render markdown formatting in messages\"],\"+vqCJH\":[\"Your account username for authentication\"],\"+yPBXI\":[\"Choose file\"],\"+zy2Nq\":[\"Type\"],\"/09cao\":[\"Low Link Security (Level \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Users outside the channel cannot send messages to it\"],\"/6BzZF\":[\"Toggle Member List\"],\"/TNOPk\":[\"User is away\"],\"/XQgft\":[\"Discover\"],\"/cF7Rs\":[\"Volume\"],\"/dqduX\":[\"Next page\"],\"/fc3q4\":[\"All Content\"],\"/kISDh\":[\"Enable Notification Sounds\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Play sounds for mentions and messages\"],\"0/0ZGA\":[\"Channel Name Mask\"],\"0D6j7U\":[\"Learn more about custom rules →\"],\"0XsHcR\":[\"Kick User\"],\"0ZpE//\":[\"Sort by Users\"],\"0bEPwz\":[\"Set Away\"],\"0dGkPt\":[\"Expand channel list\"],\"0gS7M5\":[\"Display Name\"],\"0kS+M8\":[\"ExampleNET\"],\"0rgoY7\":[\"Only connect to servers you choose\"],\"0wdd7X\":[\"Join\"],\"0wkVYx\":[\"Private Messages\"],\"111uHX\":[\"Link preview\"],\"196EG4\":[\"Delete Private Chat\"],\"1DSr1i\":[\"Register for an account\"],\"1O/24y\":[\"Toggle Channel List\"],\"1VPJJ2\":[\"External Link Warning\"],\"1ZC/dv\":[\"No unread mentions or messages\"],\"1pO1zi\":[\"Server name is required\"],\"1uwfzQ\":[\"View Channel Topic\"],\"268g7c\":[\"Enter display name\"],\"2FOFq1\":[\"Server operators on the network could potentially read your messages\"],\"2FYpfJ\":[\"More\"],\"2HF1Y2\":[[\"inviter\"],\" has invited \",[\"target\"],\" to join \",[\"channel\"]],\"2I70QL\":[\"View user profile information\"],\"2QYdmE\":[\"Users:\"],\"2QpEjG\":[\"left\"],\"2YE223\":[\"Message #\",[\"0\"],\" (Enter for new line, Shift+Enter to send)\"],\"2bimFY\":[\"Use server password\"],\"2iTmdZ\":[\"Local Storage:\"],\"2odkwe\":[\"Strict - More aggressive protection\"],\"2uDhbA\":[\"Enter username to invite\"],\"2ygf/L\":[\"← Back\"],\"2zEgxj\":[\"Search GIFs...\"],\"3RdPhl\":[\"Rename Channel\"],\"3THokf\":[\"Voiced User\"],\"3TSz9S\":[\"Minimize\"],\"3jBDvM\":[\"Channel Display Name\"],\"3ryuFU\":[\"Optional crash reports to improve the app\"],\"3uBF/8\":[\"Close viewer\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Enter account name...\"],\"4/Rr0R\":[\"Invite a user to the current channel\"],\"4EZrJN\":[\"Rules\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flood Profile (+F)\"],\"4RZQRK\":[\"What are you up to?\"],\"4hfTrB\":[\"Nickname\"],\"4n99LO\":[\"Already in \",[\"0\"]],\"4t6vMV\":[\"Automatically switch to single line for short messages\"],\"4vsHmf\":[\"Time (min)\"],\"5+INAX\":[\"Highlight messages that mention you\"],\"5R5Pv/\":[\"Oper Name\"],\"678PKt\":[\"Network Name\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Password required to join the channel. Leave empty to remove the key.\"],\"6HhMs3\":[\"Quit Message\"],\"6V3Ea3\":[\"Copied\"],\"6lGV3K\":[\"Show less\"],\"6yFOEi\":[\"Enter oper password...\"],\"7+IHTZ\":[\"No file chosen\"],\"73hrRi\":[\"nick!user@host (e.g., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Send private message\"],\"7U1W7c\":[\"Very Relaxed\"],\"7Y1YQj\":[\"Realname:\"],\"7YHArF\":[\"— open in viewer\"],\"7fjnVl\":[\"Search users...\"],\"7jL88x\":[\"Delete this message? This cannot be undone.\"],\"7nGhhM\":[\"What's on your mind?\"],\"7sEpu1\":[\"Members — \",[\"0\"]],\"7sNhEz\":[\"Username\"],\"8H0Q+x\":[\"Learn more about profiles →\"],\"8Phu0A\":[\"Display when users change their nickname\"],\"8XTG9e\":[\"Enter oper password\"],\"8XsV2J\":[\"Retry sending\"],\"8ZsakT\":[\"Password\"],\"8kR84m\":[\"You are about to open an external link:\"],\"8lCgih\":[\"Remove Rule\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"joined\"],\"other\":[\"joined \",[\"joinCount\"],\" times\"]}]],\"9BMLnJ\":[\"Reconnect to server\"],\"9OEgyT\":[\"Add reaction\"],\"9PQ8m2\":[\"G-Line (Global Ban)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Remove pattern\"],\"9bG48P\":[\"Sending\"],\"9f5f0u\":[\"Questions about privacy? Contact us:\"],\"9unqs3\":[\"Away:\"],\"9v3hwv\":[\"No servers found.\"],\"9zb2WA\":[\"Connecting\"],\"A1taO8\":[\"Search\"],\"A2adVi\":[\"Send Typing Notifications\"],\"A9Rhec\":[\"Channel Name\"],\"AWOSPo\":[\"Zoom in\"],\"AXSpEQ\":[\"Oper on Connect\"],\"AeXO77\":[\"Account\"],\"AhNP40\":[\"Seek\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Changed nickname\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Cancel reply\"],\"ApSx0O\":[\"Found \",[\"0\"],\" messages matching \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"No results found\"],\"AyNqAB\":[\"Display all server events in chat\"],\"B/QqGw\":[\"Away from keyboard\"],\"B8AaMI\":[\"This field is required\"],\"BA2c49\":[\"Server doesn't support advanced LIST filtering\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" and \",[\"3\"],\" others are typing...\"],\"BGul2A\":[\"You have unsaved changes. Are you sure you want to close without saving?\"],\"BIf9fi\":[\"Your status message\"],\"BZz3md\":[\"Your personal website\"],\"Bgm/H7\":[\"Allow entering multiple lines of text\"],\"BiQIl1\":[\"Pin this private message conversation\"],\"BlNZZ2\":[\"Click to jump to message\"],\"Bowq3c\":[\"Only operators can change the channel topic\"],\"Btozzp\":[\"This image has expired\"],\"Bycfjm\":[\"Total: \",[\"0\"]],\"C6IBQc\":[\"Copy entire JSON\"],\"C9L9wL\":[\"Data Collection\"],\"CDq4wC\":[\"Moderate User\"],\"CHVRxG\":[\"Message @\",[\"0\"],\" (Shift+Enter for new line)\"],\"CN9zdR\":[\"Oper name and password are required\"],\"CW3sYa\":[\"Add reaction \",[\"emoji\"]],\"CaAkqd\":[\"Show Quits\"],\"CbvaYj\":[\"Ban by Nickname\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Select a channel\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"joined and quit\"],\"DB8zMK\":[\"Apply\"],\"DBcWHr\":[\"Custom notification sound file\"],\"DTy9Xw\":[\"Media Previews\"],\"Dj4pSr\":[\"Choose a secure password\"],\"Du+zn+\":[\"Searching...\"],\"Du2T2f\":[\"Setting not found\"],\"DwsSVQ\":[\"Apply Filters & Refresh\"],\"E3W/zd\":[\"Default Nickname\"],\"E6nRW7\":[\"Copy URL\"],\"E703RG\":[\"Modes:\"],\"EAeu1Z\":[\"Send Invite\"],\"EFKJQT\":[\"Setting\"],\"EGPQBv\":[\"Custom Flood Rules (+f)\"],\"ELik0r\":[\"View Full Privacy Policy\"],\"EPbeC2\":[\"View or edit the channel topic\"],\"EQCDNT\":[\"Enter oper username...\"],\"EUvulZ\":[\"Found 1 message matching \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Next image\"],\"EdQY6l\":[\"None\"],\"EnqLYU\":[\"Search servers...\"],\"F0OKMc\":[\"Edit Server\"],\"F6Int2\":[\"Enable Highlights\"],\"FDoLyE\":[\"Max Users\"],\"FUU/hZ\":[\"Control how much external media is loaded in chat.\"],\"Fdp03t\":[\"on\"],\"FfPWR0\":[\"Modal\"],\"FjkaiT\":[\"Zoom out\"],\"FlqOE9\":[\"What this means:\"],\"FolHNl\":[\"Manage your account and authentication\"],\"Fp2Dif\":[\"Quit the server\"],\"G5KmCc\":[\"GZ-Line (Global Z-Line)\"],\"GDs0lz\":[\"<0>Risk:</0> Sensitive information (messages, private conversations, authentication details) could be exposed to network administrators or attackers positioned between IRC servers.\"],\"GR+2I3\":[\"Add invitation mask (e.g., nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Close popped out server notices\"],\"GlHnXw\":[\"Nick change failed: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Preview:\"],\"GtmO8/\":[\"from\"],\"GtuHUQ\":[\"Rename this channel on the server. All users will see the new name.\"],\"GuGfFX\":[\"Toggle search\"],\"GxkJXS\":[\"Uploading...\"],\"GzbwnK\":[\"Joined the channel\"],\"GzsUDB\":[\"Extended Profile\"],\"H/PnT8\":[\"Insert emoji\"],\"H6Izzl\":[\"Your preferred color code\"],\"H9jIv+\":[\"Show Joins/Parts\"],\"HAKBY9\":[\"Upload Files\"],\"HdE1If\":[\"Channel\"],\"Hk4AW9\":[\"Your preferred display name\"],\"HmHDk7\":[\"Select Member\"],\"HrQzPU\":[\"Channels on \",[\"networkName\"]],\"I2tXQ5\":[\"Message @\",[\"0\"],\" (Enter for new line, Shift+Enter to send)\"],\"I6bw/h\":[\"Ban User\"],\"I92Z+b\":[\"Enable notifications\"],\"I9D72S\":[\"Are you sure you want to delete this message? This action cannot be undone.\"],\"IA+1wo\":[\"Display when users are kicked from channels\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Save Changes\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" and \",[\"2\"],\" are typing...\"],\"IgrLD/\":[\"Pause\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Reply\"],\"IoHMnl\":[\"Maximum value is \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Connecting...\"],\"J5T9NW\":[\"User Information\"],\"J8Y5+z\":[\"Oops! The net split! ⚠️\"],\"JBHkBA\":[\"Left the channel\"],\"JCwL0Q\":[\"Enter reason (optional)\"],\"JFciKP\":[\"Toggle\"],\"JXGkhG\":[\"Change the channel name (operators only)\"],\"JcD7qf\":[\"More actions\"],\"JdkA+c\":[\"Secret (+s)\"],\"Jmu12l\":[\"Server Channels\"],\"JvQ++s\":[\"Enable Markdown\"],\"K2jwh/\":[\"No WHOIS data available\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Delete message\"],\"KKBlUU\":[\"Embed\"],\"KM0pLb\":[\"Welcome to the channel!\"],\"KR6W2h\":[\"Unignore User\"],\"KV+Bi1\":[\"Invite-Only (+i)\"],\"KdCtwE\":[\"How many seconds to monitor for flood activity before resetting counters\"],\"Kkezga\":[\"Server Password\"],\"KsiQ/8\":[\"Users must be invited to join the channel\"],\"L+gB/D\":[\"Channel Information\"],\"LC1a7n\":[\"The IRC server has reported that its server-to-server links have a low security level. This means that when your messages are relayed between IRC servers in the network, they may not be properly encrypted or the SSL/TLS certificates may not be validated correctly.\"],\"LNfLR5\":[\"Show Kicks\"],\"LQb0W/\":[\"Show All Events\"],\"LU7/yA\":[\"Alternative name for display in the UI. May contain spaces, emoji, and special characters. The real channel name (\",[\"channelName\"],\") will still be used for IRC commands.\"],\"LUb9O7\":[\"Valid server port is required\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Privacy Policy\"],\"LcuSDR\":[\"Manage your profile information and metadata\"],\"LqLS9B\":[\"Show Nick Changes\"],\"LsDQt2\":[\"Channel Settings\"],\"LtI9AS\":[\"Owner\"],\"LuNhhL\":[\"reacted to this message\"],\"M/AZNG\":[\"URL to your avatar image\"],\"M/WIer\":[\"Send Message\"],\"M8er/5\":[\"Name:\"],\"MHk+7g\":[\"Previous image\"],\"MRorGe\":[\"PM User\"],\"MVbSGP\":[\"Time Window (seconds)\"],\"MkpcsT\":[\"Your messages and settings are stored locally on your device\"],\"N/hDSy\":[\"Mark as bot - usually 'on' or empty\"],\"N7TQbE\":[\"Invite User to \",[\"channelName\"]],\"NCca/o\":[\"Enter default nickname...\"],\"Nqs6B9\":[\"Shows all external media. Any URL may cause a request to an unknown server.\"],\"Nt+9O7\":[\"Use WebSocket instead of raw TCP\"],\"NxIHzc\":[\"Kill User\"],\"O+v/cL\":[\"Browse all channels on the server\"],\"ODwSCk\":[\"Send a GIF\"],\"OGQ5kK\":[\"Configure notification sounds and highlights\"],\"OIPt1Z\":[\"Show or hide the member list sidebar\"],\"OKSNq/\":[\"Very Strict\"],\"ONWvwQ\":[\"Upload\"],\"OVKoQO\":[\"Your account password for authentication\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Bringing IRC to the future\"],\"OhCpra\":[\"Set a topic…\"],\"OkltoQ\":[\"Ban \",[\"username\"],\" by nickname (prevents them from rejoining with the same nick)\"],\"P+t/Te\":[\"No additional data\"],\"P42Wcc\":[\"Safe\"],\"PD38l0\":[\"Channel avatar preview\"],\"PD9mEt\":[\"Type a message...\"],\"PPqfdA\":[\"Open channel configuration settings\"],\"PSCjfZ\":[\"The topic that will be displayed for this channel. All users can see the topic.\"],\"PZCecv\":[\"PDF preview\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 time\"],\"other\":[[\"c\"],\" times\"]}]],\"PguS2C\":[\"Add exception mask (e.g., nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Showing \",[\"displayedChannelsCount\"],\" of \",[\"0\"],\" channels\"],\"PqhVlJ\":[\"Ban User (by Hostmask)\"],\"Q+chwU\":[\"Username:\"],\"Q6hhn8\":[\"Preferences\"],\"QF4a34\":[\"Please enter a username\"],\"QGqSZ2\":[\"Color & Formatting\"],\"QJQd1J\":[\"Edit Profile\"],\"QSzGDE\":[\"Idle\"],\"QUlny5\":[\"Welcome to \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Read more\"],\"QuSkCF\":[\"Filter channels...\"],\"QwUrDZ\":[\"changed the topic to: \",[\"topic\"]],\"R0UH07\":[\"Image \",[\"0\"],\" of \",[\"1\"]],\"R7SsBE\":[\"Mute\"],\"R8rf1X\":[\"Click to set topic\"],\"RArB3D\":[\"was kicked from \",[\"channelName\"],\" by \",[\"username\"]],\"RI3cWd\":[\"Discover the world of IRC with ObsidianIRC\"],\"RMMaN5\":[\"Moderated (+m)\"],\"RWw9Lg\":[\"Close modal\"],\"RZ2BuZ\":[\"Account registration for \",[\"account\"],\" requires verification: \",[\"message\"]],\"RySp6q\":[\"Hide comments\"],\"SPKQTd\":[\"Nickname is required\"],\"SPVjfj\":[\"Will default to 'no reason' if left empty\"],\"SQKPvQ\":[\"Invite User\"],\"SkZcl+\":[\"Choose a predefined flood protection profile. These profiles provide balanced protection settings for different use cases.\"],\"Slr+3C\":[\"Min Users\"],\"Spnlre\":[\"You invited \",[\"target\"],\" to join \",[\"channel\"]],\"T/ckN5\":[\"Open in viewer\"],\"T91vKp\":[\"Play\"],\"TV2Wdu\":[\"Learn how we handle your data and protect your privacy.\"],\"TgFpwD\":[\"Applying...\"],\"TkzSFB\":[\"No Changes\"],\"TtserG\":[\"Enter real name\"],\"Ttz9J1\":[\"Enter password...\"],\"Tz0i8g\":[\"Settings\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"React\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Save Settings\"],\"UV5hLB\":[\"No bans found\"],\"Uaj3Nd\":[\"Status Messages\"],\"Ue3uny\":[\"Default (no profile)\"],\"UkARhe\":[\"Normal - Standard protection\"],\"Umn7Cj\":[\"No comments yet. Be the first!\"],\"UtUIRh\":[[\"0\"],\" older messages\"],\"UwzP+U\":[\"Secure Connection\"],\"V0/A4O\":[\"Channel Owner\"],\"V4qgxE\":[\"Created Before (min ago)\"],\"V8yTm6\":[\"Clear search\"],\"VJMMyz\":[\"ObsidianIRC - Bringing IRC to the future\"],\"VJScHU\":[\"Reason\"],\"VLsmVV\":[\"Mute notifications\"],\"VbyRUy\":[\"Comments\"],\"Vmx0mQ\":[\"Set by:\"],\"VqnIZz\":[\"View our privacy policy and data practices\"],\"VrMygG\":[\"Minimum length is \",[\"0\"]],\"VrnTui\":[\"Your pronouns, shown in your profile\"],\"W8E3qn\":[\"Authenticated Account\"],\"WAakm9\":[\"Delete Channel\"],\"WFxTHC\":[\"Add ban mask (e.g., nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Server host is required\"],\"WRYdXW\":[\"Audio position\"],\"WUOH5B\":[\"Ignore User\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Show 1 more item\"],\"other\":[\"Show \",[\"1\"],\" more items\"]}]],\"Weq9zb\":[\"General\"],\"Wfj7Sk\":[\"Mute or unmute notification sounds\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"User Profile\"],\"X6S3lt\":[\"Search settings, channels, servers...\"],\"XEHan5\":[\"Continue Anyway\"],\"XI1+wb\":[\"Invalid format\"],\"XIXeuC\":[\"Message @\",[\"0\"]],\"XMS+k4\":[\"Start Private Message\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Unpin Private Chat\"],\"Xm/s+u\":[\"Display\"],\"Xp2n93\":[\"Shows media from your server's trusted file host. No requests are made to external services.\"],\"XvjC4F\":[\"Saving...\"],\"Y/qryO\":[\"No users found matching your search\"],\"YAqRpI\":[\"Account registration successful for \",[\"account\"],\": \",[\"message\"]],\"YEfzvP\":[\"Protected Topic (+t)\"],\"YQOn6a\":[\"Collapse member list\"],\"YRCoE9\":[\"Channel Operator\"],\"YURQaF\":[\"View Profile\"],\"YdBSvr\":[\"Control media display and external content\"],\"Yj6U3V\":[\"No Central Server:\"],\"YjvpGx\":[\"Pronouns\"],\"YqH4l4\":[\"No key\"],\"YyUPpV\":[\"Account:\"],\"ZJSWfw\":[\"Message shown when you disconnect from the server\"],\"ZR1dJ4\":[\"Invitations\"],\"ZdWg0V\":[\"Open in browser\"],\"ZhRBbl\":[\"Search messages…\"],\"Zmcu3y\":[\"Advanced Filters\"],\"a2/8e5\":[\"Topic Set After (min ago)\"],\"aHKcKc\":[\"Previous page\"],\"aJTbXX\":[\"Oper Password\"],\"aQryQv\":[\"Pattern already exists\"],\"aW9pLN\":[\"Maximum number of users allowed in the channel. Leave empty for no limit.\"],\"ah4fmZ\":[\"Also shows previews from YouTube, Vimeo, SoundCloud, and similar known services.\"],\"aifXak\":[\"No media in this channel\"],\"ap2zBz\":[\"Relaxed\"],\"az8lvo\":[\"Off\"],\"azXSNo\":[\"Expand member list\"],\"azdliB\":[\"Login to an account\"],\"b26wlF\":[\"she/her\"],\"bD/+Ei\":[\"Strict\"],\"bQ6BJn\":[\"Configure detailed flood protection rules. Each rule specifies what type of activity to monitor and what action to take when thresholds are exceeded.\"],\"beV7+y\":[\"The user will receive an invitation to join \",[\"channelName\"],\".\"],\"bk84cH\":[\"Away Message\"],\"bkHdLj\":[\"Add IRC Server\"],\"bmQLn5\":[\"Add Rule\"],\"bwRvnp\":[\"Action\"],\"c8+EVZ\":[\"Verified account\"],\"cGYUlD\":[\"No media previews are loaded.\"],\"cLF98o\":[\"Show comments (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"No users available\"],\"cSgpoS\":[\"Pin Private Chat\"],\"cde3ce\":[\"Message <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Copy formatted output\"],\"cl/A5J\":[\"Welcome to \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Delete\"],\"coPLXT\":[\"We don't store your IRC communications on our servers\"],\"crYH/6\":[\"SoundCloud player\"],\"d3sis4\":[\"Add Server\"],\"d9aN5k\":[\"Remove \",[\"username\"],\" from the channel\"],\"dEgA5A\":[\"Cancel\"],\"dGi1We\":[\"Unpin this private message conversation\"],\"dJVuyC\":[\"left \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"to\"],\"dXqxlh\":[\"<0>⚠️ Security Risk!</0> This connection may be vulnerable to interception or man-in-the-middle attacks.\"],\"da9Q/R\":[\"Changed channel modes\"],\"dhJN3N\":[\"Show comments\"],\"dj2xTE\":[\"Dismiss notification\"],\"dpCzmC\":[\"Flood Protection Settings\"],\"e9dQpT\":[\"Do you want to open this link in a new tab?\"],\"ePK91l\":[\"Edit\"],\"eYBDuB\":[\"Upload an image or provide a URL with optional \",[\"size\"],\" substitution for dynamic sizing\"],\"edBbee\":[\"Ban \",[\"username\"],\" by hostmask (prevents them from rejoining from the same IP/host)\"],\"ekfzWq\":[\"User Settings\"],\"elPDWs\":[\"Customize your IRC client experience\"],\"eu2osY\":[\"<0>💡 Recommendation:</0> Only proceed if you trust this server and understand the risks. Avoid sharing sensitive information or passwords over this connection.\"],\"euEhbr\":[\"Click to join \",[\"channel\"]],\"ez3vLd\":[\"Enable Multiline Input\"],\"f0J5Ki\":[\"Server-to-server communication may use unencrypted connections\"],\"f9BHJk\":[\"Warn User\"],\"fDOLLd\":[\"No channels found.\"],\"ffzDkB\":[\"Anonymous Analytics:\"],\"fq1GF9\":[\"Display when users disconnect from server\"],\"gEF57C\":[\"This server only supports one connection type\"],\"gJuLUI\":[\"Ignore List\"],\"gNzMrk\":[\"Current avatar\"],\"gjPWyO\":[\"Enter nickname...\"],\"gz6UQ3\":[\"Maximize\"],\"h6razj\":[\"Exclude Channel Name Mask\"],\"hG6jnw\":[\"No topic set\"],\"hG89Ed\":[\"Image\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"e.g., 100:1440\"],\"hehnjM\":[\"Amount\"],\"hzdLuQ\":[\"Only users with voice or higher can speak\"],\"i0qMbr\":[\"Home\"],\"iDNBZe\":[\"Notifications\"],\"iH8pgl\":[\"Back\"],\"iL9SZg\":[\"Ban User (by Nickname)\"],\"iNt+3c\":[\"Back to image\"],\"iQvi+a\":[\"Don't warn me about low link security for this server\"],\"iSLIjg\":[\"Connect\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Server Host\"],\"idD8Ev\":[\"Saved\"],\"iivqkW\":[\"Signed On\"],\"ij+Elv\":[\"Image preview\"],\"ilIWp7\":[\"Toggle Notifications\"],\"iuaqvB\":[\"Use * for wildcards. Examples: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Ban by Hostmask\"],\"jA4uoI\":[\"Topic:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Reason (optional)\"],\"jUV7CU\":[\"Upload Avatar\"],\"jW5Uwh\":[\"Control how much external media is loaded. Off / Safe / Trusted Sources / All Content.\"],\"jXzms5\":[\"Attachment options\"],\"jZlrte\":[\"Color\"],\"jfC/xh\":[\"Contact\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Load older messages\"],\"k3ID0F\":[\"Filter members…\"],\"k65gsE\":[\"Deep dive\"],\"k7Zgob\":[\"Cancel Connection\"],\"kAVx5h\":[\"No invitations found\"],\"kCLEPU\":[\"Connected To\"],\"kF5LKb\":[\"Ignored patterns:\"],\"kGeOx/\":[\"Join \",[\"0\"]],\"kITKr8\":[\"Loading channel modes...\"],\"kPpPsw\":[\"You are an IRC Operator\"],\"kWJmRL\":[\"You\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Copy JSON\"],\"krViRy\":[\"Click to copy as JSON\"],\"ks71ra\":[\"Exceptions\"],\"kw4lRv\":[\"Channel Half Operator\"],\"kxgIRq\":[\"Select or add a channel to get started.\"],\"ky6dWe\":[\"Avatar preview\"],\"l+GxCv\":[\"Loading channels...\"],\"l+IUVW\":[\"Account verification successful for \",[\"account\"],\": \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"reconnected\"],\"other\":[\"reconnected \",[\"reconnectCount\"],\" times\"]}]],\"l5jmzx\":[[\"0\"],\" and \",[\"1\"],\" are typing...\"],\"lHy8N5\":[\"Loading more channels...\"],\"lbpf14\":[\"Join \",[\"value\"]],\"lfFsZ4\":[\"Channels\"],\"lkNdiH\":[\"Account Name\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Upload Image\"],\"loQxaJ\":[\"I'm Back\"],\"lvfaxv\":[\"HOME\"],\"m16xKo\":[\"Add\"],\"m8flAk\":[\"Preview (not yet uploaded)\"],\"mEPxTp\":[\"<0>⚠️ Be careful!</0> Only open links from trusted sources. Malicious links can compromise your security or privacy.\"],\"mHGdhG\":[\"Server Information\"],\"mHS8lb\":[\"Message #\",[\"0\"]],\"mMYBD9\":[\"Wide - Broader protection scope\"],\"mTGsPd\":[\"Channel Topic\"],\"mU8j6O\":[\"No External Messages (+n)\"],\"mZp8FL\":[\"Auto Fallback to Single Line\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"Comments (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"User is authenticated\"],\"mwtcGl\":[\"Close comments\"],\"mzI/c+\":[\"Download\"],\"n3fGRk\":[\"set by \",[\"0\"]],\"nE9jsU\":[\"Relaxed - Less aggressive protection\"],\"nNflMD\":[\"Leave channel\"],\"nPXkBi\":[\"Loading WHOIS data...\"],\"nQnxxF\":[\"Message #\",[\"0\"],\" (Shift+Enter for new line)\"],\"nWMRxa\":[\"Unpin\"],\"nkC032\":[\"No flood profile\"],\"o69z4d\":[\"Send a warning message to \",[\"username\"]],\"o9ylQi\":[\"Search for GIFs to get started\"],\"oFGkER\":[\"Server Notices\"],\"oOi11l\":[\"Scroll to bottom\"],\"oQEzQR\":[\"New DM\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Man-in-the-middle attacks on server links are possible\"],\"oeqmmJ\":[\"Trusted Sources\"],\"ovBPCi\":[\"Default\"],\"p0Z69r\":[\"Pattern cannot be empty\"],\"p1KgtK\":[\"Failed to load audio\"],\"p59pEv\":[\"Additional Details\"],\"p7sRI6\":[\"Let others know when you are typing\"],\"pBm1od\":[\"Secret channel\"],\"pNmiXx\":[\"Your default nickname for all servers\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Account Password\"],\"peNE68\":[\"Permanent\"],\"plhHQt\":[\"No data\"],\"pm6+q5\":[\"Security Warning\"],\"pn5qSs\":[\"Additional Information\"],\"q0cR4S\":[\"are now known as **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Channel won't appear in LIST or NAMES commands\"],\"qLpTm/\":[\"Remove reaction \",[\"emoji\"]],\"qVkGWK\":[\"Pin\"],\"qY8wNa\":[\"Homepage\"],\"qb0xJ7\":[\"Use wildcards: * matches any sequence, ? matches any single character. Examples: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Channel Key (+k)\"],\"qtoOYG\":[\"No limit\"],\"r1W2AS\":[\"Filehost image\"],\"rIPR2O\":[\"Topic Set Before (min ago)\"],\"rMMSYo\":[\"Maximum length is \",[\"0\"]],\"rWtzQe\":[\"The network split and rejoined. ✅\"],\"rYG2u6\":[\"Please wait...\"],\"rdUucN\":[\"Preview\"],\"rjGI/Q\":[\"Privacy\"],\"rk8iDX\":[\"Loading GIFs...\"],\"rn6SBY\":[\"Unmute\"],\"s/UKqq\":[\"Was kicked from the channel\"],\"s8cATI\":[\"joined \",[\"channelName\"]],\"sCO9ue\":[\"The connection to <0>\",[\"serverName\"],\"</0> has the following security concerns:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"is now known as **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" has invited you to join \",[\"channel\"]],\"sby+1/\":[\"Click to copy\"],\"sfN25C\":[\"Your real or full name\"],\"sliuzR\":[\"Open Link\"],\"sqrO9R\":[\"Custom Mentions\"],\"sr6RdJ\":[\"Multiline on Shift+Enter\"],\"swrCpB\":[\"Channel has been renamed from \",[\"oldName\"],\" to \",[\"newName\"],\" by \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Advanced\"],\"t/YqKh\":[\"Remove\"],\"t47eHD\":[\"Your unique identifier on this server\"],\"tAkAh0\":[\"URL with optional \",[\"size\"],\" substitution for dynamic sizing. Example: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Show or hide the channel list sidebar\"],\"tfDRzk\":[\"Save\"],\"tiBsJk\":[\"left \",[\"channelName\"]],\"tt4/UD\":[\"quit (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Nickname {nick} already in use, retrying with {newNick}\"],\"u0a8B4\":[\"Authenticate as an IRC Operator for administrative access\"],\"u0rWFU\":[\"Created After (min ago)\"],\"u72w3t\":[\"Users and patterns to ignore\"],\"u7jc2L\":[\"quit\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Save failed: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC Servers:\"],\"usSSr/\":[\"Zoom level\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Use Shift+Enter for new lines (Enter sends)\"],\"vERlcd\":[\"Profile\"],\"vK0RL8\":[\"No topic\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Language\"],\"vaHYxN\":[\"Real Name\"],\"vhjbKr\":[\"Away\"],\"w4NYox\":[[\"title\"],\" Client\"],\"w8xQRx\":[\"Invalid value\"],\"wFjjxZ\":[\"was kicked from \",[\"channelName\"],\" by \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"No ban exceptions found\"],\"wPrGnM\":[\"Channel Admin\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Display when users join or leave channels\"],\"whqZ9r\":[\"Additional words or phrases to highlight\"],\"wm7RV4\":[\"Notification Sound\"],\"wz/Yoq\":[\"Your messages could be intercepted when relayed between servers\"],\"xCJdfg\":[\"Clear\"],\"xUHRTR\":[\"Automatically authenticate as operator on connect\"],\"xWHwwQ\":[\"Bans\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Only secure websockets are supported\"],\"xdtXa+\":[\"channel-name\"],\"xfXC7q\":[\"Text Channels\"],\"xlCYOE\":[\"Getting more messages...\"],\"xlhswE\":[\"Minimum value is \",[\"0\"]],\"xq97Ci\":[\"Add a word or phrase...\"],\"xuRqRq\":[\"Client Limit (+l)\"],\"xwF+7J\":[[\"0\"],\" is typing...\"],\"yNeucF\":[\"This server does not support extended profile metadata (IRCv3 METADATA extension). Additional fields like avatar, display name, and status are not available.\"],\"yPlrca\":[\"Channel Avatar\"],\"yQE2r9\":[\"Loading\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper Username\"],\"yYOzWD\":[\"logs\"],\"yfx9Re\":[\"IRC operator password\"],\"ygCKqB\":[\"Stop\"],\"ymDxJx\":[\"IRC operator username\"],\"yrpRsQ\":[\"Sort by Name\"],\"yz7wBu\":[\"Close\"],\"zJw+jA\":[\"sets mode: \",[\"0\"]],\"zebeLu\":[\"Enter oper username\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Invalid pattern format. Use nick!user@host format (wildcards * allowed)\"],\"+6NQQA\":[\"General Support Channel\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Disconnect\"],\"+cyFdH\":[\"Default message when marking yourself as away\"],\"+mVPqU\":[\"Render markdown formatting in messages\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Your account username for authentication\"],\"+yPBXI\":[\"Choose file\"],\"+zy2Nq\":[\"Type\"],\"/09cao\":[\"Low Link Security (Level \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Users outside the channel cannot send messages to it\"],\"/6BzZF\":[\"Toggle Member List\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"User is away\"],\"/XQgft\":[\"Discover\"],\"/cF7Rs\":[\"Volume\"],\"/dqduX\":[\"Next page\"],\"/fc3q4\":[\"All Content\"],\"/kISDh\":[\"Enable Notification Sounds\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Play sounds for mentions and messages\"],\"0/0ZGA\":[\"Channel Name Mask\"],\"0D6j7U\":[\"Learn more about custom rules →\"],\"0XsHcR\":[\"Kick User\"],\"0ZpE//\":[\"Sort by Users\"],\"0bEPwz\":[\"Set Away\"],\"0dGkPt\":[\"Expand channel list\"],\"0gS7M5\":[\"Display Name\"],\"0kS+M8\":[\"ExampleNET\"],\"0rgoY7\":[\"Only connect to servers you choose\"],\"0wdd7X\":[\"Join\"],\"0wkVYx\":[\"Private Messages\"],\"111uHX\":[\"Link preview\"],\"196EG4\":[\"Delete Private Chat\"],\"1DSr1i\":[\"Register for an account\"],\"1O/24y\":[\"Toggle Channel List\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"External Link Warning\"],\"1ZC/dv\":[\"No unread mentions or messages\"],\"1pO1zi\":[\"Server name is required\"],\"1uwfzQ\":[\"View Channel Topic\"],\"268g7c\":[\"Enter display name\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Server operators on the network could potentially read your messages\"],\"2FYpfJ\":[\"More\"],\"2HF1Y2\":[[\"inviter\"],\" has invited \",[\"target\"],\" to join \",[\"channel\"]],\"2I70QL\":[\"View user profile information\"],\"2QYdmE\":[\"Users:\"],\"2QpEjG\":[\"left\"],\"2YE223\":[\"Message #\",[\"0\"],\" (Enter for new line, Shift+Enter to send)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Use server password\"],\"2iTmdZ\":[\"Local Storage:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Strict - More aggressive protection\"],\"2uDhbA\":[\"Enter username to invite\"],\"2ygf/L\":[\"← Back\"],\"2zEgxj\":[\"Search GIFs...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Rename Channel\"],\"3THokf\":[\"Voiced User\"],\"3TSz9S\":[\"Minimize\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Channel Display Name\"],\"3ryuFU\":[\"Optional crash reports to improve the app\"],\"3uBF/8\":[\"Close viewer\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Enter account name...\"],\"4/Rr0R\":[\"Invite a user to the current channel\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Rules\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flood Profile (+F)\"],\"4RZQRK\":[\"What are you up to?\"],\"4hfTrB\":[\"Nickname\"],\"4n99LO\":[\"Already in \",[\"0\"]],\"4t6vMV\":[\"Automatically switch to single line for short messages\"],\"4vsHmf\":[\"Time (min)\"],\"5+INAX\":[\"Highlight messages that mention you\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper Name\"],\"678PKt\":[\"Network Name\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Password required to join the channel. Leave empty to remove the key.\"],\"6HhMs3\":[\"Quit Message\"],\"6V3Ea3\":[\"Copied\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Show less\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Enter oper password...\"],\"7+IHTZ\":[\"No file chosen\"],\"73hrRi\":[\"nick!user@host (e.g., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Send private message\"],\"7U1W7c\":[\"Very Relaxed\"],\"7Y1YQj\":[\"Realname:\"],\"7YHArF\":[\"— open in viewer\"],\"7fjnVl\":[\"Search users...\"],\"7jL88x\":[\"Delete this message? This cannot be undone.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"What's on your mind?\"],\"7sEpu1\":[\"Members — \",[\"0\"]],\"7sNhEz\":[\"Username\"],\"8H0Q+x\":[\"Learn more about profiles →\"],\"8Phu0A\":[\"Display when users change their nickname\"],\"8XTG9e\":[\"Enter oper password\"],\"8XsV2J\":[\"Retry sending\"],\"8ZsakT\":[\"Password\"],\"8kR84m\":[\"You are about to open an external link:\"],\"8lCgih\":[\"Remove Rule\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"joined\"],\"other\":[\"joined \",[\"joinCount\"],\" times\"]}]],\"9BMLnJ\":[\"Reconnect to server\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Add reaction\"],\"9PQ8m2\":[\"G-Line (Global Ban)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Remove pattern\"],\"9bG48P\":[\"Sending\"],\"9f5f0u\":[\"Questions about privacy? Contact us:\"],\"9unqs3\":[\"Away:\"],\"9v3hwv\":[\"No servers found.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Connecting\"],\"A1taO8\":[\"Search\"],\"A2adVi\":[\"Send Typing Notifications\"],\"A9Rhec\":[\"Channel Name\"],\"AWOSPo\":[\"Zoom in\"],\"AXSpEQ\":[\"Oper on Connect\"],\"AeXO77\":[\"Account\"],\"AhNP40\":[\"Seek\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Changed nickname\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Cancel reply\"],\"ApSx0O\":[\"Found \",[\"0\"],\" messages matching \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"No results found\"],\"AyNqAB\":[\"Display all server events in chat\"],\"B/QqGw\":[\"Away from keyboard\"],\"B8AaMI\":[\"This field is required\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Server doesn't support advanced LIST filtering\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" and \",[\"3\"],\" others are typing...\"],\"BGul2A\":[\"You have unsaved changes. Are you sure you want to close without saving?\"],\"BIf9fi\":[\"Your status message\"],\"BZz3md\":[\"Your personal website\"],\"Bgm/H7\":[\"Allow entering multiple lines of text\"],\"BiQIl1\":[\"Pin this private message conversation\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Click to jump to message\"],\"Bowq3c\":[\"Only operators can change the channel topic\"],\"Btozzp\":[\"This image has expired\"],\"Bycfjm\":[\"Total: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Copy entire JSON\"],\"C9L9wL\":[\"Data Collection\"],\"CDq4wC\":[\"Moderate User\"],\"CHVRxG\":[\"Message @\",[\"0\"],\" (Shift+Enter for new line)\"],\"CN9zdR\":[\"Oper name and password are required\"],\"CW3sYa\":[\"Add reaction \",[\"emoji\"]],\"CaAkqd\":[\"Show Quits\"],\"CbvaYj\":[\"Ban by Nickname\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Select a channel\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"joined and quit\"],\"DB8zMK\":[\"Apply\"],\"DBcWHr\":[\"Custom notification sound file\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Media Previews\"],\"Dj4pSr\":[\"Choose a secure password\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Searching...\"],\"Du2T2f\":[\"Setting not found\"],\"DwsSVQ\":[\"Apply Filters & Refresh\"],\"E3W/zd\":[\"Default Nickname\"],\"E6nRW7\":[\"Copy URL\"],\"E703RG\":[\"Modes:\"],\"EAeu1Z\":[\"Send Invite\"],\"EFKJQT\":[\"Setting\"],\"EGPQBv\":[\"Custom Flood Rules (+f)\"],\"ELik0r\":[\"View Full Privacy Policy\"],\"EPbeC2\":[\"View or edit the channel topic\"],\"EQCDNT\":[\"Enter oper username...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Found 1 message matching \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Next image\"],\"EdQY6l\":[\"None\"],\"EnqLYU\":[\"Search servers...\"],\"F0OKMc\":[\"Edit Server\"],\"F6Int2\":[\"Enable Highlights\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Max Users\"],\"FUU/hZ\":[\"Control how much external media is loaded in chat.\"],\"Fdp03t\":[\"on\"],\"FfPWR0\":[\"Modal\"],\"FjkaiT\":[\"Zoom out\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"What this means:\"],\"FolHNl\":[\"Manage your account and authentication\"],\"Fp2Dif\":[\"Quit the server\"],\"G5KmCc\":[\"GZ-Line (Global Z-Line)\"],\"GDs0lz\":[\"<0>Risk:</0> Sensitive information (messages, private conversations, authentication details) could be exposed to network administrators or attackers positioned between IRC servers.\"],\"GR+2I3\":[\"Add invitation mask (e.g., nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Close popped out server notices\"],\"GlHnXw\":[\"Nick change failed: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Preview:\"],\"GtmO8/\":[\"from\"],\"GtuHUQ\":[\"Rename this channel on the server. All users will see the new name.\"],\"GuGfFX\":[\"Toggle search\"],\"GxkJXS\":[\"Uploading...\"],\"GzbwnK\":[\"Joined the channel\"],\"GzsUDB\":[\"Extended Profile\"],\"H/PnT8\":[\"Insert emoji\"],\"H6Izzl\":[\"Your preferred color code\"],\"H9jIv+\":[\"Show Joins/Parts\"],\"HAKBY9\":[\"Upload Files\"],\"HdE1If\":[\"Channel\"],\"Hk4AW9\":[\"Your preferred display name\"],\"HmHDk7\":[\"Select Member\"],\"HrQzPU\":[\"Channels on \",[\"networkName\"]],\"I2tXQ5\":[\"Message @\",[\"0\"],\" (Enter for new line, Shift+Enter to send)\"],\"I6bw/h\":[\"Ban User\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Enable notifications\"],\"I9D72S\":[\"Are you sure you want to delete this message? This action cannot be undone.\"],\"IA+1wo\":[\"Display when users are kicked from channels\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Save Changes\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" and \",[\"2\"],\" are typing...\"],\"IgrLD/\":[\"Pause\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Reply\"],\"IoHMnl\":[\"Maximum value is \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Connecting...\"],\"J5T9NW\":[\"User Information\"],\"J8Y5+z\":[\"Oops! The net split! ⚠️\"],\"JBHkBA\":[\"Left the channel\"],\"JCwL0Q\":[\"Enter reason (optional)\"],\"JFciKP\":[\"Toggle\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Change the channel name (operators only)\"],\"JcD7qf\":[\"More actions\"],\"JdkA+c\":[\"Secret (+s)\"],\"Jmu12l\":[\"Server Channels\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Enable Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"No WHOIS data available\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Delete message\"],\"KKBlUU\":[\"Embed\"],\"KM0pLb\":[\"Welcome to the channel!\"],\"KR6W2h\":[\"Unignore User\"],\"KV+Bi1\":[\"Invite-Only (+i)\"],\"KdCtwE\":[\"How many seconds to monitor for flood activity before resetting counters\"],\"Kkezga\":[\"Server Password\"],\"KsiQ/8\":[\"Users must be invited to join the channel\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Channel Information\"],\"LC1a7n\":[\"The IRC server has reported that its server-to-server links have a low security level. This means that when your messages are relayed between IRC servers in the network, they may not be properly encrypted or the SSL/TLS certificates may not be validated correctly.\"],\"LNfLR5\":[\"Show Kicks\"],\"LQb0W/\":[\"Show All Events\"],\"LU7/yA\":[\"Alternative name for display in the UI. May contain spaces, emoji, and special characters. The real channel name (\",[\"channelName\"],\") will still be used for IRC commands.\"],\"LUb9O7\":[\"Valid server port is required\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Privacy Policy\"],\"LcuSDR\":[\"Manage your profile information and metadata\"],\"LqLS9B\":[\"Show Nick Changes\"],\"LsDQt2\":[\"Channel Settings\"],\"LtI9AS\":[\"Owner\"],\"LuNhhL\":[\"reacted to this message\"],\"M/AZNG\":[\"URL to your avatar image\"],\"M/WIer\":[\"Send Message\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Name:\"],\"MHk+7g\":[\"Previous image\"],\"MRorGe\":[\"PM User\"],\"MVbSGP\":[\"Time Window (seconds)\"],\"MkpcsT\":[\"Your messages and settings are stored locally on your device\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Mark as bot - usually 'on' or empty\"],\"N7TQbE\":[\"Invite User to \",[\"channelName\"]],\"NCca/o\":[\"Enter default nickname...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Shows all external media. Any URL may cause a request to an unknown server.\"],\"Nt+9O7\":[\"Use WebSocket instead of raw TCP\"],\"NxIHzc\":[\"Kill User\"],\"O+v/cL\":[\"Browse all channels on the server\"],\"ODwSCk\":[\"Send a GIF\"],\"OGQ5kK\":[\"Configure notification sounds and highlights\"],\"OIPt1Z\":[\"Show or hide the member list sidebar\"],\"OKSNq/\":[\"Very Strict\"],\"ONWvwQ\":[\"Upload\"],\"OVKoQO\":[\"Your account password for authentication\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Bringing IRC to the future\"],\"OhCpra\":[\"Set a topic…\"],\"OkltoQ\":[\"Ban \",[\"username\"],\" by nickname (prevents them from rejoining with the same nick)\"],\"P+t/Te\":[\"No additional data\"],\"P42Wcc\":[\"Safe\"],\"PD38l0\":[\"Channel avatar preview\"],\"PD9mEt\":[\"Type a message...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Open channel configuration settings\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"The topic that will be displayed for this channel. All users can see the topic.\"],\"PZCecv\":[\"PDF preview\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 time\"],\"other\":[[\"c\"],\" times\"]}]],\"PguS2C\":[\"Add exception mask (e.g., nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Showing \",[\"displayedChannelsCount\"],\" of \",[\"0\"],\" channels\"],\"PqhVlJ\":[\"Ban User (by Hostmask)\"],\"Q+chwU\":[\"Username:\"],\"Q6hhn8\":[\"Preferences\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Please enter a username\"],\"QGqSZ2\":[\"Color & Formatting\"],\"QJQd1J\":[\"Edit Profile\"],\"QSzGDE\":[\"Idle\"],\"QUlny5\":[\"Welcome to \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Read more\"],\"QuSkCF\":[\"Filter channels...\"],\"QwUrDZ\":[\"changed the topic to: \",[\"topic\"]],\"R0UH07\":[\"Image \",[\"0\"],\" of \",[\"1\"]],\"R7SsBE\":[\"Mute\"],\"R8rf1X\":[\"Click to set topic\"],\"RArB3D\":[\"was kicked from \",[\"channelName\"],\" by \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Discover the world of IRC with ObsidianIRC\"],\"RMMaN5\":[\"Moderated (+m)\"],\"RWw9Lg\":[\"Close modal\"],\"RZ2BuZ\":[\"Account registration for \",[\"account\"],\" requires verification: \",[\"message\"]],\"RySp6q\":[\"Hide comments\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Nickname is required\"],\"SPVjfj\":[\"Will default to 'no reason' if left empty\"],\"SQKPvQ\":[\"Invite User\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Choose a predefined flood protection profile. These profiles provide balanced protection settings for different use cases.\"],\"Slr+3C\":[\"Min Users\"],\"Spnlre\":[\"You invited \",[\"target\"],\" to join \",[\"channel\"]],\"T/ckN5\":[\"Open in viewer\"],\"T91vKp\":[\"Play\"],\"TV2Wdu\":[\"Learn how we handle your data and protect your privacy.\"],\"TgFpwD\":[\"Applying...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"No Changes\"],\"TtserG\":[\"Enter real name\"],\"Ttz9J1\":[\"Enter password...\"],\"Tz0i8g\":[\"Settings\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"React\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Save Settings\"],\"UV5hLB\":[\"No bans found\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Status Messages\"],\"Ue3uny\":[\"Default (no profile)\"],\"UkARhe\":[\"Normal - Standard protection\"],\"Umn7Cj\":[\"No comments yet. Be the first!\"],\"UtUIRh\":[[\"0\"],\" older messages\"],\"UwzP+U\":[\"Secure Connection\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Channel Owner\"],\"V4qgxE\":[\"Created Before (min ago)\"],\"V8yTm6\":[\"Clear search\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - Bringing IRC to the future\"],\"VJScHU\":[\"Reason\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Mute notifications\"],\"VbyRUy\":[\"Comments\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Set by:\"],\"VqnIZz\":[\"View our privacy policy and data practices\"],\"VrMygG\":[\"Minimum length is \",[\"0\"]],\"VrnTui\":[\"Your pronouns, shown in your profile\"],\"W8E3qn\":[\"Authenticated Account\"],\"WAakm9\":[\"Delete Channel\"],\"WFxTHC\":[\"Add ban mask (e.g., nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Server host is required\"],\"WRYdXW\":[\"Audio position\"],\"WUOH5B\":[\"Ignore User\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Show 1 more item\"],\"other\":[\"Show \",[\"1\"],\" more items\"]}]],\"Weq9zb\":[\"General\"],\"Wfj7Sk\":[\"Mute or unmute notification sounds\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"User Profile\"],\"X6S3lt\":[\"Search settings, channels, servers...\"],\"XEHan5\":[\"Continue Anyway\"],\"XI1+wb\":[\"Invalid format\"],\"XIXeuC\":[\"Message @\",[\"0\"]],\"XMS+k4\":[\"Start Private Message\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Unpin Private Chat\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Display\"],\"Xp2n93\":[\"Shows media from your server's trusted file host. No requests are made to external services.\"],\"XvjC4F\":[\"Saving...\"],\"Y/qryO\":[\"No users found matching your search\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Account registration successful for \",[\"account\"],\": \",[\"message\"]],\"YEfzvP\":[\"Protected Topic (+t)\"],\"YQOn6a\":[\"Collapse member list\"],\"YRCoE9\":[\"Channel Operator\"],\"YURQaF\":[\"View Profile\"],\"YdBSvr\":[\"Control media display and external content\"],\"Yj6U3V\":[\"No Central Server:\"],\"YjvpGx\":[\"Pronouns\"],\"YqH4l4\":[\"No key\"],\"YyUPpV\":[\"Account:\"],\"ZJSWfw\":[\"Message shown when you disconnect from the server\"],\"ZR1dJ4\":[\"Invitations\"],\"ZdWg0V\":[\"Open in browser\"],\"ZhRBbl\":[\"Search messages…\"],\"Zmcu3y\":[\"Advanced Filters\"],\"a2/8e5\":[\"Topic Set After (min ago)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Previous page\"],\"aJTbXX\":[\"Oper Password\"],\"aQryQv\":[\"Pattern already exists\"],\"aW9pLN\":[\"Maximum number of users allowed in the channel. Leave empty for no limit.\"],\"ah4fmZ\":[\"Also shows previews from YouTube, Vimeo, SoundCloud, and similar known services.\"],\"aifXak\":[\"No media in this channel\"],\"ap2zBz\":[\"Relaxed\"],\"az8lvo\":[\"Off\"],\"azXSNo\":[\"Expand member list\"],\"azdliB\":[\"Login to an account\"],\"b26wlF\":[\"she/her\"],\"bD/+Ei\":[\"Strict\"],\"bQ6BJn\":[\"Configure detailed flood protection rules. Each rule specifies what type of activity to monitor and what action to take when thresholds are exceeded.\"],\"beV7+y\":[\"The user will receive an invitation to join \",[\"channelName\"],\".\"],\"bk84cH\":[\"Away Message\"],\"bkHdLj\":[\"Add IRC Server\"],\"bmQLn5\":[\"Add Rule\"],\"bwRvnp\":[\"Action\"],\"c8+EVZ\":[\"Verified account\"],\"cGYUlD\":[\"No media previews are loaded.\"],\"cLF98o\":[\"Show comments (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"No users available\"],\"cSgpoS\":[\"Pin Private Chat\"],\"cde3ce\":[\"Message <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Copy formatted output\"],\"cl/A5J\":[\"Welcome to \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Delete\"],\"coPLXT\":[\"We don't store your IRC communications on our servers\"],\"crYH/6\":[\"SoundCloud player\"],\"d3sis4\":[\"Add Server\"],\"d9aN5k\":[\"Remove \",[\"username\"],\" from the channel\"],\"dEgA5A\":[\"Cancel\"],\"dGi1We\":[\"Unpin this private message conversation\"],\"dJVuyC\":[\"left \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"to\"],\"dXqxlh\":[\"<0>⚠️ Security Risk!</0> This connection may be vulnerable to interception or man-in-the-middle attacks.\"],\"da9Q/R\":[\"Changed channel modes\"],\"dhJN3N\":[\"Show comments\"],\"dj2xTE\":[\"Dismiss notification\"],\"dpCzmC\":[\"Flood Protection Settings\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Do you want to open this link in a new tab?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Edit\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Upload an image or provide a URL with optional \",[\"size\"],\" substitution for dynamic sizing\"],\"edBbee\":[\"Ban \",[\"username\"],\" by hostmask (prevents them from rejoining from the same IP/host)\"],\"ekfzWq\":[\"User Settings\"],\"elPDWs\":[\"Customize your IRC client experience\"],\"eu2osY\":[\"<0>💡 Recommendation:</0> Only proceed if you trust this server and understand the risks. Avoid sharing sensitive information or passwords over this connection.\"],\"euEhbr\":[\"Click to join \",[\"channel\"]],\"ez3vLd\":[\"Enable Multiline Input\"],\"f0J5Ki\":[\"Server-to-server communication may use unencrypted connections\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Warn User\"],\"fDOLLd\":[\"No channels found.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonymous Analytics:\"],\"fq1GF9\":[\"Display when users disconnect from server\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"This server only supports one connection type\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Ignore List\"],\"gNzMrk\":[\"Current avatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Enter nickname...\"],\"gz6UQ3\":[\"Maximize\"],\"h6razj\":[\"Exclude Channel Name Mask\"],\"hG6jnw\":[\"No topic set\"],\"hG89Ed\":[\"Image\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"e.g., 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Amount\"],\"hzdLuQ\":[\"Only users with voice or higher can speak\"],\"i0qMbr\":[\"Home\"],\"iDNBZe\":[\"Notifications\"],\"iH8pgl\":[\"Back\"],\"iL9SZg\":[\"Ban User (by Nickname)\"],\"iNt+3c\":[\"Back to image\"],\"iQvi+a\":[\"Don't warn me about low link security for this server\"],\"iSLIjg\":[\"Connect\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Server Host\"],\"idD8Ev\":[\"Saved\"],\"iivqkW\":[\"Signed On\"],\"ij+Elv\":[\"Image preview\"],\"ilIWp7\":[\"Toggle Notifications\"],\"iuaqvB\":[\"Use * for wildcards. Examples: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Ban by Hostmask\"],\"jA4uoI\":[\"Topic:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Reason (optional)\"],\"jUV7CU\":[\"Upload Avatar\"],\"jW5Uwh\":[\"Control how much external media is loaded. Off / Safe / Trusted Sources / All Content.\"],\"jXzms5\":[\"Attachment options\"],\"jZlrte\":[\"Color\"],\"jfC/xh\":[\"Contact\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Load older messages\"],\"k3ID0F\":[\"Filter members…\"],\"k65gsE\":[\"Deep dive\"],\"k7Zgob\":[\"Cancel Connection\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"No invitations found\"],\"kCLEPU\":[\"Connected To\"],\"kF5LKb\":[\"Ignored patterns:\"],\"kGeOx/\":[\"Join \",[\"0\"]],\"kITKr8\":[\"Loading channel modes...\"],\"kPpPsw\":[\"You are an IRC Operator\"],\"kWJmRL\":[\"You\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Copy JSON\"],\"krViRy\":[\"Click to copy as JSON\"],\"ks71ra\":[\"Exceptions\"],\"kw4lRv\":[\"Channel Half Operator\"],\"kxgIRq\":[\"Select or add a channel to get started.\"],\"ky6dWe\":[\"Avatar preview\"],\"l+GxCv\":[\"Loading channels...\"],\"l+IUVW\":[\"Account verification successful for \",[\"account\"],\": \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"reconnected\"],\"other\":[\"reconnected \",[\"reconnectCount\"],\" times\"]}]],\"l5jmzx\":[[\"0\"],\" and \",[\"1\"],\" are typing...\"],\"lHy8N5\":[\"Loading more channels...\"],\"lbpf14\":[\"Join \",[\"value\"]],\"lfFsZ4\":[\"Channels\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Account Name\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Upload Image\"],\"loQxaJ\":[\"I'm Back\"],\"lvfaxv\":[\"HOME\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Add\"],\"m8flAk\":[\"Preview (not yet uploaded)\"],\"mEPxTp\":[\"<0>⚠️ Be careful!</0> Only open links from trusted sources. Malicious links can compromise your security or privacy.\"],\"mHGdhG\":[\"Server Information\"],\"mHS8lb\":[\"Message #\",[\"0\"]],\"mMYBD9\":[\"Wide - Broader protection scope\"],\"mTGsPd\":[\"Channel Topic\"],\"mU8j6O\":[\"No External Messages (+n)\"],\"mZp8FL\":[\"Auto Fallback to Single Line\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"Comments (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"User is authenticated\"],\"mwtcGl\":[\"Close comments\"],\"mzI/c+\":[\"Download\"],\"n3fGRk\":[\"set by \",[\"0\"]],\"nE9jsU\":[\"Relaxed - Less aggressive protection\"],\"nNflMD\":[\"Leave channel\"],\"nPXkBi\":[\"Loading WHOIS data...\"],\"nQnxxF\":[\"Message #\",[\"0\"],\" (Shift+Enter for new line)\"],\"nWMRxa\":[\"Unpin\"],\"nkC032\":[\"No flood profile\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Send a warning message to \",[\"username\"]],\"o9ylQi\":[\"Search for GIFs to get started\"],\"oFGkER\":[\"Server Notices\"],\"oOi11l\":[\"Scroll to bottom\"],\"oQEzQR\":[\"New DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Man-in-the-middle attacks on server links are possible\"],\"oeqmmJ\":[\"Trusted Sources\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Default\"],\"p0Z69r\":[\"Pattern cannot be empty\"],\"p1KgtK\":[\"Failed to load audio\"],\"p59pEv\":[\"Additional Details\"],\"p7sRI6\":[\"Let others know when you are typing\"],\"pBm1od\":[\"Secret channel\"],\"pNmiXx\":[\"Your default nickname for all servers\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Account Password\"],\"peNE68\":[\"Permanent\"],\"plhHQt\":[\"No data\"],\"pm6+q5\":[\"Security Warning\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Additional Information\"],\"q0cR4S\":[\"are now known as **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Channel won't appear in LIST or NAMES commands\"],\"qLpTm/\":[\"Remove reaction \",[\"emoji\"]],\"qVkGWK\":[\"Pin\"],\"qY8wNa\":[\"Homepage\"],\"qb0xJ7\":[\"Use wildcards: * matches any sequence, ? matches any single character. Examples: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Channel Key (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"No limit\"],\"r1W2AS\":[\"Filehost image\"],\"rIPR2O\":[\"Topic Set Before (min ago)\"],\"rMMSYo\":[\"Maximum length is \",[\"0\"]],\"rWtzQe\":[\"The network split and rejoined. ✅\"],\"rYG2u6\":[\"Please wait...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Preview\"],\"rjGI/Q\":[\"Privacy\"],\"rk8iDX\":[\"Loading GIFs...\"],\"rn6SBY\":[\"Unmute\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Was kicked from the channel\"],\"s8cATI\":[\"joined \",[\"channelName\"]],\"sCO9ue\":[\"The connection to <0>\",[\"serverName\"],\"</0> has the following security concerns:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"is now known as **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" has invited you to join \",[\"channel\"]],\"sby+1/\":[\"Click to copy\"],\"sfN25C\":[\"Your real or full name\"],\"sliuzR\":[\"Open Link\"],\"sqrO9R\":[\"Custom Mentions\"],\"sr6RdJ\":[\"Multiline on Shift+Enter\"],\"swrCpB\":[\"Channel has been renamed from \",[\"oldName\"],\" to \",[\"newName\"],\" by \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Advanced\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Remove\"],\"t47eHD\":[\"Your unique identifier on this server\"],\"tAkAh0\":[\"URL with optional \",[\"size\"],\" substitution for dynamic sizing. Example: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Show or hide the channel list sidebar\"],\"tfDRzk\":[\"Save\"],\"tiBsJk\":[\"left \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"quit (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Nickname {nick} already in use, retrying with {newNick}\"],\"u0a8B4\":[\"Authenticate as an IRC Operator for administrative access\"],\"u0rWFU\":[\"Created After (min ago)\"],\"u72w3t\":[\"Users and patterns to ignore\"],\"u7jc2L\":[\"quit\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Save failed: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC Servers:\"],\"usSSr/\":[\"Zoom level\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Use Shift+Enter for new lines (Enter sends)\"],\"vERlcd\":[\"Profile\"],\"vK0RL8\":[\"No topic\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Language\"],\"vaHYxN\":[\"Real Name\"],\"vhjbKr\":[\"Away\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" Client\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Invalid value\"],\"wFjjxZ\":[\"was kicked from \",[\"channelName\"],\" by \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"No ban exceptions found\"],\"wPrGnM\":[\"Channel Admin\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Display when users join or leave channels\"],\"whqZ9r\":[\"Additional words or phrases to highlight\"],\"wm7RV4\":[\"Notification Sound\"],\"wz/Yoq\":[\"Your messages could be intercepted when relayed between servers\"],\"xCJdfg\":[\"Clear\"],\"xUHRTR\":[\"Automatically authenticate as operator on connect\"],\"xWHwwQ\":[\"Bans\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Only secure websockets are supported\"],\"xdtXa+\":[\"channel-name\"],\"xfXC7q\":[\"Text Channels\"],\"xlCYOE\":[\"Getting more messages...\"],\"xlhswE\":[\"Minimum value is \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Add a word or phrase...\"],\"xuRqRq\":[\"Client Limit (+l)\"],\"xwF+7J\":[[\"0\"],\" is typing...\"],\"yNeucF\":[\"This server does not support extended profile metadata (IRCv3 METADATA extension). Additional fields like avatar, display name, and status are not available.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Channel Avatar\"],\"yQE2r9\":[\"Loading\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper Username\"],\"yYOzWD\":[\"logs\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC operator password\"],\"ygCKqB\":[\"Stop\"],\"ymDxJx\":[\"IRC operator username\"],\"yrpRsQ\":[\"Sort by Name\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Close\"],\"zJw+jA\":[\"sets mode: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Enter oper username\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Virheellinen mallimuoto. Käytä nick!käyttäjä@isäntä-muotoa (jokerimerkit * sallittu)\"],\"+6NQQA\":[\"Yleinen tukikanava\"],\"+6NyRG\":[\"Asiakasohjelma\"],\"+K0AvT\":[\"Katkaise yhteys\"],\"+cyFdH\":[\"Oletusviesti poissaoloilmoitukselle\"],\"+mVPqU\":[\"Näytä Markdown-muotoilu viesteissä\"],\"+vqCJH\":[\"Tilin käyttäjänimi tunnistautumista varten\"],\"+yPBXI\":[\"Valitse tiedosto\"],\"+zy2Nq\":[\"Tyyppi\"],\"/09cao\":[\"Heikko linkkiturvallisuus (taso \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Kanavan ulkopuoliset käyttäjät eivät voi lähettää viestejä sille\"],\"/6BzZF\":[\"Näytä/piilota jäsenlista\"],\"/TNOPk\":[\"Käyttäjä on poissa\"],\"/XQgft\":[\"Selaa\"],\"/cF7Rs\":[\"Äänenvoimakkuus\"],\"/dqduX\":[\"Seuraava sivu\"],\"/fc3q4\":[\"Kaikki sisältö\"],\"/kISDh\":[\"Ota ilmoitusäänet käyttöön\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Ääni\"],\"/rfkZe\":[\"Toista ääniä maininnoista ja viesteistä\"],\"0/0ZGA\":[\"Kanavan nimimaski\"],\"0D6j7U\":[\"Lue lisää mukautetuista säännöistä →\"],\"0XsHcR\":[\"Poista käyttäjä\"],\"0ZpE//\":[\"Lajittele käyttäjämäärän mukaan\"],\"0bEPwz\":[\"Aseta poissa\"],\"0dGkPt\":[\"Laajenna kanavaluettelo\"],\"0gS7M5\":[\"Näyttönimi\"],\"0kS+M8\":[\"EsimerkKi\"],\"0rgoY7\":[\"Yhdistä vain valitsemiisi palvelimiin\"],\"0wdd7X\":[\"Liity\"],\"0wkVYx\":[\"Yksityisviestit\"],\"111uHX\":[\"Linkin esikatselu\"],\"196EG4\":[\"Poista yksityiskeskustelu\"],\"1DSr1i\":[\"Rekisteröidy tilille\"],\"1O/24y\":[\"Näytä/piilota kanavaluettelo\"],\"1VPJJ2\":[\"Ulkoisen linkin varoitus\"],\"1ZC/dv\":[\"Ei lukemattomia mainintoja tai viestejä\"],\"1pO1zi\":[\"Palvelimen nimi on pakollinen\"],\"1uwfzQ\":[\"Näytä kanavan aihe\"],\"268g7c\":[\"Syötä näyttönimi\"],\"2FOFq1\":[\"Verkon palvelinoperaattorit voisivat mahdollisesti lukea viestisi\"],\"2FYpfJ\":[\"Lisää\"],\"2HF1Y2\":[[\"inviter\"],\" kutsui \",[\"target\"],\" liittymään kanavalle \",[\"channel\"]],\"2I70QL\":[\"Näytä käyttäjäprofiilitiedot\"],\"2QYdmE\":[\"Käyttäjät:\"],\"2QpEjG\":[\"poistui\"],\"2YE223\":[\"Viesti #\",[\"0\"],\" (Enter = uusi rivi, Shift+Enter = lähetä)\"],\"2bimFY\":[\"Käytä palvelimen salasanaa\"],\"2iTmdZ\":[\"Paikallinen tallennustila:\"],\"2odkwe\":[\"Tiukka – aggressiivisempi suojaus\"],\"2uDhbA\":[\"Syötä kutsuttavan käyttäjänimi\"],\"2ygf/L\":[\"← Takaisin\"],\"2zEgxj\":[\"Hae GIF-kuvia...\"],\"3RdPhl\":[\"Nimeä kanava uudelleen\"],\"3THokf\":[\"Voice-käyttäjä\"],\"3TSz9S\":[\"Pienennä\"],\"3jBDvM\":[\"Kanavan näyttönimi\"],\"3ryuFU\":[\"Valinnaiset kaatumisraportit sovelluksen parantamiseksi\"],\"3uBF/8\":[\"Sulje katseluohjelma\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Syötä tilin nimi...\"],\"4/Rr0R\":[\"Kutsu käyttäjä nykyiselle kanavalle\"],\"4EZrJN\":[\"Säännöt\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Tulvasuojausprofiili (+F)\"],\"4RZQRK\":[\"Mitä olet tekemässä?\"],\"4hfTrB\":[\"Nimimerkki\"],\"4n99LO\":[\"Jo kanavalla \",[\"0\"]],\"4t6vMV\":[\"Vaihda automaattisesti yksiriviseen lyhyille viesteille\"],\"4vsHmf\":[\"Aika (min)\"],\"5+INAX\":[\"Korosta viestit, joissa mainitaan sinut\"],\"5R5Pv/\":[\"Oper-nimi\"],\"678PKt\":[\"Verkon nimi\"],\"6Aih4U\":[\"Poissa verkosta\"],\"6CO3WE\":[\"Salasana vaaditaan kanavalle liittymiseen. Jätä tyhjäksi poistaaksesi avaimen.\"],\"6HhMs3\":[\"Lähtöviesti\"],\"6V3Ea3\":[\"Kopioitu\"],\"6lGV3K\":[\"Näytä vähemmän\"],\"6yFOEi\":[\"Syötä oper-salasana...\"],\"7+IHTZ\":[\"Ei valittua tiedostoa\"],\"73hrRi\":[\"nick!käyttäjä@isäntä (esim. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Lähetä yksityisviesti\"],\"7U1W7c\":[\"Hyvin löysä\"],\"7Y1YQj\":[\"Oikea nimi:\"],\"7YHArF\":[\"— avaa katseluohjelmassa\"],\"7fjnVl\":[\"Hae käyttäjiä...\"],\"7jL88x\":[\"Poistetaanko tämä viesti? Toimintoa ei voi kumota.\"],\"7nGhhM\":[\"Mitä ajattelet?\"],\"7sEpu1\":[\"Jäsenet — \",[\"0\"]],\"7sNhEz\":[\"Käyttäjänimi\"],\"8H0Q+x\":[\"Lue lisää profiileista →\"],\"8Phu0A\":[\"Näytä kun käyttäjät vaihtavat nimimerkkinsä\"],\"8XTG9e\":[\"Syötä oper-salasana\"],\"8XsV2J\":[\"Yritä lähettää uudelleen\"],\"8ZsakT\":[\"Salasana\"],\"8kR84m\":[\"Olet avaamassa ulkoista linkkiä:\"],\"8lCgih\":[\"Poista sääntö\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"liittyi\"],\"other\":[\"liittyi \",[\"joinCount\"],\" kertaa\"]}]],\"9BMLnJ\":[\"Yhdistä uudelleen palvelimeen\"],\"9OEgyT\":[\"Lisää reaktio\"],\"9PQ8m2\":[\"G-Line (maailmanlaajuinen esto)\"],\"9Qs99X\":[\"Sähköposti:\"],\"9QupBP\":[\"Poista malli\"],\"9bG48P\":[\"Lähetetään\"],\"9f5f0u\":[\"Yksityisyyteen liittyviä kysymyksiä? Ota yhteyttä:\"],\"9unqs3\":[\"Poissa:\"],\"9v3hwv\":[\"Palvelimia ei löydetty.\"],\"9zb2WA\":[\"Yhdistetään\"],\"A1taO8\":[\"Hae\"],\"A2adVi\":[\"Lähetä kirjoitusilmoituksia\"],\"A9Rhec\":[\"Kanavan nimi\"],\"AWOSPo\":[\"Lähennä\"],\"AXSpEQ\":[\"Oper yhdistäessä\"],\"AeXO77\":[\"Tili\"],\"AhNP40\":[\"Selaa\"],\"Ai2U7L\":[\"Isäntä\"],\"AjBQnf\":[\"Vaihtoi nimimerkin\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Peruuta vastaus\"],\"ApSx0O\":[\"Löydettiin \",[\"0\"],\" viestiä, jotka vastaavat \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Tuloksia ei löydetty\"],\"AyNqAB\":[\"Näytä kaikki palvelintapahtumat chatissa\"],\"B/QqGw\":[\"Poissa näppäimistöltä\"],\"B8AaMI\":[\"Tämä kenttä on pakollinen\"],\"BA2c49\":[\"Palvelin ei tue kehittynyttä LIST-suodatusta\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" ja \",[\"3\"],\" muuta kirjoittavat...\"],\"BGul2A\":[\"Sinulla on tallentamattomia muutoksia. Haluatko varmasti sulkea tallentamatta?\"],\"BIf9fi\":[\"Tilaviestisi\"],\"BZz3md\":[\"Henkilökohtainen verkkosivustosi\"],\"Bgm/H7\":[\"Salli monirivisyöttö\"],\"BiQIl1\":[\"Kiinnitä tämä yksityisviesteistä\"],\"BlNZZ2\":[\"Siirry viestiin napsauttamalla\"],\"Bowq3c\":[\"Vain operaattorit voivat muuttaa kanavan aihetta\"],\"Btozzp\":[\"Tämä kuva on vanhentunut\"],\"Bycfjm\":[\"Yhteensä: \",[\"0\"]],\"C6IBQc\":[\"Kopioi koko JSON\"],\"C9L9wL\":[\"Tiedonkeruu\"],\"CDq4wC\":[\"Moderoi käyttäjää\"],\"CHVRxG\":[\"Viesti @\",[\"0\"],\" (Shift+Enter = uusi rivi)\"],\"CN9zdR\":[\"Oper-nimi ja salasana ovat pakollisia\"],\"CW3sYa\":[\"Lisää reaktio \",[\"emoji\"]],\"CaAkqd\":[\"Näytä poistumisviestit\"],\"CbvaYj\":[\"Estä nimimerkin perusteella\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Valitse kanava\"],\"CsekCi\":[\"Normaali\"],\"D+NlUC\":[\"Järjestelmä\"],\"D28t6+\":[\"liittyi ja poistui\"],\"DB8zMK\":[\"Käytä\"],\"DBcWHr\":[\"Mukautettu ilmoitusäänitiedosto\"],\"DTy9Xw\":[\"Median esikatselut\"],\"Dj4pSr\":[\"Valitse turvallinen salasana\"],\"Du+zn+\":[\"Haetaan...\"],\"Du2T2f\":[\"Asetusta ei löydetty\"],\"DwsSVQ\":[\"Käytä suodattimet ja päivitä\"],\"E3W/zd\":[\"Oletusnimimerkki\"],\"E6nRW7\":[\"Kopioi URL\"],\"E703RG\":[\"Tilat:\"],\"EAeu1Z\":[\"Lähetä kutsu\"],\"EFKJQT\":[\"Asetus\"],\"EGPQBv\":[\"Mukautetut tulvasäännöt (+f)\"],\"ELik0r\":[\"Lue koko tietosuojakäytäntö\"],\"EPbeC2\":[\"Näytä tai muokkaa kanavan aihetta\"],\"EQCDNT\":[\"Syötä oper-käyttäjätunnus...\"],\"EUvulZ\":[\"Löydettiin 1 viesti, joka vastaa \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Seuraava kuva\"],\"EdQY6l\":[\"Ei mitään\"],\"EnqLYU\":[\"Hae palvelimia...\"],\"F0OKMc\":[\"Muokkaa palvelinta\"],\"F6Int2\":[\"Ota korostukset käyttöön\"],\"FDoLyE\":[\"Enimmäiskäyttäjämäärä\"],\"FUU/hZ\":[\"Hallitsee, kuinka paljon ulkoista mediaa ladataan chatissa.\"],\"Fdp03t\":[\"päälle\"],\"FfPWR0\":[\"Ikkuna\"],\"FjkaiT\":[\"Loitonna\"],\"FlqOE9\":[\"Mitä tämä tarkoittaa:\"],\"FolHNl\":[\"Hallinnoi tiliäsi ja tunnistautumista\"],\"Fp2Dif\":[\"Poistui palvelimelta\"],\"G5KmCc\":[\"GZ-Line (maailmanlaajuinen Z-Line)\"],\"GDs0lz\":[\"<0>Riski:</0> Arkaluonteiset tiedot (viestit, yksityiskeskustelut, tunnistautumistiedot) voisivat paljastua verkon ylläpitäjille tai hyökkääjille, jotka sijaitsevat IRC-palvelimien välissä.\"],\"GR+2I3\":[\"Lisää kutsumask (esim. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Sulje irrotettu palvelintiedotteet-näkymä\"],\"GlHnXw\":[\"Nimen vaihto epäonnistui: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Esikatselu:\"],\"GtmO8/\":[\"lähettäjä\"],\"GtuHUQ\":[\"Nimeä tämä kanava uudelleen palvelimella. Kaikki käyttäjät näkevät uuden nimen.\"],\"GuGfFX\":[\"Näytä/piilota haku\"],\"GxkJXS\":[\"Ladataan...\"],\"GzbwnK\":[\"Liittyi kanavalle\"],\"GzsUDB\":[\"Laajennettu profiili\"],\"H/PnT8\":[\"Lisää emoji\"],\"H6Izzl\":[\"Suosikki värikoodisi\"],\"H9jIv+\":[\"Näytä liittymiset/poistumiset\"],\"HAKBY9\":[\"Lataa tiedostoja\"],\"HdE1If\":[\"Kanava\"],\"Hk4AW9\":[\"Suosikki näyttönimesi\"],\"HmHDk7\":[\"Valitse jäsen\"],\"HrQzPU\":[\"Kanavat verkossa \",[\"networkName\"]],\"I2tXQ5\":[\"Viesti @\",[\"0\"],\" (Enter = uusi rivi, Shift+Enter = lähetä)\"],\"I6bw/h\":[\"Estä käyttäjä\"],\"I92Z+b\":[\"Ota ilmoitukset käyttöön\"],\"I9D72S\":[\"Haluatko varmasti poistaa tämän viestin? Tätä toimintoa ei voi kumota.\"],\"IA+1wo\":[\"Näytä kun käyttäjiä poistetaan kanavilta\"],\"IDwkJx\":[\"IRC-operaattori\"],\"ILlU+s\":[\"Tiedot:\"],\"IUwGEM\":[\"Tallenna muutokset\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" ja \",[\"2\"],\" kirjoittavat...\"],\"IgrLD/\":[\"Tauko\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Vastaa\"],\"IoHMnl\":[\"Enimmäisarvo on \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Yhdistetään...\"],\"J5T9NW\":[\"Käyttäjätiedot\"],\"J8Y5+z\":[\"Hups! Verkon jako! ⚠️\"],\"JBHkBA\":[\"Poistui kanavalta\"],\"JCwL0Q\":[\"Syötä syy (valinnainen)\"],\"JFciKP\":[\"Vaihda\"],\"JXGkhG\":[\"Muuta kanavan nimeä (vain operaattorit)\"],\"JcD7qf\":[\"Lisää toimintoja\"],\"JdkA+c\":[\"Salainen (+s)\"],\"Jmu12l\":[\"Palvelimen kanavat\"],\"JvQ++s\":[\"Ota Markdown käyttöön\"],\"K2jwh/\":[\"WHOIS-tietoja ei saatavilla\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Poista viesti\"],\"KKBlUU\":[\"Upotus\"],\"KM0pLb\":[\"Tervetuloa kanavalle!\"],\"KR6W2h\":[\"Poista esto käyttäjältä\"],\"KV+Bi1\":[\"Vain kutsulla (+i)\"],\"KdCtwE\":[\"Kuinka monta sekuntia tulvatoimintaa seurataan ennen laskurien nollaamista\"],\"Kkezga\":[\"Palvelimen salasana\"],\"KsiQ/8\":[\"Käyttäjät täytyy kutsua kanavalle\"],\"L+gB/D\":[\"Kanavan tiedot\"],\"LC1a7n\":[\"IRC-palvelin on ilmoittanut, että sen palvelimien väliset linkit ovat heikosti suojattuja. Tämä tarkoittaa, että verkossa välitettäviä viestejäsi ei välttämättä salata asianmukaisesti tai SSL/TLS-sertifikaatteja ei tarkisteta oikein.\"],\"LNfLR5\":[\"Näytä poistamiset\"],\"LQb0W/\":[\"Näytä kaikki tapahtumat\"],\"LU7/yA\":[\"Vaihtoehtoinen nimi käyttöliittymässä näytettäväksi. Voi sisältää välilyöntejä, emojeja ja erikoismerkkejä. Todellista kanavan nimeä (\",[\"channelName\"],\") käytetään edelleen IRC-komennoissa.\"],\"LUb9O7\":[\"Kelvollinen palvelinportti on pakollinen\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Tietosuojakäytäntö\"],\"LcuSDR\":[\"Hallinnoi profiilitietojasi ja metatietoja\"],\"LqLS9B\":[\"Näytä nimimerkinvaihdot\"],\"LsDQt2\":[\"Kanavan asetukset\"],\"LtI9AS\":[\"Omistaja\"],\"LuNhhL\":[\"reagoi tähän viestiin\"],\"M/AZNG\":[\"URL avatar-kuvaasi\"],\"M/WIer\":[\"Lähetä viesti\"],\"M8er/5\":[\"Nimi:\"],\"MHk+7g\":[\"Edellinen kuva\"],\"MRorGe\":[\"Lähetä viesti käyttäjälle\"],\"MVbSGP\":[\"Aikaikkuna (sekuntia)\"],\"MkpcsT\":[\"Viestisi ja asetuksesi tallennetaan paikallisesti laitteellesi\"],\"N/hDSy\":[\"Merkitse botiksi – yleensä 'on' tai tyhjä\"],\"N7TQbE\":[\"Kutsu käyttäjä kanavalle \",[\"channelName\"]],\"NCca/o\":[\"Syötä oletusnimimerkki...\"],\"Nqs6B9\":[\"Näyttää kaiken ulkoisen median. Mikä tahansa URL voi aiheuttaa yhteyspyynnön tuntemattomalle palvelimelle.\"],\"Nt+9O7\":[\"Käytä WebSocket-yhteyttä TCP:n sijaan\"],\"NxIHzc\":[\"Katkaise yhteys\"],\"O+v/cL\":[\"Selaa kaikkia palvelimen kanavia\"],\"ODwSCk\":[\"Lähetä GIF\"],\"OGQ5kK\":[\"Määritä ilmoitusäänet ja korostukset\"],\"OIPt1Z\":[\"Näytä tai piilota jäsenlistan sivupalkki\"],\"OKSNq/\":[\"Hyvin tiukka\"],\"ONWvwQ\":[\"Lähetä\"],\"OVKoQO\":[\"Tilin salasana tunnistautumista varten\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Viedään IRC tulevaisuuteen\"],\"OhCpra\":[\"Aseta aihe…\"],\"OkltoQ\":[\"Estä \",[\"username\"],\" nimimerkin perusteella (estää liittymisen samalla nimimerkillä)\"],\"P+t/Te\":[\"Ei lisätietoja\"],\"P42Wcc\":[\"Turvallinen\"],\"PD38l0\":[\"Kanavan avatarin esikatselu\"],\"PD9mEt\":[\"Kirjoita viesti...\"],\"PPqfdA\":[\"Avaa kanavan asetukset\"],\"PSCjfZ\":[\"Aihe, joka näytetään tälle kanavalle. Kaikki käyttäjät voivat nähdä aiheen.\"],\"PZCecv\":[\"PDF-esikatselu\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 kerta\"],\"other\":[[\"c\"],\" kertaa\"]}]],\"PguS2C\":[\"Lisää poikkeusmaski (esim. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Näytetään \",[\"displayedChannelsCount\"],\"/\",[\"0\"],\" kanavaa\"],\"PqhVlJ\":[\"Estä käyttäjä (hostmaskin perusteella)\"],\"Q+chwU\":[\"Käyttäjätunnus:\"],\"Q6hhn8\":[\"Asetukset\"],\"QF4a34\":[\"Syötä käyttäjänimi\"],\"QGqSZ2\":[\"Väri ja muotoilu\"],\"QJQd1J\":[\"Muokkaa profiilia\"],\"QSzGDE\":[\"Toimeton\"],\"QUlny5\":[\"Tervetuloa palvelimeen \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Lue lisää\"],\"QuSkCF\":[\"Suodata kanavia...\"],\"QwUrDZ\":[\"vaihtoi aiheen: \",[\"topic\"]],\"R0UH07\":[\"Kuva \",[\"0\"],\"/\",[\"1\"]],\"R7SsBE\":[\"Mykistä\"],\"R8rf1X\":[\"Aseta aihe napsauttamalla\"],\"RArB3D\":[\"potkittiin kanavalta \",[\"channelName\"],\" käyttäjän \",[\"username\"],\" toimesta\"],\"RI3cWd\":[\"Tutustu IRC:n maailmaan ObsidianIRC:llä\"],\"RMMaN5\":[\"Moderoitu (+m)\"],\"RWw9Lg\":[\"Sulje ikkuna\"],\"RZ2BuZ\":[\"Tilin \",[\"account\"],\" rekisteröinti vaatii vahvistuksen: \",[\"message\"]],\"RySp6q\":[\"Piilota kommentit\"],\"SPKQTd\":[\"Nimimerkki on pakollinen\"],\"SPVjfj\":[\"Oletusarvo on 'ei syytä', jos jätetään tyhjäksi\"],\"SQKPvQ\":[\"Kutsu käyttäjä\"],\"SkZcl+\":[\"Valitse valmis tulvasuojausprofiili. Nämä profiilit tarjoavat tasapainoisia suojausasetuksia eri käyttötarkoituksiin.\"],\"Slr+3C\":[\"Vähimmäiskäyttäjämäärä\"],\"Spnlre\":[\"Kutsuit \",[\"target\"],\" liittymään kanavalle \",[\"channel\"]],\"T/ckN5\":[\"Avaa katseluohjelmassa\"],\"T91vKp\":[\"Toista\"],\"TV2Wdu\":[\"Lue, miten käsittelemme tietojasi ja suojelemme yksityisyyttäsi.\"],\"TgFpwD\":[\"Käytetään...\"],\"TkzSFB\":[\"Ei muutoksia\"],\"TtserG\":[\"Syötä oikea nimi\"],\"Ttz9J1\":[\"Syötä salasana...\"],\"Tz0i8g\":[\"Asetukset\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagoi\"],\"UE4KO5\":[\"*kanava*\"],\"UGT5vp\":[\"Tallenna asetukset\"],\"UV5hLB\":[\"Estoja ei löydetty\"],\"Uaj3Nd\":[\"Tilaviestit\"],\"Ue3uny\":[\"Oletus (ei profiilia)\"],\"UkARhe\":[\"Normaali – tavallinen suojaus\"],\"Umn7Cj\":[\"Ei vielä kommentteja. Ole ensimmäinen!\"],\"UtUIRh\":[[\"0\"],\" vanhempaa viestiä\"],\"UwzP+U\":[\"Suojattu yhteys\"],\"V0/A4O\":[\"Kanavan omistaja\"],\"V4qgxE\":[\"Luotu aiemmin kuin (min sitten)\"],\"V8yTm6\":[\"Tyhjennä haku\"],\"VJMMyz\":[\"ObsidianIRC – IRC:n tulevaisuuteen\"],\"VJScHU\":[\"Syy\"],\"VLsmVV\":[\"Mykistä ilmoitukset\"],\"VbyRUy\":[\"Kommentit\"],\"Vmx0mQ\":[\"Asettanut:\"],\"VqnIZz\":[\"Lue tietosuojakäytäntömme ja tiedonkäsittelytapamme\"],\"VrMygG\":[\"Vähimmäispituus on \",[\"0\"]],\"VrnTui\":[\"Pronominisi, näytetään profiilissasi\"],\"W8E3qn\":[\"Tunnistautunut tili\"],\"WAakm9\":[\"Poista kanava\"],\"WFxTHC\":[\"Lisää porttikieltomaski (esim. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Palvelimen osoite on pakollinen\"],\"WRYdXW\":[\"Äänentoistoasento\"],\"WUOH5B\":[\"Estä käyttäjä\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Näytä 1 kohde lisää\"],\"other\":[\"Näytä \",[\"1\"],\" kohdetta lisää\"]}]],\"Weq9zb\":[\"Yleiset\"],\"Wfj7Sk\":[\"Mykistä tai poista mykistys ilmoitusäänistä\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Käyttäjäprofiili\"],\"X6S3lt\":[\"Hae asetuksia, kanavia, palvelimia...\"],\"XEHan5\":[\"Jatka silti\"],\"XI1+wb\":[\"Virheellinen muoto\"],\"XIXeuC\":[\"Viesti @\",[\"0\"]],\"XMS+k4\":[\"Aloita yksityiskeskustelu\"],\"XWgxXq\":[\"Albumi\"],\"Xd7+IT\":[\"Irrota yksityiskeskustelu\"],\"Xm/s+u\":[\"Näyttö\"],\"Xp2n93\":[\"Näyttää mediaa palvelimesi luotetusta tiedostoisännästä. Ulkoisille palveluille ei lähetetä pyyntöjä.\"],\"XvjC4F\":[\"Tallennetaan...\"],\"Y/qryO\":[\"Hakua vastaavia käyttäjiä ei löydetty\"],\"YAqRpI\":[\"Tilin \",[\"account\"],\" rekisteröinti onnistui: \",[\"message\"]],\"YEfzvP\":[\"Suojattu aihe (+t)\"],\"YQOn6a\":[\"Pienennä jäsenlista\"],\"YRCoE9\":[\"Kanavan operaattori\"],\"YURQaF\":[\"Näytä profiili\"],\"YdBSvr\":[\"Hallinnoi median näyttöä ja ulkoista sisältöä\"],\"Yj6U3V\":[\"Ei keskuspalvelinta:\"],\"YjvpGx\":[\"Pronominit\"],\"YqH4l4\":[\"Ei avainta\"],\"YyUPpV\":[\"Tili:\"],\"ZJSWfw\":[\"Viesti, joka näytetään katkaistessasi yhteyden palvelimeen\"],\"ZR1dJ4\":[\"Kutsut\"],\"ZdWg0V\":[\"Avaa selaimessa\"],\"ZhRBbl\":[\"Hae viestejä…\"],\"Zmcu3y\":[\"Lisäsuodattimet\"],\"a2/8e5\":[\"Aihe asetettu myöhemmin kuin (min sitten)\"],\"aHKcKc\":[\"Edellinen sivu\"],\"aJTbXX\":[\"Oper-salasana\"],\"aQryQv\":[\"Malli on jo olemassa\"],\"aW9pLN\":[\"Kanavalla sallittu enimmäiskäyttäjämäärä. Jätä tyhjäksi, jos rajaa ei haluta.\"],\"ah4fmZ\":[\"Näyttää myös esikatselut YouTubesta, Vimeosta, SoundCloudista ja vastaavista tunnetuista palveluista.\"],\"aifXak\":[\"Tällä kanavalla ei ole mediaa\"],\"ap2zBz\":[\"Löysä\"],\"az8lvo\":[\"Pois\"],\"azXSNo\":[\"Laajenna jäsenlista\"],\"azdliB\":[\"Kirjaudu tilille\"],\"b26wlF\":[\"hän/hänen\"],\"bD/+Ei\":[\"Tiukka\"],\"bQ6BJn\":[\"Määritä yksityiskohtaiset tulvasuojaussäännöt. Kukin sääntö määrittää, mitä toimintaa seurataan ja mitä tehdään kun raja-arvot ylitetään.\"],\"beV7+y\":[\"Käyttäjä saa kutsun liittyä kanavalle \",[\"channelName\"],\".\"],\"bk84cH\":[\"Poissaoloviesti\"],\"bkHdLj\":[\"Lisää IRC-palvelin\"],\"bmQLn5\":[\"Lisää sääntö\"],\"bwRvnp\":[\"Toiminto\"],\"c8+EVZ\":[\"Vahvistettu tili\"],\"cGYUlD\":[\"Median esikatseluja ei ladata.\"],\"cLF98o\":[\"Näytä kommentit (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Ei käyttäjiä saatavilla\"],\"cSgpoS\":[\"Kiinnitä yksityiskeskustelu\"],\"cde3ce\":[\"Viesti <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopioi muotoiltu tuloste\"],\"cl/A5J\":[\"Tervetuloa palvelimeen \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Poista\"],\"coPLXT\":[\"Emme tallenna IRC-viestintääsi palvelimillemme\"],\"crYH/6\":[\"SoundCloud-soitin\"],\"d3sis4\":[\"Lisää palvelin\"],\"d9aN5k\":[\"Poista \",[\"username\"],\" kanavalta\"],\"dEgA5A\":[\"Peruuta\"],\"dGi1We\":[\"Irrota tämä yksityisviestiketju\"],\"dJVuyC\":[\"poistui kanavalta \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"vastaanottaja\"],\"dXqxlh\":[\"<0>⚠️ Tietoturvariski!</0> Tämä yhteys voi olla alttiina salakuuntelulle tai välimieshyökkäyksille.\"],\"da9Q/R\":[\"Muutti kanavan asetuksia\"],\"dhJN3N\":[\"Näytä kommentit\"],\"dj2xTE\":[\"Hylkää ilmoitus\"],\"dpCzmC\":[\"Tulvasuojausasetukset\"],\"e9dQpT\":[\"Haluatko avata tämän linkin uudessa välilehdessä?\"],\"ePK91l\":[\"Muokkaa\"],\"eYBDuB\":[\"Lataa kuva tai anna URL, jossa voi käyttää valinnaista \",[\"size\"],\"-muuttujaa dynaamiseen kokoon\"],\"edBbee\":[\"Estä \",[\"username\"],\" hostmaskin perusteella (estää liittymisen samasta IP-osoitteesta/hostista)\"],\"ekfzWq\":[\"Käyttäjäasetukset\"],\"elPDWs\":[\"Mukauta IRC-asiakasohjelmaasi\"],\"eu2osY\":[\"<0>💡 Suositus:</0> Jatka vain jos luotat tähän palvelimeen ja ymmärrät riskit. Vältä arkaluonteisten tietojen tai salasanojen jakamista tämän yhteyden kautta.\"],\"euEhbr\":[\"Liity kanavalle \",[\"channel\"],\" napsauttamalla\"],\"ez3vLd\":[\"Ota monirivisyöttö käyttöön\"],\"f0J5Ki\":[\"Palvelimien välinen viestintä voi käyttää salaamattomia yhteyksiä\"],\"f9BHJk\":[\"Varoita käyttäjää\"],\"fDOLLd\":[\"Kanavia ei löydetty.\"],\"ffzDkB\":[\"Anonyymi analytiikka:\"],\"fq1GF9\":[\"Näytä kun käyttäjät katkaisevat yhteyden palvelimeen\"],\"gEF57C\":[\"Tämä palvelin tukee vain yhtä yhteystyyppiä\"],\"gJuLUI\":[\"Estolistа\"],\"gNzMrk\":[\"Nykyinen avatar\"],\"gjPWyO\":[\"Syötä nimimerkki...\"],\"gz6UQ3\":[\"Suurenna\"],\"h6razj\":[\"Sulje pois kanavan nimimaski\"],\"hG6jnw\":[\"Aihetta ei ole asetettu\"],\"hG89Ed\":[\"Kuva\"],\"hZ6znB\":[\"Portti\"],\"ha+Bz5\":[\"esim. 100:1440\"],\"hehnjM\":[\"Määrä\"],\"hzdLuQ\":[\"Vain käyttäjät, joilla on voice tai korkeampi, voivat puhua\"],\"i0qMbr\":[\"Koti\"],\"iDNBZe\":[\"Ilmoitukset\"],\"iH8pgl\":[\"Takaisin\"],\"iL9SZg\":[\"Estä käyttäjä (nimimerkin perusteella)\"],\"iNt+3c\":[\"Takaisin kuvaan\"],\"iQvi+a\":[\"Älä varoita minua tämän palvelimen heikosta linkkiturvallisuudesta\"],\"iSLIjg\":[\"Yhdistä\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Palvelimen osoite\"],\"idD8Ev\":[\"Tallennettu\"],\"iivqkW\":[\"Kirjautunut\"],\"ij+Elv\":[\"Kuvan esikatselu\"],\"ilIWp7\":[\"Näytä/piilota ilmoitukset\"],\"iuaqvB\":[\"Käytä * jokerimerkkinä. Esimerkkejä: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Botti\"],\"j2DGR0\":[\"Estä hostmaskin perusteella\"],\"jA4uoI\":[\"Aihe:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Syy (valinnainen)\"],\"jUV7CU\":[\"Lataa avatar\"],\"jW5Uwh\":[\"Hallinnoi ulkoisen median lataamista. Pois / Turvallinen / Luotetut lähteet / Kaikki sisältö.\"],\"jXzms5\":[\"Liitteet-valinnat\"],\"jZlrte\":[\"Väri\"],\"jfC/xh\":[\"Yhteystiedot\"],\"jywMpv\":[\"#uusi-kanavan-nimi\"],\"k112DD\":[\"Lataa vanhempia viestejä\"],\"k3ID0F\":[\"Suodata jäseniä…\"],\"k65gsE\":[\"Syvempi tarkastelu\"],\"k7Zgob\":[\"Peruuta yhteys\"],\"kAVx5h\":[\"Kutsuja ei löydetty\"],\"kCLEPU\":[\"Yhdistetty palvelimeen\"],\"kF5LKb\":[\"Estetyt mallit:\"],\"kGeOx/\":[\"Liity kanavalle \",[\"0\"]],\"kITKr8\":[\"Ladataan kanavan tiloja...\"],\"kPpPsw\":[\"Olet IRC-operaattori\"],\"kWJmRL\":[\"Sinä\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Kopioi JSON\"],\"krViRy\":[\"Napsauta kopioidaksesi JSON-muodossa\"],\"ks71ra\":[\"Poikkeukset\"],\"kw4lRv\":[\"Kanavan puolioperaattori\"],\"kxgIRq\":[\"Valitse kanava tai lisää uusi päästäksesi alkuun.\"],\"ky6dWe\":[\"Avatarin esikatselu\"],\"l+GxCv\":[\"Ladataan kanavia...\"],\"l+IUVW\":[\"Tilin \",[\"account\"],\" vahvistus onnistui: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"yhdisti uudelleen\"],\"other\":[\"yhdisti uudelleen \",[\"reconnectCount\"],\" kertaa\"]}]],\"l5jmzx\":[[\"0\"],\" ja \",[\"1\"],\" kirjoittavat...\"],\"lHy8N5\":[\"Ladataan lisää kanavia...\"],\"lbpf14\":[\"Liity kanavaan \",[\"value\"]],\"lfFsZ4\":[\"Kanavat\"],\"lkNdiH\":[\"Tilin nimi\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Lataa kuva\"],\"loQxaJ\":[\"Olen takaisin\"],\"lvfaxv\":[\"KOTI\"],\"m16xKo\":[\"Lisää\"],\"m8flAk\":[\"Esikatselu (ei vielä lähetetty)\"],\"mEPxTp\":[\"<0>⚠️ Ole varovainen!</0> Avaa linkkejä vain luotetuista lähteistä. Haitalliset linkit voivat vaarantaa tietoturvasi tai yksityisyytesi.\"],\"mHGdhG\":[\"Palvelimen tiedot\"],\"mHS8lb\":[\"Viesti #\",[\"0\"]],\"mMYBD9\":[\"Laaja – laajempi suojauslaajuus\"],\"mTGsPd\":[\"Kanavan aihe\"],\"mU8j6O\":[\"Ei ulkoisia viestejä (+n)\"],\"mZp8FL\":[\"Automaattinen palautuminen yksiriviseksi\"],\"mdQu8G\":[\"SinunNimimerkkisi\"],\"miSSBQ\":[\"Kommentit (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Käyttäjä on tunnistautunut\"],\"mwtcGl\":[\"Sulje kommentit\"],\"mzI/c+\":[\"Lataa\"],\"n3fGRk\":[\"asettaja: \",[\"0\"]],\"nE9jsU\":[\"Löysä – vähemmän aggressiivinen suojaus\"],\"nNflMD\":[\"Poistu kanavalta\"],\"nPXkBi\":[\"Ladataan WHOIS-tietoja...\"],\"nQnxxF\":[\"Viesti #\",[\"0\"],\" (Shift+Enter = uusi rivi)\"],\"nWMRxa\":[\"Irrota kiinnitys\"],\"nkC032\":[\"Ei tulvasuojausprofiilia\"],\"o69z4d\":[\"Lähetä varoitusviesti käyttäjälle \",[\"username\"]],\"o9ylQi\":[\"Hae GIF-kuvia aloittaaksesi\"],\"oFGkER\":[\"Palvelintiedotteet\"],\"oOi11l\":[\"Siirry loppuun\"],\"oQEzQR\":[\"Uusi DM\"],\"oXOSPE\":[\"Verkossa\"],\"oal760\":[\"Välimieshyökkäykset palvelinlinkeissä ovat mahdollisia\"],\"oeqmmJ\":[\"Luotetut lähteet\"],\"ovBPCi\":[\"Oletus\"],\"p0Z69r\":[\"Malli ei voi olla tyhjä\"],\"p1KgtK\":[\"Äänen lataaminen epäonnistui\"],\"p59pEv\":[\"Lisätiedot\"],\"p7sRI6\":[\"Ilmoita muille, kun kirjoitat\"],\"pBm1od\":[\"Salainen kanava\"],\"pNmiXx\":[\"Oletusnimimerkkisi kaikille palvelimille\"],\"pUUo9G\":[\"Isäntänimi:\"],\"pVGPmz\":[\"Tilin salasana\"],\"peNE68\":[\"Pysyvä\"],\"plhHQt\":[\"Ei tietoja\"],\"pm6+q5\":[\"Tietoturvavaroitus\"],\"pn5qSs\":[\"Lisätiedot\"],\"q0cR4S\":[\"on nyt tunnettu nimellä **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Kanava ei näy LIST- tai NAMES-komennoissa\"],\"qLpTm/\":[\"Poista reaktio \",[\"emoji\"]],\"qVkGWK\":[\"Kiinnitä\"],\"qY8wNa\":[\"Kotisivu\"],\"qb0xJ7\":[\"Käytä jokerimerkkejä: * vastaa mitä tahansa merkkijonoa, ? vastaa yhtä merkkiä. Esimerkkejä: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanavan avain (+k)\"],\"qtoOYG\":[\"Ei rajaa\"],\"r1W2AS\":[\"Tiedostopalvelimen kuva\"],\"rIPR2O\":[\"Aihe asetettu aiemmin kuin (min sitten)\"],\"rMMSYo\":[\"Enimmäispituus on \",[\"0\"]],\"rWtzQe\":[\"Verkko jakautui ja yhdistyi uudelleen. ✅\"],\"rYG2u6\":[\"Odota hetki...\"],\"rdUucN\":[\"Esikatselu\"],\"rjGI/Q\":[\"Yksityisyys\"],\"rk8iDX\":[\"Ladataan GIF-kuvia...\"],\"rn6SBY\":[\"Poista mykistys\"],\"s/UKqq\":[\"Poistettiin kanavalta\"],\"s8cATI\":[\"liittyi kanavalle \",[\"channelName\"]],\"sCO9ue\":[\"Yhteydessä palvelimeen <0>\",[\"serverName\"],\"</0> on seuraavia tietoturvaongelmia:\"],\"sGH11W\":[\"Palvelin\"],\"sHI1H+\":[\"on nyt tunnettu nimellä **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" kutsui sinut liittymään kanavalle \",[\"channel\"]],\"sby+1/\":[\"Kopioi napsauttamalla\"],\"sfN25C\":[\"Oikea nimesi tai koko nimesi\"],\"sliuzR\":[\"Avaa linkki\"],\"sqrO9R\":[\"Mukautetut maininnat\"],\"sr6RdJ\":[\"Monirivinen Shift+Enter-painikkeella\"],\"swrCpB\":[\"Kanava on nimetty uudelleen nimestä \",[\"oldName\"],\" nimeen \",[\"newName\"],\" käyttäjän \",[\"user\"],\" toimesta\",[\"0\"]],\"sxkWRg\":[\"Lisäasetukset\"],\"t/YqKh\":[\"Poista\"],\"t47eHD\":[\"Yksilöllinen tunnuksesi tällä palvelimella\"],\"tAkAh0\":[\"URL, jossa valinnainen \",[\"size\"],\"-muuttuja dynaamista kokoa varten. Esimerkki: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Näytä tai piilota kanavaluettelon sivupalkki\"],\"tfDRzk\":[\"Tallenna\"],\"tiBsJk\":[\"poistui kanavalta \",[\"channelName\"]],\"tt4/UD\":[\"poistui (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Nimimerkki {nick} on jo käytössä, yritetään uudelleen nimellä {newNick}\"],\"u0a8B4\":[\"Tunnistaudu IRC-operaattoriksi ylläpito-oikeuksia varten\"],\"u0rWFU\":[\"Luotu myöhemmin kuin (min sitten)\"],\"u72w3t\":[\"Estettävät käyttäjät ja mallit\"],\"u7jc2L\":[\"poistui\"],\"uAQUqI\":[\"Tila\"],\"uB85T3\":[\"Tallennus epäonnistui: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-palvelimet:\"],\"usSSr/\":[\"Zoomaustaso\"],\"v7uvcf\":[\"Ohjelmisto:\"],\"vE8kb+\":[\"Käytä Shift+Enter uudelle riville (Enter lähettää)\"],\"vERlcd\":[\"Profiili\"],\"vK0RL8\":[\"Ei aihetta\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Kieli\"],\"vaHYxN\":[\"Oikea nimi\"],\"vhjbKr\":[\"Poissa\"],\"w4NYox\":[[\"title\"],\" asiakasohjelma\"],\"w8xQRx\":[\"Virheellinen arvo\"],\"wFjjxZ\":[\"potkittiin kanavalta \",[\"channelName\"],\" käyttäjän \",[\"username\"],\" toimesta (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Porttikieltopoikkeuksia ei löydetty\"],\"wPrGnM\":[\"Kanavan ylläpitäjä\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Näytä kun käyttäjät liittyvät kanavalle tai poistuvat\"],\"whqZ9r\":[\"Lisäsanat tai -lauseet korostettavaksi\"],\"wm7RV4\":[\"Ilmoitusääni\"],\"wz/Yoq\":[\"Viestisi voidaan siepata palvelimien välillä välitettäessä\"],\"xCJdfg\":[\"Tyhjennä\"],\"xUHRTR\":[\"Tunnistaudu automaattisesti operaattoriksi yhdistäessä\"],\"xWHwwQ\":[\"Estot\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Vain suojatut WebSocket-yhteydet ovat tuettuja\"],\"xdtXa+\":[\"kanavan-nimi\"],\"xfXC7q\":[\"Tekstikanavat\"],\"xlCYOE\":[\"Haetaan lisää viestejä...\"],\"xlhswE\":[\"Vähimmäisarvo on \",[\"0\"]],\"xq97Ci\":[\"Lisää sana tai lause...\"],\"xuRqRq\":[\"Käyttäjäraja (+l)\"],\"xwF+7J\":[[\"0\"],\" kirjoittaa...\"],\"yNeucF\":[\"Tämä palvelin ei tue laajennettua profiilimetadataa (IRCv3 METADATA -laajennus). Lisäkentät kuten avatar, näyttönimi ja tila eivät ole käytettävissä.\"],\"yPlrca\":[\"Kanavan avatar\"],\"yQE2r9\":[\"Ladataan\"],\"ySU+JY\":[\"sinun@sahkoposti.fi\"],\"yTX1Rt\":[\"Oper-käyttäjänimi\"],\"yYOzWD\":[\"lokit\"],\"yfx9Re\":[\"IRC-operaattorin salasana\"],\"ygCKqB\":[\"Pysäytä\"],\"ymDxJx\":[\"IRC-operaattorin käyttäjänimi\"],\"yrpRsQ\":[\"Lajittele nimen mukaan\"],\"yz7wBu\":[\"Sulje\"],\"zJw+jA\":[\"asettaa tilan: \",[\"0\"]],\"zebeLu\":[\"Syötä oper-käyttäjänimi\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Virheellinen mallimuoto. Käytä nick!käyttäjä@isäntä-muotoa (jokerimerkit * sallittu)\"],\"+6NQQA\":[\"Yleinen tukikanava\"],\"+6NyRG\":[\"Asiakasohjelma\"],\"+K0AvT\":[\"Katkaise yhteys\"],\"+cyFdH\":[\"Oletusviesti poissaoloilmoitukselle\"],\"+mVPqU\":[\"Näytä Markdown-muotoilu viesteissä\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Tilin käyttäjänimi tunnistautumista varten\"],\"+yPBXI\":[\"Valitse tiedosto\"],\"+zy2Nq\":[\"Tyyppi\"],\"/09cao\":[\"Heikko linkkiturvallisuus (taso \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Kanavan ulkopuoliset käyttäjät eivät voi lähettää viestejä sille\"],\"/6BzZF\":[\"Näytä/piilota jäsenlista\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Käyttäjä on poissa\"],\"/XQgft\":[\"Selaa\"],\"/cF7Rs\":[\"Äänenvoimakkuus\"],\"/dqduX\":[\"Seuraava sivu\"],\"/fc3q4\":[\"Kaikki sisältö\"],\"/kISDh\":[\"Ota ilmoitusäänet käyttöön\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Ääni\"],\"/rfkZe\":[\"Toista ääniä maininnoista ja viesteistä\"],\"0/0ZGA\":[\"Kanavan nimimaski\"],\"0D6j7U\":[\"Lue lisää mukautetuista säännöistä →\"],\"0XsHcR\":[\"Poista käyttäjä\"],\"0ZpE//\":[\"Lajittele käyttäjämäärän mukaan\"],\"0bEPwz\":[\"Aseta poissa\"],\"0dGkPt\":[\"Laajenna kanavaluettelo\"],\"0gS7M5\":[\"Näyttönimi\"],\"0kS+M8\":[\"EsimerkKi\"],\"0rgoY7\":[\"Yhdistä vain valitsemiisi palvelimiin\"],\"0wdd7X\":[\"Liity\"],\"0wkVYx\":[\"Yksityisviestit\"],\"111uHX\":[\"Linkin esikatselu\"],\"196EG4\":[\"Poista yksityiskeskustelu\"],\"1DSr1i\":[\"Rekisteröidy tilille\"],\"1O/24y\":[\"Näytä/piilota kanavaluettelo\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Ulkoisen linkin varoitus\"],\"1ZC/dv\":[\"Ei lukemattomia mainintoja tai viestejä\"],\"1pO1zi\":[\"Palvelimen nimi on pakollinen\"],\"1uwfzQ\":[\"Näytä kanavan aihe\"],\"268g7c\":[\"Syötä näyttönimi\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Verkon palvelinoperaattorit voisivat mahdollisesti lukea viestisi\"],\"2FYpfJ\":[\"Lisää\"],\"2HF1Y2\":[[\"inviter\"],\" kutsui \",[\"target\"],\" liittymään kanavalle \",[\"channel\"]],\"2I70QL\":[\"Näytä käyttäjäprofiilitiedot\"],\"2QYdmE\":[\"Käyttäjät:\"],\"2QpEjG\":[\"poistui\"],\"2YE223\":[\"Viesti #\",[\"0\"],\" (Enter = uusi rivi, Shift+Enter = lähetä)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Käytä palvelimen salasanaa\"],\"2iTmdZ\":[\"Paikallinen tallennustila:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Tiukka – aggressiivisempi suojaus\"],\"2uDhbA\":[\"Syötä kutsuttavan käyttäjänimi\"],\"2ygf/L\":[\"← Takaisin\"],\"2zEgxj\":[\"Hae GIF-kuvia...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Nimeä kanava uudelleen\"],\"3THokf\":[\"Voice-käyttäjä\"],\"3TSz9S\":[\"Pienennä\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Kanavan näyttönimi\"],\"3ryuFU\":[\"Valinnaiset kaatumisraportit sovelluksen parantamiseksi\"],\"3uBF/8\":[\"Sulje katseluohjelma\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Syötä tilin nimi...\"],\"4/Rr0R\":[\"Kutsu käyttäjä nykyiselle kanavalle\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Säännöt\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Tulvasuojausprofiili (+F)\"],\"4RZQRK\":[\"Mitä olet tekemässä?\"],\"4hfTrB\":[\"Nimimerkki\"],\"4n99LO\":[\"Jo kanavalla \",[\"0\"]],\"4t6vMV\":[\"Vaihda automaattisesti yksiriviseen lyhyille viesteille\"],\"4vsHmf\":[\"Aika (min)\"],\"5+INAX\":[\"Korosta viestit, joissa mainitaan sinut\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper-nimi\"],\"678PKt\":[\"Verkon nimi\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Poissa verkosta\"],\"6CO3WE\":[\"Salasana vaaditaan kanavalle liittymiseen. Jätä tyhjäksi poistaaksesi avaimen.\"],\"6HhMs3\":[\"Lähtöviesti\"],\"6V3Ea3\":[\"Kopioitu\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Näytä vähemmän\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Syötä oper-salasana...\"],\"7+IHTZ\":[\"Ei valittua tiedostoa\"],\"73hrRi\":[\"nick!käyttäjä@isäntä (esim. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Lähetä yksityisviesti\"],\"7U1W7c\":[\"Hyvin löysä\"],\"7Y1YQj\":[\"Oikea nimi:\"],\"7YHArF\":[\"— avaa katseluohjelmassa\"],\"7fjnVl\":[\"Hae käyttäjiä...\"],\"7jL88x\":[\"Poistetaanko tämä viesti? Toimintoa ei voi kumota.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Mitä ajattelet?\"],\"7sEpu1\":[\"Jäsenet — \",[\"0\"]],\"7sNhEz\":[\"Käyttäjänimi\"],\"8H0Q+x\":[\"Lue lisää profiileista →\"],\"8Phu0A\":[\"Näytä kun käyttäjät vaihtavat nimimerkkinsä\"],\"8XTG9e\":[\"Syötä oper-salasana\"],\"8XsV2J\":[\"Yritä lähettää uudelleen\"],\"8ZsakT\":[\"Salasana\"],\"8kR84m\":[\"Olet avaamassa ulkoista linkkiä:\"],\"8lCgih\":[\"Poista sääntö\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"liittyi\"],\"other\":[\"liittyi \",[\"joinCount\"],\" kertaa\"]}]],\"9BMLnJ\":[\"Yhdistä uudelleen palvelimeen\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Lisää reaktio\"],\"9PQ8m2\":[\"G-Line (maailmanlaajuinen esto)\"],\"9Qs99X\":[\"Sähköposti:\"],\"9QupBP\":[\"Poista malli\"],\"9bG48P\":[\"Lähetetään\"],\"9f5f0u\":[\"Yksityisyyteen liittyviä kysymyksiä? Ota yhteyttä:\"],\"9unqs3\":[\"Poissa:\"],\"9v3hwv\":[\"Palvelimia ei löydetty.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Yhdistetään\"],\"A1taO8\":[\"Hae\"],\"A2adVi\":[\"Lähetä kirjoitusilmoituksia\"],\"A9Rhec\":[\"Kanavan nimi\"],\"AWOSPo\":[\"Lähennä\"],\"AXSpEQ\":[\"Oper yhdistäessä\"],\"AeXO77\":[\"Tili\"],\"AhNP40\":[\"Selaa\"],\"Ai2U7L\":[\"Isäntä\"],\"AjBQnf\":[\"Vaihtoi nimimerkin\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Peruuta vastaus\"],\"ApSx0O\":[\"Löydettiin \",[\"0\"],\" viestiä, jotka vastaavat \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Tuloksia ei löydetty\"],\"AyNqAB\":[\"Näytä kaikki palvelintapahtumat chatissa\"],\"B/QqGw\":[\"Poissa näppäimistöltä\"],\"B8AaMI\":[\"Tämä kenttä on pakollinen\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Palvelin ei tue kehittynyttä LIST-suodatusta\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" ja \",[\"3\"],\" muuta kirjoittavat...\"],\"BGul2A\":[\"Sinulla on tallentamattomia muutoksia. Haluatko varmasti sulkea tallentamatta?\"],\"BIf9fi\":[\"Tilaviestisi\"],\"BZz3md\":[\"Henkilökohtainen verkkosivustosi\"],\"Bgm/H7\":[\"Salli monirivisyöttö\"],\"BiQIl1\":[\"Kiinnitä tämä yksityisviesteistä\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Siirry viestiin napsauttamalla\"],\"Bowq3c\":[\"Vain operaattorit voivat muuttaa kanavan aihetta\"],\"Btozzp\":[\"Tämä kuva on vanhentunut\"],\"Bycfjm\":[\"Yhteensä: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Kopioi koko JSON\"],\"C9L9wL\":[\"Tiedonkeruu\"],\"CDq4wC\":[\"Moderoi käyttäjää\"],\"CHVRxG\":[\"Viesti @\",[\"0\"],\" (Shift+Enter = uusi rivi)\"],\"CN9zdR\":[\"Oper-nimi ja salasana ovat pakollisia\"],\"CW3sYa\":[\"Lisää reaktio \",[\"emoji\"]],\"CaAkqd\":[\"Näytä poistumisviestit\"],\"CbvaYj\":[\"Estä nimimerkin perusteella\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Valitse kanava\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normaali\"],\"D+NlUC\":[\"Järjestelmä\"],\"D28t6+\":[\"liittyi ja poistui\"],\"DB8zMK\":[\"Käytä\"],\"DBcWHr\":[\"Mukautettu ilmoitusäänitiedosto\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Median esikatselut\"],\"Dj4pSr\":[\"Valitse turvallinen salasana\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Haetaan...\"],\"Du2T2f\":[\"Asetusta ei löydetty\"],\"DwsSVQ\":[\"Käytä suodattimet ja päivitä\"],\"E3W/zd\":[\"Oletusnimimerkki\"],\"E6nRW7\":[\"Kopioi URL\"],\"E703RG\":[\"Tilat:\"],\"EAeu1Z\":[\"Lähetä kutsu\"],\"EFKJQT\":[\"Asetus\"],\"EGPQBv\":[\"Mukautetut tulvasäännöt (+f)\"],\"ELik0r\":[\"Lue koko tietosuojakäytäntö\"],\"EPbeC2\":[\"Näytä tai muokkaa kanavan aihetta\"],\"EQCDNT\":[\"Syötä oper-käyttäjätunnus...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Löydettiin 1 viesti, joka vastaa \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Seuraava kuva\"],\"EdQY6l\":[\"Ei mitään\"],\"EnqLYU\":[\"Hae palvelimia...\"],\"F0OKMc\":[\"Muokkaa palvelinta\"],\"F6Int2\":[\"Ota korostukset käyttöön\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Enimmäiskäyttäjämäärä\"],\"FUU/hZ\":[\"Hallitsee, kuinka paljon ulkoista mediaa ladataan chatissa.\"],\"Fdp03t\":[\"päälle\"],\"FfPWR0\":[\"Ikkuna\"],\"FjkaiT\":[\"Loitonna\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Mitä tämä tarkoittaa:\"],\"FolHNl\":[\"Hallinnoi tiliäsi ja tunnistautumista\"],\"Fp2Dif\":[\"Poistui palvelimelta\"],\"G5KmCc\":[\"GZ-Line (maailmanlaajuinen Z-Line)\"],\"GDs0lz\":[\"<0>Riski:</0> Arkaluonteiset tiedot (viestit, yksityiskeskustelut, tunnistautumistiedot) voisivat paljastua verkon ylläpitäjille tai hyökkääjille, jotka sijaitsevat IRC-palvelimien välissä.\"],\"GR+2I3\":[\"Lisää kutsumask (esim. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Sulje irrotettu palvelintiedotteet-näkymä\"],\"GlHnXw\":[\"Nimen vaihto epäonnistui: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Esikatselu:\"],\"GtmO8/\":[\"lähettäjä\"],\"GtuHUQ\":[\"Nimeä tämä kanava uudelleen palvelimella. Kaikki käyttäjät näkevät uuden nimen.\"],\"GuGfFX\":[\"Näytä/piilota haku\"],\"GxkJXS\":[\"Ladataan...\"],\"GzbwnK\":[\"Liittyi kanavalle\"],\"GzsUDB\":[\"Laajennettu profiili\"],\"H/PnT8\":[\"Lisää emoji\"],\"H6Izzl\":[\"Suosikki värikoodisi\"],\"H9jIv+\":[\"Näytä liittymiset/poistumiset\"],\"HAKBY9\":[\"Lataa tiedostoja\"],\"HdE1If\":[\"Kanava\"],\"Hk4AW9\":[\"Suosikki näyttönimesi\"],\"HmHDk7\":[\"Valitse jäsen\"],\"HrQzPU\":[\"Kanavat verkossa \",[\"networkName\"]],\"I2tXQ5\":[\"Viesti @\",[\"0\"],\" (Enter = uusi rivi, Shift+Enter = lähetä)\"],\"I6bw/h\":[\"Estä käyttäjä\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Ota ilmoitukset käyttöön\"],\"I9D72S\":[\"Haluatko varmasti poistaa tämän viestin? Tätä toimintoa ei voi kumota.\"],\"IA+1wo\":[\"Näytä kun käyttäjiä poistetaan kanavilta\"],\"IDwkJx\":[\"IRC-operaattori\"],\"ILlU+s\":[\"Tiedot:\"],\"IUwGEM\":[\"Tallenna muutokset\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" ja \",[\"2\"],\" kirjoittavat...\"],\"IgrLD/\":[\"Tauko\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Vastaa\"],\"IoHMnl\":[\"Enimmäisarvo on \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Yhdistetään...\"],\"J5T9NW\":[\"Käyttäjätiedot\"],\"J8Y5+z\":[\"Hups! Verkon jako! ⚠️\"],\"JBHkBA\":[\"Poistui kanavalta\"],\"JCwL0Q\":[\"Syötä syy (valinnainen)\"],\"JFciKP\":[\"Vaihda\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Muuta kanavan nimeä (vain operaattorit)\"],\"JcD7qf\":[\"Lisää toimintoja\"],\"JdkA+c\":[\"Salainen (+s)\"],\"Jmu12l\":[\"Palvelimen kanavat\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Ota Markdown käyttöön\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"WHOIS-tietoja ei saatavilla\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Poista viesti\"],\"KKBlUU\":[\"Upotus\"],\"KM0pLb\":[\"Tervetuloa kanavalle!\"],\"KR6W2h\":[\"Poista esto käyttäjältä\"],\"KV+Bi1\":[\"Vain kutsulla (+i)\"],\"KdCtwE\":[\"Kuinka monta sekuntia tulvatoimintaa seurataan ennen laskurien nollaamista\"],\"Kkezga\":[\"Palvelimen salasana\"],\"KsiQ/8\":[\"Käyttäjät täytyy kutsua kanavalle\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Kanavan tiedot\"],\"LC1a7n\":[\"IRC-palvelin on ilmoittanut, että sen palvelimien väliset linkit ovat heikosti suojattuja. Tämä tarkoittaa, että verkossa välitettäviä viestejäsi ei välttämättä salata asianmukaisesti tai SSL/TLS-sertifikaatteja ei tarkisteta oikein.\"],\"LNfLR5\":[\"Näytä poistamiset\"],\"LQb0W/\":[\"Näytä kaikki tapahtumat\"],\"LU7/yA\":[\"Vaihtoehtoinen nimi käyttöliittymässä näytettäväksi. Voi sisältää välilyöntejä, emojeja ja erikoismerkkejä. Todellista kanavan nimeä (\",[\"channelName\"],\") käytetään edelleen IRC-komennoissa.\"],\"LUb9O7\":[\"Kelvollinen palvelinportti on pakollinen\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Tietosuojakäytäntö\"],\"LcuSDR\":[\"Hallinnoi profiilitietojasi ja metatietoja\"],\"LqLS9B\":[\"Näytä nimimerkinvaihdot\"],\"LsDQt2\":[\"Kanavan asetukset\"],\"LtI9AS\":[\"Omistaja\"],\"LuNhhL\":[\"reagoi tähän viestiin\"],\"M/AZNG\":[\"URL avatar-kuvaasi\"],\"M/WIer\":[\"Lähetä viesti\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Nimi:\"],\"MHk+7g\":[\"Edellinen kuva\"],\"MRorGe\":[\"Lähetä viesti käyttäjälle\"],\"MVbSGP\":[\"Aikaikkuna (sekuntia)\"],\"MkpcsT\":[\"Viestisi ja asetuksesi tallennetaan paikallisesti laitteellesi\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Merkitse botiksi – yleensä 'on' tai tyhjä\"],\"N7TQbE\":[\"Kutsu käyttäjä kanavalle \",[\"channelName\"]],\"NCca/o\":[\"Syötä oletusnimimerkki...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Näyttää kaiken ulkoisen median. Mikä tahansa URL voi aiheuttaa yhteyspyynnön tuntemattomalle palvelimelle.\"],\"Nt+9O7\":[\"Käytä WebSocket-yhteyttä TCP:n sijaan\"],\"NxIHzc\":[\"Katkaise yhteys\"],\"O+v/cL\":[\"Selaa kaikkia palvelimen kanavia\"],\"ODwSCk\":[\"Lähetä GIF\"],\"OGQ5kK\":[\"Määritä ilmoitusäänet ja korostukset\"],\"OIPt1Z\":[\"Näytä tai piilota jäsenlistan sivupalkki\"],\"OKSNq/\":[\"Hyvin tiukka\"],\"ONWvwQ\":[\"Lähetä\"],\"OVKoQO\":[\"Tilin salasana tunnistautumista varten\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Viedään IRC tulevaisuuteen\"],\"OhCpra\":[\"Aseta aihe…\"],\"OkltoQ\":[\"Estä \",[\"username\"],\" nimimerkin perusteella (estää liittymisen samalla nimimerkillä)\"],\"P+t/Te\":[\"Ei lisätietoja\"],\"P42Wcc\":[\"Turvallinen\"],\"PD38l0\":[\"Kanavan avatarin esikatselu\"],\"PD9mEt\":[\"Kirjoita viesti...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Avaa kanavan asetukset\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Aihe, joka näytetään tälle kanavalle. Kaikki käyttäjät voivat nähdä aiheen.\"],\"PZCecv\":[\"PDF-esikatselu\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 kerta\"],\"other\":[[\"c\"],\" kertaa\"]}]],\"PguS2C\":[\"Lisää poikkeusmaski (esim. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Näytetään \",[\"displayedChannelsCount\"],\"/\",[\"0\"],\" kanavaa\"],\"PqhVlJ\":[\"Estä käyttäjä (hostmaskin perusteella)\"],\"Q+chwU\":[\"Käyttäjätunnus:\"],\"Q6hhn8\":[\"Asetukset\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Syötä käyttäjänimi\"],\"QGqSZ2\":[\"Väri ja muotoilu\"],\"QJQd1J\":[\"Muokkaa profiilia\"],\"QSzGDE\":[\"Toimeton\"],\"QUlny5\":[\"Tervetuloa palvelimeen \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Lue lisää\"],\"QuSkCF\":[\"Suodata kanavia...\"],\"QwUrDZ\":[\"vaihtoi aiheen: \",[\"topic\"]],\"R0UH07\":[\"Kuva \",[\"0\"],\"/\",[\"1\"]],\"R7SsBE\":[\"Mykistä\"],\"R8rf1X\":[\"Aseta aihe napsauttamalla\"],\"RArB3D\":[\"potkittiin kanavalta \",[\"channelName\"],\" käyttäjän \",[\"username\"],\" toimesta\"],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Tutustu IRC:n maailmaan ObsidianIRC:llä\"],\"RMMaN5\":[\"Moderoitu (+m)\"],\"RWw9Lg\":[\"Sulje ikkuna\"],\"RZ2BuZ\":[\"Tilin \",[\"account\"],\" rekisteröinti vaatii vahvistuksen: \",[\"message\"]],\"RySp6q\":[\"Piilota kommentit\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Nimimerkki on pakollinen\"],\"SPVjfj\":[\"Oletusarvo on 'ei syytä', jos jätetään tyhjäksi\"],\"SQKPvQ\":[\"Kutsu käyttäjä\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Valitse valmis tulvasuojausprofiili. Nämä profiilit tarjoavat tasapainoisia suojausasetuksia eri käyttötarkoituksiin.\"],\"Slr+3C\":[\"Vähimmäiskäyttäjämäärä\"],\"Spnlre\":[\"Kutsuit \",[\"target\"],\" liittymään kanavalle \",[\"channel\"]],\"T/ckN5\":[\"Avaa katseluohjelmassa\"],\"T91vKp\":[\"Toista\"],\"TV2Wdu\":[\"Lue, miten käsittelemme tietojasi ja suojelemme yksityisyyttäsi.\"],\"TgFpwD\":[\"Käytetään...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Ei muutoksia\"],\"TtserG\":[\"Syötä oikea nimi\"],\"Ttz9J1\":[\"Syötä salasana...\"],\"Tz0i8g\":[\"Asetukset\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagoi\"],\"UE4KO5\":[\"*kanava*\"],\"UGT5vp\":[\"Tallenna asetukset\"],\"UV5hLB\":[\"Estoja ei löydetty\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Tilaviestit\"],\"Ue3uny\":[\"Oletus (ei profiilia)\"],\"UkARhe\":[\"Normaali – tavallinen suojaus\"],\"Umn7Cj\":[\"Ei vielä kommentteja. Ole ensimmäinen!\"],\"UtUIRh\":[[\"0\"],\" vanhempaa viestiä\"],\"UwzP+U\":[\"Suojattu yhteys\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Kanavan omistaja\"],\"V4qgxE\":[\"Luotu aiemmin kuin (min sitten)\"],\"V8yTm6\":[\"Tyhjennä haku\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC – IRC:n tulevaisuuteen\"],\"VJScHU\":[\"Syy\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Mykistä ilmoitukset\"],\"VbyRUy\":[\"Kommentit\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Asettanut:\"],\"VqnIZz\":[\"Lue tietosuojakäytäntömme ja tiedonkäsittelytapamme\"],\"VrMygG\":[\"Vähimmäispituus on \",[\"0\"]],\"VrnTui\":[\"Pronominisi, näytetään profiilissasi\"],\"W8E3qn\":[\"Tunnistautunut tili\"],\"WAakm9\":[\"Poista kanava\"],\"WFxTHC\":[\"Lisää porttikieltomaski (esim. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Palvelimen osoite on pakollinen\"],\"WRYdXW\":[\"Äänentoistoasento\"],\"WUOH5B\":[\"Estä käyttäjä\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Näytä 1 kohde lisää\"],\"other\":[\"Näytä \",[\"1\"],\" kohdetta lisää\"]}]],\"Weq9zb\":[\"Yleiset\"],\"Wfj7Sk\":[\"Mykistä tai poista mykistys ilmoitusäänistä\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Käyttäjäprofiili\"],\"X6S3lt\":[\"Hae asetuksia, kanavia, palvelimia...\"],\"XEHan5\":[\"Jatka silti\"],\"XI1+wb\":[\"Virheellinen muoto\"],\"XIXeuC\":[\"Viesti @\",[\"0\"]],\"XMS+k4\":[\"Aloita yksityiskeskustelu\"],\"XWgxXq\":[\"Albumi\"],\"Xd7+IT\":[\"Irrota yksityiskeskustelu\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Näyttö\"],\"Xp2n93\":[\"Näyttää mediaa palvelimesi luotetusta tiedostoisännästä. Ulkoisille palveluille ei lähetetä pyyntöjä.\"],\"XvjC4F\":[\"Tallennetaan...\"],\"Y/qryO\":[\"Hakua vastaavia käyttäjiä ei löydetty\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Tilin \",[\"account\"],\" rekisteröinti onnistui: \",[\"message\"]],\"YEfzvP\":[\"Suojattu aihe (+t)\"],\"YQOn6a\":[\"Pienennä jäsenlista\"],\"YRCoE9\":[\"Kanavan operaattori\"],\"YURQaF\":[\"Näytä profiili\"],\"YdBSvr\":[\"Hallinnoi median näyttöä ja ulkoista sisältöä\"],\"Yj6U3V\":[\"Ei keskuspalvelinta:\"],\"YjvpGx\":[\"Pronominit\"],\"YqH4l4\":[\"Ei avainta\"],\"YyUPpV\":[\"Tili:\"],\"ZJSWfw\":[\"Viesti, joka näytetään katkaistessasi yhteyden palvelimeen\"],\"ZR1dJ4\":[\"Kutsut\"],\"ZdWg0V\":[\"Avaa selaimessa\"],\"ZhRBbl\":[\"Hae viestejä…\"],\"Zmcu3y\":[\"Lisäsuodattimet\"],\"a2/8e5\":[\"Aihe asetettu myöhemmin kuin (min sitten)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Edellinen sivu\"],\"aJTbXX\":[\"Oper-salasana\"],\"aQryQv\":[\"Malli on jo olemassa\"],\"aW9pLN\":[\"Kanavalla sallittu enimmäiskäyttäjämäärä. Jätä tyhjäksi, jos rajaa ei haluta.\"],\"ah4fmZ\":[\"Näyttää myös esikatselut YouTubesta, Vimeosta, SoundCloudista ja vastaavista tunnetuista palveluista.\"],\"aifXak\":[\"Tällä kanavalla ei ole mediaa\"],\"ap2zBz\":[\"Löysä\"],\"az8lvo\":[\"Pois\"],\"azXSNo\":[\"Laajenna jäsenlista\"],\"azdliB\":[\"Kirjaudu tilille\"],\"b26wlF\":[\"hän/hänen\"],\"bD/+Ei\":[\"Tiukka\"],\"bQ6BJn\":[\"Määritä yksityiskohtaiset tulvasuojaussäännöt. Kukin sääntö määrittää, mitä toimintaa seurataan ja mitä tehdään kun raja-arvot ylitetään.\"],\"beV7+y\":[\"Käyttäjä saa kutsun liittyä kanavalle \",[\"channelName\"],\".\"],\"bk84cH\":[\"Poissaoloviesti\"],\"bkHdLj\":[\"Lisää IRC-palvelin\"],\"bmQLn5\":[\"Lisää sääntö\"],\"bwRvnp\":[\"Toiminto\"],\"c8+EVZ\":[\"Vahvistettu tili\"],\"cGYUlD\":[\"Median esikatseluja ei ladata.\"],\"cLF98o\":[\"Näytä kommentit (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Ei käyttäjiä saatavilla\"],\"cSgpoS\":[\"Kiinnitä yksityiskeskustelu\"],\"cde3ce\":[\"Viesti <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopioi muotoiltu tuloste\"],\"cl/A5J\":[\"Tervetuloa palvelimeen \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Poista\"],\"coPLXT\":[\"Emme tallenna IRC-viestintääsi palvelimillemme\"],\"crYH/6\":[\"SoundCloud-soitin\"],\"d3sis4\":[\"Lisää palvelin\"],\"d9aN5k\":[\"Poista \",[\"username\"],\" kanavalta\"],\"dEgA5A\":[\"Peruuta\"],\"dGi1We\":[\"Irrota tämä yksityisviestiketju\"],\"dJVuyC\":[\"poistui kanavalta \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"vastaanottaja\"],\"dXqxlh\":[\"<0>⚠️ Tietoturvariski!</0> Tämä yhteys voi olla alttiina salakuuntelulle tai välimieshyökkäyksille.\"],\"da9Q/R\":[\"Muutti kanavan asetuksia\"],\"dhJN3N\":[\"Näytä kommentit\"],\"dj2xTE\":[\"Hylkää ilmoitus\"],\"dpCzmC\":[\"Tulvasuojausasetukset\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Haluatko avata tämän linkin uudessa välilehdessä?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Muokkaa\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Lataa kuva tai anna URL, jossa voi käyttää valinnaista \",[\"size\"],\"-muuttujaa dynaamiseen kokoon\"],\"edBbee\":[\"Estä \",[\"username\"],\" hostmaskin perusteella (estää liittymisen samasta IP-osoitteesta/hostista)\"],\"ekfzWq\":[\"Käyttäjäasetukset\"],\"elPDWs\":[\"Mukauta IRC-asiakasohjelmaasi\"],\"eu2osY\":[\"<0>💡 Suositus:</0> Jatka vain jos luotat tähän palvelimeen ja ymmärrät riskit. Vältä arkaluonteisten tietojen tai salasanojen jakamista tämän yhteyden kautta.\"],\"euEhbr\":[\"Liity kanavalle \",[\"channel\"],\" napsauttamalla\"],\"ez3vLd\":[\"Ota monirivisyöttö käyttöön\"],\"f0J5Ki\":[\"Palvelimien välinen viestintä voi käyttää salaamattomia yhteyksiä\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Varoita käyttäjää\"],\"fDOLLd\":[\"Kanavia ei löydetty.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonyymi analytiikka:\"],\"fq1GF9\":[\"Näytä kun käyttäjät katkaisevat yhteyden palvelimeen\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Tämä palvelin tukee vain yhtä yhteystyyppiä\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Estolistа\"],\"gNzMrk\":[\"Nykyinen avatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Syötä nimimerkki...\"],\"gz6UQ3\":[\"Suurenna\"],\"h6razj\":[\"Sulje pois kanavan nimimaski\"],\"hG6jnw\":[\"Aihetta ei ole asetettu\"],\"hG89Ed\":[\"Kuva\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Portti\"],\"ha+Bz5\":[\"esim. 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Määrä\"],\"hzdLuQ\":[\"Vain käyttäjät, joilla on voice tai korkeampi, voivat puhua\"],\"i0qMbr\":[\"Koti\"],\"iDNBZe\":[\"Ilmoitukset\"],\"iH8pgl\":[\"Takaisin\"],\"iL9SZg\":[\"Estä käyttäjä (nimimerkin perusteella)\"],\"iNt+3c\":[\"Takaisin kuvaan\"],\"iQvi+a\":[\"Älä varoita minua tämän palvelimen heikosta linkkiturvallisuudesta\"],\"iSLIjg\":[\"Yhdistä\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Palvelimen osoite\"],\"idD8Ev\":[\"Tallennettu\"],\"iivqkW\":[\"Kirjautunut\"],\"ij+Elv\":[\"Kuvan esikatselu\"],\"ilIWp7\":[\"Näytä/piilota ilmoitukset\"],\"iuaqvB\":[\"Käytä * jokerimerkkinä. Esimerkkejä: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Botti\"],\"j2DGR0\":[\"Estä hostmaskin perusteella\"],\"jA4uoI\":[\"Aihe:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Syy (valinnainen)\"],\"jUV7CU\":[\"Lataa avatar\"],\"jW5Uwh\":[\"Hallinnoi ulkoisen median lataamista. Pois / Turvallinen / Luotetut lähteet / Kaikki sisältö.\"],\"jXzms5\":[\"Liitteet-valinnat\"],\"jZlrte\":[\"Väri\"],\"jfC/xh\":[\"Yhteystiedot\"],\"jywMpv\":[\"#uusi-kanavan-nimi\"],\"k112DD\":[\"Lataa vanhempia viestejä\"],\"k3ID0F\":[\"Suodata jäseniä…\"],\"k65gsE\":[\"Syvempi tarkastelu\"],\"k7Zgob\":[\"Peruuta yhteys\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Kutsuja ei löydetty\"],\"kCLEPU\":[\"Yhdistetty palvelimeen\"],\"kF5LKb\":[\"Estetyt mallit:\"],\"kGeOx/\":[\"Liity kanavalle \",[\"0\"]],\"kITKr8\":[\"Ladataan kanavan tiloja...\"],\"kPpPsw\":[\"Olet IRC-operaattori\"],\"kWJmRL\":[\"Sinä\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Kopioi JSON\"],\"krViRy\":[\"Napsauta kopioidaksesi JSON-muodossa\"],\"ks71ra\":[\"Poikkeukset\"],\"kw4lRv\":[\"Kanavan puolioperaattori\"],\"kxgIRq\":[\"Valitse kanava tai lisää uusi päästäksesi alkuun.\"],\"ky6dWe\":[\"Avatarin esikatselu\"],\"l+GxCv\":[\"Ladataan kanavia...\"],\"l+IUVW\":[\"Tilin \",[\"account\"],\" vahvistus onnistui: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"yhdisti uudelleen\"],\"other\":[\"yhdisti uudelleen \",[\"reconnectCount\"],\" kertaa\"]}]],\"l5jmzx\":[[\"0\"],\" ja \",[\"1\"],\" kirjoittavat...\"],\"lHy8N5\":[\"Ladataan lisää kanavia...\"],\"lbpf14\":[\"Liity kanavaan \",[\"value\"]],\"lfFsZ4\":[\"Kanavat\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Tilin nimi\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Lataa kuva\"],\"loQxaJ\":[\"Olen takaisin\"],\"lvfaxv\":[\"KOTI\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Lisää\"],\"m8flAk\":[\"Esikatselu (ei vielä lähetetty)\"],\"mEPxTp\":[\"<0>⚠️ Ole varovainen!</0> Avaa linkkejä vain luotetuista lähteistä. Haitalliset linkit voivat vaarantaa tietoturvasi tai yksityisyytesi.\"],\"mHGdhG\":[\"Palvelimen tiedot\"],\"mHS8lb\":[\"Viesti #\",[\"0\"]],\"mMYBD9\":[\"Laaja – laajempi suojauslaajuus\"],\"mTGsPd\":[\"Kanavan aihe\"],\"mU8j6O\":[\"Ei ulkoisia viestejä (+n)\"],\"mZp8FL\":[\"Automaattinen palautuminen yksiriviseksi\"],\"mdQu8G\":[\"SinunNimimerkkisi\"],\"miSSBQ\":[\"Kommentit (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Käyttäjä on tunnistautunut\"],\"mwtcGl\":[\"Sulje kommentit\"],\"mzI/c+\":[\"Lataa\"],\"n3fGRk\":[\"asettaja: \",[\"0\"]],\"nE9jsU\":[\"Löysä – vähemmän aggressiivinen suojaus\"],\"nNflMD\":[\"Poistu kanavalta\"],\"nPXkBi\":[\"Ladataan WHOIS-tietoja...\"],\"nQnxxF\":[\"Viesti #\",[\"0\"],\" (Shift+Enter = uusi rivi)\"],\"nWMRxa\":[\"Irrota kiinnitys\"],\"nkC032\":[\"Ei tulvasuojausprofiilia\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Lähetä varoitusviesti käyttäjälle \",[\"username\"]],\"o9ylQi\":[\"Hae GIF-kuvia aloittaaksesi\"],\"oFGkER\":[\"Palvelintiedotteet\"],\"oOi11l\":[\"Siirry loppuun\"],\"oQEzQR\":[\"Uusi DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Verkossa\"],\"oal760\":[\"Välimieshyökkäykset palvelinlinkeissä ovat mahdollisia\"],\"oeqmmJ\":[\"Luotetut lähteet\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Oletus\"],\"p0Z69r\":[\"Malli ei voi olla tyhjä\"],\"p1KgtK\":[\"Äänen lataaminen epäonnistui\"],\"p59pEv\":[\"Lisätiedot\"],\"p7sRI6\":[\"Ilmoita muille, kun kirjoitat\"],\"pBm1od\":[\"Salainen kanava\"],\"pNmiXx\":[\"Oletusnimimerkkisi kaikille palvelimille\"],\"pUUo9G\":[\"Isäntänimi:\"],\"pVGPmz\":[\"Tilin salasana\"],\"peNE68\":[\"Pysyvä\"],\"plhHQt\":[\"Ei tietoja\"],\"pm6+q5\":[\"Tietoturvavaroitus\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Lisätiedot\"],\"q0cR4S\":[\"on nyt tunnettu nimellä **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Kanava ei näy LIST- tai NAMES-komennoissa\"],\"qLpTm/\":[\"Poista reaktio \",[\"emoji\"]],\"qVkGWK\":[\"Kiinnitä\"],\"qY8wNa\":[\"Kotisivu\"],\"qb0xJ7\":[\"Käytä jokerimerkkejä: * vastaa mitä tahansa merkkijonoa, ? vastaa yhtä merkkiä. Esimerkkejä: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanavan avain (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Ei rajaa\"],\"r1W2AS\":[\"Tiedostopalvelimen kuva\"],\"rIPR2O\":[\"Aihe asetettu aiemmin kuin (min sitten)\"],\"rMMSYo\":[\"Enimmäispituus on \",[\"0\"]],\"rWtzQe\":[\"Verkko jakautui ja yhdistyi uudelleen. ✅\"],\"rYG2u6\":[\"Odota hetki...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Esikatselu\"],\"rjGI/Q\":[\"Yksityisyys\"],\"rk8iDX\":[\"Ladataan GIF-kuvia...\"],\"rn6SBY\":[\"Poista mykistys\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Poistettiin kanavalta\"],\"s8cATI\":[\"liittyi kanavalle \",[\"channelName\"]],\"sCO9ue\":[\"Yhteydessä palvelimeen <0>\",[\"serverName\"],\"</0> on seuraavia tietoturvaongelmia:\"],\"sGH11W\":[\"Palvelin\"],\"sHI1H+\":[\"on nyt tunnettu nimellä **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" kutsui sinut liittymään kanavalle \",[\"channel\"]],\"sby+1/\":[\"Kopioi napsauttamalla\"],\"sfN25C\":[\"Oikea nimesi tai koko nimesi\"],\"sliuzR\":[\"Avaa linkki\"],\"sqrO9R\":[\"Mukautetut maininnat\"],\"sr6RdJ\":[\"Monirivinen Shift+Enter-painikkeella\"],\"swrCpB\":[\"Kanava on nimetty uudelleen nimestä \",[\"oldName\"],\" nimeen \",[\"newName\"],\" käyttäjän \",[\"user\"],\" toimesta\",[\"0\"]],\"sxkWRg\":[\"Lisäasetukset\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Poista\"],\"t47eHD\":[\"Yksilöllinen tunnuksesi tällä palvelimella\"],\"tAkAh0\":[\"URL, jossa valinnainen \",[\"size\"],\"-muuttuja dynaamista kokoa varten. Esimerkki: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Näytä tai piilota kanavaluettelon sivupalkki\"],\"tfDRzk\":[\"Tallenna\"],\"tiBsJk\":[\"poistui kanavalta \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"poistui (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Nimimerkki {nick} on jo käytössä, yritetään uudelleen nimellä {newNick}\"],\"u0a8B4\":[\"Tunnistaudu IRC-operaattoriksi ylläpito-oikeuksia varten\"],\"u0rWFU\":[\"Luotu myöhemmin kuin (min sitten)\"],\"u72w3t\":[\"Estettävät käyttäjät ja mallit\"],\"u7jc2L\":[\"poistui\"],\"uAQUqI\":[\"Tila\"],\"uB85T3\":[\"Tallennus epäonnistui: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-palvelimet:\"],\"usSSr/\":[\"Zoomaustaso\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Ohjelmisto:\"],\"vE8kb+\":[\"Käytä Shift+Enter uudelle riville (Enter lähettää)\"],\"vERlcd\":[\"Profiili\"],\"vK0RL8\":[\"Ei aihetta\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Kieli\"],\"vaHYxN\":[\"Oikea nimi\"],\"vhjbKr\":[\"Poissa\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" asiakasohjelma\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Virheellinen arvo\"],\"wFjjxZ\":[\"potkittiin kanavalta \",[\"channelName\"],\" käyttäjän \",[\"username\"],\" toimesta (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Porttikieltopoikkeuksia ei löydetty\"],\"wPrGnM\":[\"Kanavan ylläpitäjä\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Näytä kun käyttäjät liittyvät kanavalle tai poistuvat\"],\"whqZ9r\":[\"Lisäsanat tai -lauseet korostettavaksi\"],\"wm7RV4\":[\"Ilmoitusääni\"],\"wz/Yoq\":[\"Viestisi voidaan siepata palvelimien välillä välitettäessä\"],\"xCJdfg\":[\"Tyhjennä\"],\"xUHRTR\":[\"Tunnistaudu automaattisesti operaattoriksi yhdistäessä\"],\"xWHwwQ\":[\"Estot\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Vain suojatut WebSocket-yhteydet ovat tuettuja\"],\"xdtXa+\":[\"kanavan-nimi\"],\"xfXC7q\":[\"Tekstikanavat\"],\"xlCYOE\":[\"Haetaan lisää viestejä...\"],\"xlhswE\":[\"Vähimmäisarvo on \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Lisää sana tai lause...\"],\"xuRqRq\":[\"Käyttäjäraja (+l)\"],\"xwF+7J\":[[\"0\"],\" kirjoittaa...\"],\"yNeucF\":[\"Tämä palvelin ei tue laajennettua profiilimetadataa (IRCv3 METADATA -laajennus). Lisäkentät kuten avatar, näyttönimi ja tila eivät ole käytettävissä.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Kanavan avatar\"],\"yQE2r9\":[\"Ladataan\"],\"ySU+JY\":[\"sinun@sahkoposti.fi\"],\"yTX1Rt\":[\"Oper-käyttäjänimi\"],\"yYOzWD\":[\"lokit\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC-operaattorin salasana\"],\"ygCKqB\":[\"Pysäytä\"],\"ymDxJx\":[\"IRC-operaattorin käyttäjänimi\"],\"yrpRsQ\":[\"Lajittele nimen mukaan\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Sulje\"],\"zJw+jA\":[\"asettaa tilan: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Syötä oper-käyttäjänimi\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
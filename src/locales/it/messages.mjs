@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Formato pattern non valido. Usa il formato nick!user@host (wildcards * consentiti)\"],\"+6NQQA\":[\"Canale di supporto generale\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Disconnetti\"],\"+cyFdH\":[\"Messaggio predefinito quando ci si segna come assenti\"],\"+mVPqU\":[\"Mostra la formattazione Markdown nei messaggi\"],\"+vqCJH\":[\"Il tuo nome utente account per l'autenticazione\"],\"+yPBXI\":[\"Scegli file\"],\"+zy2Nq\":[\"Tipo\"],\"/09cao\":[\"Sicurezza link bassa (Livello \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Gli utenti esterni non possono inviare messaggi\"],\"/6BzZF\":[\"Attiva/Disattiva lista membri\"],\"/TNOPk\":[\"L'utente è assente\"],\"/XQgft\":[\"Scopri\"],\"/cF7Rs\":[\"Volume\"],\"/dqduX\":[\"Pagina successiva\"],\"/fc3q4\":[\"Tutto il contenuto\"],\"/kISDh\":[\"Abilita suoni di notifica\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Riproduci suoni per menzioni e messaggi\"],\"0/0ZGA\":[\"Maschera nome canale\"],\"0D6j7U\":[\"Scopri di più sulle regole personalizzate →\"],\"0XsHcR\":[\"Espelli utente\"],\"0ZpE//\":[\"Ordina per utenti\"],\"0bEPwz\":[\"Imposta assente\"],\"0dGkPt\":[\"Espandi lista canali\"],\"0gS7M5\":[\"Nome visualizzato\"],\"0kS+M8\":[\"EsempioRET\"],\"0rgoY7\":[\"Connettiti solo ai server che scegli\"],\"0wdd7X\":[\"Entra\"],\"0wkVYx\":[\"Messaggi privati\"],\"111uHX\":[\"Anteprima link\"],\"196EG4\":[\"Elimina chat privata\"],\"1DSr1i\":[\"Registra un account\"],\"1O/24y\":[\"Attiva/Disattiva lista canali\"],\"1VPJJ2\":[\"Avviso link esterno\"],\"1ZC/dv\":[\"Nessuna menzione o messaggio non letto\"],\"1pO1zi\":[\"Il nome del server è obbligatorio\"],\"1uwfzQ\":[\"Visualizza topic del canale\"],\"268g7c\":[\"Inserisci nome visualizzato\"],\"2FOFq1\":[\"Gli operatori del server sulla rete potrebbero leggere i tuoi messaggi\"],\"2FYpfJ\":[\"Altro\"],\"2HF1Y2\":[[\"inviter\"],\" ha invitato \",[\"target\"],\" a unirsi a \",[\"channel\"]],\"2I70QL\":[\"Visualizza informazioni profilo utente\"],\"2QYdmE\":[\"Utenti:\"],\"2QpEjG\":[\"è uscito\"],\"2YE223\":[\"Messaggio in #\",[\"0\"],\" (Invio per nuova riga, Shift+Invio per inviare)\"],\"2bimFY\":[\"Usa password del server\"],\"2iTmdZ\":[\"Archiviazione locale:\"],\"2odkwe\":[\"Rigoroso – Protezione più aggressiva\"],\"2uDhbA\":[\"Inserisci il nome utente da invitare\"],\"2ygf/L\":[\"← Indietro\"],\"2zEgxj\":[\"Cerca GIF...\"],\"3RdPhl\":[\"Rinomina canale\"],\"3THokf\":[\"Utente con diritto di parola\"],\"3TSz9S\":[\"Minimizza\"],\"3jBDvM\":[\"Nome visualizzato del canale\"],\"3ryuFU\":[\"Segnalazioni di crash opzionali per migliorare l'app\"],\"3uBF/8\":[\"Chiudi visualizzatore\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Inserisci nome account...\"],\"4/Rr0R\":[\"Invita un utente nel canale corrente\"],\"4EZrJN\":[\"Regole\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Profilo flood (+F)\"],\"4RZQRK\":[\"Cosa stai facendo?\"],\"4hfTrB\":[\"Nickname\"],\"4n99LO\":[\"Già in \",[\"0\"]],\"4t6vMV\":[\"Passa automaticamente a riga singola per messaggi brevi\"],\"4vsHmf\":[\"Tempo (min)\"],\"5+INAX\":[\"Evidenzia i messaggi che ti menzionano\"],\"5R5Pv/\":[\"Nome oper\"],\"678PKt\":[\"Nome rete\"],\"6Aih4U\":[\"Non in linea\"],\"6CO3WE\":[\"Password richiesta per entrare. Lascia vuoto per rimuovere la chiave.\"],\"6HhMs3\":[\"Messaggio di uscita\"],\"6V3Ea3\":[\"Copiato\"],\"6lGV3K\":[\"Mostra meno\"],\"6yFOEi\":[\"Inserisci password oper...\"],\"7+IHTZ\":[\"Nessun file scelto\"],\"73hrRi\":[\"nick!user@host (es., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Invia messaggio privato\"],\"7U1W7c\":[\"Molto rilassato\"],\"7Y1YQj\":[\"Nome reale:\"],\"7YHArF\":[\"— apri nel visualizzatore\"],\"7fjnVl\":[\"Cerca utenti...\"],\"7jL88x\":[\"Eliminare questo messaggio? Questa azione non può essere annullata.\"],\"7nGhhM\":[\"A cosa stai pensando?\"],\"7sEpu1\":[\"Membri — \",[\"0\"]],\"7sNhEz\":[\"Nome utente\"],\"8H0Q+x\":[\"Scopri di più sui profili →\"],\"8Phu0A\":[\"Mostra quando gli utenti cambiano soprannome\"],\"8XTG9e\":[\"Inserisci password oper\"],\"8XsV2J\":[\"Riprova invio\"],\"8ZsakT\":[\"Password\"],\"8kR84m\":[\"Stai per aprire un link esterno:\"],\"8lCgih\":[\"Rimuovi regola\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"si è unito\"],\"other\":[\"si è unito \",[\"joinCount\"],\" volte\"]}]],\"9BMLnJ\":[\"Riconnetti al server\"],\"9OEgyT\":[\"Aggiungi reazione\"],\"9PQ8m2\":[\"G-Line (ban globale)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Rimuovi pattern\"],\"9bG48P\":[\"Invio in corso\"],\"9f5f0u\":[\"Domande sulla privacy? Contattaci:\"],\"9unqs3\":[\"Assente:\"],\"9v3hwv\":[\"Nessun server trovato.\"],\"9zb2WA\":[\"Connessione in corso\"],\"A1taO8\":[\"Cerca\"],\"A2adVi\":[\"Invia notifiche di digitazione\"],\"A9Rhec\":[\"Nome canale\"],\"AWOSPo\":[\"Ingrandisci\"],\"AXSpEQ\":[\"Oper alla connessione\"],\"AeXO77\":[\"Account\"],\"AhNP40\":[\"Cerca posizione\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Nickname cambiato\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Annulla risposta\"],\"ApSx0O\":[\"Trovati \",[\"0\"],\" messaggi corrispondenti a \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Nessun risultato trovato\"],\"AyNqAB\":[\"Mostra tutti gli eventi del server in chat\"],\"B/QqGw\":[\"Lontano dalla tastiera\"],\"B8AaMI\":[\"Questo campo è obbligatorio\"],\"BA2c49\":[\"Il server non supporta il filtro LIST avanzato\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" e altri \",[\"3\"],\" stanno scrivendo...\"],\"BGul2A\":[\"Hai modifiche non salvate. Sei sicuro di voler chiudere senza salvare?\"],\"BIf9fi\":[\"Il tuo messaggio di stato\"],\"BZz3md\":[\"Il tuo sito web personale\"],\"Bgm/H7\":[\"Consenti l'inserimento di più righe di testo\"],\"BiQIl1\":[\"Fissa questa conversazione privata\"],\"BlNZZ2\":[\"Clicca per andare al messaggio\"],\"Bowq3c\":[\"Solo gli operatori possono cambiare l'argomento\"],\"Btozzp\":[\"Questa immagine è scaduta\"],\"Bycfjm\":[\"Totale: \",[\"0\"]],\"C6IBQc\":[\"Copia JSON completo\"],\"C9L9wL\":[\"Raccolta dati\"],\"CDq4wC\":[\"Modera utente\"],\"CHVRxG\":[\"Messaggio a @\",[\"0\"],\" (Shift+Invio per nuova riga)\"],\"CN9zdR\":[\"Nome oper e password sono obbligatori\"],\"CW3sYa\":[\"Aggiungi reazione \",[\"emoji\"]],\"CaAkqd\":[\"Mostra disconnessioni\"],\"CbvaYj\":[\"Banna per nickname\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Seleziona un canale\"],\"CsekCi\":[\"Normale\"],\"D+NlUC\":[\"Sistema\"],\"D28t6+\":[\"è entrato e uscito\"],\"DB8zMK\":[\"Applica\"],\"DBcWHr\":[\"File audio di notifica personalizzato\"],\"DTy9Xw\":[\"Anteprime multimediali\"],\"Dj4pSr\":[\"Scegli una password sicura\"],\"Du+zn+\":[\"Ricerca...\"],\"Du2T2f\":[\"Impostazione non trovata\"],\"DwsSVQ\":[\"Applica filtri e aggiorna\"],\"E3W/zd\":[\"Soprannome predefinito\"],\"E6nRW7\":[\"Copia URL\"],\"E703RG\":[\"Modi:\"],\"EAeu1Z\":[\"Invia invito\"],\"EFKJQT\":[\"Impostazione\"],\"EGPQBv\":[\"Regole flood personalizzate (+f)\"],\"ELik0r\":[\"Vedi l'informativa completa sulla privacy\"],\"EPbeC2\":[\"Visualizza o modifica il topic del canale\"],\"EQCDNT\":[\"Inserisci nome utente oper...\"],\"EUvulZ\":[\"Trovato 1 messaggio corrispondente a \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Immagine successiva\"],\"EdQY6l\":[\"Nessuno\"],\"EnqLYU\":[\"Cerca server...\"],\"F0OKMc\":[\"Modifica server\"],\"F6Int2\":[\"Abilita evidenziazioni\"],\"FDoLyE\":[\"Utenti max.\"],\"FUU/hZ\":[\"Controlla quanti media esterni vengono caricati nella chat.\"],\"Fdp03t\":[\"attivo\"],\"FfPWR0\":[\"Finestra\"],\"FjkaiT\":[\"Riduci\"],\"FlqOE9\":[\"Cosa significa:\"],\"FolHNl\":[\"Gestisci il tuo account e l'autenticazione\"],\"Fp2Dif\":[\"Uscito dal server\"],\"G5KmCc\":[\"GZ-Line (Z-Line globale)\"],\"GDs0lz\":[\"<0>Rischio:</0> Informazioni sensibili (messaggi, conversazioni private, dati di autenticazione) potrebbero essere esposte ad amministratori di rete o attaccanti posizionati tra i server IRC.\"],\"GR+2I3\":[\"Aggiungi maschera di invito (es. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Chiudi avvisi server in finestra separata\"],\"GlHnXw\":[\"Cambio nick fallito: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Anteprima:\"],\"GtmO8/\":[\"da\"],\"GtuHUQ\":[\"Rinomina questo canale sul server. Tutti gli utenti vedranno il nuovo nome.\"],\"GuGfFX\":[\"Attiva/Disattiva ricerca\"],\"GxkJXS\":[\"Caricamento...\"],\"GzbwnK\":[\"È entrato nel canale\"],\"GzsUDB\":[\"Profilo esteso\"],\"H/PnT8\":[\"Inserisci emoji\"],\"H6Izzl\":[\"Il tuo codice colore preferito\"],\"H9jIv+\":[\"Mostra entrate/uscite\"],\"HAKBY9\":[\"Carica file\"],\"HdE1If\":[\"Canale\"],\"Hk4AW9\":[\"Il tuo nome visualizzato preferito\"],\"HmHDk7\":[\"Seleziona membro\"],\"HrQzPU\":[\"Canali su \",[\"networkName\"]],\"I2tXQ5\":[\"Messaggio a @\",[\"0\"],\" (Invio per nuova riga, Shift+Invio per inviare)\"],\"I6bw/h\":[\"Banna utente\"],\"I92Z+b\":[\"Abilita notifiche\"],\"I9D72S\":[\"Sei sicuro di voler eliminare questo messaggio? Questa azione non può essere annullata.\"],\"IA+1wo\":[\"Mostra quando gli utenti vengono espulsi dai canali\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Salva modifiche\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" e \",[\"2\"],\" stanno scrivendo...\"],\"IgrLD/\":[\"Pausa\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Rispondi\"],\"IoHMnl\":[\"Il valore massimo è \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Connessione...\"],\"J5T9NW\":[\"Informazioni utente\"],\"J8Y5+z\":[\"Ops! La rete si è divisa! ⚠️\"],\"JBHkBA\":[\"Ha lasciato il canale\"],\"JCwL0Q\":[\"Inserisci motivo (opzionale)\"],\"JFciKP\":[\"Attiva/Disattiva\"],\"JXGkhG\":[\"Cambia il nome del canale (solo operatori)\"],\"JcD7qf\":[\"Altre azioni\"],\"JdkA+c\":[\"Segreto (+s)\"],\"Jmu12l\":[\"Canali del server\"],\"JvQ++s\":[\"Abilita Markdown\"],\"K2jwh/\":[\"Nessun dato WHOIS disponibile\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Elimina messaggio\"],\"KKBlUU\":[\"Incorpora\"],\"KM0pLb\":[\"Benvenuto nel canale!\"],\"KR6W2h\":[\"Smetti di ignorare utente\"],\"KV+Bi1\":[\"Solo su invito (+i)\"],\"KdCtwE\":[\"Quanti secondi monitorare l'attività flood prima di reimpostare i contatori\"],\"Kkezga\":[\"Password del server\"],\"KsiQ/8\":[\"Gli utenti devono essere invitati per entrare\"],\"L+gB/D\":[\"Informazioni sul canale\"],\"LC1a7n\":[\"Il server IRC ha segnalato che i suoi collegamenti tra server hanno un basso livello di sicurezza. Ciò significa che quando i tuoi messaggi vengono instradati tra i server IRC nella rete, potrebbero non essere correttamente cifrati o i certificati SSL/TLS potrebbero non essere validati correttamente.\"],\"LNfLR5\":[\"Mostra espulsioni\"],\"LQb0W/\":[\"Mostra tutti gli eventi\"],\"LU7/yA\":[\"Nome alternativo per la visualizzazione. Può contenere spazi, emoji e caratteri speciali. Il nome reale (\",[\"channelName\"],\") verrà comunque usato per i comandi IRC.\"],\"LUb9O7\":[\"È richiesta una porta del server valida\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Informativa sulla privacy\"],\"LcuSDR\":[\"Gestisci le informazioni del tuo profilo e i metadati\"],\"LqLS9B\":[\"Mostra cambi di soprannome\"],\"LsDQt2\":[\"Impostazioni canale\"],\"LtI9AS\":[\"Proprietario\"],\"LuNhhL\":[\"ha reagito a questo messaggio\"],\"M/AZNG\":[\"URL dell'immagine del tuo avatar\"],\"M/WIer\":[\"Invia messaggio\"],\"M8er/5\":[\"Nome:\"],\"MHk+7g\":[\"Immagine precedente\"],\"MRorGe\":[\"Messaggio privato\"],\"MVbSGP\":[\"Finestra temporale (secondi)\"],\"MkpcsT\":[\"I tuoi messaggi e impostazioni sono archiviati localmente sul tuo dispositivo\"],\"N/hDSy\":[\"Segna come bot, di solito 'on' o vuoto\"],\"N7TQbE\":[\"Invita utente in \",[\"channelName\"]],\"NCca/o\":[\"Inserisci nickname predefinito...\"],\"Nqs6B9\":[\"Mostra tutti i media esterni. Qualsiasi URL potrebbe causare una richiesta a un server sconosciuto.\"],\"Nt+9O7\":[\"Usa WebSocket invece di TCP grezzo\"],\"NxIHzc\":[\"Espelli utente\"],\"O+v/cL\":[\"Sfoglia tutti i canali del server\"],\"ODwSCk\":[\"Invia un GIF\"],\"OGQ5kK\":[\"Configura suoni di notifica ed evidenziazioni\"],\"OIPt1Z\":[\"Mostra o nascondi la barra laterale dei membri\"],\"OKSNq/\":[\"Molto rigido\"],\"ONWvwQ\":[\"Carica\"],\"OVKoQO\":[\"La tua password account per l'autenticazione\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Portare IRC nel futuro\"],\"OhCpra\":[\"Imposta un topic…\"],\"OkltoQ\":[\"Banna \",[\"username\"],\" per nickname (impedisce di rientrare con lo stesso nick)\"],\"P+t/Te\":[\"Nessun dato aggiuntivo\"],\"P42Wcc\":[\"Sicuro\"],\"PD38l0\":[\"Anteprima avatar canale\"],\"PD9mEt\":[\"Scrivi un messaggio...\"],\"PPqfdA\":[\"Apri impostazioni configurazione canale\"],\"PSCjfZ\":[\"L'argomento visualizzato per questo canale. Tutti gli utenti possono vederlo.\"],\"PZCecv\":[\"Anteprima PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 volta\"],\"other\":[[\"c\"],\" volte\"]}]],\"PguS2C\":[\"Aggiungi maschera di eccezione (es. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Visualizzazione di \",[\"displayedChannelsCount\"],\" su \",[\"0\"],\" canali\"],\"PqhVlJ\":[\"Banna utente (per hostmask)\"],\"Q+chwU\":[\"Nome utente:\"],\"Q6hhn8\":[\"Preferenze\"],\"QF4a34\":[\"Inserisci un nome utente\"],\"QGqSZ2\":[\"Colore e formattazione\"],\"QJQd1J\":[\"Modifica profilo\"],\"QSzGDE\":[\"Inattivo\"],\"QUlny5\":[\"Benvenuto su \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Leggi di più\"],\"QuSkCF\":[\"Filtra canali...\"],\"QwUrDZ\":[\"ha cambiato il topic in: \",[\"topic\"]],\"R0UH07\":[\"Immagine \",[\"0\"],\" di \",[\"1\"]],\"R7SsBE\":[\"Disattiva audio\"],\"R8rf1X\":[\"Clicca per impostare il topic\"],\"RArB3D\":[\"è stato espulso da \",[\"channelName\"],\" da \",[\"username\"]],\"RI3cWd\":[\"Scopri il mondo di IRC con ObsidianIRC\"],\"RMMaN5\":[\"Moderato (+m)\"],\"RWw9Lg\":[\"Chiudi finestra\"],\"RZ2BuZ\":[\"La registrazione dell'account \",[\"account\"],\" richiede verifica: \",[\"message\"]],\"RySp6q\":[\"Nascondi commenti\"],\"SPKQTd\":[\"Il nickname è obbligatorio\"],\"SPVjfj\":[\"Il valore predefinito sarà 'nessun motivo' se lasciato vuoto\"],\"SQKPvQ\":[\"Invita utente\"],\"SkZcl+\":[\"Scegli un profilo di protezione flood predefinito. Questi profili forniscono impostazioni di protezione bilanciate per diversi casi d'uso.\"],\"Slr+3C\":[\"Utenti min.\"],\"Spnlre\":[\"Hai invitato \",[\"target\"],\" a unirsi a \",[\"channel\"]],\"T/ckN5\":[\"Apri nel visualizzatore\"],\"T91vKp\":[\"Riproduci\"],\"TV2Wdu\":[\"Scopri come gestiamo i tuoi dati e proteggiamo la tua privacy.\"],\"TgFpwD\":[\"Applicazione...\"],\"TkzSFB\":[\"Nessuna modifica\"],\"TtserG\":[\"Inserisci nome reale\"],\"Ttz9J1\":[\"Inserisci password...\"],\"Tz0i8g\":[\"Impostazioni\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagisci\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Salva impostazioni\"],\"UV5hLB\":[\"Nessun ban trovato\"],\"Uaj3Nd\":[\"Messaggi di stato\"],\"Ue3uny\":[\"Predefinito (nessun profilo)\"],\"UkARhe\":[\"Normale – Protezione standard\"],\"Umn7Cj\":[\"Ancora nessun commento. Sii il primo!\"],\"UtUIRh\":[[\"0\"],\" messaggi precedenti\"],\"UwzP+U\":[\"Connessione sicura\"],\"V0/A4O\":[\"Proprietario del canale\"],\"V4qgxE\":[\"Creato prima (min fa)\"],\"V8yTm6\":[\"Cancella ricerca\"],\"VJMMyz\":[\"ObsidianIRC - Portare IRC nel futuro\"],\"VJScHU\":[\"Motivo\"],\"VLsmVV\":[\"Silenzia notifiche\"],\"VbyRUy\":[\"Commenti\"],\"Vmx0mQ\":[\"Impostato da:\"],\"VqnIZz\":[\"Visualizza la nostra informativa sulla privacy e le pratiche sui dati\"],\"VrMygG\":[\"La lunghezza minima è \",[\"0\"]],\"VrnTui\":[\"I tuoi pronomi, mostrati nel profilo\"],\"W8E3qn\":[\"Account autenticato\"],\"WAakm9\":[\"Elimina canale\"],\"WFxTHC\":[\"Aggiungi maschera di ban (es. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"L'host del server è obbligatorio\"],\"WRYdXW\":[\"Posizione audio\"],\"WUOH5B\":[\"Ignora utente\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Mostra 1 altro elemento\"],\"other\":[\"Mostra \",[\"1\"],\" altri elementi\"]}]],\"Weq9zb\":[\"Generale\"],\"Wfj7Sk\":[\"Attiva o disattiva i suoni delle notifiche\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profilo utente\"],\"X6S3lt\":[\"Cerca impostazioni, canali, server...\"],\"XEHan5\":[\"Continua comunque\"],\"XI1+wb\":[\"Formato non valido\"],\"XIXeuC\":[\"Messaggio a @\",[\"0\"]],\"XMS+k4\":[\"Avvia messaggio privato\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Rimuovi fissaggio chat privata\"],\"Xm/s+u\":[\"Visualizzazione\"],\"Xp2n93\":[\"Mostra media dall'host di file attendibile del tuo server. Nessuna richiesta viene inviata a servizi esterni.\"],\"XvjC4F\":[\"Salvataggio...\"],\"Y/qryO\":[\"Nessun utente trovato corrispondente alla ricerca\"],\"YAqRpI\":[\"Registrazione dell'account \",[\"account\"],\" riuscita: \",[\"message\"]],\"YEfzvP\":[\"Argomento protetto (+t)\"],\"YQOn6a\":[\"Comprimi lista membri\"],\"YRCoE9\":[\"Operatore del canale\"],\"YURQaF\":[\"Vedi profilo\"],\"YdBSvr\":[\"Controlla la visualizzazione dei media e dei contenuti esterni\"],\"Yj6U3V\":[\"Nessun server centrale:\"],\"YjvpGx\":[\"Pronomi\"],\"YqH4l4\":[\"Nessuna chiave\"],\"YyUPpV\":[\"Account:\"],\"ZJSWfw\":[\"Messaggio mostrato alla disconnessione dal server\"],\"ZR1dJ4\":[\"Inviti\"],\"ZdWg0V\":[\"Apri nel browser\"],\"ZhRBbl\":[\"Cerca messaggi…\"],\"Zmcu3y\":[\"Filtri avanzati\"],\"a2/8e5\":[\"Argomento impostato dopo (min fa)\"],\"aHKcKc\":[\"Pagina precedente\"],\"aJTbXX\":[\"Password oper\"],\"aQryQv\":[\"Il pattern esiste già\"],\"aW9pLN\":[\"Numero massimo di utenti nel canale. Lascia vuoto per nessun limite.\"],\"ah4fmZ\":[\"Mostra anche anteprime da YouTube, Vimeo, SoundCloud e servizi noti simili.\"],\"aifXak\":[\"Nessun media in questo canale\"],\"ap2zBz\":[\"Rilassato\"],\"az8lvo\":[\"Disattivato\"],\"azXSNo\":[\"Espandi lista membri\"],\"azdliB\":[\"Accedi a un account\"],\"b26wlF\":[\"lei/la\"],\"bD/+Ei\":[\"Rigido\"],\"bQ6BJn\":[\"Configura regole dettagliate di protezione flood. Ogni regola specifica il tipo di attività da monitorare e l'azione da intraprendere quando le soglie vengono superate.\"],\"beV7+y\":[\"L'utente riceverà un invito per unirsi a \",[\"channelName\"],\".\"],\"bk84cH\":[\"Messaggio di assenza\"],\"bkHdLj\":[\"Aggiungi server IRC\"],\"bmQLn5\":[\"Aggiungi regola\"],\"bwRvnp\":[\"Azione\"],\"c8+EVZ\":[\"Account verificato\"],\"cGYUlD\":[\"Nessuna anteprima media caricata.\"],\"cLF98o\":[\"Mostra commenti (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Nessun utente disponibile\"],\"cSgpoS\":[\"Fissa chat privata\"],\"cde3ce\":[\"Messaggio a <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Copia output formattato\"],\"cl/A5J\":[\"Benvenuto su \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Elimina\"],\"coPLXT\":[\"Non archiviamo le tue comunicazioni IRC sui nostri server\"],\"crYH/6\":[\"Player SoundCloud\"],\"d3sis4\":[\"Aggiungi server\"],\"d9aN5k\":[\"Rimuovi \",[\"username\"],\" dal canale\"],\"dEgA5A\":[\"Annulla\"],\"dGi1We\":[\"Rimuovi il fissaggio di questa conversazione privata\"],\"dJVuyC\":[\"ha lasciato \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"a\"],\"dXqxlh\":[\"<0>⚠️ Rischio di sicurezza!</0> Questa connessione potrebbe essere vulnerabile a intercettazioni o attacchi man-in-the-middle.\"],\"da9Q/R\":[\"Modalità canale modificate\"],\"dhJN3N\":[\"Mostra commenti\"],\"dj2xTE\":[\"Ignora notifica\"],\"dpCzmC\":[\"Impostazioni protezione flood\"],\"e9dQpT\":[\"Vuoi aprire questo link in una nuova scheda?\"],\"ePK91l\":[\"Modifica\"],\"eYBDuB\":[\"Carica un'immagine o fornisci un URL con sostituzione opzionale \",[\"size\"]],\"edBbee\":[\"Banna \",[\"username\"],\" per hostmask (impedisce di rientrare dallo stesso IP/host)\"],\"ekfzWq\":[\"Impostazioni utente\"],\"elPDWs\":[\"Personalizza la tua esperienza con il client IRC\"],\"eu2osY\":[\"<0>💡 Raccomandazione:</0> Procedi solo se ti fidi di questo server e comprendi i rischi. Evita di condividere informazioni sensibili o password su questa connessione.\"],\"euEhbr\":[\"Clicca per unirti a \",[\"channel\"]],\"ez3vLd\":[\"Abilita input multiriga\"],\"f0J5Ki\":[\"Le comunicazioni tra server potrebbero usare connessioni non cifrate\"],\"f9BHJk\":[\"Avvisa utente\"],\"fDOLLd\":[\"Nessun canale trovato.\"],\"ffzDkB\":[\"Analisi anonime:\"],\"fq1GF9\":[\"Mostra quando gli utenti si disconnettono dal server\"],\"gEF57C\":[\"Questo server supporta solo un tipo di connessione\"],\"gJuLUI\":[\"Lista di ignorati\"],\"gNzMrk\":[\"Avatar attuale\"],\"gjPWyO\":[\"Inserisci nickname...\"],\"gz6UQ3\":[\"Massimizza\"],\"h6razj\":[\"Escludi maschera nome canale\"],\"hG6jnw\":[\"Nessun topic impostato\"],\"hG89Ed\":[\"Immagine\"],\"hZ6znB\":[\"Porta\"],\"ha+Bz5\":[\"es., 100:1440\"],\"hehnjM\":[\"Quantità\"],\"hzdLuQ\":[\"Solo gli utenti con voice o superiore possono parlare\"],\"i0qMbr\":[\"Home\"],\"iDNBZe\":[\"Notifiche\"],\"iH8pgl\":[\"Indietro\"],\"iL9SZg\":[\"Banna utente (per nickname)\"],\"iNt+3c\":[\"Torna all'immagine\"],\"iQvi+a\":[\"Non avvisarmi sulla bassa sicurezza del link per questo server\"],\"iSLIjg\":[\"Connetti\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Host del server\"],\"idD8Ev\":[\"Salvato\"],\"iivqkW\":[\"Connesso dal\"],\"ij+Elv\":[\"Anteprima immagine\"],\"ilIWp7\":[\"Attiva/Disattiva notifiche\"],\"iuaqvB\":[\"Usa * come wildcard. Esempi: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Banna per maschera host\"],\"jA4uoI\":[\"Argomento:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Motivo (opzionale)\"],\"jUV7CU\":[\"Carica avatar\"],\"jW5Uwh\":[\"Controlla quanti media esterni vengono caricati. Disattivato / Sicuro / Fonti affidabili / Tutto il contenuto.\"],\"jXzms5\":[\"Opzioni allegato\"],\"jZlrte\":[\"Colore\"],\"jfC/xh\":[\"Contatti\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Carica messaggi precedenti\"],\"k3ID0F\":[\"Filtra membri…\"],\"k65gsE\":[\"Analisi approfondita\"],\"k7Zgob\":[\"Annulla connessione\"],\"kAVx5h\":[\"Nessun invito trovato\"],\"kCLEPU\":[\"Connesso a\"],\"kF5LKb\":[\"Pattern ignorati:\"],\"kGeOx/\":[\"Unisciti a \",[\"0\"]],\"kITKr8\":[\"Caricamento modalità canale...\"],\"kPpPsw\":[\"Sei un IRC Operator\"],\"kWJmRL\":[\"Tu\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Copia JSON\"],\"krViRy\":[\"Clicca per copiare come JSON\"],\"ks71ra\":[\"Eccezioni\"],\"kw4lRv\":[\"Semi-operatore del canale\"],\"kxgIRq\":[\"Seleziona o aggiungi un canale per iniziare.\"],\"ky6dWe\":[\"Anteprima avatar\"],\"l+GxCv\":[\"Caricamento canali...\"],\"l+IUVW\":[\"Verifica dell'account \",[\"account\"],\" riuscita: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"si è riconnesso\"],\"other\":[\"si è riconnesso \",[\"reconnectCount\"],\" volte\"]}]],\"l5jmzx\":[[\"0\"],\" e \",[\"1\"],\" stanno scrivendo...\"],\"lHy8N5\":[\"Caricamento altri canali...\"],\"lbpf14\":[\"Entra in \",[\"value\"]],\"lfFsZ4\":[\"Canali\"],\"lkNdiH\":[\"Nome account\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Carica immagine\"],\"loQxaJ\":[\"Sono tornato\"],\"lvfaxv\":[\"HOME\"],\"m16xKo\":[\"Aggiungi\"],\"m8flAk\":[\"Anteprima (non ancora caricata)\"],\"mEPxTp\":[\"<0>⚠️ Attenzione!</0> Apri solo link da fonti attendibili. I link malevoli possono compromettere la tua sicurezza o privacy.\"],\"mHGdhG\":[\"Informazioni sul server\"],\"mHS8lb\":[\"Messaggio in #\",[\"0\"]],\"mMYBD9\":[\"Ampio – Portata di protezione estesa\"],\"mTGsPd\":[\"Argomento del canale\"],\"mU8j6O\":[\"Nessun messaggio esterno (+n)\"],\"mZp8FL\":[\"Ritorno automatico alla singola riga\"],\"mdQu8G\":[\"IlTuoNickname\"],\"miSSBQ\":[\"Commenti (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Utente autenticato\"],\"mwtcGl\":[\"Chiudi commenti\"],\"mzI/c+\":[\"Scarica\"],\"n3fGRk\":[\"impostato da \",[\"0\"]],\"nE9jsU\":[\"Rilassato – Protezione meno aggressiva\"],\"nNflMD\":[\"Abbandona canale\"],\"nPXkBi\":[\"Caricamento dati WHOIS...\"],\"nQnxxF\":[\"Messaggio in #\",[\"0\"],\" (Shift+Invio per nuova riga)\"],\"nWMRxa\":[\"Rimuovi fissaggio\"],\"nkC032\":[\"Nessun profilo flood\"],\"o69z4d\":[\"Invia un messaggio di avviso a \",[\"username\"]],\"o9ylQi\":[\"Cerca GIF per iniziare\"],\"oFGkER\":[\"Avvisi del server\"],\"oOi11l\":[\"Vai in fondo\"],\"oQEzQR\":[\"Nuovo messaggio privato\"],\"oXOSPE\":[\"In linea\"],\"oal760\":[\"Sono possibili attacchi man-in-the-middle sui link del server\"],\"oeqmmJ\":[\"Fonti attendibili\"],\"ovBPCi\":[\"Predefinito\"],\"p0Z69r\":[\"Il pattern non può essere vuoto\"],\"p1KgtK\":[\"Caricamento audio non riuscito\"],\"p59pEv\":[\"Dettagli aggiuntivi\"],\"p7sRI6\":[\"Avvisa gli altri quando stai scrivendo\"],\"pBm1od\":[\"Canale segreto\"],\"pNmiXx\":[\"Il tuo soprannome predefinito per tutti i server\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Password account\"],\"peNE68\":[\"Permanente\"],\"plhHQt\":[\"Nessun dato\"],\"pm6+q5\":[\"Avviso di sicurezza\"],\"pn5qSs\":[\"Informazioni aggiuntive\"],\"q0cR4S\":[\"ora è conosciuto come **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Il canale non apparirà nei comandi LIST o NAMES\"],\"qLpTm/\":[\"Rimuovi reazione \",[\"emoji\"]],\"qVkGWK\":[\"Fissa\"],\"qY8wNa\":[\"Homepage\"],\"qb0xJ7\":[\"Wildcard: * corrisponde a qualsiasi sequenza, ? a un singolo carattere. Esempi: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Chiave canale (+k)\"],\"qtoOYG\":[\"Nessun limite\"],\"r1W2AS\":[\"Immagine dal filehost\"],\"rIPR2O\":[\"Argomento impostato prima (min fa)\"],\"rMMSYo\":[\"La lunghezza massima è \",[\"0\"]],\"rWtzQe\":[\"La rete si è divisa e riconnessa. ✅\"],\"rYG2u6\":[\"Attendere...\"],\"rdUucN\":[\"Anteprima\"],\"rjGI/Q\":[\"Privacy\"],\"rk8iDX\":[\"Caricamento GIF...\"],\"rn6SBY\":[\"Attiva audio\"],\"s/UKqq\":[\"È stato espulso dal canale\"],\"s8cATI\":[\"si è unito a \",[\"channelName\"]],\"sCO9ue\":[\"La connessione a <0>\",[\"serverName\"],\"</0> presenta le seguenti problematiche di sicurezza:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"ora è conosciuto come **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" ti ha invitato a unirti a \",[\"channel\"]],\"sby+1/\":[\"Clicca per copiare\"],\"sfN25C\":[\"Il tuo nome reale o completo\"],\"sliuzR\":[\"Apri link\"],\"sqrO9R\":[\"Menzioni personalizzate\"],\"sr6RdJ\":[\"Multiriga con Shift+Invio\"],\"swrCpB\":[\"Il canale è stato rinominato da \",[\"oldName\"],\" a \",[\"newName\"],\" da \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Avanzato\"],\"t/YqKh\":[\"Rimuovi\"],\"t47eHD\":[\"Il tuo identificatore unico su questo server\"],\"tAkAh0\":[\"URL con sostituzione opzionale \",[\"size\"],\". Esempio: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Mostra o nascondi la barra laterale dei canali\"],\"tfDRzk\":[\"Salva\"],\"tiBsJk\":[\"ha lasciato \",[\"channelName\"]],\"tt4/UD\":[\"ha abbandonato (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Il nick {nick} è già in uso, nuovo tentativo con {newNick}\"],\"u0a8B4\":[\"Autenticarsi come operatore IRC per l'accesso amministrativo\"],\"u0rWFU\":[\"Creato dopo (min fa)\"],\"u72w3t\":[\"Utenti e modelli da ignorare\"],\"u7jc2L\":[\"ha abbandonato\"],\"uAQUqI\":[\"Stato\"],\"uB85T3\":[\"Salvataggio fallito: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"Server IRC:\"],\"usSSr/\":[\"Livello zoom\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Shift+Invio per le nuove righe (Invio invia)\"],\"vERlcd\":[\"Profilo\"],\"vK0RL8\":[\"Nessun argomento\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Lingua\"],\"vaHYxN\":[\"Nome reale\"],\"vhjbKr\":[\"Assente\"],\"w4NYox\":[\"client \",[\"title\"]],\"w8xQRx\":[\"Valore non valido\"],\"wFjjxZ\":[\"è stato espulso da \",[\"channelName\"],\" da \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nessuna eccezione di ban trovata\"],\"wPrGnM\":[\"Amministratore del canale\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Mostra quando gli utenti entrano o escono dai canali\"],\"whqZ9r\":[\"Parole o frasi aggiuntive da evidenziare\"],\"wm7RV4\":[\"Suono di notifica\"],\"wz/Yoq\":[\"I tuoi messaggi potrebbero essere intercettati durante l'instradamento tra server\"],\"xCJdfg\":[\"Cancella\"],\"xUHRTR\":[\"Autentica automaticamente come operatore alla connessione\"],\"xWHwwQ\":[\"Ban\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Sono supportati solo websocket sicuri\"],\"xdtXa+\":[\"nome-canale\"],\"xfXC7q\":[\"Canali testuali\"],\"xlCYOE\":[\"Caricamento messaggi...\"],\"xlhswE\":[\"Il valore minimo è \",[\"0\"]],\"xq97Ci\":[\"Aggiungi una parola o frase...\"],\"xuRqRq\":[\"Limite client (+l)\"],\"xwF+7J\":[[\"0\"],\" sta scrivendo...\"],\"yNeucF\":[\"Questo server non supporta i metadati del profilo esteso (estensione IRCv3 METADATA). Campi come avatar, nome visualizzato e stato non sono disponibili.\"],\"yPlrca\":[\"Avatar del canale\"],\"yQE2r9\":[\"Caricamento\"],\"ySU+JY\":[\"tuo@email.com\"],\"yTX1Rt\":[\"Nome utente operatore\"],\"yYOzWD\":[\"log\"],\"yfx9Re\":[\"Password operatore IRC\"],\"ygCKqB\":[\"Stop\"],\"ymDxJx\":[\"Nome utente operatore IRC\"],\"yrpRsQ\":[\"Ordina per nome\"],\"yz7wBu\":[\"Chiudi\"],\"zJw+jA\":[\"imposta modalità: \",[\"0\"]],\"zebeLu\":[\"Inserisci nome utente oper\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Formato pattern non valido. Usa il formato nick!user@host (wildcards * consentiti)\"],\"+6NQQA\":[\"Canale di supporto generale\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Disconnetti\"],\"+cyFdH\":[\"Messaggio predefinito quando ci si segna come assenti\"],\"+mVPqU\":[\"Mostra la formattazione Markdown nei messaggi\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Il tuo nome utente account per l'autenticazione\"],\"+yPBXI\":[\"Scegli file\"],\"+zy2Nq\":[\"Tipo\"],\"/09cao\":[\"Sicurezza link bassa (Livello \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Gli utenti esterni non possono inviare messaggi\"],\"/6BzZF\":[\"Attiva/Disattiva lista membri\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"L'utente è assente\"],\"/XQgft\":[\"Scopri\"],\"/cF7Rs\":[\"Volume\"],\"/dqduX\":[\"Pagina successiva\"],\"/fc3q4\":[\"Tutto il contenuto\"],\"/kISDh\":[\"Abilita suoni di notifica\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Riproduci suoni per menzioni e messaggi\"],\"0/0ZGA\":[\"Maschera nome canale\"],\"0D6j7U\":[\"Scopri di più sulle regole personalizzate →\"],\"0XsHcR\":[\"Espelli utente\"],\"0ZpE//\":[\"Ordina per utenti\"],\"0bEPwz\":[\"Imposta assente\"],\"0dGkPt\":[\"Espandi lista canali\"],\"0gS7M5\":[\"Nome visualizzato\"],\"0kS+M8\":[\"EsempioRET\"],\"0rgoY7\":[\"Connettiti solo ai server che scegli\"],\"0wdd7X\":[\"Entra\"],\"0wkVYx\":[\"Messaggi privati\"],\"111uHX\":[\"Anteprima link\"],\"196EG4\":[\"Elimina chat privata\"],\"1DSr1i\":[\"Registra un account\"],\"1O/24y\":[\"Attiva/Disattiva lista canali\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Avviso link esterno\"],\"1ZC/dv\":[\"Nessuna menzione o messaggio non letto\"],\"1pO1zi\":[\"Il nome del server è obbligatorio\"],\"1uwfzQ\":[\"Visualizza topic del canale\"],\"268g7c\":[\"Inserisci nome visualizzato\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Gli operatori del server sulla rete potrebbero leggere i tuoi messaggi\"],\"2FYpfJ\":[\"Altro\"],\"2HF1Y2\":[[\"inviter\"],\" ha invitato \",[\"target\"],\" a unirsi a \",[\"channel\"]],\"2I70QL\":[\"Visualizza informazioni profilo utente\"],\"2QYdmE\":[\"Utenti:\"],\"2QpEjG\":[\"è uscito\"],\"2YE223\":[\"Messaggio in #\",[\"0\"],\" (Invio per nuova riga, Shift+Invio per inviare)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Usa password del server\"],\"2iTmdZ\":[\"Archiviazione locale:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Rigoroso – Protezione più aggressiva\"],\"2uDhbA\":[\"Inserisci il nome utente da invitare\"],\"2ygf/L\":[\"← Indietro\"],\"2zEgxj\":[\"Cerca GIF...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Rinomina canale\"],\"3THokf\":[\"Utente con diritto di parola\"],\"3TSz9S\":[\"Minimizza\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Nome visualizzato del canale\"],\"3ryuFU\":[\"Segnalazioni di crash opzionali per migliorare l'app\"],\"3uBF/8\":[\"Chiudi visualizzatore\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Inserisci nome account...\"],\"4/Rr0R\":[\"Invita un utente nel canale corrente\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Regole\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Profilo flood (+F)\"],\"4RZQRK\":[\"Cosa stai facendo?\"],\"4hfTrB\":[\"Nickname\"],\"4n99LO\":[\"Già in \",[\"0\"]],\"4t6vMV\":[\"Passa automaticamente a riga singola per messaggi brevi\"],\"4vsHmf\":[\"Tempo (min)\"],\"5+INAX\":[\"Evidenzia i messaggi che ti menzionano\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Nome oper\"],\"678PKt\":[\"Nome rete\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Non in linea\"],\"6CO3WE\":[\"Password richiesta per entrare. Lascia vuoto per rimuovere la chiave.\"],\"6HhMs3\":[\"Messaggio di uscita\"],\"6V3Ea3\":[\"Copiato\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Mostra meno\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Inserisci password oper...\"],\"7+IHTZ\":[\"Nessun file scelto\"],\"73hrRi\":[\"nick!user@host (es., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Invia messaggio privato\"],\"7U1W7c\":[\"Molto rilassato\"],\"7Y1YQj\":[\"Nome reale:\"],\"7YHArF\":[\"— apri nel visualizzatore\"],\"7fjnVl\":[\"Cerca utenti...\"],\"7jL88x\":[\"Eliminare questo messaggio? Questa azione non può essere annullata.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"A cosa stai pensando?\"],\"7sEpu1\":[\"Membri — \",[\"0\"]],\"7sNhEz\":[\"Nome utente\"],\"8H0Q+x\":[\"Scopri di più sui profili →\"],\"8Phu0A\":[\"Mostra quando gli utenti cambiano soprannome\"],\"8XTG9e\":[\"Inserisci password oper\"],\"8XsV2J\":[\"Riprova invio\"],\"8ZsakT\":[\"Password\"],\"8kR84m\":[\"Stai per aprire un link esterno:\"],\"8lCgih\":[\"Rimuovi regola\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"si è unito\"],\"other\":[\"si è unito \",[\"joinCount\"],\" volte\"]}]],\"9BMLnJ\":[\"Riconnetti al server\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Aggiungi reazione\"],\"9PQ8m2\":[\"G-Line (ban globale)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Rimuovi pattern\"],\"9bG48P\":[\"Invio in corso\"],\"9f5f0u\":[\"Domande sulla privacy? Contattaci:\"],\"9unqs3\":[\"Assente:\"],\"9v3hwv\":[\"Nessun server trovato.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Connessione in corso\"],\"A1taO8\":[\"Cerca\"],\"A2adVi\":[\"Invia notifiche di digitazione\"],\"A9Rhec\":[\"Nome canale\"],\"AWOSPo\":[\"Ingrandisci\"],\"AXSpEQ\":[\"Oper alla connessione\"],\"AeXO77\":[\"Account\"],\"AhNP40\":[\"Cerca posizione\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Nickname cambiato\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Annulla risposta\"],\"ApSx0O\":[\"Trovati \",[\"0\"],\" messaggi corrispondenti a \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Nessun risultato trovato\"],\"AyNqAB\":[\"Mostra tutti gli eventi del server in chat\"],\"B/QqGw\":[\"Lontano dalla tastiera\"],\"B8AaMI\":[\"Questo campo è obbligatorio\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Il server non supporta il filtro LIST avanzato\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" e altri \",[\"3\"],\" stanno scrivendo...\"],\"BGul2A\":[\"Hai modifiche non salvate. Sei sicuro di voler chiudere senza salvare?\"],\"BIf9fi\":[\"Il tuo messaggio di stato\"],\"BZz3md\":[\"Il tuo sito web personale\"],\"Bgm/H7\":[\"Consenti l'inserimento di più righe di testo\"],\"BiQIl1\":[\"Fissa questa conversazione privata\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Clicca per andare al messaggio\"],\"Bowq3c\":[\"Solo gli operatori possono cambiare l'argomento\"],\"Btozzp\":[\"Questa immagine è scaduta\"],\"Bycfjm\":[\"Totale: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Copia JSON completo\"],\"C9L9wL\":[\"Raccolta dati\"],\"CDq4wC\":[\"Modera utente\"],\"CHVRxG\":[\"Messaggio a @\",[\"0\"],\" (Shift+Invio per nuova riga)\"],\"CN9zdR\":[\"Nome oper e password sono obbligatori\"],\"CW3sYa\":[\"Aggiungi reazione \",[\"emoji\"]],\"CaAkqd\":[\"Mostra disconnessioni\"],\"CbvaYj\":[\"Banna per nickname\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Seleziona un canale\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normale\"],\"D+NlUC\":[\"Sistema\"],\"D28t6+\":[\"è entrato e uscito\"],\"DB8zMK\":[\"Applica\"],\"DBcWHr\":[\"File audio di notifica personalizzato\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Anteprime multimediali\"],\"Dj4pSr\":[\"Scegli una password sicura\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Ricerca...\"],\"Du2T2f\":[\"Impostazione non trovata\"],\"DwsSVQ\":[\"Applica filtri e aggiorna\"],\"E3W/zd\":[\"Soprannome predefinito\"],\"E6nRW7\":[\"Copia URL\"],\"E703RG\":[\"Modi:\"],\"EAeu1Z\":[\"Invia invito\"],\"EFKJQT\":[\"Impostazione\"],\"EGPQBv\":[\"Regole flood personalizzate (+f)\"],\"ELik0r\":[\"Vedi l'informativa completa sulla privacy\"],\"EPbeC2\":[\"Visualizza o modifica il topic del canale\"],\"EQCDNT\":[\"Inserisci nome utente oper...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Trovato 1 messaggio corrispondente a \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Immagine successiva\"],\"EdQY6l\":[\"Nessuno\"],\"EnqLYU\":[\"Cerca server...\"],\"F0OKMc\":[\"Modifica server\"],\"F6Int2\":[\"Abilita evidenziazioni\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Utenti max.\"],\"FUU/hZ\":[\"Controlla quanti media esterni vengono caricati nella chat.\"],\"Fdp03t\":[\"attivo\"],\"FfPWR0\":[\"Finestra\"],\"FjkaiT\":[\"Riduci\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Cosa significa:\"],\"FolHNl\":[\"Gestisci il tuo account e l'autenticazione\"],\"Fp2Dif\":[\"Uscito dal server\"],\"G5KmCc\":[\"GZ-Line (Z-Line globale)\"],\"GDs0lz\":[\"<0>Rischio:</0> Informazioni sensibili (messaggi, conversazioni private, dati di autenticazione) potrebbero essere esposte ad amministratori di rete o attaccanti posizionati tra i server IRC.\"],\"GR+2I3\":[\"Aggiungi maschera di invito (es. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Chiudi avvisi server in finestra separata\"],\"GlHnXw\":[\"Cambio nick fallito: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Anteprima:\"],\"GtmO8/\":[\"da\"],\"GtuHUQ\":[\"Rinomina questo canale sul server. Tutti gli utenti vedranno il nuovo nome.\"],\"GuGfFX\":[\"Attiva/Disattiva ricerca\"],\"GxkJXS\":[\"Caricamento...\"],\"GzbwnK\":[\"È entrato nel canale\"],\"GzsUDB\":[\"Profilo esteso\"],\"H/PnT8\":[\"Inserisci emoji\"],\"H6Izzl\":[\"Il tuo codice colore preferito\"],\"H9jIv+\":[\"Mostra entrate/uscite\"],\"HAKBY9\":[\"Carica file\"],\"HdE1If\":[\"Canale\"],\"Hk4AW9\":[\"Il tuo nome visualizzato preferito\"],\"HmHDk7\":[\"Seleziona membro\"],\"HrQzPU\":[\"Canali su \",[\"networkName\"]],\"I2tXQ5\":[\"Messaggio a @\",[\"0\"],\" (Invio per nuova riga, Shift+Invio per inviare)\"],\"I6bw/h\":[\"Banna utente\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Abilita notifiche\"],\"I9D72S\":[\"Sei sicuro di voler eliminare questo messaggio? Questa azione non può essere annullata.\"],\"IA+1wo\":[\"Mostra quando gli utenti vengono espulsi dai canali\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Salva modifiche\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" e \",[\"2\"],\" stanno scrivendo...\"],\"IgrLD/\":[\"Pausa\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Rispondi\"],\"IoHMnl\":[\"Il valore massimo è \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Connessione...\"],\"J5T9NW\":[\"Informazioni utente\"],\"J8Y5+z\":[\"Ops! La rete si è divisa! ⚠️\"],\"JBHkBA\":[\"Ha lasciato il canale\"],\"JCwL0Q\":[\"Inserisci motivo (opzionale)\"],\"JFciKP\":[\"Attiva/Disattiva\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Cambia il nome del canale (solo operatori)\"],\"JcD7qf\":[\"Altre azioni\"],\"JdkA+c\":[\"Segreto (+s)\"],\"Jmu12l\":[\"Canali del server\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Abilita Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Nessun dato WHOIS disponibile\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Elimina messaggio\"],\"KKBlUU\":[\"Incorpora\"],\"KM0pLb\":[\"Benvenuto nel canale!\"],\"KR6W2h\":[\"Smetti di ignorare utente\"],\"KV+Bi1\":[\"Solo su invito (+i)\"],\"KdCtwE\":[\"Quanti secondi monitorare l'attività flood prima di reimpostare i contatori\"],\"Kkezga\":[\"Password del server\"],\"KsiQ/8\":[\"Gli utenti devono essere invitati per entrare\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Informazioni sul canale\"],\"LC1a7n\":[\"Il server IRC ha segnalato che i suoi collegamenti tra server hanno un basso livello di sicurezza. Ciò significa che quando i tuoi messaggi vengono instradati tra i server IRC nella rete, potrebbero non essere correttamente cifrati o i certificati SSL/TLS potrebbero non essere validati correttamente.\"],\"LNfLR5\":[\"Mostra espulsioni\"],\"LQb0W/\":[\"Mostra tutti gli eventi\"],\"LU7/yA\":[\"Nome alternativo per la visualizzazione. Può contenere spazi, emoji e caratteri speciali. Il nome reale (\",[\"channelName\"],\") verrà comunque usato per i comandi IRC.\"],\"LUb9O7\":[\"È richiesta una porta del server valida\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Informativa sulla privacy\"],\"LcuSDR\":[\"Gestisci le informazioni del tuo profilo e i metadati\"],\"LqLS9B\":[\"Mostra cambi di soprannome\"],\"LsDQt2\":[\"Impostazioni canale\"],\"LtI9AS\":[\"Proprietario\"],\"LuNhhL\":[\"ha reagito a questo messaggio\"],\"M/AZNG\":[\"URL dell'immagine del tuo avatar\"],\"M/WIer\":[\"Invia messaggio\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Nome:\"],\"MHk+7g\":[\"Immagine precedente\"],\"MRorGe\":[\"Messaggio privato\"],\"MVbSGP\":[\"Finestra temporale (secondi)\"],\"MkpcsT\":[\"I tuoi messaggi e impostazioni sono archiviati localmente sul tuo dispositivo\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Segna come bot, di solito 'on' o vuoto\"],\"N7TQbE\":[\"Invita utente in \",[\"channelName\"]],\"NCca/o\":[\"Inserisci nickname predefinito...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Mostra tutti i media esterni. Qualsiasi URL potrebbe causare una richiesta a un server sconosciuto.\"],\"Nt+9O7\":[\"Usa WebSocket invece di TCP grezzo\"],\"NxIHzc\":[\"Espelli utente\"],\"O+v/cL\":[\"Sfoglia tutti i canali del server\"],\"ODwSCk\":[\"Invia un GIF\"],\"OGQ5kK\":[\"Configura suoni di notifica ed evidenziazioni\"],\"OIPt1Z\":[\"Mostra o nascondi la barra laterale dei membri\"],\"OKSNq/\":[\"Molto rigido\"],\"ONWvwQ\":[\"Carica\"],\"OVKoQO\":[\"La tua password account per l'autenticazione\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Portare IRC nel futuro\"],\"OhCpra\":[\"Imposta un topic…\"],\"OkltoQ\":[\"Banna \",[\"username\"],\" per nickname (impedisce di rientrare con lo stesso nick)\"],\"P+t/Te\":[\"Nessun dato aggiuntivo\"],\"P42Wcc\":[\"Sicuro\"],\"PD38l0\":[\"Anteprima avatar canale\"],\"PD9mEt\":[\"Scrivi un messaggio...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Apri impostazioni configurazione canale\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"L'argomento visualizzato per questo canale. Tutti gli utenti possono vederlo.\"],\"PZCecv\":[\"Anteprima PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 volta\"],\"other\":[[\"c\"],\" volte\"]}]],\"PguS2C\":[\"Aggiungi maschera di eccezione (es. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Visualizzazione di \",[\"displayedChannelsCount\"],\" su \",[\"0\"],\" canali\"],\"PqhVlJ\":[\"Banna utente (per hostmask)\"],\"Q+chwU\":[\"Nome utente:\"],\"Q6hhn8\":[\"Preferenze\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Inserisci un nome utente\"],\"QGqSZ2\":[\"Colore e formattazione\"],\"QJQd1J\":[\"Modifica profilo\"],\"QSzGDE\":[\"Inattivo\"],\"QUlny5\":[\"Benvenuto su \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Leggi di più\"],\"QuSkCF\":[\"Filtra canali...\"],\"QwUrDZ\":[\"ha cambiato il topic in: \",[\"topic\"]],\"R0UH07\":[\"Immagine \",[\"0\"],\" di \",[\"1\"]],\"R7SsBE\":[\"Disattiva audio\"],\"R8rf1X\":[\"Clicca per impostare il topic\"],\"RArB3D\":[\"è stato espulso da \",[\"channelName\"],\" da \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Scopri il mondo di IRC con ObsidianIRC\"],\"RMMaN5\":[\"Moderato (+m)\"],\"RWw9Lg\":[\"Chiudi finestra\"],\"RZ2BuZ\":[\"La registrazione dell'account \",[\"account\"],\" richiede verifica: \",[\"message\"]],\"RySp6q\":[\"Nascondi commenti\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Il nickname è obbligatorio\"],\"SPVjfj\":[\"Il valore predefinito sarà 'nessun motivo' se lasciato vuoto\"],\"SQKPvQ\":[\"Invita utente\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Scegli un profilo di protezione flood predefinito. Questi profili forniscono impostazioni di protezione bilanciate per diversi casi d'uso.\"],\"Slr+3C\":[\"Utenti min.\"],\"Spnlre\":[\"Hai invitato \",[\"target\"],\" a unirsi a \",[\"channel\"]],\"T/ckN5\":[\"Apri nel visualizzatore\"],\"T91vKp\":[\"Riproduci\"],\"TV2Wdu\":[\"Scopri come gestiamo i tuoi dati e proteggiamo la tua privacy.\"],\"TgFpwD\":[\"Applicazione...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Nessuna modifica\"],\"TtserG\":[\"Inserisci nome reale\"],\"Ttz9J1\":[\"Inserisci password...\"],\"Tz0i8g\":[\"Impostazioni\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagisci\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Salva impostazioni\"],\"UV5hLB\":[\"Nessun ban trovato\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Messaggi di stato\"],\"Ue3uny\":[\"Predefinito (nessun profilo)\"],\"UkARhe\":[\"Normale – Protezione standard\"],\"Umn7Cj\":[\"Ancora nessun commento. Sii il primo!\"],\"UtUIRh\":[[\"0\"],\" messaggi precedenti\"],\"UwzP+U\":[\"Connessione sicura\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Proprietario del canale\"],\"V4qgxE\":[\"Creato prima (min fa)\"],\"V8yTm6\":[\"Cancella ricerca\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - Portare IRC nel futuro\"],\"VJScHU\":[\"Motivo\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Silenzia notifiche\"],\"VbyRUy\":[\"Commenti\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Impostato da:\"],\"VqnIZz\":[\"Visualizza la nostra informativa sulla privacy e le pratiche sui dati\"],\"VrMygG\":[\"La lunghezza minima è \",[\"0\"]],\"VrnTui\":[\"I tuoi pronomi, mostrati nel profilo\"],\"W8E3qn\":[\"Account autenticato\"],\"WAakm9\":[\"Elimina canale\"],\"WFxTHC\":[\"Aggiungi maschera di ban (es. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"L'host del server è obbligatorio\"],\"WRYdXW\":[\"Posizione audio\"],\"WUOH5B\":[\"Ignora utente\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Mostra 1 altro elemento\"],\"other\":[\"Mostra \",[\"1\"],\" altri elementi\"]}]],\"Weq9zb\":[\"Generale\"],\"Wfj7Sk\":[\"Attiva o disattiva i suoni delle notifiche\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profilo utente\"],\"X6S3lt\":[\"Cerca impostazioni, canali, server...\"],\"XEHan5\":[\"Continua comunque\"],\"XI1+wb\":[\"Formato non valido\"],\"XIXeuC\":[\"Messaggio a @\",[\"0\"]],\"XMS+k4\":[\"Avvia messaggio privato\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Rimuovi fissaggio chat privata\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Visualizzazione\"],\"Xp2n93\":[\"Mostra media dall'host di file attendibile del tuo server. Nessuna richiesta viene inviata a servizi esterni.\"],\"XvjC4F\":[\"Salvataggio...\"],\"Y/qryO\":[\"Nessun utente trovato corrispondente alla ricerca\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Registrazione dell'account \",[\"account\"],\" riuscita: \",[\"message\"]],\"YEfzvP\":[\"Argomento protetto (+t)\"],\"YQOn6a\":[\"Comprimi lista membri\"],\"YRCoE9\":[\"Operatore del canale\"],\"YURQaF\":[\"Vedi profilo\"],\"YdBSvr\":[\"Controlla la visualizzazione dei media e dei contenuti esterni\"],\"Yj6U3V\":[\"Nessun server centrale:\"],\"YjvpGx\":[\"Pronomi\"],\"YqH4l4\":[\"Nessuna chiave\"],\"YyUPpV\":[\"Account:\"],\"ZJSWfw\":[\"Messaggio mostrato alla disconnessione dal server\"],\"ZR1dJ4\":[\"Inviti\"],\"ZdWg0V\":[\"Apri nel browser\"],\"ZhRBbl\":[\"Cerca messaggi…\"],\"Zmcu3y\":[\"Filtri avanzati\"],\"a2/8e5\":[\"Argomento impostato dopo (min fa)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Pagina precedente\"],\"aJTbXX\":[\"Password oper\"],\"aQryQv\":[\"Il pattern esiste già\"],\"aW9pLN\":[\"Numero massimo di utenti nel canale. Lascia vuoto per nessun limite.\"],\"ah4fmZ\":[\"Mostra anche anteprime da YouTube, Vimeo, SoundCloud e servizi noti simili.\"],\"aifXak\":[\"Nessun media in questo canale\"],\"ap2zBz\":[\"Rilassato\"],\"az8lvo\":[\"Disattivato\"],\"azXSNo\":[\"Espandi lista membri\"],\"azdliB\":[\"Accedi a un account\"],\"b26wlF\":[\"lei/la\"],\"bD/+Ei\":[\"Rigido\"],\"bQ6BJn\":[\"Configura regole dettagliate di protezione flood. Ogni regola specifica il tipo di attività da monitorare e l'azione da intraprendere quando le soglie vengono superate.\"],\"beV7+y\":[\"L'utente riceverà un invito per unirsi a \",[\"channelName\"],\".\"],\"bk84cH\":[\"Messaggio di assenza\"],\"bkHdLj\":[\"Aggiungi server IRC\"],\"bmQLn5\":[\"Aggiungi regola\"],\"bwRvnp\":[\"Azione\"],\"c8+EVZ\":[\"Account verificato\"],\"cGYUlD\":[\"Nessuna anteprima media caricata.\"],\"cLF98o\":[\"Mostra commenti (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Nessun utente disponibile\"],\"cSgpoS\":[\"Fissa chat privata\"],\"cde3ce\":[\"Messaggio a <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Copia output formattato\"],\"cl/A5J\":[\"Benvenuto su \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Elimina\"],\"coPLXT\":[\"Non archiviamo le tue comunicazioni IRC sui nostri server\"],\"crYH/6\":[\"Player SoundCloud\"],\"d3sis4\":[\"Aggiungi server\"],\"d9aN5k\":[\"Rimuovi \",[\"username\"],\" dal canale\"],\"dEgA5A\":[\"Annulla\"],\"dGi1We\":[\"Rimuovi il fissaggio di questa conversazione privata\"],\"dJVuyC\":[\"ha lasciato \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"a\"],\"dXqxlh\":[\"<0>⚠️ Rischio di sicurezza!</0> Questa connessione potrebbe essere vulnerabile a intercettazioni o attacchi man-in-the-middle.\"],\"da9Q/R\":[\"Modalità canale modificate\"],\"dhJN3N\":[\"Mostra commenti\"],\"dj2xTE\":[\"Ignora notifica\"],\"dpCzmC\":[\"Impostazioni protezione flood\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Vuoi aprire questo link in una nuova scheda?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Modifica\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Carica un'immagine o fornisci un URL con sostituzione opzionale \",[\"size\"]],\"edBbee\":[\"Banna \",[\"username\"],\" per hostmask (impedisce di rientrare dallo stesso IP/host)\"],\"ekfzWq\":[\"Impostazioni utente\"],\"elPDWs\":[\"Personalizza la tua esperienza con il client IRC\"],\"eu2osY\":[\"<0>💡 Raccomandazione:</0> Procedi solo se ti fidi di questo server e comprendi i rischi. Evita di condividere informazioni sensibili o password su questa connessione.\"],\"euEhbr\":[\"Clicca per unirti a \",[\"channel\"]],\"ez3vLd\":[\"Abilita input multiriga\"],\"f0J5Ki\":[\"Le comunicazioni tra server potrebbero usare connessioni non cifrate\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Avvisa utente\"],\"fDOLLd\":[\"Nessun canale trovato.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Analisi anonime:\"],\"fq1GF9\":[\"Mostra quando gli utenti si disconnettono dal server\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Questo server supporta solo un tipo di connessione\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Lista di ignorati\"],\"gNzMrk\":[\"Avatar attuale\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Inserisci nickname...\"],\"gz6UQ3\":[\"Massimizza\"],\"h6razj\":[\"Escludi maschera nome canale\"],\"hG6jnw\":[\"Nessun topic impostato\"],\"hG89Ed\":[\"Immagine\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Porta\"],\"ha+Bz5\":[\"es., 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Quantità\"],\"hzdLuQ\":[\"Solo gli utenti con voice o superiore possono parlare\"],\"i0qMbr\":[\"Home\"],\"iDNBZe\":[\"Notifiche\"],\"iH8pgl\":[\"Indietro\"],\"iL9SZg\":[\"Banna utente (per nickname)\"],\"iNt+3c\":[\"Torna all'immagine\"],\"iQvi+a\":[\"Non avvisarmi sulla bassa sicurezza del link per questo server\"],\"iSLIjg\":[\"Connetti\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Host del server\"],\"idD8Ev\":[\"Salvato\"],\"iivqkW\":[\"Connesso dal\"],\"ij+Elv\":[\"Anteprima immagine\"],\"ilIWp7\":[\"Attiva/Disattiva notifiche\"],\"iuaqvB\":[\"Usa * come wildcard. Esempi: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Banna per maschera host\"],\"jA4uoI\":[\"Argomento:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Motivo (opzionale)\"],\"jUV7CU\":[\"Carica avatar\"],\"jW5Uwh\":[\"Controlla quanti media esterni vengono caricati. Disattivato / Sicuro / Fonti affidabili / Tutto il contenuto.\"],\"jXzms5\":[\"Opzioni allegato\"],\"jZlrte\":[\"Colore\"],\"jfC/xh\":[\"Contatti\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Carica messaggi precedenti\"],\"k3ID0F\":[\"Filtra membri…\"],\"k65gsE\":[\"Analisi approfondita\"],\"k7Zgob\":[\"Annulla connessione\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Nessun invito trovato\"],\"kCLEPU\":[\"Connesso a\"],\"kF5LKb\":[\"Pattern ignorati:\"],\"kGeOx/\":[\"Unisciti a \",[\"0\"]],\"kITKr8\":[\"Caricamento modalità canale...\"],\"kPpPsw\":[\"Sei un IRC Operator\"],\"kWJmRL\":[\"Tu\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Copia JSON\"],\"krViRy\":[\"Clicca per copiare come JSON\"],\"ks71ra\":[\"Eccezioni\"],\"kw4lRv\":[\"Semi-operatore del canale\"],\"kxgIRq\":[\"Seleziona o aggiungi un canale per iniziare.\"],\"ky6dWe\":[\"Anteprima avatar\"],\"l+GxCv\":[\"Caricamento canali...\"],\"l+IUVW\":[\"Verifica dell'account \",[\"account\"],\" riuscita: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"si è riconnesso\"],\"other\":[\"si è riconnesso \",[\"reconnectCount\"],\" volte\"]}]],\"l5jmzx\":[[\"0\"],\" e \",[\"1\"],\" stanno scrivendo...\"],\"lHy8N5\":[\"Caricamento altri canali...\"],\"lbpf14\":[\"Entra in \",[\"value\"]],\"lfFsZ4\":[\"Canali\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Nome account\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Carica immagine\"],\"loQxaJ\":[\"Sono tornato\"],\"lvfaxv\":[\"HOME\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Aggiungi\"],\"m8flAk\":[\"Anteprima (non ancora caricata)\"],\"mEPxTp\":[\"<0>⚠️ Attenzione!</0> Apri solo link da fonti attendibili. I link malevoli possono compromettere la tua sicurezza o privacy.\"],\"mHGdhG\":[\"Informazioni sul server\"],\"mHS8lb\":[\"Messaggio in #\",[\"0\"]],\"mMYBD9\":[\"Ampio – Portata di protezione estesa\"],\"mTGsPd\":[\"Argomento del canale\"],\"mU8j6O\":[\"Nessun messaggio esterno (+n)\"],\"mZp8FL\":[\"Ritorno automatico alla singola riga\"],\"mdQu8G\":[\"IlTuoNickname\"],\"miSSBQ\":[\"Commenti (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Utente autenticato\"],\"mwtcGl\":[\"Chiudi commenti\"],\"mzI/c+\":[\"Scarica\"],\"n3fGRk\":[\"impostato da \",[\"0\"]],\"nE9jsU\":[\"Rilassato – Protezione meno aggressiva\"],\"nNflMD\":[\"Abbandona canale\"],\"nPXkBi\":[\"Caricamento dati WHOIS...\"],\"nQnxxF\":[\"Messaggio in #\",[\"0\"],\" (Shift+Invio per nuova riga)\"],\"nWMRxa\":[\"Rimuovi fissaggio\"],\"nkC032\":[\"Nessun profilo flood\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Invia un messaggio di avviso a \",[\"username\"]],\"o9ylQi\":[\"Cerca GIF per iniziare\"],\"oFGkER\":[\"Avvisi del server\"],\"oOi11l\":[\"Vai in fondo\"],\"oQEzQR\":[\"Nuovo messaggio privato\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"In linea\"],\"oal760\":[\"Sono possibili attacchi man-in-the-middle sui link del server\"],\"oeqmmJ\":[\"Fonti attendibili\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Predefinito\"],\"p0Z69r\":[\"Il pattern non può essere vuoto\"],\"p1KgtK\":[\"Caricamento audio non riuscito\"],\"p59pEv\":[\"Dettagli aggiuntivi\"],\"p7sRI6\":[\"Avvisa gli altri quando stai scrivendo\"],\"pBm1od\":[\"Canale segreto\"],\"pNmiXx\":[\"Il tuo soprannome predefinito per tutti i server\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Password account\"],\"peNE68\":[\"Permanente\"],\"plhHQt\":[\"Nessun dato\"],\"pm6+q5\":[\"Avviso di sicurezza\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Informazioni aggiuntive\"],\"q0cR4S\":[\"ora è conosciuto come **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Il canale non apparirà nei comandi LIST o NAMES\"],\"qLpTm/\":[\"Rimuovi reazione \",[\"emoji\"]],\"qVkGWK\":[\"Fissa\"],\"qY8wNa\":[\"Homepage\"],\"qb0xJ7\":[\"Wildcard: * corrisponde a qualsiasi sequenza, ? a un singolo carattere. Esempi: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Chiave canale (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Nessun limite\"],\"r1W2AS\":[\"Immagine dal filehost\"],\"rIPR2O\":[\"Argomento impostato prima (min fa)\"],\"rMMSYo\":[\"La lunghezza massima è \",[\"0\"]],\"rWtzQe\":[\"La rete si è divisa e riconnessa. ✅\"],\"rYG2u6\":[\"Attendere...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Anteprima\"],\"rjGI/Q\":[\"Privacy\"],\"rk8iDX\":[\"Caricamento GIF...\"],\"rn6SBY\":[\"Attiva audio\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"È stato espulso dal canale\"],\"s8cATI\":[\"si è unito a \",[\"channelName\"]],\"sCO9ue\":[\"La connessione a <0>\",[\"serverName\"],\"</0> presenta le seguenti problematiche di sicurezza:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"ora è conosciuto come **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" ti ha invitato a unirti a \",[\"channel\"]],\"sby+1/\":[\"Clicca per copiare\"],\"sfN25C\":[\"Il tuo nome reale o completo\"],\"sliuzR\":[\"Apri link\"],\"sqrO9R\":[\"Menzioni personalizzate\"],\"sr6RdJ\":[\"Multiriga con Shift+Invio\"],\"swrCpB\":[\"Il canale è stato rinominato da \",[\"oldName\"],\" a \",[\"newName\"],\" da \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Avanzato\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Rimuovi\"],\"t47eHD\":[\"Il tuo identificatore unico su questo server\"],\"tAkAh0\":[\"URL con sostituzione opzionale \",[\"size\"],\". Esempio: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Mostra o nascondi la barra laterale dei canali\"],\"tfDRzk\":[\"Salva\"],\"tiBsJk\":[\"ha lasciato \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"ha abbandonato (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Il nick {nick} è già in uso, nuovo tentativo con {newNick}\"],\"u0a8B4\":[\"Autenticarsi come operatore IRC per l'accesso amministrativo\"],\"u0rWFU\":[\"Creato dopo (min fa)\"],\"u72w3t\":[\"Utenti e modelli da ignorare\"],\"u7jc2L\":[\"ha abbandonato\"],\"uAQUqI\":[\"Stato\"],\"uB85T3\":[\"Salvataggio fallito: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"Server IRC:\"],\"usSSr/\":[\"Livello zoom\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Shift+Invio per le nuove righe (Invio invia)\"],\"vERlcd\":[\"Profilo\"],\"vK0RL8\":[\"Nessun argomento\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Lingua\"],\"vaHYxN\":[\"Nome reale\"],\"vhjbKr\":[\"Assente\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[\"client \",[\"title\"]],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Valore non valido\"],\"wFjjxZ\":[\"è stato espulso da \",[\"channelName\"],\" da \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nessuna eccezione di ban trovata\"],\"wPrGnM\":[\"Amministratore del canale\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Mostra quando gli utenti entrano o escono dai canali\"],\"whqZ9r\":[\"Parole o frasi aggiuntive da evidenziare\"],\"wm7RV4\":[\"Suono di notifica\"],\"wz/Yoq\":[\"I tuoi messaggi potrebbero essere intercettati durante l'instradamento tra server\"],\"xCJdfg\":[\"Cancella\"],\"xUHRTR\":[\"Autentica automaticamente come operatore alla connessione\"],\"xWHwwQ\":[\"Ban\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Sono supportati solo websocket sicuri\"],\"xdtXa+\":[\"nome-canale\"],\"xfXC7q\":[\"Canali testuali\"],\"xlCYOE\":[\"Caricamento messaggi...\"],\"xlhswE\":[\"Il valore minimo è \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Aggiungi una parola o frase...\"],\"xuRqRq\":[\"Limite client (+l)\"],\"xwF+7J\":[[\"0\"],\" sta scrivendo...\"],\"yNeucF\":[\"Questo server non supporta i metadati del profilo esteso (estensione IRCv3 METADATA). Campi come avatar, nome visualizzato e stato non sono disponibili.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Avatar del canale\"],\"yQE2r9\":[\"Caricamento\"],\"ySU+JY\":[\"tuo@email.com\"],\"yTX1Rt\":[\"Nome utente operatore\"],\"yYOzWD\":[\"log\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"Password operatore IRC\"],\"ygCKqB\":[\"Stop\"],\"ymDxJx\":[\"Nome utente operatore IRC\"],\"yrpRsQ\":[\"Ordina per nome\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Chiudi\"],\"zJw+jA\":[\"imposta modalità: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Inserisci nome utente oper\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"잘못된 패턴 형식입니다. nick!user@host 형식을 사용하세요 (와일드카드 * 허용)\"],\"+6NQQA\":[\"일반 지원 채널\"],\"+6NyRG\":[\"클라이언트\"],\"+K0AvT\":[\"연결 끊기\"],\"+cyFdH\":[\"자리 비움 설정 시 기본 메시지\"],\"+mVPqU\":[\"메시지에서 마크다운 서식 렌더링\"],\"+vqCJH\":[\"인증을 위한 계정 사용자 이름\"],\"+yPBXI\":[\"파일 선택\"],\"+zy2Nq\":[\"유형\"],\"/09cao\":[\"낮은 링크 보안 (레벨 \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"채널 외부 사용자는 메시지를 보낼 수 없습니다\"],\"/6BzZF\":[\"멤버 목록 전환\"],\"/TNOPk\":[\"자리 비움 중\"],\"/XQgft\":[\"채널 탐색\"],\"/cF7Rs\":[\"볼륨\"],\"/dqduX\":[\"다음 페이지\"],\"/fc3q4\":[\"모든 콘텐츠\"],\"/kISDh\":[\"알림 소리 활성화\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"오디오\"],\"/rfkZe\":[\"멘션 및 메시지에 소리 재생\"],\"0/0ZGA\":[\"채널 이름 마스크\"],\"0D6j7U\":[\"사용자 정의 규칙에 대해 더 알아보기 →\"],\"0XsHcR\":[\"사용자 추방\"],\"0ZpE//\":[\"사용자 수순 정렬\"],\"0bEPwz\":[\"자리 비움 설정\"],\"0dGkPt\":[\"채널 목록 펼치기\"],\"0gS7M5\":[\"표시 이름\"],\"0kS+M8\":[\"예시네트워크\"],\"0rgoY7\":[\"직접 선택한 서버에만 연결합니다\"],\"0wdd7X\":[\"참여\"],\"0wkVYx\":[\"비공개 메시지\"],\"111uHX\":[\"링크 미리보기\"],\"196EG4\":[\"비공개 채팅 삭제\"],\"1DSr1i\":[\"계정 등록\"],\"1O/24y\":[\"채널 목록 전환\"],\"1VPJJ2\":[\"외부 링크 경고\"],\"1ZC/dv\":[\"읽지 않은 멘션이나 메시지가 없습니다\"],\"1pO1zi\":[\"서버 이름은 필수입니다\"],\"1uwfzQ\":[\"채널 주제 보기\"],\"268g7c\":[\"표시 이름 입력\"],\"2FOFq1\":[\"네트워크 서버 운영자가 메시지를 읽을 수 있습니다\"],\"2FYpfJ\":[\"더 보기\"],\"2HF1Y2\":[[\"inviter\"],\"이(가) \",[\"target\"],\"을(를) \",[\"channel\"],\"에 초대했습니다\"],\"2I70QL\":[\"사용자 프로필 정보 보기\"],\"2QYdmE\":[\"사용자:\"],\"2QpEjG\":[\"퇴장했습니다\"],\"2YE223\":[\"#\",[\"0\"],\"에 메시지 (Enter로 줄 바꿈, Shift+Enter로 전송)\"],\"2bimFY\":[\"서버 비밀번호 사용\"],\"2iTmdZ\":[\"로컬 저장소:\"],\"2odkwe\":[\"엄격 - 더 강력한 보호\"],\"2uDhbA\":[\"초대할 사용자 이름 입력\"],\"2ygf/L\":[\"← 뒤로\"],\"2zEgxj\":[\"GIF 검색...\"],\"3RdPhl\":[\"채널 이름 변경\"],\"3THokf\":[\"Voice 사용자\"],\"3TSz9S\":[\"최소화\"],\"3jBDvM\":[\"채널 표시 이름\"],\"3ryuFU\":[\"앱 개선을 위한 선택적 충돌 보고서\"],\"3uBF/8\":[\"뷰어 닫기\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"계정 이름 입력...\"],\"4/Rr0R\":[\"현재 채널에 사용자 초대\"],\"4EZrJN\":[\"규칙\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"플러드 프로필 (+F)\"],\"4RZQRK\":[\"지금 뭐 하세요?\"],\"4hfTrB\":[\"닉네임\"],\"4n99LO\":[\"이미 \",[\"0\"],\"에 있음\"],\"4t6vMV\":[\"짧은 메시지의 경우 자동으로 한 줄 모드로 전환\"],\"4vsHmf\":[\"시간 (분)\"],\"5+INAX\":[\"나를 멘션한 메시지 강조 표시\"],\"5R5Pv/\":[\"Oper 이름\"],\"678PKt\":[\"네트워크 이름\"],\"6Aih4U\":[\"오프라인\"],\"6CO3WE\":[\"채널 참여에 필요한 비밀번호입니다. 키를 제거하려면 비워두세요.\"],\"6HhMs3\":[\"QUIT 메시지\"],\"6V3Ea3\":[\"복사됨\"],\"6lGV3K\":[\"접기\"],\"6yFOEi\":[\"oper 비밀번호 입력...\"],\"7+IHTZ\":[\"파일 선택 안 함\"],\"73hrRi\":[\"nick!user@host (예: spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"비공개 메시지 보내기\"],\"7U1W7c\":[\"매우 완화\"],\"7Y1YQj\":[\"실명:\"],\"7YHArF\":[\"— 뷰어에서 열기\"],\"7fjnVl\":[\"사용자 검색...\"],\"7jL88x\":[\"이 메시지를 삭제하시겠습니까? 이 작업은 취소할 수 없습니다.\"],\"7nGhhM\":[\"무슨 생각을 하고 계신가요?\"],\"7sEpu1\":[\"멤버 — \",[\"0\"],\"명\"],\"7sNhEz\":[\"사용자 이름\"],\"8H0Q+x\":[\"프로필에 대해 더 알아보기 →\"],\"8Phu0A\":[\"사용자가 닉네임을 변경할 때 표시\"],\"8XTG9e\":[\"oper 비밀번호 입력\"],\"8XsV2J\":[\"다시 보내기\"],\"8ZsakT\":[\"비밀번호\"],\"8kR84m\":[\"외부 링크를 열려고 합니다:\"],\"8lCgih\":[\"규칙 제거\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"other\":[[\"joinCount\"],\"번 참가했음\"]}]],\"9BMLnJ\":[\"서버에 재연결\"],\"9OEgyT\":[\"반응 추가\"],\"9PQ8m2\":[\"G-Line (글로벌 밴)\"],\"9Qs99X\":[\"이메일:\"],\"9QupBP\":[\"패턴 제거\"],\"9bG48P\":[\"보내는 중\"],\"9f5f0u\":[\"개인정보 보호에 관한 문의사항이 있으신가요? 문의처:\"],\"9unqs3\":[\"자리비움:\"],\"9v3hwv\":[\"서버를 찾을 수 없습니다.\"],\"9zb2WA\":[\"연결 중\"],\"A1taO8\":[\"검색\"],\"A2adVi\":[\"입력 중 알림 보내기\"],\"A9Rhec\":[\"채널 이름\"],\"AWOSPo\":[\"확대\"],\"AXSpEQ\":[\"연결 시 Oper 인증\"],\"AeXO77\":[\"계정\"],\"AhNP40\":[\"탐색\"],\"Ai2U7L\":[\"호스트\"],\"AjBQnf\":[\"닉네임 변경됨\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"답장 취소\"],\"ApSx0O\":[\"\\\"\",[\"searchQuery\"],\"\\\"와 일치하는 메시지 \",[\"0\"],\"개 발견\"],\"AxPAXW\":[\"결과를 찾을 수 없습니다\"],\"AyNqAB\":[\"채팅에 모든 서버 이벤트 표시\"],\"B/QqGw\":[\"자리 비움 (AFK)\"],\"B8AaMI\":[\"이 필드는 필수입니다\"],\"BA2c49\":[\"서버가 고급 LIST 필터링을 지원하지 않습니다\"],\"BDKt3I\":[[\"0\"],\"님, \",[\"1\"],\"님, \",[\"2\"],\"님 외 \",[\"3\"],\"명이 입력 중...\"],\"BGul2A\":[\"저장되지 않은 변경 사항이 있습니다. 저장하지 않고 닫으시겠습니까?\"],\"BIf9fi\":[\"상태 메시지\"],\"BZz3md\":[\"개인 웹사이트\"],\"Bgm/H7\":[\"여러 줄 텍스트 입력 허용\"],\"BiQIl1\":[\"이 비공개 메시지 대화 고정\"],\"BlNZZ2\":[\"클릭하여 메시지로 이동\"],\"Bowq3c\":[\"운영자만 채널 주제를 변경할 수 있음\"],\"Btozzp\":[\"이 이미지가 만료되었습니다\"],\"Bycfjm\":[\"전체: \",[\"0\"]],\"C6IBQc\":[\"전체 JSON 복사\"],\"C9L9wL\":[\"데이터 수집\"],\"CDq4wC\":[\"사용자 제재\"],\"CHVRxG\":[\"@\",[\"0\"],\"에게 메시지 (Shift+Enter로 줄 바꿈)\"],\"CN9zdR\":[\"Oper 이름과 비밀번호는 필수입니다\"],\"CW3sYa\":[[\"emoji\"],\" 반응 추가\"],\"CaAkqd\":[\"QUIT 표시\"],\"CbvaYj\":[\"닉네임으로 차단\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"채널 선택\"],\"CsekCi\":[\"기본\"],\"D+NlUC\":[\"시스템\"],\"D28t6+\":[\"참여했다가 퇴장했습니다\"],\"DB8zMK\":[\"적용\"],\"DBcWHr\":[\"사용자 정의 알림 소리 파일\"],\"DTy9Xw\":[\"미디어 미리보기\"],\"Dj4pSr\":[\"안전한 비밀번호를 선택하세요\"],\"Du+zn+\":[\"검색 중...\"],\"Du2T2f\":[\"설정을 찾을 수 없습니다\"],\"DwsSVQ\":[\"필터 적용 및 새로 고침\"],\"E3W/zd\":[\"기본 닉네임\"],\"E6nRW7\":[\"URL 복사\"],\"E703RG\":[\"모드:\"],\"EAeu1Z\":[\"초대 보내기\"],\"EFKJQT\":[\"설정\"],\"EGPQBv\":[\"사용자 정의 플러드 규칙 (+f)\"],\"ELik0r\":[\"전체 개인정보 처리방침 보기\"],\"EPbeC2\":[\"채널 주제 보기 또는 편집\"],\"EQCDNT\":[\"oper 사용자 이름 입력...\"],\"EUvulZ\":[\"\\\"\",[\"searchQuery\"],\"\\\"와 일치하는 메시지 1개 발견\"],\"EatZYJ\":[\"다음 이미지\"],\"EdQY6l\":[\"없음\"],\"EnqLYU\":[\"서버 검색...\"],\"F0OKMc\":[\"서버 편집\"],\"F6Int2\":[\"강조 표시 활성화\"],\"FDoLyE\":[\"최대 사용자 수\"],\"FUU/hZ\":[\"채팅에서 로드할 외부 미디어의 범위를 제어하세요.\"],\"Fdp03t\":[\"켜기\"],\"FfPWR0\":[\"모달\"],\"FjkaiT\":[\"축소\"],\"FlqOE9\":[\"이것이 의미하는 바:\"],\"FolHNl\":[\"계정 및 인증 관리\"],\"Fp2Dif\":[\"서버에서 나갔습니다\"],\"G5KmCc\":[\"GZ-Line (글로벌 Z-Line)\"],\"GDs0lz\":[\"<0>위험:</0> 민감한 정보(메시지, 비공개 대화, 인증 정보)가 네트워크 관리자나 IRC 서버 간에 위치한 공격자에게 노출될 수 있습니다.\"],\"GR+2I3\":[\"초대 마스크 추가 (예: nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"팝업 서버 알림 닫기\"],\"GlHnXw\":[\"닉네임 변경 실패: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"미리보기:\"],\"GtmO8/\":[\"보낸 사람\"],\"GtuHUQ\":[\"서버에서 이 채널의 이름을 변경합니다. 모든 사용자에게 새 이름이 표시됩니다.\"],\"GuGfFX\":[\"검색 전환\"],\"GxkJXS\":[\"업로드 중...\"],\"GzbwnK\":[\"채널에 참여했습니다\"],\"GzsUDB\":[\"확장 프로필\"],\"H/PnT8\":[\"이모지 삽입\"],\"H6Izzl\":[\"선호하는 색상 코드\"],\"H9jIv+\":[\"입장/퇴장 표시\"],\"HAKBY9\":[\"파일 업로드\"],\"HdE1If\":[\"채널\"],\"Hk4AW9\":[\"선호하는 표시 이름\"],\"HmHDk7\":[\"멤버 선택\"],\"HrQzPU\":[[\"networkName\"],\"의 채널\"],\"I2tXQ5\":[\"@\",[\"0\"],\"에게 메시지 (Enter로 줄 바꿈, Shift+Enter로 전송)\"],\"I6bw/h\":[\"사용자 차단\"],\"I92Z+b\":[\"알림 활성화\"],\"I9D72S\":[\"이 메시지를 삭제하시겠습니까? 이 작업은 취소할 수 없습니다.\"],\"IA+1wo\":[\"사용자가 채널에서 추방될 때 표시\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"정보:\"],\"IUwGEM\":[\"변경 사항 저장\"],\"IVeGK6\":[[\"0\"],\"님, \",[\"1\"],\"님, \",[\"2\"],\"님이 입력 중...\"],\"IgrLD/\":[\"일시 정지\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"답장\"],\"IoHMnl\":[\"최대값은 \",[\"0\"],\"입니다\"],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"연결 중...\"],\"J5T9NW\":[\"사용자 정보\"],\"J8Y5+z\":[\"이런! 네트워크 분리! ⚠️\"],\"JBHkBA\":[\"채널을 나갔습니다\"],\"JCwL0Q\":[\"사유 입력 (선택 사항)\"],\"JFciKP\":[\"전환\"],\"JXGkhG\":[\"채널 이름 변경 (운영자 전용)\"],\"JcD7qf\":[\"추가 작업\"],\"JdkA+c\":[\"비밀 채널 (+s)\"],\"Jmu12l\":[\"서버 채널\"],\"JvQ++s\":[\"마크다운 활성화\"],\"K2jwh/\":[\"WHOIS 데이터를 사용할 수 없습니다\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"메시지 삭제\"],\"KKBlUU\":[\"임베드\"],\"KM0pLb\":[\"채널에 오신 것을 환영합니다!\"],\"KR6W2h\":[\"사용자 무시 해제\"],\"KV+Bi1\":[\"초대 전용 (+i)\"],\"KdCtwE\":[\"카운터를 초기화하기 전에 플러드 활동을 모니터링할 초 단위 시간\"],\"Kkezga\":[\"서버 비밀번호\"],\"KsiQ/8\":[\"채널 참여를 위해 초대가 필요합니다\"],\"L+gB/D\":[\"채널 정보\"],\"LC1a7n\":[\"IRC 서버에서 서버 간 링크의 보안 수준이 낮다고 보고했습니다. 즉, 메시지가 네트워크의 IRC 서버 간에 전달될 때 적절히 암호화되지 않거나 SSL/TLS 인증서가 올바르게 검증되지 않을 수 있습니다.\"],\"LNfLR5\":[\"추방 표시\"],\"LQb0W/\":[\"모든 이벤트 표시\"],\"LU7/yA\":[\"UI에 표시할 대체 이름입니다. 공백, 이모지, 특수 문자를 포함할 수 있습니다. IRC 명령에는 실제 채널 이름(\",[\"channelName\"],\")이 사용됩니다.\"],\"LUb9O7\":[\"올바른 서버 포트가 필요합니다\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"개인정보 처리방침\"],\"LcuSDR\":[\"프로필 정보 및 메타데이터 관리\"],\"LqLS9B\":[\"닉네임 변경 표시\"],\"LsDQt2\":[\"채널 설정\"],\"LtI9AS\":[\"소유자\"],\"LuNhhL\":[\"이 메시지에 반응했습니다\"],\"M/AZNG\":[\"아바타 이미지의 URL\"],\"M/WIer\":[\"메시지 보내기\"],\"M8er/5\":[\"이름:\"],\"MHk+7g\":[\"이전 이미지\"],\"MRorGe\":[\"사용자에게 PM\"],\"MVbSGP\":[\"시간 창 (초)\"],\"MkpcsT\":[\"메시지와 설정은 기기에 로컬로 저장됩니다\"],\"N/hDSy\":[\"봇으로 표시 - 보통 'on' 또는 비워두기\"],\"N7TQbE\":[[\"channelName\"],\"에 사용자 초대\"],\"NCca/o\":[\"기본 닉네임 입력...\"],\"Nqs6B9\":[\"모든 외부 미디어를 표시합니다. 모든 URL이 알 수 없는 서버에 요청을 보낼 수 있습니다.\"],\"Nt+9O7\":[\"원시 TCP 대신 WebSocket 사용\"],\"NxIHzc\":[\"사용자 연결 끊기\"],\"O+v/cL\":[\"서버의 모든 채널 검색\"],\"ODwSCk\":[\"GIF 보내기\"],\"OGQ5kK\":[\"알림 소리 및 강조 표시 설정\"],\"OIPt1Z\":[\"멤버 목록 사이드바 표시 또는 숨기기\"],\"OKSNq/\":[\"매우 엄격\"],\"ONWvwQ\":[\"업로드\"],\"OVKoQO\":[\"인증을 위한 계정 비밀번호\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - IRC를 미래로\"],\"OhCpra\":[\"주제 설정…\"],\"OkltoQ\":[\"닉네임으로 \",[\"username\"],\" 차단 (동일 닉으로 재입장 방지)\"],\"P+t/Te\":[\"추가 데이터 없음\"],\"P42Wcc\":[\"안전\"],\"PD38l0\":[\"채널 아바타 미리보기\"],\"PD9mEt\":[\"메시지를 입력하세요...\"],\"PPqfdA\":[\"채널 구성 설정 열기\"],\"PSCjfZ\":[\"이 채널에 표시될 주제입니다. 모든 사용자가 주제를 볼 수 있습니다.\"],\"PZCecv\":[\"PDF 미리보기\"],\"PeLgsC\":[[\"c\",\"plural\",{\"other\":[[\"c\"],\"번\"]}]],\"PguS2C\":[\"예외 마스크 추가 (예: nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[[\"0\"],\"개 채널 중 \",[\"displayedChannelsCount\"],\"개 표시\"],\"PqhVlJ\":[\"사용자 차단 (호스트마스크)\"],\"Q+chwU\":[\"사용자명:\"],\"Q6hhn8\":[\"환경설정\"],\"QF4a34\":[\"사용자 이름을 입력하세요\"],\"QGqSZ2\":[\"색상 및 서식\"],\"QJQd1J\":[\"프로필 편집\"],\"QSzGDE\":[\"유휴\"],\"QUlny5\":[[\"0\"],\"에 오신 것을 환영합니다!\"],\"Qoq+GP\":[\"더 보기\"],\"QuSkCF\":[\"채널 필터링...\"],\"QwUrDZ\":[\"주제를 변경했습니다: \",[\"topic\"]],\"R0UH07\":[[\"1\"],\"개 중 \",[\"0\"],\"번째 이미지\"],\"R7SsBE\":[\"음소거\"],\"R8rf1X\":[\"클릭하여 주제 설정\"],\"RArB3D\":[[\"username\"],\"에 의해 \",[\"channelName\"],\"에서 추방당했습니다\"],\"RI3cWd\":[\"ObsidianIRC와 함께 IRC의 세계를 탐험하세요\"],\"RMMaN5\":[\"발언권 제한 (+m)\"],\"RWw9Lg\":[\"모달 닫기\"],\"RZ2BuZ\":[[\"account\"],\" 계정 등록에 인증이 필요합니다: \",[\"message\"]],\"RySp6q\":[\"댓글 숨기기\"],\"SPKQTd\":[\"닉네임은 필수입니다\"],\"SPVjfj\":[\"비워두면 기본값인 '사유 없음'이 사용됩니다\"],\"SQKPvQ\":[\"사용자 초대\"],\"SkZcl+\":[\"미리 정의된 플러드 방지 프로필을 선택하세요. 각 프로필은 다양한 사용 사례에 맞게 균형 잡힌 보호 설정을 제공합니다.\"],\"Slr+3C\":[\"최소 사용자 수\"],\"Spnlre\":[[\"target\"],\"을(를) \",[\"channel\"],\"에 초대했습니다\"],\"T/ckN5\":[\"뷰어에서 열기\"],\"T91vKp\":[\"재생\"],\"TV2Wdu\":[\"데이터 처리 방식 및 개인정보 보호 방법을 알아보세요.\"],\"TgFpwD\":[\"적용 중...\"],\"TkzSFB\":[\"변경 사항 없음\"],\"TtserG\":[\"실명 입력\"],\"Ttz9J1\":[\"비밀번호 입력...\"],\"Tz0i8g\":[\"설정\"],\"U3pytU\":[\"관리자\"],\"UDb2YD\":[\"반응\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"설정 저장\"],\"UV5hLB\":[\"차단된 사용자가 없습니다\"],\"Uaj3Nd\":[\"상태 메시지\"],\"Ue3uny\":[\"기본값 (프로필 없음)\"],\"UkARhe\":[\"기본 - 표준 보호\"],\"Umn7Cj\":[\"아직 댓글이 없습니다. 첫 번째 댓글을 남겨보세요!\"],\"UtUIRh\":[\"이전 메시지 \",[\"0\"],\"개\"],\"UwzP+U\":[\"보안 연결\"],\"V0/A4O\":[\"채널 소유자\"],\"V4qgxE\":[\"생성 시각 이전 (분 전)\"],\"V8yTm6\":[\"검색 지우기\"],\"VJMMyz\":[\"ObsidianIRC - IRC를 미래로\"],\"VJScHU\":[\"사유\"],\"VLsmVV\":[\"알림 음소거\"],\"VbyRUy\":[\"댓글\"],\"Vmx0mQ\":[\"설정자:\"],\"VqnIZz\":[\"개인정보 처리방침 및 데이터 관행 보기\"],\"VrMygG\":[\"최소 길이는 \",[\"0\"],\"자입니다\"],\"VrnTui\":[\"프로필에 표시되는 대명사\"],\"W8E3qn\":[\"인증된 계정\"],\"WAakm9\":[\"채널 삭제\"],\"WFxTHC\":[\"차단 마스크 추가 (예: nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"서버 호스트는 필수입니다\"],\"WRYdXW\":[\"오디오 재생 위치\"],\"WUOH5B\":[\"사용자 무시\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"other\":[[\"1\"],\"개 더 보기\"]}]],\"Weq9zb\":[\"일반\"],\"Wfj7Sk\":[\"알림 소리 음소거 또는 해제\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"사용자 프로필\"],\"X6S3lt\":[\"설정, 채널, 서버 검색...\"],\"XEHan5\":[\"계속 진행\"],\"XI1+wb\":[\"잘못된 형식\"],\"XIXeuC\":[\"@\",[\"0\"],\"에게 메시지 보내기\"],\"XMS+k4\":[\"비공개 메시지 시작\"],\"XWgxXq\":[\"앨범\"],\"Xd7+IT\":[\"비공개 채팅 고정 해제\"],\"Xm/s+u\":[\"화면 표시\"],\"Xp2n93\":[\"서버의 신뢰할 수 있는 파일 호스트의 미디어를 표시합니다. 외부 서비스에 요청이 전송되지 않습니다.\"],\"XvjC4F\":[\"저장 중...\"],\"Y/qryO\":[\"검색 결과와 일치하는 사용자가 없습니다\"],\"YAqRpI\":[[\"account\"],\" 계정 등록 성공: \",[\"message\"]],\"YEfzvP\":[\"주제 보호 (+t)\"],\"YQOn6a\":[\"멤버 목록 접기\"],\"YRCoE9\":[\"채널 Op\"],\"YURQaF\":[\"프로필 보기\"],\"YdBSvr\":[\"미디어 표시 및 외부 콘텐츠 제어\"],\"Yj6U3V\":[\"중앙 서버 없음:\"],\"YjvpGx\":[\"대명사\"],\"YqH4l4\":[\"키 없음\"],\"YyUPpV\":[\"계정:\"],\"ZJSWfw\":[\"서버 연결 종료 시 표시할 메시지\"],\"ZR1dJ4\":[\"초대\"],\"ZdWg0V\":[\"브라우저에서 열기\"],\"ZhRBbl\":[\"메시지 검색…\"],\"Zmcu3y\":[\"고급 필터\"],\"a2/8e5\":[\"주제 설정 이후 (분 전)\"],\"aHKcKc\":[\"이전 페이지\"],\"aJTbXX\":[\"Oper 비밀번호\"],\"aQryQv\":[\"이미 존재하는 패턴입니다\"],\"aW9pLN\":[\"채널에 허용되는 최대 사용자 수입니다. 제한 없이 두려면 비워두세요.\"],\"ah4fmZ\":[\"YouTube, Vimeo, SoundCloud 등 알려진 서비스의 미리보기도 표시합니다.\"],\"aifXak\":[\"이 채널에 미디어가 없습니다\"],\"ap2zBz\":[\"완화\"],\"az8lvo\":[\"끄기\"],\"azXSNo\":[\"멤버 목록 펼치기\"],\"azdliB\":[\"계정에 로그인\"],\"b26wlF\":[\"그녀/그녀의\"],\"bD/+Ei\":[\"엄격\"],\"bQ6BJn\":[\"상세한 플러드 방지 규칙을 설정하세요. 각 규칙은 모니터링할 활동 유형과 임계값 초과 시 취할 조치를 지정합니다.\"],\"beV7+y\":[\"사용자가 \",[\"channelName\"],\" 참여 초대를 받게 됩니다.\"],\"bk84cH\":[\"자리 비움 메시지\"],\"bkHdLj\":[\"IRC 서버 추가\"],\"bmQLn5\":[\"규칙 추가\"],\"bwRvnp\":[\"작업\"],\"c8+EVZ\":[\"인증된 계정\"],\"cGYUlD\":[\"미디어 미리보기가 로드되지 않습니다.\"],\"cLF98o\":[\"댓글 보기 (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"사용 가능한 사용자가 없습니다\"],\"cSgpoS\":[\"비공개 채팅 고정\"],\"cde3ce\":[\"<0>\",[\"0\"],\"</0>에게 메시지\"],\"chQsxg\":[\"형식화된 출력 복사\"],\"cl/A5J\":[[\"__DEFAULT_IRC_SERVER_NAME__\"],\"에 오신 것을 환영합니다!\"],\"cnGeoo\":[\"삭제\"],\"coPLXT\":[\"IRC 통신 내용은 서버에 저장되지 않습니다\"],\"crYH/6\":[\"SoundCloud 플레이어\"],\"d3sis4\":[\"서버 추가\"],\"d9aN5k\":[\"채널에서 \",[\"username\"],\" 제거\"],\"dEgA5A\":[\"취소\"],\"dGi1We\":[\"이 비공개 메시지 대화 고정 해제\"],\"dJVuyC\":[[\"channelName\"],\"에서 나갔습니다 (\",[\"reason\"],\")\"],\"dMtLDE\":[\"받는 사람\"],\"dXqxlh\":[\"<0>⚠️ 보안 위험!</0> 이 연결은 도청 또는 중간자 공격에 취약할 수 있습니다.\"],\"da9Q/R\":[\"채널 모드 변경됨\"],\"dhJN3N\":[\"댓글 보기\"],\"dj2xTE\":[\"알림 닫기\"],\"dpCzmC\":[\"플러드 방지 설정\"],\"e9dQpT\":[\"이 링크를 새 탭에서 여시겠습니까?\"],\"ePK91l\":[\"편집\"],\"eYBDuB\":[\"이미지를 업로드하거나 동적 크기 조정을 위해 \",[\"size\"],\" 대체가 있는 URL을 제공하세요\"],\"edBbee\":[\"호스트마스크로 \",[\"username\"],\" 차단 (동일 IP/호스트에서 재입장 방지)\"],\"ekfzWq\":[\"사용자 설정\"],\"elPDWs\":[\"IRC 클라이언트 환경을 맞춤 설정하세요\"],\"eu2osY\":[\"<0>💡 권장사항:</0> 이 서버를 신뢰하고 위험을 충분히 이해한 경우에만 계속하세요. 이 연결을 통해 민감한 정보나 비밀번호를 공유하지 마세요.\"],\"euEhbr\":[[\"channel\"],\"에 참여하려면 클릭\"],\"ez3vLd\":[\"여러 줄 입력 활성화\"],\"f0J5Ki\":[\"서버 간 통신에 암호화되지 않은 연결이 사용될 수 있습니다\"],\"f9BHJk\":[\"사용자 경고\"],\"fDOLLd\":[\"채널을 찾을 수 없습니다.\"],\"ffzDkB\":[\"익명 분석:\"],\"fq1GF9\":[\"사용자가 서버에서 연결을 끊을 때 표시\"],\"gEF57C\":[\"이 서버는 하나의 연결 유형만 지원합니다\"],\"gJuLUI\":[\"무시 목록\"],\"gNzMrk\":[\"현재 아바타\"],\"gjPWyO\":[\"닉네임 입력...\"],\"gz6UQ3\":[\"최대화\"],\"h6razj\":[\"채널 이름 마스크 제외\"],\"hG6jnw\":[\"설정된 주제 없음\"],\"hG89Ed\":[\"이미지\"],\"hZ6znB\":[\"포트\"],\"ha+Bz5\":[\"예: 100:1440\"],\"hehnjM\":[\"횟수\"],\"hzdLuQ\":[\"Voice 이상의 권한을 가진 사용자만 발언 가능\"],\"i0qMbr\":[\"홈\"],\"iDNBZe\":[\"알림\"],\"iH8pgl\":[\"뒤로\"],\"iL9SZg\":[\"사용자 차단 (닉네임)\"],\"iNt+3c\":[\"이미지로 돌아가기\"],\"iQvi+a\":[\"이 서버의 낮은 링크 보안에 대해 다시 경고하지 않음\"],\"iSLIjg\":[\"연결\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"서버 호스트\"],\"idD8Ev\":[\"저장됨\"],\"iivqkW\":[\"접속 시각\"],\"ij+Elv\":[\"이미지 미리보기\"],\"ilIWp7\":[\"알림 전환\"],\"iuaqvB\":[\"와일드카드로 *를 사용하세요. 예: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"봇\"],\"j2DGR0\":[\"호스트마스크로 차단\"],\"jA4uoI\":[\"주제:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"사유 (선택 사항)\"],\"jUV7CU\":[\"아바타 업로드\"],\"jW5Uwh\":[\"로드할 외부 미디어의 범위를 제어합니다. 끄기 / 안전 / 신뢰할 수 있는 출처 / 모든 콘텐츠.\"],\"jXzms5\":[\"첨부 옵션\"],\"jZlrte\":[\"색상\"],\"jfC/xh\":[\"연락처\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"이전 메시지 불러오기\"],\"k3ID0F\":[\"멤버 필터링…\"],\"k65gsE\":[\"자세히 보기\"],\"k7Zgob\":[\"연결 취소\"],\"kAVx5h\":[\"초대가 없습니다\"],\"kCLEPU\":[\"연결된 서버\"],\"kF5LKb\":[\"무시된 패턴:\"],\"kGeOx/\":[[\"0\"],\" 참가\"],\"kITKr8\":[\"채널 모드 불러오는 중...\"],\"kPpPsw\":[\"당신은 IRC Operator입니다\"],\"kWJmRL\":[\"나\"],\"kfcRb0\":[\"아바타\"],\"kjMqSj\":[\"JSON 복사\"],\"krViRy\":[\"JSON으로 복사하려면 클릭\"],\"ks71ra\":[\"예외\"],\"kw4lRv\":[\"채널 Halfop\"],\"kxgIRq\":[\"시작하려면 채널을 선택하거나 추가하세요.\"],\"ky6dWe\":[\"아바타 미리보기\"],\"l+GxCv\":[\"채널 불러오는 중...\"],\"l+IUVW\":[[\"account\"],\" 계정 인증 성공: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"other\":[[\"reconnectCount\"],\"번 재연결됨\"]}]],\"l5jmzx\":[[\"0\"],\"님과 \",[\"1\"],\"님이 입력 중...\"],\"lHy8N5\":[\"채널 더 불러오는 중...\"],\"lbpf14\":[[\"value\"],\" 참여\"],\"lfFsZ4\":[\"채널\"],\"lkNdiH\":[\"계정 이름\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"이미지 업로드\"],\"loQxaJ\":[\"돌아왔습니다\"],\"lvfaxv\":[\"홈\"],\"m16xKo\":[\"추가\"],\"m8flAk\":[\"미리보기 (아직 업로드되지 않음)\"],\"mEPxTp\":[\"<0>⚠️ 주의하세요!</0> 신뢰할 수 있는 출처의 링크만 여세요. 악성 링크는 보안이나 개인정보를 침해할 수 있습니다.\"],\"mHGdhG\":[\"서버 정보\"],\"mHS8lb\":[\"#\",[\"0\"],\"에 메시지 보내기\"],\"mMYBD9\":[\"광역 - 더 넓은 보호 범위\"],\"mTGsPd\":[\"채널 주제\"],\"mU8j6O\":[\"외부 메시지 차단 (+n)\"],\"mZp8FL\":[\"자동으로 한 줄 모드로 전환\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"댓글 (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"인증된 사용자\"],\"mwtcGl\":[\"댓글 닫기\"],\"mzI/c+\":[\"다운로드\"],\"n3fGRk\":[[\"0\"],\"이(가) 설정\"],\"nE9jsU\":[\"완화 - 덜 공격적인 보호\"],\"nNflMD\":[\"채널 나가기\"],\"nPXkBi\":[\"WHOIS 데이터 불러오는 중...\"],\"nQnxxF\":[\"#\",[\"0\"],\"에 메시지 (Shift+Enter로 줄 바꿈)\"],\"nWMRxa\":[\"고정 해제\"],\"nkC032\":[\"플러드 프로필 없음\"],\"o69z4d\":[[\"username\"],\"에게 경고 메시지 보내기\"],\"o9ylQi\":[\"GIF를 검색하여 시작하세요\"],\"oFGkER\":[\"서버 알림\"],\"oOi11l\":[\"맨 아래로 스크롤\"],\"oQEzQR\":[\"새 DM\"],\"oXOSPE\":[\"온라인\"],\"oal760\":[\"서버 링크에 대한 중간자 공격이 가능합니다\"],\"oeqmmJ\":[\"신뢰할 수 있는 출처\"],\"ovBPCi\":[\"기본값\"],\"p0Z69r\":[\"패턴은 비워둘 수 없습니다\"],\"p1KgtK\":[\"오디오를 불러오지 못했습니다\"],\"p59pEv\":[\"추가 세부정보\"],\"p7sRI6\":[\"입력 중임을 다른 사람에게 알림\"],\"pBm1od\":[\"비밀 채널\"],\"pNmiXx\":[\"모든 서버에 사용할 기본 닉네임\"],\"pUUo9G\":[\"호스트명:\"],\"pVGPmz\":[\"계정 비밀번호\"],\"peNE68\":[\"영구\"],\"plhHQt\":[\"데이터 없음\"],\"pm6+q5\":[\"보안 경고\"],\"pn5qSs\":[\"추가 정보\"],\"q0cR4S\":[\"이제 **\",[\"newNick\"],\"**(으)로 알려져 있습니다\"],\"qFcunY\":[\"LIST 또는 NAMES 명령에 채널이 표시되지 않음\"],\"qLpTm/\":[[\"emoji\"],\" 반응 제거\"],\"qVkGWK\":[\"고정\"],\"qY8wNa\":[\"홈페이지\"],\"qb0xJ7\":[\"와일드카드 사용: *는 임의의 문자열, ?는 임의의 단일 문자. 예: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"채널 키 (+k)\"],\"qtoOYG\":[\"제한 없음\"],\"r1W2AS\":[\"파일 호스트 이미지\"],\"rIPR2O\":[\"주제 설정 이전 (분 전)\"],\"rMMSYo\":[\"최대 길이는 \",[\"0\"],\"자입니다\"],\"rWtzQe\":[\"네트워크가 분리되었다가 재연결되었습니다. ✅\"],\"rYG2u6\":[\"잠시만 기다려 주세요...\"],\"rdUucN\":[\"미리보기\"],\"rjGI/Q\":[\"개인정보 보호\"],\"rk8iDX\":[\"GIF 불러오는 중...\"],\"rn6SBY\":[\"음소거 해제\"],\"s/UKqq\":[\"채널에서 추방되었습니다\"],\"s8cATI\":[[\"channelName\"],\"에 참가했습니다\"],\"sCO9ue\":[\"<0>\",[\"serverName\"],\"</0>에 대한 연결에 다음과 같은 보안 문제가 있습니다:\"],\"sGH11W\":[\"서버\"],\"sHI1H+\":[\"이제 **\",[\"newNick\"],\"**(으)로 알려져 있습니다\"],\"sJyV04\":[[\"inviter\"],\"이(가) 당신을 \",[\"channel\"],\"에 초대했습니다\"],\"sby+1/\":[\"클릭하여 복사\"],\"sfN25C\":[\"실명 또는 전체 이름\"],\"sliuzR\":[\"링크 열기\"],\"sqrO9R\":[\"사용자 정의 멘션\"],\"sr6RdJ\":[\"Shift+Enter로 여러 줄 입력\"],\"swrCpB\":[[\"user\"],\"이(가) 채널을 \",[\"oldName\"],\"에서 \",[\"newName\"],\"(으)로 이름을 변경했습니다\",[\"0\"]],\"sxkWRg\":[\"고급\"],\"t/YqKh\":[\"제거\"],\"t47eHD\":[\"이 서버에서의 고유 식별자\"],\"tAkAh0\":[\"동적 크기 조정을 위한 \",[\"size\"],\" 대체가 있는 URL. 예: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"채널 목록 사이드바 표시 또는 숨기기\"],\"tfDRzk\":[\"저장\"],\"tiBsJk\":[[\"channelName\"],\"에서 나갔습니다\"],\"tt4/UD\":[\"서버를 나갔습니다 (\",[\"reason\"],\")\"],\"u0TcnO\":[\"닉네임 {nick}이(가) 이미 사용 중입니다. {newNick}(으)로 다시 시도합니다\"],\"u0a8B4\":[\"관리 권한을 위해 IRC Operator로 인증\"],\"u0rWFU\":[\"생성 시각 이후 (분 전)\"],\"u72w3t\":[\"무시할 사용자 및 패턴\"],\"u7jc2L\":[\"서버를 나갔습니다\"],\"uAQUqI\":[\"상태\"],\"uB85T3\":[\"저장 실패: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC 서버:\"],\"usSSr/\":[\"확대/축소 수준\"],\"v7uvcf\":[\"소프트웨어:\"],\"vE8kb+\":[\"줄 바꿈은 Shift+Enter (Enter로 전송)\"],\"vERlcd\":[\"프로필\"],\"vK0RL8\":[\"주제 없음\"],\"vSJd18\":[\"동영상\"],\"vXIe7J\":[\"언어\"],\"vaHYxN\":[\"실명\"],\"vhjbKr\":[\"자리 비움\"],\"w4NYox\":[[\"title\"],\" 클라이언트\"],\"w8xQRx\":[\"잘못된 값\"],\"wFjjxZ\":[[\"username\"],\"에 의해 \",[\"channelName\"],\"에서 추방당했습니다 (\",[\"reason\"],\")\"],\"wGjaGl\":[\"차단 예외가 없습니다\"],\"wPrGnM\":[\"채널 관리자\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"사용자가 채널에 입장하거나 퇴장할 때 표시\"],\"whqZ9r\":[\"강조할 추가 단어 또는 문구\"],\"wm7RV4\":[\"알림 소리\"],\"wz/Yoq\":[\"서버 간 전달 시 메시지가 도청될 수 있습니다\"],\"xCJdfg\":[\"지우기\"],\"xUHRTR\":[\"연결 시 자동으로 operator로 인증\"],\"xWHwwQ\":[\"차단 목록\"],\"xYilR2\":[\"미디어\"],\"xceQrO\":[\"보안 웹소켓만 지원됩니다\"],\"xdtXa+\":[\"채널-이름\"],\"xfXC7q\":[\"텍스트 채널\"],\"xlCYOE\":[\"메시지를 더 불러오는 중...\"],\"xlhswE\":[\"최솟값은 \",[\"0\"],\"입니다\"],\"xq97Ci\":[\"단어나 문구 추가...\"],\"xuRqRq\":[\"클라이언트 제한 (+l)\"],\"xwF+7J\":[[\"0\"],\"님이 입력 중...\"],\"yNeucF\":[\"이 서버는 확장 프로필 메타데이터(IRCv3 METADATA 확장)를 지원하지 않습니다. 아바타, 표시 이름, 상태 등의 추가 필드를 사용할 수 없습니다.\"],\"yPlrca\":[\"채널 아바타\"],\"yQE2r9\":[\"로딩 중\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper 사용자 이름\"],\"yYOzWD\":[\"로그\"],\"yfx9Re\":[\"IRC operator 비밀번호\"],\"ygCKqB\":[\"정지\"],\"ymDxJx\":[\"IRC operator 사용자 이름\"],\"yrpRsQ\":[\"이름순 정렬\"],\"yz7wBu\":[\"닫기\"],\"zJw+jA\":[\"모드 설정: \",[\"0\"]],\"zebeLu\":[\"oper 사용자 이름 입력\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"잘못된 패턴 형식입니다. nick!user@host 형식을 사용하세요 (와일드카드 * 허용)\"],\"+6NQQA\":[\"일반 지원 채널\"],\"+6NyRG\":[\"클라이언트\"],\"+K0AvT\":[\"연결 끊기\"],\"+cyFdH\":[\"자리 비움 설정 시 기본 메시지\"],\"+mVPqU\":[\"메시지에서 마크다운 서식 렌더링\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"인증을 위한 계정 사용자 이름\"],\"+yPBXI\":[\"파일 선택\"],\"+zy2Nq\":[\"유형\"],\"/09cao\":[\"낮은 링크 보안 (레벨 \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"채널 외부 사용자는 메시지를 보낼 수 없습니다\"],\"/6BzZF\":[\"멤버 목록 전환\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"자리 비움 중\"],\"/XQgft\":[\"채널 탐색\"],\"/cF7Rs\":[\"볼륨\"],\"/dqduX\":[\"다음 페이지\"],\"/fc3q4\":[\"모든 콘텐츠\"],\"/kISDh\":[\"알림 소리 활성화\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"오디오\"],\"/rfkZe\":[\"멘션 및 메시지에 소리 재생\"],\"0/0ZGA\":[\"채널 이름 마스크\"],\"0D6j7U\":[\"사용자 정의 규칙에 대해 더 알아보기 →\"],\"0XsHcR\":[\"사용자 추방\"],\"0ZpE//\":[\"사용자 수순 정렬\"],\"0bEPwz\":[\"자리 비움 설정\"],\"0dGkPt\":[\"채널 목록 펼치기\"],\"0gS7M5\":[\"표시 이름\"],\"0kS+M8\":[\"예시네트워크\"],\"0rgoY7\":[\"직접 선택한 서버에만 연결합니다\"],\"0wdd7X\":[\"참여\"],\"0wkVYx\":[\"비공개 메시지\"],\"111uHX\":[\"링크 미리보기\"],\"196EG4\":[\"비공개 채팅 삭제\"],\"1DSr1i\":[\"계정 등록\"],\"1O/24y\":[\"채널 목록 전환\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"외부 링크 경고\"],\"1ZC/dv\":[\"읽지 않은 멘션이나 메시지가 없습니다\"],\"1pO1zi\":[\"서버 이름은 필수입니다\"],\"1uwfzQ\":[\"채널 주제 보기\"],\"268g7c\":[\"표시 이름 입력\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"네트워크 서버 운영자가 메시지를 읽을 수 있습니다\"],\"2FYpfJ\":[\"더 보기\"],\"2HF1Y2\":[[\"inviter\"],\"이(가) \",[\"target\"],\"을(를) \",[\"channel\"],\"에 초대했습니다\"],\"2I70QL\":[\"사용자 프로필 정보 보기\"],\"2QYdmE\":[\"사용자:\"],\"2QpEjG\":[\"퇴장했습니다\"],\"2YE223\":[\"#\",[\"0\"],\"에 메시지 (Enter로 줄 바꿈, Shift+Enter로 전송)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"서버 비밀번호 사용\"],\"2iTmdZ\":[\"로컬 저장소:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"엄격 - 더 강력한 보호\"],\"2uDhbA\":[\"초대할 사용자 이름 입력\"],\"2ygf/L\":[\"← 뒤로\"],\"2zEgxj\":[\"GIF 검색...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"채널 이름 변경\"],\"3THokf\":[\"Voice 사용자\"],\"3TSz9S\":[\"최소화\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"채널 표시 이름\"],\"3ryuFU\":[\"앱 개선을 위한 선택적 충돌 보고서\"],\"3uBF/8\":[\"뷰어 닫기\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"계정 이름 입력...\"],\"4/Rr0R\":[\"현재 채널에 사용자 초대\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"규칙\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"플러드 프로필 (+F)\"],\"4RZQRK\":[\"지금 뭐 하세요?\"],\"4hfTrB\":[\"닉네임\"],\"4n99LO\":[\"이미 \",[\"0\"],\"에 있음\"],\"4t6vMV\":[\"짧은 메시지의 경우 자동으로 한 줄 모드로 전환\"],\"4vsHmf\":[\"시간 (분)\"],\"5+INAX\":[\"나를 멘션한 메시지 강조 표시\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper 이름\"],\"678PKt\":[\"네트워크 이름\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"오프라인\"],\"6CO3WE\":[\"채널 참여에 필요한 비밀번호입니다. 키를 제거하려면 비워두세요.\"],\"6HhMs3\":[\"QUIT 메시지\"],\"6V3Ea3\":[\"복사됨\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"접기\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"oper 비밀번호 입력...\"],\"7+IHTZ\":[\"파일 선택 안 함\"],\"73hrRi\":[\"nick!user@host (예: spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"비공개 메시지 보내기\"],\"7U1W7c\":[\"매우 완화\"],\"7Y1YQj\":[\"실명:\"],\"7YHArF\":[\"— 뷰어에서 열기\"],\"7fjnVl\":[\"사용자 검색...\"],\"7jL88x\":[\"이 메시지를 삭제하시겠습니까? 이 작업은 취소할 수 없습니다.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"무슨 생각을 하고 계신가요?\"],\"7sEpu1\":[\"멤버 — \",[\"0\"],\"명\"],\"7sNhEz\":[\"사용자 이름\"],\"8H0Q+x\":[\"프로필에 대해 더 알아보기 →\"],\"8Phu0A\":[\"사용자가 닉네임을 변경할 때 표시\"],\"8XTG9e\":[\"oper 비밀번호 입력\"],\"8XsV2J\":[\"다시 보내기\"],\"8ZsakT\":[\"비밀번호\"],\"8kR84m\":[\"외부 링크를 열려고 합니다:\"],\"8lCgih\":[\"규칙 제거\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"other\":[[\"joinCount\"],\"번 참가했음\"]}]],\"9BMLnJ\":[\"서버에 재연결\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"반응 추가\"],\"9PQ8m2\":[\"G-Line (글로벌 밴)\"],\"9Qs99X\":[\"이메일:\"],\"9QupBP\":[\"패턴 제거\"],\"9bG48P\":[\"보내는 중\"],\"9f5f0u\":[\"개인정보 보호에 관한 문의사항이 있으신가요? 문의처:\"],\"9unqs3\":[\"자리비움:\"],\"9v3hwv\":[\"서버를 찾을 수 없습니다.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"연결 중\"],\"A1taO8\":[\"검색\"],\"A2adVi\":[\"입력 중 알림 보내기\"],\"A9Rhec\":[\"채널 이름\"],\"AWOSPo\":[\"확대\"],\"AXSpEQ\":[\"연결 시 Oper 인증\"],\"AeXO77\":[\"계정\"],\"AhNP40\":[\"탐색\"],\"Ai2U7L\":[\"호스트\"],\"AjBQnf\":[\"닉네임 변경됨\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"답장 취소\"],\"ApSx0O\":[\"\\\"\",[\"searchQuery\"],\"\\\"와 일치하는 메시지 \",[\"0\"],\"개 발견\"],\"AxPAXW\":[\"결과를 찾을 수 없습니다\"],\"AyNqAB\":[\"채팅에 모든 서버 이벤트 표시\"],\"B/QqGw\":[\"자리 비움 (AFK)\"],\"B8AaMI\":[\"이 필드는 필수입니다\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"서버가 고급 LIST 필터링을 지원하지 않습니다\"],\"BDKt3I\":[[\"0\"],\"님, \",[\"1\"],\"님, \",[\"2\"],\"님 외 \",[\"3\"],\"명이 입력 중...\"],\"BGul2A\":[\"저장되지 않은 변경 사항이 있습니다. 저장하지 않고 닫으시겠습니까?\"],\"BIf9fi\":[\"상태 메시지\"],\"BZz3md\":[\"개인 웹사이트\"],\"Bgm/H7\":[\"여러 줄 텍스트 입력 허용\"],\"BiQIl1\":[\"이 비공개 메시지 대화 고정\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"클릭하여 메시지로 이동\"],\"Bowq3c\":[\"운영자만 채널 주제를 변경할 수 있음\"],\"Btozzp\":[\"이 이미지가 만료되었습니다\"],\"Bycfjm\":[\"전체: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"전체 JSON 복사\"],\"C9L9wL\":[\"데이터 수집\"],\"CDq4wC\":[\"사용자 제재\"],\"CHVRxG\":[\"@\",[\"0\"],\"에게 메시지 (Shift+Enter로 줄 바꿈)\"],\"CN9zdR\":[\"Oper 이름과 비밀번호는 필수입니다\"],\"CW3sYa\":[[\"emoji\"],\" 반응 추가\"],\"CaAkqd\":[\"QUIT 표시\"],\"CbvaYj\":[\"닉네임으로 차단\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"채널 선택\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"기본\"],\"D+NlUC\":[\"시스템\"],\"D28t6+\":[\"참여했다가 퇴장했습니다\"],\"DB8zMK\":[\"적용\"],\"DBcWHr\":[\"사용자 정의 알림 소리 파일\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"미디어 미리보기\"],\"Dj4pSr\":[\"안전한 비밀번호를 선택하세요\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"검색 중...\"],\"Du2T2f\":[\"설정을 찾을 수 없습니다\"],\"DwsSVQ\":[\"필터 적용 및 새로 고침\"],\"E3W/zd\":[\"기본 닉네임\"],\"E6nRW7\":[\"URL 복사\"],\"E703RG\":[\"모드:\"],\"EAeu1Z\":[\"초대 보내기\"],\"EFKJQT\":[\"설정\"],\"EGPQBv\":[\"사용자 정의 플러드 규칙 (+f)\"],\"ELik0r\":[\"전체 개인정보 처리방침 보기\"],\"EPbeC2\":[\"채널 주제 보기 또는 편집\"],\"EQCDNT\":[\"oper 사용자 이름 입력...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"\\\"\",[\"searchQuery\"],\"\\\"와 일치하는 메시지 1개 발견\"],\"EatZYJ\":[\"다음 이미지\"],\"EdQY6l\":[\"없음\"],\"EnqLYU\":[\"서버 검색...\"],\"F0OKMc\":[\"서버 편집\"],\"F6Int2\":[\"강조 표시 활성화\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"최대 사용자 수\"],\"FUU/hZ\":[\"채팅에서 로드할 외부 미디어의 범위를 제어하세요.\"],\"Fdp03t\":[\"켜기\"],\"FfPWR0\":[\"모달\"],\"FjkaiT\":[\"축소\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"이것이 의미하는 바:\"],\"FolHNl\":[\"계정 및 인증 관리\"],\"Fp2Dif\":[\"서버에서 나갔습니다\"],\"G5KmCc\":[\"GZ-Line (글로벌 Z-Line)\"],\"GDs0lz\":[\"<0>위험:</0> 민감한 정보(메시지, 비공개 대화, 인증 정보)가 네트워크 관리자나 IRC 서버 간에 위치한 공격자에게 노출될 수 있습니다.\"],\"GR+2I3\":[\"초대 마스크 추가 (예: nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"팝업 서버 알림 닫기\"],\"GlHnXw\":[\"닉네임 변경 실패: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"미리보기:\"],\"GtmO8/\":[\"보낸 사람\"],\"GtuHUQ\":[\"서버에서 이 채널의 이름을 변경합니다. 모든 사용자에게 새 이름이 표시됩니다.\"],\"GuGfFX\":[\"검색 전환\"],\"GxkJXS\":[\"업로드 중...\"],\"GzbwnK\":[\"채널에 참여했습니다\"],\"GzsUDB\":[\"확장 프로필\"],\"H/PnT8\":[\"이모지 삽입\"],\"H6Izzl\":[\"선호하는 색상 코드\"],\"H9jIv+\":[\"입장/퇴장 표시\"],\"HAKBY9\":[\"파일 업로드\"],\"HdE1If\":[\"채널\"],\"Hk4AW9\":[\"선호하는 표시 이름\"],\"HmHDk7\":[\"멤버 선택\"],\"HrQzPU\":[[\"networkName\"],\"의 채널\"],\"I2tXQ5\":[\"@\",[\"0\"],\"에게 메시지 (Enter로 줄 바꿈, Shift+Enter로 전송)\"],\"I6bw/h\":[\"사용자 차단\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"알림 활성화\"],\"I9D72S\":[\"이 메시지를 삭제하시겠습니까? 이 작업은 취소할 수 없습니다.\"],\"IA+1wo\":[\"사용자가 채널에서 추방될 때 표시\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"정보:\"],\"IUwGEM\":[\"변경 사항 저장\"],\"IVeGK6\":[[\"0\"],\"님, \",[\"1\"],\"님, \",[\"2\"],\"님이 입력 중...\"],\"IgrLD/\":[\"일시 정지\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"답장\"],\"IoHMnl\":[\"최대값은 \",[\"0\"],\"입니다\"],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"연결 중...\"],\"J5T9NW\":[\"사용자 정보\"],\"J8Y5+z\":[\"이런! 네트워크 분리! ⚠️\"],\"JBHkBA\":[\"채널을 나갔습니다\"],\"JCwL0Q\":[\"사유 입력 (선택 사항)\"],\"JFciKP\":[\"전환\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"채널 이름 변경 (운영자 전용)\"],\"JcD7qf\":[\"추가 작업\"],\"JdkA+c\":[\"비밀 채널 (+s)\"],\"Jmu12l\":[\"서버 채널\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"마크다운 활성화\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"WHOIS 데이터를 사용할 수 없습니다\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"메시지 삭제\"],\"KKBlUU\":[\"임베드\"],\"KM0pLb\":[\"채널에 오신 것을 환영합니다!\"],\"KR6W2h\":[\"사용자 무시 해제\"],\"KV+Bi1\":[\"초대 전용 (+i)\"],\"KdCtwE\":[\"카운터를 초기화하기 전에 플러드 활동을 모니터링할 초 단위 시간\"],\"Kkezga\":[\"서버 비밀번호\"],\"KsiQ/8\":[\"채널 참여를 위해 초대가 필요합니다\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"채널 정보\"],\"LC1a7n\":[\"IRC 서버에서 서버 간 링크의 보안 수준이 낮다고 보고했습니다. 즉, 메시지가 네트워크의 IRC 서버 간에 전달될 때 적절히 암호화되지 않거나 SSL/TLS 인증서가 올바르게 검증되지 않을 수 있습니다.\"],\"LNfLR5\":[\"추방 표시\"],\"LQb0W/\":[\"모든 이벤트 표시\"],\"LU7/yA\":[\"UI에 표시할 대체 이름입니다. 공백, 이모지, 특수 문자를 포함할 수 있습니다. IRC 명령에는 실제 채널 이름(\",[\"channelName\"],\")이 사용됩니다.\"],\"LUb9O7\":[\"올바른 서버 포트가 필요합니다\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"개인정보 처리방침\"],\"LcuSDR\":[\"프로필 정보 및 메타데이터 관리\"],\"LqLS9B\":[\"닉네임 변경 표시\"],\"LsDQt2\":[\"채널 설정\"],\"LtI9AS\":[\"소유자\"],\"LuNhhL\":[\"이 메시지에 반응했습니다\"],\"M/AZNG\":[\"아바타 이미지의 URL\"],\"M/WIer\":[\"메시지 보내기\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"이름:\"],\"MHk+7g\":[\"이전 이미지\"],\"MRorGe\":[\"사용자에게 PM\"],\"MVbSGP\":[\"시간 창 (초)\"],\"MkpcsT\":[\"메시지와 설정은 기기에 로컬로 저장됩니다\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"봇으로 표시 - 보통 'on' 또는 비워두기\"],\"N7TQbE\":[[\"channelName\"],\"에 사용자 초대\"],\"NCca/o\":[\"기본 닉네임 입력...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"모든 외부 미디어를 표시합니다. 모든 URL이 알 수 없는 서버에 요청을 보낼 수 있습니다.\"],\"Nt+9O7\":[\"원시 TCP 대신 WebSocket 사용\"],\"NxIHzc\":[\"사용자 연결 끊기\"],\"O+v/cL\":[\"서버의 모든 채널 검색\"],\"ODwSCk\":[\"GIF 보내기\"],\"OGQ5kK\":[\"알림 소리 및 강조 표시 설정\"],\"OIPt1Z\":[\"멤버 목록 사이드바 표시 또는 숨기기\"],\"OKSNq/\":[\"매우 엄격\"],\"ONWvwQ\":[\"업로드\"],\"OVKoQO\":[\"인증을 위한 계정 비밀번호\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - IRC를 미래로\"],\"OhCpra\":[\"주제 설정…\"],\"OkltoQ\":[\"닉네임으로 \",[\"username\"],\" 차단 (동일 닉으로 재입장 방지)\"],\"P+t/Te\":[\"추가 데이터 없음\"],\"P42Wcc\":[\"안전\"],\"PD38l0\":[\"채널 아바타 미리보기\"],\"PD9mEt\":[\"메시지를 입력하세요...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"채널 구성 설정 열기\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"이 채널에 표시될 주제입니다. 모든 사용자가 주제를 볼 수 있습니다.\"],\"PZCecv\":[\"PDF 미리보기\"],\"PeLgsC\":[[\"c\",\"plural\",{\"other\":[[\"c\"],\"번\"]}]],\"PguS2C\":[\"예외 마스크 추가 (예: nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[[\"0\"],\"개 채널 중 \",[\"displayedChannelsCount\"],\"개 표시\"],\"PqhVlJ\":[\"사용자 차단 (호스트마스크)\"],\"Q+chwU\":[\"사용자명:\"],\"Q6hhn8\":[\"환경설정\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"사용자 이름을 입력하세요\"],\"QGqSZ2\":[\"색상 및 서식\"],\"QJQd1J\":[\"프로필 편집\"],\"QSzGDE\":[\"유휴\"],\"QUlny5\":[[\"0\"],\"에 오신 것을 환영합니다!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"더 보기\"],\"QuSkCF\":[\"채널 필터링...\"],\"QwUrDZ\":[\"주제를 변경했습니다: \",[\"topic\"]],\"R0UH07\":[[\"1\"],\"개 중 \",[\"0\"],\"번째 이미지\"],\"R7SsBE\":[\"음소거\"],\"R8rf1X\":[\"클릭하여 주제 설정\"],\"RArB3D\":[[\"username\"],\"에 의해 \",[\"channelName\"],\"에서 추방당했습니다\"],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"ObsidianIRC와 함께 IRC의 세계를 탐험하세요\"],\"RMMaN5\":[\"발언권 제한 (+m)\"],\"RWw9Lg\":[\"모달 닫기\"],\"RZ2BuZ\":[[\"account\"],\" 계정 등록에 인증이 필요합니다: \",[\"message\"]],\"RySp6q\":[\"댓글 숨기기\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"닉네임은 필수입니다\"],\"SPVjfj\":[\"비워두면 기본값인 '사유 없음'이 사용됩니다\"],\"SQKPvQ\":[\"사용자 초대\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"미리 정의된 플러드 방지 프로필을 선택하세요. 각 프로필은 다양한 사용 사례에 맞게 균형 잡힌 보호 설정을 제공합니다.\"],\"Slr+3C\":[\"최소 사용자 수\"],\"Spnlre\":[[\"target\"],\"을(를) \",[\"channel\"],\"에 초대했습니다\"],\"T/ckN5\":[\"뷰어에서 열기\"],\"T91vKp\":[\"재생\"],\"TV2Wdu\":[\"데이터 처리 방식 및 개인정보 보호 방법을 알아보세요.\"],\"TgFpwD\":[\"적용 중...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"변경 사항 없음\"],\"TtserG\":[\"실명 입력\"],\"Ttz9J1\":[\"비밀번호 입력...\"],\"Tz0i8g\":[\"설정\"],\"U3pytU\":[\"관리자\"],\"UDb2YD\":[\"반응\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"설정 저장\"],\"UV5hLB\":[\"차단된 사용자가 없습니다\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"상태 메시지\"],\"Ue3uny\":[\"기본값 (프로필 없음)\"],\"UkARhe\":[\"기본 - 표준 보호\"],\"Umn7Cj\":[\"아직 댓글이 없습니다. 첫 번째 댓글을 남겨보세요!\"],\"UtUIRh\":[\"이전 메시지 \",[\"0\"],\"개\"],\"UwzP+U\":[\"보안 연결\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"채널 소유자\"],\"V4qgxE\":[\"생성 시각 이전 (분 전)\"],\"V8yTm6\":[\"검색 지우기\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - IRC를 미래로\"],\"VJScHU\":[\"사유\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"알림 음소거\"],\"VbyRUy\":[\"댓글\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"설정자:\"],\"VqnIZz\":[\"개인정보 처리방침 및 데이터 관행 보기\"],\"VrMygG\":[\"최소 길이는 \",[\"0\"],\"자입니다\"],\"VrnTui\":[\"프로필에 표시되는 대명사\"],\"W8E3qn\":[\"인증된 계정\"],\"WAakm9\":[\"채널 삭제\"],\"WFxTHC\":[\"차단 마스크 추가 (예: nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"서버 호스트는 필수입니다\"],\"WRYdXW\":[\"오디오 재생 위치\"],\"WUOH5B\":[\"사용자 무시\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"other\":[[\"1\"],\"개 더 보기\"]}]],\"Weq9zb\":[\"일반\"],\"Wfj7Sk\":[\"알림 소리 음소거 또는 해제\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"사용자 프로필\"],\"X6S3lt\":[\"설정, 채널, 서버 검색...\"],\"XEHan5\":[\"계속 진행\"],\"XI1+wb\":[\"잘못된 형식\"],\"XIXeuC\":[\"@\",[\"0\"],\"에게 메시지 보내기\"],\"XMS+k4\":[\"비공개 메시지 시작\"],\"XWgxXq\":[\"앨범\"],\"Xd7+IT\":[\"비공개 채팅 고정 해제\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"화면 표시\"],\"Xp2n93\":[\"서버의 신뢰할 수 있는 파일 호스트의 미디어를 표시합니다. 외부 서비스에 요청이 전송되지 않습니다.\"],\"XvjC4F\":[\"저장 중...\"],\"Y/qryO\":[\"검색 결과와 일치하는 사용자가 없습니다\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[[\"account\"],\" 계정 등록 성공: \",[\"message\"]],\"YEfzvP\":[\"주제 보호 (+t)\"],\"YQOn6a\":[\"멤버 목록 접기\"],\"YRCoE9\":[\"채널 Op\"],\"YURQaF\":[\"프로필 보기\"],\"YdBSvr\":[\"미디어 표시 및 외부 콘텐츠 제어\"],\"Yj6U3V\":[\"중앙 서버 없음:\"],\"YjvpGx\":[\"대명사\"],\"YqH4l4\":[\"키 없음\"],\"YyUPpV\":[\"계정:\"],\"ZJSWfw\":[\"서버 연결 종료 시 표시할 메시지\"],\"ZR1dJ4\":[\"초대\"],\"ZdWg0V\":[\"브라우저에서 열기\"],\"ZhRBbl\":[\"메시지 검색…\"],\"Zmcu3y\":[\"고급 필터\"],\"a2/8e5\":[\"주제 설정 이후 (분 전)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"이전 페이지\"],\"aJTbXX\":[\"Oper 비밀번호\"],\"aQryQv\":[\"이미 존재하는 패턴입니다\"],\"aW9pLN\":[\"채널에 허용되는 최대 사용자 수입니다. 제한 없이 두려면 비워두세요.\"],\"ah4fmZ\":[\"YouTube, Vimeo, SoundCloud 등 알려진 서비스의 미리보기도 표시합니다.\"],\"aifXak\":[\"이 채널에 미디어가 없습니다\"],\"ap2zBz\":[\"완화\"],\"az8lvo\":[\"끄기\"],\"azXSNo\":[\"멤버 목록 펼치기\"],\"azdliB\":[\"계정에 로그인\"],\"b26wlF\":[\"그녀/그녀의\"],\"bD/+Ei\":[\"엄격\"],\"bQ6BJn\":[\"상세한 플러드 방지 규칙을 설정하세요. 각 규칙은 모니터링할 활동 유형과 임계값 초과 시 취할 조치를 지정합니다.\"],\"beV7+y\":[\"사용자가 \",[\"channelName\"],\" 참여 초대를 받게 됩니다.\"],\"bk84cH\":[\"자리 비움 메시지\"],\"bkHdLj\":[\"IRC 서버 추가\"],\"bmQLn5\":[\"규칙 추가\"],\"bwRvnp\":[\"작업\"],\"c8+EVZ\":[\"인증된 계정\"],\"cGYUlD\":[\"미디어 미리보기가 로드되지 않습니다.\"],\"cLF98o\":[\"댓글 보기 (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"사용 가능한 사용자가 없습니다\"],\"cSgpoS\":[\"비공개 채팅 고정\"],\"cde3ce\":[\"<0>\",[\"0\"],\"</0>에게 메시지\"],\"chQsxg\":[\"형식화된 출력 복사\"],\"cl/A5J\":[[\"__DEFAULT_IRC_SERVER_NAME__\"],\"에 오신 것을 환영합니다!\"],\"cnGeoo\":[\"삭제\"],\"coPLXT\":[\"IRC 통신 내용은 서버에 저장되지 않습니다\"],\"crYH/6\":[\"SoundCloud 플레이어\"],\"d3sis4\":[\"서버 추가\"],\"d9aN5k\":[\"채널에서 \",[\"username\"],\" 제거\"],\"dEgA5A\":[\"취소\"],\"dGi1We\":[\"이 비공개 메시지 대화 고정 해제\"],\"dJVuyC\":[[\"channelName\"],\"에서 나갔습니다 (\",[\"reason\"],\")\"],\"dMtLDE\":[\"받는 사람\"],\"dXqxlh\":[\"<0>⚠️ 보안 위험!</0> 이 연결은 도청 또는 중간자 공격에 취약할 수 있습니다.\"],\"da9Q/R\":[\"채널 모드 변경됨\"],\"dhJN3N\":[\"댓글 보기\"],\"dj2xTE\":[\"알림 닫기\"],\"dpCzmC\":[\"플러드 방지 설정\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"이 링크를 새 탭에서 여시겠습니까?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"편집\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"이미지를 업로드하거나 동적 크기 조정을 위해 \",[\"size\"],\" 대체가 있는 URL을 제공하세요\"],\"edBbee\":[\"호스트마스크로 \",[\"username\"],\" 차단 (동일 IP/호스트에서 재입장 방지)\"],\"ekfzWq\":[\"사용자 설정\"],\"elPDWs\":[\"IRC 클라이언트 환경을 맞춤 설정하세요\"],\"eu2osY\":[\"<0>💡 권장사항:</0> 이 서버를 신뢰하고 위험을 충분히 이해한 경우에만 계속하세요. 이 연결을 통해 민감한 정보나 비밀번호를 공유하지 마세요.\"],\"euEhbr\":[[\"channel\"],\"에 참여하려면 클릭\"],\"ez3vLd\":[\"여러 줄 입력 활성화\"],\"f0J5Ki\":[\"서버 간 통신에 암호화되지 않은 연결이 사용될 수 있습니다\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"사용자 경고\"],\"fDOLLd\":[\"채널을 찾을 수 없습니다.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"익명 분석:\"],\"fq1GF9\":[\"사용자가 서버에서 연결을 끊을 때 표시\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"이 서버는 하나의 연결 유형만 지원합니다\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"무시 목록\"],\"gNzMrk\":[\"현재 아바타\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"닉네임 입력...\"],\"gz6UQ3\":[\"최대화\"],\"h6razj\":[\"채널 이름 마스크 제외\"],\"hG6jnw\":[\"설정된 주제 없음\"],\"hG89Ed\":[\"이미지\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"포트\"],\"ha+Bz5\":[\"예: 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"횟수\"],\"hzdLuQ\":[\"Voice 이상의 권한을 가진 사용자만 발언 가능\"],\"i0qMbr\":[\"홈\"],\"iDNBZe\":[\"알림\"],\"iH8pgl\":[\"뒤로\"],\"iL9SZg\":[\"사용자 차단 (닉네임)\"],\"iNt+3c\":[\"이미지로 돌아가기\"],\"iQvi+a\":[\"이 서버의 낮은 링크 보안에 대해 다시 경고하지 않음\"],\"iSLIjg\":[\"연결\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"서버 호스트\"],\"idD8Ev\":[\"저장됨\"],\"iivqkW\":[\"접속 시각\"],\"ij+Elv\":[\"이미지 미리보기\"],\"ilIWp7\":[\"알림 전환\"],\"iuaqvB\":[\"와일드카드로 *를 사용하세요. 예: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"봇\"],\"j2DGR0\":[\"호스트마스크로 차단\"],\"jA4uoI\":[\"주제:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"사유 (선택 사항)\"],\"jUV7CU\":[\"아바타 업로드\"],\"jW5Uwh\":[\"로드할 외부 미디어의 범위를 제어합니다. 끄기 / 안전 / 신뢰할 수 있는 출처 / 모든 콘텐츠.\"],\"jXzms5\":[\"첨부 옵션\"],\"jZlrte\":[\"색상\"],\"jfC/xh\":[\"연락처\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"이전 메시지 불러오기\"],\"k3ID0F\":[\"멤버 필터링…\"],\"k65gsE\":[\"자세히 보기\"],\"k7Zgob\":[\"연결 취소\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"초대가 없습니다\"],\"kCLEPU\":[\"연결된 서버\"],\"kF5LKb\":[\"무시된 패턴:\"],\"kGeOx/\":[[\"0\"],\" 참가\"],\"kITKr8\":[\"채널 모드 불러오는 중...\"],\"kPpPsw\":[\"당신은 IRC Operator입니다\"],\"kWJmRL\":[\"나\"],\"kfcRb0\":[\"아바타\"],\"kjMqSj\":[\"JSON 복사\"],\"krViRy\":[\"JSON으로 복사하려면 클릭\"],\"ks71ra\":[\"예외\"],\"kw4lRv\":[\"채널 Halfop\"],\"kxgIRq\":[\"시작하려면 채널을 선택하거나 추가하세요.\"],\"ky6dWe\":[\"아바타 미리보기\"],\"l+GxCv\":[\"채널 불러오는 중...\"],\"l+IUVW\":[[\"account\"],\" 계정 인증 성공: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"other\":[[\"reconnectCount\"],\"번 재연결됨\"]}]],\"l5jmzx\":[[\"0\"],\"님과 \",[\"1\"],\"님이 입력 중...\"],\"lHy8N5\":[\"채널 더 불러오는 중...\"],\"lbpf14\":[[\"value\"],\" 참여\"],\"lfFsZ4\":[\"채널\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"계정 이름\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"이미지 업로드\"],\"loQxaJ\":[\"돌아왔습니다\"],\"lvfaxv\":[\"홈\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"추가\"],\"m8flAk\":[\"미리보기 (아직 업로드되지 않음)\"],\"mEPxTp\":[\"<0>⚠️ 주의하세요!</0> 신뢰할 수 있는 출처의 링크만 여세요. 악성 링크는 보안이나 개인정보를 침해할 수 있습니다.\"],\"mHGdhG\":[\"서버 정보\"],\"mHS8lb\":[\"#\",[\"0\"],\"에 메시지 보내기\"],\"mMYBD9\":[\"광역 - 더 넓은 보호 범위\"],\"mTGsPd\":[\"채널 주제\"],\"mU8j6O\":[\"외부 메시지 차단 (+n)\"],\"mZp8FL\":[\"자동으로 한 줄 모드로 전환\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"댓글 (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"인증된 사용자\"],\"mwtcGl\":[\"댓글 닫기\"],\"mzI/c+\":[\"다운로드\"],\"n3fGRk\":[[\"0\"],\"이(가) 설정\"],\"nE9jsU\":[\"완화 - 덜 공격적인 보호\"],\"nNflMD\":[\"채널 나가기\"],\"nPXkBi\":[\"WHOIS 데이터 불러오는 중...\"],\"nQnxxF\":[\"#\",[\"0\"],\"에 메시지 (Shift+Enter로 줄 바꿈)\"],\"nWMRxa\":[\"고정 해제\"],\"nkC032\":[\"플러드 프로필 없음\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[[\"username\"],\"에게 경고 메시지 보내기\"],\"o9ylQi\":[\"GIF를 검색하여 시작하세요\"],\"oFGkER\":[\"서버 알림\"],\"oOi11l\":[\"맨 아래로 스크롤\"],\"oQEzQR\":[\"새 DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"온라인\"],\"oal760\":[\"서버 링크에 대한 중간자 공격이 가능합니다\"],\"oeqmmJ\":[\"신뢰할 수 있는 출처\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"기본값\"],\"p0Z69r\":[\"패턴은 비워둘 수 없습니다\"],\"p1KgtK\":[\"오디오를 불러오지 못했습니다\"],\"p59pEv\":[\"추가 세부정보\"],\"p7sRI6\":[\"입력 중임을 다른 사람에게 알림\"],\"pBm1od\":[\"비밀 채널\"],\"pNmiXx\":[\"모든 서버에 사용할 기본 닉네임\"],\"pUUo9G\":[\"호스트명:\"],\"pVGPmz\":[\"계정 비밀번호\"],\"peNE68\":[\"영구\"],\"plhHQt\":[\"데이터 없음\"],\"pm6+q5\":[\"보안 경고\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"추가 정보\"],\"q0cR4S\":[\"이제 **\",[\"newNick\"],\"**(으)로 알려져 있습니다\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"LIST 또는 NAMES 명령에 채널이 표시되지 않음\"],\"qLpTm/\":[[\"emoji\"],\" 반응 제거\"],\"qVkGWK\":[\"고정\"],\"qY8wNa\":[\"홈페이지\"],\"qb0xJ7\":[\"와일드카드 사용: *는 임의의 문자열, ?는 임의의 단일 문자. 예: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"채널 키 (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"제한 없음\"],\"r1W2AS\":[\"파일 호스트 이미지\"],\"rIPR2O\":[\"주제 설정 이전 (분 전)\"],\"rMMSYo\":[\"최대 길이는 \",[\"0\"],\"자입니다\"],\"rWtzQe\":[\"네트워크가 분리되었다가 재연결되었습니다. ✅\"],\"rYG2u6\":[\"잠시만 기다려 주세요...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"미리보기\"],\"rjGI/Q\":[\"개인정보 보호\"],\"rk8iDX\":[\"GIF 불러오는 중...\"],\"rn6SBY\":[\"음소거 해제\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"채널에서 추방되었습니다\"],\"s8cATI\":[[\"channelName\"],\"에 참가했습니다\"],\"sCO9ue\":[\"<0>\",[\"serverName\"],\"</0>에 대한 연결에 다음과 같은 보안 문제가 있습니다:\"],\"sGH11W\":[\"서버\"],\"sHI1H+\":[\"이제 **\",[\"newNick\"],\"**(으)로 알려져 있습니다\"],\"sJyV04\":[[\"inviter\"],\"이(가) 당신을 \",[\"channel\"],\"에 초대했습니다\"],\"sby+1/\":[\"클릭하여 복사\"],\"sfN25C\":[\"실명 또는 전체 이름\"],\"sliuzR\":[\"링크 열기\"],\"sqrO9R\":[\"사용자 정의 멘션\"],\"sr6RdJ\":[\"Shift+Enter로 여러 줄 입력\"],\"swrCpB\":[[\"user\"],\"이(가) 채널을 \",[\"oldName\"],\"에서 \",[\"newName\"],\"(으)로 이름을 변경했습니다\",[\"0\"]],\"sxkWRg\":[\"고급\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"제거\"],\"t47eHD\":[\"이 서버에서의 고유 식별자\"],\"tAkAh0\":[\"동적 크기 조정을 위한 \",[\"size\"],\" 대체가 있는 URL. 예: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"채널 목록 사이드바 표시 또는 숨기기\"],\"tfDRzk\":[\"저장\"],\"tiBsJk\":[[\"channelName\"],\"에서 나갔습니다\"],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"서버를 나갔습니다 (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"닉네임 {nick}이(가) 이미 사용 중입니다. {newNick}(으)로 다시 시도합니다\"],\"u0a8B4\":[\"관리 권한을 위해 IRC Operator로 인증\"],\"u0rWFU\":[\"생성 시각 이후 (분 전)\"],\"u72w3t\":[\"무시할 사용자 및 패턴\"],\"u7jc2L\":[\"서버를 나갔습니다\"],\"uAQUqI\":[\"상태\"],\"uB85T3\":[\"저장 실패: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC 서버:\"],\"usSSr/\":[\"확대/축소 수준\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"소프트웨어:\"],\"vE8kb+\":[\"줄 바꿈은 Shift+Enter (Enter로 전송)\"],\"vERlcd\":[\"프로필\"],\"vK0RL8\":[\"주제 없음\"],\"vSJd18\":[\"동영상\"],\"vXIe7J\":[\"언어\"],\"vaHYxN\":[\"실명\"],\"vhjbKr\":[\"자리 비움\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" 클라이언트\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"잘못된 값\"],\"wFjjxZ\":[[\"username\"],\"에 의해 \",[\"channelName\"],\"에서 추방당했습니다 (\",[\"reason\"],\")\"],\"wGjaGl\":[\"차단 예외가 없습니다\"],\"wPrGnM\":[\"채널 관리자\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"사용자가 채널에 입장하거나 퇴장할 때 표시\"],\"whqZ9r\":[\"강조할 추가 단어 또는 문구\"],\"wm7RV4\":[\"알림 소리\"],\"wz/Yoq\":[\"서버 간 전달 시 메시지가 도청될 수 있습니다\"],\"xCJdfg\":[\"지우기\"],\"xUHRTR\":[\"연결 시 자동으로 operator로 인증\"],\"xWHwwQ\":[\"차단 목록\"],\"xYilR2\":[\"미디어\"],\"xceQrO\":[\"보안 웹소켓만 지원됩니다\"],\"xdtXa+\":[\"채널-이름\"],\"xfXC7q\":[\"텍스트 채널\"],\"xlCYOE\":[\"메시지를 더 불러오는 중...\"],\"xlhswE\":[\"최솟값은 \",[\"0\"],\"입니다\"],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"단어나 문구 추가...\"],\"xuRqRq\":[\"클라이언트 제한 (+l)\"],\"xwF+7J\":[[\"0\"],\"님이 입력 중...\"],\"yNeucF\":[\"이 서버는 확장 프로필 메타데이터(IRCv3 METADATA 확장)를 지원하지 않습니다. 아바타, 표시 이름, 상태 등의 추가 필드를 사용할 수 없습니다.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"채널 아바타\"],\"yQE2r9\":[\"로딩 중\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper 사용자 이름\"],\"yYOzWD\":[\"로그\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC operator 비밀번호\"],\"ygCKqB\":[\"정지\"],\"ymDxJx\":[\"IRC operator 사용자 이름\"],\"yrpRsQ\":[\"이름순 정렬\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"닫기\"],\"zJw+jA\":[\"모드 설정: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"oper 사용자 이름 입력\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Nieprawidłowy format wzorca. Użyj formatu nick!user@host (dozwolone symbole wieloznaczne *)\"],\"+6NQQA\":[\"Ogólny kanał wsparcia\"],\"+6NyRG\":[\"Klient\"],\"+K0AvT\":[\"Rozłącz\"],\"+cyFdH\":[\"Domyślna wiadomość przy ustawianiu statusu nieobecności\"],\"+mVPqU\":[\"Renderuj formatowanie Markdown w wiadomościach\"],\"+vqCJH\":[\"Nazwa użytkownika Twojego konta do uwierzytelniania\"],\"+yPBXI\":[\"Wybierz plik\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Niskie bezpieczeństwo połączenia (poziom \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Użytkownicy spoza kanału nie mogą wysyłać do niego wiadomości\"],\"/6BzZF\":[\"Przełącz listę członków\"],\"/TNOPk\":[\"Użytkownik jest nieobecny\"],\"/XQgft\":[\"Odkryj\"],\"/cF7Rs\":[\"Głośność\"],\"/dqduX\":[\"Następna strona\"],\"/fc3q4\":[\"Wszystkie treści\"],\"/kISDh\":[\"Włącz dźwięki powiadomień\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Odtwarzaj dźwięki dla wzmianek i wiadomości\"],\"0/0ZGA\":[\"Maska nazwy kanału\"],\"0D6j7U\":[\"Dowiedz się więcej o niestandardowych regułach →\"],\"0XsHcR\":[\"Wyrzuć użytkownika\"],\"0ZpE//\":[\"Sortuj według użytkowników\"],\"0bEPwz\":[\"Ustaw nieobecność\"],\"0dGkPt\":[\"Rozwiń listę kanałów\"],\"0gS7M5\":[\"Wyświetlana nazwa\"],\"0kS+M8\":[\"PrzykładSIEĆ\"],\"0rgoY7\":[\"Łącz się tylko z serwerami, które wybierzesz\"],\"0wdd7X\":[\"Dołącz\"],\"0wkVYx\":[\"Wiadomości prywatne\"],\"111uHX\":[\"Podgląd linku\"],\"196EG4\":[\"Usuń prywatną rozmowę\"],\"1DSr1i\":[\"Zarejestruj konto\"],\"1O/24y\":[\"Przełącz listę kanałów\"],\"1VPJJ2\":[\"Ostrzeżenie o zewnętrznym linku\"],\"1ZC/dv\":[\"Brak nieprzeczytanych wzmianek lub wiadomości\"],\"1pO1zi\":[\"Nazwa serwera jest wymagana\"],\"1uwfzQ\":[\"Zobacz temat kanału\"],\"268g7c\":[\"Wpisz wyświetlaną nazwę\"],\"2FOFq1\":[\"Operatorzy serwerów w sieci mogą potencjalnie odczytywać Twoje wiadomości\"],\"2FYpfJ\":[\"Więcej\"],\"2HF1Y2\":[[\"inviter\"],\" zaprosił \",[\"target\"],\" do dołączenia do \",[\"channel\"]],\"2I70QL\":[\"Zobacz informacje o profilu użytkownika\"],\"2QYdmE\":[\"Użytkownicy:\"],\"2QpEjG\":[\"wyszedł\"],\"2YE223\":[\"Wiadomość na #\",[\"0\"],\" (Enter – nowa linia, Shift+Enter – wyślij)\"],\"2bimFY\":[\"Użyj hasła serwera\"],\"2iTmdZ\":[\"Pamięć lokalna:\"],\"2odkwe\":[\"Rygorystyczny – bardziej agresywna ochrona\"],\"2uDhbA\":[\"Wpisz nazwę użytkownika do zaproszenia\"],\"2ygf/L\":[\"← Wróć\"],\"2zEgxj\":[\"Szukaj GIFów...\"],\"3RdPhl\":[\"Zmień nazwę kanału\"],\"3THokf\":[\"Użytkownik z głosem\"],\"3TSz9S\":[\"Minimalizuj\"],\"3jBDvM\":[\"Wyświetlana nazwa kanału\"],\"3ryuFU\":[\"Opcjonalne raporty o błędach w celu ulepszenia aplikacji\"],\"3uBF/8\":[\"Zamknij przeglądarkę\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Wprowadź nazwę konta...\"],\"4/Rr0R\":[\"Zaproś użytkownika do bieżącego kanału\"],\"4EZrJN\":[\"Reguły\"],\"4JJtW9\":[\"#przepełnienie\"],\"4NqeT4\":[\"Profil floodu (+F)\"],\"4RZQRK\":[\"Co teraz robisz?\"],\"4hfTrB\":[\"Nick\"],\"4n99LO\":[\"Już w \",[\"0\"]],\"4t6vMV\":[\"Automatycznie przełącz na jedną linię dla krótkich wiadomości\"],\"4vsHmf\":[\"Czas (min)\"],\"5+INAX\":[\"Podświetlaj wiadomości, w których jesteś wzmiankowany\"],\"5R5Pv/\":[\"Nazwa operatora\"],\"678PKt\":[\"Nazwa sieci\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Hasło wymagane do dołączenia do kanału. Pozostaw puste, aby usunąć klucz.\"],\"6HhMs3\":[\"Wiadomość pożegnalna\"],\"6V3Ea3\":[\"Skopiowano\"],\"6lGV3K\":[\"Pokaż mniej\"],\"6yFOEi\":[\"Wprowadź hasło opera...\"],\"7+IHTZ\":[\"Nie wybrano pliku\"],\"73hrRi\":[\"nick!user@host (np. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Wyślij wiadomość prywatną\"],\"7U1W7c\":[\"Bardzo łagodny\"],\"7Y1YQj\":[\"Imię i nazwisko:\"],\"7YHArF\":[\"— otwórz w przeglądarce\"],\"7fjnVl\":[\"Szukaj użytkowników...\"],\"7jL88x\":[\"Usunąć tę wiadomość? Tej operacji nie można cofnąć.\"],\"7nGhhM\":[\"Co masz na myśli?\"],\"7sEpu1\":[\"Członkowie — \",[\"0\"]],\"7sNhEz\":[\"Nazwa użytkownika\"],\"8H0Q+x\":[\"Dowiedz się więcej o profilach →\"],\"8Phu0A\":[\"Wyświetlaj gdy użytkownicy zmieniają nick\"],\"8XTG9e\":[\"Wpisz hasło operatora\"],\"8XsV2J\":[\"Spróbuj wysłać ponownie\"],\"8ZsakT\":[\"Hasło\"],\"8kR84m\":[\"Zamierzasz otworzyć zewnętrzny link:\"],\"8lCgih\":[\"Usuń regułę\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"dołączył\"],\"few\":[\"dołączył \",[\"joinCount\"],\" razy\"],\"many\":[\"dołączył \",[\"joinCount\"],\" razy\"],\"other\":[\"dołączył \",[\"joinCount\"],\" razy\"]}]],\"9BMLnJ\":[\"Ponownie połącz z serwerem\"],\"9OEgyT\":[\"Dodaj reakcję\"],\"9PQ8m2\":[\"G-Line (globalny ban)\"],\"9Qs99X\":[\"E-mail:\"],\"9QupBP\":[\"Usuń wzorzec\"],\"9bG48P\":[\"Wysyłanie\"],\"9f5f0u\":[\"Pytania dotyczące prywatności? Skontaktuj się z nami:\"],\"9unqs3\":[\"Nieobecny:\"],\"9v3hwv\":[\"Nie znaleziono serwerów.\"],\"9zb2WA\":[\"Łączenie\"],\"A1taO8\":[\"Szukaj\"],\"A2adVi\":[\"Wysyłaj powiadomienia o pisaniu\"],\"A9Rhec\":[\"Nazwa kanału\"],\"AWOSPo\":[\"Powiększ\"],\"AXSpEQ\":[\"Operator przy połączeniu\"],\"AeXO77\":[\"Konto\"],\"AhNP40\":[\"Przewijaj\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Zmieniono nick\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Anuluj odpowiedź\"],\"ApSx0O\":[\"Znaleziono \",[\"0\"],\" wiadomości pasujących do \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Nie znaleziono wyników\"],\"AyNqAB\":[\"Wyświetl wszystkie zdarzenia serwera w czacie\"],\"B/QqGw\":[\"Z dala od klawiatury\"],\"B8AaMI\":[\"To pole jest wymagane\"],\"BA2c49\":[\"Serwer nie obsługuje zaawansowanego filtrowania LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" i \",[\"3\"],\" innych pisze...\"],\"BGul2A\":[\"Masz niezapisane zmiany. Czy na pewno chcesz zamknąć bez zapisywania?\"],\"BIf9fi\":[\"Twoja wiadomość statusu\"],\"BZz3md\":[\"Twoja strona internetowa\"],\"Bgm/H7\":[\"Zezwól na wpisywanie wielu linii tekstu\"],\"BiQIl1\":[\"Przypnij tę prywatną rozmowę\"],\"BlNZZ2\":[\"Kliknij, aby przejść do wiadomości\"],\"Bowq3c\":[\"Tylko operatorzy mogą zmieniać temat kanału\"],\"Btozzp\":[\"Ten obraz wygasł\"],\"Bycfjm\":[\"Łącznie: \",[\"0\"]],\"C6IBQc\":[\"Kopiuj cały JSON\"],\"C9L9wL\":[\"Zbieranie danych\"],\"CDq4wC\":[\"Moderuj użytkownika\"],\"CHVRxG\":[\"Wiadomość do @\",[\"0\"],\" (Shift+Enter – nowa linia)\"],\"CN9zdR\":[\"Nazwa operatora i hasło są wymagane\"],\"CW3sYa\":[\"Dodaj reakcję \",[\"emoji\"]],\"CaAkqd\":[\"Pokaż rozłączenia\"],\"CbvaYj\":[\"Zablokuj po nicku\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Wybierz kanał\"],\"CsekCi\":[\"Normalny\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"dołączył i wyszedł\"],\"DB8zMK\":[\"Zastosuj\"],\"DBcWHr\":[\"Niestandardowy plik dźwięku powiadomień\"],\"DTy9Xw\":[\"Podglądy mediów\"],\"Dj4pSr\":[\"Wybierz bezpieczne hasło\"],\"Du+zn+\":[\"Wyszukiwanie...\"],\"Du2T2f\":[\"Nie znaleziono ustawienia\"],\"DwsSVQ\":[\"Zastosuj filtry i odśwież\"],\"E3W/zd\":[\"Domyślny nick\"],\"E6nRW7\":[\"Kopiuj URL\"],\"E703RG\":[\"Tryby:\"],\"EAeu1Z\":[\"Wyślij zaproszenie\"],\"EFKJQT\":[\"Ustawienie\"],\"EGPQBv\":[\"Niestandardowe reguły floodu (+f)\"],\"ELik0r\":[\"Zobacz pełną politykę prywatności\"],\"EPbeC2\":[\"Zobacz lub edytuj temat kanału\"],\"EQCDNT\":[\"Wprowadź nazwę użytkownika opera...\"],\"EUvulZ\":[\"Znaleziono 1 wiadomość pasującą do \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Następny obraz\"],\"EdQY6l\":[\"Brak\"],\"EnqLYU\":[\"Szukaj serwerów...\"],\"F0OKMc\":[\"Edytuj serwer\"],\"F6Int2\":[\"Włącz podświetlenia\"],\"FDoLyE\":[\"Maks. użytkowników\"],\"FUU/hZ\":[\"Kontroluje ilość zewnętrznych mediów ładowanych na czacie.\"],\"Fdp03t\":[\"wł\"],\"FfPWR0\":[\"Okno\"],\"FjkaiT\":[\"Pomniejsz\"],\"FlqOE9\":[\"Co to oznacza:\"],\"FolHNl\":[\"Zarządzaj swoim kontem i uwierzytelnianiem\"],\"Fp2Dif\":[\"Opuścił serwer\"],\"G5KmCc\":[\"GZ-Line (globalna Z-Line)\"],\"GDs0lz\":[\"<0>Ryzyko:</0> Poufne informacje (wiadomości, prywatne rozmowy, dane uwierzytelniające) mogą być widoczne dla administratorów sieci lub atakujących znajdujących się między serwerami IRC.\"],\"GR+2I3\":[\"Dodaj maskę zaproszenia (np. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Zamknij wyskakujące powiadomienia serwera\"],\"GlHnXw\":[\"Zmiana nicku nie powiodła się: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Podgląd:\"],\"GtmO8/\":[\"od\"],\"GtuHUQ\":[\"Zmień nazwę tego kanału na serwerze. Wszyscy użytkownicy zobaczą nową nazwę.\"],\"GuGfFX\":[\"Przełącz wyszukiwanie\"],\"GxkJXS\":[\"Przesyłanie...\"],\"GzbwnK\":[\"Dołączył do kanału\"],\"GzsUDB\":[\"Rozszerzony profil\"],\"H/PnT8\":[\"Wstaw emoji\"],\"H6Izzl\":[\"Twój preferowany kod koloru\"],\"H9jIv+\":[\"Pokaż dołączenia/odejścia\"],\"HAKBY9\":[\"Prześlij pliki\"],\"HdE1If\":[\"Kanał\"],\"Hk4AW9\":[\"Twoja preferowana wyświetlana nazwa\"],\"HmHDk7\":[\"Wybierz członka\"],\"HrQzPU\":[\"Kanały na \",[\"networkName\"]],\"I2tXQ5\":[\"Wiadomość do @\",[\"0\"],\" (Enter – nowa linia, Shift+Enter – wyślij)\"],\"I6bw/h\":[\"Zablokuj użytkownika\"],\"I92Z+b\":[\"Włącz powiadomienia\"],\"I9D72S\":[\"Czy na pewno chcesz usunąć tę wiadomość? Tej operacji nie można cofnąć.\"],\"IA+1wo\":[\"Wyświetlaj gdy użytkownicy są wyrzucani z kanałów\"],\"IDwkJx\":[\"Operator IRC\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Zapisz zmiany\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" i \",[\"2\"],\" piszą...\"],\"IgrLD/\":[\"Pauza\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Odpowiedz\"],\"IoHMnl\":[\"Maksymalna wartość wynosi \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Łączenie...\"],\"J5T9NW\":[\"Informacje o użytkowniku\"],\"J8Y5+z\":[\"Ups! Podział sieci! ⚠️\"],\"JBHkBA\":[\"Opuścił kanał\"],\"JCwL0Q\":[\"Wpisz powód (opcjonalnie)\"],\"JFciKP\":[\"Przełącz\"],\"JXGkhG\":[\"Zmień nazwę kanału (tylko dla operatorów)\"],\"JcD7qf\":[\"Więcej akcji\"],\"JdkA+c\":[\"Tajny (+s)\"],\"Jmu12l\":[\"Kanały serwera\"],\"JvQ++s\":[\"Włącz Markdown\"],\"K2jwh/\":[\"Brak dostępnych danych WHOIS\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Usuń wiadomość\"],\"KKBlUU\":[\"Osadź\"],\"KM0pLb\":[\"Witamy na kanale!\"],\"KR6W2h\":[\"Przestań ignorować użytkownika\"],\"KV+Bi1\":[\"Tylko na zaproszenie (+i)\"],\"KdCtwE\":[\"Ile sekund monitorować aktywność floodowania przed zresetowaniem liczników\"],\"Kkezga\":[\"Hasło serwera\"],\"KsiQ/8\":[\"Użytkownicy muszą być zaproszeni, aby dołączyć do kanału\"],\"L+gB/D\":[\"Informacje o kanale\"],\"LC1a7n\":[\"Serwer IRC zgłosił, że jego połączenia między serwerami mają niski poziom bezpieczeństwa. Oznacza to, że gdy Twoje wiadomości są przekazywane między serwerami IRC w sieci, mogą nie być właściwie szyfrowane lub certyfikaty SSL/TLS mogą nie być poprawnie weryfikowane.\"],\"LNfLR5\":[\"Pokaż wyrzucenia\"],\"LQb0W/\":[\"Pokaż wszystkie zdarzenia\"],\"LU7/yA\":[\"Alternatywna nazwa wyświetlana w interfejsie. Może zawierać spacje, emoji i znaki specjalne. Prawdziwa nazwa kanału (\",[\"channelName\"],\") nadal będzie używana w poleceniach IRC.\"],\"LUb9O7\":[\"Wymagany jest prawidłowy port serwera\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Polityka prywatności\"],\"LcuSDR\":[\"Zarządzaj informacjami w profilu i metadanymi\"],\"LqLS9B\":[\"Pokaż zmiany nicku\"],\"LsDQt2\":[\"Ustawienia kanału\"],\"LtI9AS\":[\"Właściciel\"],\"LuNhhL\":[\"zareagował na tę wiadomość\"],\"M/AZNG\":[\"URL do obrazu Twojego awatara\"],\"M/WIer\":[\"Wyślij wiadomość\"],\"M8er/5\":[\"Nazwa:\"],\"MHk+7g\":[\"Poprzedni obraz\"],\"MRorGe\":[\"Wyślij wiadomość prywatną\"],\"MVbSGP\":[\"Okno czasowe (sekundy)\"],\"MkpcsT\":[\"Twoje wiadomości i ustawienia są przechowywane lokalnie na Twoim urządzeniu\"],\"N/hDSy\":[\"Oznacz jako bota – zazwyczaj 'on' lub puste\"],\"N7TQbE\":[\"Zaproś użytkownika do \",[\"channelName\"]],\"NCca/o\":[\"Wprowadź domyślny pseudonim...\"],\"Nqs6B9\":[\"Wyświetla wszystkie zewnętrzne media. Każdy URL może spowodować żądanie do nieznanego serwera.\"],\"Nt+9O7\":[\"Użyj WebSocket zamiast surowego TCP\"],\"NxIHzc\":[\"Rozłącz użytkownika\"],\"O+v/cL\":[\"Przeglądaj wszystkie kanały na serwerze\"],\"ODwSCk\":[\"Wyślij GIF\"],\"OGQ5kK\":[\"Konfiguruj dźwięki powiadomień i podświetlenia\"],\"OIPt1Z\":[\"Pokaż lub ukryj panel listy członków\"],\"OKSNq/\":[\"Bardzo rygorystyczny\"],\"ONWvwQ\":[\"Prześlij\"],\"OVKoQO\":[\"Hasło Twojego konta do uwierzytelniania\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Przenosimy IRC w przyszłość\"],\"OhCpra\":[\"Ustaw temat…\"],\"OkltoQ\":[\"Zablokuj \",[\"username\"],\" po nicku (uniemożliwia ponowne dołączenie z tym samym nickiem)\"],\"P+t/Te\":[\"Brak dodatkowych danych\"],\"P42Wcc\":[\"Bezpieczne\"],\"PD38l0\":[\"Podgląd awatara kanału\"],\"PD9mEt\":[\"Wpisz wiadomość...\"],\"PPqfdA\":[\"Otwórz ustawienia konfiguracji kanału\"],\"PSCjfZ\":[\"Temat, który będzie wyświetlany dla tego kanału. Wszyscy użytkownicy mogą zobaczyć temat.\"],\"PZCecv\":[\"Podgląd PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 raz\"],\"few\":[[\"c\"],\" razy\"],\"many\":[[\"c\"],\" razy\"],\"other\":[[\"c\"],\" razy\"]}]],\"PguS2C\":[\"Dodaj maskę wyjątku (np. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Wyświetlanie \",[\"displayedChannelsCount\"],\" z \",[\"0\"],\" kanałów\"],\"PqhVlJ\":[\"Zablokuj użytkownika (po hostmasce)\"],\"Q+chwU\":[\"Nazwa użytkownika:\"],\"Q6hhn8\":[\"Preferencje\"],\"QF4a34\":[\"Proszę podać nazwę użytkownika\"],\"QGqSZ2\":[\"Kolor i formatowanie\"],\"QJQd1J\":[\"Edytuj profil\"],\"QSzGDE\":[\"Bezczynny\"],\"QUlny5\":[\"Witamy na \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Czytaj więcej\"],\"QuSkCF\":[\"Filtruj kanały...\"],\"QwUrDZ\":[\"zmienił temat na: \",[\"topic\"]],\"R0UH07\":[\"Obraz \",[\"0\"],\" z \",[\"1\"]],\"R7SsBE\":[\"Wycisz\"],\"R8rf1X\":[\"Kliknij, aby ustawić temat\"],\"RArB3D\":[\"został wyrzucony z \",[\"channelName\"],\" przez \",[\"username\"]],\"RI3cWd\":[\"Odkryj świat IRC z ObsidianIRC\"],\"RMMaN5\":[\"Moderowany (+m)\"],\"RWw9Lg\":[\"Zamknij okno\"],\"RZ2BuZ\":[\"Rejestracja konta \",[\"account\"],\" wymaga weryfikacji: \",[\"message\"]],\"RySp6q\":[\"Ukryj komentarze\"],\"SPKQTd\":[\"Nick jest wymagany\"],\"SPVjfj\":[\"Domyślnie 'brak powodu', jeśli pozostawione puste\"],\"SQKPvQ\":[\"Zaproś użytkownika\"],\"SkZcl+\":[\"Wybierz wstępnie zdefiniowany profil ochrony przed floodem. Profile te oferują zrównoważone ustawienia ochrony dla różnych przypadków użycia.\"],\"Slr+3C\":[\"Min. użytkowników\"],\"Spnlre\":[\"Zaprosiłeś \",[\"target\"],\" do dołączenia do \",[\"channel\"]],\"T/ckN5\":[\"Otwórz w przeglądarce mediów\"],\"T91vKp\":[\"Odtwórz\"],\"TV2Wdu\":[\"Dowiedz się, jak przetwarzamy Twoje dane i chronimy Twoją prywatność.\"],\"TgFpwD\":[\"Stosowanie...\"],\"TkzSFB\":[\"Brak zmian\"],\"TtserG\":[\"Wpisz prawdziwe imię i nazwisko\"],\"Ttz9J1\":[\"Wprowadź hasło...\"],\"Tz0i8g\":[\"Ustawienia\"],\"U3pytU\":[\"Administrator\"],\"UDb2YD\":[\"Zareaguj\"],\"UE4KO5\":[\"*kanał*\"],\"UGT5vp\":[\"Zapisz ustawienia\"],\"UV5hLB\":[\"Nie znaleziono żadnych banów\"],\"Uaj3Nd\":[\"Wiadomości statusu\"],\"Ue3uny\":[\"Domyślne (brak profilu)\"],\"UkARhe\":[\"Normalny – standardowa ochrona\"],\"Umn7Cj\":[\"Brak komentarzy. Bądź pierwszy!\"],\"UtUIRh\":[[\"0\"],\" starszych wiadomości\"],\"UwzP+U\":[\"Bezpieczne połączenie\"],\"V0/A4O\":[\"Właściciel kanału\"],\"V4qgxE\":[\"Utworzone przed (min temu)\"],\"V8yTm6\":[\"Wyczyść wyszukiwanie\"],\"VJMMyz\":[\"ObsidianIRC – IRC w nowoczesnym wydaniu\"],\"VJScHU\":[\"Powód\"],\"VLsmVV\":[\"Wycisz powiadomienia\"],\"VbyRUy\":[\"Komentarze\"],\"Vmx0mQ\":[\"Ustawione przez:\"],\"VqnIZz\":[\"Zobacz naszą politykę prywatności i zasady przetwarzania danych\"],\"VrMygG\":[\"Minimalna długość wynosi \",[\"0\"]],\"VrnTui\":[\"Twoje zaimki, widoczne w profilu\"],\"W8E3qn\":[\"Uwierzytelnione konto\"],\"WAakm9\":[\"Usuń kanał\"],\"WFxTHC\":[\"Dodaj maskę bana (np. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Host serwera jest wymagany\"],\"WRYdXW\":[\"Pozycja audio\"],\"WUOH5B\":[\"Ignoruj użytkownika\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Pokaż 1 więcej element\"],\"few\":[\"Pokaż \",[\"1\"],\" więcej elementów\"],\"many\":[\"Pokaż \",[\"1\"],\" więcej elementów\"],\"other\":[\"Pokaż \",[\"1\"],\" więcej elementów\"]}]],\"Weq9zb\":[\"Ogólne\"],\"Wfj7Sk\":[\"Wycisz lub odcisz dźwięki powiadomień\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profil użytkownika\"],\"X6S3lt\":[\"Szukaj ustawień, kanałów, serwerów...\"],\"XEHan5\":[\"Kontynuuj mimo to\"],\"XI1+wb\":[\"Nieprawidłowy format\"],\"XIXeuC\":[\"Wiadomość do @\",[\"0\"]],\"XMS+k4\":[\"Rozpocznij prywatną rozmowę\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Odepnij prywatną rozmowę\"],\"Xm/s+u\":[\"Wyświetlanie\"],\"Xp2n93\":[\"Wyświetla media z zaufanego hosta plików Twojego serwera. Żadne żądania nie są wysyłane do zewnętrznych serwisów.\"],\"XvjC4F\":[\"Zapisywanie...\"],\"Y/qryO\":[\"Nie znaleziono użytkowników pasujących do wyszukiwania\"],\"YAqRpI\":[\"Rejestracja konta \",[\"account\"],\" powiodła się: \",[\"message\"]],\"YEfzvP\":[\"Chroniony temat (+t)\"],\"YQOn6a\":[\"Zwiń listę członków\"],\"YRCoE9\":[\"Operator kanału\"],\"YURQaF\":[\"Zobacz profil\"],\"YdBSvr\":[\"Kontroluj wyświetlanie mediów i zewnętrznych treści\"],\"Yj6U3V\":[\"Brak centralnego serwera:\"],\"YjvpGx\":[\"Zaimki\"],\"YqH4l4\":[\"Brak klucza\"],\"YyUPpV\":[\"Konto:\"],\"ZJSWfw\":[\"Wiadomość wyświetlana przy rozłączeniu z serwera\"],\"ZR1dJ4\":[\"Zaproszenia\"],\"ZdWg0V\":[\"Otwórz w przeglądarce\"],\"ZhRBbl\":[\"Szukaj wiadomości…\"],\"Zmcu3y\":[\"Zaawansowane filtry\"],\"a2/8e5\":[\"Temat ustawiony po (min temu)\"],\"aHKcKc\":[\"Poprzednia strona\"],\"aJTbXX\":[\"Hasło operatora\"],\"aQryQv\":[\"Wzorzec już istnieje\"],\"aW9pLN\":[\"Maksymalna liczba użytkowników dozwolona na kanale. Pozostaw puste, aby nie było limitu.\"],\"ah4fmZ\":[\"Wyświetla również podglądy z YouTube, Vimeo, SoundCloud i podobnych znanych serwisów.\"],\"aifXak\":[\"Brak mediów na tym kanale\"],\"ap2zBz\":[\"Łagodny\"],\"az8lvo\":[\"Wyłączone\"],\"azXSNo\":[\"Rozwiń listę członków\"],\"azdliB\":[\"Zaloguj się na konto\"],\"b26wlF\":[\"ona/jej\"],\"bD/+Ei\":[\"Rygorystyczny\"],\"bQ6BJn\":[\"Konfiguruj szczegółowe reguły ochrony przed floodem. Każda reguła określa, jaki rodzaj aktywności monitorować i jakie działanie podjąć po przekroczeniu progów.\"],\"beV7+y\":[\"Użytkownik otrzyma zaproszenie do dołączenia do \",[\"channelName\"],\".\"],\"bk84cH\":[\"Wiadomość o nieobecności\"],\"bkHdLj\":[\"Dodaj serwer IRC\"],\"bmQLn5\":[\"Dodaj regułę\"],\"bwRvnp\":[\"Akcja\"],\"c8+EVZ\":[\"Zweryfikowane konto\"],\"cGYUlD\":[\"Nie wczytano żadnych podglądów mediów.\"],\"cLF98o\":[\"Pokaż komentarze (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Brak dostępnych użytkowników\"],\"cSgpoS\":[\"Przypnij prywatną rozmowę\"],\"cde3ce\":[\"Wiadomość do <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopiuj sformatowane wyjście\"],\"cl/A5J\":[\"Witamy na \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Usuń\"],\"coPLXT\":[\"Nie przechowujemy Twoich komunikatów IRC na naszych serwerach\"],\"crYH/6\":[\"Odtwarzacz SoundCloud\"],\"d3sis4\":[\"Dodaj serwer\"],\"d9aN5k\":[\"Usuń \",[\"username\"],\" z kanału\"],\"dEgA5A\":[\"Anuluj\"],\"dGi1We\":[\"Odepnij tę prywatną rozmowę\"],\"dJVuyC\":[\"opuścił \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"do\"],\"dXqxlh\":[\"<0>⚠️ Zagrożenie bezpieczeństwa!</0> To połączenie może być podatne na przechwycenie lub ataki typu man-in-the-middle.\"],\"da9Q/R\":[\"Zmieniono tryby kanału\"],\"dhJN3N\":[\"Pokaż komentarze\"],\"dj2xTE\":[\"Odrzuć powiadomienie\"],\"dpCzmC\":[\"Ustawienia ochrony przed floodem\"],\"e9dQpT\":[\"Czy chcesz otworzyć ten link w nowej karcie?\"],\"ePK91l\":[\"Edytuj\"],\"eYBDuB\":[\"Prześlij obraz lub podaj URL z opcjonalnym podstawieniem \",[\"size\"],\" dla dynamicznego rozmiaru\"],\"edBbee\":[\"Zablokuj \",[\"username\"],\" po hostmasce (uniemożliwia ponowne dołączenie z tego samego IP/hosta)\"],\"ekfzWq\":[\"Ustawienia użytkownika\"],\"elPDWs\":[\"Dostosuj swoje doświadczenie z klientem IRC\"],\"eu2osY\":[\"<0>💡 Zalecenie:</0> Kontynuuj tylko jeśli ufasz temu serwerowi i rozumiesz ryzyko. Unikaj udostępniania poufnych informacji lub haseł przez to połączenie.\"],\"euEhbr\":[\"Kliknij, aby dołączyć do \",[\"channel\"]],\"ez3vLd\":[\"Włącz wieloliniowe wprowadzanie\"],\"f0J5Ki\":[\"Komunikacja między serwerami może używać nieszyfrowanych połączeń\"],\"f9BHJk\":[\"Ostrzeż użytkownika\"],\"fDOLLd\":[\"Nie znaleziono kanałów.\"],\"ffzDkB\":[\"Anonimowa analityka:\"],\"fq1GF9\":[\"Wyświetlaj gdy użytkownicy rozłączają się z serwera\"],\"gEF57C\":[\"Ten serwer obsługuje tylko jeden typ połączenia\"],\"gJuLUI\":[\"Lista ignorowanych\"],\"gNzMrk\":[\"Bieżący awatar\"],\"gjPWyO\":[\"Wprowadź pseudonim...\"],\"gz6UQ3\":[\"Maksymalizuj\"],\"h6razj\":[\"Wyklucz maskę nazwy kanału\"],\"hG6jnw\":[\"Nie ustawiono tematu\"],\"hG89Ed\":[\"Obraz\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"np. 100:1440\"],\"hehnjM\":[\"Ilość\"],\"hzdLuQ\":[\"Tylko użytkownicy z głosem lub wyżej mogą mówić\"],\"i0qMbr\":[\"Strona główna\"],\"iDNBZe\":[\"Powiadomienia\"],\"iH8pgl\":[\"Wróć\"],\"iL9SZg\":[\"Zablokuj użytkownika (po nicku)\"],\"iNt+3c\":[\"Wróć do obrazu\"],\"iQvi+a\":[\"Nie ostrzegaj mnie o niskim poziomie bezpieczeństwa połączeń dla tego serwera\"],\"iSLIjg\":[\"Połącz\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Host serwera\"],\"idD8Ev\":[\"Zapisano\"],\"iivqkW\":[\"Zalogowany od\"],\"ij+Elv\":[\"Podgląd obrazu\"],\"ilIWp7\":[\"Przełącz powiadomienia\"],\"iuaqvB\":[\"Użyj * jako symbolu wieloznacznego. Przykłady: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Zablokuj po hostmasce\"],\"jA4uoI\":[\"Temat:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Powód (opcjonalnie)\"],\"jUV7CU\":[\"Prześlij awatar\"],\"jW5Uwh\":[\"Kontroluj ilość wczytywanego zewnętrznego medium. Wyłączone / Bezpieczne / Zaufane źródła / Wszystkie treści.\"],\"jXzms5\":[\"Opcje załącznika\"],\"jZlrte\":[\"Kolor\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#nowa-nazwa-kanału\"],\"k112DD\":[\"Wczytaj starsze wiadomości\"],\"k3ID0F\":[\"Filtruj członków…\"],\"k65gsE\":[\"Szczegóły\"],\"k7Zgob\":[\"Anuluj połączenie\"],\"kAVx5h\":[\"Nie znaleziono zaproszeń\"],\"kCLEPU\":[\"Połączony z\"],\"kF5LKb\":[\"Ignorowane wzorce:\"],\"kGeOx/\":[\"Dołącz do \",[\"0\"]],\"kITKr8\":[\"Wczytywanie trybów kanału...\"],\"kPpPsw\":[\"Jesteś operatorem IRC\"],\"kWJmRL\":[\"Ty\"],\"kfcRb0\":[\"Awatar\"],\"kjMqSj\":[\"Kopiuj JSON\"],\"krViRy\":[\"Kliknij, aby skopiować jako JSON\"],\"ks71ra\":[\"Wyjątki\"],\"kw4lRv\":[\"Pół-operator kanału\"],\"kxgIRq\":[\"Wybierz lub dodaj kanał, aby zacząć.\"],\"ky6dWe\":[\"Podgląd awatara\"],\"l+GxCv\":[\"Wczytywanie kanałów...\"],\"l+IUVW\":[\"Weryfikacja konta \",[\"account\"],\" powiodła się: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"ponownie połączył\"],\"few\":[\"ponownie połączył \",[\"reconnectCount\"],\" razy\"],\"many\":[\"ponownie połączył \",[\"reconnectCount\"],\" razy\"],\"other\":[\"ponownie połączył \",[\"reconnectCount\"],\" razy\"]}]],\"l5jmzx\":[[\"0\"],\" i \",[\"1\"],\" piszą...\"],\"lHy8N5\":[\"Wczytywanie kolejnych kanałów...\"],\"lbpf14\":[\"Dołącz do \",[\"value\"]],\"lfFsZ4\":[\"Kanały\"],\"lkNdiH\":[\"Nazwa konta\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Prześlij obraz\"],\"loQxaJ\":[\"Wróciłem\"],\"lvfaxv\":[\"STRONA GŁÓWNA\"],\"m16xKo\":[\"Dodaj\"],\"m8flAk\":[\"Podgląd (jeszcze nie przesłany)\"],\"mEPxTp\":[\"<0>⚠️ Uwaga!</0> Otwieraj tylko linki z zaufanych źródeł. Złośliwe linki mogą narazić Twoje bezpieczeństwo lub prywatność.\"],\"mHGdhG\":[\"Informacje o serwerze\"],\"mHS8lb\":[\"Wiadomość na #\",[\"0\"]],\"mMYBD9\":[\"Szeroki – szerszy zakres ochrony\"],\"mTGsPd\":[\"Temat kanału\"],\"mU8j6O\":[\"Brak zewnętrznych wiadomości (+n)\"],\"mZp8FL\":[\"Automatyczny powrót do jednej linii\"],\"mdQu8G\":[\"TwójNick\"],\"miSSBQ\":[\"Komentarze (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Użytkownik jest uwierzytelniony\"],\"mwtcGl\":[\"Zamknij komentarze\"],\"mzI/c+\":[\"Pobierz\"],\"n3fGRk\":[\"ustawione przez \",[\"0\"]],\"nE9jsU\":[\"Łagodny – mniej agresywna ochrona\"],\"nNflMD\":[\"Opuść kanał\"],\"nPXkBi\":[\"Wczytywanie danych WHOIS...\"],\"nQnxxF\":[\"Wiadomość na #\",[\"0\"],\" (Shift+Enter – nowa linia)\"],\"nWMRxa\":[\"Odepnij\"],\"nkC032\":[\"Brak profilu floodu\"],\"o69z4d\":[\"Wyślij wiadomość ostrzegawczą do \",[\"username\"]],\"o9ylQi\":[\"Wyszukaj GIFy, aby rozpocząć\"],\"oFGkER\":[\"Powiadomienia serwera\"],\"oOi11l\":[\"Przewiń na dół\"],\"oQEzQR\":[\"Nowy DM\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Ataki man-in-the-middle na połączenia serwera są możliwe\"],\"oeqmmJ\":[\"Zaufane źródła\"],\"ovBPCi\":[\"Domyślne\"],\"p0Z69r\":[\"Wzorzec nie może być pusty\"],\"p1KgtK\":[\"Nie udało się załadować audio\"],\"p59pEv\":[\"Dodatkowe szczegóły\"],\"p7sRI6\":[\"Informuj innych, gdy piszesz\"],\"pBm1od\":[\"Tajny kanał\"],\"pNmiXx\":[\"Twój domyślny nick dla wszystkich serwerów\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Hasło konta\"],\"peNE68\":[\"Stały\"],\"plhHQt\":[\"Brak danych\"],\"pm6+q5\":[\"Ostrzeżenie o bezpieczeństwie\"],\"pn5qSs\":[\"Dodatkowe informacje\"],\"q0cR4S\":[\"jest teraz znany jako **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Kanał nie będzie widoczny w poleceniach LIST ani NAMES\"],\"qLpTm/\":[\"Usuń reakcję \",[\"emoji\"]],\"qVkGWK\":[\"Przypnij\"],\"qY8wNa\":[\"Strona internetowa\"],\"qb0xJ7\":[\"Użyj symboli wieloznacznych: * pasuje do dowolnej sekwencji, ? pasuje do dowolnego pojedynczego znaku. Przykłady: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Klucz kanału (+k)\"],\"qtoOYG\":[\"Brak limitu\"],\"r1W2AS\":[\"Obraz z serwera plików\"],\"rIPR2O\":[\"Temat ustawiony przed (min temu)\"],\"rMMSYo\":[\"Maksymalna długość wynosi \",[\"0\"]],\"rWtzQe\":[\"Sieć rozdzieliła się i ponownie połączyła. ✅\"],\"rYG2u6\":[\"Proszę czekać...\"],\"rdUucN\":[\"Podgląd\"],\"rjGI/Q\":[\"Prywatność\"],\"rk8iDX\":[\"Wczytywanie GIFów...\"],\"rn6SBY\":[\"Odcisz\"],\"s/UKqq\":[\"Został wyrzucony z kanału\"],\"s8cATI\":[\"dołączył do \",[\"channelName\"]],\"sCO9ue\":[\"Połączenie z <0>\",[\"serverName\"],\"</0> ma następujące problemy z bezpieczeństwem:\"],\"sGH11W\":[\"Serwer\"],\"sHI1H+\":[\"jest teraz znany jako **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" zaprosił cię do dołączenia do \",[\"channel\"]],\"sby+1/\":[\"Kliknij, aby skopiować\"],\"sfN25C\":[\"Twoje prawdziwe imię i nazwisko\"],\"sliuzR\":[\"Otwórz link\"],\"sqrO9R\":[\"Niestandardowe wzmianki\"],\"sr6RdJ\":[\"Wieloliniowy przy Shift+Enter\"],\"swrCpB\":[\"Kanał został przemianowany z \",[\"oldName\"],\" na \",[\"newName\"],\" przez \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Zaawansowane\"],\"t/YqKh\":[\"Usuń\"],\"t47eHD\":[\"Twój unikalny identyfikator na tym serwerze\"],\"tAkAh0\":[\"URL z opcjonalnym podstawieniem \",[\"size\"],\" dla dynamicznego rozmiaru. Przykład: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Pokaż lub ukryj panel listy kanałów\"],\"tfDRzk\":[\"Zapisz\"],\"tiBsJk\":[\"opuścił \",[\"channelName\"]],\"tt4/UD\":[\"wyszedł (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Nick {nick} jest już używany, ponawiam z {newNick}\"],\"u0a8B4\":[\"Uwierzytelnij się jako operator IRC, aby uzyskać dostęp administracyjny\"],\"u0rWFU\":[\"Utworzone po (min temu)\"],\"u72w3t\":[\"Użytkownicy i wzorce do ignorowania\"],\"u7jc2L\":[\"wyszedł\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Zapis nieudany: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"Serwery IRC:\"],\"usSSr/\":[\"Poziom powiększenia\"],\"v7uvcf\":[\"Oprogramowanie:\"],\"vE8kb+\":[\"Użyj Shift+Enter dla nowych linii (Enter wysyła)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Brak tematu\"],\"vSJd18\":[\"Wideo\"],\"vXIe7J\":[\"Język\"],\"vaHYxN\":[\"Prawdziwe imię i nazwisko\"],\"vhjbKr\":[\"Nieobecny\"],\"w4NYox\":[\"klient \",[\"title\"]],\"w8xQRx\":[\"Nieprawidłowa wartość\"],\"wFjjxZ\":[\"został wyrzucony z \",[\"channelName\"],\" przez \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nie znaleziono wyjątków od bana\"],\"wPrGnM\":[\"Administrator kanału\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Wyświetlaj gdy użytkownicy dołączają lub opuszczają kanały\"],\"whqZ9r\":[\"Dodatkowe słowa lub frazy do podświetlenia\"],\"wm7RV4\":[\"Dźwięk powiadomienia\"],\"wz/Yoq\":[\"Twoje wiadomości mogą zostać przechwycone podczas przekazywania między serwerami\"],\"xCJdfg\":[\"Wyczyść\"],\"xUHRTR\":[\"Automatycznie uwierzytelniaj jako operator przy połączeniu\"],\"xWHwwQ\":[\"Blokady\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Obsługiwane są tylko bezpieczne WebSocket\"],\"xdtXa+\":[\"nazwa-kanału\"],\"xfXC7q\":[\"Kanały tekstowe\"],\"xlCYOE\":[\"Pobieranie kolejnych wiadomości...\"],\"xlhswE\":[\"Minimalna wartość wynosi \",[\"0\"]],\"xq97Ci\":[\"Dodaj słowo lub frazę...\"],\"xuRqRq\":[\"Limit klientów (+l)\"],\"xwF+7J\":[[\"0\"],\" pisze...\"],\"yNeucF\":[\"Ten serwer nie obsługuje rozszerzonych metadanych profilu (rozszerzenie IRCv3 METADATA). Dodatkowe pola, takie jak awatar, wyświetlana nazwa i status, nie są dostępne.\"],\"yPlrca\":[\"Awatar kanału\"],\"yQE2r9\":[\"Ładowanie\"],\"ySU+JY\":[\"twoj@email.com\"],\"yTX1Rt\":[\"Nazwa użytkownika operatora\"],\"yYOzWD\":[\"logi\"],\"yfx9Re\":[\"Hasło operatora IRC\"],\"ygCKqB\":[\"Zatrzymaj\"],\"ymDxJx\":[\"Nazwa użytkownika operatora IRC\"],\"yrpRsQ\":[\"Sortuj według nazwy\"],\"yz7wBu\":[\"Zamknij\"],\"zJw+jA\":[\"ustawia tryb: \",[\"0\"]],\"zebeLu\":[\"Wpisz nazwę użytkownika operatora\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Nieprawidłowy format wzorca. Użyj formatu nick!user@host (dozwolone symbole wieloznaczne *)\"],\"+6NQQA\":[\"Ogólny kanał wsparcia\"],\"+6NyRG\":[\"Klient\"],\"+K0AvT\":[\"Rozłącz\"],\"+cyFdH\":[\"Domyślna wiadomość przy ustawianiu statusu nieobecności\"],\"+mVPqU\":[\"Renderuj formatowanie Markdown w wiadomościach\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Nazwa użytkownika Twojego konta do uwierzytelniania\"],\"+yPBXI\":[\"Wybierz plik\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Niskie bezpieczeństwo połączenia (poziom \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Użytkownicy spoza kanału nie mogą wysyłać do niego wiadomości\"],\"/6BzZF\":[\"Przełącz listę członków\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Użytkownik jest nieobecny\"],\"/XQgft\":[\"Odkryj\"],\"/cF7Rs\":[\"Głośność\"],\"/dqduX\":[\"Następna strona\"],\"/fc3q4\":[\"Wszystkie treści\"],\"/kISDh\":[\"Włącz dźwięki powiadomień\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Odtwarzaj dźwięki dla wzmianek i wiadomości\"],\"0/0ZGA\":[\"Maska nazwy kanału\"],\"0D6j7U\":[\"Dowiedz się więcej o niestandardowych regułach →\"],\"0XsHcR\":[\"Wyrzuć użytkownika\"],\"0ZpE//\":[\"Sortuj według użytkowników\"],\"0bEPwz\":[\"Ustaw nieobecność\"],\"0dGkPt\":[\"Rozwiń listę kanałów\"],\"0gS7M5\":[\"Wyświetlana nazwa\"],\"0kS+M8\":[\"PrzykładSIEĆ\"],\"0rgoY7\":[\"Łącz się tylko z serwerami, które wybierzesz\"],\"0wdd7X\":[\"Dołącz\"],\"0wkVYx\":[\"Wiadomości prywatne\"],\"111uHX\":[\"Podgląd linku\"],\"196EG4\":[\"Usuń prywatną rozmowę\"],\"1DSr1i\":[\"Zarejestruj konto\"],\"1O/24y\":[\"Przełącz listę kanałów\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Ostrzeżenie o zewnętrznym linku\"],\"1ZC/dv\":[\"Brak nieprzeczytanych wzmianek lub wiadomości\"],\"1pO1zi\":[\"Nazwa serwera jest wymagana\"],\"1uwfzQ\":[\"Zobacz temat kanału\"],\"268g7c\":[\"Wpisz wyświetlaną nazwę\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Operatorzy serwerów w sieci mogą potencjalnie odczytywać Twoje wiadomości\"],\"2FYpfJ\":[\"Więcej\"],\"2HF1Y2\":[[\"inviter\"],\" zaprosił \",[\"target\"],\" do dołączenia do \",[\"channel\"]],\"2I70QL\":[\"Zobacz informacje o profilu użytkownika\"],\"2QYdmE\":[\"Użytkownicy:\"],\"2QpEjG\":[\"wyszedł\"],\"2YE223\":[\"Wiadomość na #\",[\"0\"],\" (Enter – nowa linia, Shift+Enter – wyślij)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Użyj hasła serwera\"],\"2iTmdZ\":[\"Pamięć lokalna:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Rygorystyczny – bardziej agresywna ochrona\"],\"2uDhbA\":[\"Wpisz nazwę użytkownika do zaproszenia\"],\"2ygf/L\":[\"← Wróć\"],\"2zEgxj\":[\"Szukaj GIFów...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Zmień nazwę kanału\"],\"3THokf\":[\"Użytkownik z głosem\"],\"3TSz9S\":[\"Minimalizuj\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Wyświetlana nazwa kanału\"],\"3ryuFU\":[\"Opcjonalne raporty o błędach w celu ulepszenia aplikacji\"],\"3uBF/8\":[\"Zamknij przeglądarkę\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Wprowadź nazwę konta...\"],\"4/Rr0R\":[\"Zaproś użytkownika do bieżącego kanału\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Reguły\"],\"4JJtW9\":[\"#przepełnienie\"],\"4NqeT4\":[\"Profil floodu (+F)\"],\"4RZQRK\":[\"Co teraz robisz?\"],\"4hfTrB\":[\"Nick\"],\"4n99LO\":[\"Już w \",[\"0\"]],\"4t6vMV\":[\"Automatycznie przełącz na jedną linię dla krótkich wiadomości\"],\"4vsHmf\":[\"Czas (min)\"],\"5+INAX\":[\"Podświetlaj wiadomości, w których jesteś wzmiankowany\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Nazwa operatora\"],\"678PKt\":[\"Nazwa sieci\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Hasło wymagane do dołączenia do kanału. Pozostaw puste, aby usunąć klucz.\"],\"6HhMs3\":[\"Wiadomość pożegnalna\"],\"6V3Ea3\":[\"Skopiowano\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Pokaż mniej\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Wprowadź hasło opera...\"],\"7+IHTZ\":[\"Nie wybrano pliku\"],\"73hrRi\":[\"nick!user@host (np. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Wyślij wiadomość prywatną\"],\"7U1W7c\":[\"Bardzo łagodny\"],\"7Y1YQj\":[\"Imię i nazwisko:\"],\"7YHArF\":[\"— otwórz w przeglądarce\"],\"7fjnVl\":[\"Szukaj użytkowników...\"],\"7jL88x\":[\"Usunąć tę wiadomość? Tej operacji nie można cofnąć.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Co masz na myśli?\"],\"7sEpu1\":[\"Członkowie — \",[\"0\"]],\"7sNhEz\":[\"Nazwa użytkownika\"],\"8H0Q+x\":[\"Dowiedz się więcej o profilach →\"],\"8Phu0A\":[\"Wyświetlaj gdy użytkownicy zmieniają nick\"],\"8XTG9e\":[\"Wpisz hasło operatora\"],\"8XsV2J\":[\"Spróbuj wysłać ponownie\"],\"8ZsakT\":[\"Hasło\"],\"8kR84m\":[\"Zamierzasz otworzyć zewnętrzny link:\"],\"8lCgih\":[\"Usuń regułę\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"dołączył\"],\"few\":[\"dołączył \",[\"joinCount\"],\" razy\"],\"many\":[\"dołączył \",[\"joinCount\"],\" razy\"],\"other\":[\"dołączył \",[\"joinCount\"],\" razy\"]}]],\"9BMLnJ\":[\"Ponownie połącz z serwerem\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Dodaj reakcję\"],\"9PQ8m2\":[\"G-Line (globalny ban)\"],\"9Qs99X\":[\"E-mail:\"],\"9QupBP\":[\"Usuń wzorzec\"],\"9bG48P\":[\"Wysyłanie\"],\"9f5f0u\":[\"Pytania dotyczące prywatności? Skontaktuj się z nami:\"],\"9unqs3\":[\"Nieobecny:\"],\"9v3hwv\":[\"Nie znaleziono serwerów.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Łączenie\"],\"A1taO8\":[\"Szukaj\"],\"A2adVi\":[\"Wysyłaj powiadomienia o pisaniu\"],\"A9Rhec\":[\"Nazwa kanału\"],\"AWOSPo\":[\"Powiększ\"],\"AXSpEQ\":[\"Operator przy połączeniu\"],\"AeXO77\":[\"Konto\"],\"AhNP40\":[\"Przewijaj\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Zmieniono nick\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Anuluj odpowiedź\"],\"ApSx0O\":[\"Znaleziono \",[\"0\"],\" wiadomości pasujących do \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Nie znaleziono wyników\"],\"AyNqAB\":[\"Wyświetl wszystkie zdarzenia serwera w czacie\"],\"B/QqGw\":[\"Z dala od klawiatury\"],\"B8AaMI\":[\"To pole jest wymagane\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Serwer nie obsługuje zaawansowanego filtrowania LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" i \",[\"3\"],\" innych pisze...\"],\"BGul2A\":[\"Masz niezapisane zmiany. Czy na pewno chcesz zamknąć bez zapisywania?\"],\"BIf9fi\":[\"Twoja wiadomość statusu\"],\"BZz3md\":[\"Twoja strona internetowa\"],\"Bgm/H7\":[\"Zezwól na wpisywanie wielu linii tekstu\"],\"BiQIl1\":[\"Przypnij tę prywatną rozmowę\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Kliknij, aby przejść do wiadomości\"],\"Bowq3c\":[\"Tylko operatorzy mogą zmieniać temat kanału\"],\"Btozzp\":[\"Ten obraz wygasł\"],\"Bycfjm\":[\"Łącznie: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Kopiuj cały JSON\"],\"C9L9wL\":[\"Zbieranie danych\"],\"CDq4wC\":[\"Moderuj użytkownika\"],\"CHVRxG\":[\"Wiadomość do @\",[\"0\"],\" (Shift+Enter – nowa linia)\"],\"CN9zdR\":[\"Nazwa operatora i hasło są wymagane\"],\"CW3sYa\":[\"Dodaj reakcję \",[\"emoji\"]],\"CaAkqd\":[\"Pokaż rozłączenia\"],\"CbvaYj\":[\"Zablokuj po nicku\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Wybierz kanał\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normalny\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"dołączył i wyszedł\"],\"DB8zMK\":[\"Zastosuj\"],\"DBcWHr\":[\"Niestandardowy plik dźwięku powiadomień\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Podglądy mediów\"],\"Dj4pSr\":[\"Wybierz bezpieczne hasło\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Wyszukiwanie...\"],\"Du2T2f\":[\"Nie znaleziono ustawienia\"],\"DwsSVQ\":[\"Zastosuj filtry i odśwież\"],\"E3W/zd\":[\"Domyślny nick\"],\"E6nRW7\":[\"Kopiuj URL\"],\"E703RG\":[\"Tryby:\"],\"EAeu1Z\":[\"Wyślij zaproszenie\"],\"EFKJQT\":[\"Ustawienie\"],\"EGPQBv\":[\"Niestandardowe reguły floodu (+f)\"],\"ELik0r\":[\"Zobacz pełną politykę prywatności\"],\"EPbeC2\":[\"Zobacz lub edytuj temat kanału\"],\"EQCDNT\":[\"Wprowadź nazwę użytkownika opera...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Znaleziono 1 wiadomość pasującą do \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Następny obraz\"],\"EdQY6l\":[\"Brak\"],\"EnqLYU\":[\"Szukaj serwerów...\"],\"F0OKMc\":[\"Edytuj serwer\"],\"F6Int2\":[\"Włącz podświetlenia\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Maks. użytkowników\"],\"FUU/hZ\":[\"Kontroluje ilość zewnętrznych mediów ładowanych na czacie.\"],\"Fdp03t\":[\"wł\"],\"FfPWR0\":[\"Okno\"],\"FjkaiT\":[\"Pomniejsz\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Co to oznacza:\"],\"FolHNl\":[\"Zarządzaj swoim kontem i uwierzytelnianiem\"],\"Fp2Dif\":[\"Opuścił serwer\"],\"G5KmCc\":[\"GZ-Line (globalna Z-Line)\"],\"GDs0lz\":[\"<0>Ryzyko:</0> Poufne informacje (wiadomości, prywatne rozmowy, dane uwierzytelniające) mogą być widoczne dla administratorów sieci lub atakujących znajdujących się między serwerami IRC.\"],\"GR+2I3\":[\"Dodaj maskę zaproszenia (np. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Zamknij wyskakujące powiadomienia serwera\"],\"GlHnXw\":[\"Zmiana nicku nie powiodła się: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Podgląd:\"],\"GtmO8/\":[\"od\"],\"GtuHUQ\":[\"Zmień nazwę tego kanału na serwerze. Wszyscy użytkownicy zobaczą nową nazwę.\"],\"GuGfFX\":[\"Przełącz wyszukiwanie\"],\"GxkJXS\":[\"Przesyłanie...\"],\"GzbwnK\":[\"Dołączył do kanału\"],\"GzsUDB\":[\"Rozszerzony profil\"],\"H/PnT8\":[\"Wstaw emoji\"],\"H6Izzl\":[\"Twój preferowany kod koloru\"],\"H9jIv+\":[\"Pokaż dołączenia/odejścia\"],\"HAKBY9\":[\"Prześlij pliki\"],\"HdE1If\":[\"Kanał\"],\"Hk4AW9\":[\"Twoja preferowana wyświetlana nazwa\"],\"HmHDk7\":[\"Wybierz członka\"],\"HrQzPU\":[\"Kanały na \",[\"networkName\"]],\"I2tXQ5\":[\"Wiadomość do @\",[\"0\"],\" (Enter – nowa linia, Shift+Enter – wyślij)\"],\"I6bw/h\":[\"Zablokuj użytkownika\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Włącz powiadomienia\"],\"I9D72S\":[\"Czy na pewno chcesz usunąć tę wiadomość? Tej operacji nie można cofnąć.\"],\"IA+1wo\":[\"Wyświetlaj gdy użytkownicy są wyrzucani z kanałów\"],\"IDwkJx\":[\"Operator IRC\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Zapisz zmiany\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" i \",[\"2\"],\" piszą...\"],\"IgrLD/\":[\"Pauza\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Odpowiedz\"],\"IoHMnl\":[\"Maksymalna wartość wynosi \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Łączenie...\"],\"J5T9NW\":[\"Informacje o użytkowniku\"],\"J8Y5+z\":[\"Ups! Podział sieci! ⚠️\"],\"JBHkBA\":[\"Opuścił kanał\"],\"JCwL0Q\":[\"Wpisz powód (opcjonalnie)\"],\"JFciKP\":[\"Przełącz\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Zmień nazwę kanału (tylko dla operatorów)\"],\"JcD7qf\":[\"Więcej akcji\"],\"JdkA+c\":[\"Tajny (+s)\"],\"Jmu12l\":[\"Kanały serwera\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Włącz Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Brak dostępnych danych WHOIS\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Usuń wiadomość\"],\"KKBlUU\":[\"Osadź\"],\"KM0pLb\":[\"Witamy na kanale!\"],\"KR6W2h\":[\"Przestań ignorować użytkownika\"],\"KV+Bi1\":[\"Tylko na zaproszenie (+i)\"],\"KdCtwE\":[\"Ile sekund monitorować aktywność floodowania przed zresetowaniem liczników\"],\"Kkezga\":[\"Hasło serwera\"],\"KsiQ/8\":[\"Użytkownicy muszą być zaproszeni, aby dołączyć do kanału\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Informacje o kanale\"],\"LC1a7n\":[\"Serwer IRC zgłosił, że jego połączenia między serwerami mają niski poziom bezpieczeństwa. Oznacza to, że gdy Twoje wiadomości są przekazywane między serwerami IRC w sieci, mogą nie być właściwie szyfrowane lub certyfikaty SSL/TLS mogą nie być poprawnie weryfikowane.\"],\"LNfLR5\":[\"Pokaż wyrzucenia\"],\"LQb0W/\":[\"Pokaż wszystkie zdarzenia\"],\"LU7/yA\":[\"Alternatywna nazwa wyświetlana w interfejsie. Może zawierać spacje, emoji i znaki specjalne. Prawdziwa nazwa kanału (\",[\"channelName\"],\") nadal będzie używana w poleceniach IRC.\"],\"LUb9O7\":[\"Wymagany jest prawidłowy port serwera\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Polityka prywatności\"],\"LcuSDR\":[\"Zarządzaj informacjami w profilu i metadanymi\"],\"LqLS9B\":[\"Pokaż zmiany nicku\"],\"LsDQt2\":[\"Ustawienia kanału\"],\"LtI9AS\":[\"Właściciel\"],\"LuNhhL\":[\"zareagował na tę wiadomość\"],\"M/AZNG\":[\"URL do obrazu Twojego awatara\"],\"M/WIer\":[\"Wyślij wiadomość\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Nazwa:\"],\"MHk+7g\":[\"Poprzedni obraz\"],\"MRorGe\":[\"Wyślij wiadomość prywatną\"],\"MVbSGP\":[\"Okno czasowe (sekundy)\"],\"MkpcsT\":[\"Twoje wiadomości i ustawienia są przechowywane lokalnie na Twoim urządzeniu\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Oznacz jako bota – zazwyczaj 'on' lub puste\"],\"N7TQbE\":[\"Zaproś użytkownika do \",[\"channelName\"]],\"NCca/o\":[\"Wprowadź domyślny pseudonim...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Wyświetla wszystkie zewnętrzne media. Każdy URL może spowodować żądanie do nieznanego serwera.\"],\"Nt+9O7\":[\"Użyj WebSocket zamiast surowego TCP\"],\"NxIHzc\":[\"Rozłącz użytkownika\"],\"O+v/cL\":[\"Przeglądaj wszystkie kanały na serwerze\"],\"ODwSCk\":[\"Wyślij GIF\"],\"OGQ5kK\":[\"Konfiguruj dźwięki powiadomień i podświetlenia\"],\"OIPt1Z\":[\"Pokaż lub ukryj panel listy członków\"],\"OKSNq/\":[\"Bardzo rygorystyczny\"],\"ONWvwQ\":[\"Prześlij\"],\"OVKoQO\":[\"Hasło Twojego konta do uwierzytelniania\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Przenosimy IRC w przyszłość\"],\"OhCpra\":[\"Ustaw temat…\"],\"OkltoQ\":[\"Zablokuj \",[\"username\"],\" po nicku (uniemożliwia ponowne dołączenie z tym samym nickiem)\"],\"P+t/Te\":[\"Brak dodatkowych danych\"],\"P42Wcc\":[\"Bezpieczne\"],\"PD38l0\":[\"Podgląd awatara kanału\"],\"PD9mEt\":[\"Wpisz wiadomość...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Otwórz ustawienia konfiguracji kanału\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Temat, który będzie wyświetlany dla tego kanału. Wszyscy użytkownicy mogą zobaczyć temat.\"],\"PZCecv\":[\"Podgląd PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 raz\"],\"few\":[[\"c\"],\" razy\"],\"many\":[[\"c\"],\" razy\"],\"other\":[[\"c\"],\" razy\"]}]],\"PguS2C\":[\"Dodaj maskę wyjątku (np. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Wyświetlanie \",[\"displayedChannelsCount\"],\" z \",[\"0\"],\" kanałów\"],\"PqhVlJ\":[\"Zablokuj użytkownika (po hostmasce)\"],\"Q+chwU\":[\"Nazwa użytkownika:\"],\"Q6hhn8\":[\"Preferencje\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Proszę podać nazwę użytkownika\"],\"QGqSZ2\":[\"Kolor i formatowanie\"],\"QJQd1J\":[\"Edytuj profil\"],\"QSzGDE\":[\"Bezczynny\"],\"QUlny5\":[\"Witamy na \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Czytaj więcej\"],\"QuSkCF\":[\"Filtruj kanały...\"],\"QwUrDZ\":[\"zmienił temat na: \",[\"topic\"]],\"R0UH07\":[\"Obraz \",[\"0\"],\" z \",[\"1\"]],\"R7SsBE\":[\"Wycisz\"],\"R8rf1X\":[\"Kliknij, aby ustawić temat\"],\"RArB3D\":[\"został wyrzucony z \",[\"channelName\"],\" przez \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Odkryj świat IRC z ObsidianIRC\"],\"RMMaN5\":[\"Moderowany (+m)\"],\"RWw9Lg\":[\"Zamknij okno\"],\"RZ2BuZ\":[\"Rejestracja konta \",[\"account\"],\" wymaga weryfikacji: \",[\"message\"]],\"RySp6q\":[\"Ukryj komentarze\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Nick jest wymagany\"],\"SPVjfj\":[\"Domyślnie 'brak powodu', jeśli pozostawione puste\"],\"SQKPvQ\":[\"Zaproś użytkownika\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Wybierz wstępnie zdefiniowany profil ochrony przed floodem. Profile te oferują zrównoważone ustawienia ochrony dla różnych przypadków użycia.\"],\"Slr+3C\":[\"Min. użytkowników\"],\"Spnlre\":[\"Zaprosiłeś \",[\"target\"],\" do dołączenia do \",[\"channel\"]],\"T/ckN5\":[\"Otwórz w przeglądarce mediów\"],\"T91vKp\":[\"Odtwórz\"],\"TV2Wdu\":[\"Dowiedz się, jak przetwarzamy Twoje dane i chronimy Twoją prywatność.\"],\"TgFpwD\":[\"Stosowanie...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Brak zmian\"],\"TtserG\":[\"Wpisz prawdziwe imię i nazwisko\"],\"Ttz9J1\":[\"Wprowadź hasło...\"],\"Tz0i8g\":[\"Ustawienia\"],\"U3pytU\":[\"Administrator\"],\"UDb2YD\":[\"Zareaguj\"],\"UE4KO5\":[\"*kanał*\"],\"UGT5vp\":[\"Zapisz ustawienia\"],\"UV5hLB\":[\"Nie znaleziono żadnych banów\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Wiadomości statusu\"],\"Ue3uny\":[\"Domyślne (brak profilu)\"],\"UkARhe\":[\"Normalny – standardowa ochrona\"],\"Umn7Cj\":[\"Brak komentarzy. Bądź pierwszy!\"],\"UtUIRh\":[[\"0\"],\" starszych wiadomości\"],\"UwzP+U\":[\"Bezpieczne połączenie\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Właściciel kanału\"],\"V4qgxE\":[\"Utworzone przed (min temu)\"],\"V8yTm6\":[\"Wyczyść wyszukiwanie\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC – IRC w nowoczesnym wydaniu\"],\"VJScHU\":[\"Powód\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Wycisz powiadomienia\"],\"VbyRUy\":[\"Komentarze\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Ustawione przez:\"],\"VqnIZz\":[\"Zobacz naszą politykę prywatności i zasady przetwarzania danych\"],\"VrMygG\":[\"Minimalna długość wynosi \",[\"0\"]],\"VrnTui\":[\"Twoje zaimki, widoczne w profilu\"],\"W8E3qn\":[\"Uwierzytelnione konto\"],\"WAakm9\":[\"Usuń kanał\"],\"WFxTHC\":[\"Dodaj maskę bana (np. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Host serwera jest wymagany\"],\"WRYdXW\":[\"Pozycja audio\"],\"WUOH5B\":[\"Ignoruj użytkownika\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Pokaż 1 więcej element\"],\"few\":[\"Pokaż \",[\"1\"],\" więcej elementów\"],\"many\":[\"Pokaż \",[\"1\"],\" więcej elementów\"],\"other\":[\"Pokaż \",[\"1\"],\" więcej elementów\"]}]],\"Weq9zb\":[\"Ogólne\"],\"Wfj7Sk\":[\"Wycisz lub odcisz dźwięki powiadomień\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profil użytkownika\"],\"X6S3lt\":[\"Szukaj ustawień, kanałów, serwerów...\"],\"XEHan5\":[\"Kontynuuj mimo to\"],\"XI1+wb\":[\"Nieprawidłowy format\"],\"XIXeuC\":[\"Wiadomość do @\",[\"0\"]],\"XMS+k4\":[\"Rozpocznij prywatną rozmowę\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Odepnij prywatną rozmowę\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Wyświetlanie\"],\"Xp2n93\":[\"Wyświetla media z zaufanego hosta plików Twojego serwera. Żadne żądania nie są wysyłane do zewnętrznych serwisów.\"],\"XvjC4F\":[\"Zapisywanie...\"],\"Y/qryO\":[\"Nie znaleziono użytkowników pasujących do wyszukiwania\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Rejestracja konta \",[\"account\"],\" powiodła się: \",[\"message\"]],\"YEfzvP\":[\"Chroniony temat (+t)\"],\"YQOn6a\":[\"Zwiń listę członków\"],\"YRCoE9\":[\"Operator kanału\"],\"YURQaF\":[\"Zobacz profil\"],\"YdBSvr\":[\"Kontroluj wyświetlanie mediów i zewnętrznych treści\"],\"Yj6U3V\":[\"Brak centralnego serwera:\"],\"YjvpGx\":[\"Zaimki\"],\"YqH4l4\":[\"Brak klucza\"],\"YyUPpV\":[\"Konto:\"],\"ZJSWfw\":[\"Wiadomość wyświetlana przy rozłączeniu z serwera\"],\"ZR1dJ4\":[\"Zaproszenia\"],\"ZdWg0V\":[\"Otwórz w przeglądarce\"],\"ZhRBbl\":[\"Szukaj wiadomości…\"],\"Zmcu3y\":[\"Zaawansowane filtry\"],\"a2/8e5\":[\"Temat ustawiony po (min temu)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Poprzednia strona\"],\"aJTbXX\":[\"Hasło operatora\"],\"aQryQv\":[\"Wzorzec już istnieje\"],\"aW9pLN\":[\"Maksymalna liczba użytkowników dozwolona na kanale. Pozostaw puste, aby nie było limitu.\"],\"ah4fmZ\":[\"Wyświetla również podglądy z YouTube, Vimeo, SoundCloud i podobnych znanych serwisów.\"],\"aifXak\":[\"Brak mediów na tym kanale\"],\"ap2zBz\":[\"Łagodny\"],\"az8lvo\":[\"Wyłączone\"],\"azXSNo\":[\"Rozwiń listę członków\"],\"azdliB\":[\"Zaloguj się na konto\"],\"b26wlF\":[\"ona/jej\"],\"bD/+Ei\":[\"Rygorystyczny\"],\"bQ6BJn\":[\"Konfiguruj szczegółowe reguły ochrony przed floodem. Każda reguła określa, jaki rodzaj aktywności monitorować i jakie działanie podjąć po przekroczeniu progów.\"],\"beV7+y\":[\"Użytkownik otrzyma zaproszenie do dołączenia do \",[\"channelName\"],\".\"],\"bk84cH\":[\"Wiadomość o nieobecności\"],\"bkHdLj\":[\"Dodaj serwer IRC\"],\"bmQLn5\":[\"Dodaj regułę\"],\"bwRvnp\":[\"Akcja\"],\"c8+EVZ\":[\"Zweryfikowane konto\"],\"cGYUlD\":[\"Nie wczytano żadnych podglądów mediów.\"],\"cLF98o\":[\"Pokaż komentarze (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Brak dostępnych użytkowników\"],\"cSgpoS\":[\"Przypnij prywatną rozmowę\"],\"cde3ce\":[\"Wiadomość do <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopiuj sformatowane wyjście\"],\"cl/A5J\":[\"Witamy na \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Usuń\"],\"coPLXT\":[\"Nie przechowujemy Twoich komunikatów IRC na naszych serwerach\"],\"crYH/6\":[\"Odtwarzacz SoundCloud\"],\"d3sis4\":[\"Dodaj serwer\"],\"d9aN5k\":[\"Usuń \",[\"username\"],\" z kanału\"],\"dEgA5A\":[\"Anuluj\"],\"dGi1We\":[\"Odepnij tę prywatną rozmowę\"],\"dJVuyC\":[\"opuścił \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"do\"],\"dXqxlh\":[\"<0>⚠️ Zagrożenie bezpieczeństwa!</0> To połączenie może być podatne na przechwycenie lub ataki typu man-in-the-middle.\"],\"da9Q/R\":[\"Zmieniono tryby kanału\"],\"dhJN3N\":[\"Pokaż komentarze\"],\"dj2xTE\":[\"Odrzuć powiadomienie\"],\"dpCzmC\":[\"Ustawienia ochrony przed floodem\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Czy chcesz otworzyć ten link w nowej karcie?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Edytuj\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Prześlij obraz lub podaj URL z opcjonalnym podstawieniem \",[\"size\"],\" dla dynamicznego rozmiaru\"],\"edBbee\":[\"Zablokuj \",[\"username\"],\" po hostmasce (uniemożliwia ponowne dołączenie z tego samego IP/hosta)\"],\"ekfzWq\":[\"Ustawienia użytkownika\"],\"elPDWs\":[\"Dostosuj swoje doświadczenie z klientem IRC\"],\"eu2osY\":[\"<0>💡 Zalecenie:</0> Kontynuuj tylko jeśli ufasz temu serwerowi i rozumiesz ryzyko. Unikaj udostępniania poufnych informacji lub haseł przez to połączenie.\"],\"euEhbr\":[\"Kliknij, aby dołączyć do \",[\"channel\"]],\"ez3vLd\":[\"Włącz wieloliniowe wprowadzanie\"],\"f0J5Ki\":[\"Komunikacja między serwerami może używać nieszyfrowanych połączeń\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Ostrzeż użytkownika\"],\"fDOLLd\":[\"Nie znaleziono kanałów.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonimowa analityka:\"],\"fq1GF9\":[\"Wyświetlaj gdy użytkownicy rozłączają się z serwera\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Ten serwer obsługuje tylko jeden typ połączenia\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Lista ignorowanych\"],\"gNzMrk\":[\"Bieżący awatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Wprowadź pseudonim...\"],\"gz6UQ3\":[\"Maksymalizuj\"],\"h6razj\":[\"Wyklucz maskę nazwy kanału\"],\"hG6jnw\":[\"Nie ustawiono tematu\"],\"hG89Ed\":[\"Obraz\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"np. 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Ilość\"],\"hzdLuQ\":[\"Tylko użytkownicy z głosem lub wyżej mogą mówić\"],\"i0qMbr\":[\"Strona główna\"],\"iDNBZe\":[\"Powiadomienia\"],\"iH8pgl\":[\"Wróć\"],\"iL9SZg\":[\"Zablokuj użytkownika (po nicku)\"],\"iNt+3c\":[\"Wróć do obrazu\"],\"iQvi+a\":[\"Nie ostrzegaj mnie o niskim poziomie bezpieczeństwa połączeń dla tego serwera\"],\"iSLIjg\":[\"Połącz\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Host serwera\"],\"idD8Ev\":[\"Zapisano\"],\"iivqkW\":[\"Zalogowany od\"],\"ij+Elv\":[\"Podgląd obrazu\"],\"ilIWp7\":[\"Przełącz powiadomienia\"],\"iuaqvB\":[\"Użyj * jako symbolu wieloznacznego. Przykłady: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Zablokuj po hostmasce\"],\"jA4uoI\":[\"Temat:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Powód (opcjonalnie)\"],\"jUV7CU\":[\"Prześlij awatar\"],\"jW5Uwh\":[\"Kontroluj ilość wczytywanego zewnętrznego medium. Wyłączone / Bezpieczne / Zaufane źródła / Wszystkie treści.\"],\"jXzms5\":[\"Opcje załącznika\"],\"jZlrte\":[\"Kolor\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#nowa-nazwa-kanału\"],\"k112DD\":[\"Wczytaj starsze wiadomości\"],\"k3ID0F\":[\"Filtruj członków…\"],\"k65gsE\":[\"Szczegóły\"],\"k7Zgob\":[\"Anuluj połączenie\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Nie znaleziono zaproszeń\"],\"kCLEPU\":[\"Połączony z\"],\"kF5LKb\":[\"Ignorowane wzorce:\"],\"kGeOx/\":[\"Dołącz do \",[\"0\"]],\"kITKr8\":[\"Wczytywanie trybów kanału...\"],\"kPpPsw\":[\"Jesteś operatorem IRC\"],\"kWJmRL\":[\"Ty\"],\"kfcRb0\":[\"Awatar\"],\"kjMqSj\":[\"Kopiuj JSON\"],\"krViRy\":[\"Kliknij, aby skopiować jako JSON\"],\"ks71ra\":[\"Wyjątki\"],\"kw4lRv\":[\"Pół-operator kanału\"],\"kxgIRq\":[\"Wybierz lub dodaj kanał, aby zacząć.\"],\"ky6dWe\":[\"Podgląd awatara\"],\"l+GxCv\":[\"Wczytywanie kanałów...\"],\"l+IUVW\":[\"Weryfikacja konta \",[\"account\"],\" powiodła się: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"ponownie połączył\"],\"few\":[\"ponownie połączył \",[\"reconnectCount\"],\" razy\"],\"many\":[\"ponownie połączył \",[\"reconnectCount\"],\" razy\"],\"other\":[\"ponownie połączył \",[\"reconnectCount\"],\" razy\"]}]],\"l5jmzx\":[[\"0\"],\" i \",[\"1\"],\" piszą...\"],\"lHy8N5\":[\"Wczytywanie kolejnych kanałów...\"],\"lbpf14\":[\"Dołącz do \",[\"value\"]],\"lfFsZ4\":[\"Kanały\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Nazwa konta\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Prześlij obraz\"],\"loQxaJ\":[\"Wróciłem\"],\"lvfaxv\":[\"STRONA GŁÓWNA\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Dodaj\"],\"m8flAk\":[\"Podgląd (jeszcze nie przesłany)\"],\"mEPxTp\":[\"<0>⚠️ Uwaga!</0> Otwieraj tylko linki z zaufanych źródeł. Złośliwe linki mogą narazić Twoje bezpieczeństwo lub prywatność.\"],\"mHGdhG\":[\"Informacje o serwerze\"],\"mHS8lb\":[\"Wiadomość na #\",[\"0\"]],\"mMYBD9\":[\"Szeroki – szerszy zakres ochrony\"],\"mTGsPd\":[\"Temat kanału\"],\"mU8j6O\":[\"Brak zewnętrznych wiadomości (+n)\"],\"mZp8FL\":[\"Automatyczny powrót do jednej linii\"],\"mdQu8G\":[\"TwójNick\"],\"miSSBQ\":[\"Komentarze (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Użytkownik jest uwierzytelniony\"],\"mwtcGl\":[\"Zamknij komentarze\"],\"mzI/c+\":[\"Pobierz\"],\"n3fGRk\":[\"ustawione przez \",[\"0\"]],\"nE9jsU\":[\"Łagodny – mniej agresywna ochrona\"],\"nNflMD\":[\"Opuść kanał\"],\"nPXkBi\":[\"Wczytywanie danych WHOIS...\"],\"nQnxxF\":[\"Wiadomość na #\",[\"0\"],\" (Shift+Enter – nowa linia)\"],\"nWMRxa\":[\"Odepnij\"],\"nkC032\":[\"Brak profilu floodu\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Wyślij wiadomość ostrzegawczą do \",[\"username\"]],\"o9ylQi\":[\"Wyszukaj GIFy, aby rozpocząć\"],\"oFGkER\":[\"Powiadomienia serwera\"],\"oOi11l\":[\"Przewiń na dół\"],\"oQEzQR\":[\"Nowy DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Ataki man-in-the-middle na połączenia serwera są możliwe\"],\"oeqmmJ\":[\"Zaufane źródła\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Domyślne\"],\"p0Z69r\":[\"Wzorzec nie może być pusty\"],\"p1KgtK\":[\"Nie udało się załadować audio\"],\"p59pEv\":[\"Dodatkowe szczegóły\"],\"p7sRI6\":[\"Informuj innych, gdy piszesz\"],\"pBm1od\":[\"Tajny kanał\"],\"pNmiXx\":[\"Twój domyślny nick dla wszystkich serwerów\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Hasło konta\"],\"peNE68\":[\"Stały\"],\"plhHQt\":[\"Brak danych\"],\"pm6+q5\":[\"Ostrzeżenie o bezpieczeństwie\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Dodatkowe informacje\"],\"q0cR4S\":[\"jest teraz znany jako **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Kanał nie będzie widoczny w poleceniach LIST ani NAMES\"],\"qLpTm/\":[\"Usuń reakcję \",[\"emoji\"]],\"qVkGWK\":[\"Przypnij\"],\"qY8wNa\":[\"Strona internetowa\"],\"qb0xJ7\":[\"Użyj symboli wieloznacznych: * pasuje do dowolnej sekwencji, ? pasuje do dowolnego pojedynczego znaku. Przykłady: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Klucz kanału (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Brak limitu\"],\"r1W2AS\":[\"Obraz z serwera plików\"],\"rIPR2O\":[\"Temat ustawiony przed (min temu)\"],\"rMMSYo\":[\"Maksymalna długość wynosi \",[\"0\"]],\"rWtzQe\":[\"Sieć rozdzieliła się i ponownie połączyła. ✅\"],\"rYG2u6\":[\"Proszę czekać...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Podgląd\"],\"rjGI/Q\":[\"Prywatność\"],\"rk8iDX\":[\"Wczytywanie GIFów...\"],\"rn6SBY\":[\"Odcisz\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Został wyrzucony z kanału\"],\"s8cATI\":[\"dołączył do \",[\"channelName\"]],\"sCO9ue\":[\"Połączenie z <0>\",[\"serverName\"],\"</0> ma następujące problemy z bezpieczeństwem:\"],\"sGH11W\":[\"Serwer\"],\"sHI1H+\":[\"jest teraz znany jako **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" zaprosił cię do dołączenia do \",[\"channel\"]],\"sby+1/\":[\"Kliknij, aby skopiować\"],\"sfN25C\":[\"Twoje prawdziwe imię i nazwisko\"],\"sliuzR\":[\"Otwórz link\"],\"sqrO9R\":[\"Niestandardowe wzmianki\"],\"sr6RdJ\":[\"Wieloliniowy przy Shift+Enter\"],\"swrCpB\":[\"Kanał został przemianowany z \",[\"oldName\"],\" na \",[\"newName\"],\" przez \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Zaawansowane\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Usuń\"],\"t47eHD\":[\"Twój unikalny identyfikator na tym serwerze\"],\"tAkAh0\":[\"URL z opcjonalnym podstawieniem \",[\"size\"],\" dla dynamicznego rozmiaru. Przykład: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Pokaż lub ukryj panel listy kanałów\"],\"tfDRzk\":[\"Zapisz\"],\"tiBsJk\":[\"opuścił \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"wyszedł (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Nick {nick} jest już używany, ponawiam z {newNick}\"],\"u0a8B4\":[\"Uwierzytelnij się jako operator IRC, aby uzyskać dostęp administracyjny\"],\"u0rWFU\":[\"Utworzone po (min temu)\"],\"u72w3t\":[\"Użytkownicy i wzorce do ignorowania\"],\"u7jc2L\":[\"wyszedł\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Zapis nieudany: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"Serwery IRC:\"],\"usSSr/\":[\"Poziom powiększenia\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Oprogramowanie:\"],\"vE8kb+\":[\"Użyj Shift+Enter dla nowych linii (Enter wysyła)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Brak tematu\"],\"vSJd18\":[\"Wideo\"],\"vXIe7J\":[\"Język\"],\"vaHYxN\":[\"Prawdziwe imię i nazwisko\"],\"vhjbKr\":[\"Nieobecny\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[\"klient \",[\"title\"]],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Nieprawidłowa wartość\"],\"wFjjxZ\":[\"został wyrzucony z \",[\"channelName\"],\" przez \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nie znaleziono wyjątków od bana\"],\"wPrGnM\":[\"Administrator kanału\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Wyświetlaj gdy użytkownicy dołączają lub opuszczają kanały\"],\"whqZ9r\":[\"Dodatkowe słowa lub frazy do podświetlenia\"],\"wm7RV4\":[\"Dźwięk powiadomienia\"],\"wz/Yoq\":[\"Twoje wiadomości mogą zostać przechwycone podczas przekazywania między serwerami\"],\"xCJdfg\":[\"Wyczyść\"],\"xUHRTR\":[\"Automatycznie uwierzytelniaj jako operator przy połączeniu\"],\"xWHwwQ\":[\"Blokady\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Obsługiwane są tylko bezpieczne WebSocket\"],\"xdtXa+\":[\"nazwa-kanału\"],\"xfXC7q\":[\"Kanały tekstowe\"],\"xlCYOE\":[\"Pobieranie kolejnych wiadomości...\"],\"xlhswE\":[\"Minimalna wartość wynosi \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Dodaj słowo lub frazę...\"],\"xuRqRq\":[\"Limit klientów (+l)\"],\"xwF+7J\":[[\"0\"],\" pisze...\"],\"yNeucF\":[\"Ten serwer nie obsługuje rozszerzonych metadanych profilu (rozszerzenie IRCv3 METADATA). Dodatkowe pola, takie jak awatar, wyświetlana nazwa i status, nie są dostępne.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Awatar kanału\"],\"yQE2r9\":[\"Ładowanie\"],\"ySU+JY\":[\"twoj@email.com\"],\"yTX1Rt\":[\"Nazwa użytkownika operatora\"],\"yYOzWD\":[\"logi\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"Hasło operatora IRC\"],\"ygCKqB\":[\"Zatrzymaj\"],\"ymDxJx\":[\"Nazwa użytkownika operatora IRC\"],\"yrpRsQ\":[\"Sortuj według nazwy\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Zamknij\"],\"zJw+jA\":[\"ustawia tryb: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Wpisz nazwę użytkownika operatora\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Format model invalid. Folosiți formatul nick!user@host (caractere wildcard * permise)\"],\"+6NQQA\":[\"Canal general de suport\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Deconectează\"],\"+cyFdH\":[\"Mesaj implicit când vă marcați ca absent\"],\"+mVPqU\":[\"Afișați formatarea Markdown în mesaje\"],\"+vqCJH\":[\"Numele de utilizator al contului dvs. pentru autentificare\"],\"+yPBXI\":[\"Alege fișier\"],\"+zy2Nq\":[\"Tip\"],\"/09cao\":[\"Securitate scăzută a legăturii (Nivel \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Utilizatorii din afara canalului nu pot trimite mesaje\"],\"/6BzZF\":[\"Comută lista de membri\"],\"/TNOPk\":[\"Utilizatorul este absent\"],\"/XQgft\":[\"Descoperă\"],\"/cF7Rs\":[\"Volum\"],\"/dqduX\":[\"Pagina următoare\"],\"/fc3q4\":[\"Tot conținutul\"],\"/kISDh\":[\"Activați sunetele de notificare\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Redați sunete pentru mențiuni și mesaje\"],\"0/0ZGA\":[\"Mască nume canal\"],\"0D6j7U\":[\"Aflați mai multe despre regulile personalizate →\"],\"0XsHcR\":[\"Dă afară utilizatorul\"],\"0ZpE//\":[\"Sortare după utilizatori\"],\"0bEPwz\":[\"Setează ca absent\"],\"0dGkPt\":[\"Extinde lista de canale\"],\"0gS7M5\":[\"Nume afișat\"],\"0kS+M8\":[\"ExempluRET\"],\"0rgoY7\":[\"Conectați-vă doar la serverele pe care le alegeți\"],\"0wdd7X\":[\"Alătură-te\"],\"0wkVYx\":[\"Mesaje private\"],\"111uHX\":[\"Previzualizare link\"],\"196EG4\":[\"Șterge conversația privată\"],\"1DSr1i\":[\"Înregistrează un cont\"],\"1O/24y\":[\"Comută lista de canale\"],\"1VPJJ2\":[\"Avertisment link extern\"],\"1ZC/dv\":[\"Nicio mențiune sau mesaj necitit\"],\"1pO1zi\":[\"Numele serverului este obligatoriu\"],\"1uwfzQ\":[\"Vezi subiectul canalului\"],\"268g7c\":[\"Introdu numele afișat\"],\"2FOFq1\":[\"Operatorii de server din rețea pot citi mesajele tale\"],\"2FYpfJ\":[\"Mai mult\"],\"2HF1Y2\":[[\"inviter\"],\" l-a invitat pe \",[\"target\"],\" să se alăture la \",[\"channel\"]],\"2I70QL\":[\"Vezi informațiile profilului utilizatorului\"],\"2QYdmE\":[\"Utilizatori:\"],\"2QpEjG\":[\"a ieșit\"],\"2YE223\":[\"Mesaj #\",[\"0\"],\" (Enter pentru linie nouă, Shift+Enter pentru trimitere)\"],\"2bimFY\":[\"Folosește parola serverului\"],\"2iTmdZ\":[\"Stocare locală:\"],\"2odkwe\":[\"Strict – Protecție mai agresivă\"],\"2uDhbA\":[\"Introdu numele de utilizator de invitat\"],\"2ygf/L\":[\"← Înapoi\"],\"2zEgxj\":[\"Caută GIF-uri...\"],\"3RdPhl\":[\"Redenumește canalul\"],\"3THokf\":[\"Utilizator cu drept de vorbire\"],\"3TSz9S\":[\"Minimizează\"],\"3jBDvM\":[\"Nume afișat canal\"],\"3ryuFU\":[\"Rapoarte opționale de erori pentru îmbunătățirea aplicației\"],\"3uBF/8\":[\"Închide vizualizatorul\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Introduceți numele contului...\"],\"4/Rr0R\":[\"Invită un utilizator în canalul curent\"],\"4EZrJN\":[\"Reguli\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Profil flood (+F)\"],\"4RZQRK\":[\"Ce faci?\"],\"4hfTrB\":[\"Poreclă\"],\"4n99LO\":[\"Deja în \",[\"0\"]],\"4t6vMV\":[\"Comutare automată la linie unică pentru mesaje scurte\"],\"4vsHmf\":[\"Timp (min)\"],\"5+INAX\":[\"Evidențiați mesajele care vă menționează\"],\"5R5Pv/\":[\"Nume oper\"],\"678PKt\":[\"Nume rețea\"],\"6Aih4U\":[\"Deconectat\"],\"6CO3WE\":[\"Parolă necesară pentru a intra în canal. Lăsați gol pentru a elimina cheia.\"],\"6HhMs3\":[\"Mesaj de ieșire\"],\"6V3Ea3\":[\"Copiat\"],\"6lGV3K\":[\"Arată mai puțin\"],\"6yFOEi\":[\"Introduceți parola oper...\"],\"7+IHTZ\":[\"Niciun fișier ales\"],\"73hrRi\":[\"nick!user@host (ex., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Trimite mesaj privat\"],\"7U1W7c\":[\"Foarte relaxat\"],\"7Y1YQj\":[\"Nume real:\"],\"7YHArF\":[\"— deschide în vizualizator\"],\"7fjnVl\":[\"Caută utilizatori...\"],\"7jL88x\":[\"Ștergeți acest mesaj? Această acțiune nu poate fi anulată.\"],\"7nGhhM\":[\"La ce te gândești?\"],\"7sEpu1\":[\"Membri — \",[\"0\"]],\"7sNhEz\":[\"Nume de utilizator\"],\"8H0Q+x\":[\"Aflați mai multe despre profiluri →\"],\"8Phu0A\":[\"Afișează când utilizatorii își schimbă pseudonimul\"],\"8XTG9e\":[\"Introdu parola oper\"],\"8XsV2J\":[\"Reîncearcă trimiterea\"],\"8ZsakT\":[\"Parolă\"],\"8kR84m\":[\"Ești pe cale să deschizi un link extern:\"],\"8lCgih\":[\"Eliminați regula\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"s-a alăturat\"],\"few\":[\"s-a alăturat de \",[\"joinCount\"],\" ori\"],\"other\":[\"s-a alăturat de \",[\"joinCount\"],\" ori\"]}]],\"9BMLnJ\":[\"Reconectează-te la server\"],\"9OEgyT\":[\"Adaugă reacție\"],\"9PQ8m2\":[\"G-Line (ban global)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Elimină șablon\"],\"9bG48P\":[\"Se trimite\"],\"9f5f0u\":[\"Întrebări despre confidențialitate? Contactați-ne:\"],\"9unqs3\":[\"Absent:\"],\"9v3hwv\":[\"Niciun server găsit.\"],\"9zb2WA\":[\"Se conectează\"],\"A1taO8\":[\"Caută\"],\"A2adVi\":[\"Trimiteți notificări de tastare\"],\"A9Rhec\":[\"Nume canal\"],\"AWOSPo\":[\"Mărește\"],\"AXSpEQ\":[\"Oper la conectare\"],\"AeXO77\":[\"Cont\"],\"AhNP40\":[\"Derulare\"],\"Ai2U7L\":[\"Gazdă\"],\"AjBQnf\":[\"Poreclă schimbată\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Anulează răspunsul\"],\"ApSx0O\":[\"S-au găsit \",[\"0\"],\" mesaje care corespund cu \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Niciun rezultat găsit\"],\"AyNqAB\":[\"Afișează toate evenimentele serverului în chat\"],\"B/QqGw\":[\"Departe de tastatură\"],\"B8AaMI\":[\"Acest câmp este obligatoriu\"],\"BA2c49\":[\"Serverul nu acceptă filtrarea avansată LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" și alți \",[\"3\"],\" scriu...\"],\"BGul2A\":[\"Ai modificări nesalvate. Ești sigur că vrei să închizi fără să salvezi?\"],\"BIf9fi\":[\"Mesajul dvs. de stare\"],\"BZz3md\":[\"Site-ul dvs. web personal\"],\"Bgm/H7\":[\"Permite introducerea mai multor rânduri de text\"],\"BiQIl1\":[\"Fixează această conversație privată\"],\"BlNZZ2\":[\"Click pentru a sări la mesaj\"],\"Bowq3c\":[\"Doar operatorii pot schimba subiectul canalului\"],\"Btozzp\":[\"Această imagine a expirat\"],\"Bycfjm\":[\"Total: \",[\"0\"]],\"C6IBQc\":[\"Copiați JSON complet\"],\"C9L9wL\":[\"Colectare de date\"],\"CDq4wC\":[\"Moderează utilizatorul\"],\"CHVRxG\":[\"Mesaj @\",[\"0\"],\" (Shift+Enter pentru linie nouă)\"],\"CN9zdR\":[\"Numele oper și parola sunt obligatorii\"],\"CW3sYa\":[\"Adaugă reacția \",[\"emoji\"]],\"CaAkqd\":[\"Afișați deconectările\"],\"CbvaYj\":[\"Banare după poreclă\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Selectează un canal\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"Sistem\"],\"D28t6+\":[\"s-a alăturat și a ieșit\"],\"DB8zMK\":[\"Aplică\"],\"DBcWHr\":[\"Fișier audio de notificare personalizat\"],\"DTy9Xw\":[\"Previzualizări media\"],\"Dj4pSr\":[\"Alege o parolă sigură\"],\"Du+zn+\":[\"Se caută...\"],\"Du2T2f\":[\"Setarea nu a fost găsită\"],\"DwsSVQ\":[\"Aplică filtrele și actualizează\"],\"E3W/zd\":[\"Pseudonim implicit\"],\"E6nRW7\":[\"Copiază URL\"],\"E703RG\":[\"Moduri:\"],\"EAeu1Z\":[\"Trimiteți invitația\"],\"EFKJQT\":[\"Setare\"],\"EGPQBv\":[\"Reguli flood personalizate (+f)\"],\"ELik0r\":[\"Vizualizați politica completă de confidențialitate\"],\"EPbeC2\":[\"Vezi sau editează subiectul canalului\"],\"EQCDNT\":[\"Introduceți numele de utilizator oper...\"],\"EUvulZ\":[\"S-a găsit 1 mesaj care corespunde cu \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Imaginea următoare\"],\"EdQY6l\":[\"Niciunul\"],\"EnqLYU\":[\"Caută servere...\"],\"F0OKMc\":[\"Editează server\"],\"F6Int2\":[\"Activați evidențierile\"],\"FDoLyE\":[\"Utilizatori max.\"],\"FUU/hZ\":[\"Controlează câte conținuturi media externe sunt încărcate în chat.\"],\"Fdp03t\":[\"activ\"],\"FfPWR0\":[\"Modal\"],\"FjkaiT\":[\"Micșorează\"],\"FlqOE9\":[\"Ce înseamnă aceasta:\"],\"FolHNl\":[\"Gestionează-ți contul și autentificarea\"],\"Fp2Dif\":[\"A ieșit de pe server\"],\"G5KmCc\":[\"GZ-Line (Z-Line globală)\"],\"GDs0lz\":[\"<0>Risc:</0> Informațiile sensibile (mesaje, conversații private, date de autentificare) pot fi expuse administratorilor de rețea sau atacatorilor poziționați între serverele IRC.\"],\"GR+2I3\":[\"Adaugă mască de invitație (ex. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Închide notificările server detașate\"],\"GlHnXw\":[\"Schimbarea poreclelei a eșuat: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Previzualizare:\"],\"GtmO8/\":[\"de la\"],\"GtuHUQ\":[\"Redenumiți acest canal pe server. Toți utilizatorii vor vedea noul nume.\"],\"GuGfFX\":[\"Comută căutarea\"],\"GxkJXS\":[\"Se încarcă...\"],\"GzbwnK\":[\"S-a alăturat canalului\"],\"GzsUDB\":[\"Profil extins\"],\"H/PnT8\":[\"Inserează emoji\"],\"H6Izzl\":[\"Codul dvs. de culoare preferat\"],\"H9jIv+\":[\"Afișați intrări/ieșiri\"],\"HAKBY9\":[\"Încărcați fișiere\"],\"HdE1If\":[\"Canal\"],\"Hk4AW9\":[\"Numele dvs. de afișare preferat\"],\"HmHDk7\":[\"Selectează un membru\"],\"HrQzPU\":[\"Canale pe \",[\"networkName\"]],\"I2tXQ5\":[\"Mesaj @\",[\"0\"],\" (Enter pentru linie nouă, Shift+Enter pentru trimitere)\"],\"I6bw/h\":[\"Banează utilizatorul\"],\"I92Z+b\":[\"Activează notificările\"],\"I9D72S\":[\"Sigur doriți să ștergeți acest mesaj? Această acțiune nu poate fi anulată.\"],\"IA+1wo\":[\"Afișează când utilizatorii sunt expulzați din canale\"],\"IDwkJx\":[\"Operator IRC\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Salvează modificările\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" și \",[\"2\"],\" scriu...\"],\"IgrLD/\":[\"Pauză\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Răspunde\"],\"IoHMnl\":[\"Valoarea maximă este \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Se conectează...\"],\"J5T9NW\":[\"Informații utilizator\"],\"J8Y5+z\":[\"Oops! Rețeaua s-a împărțit! ⚠️\"],\"JBHkBA\":[\"A părăsit canalul\"],\"JCwL0Q\":[\"Introdu motivul (opțional)\"],\"JFciKP\":[\"Comută\"],\"JXGkhG\":[\"Schimbă numele canalului (numai operatori)\"],\"JcD7qf\":[\"Mai multe acțiuni\"],\"JdkA+c\":[\"Secret (+s)\"],\"Jmu12l\":[\"Canale server\"],\"JvQ++s\":[\"Activați Markdown\"],\"K2jwh/\":[\"Nu există date WHOIS disponibile\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Șterge mesaj\"],\"KKBlUU\":[\"Încorporare\"],\"KM0pLb\":[\"Bine ai venit în canal!\"],\"KR6W2h\":[\"Nu mai ignora utilizatorul\"],\"KV+Bi1\":[\"Doar pe invitație (+i)\"],\"KdCtwE\":[\"Câte secunde se monitorizează activitatea flood înainte de resetarea contoarelor\"],\"Kkezga\":[\"Parolă server\"],\"KsiQ/8\":[\"Utilizatorii trebuie invitați pentru a intra în canal\"],\"L+gB/D\":[\"Informații canal\"],\"LC1a7n\":[\"Serverul IRC a raportat că legăturile sale server-la-server au un nivel scăzut de securitate. Aceasta înseamnă că atunci când mesajele tale sunt transmise între serverele IRC din rețea, este posibil ca acestea să nu fie criptate corespunzător sau certificatele SSL/TLS să nu fie validate corect.\"],\"LNfLR5\":[\"Afișați expulzările\"],\"LQb0W/\":[\"Afișați toate evenimentele\"],\"LU7/yA\":[\"Nume alternativ pentru afișaj. Poate conține spații, emoji și caractere speciale. Numele real (\",[\"channelName\"],\") va fi folosit în continuare pentru comenzile IRC.\"],\"LUb9O7\":[\"Este necesar un port de server valid\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Politică de confidențialitate\"],\"LcuSDR\":[\"Gestionează informațiile profilului și metadatele\"],\"LqLS9B\":[\"Afișați schimbările de pseudonim\"],\"LsDQt2\":[\"Setări canal\"],\"LtI9AS\":[\"Proprietar\"],\"LuNhhL\":[\"a reacționat la acest mesaj\"],\"M/AZNG\":[\"URL-ul imaginii dvs. de avatar\"],\"M/WIer\":[\"Trimite mesaj\"],\"M8er/5\":[\"Nume:\"],\"MHk+7g\":[\"Imaginea anterioară\"],\"MRorGe\":[\"Mesaj privat\"],\"MVbSGP\":[\"Fereastră de timp (secunde)\"],\"MkpcsT\":[\"Mesajele și setările dvs. sunt stocate local pe dispozitivul dvs.\"],\"N/hDSy\":[\"Marcați ca bot, de obicei 'on' sau gol\"],\"N7TQbE\":[\"Invitați utilizatorul în \",[\"channelName\"]],\"NCca/o\":[\"Introduceți porecla implicită...\"],\"Nqs6B9\":[\"Afișează toate conținuturile externe. Orice URL poate genera o solicitare către un server necunoscut.\"],\"Nt+9O7\":[\"Utilizați WebSocket în loc de TCP brut\"],\"NxIHzc\":[\"Expulzați utilizatorul\"],\"O+v/cL\":[\"Răsfoiește toate canalele de pe server\"],\"ODwSCk\":[\"Trimite un GIF\"],\"OGQ5kK\":[\"Configurează sunetele de notificare și evidențierile\"],\"OIPt1Z\":[\"Afișează sau ascunde bara laterală cu lista de membri\"],\"OKSNq/\":[\"Foarte strict\"],\"ONWvwQ\":[\"Încărcați\"],\"OVKoQO\":[\"Parola contului dvs. pentru autentificare\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Aducem IRC în viitor\"],\"OhCpra\":[\"Setează un subiect…\"],\"OkltoQ\":[\"Banează \",[\"username\"],\" după poreclă (împiedică reconectarea cu același nick)\"],\"P+t/Te\":[\"Nicio dată suplimentară\"],\"P42Wcc\":[\"Sigur\"],\"PD38l0\":[\"Previzualizare avatar canal\"],\"PD9mEt\":[\"Scrie un mesaj...\"],\"PPqfdA\":[\"Deschide setările de configurare ale canalului\"],\"PSCjfZ\":[\"Subiectul afișat pentru acest canal. Toți utilizatorii îl pot vedea.\"],\"PZCecv\":[\"Previzualizare PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 dată\"],\"few\":[[\"c\"],\" ori\"],\"other\":[[\"c\"],\" ori\"]}]],\"PguS2C\":[\"Adaugă mască de excepție (ex. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Se afișează \",[\"displayedChannelsCount\"],\" din \",[\"0\"],\" canale\"],\"PqhVlJ\":[\"Banează utilizator (după hostmask)\"],\"Q+chwU\":[\"Nume utilizator:\"],\"Q6hhn8\":[\"Preferințe\"],\"QF4a34\":[\"Introduceți un nume de utilizator\"],\"QGqSZ2\":[\"Culoare și formatare\"],\"QJQd1J\":[\"Editați profilul\"],\"QSzGDE\":[\"Inactiv\"],\"QUlny5\":[\"Bine ai venit la \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Citește mai mult\"],\"QuSkCF\":[\"Filtrează canale...\"],\"QwUrDZ\":[\"a schimbat subiectul la: \",[\"topic\"]],\"R0UH07\":[\"Imaginea \",[\"0\"],\" din \",[\"1\"]],\"R7SsBE\":[\"Dezactivare sunet\"],\"R8rf1X\":[\"Click pentru a seta subiectul\"],\"RArB3D\":[\"a fost dat afară din \",[\"channelName\"],\" de \",[\"username\"]],\"RI3cWd\":[\"Descoperă lumea IRC cu ObsidianIRC\"],\"RMMaN5\":[\"Moderat (+m)\"],\"RWw9Lg\":[\"Închide fereastra\"],\"RZ2BuZ\":[\"Înregistrarea contului \",[\"account\"],\" necesită verificare: \",[\"message\"]],\"RySp6q\":[\"Ascundeți comentariile\"],\"SPKQTd\":[\"Porecla este obligatorie\"],\"SPVjfj\":[\"Va fi implicit „niciun motiv\\\" dacă este lăsat gol\"],\"SQKPvQ\":[\"Invită utilizator\"],\"SkZcl+\":[\"Alegeți un profil de protecție flood predefinit. Aceste profiluri oferă setări de protecție echilibrate pentru diferite cazuri de utilizare.\"],\"Slr+3C\":[\"Utilizatori min.\"],\"Spnlre\":[\"L-ai invitat pe \",[\"target\"],\" să se alăture la \",[\"channel\"]],\"T/ckN5\":[\"Deschide în vizualizator\"],\"T91vKp\":[\"Redare\"],\"TV2Wdu\":[\"Aflați cum gestionăm datele dvs. și vă protejăm confidențialitatea.\"],\"TgFpwD\":[\"Se aplică...\"],\"TkzSFB\":[\"Nicio modificare\"],\"TtserG\":[\"Introdu numele real\"],\"Ttz9J1\":[\"Introduceți parola...\"],\"Tz0i8g\":[\"Setări\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reacționează\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Salvați setările\"],\"UV5hLB\":[\"Nu s-au găsit banuri\"],\"Uaj3Nd\":[\"Mesaje de stare\"],\"Ue3uny\":[\"Implicit (fără profil)\"],\"UkARhe\":[\"Normal – Protecție standard\"],\"Umn7Cj\":[\"Niciun comentariu. Fiți primul!\"],\"UtUIRh\":[[\"0\"],\" mesaje mai vechi\"],\"UwzP+U\":[\"Conexiune securizată\"],\"V0/A4O\":[\"Proprietar de canal\"],\"V4qgxE\":[\"Creat înainte (min în urmă)\"],\"V8yTm6\":[\"Șterge căutarea\"],\"VJMMyz\":[\"ObsidianIRC - Aducând IRC în viitor\"],\"VJScHU\":[\"Motiv\"],\"VLsmVV\":[\"Dezactivează notificările\"],\"VbyRUy\":[\"Comentarii\"],\"Vmx0mQ\":[\"Setat de:\"],\"VqnIZz\":[\"Vezi politica noastră de confidențialitate și practicile privind datele\"],\"VrMygG\":[\"Lungimea minimă este \",[\"0\"]],\"VrnTui\":[\"Pronumele dvs., afișate în profil\"],\"W8E3qn\":[\"Cont autentificat\"],\"WAakm9\":[\"Șterge canal\"],\"WFxTHC\":[\"Adaugă mască de banare (ex. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Adresa serverului este obligatorie\"],\"WRYdXW\":[\"Poziție audio\"],\"WUOH5B\":[\"Ignoră utilizatorul\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Arată 1 element în plus\"],\"few\":[\"Arată \",[\"1\"],\" elemente în plus\"],\"other\":[\"Arată \",[\"1\"],\" de elemente în plus\"]}]],\"Weq9zb\":[\"General\"],\"Wfj7Sk\":[\"Activează sau dezactivează sunetele de notificare\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profil utilizator\"],\"X6S3lt\":[\"Caută setări, canale, servere...\"],\"XEHan5\":[\"Continuă oricum\"],\"XI1+wb\":[\"Format invalid\"],\"XIXeuC\":[\"Mesaj @\",[\"0\"]],\"XMS+k4\":[\"Începe un mesaj privat\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Anulează fixarea conversației private\"],\"Xm/s+u\":[\"Afișare\"],\"Xp2n93\":[\"Afișează media de pe gazda de fișiere de încredere a serverului. Nu se fac solicitări către servicii externe.\"],\"XvjC4F\":[\"Se salvează...\"],\"Y/qryO\":[\"Niciun utilizator găsit care să corespundă căutării\"],\"YAqRpI\":[\"Înregistrarea contului \",[\"account\"],\" a reușit: \",[\"message\"]],\"YEfzvP\":[\"Subiect protejat (+t)\"],\"YQOn6a\":[\"Restrânge lista de membri\"],\"YRCoE9\":[\"Operator de canal\"],\"YURQaF\":[\"Vizualizați profilul\"],\"YdBSvr\":[\"Controlează afișarea media și conținutul extern\"],\"Yj6U3V\":[\"Fără server central:\"],\"YjvpGx\":[\"Pronume\"],\"YqH4l4\":[\"Nicio cheie\"],\"YyUPpV\":[\"Cont:\"],\"ZJSWfw\":[\"Mesaj afișat la deconectarea de la server\"],\"ZR1dJ4\":[\"Invitații\"],\"ZdWg0V\":[\"Deschide în browser\"],\"ZhRBbl\":[\"Caută mesaje…\"],\"Zmcu3y\":[\"Filtre avansate\"],\"a2/8e5\":[\"Subiect setat după (min în urmă)\"],\"aHKcKc\":[\"Pagina anterioară\"],\"aJTbXX\":[\"Parolă oper\"],\"aQryQv\":[\"Modelul există deja\"],\"aW9pLN\":[\"Numărul maxim de utilizatori permis în canal. Lăsați gol pentru fără limită.\"],\"ah4fmZ\":[\"Afișează și previzualizări de pe YouTube, Vimeo, SoundCloud și servicii similare cunoscute.\"],\"aifXak\":[\"Niciun fișier media în acest canal\"],\"ap2zBz\":[\"Relaxat\"],\"az8lvo\":[\"Oprit\"],\"azXSNo\":[\"Extinde lista de membri\"],\"azdliB\":[\"Conectează-te la un cont\"],\"b26wlF\":[\"ea/ei\"],\"bD/+Ei\":[\"Strict\"],\"bQ6BJn\":[\"Configurați reguli detaliate de protecție flood. Fiecare regulă specifică tipul de activitate de monitorizat și acțiunea de întreprins când pragurile sunt depășite.\"],\"beV7+y\":[\"Utilizatorul va primi o invitație să se alăture \",[\"channelName\"],\".\"],\"bk84cH\":[\"Mesaj de absență\"],\"bkHdLj\":[\"Adaugă server IRC\"],\"bmQLn5\":[\"Adaugă regulă\"],\"bwRvnp\":[\"Acțiune\"],\"c8+EVZ\":[\"Cont verificat\"],\"cGYUlD\":[\"Nu sunt încărcate previzualizări media.\"],\"cLF98o\":[\"Afișați comentariile (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Niciun utilizator disponibil\"],\"cSgpoS\":[\"Fixează conversația privată\"],\"cde3ce\":[\"Mesaj <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Copiați ieșirea formatată\"],\"cl/A5J\":[\"Bine ai venit la \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Șterge\"],\"coPLXT\":[\"Nu stocăm comunicațiile dvs. IRC pe serverele noastre\"],\"crYH/6\":[\"Player SoundCloud\"],\"d3sis4\":[\"Adaugă server\"],\"d9aN5k\":[\"Elimină \",[\"username\"],\" din canal\"],\"dEgA5A\":[\"Anulează\"],\"dGi1We\":[\"Anulează fixarea acestei conversații private\"],\"dJVuyC\":[\"a părăsit \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"către\"],\"dXqxlh\":[\"<0>⚠️ Risc de securitate!</0> Această conexiune poate fi vulnerabilă la interceptare sau atacuri de tip man-in-the-middle.\"],\"da9Q/R\":[\"Moduri canal modificate\"],\"dhJN3N\":[\"Afișați comentariile\"],\"dj2xTE\":[\"Respinge notificarea\"],\"dpCzmC\":[\"Setări de protecție flood\"],\"e9dQpT\":[\"Dorești să deschizi acest link într-o filă nouă?\"],\"ePK91l\":[\"Editează\"],\"eYBDuB\":[\"Încărcați o imagine sau furnizați o adresă URL cu înlocuire opțională \",[\"size\"]],\"edBbee\":[\"Banează \",[\"username\"],\" după hostmask (împiedică reconectarea de pe același IP/host)\"],\"ekfzWq\":[\"Setări utilizator\"],\"elPDWs\":[\"Personalizează experiența clientului IRC\"],\"eu2osY\":[\"<0>💡 Recomandare:</0> Continuați doar dacă aveți încredere în acest server și înțelegeți riscurile. Evitați să partajați informații sensibile sau parole prin această conexiune.\"],\"euEhbr\":[\"Faceți clic pentru a vă alătura \",[\"channel\"]],\"ez3vLd\":[\"Activați introducerea pe mai multe rânduri\"],\"f0J5Ki\":[\"Comunicarea server-la-server poate folosi conexiuni necriptate\"],\"f9BHJk\":[\"Avertizează utilizatorul\"],\"fDOLLd\":[\"Nu s-au găsit canale.\"],\"ffzDkB\":[\"Analize anonime:\"],\"fq1GF9\":[\"Afișează când utilizatorii se deconectează de la server\"],\"gEF57C\":[\"Acest server acceptă doar un tip de conexiune\"],\"gJuLUI\":[\"Listă de ignorați\"],\"gNzMrk\":[\"Avatar curent\"],\"gjPWyO\":[\"Introduceți porecla...\"],\"gz6UQ3\":[\"Maximizează\"],\"h6razj\":[\"Excludeți masca numelui canalului\"],\"hG6jnw\":[\"Niciun subiect setat\"],\"hG89Ed\":[\"Imagine\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"ex., 100:1440\"],\"hehnjM\":[\"Cantitate\"],\"hzdLuQ\":[\"Doar utilizatorii cu voice sau mai mult pot vorbi\"],\"i0qMbr\":[\"Acasă\"],\"iDNBZe\":[\"Notificări\"],\"iH8pgl\":[\"Înapoi\"],\"iL9SZg\":[\"Banează utilizator (după poreclă)\"],\"iNt+3c\":[\"Înapoi la imagine\"],\"iQvi+a\":[\"Nu mă avertiza despre securitatea scăzută a legăturilor pentru acest server\"],\"iSLIjg\":[\"Conectare\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Adresă server\"],\"idD8Ev\":[\"Salvat\"],\"iivqkW\":[\"Conectat la\"],\"ij+Elv\":[\"Previzualizare imagine\"],\"ilIWp7\":[\"Comută notificările\"],\"iuaqvB\":[\"Folosiți * pentru wildcard. Exemple: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Banare după mască gazdă\"],\"jA4uoI\":[\"Subiect:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Motiv (opțional)\"],\"jUV7CU\":[\"Încarcă avatar\"],\"jW5Uwh\":[\"Controlează câtă media externă se încarcă. Dezactivat / Sigur / Surse de încredere / Tot conținutul.\"],\"jXzms5\":[\"Opțiuni atașament\"],\"jZlrte\":[\"Culoare\"],\"jfC/xh\":[\"Contact\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Încarcă mesaje mai vechi\"],\"k3ID0F\":[\"Filtrează membri…\"],\"k65gsE\":[\"Detalii\"],\"k7Zgob\":[\"Anulează conexiunea\"],\"kAVx5h\":[\"Nu s-au găsit invitații\"],\"kCLEPU\":[\"Conectat la\"],\"kF5LKb\":[\"Modele ignorate:\"],\"kGeOx/\":[\"Alătură-te la \",[\"0\"]],\"kITKr8\":[\"Se încarcă modurile canalului...\"],\"kPpPsw\":[\"Ești un Operator IRC\"],\"kWJmRL\":[\"Tu\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Copiați JSON\"],\"krViRy\":[\"Clic pentru copiere ca JSON\"],\"ks71ra\":[\"Excepții\"],\"kw4lRv\":[\"Semi-operator de canal\"],\"kxgIRq\":[\"Selectează sau adaugă un canal pentru a începe.\"],\"ky6dWe\":[\"Previzualizare avatar\"],\"l+GxCv\":[\"Se încarcă canalele...\"],\"l+IUVW\":[\"Verificarea contului \",[\"account\"],\" a reușit: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"s-a reconectat\"],\"few\":[\"s-a reconectat de \",[\"reconnectCount\"],\" ori\"],\"other\":[\"s-a reconectat de \",[\"reconnectCount\"],\" ori\"]}]],\"l5jmzx\":[[\"0\"],\" și \",[\"1\"],\" scriu...\"],\"lHy8N5\":[\"Se încarcă mai multe canale...\"],\"lbpf14\":[\"Intrați în \",[\"value\"]],\"lfFsZ4\":[\"Canale\"],\"lkNdiH\":[\"Nume cont\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Încarcă imagine\"],\"loQxaJ\":[\"M-am întors\"],\"lvfaxv\":[\"ACASĂ\"],\"m16xKo\":[\"Adaugă\"],\"m8flAk\":[\"Previzualizare (neîncărcat încă)\"],\"mEPxTp\":[\"<0>⚠️ Atenție!</0> Deschide numai linkuri din surse de încredere. Linkurile malițioase îți pot compromite securitatea sau confidențialitatea.\"],\"mHGdhG\":[\"Informații server\"],\"mHS8lb\":[\"Mesaj #\",[\"0\"]],\"mMYBD9\":[\"Larg – Domeniu de protecție mai amplu\"],\"mTGsPd\":[\"Subiect canal\"],\"mU8j6O\":[\"Fără mesaje externe (+n)\"],\"mZp8FL\":[\"Revenire automată la o singură linie\"],\"mdQu8G\":[\"NumeleTău\"],\"miSSBQ\":[\"Comentarii (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Utilizatorul este autentificat\"],\"mwtcGl\":[\"Închide comentariile\"],\"mzI/c+\":[\"Descarcă\"],\"n3fGRk\":[\"setat de \",[\"0\"]],\"nE9jsU\":[\"Relaxat – Protecție mai puțin agresivă\"],\"nNflMD\":[\"Părăsește canalul\"],\"nPXkBi\":[\"Se încarcă datele WHOIS...\"],\"nQnxxF\":[\"Mesaj #\",[\"0\"],\" (Shift+Enter pentru linie nouă)\"],\"nWMRxa\":[\"Anulează fixarea\"],\"nkC032\":[\"Niciun profil anti-flood\"],\"o69z4d\":[\"Trimite un mesaj de avertizare către \",[\"username\"]],\"o9ylQi\":[\"Căutați GIF-uri pentru a începe\"],\"oFGkER\":[\"Notificări server\"],\"oOi11l\":[\"Derulează în jos\"],\"oQEzQR\":[\"Mesaj direct nou\"],\"oXOSPE\":[\"Conectat\"],\"oal760\":[\"Atacurile man-in-the-middle asupra legăturilor de server sunt posibile\"],\"oeqmmJ\":[\"Surse de încredere\"],\"ovBPCi\":[\"Implicit\"],\"p0Z69r\":[\"Modelul nu poate fi gol\"],\"p1KgtK\":[\"Eroare la încărcarea audio\"],\"p59pEv\":[\"Detalii suplimentare\"],\"p7sRI6\":[\"Anunțați ceilalți când scrieți\"],\"pBm1od\":[\"Canal secret\"],\"pNmiXx\":[\"Pseudonimul dvs. implicit pentru toate serverele\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Parolă cont\"],\"peNE68\":[\"Permanent\"],\"plhHQt\":[\"Fără date\"],\"pm6+q5\":[\"Avertisment de securitate\"],\"pn5qSs\":[\"Informații suplimentare\"],\"q0cR4S\":[\"acum este cunoscut ca **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Canalul nu va apărea în comenzile LIST sau NAMES\"],\"qLpTm/\":[\"Elimină reacția \",[\"emoji\"]],\"qVkGWK\":[\"Fixează\"],\"qY8wNa\":[\"Pagină principală\"],\"qb0xJ7\":[\"Wildcard: * se potrivește oricărei secvențe, ? unui singur caracter. Exemple: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Cheie canal (+k)\"],\"qtoOYG\":[\"Nicio limită\"],\"r1W2AS\":[\"Imagine filehost\"],\"rIPR2O\":[\"Subiect setat înainte (min în urmă)\"],\"rMMSYo\":[\"Lungimea maximă este \",[\"0\"]],\"rWtzQe\":[\"Rețeaua s-a împărțit și s-a reconectat. ✅\"],\"rYG2u6\":[\"Vă rugăm așteptați...\"],\"rdUucN\":[\"Previzualizare\"],\"rjGI/Q\":[\"Confidențialitate\"],\"rk8iDX\":[\"Se încarcă GIF-urile...\"],\"rn6SBY\":[\"Activare sunet\"],\"s/UKqq\":[\"A fost dat afară din canal\"],\"s8cATI\":[\"s-a alăturat la \",[\"channelName\"]],\"sCO9ue\":[\"Conexiunea la <0>\",[\"serverName\"],\"</0> prezintă următoarele probleme de securitate:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"acum este cunoscut ca **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" te-a invitat să te alături la \",[\"channel\"]],\"sby+1/\":[\"Faceți clic pentru a copia\"],\"sfN25C\":[\"Numele dvs. real sau complet\"],\"sliuzR\":[\"Deschide linkul\"],\"sqrO9R\":[\"Mențiuni personalizate\"],\"sr6RdJ\":[\"Multilinie cu Shift+Enter\"],\"swrCpB\":[\"Canalul a fost redenumit din \",[\"oldName\"],\" în \",[\"newName\"],\" de \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Avansat\"],\"t/YqKh\":[\"Elimină\"],\"t47eHD\":[\"Identificatorul dvs. unic pe acest server\"],\"tAkAh0\":[\"URL cu înlocuire opțională \",[\"size\"],\". Exemplu: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Afișează sau ascunde bara laterală cu lista de canale\"],\"tfDRzk\":[\"Salvează\"],\"tiBsJk\":[\"a părăsit \",[\"channelName\"]],\"tt4/UD\":[\"a ieșit (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Porecla {nick} este deja utilizată, reîncercare cu {newNick}\"],\"u0a8B4\":[\"Autentificați-vă ca operator IRC pentru acces administrativ\"],\"u0rWFU\":[\"Creat după (min în urmă)\"],\"u72w3t\":[\"Utilizatori și modele de ignorat\"],\"u7jc2L\":[\"a ieșit\"],\"uAQUqI\":[\"Stare\"],\"uB85T3\":[\"Salvare eșuată: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"Servere IRC:\"],\"usSSr/\":[\"Nivel de zoom\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Shift+Enter pentru rânduri noi (Enter trimite)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Fără subiect\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Limbă\"],\"vaHYxN\":[\"Nume real\"],\"vhjbKr\":[\"Absent\"],\"w4NYox\":[\"client \",[\"title\"]],\"w8xQRx\":[\"Valoare invalidă\"],\"wFjjxZ\":[\"a fost dat afară din \",[\"channelName\"],\" de \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nu s-au găsit excepții de banare\"],\"wPrGnM\":[\"Administrator de canal\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Afișează când utilizatorii intră sau ies din canale\"],\"whqZ9r\":[\"Cuvinte sau fraze suplimentare de evidențiat\"],\"wm7RV4\":[\"Sunet de notificare\"],\"wz/Yoq\":[\"Mesajele tale pot fi interceptate când sunt transmise între servere\"],\"xCJdfg\":[\"Șterge\"],\"xUHRTR\":[\"Autentificare automată ca operator la conectare\"],\"xWHwwQ\":[\"Banuri\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Sunt acceptate numai websocket-uri securizate\"],\"xdtXa+\":[\"nume-canal\"],\"xfXC7q\":[\"Canale text\"],\"xlCYOE\":[\"Se încarcă mai multe mesaje...\"],\"xlhswE\":[\"Valoarea minimă este \",[\"0\"]],\"xq97Ci\":[\"Adaugă un cuvânt sau o expresie...\"],\"xuRqRq\":[\"Limită clienți (+l)\"],\"xwF+7J\":[[\"0\"],\" scrie...\"],\"yNeucF\":[\"Acest server nu acceptă metadate extinse de profil (extensia IRCv3 METADATA). Câmpurile precum avatar, nume afișat și stare nu sunt disponibile.\"],\"yPlrca\":[\"Avatar canal\"],\"yQE2r9\":[\"Se încarcă\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Nume utilizator operator\"],\"yYOzWD\":[\"jurnale\"],\"yfx9Re\":[\"Parola operatorului IRC\"],\"ygCKqB\":[\"Oprește\"],\"ymDxJx\":[\"Numele de utilizator al operatorului IRC\"],\"yrpRsQ\":[\"Sortare după nume\"],\"yz7wBu\":[\"Închide\"],\"zJw+jA\":[\"setează modul: \",[\"0\"]],\"zebeLu\":[\"Introdu numele de utilizator oper\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Format model invalid. Folosiți formatul nick!user@host (caractere wildcard * permise)\"],\"+6NQQA\":[\"Canal general de suport\"],\"+6NyRG\":[\"Client\"],\"+K0AvT\":[\"Deconectează\"],\"+cyFdH\":[\"Mesaj implicit când vă marcați ca absent\"],\"+mVPqU\":[\"Afișați formatarea Markdown în mesaje\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Numele de utilizator al contului dvs. pentru autentificare\"],\"+yPBXI\":[\"Alege fișier\"],\"+zy2Nq\":[\"Tip\"],\"/09cao\":[\"Securitate scăzută a legăturii (Nivel \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Utilizatorii din afara canalului nu pot trimite mesaje\"],\"/6BzZF\":[\"Comută lista de membri\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Utilizatorul este absent\"],\"/XQgft\":[\"Descoperă\"],\"/cF7Rs\":[\"Volum\"],\"/dqduX\":[\"Pagina următoare\"],\"/fc3q4\":[\"Tot conținutul\"],\"/kISDh\":[\"Activați sunetele de notificare\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Audio\"],\"/rfkZe\":[\"Redați sunete pentru mențiuni și mesaje\"],\"0/0ZGA\":[\"Mască nume canal\"],\"0D6j7U\":[\"Aflați mai multe despre regulile personalizate →\"],\"0XsHcR\":[\"Dă afară utilizatorul\"],\"0ZpE//\":[\"Sortare după utilizatori\"],\"0bEPwz\":[\"Setează ca absent\"],\"0dGkPt\":[\"Extinde lista de canale\"],\"0gS7M5\":[\"Nume afișat\"],\"0kS+M8\":[\"ExempluRET\"],\"0rgoY7\":[\"Conectați-vă doar la serverele pe care le alegeți\"],\"0wdd7X\":[\"Alătură-te\"],\"0wkVYx\":[\"Mesaje private\"],\"111uHX\":[\"Previzualizare link\"],\"196EG4\":[\"Șterge conversația privată\"],\"1DSr1i\":[\"Înregistrează un cont\"],\"1O/24y\":[\"Comută lista de canale\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Avertisment link extern\"],\"1ZC/dv\":[\"Nicio mențiune sau mesaj necitit\"],\"1pO1zi\":[\"Numele serverului este obligatoriu\"],\"1uwfzQ\":[\"Vezi subiectul canalului\"],\"268g7c\":[\"Introdu numele afișat\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Operatorii de server din rețea pot citi mesajele tale\"],\"2FYpfJ\":[\"Mai mult\"],\"2HF1Y2\":[[\"inviter\"],\" l-a invitat pe \",[\"target\"],\" să se alăture la \",[\"channel\"]],\"2I70QL\":[\"Vezi informațiile profilului utilizatorului\"],\"2QYdmE\":[\"Utilizatori:\"],\"2QpEjG\":[\"a ieșit\"],\"2YE223\":[\"Mesaj #\",[\"0\"],\" (Enter pentru linie nouă, Shift+Enter pentru trimitere)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Folosește parola serverului\"],\"2iTmdZ\":[\"Stocare locală:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Strict – Protecție mai agresivă\"],\"2uDhbA\":[\"Introdu numele de utilizator de invitat\"],\"2ygf/L\":[\"← Înapoi\"],\"2zEgxj\":[\"Caută GIF-uri...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Redenumește canalul\"],\"3THokf\":[\"Utilizator cu drept de vorbire\"],\"3TSz9S\":[\"Minimizează\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Nume afișat canal\"],\"3ryuFU\":[\"Rapoarte opționale de erori pentru îmbunătățirea aplicației\"],\"3uBF/8\":[\"Închide vizualizatorul\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Introduceți numele contului...\"],\"4/Rr0R\":[\"Invită un utilizator în canalul curent\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Reguli\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Profil flood (+F)\"],\"4RZQRK\":[\"Ce faci?\"],\"4hfTrB\":[\"Poreclă\"],\"4n99LO\":[\"Deja în \",[\"0\"]],\"4t6vMV\":[\"Comutare automată la linie unică pentru mesaje scurte\"],\"4vsHmf\":[\"Timp (min)\"],\"5+INAX\":[\"Evidențiați mesajele care vă menționează\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Nume oper\"],\"678PKt\":[\"Nume rețea\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Deconectat\"],\"6CO3WE\":[\"Parolă necesară pentru a intra în canal. Lăsați gol pentru a elimina cheia.\"],\"6HhMs3\":[\"Mesaj de ieșire\"],\"6V3Ea3\":[\"Copiat\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Arată mai puțin\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Introduceți parola oper...\"],\"7+IHTZ\":[\"Niciun fișier ales\"],\"73hrRi\":[\"nick!user@host (ex., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Trimite mesaj privat\"],\"7U1W7c\":[\"Foarte relaxat\"],\"7Y1YQj\":[\"Nume real:\"],\"7YHArF\":[\"— deschide în vizualizator\"],\"7fjnVl\":[\"Caută utilizatori...\"],\"7jL88x\":[\"Ștergeți acest mesaj? Această acțiune nu poate fi anulată.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"La ce te gândești?\"],\"7sEpu1\":[\"Membri — \",[\"0\"]],\"7sNhEz\":[\"Nume de utilizator\"],\"8H0Q+x\":[\"Aflați mai multe despre profiluri →\"],\"8Phu0A\":[\"Afișează când utilizatorii își schimbă pseudonimul\"],\"8XTG9e\":[\"Introdu parola oper\"],\"8XsV2J\":[\"Reîncearcă trimiterea\"],\"8ZsakT\":[\"Parolă\"],\"8kR84m\":[\"Ești pe cale să deschizi un link extern:\"],\"8lCgih\":[\"Eliminați regula\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"s-a alăturat\"],\"few\":[\"s-a alăturat de \",[\"joinCount\"],\" ori\"],\"other\":[\"s-a alăturat de \",[\"joinCount\"],\" ori\"]}]],\"9BMLnJ\":[\"Reconectează-te la server\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Adaugă reacție\"],\"9PQ8m2\":[\"G-Line (ban global)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Elimină șablon\"],\"9bG48P\":[\"Se trimite\"],\"9f5f0u\":[\"Întrebări despre confidențialitate? Contactați-ne:\"],\"9unqs3\":[\"Absent:\"],\"9v3hwv\":[\"Niciun server găsit.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Se conectează\"],\"A1taO8\":[\"Caută\"],\"A2adVi\":[\"Trimiteți notificări de tastare\"],\"A9Rhec\":[\"Nume canal\"],\"AWOSPo\":[\"Mărește\"],\"AXSpEQ\":[\"Oper la conectare\"],\"AeXO77\":[\"Cont\"],\"AhNP40\":[\"Derulare\"],\"Ai2U7L\":[\"Gazdă\"],\"AjBQnf\":[\"Poreclă schimbată\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Anulează răspunsul\"],\"ApSx0O\":[\"S-au găsit \",[\"0\"],\" mesaje care corespund cu \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Niciun rezultat găsit\"],\"AyNqAB\":[\"Afișează toate evenimentele serverului în chat\"],\"B/QqGw\":[\"Departe de tastatură\"],\"B8AaMI\":[\"Acest câmp este obligatoriu\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Serverul nu acceptă filtrarea avansată LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" și alți \",[\"3\"],\" scriu...\"],\"BGul2A\":[\"Ai modificări nesalvate. Ești sigur că vrei să închizi fără să salvezi?\"],\"BIf9fi\":[\"Mesajul dvs. de stare\"],\"BZz3md\":[\"Site-ul dvs. web personal\"],\"Bgm/H7\":[\"Permite introducerea mai multor rânduri de text\"],\"BiQIl1\":[\"Fixează această conversație privată\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Click pentru a sări la mesaj\"],\"Bowq3c\":[\"Doar operatorii pot schimba subiectul canalului\"],\"Btozzp\":[\"Această imagine a expirat\"],\"Bycfjm\":[\"Total: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Copiați JSON complet\"],\"C9L9wL\":[\"Colectare de date\"],\"CDq4wC\":[\"Moderează utilizatorul\"],\"CHVRxG\":[\"Mesaj @\",[\"0\"],\" (Shift+Enter pentru linie nouă)\"],\"CN9zdR\":[\"Numele oper și parola sunt obligatorii\"],\"CW3sYa\":[\"Adaugă reacția \",[\"emoji\"]],\"CaAkqd\":[\"Afișați deconectările\"],\"CbvaYj\":[\"Banare după poreclă\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Selectează un canal\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"Sistem\"],\"D28t6+\":[\"s-a alăturat și a ieșit\"],\"DB8zMK\":[\"Aplică\"],\"DBcWHr\":[\"Fișier audio de notificare personalizat\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Previzualizări media\"],\"Dj4pSr\":[\"Alege o parolă sigură\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Se caută...\"],\"Du2T2f\":[\"Setarea nu a fost găsită\"],\"DwsSVQ\":[\"Aplică filtrele și actualizează\"],\"E3W/zd\":[\"Pseudonim implicit\"],\"E6nRW7\":[\"Copiază URL\"],\"E703RG\":[\"Moduri:\"],\"EAeu1Z\":[\"Trimiteți invitația\"],\"EFKJQT\":[\"Setare\"],\"EGPQBv\":[\"Reguli flood personalizate (+f)\"],\"ELik0r\":[\"Vizualizați politica completă de confidențialitate\"],\"EPbeC2\":[\"Vezi sau editează subiectul canalului\"],\"EQCDNT\":[\"Introduceți numele de utilizator oper...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"S-a găsit 1 mesaj care corespunde cu \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Imaginea următoare\"],\"EdQY6l\":[\"Niciunul\"],\"EnqLYU\":[\"Caută servere...\"],\"F0OKMc\":[\"Editează server\"],\"F6Int2\":[\"Activați evidențierile\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Utilizatori max.\"],\"FUU/hZ\":[\"Controlează câte conținuturi media externe sunt încărcate în chat.\"],\"Fdp03t\":[\"activ\"],\"FfPWR0\":[\"Modal\"],\"FjkaiT\":[\"Micșorează\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Ce înseamnă aceasta:\"],\"FolHNl\":[\"Gestionează-ți contul și autentificarea\"],\"Fp2Dif\":[\"A ieșit de pe server\"],\"G5KmCc\":[\"GZ-Line (Z-Line globală)\"],\"GDs0lz\":[\"<0>Risc:</0> Informațiile sensibile (mesaje, conversații private, date de autentificare) pot fi expuse administratorilor de rețea sau atacatorilor poziționați între serverele IRC.\"],\"GR+2I3\":[\"Adaugă mască de invitație (ex. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Închide notificările server detașate\"],\"GlHnXw\":[\"Schimbarea poreclelei a eșuat: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Previzualizare:\"],\"GtmO8/\":[\"de la\"],\"GtuHUQ\":[\"Redenumiți acest canal pe server. Toți utilizatorii vor vedea noul nume.\"],\"GuGfFX\":[\"Comută căutarea\"],\"GxkJXS\":[\"Se încarcă...\"],\"GzbwnK\":[\"S-a alăturat canalului\"],\"GzsUDB\":[\"Profil extins\"],\"H/PnT8\":[\"Inserează emoji\"],\"H6Izzl\":[\"Codul dvs. de culoare preferat\"],\"H9jIv+\":[\"Afișați intrări/ieșiri\"],\"HAKBY9\":[\"Încărcați fișiere\"],\"HdE1If\":[\"Canal\"],\"Hk4AW9\":[\"Numele dvs. de afișare preferat\"],\"HmHDk7\":[\"Selectează un membru\"],\"HrQzPU\":[\"Canale pe \",[\"networkName\"]],\"I2tXQ5\":[\"Mesaj @\",[\"0\"],\" (Enter pentru linie nouă, Shift+Enter pentru trimitere)\"],\"I6bw/h\":[\"Banează utilizatorul\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Activează notificările\"],\"I9D72S\":[\"Sigur doriți să ștergeți acest mesaj? Această acțiune nu poate fi anulată.\"],\"IA+1wo\":[\"Afișează când utilizatorii sunt expulzați din canale\"],\"IDwkJx\":[\"Operator IRC\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Salvează modificările\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" și \",[\"2\"],\" scriu...\"],\"IgrLD/\":[\"Pauză\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Răspunde\"],\"IoHMnl\":[\"Valoarea maximă este \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Se conectează...\"],\"J5T9NW\":[\"Informații utilizator\"],\"J8Y5+z\":[\"Oops! Rețeaua s-a împărțit! ⚠️\"],\"JBHkBA\":[\"A părăsit canalul\"],\"JCwL0Q\":[\"Introdu motivul (opțional)\"],\"JFciKP\":[\"Comută\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Schimbă numele canalului (numai operatori)\"],\"JcD7qf\":[\"Mai multe acțiuni\"],\"JdkA+c\":[\"Secret (+s)\"],\"Jmu12l\":[\"Canale server\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Activați Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Nu există date WHOIS disponibile\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Șterge mesaj\"],\"KKBlUU\":[\"Încorporare\"],\"KM0pLb\":[\"Bine ai venit în canal!\"],\"KR6W2h\":[\"Nu mai ignora utilizatorul\"],\"KV+Bi1\":[\"Doar pe invitație (+i)\"],\"KdCtwE\":[\"Câte secunde se monitorizează activitatea flood înainte de resetarea contoarelor\"],\"Kkezga\":[\"Parolă server\"],\"KsiQ/8\":[\"Utilizatorii trebuie invitați pentru a intra în canal\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Informații canal\"],\"LC1a7n\":[\"Serverul IRC a raportat că legăturile sale server-la-server au un nivel scăzut de securitate. Aceasta înseamnă că atunci când mesajele tale sunt transmise între serverele IRC din rețea, este posibil ca acestea să nu fie criptate corespunzător sau certificatele SSL/TLS să nu fie validate corect.\"],\"LNfLR5\":[\"Afișați expulzările\"],\"LQb0W/\":[\"Afișați toate evenimentele\"],\"LU7/yA\":[\"Nume alternativ pentru afișaj. Poate conține spații, emoji și caractere speciale. Numele real (\",[\"channelName\"],\") va fi folosit în continuare pentru comenzile IRC.\"],\"LUb9O7\":[\"Este necesar un port de server valid\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Politică de confidențialitate\"],\"LcuSDR\":[\"Gestionează informațiile profilului și metadatele\"],\"LqLS9B\":[\"Afișați schimbările de pseudonim\"],\"LsDQt2\":[\"Setări canal\"],\"LtI9AS\":[\"Proprietar\"],\"LuNhhL\":[\"a reacționat la acest mesaj\"],\"M/AZNG\":[\"URL-ul imaginii dvs. de avatar\"],\"M/WIer\":[\"Trimite mesaj\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Nume:\"],\"MHk+7g\":[\"Imaginea anterioară\"],\"MRorGe\":[\"Mesaj privat\"],\"MVbSGP\":[\"Fereastră de timp (secunde)\"],\"MkpcsT\":[\"Mesajele și setările dvs. sunt stocate local pe dispozitivul dvs.\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Marcați ca bot, de obicei 'on' sau gol\"],\"N7TQbE\":[\"Invitați utilizatorul în \",[\"channelName\"]],\"NCca/o\":[\"Introduceți porecla implicită...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Afișează toate conținuturile externe. Orice URL poate genera o solicitare către un server necunoscut.\"],\"Nt+9O7\":[\"Utilizați WebSocket în loc de TCP brut\"],\"NxIHzc\":[\"Expulzați utilizatorul\"],\"O+v/cL\":[\"Răsfoiește toate canalele de pe server\"],\"ODwSCk\":[\"Trimite un GIF\"],\"OGQ5kK\":[\"Configurează sunetele de notificare și evidențierile\"],\"OIPt1Z\":[\"Afișează sau ascunde bara laterală cu lista de membri\"],\"OKSNq/\":[\"Foarte strict\"],\"ONWvwQ\":[\"Încărcați\"],\"OVKoQO\":[\"Parola contului dvs. pentru autentificare\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Aducem IRC în viitor\"],\"OhCpra\":[\"Setează un subiect…\"],\"OkltoQ\":[\"Banează \",[\"username\"],\" după poreclă (împiedică reconectarea cu același nick)\"],\"P+t/Te\":[\"Nicio dată suplimentară\"],\"P42Wcc\":[\"Sigur\"],\"PD38l0\":[\"Previzualizare avatar canal\"],\"PD9mEt\":[\"Scrie un mesaj...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Deschide setările de configurare ale canalului\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Subiectul afișat pentru acest canal. Toți utilizatorii îl pot vedea.\"],\"PZCecv\":[\"Previzualizare PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 dată\"],\"few\":[[\"c\"],\" ori\"],\"other\":[[\"c\"],\" ori\"]}]],\"PguS2C\":[\"Adaugă mască de excepție (ex. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Se afișează \",[\"displayedChannelsCount\"],\" din \",[\"0\"],\" canale\"],\"PqhVlJ\":[\"Banează utilizator (după hostmask)\"],\"Q+chwU\":[\"Nume utilizator:\"],\"Q6hhn8\":[\"Preferințe\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Introduceți un nume de utilizator\"],\"QGqSZ2\":[\"Culoare și formatare\"],\"QJQd1J\":[\"Editați profilul\"],\"QSzGDE\":[\"Inactiv\"],\"QUlny5\":[\"Bine ai venit la \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Citește mai mult\"],\"QuSkCF\":[\"Filtrează canale...\"],\"QwUrDZ\":[\"a schimbat subiectul la: \",[\"topic\"]],\"R0UH07\":[\"Imaginea \",[\"0\"],\" din \",[\"1\"]],\"R7SsBE\":[\"Dezactivare sunet\"],\"R8rf1X\":[\"Click pentru a seta subiectul\"],\"RArB3D\":[\"a fost dat afară din \",[\"channelName\"],\" de \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Descoperă lumea IRC cu ObsidianIRC\"],\"RMMaN5\":[\"Moderat (+m)\"],\"RWw9Lg\":[\"Închide fereastra\"],\"RZ2BuZ\":[\"Înregistrarea contului \",[\"account\"],\" necesită verificare: \",[\"message\"]],\"RySp6q\":[\"Ascundeți comentariile\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Porecla este obligatorie\"],\"SPVjfj\":[\"Va fi implicit „niciun motiv\\\" dacă este lăsat gol\"],\"SQKPvQ\":[\"Invită utilizator\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Alegeți un profil de protecție flood predefinit. Aceste profiluri oferă setări de protecție echilibrate pentru diferite cazuri de utilizare.\"],\"Slr+3C\":[\"Utilizatori min.\"],\"Spnlre\":[\"L-ai invitat pe \",[\"target\"],\" să se alăture la \",[\"channel\"]],\"T/ckN5\":[\"Deschide în vizualizator\"],\"T91vKp\":[\"Redare\"],\"TV2Wdu\":[\"Aflați cum gestionăm datele dvs. și vă protejăm confidențialitatea.\"],\"TgFpwD\":[\"Se aplică...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Nicio modificare\"],\"TtserG\":[\"Introdu numele real\"],\"Ttz9J1\":[\"Introduceți parola...\"],\"Tz0i8g\":[\"Setări\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reacționează\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Salvați setările\"],\"UV5hLB\":[\"Nu s-au găsit banuri\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Mesaje de stare\"],\"Ue3uny\":[\"Implicit (fără profil)\"],\"UkARhe\":[\"Normal – Protecție standard\"],\"Umn7Cj\":[\"Niciun comentariu. Fiți primul!\"],\"UtUIRh\":[[\"0\"],\" mesaje mai vechi\"],\"UwzP+U\":[\"Conexiune securizată\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Proprietar de canal\"],\"V4qgxE\":[\"Creat înainte (min în urmă)\"],\"V8yTm6\":[\"Șterge căutarea\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - Aducând IRC în viitor\"],\"VJScHU\":[\"Motiv\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Dezactivează notificările\"],\"VbyRUy\":[\"Comentarii\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Setat de:\"],\"VqnIZz\":[\"Vezi politica noastră de confidențialitate și practicile privind datele\"],\"VrMygG\":[\"Lungimea minimă este \",[\"0\"]],\"VrnTui\":[\"Pronumele dvs., afișate în profil\"],\"W8E3qn\":[\"Cont autentificat\"],\"WAakm9\":[\"Șterge canal\"],\"WFxTHC\":[\"Adaugă mască de banare (ex. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Adresa serverului este obligatorie\"],\"WRYdXW\":[\"Poziție audio\"],\"WUOH5B\":[\"Ignoră utilizatorul\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Arată 1 element în plus\"],\"few\":[\"Arată \",[\"1\"],\" elemente în plus\"],\"other\":[\"Arată \",[\"1\"],\" de elemente în plus\"]}]],\"Weq9zb\":[\"General\"],\"Wfj7Sk\":[\"Activează sau dezactivează sunetele de notificare\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Profil utilizator\"],\"X6S3lt\":[\"Caută setări, canale, servere...\"],\"XEHan5\":[\"Continuă oricum\"],\"XI1+wb\":[\"Format invalid\"],\"XIXeuC\":[\"Mesaj @\",[\"0\"]],\"XMS+k4\":[\"Începe un mesaj privat\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Anulează fixarea conversației private\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Afișare\"],\"Xp2n93\":[\"Afișează media de pe gazda de fișiere de încredere a serverului. Nu se fac solicitări către servicii externe.\"],\"XvjC4F\":[\"Se salvează...\"],\"Y/qryO\":[\"Niciun utilizator găsit care să corespundă căutării\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Înregistrarea contului \",[\"account\"],\" a reușit: \",[\"message\"]],\"YEfzvP\":[\"Subiect protejat (+t)\"],\"YQOn6a\":[\"Restrânge lista de membri\"],\"YRCoE9\":[\"Operator de canal\"],\"YURQaF\":[\"Vizualizați profilul\"],\"YdBSvr\":[\"Controlează afișarea media și conținutul extern\"],\"Yj6U3V\":[\"Fără server central:\"],\"YjvpGx\":[\"Pronume\"],\"YqH4l4\":[\"Nicio cheie\"],\"YyUPpV\":[\"Cont:\"],\"ZJSWfw\":[\"Mesaj afișat la deconectarea de la server\"],\"ZR1dJ4\":[\"Invitații\"],\"ZdWg0V\":[\"Deschide în browser\"],\"ZhRBbl\":[\"Caută mesaje…\"],\"Zmcu3y\":[\"Filtre avansate\"],\"a2/8e5\":[\"Subiect setat după (min în urmă)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Pagina anterioară\"],\"aJTbXX\":[\"Parolă oper\"],\"aQryQv\":[\"Modelul există deja\"],\"aW9pLN\":[\"Numărul maxim de utilizatori permis în canal. Lăsați gol pentru fără limită.\"],\"ah4fmZ\":[\"Afișează și previzualizări de pe YouTube, Vimeo, SoundCloud și servicii similare cunoscute.\"],\"aifXak\":[\"Niciun fișier media în acest canal\"],\"ap2zBz\":[\"Relaxat\"],\"az8lvo\":[\"Oprit\"],\"azXSNo\":[\"Extinde lista de membri\"],\"azdliB\":[\"Conectează-te la un cont\"],\"b26wlF\":[\"ea/ei\"],\"bD/+Ei\":[\"Strict\"],\"bQ6BJn\":[\"Configurați reguli detaliate de protecție flood. Fiecare regulă specifică tipul de activitate de monitorizat și acțiunea de întreprins când pragurile sunt depășite.\"],\"beV7+y\":[\"Utilizatorul va primi o invitație să se alăture \",[\"channelName\"],\".\"],\"bk84cH\":[\"Mesaj de absență\"],\"bkHdLj\":[\"Adaugă server IRC\"],\"bmQLn5\":[\"Adaugă regulă\"],\"bwRvnp\":[\"Acțiune\"],\"c8+EVZ\":[\"Cont verificat\"],\"cGYUlD\":[\"Nu sunt încărcate previzualizări media.\"],\"cLF98o\":[\"Afișați comentariile (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Niciun utilizator disponibil\"],\"cSgpoS\":[\"Fixează conversația privată\"],\"cde3ce\":[\"Mesaj <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Copiați ieșirea formatată\"],\"cl/A5J\":[\"Bine ai venit la \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Șterge\"],\"coPLXT\":[\"Nu stocăm comunicațiile dvs. IRC pe serverele noastre\"],\"crYH/6\":[\"Player SoundCloud\"],\"d3sis4\":[\"Adaugă server\"],\"d9aN5k\":[\"Elimină \",[\"username\"],\" din canal\"],\"dEgA5A\":[\"Anulează\"],\"dGi1We\":[\"Anulează fixarea acestei conversații private\"],\"dJVuyC\":[\"a părăsit \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"către\"],\"dXqxlh\":[\"<0>⚠️ Risc de securitate!</0> Această conexiune poate fi vulnerabilă la interceptare sau atacuri de tip man-in-the-middle.\"],\"da9Q/R\":[\"Moduri canal modificate\"],\"dhJN3N\":[\"Afișați comentariile\"],\"dj2xTE\":[\"Respinge notificarea\"],\"dpCzmC\":[\"Setări de protecție flood\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Dorești să deschizi acest link într-o filă nouă?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Editează\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Încărcați o imagine sau furnizați o adresă URL cu înlocuire opțională \",[\"size\"]],\"edBbee\":[\"Banează \",[\"username\"],\" după hostmask (împiedică reconectarea de pe același IP/host)\"],\"ekfzWq\":[\"Setări utilizator\"],\"elPDWs\":[\"Personalizează experiența clientului IRC\"],\"eu2osY\":[\"<0>💡 Recomandare:</0> Continuați doar dacă aveți încredere în acest server și înțelegeți riscurile. Evitați să partajați informații sensibile sau parole prin această conexiune.\"],\"euEhbr\":[\"Faceți clic pentru a vă alătura \",[\"channel\"]],\"ez3vLd\":[\"Activați introducerea pe mai multe rânduri\"],\"f0J5Ki\":[\"Comunicarea server-la-server poate folosi conexiuni necriptate\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Avertizează utilizatorul\"],\"fDOLLd\":[\"Nu s-au găsit canale.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Analize anonime:\"],\"fq1GF9\":[\"Afișează când utilizatorii se deconectează de la server\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Acest server acceptă doar un tip de conexiune\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Listă de ignorați\"],\"gNzMrk\":[\"Avatar curent\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Introduceți porecla...\"],\"gz6UQ3\":[\"Maximizează\"],\"h6razj\":[\"Excludeți masca numelui canalului\"],\"hG6jnw\":[\"Niciun subiect setat\"],\"hG89Ed\":[\"Imagine\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"ex., 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Cantitate\"],\"hzdLuQ\":[\"Doar utilizatorii cu voice sau mai mult pot vorbi\"],\"i0qMbr\":[\"Acasă\"],\"iDNBZe\":[\"Notificări\"],\"iH8pgl\":[\"Înapoi\"],\"iL9SZg\":[\"Banează utilizator (după poreclă)\"],\"iNt+3c\":[\"Înapoi la imagine\"],\"iQvi+a\":[\"Nu mă avertiza despre securitatea scăzută a legăturilor pentru acest server\"],\"iSLIjg\":[\"Conectare\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Adresă server\"],\"idD8Ev\":[\"Salvat\"],\"iivqkW\":[\"Conectat la\"],\"ij+Elv\":[\"Previzualizare imagine\"],\"ilIWp7\":[\"Comută notificările\"],\"iuaqvB\":[\"Folosiți * pentru wildcard. Exemple: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Banare după mască gazdă\"],\"jA4uoI\":[\"Subiect:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Motiv (opțional)\"],\"jUV7CU\":[\"Încarcă avatar\"],\"jW5Uwh\":[\"Controlează câtă media externă se încarcă. Dezactivat / Sigur / Surse de încredere / Tot conținutul.\"],\"jXzms5\":[\"Opțiuni atașament\"],\"jZlrte\":[\"Culoare\"],\"jfC/xh\":[\"Contact\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Încarcă mesaje mai vechi\"],\"k3ID0F\":[\"Filtrează membri…\"],\"k65gsE\":[\"Detalii\"],\"k7Zgob\":[\"Anulează conexiunea\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Nu s-au găsit invitații\"],\"kCLEPU\":[\"Conectat la\"],\"kF5LKb\":[\"Modele ignorate:\"],\"kGeOx/\":[\"Alătură-te la \",[\"0\"]],\"kITKr8\":[\"Se încarcă modurile canalului...\"],\"kPpPsw\":[\"Ești un Operator IRC\"],\"kWJmRL\":[\"Tu\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Copiați JSON\"],\"krViRy\":[\"Clic pentru copiere ca JSON\"],\"ks71ra\":[\"Excepții\"],\"kw4lRv\":[\"Semi-operator de canal\"],\"kxgIRq\":[\"Selectează sau adaugă un canal pentru a începe.\"],\"ky6dWe\":[\"Previzualizare avatar\"],\"l+GxCv\":[\"Se încarcă canalele...\"],\"l+IUVW\":[\"Verificarea contului \",[\"account\"],\" a reușit: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"s-a reconectat\"],\"few\":[\"s-a reconectat de \",[\"reconnectCount\"],\" ori\"],\"other\":[\"s-a reconectat de \",[\"reconnectCount\"],\" ori\"]}]],\"l5jmzx\":[[\"0\"],\" și \",[\"1\"],\" scriu...\"],\"lHy8N5\":[\"Se încarcă mai multe canale...\"],\"lbpf14\":[\"Intrați în \",[\"value\"]],\"lfFsZ4\":[\"Canale\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Nume cont\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Încarcă imagine\"],\"loQxaJ\":[\"M-am întors\"],\"lvfaxv\":[\"ACASĂ\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Adaugă\"],\"m8flAk\":[\"Previzualizare (neîncărcat încă)\"],\"mEPxTp\":[\"<0>⚠️ Atenție!</0> Deschide numai linkuri din surse de încredere. Linkurile malițioase îți pot compromite securitatea sau confidențialitatea.\"],\"mHGdhG\":[\"Informații server\"],\"mHS8lb\":[\"Mesaj #\",[\"0\"]],\"mMYBD9\":[\"Larg – Domeniu de protecție mai amplu\"],\"mTGsPd\":[\"Subiect canal\"],\"mU8j6O\":[\"Fără mesaje externe (+n)\"],\"mZp8FL\":[\"Revenire automată la o singură linie\"],\"mdQu8G\":[\"NumeleTău\"],\"miSSBQ\":[\"Comentarii (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Utilizatorul este autentificat\"],\"mwtcGl\":[\"Închide comentariile\"],\"mzI/c+\":[\"Descarcă\"],\"n3fGRk\":[\"setat de \",[\"0\"]],\"nE9jsU\":[\"Relaxat – Protecție mai puțin agresivă\"],\"nNflMD\":[\"Părăsește canalul\"],\"nPXkBi\":[\"Se încarcă datele WHOIS...\"],\"nQnxxF\":[\"Mesaj #\",[\"0\"],\" (Shift+Enter pentru linie nouă)\"],\"nWMRxa\":[\"Anulează fixarea\"],\"nkC032\":[\"Niciun profil anti-flood\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Trimite un mesaj de avertizare către \",[\"username\"]],\"o9ylQi\":[\"Căutați GIF-uri pentru a începe\"],\"oFGkER\":[\"Notificări server\"],\"oOi11l\":[\"Derulează în jos\"],\"oQEzQR\":[\"Mesaj direct nou\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Conectat\"],\"oal760\":[\"Atacurile man-in-the-middle asupra legăturilor de server sunt posibile\"],\"oeqmmJ\":[\"Surse de încredere\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Implicit\"],\"p0Z69r\":[\"Modelul nu poate fi gol\"],\"p1KgtK\":[\"Eroare la încărcarea audio\"],\"p59pEv\":[\"Detalii suplimentare\"],\"p7sRI6\":[\"Anunțați ceilalți când scrieți\"],\"pBm1od\":[\"Canal secret\"],\"pNmiXx\":[\"Pseudonimul dvs. implicit pentru toate serverele\"],\"pUUo9G\":[\"Hostname:\"],\"pVGPmz\":[\"Parolă cont\"],\"peNE68\":[\"Permanent\"],\"plhHQt\":[\"Fără date\"],\"pm6+q5\":[\"Avertisment de securitate\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Informații suplimentare\"],\"q0cR4S\":[\"acum este cunoscut ca **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Canalul nu va apărea în comenzile LIST sau NAMES\"],\"qLpTm/\":[\"Elimină reacția \",[\"emoji\"]],\"qVkGWK\":[\"Fixează\"],\"qY8wNa\":[\"Pagină principală\"],\"qb0xJ7\":[\"Wildcard: * se potrivește oricărei secvențe, ? unui singur caracter. Exemple: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Cheie canal (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Nicio limită\"],\"r1W2AS\":[\"Imagine filehost\"],\"rIPR2O\":[\"Subiect setat înainte (min în urmă)\"],\"rMMSYo\":[\"Lungimea maximă este \",[\"0\"]],\"rWtzQe\":[\"Rețeaua s-a împărțit și s-a reconectat. ✅\"],\"rYG2u6\":[\"Vă rugăm așteptați...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Previzualizare\"],\"rjGI/Q\":[\"Confidențialitate\"],\"rk8iDX\":[\"Se încarcă GIF-urile...\"],\"rn6SBY\":[\"Activare sunet\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"A fost dat afară din canal\"],\"s8cATI\":[\"s-a alăturat la \",[\"channelName\"]],\"sCO9ue\":[\"Conexiunea la <0>\",[\"serverName\"],\"</0> prezintă următoarele probleme de securitate:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"acum este cunoscut ca **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" te-a invitat să te alături la \",[\"channel\"]],\"sby+1/\":[\"Faceți clic pentru a copia\"],\"sfN25C\":[\"Numele dvs. real sau complet\"],\"sliuzR\":[\"Deschide linkul\"],\"sqrO9R\":[\"Mențiuni personalizate\"],\"sr6RdJ\":[\"Multilinie cu Shift+Enter\"],\"swrCpB\":[\"Canalul a fost redenumit din \",[\"oldName\"],\" în \",[\"newName\"],\" de \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Avansat\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Elimină\"],\"t47eHD\":[\"Identificatorul dvs. unic pe acest server\"],\"tAkAh0\":[\"URL cu înlocuire opțională \",[\"size\"],\". Exemplu: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Afișează sau ascunde bara laterală cu lista de canale\"],\"tfDRzk\":[\"Salvează\"],\"tiBsJk\":[\"a părăsit \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"a ieșit (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Porecla {nick} este deja utilizată, reîncercare cu {newNick}\"],\"u0a8B4\":[\"Autentificați-vă ca operator IRC pentru acces administrativ\"],\"u0rWFU\":[\"Creat după (min în urmă)\"],\"u72w3t\":[\"Utilizatori și modele de ignorat\"],\"u7jc2L\":[\"a ieșit\"],\"uAQUqI\":[\"Stare\"],\"uB85T3\":[\"Salvare eșuată: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"Servere IRC:\"],\"usSSr/\":[\"Nivel de zoom\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Software:\"],\"vE8kb+\":[\"Shift+Enter pentru rânduri noi (Enter trimite)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Fără subiect\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Limbă\"],\"vaHYxN\":[\"Nume real\"],\"vhjbKr\":[\"Absent\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[\"client \",[\"title\"]],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Valoare invalidă\"],\"wFjjxZ\":[\"a fost dat afară din \",[\"channelName\"],\" de \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Nu s-au găsit excepții de banare\"],\"wPrGnM\":[\"Administrator de canal\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Afișează când utilizatorii intră sau ies din canale\"],\"whqZ9r\":[\"Cuvinte sau fraze suplimentare de evidențiat\"],\"wm7RV4\":[\"Sunet de notificare\"],\"wz/Yoq\":[\"Mesajele tale pot fi interceptate când sunt transmise între servere\"],\"xCJdfg\":[\"Șterge\"],\"xUHRTR\":[\"Autentificare automată ca operator la conectare\"],\"xWHwwQ\":[\"Banuri\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Sunt acceptate numai websocket-uri securizate\"],\"xdtXa+\":[\"nume-canal\"],\"xfXC7q\":[\"Canale text\"],\"xlCYOE\":[\"Se încarcă mai multe mesaje...\"],\"xlhswE\":[\"Valoarea minimă este \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Adaugă un cuvânt sau o expresie...\"],\"xuRqRq\":[\"Limită clienți (+l)\"],\"xwF+7J\":[[\"0\"],\" scrie...\"],\"yNeucF\":[\"Acest server nu acceptă metadate extinse de profil (extensia IRCv3 METADATA). Câmpurile precum avatar, nume afișat și stare nu sunt disponibile.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Avatar canal\"],\"yQE2r9\":[\"Se încarcă\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Nume utilizator operator\"],\"yYOzWD\":[\"jurnale\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"Parola operatorului IRC\"],\"ygCKqB\":[\"Oprește\"],\"ymDxJx\":[\"Numele de utilizator al operatorului IRC\"],\"yrpRsQ\":[\"Sortare după nume\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Închide\"],\"zJw+jA\":[\"setează modul: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Introdu numele de utilizator oper\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
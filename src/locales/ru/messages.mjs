@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Неверный формат шаблона. Используйте формат nick!user@host (допускаются маски *)\"],\"+6NQQA\":[\"Канал общей поддержки\"],\"+6NyRG\":[\"Клиент\"],\"+K0AvT\":[\"Отключиться\"],\"+cyFdH\":[\"Сообщение по умолчанию при переходе в режим отсутствия\"],\"+mVPqU\":[\"Отображать форматирование Markdown в сообщениях\"],\"+vqCJH\":[\"Имя пользователя вашего аккаунта для аутентификации\"],\"+yPBXI\":[\"Выбрать файл\"],\"+zy2Nq\":[\"Тип\"],\"/09cao\":[\"Низкий уровень безопасности соединения (уровень \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Пользователи вне канала не могут отправлять в него сообщения\"],\"/6BzZF\":[\"Показать/скрыть список участников\"],\"/TNOPk\":[\"Пользователь отсутствует\"],\"/XQgft\":[\"Обзор\"],\"/cF7Rs\":[\"Громкость\"],\"/dqduX\":[\"Следующая страница\"],\"/fc3q4\":[\"Весь контент\"],\"/kISDh\":[\"Включить звуки уведомлений\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Аудио\"],\"/rfkZe\":[\"Воспроизводить звуки для упоминаний и сообщений\"],\"0/0ZGA\":[\"Маска имени канала\"],\"0D6j7U\":[\"Подробнее о пользовательских правилах →\"],\"0XsHcR\":[\"Исключить пользователя\"],\"0ZpE//\":[\"Сортировать по пользователям\"],\"0bEPwz\":[\"Отметиться как отсутствующий\"],\"0dGkPt\":[\"Развернуть список каналов\"],\"0gS7M5\":[\"Отображаемое имя\"],\"0kS+M8\":[\"ПримерНЕТ\"],\"0rgoY7\":[\"Подключайтесь только к выбранным вами серверам\"],\"0wdd7X\":[\"Войти\"],\"0wkVYx\":[\"Личные сообщения\"],\"111uHX\":[\"Предпросмотр ссылки\"],\"196EG4\":[\"Удалить личную переписку\"],\"1DSr1i\":[\"Зарегистрировать аккаунт\"],\"1O/24y\":[\"Показать/скрыть список каналов\"],\"1VPJJ2\":[\"Предупреждение о внешней ссылке\"],\"1ZC/dv\":[\"Нет непрочитанных упоминаний или сообщений\"],\"1pO1zi\":[\"Необходимо указать имя сервера\"],\"1uwfzQ\":[\"Просмотреть тему канала\"],\"268g7c\":[\"Введите отображаемое имя\"],\"2FOFq1\":[\"Операторы серверов сети потенциально могут читать ваши сообщения\"],\"2FYpfJ\":[\"Ещё\"],\"2HF1Y2\":[[\"inviter\"],\" пригласил \",[\"target\"],\" присоединиться к \",[\"channel\"]],\"2I70QL\":[\"Просмотреть информацию профиля пользователя\"],\"2QYdmE\":[\"Пользователи:\"],\"2QpEjG\":[\"вышел\"],\"2YE223\":[\"Сообщение #\",[\"0\"],\" (Enter — новая строка, Shift+Enter — отправить)\"],\"2bimFY\":[\"Использовать пароль сервера\"],\"2iTmdZ\":[\"Локальное хранилище:\"],\"2odkwe\":[\"Строгий — более агрессивная защита\"],\"2uDhbA\":[\"Введите имя пользователя для приглашения\"],\"2ygf/L\":[\"← Назад\"],\"2zEgxj\":[\"Поиск GIF...\"],\"3RdPhl\":[\"Переименовать канал\"],\"3THokf\":[\"Пользователь с голосом\"],\"3TSz9S\":[\"Свернуть\"],\"3jBDvM\":[\"Отображаемое имя канала\"],\"3ryuFU\":[\"Необязательные отчёты о сбоях для улучшения приложения\"],\"3uBF/8\":[\"Закрыть просмотрщик\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Введите имя аккаунта...\"],\"4/Rr0R\":[\"Пригласить пользователя в текущий канал\"],\"4EZrJN\":[\"Правила\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Профиль флуда (+F)\"],\"4RZQRK\":[\"Чем ты занимаешься?\"],\"4hfTrB\":[\"Никнейм\"],\"4n99LO\":[\"Уже в \",[\"0\"]],\"4t6vMV\":[\"Автоматически переключаться на однострочный режим для коротких сообщений\"],\"4vsHmf\":[\"Время (мин)\"],\"5+INAX\":[\"Выделять сообщения, в которых упоминается ваш никнейм\"],\"5R5Pv/\":[\"Имя оператора\"],\"678PKt\":[\"Название сети\"],\"6Aih4U\":[\"Не в сети\"],\"6CO3WE\":[\"Пароль для входа в канал. Оставьте пустым, чтобы удалить ключ.\"],\"6HhMs3\":[\"Сообщение при выходе\"],\"6V3Ea3\":[\"Скопировано\"],\"6lGV3K\":[\"Свернуть\"],\"6yFOEi\":[\"Введите пароль опера...\"],\"7+IHTZ\":[\"Файл не выбран\"],\"73hrRi\":[\"nick!user@host (например: spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Отправить личное сообщение\"],\"7U1W7c\":[\"Очень мягкий\"],\"7Y1YQj\":[\"Имя:\"],\"7YHArF\":[\"— открыть в просмотрщике\"],\"7fjnVl\":[\"Поиск пользователей...\"],\"7jL88x\":[\"Удалить это сообщение? Это действие нельзя отменить.\"],\"7nGhhM\":[\"О чём вы думаете?\"],\"7sEpu1\":[\"Участники — \",[\"0\"]],\"7sNhEz\":[\"Имя пользователя\"],\"8H0Q+x\":[\"Подробнее о профилях →\"],\"8Phu0A\":[\"Показывать, когда пользователи меняют никнейм\"],\"8XTG9e\":[\"Введите пароль оператора\"],\"8XsV2J\":[\"Повторить отправку\"],\"8ZsakT\":[\"Пароль\"],\"8kR84m\":[\"Вы собираетесь открыть внешнюю ссылку:\"],\"8lCgih\":[\"Удалить правило\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"присоединился\"],\"few\":[\"присоединился \",[\"joinCount\"],\" раза\"],\"many\":[\"присоединился \",[\"joinCount\"],\" раз\"],\"other\":[\"присоединился \",[\"joinCount\"],\" раза\"]}]],\"9BMLnJ\":[\"Переподключиться к серверу\"],\"9OEgyT\":[\"Добавить реакцию\"],\"9PQ8m2\":[\"G-Line (глобальный бан)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Удалить шаблон\"],\"9bG48P\":[\"Отправка\"],\"9f5f0u\":[\"Вопросы о конфиденциальности? Свяжитесь с нами:\"],\"9unqs3\":[\"Отсутствие:\"],\"9v3hwv\":[\"Серверы не найдены.\"],\"9zb2WA\":[\"Подключение\"],\"A1taO8\":[\"Поиск\"],\"A2adVi\":[\"Отправлять уведомления о наборе текста\"],\"A9Rhec\":[\"Имя канала\"],\"AWOSPo\":[\"Увеличить\"],\"AXSpEQ\":[\"Войти как оператор при подключении\"],\"AeXO77\":[\"Аккаунт\"],\"AhNP40\":[\"Перемотка\"],\"Ai2U7L\":[\"Хост\"],\"AjBQnf\":[\"Изменил никнейм\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Отменить ответ\"],\"ApSx0O\":[\"Найдено \",[\"0\"],\" сообщений, соответствующих \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Результаты не найдены\"],\"AyNqAB\":[\"Отображать все события сервера в чате\"],\"B/QqGw\":[\"Отошёл от клавиатуры\"],\"B8AaMI\":[\"Это поле обязательно для заполнения\"],\"BA2c49\":[\"Сервер не поддерживает расширенную фильтрацию LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" и ещё \",[\"3\"],\" печатают...\"],\"BGul2A\":[\"У вас есть несохранённые изменения. Вы уверены, что хотите закрыть без сохранения?\"],\"BIf9fi\":[\"Ваше статусное сообщение\"],\"BZz3md\":[\"Ваш личный сайт\"],\"Bgm/H7\":[\"Разрешить ввод нескольких строк текста\"],\"BiQIl1\":[\"Закрепить эту личную переписку\"],\"BlNZZ2\":[\"Нажмите, чтобы перейти к сообщению\"],\"Bowq3c\":[\"Только операторы могут изменять тему канала\"],\"Btozzp\":[\"Срок действия этого изображения истёк\"],\"Bycfjm\":[\"Всего: \",[\"0\"]],\"C6IBQc\":[\"Копировать весь JSON\"],\"C9L9wL\":[\"Сбор данных\"],\"CDq4wC\":[\"Модерировать пользователя\"],\"CHVRxG\":[\"Сообщение @\",[\"0\"],\" (Shift+Enter — новая строка)\"],\"CN9zdR\":[\"Необходимо указать имя и пароль оператора\"],\"CW3sYa\":[\"Добавить реакцию \",[\"emoji\"]],\"CaAkqd\":[\"Показывать выходы\"],\"CbvaYj\":[\"Забанить по никнейму\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Выберите канал\"],\"CsekCi\":[\"Обычный\"],\"D+NlUC\":[\"Система\"],\"D28t6+\":[\"подключился и отключился\"],\"DB8zMK\":[\"Применить\"],\"DBcWHr\":[\"Пользовательский файл звука уведомления\"],\"DTy9Xw\":[\"Предпросмотр медиа\"],\"Dj4pSr\":[\"Выберите надёжный пароль\"],\"Du+zn+\":[\"Поиск...\"],\"Du2T2f\":[\"Настройка не найдена\"],\"DwsSVQ\":[\"Применить фильтры и обновить\"],\"E3W/zd\":[\"Никнейм по умолчанию\"],\"E6nRW7\":[\"Копировать URL\"],\"E703RG\":[\"Режимы:\"],\"EAeu1Z\":[\"Отправить приглашение\"],\"EFKJQT\":[\"Настройка\"],\"EGPQBv\":[\"Пользовательские правила флуда (+f)\"],\"ELik0r\":[\"Просмотреть полную политику конфиденциальности\"],\"EPbeC2\":[\"Просмотреть или изменить тему канала\"],\"EQCDNT\":[\"Введите имя пользователя опера...\"],\"EUvulZ\":[\"Найдено 1 сообщение, соответствующее \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Следующее изображение\"],\"EdQY6l\":[\"Нет\"],\"EnqLYU\":[\"Поиск серверов...\"],\"F0OKMc\":[\"Редактировать сервер\"],\"F6Int2\":[\"Включить выделения\"],\"FDoLyE\":[\"Макс. пользователей\"],\"FUU/hZ\":[\"Управляет количеством внешних медиафайлов, загружаемых в чат.\"],\"Fdp03t\":[\"вкл\"],\"FfPWR0\":[\"Диалог\"],\"FjkaiT\":[\"Уменьшить\"],\"FlqOE9\":[\"Что это означает:\"],\"FolHNl\":[\"Управление аккаунтом и аутентификацией\"],\"Fp2Dif\":[\"Покинул сервер\"],\"G5KmCc\":[\"GZ-Line (глобальная Z-Line)\"],\"GDs0lz\":[\"<0>Риск:</0> Конфиденциальная информация (сообщения, личные переписки, данные аутентификации) может быть раскрыта сетевым администраторам или злоумышленникам, находящимся между IRC-серверами.\"],\"GR+2I3\":[\"Добавить маску приглашения (например: nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Закрыть всплывающие уведомления сервера\"],\"GlHnXw\":[\"Смена ника не удалась: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Предпросмотр:\"],\"GtmO8/\":[\"от\"],\"GtuHUQ\":[\"Переименовать этот канал на сервере. Все пользователи увидят новое имя.\"],\"GuGfFX\":[\"Включить/выключить поиск\"],\"GxkJXS\":[\"Загрузка...\"],\"GzbwnK\":[\"Присоединился к каналу\"],\"GzsUDB\":[\"Расширенный профиль\"],\"H/PnT8\":[\"Вставить эмодзи\"],\"H6Izzl\":[\"Ваш предпочтительный цветовой код\"],\"H9jIv+\":[\"Показывать входы/выходы\"],\"HAKBY9\":[\"Загрузить файлы\"],\"HdE1If\":[\"Канал\"],\"Hk4AW9\":[\"Ваше предпочтительное отображаемое имя\"],\"HmHDk7\":[\"Выбрать участника\"],\"HrQzPU\":[\"Каналы на \",[\"networkName\"]],\"I2tXQ5\":[\"Сообщение @\",[\"0\"],\" (Enter — новая строка, Shift+Enter — отправить)\"],\"I6bw/h\":[\"Забанить пользователя\"],\"I92Z+b\":[\"Включить уведомления\"],\"I9D72S\":[\"Вы уверены, что хотите удалить это сообщение? Это действие нельзя отменить.\"],\"IA+1wo\":[\"Показывать, когда пользователей исключают из каналов\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Инфо:\"],\"IUwGEM\":[\"Сохранить изменения\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" и \",[\"2\"],\" печатают...\"],\"IgrLD/\":[\"Пауза\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Ответить\"],\"IoHMnl\":[\"Максимальное значение: \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Подключение...\"],\"J5T9NW\":[\"Информация о пользователе\"],\"J8Y5+z\":[\"Упс! Разрыв сети! ⚠️\"],\"JBHkBA\":[\"Покинул канал\"],\"JCwL0Q\":[\"Укажите причину (необязательно)\"],\"JFciKP\":[\"Переключить\"],\"JXGkhG\":[\"Изменить имя канала (только для операторов)\"],\"JcD7qf\":[\"Другие действия\"],\"JdkA+c\":[\"Секретный (+s)\"],\"Jmu12l\":[\"Каналы сервера\"],\"JvQ++s\":[\"Включить Markdown\"],\"K2jwh/\":[\"Данные WHOIS недоступны\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Удалить сообщение\"],\"KKBlUU\":[\"Встроить\"],\"KM0pLb\":[\"Добро пожаловать в канал!\"],\"KR6W2h\":[\"Перестать игнорировать пользователя\"],\"KV+Bi1\":[\"Только по приглашению (+i)\"],\"KdCtwE\":[\"Сколько секунд отслеживать флуд-активность до сброса счётчиков\"],\"Kkezga\":[\"Пароль сервера\"],\"KsiQ/8\":[\"Для входа в канал необходимо приглашение\"],\"L+gB/D\":[\"Информация о канале\"],\"LC1a7n\":[\"IRC-сервер сообщил о низком уровне безопасности межсерверных соединений. Это означает, что при передаче ваших сообщений между IRC-серверами сети они могут быть недостаточно зашифрованы или SSL/TLS-сертификаты могут не проверяться должным образом.\"],\"LNfLR5\":[\"Показывать исключения\"],\"LQb0W/\":[\"Показывать все события\"],\"LU7/yA\":[\"Альтернативное имя для отображения в интерфейсе. Может содержать пробелы, эмодзи и специальные символы. Настоящее имя канала (\",[\"channelName\"],\") по-прежнему будет использоваться для IRC-команд.\"],\"LUb9O7\":[\"Необходимо указать корректный порт сервера\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Политика конфиденциальности\"],\"LcuSDR\":[\"Управление информацией профиля и метаданными\"],\"LqLS9B\":[\"Показывать смену никнейма\"],\"LsDQt2\":[\"Настройки канала\"],\"LtI9AS\":[\"Владелец\"],\"LuNhhL\":[\"отреагировал на это сообщение\"],\"M/AZNG\":[\"URL вашего аватара\"],\"M/WIer\":[\"Отправить сообщение\"],\"M8er/5\":[\"Имя:\"],\"MHk+7g\":[\"Предыдущее изображение\"],\"MRorGe\":[\"Написать в личку\"],\"MVbSGP\":[\"Временное окно (секунды)\"],\"MkpcsT\":[\"Ваши сообщения и настройки хранятся локально на вашем устройстве\"],\"N/hDSy\":[\"Пометить как бота — обычно «on» или пусто\"],\"N7TQbE\":[\"Пригласить пользователя в \",[\"channelName\"]],\"NCca/o\":[\"Введите ник по умолчанию...\"],\"Nqs6B9\":[\"Показывает весь внешний медиаконтент. Любой URL может вызвать запрос к неизвестному серверу.\"],\"Nt+9O7\":[\"Использовать WebSocket вместо обычного TCP\"],\"NxIHzc\":[\"Отключить пользователя\"],\"O+v/cL\":[\"Просмотреть все каналы на сервере\"],\"ODwSCk\":[\"Отправить GIF\"],\"OGQ5kK\":[\"Настройка звуков уведомлений и выделений\"],\"OIPt1Z\":[\"Показать или скрыть боковую панель списка участников\"],\"OKSNq/\":[\"Очень строгий\"],\"ONWvwQ\":[\"Загрузить\"],\"OVKoQO\":[\"Пароль вашего аккаунта для аутентификации\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Перенося IRC в будущее\"],\"OhCpra\":[\"Задать тему…\"],\"OkltoQ\":[\"Забанить \",[\"username\"],\" по никнейму (запрещает переподключение с тем же ником)\"],\"P+t/Te\":[\"Нет дополнительных данных\"],\"P42Wcc\":[\"Безопасно\"],\"PD38l0\":[\"Предпросмотр аватара канала\"],\"PD9mEt\":[\"Введите сообщение...\"],\"PPqfdA\":[\"Открыть настройки конфигурации канала\"],\"PSCjfZ\":[\"Тема, которая будет отображаться для этого канала. Тему видят все пользователи.\"],\"PZCecv\":[\"Предпросмотр PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 раз\"],\"few\":[[\"c\"],\" раза\"],\"many\":[[\"c\"],\" раз\"],\"other\":[[\"c\"],\" раза\"]}]],\"PguS2C\":[\"Добавить маску исключения (например: nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Показано \",[\"displayedChannelsCount\"],\" из \",[\"0\"],\" каналов\"],\"PqhVlJ\":[\"Забанить пользователя (по hostmask)\"],\"Q+chwU\":[\"Имя пользователя:\"],\"Q6hhn8\":[\"Настройки\"],\"QF4a34\":[\"Введите имя пользователя\"],\"QGqSZ2\":[\"Цвет и форматирование\"],\"QJQd1J\":[\"Редактировать профиль\"],\"QSzGDE\":[\"Не активен\"],\"QUlny5\":[\"Добро пожаловать на \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Читать далее\"],\"QuSkCF\":[\"Фильтр каналов...\"],\"QwUrDZ\":[\"изменил тему на: \",[\"topic\"]],\"R0UH07\":[\"Изображение \",[\"0\"],\" из \",[\"1\"]],\"R7SsBE\":[\"Выкл. звук\"],\"R8rf1X\":[\"Нажмите, чтобы задать тему\"],\"RArB3D\":[\"был кикнут из \",[\"channelName\"],\" пользователем \",[\"username\"]],\"RI3cWd\":[\"Откройте мир IRC вместе с ObsidianIRC\"],\"RMMaN5\":[\"Модерируемый (+m)\"],\"RWw9Lg\":[\"Закрыть диалог\"],\"RZ2BuZ\":[\"Регистрация аккаунта \",[\"account\"],\" требует подтверждения: \",[\"message\"]],\"RySp6q\":[\"Скрыть комментарии\"],\"SPKQTd\":[\"Необходимо указать никнейм\"],\"SPVjfj\":[\"Если оставить пустым, будет использоваться «без причины»\"],\"SQKPvQ\":[\"Пригласить пользователя\"],\"SkZcl+\":[\"Выберите заранее заданный профиль защиты от флуда. Эти профили предоставляют сбалансированные настройки защиты для различных сценариев использования.\"],\"Slr+3C\":[\"Мин. пользователей\"],\"Spnlre\":[\"Вы пригласили \",[\"target\"],\" присоединиться к \",[\"channel\"]],\"T/ckN5\":[\"Открыть в просмотрщике\"],\"T91vKp\":[\"Воспроизвести\"],\"TV2Wdu\":[\"Узнайте, как мы обрабатываем ваши данные и защищаем вашу конфиденциальность.\"],\"TgFpwD\":[\"Применяется...\"],\"TkzSFB\":[\"Нет изменений\"],\"TtserG\":[\"Введите настоящее имя\"],\"Ttz9J1\":[\"Введите пароль...\"],\"Tz0i8g\":[\"Настройки\"],\"U3pytU\":[\"Администратор\"],\"UDb2YD\":[\"Реакция\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Сохранить настройки\"],\"UV5hLB\":[\"Баны не найдены\"],\"Uaj3Nd\":[\"Статусные сообщения\"],\"Ue3uny\":[\"По умолчанию (без профиля)\"],\"UkARhe\":[\"Обычный — стандартная защита\"],\"Umn7Cj\":[\"Комментариев пока нет. Будьте первым!\"],\"UtUIRh\":[[\"0\"],\" старых сообщений\"],\"UwzP+U\":[\"Защищённое соединение\"],\"V0/A4O\":[\"Владелец канала\"],\"V4qgxE\":[\"Создан до (мин назад)\"],\"V8yTm6\":[\"Очистить поиск\"],\"VJMMyz\":[\"ObsidianIRC — IRC будущего\"],\"VJScHU\":[\"Причина\"],\"VLsmVV\":[\"Отключить уведомления\"],\"VbyRUy\":[\"Комментарии\"],\"Vmx0mQ\":[\"Установлено:\"],\"VqnIZz\":[\"Ознакомьтесь с нашей политикой конфиденциальности и практикой обработки данных\"],\"VrMygG\":[\"Минимальная длина: \",[\"0\"]],\"VrnTui\":[\"Ваши местоимения, отображаемые в профиле\"],\"W8E3qn\":[\"Аутентифицированный аккаунт\"],\"WAakm9\":[\"Удалить канал\"],\"WFxTHC\":[\"Добавить маску бана (например: nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Необходимо указать хост сервера\"],\"WRYdXW\":[\"Позиция в аудио\"],\"WUOH5B\":[\"Игнорировать пользователя\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Показать ещё 1 элемент\"],\"few\":[\"Показать ещё \",[\"1\"],\" элемента\"],\"many\":[\"Показать ещё \",[\"1\"],\" элементов\"],\"other\":[\"Показать ещё \",[\"1\"],\" элемента\"]}]],\"Weq9zb\":[\"Основное\"],\"Wfj7Sk\":[\"Включить или отключить звуки уведомлений\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Профиль пользователя\"],\"X6S3lt\":[\"Поиск настроек, каналов, серверов...\"],\"XEHan5\":[\"Всё равно продолжить\"],\"XI1+wb\":[\"Неверный формат\"],\"XIXeuC\":[\"Сообщение @\",[\"0\"]],\"XMS+k4\":[\"Начать личный чат\"],\"XWgxXq\":[\"Альбом\"],\"Xd7+IT\":[\"Открепить личный чат\"],\"Xm/s+u\":[\"Отображение\"],\"Xp2n93\":[\"Показывает медиафайлы с доверенного файлового хоста вашего сервера. Запросы к внешним сервисам не выполняются.\"],\"XvjC4F\":[\"Сохранение...\"],\"Y/qryO\":[\"Пользователи по вашему запросу не найдены\"],\"YAqRpI\":[\"Регистрация аккаунта \",[\"account\"],\" успешна: \",[\"message\"]],\"YEfzvP\":[\"Защищённая тема (+t)\"],\"YQOn6a\":[\"Свернуть список участников\"],\"YRCoE9\":[\"Оператор канала\"],\"YURQaF\":[\"Просмотреть профиль\"],\"YdBSvr\":[\"Управление отображением медиа и внешнего контента\"],\"Yj6U3V\":[\"Нет центрального сервера:\"],\"YjvpGx\":[\"Местоимения\"],\"YqH4l4\":[\"Без ключа\"],\"YyUPpV\":[\"Аккаунт:\"],\"ZJSWfw\":[\"Сообщение, отображаемое при отключении от сервера\"],\"ZR1dJ4\":[\"Приглашения\"],\"ZdWg0V\":[\"Открыть в браузере\"],\"ZhRBbl\":[\"Поиск сообщений…\"],\"Zmcu3y\":[\"Расширенные фильтры\"],\"a2/8e5\":[\"Тема установлена после (мин назад)\"],\"aHKcKc\":[\"Предыдущая страница\"],\"aJTbXX\":[\"Пароль оператора\"],\"aQryQv\":[\"Такой шаблон уже существует\"],\"aW9pLN\":[\"Максимальное количество пользователей в канале. Оставьте пустым для снятия ограничения.\"],\"ah4fmZ\":[\"Также показывает превью с YouTube, Vimeo, SoundCloud и других известных сервисов.\"],\"aifXak\":[\"В этом канале нет медиафайлов\"],\"ap2zBz\":[\"Мягкий\"],\"az8lvo\":[\"Выкл.\"],\"azXSNo\":[\"Развернуть список участников\"],\"azdliB\":[\"Войти в аккаунт\"],\"b26wlF\":[\"она/её\"],\"bD/+Ei\":[\"Строгий\"],\"bQ6BJn\":[\"Настройте подробные правила защиты от флуда. Каждое правило задаёт тип активности для мониторинга и действие при превышении порога.\"],\"beV7+y\":[\"Пользователь получит приглашение вступить в \",[\"channelName\"],\".\"],\"bk84cH\":[\"Сообщение об отсутствии\"],\"bkHdLj\":[\"Добавить IRC-сервер\"],\"bmQLn5\":[\"Добавить правило\"],\"bwRvnp\":[\"Действие\"],\"c8+EVZ\":[\"Верифицированный аккаунт\"],\"cGYUlD\":[\"Предпросмотр медиа не загружается.\"],\"cLF98o\":[\"Показать комментарии (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Нет доступных пользователей\"],\"cSgpoS\":[\"Закрепить личный чат\"],\"cde3ce\":[\"Написать <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Копировать форматированный вывод\"],\"cl/A5J\":[\"Добро пожаловать на \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Удалить\"],\"coPLXT\":[\"Мы не храним ваши IRC-переписки на наших серверах\"],\"crYH/6\":[\"Плеер SoundCloud\"],\"d3sis4\":[\"Добавить сервер\"],\"d9aN5k\":[\"Удалить \",[\"username\"],\" из канала\"],\"dEgA5A\":[\"Отмена\"],\"dGi1We\":[\"Открепить эту личную переписку\"],\"dJVuyC\":[\"покинул \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"кому\"],\"dXqxlh\":[\"<0>⚠️ Угроза безопасности!</0> Это соединение может быть уязвимо для перехвата или атак типа «человек посередине».\"],\"da9Q/R\":[\"Изменил режимы канала\"],\"dhJN3N\":[\"Показать комментарии\"],\"dj2xTE\":[\"Закрыть уведомление\"],\"dpCzmC\":[\"Настройки защиты от флуда\"],\"e9dQpT\":[\"Открыть эту ссылку в новой вкладке?\"],\"ePK91l\":[\"Изменить\"],\"eYBDuB\":[\"Загрузите изображение или укажите URL с необязательной подстановкой \",[\"size\"],\" для динамического масштабирования\"],\"edBbee\":[\"Забанить \",[\"username\"],\" по hostmask (запрещает переподключение с того же IP/хоста)\"],\"ekfzWq\":[\"Настройки пользователя\"],\"elPDWs\":[\"Настройте IRC-клиент под себя\"],\"eu2osY\":[\"<0>💡 Рекомендация:</0> Продолжайте только если вы доверяете этому серверу и понимаете риски. Избегайте передачи конфиденциальной информации или паролей через это соединение.\"],\"euEhbr\":[\"Нажмите, чтобы войти в \",[\"channel\"]],\"ez3vLd\":[\"Включить многострочный ввод\"],\"f0J5Ki\":[\"Межсерверное взаимодействие может использовать незашифрованные соединения\"],\"f9BHJk\":[\"Предупредить пользователя\"],\"fDOLLd\":[\"Каналы не найдены.\"],\"ffzDkB\":[\"Анонимная аналитика:\"],\"fq1GF9\":[\"Показывать, когда пользователи отключаются от сервера\"],\"gEF57C\":[\"Этот сервер поддерживает только один тип подключения\"],\"gJuLUI\":[\"Список игнорирования\"],\"gNzMrk\":[\"Текущий аватар\"],\"gjPWyO\":[\"Введите ник...\"],\"gz6UQ3\":[\"Развернуть\"],\"h6razj\":[\"Исключить маску имени канала\"],\"hG6jnw\":[\"Тема не задана\"],\"hG89Ed\":[\"Изображение\"],\"hZ6znB\":[\"Порт\"],\"ha+Bz5\":[\"например: 100:1440\"],\"hehnjM\":[\"Количество\"],\"hzdLuQ\":[\"Говорить могут только пользователи с голосом или выше\"],\"i0qMbr\":[\"Главная\"],\"iDNBZe\":[\"Уведомления\"],\"iH8pgl\":[\"Назад\"],\"iL9SZg\":[\"Забанить пользователя (по никнейму)\"],\"iNt+3c\":[\"Вернуться к изображению\"],\"iQvi+a\":[\"Не предупреждать меня о низком уровне безопасности соединений для этого сервера\"],\"iSLIjg\":[\"Подключиться\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Хост сервера\"],\"idD8Ev\":[\"Сохранено\"],\"iivqkW\":[\"В сети с\"],\"ij+Elv\":[\"Предпросмотр изображения\"],\"ilIWp7\":[\"Включить/выключить уведомления\"],\"iuaqvB\":[\"Используйте * в качестве маски. Примеры: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Бот\"],\"j2DGR0\":[\"Забанить по hostmask\"],\"jA4uoI\":[\"Тема:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Причина (необязательно)\"],\"jUV7CU\":[\"Загрузить аватар\"],\"jW5Uwh\":[\"Управление загрузкой внешних медиафайлов. Выкл / Безопасно / Доверенные источники / Весь контент.\"],\"jXzms5\":[\"Параметры вложения\"],\"jZlrte\":[\"Цвет\"],\"jfC/xh\":[\"Контакт\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Загрузить старые сообщения\"],\"k3ID0F\":[\"Фильтр участников…\"],\"k65gsE\":[\"Подробнее\"],\"k7Zgob\":[\"Отменить подключение\"],\"kAVx5h\":[\"Приглашения не найдены\"],\"kCLEPU\":[\"Подключён к\"],\"kF5LKb\":[\"Игнорируемые шаблоны:\"],\"kGeOx/\":[\"Присоединиться к \",[\"0\"]],\"kITKr8\":[\"Загрузка режимов канала...\"],\"kPpPsw\":[\"Вы являетесь IRC-оператором\"],\"kWJmRL\":[\"ты\"],\"kfcRb0\":[\"Аватар\"],\"kjMqSj\":[\"Копировать JSON\"],\"krViRy\":[\"Нажмите для копирования как JSON\"],\"ks71ra\":[\"Исключения\"],\"kw4lRv\":[\"Полуоператор канала\"],\"kxgIRq\":[\"Выберите или добавьте канал для начала.\"],\"ky6dWe\":[\"Предпросмотр аватара\"],\"l+GxCv\":[\"Загрузка каналов...\"],\"l+IUVW\":[\"Верификация аккаунта \",[\"account\"],\" успешна: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"переподключился\"],\"few\":[\"переподключился \",[\"reconnectCount\"],\" раза\"],\"many\":[\"переподключился \",[\"reconnectCount\"],\" раз\"],\"other\":[\"переподключился \",[\"reconnectCount\"],\" раза\"]}]],\"l5jmzx\":[[\"0\"],\" и \",[\"1\"],\" печатают...\"],\"lHy8N5\":[\"Загрузка дополнительных каналов...\"],\"lbpf14\":[\"Войти в \",[\"value\"]],\"lfFsZ4\":[\"Каналы\"],\"lkNdiH\":[\"Имя аккаунта\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Загрузить изображение\"],\"loQxaJ\":[\"Я вернулся\"],\"lvfaxv\":[\"ГЛАВНАЯ\"],\"m16xKo\":[\"Добавить\"],\"m8flAk\":[\"Предпросмотр (ещё не загружено)\"],\"mEPxTp\":[\"<0>⚠️ Будьте осторожны!</0> Открывайте ссылки только из доверенных источников. Вредоносные ссылки могут угрожать вашей безопасности или конфиденциальности.\"],\"mHGdhG\":[\"Информация о сервере\"],\"mHS8lb\":[\"Сообщение #\",[\"0\"]],\"mMYBD9\":[\"Широкий — более широкая область защиты\"],\"mTGsPd\":[\"Тема канала\"],\"mU8j6O\":[\"Без внешних сообщений (+n)\"],\"mZp8FL\":[\"Автоматический возврат к однострочному режиму\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"Комментарии (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Пользователь аутентифицирован\"],\"mwtcGl\":[\"Закрыть комментарии\"],\"mzI/c+\":[\"Скачать\"],\"n3fGRk\":[\"установил \",[\"0\"]],\"nE9jsU\":[\"Мягкий — менее строгая защита\"],\"nNflMD\":[\"Покинуть канал\"],\"nPXkBi\":[\"Загрузка данных WHOIS...\"],\"nQnxxF\":[\"Сообщение #\",[\"0\"],\" (Shift+Enter — новая строка)\"],\"nWMRxa\":[\"Открепить\"],\"nkC032\":[\"Без профиля флуда\"],\"o69z4d\":[\"Отправить предупреждение пользователю \",[\"username\"]],\"o9ylQi\":[\"Найдите GIF для начала\"],\"oFGkER\":[\"Уведомления сервера\"],\"oOi11l\":[\"Прокрутить вниз\"],\"oQEzQR\":[\"Новое DM\"],\"oXOSPE\":[\"В сети\"],\"oal760\":[\"Возможны атаки типа «человек посередине» на межсерверные соединения\"],\"oeqmmJ\":[\"Доверенные источники\"],\"ovBPCi\":[\"По умолчанию\"],\"p0Z69r\":[\"Шаблон не может быть пустым\"],\"p1KgtK\":[\"Не удалось загрузить аудио\"],\"p59pEv\":[\"Подробности\"],\"p7sRI6\":[\"Сообщать другим, когда вы печатаете\"],\"pBm1od\":[\"Секретный канал\"],\"pNmiXx\":[\"Ваш никнейм по умолчанию для всех серверов\"],\"pUUo9G\":[\"Хост:\"],\"pVGPmz\":[\"Пароль аккаунта\"],\"peNE68\":[\"Навсегда\"],\"plhHQt\":[\"Нет данных\"],\"pm6+q5\":[\"Предупреждение безопасности\"],\"pn5qSs\":[\"Дополнительная информация\"],\"q0cR4S\":[\"теперь известен как **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Канал не будет отображаться в командах LIST и NAMES\"],\"qLpTm/\":[\"Убрать реакцию \",[\"emoji\"]],\"qVkGWK\":[\"Закрепить\"],\"qY8wNa\":[\"Сайт\"],\"qb0xJ7\":[\"Используйте маски: * соответствует любой последовательности, ? — любому одному символу. Примеры: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Ключ канала (+k)\"],\"qtoOYG\":[\"Без ограничений\"],\"r1W2AS\":[\"Изображение с файлового хостинга\"],\"rIPR2O\":[\"Тема установлена до (мин назад)\"],\"rMMSYo\":[\"Максимальная длина: \",[\"0\"]],\"rWtzQe\":[\"Сеть разделилась и воссоединилась. ✅\"],\"rYG2u6\":[\"Пожалуйста, подождите...\"],\"rdUucN\":[\"Предпросмотр\"],\"rjGI/Q\":[\"Конфиденциальность\"],\"rk8iDX\":[\"Загрузка GIF...\"],\"rn6SBY\":[\"Вкл. звук\"],\"s/UKqq\":[\"Был исключён из канала\"],\"s8cATI\":[\"присоединился к \",[\"channelName\"]],\"sCO9ue\":[\"Соединение с <0>\",[\"serverName\"],\"</0> имеет следующие проблемы безопасности:\"],\"sGH11W\":[\"Сервер\"],\"sHI1H+\":[\"теперь известен как **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" пригласил вас присоединиться к \",[\"channel\"]],\"sby+1/\":[\"Нажмите, чтобы скопировать\"],\"sfN25C\":[\"Ваше настоящее или полное имя\"],\"sliuzR\":[\"Открыть ссылку\"],\"sqrO9R\":[\"Пользовательские упоминания\"],\"sr6RdJ\":[\"Многострочный режим по Shift+Enter\"],\"swrCpB\":[\"Канал был переименован с \",[\"oldName\"],\" на \",[\"newName\"],\" пользователем \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Дополнительно\"],\"t/YqKh\":[\"Удалить\"],\"t47eHD\":[\"Ваш уникальный идентификатор на этом сервере\"],\"tAkAh0\":[\"URL с необязательной подстановкой \",[\"size\"],\" для динамического масштабирования. Пример: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Показать или скрыть боковую панель списка каналов\"],\"tfDRzk\":[\"Сохранить\"],\"tiBsJk\":[\"покинул \",[\"channelName\"]],\"tt4/UD\":[\"вышел (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Ник {nick} уже используется, повторная попытка с {newNick}\"],\"u0a8B4\":[\"Аутентифицироваться как IRC-оператор для административного доступа\"],\"u0rWFU\":[\"Создан после (мин назад)\"],\"u72w3t\":[\"Пользователи и шаблоны для игнорирования\"],\"u7jc2L\":[\"вышел\"],\"uAQUqI\":[\"Статус\"],\"uB85T3\":[\"Ошибка сохранения: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-серверы:\"],\"usSSr/\":[\"Масштаб\"],\"v7uvcf\":[\"Программа:\"],\"vE8kb+\":[\"Shift+Enter для новой строки (Enter отправляет)\"],\"vERlcd\":[\"Профиль\"],\"vK0RL8\":[\"Без темы\"],\"vSJd18\":[\"Видео\"],\"vXIe7J\":[\"Язык\"],\"vaHYxN\":[\"Настоящее имя\"],\"vhjbKr\":[\"Отсутствую\"],\"w4NYox\":[\"клиент \",[\"title\"]],\"w8xQRx\":[\"Неверное значение\"],\"wFjjxZ\":[\"был кикнут из \",[\"channelName\"],\" пользователем \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Исключения из банов не найдены\"],\"wPrGnM\":[\"Администратор канала\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Показывать, когда пользователи входят в каналы или покидают их\"],\"whqZ9r\":[\"Дополнительные слова или фразы для выделения\"],\"wm7RV4\":[\"Звук уведомления\"],\"wz/Yoq\":[\"Ваши сообщения могут быть перехвачены при передаче между серверами\"],\"xCJdfg\":[\"Очистить\"],\"xUHRTR\":[\"Автоматически аутентифицироваться как оператор при подключении\"],\"xWHwwQ\":[\"Баны\"],\"xYilR2\":[\"Медиа\"],\"xceQrO\":[\"Поддерживаются только защищённые WebSocket-соединения\"],\"xdtXa+\":[\"имя-канала\"],\"xfXC7q\":[\"Текстовые каналы\"],\"xlCYOE\":[\"Загрузка сообщений...\"],\"xlhswE\":[\"Минимальное значение: \",[\"0\"]],\"xq97Ci\":[\"Добавить слово или фразу...\"],\"xuRqRq\":[\"Лимит пользователей (+l)\"],\"xwF+7J\":[[\"0\"],\" печатает...\"],\"yNeucF\":[\"Этот сервер не поддерживает расширенные метаданные профиля (расширение IRCv3 METADATA). Дополнительные поля, такие как аватар, отображаемое имя и статус, недоступны.\"],\"yPlrca\":[\"Аватар канала\"],\"yQE2r9\":[\"Загрузка\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Имя пользователя оператора\"],\"yYOzWD\":[\"логи\"],\"yfx9Re\":[\"Пароль IRC-оператора\"],\"ygCKqB\":[\"Стоп\"],\"ymDxJx\":[\"Имя пользователя IRC-оператора\"],\"yrpRsQ\":[\"Сортировать по имени\"],\"yz7wBu\":[\"Закрыть\"],\"zJw+jA\":[\"устанавливает режим: \",[\"0\"]],\"zebeLu\":[\"Введите имя пользователя оператора\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Неверный формат шаблона. Используйте формат nick!user@host (допускаются маски *)\"],\"+6NQQA\":[\"Канал общей поддержки\"],\"+6NyRG\":[\"Клиент\"],\"+K0AvT\":[\"Отключиться\"],\"+cyFdH\":[\"Сообщение по умолчанию при переходе в режим отсутствия\"],\"+mVPqU\":[\"Отображать форматирование Markdown в сообщениях\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Имя пользователя вашего аккаунта для аутентификации\"],\"+yPBXI\":[\"Выбрать файл\"],\"+zy2Nq\":[\"Тип\"],\"/09cao\":[\"Низкий уровень безопасности соединения (уровень \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Пользователи вне канала не могут отправлять в него сообщения\"],\"/6BzZF\":[\"Показать/скрыть список участников\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Пользователь отсутствует\"],\"/XQgft\":[\"Обзор\"],\"/cF7Rs\":[\"Громкость\"],\"/dqduX\":[\"Следующая страница\"],\"/fc3q4\":[\"Весь контент\"],\"/kISDh\":[\"Включить звуки уведомлений\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Аудио\"],\"/rfkZe\":[\"Воспроизводить звуки для упоминаний и сообщений\"],\"0/0ZGA\":[\"Маска имени канала\"],\"0D6j7U\":[\"Подробнее о пользовательских правилах →\"],\"0XsHcR\":[\"Исключить пользователя\"],\"0ZpE//\":[\"Сортировать по пользователям\"],\"0bEPwz\":[\"Отметиться как отсутствующий\"],\"0dGkPt\":[\"Развернуть список каналов\"],\"0gS7M5\":[\"Отображаемое имя\"],\"0kS+M8\":[\"ПримерНЕТ\"],\"0rgoY7\":[\"Подключайтесь только к выбранным вами серверам\"],\"0wdd7X\":[\"Войти\"],\"0wkVYx\":[\"Личные сообщения\"],\"111uHX\":[\"Предпросмотр ссылки\"],\"196EG4\":[\"Удалить личную переписку\"],\"1DSr1i\":[\"Зарегистрировать аккаунт\"],\"1O/24y\":[\"Показать/скрыть список каналов\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Предупреждение о внешней ссылке\"],\"1ZC/dv\":[\"Нет непрочитанных упоминаний или сообщений\"],\"1pO1zi\":[\"Необходимо указать имя сервера\"],\"1uwfzQ\":[\"Просмотреть тему канала\"],\"268g7c\":[\"Введите отображаемое имя\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Операторы серверов сети потенциально могут читать ваши сообщения\"],\"2FYpfJ\":[\"Ещё\"],\"2HF1Y2\":[[\"inviter\"],\" пригласил \",[\"target\"],\" присоединиться к \",[\"channel\"]],\"2I70QL\":[\"Просмотреть информацию профиля пользователя\"],\"2QYdmE\":[\"Пользователи:\"],\"2QpEjG\":[\"вышел\"],\"2YE223\":[\"Сообщение #\",[\"0\"],\" (Enter — новая строка, Shift+Enter — отправить)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Использовать пароль сервера\"],\"2iTmdZ\":[\"Локальное хранилище:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Строгий — более агрессивная защита\"],\"2uDhbA\":[\"Введите имя пользователя для приглашения\"],\"2ygf/L\":[\"← Назад\"],\"2zEgxj\":[\"Поиск GIF...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Переименовать канал\"],\"3THokf\":[\"Пользователь с голосом\"],\"3TSz9S\":[\"Свернуть\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Отображаемое имя канала\"],\"3ryuFU\":[\"Необязательные отчёты о сбоях для улучшения приложения\"],\"3uBF/8\":[\"Закрыть просмотрщик\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Введите имя аккаунта...\"],\"4/Rr0R\":[\"Пригласить пользователя в текущий канал\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Правила\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Профиль флуда (+F)\"],\"4RZQRK\":[\"Чем ты занимаешься?\"],\"4hfTrB\":[\"Никнейм\"],\"4n99LO\":[\"Уже в \",[\"0\"]],\"4t6vMV\":[\"Автоматически переключаться на однострочный режим для коротких сообщений\"],\"4vsHmf\":[\"Время (мин)\"],\"5+INAX\":[\"Выделять сообщения, в которых упоминается ваш никнейм\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Имя оператора\"],\"678PKt\":[\"Название сети\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Не в сети\"],\"6CO3WE\":[\"Пароль для входа в канал. Оставьте пустым, чтобы удалить ключ.\"],\"6HhMs3\":[\"Сообщение при выходе\"],\"6V3Ea3\":[\"Скопировано\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Свернуть\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Введите пароль опера...\"],\"7+IHTZ\":[\"Файл не выбран\"],\"73hrRi\":[\"nick!user@host (например: spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Отправить личное сообщение\"],\"7U1W7c\":[\"Очень мягкий\"],\"7Y1YQj\":[\"Имя:\"],\"7YHArF\":[\"— открыть в просмотрщике\"],\"7fjnVl\":[\"Поиск пользователей...\"],\"7jL88x\":[\"Удалить это сообщение? Это действие нельзя отменить.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"О чём вы думаете?\"],\"7sEpu1\":[\"Участники — \",[\"0\"]],\"7sNhEz\":[\"Имя пользователя\"],\"8H0Q+x\":[\"Подробнее о профилях →\"],\"8Phu0A\":[\"Показывать, когда пользователи меняют никнейм\"],\"8XTG9e\":[\"Введите пароль оператора\"],\"8XsV2J\":[\"Повторить отправку\"],\"8ZsakT\":[\"Пароль\"],\"8kR84m\":[\"Вы собираетесь открыть внешнюю ссылку:\"],\"8lCgih\":[\"Удалить правило\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"присоединился\"],\"few\":[\"присоединился \",[\"joinCount\"],\" раза\"],\"many\":[\"присоединился \",[\"joinCount\"],\" раз\"],\"other\":[\"присоединился \",[\"joinCount\"],\" раза\"]}]],\"9BMLnJ\":[\"Переподключиться к серверу\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Добавить реакцию\"],\"9PQ8m2\":[\"G-Line (глобальный бан)\"],\"9Qs99X\":[\"Email:\"],\"9QupBP\":[\"Удалить шаблон\"],\"9bG48P\":[\"Отправка\"],\"9f5f0u\":[\"Вопросы о конфиденциальности? Свяжитесь с нами:\"],\"9unqs3\":[\"Отсутствие:\"],\"9v3hwv\":[\"Серверы не найдены.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Подключение\"],\"A1taO8\":[\"Поиск\"],\"A2adVi\":[\"Отправлять уведомления о наборе текста\"],\"A9Rhec\":[\"Имя канала\"],\"AWOSPo\":[\"Увеличить\"],\"AXSpEQ\":[\"Войти как оператор при подключении\"],\"AeXO77\":[\"Аккаунт\"],\"AhNP40\":[\"Перемотка\"],\"Ai2U7L\":[\"Хост\"],\"AjBQnf\":[\"Изменил никнейм\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Отменить ответ\"],\"ApSx0O\":[\"Найдено \",[\"0\"],\" сообщений, соответствующих \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Результаты не найдены\"],\"AyNqAB\":[\"Отображать все события сервера в чате\"],\"B/QqGw\":[\"Отошёл от клавиатуры\"],\"B8AaMI\":[\"Это поле обязательно для заполнения\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Сервер не поддерживает расширенную фильтрацию LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" и ещё \",[\"3\"],\" печатают...\"],\"BGul2A\":[\"У вас есть несохранённые изменения. Вы уверены, что хотите закрыть без сохранения?\"],\"BIf9fi\":[\"Ваше статусное сообщение\"],\"BZz3md\":[\"Ваш личный сайт\"],\"Bgm/H7\":[\"Разрешить ввод нескольких строк текста\"],\"BiQIl1\":[\"Закрепить эту личную переписку\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Нажмите, чтобы перейти к сообщению\"],\"Bowq3c\":[\"Только операторы могут изменять тему канала\"],\"Btozzp\":[\"Срок действия этого изображения истёк\"],\"Bycfjm\":[\"Всего: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Копировать весь JSON\"],\"C9L9wL\":[\"Сбор данных\"],\"CDq4wC\":[\"Модерировать пользователя\"],\"CHVRxG\":[\"Сообщение @\",[\"0\"],\" (Shift+Enter — новая строка)\"],\"CN9zdR\":[\"Необходимо указать имя и пароль оператора\"],\"CW3sYa\":[\"Добавить реакцию \",[\"emoji\"]],\"CaAkqd\":[\"Показывать выходы\"],\"CbvaYj\":[\"Забанить по никнейму\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Выберите канал\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Обычный\"],\"D+NlUC\":[\"Система\"],\"D28t6+\":[\"подключился и отключился\"],\"DB8zMK\":[\"Применить\"],\"DBcWHr\":[\"Пользовательский файл звука уведомления\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Предпросмотр медиа\"],\"Dj4pSr\":[\"Выберите надёжный пароль\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Поиск...\"],\"Du2T2f\":[\"Настройка не найдена\"],\"DwsSVQ\":[\"Применить фильтры и обновить\"],\"E3W/zd\":[\"Никнейм по умолчанию\"],\"E6nRW7\":[\"Копировать URL\"],\"E703RG\":[\"Режимы:\"],\"EAeu1Z\":[\"Отправить приглашение\"],\"EFKJQT\":[\"Настройка\"],\"EGPQBv\":[\"Пользовательские правила флуда (+f)\"],\"ELik0r\":[\"Просмотреть полную политику конфиденциальности\"],\"EPbeC2\":[\"Просмотреть или изменить тему канала\"],\"EQCDNT\":[\"Введите имя пользователя опера...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Найдено 1 сообщение, соответствующее \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Следующее изображение\"],\"EdQY6l\":[\"Нет\"],\"EnqLYU\":[\"Поиск серверов...\"],\"F0OKMc\":[\"Редактировать сервер\"],\"F6Int2\":[\"Включить выделения\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Макс. пользователей\"],\"FUU/hZ\":[\"Управляет количеством внешних медиафайлов, загружаемых в чат.\"],\"Fdp03t\":[\"вкл\"],\"FfPWR0\":[\"Диалог\"],\"FjkaiT\":[\"Уменьшить\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Что это означает:\"],\"FolHNl\":[\"Управление аккаунтом и аутентификацией\"],\"Fp2Dif\":[\"Покинул сервер\"],\"G5KmCc\":[\"GZ-Line (глобальная Z-Line)\"],\"GDs0lz\":[\"<0>Риск:</0> Конфиденциальная информация (сообщения, личные переписки, данные аутентификации) может быть раскрыта сетевым администраторам или злоумышленникам, находящимся между IRC-серверами.\"],\"GR+2I3\":[\"Добавить маску приглашения (например: nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Закрыть всплывающие уведомления сервера\"],\"GlHnXw\":[\"Смена ника не удалась: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Предпросмотр:\"],\"GtmO8/\":[\"от\"],\"GtuHUQ\":[\"Переименовать этот канал на сервере. Все пользователи увидят новое имя.\"],\"GuGfFX\":[\"Включить/выключить поиск\"],\"GxkJXS\":[\"Загрузка...\"],\"GzbwnK\":[\"Присоединился к каналу\"],\"GzsUDB\":[\"Расширенный профиль\"],\"H/PnT8\":[\"Вставить эмодзи\"],\"H6Izzl\":[\"Ваш предпочтительный цветовой код\"],\"H9jIv+\":[\"Показывать входы/выходы\"],\"HAKBY9\":[\"Загрузить файлы\"],\"HdE1If\":[\"Канал\"],\"Hk4AW9\":[\"Ваше предпочтительное отображаемое имя\"],\"HmHDk7\":[\"Выбрать участника\"],\"HrQzPU\":[\"Каналы на \",[\"networkName\"]],\"I2tXQ5\":[\"Сообщение @\",[\"0\"],\" (Enter — новая строка, Shift+Enter — отправить)\"],\"I6bw/h\":[\"Забанить пользователя\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Включить уведомления\"],\"I9D72S\":[\"Вы уверены, что хотите удалить это сообщение? Это действие нельзя отменить.\"],\"IA+1wo\":[\"Показывать, когда пользователей исключают из каналов\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"Инфо:\"],\"IUwGEM\":[\"Сохранить изменения\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" и \",[\"2\"],\" печатают...\"],\"IgrLD/\":[\"Пауза\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"Ответить\"],\"IoHMnl\":[\"Максимальное значение: \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Подключение...\"],\"J5T9NW\":[\"Информация о пользователе\"],\"J8Y5+z\":[\"Упс! Разрыв сети! ⚠️\"],\"JBHkBA\":[\"Покинул канал\"],\"JCwL0Q\":[\"Укажите причину (необязательно)\"],\"JFciKP\":[\"Переключить\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Изменить имя канала (только для операторов)\"],\"JcD7qf\":[\"Другие действия\"],\"JdkA+c\":[\"Секретный (+s)\"],\"Jmu12l\":[\"Каналы сервера\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Включить Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Данные WHOIS недоступны\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Удалить сообщение\"],\"KKBlUU\":[\"Встроить\"],\"KM0pLb\":[\"Добро пожаловать в канал!\"],\"KR6W2h\":[\"Перестать игнорировать пользователя\"],\"KV+Bi1\":[\"Только по приглашению (+i)\"],\"KdCtwE\":[\"Сколько секунд отслеживать флуд-активность до сброса счётчиков\"],\"Kkezga\":[\"Пароль сервера\"],\"KsiQ/8\":[\"Для входа в канал необходимо приглашение\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Информация о канале\"],\"LC1a7n\":[\"IRC-сервер сообщил о низком уровне безопасности межсерверных соединений. Это означает, что при передаче ваших сообщений между IRC-серверами сети они могут быть недостаточно зашифрованы или SSL/TLS-сертификаты могут не проверяться должным образом.\"],\"LNfLR5\":[\"Показывать исключения\"],\"LQb0W/\":[\"Показывать все события\"],\"LU7/yA\":[\"Альтернативное имя для отображения в интерфейсе. Может содержать пробелы, эмодзи и специальные символы. Настоящее имя канала (\",[\"channelName\"],\") по-прежнему будет использоваться для IRC-команд.\"],\"LUb9O7\":[\"Необходимо указать корректный порт сервера\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Политика конфиденциальности\"],\"LcuSDR\":[\"Управление информацией профиля и метаданными\"],\"LqLS9B\":[\"Показывать смену никнейма\"],\"LsDQt2\":[\"Настройки канала\"],\"LtI9AS\":[\"Владелец\"],\"LuNhhL\":[\"отреагировал на это сообщение\"],\"M/AZNG\":[\"URL вашего аватара\"],\"M/WIer\":[\"Отправить сообщение\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Имя:\"],\"MHk+7g\":[\"Предыдущее изображение\"],\"MRorGe\":[\"Написать в личку\"],\"MVbSGP\":[\"Временное окно (секунды)\"],\"MkpcsT\":[\"Ваши сообщения и настройки хранятся локально на вашем устройстве\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Пометить как бота — обычно «on» или пусто\"],\"N7TQbE\":[\"Пригласить пользователя в \",[\"channelName\"]],\"NCca/o\":[\"Введите ник по умолчанию...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Показывает весь внешний медиаконтент. Любой URL может вызвать запрос к неизвестному серверу.\"],\"Nt+9O7\":[\"Использовать WebSocket вместо обычного TCP\"],\"NxIHzc\":[\"Отключить пользователя\"],\"O+v/cL\":[\"Просмотреть все каналы на сервере\"],\"ODwSCk\":[\"Отправить GIF\"],\"OGQ5kK\":[\"Настройка звуков уведомлений и выделений\"],\"OIPt1Z\":[\"Показать или скрыть боковую панель списка участников\"],\"OKSNq/\":[\"Очень строгий\"],\"ONWvwQ\":[\"Загрузить\"],\"OVKoQO\":[\"Пароль вашего аккаунта для аутентификации\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Перенося IRC в будущее\"],\"OhCpra\":[\"Задать тему…\"],\"OkltoQ\":[\"Забанить \",[\"username\"],\" по никнейму (запрещает переподключение с тем же ником)\"],\"P+t/Te\":[\"Нет дополнительных данных\"],\"P42Wcc\":[\"Безопасно\"],\"PD38l0\":[\"Предпросмотр аватара канала\"],\"PD9mEt\":[\"Введите сообщение...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Открыть настройки конфигурации канала\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Тема, которая будет отображаться для этого канала. Тему видят все пользователи.\"],\"PZCecv\":[\"Предпросмотр PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 раз\"],\"few\":[[\"c\"],\" раза\"],\"many\":[[\"c\"],\" раз\"],\"other\":[[\"c\"],\" раза\"]}]],\"PguS2C\":[\"Добавить маску исключения (например: nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Показано \",[\"displayedChannelsCount\"],\" из \",[\"0\"],\" каналов\"],\"PqhVlJ\":[\"Забанить пользователя (по hostmask)\"],\"Q+chwU\":[\"Имя пользователя:\"],\"Q6hhn8\":[\"Настройки\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Введите имя пользователя\"],\"QGqSZ2\":[\"Цвет и форматирование\"],\"QJQd1J\":[\"Редактировать профиль\"],\"QSzGDE\":[\"Не активен\"],\"QUlny5\":[\"Добро пожаловать на \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Читать далее\"],\"QuSkCF\":[\"Фильтр каналов...\"],\"QwUrDZ\":[\"изменил тему на: \",[\"topic\"]],\"R0UH07\":[\"Изображение \",[\"0\"],\" из \",[\"1\"]],\"R7SsBE\":[\"Выкл. звук\"],\"R8rf1X\":[\"Нажмите, чтобы задать тему\"],\"RArB3D\":[\"был кикнут из \",[\"channelName\"],\" пользователем \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Откройте мир IRC вместе с ObsidianIRC\"],\"RMMaN5\":[\"Модерируемый (+m)\"],\"RWw9Lg\":[\"Закрыть диалог\"],\"RZ2BuZ\":[\"Регистрация аккаунта \",[\"account\"],\" требует подтверждения: \",[\"message\"]],\"RySp6q\":[\"Скрыть комментарии\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Необходимо указать никнейм\"],\"SPVjfj\":[\"Если оставить пустым, будет использоваться «без причины»\"],\"SQKPvQ\":[\"Пригласить пользователя\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Выберите заранее заданный профиль защиты от флуда. Эти профили предоставляют сбалансированные настройки защиты для различных сценариев использования.\"],\"Slr+3C\":[\"Мин. пользователей\"],\"Spnlre\":[\"Вы пригласили \",[\"target\"],\" присоединиться к \",[\"channel\"]],\"T/ckN5\":[\"Открыть в просмотрщике\"],\"T91vKp\":[\"Воспроизвести\"],\"TV2Wdu\":[\"Узнайте, как мы обрабатываем ваши данные и защищаем вашу конфиденциальность.\"],\"TgFpwD\":[\"Применяется...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Нет изменений\"],\"TtserG\":[\"Введите настоящее имя\"],\"Ttz9J1\":[\"Введите пароль...\"],\"Tz0i8g\":[\"Настройки\"],\"U3pytU\":[\"Администратор\"],\"UDb2YD\":[\"Реакция\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"Сохранить настройки\"],\"UV5hLB\":[\"Баны не найдены\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Статусные сообщения\"],\"Ue3uny\":[\"По умолчанию (без профиля)\"],\"UkARhe\":[\"Обычный — стандартная защита\"],\"Umn7Cj\":[\"Комментариев пока нет. Будьте первым!\"],\"UtUIRh\":[[\"0\"],\" старых сообщений\"],\"UwzP+U\":[\"Защищённое соединение\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Владелец канала\"],\"V4qgxE\":[\"Создан до (мин назад)\"],\"V8yTm6\":[\"Очистить поиск\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC — IRC будущего\"],\"VJScHU\":[\"Причина\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Отключить уведомления\"],\"VbyRUy\":[\"Комментарии\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Установлено:\"],\"VqnIZz\":[\"Ознакомьтесь с нашей политикой конфиденциальности и практикой обработки данных\"],\"VrMygG\":[\"Минимальная длина: \",[\"0\"]],\"VrnTui\":[\"Ваши местоимения, отображаемые в профиле\"],\"W8E3qn\":[\"Аутентифицированный аккаунт\"],\"WAakm9\":[\"Удалить канал\"],\"WFxTHC\":[\"Добавить маску бана (например: nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Необходимо указать хост сервера\"],\"WRYdXW\":[\"Позиция в аудио\"],\"WUOH5B\":[\"Игнорировать пользователя\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Показать ещё 1 элемент\"],\"few\":[\"Показать ещё \",[\"1\"],\" элемента\"],\"many\":[\"Показать ещё \",[\"1\"],\" элементов\"],\"other\":[\"Показать ещё \",[\"1\"],\" элемента\"]}]],\"Weq9zb\":[\"Основное\"],\"Wfj7Sk\":[\"Включить или отключить звуки уведомлений\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Профиль пользователя\"],\"X6S3lt\":[\"Поиск настроек, каналов, серверов...\"],\"XEHan5\":[\"Всё равно продолжить\"],\"XI1+wb\":[\"Неверный формат\"],\"XIXeuC\":[\"Сообщение @\",[\"0\"]],\"XMS+k4\":[\"Начать личный чат\"],\"XWgxXq\":[\"Альбом\"],\"Xd7+IT\":[\"Открепить личный чат\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Отображение\"],\"Xp2n93\":[\"Показывает медиафайлы с доверенного файлового хоста вашего сервера. Запросы к внешним сервисам не выполняются.\"],\"XvjC4F\":[\"Сохранение...\"],\"Y/qryO\":[\"Пользователи по вашему запросу не найдены\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Регистрация аккаунта \",[\"account\"],\" успешна: \",[\"message\"]],\"YEfzvP\":[\"Защищённая тема (+t)\"],\"YQOn6a\":[\"Свернуть список участников\"],\"YRCoE9\":[\"Оператор канала\"],\"YURQaF\":[\"Просмотреть профиль\"],\"YdBSvr\":[\"Управление отображением медиа и внешнего контента\"],\"Yj6U3V\":[\"Нет центрального сервера:\"],\"YjvpGx\":[\"Местоимения\"],\"YqH4l4\":[\"Без ключа\"],\"YyUPpV\":[\"Аккаунт:\"],\"ZJSWfw\":[\"Сообщение, отображаемое при отключении от сервера\"],\"ZR1dJ4\":[\"Приглашения\"],\"ZdWg0V\":[\"Открыть в браузере\"],\"ZhRBbl\":[\"Поиск сообщений…\"],\"Zmcu3y\":[\"Расширенные фильтры\"],\"a2/8e5\":[\"Тема установлена после (мин назад)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Предыдущая страница\"],\"aJTbXX\":[\"Пароль оператора\"],\"aQryQv\":[\"Такой шаблон уже существует\"],\"aW9pLN\":[\"Максимальное количество пользователей в канале. Оставьте пустым для снятия ограничения.\"],\"ah4fmZ\":[\"Также показывает превью с YouTube, Vimeo, SoundCloud и других известных сервисов.\"],\"aifXak\":[\"В этом канале нет медиафайлов\"],\"ap2zBz\":[\"Мягкий\"],\"az8lvo\":[\"Выкл.\"],\"azXSNo\":[\"Развернуть список участников\"],\"azdliB\":[\"Войти в аккаунт\"],\"b26wlF\":[\"она/её\"],\"bD/+Ei\":[\"Строгий\"],\"bQ6BJn\":[\"Настройте подробные правила защиты от флуда. Каждое правило задаёт тип активности для мониторинга и действие при превышении порога.\"],\"beV7+y\":[\"Пользователь получит приглашение вступить в \",[\"channelName\"],\".\"],\"bk84cH\":[\"Сообщение об отсутствии\"],\"bkHdLj\":[\"Добавить IRC-сервер\"],\"bmQLn5\":[\"Добавить правило\"],\"bwRvnp\":[\"Действие\"],\"c8+EVZ\":[\"Верифицированный аккаунт\"],\"cGYUlD\":[\"Предпросмотр медиа не загружается.\"],\"cLF98o\":[\"Показать комментарии (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Нет доступных пользователей\"],\"cSgpoS\":[\"Закрепить личный чат\"],\"cde3ce\":[\"Написать <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Копировать форматированный вывод\"],\"cl/A5J\":[\"Добро пожаловать на \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Удалить\"],\"coPLXT\":[\"Мы не храним ваши IRC-переписки на наших серверах\"],\"crYH/6\":[\"Плеер SoundCloud\"],\"d3sis4\":[\"Добавить сервер\"],\"d9aN5k\":[\"Удалить \",[\"username\"],\" из канала\"],\"dEgA5A\":[\"Отмена\"],\"dGi1We\":[\"Открепить эту личную переписку\"],\"dJVuyC\":[\"покинул \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"кому\"],\"dXqxlh\":[\"<0>⚠️ Угроза безопасности!</0> Это соединение может быть уязвимо для перехвата или атак типа «человек посередине».\"],\"da9Q/R\":[\"Изменил режимы канала\"],\"dhJN3N\":[\"Показать комментарии\"],\"dj2xTE\":[\"Закрыть уведомление\"],\"dpCzmC\":[\"Настройки защиты от флуда\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Открыть эту ссылку в новой вкладке?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Изменить\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Загрузите изображение или укажите URL с необязательной подстановкой \",[\"size\"],\" для динамического масштабирования\"],\"edBbee\":[\"Забанить \",[\"username\"],\" по hostmask (запрещает переподключение с того же IP/хоста)\"],\"ekfzWq\":[\"Настройки пользователя\"],\"elPDWs\":[\"Настройте IRC-клиент под себя\"],\"eu2osY\":[\"<0>💡 Рекомендация:</0> Продолжайте только если вы доверяете этому серверу и понимаете риски. Избегайте передачи конфиденциальной информации или паролей через это соединение.\"],\"euEhbr\":[\"Нажмите, чтобы войти в \",[\"channel\"]],\"ez3vLd\":[\"Включить многострочный ввод\"],\"f0J5Ki\":[\"Межсерверное взаимодействие может использовать незашифрованные соединения\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Предупредить пользователя\"],\"fDOLLd\":[\"Каналы не найдены.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Анонимная аналитика:\"],\"fq1GF9\":[\"Показывать, когда пользователи отключаются от сервера\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Этот сервер поддерживает только один тип подключения\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Список игнорирования\"],\"gNzMrk\":[\"Текущий аватар\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Введите ник...\"],\"gz6UQ3\":[\"Развернуть\"],\"h6razj\":[\"Исключить маску имени канала\"],\"hG6jnw\":[\"Тема не задана\"],\"hG89Ed\":[\"Изображение\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Порт\"],\"ha+Bz5\":[\"например: 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Количество\"],\"hzdLuQ\":[\"Говорить могут только пользователи с голосом или выше\"],\"i0qMbr\":[\"Главная\"],\"iDNBZe\":[\"Уведомления\"],\"iH8pgl\":[\"Назад\"],\"iL9SZg\":[\"Забанить пользователя (по никнейму)\"],\"iNt+3c\":[\"Вернуться к изображению\"],\"iQvi+a\":[\"Не предупреждать меня о низком уровне безопасности соединений для этого сервера\"],\"iSLIjg\":[\"Подключиться\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Хост сервера\"],\"idD8Ev\":[\"Сохранено\"],\"iivqkW\":[\"В сети с\"],\"ij+Elv\":[\"Предпросмотр изображения\"],\"ilIWp7\":[\"Включить/выключить уведомления\"],\"iuaqvB\":[\"Используйте * в качестве маски. Примеры: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Бот\"],\"j2DGR0\":[\"Забанить по hostmask\"],\"jA4uoI\":[\"Тема:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Причина (необязательно)\"],\"jUV7CU\":[\"Загрузить аватар\"],\"jW5Uwh\":[\"Управление загрузкой внешних медиафайлов. Выкл / Безопасно / Доверенные источники / Весь контент.\"],\"jXzms5\":[\"Параметры вложения\"],\"jZlrte\":[\"Цвет\"],\"jfC/xh\":[\"Контакт\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"Загрузить старые сообщения\"],\"k3ID0F\":[\"Фильтр участников…\"],\"k65gsE\":[\"Подробнее\"],\"k7Zgob\":[\"Отменить подключение\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Приглашения не найдены\"],\"kCLEPU\":[\"Подключён к\"],\"kF5LKb\":[\"Игнорируемые шаблоны:\"],\"kGeOx/\":[\"Присоединиться к \",[\"0\"]],\"kITKr8\":[\"Загрузка режимов канала...\"],\"kPpPsw\":[\"Вы являетесь IRC-оператором\"],\"kWJmRL\":[\"ты\"],\"kfcRb0\":[\"Аватар\"],\"kjMqSj\":[\"Копировать JSON\"],\"krViRy\":[\"Нажмите для копирования как JSON\"],\"ks71ra\":[\"Исключения\"],\"kw4lRv\":[\"Полуоператор канала\"],\"kxgIRq\":[\"Выберите или добавьте канал для начала.\"],\"ky6dWe\":[\"Предпросмотр аватара\"],\"l+GxCv\":[\"Загрузка каналов...\"],\"l+IUVW\":[\"Верификация аккаунта \",[\"account\"],\" успешна: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"переподключился\"],\"few\":[\"переподключился \",[\"reconnectCount\"],\" раза\"],\"many\":[\"переподключился \",[\"reconnectCount\"],\" раз\"],\"other\":[\"переподключился \",[\"reconnectCount\"],\" раза\"]}]],\"l5jmzx\":[[\"0\"],\" и \",[\"1\"],\" печатают...\"],\"lHy8N5\":[\"Загрузка дополнительных каналов...\"],\"lbpf14\":[\"Войти в \",[\"value\"]],\"lfFsZ4\":[\"Каналы\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Имя аккаунта\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Загрузить изображение\"],\"loQxaJ\":[\"Я вернулся\"],\"lvfaxv\":[\"ГЛАВНАЯ\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Добавить\"],\"m8flAk\":[\"Предпросмотр (ещё не загружено)\"],\"mEPxTp\":[\"<0>⚠️ Будьте осторожны!</0> Открывайте ссылки только из доверенных источников. Вредоносные ссылки могут угрожать вашей безопасности или конфиденциальности.\"],\"mHGdhG\":[\"Информация о сервере\"],\"mHS8lb\":[\"Сообщение #\",[\"0\"]],\"mMYBD9\":[\"Широкий — более широкая область защиты\"],\"mTGsPd\":[\"Тема канала\"],\"mU8j6O\":[\"Без внешних сообщений (+n)\"],\"mZp8FL\":[\"Автоматический возврат к однострочному режиму\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"Комментарии (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Пользователь аутентифицирован\"],\"mwtcGl\":[\"Закрыть комментарии\"],\"mzI/c+\":[\"Скачать\"],\"n3fGRk\":[\"установил \",[\"0\"]],\"nE9jsU\":[\"Мягкий — менее строгая защита\"],\"nNflMD\":[\"Покинуть канал\"],\"nPXkBi\":[\"Загрузка данных WHOIS...\"],\"nQnxxF\":[\"Сообщение #\",[\"0\"],\" (Shift+Enter — новая строка)\"],\"nWMRxa\":[\"Открепить\"],\"nkC032\":[\"Без профиля флуда\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Отправить предупреждение пользователю \",[\"username\"]],\"o9ylQi\":[\"Найдите GIF для начала\"],\"oFGkER\":[\"Уведомления сервера\"],\"oOi11l\":[\"Прокрутить вниз\"],\"oQEzQR\":[\"Новое DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"В сети\"],\"oal760\":[\"Возможны атаки типа «человек посередине» на межсерверные соединения\"],\"oeqmmJ\":[\"Доверенные источники\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"По умолчанию\"],\"p0Z69r\":[\"Шаблон не может быть пустым\"],\"p1KgtK\":[\"Не удалось загрузить аудио\"],\"p59pEv\":[\"Подробности\"],\"p7sRI6\":[\"Сообщать другим, когда вы печатаете\"],\"pBm1od\":[\"Секретный канал\"],\"pNmiXx\":[\"Ваш никнейм по умолчанию для всех серверов\"],\"pUUo9G\":[\"Хост:\"],\"pVGPmz\":[\"Пароль аккаунта\"],\"peNE68\":[\"Навсегда\"],\"plhHQt\":[\"Нет данных\"],\"pm6+q5\":[\"Предупреждение безопасности\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Дополнительная информация\"],\"q0cR4S\":[\"теперь известен как **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Канал не будет отображаться в командах LIST и NAMES\"],\"qLpTm/\":[\"Убрать реакцию \",[\"emoji\"]],\"qVkGWK\":[\"Закрепить\"],\"qY8wNa\":[\"Сайт\"],\"qb0xJ7\":[\"Используйте маски: * соответствует любой последовательности, ? — любому одному символу. Примеры: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Ключ канала (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Без ограничений\"],\"r1W2AS\":[\"Изображение с файлового хостинга\"],\"rIPR2O\":[\"Тема установлена до (мин назад)\"],\"rMMSYo\":[\"Максимальная длина: \",[\"0\"]],\"rWtzQe\":[\"Сеть разделилась и воссоединилась. ✅\"],\"rYG2u6\":[\"Пожалуйста, подождите...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Предпросмотр\"],\"rjGI/Q\":[\"Конфиденциальность\"],\"rk8iDX\":[\"Загрузка GIF...\"],\"rn6SBY\":[\"Вкл. звук\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Был исключён из канала\"],\"s8cATI\":[\"присоединился к \",[\"channelName\"]],\"sCO9ue\":[\"Соединение с <0>\",[\"serverName\"],\"</0> имеет следующие проблемы безопасности:\"],\"sGH11W\":[\"Сервер\"],\"sHI1H+\":[\"теперь известен как **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" пригласил вас присоединиться к \",[\"channel\"]],\"sby+1/\":[\"Нажмите, чтобы скопировать\"],\"sfN25C\":[\"Ваше настоящее или полное имя\"],\"sliuzR\":[\"Открыть ссылку\"],\"sqrO9R\":[\"Пользовательские упоминания\"],\"sr6RdJ\":[\"Многострочный режим по Shift+Enter\"],\"swrCpB\":[\"Канал был переименован с \",[\"oldName\"],\" на \",[\"newName\"],\" пользователем \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Дополнительно\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Удалить\"],\"t47eHD\":[\"Ваш уникальный идентификатор на этом сервере\"],\"tAkAh0\":[\"URL с необязательной подстановкой \",[\"size\"],\" для динамического масштабирования. Пример: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Показать или скрыть боковую панель списка каналов\"],\"tfDRzk\":[\"Сохранить\"],\"tiBsJk\":[\"покинул \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"вышел (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Ник {nick} уже используется, повторная попытка с {newNick}\"],\"u0a8B4\":[\"Аутентифицироваться как IRC-оператор для административного доступа\"],\"u0rWFU\":[\"Создан после (мин назад)\"],\"u72w3t\":[\"Пользователи и шаблоны для игнорирования\"],\"u7jc2L\":[\"вышел\"],\"uAQUqI\":[\"Статус\"],\"uB85T3\":[\"Ошибка сохранения: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-серверы:\"],\"usSSr/\":[\"Масштаб\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Программа:\"],\"vE8kb+\":[\"Shift+Enter для новой строки (Enter отправляет)\"],\"vERlcd\":[\"Профиль\"],\"vK0RL8\":[\"Без темы\"],\"vSJd18\":[\"Видео\"],\"vXIe7J\":[\"Язык\"],\"vaHYxN\":[\"Настоящее имя\"],\"vhjbKr\":[\"Отсутствую\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[\"клиент \",[\"title\"]],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Неверное значение\"],\"wFjjxZ\":[\"был кикнут из \",[\"channelName\"],\" пользователем \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Исключения из банов не найдены\"],\"wPrGnM\":[\"Администратор канала\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Показывать, когда пользователи входят в каналы или покидают их\"],\"whqZ9r\":[\"Дополнительные слова или фразы для выделения\"],\"wm7RV4\":[\"Звук уведомления\"],\"wz/Yoq\":[\"Ваши сообщения могут быть перехвачены при передаче между серверами\"],\"xCJdfg\":[\"Очистить\"],\"xUHRTR\":[\"Автоматически аутентифицироваться как оператор при подключении\"],\"xWHwwQ\":[\"Баны\"],\"xYilR2\":[\"Медиа\"],\"xceQrO\":[\"Поддерживаются только защищённые WebSocket-соединения\"],\"xdtXa+\":[\"имя-канала\"],\"xfXC7q\":[\"Текстовые каналы\"],\"xlCYOE\":[\"Загрузка сообщений...\"],\"xlhswE\":[\"Минимальное значение: \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Добавить слово или фразу...\"],\"xuRqRq\":[\"Лимит пользователей (+l)\"],\"xwF+7J\":[[\"0\"],\" печатает...\"],\"yNeucF\":[\"Этот сервер не поддерживает расширенные метаданные профиля (расширение IRCv3 METADATA). Дополнительные поля, такие как аватар, отображаемое имя и статус, недоступны.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Аватар канала\"],\"yQE2r9\":[\"Загрузка\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Имя пользователя оператора\"],\"yYOzWD\":[\"логи\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"Пароль IRC-оператора\"],\"ygCKqB\":[\"Стоп\"],\"ymDxJx\":[\"Имя пользователя IRC-оператора\"],\"yrpRsQ\":[\"Сортировать по имени\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Закрыть\"],\"zJw+jA\":[\"устанавливает режим: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Введите имя пользователя оператора\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
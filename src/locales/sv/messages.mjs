@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Ogiltigt mönsterformat. Använd formatet nick!user@host (jokertecken * tillåts)\"],\"+6NQQA\":[\"Allmän supportkanal\"],\"+6NyRG\":[\"Klient\"],\"+K0AvT\":[\"Koppla från\"],\"+cyFdH\":[\"Standardmeddelande när du markerar dig som borta\"],\"+mVPqU\":[\"Rendera markdown-formatering i meddelanden\"],\"+vqCJH\":[\"Ditt kontoanvändarnamn för autentisering\"],\"+yPBXI\":[\"Välj fil\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Låg länksäkerhet (nivå \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Användare utanför kanalen kan inte skicka meddelanden till den\"],\"/6BzZF\":[\"Växla medlemslista\"],\"/TNOPk\":[\"Användaren är borta\"],\"/XQgft\":[\"Utforska\"],\"/cF7Rs\":[\"Volym\"],\"/dqduX\":[\"Nästa sida\"],\"/fc3q4\":[\"Allt innehåll\"],\"/kISDh\":[\"Aktivera aviseringsljud\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Ljud\"],\"/rfkZe\":[\"Spela upp ljud för omnämnanden och meddelanden\"],\"0/0ZGA\":[\"Kanalnamns-mask\"],\"0D6j7U\":[\"Läs mer om anpassade regler →\"],\"0XsHcR\":[\"Sparka ut användare\"],\"0ZpE//\":[\"Sortera efter användare\"],\"0bEPwz\":[\"Ange borta\"],\"0dGkPt\":[\"Expandera kanallistan\"],\"0gS7M5\":[\"Visningsnamn\"],\"0kS+M8\":[\"ExempelNÄT\"],\"0rgoY7\":[\"Anslut bara till servrar du väljer\"],\"0wdd7X\":[\"Gå med\"],\"0wkVYx\":[\"Privata meddelanden\"],\"111uHX\":[\"Länkförhandsvisning\"],\"196EG4\":[\"Ta bort privatchatt\"],\"1DSr1i\":[\"Registrera ett konto\"],\"1O/24y\":[\"Växla kanallista\"],\"1VPJJ2\":[\"Varning för extern länk\"],\"1ZC/dv\":[\"Inga olästa omnämnanden eller meddelanden\"],\"1pO1zi\":[\"Servernamn krävs\"],\"1uwfzQ\":[\"Visa kanalämne\"],\"268g7c\":[\"Ange visningsnamn\"],\"2FOFq1\":[\"Serveroperatörer på nätverket kan potentiellt läsa dina meddelanden\"],\"2FYpfJ\":[\"Mer\"],\"2HF1Y2\":[[\"inviter\"],\" bjöd in \",[\"target\"],\" att gå med i \",[\"channel\"]],\"2I70QL\":[\"Visa användarprofilinformation\"],\"2QYdmE\":[\"Användare:\"],\"2QpEjG\":[\"lämnade\"],\"2YE223\":[\"Meddelande #\",[\"0\"],\" (Enter för ny rad, Shift+Enter för att skicka)\"],\"2bimFY\":[\"Använd serverlösenord\"],\"2iTmdZ\":[\"Lokal lagring:\"],\"2odkwe\":[\"Strikt – mer aggressivt skydd\"],\"2uDhbA\":[\"Ange användarnamn att bjuda in\"],\"2ygf/L\":[\"← Tillbaka\"],\"2zEgxj\":[\"Sök GIF:ar...\"],\"3RdPhl\":[\"Byt namn på kanal\"],\"3THokf\":[\"Voice-användare\"],\"3TSz9S\":[\"Minimera\"],\"3jBDvM\":[\"Kanalens visningsnamn\"],\"3ryuFU\":[\"Valfria kraschrapporter för att förbättra appen\"],\"3uBF/8\":[\"Stäng visaren\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Ange kontonamn...\"],\"4/Rr0R\":[\"Bjud in en användare till den aktuella kanalen\"],\"4EZrJN\":[\"Regler\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flödesprofil (+F)\"],\"4RZQRK\":[\"Vad håller du på med?\"],\"4hfTrB\":[\"Nick\"],\"4n99LO\":[\"Redan i \",[\"0\"]],\"4t6vMV\":[\"Växla automatiskt till enkel rad för korta meddelanden\"],\"4vsHmf\":[\"Tid (min)\"],\"5+INAX\":[\"Markera meddelanden som nämner dig\"],\"5R5Pv/\":[\"Oper-namn\"],\"678PKt\":[\"Nätverksnamn\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Lösenord krävs för att gå med i kanalen. Lämna tomt för att ta bort nyckeln.\"],\"6HhMs3\":[\"Quit-meddelande\"],\"6V3Ea3\":[\"Kopierat\"],\"6lGV3K\":[\"Visa mindre\"],\"6yFOEi\":[\"Ange oper-lösenord...\"],\"7+IHTZ\":[\"Ingen fil vald\"],\"73hrRi\":[\"nick!user@host (t.ex. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Skicka privat meddelande\"],\"7U1W7c\":[\"Mycket avslappnat\"],\"7Y1YQj\":[\"Riktigt namn:\"],\"7YHArF\":[\"— öppna i visaren\"],\"7fjnVl\":[\"Sök användare...\"],\"7jL88x\":[\"Ta bort det här meddelandet? Det kan inte ångras.\"],\"7nGhhM\":[\"Vad tänker du på?\"],\"7sEpu1\":[\"Medlemmar — \",[\"0\"]],\"7sNhEz\":[\"Användarnamn\"],\"8H0Q+x\":[\"Läs mer om profiler →\"],\"8Phu0A\":[\"Visa när användare byter nick\"],\"8XTG9e\":[\"Ange oper-lösenord\"],\"8XsV2J\":[\"Försök skicka igen\"],\"8ZsakT\":[\"Lösenord\"],\"8kR84m\":[\"Du håller på att öppna en extern länk:\"],\"8lCgih\":[\"Ta bort regel\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"gick med\"],\"other\":[\"gick med \",[\"joinCount\"],\" gånger\"]}]],\"9BMLnJ\":[\"Återanslut till server\"],\"9OEgyT\":[\"Lägg till reaktion\"],\"9PQ8m2\":[\"G-Line (globalt ban)\"],\"9Qs99X\":[\"E-post:\"],\"9QupBP\":[\"Ta bort mönster\"],\"9bG48P\":[\"Skickar\"],\"9f5f0u\":[\"Frågor om integritet? Kontakta oss:\"],\"9unqs3\":[\"Frånvarande:\"],\"9v3hwv\":[\"Inga servrar hittades.\"],\"9zb2WA\":[\"Ansluter\"],\"A1taO8\":[\"Sök\"],\"A2adVi\":[\"Skicka skriv-aviseringar\"],\"A9Rhec\":[\"Kanalnamn\"],\"AWOSPo\":[\"Zooma in\"],\"AXSpEQ\":[\"Oper vid anslutning\"],\"AeXO77\":[\"Konto\"],\"AhNP40\":[\"Sök position\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Bytte nick\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Avbryt svar\"],\"ApSx0O\":[\"Hittade \",[\"0\"],\" meddelanden som matchar \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Inga resultat hittades\"],\"AyNqAB\":[\"Visa alla serverhändelser i chatten\"],\"B/QqGw\":[\"Borta från tangentbordet\"],\"B8AaMI\":[\"Det här fältet krävs\"],\"BA2c49\":[\"Servern stöder inte avancerad LIST-filtrering\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" och \",[\"3\"],\" andra skriver...\"],\"BGul2A\":[\"Du har osparade ändringar. Är du säker på att du vill stänga utan att spara?\"],\"BIf9fi\":[\"Ditt statusmeddelande\"],\"BZz3md\":[\"Din personliga webbplats\"],\"Bgm/H7\":[\"Tillåt att skriva flera rader text\"],\"BiQIl1\":[\"Fäst den här privata meddelandekonversationen\"],\"BlNZZ2\":[\"Klicka för att hoppa till meddelandet\"],\"Bowq3c\":[\"Endast operatörer kan ändra kanalämnet\"],\"Btozzp\":[\"Den här bilden har gått ut\"],\"Bycfjm\":[\"Totalt: \",[\"0\"]],\"C6IBQc\":[\"Kopiera hela JSON\"],\"C9L9wL\":[\"Datainsamling\"],\"CDq4wC\":[\"Moderera användare\"],\"CHVRxG\":[\"Meddelande @\",[\"0\"],\" (Shift+Enter för ny rad)\"],\"CN9zdR\":[\"Oper-namn och lösenord krävs\"],\"CW3sYa\":[\"Lägg till reaktion \",[\"emoji\"]],\"CaAkqd\":[\"Visa quit-meddelanden\"],\"CbvaYj\":[\"Banna via nick\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Välj en kanal\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"gick med och lämnade\"],\"DB8zMK\":[\"Tillämpa\"],\"DBcWHr\":[\"Anpassad aviseringsljudfil\"],\"DTy9Xw\":[\"Medieförhandsvisningar\"],\"Dj4pSr\":[\"Välj ett säkert lösenord\"],\"Du+zn+\":[\"Söker...\"],\"Du2T2f\":[\"Inställningen hittades inte\"],\"DwsSVQ\":[\"Tillämpa filter och uppdatera\"],\"E3W/zd\":[\"Standard-nick\"],\"E6nRW7\":[\"Kopiera URL\"],\"E703RG\":[\"Lägen:\"],\"EAeu1Z\":[\"Skicka inbjudan\"],\"EFKJQT\":[\"Inställning\"],\"EGPQBv\":[\"Anpassade flödesregler (+f)\"],\"ELik0r\":[\"Visa fullständig integritetspolicy\"],\"EPbeC2\":[\"Visa eller redigera kanalämnet\"],\"EQCDNT\":[\"Ange oper-användarnamn...\"],\"EUvulZ\":[\"Hittade 1 meddelande som matchar \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Nästa bild\"],\"EdQY6l\":[\"Ingen\"],\"EnqLYU\":[\"Sök servrar...\"],\"F0OKMc\":[\"Redigera server\"],\"F6Int2\":[\"Aktivera markeringar\"],\"FDoLyE\":[\"Max användare\"],\"FUU/hZ\":[\"Styr hur mycket externt media som laddas i chatten.\"],\"Fdp03t\":[\"på\"],\"FfPWR0\":[\"Dialog\"],\"FjkaiT\":[\"Zooma ut\"],\"FlqOE9\":[\"Vad detta innebär:\"],\"FolHNl\":[\"Hantera ditt konto och autentisering\"],\"Fp2Dif\":[\"Lämnade servern\"],\"G5KmCc\":[\"GZ-Line (global Z-Line)\"],\"GDs0lz\":[\"<0>Risk:</0> Känslig information (meddelanden, privata konversationer, autentiseringsuppgifter) kan exponeras för nätverksadministratörer eller angripare som befinner sig mellan IRC-servrar.\"],\"GR+2I3\":[\"Lägg till inbjudningsmask (t.ex. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Stäng utfällt servermeddelanden-fönster\"],\"GlHnXw\":[\"Namnbyte misslyckades: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Förhandsvisning:\"],\"GtmO8/\":[\"från\"],\"GtuHUQ\":[\"Byt namn på den här kanalen på servern. Alla användare ser det nya namnet.\"],\"GuGfFX\":[\"Växla sökning\"],\"GxkJXS\":[\"Laddar upp...\"],\"GzbwnK\":[\"Gick med i kanalen\"],\"GzsUDB\":[\"Utökad profil\"],\"H/PnT8\":[\"Infoga emoji\"],\"H6Izzl\":[\"Din föredragna färgkod\"],\"H9jIv+\":[\"Visa join/part\"],\"HAKBY9\":[\"Ladda upp filer\"],\"HdE1If\":[\"Kanal\"],\"Hk4AW9\":[\"Ditt föredragna visningsnamn\"],\"HmHDk7\":[\"Välj medlem\"],\"HrQzPU\":[\"Kanaler på \",[\"networkName\"]],\"I2tXQ5\":[\"Meddelande @\",[\"0\"],\" (Enter för ny rad, Shift+Enter för att skicka)\"],\"I6bw/h\":[\"Banna användare\"],\"I92Z+b\":[\"Aktivera aviseringar\"],\"I9D72S\":[\"Är du säker på att du vill ta bort det här meddelandet? Den här åtgärden kan inte ångras.\"],\"IA+1wo\":[\"Visa när användare sparkats ut från kanaler\"],\"IDwkJx\":[\"IRC-operatör\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Spara ändringar\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" och \",[\"2\"],\" skriver...\"],\"IgrLD/\":[\"Pausa\"],\"Im6JED\":[\"VISKNING\"],\"ImOQa9\":[\"Svara\"],\"IoHMnl\":[\"Maximalt värde är \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Ansluter...\"],\"J5T9NW\":[\"Användarinformation\"],\"J8Y5+z\":[\"Hoppsan! Nätverksuppdelning! ⚠️\"],\"JBHkBA\":[\"Lämnade kanalen\"],\"JCwL0Q\":[\"Ange anledning (valfritt)\"],\"JFciKP\":[\"Växla\"],\"JXGkhG\":[\"Ändra kanalnamnet (endast operatörer)\"],\"JcD7qf\":[\"Fler åtgärder\"],\"JdkA+c\":[\"Hemlig (+s)\"],\"Jmu12l\":[\"Serverkanaler\"],\"JvQ++s\":[\"Aktivera Markdown\"],\"K2jwh/\":[\"Ingen WHOIS-data tillgänglig\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Ta bort meddelande\"],\"KKBlUU\":[\"Inbäddning\"],\"KM0pLb\":[\"Välkommen till kanalen!\"],\"KR6W2h\":[\"Sluta ignorera användare\"],\"KV+Bi1\":[\"Endast inbjudna (+i)\"],\"KdCtwE\":[\"Hur många sekunder flödaktivitet ska övervakas innan räknarna återställs\"],\"Kkezga\":[\"Serverlösenord\"],\"KsiQ/8\":[\"Användare måste bjudas in för att gå med i kanalen\"],\"L+gB/D\":[\"Kanalinformation\"],\"LC1a7n\":[\"IRC-servern har rapporterat att dess server-till-server-länkar har en låg säkerhetsnivå. Det innebär att när dina meddelanden vidarebefordras mellan IRC-servrar i nätverket kanske de inte krypteras ordentligt eller att SSL/TLS-certifikaten inte valideras korrekt.\"],\"LNfLR5\":[\"Visa utsparkningar\"],\"LQb0W/\":[\"Visa alla händelser\"],\"LU7/yA\":[\"Alternativt namn för visning i gränssnittet. Får innehålla mellanslag, emoji och specialtecken. Det riktiga kanalnamnet (\",[\"channelName\"],\") används fortfarande för IRC-kommandon.\"],\"LUb9O7\":[\"Giltig serverport krävs\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Integritetspolicy\"],\"LcuSDR\":[\"Hantera din profilinformation och metadata\"],\"LqLS9B\":[\"Visa nickbyten\"],\"LsDQt2\":[\"Kanalinställningar\"],\"LtI9AS\":[\"Ägare\"],\"LuNhhL\":[\"reagerade på det här meddelandet\"],\"M/AZNG\":[\"URL till din avatarbild\"],\"M/WIer\":[\"Skicka meddelande\"],\"M8er/5\":[\"Namn:\"],\"MHk+7g\":[\"Föregående bild\"],\"MRorGe\":[\"PM-användare\"],\"MVbSGP\":[\"Tidsfönster (sekunder)\"],\"MkpcsT\":[\"Dina meddelanden och inställningar lagras lokalt på din enhet\"],\"N/hDSy\":[\"Markera som bot – vanligtvis 'on' eller tomt\"],\"N7TQbE\":[\"Bjud in användare till \",[\"channelName\"]],\"NCca/o\":[\"Ange standardsmeknamn...\"],\"Nqs6B9\":[\"Visar allt externt media. Valfri URL kan orsaka en begäran till en okänd server.\"],\"Nt+9O7\":[\"Använd WebSocket istället för råa TCP\"],\"NxIHzc\":[\"Koppla från användare\"],\"O+v/cL\":[\"Bläddra bland alla kanaler på servern\"],\"ODwSCk\":[\"Skicka en GIF\"],\"OGQ5kK\":[\"Konfigurera aviseringsljud och markeringar\"],\"OIPt1Z\":[\"Visa eller dölj medlemslistans sidopanel\"],\"OKSNq/\":[\"Mycket strikt\"],\"ONWvwQ\":[\"Ladda upp\"],\"OVKoQO\":[\"Ditt kontolösenord för autentisering\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - För IRC in i framtiden\"],\"OhCpra\":[\"Ange ett ämne…\"],\"OkltoQ\":[\"Banna \",[\"username\"],\" via nick (förhindrar återanslutning med samma nick)\"],\"P+t/Te\":[\"Inga ytterligare data\"],\"P42Wcc\":[\"Säkert\"],\"PD38l0\":[\"Förhandsvisning av kanalavatar\"],\"PD9mEt\":[\"Skriv ett meddelande...\"],\"PPqfdA\":[\"Öppna kanalens konfigurationsinställningar\"],\"PSCjfZ\":[\"Ämnet som visas för den här kanalen. Alla användare kan se ämnet.\"],\"PZCecv\":[\"PDF-förhandsgranskning\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 gång\"],\"other\":[[\"c\"],\" gånger\"]}]],\"PguS2C\":[\"Lägg till undantagsmask (t.ex. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Visar \",[\"displayedChannelsCount\"],\" av \",[\"0\"],\" kanaler\"],\"PqhVlJ\":[\"Banna användare (via hostmask)\"],\"Q+chwU\":[\"Användarnamn:\"],\"Q6hhn8\":[\"Inställningar\"],\"QF4a34\":[\"Ange ett användarnamn\"],\"QGqSZ2\":[\"Färg och formatering\"],\"QJQd1J\":[\"Redigera profil\"],\"QSzGDE\":[\"Inaktiv\"],\"QUlny5\":[\"Välkommen till \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Läs mer\"],\"QuSkCF\":[\"Filtrera kanaler...\"],\"QwUrDZ\":[\"ändrade ämnet till: \",[\"topic\"]],\"R0UH07\":[\"Bild \",[\"0\"],\" av \",[\"1\"]],\"R7SsBE\":[\"Tysta\"],\"R8rf1X\":[\"Klicka för att ange ämne\"],\"RArB3D\":[\"kastades ut från \",[\"channelName\"],\" av \",[\"username\"]],\"RI3cWd\":[\"Utforska IRC-världen med ObsidianIRC\"],\"RMMaN5\":[\"Modererad (+m)\"],\"RWw9Lg\":[\"Stäng dialog\"],\"RZ2BuZ\":[\"Kontoregistrering för \",[\"account\"],\" kräver verifiering: \",[\"message\"]],\"RySp6q\":[\"Dölj kommentarer\"],\"SPKQTd\":[\"Nick krävs\"],\"SPVjfj\":[\"Standardvärdet är 'ingen anledning' om det lämnas tomt\"],\"SQKPvQ\":[\"Bjud in användare\"],\"SkZcl+\":[\"Välj en fördefinierad flödeskyddsprofil. Dessa profiler ger balanserade skyddsinställningar för olika användningsfall.\"],\"Slr+3C\":[\"Min användare\"],\"Spnlre\":[\"Du bjöd in \",[\"target\"],\" att gå med i \",[\"channel\"]],\"T/ckN5\":[\"Öppna i visaren\"],\"T91vKp\":[\"Spela upp\"],\"TV2Wdu\":[\"Läs om hur vi hanterar dina uppgifter och skyddar din integritet.\"],\"TgFpwD\":[\"Tillämpar...\"],\"TkzSFB\":[\"Inga ändringar\"],\"TtserG\":[\"Ange riktigt namn\"],\"Ttz9J1\":[\"Ange lösenord...\"],\"Tz0i8g\":[\"Inställningar\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagera\"],\"UE4KO5\":[\"*kanal*\"],\"UGT5vp\":[\"Spara inställningar\"],\"UV5hLB\":[\"Inga banningar hittades\"],\"Uaj3Nd\":[\"Statusmeddelanden\"],\"Ue3uny\":[\"Standard (ingen profil)\"],\"UkARhe\":[\"Normal – standardskydd\"],\"Umn7Cj\":[\"Inga kommentarer ännu. Var den första!\"],\"UtUIRh\":[[\"0\"],\" äldre meddelanden\"],\"UwzP+U\":[\"Säker anslutning\"],\"V0/A4O\":[\"Kanalägare\"],\"V4qgxE\":[\"Skapad före (min sedan)\"],\"V8yTm6\":[\"Rensa sökning\"],\"VJMMyz\":[\"ObsidianIRC - För IRC in i framtiden\"],\"VJScHU\":[\"Anledning\"],\"VLsmVV\":[\"Tysta aviseringar\"],\"VbyRUy\":[\"Kommentarer\"],\"Vmx0mQ\":[\"Satt av:\"],\"VqnIZz\":[\"Visa vår integritetspolicy och datapraxis\"],\"VrMygG\":[\"Minimal längd är \",[\"0\"]],\"VrnTui\":[\"Dina pronomen, visas i din profil\"],\"W8E3qn\":[\"Autentiserat konto\"],\"WAakm9\":[\"Ta bort kanal\"],\"WFxTHC\":[\"Lägg till banmask (t.ex. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Serveradress krävs\"],\"WRYdXW\":[\"Ljudposition\"],\"WUOH5B\":[\"Ignorera användare\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Visa 1 till\"],\"other\":[\"Visa \",[\"1\"],\" till\"]}]],\"Weq9zb\":[\"Allmänt\"],\"Wfj7Sk\":[\"Tysta eller aktivera aviseringsljud\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*skräp*\"],\"WzMCru\":[\"Användarprofil\"],\"X6S3lt\":[\"Sök inställningar, kanaler, servrar...\"],\"XEHan5\":[\"Fortsätt ändå\"],\"XI1+wb\":[\"Ogiltigt format\"],\"XIXeuC\":[\"Meddelande @\",[\"0\"]],\"XMS+k4\":[\"Starta privat meddelande\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Lossa privatchatt\"],\"Xm/s+u\":[\"Visning\"],\"Xp2n93\":[\"Visar media från serverns betrodda filvärd. Inga begäranden görs till externa tjänster.\"],\"XvjC4F\":[\"Sparar...\"],\"Y/qryO\":[\"Inga användare hittades som matchar din sökning\"],\"YAqRpI\":[\"Kontoregistrering för \",[\"account\"],\" lyckades: \",[\"message\"]],\"YEfzvP\":[\"Skyddat ämne (+t)\"],\"YQOn6a\":[\"Dölj medlemslistan\"],\"YRCoE9\":[\"Kanaloperatör\"],\"YURQaF\":[\"Visa profil\"],\"YdBSvr\":[\"Styr medievisning och externt innehåll\"],\"Yj6U3V\":[\"Ingen central server:\"],\"YjvpGx\":[\"Pronomen\"],\"YqH4l4\":[\"Ingen nyckel\"],\"YyUPpV\":[\"Konto:\"],\"ZJSWfw\":[\"Meddelande som visas när du kopplar från servern\"],\"ZR1dJ4\":[\"Inbjudningar\"],\"ZdWg0V\":[\"Öppna i webbläsare\"],\"ZhRBbl\":[\"Sök meddelanden…\"],\"Zmcu3y\":[\"Avancerade filter\"],\"a2/8e5\":[\"Ämne angivet efter (min sedan)\"],\"aHKcKc\":[\"Föregående sida\"],\"aJTbXX\":[\"Oper-lösenord\"],\"aQryQv\":[\"Mönstret finns redan\"],\"aW9pLN\":[\"Maximalt antal användare som tillåts i kanalen. Lämna tomt för ingen gräns.\"],\"ah4fmZ\":[\"Visar också förhandsvisningar från YouTube, Vimeo, SoundCloud och liknande kända tjänster.\"],\"aifXak\":[\"Inget media i den här kanalen\"],\"ap2zBz\":[\"Avslappnat\"],\"az8lvo\":[\"Av\"],\"azXSNo\":[\"Expandera medlemslistan\"],\"azdliB\":[\"Logga in på ett konto\"],\"b26wlF\":[\"hon/hennes\"],\"bD/+Ei\":[\"Strikt\"],\"bQ6BJn\":[\"Konfigurera detaljerade flödeskyddsregler. Varje regel anger vilken typ av aktivitet som ska övervakas och vilken åtgärd som ska vidtas när gränser överskrids.\"],\"beV7+y\":[\"Användaren får en inbjudan att gå med i \",[\"channelName\"],\".\"],\"bk84cH\":[\"Borta-meddelande\"],\"bkHdLj\":[\"Lägg till IRC-server\"],\"bmQLn5\":[\"Lägg till regel\"],\"bwRvnp\":[\"Åtgärd\"],\"c8+EVZ\":[\"Verifierat konto\"],\"cGYUlD\":[\"Inga medieförhandsvisningar laddas.\"],\"cLF98o\":[\"Visa kommentarer (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Inga användare tillgängliga\"],\"cSgpoS\":[\"Fäst privatchatt\"],\"cde3ce\":[\"Meddelande <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopiera formaterad utdata\"],\"cl/A5J\":[\"Välkommen till \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Ta bort\"],\"coPLXT\":[\"Vi lagrar inte dina IRC-kommunikationer på våra servrar\"],\"crYH/6\":[\"SoundCloud-spelare\"],\"d3sis4\":[\"Lägg till server\"],\"d9aN5k\":[\"Ta bort \",[\"username\"],\" från kanalen\"],\"dEgA5A\":[\"Avbryt\"],\"dGi1We\":[\"Lossa den här privata meddelandekonversationen\"],\"dJVuyC\":[\"lämnade \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"till\"],\"dXqxlh\":[\"<0>⚠️ Säkerhetsrisk!</0> Den här anslutningen kan vara sårbar för avlyssning eller man-in-the-middle-attacker.\"],\"da9Q/R\":[\"Ändrade kanallägen\"],\"dhJN3N\":[\"Visa kommentarer\"],\"dj2xTE\":[\"Stäng avisering\"],\"dpCzmC\":[\"Inställningar för flödeskydd\"],\"e9dQpT\":[\"Vill du öppna den här länken i en ny flik?\"],\"ePK91l\":[\"Redigera\"],\"eYBDuB\":[\"Ladda upp en bild eller ange en URL med valfri \",[\"size\"],\"-ersättning för dynamisk storleksanpassning\"],\"edBbee\":[\"Banna \",[\"username\"],\" via hostmask (förhindrar återanslutning från samma IP/host)\"],\"ekfzWq\":[\"Användarinställningar\"],\"elPDWs\":[\"Anpassa din IRC-klientupplevelse\"],\"eu2osY\":[\"<0>💡 Rekommendation:</0> Fortsätt bara om du litar på den här servern och förstår riskerna. Undvik att dela känslig information eller lösenord via den här anslutningen.\"],\"euEhbr\":[\"Klicka för att gå med i \",[\"channel\"]],\"ez3vLd\":[\"Aktivera flerrads-inmatning\"],\"f0J5Ki\":[\"Server-till-server-kommunikation kan använda okrypterade anslutningar\"],\"f9BHJk\":[\"Varna användare\"],\"fDOLLd\":[\"Inga kanaler hittades.\"],\"ffzDkB\":[\"Anonym analys:\"],\"fq1GF9\":[\"Visa när användare kopplar från servern\"],\"gEF57C\":[\"Den här servern stöder bara en anslutningstyp\"],\"gJuLUI\":[\"Ignorera-lista\"],\"gNzMrk\":[\"Nuvarande avatar\"],\"gjPWyO\":[\"Ange smeknamn...\"],\"gz6UQ3\":[\"Maximera\"],\"h6razj\":[\"Uteslut kanalnamns-mask\"],\"hG6jnw\":[\"Inget ämne angivet\"],\"hG89Ed\":[\"Bild\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"t.ex. 100:1440\"],\"hehnjM\":[\"Mängd\"],\"hzdLuQ\":[\"Endast användare med Voice eller högre kan tala\"],\"i0qMbr\":[\"Hem\"],\"iDNBZe\":[\"Aviseringar\"],\"iH8pgl\":[\"Tillbaka\"],\"iL9SZg\":[\"Banna användare (via nick)\"],\"iNt+3c\":[\"Tillbaka till bild\"],\"iQvi+a\":[\"Varna mig inte om låg länksäkerhet för den här servern\"],\"iSLIjg\":[\"Anslut\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Serveradress\"],\"idD8Ev\":[\"Sparat\"],\"iivqkW\":[\"Inloggad sedan\"],\"ij+Elv\":[\"Bildförhandsvisning\"],\"ilIWp7\":[\"Växla aviseringar\"],\"iuaqvB\":[\"Använd * som jokertecken. Exempel: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Banna via hostmask\"],\"jA4uoI\":[\"Ämne:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Anledning (valfritt)\"],\"jUV7CU\":[\"Ladda upp avatar\"],\"jW5Uwh\":[\"Styr hur mycket externt media som laddas. Av / Säkert / Betrodda källor / Allt innehåll.\"],\"jXzms5\":[\"Bilagealternativ\"],\"jZlrte\":[\"Färg\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#nytt-kanalnamn\"],\"k112DD\":[\"Ladda äldre meddelanden\"],\"k3ID0F\":[\"Filtrera medlemmar…\"],\"k65gsE\":[\"Fördjupning\"],\"k7Zgob\":[\"Avbryt anslutning\"],\"kAVx5h\":[\"Inga inbjudningar hittades\"],\"kCLEPU\":[\"Ansluten till\"],\"kF5LKb\":[\"Ignorerade mönster:\"],\"kGeOx/\":[\"Gå med i \",[\"0\"]],\"kITKr8\":[\"Laddar kanallägen...\"],\"kPpPsw\":[\"Du är en IRC-operatör\"],\"kWJmRL\":[\"Du\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Kopiera JSON\"],\"krViRy\":[\"Klicka för att kopiera som JSON\"],\"ks71ra\":[\"Undantag\"],\"kw4lRv\":[\"Kanal-halvoperatör\"],\"kxgIRq\":[\"Välj eller lägg till en kanal för att komma igång.\"],\"ky6dWe\":[\"Förhandsvisning av avatar\"],\"l+GxCv\":[\"Laddar kanaler...\"],\"l+IUVW\":[\"Kontoverifiering för \",[\"account\"],\" lyckades: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"återanslöt\"],\"other\":[\"återanslöt \",[\"reconnectCount\"],\" gånger\"]}]],\"l5jmzx\":[[\"0\"],\" och \",[\"1\"],\" skriver...\"],\"lHy8N5\":[\"Laddar fler kanaler...\"],\"lbpf14\":[\"Gå med i \",[\"value\"]],\"lfFsZ4\":[\"Kanaler\"],\"lkNdiH\":[\"Kontonamn\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Ladda upp bild\"],\"loQxaJ\":[\"Jag är tillbaka\"],\"lvfaxv\":[\"HEM\"],\"m16xKo\":[\"Lägg till\"],\"m8flAk\":[\"Förhandsvisning (ej uppladdad ännu)\"],\"mEPxTp\":[\"<0>⚠️ Var försiktig!</0> Öppna bara länkar från betrodda källor. Skadliga länkar kan äventyra din säkerhet eller integritet.\"],\"mHGdhG\":[\"Serverinformation\"],\"mHS8lb\":[\"Meddelande #\",[\"0\"]],\"mMYBD9\":[\"Brett – bredare skyddsomfång\"],\"mTGsPd\":[\"Kanalämne\"],\"mU8j6O\":[\"Inga externa meddelanden (+n)\"],\"mZp8FL\":[\"Automatisk återgång till enkel rad\"],\"mdQu8G\":[\"DittNick\"],\"miSSBQ\":[\"Kommentarer (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Användaren är autentiserad\"],\"mwtcGl\":[\"Stäng kommentarer\"],\"mzI/c+\":[\"Ladda ned\"],\"n3fGRk\":[\"angett av \",[\"0\"]],\"nE9jsU\":[\"Avslappnat – mindre aggressivt skydd\"],\"nNflMD\":[\"Lämna kanal\"],\"nPXkBi\":[\"Laddar WHOIS-data...\"],\"nQnxxF\":[\"Meddelande #\",[\"0\"],\" (Shift+Enter för ny rad)\"],\"nWMRxa\":[\"Lossa\"],\"nkC032\":[\"Ingen flödesprofil\"],\"o69z4d\":[\"Skicka ett varningsmeddelande till \",[\"username\"]],\"o9ylQi\":[\"Sök efter GIF:ar för att komma igång\"],\"oFGkER\":[\"Servermeddelanden\"],\"oOi11l\":[\"Scrolla till botten\"],\"oQEzQR\":[\"Nytt DM\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Man-in-the-middle-attacker på serverlänkar är möjliga\"],\"oeqmmJ\":[\"Betrodda källor\"],\"ovBPCi\":[\"Standard\"],\"p0Z69r\":[\"Mönstret kan inte vara tomt\"],\"p1KgtK\":[\"Det gick inte att läsa in ljud\"],\"p59pEv\":[\"Ytterligare detaljer\"],\"p7sRI6\":[\"Låt andra se när du skriver\"],\"pBm1od\":[\"Hemlig kanal\"],\"pNmiXx\":[\"Ditt standard-nick för alla servrar\"],\"pUUo9G\":[\"Värdnamn:\"],\"pVGPmz\":[\"Kontolösenord\"],\"peNE68\":[\"Permanent\"],\"plhHQt\":[\"Inga data\"],\"pm6+q5\":[\"Säkerhetsvarning\"],\"pn5qSs\":[\"Ytterligare information\"],\"q0cR4S\":[\"är nu känd som **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Kanalen visas inte i LIST- eller NAMES-kommandon\"],\"qLpTm/\":[\"Ta bort reaktion \",[\"emoji\"]],\"qVkGWK\":[\"Fäst\"],\"qY8wNa\":[\"Hemsida\"],\"qb0xJ7\":[\"Använd jokertecken: * matchar valfri sekvens, ? matchar valfritt enskilt tecken. Exempel: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanalnyckel (+k)\"],\"qtoOYG\":[\"Ingen gräns\"],\"r1W2AS\":[\"Filserverbild\"],\"rIPR2O\":[\"Ämne angivet före (min sedan)\"],\"rMMSYo\":[\"Maximal längd är \",[\"0\"]],\"rWtzQe\":[\"Nätverket splittrades och återanslöts. ✅\"],\"rYG2u6\":[\"Vänta...\"],\"rdUucN\":[\"Förhandsvisning\"],\"rjGI/Q\":[\"Integritet\"],\"rk8iDX\":[\"Laddar GIF:ar...\"],\"rn6SBY\":[\"Sluta tysta\"],\"s/UKqq\":[\"Sparkades ut från kanalen\"],\"s8cATI\":[\"gick med i \",[\"channelName\"]],\"sCO9ue\":[\"Anslutningen till <0>\",[\"serverName\"],\"</0> har följande säkerhetsproblem:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"är nu känd som **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" bjöd in dig att gå med i \",[\"channel\"]],\"sby+1/\":[\"Klicka för att kopiera\"],\"sfN25C\":[\"Ditt riktiga eller fullständiga namn\"],\"sliuzR\":[\"Öppna länk\"],\"sqrO9R\":[\"Anpassade omnämnanden\"],\"sr6RdJ\":[\"Flerrad med Shift+Enter\"],\"swrCpB\":[\"Kanalen har döpts om från \",[\"oldName\"],\" till \",[\"newName\"],\" av \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Avancerat\"],\"t/YqKh\":[\"Ta bort\"],\"t47eHD\":[\"Din unika identifierare på den här servern\"],\"tAkAh0\":[\"URL med valfri \",[\"size\"],\"-ersättning för dynamisk storleksanpassning. Exempel: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Visa eller dölj kanallistans sidopanel\"],\"tfDRzk\":[\"Spara\"],\"tiBsJk\":[\"lämnade \",[\"channelName\"]],\"tt4/UD\":[\"lämnade (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Smeknamnet {nick} används redan, försöker med {newNick}\"],\"u0a8B4\":[\"Autentisera som IRC-operatör för administrativ åtkomst\"],\"u0rWFU\":[\"Skapad efter (min sedan)\"],\"u72w3t\":[\"Användare och mönster att ignorera\"],\"u7jc2L\":[\"lämnade\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Sparning misslyckades: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-servrar:\"],\"usSSr/\":[\"Zoomnivå\"],\"v7uvcf\":[\"Programvara:\"],\"vE8kb+\":[\"Använd Shift+Enter för nya rader (Enter skickar)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Inget ämne\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Språk\"],\"vaHYxN\":[\"Riktigt namn\"],\"vhjbKr\":[\"Borta\"],\"w4NYox\":[[\"title\"],\" klient\"],\"w8xQRx\":[\"Ogiltigt värde\"],\"wFjjxZ\":[\"kastades ut från \",[\"channelName\"],\" av \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Inga banundantag hittades\"],\"wPrGnM\":[\"Kanaladmin\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Visa när användare går med i eller lämnar kanaler\"],\"whqZ9r\":[\"Ytterligare ord eller fraser att markera\"],\"wm7RV4\":[\"Aviseringsljud\"],\"wz/Yoq\":[\"Dina meddelanden kan avlyssnas när de vidarebefordras mellan servrar\"],\"xCJdfg\":[\"Rensa\"],\"xUHRTR\":[\"Autentisera automatiskt som operatör vid anslutning\"],\"xWHwwQ\":[\"Banningar\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Endast säkra websockets stöds\"],\"xdtXa+\":[\"kanalnamn\"],\"xfXC7q\":[\"Textkanaler\"],\"xlCYOE\":[\"Hämtar fler meddelanden...\"],\"xlhswE\":[\"Minimalt värde är \",[\"0\"]],\"xq97Ci\":[\"Lägg till ett ord eller en fras...\"],\"xuRqRq\":[\"Klientgräns (+l)\"],\"xwF+7J\":[[\"0\"],\" skriver...\"],\"yNeucF\":[\"Den här servern stöder inte utökad profilmetadata (IRCv3 METADATA-tillägget). Ytterligare fält som avatar, visningsnamn och status är inte tillgängliga.\"],\"yPlrca\":[\"Kanalavatar\"],\"yQE2r9\":[\"Laddar\"],\"ySU+JY\":[\"din@epost.se\"],\"yTX1Rt\":[\"Oper-användarnamn\"],\"yYOzWD\":[\"loggar\"],\"yfx9Re\":[\"IRC-operatörslösenord\"],\"ygCKqB\":[\"Stoppa\"],\"ymDxJx\":[\"IRC-operatörens användarnamn\"],\"yrpRsQ\":[\"Sortera efter namn\"],\"yz7wBu\":[\"Stäng\"],\"zJw+jA\":[\"anger läge: \",[\"0\"]],\"zebeLu\":[\"Ange oper-användarnamn\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Ogiltigt mönsterformat. Använd formatet nick!user@host (jokertecken * tillåts)\"],\"+6NQQA\":[\"Allmän supportkanal\"],\"+6NyRG\":[\"Klient\"],\"+K0AvT\":[\"Koppla från\"],\"+cyFdH\":[\"Standardmeddelande när du markerar dig som borta\"],\"+mVPqU\":[\"Rendera markdown-formatering i meddelanden\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Ditt kontoanvändarnamn för autentisering\"],\"+yPBXI\":[\"Välj fil\"],\"+zy2Nq\":[\"Typ\"],\"/09cao\":[\"Låg länksäkerhet (nivå \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Användare utanför kanalen kan inte skicka meddelanden till den\"],\"/6BzZF\":[\"Växla medlemslista\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Användaren är borta\"],\"/XQgft\":[\"Utforska\"],\"/cF7Rs\":[\"Volym\"],\"/dqduX\":[\"Nästa sida\"],\"/fc3q4\":[\"Allt innehåll\"],\"/kISDh\":[\"Aktivera aviseringsljud\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Ljud\"],\"/rfkZe\":[\"Spela upp ljud för omnämnanden och meddelanden\"],\"0/0ZGA\":[\"Kanalnamns-mask\"],\"0D6j7U\":[\"Läs mer om anpassade regler →\"],\"0XsHcR\":[\"Sparka ut användare\"],\"0ZpE//\":[\"Sortera efter användare\"],\"0bEPwz\":[\"Ange borta\"],\"0dGkPt\":[\"Expandera kanallistan\"],\"0gS7M5\":[\"Visningsnamn\"],\"0kS+M8\":[\"ExempelNÄT\"],\"0rgoY7\":[\"Anslut bara till servrar du väljer\"],\"0wdd7X\":[\"Gå med\"],\"0wkVYx\":[\"Privata meddelanden\"],\"111uHX\":[\"Länkförhandsvisning\"],\"196EG4\":[\"Ta bort privatchatt\"],\"1DSr1i\":[\"Registrera ett konto\"],\"1O/24y\":[\"Växla kanallista\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Varning för extern länk\"],\"1ZC/dv\":[\"Inga olästa omnämnanden eller meddelanden\"],\"1pO1zi\":[\"Servernamn krävs\"],\"1uwfzQ\":[\"Visa kanalämne\"],\"268g7c\":[\"Ange visningsnamn\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Serveroperatörer på nätverket kan potentiellt läsa dina meddelanden\"],\"2FYpfJ\":[\"Mer\"],\"2HF1Y2\":[[\"inviter\"],\" bjöd in \",[\"target\"],\" att gå med i \",[\"channel\"]],\"2I70QL\":[\"Visa användarprofilinformation\"],\"2QYdmE\":[\"Användare:\"],\"2QpEjG\":[\"lämnade\"],\"2YE223\":[\"Meddelande #\",[\"0\"],\" (Enter för ny rad, Shift+Enter för att skicka)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Använd serverlösenord\"],\"2iTmdZ\":[\"Lokal lagring:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Strikt – mer aggressivt skydd\"],\"2uDhbA\":[\"Ange användarnamn att bjuda in\"],\"2ygf/L\":[\"← Tillbaka\"],\"2zEgxj\":[\"Sök GIF:ar...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Byt namn på kanal\"],\"3THokf\":[\"Voice-användare\"],\"3TSz9S\":[\"Minimera\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Kanalens visningsnamn\"],\"3ryuFU\":[\"Valfria kraschrapporter för att förbättra appen\"],\"3uBF/8\":[\"Stäng visaren\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Ange kontonamn...\"],\"4/Rr0R\":[\"Bjud in en användare till den aktuella kanalen\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Regler\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flödesprofil (+F)\"],\"4RZQRK\":[\"Vad håller du på med?\"],\"4hfTrB\":[\"Nick\"],\"4n99LO\":[\"Redan i \",[\"0\"]],\"4t6vMV\":[\"Växla automatiskt till enkel rad för korta meddelanden\"],\"4vsHmf\":[\"Tid (min)\"],\"5+INAX\":[\"Markera meddelanden som nämner dig\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper-namn\"],\"678PKt\":[\"Nätverksnamn\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Offline\"],\"6CO3WE\":[\"Lösenord krävs för att gå med i kanalen. Lämna tomt för att ta bort nyckeln.\"],\"6HhMs3\":[\"Quit-meddelande\"],\"6V3Ea3\":[\"Kopierat\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Visa mindre\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Ange oper-lösenord...\"],\"7+IHTZ\":[\"Ingen fil vald\"],\"73hrRi\":[\"nick!user@host (t.ex. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Skicka privat meddelande\"],\"7U1W7c\":[\"Mycket avslappnat\"],\"7Y1YQj\":[\"Riktigt namn:\"],\"7YHArF\":[\"— öppna i visaren\"],\"7fjnVl\":[\"Sök användare...\"],\"7jL88x\":[\"Ta bort det här meddelandet? Det kan inte ångras.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Vad tänker du på?\"],\"7sEpu1\":[\"Medlemmar — \",[\"0\"]],\"7sNhEz\":[\"Användarnamn\"],\"8H0Q+x\":[\"Läs mer om profiler →\"],\"8Phu0A\":[\"Visa när användare byter nick\"],\"8XTG9e\":[\"Ange oper-lösenord\"],\"8XsV2J\":[\"Försök skicka igen\"],\"8ZsakT\":[\"Lösenord\"],\"8kR84m\":[\"Du håller på att öppna en extern länk:\"],\"8lCgih\":[\"Ta bort regel\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"gick med\"],\"other\":[\"gick med \",[\"joinCount\"],\" gånger\"]}]],\"9BMLnJ\":[\"Återanslut till server\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Lägg till reaktion\"],\"9PQ8m2\":[\"G-Line (globalt ban)\"],\"9Qs99X\":[\"E-post:\"],\"9QupBP\":[\"Ta bort mönster\"],\"9bG48P\":[\"Skickar\"],\"9f5f0u\":[\"Frågor om integritet? Kontakta oss:\"],\"9unqs3\":[\"Frånvarande:\"],\"9v3hwv\":[\"Inga servrar hittades.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Ansluter\"],\"A1taO8\":[\"Sök\"],\"A2adVi\":[\"Skicka skriv-aviseringar\"],\"A9Rhec\":[\"Kanalnamn\"],\"AWOSPo\":[\"Zooma in\"],\"AXSpEQ\":[\"Oper vid anslutning\"],\"AeXO77\":[\"Konto\"],\"AhNP40\":[\"Sök position\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Bytte nick\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Avbryt svar\"],\"ApSx0O\":[\"Hittade \",[\"0\"],\" meddelanden som matchar \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Inga resultat hittades\"],\"AyNqAB\":[\"Visa alla serverhändelser i chatten\"],\"B/QqGw\":[\"Borta från tangentbordet\"],\"B8AaMI\":[\"Det här fältet krävs\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Servern stöder inte avancerad LIST-filtrering\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" och \",[\"3\"],\" andra skriver...\"],\"BGul2A\":[\"Du har osparade ändringar. Är du säker på att du vill stänga utan att spara?\"],\"BIf9fi\":[\"Ditt statusmeddelande\"],\"BZz3md\":[\"Din personliga webbplats\"],\"Bgm/H7\":[\"Tillåt att skriva flera rader text\"],\"BiQIl1\":[\"Fäst den här privata meddelandekonversationen\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Klicka för att hoppa till meddelandet\"],\"Bowq3c\":[\"Endast operatörer kan ändra kanalämnet\"],\"Btozzp\":[\"Den här bilden har gått ut\"],\"Bycfjm\":[\"Totalt: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Kopiera hela JSON\"],\"C9L9wL\":[\"Datainsamling\"],\"CDq4wC\":[\"Moderera användare\"],\"CHVRxG\":[\"Meddelande @\",[\"0\"],\" (Shift+Enter för ny rad)\"],\"CN9zdR\":[\"Oper-namn och lösenord krävs\"],\"CW3sYa\":[\"Lägg till reaktion \",[\"emoji\"]],\"CaAkqd\":[\"Visa quit-meddelanden\"],\"CbvaYj\":[\"Banna via nick\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Välj en kanal\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"System\"],\"D28t6+\":[\"gick med och lämnade\"],\"DB8zMK\":[\"Tillämpa\"],\"DBcWHr\":[\"Anpassad aviseringsljudfil\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Medieförhandsvisningar\"],\"Dj4pSr\":[\"Välj ett säkert lösenord\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Söker...\"],\"Du2T2f\":[\"Inställningen hittades inte\"],\"DwsSVQ\":[\"Tillämpa filter och uppdatera\"],\"E3W/zd\":[\"Standard-nick\"],\"E6nRW7\":[\"Kopiera URL\"],\"E703RG\":[\"Lägen:\"],\"EAeu1Z\":[\"Skicka inbjudan\"],\"EFKJQT\":[\"Inställning\"],\"EGPQBv\":[\"Anpassade flödesregler (+f)\"],\"ELik0r\":[\"Visa fullständig integritetspolicy\"],\"EPbeC2\":[\"Visa eller redigera kanalämnet\"],\"EQCDNT\":[\"Ange oper-användarnamn...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Hittade 1 meddelande som matchar \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Nästa bild\"],\"EdQY6l\":[\"Ingen\"],\"EnqLYU\":[\"Sök servrar...\"],\"F0OKMc\":[\"Redigera server\"],\"F6Int2\":[\"Aktivera markeringar\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Max användare\"],\"FUU/hZ\":[\"Styr hur mycket externt media som laddas i chatten.\"],\"Fdp03t\":[\"på\"],\"FfPWR0\":[\"Dialog\"],\"FjkaiT\":[\"Zooma ut\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Vad detta innebär:\"],\"FolHNl\":[\"Hantera ditt konto och autentisering\"],\"Fp2Dif\":[\"Lämnade servern\"],\"G5KmCc\":[\"GZ-Line (global Z-Line)\"],\"GDs0lz\":[\"<0>Risk:</0> Känslig information (meddelanden, privata konversationer, autentiseringsuppgifter) kan exponeras för nätverksadministratörer eller angripare som befinner sig mellan IRC-servrar.\"],\"GR+2I3\":[\"Lägg till inbjudningsmask (t.ex. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Stäng utfällt servermeddelanden-fönster\"],\"GlHnXw\":[\"Namnbyte misslyckades: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Förhandsvisning:\"],\"GtmO8/\":[\"från\"],\"GtuHUQ\":[\"Byt namn på den här kanalen på servern. Alla användare ser det nya namnet.\"],\"GuGfFX\":[\"Växla sökning\"],\"GxkJXS\":[\"Laddar upp...\"],\"GzbwnK\":[\"Gick med i kanalen\"],\"GzsUDB\":[\"Utökad profil\"],\"H/PnT8\":[\"Infoga emoji\"],\"H6Izzl\":[\"Din föredragna färgkod\"],\"H9jIv+\":[\"Visa join/part\"],\"HAKBY9\":[\"Ladda upp filer\"],\"HdE1If\":[\"Kanal\"],\"Hk4AW9\":[\"Ditt föredragna visningsnamn\"],\"HmHDk7\":[\"Välj medlem\"],\"HrQzPU\":[\"Kanaler på \",[\"networkName\"]],\"I2tXQ5\":[\"Meddelande @\",[\"0\"],\" (Enter för ny rad, Shift+Enter för att skicka)\"],\"I6bw/h\":[\"Banna användare\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Aktivera aviseringar\"],\"I9D72S\":[\"Är du säker på att du vill ta bort det här meddelandet? Den här åtgärden kan inte ångras.\"],\"IA+1wo\":[\"Visa när användare sparkats ut från kanaler\"],\"IDwkJx\":[\"IRC-operatör\"],\"ILlU+s\":[\"Info:\"],\"IUwGEM\":[\"Spara ändringar\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" och \",[\"2\"],\" skriver...\"],\"IgrLD/\":[\"Pausa\"],\"Im6JED\":[\"VISKNING\"],\"ImOQa9\":[\"Svara\"],\"IoHMnl\":[\"Maximalt värde är \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Ansluter...\"],\"J5T9NW\":[\"Användarinformation\"],\"J8Y5+z\":[\"Hoppsan! Nätverksuppdelning! ⚠️\"],\"JBHkBA\":[\"Lämnade kanalen\"],\"JCwL0Q\":[\"Ange anledning (valfritt)\"],\"JFciKP\":[\"Växla\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Ändra kanalnamnet (endast operatörer)\"],\"JcD7qf\":[\"Fler åtgärder\"],\"JdkA+c\":[\"Hemlig (+s)\"],\"Jmu12l\":[\"Serverkanaler\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Aktivera Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Ingen WHOIS-data tillgänglig\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Ta bort meddelande\"],\"KKBlUU\":[\"Inbäddning\"],\"KM0pLb\":[\"Välkommen till kanalen!\"],\"KR6W2h\":[\"Sluta ignorera användare\"],\"KV+Bi1\":[\"Endast inbjudna (+i)\"],\"KdCtwE\":[\"Hur många sekunder flödaktivitet ska övervakas innan räknarna återställs\"],\"Kkezga\":[\"Serverlösenord\"],\"KsiQ/8\":[\"Användare måste bjudas in för att gå med i kanalen\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Kanalinformation\"],\"LC1a7n\":[\"IRC-servern har rapporterat att dess server-till-server-länkar har en låg säkerhetsnivå. Det innebär att när dina meddelanden vidarebefordras mellan IRC-servrar i nätverket kanske de inte krypteras ordentligt eller att SSL/TLS-certifikaten inte valideras korrekt.\"],\"LNfLR5\":[\"Visa utsparkningar\"],\"LQb0W/\":[\"Visa alla händelser\"],\"LU7/yA\":[\"Alternativt namn för visning i gränssnittet. Får innehålla mellanslag, emoji och specialtecken. Det riktiga kanalnamnet (\",[\"channelName\"],\") används fortfarande för IRC-kommandon.\"],\"LUb9O7\":[\"Giltig serverport krävs\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Integritetspolicy\"],\"LcuSDR\":[\"Hantera din profilinformation och metadata\"],\"LqLS9B\":[\"Visa nickbyten\"],\"LsDQt2\":[\"Kanalinställningar\"],\"LtI9AS\":[\"Ägare\"],\"LuNhhL\":[\"reagerade på det här meddelandet\"],\"M/AZNG\":[\"URL till din avatarbild\"],\"M/WIer\":[\"Skicka meddelande\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Namn:\"],\"MHk+7g\":[\"Föregående bild\"],\"MRorGe\":[\"PM-användare\"],\"MVbSGP\":[\"Tidsfönster (sekunder)\"],\"MkpcsT\":[\"Dina meddelanden och inställningar lagras lokalt på din enhet\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Markera som bot – vanligtvis 'on' eller tomt\"],\"N7TQbE\":[\"Bjud in användare till \",[\"channelName\"]],\"NCca/o\":[\"Ange standardsmeknamn...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Visar allt externt media. Valfri URL kan orsaka en begäran till en okänd server.\"],\"Nt+9O7\":[\"Använd WebSocket istället för råa TCP\"],\"NxIHzc\":[\"Koppla från användare\"],\"O+v/cL\":[\"Bläddra bland alla kanaler på servern\"],\"ODwSCk\":[\"Skicka en GIF\"],\"OGQ5kK\":[\"Konfigurera aviseringsljud och markeringar\"],\"OIPt1Z\":[\"Visa eller dölj medlemslistans sidopanel\"],\"OKSNq/\":[\"Mycket strikt\"],\"ONWvwQ\":[\"Ladda upp\"],\"OVKoQO\":[\"Ditt kontolösenord för autentisering\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - För IRC in i framtiden\"],\"OhCpra\":[\"Ange ett ämne…\"],\"OkltoQ\":[\"Banna \",[\"username\"],\" via nick (förhindrar återanslutning med samma nick)\"],\"P+t/Te\":[\"Inga ytterligare data\"],\"P42Wcc\":[\"Säkert\"],\"PD38l0\":[\"Förhandsvisning av kanalavatar\"],\"PD9mEt\":[\"Skriv ett meddelande...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Öppna kanalens konfigurationsinställningar\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Ämnet som visas för den här kanalen. Alla användare kan se ämnet.\"],\"PZCecv\":[\"PDF-förhandsgranskning\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 gång\"],\"other\":[[\"c\"],\" gånger\"]}]],\"PguS2C\":[\"Lägg till undantagsmask (t.ex. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Visar \",[\"displayedChannelsCount\"],\" av \",[\"0\"],\" kanaler\"],\"PqhVlJ\":[\"Banna användare (via hostmask)\"],\"Q+chwU\":[\"Användarnamn:\"],\"Q6hhn8\":[\"Inställningar\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Ange ett användarnamn\"],\"QGqSZ2\":[\"Färg och formatering\"],\"QJQd1J\":[\"Redigera profil\"],\"QSzGDE\":[\"Inaktiv\"],\"QUlny5\":[\"Välkommen till \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Läs mer\"],\"QuSkCF\":[\"Filtrera kanaler...\"],\"QwUrDZ\":[\"ändrade ämnet till: \",[\"topic\"]],\"R0UH07\":[\"Bild \",[\"0\"],\" av \",[\"1\"]],\"R7SsBE\":[\"Tysta\"],\"R8rf1X\":[\"Klicka för att ange ämne\"],\"RArB3D\":[\"kastades ut från \",[\"channelName\"],\" av \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Utforska IRC-världen med ObsidianIRC\"],\"RMMaN5\":[\"Modererad (+m)\"],\"RWw9Lg\":[\"Stäng dialog\"],\"RZ2BuZ\":[\"Kontoregistrering för \",[\"account\"],\" kräver verifiering: \",[\"message\"]],\"RySp6q\":[\"Dölj kommentarer\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Nick krävs\"],\"SPVjfj\":[\"Standardvärdet är 'ingen anledning' om det lämnas tomt\"],\"SQKPvQ\":[\"Bjud in användare\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Välj en fördefinierad flödeskyddsprofil. Dessa profiler ger balanserade skyddsinställningar för olika användningsfall.\"],\"Slr+3C\":[\"Min användare\"],\"Spnlre\":[\"Du bjöd in \",[\"target\"],\" att gå med i \",[\"channel\"]],\"T/ckN5\":[\"Öppna i visaren\"],\"T91vKp\":[\"Spela upp\"],\"TV2Wdu\":[\"Läs om hur vi hanterar dina uppgifter och skyddar din integritet.\"],\"TgFpwD\":[\"Tillämpar...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Inga ändringar\"],\"TtserG\":[\"Ange riktigt namn\"],\"Ttz9J1\":[\"Ange lösenord...\"],\"Tz0i8g\":[\"Inställningar\"],\"U3pytU\":[\"Admin\"],\"UDb2YD\":[\"Reagera\"],\"UE4KO5\":[\"*kanal*\"],\"UGT5vp\":[\"Spara inställningar\"],\"UV5hLB\":[\"Inga banningar hittades\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Statusmeddelanden\"],\"Ue3uny\":[\"Standard (ingen profil)\"],\"UkARhe\":[\"Normal – standardskydd\"],\"Umn7Cj\":[\"Inga kommentarer ännu. Var den första!\"],\"UtUIRh\":[[\"0\"],\" äldre meddelanden\"],\"UwzP+U\":[\"Säker anslutning\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Kanalägare\"],\"V4qgxE\":[\"Skapad före (min sedan)\"],\"V8yTm6\":[\"Rensa sökning\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - För IRC in i framtiden\"],\"VJScHU\":[\"Anledning\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Tysta aviseringar\"],\"VbyRUy\":[\"Kommentarer\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Satt av:\"],\"VqnIZz\":[\"Visa vår integritetspolicy och datapraxis\"],\"VrMygG\":[\"Minimal längd är \",[\"0\"]],\"VrnTui\":[\"Dina pronomen, visas i din profil\"],\"W8E3qn\":[\"Autentiserat konto\"],\"WAakm9\":[\"Ta bort kanal\"],\"WFxTHC\":[\"Lägg till banmask (t.ex. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Serveradress krävs\"],\"WRYdXW\":[\"Ljudposition\"],\"WUOH5B\":[\"Ignorera användare\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Visa 1 till\"],\"other\":[\"Visa \",[\"1\"],\" till\"]}]],\"Weq9zb\":[\"Allmänt\"],\"Wfj7Sk\":[\"Tysta eller aktivera aviseringsljud\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*skräp*\"],\"WzMCru\":[\"Användarprofil\"],\"X6S3lt\":[\"Sök inställningar, kanaler, servrar...\"],\"XEHan5\":[\"Fortsätt ändå\"],\"XI1+wb\":[\"Ogiltigt format\"],\"XIXeuC\":[\"Meddelande @\",[\"0\"]],\"XMS+k4\":[\"Starta privat meddelande\"],\"XWgxXq\":[\"Album\"],\"Xd7+IT\":[\"Lossa privatchatt\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Visning\"],\"Xp2n93\":[\"Visar media från serverns betrodda filvärd. Inga begäranden görs till externa tjänster.\"],\"XvjC4F\":[\"Sparar...\"],\"Y/qryO\":[\"Inga användare hittades som matchar din sökning\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Kontoregistrering för \",[\"account\"],\" lyckades: \",[\"message\"]],\"YEfzvP\":[\"Skyddat ämne (+t)\"],\"YQOn6a\":[\"Dölj medlemslistan\"],\"YRCoE9\":[\"Kanaloperatör\"],\"YURQaF\":[\"Visa profil\"],\"YdBSvr\":[\"Styr medievisning och externt innehåll\"],\"Yj6U3V\":[\"Ingen central server:\"],\"YjvpGx\":[\"Pronomen\"],\"YqH4l4\":[\"Ingen nyckel\"],\"YyUPpV\":[\"Konto:\"],\"ZJSWfw\":[\"Meddelande som visas när du kopplar från servern\"],\"ZR1dJ4\":[\"Inbjudningar\"],\"ZdWg0V\":[\"Öppna i webbläsare\"],\"ZhRBbl\":[\"Sök meddelanden…\"],\"Zmcu3y\":[\"Avancerade filter\"],\"a2/8e5\":[\"Ämne angivet efter (min sedan)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Föregående sida\"],\"aJTbXX\":[\"Oper-lösenord\"],\"aQryQv\":[\"Mönstret finns redan\"],\"aW9pLN\":[\"Maximalt antal användare som tillåts i kanalen. Lämna tomt för ingen gräns.\"],\"ah4fmZ\":[\"Visar också förhandsvisningar från YouTube, Vimeo, SoundCloud och liknande kända tjänster.\"],\"aifXak\":[\"Inget media i den här kanalen\"],\"ap2zBz\":[\"Avslappnat\"],\"az8lvo\":[\"Av\"],\"azXSNo\":[\"Expandera medlemslistan\"],\"azdliB\":[\"Logga in på ett konto\"],\"b26wlF\":[\"hon/hennes\"],\"bD/+Ei\":[\"Strikt\"],\"bQ6BJn\":[\"Konfigurera detaljerade flödeskyddsregler. Varje regel anger vilken typ av aktivitet som ska övervakas och vilken åtgärd som ska vidtas när gränser överskrids.\"],\"beV7+y\":[\"Användaren får en inbjudan att gå med i \",[\"channelName\"],\".\"],\"bk84cH\":[\"Borta-meddelande\"],\"bkHdLj\":[\"Lägg till IRC-server\"],\"bmQLn5\":[\"Lägg till regel\"],\"bwRvnp\":[\"Åtgärd\"],\"c8+EVZ\":[\"Verifierat konto\"],\"cGYUlD\":[\"Inga medieförhandsvisningar laddas.\"],\"cLF98o\":[\"Visa kommentarer (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Inga användare tillgängliga\"],\"cSgpoS\":[\"Fäst privatchatt\"],\"cde3ce\":[\"Meddelande <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Kopiera formaterad utdata\"],\"cl/A5J\":[\"Välkommen till \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Ta bort\"],\"coPLXT\":[\"Vi lagrar inte dina IRC-kommunikationer på våra servrar\"],\"crYH/6\":[\"SoundCloud-spelare\"],\"d3sis4\":[\"Lägg till server\"],\"d9aN5k\":[\"Ta bort \",[\"username\"],\" från kanalen\"],\"dEgA5A\":[\"Avbryt\"],\"dGi1We\":[\"Lossa den här privata meddelandekonversationen\"],\"dJVuyC\":[\"lämnade \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"till\"],\"dXqxlh\":[\"<0>⚠️ Säkerhetsrisk!</0> Den här anslutningen kan vara sårbar för avlyssning eller man-in-the-middle-attacker.\"],\"da9Q/R\":[\"Ändrade kanallägen\"],\"dhJN3N\":[\"Visa kommentarer\"],\"dj2xTE\":[\"Stäng avisering\"],\"dpCzmC\":[\"Inställningar för flödeskydd\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Vill du öppna den här länken i en ny flik?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Redigera\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Ladda upp en bild eller ange en URL med valfri \",[\"size\"],\"-ersättning för dynamisk storleksanpassning\"],\"edBbee\":[\"Banna \",[\"username\"],\" via hostmask (förhindrar återanslutning från samma IP/host)\"],\"ekfzWq\":[\"Användarinställningar\"],\"elPDWs\":[\"Anpassa din IRC-klientupplevelse\"],\"eu2osY\":[\"<0>💡 Rekommendation:</0> Fortsätt bara om du litar på den här servern och förstår riskerna. Undvik att dela känslig information eller lösenord via den här anslutningen.\"],\"euEhbr\":[\"Klicka för att gå med i \",[\"channel\"]],\"ez3vLd\":[\"Aktivera flerrads-inmatning\"],\"f0J5Ki\":[\"Server-till-server-kommunikation kan använda okrypterade anslutningar\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Varna användare\"],\"fDOLLd\":[\"Inga kanaler hittades.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonym analys:\"],\"fq1GF9\":[\"Visa när användare kopplar från servern\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Den här servern stöder bara en anslutningstyp\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Ignorera-lista\"],\"gNzMrk\":[\"Nuvarande avatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Ange smeknamn...\"],\"gz6UQ3\":[\"Maximera\"],\"h6razj\":[\"Uteslut kanalnamns-mask\"],\"hG6jnw\":[\"Inget ämne angivet\"],\"hG89Ed\":[\"Bild\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"t.ex. 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Mängd\"],\"hzdLuQ\":[\"Endast användare med Voice eller högre kan tala\"],\"i0qMbr\":[\"Hem\"],\"iDNBZe\":[\"Aviseringar\"],\"iH8pgl\":[\"Tillbaka\"],\"iL9SZg\":[\"Banna användare (via nick)\"],\"iNt+3c\":[\"Tillbaka till bild\"],\"iQvi+a\":[\"Varna mig inte om låg länksäkerhet för den här servern\"],\"iSLIjg\":[\"Anslut\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Serveradress\"],\"idD8Ev\":[\"Sparat\"],\"iivqkW\":[\"Inloggad sedan\"],\"ij+Elv\":[\"Bildförhandsvisning\"],\"ilIWp7\":[\"Växla aviseringar\"],\"iuaqvB\":[\"Använd * som jokertecken. Exempel: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Banna via hostmask\"],\"jA4uoI\":[\"Ämne:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Anledning (valfritt)\"],\"jUV7CU\":[\"Ladda upp avatar\"],\"jW5Uwh\":[\"Styr hur mycket externt media som laddas. Av / Säkert / Betrodda källor / Allt innehåll.\"],\"jXzms5\":[\"Bilagealternativ\"],\"jZlrte\":[\"Färg\"],\"jfC/xh\":[\"Kontakt\"],\"jywMpv\":[\"#nytt-kanalnamn\"],\"k112DD\":[\"Ladda äldre meddelanden\"],\"k3ID0F\":[\"Filtrera medlemmar…\"],\"k65gsE\":[\"Fördjupning\"],\"k7Zgob\":[\"Avbryt anslutning\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Inga inbjudningar hittades\"],\"kCLEPU\":[\"Ansluten till\"],\"kF5LKb\":[\"Ignorerade mönster:\"],\"kGeOx/\":[\"Gå med i \",[\"0\"]],\"kITKr8\":[\"Laddar kanallägen...\"],\"kPpPsw\":[\"Du är en IRC-operatör\"],\"kWJmRL\":[\"Du\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"Kopiera JSON\"],\"krViRy\":[\"Klicka för att kopiera som JSON\"],\"ks71ra\":[\"Undantag\"],\"kw4lRv\":[\"Kanal-halvoperatör\"],\"kxgIRq\":[\"Välj eller lägg till en kanal för att komma igång.\"],\"ky6dWe\":[\"Förhandsvisning av avatar\"],\"l+GxCv\":[\"Laddar kanaler...\"],\"l+IUVW\":[\"Kontoverifiering för \",[\"account\"],\" lyckades: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"återanslöt\"],\"other\":[\"återanslöt \",[\"reconnectCount\"],\" gånger\"]}]],\"l5jmzx\":[[\"0\"],\" och \",[\"1\"],\" skriver...\"],\"lHy8N5\":[\"Laddar fler kanaler...\"],\"lbpf14\":[\"Gå med i \",[\"value\"]],\"lfFsZ4\":[\"Kanaler\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Kontonamn\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Ladda upp bild\"],\"loQxaJ\":[\"Jag är tillbaka\"],\"lvfaxv\":[\"HEM\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Lägg till\"],\"m8flAk\":[\"Förhandsvisning (ej uppladdad ännu)\"],\"mEPxTp\":[\"<0>⚠️ Var försiktig!</0> Öppna bara länkar från betrodda källor. Skadliga länkar kan äventyra din säkerhet eller integritet.\"],\"mHGdhG\":[\"Serverinformation\"],\"mHS8lb\":[\"Meddelande #\",[\"0\"]],\"mMYBD9\":[\"Brett – bredare skyddsomfång\"],\"mTGsPd\":[\"Kanalämne\"],\"mU8j6O\":[\"Inga externa meddelanden (+n)\"],\"mZp8FL\":[\"Automatisk återgång till enkel rad\"],\"mdQu8G\":[\"DittNick\"],\"miSSBQ\":[\"Kommentarer (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Användaren är autentiserad\"],\"mwtcGl\":[\"Stäng kommentarer\"],\"mzI/c+\":[\"Ladda ned\"],\"n3fGRk\":[\"angett av \",[\"0\"]],\"nE9jsU\":[\"Avslappnat – mindre aggressivt skydd\"],\"nNflMD\":[\"Lämna kanal\"],\"nPXkBi\":[\"Laddar WHOIS-data...\"],\"nQnxxF\":[\"Meddelande #\",[\"0\"],\" (Shift+Enter för ny rad)\"],\"nWMRxa\":[\"Lossa\"],\"nkC032\":[\"Ingen flödesprofil\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Skicka ett varningsmeddelande till \",[\"username\"]],\"o9ylQi\":[\"Sök efter GIF:ar för att komma igång\"],\"oFGkER\":[\"Servermeddelanden\"],\"oOi11l\":[\"Scrolla till botten\"],\"oQEzQR\":[\"Nytt DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Online\"],\"oal760\":[\"Man-in-the-middle-attacker på serverlänkar är möjliga\"],\"oeqmmJ\":[\"Betrodda källor\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Standard\"],\"p0Z69r\":[\"Mönstret kan inte vara tomt\"],\"p1KgtK\":[\"Det gick inte att läsa in ljud\"],\"p59pEv\":[\"Ytterligare detaljer\"],\"p7sRI6\":[\"Låt andra se när du skriver\"],\"pBm1od\":[\"Hemlig kanal\"],\"pNmiXx\":[\"Ditt standard-nick för alla servrar\"],\"pUUo9G\":[\"Värdnamn:\"],\"pVGPmz\":[\"Kontolösenord\"],\"peNE68\":[\"Permanent\"],\"plhHQt\":[\"Inga data\"],\"pm6+q5\":[\"Säkerhetsvarning\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Ytterligare information\"],\"q0cR4S\":[\"är nu känd som **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Kanalen visas inte i LIST- eller NAMES-kommandon\"],\"qLpTm/\":[\"Ta bort reaktion \",[\"emoji\"]],\"qVkGWK\":[\"Fäst\"],\"qY8wNa\":[\"Hemsida\"],\"qb0xJ7\":[\"Använd jokertecken: * matchar valfri sekvens, ? matchar valfritt enskilt tecken. Exempel: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanalnyckel (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Ingen gräns\"],\"r1W2AS\":[\"Filserverbild\"],\"rIPR2O\":[\"Ämne angivet före (min sedan)\"],\"rMMSYo\":[\"Maximal längd är \",[\"0\"]],\"rWtzQe\":[\"Nätverket splittrades och återanslöts. ✅\"],\"rYG2u6\":[\"Vänta...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Förhandsvisning\"],\"rjGI/Q\":[\"Integritet\"],\"rk8iDX\":[\"Laddar GIF:ar...\"],\"rn6SBY\":[\"Sluta tysta\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Sparkades ut från kanalen\"],\"s8cATI\":[\"gick med i \",[\"channelName\"]],\"sCO9ue\":[\"Anslutningen till <0>\",[\"serverName\"],\"</0> har följande säkerhetsproblem:\"],\"sGH11W\":[\"Server\"],\"sHI1H+\":[\"är nu känd som **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" bjöd in dig att gå med i \",[\"channel\"]],\"sby+1/\":[\"Klicka för att kopiera\"],\"sfN25C\":[\"Ditt riktiga eller fullständiga namn\"],\"sliuzR\":[\"Öppna länk\"],\"sqrO9R\":[\"Anpassade omnämnanden\"],\"sr6RdJ\":[\"Flerrad med Shift+Enter\"],\"swrCpB\":[\"Kanalen har döpts om från \",[\"oldName\"],\" till \",[\"newName\"],\" av \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Avancerat\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Ta bort\"],\"t47eHD\":[\"Din unika identifierare på den här servern\"],\"tAkAh0\":[\"URL med valfri \",[\"size\"],\"-ersättning för dynamisk storleksanpassning. Exempel: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Visa eller dölj kanallistans sidopanel\"],\"tfDRzk\":[\"Spara\"],\"tiBsJk\":[\"lämnade \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"lämnade (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Smeknamnet {nick} används redan, försöker med {newNick}\"],\"u0a8B4\":[\"Autentisera som IRC-operatör för administrativ åtkomst\"],\"u0rWFU\":[\"Skapad efter (min sedan)\"],\"u72w3t\":[\"Användare och mönster att ignorera\"],\"u7jc2L\":[\"lämnade\"],\"uAQUqI\":[\"Status\"],\"uB85T3\":[\"Sparning misslyckades: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC-servrar:\"],\"usSSr/\":[\"Zoomnivå\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Programvara:\"],\"vE8kb+\":[\"Använd Shift+Enter för nya rader (Enter skickar)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Inget ämne\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Språk\"],\"vaHYxN\":[\"Riktigt namn\"],\"vhjbKr\":[\"Borta\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" klient\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Ogiltigt värde\"],\"wFjjxZ\":[\"kastades ut från \",[\"channelName\"],\" av \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Inga banundantag hittades\"],\"wPrGnM\":[\"Kanaladmin\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Visa när användare går med i eller lämnar kanaler\"],\"whqZ9r\":[\"Ytterligare ord eller fraser att markera\"],\"wm7RV4\":[\"Aviseringsljud\"],\"wz/Yoq\":[\"Dina meddelanden kan avlyssnas när de vidarebefordras mellan servrar\"],\"xCJdfg\":[\"Rensa\"],\"xUHRTR\":[\"Autentisera automatiskt som operatör vid anslutning\"],\"xWHwwQ\":[\"Banningar\"],\"xYilR2\":[\"Media\"],\"xceQrO\":[\"Endast säkra websockets stöds\"],\"xdtXa+\":[\"kanalnamn\"],\"xfXC7q\":[\"Textkanaler\"],\"xlCYOE\":[\"Hämtar fler meddelanden...\"],\"xlhswE\":[\"Minimalt värde är \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Lägg till ett ord eller en fras...\"],\"xuRqRq\":[\"Klientgräns (+l)\"],\"xwF+7J\":[[\"0\"],\" skriver...\"],\"yNeucF\":[\"Den här servern stöder inte utökad profilmetadata (IRCv3 METADATA-tillägget). Ytterligare fält som avatar, visningsnamn och status är inte tillgängliga.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Kanalavatar\"],\"yQE2r9\":[\"Laddar\"],\"ySU+JY\":[\"din@epost.se\"],\"yTX1Rt\":[\"Oper-användarnamn\"],\"yYOzWD\":[\"loggar\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC-operatörslösenord\"],\"ygCKqB\":[\"Stoppa\"],\"ymDxJx\":[\"IRC-operatörens användarnamn\"],\"yrpRsQ\":[\"Sortera efter namn\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Stäng\"],\"zJw+jA\":[\"anger läge: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Ange oper-användarnamn\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Geçersiz desen biçimi. nick!user@host biçimini kullanın (joker karakter * kullanılabilir)\"],\"+6NQQA\":[\"Genel Destek Kanalı\"],\"+6NyRG\":[\"İstemci\"],\"+K0AvT\":[\"Bağlantıyı Kes\"],\"+cyFdH\":[\"Uzakta olarak işaretlendiğinde gösterilecek varsayılan mesaj\"],\"+mVPqU\":[\"Mesajlarda markdown biçimlendirmesini işle\"],\"+vqCJH\":[\"Kimlik doğrulama için hesap kullanıcı adınız\"],\"+yPBXI\":[\"Dosya seç\"],\"+zy2Nq\":[\"Tür\"],\"/09cao\":[\"Düşük Bağlantı Güvenliği (Seviye \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Kanal dışındaki kullanıcılar kanala mesaj gönderemez\"],\"/6BzZF\":[\"Üye Listesini Aç/Kapat\"],\"/TNOPk\":[\"Kullanıcı uzakta\"],\"/XQgft\":[\"Keşfet\"],\"/cF7Rs\":[\"Ses Seviyesi\"],\"/dqduX\":[\"Sonraki sayfa\"],\"/fc3q4\":[\"Tüm İçerik\"],\"/kISDh\":[\"Bildirim Seslerini Etkinleştir\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Ses\"],\"/rfkZe\":[\"Bahisler ve mesajlar için ses çal\"],\"0/0ZGA\":[\"Kanal Adı Maskesi\"],\"0D6j7U\":[\"Özel kurallar hakkında daha fazla bilgi →\"],\"0XsHcR\":[\"Kullanıcıyı At\"],\"0ZpE//\":[\"Kullanıcıya Göre Sırala\"],\"0bEPwz\":[\"Uzakta Olarak İşaretle\"],\"0dGkPt\":[\"Kanal listesini genişlet\"],\"0gS7M5\":[\"Görünen Ad\"],\"0kS+M8\":[\"ÖrnekAĞ\"],\"0rgoY7\":[\"Yalnızca seçtiğiniz sunuculara bağlanın\"],\"0wdd7X\":[\"Katıl\"],\"0wkVYx\":[\"Özel Mesajlar\"],\"111uHX\":[\"Bağlantı önizlemesi\"],\"196EG4\":[\"Özel Sohbeti Sil\"],\"1DSr1i\":[\"Hesap kaydı oluştur\"],\"1O/24y\":[\"Kanal Listesini Aç/Kapat\"],\"1VPJJ2\":[\"Harici Bağlantı Uyarısı\"],\"1ZC/dv\":[\"Okunmamış bahis veya mesaj yok\"],\"1pO1zi\":[\"Sunucu adı gereklidir\"],\"1uwfzQ\":[\"Kanal Konusunu Görüntüle\"],\"268g7c\":[\"Görünen adı girin\"],\"2FOFq1\":[\"Ağdaki sunucu operatörleri mesajlarınızı okuyabilir\"],\"2FYpfJ\":[\"Daha fazla\"],\"2HF1Y2\":[[\"inviter\"],\", \",[\"target\"],\" kişisini \",[\"channel\"],\" kanalına davet etti\"],\"2I70QL\":[\"Kullanıcı profil bilgilerini görüntüle\"],\"2QYdmE\":[\"Kullanıcılar:\"],\"2QpEjG\":[\"ayrıldı\"],\"2YE223\":[\"#\",[\"0\"],\" kanalına mesaj (yeni satır için Enter, göndermek için Shift+Enter)\"],\"2bimFY\":[\"Sunucu şifresini kullan\"],\"2iTmdZ\":[\"Yerel Depolama:\"],\"2odkwe\":[\"Katı - Daha agresif koruma\"],\"2uDhbA\":[\"Davet edilecek kullanıcı adını girin\"],\"2ygf/L\":[\"← Geri\"],\"2zEgxj\":[\"GIF ara...\"],\"3RdPhl\":[\"Kanalı Yeniden Adlandır\"],\"3THokf\":[\"Sesli Kullanıcı\"],\"3TSz9S\":[\"Küçült\"],\"3jBDvM\":[\"Kanal Görünen Adı\"],\"3ryuFU\":[\"Uygulamayı geliştirmek için isteğe bağlı çökme raporları\"],\"3uBF/8\":[\"Görüntüleyiciyi kapat\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Hesap adını girin...\"],\"4/Rr0R\":[\"Mevcut kanala bir kullanıcı davet et\"],\"4EZrJN\":[\"Kurallar\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flood Profili (+F)\"],\"4RZQRK\":[\"Ne yapıyorsun?\"],\"4hfTrB\":[\"Takma Ad\"],\"4n99LO\":[[\"0\"],\" kanalında zaten var\"],\"4t6vMV\":[\"Kısa mesajlar için otomatik olarak tek satıra geç\"],\"4vsHmf\":[\"Süre (dk)\"],\"5+INAX\":[\"Sizi bahseden mesajları vurgula\"],\"5R5Pv/\":[\"Oper Adı\"],\"678PKt\":[\"Ağ Adı\"],\"6Aih4U\":[\"Çevrimdışı\"],\"6CO3WE\":[\"Kanala katılmak için şifre gerekli. Anahtarı kaldırmak için boş bırakın.\"],\"6HhMs3\":[\"Ayrılma Mesajı\"],\"6V3Ea3\":[\"Kopyalandı\"],\"6lGV3K\":[\"Daha az göster\"],\"6yFOEi\":[\"Oper şifresini girin...\"],\"7+IHTZ\":[\"Dosya seçilmedi\"],\"73hrRi\":[\"nick!user@host (örn. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Özel mesaj gönder\"],\"7U1W7c\":[\"Çok Rahat\"],\"7Y1YQj\":[\"Gerçek ad:\"],\"7YHArF\":[\"— görüntüleyicide aç\"],\"7fjnVl\":[\"Kullanıcı ara...\"],\"7jL88x\":[\"Bu mesaj silinsin mi? Bu işlem geri alınamaz.\"],\"7nGhhM\":[\"Aklınızda ne var?\"],\"7sEpu1\":[\"Üyeler — \",[\"0\"]],\"7sNhEz\":[\"Kullanıcı Adı\"],\"8H0Q+x\":[\"Profiller hakkında daha fazla bilgi →\"],\"8Phu0A\":[\"Kullanıcılar takma adını değiştirdiğinde göster\"],\"8XTG9e\":[\"Oper şifresini girin\"],\"8XsV2J\":[\"Yeniden gönder\"],\"8ZsakT\":[\"Şifre\"],\"8kR84m\":[\"Harici bir bağlantı açmak üzeresiniz:\"],\"8lCgih\":[\"Kuralı Kaldır\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"katıldı\"],\"other\":[[\"joinCount\"],\" kez katıldı\"]}]],\"9BMLnJ\":[\"Sunucuya yeniden bağlan\"],\"9OEgyT\":[\"Tepki ekle\"],\"9PQ8m2\":[\"G-Line (global yasak)\"],\"9Qs99X\":[\"E-posta:\"],\"9QupBP\":[\"Deseni kaldır\"],\"9bG48P\":[\"Gönderiliyor\"],\"9f5f0u\":[\"Gizlilik hakkında sorularınız mı var? Bize ulaşın:\"],\"9unqs3\":[\"Uzakta:\"],\"9v3hwv\":[\"Sunucu bulunamadı.\"],\"9zb2WA\":[\"Bağlanıyor\"],\"A1taO8\":[\"Ara\"],\"A2adVi\":[\"Yazıyor Bildirimleri Gönder\"],\"A9Rhec\":[\"Kanal Adı\"],\"AWOSPo\":[\"Yakınlaştır\"],\"AXSpEQ\":[\"Bağlanırken Oper Ol\"],\"AeXO77\":[\"Hesap\"],\"AhNP40\":[\"Konuma git\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Takma ad değiştirildi\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Yanıtı iptal et\"],\"ApSx0O\":[\"\\\"\",[\"searchQuery\"],\"\\\" ile eşleşen \",[\"0\"],\" mesaj bulundu\"],\"AxPAXW\":[\"Sonuç bulunamadı\"],\"AyNqAB\":[\"Tüm sunucu olaylarını sohbette göster\"],\"B/QqGw\":[\"Klavyeden uzakta\"],\"B8AaMI\":[\"Bu alan zorunludur\"],\"BA2c49\":[\"Sunucu gelişmiş LIST filtrelemesini desteklemiyor\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" ve \",[\"3\"],\" kişi daha yazıyor...\"],\"BGul2A\":[\"Kaydedilmemiş değişiklikleriniz var. Kaydetmeden kapatmak istediğinizden emin misiniz?\"],\"BIf9fi\":[\"Durum mesajınız\"],\"BZz3md\":[\"Kişisel web siteniz\"],\"Bgm/H7\":[\"Çok satırlı metin girişine izin ver\"],\"BiQIl1\":[\"Bu özel mesaj konuşmasını sabitle\"],\"BlNZZ2\":[\"Mesaja gitmek için tıklayın\"],\"Bowq3c\":[\"Yalnızca operatörler kanal konusunu değiştirebilir\"],\"Btozzp\":[\"Bu görüntünün süresi doldu\"],\"Bycfjm\":[\"Toplam: \",[\"0\"]],\"C6IBQc\":[\"Tüm JSON'u kopyala\"],\"C9L9wL\":[\"Veri Toplama\"],\"CDq4wC\":[\"Kullanıcıyı Yönet\"],\"CHVRxG\":[\"@\",[\"0\"],\"'a mesaj (yeni satır için Shift+Enter)\"],\"CN9zdR\":[\"Oper adı ve şifresi gereklidir\"],\"CW3sYa\":[[\"emoji\"],\" tepkisi ekle\"],\"CaAkqd\":[\"Ayrılmaları Göster\"],\"CbvaYj\":[\"Takma Adıyla Yasakla\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Bir kanal seçin\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"Sistem\"],\"D28t6+\":[\"katıldı ve ayrıldı\"],\"DB8zMK\":[\"Uygula\"],\"DBcWHr\":[\"Özel bildirim sesi dosyası\"],\"DTy9Xw\":[\"Medya Önizlemeleri\"],\"Dj4pSr\":[\"Güvenli bir şifre seçin\"],\"Du+zn+\":[\"Aranıyor...\"],\"Du2T2f\":[\"Ayar bulunamadı\"],\"DwsSVQ\":[\"Filtreleri Uygula ve Yenile\"],\"E3W/zd\":[\"Varsayılan Takma Ad\"],\"E6nRW7\":[\"URL'yi Kopyala\"],\"E703RG\":[\"Modlar:\"],\"EAeu1Z\":[\"Davet Gönder\"],\"EFKJQT\":[\"Ayar\"],\"EGPQBv\":[\"Özel Flood Kuralları (+f)\"],\"ELik0r\":[\"Tam Gizlilik Politikasını Görüntüle\"],\"EPbeC2\":[\"Kanal konusunu görüntüle veya düzenle\"],\"EQCDNT\":[\"Oper kullanıcı adını girin...\"],\"EUvulZ\":[\"\\\"\",[\"searchQuery\"],\"\\\" ile eşleşen 1 mesaj bulundu\"],\"EatZYJ\":[\"Sonraki görüntü\"],\"EdQY6l\":[\"Yok\"],\"EnqLYU\":[\"Sunucu ara...\"],\"F0OKMc\":[\"Sunucuyu Düzenle\"],\"F6Int2\":[\"Vurgulamayı Etkinleştir\"],\"FDoLyE\":[\"Maks. Kullanıcı\"],\"FUU/hZ\":[\"Sohbette ne kadar harici medyanın yükleneceğini denetleyin.\"],\"Fdp03t\":[\"açık\"],\"FfPWR0\":[\"Pencere\"],\"FjkaiT\":[\"Uzaklaştır\"],\"FlqOE9\":[\"Bu ne anlama geliyor:\"],\"FolHNl\":[\"Hesabınızı ve kimlik doğrulamayı yönetin\"],\"Fp2Dif\":[\"Sunucudan ayrıldı\"],\"G5KmCc\":[\"GZ-Line (global Z-Line)\"],\"GDs0lz\":[\"<0>Risk:</0> Hassas bilgiler (mesajlar, özel konuşmalar, kimlik doğrulama bilgileri) ağ yöneticilerine veya IRC sunucuları arasına konumlanmış saldırganlara açık olabilir.\"],\"GR+2I3\":[\"Davet maskesi ekle (örn. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Açılır sunucu bildirimlerini kapat\"],\"GlHnXw\":[\"Takma ad değişikliği başarısız: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Önizleme:\"],\"GtmO8/\":[\"kimden\"],\"GtuHUQ\":[\"Bu kanalı sunucuda yeniden adlandır. Tüm kullanıcılar yeni adı görecek.\"],\"GuGfFX\":[\"Aramayı aç/kapat\"],\"GxkJXS\":[\"Yükleniyor...\"],\"GzbwnK\":[\"Kanala katıldı\"],\"GzsUDB\":[\"Genişletilmiş Profil\"],\"H/PnT8\":[\"Emoji ekle\"],\"H6Izzl\":[\"Tercih ettiğiniz renk kodu\"],\"H9jIv+\":[\"Katılma/Ayrılma Göster\"],\"HAKBY9\":[\"Dosyaları yükle\"],\"HdE1If\":[\"Kanal\"],\"Hk4AW9\":[\"Tercih ettiğiniz görünen ad\"],\"HmHDk7\":[\"Üye Seç\"],\"HrQzPU\":[[\"networkName\"],\" üzerindeki kanallar\"],\"I2tXQ5\":[\"@\",[\"0\"],\"'a mesaj (yeni satır için Enter, göndermek için Shift+Enter)\"],\"I6bw/h\":[\"Kullanıcıyı Yasakla\"],\"I92Z+b\":[\"Bildirimleri etkinleştir\"],\"I9D72S\":[\"Bu mesajı silmek istediğinizden emin misiniz? Bu işlem geri alınamaz.\"],\"IA+1wo\":[\"Kullanıcılar kanaldan atıldığında göster\"],\"IDwkJx\":[\"IRC Operatörü\"],\"ILlU+s\":[\"Bilgi:\"],\"IUwGEM\":[\"Değişiklikleri Kaydet\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" ve \",[\"2\"],\" yazıyor...\"],\"IgrLD/\":[\"Duraklat\"],\"Im6JED\":[\"FISISALTI\"],\"ImOQa9\":[\"Yanıtla\"],\"IoHMnl\":[\"Maksimum değer \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Bağlanıyor...\"],\"J5T9NW\":[\"Kullanıcı Bilgileri\"],\"J8Y5+z\":[\"Eyvah! Ağ bölündü! ⚠️\"],\"JBHkBA\":[\"Kanaldan ayrıldı\"],\"JCwL0Q\":[\"Neden girin (isteğe bağlı)\"],\"JFciKP\":[\"Değiştir\"],\"JXGkhG\":[\"Kanal adını değiştir (yalnızca operatörler)\"],\"JcD7qf\":[\"Daha fazla işlem\"],\"JdkA+c\":[\"Gizli (+s)\"],\"Jmu12l\":[\"Sunucu Kanalları\"],\"JvQ++s\":[\"Markdown'ı Etkinleştir\"],\"K2jwh/\":[\"WHOIS verisi mevcut değil\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Mesajı sil\"],\"KKBlUU\":[\"Gömülü\"],\"KM0pLb\":[\"Kanala hoş geldiniz!\"],\"KR6W2h\":[\"Kullanıcının Engelini Kaldır\"],\"KV+Bi1\":[\"Yalnızca Davetli (+i)\"],\"KdCtwE\":[\"Sayaçları sıfırlamadan önce flood etkinliğinin kaç saniye izleneceği\"],\"Kkezga\":[\"Sunucu Şifresi\"],\"KsiQ/8\":[\"Kullanıcıların kanala katılmak için davet edilmesi gerekir\"],\"L+gB/D\":[\"Kanal bilgisi\"],\"LC1a7n\":[\"IRC sunucusu, sunucular arası bağlantılarının düşük güvenlik seviyesine sahip olduğunu bildirdi. Bu, mesajlarınız ağdaki IRC sunucuları arasında iletilirken düzgün şifrelenmeyebileceği veya SSL/TLS sertifikalarının doğru şekilde doğrulanmayabileceği anlamına gelir.\"],\"LNfLR5\":[\"Atmaları Göster\"],\"LQb0W/\":[\"Tüm Olayları Göster\"],\"LU7/yA\":[\"Arayüzde gösterilecek alternatif ad. Boşluk, emoji ve özel karakter içerebilir. IRC komutlarında gerçek kanal adı (\",[\"channelName\"],\") kullanılmaya devam eder.\"],\"LUb9O7\":[\"Geçerli bir sunucu portu gereklidir\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Gizlilik Politikası\"],\"LcuSDR\":[\"Profil bilgilerinizi ve meta verilerinizi yönetin\"],\"LqLS9B\":[\"Takma Ad Değişikliklerini Göster\"],\"LsDQt2\":[\"Kanal Ayarları\"],\"LtI9AS\":[\"Sahip\"],\"LuNhhL\":[\"bu mesaja tepki verdi\"],\"M/AZNG\":[\"Avatar görüntünüzün URL'si\"],\"M/WIer\":[\"Mesaj Gönder\"],\"M8er/5\":[\"Ad:\"],\"MHk+7g\":[\"Önceki görüntü\"],\"MRorGe\":[\"Kullanıcıya PM Gönder\"],\"MVbSGP\":[\"Zaman Penceresi (saniye)\"],\"MkpcsT\":[\"Mesajlarınız ve ayarlarınız cihazınızda yerel olarak saklanır\"],\"N/hDSy\":[\"Bot olarak işaretle - genellikle 'on' veya boş\"],\"N7TQbE\":[[\"channelName\"],\" kanalına Kullanıcı Davet Et\"],\"NCca/o\":[\"Varsayılan takma adı girin...\"],\"Nqs6B9\":[\"Tüm harici medyayı gösterir. Herhangi bir URL bilinmeyen bir sunucuya istek gönderebilir.\"],\"Nt+9O7\":[\"Ham TCP yerine WebSocket kullan\"],\"NxIHzc\":[\"Kullanıcıyı at\"],\"O+v/cL\":[\"Sunucudaki tüm kanalları görüntüle\"],\"ODwSCk\":[\"GIF gönder\"],\"OGQ5kK\":[\"Bildirim seslerini ve vurguları yapılandır\"],\"OIPt1Z\":[\"Üye listesi kenar çubuğunu göster veya gizle\"],\"OKSNq/\":[\"Çok Katı\"],\"ONWvwQ\":[\"Yükle\"],\"OVKoQO\":[\"Kimlik doğrulama için hesap şifreniz\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - IRC'yi geleceğe taşıyor\"],\"OhCpra\":[\"Konu belirle…\"],\"OkltoQ\":[[\"username\"],\" kullanıcısını takma adıyla yasakla (aynı takma adla yeniden katılmasını engeller)\"],\"P+t/Te\":[\"Ek veri yok\"],\"P42Wcc\":[\"Güvenli\"],\"PD38l0\":[\"Kanal avatarı önizlemesi\"],\"PD9mEt\":[\"Mesaj yazın...\"],\"PPqfdA\":[\"Kanal yapılandırma ayarlarını aç\"],\"PSCjfZ\":[\"Bu kanal için görüntülenecek konu. Tüm kullanıcılar konuyu görebilir.\"],\"PZCecv\":[\"PDF önizleme\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 kez\"],\"other\":[[\"c\"],\" kez\"]}]],\"PguS2C\":[\"İstisna maskesi ekle (örn. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[[\"0\"],\" kanaldan \",[\"displayedChannelsCount\"],\" tanesi gösteriliyor\"],\"PqhVlJ\":[\"Kullanıcıyı Yasakla (Host Maskesiyle)\"],\"Q+chwU\":[\"Kullanıcı adı:\"],\"Q6hhn8\":[\"Tercihler\"],\"QF4a34\":[\"Lütfen bir kullanıcı adı girin\"],\"QGqSZ2\":[\"Renk ve Biçimlendirme\"],\"QJQd1J\":[\"Profili Düzenle\"],\"QSzGDE\":[\"Boşta\"],\"QUlny5\":[[\"0\"],\"'a hoş geldiniz!\"],\"Qoq+GP\":[\"Devamını oku\"],\"QuSkCF\":[\"Kanalları filtrele...\"],\"QwUrDZ\":[\"konuyu şu şekilde değiştirdi: \",[\"topic\"]],\"R0UH07\":[[\"1\"],\" görselinden \",[\"0\"],\". görsel\"],\"R7SsBE\":[\"Sessize Al\"],\"R8rf1X\":[\"Konu belirlemek için tıklayın\"],\"RArB3D\":[[\"channelName\"],\" kanalından \",[\"username\"],\" tarafından atıldı\"],\"RI3cWd\":[\"ObsidianIRC ile IRC dünyasını keşfedin\"],\"RMMaN5\":[\"Moderasyonlu (+m)\"],\"RWw9Lg\":[\"Pencereyi kapat\"],\"RZ2BuZ\":[[\"account\"],\" hesap kaydı doğrulama gerektiriyor: \",[\"message\"]],\"RySp6q\":[\"Yorumları gizle\"],\"SPKQTd\":[\"Takma ad gereklidir\"],\"SPVjfj\":[\"Boş bırakılırsa varsayılan olarak 'neden yok' kullanılır\"],\"SQKPvQ\":[\"Kullanıcı Davet Et\"],\"SkZcl+\":[\"Önceden tanımlanmış bir flood koruma profili seçin. Bu profiller, farklı kullanım durumları için dengeli koruma ayarları sunar.\"],\"Slr+3C\":[\"Min. Kullanıcı\"],\"Spnlre\":[[\"target\"],\" kişisini \",[\"channel\"],\" kanalına davet ettiniz\"],\"T/ckN5\":[\"Görüntüleyicide aç\"],\"T91vKp\":[\"Oynat\"],\"TV2Wdu\":[\"Verilerinizi nasıl işlediğimizi ve gizliliğinizi nasıl koruduğumuzu öğrenin.\"],\"TgFpwD\":[\"Uygulanıyor...\"],\"TkzSFB\":[\"Değişiklik Yok\"],\"TtserG\":[\"Gerçek adı girin\"],\"Ttz9J1\":[\"Şifreyi girin...\"],\"Tz0i8g\":[\"Ayarlar\"],\"U3pytU\":[\"Yönetici\"],\"UDb2YD\":[\"Tepki Ver\"],\"UE4KO5\":[\"*kanal*\"],\"UGT5vp\":[\"Ayarları Kaydet\"],\"UV5hLB\":[\"Yasak bulunamadı\"],\"Uaj3Nd\":[\"Durum Mesajları\"],\"Ue3uny\":[\"Varsayılan (profil yok)\"],\"UkARhe\":[\"Normal - Standart koruma\"],\"Umn7Cj\":[\"Henüz yorum yok. İlk sen ol!\"],\"UtUIRh\":[[\"0\"],\" eski mesaj\"],\"UwzP+U\":[\"Güvenli Bağlantı\"],\"V0/A4O\":[\"Kanal Sahibi\"],\"V4qgxE\":[\"Şu kadar dakika önce önce oluşturulan\"],\"V8yTm6\":[\"Aramayı temizle\"],\"VJMMyz\":[\"ObsidianIRC - IRC'yi geleceğe taşıyor\"],\"VJScHU\":[\"Neden\"],\"VLsmVV\":[\"Bildirimleri sessize al\"],\"VbyRUy\":[\"Yorumlar\"],\"Vmx0mQ\":[\"Ayarlayan:\"],\"VqnIZz\":[\"Gizlilik politikamızı ve veri uygulamalarımızı görüntüleyin\"],\"VrMygG\":[\"Minimum uzunluk \",[\"0\"]],\"VrnTui\":[\"Profilinizde gösterilen zamirleriniz\"],\"W8E3qn\":[\"Doğrulanmış Hesap\"],\"WAakm9\":[\"Kanalı Sil\"],\"WFxTHC\":[\"Yasaklama maskesi ekle (örn. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Sunucu hostu gereklidir\"],\"WRYdXW\":[\"Ses konumu\"],\"WUOH5B\":[\"Kullanıcıyı Engelle\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"1 öğe daha göster\"],\"other\":[[\"1\"],\" öğe daha göster\"]}]],\"Weq9zb\":[\"Genel\"],\"Wfj7Sk\":[\"Bildirim seslerini sessize al veya aç\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Kullanıcı Profili\"],\"X6S3lt\":[\"Ayarlar, kanallar, sunucular arayın...\"],\"XEHan5\":[\"Yine de Devam Et\"],\"XI1+wb\":[\"Geçersiz biçim\"],\"XIXeuC\":[\"@\",[\"0\"],\"'a mesaj\"],\"XMS+k4\":[\"Özel Mesaj Başlat\"],\"XWgxXq\":[\"Albüm\"],\"Xd7+IT\":[\"Özel Sohbetin Sabitlemesini Kaldır\"],\"Xm/s+u\":[\"Görünüm\"],\"Xp2n93\":[\"Sunucunuzun güvenilir dosya hostundan medya gösterir. Harici hizmetlere istek gönderilmez.\"],\"XvjC4F\":[\"Kaydediliyor...\"],\"Y/qryO\":[\"Aramanızla eşleşen kullanıcı bulunamadı\"],\"YAqRpI\":[[\"account\"],\" hesap kaydı başarılı: \",[\"message\"]],\"YEfzvP\":[\"Korumalı Konu (+t)\"],\"YQOn6a\":[\"Üye listesini daralt\"],\"YRCoE9\":[\"Kanal Operatörü\"],\"YURQaF\":[\"Profili Görüntüle\"],\"YdBSvr\":[\"Medya gösterimini ve harici içeriği denetleyin\"],\"Yj6U3V\":[\"Merkezi Sunucu Yok:\"],\"YjvpGx\":[\"Zamirler\"],\"YqH4l4\":[\"Anahtar yok\"],\"YyUPpV\":[\"Hesap:\"],\"ZJSWfw\":[\"Sunucudan ayrıldığınızda gösterilen mesaj\"],\"ZR1dJ4\":[\"Davetler\"],\"ZdWg0V\":[\"Tarayıcıda aç\"],\"ZhRBbl\":[\"Mesajlarda ara…\"],\"Zmcu3y\":[\"Gelişmiş Filtreler\"],\"a2/8e5\":[\"Şu kadar dakika önce sonra konu belirlenen\"],\"aHKcKc\":[\"Önceki sayfa\"],\"aJTbXX\":[\"Oper Şifresi\"],\"aQryQv\":[\"Desen zaten mevcut\"],\"aW9pLN\":[\"Kanalda izin verilen maksimum kullanıcı sayısı. Sınır olmaması için boş bırakın.\"],\"ah4fmZ\":[\"YouTube, Vimeo, SoundCloud ve benzeri bilinen hizmetlerden önizlemeler de gösterir.\"],\"aifXak\":[\"Bu kanalda medya yok\"],\"ap2zBz\":[\"Rahat\"],\"az8lvo\":[\"Kapalı\"],\"azXSNo\":[\"Üye listesini genişlet\"],\"azdliB\":[\"Bir hesaba giriş yap\"],\"b26wlF\":[\"o/onun\"],\"bD/+Ei\":[\"Katı\"],\"bQ6BJn\":[\"Ayrıntılı flood koruma kurallarını yapılandırın. Her kural, hangi etkinlik türünün izleneceğini ve eşikler aşıldığında hangi işlemin yapılacağını belirtir.\"],\"beV7+y\":[\"Kullanıcı \",[\"channelName\"],\" kanalına katılmak için davet alacak.\"],\"bk84cH\":[\"Uzakta Mesajı\"],\"bkHdLj\":[\"IRC Sunucusu Ekle\"],\"bmQLn5\":[\"Kural Ekle\"],\"bwRvnp\":[\"İşlem\"],\"c8+EVZ\":[\"Doğrulanmış hesap\"],\"cGYUlD\":[\"Medya önizlemesi yüklenmiyor.\"],\"cLF98o\":[\"Yorumları göster (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Kullanılabilir kullanıcı yok\"],\"cSgpoS\":[\"Özel Sohbeti Sabitle\"],\"cde3ce\":[\"<0>\",[\"0\"],\"</0>'a mesaj\"],\"chQsxg\":[\"Biçimlendirilmiş çıktıyı kopyala\"],\"cl/A5J\":[[\"__DEFAULT_IRC_SERVER_NAME__\"],\"'a hoş geldiniz!\"],\"cnGeoo\":[\"Sil\"],\"coPLXT\":[\"IRC iletişimlerinizi sunucularımızda saklamıyoruz\"],\"crYH/6\":[\"SoundCloud oynatıcı\"],\"d3sis4\":[\"Sunucu Ekle\"],\"d9aN5k\":[[\"username\"],\" kullanıcısını kanaldan kaldır\"],\"dEgA5A\":[\"İptal\"],\"dGi1We\":[\"Bu özel mesaj konuşmasının sabitlemesini kaldır\"],\"dJVuyC\":[[\"channelName\"],\" kanalından ayrıldı (\",[\"reason\"],\")\"],\"dMtLDE\":[\"kime\"],\"dXqxlh\":[\"<0>⚠️ Güvenlik Riski!</0> Bu bağlantı, araya girme veya ortadaki adam saldırılarına karşı savunmasız olabilir.\"],\"da9Q/R\":[\"Kanal modları değiştirildi\"],\"dhJN3N\":[\"Yorumları göster\"],\"dj2xTE\":[\"Bildirimi kapat\"],\"dpCzmC\":[\"Flood Koruma Ayarları\"],\"e9dQpT\":[\"Bu bağlantıyı yeni sekmede açmak istiyor musunuz?\"],\"ePK91l\":[\"Düzenle\"],\"eYBDuB\":[\"Bir görüntü yükleyin veya dinamik boyutlandırma için isteğe bağlı \",[\"size\"],\" değişkeni içeren bir URL sağlayın\"],\"edBbee\":[[\"username\"],\" kullanıcısını host maskesiyle yasakla (aynı IP/host'tan yeniden katılmasını engeller)\"],\"ekfzWq\":[\"Kullanıcı Ayarları\"],\"elPDWs\":[\"IRC istemci deneyiminizi özelleştirin\"],\"eu2osY\":[\"<0>💡 Öneri:</0> Yalnızca bu sunucuya güveniyorsanız ve risklerin farkındaysanız devam edin. Bu bağlantı üzerinden hassas bilgi veya şifre paylaşmaktan kaçının.\"],\"euEhbr\":[[\"channel\"],\" kanalına katılmak için tıklayın\"],\"ez3vLd\":[\"Çok Satırlı Girişi Etkinleştir\"],\"f0J5Ki\":[\"Sunucular arası iletişim şifrelenmemiş bağlantılar kullanıyor olabilir\"],\"f9BHJk\":[\"Kullanıcıyı Uyar\"],\"fDOLLd\":[\"Kanal bulunamadı.\"],\"ffzDkB\":[\"Anonim Analitik:\"],\"fq1GF9\":[\"Kullanıcılar sunucudan ayrıldığında göster\"],\"gEF57C\":[\"Bu sunucu yalnızca bir bağlantı türünü destekliyor\"],\"gJuLUI\":[\"Engelleme Listesi\"],\"gNzMrk\":[\"Mevcut avatar\"],\"gjPWyO\":[\"Takma adı girin...\"],\"gz6UQ3\":[\"Büyüt\"],\"h6razj\":[\"Kanal Adı Maskesini Hariç Tut\"],\"hG6jnw\":[\"Konu belirlenmemiş\"],\"hG89Ed\":[\"Görüntü\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"örn. 100:1440\"],\"hehnjM\":[\"Miktar\"],\"hzdLuQ\":[\"Yalnızca Voice veya daha yüksek yetkiye sahip kullanıcılar konuşabilir\"],\"i0qMbr\":[\"Ana Sayfa\"],\"iDNBZe\":[\"Bildirimler\"],\"iH8pgl\":[\"Geri\"],\"iL9SZg\":[\"Kullanıcıyı Yasakla (Takma Adıyla)\"],\"iNt+3c\":[\"Görüntüye geri dön\"],\"iQvi+a\":[\"Bu sunucu için düşük bağlantı güvenliği konusunda uyarma\"],\"iSLIjg\":[\"Bağlan\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Sunucu Hostu\"],\"idD8Ev\":[\"Kaydedildi\"],\"iivqkW\":[\"Giriş Yapıldı\"],\"ij+Elv\":[\"Görüntü önizlemesi\"],\"ilIWp7\":[\"Bildirimleri Aç/Kapat\"],\"iuaqvB\":[\"Joker karakter için * kullanın. Örnekler: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Host Maskesiyle Yasakla\"],\"jA4uoI\":[\"Konu:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Neden (isteğe bağlı)\"],\"jUV7CU\":[\"Avatar Yükle\"],\"jW5Uwh\":[\"Ne kadar harici medya yükleneceğini denetleyin. Kapalı / Güvenli / Güvenilir Kaynaklar / Tüm İçerik.\"],\"jXzms5\":[\"Ek seçenekleri\"],\"jZlrte\":[\"Renk\"],\"jfC/xh\":[\"İletişim\"],\"jywMpv\":[\"#yeni-kanal-adı\"],\"k112DD\":[\"Eski mesajları yükle\"],\"k3ID0F\":[\"Üyeleri filtrele…\"],\"k65gsE\":[\"Derinlemesine incele\"],\"k7Zgob\":[\"Bağlantıyı İptal Et\"],\"kAVx5h\":[\"Davet bulunamadı\"],\"kCLEPU\":[\"Bağlı Olduğu Sunucu\"],\"kF5LKb\":[\"Engellenen desenler:\"],\"kGeOx/\":[[\"0\"],\" kanalına katıl\"],\"kITKr8\":[\"Kanal modları yükleniyor...\"],\"kPpPsw\":[\"IRC Operatörüsünüz\"],\"kWJmRL\":[\"Siz\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"JSON kopyala\"],\"krViRy\":[\"JSON olarak kopyalamak için tıklayın\"],\"ks71ra\":[\"İstisnalar\"],\"kw4lRv\":[\"Kanal Yarı Operatörü\"],\"kxgIRq\":[\"Başlamak için bir kanal seçin veya ekleyin.\"],\"ky6dWe\":[\"Avatar önizlemesi\"],\"l+GxCv\":[\"Kanallar yükleniyor...\"],\"l+IUVW\":[[\"account\"],\" hesap doğrulaması başarılı: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"yeniden bağlandı\"],\"other\":[[\"reconnectCount\"],\" kez yeniden bağlandı\"]}]],\"l5jmzx\":[[\"0\"],\" ve \",[\"1\"],\" yazıyor...\"],\"lHy8N5\":[\"Daha fazla kanal yükleniyor...\"],\"lbpf14\":[[\"value\"],\" kanalına katıl\"],\"lfFsZ4\":[\"Kanallar\"],\"lkNdiH\":[\"Hesap Adı\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Görüntü Yükle\"],\"loQxaJ\":[\"Geri Döndüm\"],\"lvfaxv\":[\"ANA SAYFA\"],\"m16xKo\":[\"Ekle\"],\"m8flAk\":[\"Önizleme (henüz yüklenmedi)\"],\"mEPxTp\":[\"<0>⚠️ Dikkatli olun!</0> Yalnızca güvenilir kaynaklardan gelen bağlantıları açın. Kötü amaçlı bağlantılar güvenliğinizi veya gizliliğinizi tehlikeye atabilir.\"],\"mHGdhG\":[\"Sunucu bilgisi\"],\"mHS8lb\":[\"#\",[\"0\"],\" kanalına mesaj\"],\"mMYBD9\":[\"Geniş - Daha kapsamlı koruma alanı\"],\"mTGsPd\":[\"Kanal Konusu\"],\"mU8j6O\":[\"Harici Mesaj Yok (+n)\"],\"mZp8FL\":[\"Otomatik Tek Satıra Dön\"],\"mdQu8G\":[\"TakmaAdınız\"],\"miSSBQ\":[\"Yorumlar (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Kullanıcı kimliği doğrulandı\"],\"mwtcGl\":[\"Yorumları kapat\"],\"mzI/c+\":[\"İndir\"],\"n3fGRk\":[[\"0\"],\" tarafından ayarlandı\"],\"nE9jsU\":[\"Rahat - Daha az agresif koruma\"],\"nNflMD\":[\"Kanaldan ayrıl\"],\"nPXkBi\":[\"WHOIS verisi yükleniyor...\"],\"nQnxxF\":[\"#\",[\"0\"],\" kanalına mesaj (yeni satır için Shift+Enter)\"],\"nWMRxa\":[\"Sabitlemeyi Kaldır\"],\"nkC032\":[\"Flood profili yok\"],\"o69z4d\":[[\"username\"],\" kullanıcısına uyarı mesajı gönder\"],\"o9ylQi\":[\"Başlamak için GIF arayın\"],\"oFGkER\":[\"Sunucu Bildirimleri\"],\"oOi11l\":[\"En alta kaydır\"],\"oQEzQR\":[\"Yeni DM\"],\"oXOSPE\":[\"Çevrimiçi\"],\"oal760\":[\"Sunucu bağlantılarında ortadaki adam saldırıları mümkün\"],\"oeqmmJ\":[\"Güvenilir Kaynaklar\"],\"ovBPCi\":[\"Varsayılan\"],\"p0Z69r\":[\"Desen boş olamaz\"],\"p1KgtK\":[\"Ses yüklenemedi\"],\"p59pEv\":[\"Ek ayrıntılar\"],\"p7sRI6\":[\"Yazarken diğerlerini bilgilendir\"],\"pBm1od\":[\"Gizli kanal\"],\"pNmiXx\":[\"Tüm sunucular için varsayılan takma adınız\"],\"pUUo9G\":[\"Ana makine adı:\"],\"pVGPmz\":[\"Hesap Şifresi\"],\"peNE68\":[\"Kalıcı\"],\"plhHQt\":[\"Veri yok\"],\"pm6+q5\":[\"Güvenlik Uyarısı\"],\"pn5qSs\":[\"Ek Bilgiler\"],\"q0cR4S\":[\"artık **\",[\"newNick\"],\"** olarak bilinmektedir\"],\"qFcunY\":[\"Kanal LIST veya NAMES komutlarında görünmeyecek\"],\"qLpTm/\":[[\"emoji\"],\" tepkisini kaldır\"],\"qVkGWK\":[\"Sabitle\"],\"qY8wNa\":[\"Ana Sayfa\"],\"qb0xJ7\":[\"Joker karakter kullanın: * herhangi bir diziyle eşleşir, ? herhangi bir tek karakterle eşleşir. Örnekler: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanal Anahtarı (+k)\"],\"qtoOYG\":[\"Sınır yok\"],\"r1W2AS\":[\"Dosya sunucu görseli\"],\"rIPR2O\":[\"Şu kadar dakika önce önce konu belirlenen\"],\"rMMSYo\":[\"Maksimum uzunluk \",[\"0\"]],\"rWtzQe\":[\"Ağ bölündü ve yeniden birleşti. ✅\"],\"rYG2u6\":[\"Lütfen bekleyin...\"],\"rdUucN\":[\"Önizleme\"],\"rjGI/Q\":[\"Gizlilik\"],\"rk8iDX\":[\"GIF'ler yükleniyor...\"],\"rn6SBY\":[\"Sesi Aç\"],\"s/UKqq\":[\"Kanaldan atıldı\"],\"s8cATI\":[[\"channelName\"],\" kanalına katıldı\"],\"sCO9ue\":[\"<0>\",[\"serverName\"],\"</0> bağlantısında aşağıdaki güvenlik endişeleri bulunmaktadır:\"],\"sGH11W\":[\"Sunucu\"],\"sHI1H+\":[\"artık **\",[\"newNick\"],\"** olarak bilinmektedir\"],\"sJyV04\":[[\"inviter\"],\", sizi \",[\"channel\"],\" kanalına davet etti\"],\"sby+1/\":[\"Kopyalamak için tıklayın\"],\"sfN25C\":[\"Gerçek veya tam adınız\"],\"sliuzR\":[\"Bağlantıyı Aç\"],\"sqrO9R\":[\"Özel Bahsediler\"],\"sr6RdJ\":[\"Shift+Enter ile çok satır\"],\"swrCpB\":[\"Kanal, \",[\"user\"],\" tarafından \",[\"oldName\"],\" yerine \",[\"newName\"],\" olarak yeniden adlandırıldı\",[\"0\"]],\"sxkWRg\":[\"Gelişmiş\"],\"t/YqKh\":[\"Kaldır\"],\"t47eHD\":[\"Bu sunucudaki benzersiz tanımlayıcınız\"],\"tAkAh0\":[\"Dinamik boyutlandırma için isteğe bağlı \",[\"size\"],\" değişkeni içeren URL. Örnek: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Kanal listesi kenar çubuğunu göster veya gizle\"],\"tfDRzk\":[\"Kaydet\"],\"tiBsJk\":[[\"channelName\"],\" kanalından ayrıldı\"],\"tt4/UD\":[\"ayrıldı (\",[\"reason\"],\")\"],\"u0TcnO\":[\"{nick} takma adı zaten kullanımda, {newNick} ile yeniden deneniyor\"],\"u0a8B4\":[\"Yönetici erişimi için IRC Operatörü olarak kimlik doğrula\"],\"u0rWFU\":[\"Şu kadar dakika önce sonra oluşturulan\"],\"u72w3t\":[\"Engellenecek kullanıcılar ve desenler\"],\"u7jc2L\":[\"ayrıldı\"],\"uAQUqI\":[\"Durum\"],\"uB85T3\":[\"Kaydetme başarısız: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC Sunucuları:\"],\"usSSr/\":[\"Yakınlaştırma seviyesi\"],\"v7uvcf\":[\"Yazılım:\"],\"vE8kb+\":[\"Yeni satır için Shift+Enter kullanın (Enter gönderir)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Konu yok\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Dil\"],\"vaHYxN\":[\"Gerçek Ad\"],\"vhjbKr\":[\"Uzakta\"],\"w4NYox\":[[\"title\"],\" istemcisi\"],\"w8xQRx\":[\"Geçersiz değer\"],\"wFjjxZ\":[[\"channelName\"],\" kanalından \",[\"username\"],\" tarafından atıldı (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Yasak istisnası bulunamadı\"],\"wPrGnM\":[\"Kanal Yöneticisi\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Kullanıcılar kanala katıldığında veya ayrıldığında göster\"],\"whqZ9r\":[\"Vurgulanacak ek kelimeler veya ifadeler\"],\"wm7RV4\":[\"Bildirim Sesi\"],\"wz/Yoq\":[\"Mesajlarınız sunucular arasında iletilirken ele geçirilebilir\"],\"xCJdfg\":[\"Temizle\"],\"xUHRTR\":[\"Bağlanırken otomatik olarak operatör kimliği doğrula\"],\"xWHwwQ\":[\"Yasaklar\"],\"xYilR2\":[\"Medya\"],\"xceQrO\":[\"Yalnızca güvenli websocket'ler desteklenmektedir\"],\"xdtXa+\":[\"kanal-adı\"],\"xfXC7q\":[\"Metin Kanalları\"],\"xlCYOE\":[\"Daha fazla mesaj alınıyor...\"],\"xlhswE\":[\"Minimum değer \",[\"0\"]],\"xq97Ci\":[\"Kelime veya ifade ekle...\"],\"xuRqRq\":[\"İstemci Sınırı (+l)\"],\"xwF+7J\":[[\"0\"],\" yazıyor...\"],\"yNeucF\":[\"Bu sunucu genişletilmiş profil meta verilerini (IRCv3 METADATA uzantısı) desteklemiyor. Avatar, görünen ad ve durum gibi ek alanlar mevcut değil.\"],\"yPlrca\":[\"Kanal Avatarı\"],\"yQE2r9\":[\"Yükleniyor\"],\"ySU+JY\":[\"eposta@adresiniz.com\"],\"yTX1Rt\":[\"Oper Kullanıcı Adı\"],\"yYOzWD\":[\"günlükler\"],\"yfx9Re\":[\"IRC operatör şifresi\"],\"ygCKqB\":[\"Durdur\"],\"ymDxJx\":[\"IRC operatör kullanıcı adı\"],\"yrpRsQ\":[\"Ada Göre Sırala\"],\"yz7wBu\":[\"Kapat\"],\"zJw+jA\":[\"modu ayarlar: \",[\"0\"]],\"zebeLu\":[\"Oper kullanıcı adını girin\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Geçersiz desen biçimi. nick!user@host biçimini kullanın (joker karakter * kullanılabilir)\"],\"+6NQQA\":[\"Genel Destek Kanalı\"],\"+6NyRG\":[\"İstemci\"],\"+K0AvT\":[\"Bağlantıyı Kes\"],\"+cyFdH\":[\"Uzakta olarak işaretlendiğinde gösterilecek varsayılan mesaj\"],\"+mVPqU\":[\"Mesajlarda markdown biçimlendirmesini işle\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Kimlik doğrulama için hesap kullanıcı adınız\"],\"+yPBXI\":[\"Dosya seç\"],\"+zy2Nq\":[\"Tür\"],\"/09cao\":[\"Düşük Bağlantı Güvenliği (Seviye \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Kanal dışındaki kullanıcılar kanala mesaj gönderemez\"],\"/6BzZF\":[\"Üye Listesini Aç/Kapat\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Kullanıcı uzakta\"],\"/XQgft\":[\"Keşfet\"],\"/cF7Rs\":[\"Ses Seviyesi\"],\"/dqduX\":[\"Sonraki sayfa\"],\"/fc3q4\":[\"Tüm İçerik\"],\"/kISDh\":[\"Bildirim Seslerini Etkinleştir\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Ses\"],\"/rfkZe\":[\"Bahisler ve mesajlar için ses çal\"],\"0/0ZGA\":[\"Kanal Adı Maskesi\"],\"0D6j7U\":[\"Özel kurallar hakkında daha fazla bilgi →\"],\"0XsHcR\":[\"Kullanıcıyı At\"],\"0ZpE//\":[\"Kullanıcıya Göre Sırala\"],\"0bEPwz\":[\"Uzakta Olarak İşaretle\"],\"0dGkPt\":[\"Kanal listesini genişlet\"],\"0gS7M5\":[\"Görünen Ad\"],\"0kS+M8\":[\"ÖrnekAĞ\"],\"0rgoY7\":[\"Yalnızca seçtiğiniz sunuculara bağlanın\"],\"0wdd7X\":[\"Katıl\"],\"0wkVYx\":[\"Özel Mesajlar\"],\"111uHX\":[\"Bağlantı önizlemesi\"],\"196EG4\":[\"Özel Sohbeti Sil\"],\"1DSr1i\":[\"Hesap kaydı oluştur\"],\"1O/24y\":[\"Kanal Listesini Aç/Kapat\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Harici Bağlantı Uyarısı\"],\"1ZC/dv\":[\"Okunmamış bahis veya mesaj yok\"],\"1pO1zi\":[\"Sunucu adı gereklidir\"],\"1uwfzQ\":[\"Kanal Konusunu Görüntüle\"],\"268g7c\":[\"Görünen adı girin\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Ağdaki sunucu operatörleri mesajlarınızı okuyabilir\"],\"2FYpfJ\":[\"Daha fazla\"],\"2HF1Y2\":[[\"inviter\"],\", \",[\"target\"],\" kişisini \",[\"channel\"],\" kanalına davet etti\"],\"2I70QL\":[\"Kullanıcı profil bilgilerini görüntüle\"],\"2QYdmE\":[\"Kullanıcılar:\"],\"2QpEjG\":[\"ayrıldı\"],\"2YE223\":[\"#\",[\"0\"],\" kanalına mesaj (yeni satır için Enter, göndermek için Shift+Enter)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Sunucu şifresini kullan\"],\"2iTmdZ\":[\"Yerel Depolama:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Katı - Daha agresif koruma\"],\"2uDhbA\":[\"Davet edilecek kullanıcı adını girin\"],\"2ygf/L\":[\"← Geri\"],\"2zEgxj\":[\"GIF ara...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Kanalı Yeniden Adlandır\"],\"3THokf\":[\"Sesli Kullanıcı\"],\"3TSz9S\":[\"Küçült\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Kanal Görünen Adı\"],\"3ryuFU\":[\"Uygulamayı geliştirmek için isteğe bağlı çökme raporları\"],\"3uBF/8\":[\"Görüntüleyiciyi kapat\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Hesap adını girin...\"],\"4/Rr0R\":[\"Mevcut kanala bir kullanıcı davet et\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Kurallar\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"Flood Profili (+F)\"],\"4RZQRK\":[\"Ne yapıyorsun?\"],\"4hfTrB\":[\"Takma Ad\"],\"4n99LO\":[[\"0\"],\" kanalında zaten var\"],\"4t6vMV\":[\"Kısa mesajlar için otomatik olarak tek satıra geç\"],\"4vsHmf\":[\"Süre (dk)\"],\"5+INAX\":[\"Sizi bahseden mesajları vurgula\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper Adı\"],\"678PKt\":[\"Ağ Adı\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Çevrimdışı\"],\"6CO3WE\":[\"Kanala katılmak için şifre gerekli. Anahtarı kaldırmak için boş bırakın.\"],\"6HhMs3\":[\"Ayrılma Mesajı\"],\"6V3Ea3\":[\"Kopyalandı\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Daha az göster\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Oper şifresini girin...\"],\"7+IHTZ\":[\"Dosya seçilmedi\"],\"73hrRi\":[\"nick!user@host (örn. spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Özel mesaj gönder\"],\"7U1W7c\":[\"Çok Rahat\"],\"7Y1YQj\":[\"Gerçek ad:\"],\"7YHArF\":[\"— görüntüleyicide aç\"],\"7fjnVl\":[\"Kullanıcı ara...\"],\"7jL88x\":[\"Bu mesaj silinsin mi? Bu işlem geri alınamaz.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Aklınızda ne var?\"],\"7sEpu1\":[\"Üyeler — \",[\"0\"]],\"7sNhEz\":[\"Kullanıcı Adı\"],\"8H0Q+x\":[\"Profiller hakkında daha fazla bilgi →\"],\"8Phu0A\":[\"Kullanıcılar takma adını değiştirdiğinde göster\"],\"8XTG9e\":[\"Oper şifresini girin\"],\"8XsV2J\":[\"Yeniden gönder\"],\"8ZsakT\":[\"Şifre\"],\"8kR84m\":[\"Harici bir bağlantı açmak üzeresiniz:\"],\"8lCgih\":[\"Kuralı Kaldır\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"katıldı\"],\"other\":[[\"joinCount\"],\" kez katıldı\"]}]],\"9BMLnJ\":[\"Sunucuya yeniden bağlan\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Tepki ekle\"],\"9PQ8m2\":[\"G-Line (global yasak)\"],\"9Qs99X\":[\"E-posta:\"],\"9QupBP\":[\"Deseni kaldır\"],\"9bG48P\":[\"Gönderiliyor\"],\"9f5f0u\":[\"Gizlilik hakkında sorularınız mı var? Bize ulaşın:\"],\"9unqs3\":[\"Uzakta:\"],\"9v3hwv\":[\"Sunucu bulunamadı.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Bağlanıyor\"],\"A1taO8\":[\"Ara\"],\"A2adVi\":[\"Yazıyor Bildirimleri Gönder\"],\"A9Rhec\":[\"Kanal Adı\"],\"AWOSPo\":[\"Yakınlaştır\"],\"AXSpEQ\":[\"Bağlanırken Oper Ol\"],\"AeXO77\":[\"Hesap\"],\"AhNP40\":[\"Konuma git\"],\"Ai2U7L\":[\"Host\"],\"AjBQnf\":[\"Takma ad değiştirildi\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Yanıtı iptal et\"],\"ApSx0O\":[\"\\\"\",[\"searchQuery\"],\"\\\" ile eşleşen \",[\"0\"],\" mesaj bulundu\"],\"AxPAXW\":[\"Sonuç bulunamadı\"],\"AyNqAB\":[\"Tüm sunucu olaylarını sohbette göster\"],\"B/QqGw\":[\"Klavyeden uzakta\"],\"B8AaMI\":[\"Bu alan zorunludur\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Sunucu gelişmiş LIST filtrelemesini desteklemiyor\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" ve \",[\"3\"],\" kişi daha yazıyor...\"],\"BGul2A\":[\"Kaydedilmemiş değişiklikleriniz var. Kaydetmeden kapatmak istediğinizden emin misiniz?\"],\"BIf9fi\":[\"Durum mesajınız\"],\"BZz3md\":[\"Kişisel web siteniz\"],\"Bgm/H7\":[\"Çok satırlı metin girişine izin ver\"],\"BiQIl1\":[\"Bu özel mesaj konuşmasını sabitle\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Mesaja gitmek için tıklayın\"],\"Bowq3c\":[\"Yalnızca operatörler kanal konusunu değiştirebilir\"],\"Btozzp\":[\"Bu görüntünün süresi doldu\"],\"Bycfjm\":[\"Toplam: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Tüm JSON'u kopyala\"],\"C9L9wL\":[\"Veri Toplama\"],\"CDq4wC\":[\"Kullanıcıyı Yönet\"],\"CHVRxG\":[\"@\",[\"0\"],\"'a mesaj (yeni satır için Shift+Enter)\"],\"CN9zdR\":[\"Oper adı ve şifresi gereklidir\"],\"CW3sYa\":[[\"emoji\"],\" tepkisi ekle\"],\"CaAkqd\":[\"Ayrılmaları Göster\"],\"CbvaYj\":[\"Takma Adıyla Yasakla\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Bir kanal seçin\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Normal\"],\"D+NlUC\":[\"Sistem\"],\"D28t6+\":[\"katıldı ve ayrıldı\"],\"DB8zMK\":[\"Uygula\"],\"DBcWHr\":[\"Özel bildirim sesi dosyası\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Medya Önizlemeleri\"],\"Dj4pSr\":[\"Güvenli bir şifre seçin\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Aranıyor...\"],\"Du2T2f\":[\"Ayar bulunamadı\"],\"DwsSVQ\":[\"Filtreleri Uygula ve Yenile\"],\"E3W/zd\":[\"Varsayılan Takma Ad\"],\"E6nRW7\":[\"URL'yi Kopyala\"],\"E703RG\":[\"Modlar:\"],\"EAeu1Z\":[\"Davet Gönder\"],\"EFKJQT\":[\"Ayar\"],\"EGPQBv\":[\"Özel Flood Kuralları (+f)\"],\"ELik0r\":[\"Tam Gizlilik Politikasını Görüntüle\"],\"EPbeC2\":[\"Kanal konusunu görüntüle veya düzenle\"],\"EQCDNT\":[\"Oper kullanıcı adını girin...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"\\\"\",[\"searchQuery\"],\"\\\" ile eşleşen 1 mesaj bulundu\"],\"EatZYJ\":[\"Sonraki görüntü\"],\"EdQY6l\":[\"Yok\"],\"EnqLYU\":[\"Sunucu ara...\"],\"F0OKMc\":[\"Sunucuyu Düzenle\"],\"F6Int2\":[\"Vurgulamayı Etkinleştir\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Maks. Kullanıcı\"],\"FUU/hZ\":[\"Sohbette ne kadar harici medyanın yükleneceğini denetleyin.\"],\"Fdp03t\":[\"açık\"],\"FfPWR0\":[\"Pencere\"],\"FjkaiT\":[\"Uzaklaştır\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Bu ne anlama geliyor:\"],\"FolHNl\":[\"Hesabınızı ve kimlik doğrulamayı yönetin\"],\"Fp2Dif\":[\"Sunucudan ayrıldı\"],\"G5KmCc\":[\"GZ-Line (global Z-Line)\"],\"GDs0lz\":[\"<0>Risk:</0> Hassas bilgiler (mesajlar, özel konuşmalar, kimlik doğrulama bilgileri) ağ yöneticilerine veya IRC sunucuları arasına konumlanmış saldırganlara açık olabilir.\"],\"GR+2I3\":[\"Davet maskesi ekle (örn. nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Açılır sunucu bildirimlerini kapat\"],\"GlHnXw\":[\"Takma ad değişikliği başarısız: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Önizleme:\"],\"GtmO8/\":[\"kimden\"],\"GtuHUQ\":[\"Bu kanalı sunucuda yeniden adlandır. Tüm kullanıcılar yeni adı görecek.\"],\"GuGfFX\":[\"Aramayı aç/kapat\"],\"GxkJXS\":[\"Yükleniyor...\"],\"GzbwnK\":[\"Kanala katıldı\"],\"GzsUDB\":[\"Genişletilmiş Profil\"],\"H/PnT8\":[\"Emoji ekle\"],\"H6Izzl\":[\"Tercih ettiğiniz renk kodu\"],\"H9jIv+\":[\"Katılma/Ayrılma Göster\"],\"HAKBY9\":[\"Dosyaları yükle\"],\"HdE1If\":[\"Kanal\"],\"Hk4AW9\":[\"Tercih ettiğiniz görünen ad\"],\"HmHDk7\":[\"Üye Seç\"],\"HrQzPU\":[[\"networkName\"],\" üzerindeki kanallar\"],\"I2tXQ5\":[\"@\",[\"0\"],\"'a mesaj (yeni satır için Enter, göndermek için Shift+Enter)\"],\"I6bw/h\":[\"Kullanıcıyı Yasakla\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Bildirimleri etkinleştir\"],\"I9D72S\":[\"Bu mesajı silmek istediğinizden emin misiniz? Bu işlem geri alınamaz.\"],\"IA+1wo\":[\"Kullanıcılar kanaldan atıldığında göster\"],\"IDwkJx\":[\"IRC Operatörü\"],\"ILlU+s\":[\"Bilgi:\"],\"IUwGEM\":[\"Değişiklikleri Kaydet\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" ve \",[\"2\"],\" yazıyor...\"],\"IgrLD/\":[\"Duraklat\"],\"Im6JED\":[\"FISISALTI\"],\"ImOQa9\":[\"Yanıtla\"],\"IoHMnl\":[\"Maksimum değer \",[\"0\"]],\"IvMj+0\":[\"Op\"],\"J28zul\":[\"Bağlanıyor...\"],\"J5T9NW\":[\"Kullanıcı Bilgileri\"],\"J8Y5+z\":[\"Eyvah! Ağ bölündü! ⚠️\"],\"JBHkBA\":[\"Kanaldan ayrıldı\"],\"JCwL0Q\":[\"Neden girin (isteğe bağlı)\"],\"JFciKP\":[\"Değiştir\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Kanal adını değiştir (yalnızca operatörler)\"],\"JcD7qf\":[\"Daha fazla işlem\"],\"JdkA+c\":[\"Gizli (+s)\"],\"Jmu12l\":[\"Sunucu Kanalları\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Markdown'ı Etkinleştir\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"WHOIS verisi mevcut değil\"],\"KAXSwC\":[\"Voice\"],\"KDfTdX\":[\"Mesajı sil\"],\"KKBlUU\":[\"Gömülü\"],\"KM0pLb\":[\"Kanala hoş geldiniz!\"],\"KR6W2h\":[\"Kullanıcının Engelini Kaldır\"],\"KV+Bi1\":[\"Yalnızca Davetli (+i)\"],\"KdCtwE\":[\"Sayaçları sıfırlamadan önce flood etkinliğinin kaç saniye izleneceği\"],\"Kkezga\":[\"Sunucu Şifresi\"],\"KsiQ/8\":[\"Kullanıcıların kanala katılmak için davet edilmesi gerekir\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Kanal bilgisi\"],\"LC1a7n\":[\"IRC sunucusu, sunucular arası bağlantılarının düşük güvenlik seviyesine sahip olduğunu bildirdi. Bu, mesajlarınız ağdaki IRC sunucuları arasında iletilirken düzgün şifrelenmeyebileceği veya SSL/TLS sertifikalarının doğru şekilde doğrulanmayabileceği anlamına gelir.\"],\"LNfLR5\":[\"Atmaları Göster\"],\"LQb0W/\":[\"Tüm Olayları Göster\"],\"LU7/yA\":[\"Arayüzde gösterilecek alternatif ad. Boşluk, emoji ve özel karakter içerebilir. IRC komutlarında gerçek kanal adı (\",[\"channelName\"],\") kullanılmaya devam eder.\"],\"LUb9O7\":[\"Geçerli bir sunucu portu gereklidir\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Gizlilik Politikası\"],\"LcuSDR\":[\"Profil bilgilerinizi ve meta verilerinizi yönetin\"],\"LqLS9B\":[\"Takma Ad Değişikliklerini Göster\"],\"LsDQt2\":[\"Kanal Ayarları\"],\"LtI9AS\":[\"Sahip\"],\"LuNhhL\":[\"bu mesaja tepki verdi\"],\"M/AZNG\":[\"Avatar görüntünüzün URL'si\"],\"M/WIer\":[\"Mesaj Gönder\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Ad:\"],\"MHk+7g\":[\"Önceki görüntü\"],\"MRorGe\":[\"Kullanıcıya PM Gönder\"],\"MVbSGP\":[\"Zaman Penceresi (saniye)\"],\"MkpcsT\":[\"Mesajlarınız ve ayarlarınız cihazınızda yerel olarak saklanır\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Bot olarak işaretle - genellikle 'on' veya boş\"],\"N7TQbE\":[[\"channelName\"],\" kanalına Kullanıcı Davet Et\"],\"NCca/o\":[\"Varsayılan takma adı girin...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Tüm harici medyayı gösterir. Herhangi bir URL bilinmeyen bir sunucuya istek gönderebilir.\"],\"Nt+9O7\":[\"Ham TCP yerine WebSocket kullan\"],\"NxIHzc\":[\"Kullanıcıyı at\"],\"O+v/cL\":[\"Sunucudaki tüm kanalları görüntüle\"],\"ODwSCk\":[\"GIF gönder\"],\"OGQ5kK\":[\"Bildirim seslerini ve vurguları yapılandır\"],\"OIPt1Z\":[\"Üye listesi kenar çubuğunu göster veya gizle\"],\"OKSNq/\":[\"Çok Katı\"],\"ONWvwQ\":[\"Yükle\"],\"OVKoQO\":[\"Kimlik doğrulama için hesap şifreniz\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - IRC'yi geleceğe taşıyor\"],\"OhCpra\":[\"Konu belirle…\"],\"OkltoQ\":[[\"username\"],\" kullanıcısını takma adıyla yasakla (aynı takma adla yeniden katılmasını engeller)\"],\"P+t/Te\":[\"Ek veri yok\"],\"P42Wcc\":[\"Güvenli\"],\"PD38l0\":[\"Kanal avatarı önizlemesi\"],\"PD9mEt\":[\"Mesaj yazın...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Kanal yapılandırma ayarlarını aç\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Bu kanal için görüntülenecek konu. Tüm kullanıcılar konuyu görebilir.\"],\"PZCecv\":[\"PDF önizleme\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 kez\"],\"other\":[[\"c\"],\" kez\"]}]],\"PguS2C\":[\"İstisna maskesi ekle (örn. nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[[\"0\"],\" kanaldan \",[\"displayedChannelsCount\"],\" tanesi gösteriliyor\"],\"PqhVlJ\":[\"Kullanıcıyı Yasakla (Host Maskesiyle)\"],\"Q+chwU\":[\"Kullanıcı adı:\"],\"Q6hhn8\":[\"Tercihler\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Lütfen bir kullanıcı adı girin\"],\"QGqSZ2\":[\"Renk ve Biçimlendirme\"],\"QJQd1J\":[\"Profili Düzenle\"],\"QSzGDE\":[\"Boşta\"],\"QUlny5\":[[\"0\"],\"'a hoş geldiniz!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Devamını oku\"],\"QuSkCF\":[\"Kanalları filtrele...\"],\"QwUrDZ\":[\"konuyu şu şekilde değiştirdi: \",[\"topic\"]],\"R0UH07\":[[\"1\"],\" görselinden \",[\"0\"],\". görsel\"],\"R7SsBE\":[\"Sessize Al\"],\"R8rf1X\":[\"Konu belirlemek için tıklayın\"],\"RArB3D\":[[\"channelName\"],\" kanalından \",[\"username\"],\" tarafından atıldı\"],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"ObsidianIRC ile IRC dünyasını keşfedin\"],\"RMMaN5\":[\"Moderasyonlu (+m)\"],\"RWw9Lg\":[\"Pencereyi kapat\"],\"RZ2BuZ\":[[\"account\"],\" hesap kaydı doğrulama gerektiriyor: \",[\"message\"]],\"RySp6q\":[\"Yorumları gizle\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Takma ad gereklidir\"],\"SPVjfj\":[\"Boş bırakılırsa varsayılan olarak 'neden yok' kullanılır\"],\"SQKPvQ\":[\"Kullanıcı Davet Et\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Önceden tanımlanmış bir flood koruma profili seçin. Bu profiller, farklı kullanım durumları için dengeli koruma ayarları sunar.\"],\"Slr+3C\":[\"Min. Kullanıcı\"],\"Spnlre\":[[\"target\"],\" kişisini \",[\"channel\"],\" kanalına davet ettiniz\"],\"T/ckN5\":[\"Görüntüleyicide aç\"],\"T91vKp\":[\"Oynat\"],\"TV2Wdu\":[\"Verilerinizi nasıl işlediğimizi ve gizliliğinizi nasıl koruduğumuzu öğrenin.\"],\"TgFpwD\":[\"Uygulanıyor...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Değişiklik Yok\"],\"TtserG\":[\"Gerçek adı girin\"],\"Ttz9J1\":[\"Şifreyi girin...\"],\"Tz0i8g\":[\"Ayarlar\"],\"U3pytU\":[\"Yönetici\"],\"UDb2YD\":[\"Tepki Ver\"],\"UE4KO5\":[\"*kanal*\"],\"UGT5vp\":[\"Ayarları Kaydet\"],\"UV5hLB\":[\"Yasak bulunamadı\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Durum Mesajları\"],\"Ue3uny\":[\"Varsayılan (profil yok)\"],\"UkARhe\":[\"Normal - Standart koruma\"],\"Umn7Cj\":[\"Henüz yorum yok. İlk sen ol!\"],\"UtUIRh\":[[\"0\"],\" eski mesaj\"],\"UwzP+U\":[\"Güvenli Bağlantı\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Kanal Sahibi\"],\"V4qgxE\":[\"Şu kadar dakika önce önce oluşturulan\"],\"V8yTm6\":[\"Aramayı temizle\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - IRC'yi geleceğe taşıyor\"],\"VJScHU\":[\"Neden\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Bildirimleri sessize al\"],\"VbyRUy\":[\"Yorumlar\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Ayarlayan:\"],\"VqnIZz\":[\"Gizlilik politikamızı ve veri uygulamalarımızı görüntüleyin\"],\"VrMygG\":[\"Minimum uzunluk \",[\"0\"]],\"VrnTui\":[\"Profilinizde gösterilen zamirleriniz\"],\"W8E3qn\":[\"Doğrulanmış Hesap\"],\"WAakm9\":[\"Kanalı Sil\"],\"WFxTHC\":[\"Yasaklama maskesi ekle (örn. nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Sunucu hostu gereklidir\"],\"WRYdXW\":[\"Ses konumu\"],\"WUOH5B\":[\"Kullanıcıyı Engelle\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"1 öğe daha göster\"],\"other\":[[\"1\"],\" öğe daha göster\"]}]],\"Weq9zb\":[\"Genel\"],\"Wfj7Sk\":[\"Bildirim seslerini sessize al veya aç\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"Kullanıcı Profili\"],\"X6S3lt\":[\"Ayarlar, kanallar, sunucular arayın...\"],\"XEHan5\":[\"Yine de Devam Et\"],\"XI1+wb\":[\"Geçersiz biçim\"],\"XIXeuC\":[\"@\",[\"0\"],\"'a mesaj\"],\"XMS+k4\":[\"Özel Mesaj Başlat\"],\"XWgxXq\":[\"Albüm\"],\"Xd7+IT\":[\"Özel Sohbetin Sabitlemesini Kaldır\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Görünüm\"],\"Xp2n93\":[\"Sunucunuzun güvenilir dosya hostundan medya gösterir. Harici hizmetlere istek gönderilmez.\"],\"XvjC4F\":[\"Kaydediliyor...\"],\"Y/qryO\":[\"Aramanızla eşleşen kullanıcı bulunamadı\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[[\"account\"],\" hesap kaydı başarılı: \",[\"message\"]],\"YEfzvP\":[\"Korumalı Konu (+t)\"],\"YQOn6a\":[\"Üye listesini daralt\"],\"YRCoE9\":[\"Kanal Operatörü\"],\"YURQaF\":[\"Profili Görüntüle\"],\"YdBSvr\":[\"Medya gösterimini ve harici içeriği denetleyin\"],\"Yj6U3V\":[\"Merkezi Sunucu Yok:\"],\"YjvpGx\":[\"Zamirler\"],\"YqH4l4\":[\"Anahtar yok\"],\"YyUPpV\":[\"Hesap:\"],\"ZJSWfw\":[\"Sunucudan ayrıldığınızda gösterilen mesaj\"],\"ZR1dJ4\":[\"Davetler\"],\"ZdWg0V\":[\"Tarayıcıda aç\"],\"ZhRBbl\":[\"Mesajlarda ara…\"],\"Zmcu3y\":[\"Gelişmiş Filtreler\"],\"a2/8e5\":[\"Şu kadar dakika önce sonra konu belirlenen\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Önceki sayfa\"],\"aJTbXX\":[\"Oper Şifresi\"],\"aQryQv\":[\"Desen zaten mevcut\"],\"aW9pLN\":[\"Kanalda izin verilen maksimum kullanıcı sayısı. Sınır olmaması için boş bırakın.\"],\"ah4fmZ\":[\"YouTube, Vimeo, SoundCloud ve benzeri bilinen hizmetlerden önizlemeler de gösterir.\"],\"aifXak\":[\"Bu kanalda medya yok\"],\"ap2zBz\":[\"Rahat\"],\"az8lvo\":[\"Kapalı\"],\"azXSNo\":[\"Üye listesini genişlet\"],\"azdliB\":[\"Bir hesaba giriş yap\"],\"b26wlF\":[\"o/onun\"],\"bD/+Ei\":[\"Katı\"],\"bQ6BJn\":[\"Ayrıntılı flood koruma kurallarını yapılandırın. Her kural, hangi etkinlik türünün izleneceğini ve eşikler aşıldığında hangi işlemin yapılacağını belirtir.\"],\"beV7+y\":[\"Kullanıcı \",[\"channelName\"],\" kanalına katılmak için davet alacak.\"],\"bk84cH\":[\"Uzakta Mesajı\"],\"bkHdLj\":[\"IRC Sunucusu Ekle\"],\"bmQLn5\":[\"Kural Ekle\"],\"bwRvnp\":[\"İşlem\"],\"c8+EVZ\":[\"Doğrulanmış hesap\"],\"cGYUlD\":[\"Medya önizlemesi yüklenmiyor.\"],\"cLF98o\":[\"Yorumları göster (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Kullanılabilir kullanıcı yok\"],\"cSgpoS\":[\"Özel Sohbeti Sabitle\"],\"cde3ce\":[\"<0>\",[\"0\"],\"</0>'a mesaj\"],\"chQsxg\":[\"Biçimlendirilmiş çıktıyı kopyala\"],\"cl/A5J\":[[\"__DEFAULT_IRC_SERVER_NAME__\"],\"'a hoş geldiniz!\"],\"cnGeoo\":[\"Sil\"],\"coPLXT\":[\"IRC iletişimlerinizi sunucularımızda saklamıyoruz\"],\"crYH/6\":[\"SoundCloud oynatıcı\"],\"d3sis4\":[\"Sunucu Ekle\"],\"d9aN5k\":[[\"username\"],\" kullanıcısını kanaldan kaldır\"],\"dEgA5A\":[\"İptal\"],\"dGi1We\":[\"Bu özel mesaj konuşmasının sabitlemesini kaldır\"],\"dJVuyC\":[[\"channelName\"],\" kanalından ayrıldı (\",[\"reason\"],\")\"],\"dMtLDE\":[\"kime\"],\"dXqxlh\":[\"<0>⚠️ Güvenlik Riski!</0> Bu bağlantı, araya girme veya ortadaki adam saldırılarına karşı savunmasız olabilir.\"],\"da9Q/R\":[\"Kanal modları değiştirildi\"],\"dhJN3N\":[\"Yorumları göster\"],\"dj2xTE\":[\"Bildirimi kapat\"],\"dpCzmC\":[\"Flood Koruma Ayarları\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Bu bağlantıyı yeni sekmede açmak istiyor musunuz?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Düzenle\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Bir görüntü yükleyin veya dinamik boyutlandırma için isteğe bağlı \",[\"size\"],\" değişkeni içeren bir URL sağlayın\"],\"edBbee\":[[\"username\"],\" kullanıcısını host maskesiyle yasakla (aynı IP/host'tan yeniden katılmasını engeller)\"],\"ekfzWq\":[\"Kullanıcı Ayarları\"],\"elPDWs\":[\"IRC istemci deneyiminizi özelleştirin\"],\"eu2osY\":[\"<0>💡 Öneri:</0> Yalnızca bu sunucuya güveniyorsanız ve risklerin farkındaysanız devam edin. Bu bağlantı üzerinden hassas bilgi veya şifre paylaşmaktan kaçının.\"],\"euEhbr\":[[\"channel\"],\" kanalına katılmak için tıklayın\"],\"ez3vLd\":[\"Çok Satırlı Girişi Etkinleştir\"],\"f0J5Ki\":[\"Sunucular arası iletişim şifrelenmemiş bağlantılar kullanıyor olabilir\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Kullanıcıyı Uyar\"],\"fDOLLd\":[\"Kanal bulunamadı.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Anonim Analitik:\"],\"fq1GF9\":[\"Kullanıcılar sunucudan ayrıldığında göster\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Bu sunucu yalnızca bir bağlantı türünü destekliyor\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Engelleme Listesi\"],\"gNzMrk\":[\"Mevcut avatar\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Takma adı girin...\"],\"gz6UQ3\":[\"Büyüt\"],\"h6razj\":[\"Kanal Adı Maskesini Hariç Tut\"],\"hG6jnw\":[\"Konu belirlenmemiş\"],\"hG89Ed\":[\"Görüntü\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Port\"],\"ha+Bz5\":[\"örn. 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Miktar\"],\"hzdLuQ\":[\"Yalnızca Voice veya daha yüksek yetkiye sahip kullanıcılar konuşabilir\"],\"i0qMbr\":[\"Ana Sayfa\"],\"iDNBZe\":[\"Bildirimler\"],\"iH8pgl\":[\"Geri\"],\"iL9SZg\":[\"Kullanıcıyı Yasakla (Takma Adıyla)\"],\"iNt+3c\":[\"Görüntüye geri dön\"],\"iQvi+a\":[\"Bu sunucu için düşük bağlantı güvenliği konusunda uyarma\"],\"iSLIjg\":[\"Bağlan\"],\"iWXkHH\":[\"Halfop\"],\"iZeTtp\":[\"Sunucu Hostu\"],\"idD8Ev\":[\"Kaydedildi\"],\"iivqkW\":[\"Giriş Yapıldı\"],\"ij+Elv\":[\"Görüntü önizlemesi\"],\"ilIWp7\":[\"Bildirimleri Aç/Kapat\"],\"iuaqvB\":[\"Joker karakter için * kullanın. Örnekler: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Bot\"],\"j2DGR0\":[\"Host Maskesiyle Yasakla\"],\"jA4uoI\":[\"Konu:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Neden (isteğe bağlı)\"],\"jUV7CU\":[\"Avatar Yükle\"],\"jW5Uwh\":[\"Ne kadar harici medya yükleneceğini denetleyin. Kapalı / Güvenli / Güvenilir Kaynaklar / Tüm İçerik.\"],\"jXzms5\":[\"Ek seçenekleri\"],\"jZlrte\":[\"Renk\"],\"jfC/xh\":[\"İletişim\"],\"jywMpv\":[\"#yeni-kanal-adı\"],\"k112DD\":[\"Eski mesajları yükle\"],\"k3ID0F\":[\"Üyeleri filtrele…\"],\"k65gsE\":[\"Derinlemesine incele\"],\"k7Zgob\":[\"Bağlantıyı İptal Et\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Davet bulunamadı\"],\"kCLEPU\":[\"Bağlı Olduğu Sunucu\"],\"kF5LKb\":[\"Engellenen desenler:\"],\"kGeOx/\":[[\"0\"],\" kanalına katıl\"],\"kITKr8\":[\"Kanal modları yükleniyor...\"],\"kPpPsw\":[\"IRC Operatörüsünüz\"],\"kWJmRL\":[\"Siz\"],\"kfcRb0\":[\"Avatar\"],\"kjMqSj\":[\"JSON kopyala\"],\"krViRy\":[\"JSON olarak kopyalamak için tıklayın\"],\"ks71ra\":[\"İstisnalar\"],\"kw4lRv\":[\"Kanal Yarı Operatörü\"],\"kxgIRq\":[\"Başlamak için bir kanal seçin veya ekleyin.\"],\"ky6dWe\":[\"Avatar önizlemesi\"],\"l+GxCv\":[\"Kanallar yükleniyor...\"],\"l+IUVW\":[[\"account\"],\" hesap doğrulaması başarılı: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"yeniden bağlandı\"],\"other\":[[\"reconnectCount\"],\" kez yeniden bağlandı\"]}]],\"l5jmzx\":[[\"0\"],\" ve \",[\"1\"],\" yazıyor...\"],\"lHy8N5\":[\"Daha fazla kanal yükleniyor...\"],\"lbpf14\":[[\"value\"],\" kanalına katıl\"],\"lfFsZ4\":[\"Kanallar\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Hesap Adı\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Görüntü Yükle\"],\"loQxaJ\":[\"Geri Döndüm\"],\"lvfaxv\":[\"ANA SAYFA\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Ekle\"],\"m8flAk\":[\"Önizleme (henüz yüklenmedi)\"],\"mEPxTp\":[\"<0>⚠️ Dikkatli olun!</0> Yalnızca güvenilir kaynaklardan gelen bağlantıları açın. Kötü amaçlı bağlantılar güvenliğinizi veya gizliliğinizi tehlikeye atabilir.\"],\"mHGdhG\":[\"Sunucu bilgisi\"],\"mHS8lb\":[\"#\",[\"0\"],\" kanalına mesaj\"],\"mMYBD9\":[\"Geniş - Daha kapsamlı koruma alanı\"],\"mTGsPd\":[\"Kanal Konusu\"],\"mU8j6O\":[\"Harici Mesaj Yok (+n)\"],\"mZp8FL\":[\"Otomatik Tek Satıra Dön\"],\"mdQu8G\":[\"TakmaAdınız\"],\"miSSBQ\":[\"Yorumlar (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Kullanıcı kimliği doğrulandı\"],\"mwtcGl\":[\"Yorumları kapat\"],\"mzI/c+\":[\"İndir\"],\"n3fGRk\":[[\"0\"],\" tarafından ayarlandı\"],\"nE9jsU\":[\"Rahat - Daha az agresif koruma\"],\"nNflMD\":[\"Kanaldan ayrıl\"],\"nPXkBi\":[\"WHOIS verisi yükleniyor...\"],\"nQnxxF\":[\"#\",[\"0\"],\" kanalına mesaj (yeni satır için Shift+Enter)\"],\"nWMRxa\":[\"Sabitlemeyi Kaldır\"],\"nkC032\":[\"Flood profili yok\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[[\"username\"],\" kullanıcısına uyarı mesajı gönder\"],\"o9ylQi\":[\"Başlamak için GIF arayın\"],\"oFGkER\":[\"Sunucu Bildirimleri\"],\"oOi11l\":[\"En alta kaydır\"],\"oQEzQR\":[\"Yeni DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Çevrimiçi\"],\"oal760\":[\"Sunucu bağlantılarında ortadaki adam saldırıları mümkün\"],\"oeqmmJ\":[\"Güvenilir Kaynaklar\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"Varsayılan\"],\"p0Z69r\":[\"Desen boş olamaz\"],\"p1KgtK\":[\"Ses yüklenemedi\"],\"p59pEv\":[\"Ek ayrıntılar\"],\"p7sRI6\":[\"Yazarken diğerlerini bilgilendir\"],\"pBm1od\":[\"Gizli kanal\"],\"pNmiXx\":[\"Tüm sunucular için varsayılan takma adınız\"],\"pUUo9G\":[\"Ana makine adı:\"],\"pVGPmz\":[\"Hesap Şifresi\"],\"peNE68\":[\"Kalıcı\"],\"plhHQt\":[\"Veri yok\"],\"pm6+q5\":[\"Güvenlik Uyarısı\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Ek Bilgiler\"],\"q0cR4S\":[\"artık **\",[\"newNick\"],\"** olarak bilinmektedir\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Kanal LIST veya NAMES komutlarında görünmeyecek\"],\"qLpTm/\":[[\"emoji\"],\" tepkisini kaldır\"],\"qVkGWK\":[\"Sabitle\"],\"qY8wNa\":[\"Ana Sayfa\"],\"qb0xJ7\":[\"Joker karakter kullanın: * herhangi bir diziyle eşleşir, ? herhangi bir tek karakterle eşleşir. Örnekler: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Kanal Anahtarı (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Sınır yok\"],\"r1W2AS\":[\"Dosya sunucu görseli\"],\"rIPR2O\":[\"Şu kadar dakika önce önce konu belirlenen\"],\"rMMSYo\":[\"Maksimum uzunluk \",[\"0\"]],\"rWtzQe\":[\"Ağ bölündü ve yeniden birleşti. ✅\"],\"rYG2u6\":[\"Lütfen bekleyin...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Önizleme\"],\"rjGI/Q\":[\"Gizlilik\"],\"rk8iDX\":[\"GIF'ler yükleniyor...\"],\"rn6SBY\":[\"Sesi Aç\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Kanaldan atıldı\"],\"s8cATI\":[[\"channelName\"],\" kanalına katıldı\"],\"sCO9ue\":[\"<0>\",[\"serverName\"],\"</0> bağlantısında aşağıdaki güvenlik endişeleri bulunmaktadır:\"],\"sGH11W\":[\"Sunucu\"],\"sHI1H+\":[\"artık **\",[\"newNick\"],\"** olarak bilinmektedir\"],\"sJyV04\":[[\"inviter\"],\", sizi \",[\"channel\"],\" kanalına davet etti\"],\"sby+1/\":[\"Kopyalamak için tıklayın\"],\"sfN25C\":[\"Gerçek veya tam adınız\"],\"sliuzR\":[\"Bağlantıyı Aç\"],\"sqrO9R\":[\"Özel Bahsediler\"],\"sr6RdJ\":[\"Shift+Enter ile çok satır\"],\"swrCpB\":[\"Kanal, \",[\"user\"],\" tarafından \",[\"oldName\"],\" yerine \",[\"newName\"],\" olarak yeniden adlandırıldı\",[\"0\"]],\"sxkWRg\":[\"Gelişmiş\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Kaldır\"],\"t47eHD\":[\"Bu sunucudaki benzersiz tanımlayıcınız\"],\"tAkAh0\":[\"Dinamik boyutlandırma için isteğe bağlı \",[\"size\"],\" değişkeni içeren URL. Örnek: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Kanal listesi kenar çubuğunu göster veya gizle\"],\"tfDRzk\":[\"Kaydet\"],\"tiBsJk\":[[\"channelName\"],\" kanalından ayrıldı\"],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"ayrıldı (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"{nick} takma adı zaten kullanımda, {newNick} ile yeniden deneniyor\"],\"u0a8B4\":[\"Yönetici erişimi için IRC Operatörü olarak kimlik doğrula\"],\"u0rWFU\":[\"Şu kadar dakika önce sonra oluşturulan\"],\"u72w3t\":[\"Engellenecek kullanıcılar ve desenler\"],\"u7jc2L\":[\"ayrıldı\"],\"uAQUqI\":[\"Durum\"],\"uB85T3\":[\"Kaydetme başarısız: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC Sunucuları:\"],\"usSSr/\":[\"Yakınlaştırma seviyesi\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Yazılım:\"],\"vE8kb+\":[\"Yeni satır için Shift+Enter kullanın (Enter gönderir)\"],\"vERlcd\":[\"Profil\"],\"vK0RL8\":[\"Konu yok\"],\"vSJd18\":[\"Video\"],\"vXIe7J\":[\"Dil\"],\"vaHYxN\":[\"Gerçek Ad\"],\"vhjbKr\":[\"Uzakta\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" istemcisi\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Geçersiz değer\"],\"wFjjxZ\":[[\"channelName\"],\" kanalından \",[\"username\"],\" tarafından atıldı (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Yasak istisnası bulunamadı\"],\"wPrGnM\":[\"Kanal Yöneticisi\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Kullanıcılar kanala katıldığında veya ayrıldığında göster\"],\"whqZ9r\":[\"Vurgulanacak ek kelimeler veya ifadeler\"],\"wm7RV4\":[\"Bildirim Sesi\"],\"wz/Yoq\":[\"Mesajlarınız sunucular arasında iletilirken ele geçirilebilir\"],\"xCJdfg\":[\"Temizle\"],\"xUHRTR\":[\"Bağlanırken otomatik olarak operatör kimliği doğrula\"],\"xWHwwQ\":[\"Yasaklar\"],\"xYilR2\":[\"Medya\"],\"xceQrO\":[\"Yalnızca güvenli websocket'ler desteklenmektedir\"],\"xdtXa+\":[\"kanal-adı\"],\"xfXC7q\":[\"Metin Kanalları\"],\"xlCYOE\":[\"Daha fazla mesaj alınıyor...\"],\"xlhswE\":[\"Minimum değer \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Kelime veya ifade ekle...\"],\"xuRqRq\":[\"İstemci Sınırı (+l)\"],\"xwF+7J\":[[\"0\"],\" yazıyor...\"],\"yNeucF\":[\"Bu sunucu genişletilmiş profil meta verilerini (IRCv3 METADATA uzantısı) desteklemiyor. Avatar, görünen ad ve durum gibi ek alanlar mevcut değil.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Kanal Avatarı\"],\"yQE2r9\":[\"Yükleniyor\"],\"ySU+JY\":[\"eposta@adresiniz.com\"],\"yTX1Rt\":[\"Oper Kullanıcı Adı\"],\"yYOzWD\":[\"günlükler\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC operatör şifresi\"],\"ygCKqB\":[\"Durdur\"],\"ymDxJx\":[\"IRC operatör kullanıcı adı\"],\"yrpRsQ\":[\"Ada Göre Sırala\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Kapat\"],\"zJw+jA\":[\"modu ayarlar: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Oper kullanıcı adını girin\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Недійсний формат шаблону. Використовуйте формат nick!user@host (дозволені символи * як шаблони)\"],\"+6NQQA\":[\"Загальний канал підтримки\"],\"+6NyRG\":[\"Клієнт\"],\"+K0AvT\":[\"Від'єднатися\"],\"+cyFdH\":[\"Стандартне повідомлення при позначенні себе відсутнім\"],\"+mVPqU\":[\"Відображати Markdown-форматування у повідомленнях\"],\"+vqCJH\":[\"Ім'я користувача вашого акаунту для автентифікації\"],\"+yPBXI\":[\"Вибрати файл\"],\"+zy2Nq\":[\"Тип\"],\"/09cao\":[\"Низький рівень безпеки з'єднання (Рівень \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Користувачі за межами каналу не можуть надсилати до нього повідомлення\"],\"/6BzZF\":[\"Перемкнути список учасників\"],\"/TNOPk\":[\"Користувач відсутній\"],\"/XQgft\":[\"Дослідити\"],\"/cF7Rs\":[\"Гучність\"],\"/dqduX\":[\"Наступна сторінка\"],\"/fc3q4\":[\"Весь вміст\"],\"/kISDh\":[\"Увімкнути звуки сповіщень\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Аудіо\"],\"/rfkZe\":[\"Відтворювати звуки для згадок і повідомлень\"],\"0/0ZGA\":[\"Маска назви каналу\"],\"0D6j7U\":[\"Дізнатися більше про власні правила →\"],\"0XsHcR\":[\"Вигнати користувача\"],\"0ZpE//\":[\"Сортувати за кількістю користувачів\"],\"0bEPwz\":[\"Встановити статус «Відсутній»\"],\"0dGkPt\":[\"Розгорнути список каналів\"],\"0gS7M5\":[\"Відображуване ім'я\"],\"0kS+M8\":[\"ПрикладМЕРЕЖА\"],\"0rgoY7\":[\"Підключатися лише до обраних вами серверів\"],\"0wdd7X\":[\"Приєднатися\"],\"0wkVYx\":[\"Приватні повідомлення\"],\"111uHX\":[\"Попередній перегляд посилання\"],\"196EG4\":[\"Видалити приватний чат\"],\"1DSr1i\":[\"Зареєструвати акаунт\"],\"1O/24y\":[\"Перемкнути список каналів\"],\"1VPJJ2\":[\"Попередження про зовнішнє посилання\"],\"1ZC/dv\":[\"Немає непрочитаних згадувань або повідомлень\"],\"1pO1zi\":[\"Назва сервера обов'язкова\"],\"1uwfzQ\":[\"Переглянути тему каналу\"],\"268g7c\":[\"Введіть відображуване ім'я\"],\"2FOFq1\":[\"Оператори сервера в мережі можуть потенційно читати ваші повідомлення\"],\"2FYpfJ\":[\"Більше\"],\"2HF1Y2\":[[\"inviter\"],\" запросив \",[\"target\"],\" приєднатися до \",[\"channel\"]],\"2I70QL\":[\"Переглянути інформацію профілю користувача\"],\"2QYdmE\":[\"Користувачі:\"],\"2QpEjG\":[\"вийшов\"],\"2YE223\":[\"Повідомлення #\",[\"0\"],\" (Enter для нового рядка, Shift+Enter для відправки)\"],\"2bimFY\":[\"Використовувати пароль сервера\"],\"2iTmdZ\":[\"Локальне сховище:\"],\"2odkwe\":[\"Строгий - більш агресивний захист\"],\"2uDhbA\":[\"Введіть ім'я користувача для запрошення\"],\"2ygf/L\":[\"← Назад\"],\"2zEgxj\":[\"Пошук GIF...\"],\"3RdPhl\":[\"Перейменувати канал\"],\"3THokf\":[\"Користувач з голосом\"],\"3TSz9S\":[\"Згорнути\"],\"3jBDvM\":[\"Відображувана назва каналу\"],\"3ryuFU\":[\"Необов'язкові звіти про збої для покращення додатку\"],\"3uBF/8\":[\"Закрити переглядач\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Введіть ім'я акаунту...\"],\"4/Rr0R\":[\"Запросити користувача до поточного каналу\"],\"4EZrJN\":[\"Правила\"],\"4JJtW9\":[\"#переповнення\"],\"4NqeT4\":[\"Профіль флуду (+F)\"],\"4RZQRK\":[\"Що ти зараз робиш?\"],\"4hfTrB\":[\"Псевдонім\"],\"4n99LO\":[\"Вже в \",[\"0\"]],\"4t6vMV\":[\"Автоматично перемикатися на один рядок для коротких повідомлень\"],\"4vsHmf\":[\"Час (хв)\"],\"5+INAX\":[\"Виділяти повідомлення, що вас згадують\"],\"5R5Pv/\":[\"Ім'я оператора\"],\"678PKt\":[\"Назва мережі\"],\"6Aih4U\":[\"Офлайн\"],\"6CO3WE\":[\"Пароль для входу до каналу. Залиште порожнім для видалення ключа.\"],\"6HhMs3\":[\"Повідомлення про вихід\"],\"6V3Ea3\":[\"Скопійовано\"],\"6lGV3K\":[\"Показати менше\"],\"6yFOEi\":[\"Введіть пароль оператора...\"],\"7+IHTZ\":[\"Файл не вибрано\"],\"73hrRi\":[\"nick!user@host (напр., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Надіслати приватне повідомлення\"],\"7U1W7c\":[\"Дуже розслаблений\"],\"7Y1YQj\":[\"Справжнє ім'я:\"],\"7YHArF\":[\"— відкрити у переглядачі\"],\"7fjnVl\":[\"Пошук користувачів...\"],\"7jL88x\":[\"Видалити це повідомлення? Цю дію неможливо скасувати.\"],\"7nGhhM\":[\"Що у вас на думці?\"],\"7sEpu1\":[\"Учасники — \",[\"0\"]],\"7sNhEz\":[\"Ім'я користувача\"],\"8H0Q+x\":[\"Дізнатися більше про профілі →\"],\"8Phu0A\":[\"Відображати, коли користувачі змінюють псевдонім\"],\"8XTG9e\":[\"Введіть пароль оператора\"],\"8XsV2J\":[\"Повторити надсилання\"],\"8ZsakT\":[\"Пароль\"],\"8kR84m\":[\"Ви збираєтеся відкрити зовнішнє посилання:\"],\"8lCgih\":[\"Видалити правило\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"приєднався\"],\"few\":[\"приєднався \",[\"joinCount\"],\" рази\"],\"many\":[\"приєднався \",[\"joinCount\"],\" разів\"],\"other\":[\"приєднався \",[\"joinCount\"],\" рази\"]}]],\"9BMLnJ\":[\"Перепідключитися до сервера\"],\"9OEgyT\":[\"Додати реакцію\"],\"9PQ8m2\":[\"G-Line (глобальний бан)\"],\"9Qs99X\":[\"Електронна пошта:\"],\"9QupBP\":[\"Видалити шаблон\"],\"9bG48P\":[\"Надсилання\"],\"9f5f0u\":[\"Питання про конфіденційність? Зв'яжіться з нами:\"],\"9unqs3\":[\"Відсутність:\"],\"9v3hwv\":[\"Сервери не знайдено.\"],\"9zb2WA\":[\"Підключення\"],\"A1taO8\":[\"Пошук\"],\"A2adVi\":[\"Надсилати сповіщення про введення\"],\"A9Rhec\":[\"Назва каналу\"],\"AWOSPo\":[\"Збільшити\"],\"AXSpEQ\":[\"Оператор при підключенні\"],\"AeXO77\":[\"Акаунт\"],\"AhNP40\":[\"Перемотати\"],\"Ai2U7L\":[\"Хост\"],\"AjBQnf\":[\"Змінено псевдонім\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Скасувати відповідь\"],\"ApSx0O\":[\"Знайдено \",[\"0\"],\" повідомлень, що відповідають \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Результатів не знайдено\"],\"AyNqAB\":[\"Відображати всі події сервера в чаті\"],\"B/QqGw\":[\"Відійшов від клавіатури\"],\"B8AaMI\":[\"Це поле обов'язкове\"],\"BA2c49\":[\"Сервер не підтримує розширену фільтрацію LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" та ще \",[\"3\"],\" пишуть...\"],\"BGul2A\":[\"У вас є незбережені зміни. Ви впевнені, що хочете закрити без збереження?\"],\"BIf9fi\":[\"Ваше статусне повідомлення\"],\"BZz3md\":[\"Ваш особистий веб-сайт\"],\"Bgm/H7\":[\"Дозволити введення кількох рядків тексту\"],\"BiQIl1\":[\"Закріпити цю приватну розмову\"],\"BlNZZ2\":[\"Натисніть, щоб перейти до повідомлення\"],\"Bowq3c\":[\"Тільки оператори можуть змінювати тему каналу\"],\"Btozzp\":[\"Термін дії цього зображення минув\"],\"Bycfjm\":[\"Всього: \",[\"0\"]],\"C6IBQc\":[\"Скопіювати весь JSON\"],\"C9L9wL\":[\"Збір даних\"],\"CDq4wC\":[\"Помірний режим для користувача\"],\"CHVRxG\":[\"Повідомлення @\",[\"0\"],\" (Shift+Enter для нового рядка)\"],\"CN9zdR\":[\"Ім'я та пароль оператора обов'язкові\"],\"CW3sYa\":[\"Додати реакцію \",[\"emoji\"]],\"CaAkqd\":[\"Показати виходи\"],\"CbvaYj\":[\"Блокування за псевдонімом\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Вибрати канал\"],\"CsekCi\":[\"Нормальний\"],\"D+NlUC\":[\"Система\"],\"D28t6+\":[\"приєднався та вийшов\"],\"DB8zMK\":[\"Застосувати\"],\"DBcWHr\":[\"Власний файл звуку сповіщення\"],\"DTy9Xw\":[\"Попередній перегляд медіа\"],\"Dj4pSr\":[\"Виберіть надійний пароль\"],\"Du+zn+\":[\"Пошук...\"],\"Du2T2f\":[\"Налаштування не знайдено\"],\"DwsSVQ\":[\"Застосувати фільтри та оновити\"],\"E3W/zd\":[\"Стандартний псевдонім\"],\"E6nRW7\":[\"Копіювати URL\"],\"E703RG\":[\"Режими:\"],\"EAeu1Z\":[\"Надіслати запрошення\"],\"EFKJQT\":[\"Налаштування\"],\"EGPQBv\":[\"Власні правила флуду (+f)\"],\"ELik0r\":[\"Переглянути повну політику конфіденційності\"],\"EPbeC2\":[\"Переглянути або редагувати тему каналу\"],\"EQCDNT\":[\"Введіть ім'я користувача оператора...\"],\"EUvulZ\":[\"Знайдено 1 повідомлення, що відповідає \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Наступне зображення\"],\"EdQY6l\":[\"Немає\"],\"EnqLYU\":[\"Пошук серверів...\"],\"F0OKMc\":[\"Редагувати сервер\"],\"F6Int2\":[\"Увімкнути виділення\"],\"FDoLyE\":[\"Макс. користувачів\"],\"FUU/hZ\":[\"Контролюйте, скільки зовнішніх медіа завантажується у чаті.\"],\"Fdp03t\":[\"увімк\"],\"FfPWR0\":[\"Модальне вікно\"],\"FjkaiT\":[\"Зменшити\"],\"FlqOE9\":[\"Що це означає:\"],\"FolHNl\":[\"Керуйте своїм обліковим записом та автентифікацією\"],\"Fp2Dif\":[\"Вийти з сервера\"],\"G5KmCc\":[\"GZ-Line (глобальна Z-Line)\"],\"GDs0lz\":[\"<0>Ризик:</0> Конфіденційна інформація (повідомлення, приватні розмови, дані автентифікації) може бути доступна мережевим адміністраторам або зловмисникам між IRC-серверами.\"],\"GR+2I3\":[\"Додати маску запрошення (напр., nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Закрити виспливаючі сповіщення сервера\"],\"GlHnXw\":[\"Зміна псевдоніму не вдалась: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"Попередній перегляд:\"],\"GtmO8/\":[\"від\"],\"GtuHUQ\":[\"Перейменувати цей канал на сервері. Усі користувачі побачать нову назву.\"],\"GuGfFX\":[\"Перемкнути пошук\"],\"GxkJXS\":[\"Завантаження...\"],\"GzbwnK\":[\"Приєднався до каналу\"],\"GzsUDB\":[\"Розширений профіль\"],\"H/PnT8\":[\"Вставити емодзі\"],\"H6Izzl\":[\"Ваш бажаний код кольору\"],\"H9jIv+\":[\"Показати входи/виходи\"],\"HAKBY9\":[\"Завантажити файли\"],\"HdE1If\":[\"Канал\"],\"Hk4AW9\":[\"Ваше бажане відображуване ім'я\"],\"HmHDk7\":[\"Вибрати учасника\"],\"HrQzPU\":[\"Канали на \",[\"networkName\"]],\"I2tXQ5\":[\"Повідомлення @\",[\"0\"],\" (Enter для нового рядка, Shift+Enter для відправки)\"],\"I6bw/h\":[\"Заблокувати користувача\"],\"I92Z+b\":[\"Увімкнути сповіщення\"],\"I9D72S\":[\"Ви впевнені, що хочете видалити це повідомлення? Цю дію неможливо скасувати.\"],\"IA+1wo\":[\"Відображати, коли користувачів виганяють з каналів\"],\"IDwkJx\":[\"IRC оператор\"],\"ILlU+s\":[\"Інфо:\"],\"IUwGEM\":[\"Зберегти зміни\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" та \",[\"2\"],\" пишуть...\"],\"IgrLD/\":[\"Пауза\"],\"Im6JED\":[\"ШЕПІТ\"],\"ImOQa9\":[\"Відповісти\"],\"IoHMnl\":[\"Максимальне значення: \",[\"0\"]],\"IvMj+0\":[\"Оп\"],\"J28zul\":[\"Підключення...\"],\"J5T9NW\":[\"Інформація про користувача\"],\"J8Y5+z\":[\"Ой! Розрив мережі! ⚠️\"],\"JBHkBA\":[\"Покинув канал\"],\"JCwL0Q\":[\"Введіть причину (необов'язково)\"],\"JFciKP\":[\"Перемкнути\"],\"JXGkhG\":[\"Змінити назву каналу (лише оператори)\"],\"JcD7qf\":[\"Більше дій\"],\"JdkA+c\":[\"Секретний (+s)\"],\"Jmu12l\":[\"Канали сервера\"],\"JvQ++s\":[\"Увімкнути Markdown\"],\"K2jwh/\":[\"Дані WHOIS недоступні\"],\"KAXSwC\":[\"Голос\"],\"KDfTdX\":[\"Видалити повідомлення\"],\"KKBlUU\":[\"Вбудувати\"],\"KM0pLb\":[\"Ласкаво просимо до каналу!\"],\"KR6W2h\":[\"Скасувати ігнорування\"],\"KV+Bi1\":[\"Тільки за запрошенням (+i)\"],\"KdCtwE\":[\"Скільки секунд відстежувати активність флуду перед скиданням лічильників\"],\"Kkezga\":[\"Пароль сервера\"],\"KsiQ/8\":[\"Користувачі повинні бути запрошені для входу до каналу\"],\"L+gB/D\":[\"Інформація про канал\"],\"LC1a7n\":[\"IRC-сервер повідомив, що зв'язки між серверами мають низький рівень безпеки. Це означає, що коли ваші повідомлення передаються між IRC-серверами в мережі, вони можуть бути не належним чином зашифровані або SSL/TLS-сертифікати можуть не перевірятися правильно.\"],\"LNfLR5\":[\"Показати виключення\"],\"LQb0W/\":[\"Показати всі події\"],\"LU7/yA\":[\"Альтернативна назва для відображення в інтерфейсі. Може містити пробіли, емодзі та спеціальні символи. Справжня назва каналу (\",[\"channelName\"],\") використовуватиметься для IRC-команд.\"],\"LUb9O7\":[\"Потрібен дійсний порт сервера\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Політика конфіденційності\"],\"LcuSDR\":[\"Керуйте інформацією профілю та метаданими\"],\"LqLS9B\":[\"Показати зміни псевдоніма\"],\"LsDQt2\":[\"Налаштування каналу\"],\"LtI9AS\":[\"Власник\"],\"LuNhhL\":[\"відреагував на це повідомлення\"],\"M/AZNG\":[\"URL зображення вашого аватара\"],\"M/WIer\":[\"Надіслати повідомлення\"],\"M8er/5\":[\"Ім'я:\"],\"MHk+7g\":[\"Попереднє зображення\"],\"MRorGe\":[\"ПП користувачу\"],\"MVbSGP\":[\"Часове вікно (секунди)\"],\"MkpcsT\":[\"Ваші повідомлення та налаштування зберігаються локально на вашому пристрої\"],\"N/hDSy\":[\"Позначити як бот - зазвичай 'on' або порожньо\"],\"N7TQbE\":[\"Запросити користувача до \",[\"channelName\"]],\"NCca/o\":[\"Введіть стандартний нік...\"],\"Nqs6B9\":[\"Показує всі зовнішні медіа. Будь-яка URL може спричинити запит до невідомого сервера.\"],\"Nt+9O7\":[\"Використовувати WebSocket замість звичайного TCP\"],\"NxIHzc\":[\"Відключити користувача\"],\"O+v/cL\":[\"Переглянути всі канали на сервері\"],\"ODwSCk\":[\"Надіслати GIF\"],\"OGQ5kK\":[\"Налаштувати звуки сповіщень та виділення\"],\"OIPt1Z\":[\"Показати або приховати бічну панель зі списком учасників\"],\"OKSNq/\":[\"Дуже строгий\"],\"ONWvwQ\":[\"Завантажити\"],\"OVKoQO\":[\"Пароль вашого акаунту для автентифікації\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Привносимо IRC у майбутнє\"],\"OhCpra\":[\"Встановити тему…\"],\"OkltoQ\":[\"Заблокувати \",[\"username\"],\" за псевдонімом (запобігає повторному входу з тим же ніком)\"],\"P+t/Te\":[\"Немає додаткових даних\"],\"P42Wcc\":[\"Безпечно\"],\"PD38l0\":[\"Попередній перегляд аватара каналу\"],\"PD9mEt\":[\"Введіть повідомлення...\"],\"PPqfdA\":[\"Відкрити налаштування конфігурації каналу\"],\"PSCjfZ\":[\"Тема, яка відображатиметься для цього каналу. Усі користувачі можуть бачити тему.\"],\"PZCecv\":[\"Попередній перегляд PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 раз\"],\"few\":[[\"c\"],\" рази\"],\"many\":[[\"c\"],\" разів\"],\"other\":[[\"c\"],\" рази\"]}]],\"PguS2C\":[\"Додати маску винятку (напр., nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Показано \",[\"displayedChannelsCount\"],\" із \",[\"0\"],\" каналів\"],\"PqhVlJ\":[\"Заблокувати користувача (за маскою хоста)\"],\"Q+chwU\":[\"Ім'я користувача:\"],\"Q6hhn8\":[\"Налаштування\"],\"QF4a34\":[\"Будь ласка, введіть ім'я користувача\"],\"QGqSZ2\":[\"Колір і форматування\"],\"QJQd1J\":[\"Редагувати профіль\"],\"QSzGDE\":[\"Бездіяльний\"],\"QUlny5\":[\"Ласкаво просимо до \",[\"0\"],\"!\"],\"Qoq+GP\":[\"Читати далі\"],\"QuSkCF\":[\"Фільтрувати канали...\"],\"QwUrDZ\":[\"змінив тему на: \",[\"topic\"]],\"R0UH07\":[\"Зображення \",[\"0\"],\" з \",[\"1\"]],\"R7SsBE\":[\"Вимкнути звук\"],\"R8rf1X\":[\"Натисніть для встановлення теми\"],\"RArB3D\":[\"був кікнутий з \",[\"channelName\"],\" користувачем \",[\"username\"]],\"RI3cWd\":[\"Відкрийте світ IRC з ObsidianIRC\"],\"RMMaN5\":[\"Модерований (+m)\"],\"RWw9Lg\":[\"Закрити вікно\"],\"RZ2BuZ\":[\"Реєстрація облікового запису \",[\"account\"],\" потребує підтвердження: \",[\"message\"]],\"RySp6q\":[\"Приховати коментарі\"],\"SPKQTd\":[\"Псевдонім обов'язковий\"],\"SPVjfj\":[\"За замовчуванням буде 'без причини', якщо залишити порожнім\"],\"SQKPvQ\":[\"Запросити користувача\"],\"SkZcl+\":[\"Виберіть готовий профіль захисту від флуду. Ці профілі надають збалансовані налаштування захисту для різних випадків використання.\"],\"Slr+3C\":[\"Мін. користувачів\"],\"Spnlre\":[\"Ви запросили \",[\"target\"],\" приєднатися до \",[\"channel\"]],\"T/ckN5\":[\"Відкрити у переглядачі\"],\"T91vKp\":[\"Відтворити\"],\"TV2Wdu\":[\"Дізнайтесь, як ми обробляємо ваші дані та захищаємо вашу конфіденційність.\"],\"TgFpwD\":[\"Застосовується...\"],\"TkzSFB\":[\"Без змін\"],\"TtserG\":[\"Введіть справжнє ім'я\"],\"Ttz9J1\":[\"Введіть пароль...\"],\"Tz0i8g\":[\"Налаштування\"],\"U3pytU\":[\"Адмін\"],\"UDb2YD\":[\"React\"],\"UE4KO5\":[\"*канал*\"],\"UGT5vp\":[\"Зберегти налаштування\"],\"UV5hLB\":[\"Заборон не знайдено\"],\"Uaj3Nd\":[\"Статусні повідомлення\"],\"Ue3uny\":[\"За замовчуванням (без профілю)\"],\"UkARhe\":[\"Нормальний - стандартний захист\"],\"Umn7Cj\":[\"Коментарів ще немає. Будьте першим!\"],\"UtUIRh\":[[\"0\"],\" старіших повідомлень\"],\"UwzP+U\":[\"Безпечне з'єднання\"],\"V0/A4O\":[\"Власник каналу\"],\"V4qgxE\":[\"Створено до (хв тому)\"],\"V8yTm6\":[\"Очистити пошук\"],\"VJMMyz\":[\"ObsidianIRC - Привносимо IRC у майбутнє\"],\"VJScHU\":[\"Причина\"],\"VLsmVV\":[\"Вимкнути сповіщення\"],\"VbyRUy\":[\"Коментарі\"],\"Vmx0mQ\":[\"Встановлено:\"],\"VqnIZz\":[\"Переглянути нашу політику конфіденційності та практики роботи з даними\"],\"VrMygG\":[\"Мінімальна довжина: \",[\"0\"]],\"VrnTui\":[\"Ваші займенники, відображені у вашому профілі\"],\"W8E3qn\":[\"Автентифікований акаунт\"],\"WAakm9\":[\"Видалити канал\"],\"WFxTHC\":[\"Додати маску бану (напр., nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Хост сервера обов'язковий\"],\"WRYdXW\":[\"Позиція аудіо\"],\"WUOH5B\":[\"Ігнорувати користувача\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Показати ще 1 елемент\"],\"few\":[\"Показати ще \",[\"1\"],\" елементи\"],\"many\":[\"Показати ще \",[\"1\"],\" елементів\"],\"other\":[\"Показати ще \",[\"1\"],\" елементи\"]}]],\"Weq9zb\":[\"Загальне\"],\"Wfj7Sk\":[\"Вимкнути або увімкнути звуки сповіщень\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*спам*\"],\"WzMCru\":[\"Профіль користувача\"],\"X6S3lt\":[\"Пошук налаштувань, каналів, серверів...\"],\"XEHan5\":[\"Продовжити все одно\"],\"XI1+wb\":[\"Недійсний формат\"],\"XIXeuC\":[\"Повідомлення @\",[\"0\"]],\"XMS+k4\":[\"Почати приватне повідомлення\"],\"XWgxXq\":[\"Альбом\"],\"Xd7+IT\":[\"Відкріпити приватну розмову\"],\"Xm/s+u\":[\"Дисплей\"],\"Xp2n93\":[\"Показує медіа з надійного файлового хоста вашого сервера. Запити до зовнішніх сервісів не здійснюються.\"],\"XvjC4F\":[\"Збереження...\"],\"Y/qryO\":[\"Користувачів за вашим запитом не знайдено\"],\"YAqRpI\":[\"Реєстрація облікового запису \",[\"account\"],\" успішна: \",[\"message\"]],\"YEfzvP\":[\"Захищена тема (+t)\"],\"YQOn6a\":[\"Згорнути список учасників\"],\"YRCoE9\":[\"Оператор каналу\"],\"YURQaF\":[\"Переглянути профіль\"],\"YdBSvr\":[\"Керувати відображенням медіа та зовнішнього вмісту\"],\"Yj6U3V\":[\"Без центрального сервера:\"],\"YjvpGx\":[\"Займенники\"],\"YqH4l4\":[\"Без ключа\"],\"YyUPpV\":[\"Акаунт:\"],\"ZJSWfw\":[\"Повідомлення при від'єднанні від сервера\"],\"ZR1dJ4\":[\"Запрошення\"],\"ZdWg0V\":[\"Відкрити в браузері\"],\"ZhRBbl\":[\"Пошук повідомлень…\"],\"Zmcu3y\":[\"Розширені фільтри\"],\"a2/8e5\":[\"Тему встановлено після (хв тому)\"],\"aHKcKc\":[\"Попередня сторінка\"],\"aJTbXX\":[\"Пароль оператора\"],\"aQryQv\":[\"Шаблон вже існує\"],\"aW9pLN\":[\"Максимальна кількість користувачів у каналі. Залиште порожнім для відсутності обмежень.\"],\"ah4fmZ\":[\"Також показує попередній перегляд з YouTube, Vimeo, SoundCloud та подібних відомих сервісів.\"],\"aifXak\":[\"Немає медіа у цьому каналі\"],\"ap2zBz\":[\"Розслаблений\"],\"az8lvo\":[\"Вимк\"],\"azXSNo\":[\"Розгорнути список учасників\"],\"azdliB\":[\"Увійти в акаунт\"],\"b26wlF\":[\"вона/її\"],\"bD/+Ei\":[\"Строгий\"],\"bQ6BJn\":[\"Налаштуйте детальні правила захисту від флуду. Кожне правило вказує, яку активність відстежувати і які дії вживати при перевищенні порогів.\"],\"beV7+y\":[\"Користувач отримає запрошення приєднатися до \",[\"channelName\"],\".\"],\"bk84cH\":[\"Повідомлення про відсутність\"],\"bkHdLj\":[\"Додати IRC-сервер\"],\"bmQLn5\":[\"Додати правило\"],\"bwRvnp\":[\"Дія\"],\"c8+EVZ\":[\"Підтверджений акаунт\"],\"cGYUlD\":[\"Медіа-перегляди не завантажені.\"],\"cLF98o\":[\"Показати коментарі (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Користувачів немає\"],\"cSgpoS\":[\"Закріпити приватну розмову\"],\"cde3ce\":[\"Повідомлення <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Скопіювати форматований вивід\"],\"cl/A5J\":[\"Ласкаво просимо до \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Видалити\"],\"coPLXT\":[\"Ми не зберігаємо ваші IRC-комунікації на наших серверах\"],\"crYH/6\":[\"Програвач SoundCloud\"],\"d3sis4\":[\"Додати сервер\"],\"d9aN5k\":[\"Видалити \",[\"username\"],\" з каналу\"],\"dEgA5A\":[\"Скасувати\"],\"dGi1We\":[\"Відкріпити цю приватну розмову\"],\"dJVuyC\":[\"покинув \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"до\"],\"dXqxlh\":[\"<0>⚠️ Загроза безпеці!</0> Це з'єднання може бути вразливим до перехоплення або атак «людина посередині».\"],\"da9Q/R\":[\"Змінено режими каналу\"],\"dhJN3N\":[\"Показати коментарі\"],\"dj2xTE\":[\"Відхилити сповіщення\"],\"dpCzmC\":[\"Налаштування захисту від флуду\"],\"e9dQpT\":[\"Бажаєте відкрити це посилання в новій вкладці?\"],\"ePK91l\":[\"Редагувати\"],\"eYBDuB\":[\"Завантажте зображення або вкажіть URL з необов'язковою підстановкою \",[\"size\"],\" для динамічного розміру\"],\"edBbee\":[\"Заблокувати \",[\"username\"],\" за маскою хоста (запобігає повторному входу з тієї ж IP/хоста)\"],\"ekfzWq\":[\"Налаштування користувача\"],\"elPDWs\":[\"Налаштуйте свій IRC-клієнт\"],\"eu2osY\":[\"<0>💡 Рекомендація:</0> Продовжуйте лише якщо довіряєте цьому серверу і розумієте ризики. Уникайте передачі конфіденційної інформації або паролів через це з'єднання.\"],\"euEhbr\":[\"Натисніть, щоб приєднатися до \",[\"channel\"]],\"ez3vLd\":[\"Увімкнути багаторядкове введення\"],\"f0J5Ki\":[\"Зв'язок між серверами може використовувати незашифровані з'єднання\"],\"f9BHJk\":[\"Попередити користувача\"],\"fDOLLd\":[\"Канали не знайдено.\"],\"ffzDkB\":[\"Анонімна аналітика:\"],\"fq1GF9\":[\"Відображати, коли користувачі від'єднуються від сервера\"],\"gEF57C\":[\"Цей сервер підтримує лише один тип з'єднання\"],\"gJuLUI\":[\"Список ігнорування\"],\"gNzMrk\":[\"Поточний аватар\"],\"gjPWyO\":[\"Введіть нік...\"],\"gz6UQ3\":[\"Розгорнути\"],\"h6razj\":[\"Маска виключення назви каналу\"],\"hG6jnw\":[\"Тема не встановлена\"],\"hG89Ed\":[\"Зображення\"],\"hZ6znB\":[\"Порт\"],\"ha+Bz5\":[\"напр., 100:1440\"],\"hehnjM\":[\"Кількість\"],\"hzdLuQ\":[\"Говорити можуть лише користувачі з голосом або вище\"],\"i0qMbr\":[\"Головна\"],\"iDNBZe\":[\"Сповіщення\"],\"iH8pgl\":[\"Назад\"],\"iL9SZg\":[\"Заблокувати користувача (за псевдонімом)\"],\"iNt+3c\":[\"Назад до зображення\"],\"iQvi+a\":[\"Не попереджати мене про низький рівень безпеки для цього сервера\"],\"iSLIjg\":[\"Підключитися\"],\"iWXkHH\":[\"Напів-оператор\"],\"iZeTtp\":[\"Хост сервера\"],\"idD8Ev\":[\"Збережено\"],\"iivqkW\":[\"Час входу\"],\"ij+Elv\":[\"Попередній перегляд зображення\"],\"ilIWp7\":[\"Перемкнути сповіщення\"],\"iuaqvB\":[\"Використовуйте * для шаблонів. Приклади: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Бот\"],\"j2DGR0\":[\"Блокування за маскою хоста\"],\"jA4uoI\":[\"Тема:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Причина (необов'язково)\"],\"jUV7CU\":[\"Завантажити аватар\"],\"jW5Uwh\":[\"Контролюйте завантаження зовнішніх медіа. Вимк / Безпечно / Надійні джерела / Весь вміст.\"],\"jXzms5\":[\"Параметри вкладення\"],\"jZlrte\":[\"Колір\"],\"jfC/xh\":[\"Контакт\"],\"jywMpv\":[\"#нова-назва-каналу\"],\"k112DD\":[\"Завантажити старіші повідомлення\"],\"k3ID0F\":[\"Фільтрувати учасників…\"],\"k65gsE\":[\"Детальний огляд\"],\"k7Zgob\":[\"Скасувати підключення\"],\"kAVx5h\":[\"Запрошень не знайдено\"],\"kCLEPU\":[\"Підключено до\"],\"kF5LKb\":[\"Ігноровані шаблони:\"],\"kGeOx/\":[\"Приєднатися до \",[\"0\"]],\"kITKr8\":[\"Завантаження режимів каналу...\"],\"kPpPsw\":[\"Ви є IRC-оператором\"],\"kWJmRL\":[\"Ви\"],\"kfcRb0\":[\"Аватар\"],\"kjMqSj\":[\"Скопіювати JSON\"],\"krViRy\":[\"Натисніть для копіювання як JSON\"],\"ks71ra\":[\"Винятки\"],\"kw4lRv\":[\"Напів-оператор каналу\"],\"kxgIRq\":[\"Виберіть або додайте канал для початку.\"],\"ky6dWe\":[\"Попередній перегляд аватара\"],\"l+GxCv\":[\"Завантаження каналів...\"],\"l+IUVW\":[\"Верифікація облікового запису \",[\"account\"],\" успішна: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"перепідключився\"],\"few\":[\"перепідключився \",[\"reconnectCount\"],\" рази\"],\"many\":[\"перепідключився \",[\"reconnectCount\"],\" разів\"],\"other\":[\"перепідключився \",[\"reconnectCount\"],\" рази\"]}]],\"l5jmzx\":[[\"0\"],\" та \",[\"1\"],\" пишуть...\"],\"lHy8N5\":[\"Завантаження більше каналів...\"],\"lbpf14\":[\"Приєднатися до \",[\"value\"]],\"lfFsZ4\":[\"Канали\"],\"lkNdiH\":[\"Ім'я акаунту\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Завантажити зображення\"],\"loQxaJ\":[\"Я повернувся\"],\"lvfaxv\":[\"ГОЛОВНА\"],\"m16xKo\":[\"Додати\"],\"m8flAk\":[\"Попередній перегляд (ще не завантажено)\"],\"mEPxTp\":[\"<0>⚠️ Будьте обережні!</0> Відкривайте лише посилання з надійних джерел. Шкідливі посилання можуть порушити вашу безпеку або конфіденційність.\"],\"mHGdhG\":[\"Інформація про сервер\"],\"mHS8lb\":[\"Повідомлення #\",[\"0\"]],\"mMYBD9\":[\"Широкий - ширша область захисту\"],\"mTGsPd\":[\"Тема каналу\"],\"mU8j6O\":[\"Без зовнішніх повідомлень (+n)\"],\"mZp8FL\":[\"Автоматичне повернення до одного рядка\"],\"mdQu8G\":[\"ВашПсевдонім\"],\"miSSBQ\":[\"Коментарі (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"Користувач автентифікований\"],\"mwtcGl\":[\"Закрити коментарі\"],\"mzI/c+\":[\"Завантажити\"],\"n3fGRk\":[\"встановлено \",[\"0\"]],\"nE9jsU\":[\"Розслаблений - менш агресивний захист\"],\"nNflMD\":[\"Покинути канал\"],\"nPXkBi\":[\"Завантаження даних WHOIS...\"],\"nQnxxF\":[\"Повідомлення #\",[\"0\"],\" (Shift+Enter для нового рядка)\"],\"nWMRxa\":[\"Відкріпити\"],\"nkC032\":[\"Без профілю флуду\"],\"o69z4d\":[\"Надіслати попереджувальне повідомлення \",[\"username\"]],\"o9ylQi\":[\"Знайдіть GIF для початку\"],\"oFGkER\":[\"Повідомлення сервера\"],\"oOi11l\":[\"Прокрутити вниз\"],\"oQEzQR\":[\"Нове DM\"],\"oXOSPE\":[\"Онлайн\"],\"oal760\":[\"Можливі атаки «людина посередині» на з'єднання сервера\"],\"oeqmmJ\":[\"Надійні джерела\"],\"ovBPCi\":[\"За замовчуванням\"],\"p0Z69r\":[\"Шаблон не може бути порожнім\"],\"p1KgtK\":[\"Не вдалося завантажити аудіо\"],\"p59pEv\":[\"Детальніше\"],\"p7sRI6\":[\"Повідомляти інших, коли ви друкуєте\"],\"pBm1od\":[\"Секретний канал\"],\"pNmiXx\":[\"Ваш псевдонім за замовчуванням для всіх серверів\"],\"pUUo9G\":[\"Хост:\"],\"pVGPmz\":[\"Пароль акаунту\"],\"peNE68\":[\"Постійний\"],\"plhHQt\":[\"Немає даних\"],\"pm6+q5\":[\"Попередження про безпеку\"],\"pn5qSs\":[\"Додаткова інформація\"],\"q0cR4S\":[\"тепер відомий як **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"Канал не відображатиметься у командах LIST або NAMES\"],\"qLpTm/\":[\"Видалити реакцію \",[\"emoji\"]],\"qVkGWK\":[\"Закріпити\"],\"qY8wNa\":[\"Головна сторінка\"],\"qb0xJ7\":[\"Використовуйте шаблони: * відповідає будь-якій послідовності, ? відповідає будь-якому одному символу. Приклади: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Ключ каналу (+k)\"],\"qtoOYG\":[\"Без обмежень\"],\"r1W2AS\":[\"Зображення з файлового хостингу\"],\"rIPR2O\":[\"Тему встановлено до (хв тому)\"],\"rMMSYo\":[\"Максимальна довжина: \",[\"0\"]],\"rWtzQe\":[\"Мережа розділилась і возз'єдналась. ✅\"],\"rYG2u6\":[\"Будь ласка, зачекайте...\"],\"rdUucN\":[\"Попередній перегляд\"],\"rjGI/Q\":[\"Конфіденційність\"],\"rk8iDX\":[\"Завантаження GIF...\"],\"rn6SBY\":[\"Увімкнути звук\"],\"s/UKqq\":[\"Виключено з каналу\"],\"s8cATI\":[\"приєднався до \",[\"channelName\"]],\"sCO9ue\":[\"З'єднання з <0>\",[\"serverName\"],\"</0> має такі проблеми безпеки:\"],\"sGH11W\":[\"Сервер\"],\"sHI1H+\":[\"тепер відомий як **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" запросив вас приєднатися до \",[\"channel\"]],\"sby+1/\":[\"Натисніть для копіювання\"],\"sfN25C\":[\"Ваше справжнє або повне ім'я\"],\"sliuzR\":[\"Відкрити посилання\"],\"sqrO9R\":[\"Власні згадки\"],\"sr6RdJ\":[\"Багаторядковий на Shift+Enter\"],\"swrCpB\":[\"Канал перейменовано з \",[\"oldName\"],\" на \",[\"newName\"],\" користувачем \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Додаткові\"],\"t/YqKh\":[\"Видалити\"],\"t47eHD\":[\"Ваш унікальний ідентифікатор на цьому сервері\"],\"tAkAh0\":[\"URL з необов'язковою підстановкою \",[\"size\"],\" для динамічного розміру. Приклад: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"Показати або приховати бічну панель зі списком каналів\"],\"tfDRzk\":[\"Зберегти\"],\"tiBsJk\":[\"покинув \",[\"channelName\"]],\"tt4/UD\":[\"вийшов (\",[\"reason\"],\")\"],\"u0TcnO\":[\"Псевдонім {nick} вже використовується, повторна спроба з {newNick}\"],\"u0a8B4\":[\"Автентифікуватися як IRC-оператор для адміністративного доступу\"],\"u0rWFU\":[\"Створено після (хв тому)\"],\"u72w3t\":[\"Користувачі та шаблони для ігнорування\"],\"u7jc2L\":[\"вийшов\"],\"uAQUqI\":[\"Статус\"],\"uB85T3\":[\"Помилка збереження: \",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC сервери:\"],\"usSSr/\":[\"Рівень масштабу\"],\"v7uvcf\":[\"Програма:\"],\"vE8kb+\":[\"Використовуйте Shift+Enter для нового рядка (Enter надсилає)\"],\"vERlcd\":[\"Профіль\"],\"vK0RL8\":[\"Без теми\"],\"vSJd18\":[\"Відео\"],\"vXIe7J\":[\"Мова\"],\"vaHYxN\":[\"Справжнє ім'я\"],\"vhjbKr\":[\"Відсутній\"],\"w4NYox\":[\"клієнт \",[\"title\"]],\"w8xQRx\":[\"Недійсне значення\"],\"wFjjxZ\":[\"був кікнутий з \",[\"channelName\"],\" користувачем \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Винятків з заборон не знайдено\"],\"wPrGnM\":[\"Адміністратор каналу\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Відображати, коли користувачі входять або виходять з каналів\"],\"whqZ9r\":[\"Додаткові слова або фрази для виділення\"],\"wm7RV4\":[\"Звук сповіщення\"],\"wz/Yoq\":[\"Ваші повідомлення можуть бути перехоплені при передачі між серверами\"],\"xCJdfg\":[\"Очистити\"],\"xUHRTR\":[\"Автоматично автентифікуватися як оператор при підключенні\"],\"xWHwwQ\":[\"Блокування\"],\"xYilR2\":[\"Медіа\"],\"xceQrO\":[\"Підтримуються лише захищені websocket-з'єднання\"],\"xdtXa+\":[\"назва-каналу\"],\"xfXC7q\":[\"Текстові канали\"],\"xlCYOE\":[\"Отримання більше повідомлень...\"],\"xlhswE\":[\"Мінімальне значення: \",[\"0\"]],\"xq97Ci\":[\"Додати слово або фразу...\"],\"xuRqRq\":[\"Ліміт клієнтів (+l)\"],\"xwF+7J\":[[\"0\"],\" пише...\"],\"yNeucF\":[\"Цей сервер не підтримує розширені метадані профілю (розширення IRCv3 METADATA). Додаткові поля, такі як аватар, відображуване ім'я та статус, недоступні.\"],\"yPlrca\":[\"Аватар каналу\"],\"yQE2r9\":[\"Завантаження\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Ім'я оператора\"],\"yYOzWD\":[\"логи\"],\"yfx9Re\":[\"Пароль IRC оператора\"],\"ygCKqB\":[\"Зупинити\"],\"ymDxJx\":[\"Ім'я IRC оператора\"],\"yrpRsQ\":[\"Сортувати за назвою\"],\"yz7wBu\":[\"Закрити\"],\"zJw+jA\":[\"встановлює режим: \",[\"0\"]],\"zebeLu\":[\"Введіть ім'я оператора\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"Недійсний формат шаблону. Використовуйте формат nick!user@host (дозволені символи * як шаблони)\"],\"+6NQQA\":[\"Загальний канал підтримки\"],\"+6NyRG\":[\"Клієнт\"],\"+K0AvT\":[\"Від'єднатися\"],\"+cyFdH\":[\"Стандартне повідомлення при позначенні себе відсутнім\"],\"+mVPqU\":[\"Відображати Markdown-форматування у повідомленнях\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"Ім'я користувача вашого акаунту для автентифікації\"],\"+yPBXI\":[\"Вибрати файл\"],\"+zy2Nq\":[\"Тип\"],\"/09cao\":[\"Низький рівень безпеки з'єднання (Рівень \",[\"securityLevel\"],\")\"],\"/3BQ4J\":[\"Користувачі за межами каналу не можуть надсилати до нього повідомлення\"],\"/6BzZF\":[\"Перемкнути список учасників\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"Користувач відсутній\"],\"/XQgft\":[\"Дослідити\"],\"/cF7Rs\":[\"Гучність\"],\"/dqduX\":[\"Наступна сторінка\"],\"/fc3q4\":[\"Весь вміст\"],\"/kISDh\":[\"Увімкнути звуки сповіщень\"],\"/n04sB\":[\"Kill\"],\"/rTz0M\":[\"Аудіо\"],\"/rfkZe\":[\"Відтворювати звуки для згадок і повідомлень\"],\"0/0ZGA\":[\"Маска назви каналу\"],\"0D6j7U\":[\"Дізнатися більше про власні правила →\"],\"0XsHcR\":[\"Вигнати користувача\"],\"0ZpE//\":[\"Сортувати за кількістю користувачів\"],\"0bEPwz\":[\"Встановити статус «Відсутній»\"],\"0dGkPt\":[\"Розгорнути список каналів\"],\"0gS7M5\":[\"Відображуване ім'я\"],\"0kS+M8\":[\"ПрикладМЕРЕЖА\"],\"0rgoY7\":[\"Підключатися лише до обраних вами серверів\"],\"0wdd7X\":[\"Приєднатися\"],\"0wkVYx\":[\"Приватні повідомлення\"],\"111uHX\":[\"Попередній перегляд посилання\"],\"196EG4\":[\"Видалити приватний чат\"],\"1DSr1i\":[\"Зареєструвати акаунт\"],\"1O/24y\":[\"Перемкнути список каналів\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"Попередження про зовнішнє посилання\"],\"1ZC/dv\":[\"Немає непрочитаних згадувань або повідомлень\"],\"1pO1zi\":[\"Назва сервера обов'язкова\"],\"1uwfzQ\":[\"Переглянути тему каналу\"],\"268g7c\":[\"Введіть відображуване ім'я\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"Оператори сервера в мережі можуть потенційно читати ваші повідомлення\"],\"2FYpfJ\":[\"Більше\"],\"2HF1Y2\":[[\"inviter\"],\" запросив \",[\"target\"],\" приєднатися до \",[\"channel\"]],\"2I70QL\":[\"Переглянути інформацію профілю користувача\"],\"2QYdmE\":[\"Користувачі:\"],\"2QpEjG\":[\"вийшов\"],\"2YE223\":[\"Повідомлення #\",[\"0\"],\" (Enter для нового рядка, Shift+Enter для відправки)\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"Використовувати пароль сервера\"],\"2iTmdZ\":[\"Локальне сховище:\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"Строгий - більш агресивний захист\"],\"2uDhbA\":[\"Введіть ім'я користувача для запрошення\"],\"2ygf/L\":[\"← Назад\"],\"2zEgxj\":[\"Пошук GIF...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"Перейменувати канал\"],\"3THokf\":[\"Користувач з голосом\"],\"3TSz9S\":[\"Згорнути\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"Відображувана назва каналу\"],\"3ryuFU\":[\"Необов'язкові звіти про збої для покращення додатку\"],\"3uBF/8\":[\"Закрити переглядач\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"Введіть ім'я акаунту...\"],\"4/Rr0R\":[\"Запросити користувача до поточного каналу\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"Правила\"],\"4JJtW9\":[\"#переповнення\"],\"4NqeT4\":[\"Профіль флуду (+F)\"],\"4RZQRK\":[\"Що ти зараз робиш?\"],\"4hfTrB\":[\"Псевдонім\"],\"4n99LO\":[\"Вже в \",[\"0\"]],\"4t6vMV\":[\"Автоматично перемикатися на один рядок для коротких повідомлень\"],\"4vsHmf\":[\"Час (хв)\"],\"5+INAX\":[\"Виділяти повідомлення, що вас згадують\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Ім'я оператора\"],\"678PKt\":[\"Назва мережі\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"Офлайн\"],\"6CO3WE\":[\"Пароль для входу до каналу. Залиште порожнім для видалення ключа.\"],\"6HhMs3\":[\"Повідомлення про вихід\"],\"6V3Ea3\":[\"Скопійовано\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"Показати менше\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"Введіть пароль оператора...\"],\"7+IHTZ\":[\"Файл не вибрано\"],\"73hrRi\":[\"nick!user@host (напр., spam*!*@*, *!*@badhost.com)\"],\"7QkKyN\":[\"Надіслати приватне повідомлення\"],\"7U1W7c\":[\"Дуже розслаблений\"],\"7Y1YQj\":[\"Справжнє ім'я:\"],\"7YHArF\":[\"— відкрити у переглядачі\"],\"7fjnVl\":[\"Пошук користувачів...\"],\"7jL88x\":[\"Видалити це повідомлення? Цю дію неможливо скасувати.\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"Що у вас на думці?\"],\"7sEpu1\":[\"Учасники — \",[\"0\"]],\"7sNhEz\":[\"Ім'я користувача\"],\"8H0Q+x\":[\"Дізнатися більше про профілі →\"],\"8Phu0A\":[\"Відображати, коли користувачі змінюють псевдонім\"],\"8XTG9e\":[\"Введіть пароль оператора\"],\"8XsV2J\":[\"Повторити надсилання\"],\"8ZsakT\":[\"Пароль\"],\"8kR84m\":[\"Ви збираєтеся відкрити зовнішнє посилання:\"],\"8lCgih\":[\"Видалити правило\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"one\":[\"приєднався\"],\"few\":[\"приєднався \",[\"joinCount\"],\" рази\"],\"many\":[\"приєднався \",[\"joinCount\"],\" разів\"],\"other\":[\"приєднався \",[\"joinCount\"],\" рази\"]}]],\"9BMLnJ\":[\"Перепідключитися до сервера\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"Додати реакцію\"],\"9PQ8m2\":[\"G-Line (глобальний бан)\"],\"9Qs99X\":[\"Електронна пошта:\"],\"9QupBP\":[\"Видалити шаблон\"],\"9bG48P\":[\"Надсилання\"],\"9f5f0u\":[\"Питання про конфіденційність? Зв'яжіться з нами:\"],\"9unqs3\":[\"Відсутність:\"],\"9v3hwv\":[\"Сервери не знайдено.\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"Підключення\"],\"A1taO8\":[\"Пошук\"],\"A2adVi\":[\"Надсилати сповіщення про введення\"],\"A9Rhec\":[\"Назва каналу\"],\"AWOSPo\":[\"Збільшити\"],\"AXSpEQ\":[\"Оператор при підключенні\"],\"AeXO77\":[\"Акаунт\"],\"AhNP40\":[\"Перемотати\"],\"Ai2U7L\":[\"Хост\"],\"AjBQnf\":[\"Змінено псевдонім\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"Скасувати відповідь\"],\"ApSx0O\":[\"Знайдено \",[\"0\"],\" повідомлень, що відповідають \\\"\",[\"searchQuery\"],\"\\\"\"],\"AxPAXW\":[\"Результатів не знайдено\"],\"AyNqAB\":[\"Відображати всі події сервера в чаті\"],\"B/QqGw\":[\"Відійшов від клавіатури\"],\"B8AaMI\":[\"Це поле обов'язкове\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"Сервер не підтримує розширену фільтрацію LIST\"],\"BDKt3I\":[[\"0\"],\", \",[\"1\"],\", \",[\"2\"],\" та ще \",[\"3\"],\" пишуть...\"],\"BGul2A\":[\"У вас є незбережені зміни. Ви впевнені, що хочете закрити без збереження?\"],\"BIf9fi\":[\"Ваше статусне повідомлення\"],\"BZz3md\":[\"Ваш особистий веб-сайт\"],\"Bgm/H7\":[\"Дозволити введення кількох рядків тексту\"],\"BiQIl1\":[\"Закріпити цю приватну розмову\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"Натисніть, щоб перейти до повідомлення\"],\"Bowq3c\":[\"Тільки оператори можуть змінювати тему каналу\"],\"Btozzp\":[\"Термін дії цього зображення минув\"],\"Bycfjm\":[\"Всього: \",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"Скопіювати весь JSON\"],\"C9L9wL\":[\"Збір даних\"],\"CDq4wC\":[\"Помірний режим для користувача\"],\"CHVRxG\":[\"Повідомлення @\",[\"0\"],\" (Shift+Enter для нового рядка)\"],\"CN9zdR\":[\"Ім'я та пароль оператора обов'язкові\"],\"CW3sYa\":[\"Додати реакцію \",[\"emoji\"]],\"CaAkqd\":[\"Показати виходи\"],\"CbvaYj\":[\"Блокування за псевдонімом\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"Вибрати канал\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"Нормальний\"],\"D+NlUC\":[\"Система\"],\"D28t6+\":[\"приєднався та вийшов\"],\"DB8zMK\":[\"Застосувати\"],\"DBcWHr\":[\"Власний файл звуку сповіщення\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"Попередній перегляд медіа\"],\"Dj4pSr\":[\"Виберіть надійний пароль\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"Пошук...\"],\"Du2T2f\":[\"Налаштування не знайдено\"],\"DwsSVQ\":[\"Застосувати фільтри та оновити\"],\"E3W/zd\":[\"Стандартний псевдонім\"],\"E6nRW7\":[\"Копіювати URL\"],\"E703RG\":[\"Режими:\"],\"EAeu1Z\":[\"Надіслати запрошення\"],\"EFKJQT\":[\"Налаштування\"],\"EGPQBv\":[\"Власні правила флуду (+f)\"],\"ELik0r\":[\"Переглянути повну політику конфіденційності\"],\"EPbeC2\":[\"Переглянути або редагувати тему каналу\"],\"EQCDNT\":[\"Введіть ім'я користувача оператора...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"Знайдено 1 повідомлення, що відповідає \\\"\",[\"searchQuery\"],\"\\\"\"],\"EatZYJ\":[\"Наступне зображення\"],\"EdQY6l\":[\"Немає\"],\"EnqLYU\":[\"Пошук серверів...\"],\"F0OKMc\":[\"Редагувати сервер\"],\"F6Int2\":[\"Увімкнути виділення\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"Макс. користувачів\"],\"FUU/hZ\":[\"Контролюйте, скільки зовнішніх медіа завантажується у чаті.\"],\"Fdp03t\":[\"увімк\"],\"FfPWR0\":[\"Модальне вікно\"],\"FjkaiT\":[\"Зменшити\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"Що це означає:\"],\"FolHNl\":[\"Керуйте своїм обліковим записом та автентифікацією\"],\"Fp2Dif\":[\"Вийти з сервера\"],\"G5KmCc\":[\"GZ-Line (глобальна Z-Line)\"],\"GDs0lz\":[\"<0>Ризик:</0> Конфіденційна інформація (повідомлення, приватні розмови, дані автентифікації) може бути доступна мережевим адміністраторам або зловмисникам між IRC-серверами.\"],\"GR+2I3\":[\"Додати маску запрошення (напр., nick!*@*, *!*@host.com)\"],\"GRLyMU\":[\"Закрити виспливаючі сповіщення сервера\"],\"GlHnXw\":[\"Зміна псевдоніму не вдалась: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"Попередній перегляд:\"],\"GtmO8/\":[\"від\"],\"GtuHUQ\":[\"Перейменувати цей канал на сервері. Усі користувачі побачать нову назву.\"],\"GuGfFX\":[\"Перемкнути пошук\"],\"GxkJXS\":[\"Завантаження...\"],\"GzbwnK\":[\"Приєднався до каналу\"],\"GzsUDB\":[\"Розширений профіль\"],\"H/PnT8\":[\"Вставити емодзі\"],\"H6Izzl\":[\"Ваш бажаний код кольору\"],\"H9jIv+\":[\"Показати входи/виходи\"],\"HAKBY9\":[\"Завантажити файли\"],\"HdE1If\":[\"Канал\"],\"Hk4AW9\":[\"Ваше бажане відображуване ім'я\"],\"HmHDk7\":[\"Вибрати учасника\"],\"HrQzPU\":[\"Канали на \",[\"networkName\"]],\"I2tXQ5\":[\"Повідомлення @\",[\"0\"],\" (Enter для нового рядка, Shift+Enter для відправки)\"],\"I6bw/h\":[\"Заблокувати користувача\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"Увімкнути сповіщення\"],\"I9D72S\":[\"Ви впевнені, що хочете видалити це повідомлення? Цю дію неможливо скасувати.\"],\"IA+1wo\":[\"Відображати, коли користувачів виганяють з каналів\"],\"IDwkJx\":[\"IRC оператор\"],\"ILlU+s\":[\"Інфо:\"],\"IUwGEM\":[\"Зберегти зміни\"],\"IVeGK6\":[[\"0\"],\", \",[\"1\"],\" та \",[\"2\"],\" пишуть...\"],\"IgrLD/\":[\"Пауза\"],\"Im6JED\":[\"ШЕПІТ\"],\"ImOQa9\":[\"Відповісти\"],\"IoHMnl\":[\"Максимальне значення: \",[\"0\"]],\"IvMj+0\":[\"Оп\"],\"J28zul\":[\"Підключення...\"],\"J5T9NW\":[\"Інформація про користувача\"],\"J8Y5+z\":[\"Ой! Розрив мережі! ⚠️\"],\"JBHkBA\":[\"Покинув канал\"],\"JCwL0Q\":[\"Введіть причину (необов'язково)\"],\"JFciKP\":[\"Перемкнути\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"Змінити назву каналу (лише оператори)\"],\"JcD7qf\":[\"Більше дій\"],\"JdkA+c\":[\"Секретний (+s)\"],\"Jmu12l\":[\"Канали сервера\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"Увімкнути Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"Дані WHOIS недоступні\"],\"KAXSwC\":[\"Голос\"],\"KDfTdX\":[\"Видалити повідомлення\"],\"KKBlUU\":[\"Вбудувати\"],\"KM0pLb\":[\"Ласкаво просимо до каналу!\"],\"KR6W2h\":[\"Скасувати ігнорування\"],\"KV+Bi1\":[\"Тільки за запрошенням (+i)\"],\"KdCtwE\":[\"Скільки секунд відстежувати активність флуду перед скиданням лічильників\"],\"Kkezga\":[\"Пароль сервера\"],\"KsiQ/8\":[\"Користувачі повинні бути запрошені для входу до каналу\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"Інформація про канал\"],\"LC1a7n\":[\"IRC-сервер повідомив, що зв'язки між серверами мають низький рівень безпеки. Це означає, що коли ваші повідомлення передаються між IRC-серверами в мережі, вони можуть бути не належним чином зашифровані або SSL/TLS-сертифікати можуть не перевірятися правильно.\"],\"LNfLR5\":[\"Показати виключення\"],\"LQb0W/\":[\"Показати всі події\"],\"LU7/yA\":[\"Альтернативна назва для відображення в інтерфейсі. Може містити пробіли, емодзі та спеціальні символи. Справжня назва каналу (\",[\"channelName\"],\") використовуватиметься для IRC-команд.\"],\"LUb9O7\":[\"Потрібен дійсний порт сервера\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"Політика конфіденційності\"],\"LcuSDR\":[\"Керуйте інформацією профілю та метаданими\"],\"LqLS9B\":[\"Показати зміни псевдоніма\"],\"LsDQt2\":[\"Налаштування каналу\"],\"LtI9AS\":[\"Власник\"],\"LuNhhL\":[\"відреагував на це повідомлення\"],\"M/AZNG\":[\"URL зображення вашого аватара\"],\"M/WIer\":[\"Надіслати повідомлення\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"Ім'я:\"],\"MHk+7g\":[\"Попереднє зображення\"],\"MRorGe\":[\"ПП користувачу\"],\"MVbSGP\":[\"Часове вікно (секунди)\"],\"MkpcsT\":[\"Ваші повідомлення та налаштування зберігаються локально на вашому пристрої\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"Позначити як бот - зазвичай 'on' або порожньо\"],\"N7TQbE\":[\"Запросити користувача до \",[\"channelName\"]],\"NCca/o\":[\"Введіть стандартний нік...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"Показує всі зовнішні медіа. Будь-яка URL може спричинити запит до невідомого сервера.\"],\"Nt+9O7\":[\"Використовувати WebSocket замість звичайного TCP\"],\"NxIHzc\":[\"Відключити користувача\"],\"O+v/cL\":[\"Переглянути всі канали на сервері\"],\"ODwSCk\":[\"Надіслати GIF\"],\"OGQ5kK\":[\"Налаштувати звуки сповіщень та виділення\"],\"OIPt1Z\":[\"Показати або приховати бічну панель зі списком учасників\"],\"OKSNq/\":[\"Дуже строгий\"],\"ONWvwQ\":[\"Завантажити\"],\"OVKoQO\":[\"Пароль вашого акаунту для автентифікації\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - Привносимо IRC у майбутнє\"],\"OhCpra\":[\"Встановити тему…\"],\"OkltoQ\":[\"Заблокувати \",[\"username\"],\" за псевдонімом (запобігає повторному входу з тим же ніком)\"],\"P+t/Te\":[\"Немає додаткових даних\"],\"P42Wcc\":[\"Безпечно\"],\"PD38l0\":[\"Попередній перегляд аватара каналу\"],\"PD9mEt\":[\"Введіть повідомлення...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"Відкрити налаштування конфігурації каналу\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"Тема, яка відображатиметься для цього каналу. Усі користувачі можуть бачити тему.\"],\"PZCecv\":[\"Попередній перегляд PDF\"],\"PeLgsC\":[[\"c\",\"plural\",{\"one\":[\"1 раз\"],\"few\":[[\"c\"],\" рази\"],\"many\":[[\"c\"],\" разів\"],\"other\":[[\"c\"],\" рази\"]}]],\"PguS2C\":[\"Додати маску винятку (напр., nick!*@*, *!*@host.com)\"],\"Pil5Ty\":[\"Показано \",[\"displayedChannelsCount\"],\" із \",[\"0\"],\" каналів\"],\"PqhVlJ\":[\"Заблокувати користувача (за маскою хоста)\"],\"Q+chwU\":[\"Ім'я користувача:\"],\"Q6hhn8\":[\"Налаштування\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"Будь ласка, введіть ім'я користувача\"],\"QGqSZ2\":[\"Колір і форматування\"],\"QJQd1J\":[\"Редагувати профіль\"],\"QSzGDE\":[\"Бездіяльний\"],\"QUlny5\":[\"Ласкаво просимо до \",[\"0\"],\"!\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"Читати далі\"],\"QuSkCF\":[\"Фільтрувати канали...\"],\"QwUrDZ\":[\"змінив тему на: \",[\"topic\"]],\"R0UH07\":[\"Зображення \",[\"0\"],\" з \",[\"1\"]],\"R7SsBE\":[\"Вимкнути звук\"],\"R8rf1X\":[\"Натисніть для встановлення теми\"],\"RArB3D\":[\"був кікнутий з \",[\"channelName\"],\" користувачем \",[\"username\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"Відкрийте світ IRC з ObsidianIRC\"],\"RMMaN5\":[\"Модерований (+m)\"],\"RWw9Lg\":[\"Закрити вікно\"],\"RZ2BuZ\":[\"Реєстрація облікового запису \",[\"account\"],\" потребує підтвердження: \",[\"message\"]],\"RySp6q\":[\"Приховати коментарі\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"Псевдонім обов'язковий\"],\"SPVjfj\":[\"За замовчуванням буде 'без причини', якщо залишити порожнім\"],\"SQKPvQ\":[\"Запросити користувача\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"Виберіть готовий профіль захисту від флуду. Ці профілі надають збалансовані налаштування захисту для різних випадків використання.\"],\"Slr+3C\":[\"Мін. користувачів\"],\"Spnlre\":[\"Ви запросили \",[\"target\"],\" приєднатися до \",[\"channel\"]],\"T/ckN5\":[\"Відкрити у переглядачі\"],\"T91vKp\":[\"Відтворити\"],\"TV2Wdu\":[\"Дізнайтесь, як ми обробляємо ваші дані та захищаємо вашу конфіденційність.\"],\"TgFpwD\":[\"Застосовується...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"Без змін\"],\"TtserG\":[\"Введіть справжнє ім'я\"],\"Ttz9J1\":[\"Введіть пароль...\"],\"Tz0i8g\":[\"Налаштування\"],\"U3pytU\":[\"Адмін\"],\"UDb2YD\":[\"React\"],\"UE4KO5\":[\"*канал*\"],\"UGT5vp\":[\"Зберегти налаштування\"],\"UV5hLB\":[\"Заборон не знайдено\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"Статусні повідомлення\"],\"Ue3uny\":[\"За замовчуванням (без профілю)\"],\"UkARhe\":[\"Нормальний - стандартний захист\"],\"Umn7Cj\":[\"Коментарів ще немає. Будьте першим!\"],\"UtUIRh\":[[\"0\"],\" старіших повідомлень\"],\"UwzP+U\":[\"Безпечне з'єднання\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"Власник каналу\"],\"V4qgxE\":[\"Створено до (хв тому)\"],\"V8yTm6\":[\"Очистити пошук\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - Привносимо IRC у майбутнє\"],\"VJScHU\":[\"Причина\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"Вимкнути сповіщення\"],\"VbyRUy\":[\"Коментарі\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"Встановлено:\"],\"VqnIZz\":[\"Переглянути нашу політику конфіденційності та практики роботи з даними\"],\"VrMygG\":[\"Мінімальна довжина: \",[\"0\"]],\"VrnTui\":[\"Ваші займенники, відображені у вашому профілі\"],\"W8E3qn\":[\"Автентифікований акаунт\"],\"WAakm9\":[\"Видалити канал\"],\"WFxTHC\":[\"Додати маску бану (напр., nick!*@*, *!*@host.com)\"],\"WN1g9F\":[\"Хост сервера обов'язковий\"],\"WRYdXW\":[\"Позиція аудіо\"],\"WUOH5B\":[\"Ігнорувати користувача\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"one\":[\"Показати ще 1 елемент\"],\"few\":[\"Показати ще \",[\"1\"],\" елементи\"],\"many\":[\"Показати ще \",[\"1\"],\" елементів\"],\"other\":[\"Показати ще \",[\"1\"],\" елементи\"]}]],\"Weq9zb\":[\"Загальне\"],\"Wfj7Sk\":[\"Вимкнути або увімкнути звуки сповіщень\"],\"Wm7gbG\":[\"GitHub:\"],\"WyeHWY\":[\"*спам*\"],\"WzMCru\":[\"Профіль користувача\"],\"X6S3lt\":[\"Пошук налаштувань, каналів, серверів...\"],\"XEHan5\":[\"Продовжити все одно\"],\"XI1+wb\":[\"Недійсний формат\"],\"XIXeuC\":[\"Повідомлення @\",[\"0\"]],\"XMS+k4\":[\"Почати приватне повідомлення\"],\"XWgxXq\":[\"Альбом\"],\"Xd7+IT\":[\"Відкріпити приватну розмову\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"Дисплей\"],\"Xp2n93\":[\"Показує медіа з надійного файлового хоста вашого сервера. Запити до зовнішніх сервісів не здійснюються.\"],\"XvjC4F\":[\"Збереження...\"],\"Y/qryO\":[\"Користувачів за вашим запитом не знайдено\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"Реєстрація облікового запису \",[\"account\"],\" успішна: \",[\"message\"]],\"YEfzvP\":[\"Захищена тема (+t)\"],\"YQOn6a\":[\"Згорнути список учасників\"],\"YRCoE9\":[\"Оператор каналу\"],\"YURQaF\":[\"Переглянути профіль\"],\"YdBSvr\":[\"Керувати відображенням медіа та зовнішнього вмісту\"],\"Yj6U3V\":[\"Без центрального сервера:\"],\"YjvpGx\":[\"Займенники\"],\"YqH4l4\":[\"Без ключа\"],\"YyUPpV\":[\"Акаунт:\"],\"ZJSWfw\":[\"Повідомлення при від'єднанні від сервера\"],\"ZR1dJ4\":[\"Запрошення\"],\"ZdWg0V\":[\"Відкрити в браузері\"],\"ZhRBbl\":[\"Пошук повідомлень…\"],\"Zmcu3y\":[\"Розширені фільтри\"],\"a2/8e5\":[\"Тему встановлено після (хв тому)\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"Попередня сторінка\"],\"aJTbXX\":[\"Пароль оператора\"],\"aQryQv\":[\"Шаблон вже існує\"],\"aW9pLN\":[\"Максимальна кількість користувачів у каналі. Залиште порожнім для відсутності обмежень.\"],\"ah4fmZ\":[\"Також показує попередній перегляд з YouTube, Vimeo, SoundCloud та подібних відомих сервісів.\"],\"aifXak\":[\"Немає медіа у цьому каналі\"],\"ap2zBz\":[\"Розслаблений\"],\"az8lvo\":[\"Вимк\"],\"azXSNo\":[\"Розгорнути список учасників\"],\"azdliB\":[\"Увійти в акаунт\"],\"b26wlF\":[\"вона/її\"],\"bD/+Ei\":[\"Строгий\"],\"bQ6BJn\":[\"Налаштуйте детальні правила захисту від флуду. Кожне правило вказує, яку активність відстежувати і які дії вживати при перевищенні порогів.\"],\"beV7+y\":[\"Користувач отримає запрошення приєднатися до \",[\"channelName\"],\".\"],\"bk84cH\":[\"Повідомлення про відсутність\"],\"bkHdLj\":[\"Додати IRC-сервер\"],\"bmQLn5\":[\"Додати правило\"],\"bwRvnp\":[\"Дія\"],\"c8+EVZ\":[\"Підтверджений акаунт\"],\"cGYUlD\":[\"Медіа-перегляди не завантажені.\"],\"cLF98o\":[\"Показати коментарі (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"Користувачів немає\"],\"cSgpoS\":[\"Закріпити приватну розмову\"],\"cde3ce\":[\"Повідомлення <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"Скопіювати форматований вивід\"],\"cl/A5J\":[\"Ласкаво просимо до \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"!\"],\"cnGeoo\":[\"Видалити\"],\"coPLXT\":[\"Ми не зберігаємо ваші IRC-комунікації на наших серверах\"],\"crYH/6\":[\"Програвач SoundCloud\"],\"d3sis4\":[\"Додати сервер\"],\"d9aN5k\":[\"Видалити \",[\"username\"],\" з каналу\"],\"dEgA5A\":[\"Скасувати\"],\"dGi1We\":[\"Відкріпити цю приватну розмову\"],\"dJVuyC\":[\"покинув \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"до\"],\"dXqxlh\":[\"<0>⚠️ Загроза безпеці!</0> Це з'єднання може бути вразливим до перехоплення або атак «людина посередині».\"],\"da9Q/R\":[\"Змінено режими каналу\"],\"dhJN3N\":[\"Показати коментарі\"],\"dj2xTE\":[\"Відхилити сповіщення\"],\"dpCzmC\":[\"Налаштування захисту від флуду\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"Бажаєте відкрити це посилання в новій вкладці?\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"Редагувати\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"Завантажте зображення або вкажіть URL з необов'язковою підстановкою \",[\"size\"],\" для динамічного розміру\"],\"edBbee\":[\"Заблокувати \",[\"username\"],\" за маскою хоста (запобігає повторному входу з тієї ж IP/хоста)\"],\"ekfzWq\":[\"Налаштування користувача\"],\"elPDWs\":[\"Налаштуйте свій IRC-клієнт\"],\"eu2osY\":[\"<0>💡 Рекомендація:</0> Продовжуйте лише якщо довіряєте цьому серверу і розумієте ризики. Уникайте передачі конфіденційної інформації або паролів через це з'єднання.\"],\"euEhbr\":[\"Натисніть, щоб приєднатися до \",[\"channel\"]],\"ez3vLd\":[\"Увімкнути багаторядкове введення\"],\"f0J5Ki\":[\"Зв'язок між серверами може використовувати незашифровані з'єднання\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"Попередити користувача\"],\"fDOLLd\":[\"Канали не знайдено.\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"Анонімна аналітика:\"],\"fq1GF9\":[\"Відображати, коли користувачі від'єднуються від сервера\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"Цей сервер підтримує лише один тип з'єднання\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"Список ігнорування\"],\"gNzMrk\":[\"Поточний аватар\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"Введіть нік...\"],\"gz6UQ3\":[\"Розгорнути\"],\"h6razj\":[\"Маска виключення назви каналу\"],\"hG6jnw\":[\"Тема не встановлена\"],\"hG89Ed\":[\"Зображення\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"Порт\"],\"ha+Bz5\":[\"напр., 100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"Кількість\"],\"hzdLuQ\":[\"Говорити можуть лише користувачі з голосом або вище\"],\"i0qMbr\":[\"Головна\"],\"iDNBZe\":[\"Сповіщення\"],\"iH8pgl\":[\"Назад\"],\"iL9SZg\":[\"Заблокувати користувача (за псевдонімом)\"],\"iNt+3c\":[\"Назад до зображення\"],\"iQvi+a\":[\"Не попереджати мене про низький рівень безпеки для цього сервера\"],\"iSLIjg\":[\"Підключитися\"],\"iWXkHH\":[\"Напів-оператор\"],\"iZeTtp\":[\"Хост сервера\"],\"idD8Ev\":[\"Збережено\"],\"iivqkW\":[\"Час входу\"],\"ij+Elv\":[\"Попередній перегляд зображення\"],\"ilIWp7\":[\"Перемкнути сповіщення\"],\"iuaqvB\":[\"Використовуйте * для шаблонів. Приклади: baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"Бот\"],\"j2DGR0\":[\"Блокування за маскою хоста\"],\"jA4uoI\":[\"Тема:\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"Причина (необов'язково)\"],\"jUV7CU\":[\"Завантажити аватар\"],\"jW5Uwh\":[\"Контролюйте завантаження зовнішніх медіа. Вимк / Безпечно / Надійні джерела / Весь вміст.\"],\"jXzms5\":[\"Параметри вкладення\"],\"jZlrte\":[\"Колір\"],\"jfC/xh\":[\"Контакт\"],\"jywMpv\":[\"#нова-назва-каналу\"],\"k112DD\":[\"Завантажити старіші повідомлення\"],\"k3ID0F\":[\"Фільтрувати учасників…\"],\"k65gsE\":[\"Детальний огляд\"],\"k7Zgob\":[\"Скасувати підключення\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"Запрошень не знайдено\"],\"kCLEPU\":[\"Підключено до\"],\"kF5LKb\":[\"Ігноровані шаблони:\"],\"kGeOx/\":[\"Приєднатися до \",[\"0\"]],\"kITKr8\":[\"Завантаження режимів каналу...\"],\"kPpPsw\":[\"Ви є IRC-оператором\"],\"kWJmRL\":[\"Ви\"],\"kfcRb0\":[\"Аватар\"],\"kjMqSj\":[\"Скопіювати JSON\"],\"krViRy\":[\"Натисніть для копіювання як JSON\"],\"ks71ra\":[\"Винятки\"],\"kw4lRv\":[\"Напів-оператор каналу\"],\"kxgIRq\":[\"Виберіть або додайте канал для початку.\"],\"ky6dWe\":[\"Попередній перегляд аватара\"],\"l+GxCv\":[\"Завантаження каналів...\"],\"l+IUVW\":[\"Верифікація облікового запису \",[\"account\"],\" успішна: \",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"one\":[\"перепідключився\"],\"few\":[\"перепідключився \",[\"reconnectCount\"],\" рази\"],\"many\":[\"перепідключився \",[\"reconnectCount\"],\" разів\"],\"other\":[\"перепідключився \",[\"reconnectCount\"],\" рази\"]}]],\"l5jmzx\":[[\"0\"],\" та \",[\"1\"],\" пишуть...\"],\"lHy8N5\":[\"Завантаження більше каналів...\"],\"lbpf14\":[\"Приєднатися до \",[\"value\"]],\"lfFsZ4\":[\"Канали\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"Ім'я акаунту\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"Завантажити зображення\"],\"loQxaJ\":[\"Я повернувся\"],\"lvfaxv\":[\"ГОЛОВНА\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"Додати\"],\"m8flAk\":[\"Попередній перегляд (ще не завантажено)\"],\"mEPxTp\":[\"<0>⚠️ Будьте обережні!</0> Відкривайте лише посилання з надійних джерел. Шкідливі посилання можуть порушити вашу безпеку або конфіденційність.\"],\"mHGdhG\":[\"Інформація про сервер\"],\"mHS8lb\":[\"Повідомлення #\",[\"0\"]],\"mMYBD9\":[\"Широкий - ширша область захисту\"],\"mTGsPd\":[\"Тема каналу\"],\"mU8j6O\":[\"Без зовнішніх повідомлень (+n)\"],\"mZp8FL\":[\"Автоматичне повернення до одного рядка\"],\"mdQu8G\":[\"ВашПсевдонім\"],\"miSSBQ\":[\"Коментарі (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"Користувач автентифікований\"],\"mwtcGl\":[\"Закрити коментарі\"],\"mzI/c+\":[\"Завантажити\"],\"n3fGRk\":[\"встановлено \",[\"0\"]],\"nE9jsU\":[\"Розслаблений - менш агресивний захист\"],\"nNflMD\":[\"Покинути канал\"],\"nPXkBi\":[\"Завантаження даних WHOIS...\"],\"nQnxxF\":[\"Повідомлення #\",[\"0\"],\" (Shift+Enter для нового рядка)\"],\"nWMRxa\":[\"Відкріпити\"],\"nkC032\":[\"Без профілю флуду\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"Надіслати попереджувальне повідомлення \",[\"username\"]],\"o9ylQi\":[\"Знайдіть GIF для початку\"],\"oFGkER\":[\"Повідомлення сервера\"],\"oOi11l\":[\"Прокрутити вниз\"],\"oQEzQR\":[\"Нове DM\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"Онлайн\"],\"oal760\":[\"Можливі атаки «людина посередині» на з'єднання сервера\"],\"oeqmmJ\":[\"Надійні джерела\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"За замовчуванням\"],\"p0Z69r\":[\"Шаблон не може бути порожнім\"],\"p1KgtK\":[\"Не вдалося завантажити аудіо\"],\"p59pEv\":[\"Детальніше\"],\"p7sRI6\":[\"Повідомляти інших, коли ви друкуєте\"],\"pBm1od\":[\"Секретний канал\"],\"pNmiXx\":[\"Ваш псевдонім за замовчуванням для всіх серверів\"],\"pUUo9G\":[\"Хост:\"],\"pVGPmz\":[\"Пароль акаунту\"],\"peNE68\":[\"Постійний\"],\"plhHQt\":[\"Немає даних\"],\"pm6+q5\":[\"Попередження про безпеку\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"Додаткова інформація\"],\"q0cR4S\":[\"тепер відомий як **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"Канал не відображатиметься у командах LIST або NAMES\"],\"qLpTm/\":[\"Видалити реакцію \",[\"emoji\"]],\"qVkGWK\":[\"Закріпити\"],\"qY8wNa\":[\"Головна сторінка\"],\"qb0xJ7\":[\"Використовуйте шаблони: * відповідає будь-якій послідовності, ? відповідає будь-якому одному символу. Приклади: nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"Ключ каналу (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"Без обмежень\"],\"r1W2AS\":[\"Зображення з файлового хостингу\"],\"rIPR2O\":[\"Тему встановлено до (хв тому)\"],\"rMMSYo\":[\"Максимальна довжина: \",[\"0\"]],\"rWtzQe\":[\"Мережа розділилась і возз'єдналась. ✅\"],\"rYG2u6\":[\"Будь ласка, зачекайте...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"Попередній перегляд\"],\"rjGI/Q\":[\"Конфіденційність\"],\"rk8iDX\":[\"Завантаження GIF...\"],\"rn6SBY\":[\"Увімкнути звук\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"Виключено з каналу\"],\"s8cATI\":[\"приєднався до \",[\"channelName\"]],\"sCO9ue\":[\"З'єднання з <0>\",[\"serverName\"],\"</0> має такі проблеми безпеки:\"],\"sGH11W\":[\"Сервер\"],\"sHI1H+\":[\"тепер відомий як **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" запросив вас приєднатися до \",[\"channel\"]],\"sby+1/\":[\"Натисніть для копіювання\"],\"sfN25C\":[\"Ваше справжнє або повне ім'я\"],\"sliuzR\":[\"Відкрити посилання\"],\"sqrO9R\":[\"Власні згадки\"],\"sr6RdJ\":[\"Багаторядковий на Shift+Enter\"],\"swrCpB\":[\"Канал перейменовано з \",[\"oldName\"],\" на \",[\"newName\"],\" користувачем \",[\"user\"],[\"0\"]],\"sxkWRg\":[\"Додаткові\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"Видалити\"],\"t47eHD\":[\"Ваш унікальний ідентифікатор на цьому сервері\"],\"tAkAh0\":[\"URL з необов'язковою підстановкою \",[\"size\"],\" для динамічного розміру. Приклад: https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"Показати або приховати бічну панель зі списком каналів\"],\"tfDRzk\":[\"Зберегти\"],\"tiBsJk\":[\"покинув \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"вийшов (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"Псевдонім {nick} вже використовується, повторна спроба з {newNick}\"],\"u0a8B4\":[\"Автентифікуватися як IRC-оператор для адміністративного доступу\"],\"u0rWFU\":[\"Створено після (хв тому)\"],\"u72w3t\":[\"Користувачі та шаблони для ігнорування\"],\"u7jc2L\":[\"вийшов\"],\"uAQUqI\":[\"Статус\"],\"uB85T3\":[\"Помилка збереження: \",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC сервери:\"],\"usSSr/\":[\"Рівень масштабу\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"Програма:\"],\"vE8kb+\":[\"Використовуйте Shift+Enter для нового рядка (Enter надсилає)\"],\"vERlcd\":[\"Профіль\"],\"vK0RL8\":[\"Без теми\"],\"vSJd18\":[\"Відео\"],\"vXIe7J\":[\"Мова\"],\"vaHYxN\":[\"Справжнє ім'я\"],\"vhjbKr\":[\"Відсутній\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[\"клієнт \",[\"title\"]],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"Недійсне значення\"],\"wFjjxZ\":[\"був кікнутий з \",[\"channelName\"],\" користувачем \",[\"username\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"Винятків з заборон не знайдено\"],\"wPrGnM\":[\"Адміністратор каналу\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"Відображати, коли користувачі входять або виходять з каналів\"],\"whqZ9r\":[\"Додаткові слова або фрази для виділення\"],\"wm7RV4\":[\"Звук сповіщення\"],\"wz/Yoq\":[\"Ваші повідомлення можуть бути перехоплені при передачі між серверами\"],\"xCJdfg\":[\"Очистити\"],\"xUHRTR\":[\"Автоматично автентифікуватися як оператор при підключенні\"],\"xWHwwQ\":[\"Блокування\"],\"xYilR2\":[\"Медіа\"],\"xceQrO\":[\"Підтримуються лише захищені websocket-з'єднання\"],\"xdtXa+\":[\"назва-каналу\"],\"xfXC7q\":[\"Текстові канали\"],\"xlCYOE\":[\"Отримання більше повідомлень...\"],\"xlhswE\":[\"Мінімальне значення: \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"Додати слово або фразу...\"],\"xuRqRq\":[\"Ліміт клієнтів (+l)\"],\"xwF+7J\":[[\"0\"],\" пише...\"],\"yNeucF\":[\"Цей сервер не підтримує розширені метадані профілю (розширення IRCv3 METADATA). Додаткові поля, такі як аватар, відображуване ім'я та статус, недоступні.\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"Аватар каналу\"],\"yQE2r9\":[\"Завантаження\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Ім'я оператора\"],\"yYOzWD\":[\"логи\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"Пароль IRC оператора\"],\"ygCKqB\":[\"Зупинити\"],\"ymDxJx\":[\"Ім'я IRC оператора\"],\"yrpRsQ\":[\"Сортувати за назвою\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"Закрити\"],\"zJw+jA\":[\"встановлює режим: \",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"Введіть ім'я оператора\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
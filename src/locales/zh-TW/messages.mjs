@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"無效的模式格式，請使用 nick!user@host 格式（允許萬用字元 *）\"],\"+6NQQA\":[\"通用支持频道\"],\"+6NyRG\":[\"客戶端\"],\"+K0AvT\":[\"断开连接\"],\"+cyFdH\":[\"標記為離開時的預設訊息\"],\"+mVPqU\":[\"在訊息中渲染 Markdown 格式\"],\"+vqCJH\":[\"您的帳戶認證使用者名稱\"],\"+yPBXI\":[\"選擇檔案\"],\"+zy2Nq\":[\"類型\"],\"/09cao\":[\"链路安全级别较低（级别 \",[\"securityLevel\"],\"）\"],\"/3BQ4J\":[\"頻道外的使用者無法傳送訊息\"],\"/6BzZF\":[\"切换成员列表\"],\"/TNOPk\":[\"使用者已離開\"],\"/XQgft\":[\"探索\"],\"/cF7Rs\":[\"音量\"],\"/dqduX\":[\"下一页\"],\"/fc3q4\":[\"所有内容\"],\"/kISDh\":[\"啟用通知聲音\"],\"/n04sB\":[\"踢出\"],\"/rTz0M\":[\"音訊\"],\"/rfkZe\":[\"提及和訊息時播放聲音\"],\"0/0ZGA\":[\"頻道名稱遮罩\"],\"0D6j7U\":[\"了解更多自訂規則 →\"],\"0XsHcR\":[\"踢出用户\"],\"0ZpE//\":[\"依使用者數排序\"],\"0bEPwz\":[\"设置为离开\"],\"0dGkPt\":[\"展开频道列表\"],\"0gS7M5\":[\"显示名称\"],\"0kS+M8\":[\"範例網路\"],\"0rgoY7\":[\"僅連線至您選擇的伺服器\"],\"0wdd7X\":[\"加入\"],\"0wkVYx\":[\"私人訊息\"],\"111uHX\":[\"链接预览\"],\"196EG4\":[\"删除私聊\"],\"1DSr1i\":[\"注册账户\"],\"1O/24y\":[\"切换频道列表\"],\"1VPJJ2\":[\"外部链接警告\"],\"1ZC/dv\":[\"沒有未讀提及或訊息\"],\"1pO1zi\":[\"服务器名称为必填项\"],\"1uwfzQ\":[\"查看频道主题\"],\"268g7c\":[\"输入显示名称\"],\"2FOFq1\":[\"网络上的服务器管理员可能读取您的消息\"],\"2FYpfJ\":[\"更多\"],\"2HF1Y2\":[[\"inviter\"],\" 邀請了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"2I70QL\":[\"查看用户资料信息\"],\"2QYdmE\":[\"用戶：\"],\"2QpEjG\":[\"已離開\"],\"2YE223\":[\"发消息到 #\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"2bimFY\":[\"使用服务器密码\"],\"2iTmdZ\":[\"本機儲存：\"],\"2odkwe\":[\"嚴格 – 更強的保護\"],\"2uDhbA\":[\"输入要邀请的用户名\"],\"2ygf/L\":[\"← 返回\"],\"2zEgxj\":[\"搜索 GIF...\"],\"3RdPhl\":[\"重命名频道\"],\"3THokf\":[\"有發言權的使用者\"],\"3TSz9S\":[\"最小化\"],\"3jBDvM\":[\"頻道顯示名稱\"],\"3ryuFU\":[\"可選的當機報告以改善應用程式\"],\"3uBF/8\":[\"关闭查看器\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"輸入帳戶名稱...\"],\"4/Rr0R\":[\"邀请用户加入当前频道\"],\"4EZrJN\":[\"規則\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"洪水設定檔 (+F)\"],\"4RZQRK\":[\"你在做什麼?\"],\"4hfTrB\":[\"昵称\"],\"4n99LO\":[\"已在 \",[\"0\"]],\"4t6vMV\":[\"短訊息自動切換至單行\"],\"4vsHmf\":[\"時間（分鐘）\"],\"5+INAX\":[\"醒目提示提及您的訊息\"],\"5R5Pv/\":[\"Oper 用户名\"],\"678PKt\":[\"网络名称\"],\"6Aih4U\":[\"离线\"],\"6CO3WE\":[\"加入頻道需要密碼，留空可移除金鑰。\"],\"6HhMs3\":[\"退出消息\"],\"6V3Ea3\":[\"已复制\"],\"6lGV3K\":[\"收起\"],\"6yFOEi\":[\"輸入 oper 密碼...\"],\"7+IHTZ\":[\"未選擇檔案\"],\"73hrRi\":[\"nick!user@host（例如：spam*!*@*, *!*@badhost.com）\"],\"7QkKyN\":[\"发送私信\"],\"7U1W7c\":[\"非常宽松\"],\"7Y1YQj\":[\"真實姓名：\"],\"7YHArF\":[\"— 在檢視器中開啟\"],\"7fjnVl\":[\"搜索用户...\"],\"7jL88x\":[\"刪除此訊息？此操作無法復原。\"],\"7nGhhM\":[\"您在想什么？\"],\"7sEpu1\":[\"成员 — \",[\"0\"]],\"7sNhEz\":[\"用户名\"],\"8H0Q+x\":[\"了解更多設定檔 →\"],\"8Phu0A\":[\"顯示使用者更改暱稱的事件\"],\"8XTG9e\":[\"输入 oper 密码\"],\"8XsV2J\":[\"重新傳送\"],\"8ZsakT\":[\"密码\"],\"8kR84m\":[\"您即将打开一个外部链接：\"],\"8lCgih\":[\"移除規則\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"other\":[\"已加入 \",[\"joinCount\"],\" 次\"]}]],\"9BMLnJ\":[\"重新连接服务器\"],\"9OEgyT\":[\"添加表情\"],\"9PQ8m2\":[\"G-Line（全域封禁）\"],\"9Qs99X\":[\"電子郵件：\"],\"9QupBP\":[\"删除规则\"],\"9bG48P\":[\"傳送中\"],\"9f5f0u\":[\"有隱私問題？請聯絡我們：\"],\"9unqs3\":[\"離開：\"],\"9v3hwv\":[\"未找到服务器。\"],\"9zb2WA\":[\"连接中\"],\"A1taO8\":[\"搜索\"],\"A2adVi\":[\"傳送正在輸入通知\"],\"A9Rhec\":[\"頻道名稱\"],\"AWOSPo\":[\"放大\"],\"AXSpEQ\":[\"連線時獲取 Oper\"],\"AeXO77\":[\"账户\"],\"AhNP40\":[\"跳转\"],\"Ai2U7L\":[\"主機\"],\"AjBQnf\":[\"已更改昵称\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"取消回复\"],\"ApSx0O\":[\"找到 \",[\"0\"],\" 則符合「\",[\"searchQuery\"],\"」的訊息\"],\"AxPAXW\":[\"未找到結果\"],\"AyNqAB\":[\"在聊天中顯示所有伺服器事件\"],\"B/QqGw\":[\"暂时离开\"],\"B8AaMI\":[\"此欄位為必填\"],\"BA2c49\":[\"伺服器不支援進階 LIST 篩選\"],\"BDKt3I\":[[\"0\"],\"、\",[\"1\"],\"、\",[\"2\"],\" 以及另外 \",[\"3\"],\" 人正在输入...\"],\"BGul2A\":[\"您有未保存的更改。确定要关闭而不保存吗？\"],\"BIf9fi\":[\"您的狀態訊息\"],\"BZz3md\":[\"您的個人網站\"],\"Bgm/H7\":[\"允許輸入多行文字\"],\"BiQIl1\":[\"置顶此私信对话\"],\"BlNZZ2\":[\"点击跳转到消息\"],\"Bowq3c\":[\"只有管理員可以更改頻道主題\"],\"Btozzp\":[\"此图片已过期\"],\"Bycfjm\":[\"總計：\",[\"0\"]],\"C6IBQc\":[\"複製完整 JSON\"],\"C9L9wL\":[\"資料收集\"],\"CDq4wC\":[\"管理用户\"],\"CHVRxG\":[\"发消息给 @\",[\"0\"],\"（Shift+Enter 换行）\"],\"CN9zdR\":[\"Oper 用户名和密码为必填项\"],\"CW3sYa\":[\"添加表情 \",[\"emoji\"]],\"CaAkqd\":[\"顯示退出事件\"],\"CbvaYj\":[\"依暱稱封禁\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"选择频道\"],\"CsekCi\":[\"普通\"],\"D+NlUC\":[\"系统\"],\"D28t6+\":[\"已加入並退出\"],\"DB8zMK\":[\"套用\"],\"DBcWHr\":[\"自訂通知音效檔\"],\"DTy9Xw\":[\"媒體預覽\"],\"Dj4pSr\":[\"选择一个安全密码\"],\"Du+zn+\":[\"搜尋中...\"],\"Du2T2f\":[\"未找到設定\"],\"DwsSVQ\":[\"套用篩選並重新整理\"],\"E3W/zd\":[\"預設暱稱\"],\"E6nRW7\":[\"复制链接\"],\"E703RG\":[\"模式：\"],\"EAeu1Z\":[\"傳送邀請\"],\"EFKJQT\":[\"設定\"],\"EGPQBv\":[\"自訂洪水規則 (+f)\"],\"ELik0r\":[\"查看完整隱私權政策\"],\"EPbeC2\":[\"查看或编辑频道主题\"],\"EQCDNT\":[\"輸入 oper 用戶名...\"],\"EUvulZ\":[\"找到 1 則符合「\",[\"searchQuery\"],\"」的訊息\"],\"EatZYJ\":[\"下一张图片\"],\"EdQY6l\":[\"無\"],\"EnqLYU\":[\"搜索服务器...\"],\"F0OKMc\":[\"编辑服务器\"],\"F6Int2\":[\"啟用醒目提示\"],\"FDoLyE\":[\"最多使用者數\"],\"FUU/hZ\":[\"控制聊天中載入的外部媒體數量。\"],\"Fdp03t\":[\"開啟\"],\"FfPWR0\":[\"弹窗\"],\"FjkaiT\":[\"缩小\"],\"FlqOE9\":[\"这意味着：\"],\"FolHNl\":[\"管理您的账户和身份验证\"],\"Fp2Dif\":[\"已退出服务器\"],\"G5KmCc\":[\"GZ-Line（全域 Z-Line）\"],\"GDs0lz\":[\"<0>风险：</0> 敏感信息（消息、私人对话、身份验证详情）可能会暴露给网络管理员或位于 IRC 服务器之间的攻击者。\"],\"GR+2I3\":[\"新增邀請遮罩（例如 nick!*@*, *!*@host.com）\"],\"GRLyMU\":[\"关闭弹出的服务器通知\"],\"GlHnXw\":[\"暱稱修改失敗: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"預覽：\"],\"GtmO8/\":[\"来自\"],\"GtuHUQ\":[\"在伺服器上重新命名此頻道，所有使用者都會看到新名稱。\"],\"GuGfFX\":[\"切换搜索\"],\"GxkJXS\":[\"上傳中...\"],\"GzbwnK\":[\"已加入频道\"],\"GzsUDB\":[\"擴充個人資料\"],\"H/PnT8\":[\"插入表情\"],\"H6Izzl\":[\"您的首選顏色代碼\"],\"H9jIv+\":[\"顯示加入/離開\"],\"HAKBY9\":[\"上傳檔案\"],\"HdE1If\":[\"頻道\"],\"Hk4AW9\":[\"您的首選顯示名稱\"],\"HmHDk7\":[\"选择成员\"],\"HrQzPU\":[[\"networkName\"],\" 上的頻道\"],\"I2tXQ5\":[\"发消息给 @\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"I6bw/h\":[\"封禁使用者\"],\"I92Z+b\":[\"启用通知\"],\"I9D72S\":[\"您確定要刪除此訊息嗎？此操作無法復原。\"],\"IA+1wo\":[\"顯示使用者被踢出頻道的事件\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"資訊：\"],\"IUwGEM\":[\"保存更改\"],\"IVeGK6\":[[\"0\"],\"、\",[\"1\"],\" 和 \",[\"2\"],\" 正在输入...\"],\"IgrLD/\":[\"暫停\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"回复\"],\"IoHMnl\":[\"最大值為 \",[\"0\"]],\"IvMj+0\":[\"管理员\"],\"J28zul\":[\"正在連線...\"],\"J5T9NW\":[\"使用者資訊\"],\"J8Y5+z\":[\"哎呀！網路分裂！⚠️\"],\"JBHkBA\":[\"已离开频道\"],\"JCwL0Q\":[\"输入原因（可选）\"],\"JFciKP\":[\"切换\"],\"JXGkhG\":[\"更改频道名称（仅限管理员）\"],\"JcD7qf\":[\"更多操作\"],\"JdkA+c\":[\"隱密 (+s)\"],\"Jmu12l\":[\"服务器频道\"],\"JvQ++s\":[\"啟用 Markdown\"],\"K2jwh/\":[\"無可用 WHOIS 資料\"],\"KAXSwC\":[\"语音权限\"],\"KDfTdX\":[\"删除消息\"],\"KKBlUU\":[\"嵌入\"],\"KM0pLb\":[\"欢迎来到本频道！\"],\"KR6W2h\":[\"取消忽略使用者\"],\"KV+Bi1\":[\"僅限邀請 (+i)\"],\"KdCtwE\":[\"在重置計數器之前監控洪水活動的秒數\"],\"Kkezga\":[\"服务器密码\"],\"KsiQ/8\":[\"使用者必須受邀才能加入頻道\"],\"L+gB/D\":[\"頻道資訊\"],\"LC1a7n\":[\"IRC 服务器报告其服务器间链路的安全级别较低。这意味着当您的消息在网络中的 IRC 服务器之间转发时，可能未经过妥善加密，或者 SSL/TLS 证书未被正确验证。\"],\"LNfLR5\":[\"顯示踢出事件\"],\"LQb0W/\":[\"顯示所有事件\"],\"LU7/yA\":[\"顯示用的別名，可包含空格、表情符號和特殊字元。真實頻道名（\",[\"channelName\"],\"）仍用於 IRC 指令。\"],\"LUb9O7\":[\"需要有效的服务器端口\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"隱私權政策\"],\"LcuSDR\":[\"管理您的个人资料信息和元数据\"],\"LqLS9B\":[\"顯示暱稱變更\"],\"LsDQt2\":[\"频道设置\"],\"LtI9AS\":[\"频道所有者\"],\"LuNhhL\":[\"对此消息做出了回应\"],\"M/AZNG\":[\"頭像圖片 URL\"],\"M/WIer\":[\"傳送訊息\"],\"M8er/5\":[\"名稱：\"],\"MHk+7g\":[\"上一张图片\"],\"MRorGe\":[\"私信用户\"],\"MVbSGP\":[\"時間視窗（秒）\"],\"MkpcsT\":[\"您的訊息和設定儲存在本機裝置上\"],\"N/hDSy\":[\"標記為機器人——通常為 'on' 或空白\"],\"N7TQbE\":[\"邀請使用者加入 \",[\"channelName\"]],\"NCca/o\":[\"輸入預設暱稱...\"],\"Nqs6B9\":[\"显示所有外部媒体。任何 URL 都可能向未知服务器发送请求。\"],\"Nt+9O7\":[\"使用 WebSocket 而非原始 TCP\"],\"NxIHzc\":[\"踢出用戶\"],\"O+v/cL\":[\"浏览服务器上的所有频道\"],\"ODwSCk\":[\"发送 GIF\"],\"OGQ5kK\":[\"配置通知声音和高亮提示\"],\"OIPt1Z\":[\"显示或隐藏成员列表侧栏\"],\"OKSNq/\":[\"非常严格\"],\"ONWvwQ\":[\"上傳\"],\"OVKoQO\":[\"您的帳戶認證密碼\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - 將 IRC 帶入未來\"],\"OhCpra\":[\"设置主题…\"],\"OkltoQ\":[\"通过昵称封禁 \",[\"username\"],\"（阻止其使用相同昵称重新加入）\"],\"P+t/Te\":[\"無其他資料\"],\"P42Wcc\":[\"安全\"],\"PD38l0\":[\"频道头像预览\"],\"PD9mEt\":[\"输入消息...\"],\"PPqfdA\":[\"打开频道配置设置\"],\"PSCjfZ\":[\"此頻道顯示的主題，所有使用者均可查看。\"],\"PZCecv\":[\"PDF 預覽\"],\"PeLgsC\":[[\"c\",\"plural\",{\"other\":[[\"c\"],\" 次\"]}]],\"PguS2C\":[\"新增例外遮罩（例如 nick!*@*, *!*@host.com）\"],\"Pil5Ty\":[\"顯示 \",[\"displayedChannelsCount\"],\"，共 \",[\"0\"],\" 個頻道\"],\"PqhVlJ\":[\"封禁用户（按 Hostmask）\"],\"Q+chwU\":[\"用戶名：\"],\"Q6hhn8\":[\"偏好设置\"],\"QF4a34\":[\"請輸入使用者名稱\"],\"QGqSZ2\":[\"颜色与格式\"],\"QJQd1J\":[\"編輯資料\"],\"QSzGDE\":[\"閒置\"],\"QUlny5\":[\"欢迎来到 \",[\"0\"],\"！\"],\"Qoq+GP\":[\"阅读更多\"],\"QuSkCF\":[\"筛选频道...\"],\"QwUrDZ\":[\"將主題更改為：\",[\"topic\"]],\"R0UH07\":[\"第 \",[\"0\"],\" 張，共 \",[\"1\"],\" 張\"],\"R7SsBE\":[\"靜音\"],\"R8rf1X\":[\"点击设置主题\"],\"RArB3D\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"]],\"RI3cWd\":[\"使用 ObsidianIRC 探索 IRC 的世界\"],\"RMMaN5\":[\"已審核 (+m)\"],\"RWw9Lg\":[\"關閉視窗\"],\"RZ2BuZ\":[\"帳戶 \",[\"account\"],\" 註冊需要驗證：\",[\"message\"]],\"RySp6q\":[\"隱藏評論\"],\"SPKQTd\":[\"昵称为必填项\"],\"SPVjfj\":[\"留空将默认显示\\\"无原因\\\"\"],\"SQKPvQ\":[\"邀请用户\"],\"SkZcl+\":[\"選擇預定義的洪水保護設定檔。這些設定檔為不同使用場景提供均衡的保護設定。\"],\"Slr+3C\":[\"最少使用者數\"],\"Spnlre\":[\"您邀請了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"T/ckN5\":[\"在查看器中打开\"],\"T91vKp\":[\"播放\"],\"TV2Wdu\":[\"了解我們如何處理您的資料並保護您的隱私。\"],\"TgFpwD\":[\"正在套用...\"],\"TkzSFB\":[\"无更改\"],\"TtserG\":[\"输入真实姓名\"],\"Ttz9J1\":[\"輸入密碼...\"],\"Tz0i8g\":[\"設定\"],\"U3pytU\":[\"管理员\"],\"UDb2YD\":[\"添加表情\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"儲存設定\"],\"UV5hLB\":[\"未找到封禁\"],\"Uaj3Nd\":[\"状态消息\"],\"Ue3uny\":[\"預設（無設定檔）\"],\"UkARhe\":[\"普通 – 標準保護\"],\"Umn7Cj\":[\"尚無評論，成為第一個吧！\"],\"UtUIRh\":[[\"0\"],\" 則較早的訊息\"],\"UwzP+U\":[\"安全連線\"],\"V0/A4O\":[\"頻道擁有者\"],\"V4qgxE\":[\"建立時間早於（分鐘前）\"],\"V8yTm6\":[\"清除搜索\"],\"VJMMyz\":[\"ObsidianIRC - 将 IRC 带入未来\"],\"VJScHU\":[\"原因\"],\"VLsmVV\":[\"静音通知\"],\"VbyRUy\":[\"評論\"],\"Vmx0mQ\":[\"設定者：\"],\"VqnIZz\":[\"查看我们的隐私政策和数据使用规范\"],\"VrMygG\":[\"最小長度為 \",[\"0\"]],\"VrnTui\":[\"您的代名詞，顯示在個人資料中\"],\"W8E3qn\":[\"已驗證帳戶\"],\"WAakm9\":[\"删除频道\"],\"WFxTHC\":[\"新增封禁遮罩（例如 nick!*@*, *!*@host.com）\"],\"WN1g9F\":[\"服务器地址为必填项\"],\"WRYdXW\":[\"音频进度\"],\"WUOH5B\":[\"忽略使用者\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"other\":[\"顯示另外 \",[\"1\"],\" 個項目\"]}]],\"Weq9zb\":[\"一般\"],\"Wfj7Sk\":[\"开启或关闭通知声音\"],\"Wm7gbG\":[\"GitHub：\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"用户资料\"],\"X6S3lt\":[\"搜索设置、频道、服务器...\"],\"XEHan5\":[\"仍然继续\"],\"XI1+wb\":[\"格式無效\"],\"XIXeuC\":[\"发消息给 @\",[\"0\"]],\"XMS+k4\":[\"发起私信\"],\"XWgxXq\":[\"相簿\"],\"Xd7+IT\":[\"取消置顶私聊\"],\"Xm/s+u\":[\"顯示\"],\"Xp2n93\":[\"显示来自服务器受信任文件主机的媒体。不会向外部服务发送任何请求。\"],\"XvjC4F\":[\"正在儲存...\"],\"Y/qryO\":[\"未找到与搜索条件匹配的用户\"],\"YAqRpI\":[\"帳戶 \",[\"account\"],\" 註冊成功：\",[\"message\"]],\"YEfzvP\":[\"受保護主題 (+t)\"],\"YQOn6a\":[\"收起成员列表\"],\"YRCoE9\":[\"頻道操作員\"],\"YURQaF\":[\"查看個人資料\"],\"YdBSvr\":[\"控制媒体显示和外部内容\"],\"Yj6U3V\":[\"無中央伺服器：\"],\"YjvpGx\":[\"代词\"],\"YqH4l4\":[\"无密钥\"],\"YyUPpV\":[\"帳戶：\"],\"ZJSWfw\":[\"中斷伺服器連線時顯示的訊息\"],\"ZR1dJ4\":[\"邀請\"],\"ZdWg0V\":[\"在浏览器中打开\"],\"ZhRBbl\":[\"搜索消息…\"],\"Zmcu3y\":[\"進階篩選\"],\"a2/8e5\":[\"主題設定時間晚於（分鐘前）\"],\"aHKcKc\":[\"上一页\"],\"aJTbXX\":[\"Oper 密码\"],\"aQryQv\":[\"模式已存在\"],\"aW9pLN\":[\"頻道允許的最大使用者數，留空表示無限制。\"],\"ah4fmZ\":[\"同时显示来自 YouTube、Vimeo、SoundCloud 及类似知名服务的预览。\"],\"aifXak\":[\"此頻道沒有媒體\"],\"ap2zBz\":[\"宽松\"],\"az8lvo\":[\"关\"],\"azXSNo\":[\"展开成员列表\"],\"azdliB\":[\"登录账户\"],\"b26wlF\":[\"她/她的\"],\"bD/+Ei\":[\"严格\"],\"bQ6BJn\":[\"設定詳細的洪水保護規則。每條規則指定要監控的活動類型以及超過閾值時採取的操作。\"],\"beV7+y\":[\"使用者將收到加入 \",[\"channelName\"],\" 的邀請。\"],\"bk84cH\":[\"离开消息\"],\"bkHdLj\":[\"添加 IRC 服务器\"],\"bmQLn5\":[\"新增規則\"],\"bwRvnp\":[\"操作\"],\"c8+EVZ\":[\"已验证账户\"],\"cGYUlD\":[\"未加载任何媒体预览。\"],\"cLF98o\":[\"顯示評論 (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"没有可用用户\"],\"cSgpoS\":[\"置顶私聊\"],\"cde3ce\":[\"发消息给 <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"複製格式化輸出\"],\"cl/A5J\":[\"欢迎来到 \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"！\"],\"cnGeoo\":[\"删除\"],\"coPLXT\":[\"我們不在伺服器上儲存您的 IRC 通訊\"],\"crYH/6\":[\"SoundCloud 播放器\"],\"d3sis4\":[\"添加服务器\"],\"d9aN5k\":[\"将 \",[\"username\"],\" 移出频道\"],\"dEgA5A\":[\"取消\"],\"dGi1We\":[\"取消置顶此私信对话\"],\"dJVuyC\":[\"離開了 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"至\"],\"dXqxlh\":[\"<0>⚠️ 安全风险！</0> 此连接可能容易遭受窃听或中间人攻击。\"],\"da9Q/R\":[\"已更改频道模式\"],\"dhJN3N\":[\"顯示評論\"],\"dj2xTE\":[\"关闭通知\"],\"dpCzmC\":[\"洪水保護設定\"],\"e9dQpT\":[\"是否在新标签页中打开此链接？\"],\"ePK91l\":[\"编辑\"],\"eYBDuB\":[\"上傳圖片或提供含可選 \",[\"size\"],\" 替換的 URL\"],\"edBbee\":[\"通过 hostmask 封禁 \",[\"username\"],\"（阻止其从相同 IP/主机重新加入）\"],\"ekfzWq\":[\"使用者設定\"],\"elPDWs\":[\"自定义您的 IRC 客户端体验\"],\"eu2osY\":[\"<0>💡 建议：</0> 仅在您信任此服务器并了解相关风险的情况下继续操作。避免通过此连接共享敏感信息或密码。\"],\"euEhbr\":[\"點擊加入 \",[\"channel\"]],\"ez3vLd\":[\"啟用多行輸入\"],\"f0J5Ki\":[\"服务器之间的通信可能使用未加密的连接\"],\"f9BHJk\":[\"警告用户\"],\"fDOLLd\":[\"未找到頻道。\"],\"ffzDkB\":[\"匿名分析：\"],\"fq1GF9\":[\"顯示使用者中斷伺服器連線的事件\"],\"gEF57C\":[\"此伺服器僅支援一種連線類型\"],\"gJuLUI\":[\"忽略清單\"],\"gNzMrk\":[\"目前頭像\"],\"gjPWyO\":[\"輸入暱稱...\"],\"gz6UQ3\":[\"最大化\"],\"h6razj\":[\"排除頻道名稱遮罩\"],\"hG6jnw\":[\"未设置主题\"],\"hG89Ed\":[\"圖片\"],\"hZ6znB\":[\"端口\"],\"ha+Bz5\":[\"例如：100:1440\"],\"hehnjM\":[\"數量\"],\"hzdLuQ\":[\"只有獲得發言權或更高權限的使用者才能發言\"],\"i0qMbr\":[\"主页\"],\"iDNBZe\":[\"通知\"],\"iH8pgl\":[\"返回\"],\"iL9SZg\":[\"封禁用户（按昵称）\"],\"iNt+3c\":[\"返回图片\"],\"iQvi+a\":[\"不再提醒我此服务器的低链路安全问题\"],\"iSLIjg\":[\"連線\"],\"iWXkHH\":[\"半管理员\"],\"iZeTtp\":[\"服务器地址\"],\"idD8Ev\":[\"已儲存\"],\"iivqkW\":[\"登入時間\"],\"ij+Elv\":[\"图片预览\"],\"ilIWp7\":[\"切换通知\"],\"iuaqvB\":[\"使用 * 作為萬用字元。範例：baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"机器人\"],\"j2DGR0\":[\"依主機遮罩封禁\"],\"jA4uoI\":[\"話題：\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"原因（可选）\"],\"jUV7CU\":[\"上傳頭像\"],\"jW5Uwh\":[\"控制載入的外部媒體量。關閉 / 安全 / 可信來源 / 所有內容。\"],\"jXzms5\":[\"附件选项\"],\"jZlrte\":[\"颜色\"],\"jfC/xh\":[\"聯絡方式\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"載入較早的訊息\"],\"k3ID0F\":[\"筛选成员…\"],\"k65gsE\":[\"深入查看\"],\"k7Zgob\":[\"取消连接\"],\"kAVx5h\":[\"未找到邀請\"],\"kCLEPU\":[\"已連線至\"],\"kF5LKb\":[\"已忽略的模式：\"],\"kGeOx/\":[\"加入 \",[\"0\"]],\"kITKr8\":[\"正在載入頻道模式...\"],\"kPpPsw\":[\"您是 IRC Operator\"],\"kWJmRL\":[\"您\"],\"kfcRb0\":[\"头像\"],\"kjMqSj\":[\"複製 JSON\"],\"krViRy\":[\"點擊以 JSON 格式複製\"],\"ks71ra\":[\"例外\"],\"kw4lRv\":[\"頻道半操作員\"],\"kxgIRq\":[\"选择或添加频道以开始使用。\"],\"ky6dWe\":[\"头像预览\"],\"l+GxCv\":[\"正在載入頻道...\"],\"l+IUVW\":[\"帳戶 \",[\"account\"],\" 驗證成功：\",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"other\":[\"已重新連線 \",[\"reconnectCount\"],\" 次\"]}]],\"l5jmzx\":[[\"0\"],\" 和 \",[\"1\"],\" 正在输入...\"],\"lHy8N5\":[\"正在載入更多頻道...\"],\"lbpf14\":[\"加入 \",[\"value\"]],\"lfFsZ4\":[\"頻道\"],\"lkNdiH\":[\"帳戶名稱\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"上传图片\"],\"loQxaJ\":[\"我回来了\"],\"lvfaxv\":[\"首頁\"],\"m16xKo\":[\"新增\"],\"m8flAk\":[\"預覽（尚未上傳）\"],\"mEPxTp\":[\"<0>⚠️ 请注意！</0> 仅打开来自可信来源的链接。恶意链接可能危害您的安全或隐私。\"],\"mHGdhG\":[\"伺服器資訊\"],\"mHS8lb\":[\"发消息到 #\",[\"0\"]],\"mMYBD9\":[\"寬泛 – 更廣的保護範圍\"],\"mTGsPd\":[\"頻道主題\"],\"mU8j6O\":[\"禁止外部訊息 (+n)\"],\"mZp8FL\":[\"自動回退至單行\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"評論 (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"用户已认证\"],\"mwtcGl\":[\"关闭评论\"],\"mzI/c+\":[\"下载\"],\"n3fGRk\":[\"由 \",[\"0\"],\" 設定\"],\"nE9jsU\":[\"寬鬆 – 較弱的保護\"],\"nNflMD\":[\"离开频道\"],\"nPXkBi\":[\"正在載入 WHOIS 資料...\"],\"nQnxxF\":[\"发消息到 #\",[\"0\"],\"（Shift+Enter 换行）\"],\"nWMRxa\":[\"取消置顶\"],\"nkC032\":[\"无洪水防护配置\"],\"o69z4d\":[\"向 \",[\"username\"],\" 发送警告消息\"],\"o9ylQi\":[\"搜尋 GIF 以開始\"],\"oFGkER\":[\"服务器通知\"],\"oOi11l\":[\"滚动到底部\"],\"oQEzQR\":[\"新私訊\"],\"oXOSPE\":[\"在线\"],\"oal760\":[\"服务器链路可能遭受中间人攻击\"],\"oeqmmJ\":[\"受信任来源\"],\"ovBPCi\":[\"默认\"],\"p0Z69r\":[\"模式不能為空\"],\"p1KgtK\":[\"音频加载失败\"],\"p59pEv\":[\"更多詳情\"],\"p7sRI6\":[\"讓其他人知道您正在輸入\"],\"pBm1od\":[\"秘密频道\"],\"pNmiXx\":[\"所有伺服器的預設暱稱\"],\"pUUo9G\":[\"主機名：\"],\"pVGPmz\":[\"账户密码\"],\"peNE68\":[\"永久\"],\"plhHQt\":[\"無資料\"],\"pm6+q5\":[\"安全警告\"],\"pn5qSs\":[\"其他資訊\"],\"q0cR4S\":[\"現在稱為 **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"頻道不會出現在 LIST 或 NAMES 指令中\"],\"qLpTm/\":[\"移除表情 \",[\"emoji\"]],\"qVkGWK\":[\"置顶\"],\"qY8wNa\":[\"主页\"],\"qb0xJ7\":[\"萬用字元：* 符合任意字元，? 符合單一字元。範例：nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"頻道金鑰 (+k)\"],\"qtoOYG\":[\"无限制\"],\"r1W2AS\":[\"檔案託管圖片\"],\"rIPR2O\":[\"主題設定時間早於（分鐘前）\"],\"rMMSYo\":[\"最大長度為 \",[\"0\"]],\"rWtzQe\":[\"網路已分裂並重新連接。✅\"],\"rYG2u6\":[\"请稍候...\"],\"rdUucN\":[\"预览\"],\"rjGI/Q\":[\"隐私\"],\"rk8iDX\":[\"正在載入 GIF...\"],\"rn6SBY\":[\"取消靜音\"],\"s/UKqq\":[\"已被踢出频道\"],\"s8cATI\":[\"加入了 \",[\"channelName\"]],\"sCO9ue\":[\"与 <0>\",[\"serverName\"],\"</0> 的连接存在以下安全问题：\"],\"sGH11W\":[\"伺服器\"],\"sHI1H+\":[\"現在稱為 **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" 邀請您加入 \",[\"channel\"]],\"sby+1/\":[\"點擊複製\"],\"sfN25C\":[\"您的真實姓名或全名\"],\"sliuzR\":[\"打开链接\"],\"sqrO9R\":[\"自訂提及\"],\"sr6RdJ\":[\"Shift+Enter 換行\"],\"swrCpB\":[\"頻道已由 \",[\"user\"],\" 從 \",[\"oldName\"],\" 重新命名為 \",[\"newName\"],[\"0\"]],\"sxkWRg\":[\"進階\"],\"t/YqKh\":[\"移除\"],\"t47eHD\":[\"您在此伺服器上的唯一識別碼\"],\"tAkAh0\":[\"含可選 \",[\"size\"],\" 替換的 URL。範例：https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"显示或隐藏频道列表侧栏\"],\"tfDRzk\":[\"保存\"],\"tiBsJk\":[\"離開了 \",[\"channelName\"]],\"tt4/UD\":[\"退出了 (\",[\"reason\"],\")\"],\"u0TcnO\":[\"暱稱 {nick} 已被使用，正在用 {newNick} 重試\"],\"u0a8B4\":[\"以 IRC 操作員身分進行管理存取認證\"],\"u0rWFU\":[\"建立時間晚於（分鐘前）\"],\"u72w3t\":[\"要忽略的使用者和模式\"],\"u7jc2L\":[\"退出了\"],\"uAQUqI\":[\"状态\"],\"uB85T3\":[\"儲存失敗：\",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC 伺服器：\"],\"usSSr/\":[\"缩放级别\"],\"v7uvcf\":[\"軟體：\"],\"vE8kb+\":[\"Shift+Enter 換行（Enter 傳送）\"],\"vERlcd\":[\"个人资料\"],\"vK0RL8\":[\"無主題\"],\"vSJd18\":[\"影片\"],\"vXIe7J\":[\"语言\"],\"vaHYxN\":[\"真实姓名\"],\"vhjbKr\":[\"离开\"],\"w4NYox\":[[\"title\"],\" 客戶端\"],\"w8xQRx\":[\"值無效\"],\"wFjjxZ\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"未找到封禁例外\"],\"wPrGnM\":[\"頻道管理員\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"顯示使用者加入或離開頻道的事件\"],\"whqZ9r\":[\"要醒目提示的額外詞語或短語\"],\"wm7RV4\":[\"通知聲音\"],\"wz/Yoq\":[\"您的消息在服务器之间转发时可能被截获\"],\"xCJdfg\":[\"清除\"],\"xUHRTR\":[\"連線時自動以操作員身分認證\"],\"xWHwwQ\":[\"封禁\"],\"xYilR2\":[\"媒体\"],\"xceQrO\":[\"仅支持安全的 WebSocket 连接\"],\"xdtXa+\":[\"頻道名稱\"],\"xfXC7q\":[\"文字頻道\"],\"xlCYOE\":[\"正在載入更多訊息...\"],\"xlhswE\":[\"最小值為 \",[\"0\"]],\"xq97Ci\":[\"添加词语或短语...\"],\"xuRqRq\":[\"用戶端限制 (+l)\"],\"xwF+7J\":[[\"0\"],\" 正在输入...\"],\"yNeucF\":[\"此伺服器不支援擴充個人資料元資料（IRCv3 METADATA 擴充功能）。頭像、顯示名稱和狀態等欄位不可用。\"],\"yPlrca\":[\"頻道頭像\"],\"yQE2r9\":[\"載入中\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper 使用者名稱\"],\"yYOzWD\":[\"日志\"],\"yfx9Re\":[\"IRC 操作員密碼\"],\"ygCKqB\":[\"停止\"],\"ymDxJx\":[\"IRC 操作員使用者名稱\"],\"yrpRsQ\":[\"依名稱排序\"],\"yz7wBu\":[\"关闭\"],\"zJw+jA\":[\"設定模式：\",[\"0\"]],\"zebeLu\":[\"输入 oper 用户名\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"無效的模式格式，請使用 nick!user@host 格式（允許萬用字元 *）\"],\"+6NQQA\":[\"通用支持频道\"],\"+6NyRG\":[\"客戶端\"],\"+K0AvT\":[\"断开连接\"],\"+cyFdH\":[\"標記為離開時的預設訊息\"],\"+mVPqU\":[\"在訊息中渲染 Markdown 格式\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"您的帳戶認證使用者名稱\"],\"+yPBXI\":[\"選擇檔案\"],\"+zy2Nq\":[\"類型\"],\"/09cao\":[\"链路安全级别较低（级别 \",[\"securityLevel\"],\"）\"],\"/3BQ4J\":[\"頻道外的使用者無法傳送訊息\"],\"/6BzZF\":[\"切换成员列表\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"使用者已離開\"],\"/XQgft\":[\"探索\"],\"/cF7Rs\":[\"音量\"],\"/dqduX\":[\"下一页\"],\"/fc3q4\":[\"所有内容\"],\"/kISDh\":[\"啟用通知聲音\"],\"/n04sB\":[\"踢出\"],\"/rTz0M\":[\"音訊\"],\"/rfkZe\":[\"提及和訊息時播放聲音\"],\"0/0ZGA\":[\"頻道名稱遮罩\"],\"0D6j7U\":[\"了解更多自訂規則 →\"],\"0XsHcR\":[\"踢出用户\"],\"0ZpE//\":[\"依使用者數排序\"],\"0bEPwz\":[\"设置为离开\"],\"0dGkPt\":[\"展开频道列表\"],\"0gS7M5\":[\"显示名称\"],\"0kS+M8\":[\"範例網路\"],\"0rgoY7\":[\"僅連線至您選擇的伺服器\"],\"0wdd7X\":[\"加入\"],\"0wkVYx\":[\"私人訊息\"],\"111uHX\":[\"链接预览\"],\"196EG4\":[\"删除私聊\"],\"1DSr1i\":[\"注册账户\"],\"1O/24y\":[\"切换频道列表\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"外部链接警告\"],\"1ZC/dv\":[\"沒有未讀提及或訊息\"],\"1pO1zi\":[\"服务器名称为必填项\"],\"1uwfzQ\":[\"查看频道主题\"],\"268g7c\":[\"输入显示名称\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"网络上的服务器管理员可能读取您的消息\"],\"2FYpfJ\":[\"更多\"],\"2HF1Y2\":[[\"inviter\"],\" 邀請了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"2I70QL\":[\"查看用户资料信息\"],\"2QYdmE\":[\"用戶：\"],\"2QpEjG\":[\"已離開\"],\"2YE223\":[\"发消息到 #\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"使用服务器密码\"],\"2iTmdZ\":[\"本機儲存：\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"嚴格 – 更強的保護\"],\"2uDhbA\":[\"输入要邀请的用户名\"],\"2ygf/L\":[\"← 返回\"],\"2zEgxj\":[\"搜索 GIF...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"重命名频道\"],\"3THokf\":[\"有發言權的使用者\"],\"3TSz9S\":[\"最小化\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"頻道顯示名稱\"],\"3ryuFU\":[\"可選的當機報告以改善應用程式\"],\"3uBF/8\":[\"关闭查看器\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"輸入帳戶名稱...\"],\"4/Rr0R\":[\"邀请用户加入当前频道\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"規則\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"洪水設定檔 (+F)\"],\"4RZQRK\":[\"你在做什麼?\"],\"4hfTrB\":[\"昵称\"],\"4n99LO\":[\"已在 \",[\"0\"]],\"4t6vMV\":[\"短訊息自動切換至單行\"],\"4vsHmf\":[\"時間（分鐘）\"],\"5+INAX\":[\"醒目提示提及您的訊息\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper 用户名\"],\"678PKt\":[\"网络名称\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"离线\"],\"6CO3WE\":[\"加入頻道需要密碼，留空可移除金鑰。\"],\"6HhMs3\":[\"退出消息\"],\"6V3Ea3\":[\"已复制\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"收起\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"輸入 oper 密碼...\"],\"7+IHTZ\":[\"未選擇檔案\"],\"73hrRi\":[\"nick!user@host（例如：spam*!*@*, *!*@badhost.com）\"],\"7QkKyN\":[\"发送私信\"],\"7U1W7c\":[\"非常宽松\"],\"7Y1YQj\":[\"真實姓名：\"],\"7YHArF\":[\"— 在檢視器中開啟\"],\"7fjnVl\":[\"搜索用户...\"],\"7jL88x\":[\"刪除此訊息？此操作無法復原。\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"您在想什么？\"],\"7sEpu1\":[\"成员 — \",[\"0\"]],\"7sNhEz\":[\"用户名\"],\"8H0Q+x\":[\"了解更多設定檔 →\"],\"8Phu0A\":[\"顯示使用者更改暱稱的事件\"],\"8XTG9e\":[\"输入 oper 密码\"],\"8XsV2J\":[\"重新傳送\"],\"8ZsakT\":[\"密码\"],\"8kR84m\":[\"您即将打开一个外部链接：\"],\"8lCgih\":[\"移除規則\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"other\":[\"已加入 \",[\"joinCount\"],\" 次\"]}]],\"9BMLnJ\":[\"重新连接服务器\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"添加表情\"],\"9PQ8m2\":[\"G-Line（全域封禁）\"],\"9Qs99X\":[\"電子郵件：\"],\"9QupBP\":[\"删除规则\"],\"9bG48P\":[\"傳送中\"],\"9f5f0u\":[\"有隱私問題？請聯絡我們：\"],\"9unqs3\":[\"離開：\"],\"9v3hwv\":[\"未找到服务器。\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"连接中\"],\"A1taO8\":[\"搜索\"],\"A2adVi\":[\"傳送正在輸入通知\"],\"A9Rhec\":[\"頻道名稱\"],\"AWOSPo\":[\"放大\"],\"AXSpEQ\":[\"連線時獲取 Oper\"],\"AeXO77\":[\"账户\"],\"AhNP40\":[\"跳转\"],\"Ai2U7L\":[\"主機\"],\"AjBQnf\":[\"已更改昵称\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"取消回复\"],\"ApSx0O\":[\"找到 \",[\"0\"],\" 則符合「\",[\"searchQuery\"],\"」的訊息\"],\"AxPAXW\":[\"未找到結果\"],\"AyNqAB\":[\"在聊天中顯示所有伺服器事件\"],\"B/QqGw\":[\"暂时离开\"],\"B8AaMI\":[\"此欄位為必填\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"伺服器不支援進階 LIST 篩選\"],\"BDKt3I\":[[\"0\"],\"、\",[\"1\"],\"、\",[\"2\"],\" 以及另外 \",[\"3\"],\" 人正在输入...\"],\"BGul2A\":[\"您有未保存的更改。确定要关闭而不保存吗？\"],\"BIf9fi\":[\"您的狀態訊息\"],\"BZz3md\":[\"您的個人網站\"],\"Bgm/H7\":[\"允許輸入多行文字\"],\"BiQIl1\":[\"置顶此私信对话\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"点击跳转到消息\"],\"Bowq3c\":[\"只有管理員可以更改頻道主題\"],\"Btozzp\":[\"此图片已过期\"],\"Bycfjm\":[\"總計：\",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"複製完整 JSON\"],\"C9L9wL\":[\"資料收集\"],\"CDq4wC\":[\"管理用户\"],\"CHVRxG\":[\"发消息给 @\",[\"0\"],\"（Shift+Enter 换行）\"],\"CN9zdR\":[\"Oper 用户名和密码为必填项\"],\"CW3sYa\":[\"添加表情 \",[\"emoji\"]],\"CaAkqd\":[\"顯示退出事件\"],\"CbvaYj\":[\"依暱稱封禁\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"选择频道\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"普通\"],\"D+NlUC\":[\"系统\"],\"D28t6+\":[\"已加入並退出\"],\"DB8zMK\":[\"套用\"],\"DBcWHr\":[\"自訂通知音效檔\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"媒體預覽\"],\"Dj4pSr\":[\"选择一个安全密码\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"搜尋中...\"],\"Du2T2f\":[\"未找到設定\"],\"DwsSVQ\":[\"套用篩選並重新整理\"],\"E3W/zd\":[\"預設暱稱\"],\"E6nRW7\":[\"复制链接\"],\"E703RG\":[\"模式：\"],\"EAeu1Z\":[\"傳送邀請\"],\"EFKJQT\":[\"設定\"],\"EGPQBv\":[\"自訂洪水規則 (+f)\"],\"ELik0r\":[\"查看完整隱私權政策\"],\"EPbeC2\":[\"查看或编辑频道主题\"],\"EQCDNT\":[\"輸入 oper 用戶名...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"找到 1 則符合「\",[\"searchQuery\"],\"」的訊息\"],\"EatZYJ\":[\"下一张图片\"],\"EdQY6l\":[\"無\"],\"EnqLYU\":[\"搜索服务器...\"],\"F0OKMc\":[\"编辑服务器\"],\"F6Int2\":[\"啟用醒目提示\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"最多使用者數\"],\"FUU/hZ\":[\"控制聊天中載入的外部媒體數量。\"],\"Fdp03t\":[\"開啟\"],\"FfPWR0\":[\"弹窗\"],\"FjkaiT\":[\"缩小\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"这意味着：\"],\"FolHNl\":[\"管理您的账户和身份验证\"],\"Fp2Dif\":[\"已退出服务器\"],\"G5KmCc\":[\"GZ-Line（全域 Z-Line）\"],\"GDs0lz\":[\"<0>风险：</0> 敏感信息（消息、私人对话、身份验证详情）可能会暴露给网络管理员或位于 IRC 服务器之间的攻击者。\"],\"GR+2I3\":[\"新增邀請遮罩（例如 nick!*@*, *!*@host.com）\"],\"GRLyMU\":[\"关闭弹出的服务器通知\"],\"GlHnXw\":[\"暱稱修改失敗: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"預覽：\"],\"GtmO8/\":[\"来自\"],\"GtuHUQ\":[\"在伺服器上重新命名此頻道，所有使用者都會看到新名稱。\"],\"GuGfFX\":[\"切换搜索\"],\"GxkJXS\":[\"上傳中...\"],\"GzbwnK\":[\"已加入频道\"],\"GzsUDB\":[\"擴充個人資料\"],\"H/PnT8\":[\"插入表情\"],\"H6Izzl\":[\"您的首選顏色代碼\"],\"H9jIv+\":[\"顯示加入/離開\"],\"HAKBY9\":[\"上傳檔案\"],\"HdE1If\":[\"頻道\"],\"Hk4AW9\":[\"您的首選顯示名稱\"],\"HmHDk7\":[\"选择成员\"],\"HrQzPU\":[[\"networkName\"],\" 上的頻道\"],\"I2tXQ5\":[\"发消息给 @\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"I6bw/h\":[\"封禁使用者\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"启用通知\"],\"I9D72S\":[\"您確定要刪除此訊息嗎？此操作無法復原。\"],\"IA+1wo\":[\"顯示使用者被踢出頻道的事件\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"資訊：\"],\"IUwGEM\":[\"保存更改\"],\"IVeGK6\":[[\"0\"],\"、\",[\"1\"],\" 和 \",[\"2\"],\" 正在输入...\"],\"IgrLD/\":[\"暫停\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"回复\"],\"IoHMnl\":[\"最大值為 \",[\"0\"]],\"IvMj+0\":[\"管理员\"],\"J28zul\":[\"正在連線...\"],\"J5T9NW\":[\"使用者資訊\"],\"J8Y5+z\":[\"哎呀！網路分裂！⚠️\"],\"JBHkBA\":[\"已离开频道\"],\"JCwL0Q\":[\"输入原因（可选）\"],\"JFciKP\":[\"切换\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"更改频道名称（仅限管理员）\"],\"JcD7qf\":[\"更多操作\"],\"JdkA+c\":[\"隱密 (+s)\"],\"Jmu12l\":[\"服务器频道\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"啟用 Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"無可用 WHOIS 資料\"],\"KAXSwC\":[\"语音权限\"],\"KDfTdX\":[\"删除消息\"],\"KKBlUU\":[\"嵌入\"],\"KM0pLb\":[\"欢迎来到本频道！\"],\"KR6W2h\":[\"取消忽略使用者\"],\"KV+Bi1\":[\"僅限邀請 (+i)\"],\"KdCtwE\":[\"在重置計數器之前監控洪水活動的秒數\"],\"Kkezga\":[\"服务器密码\"],\"KsiQ/8\":[\"使用者必須受邀才能加入頻道\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"頻道資訊\"],\"LC1a7n\":[\"IRC 服务器报告其服务器间链路的安全级别较低。这意味着当您的消息在网络中的 IRC 服务器之间转发时，可能未经过妥善加密，或者 SSL/TLS 证书未被正确验证。\"],\"LNfLR5\":[\"顯示踢出事件\"],\"LQb0W/\":[\"顯示所有事件\"],\"LU7/yA\":[\"顯示用的別名，可包含空格、表情符號和特殊字元。真實頻道名（\",[\"channelName\"],\"）仍用於 IRC 指令。\"],\"LUb9O7\":[\"需要有效的服务器端口\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"隱私權政策\"],\"LcuSDR\":[\"管理您的个人资料信息和元数据\"],\"LqLS9B\":[\"顯示暱稱變更\"],\"LsDQt2\":[\"频道设置\"],\"LtI9AS\":[\"频道所有者\"],\"LuNhhL\":[\"对此消息做出了回应\"],\"M/AZNG\":[\"頭像圖片 URL\"],\"M/WIer\":[\"傳送訊息\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"名稱：\"],\"MHk+7g\":[\"上一张图片\"],\"MRorGe\":[\"私信用户\"],\"MVbSGP\":[\"時間視窗（秒）\"],\"MkpcsT\":[\"您的訊息和設定儲存在本機裝置上\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"標記為機器人——通常為 'on' 或空白\"],\"N7TQbE\":[\"邀請使用者加入 \",[\"channelName\"]],\"NCca/o\":[\"輸入預設暱稱...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"显示所有外部媒体。任何 URL 都可能向未知服务器发送请求。\"],\"Nt+9O7\":[\"使用 WebSocket 而非原始 TCP\"],\"NxIHzc\":[\"踢出用戶\"],\"O+v/cL\":[\"浏览服务器上的所有频道\"],\"ODwSCk\":[\"发送 GIF\"],\"OGQ5kK\":[\"配置通知声音和高亮提示\"],\"OIPt1Z\":[\"显示或隐藏成员列表侧栏\"],\"OKSNq/\":[\"非常严格\"],\"ONWvwQ\":[\"上傳\"],\"OVKoQO\":[\"您的帳戶認證密碼\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - 將 IRC 帶入未來\"],\"OhCpra\":[\"设置主题…\"],\"OkltoQ\":[\"通过昵称封禁 \",[\"username\"],\"（阻止其使用相同昵称重新加入）\"],\"P+t/Te\":[\"無其他資料\"],\"P42Wcc\":[\"安全\"],\"PD38l0\":[\"频道头像预览\"],\"PD9mEt\":[\"输入消息...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"打开频道配置设置\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"此頻道顯示的主題，所有使用者均可查看。\"],\"PZCecv\":[\"PDF 預覽\"],\"PeLgsC\":[[\"c\",\"plural\",{\"other\":[[\"c\"],\" 次\"]}]],\"PguS2C\":[\"新增例外遮罩（例如 nick!*@*, *!*@host.com）\"],\"Pil5Ty\":[\"顯示 \",[\"displayedChannelsCount\"],\"，共 \",[\"0\"],\" 個頻道\"],\"PqhVlJ\":[\"封禁用户（按 Hostmask）\"],\"Q+chwU\":[\"用戶名：\"],\"Q6hhn8\":[\"偏好设置\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"請輸入使用者名稱\"],\"QGqSZ2\":[\"颜色与格式\"],\"QJQd1J\":[\"編輯資料\"],\"QSzGDE\":[\"閒置\"],\"QUlny5\":[\"欢迎来到 \",[\"0\"],\"！\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"阅读更多\"],\"QuSkCF\":[\"筛选频道...\"],\"QwUrDZ\":[\"將主題更改為：\",[\"topic\"]],\"R0UH07\":[\"第 \",[\"0\"],\" 張，共 \",[\"1\"],\" 張\"],\"R7SsBE\":[\"靜音\"],\"R8rf1X\":[\"点击设置主题\"],\"RArB3D\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"使用 ObsidianIRC 探索 IRC 的世界\"],\"RMMaN5\":[\"已審核 (+m)\"],\"RWw9Lg\":[\"關閉視窗\"],\"RZ2BuZ\":[\"帳戶 \",[\"account\"],\" 註冊需要驗證：\",[\"message\"]],\"RySp6q\":[\"隱藏評論\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"昵称为必填项\"],\"SPVjfj\":[\"留空将默认显示\\\"无原因\\\"\"],\"SQKPvQ\":[\"邀请用户\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"選擇預定義的洪水保護設定檔。這些設定檔為不同使用場景提供均衡的保護設定。\"],\"Slr+3C\":[\"最少使用者數\"],\"Spnlre\":[\"您邀請了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"T/ckN5\":[\"在查看器中打开\"],\"T91vKp\":[\"播放\"],\"TV2Wdu\":[\"了解我們如何處理您的資料並保護您的隱私。\"],\"TgFpwD\":[\"正在套用...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"无更改\"],\"TtserG\":[\"输入真实姓名\"],\"Ttz9J1\":[\"輸入密碼...\"],\"Tz0i8g\":[\"設定\"],\"U3pytU\":[\"管理员\"],\"UDb2YD\":[\"添加表情\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"儲存設定\"],\"UV5hLB\":[\"未找到封禁\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"状态消息\"],\"Ue3uny\":[\"預設（無設定檔）\"],\"UkARhe\":[\"普通 – 標準保護\"],\"Umn7Cj\":[\"尚無評論，成為第一個吧！\"],\"UtUIRh\":[[\"0\"],\" 則較早的訊息\"],\"UwzP+U\":[\"安全連線\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"頻道擁有者\"],\"V4qgxE\":[\"建立時間早於（分鐘前）\"],\"V8yTm6\":[\"清除搜索\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - 将 IRC 带入未来\"],\"VJScHU\":[\"原因\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"静音通知\"],\"VbyRUy\":[\"評論\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"設定者：\"],\"VqnIZz\":[\"查看我们的隐私政策和数据使用规范\"],\"VrMygG\":[\"最小長度為 \",[\"0\"]],\"VrnTui\":[\"您的代名詞，顯示在個人資料中\"],\"W8E3qn\":[\"已驗證帳戶\"],\"WAakm9\":[\"删除频道\"],\"WFxTHC\":[\"新增封禁遮罩（例如 nick!*@*, *!*@host.com）\"],\"WN1g9F\":[\"服务器地址为必填项\"],\"WRYdXW\":[\"音频进度\"],\"WUOH5B\":[\"忽略使用者\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"other\":[\"顯示另外 \",[\"1\"],\" 個項目\"]}]],\"Weq9zb\":[\"一般\"],\"Wfj7Sk\":[\"开启或关闭通知声音\"],\"Wm7gbG\":[\"GitHub：\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"用户资料\"],\"X6S3lt\":[\"搜索设置、频道、服务器...\"],\"XEHan5\":[\"仍然继续\"],\"XI1+wb\":[\"格式無效\"],\"XIXeuC\":[\"发消息给 @\",[\"0\"]],\"XMS+k4\":[\"发起私信\"],\"XWgxXq\":[\"相簿\"],\"Xd7+IT\":[\"取消置顶私聊\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"顯示\"],\"Xp2n93\":[\"显示来自服务器受信任文件主机的媒体。不会向外部服务发送任何请求。\"],\"XvjC4F\":[\"正在儲存...\"],\"Y/qryO\":[\"未找到与搜索条件匹配的用户\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"帳戶 \",[\"account\"],\" 註冊成功：\",[\"message\"]],\"YEfzvP\":[\"受保護主題 (+t)\"],\"YQOn6a\":[\"收起成员列表\"],\"YRCoE9\":[\"頻道操作員\"],\"YURQaF\":[\"查看個人資料\"],\"YdBSvr\":[\"控制媒体显示和外部内容\"],\"Yj6U3V\":[\"無中央伺服器：\"],\"YjvpGx\":[\"代词\"],\"YqH4l4\":[\"无密钥\"],\"YyUPpV\":[\"帳戶：\"],\"ZJSWfw\":[\"中斷伺服器連線時顯示的訊息\"],\"ZR1dJ4\":[\"邀請\"],\"ZdWg0V\":[\"在浏览器中打开\"],\"ZhRBbl\":[\"搜索消息…\"],\"Zmcu3y\":[\"進階篩選\"],\"a2/8e5\":[\"主題設定時間晚於（分鐘前）\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"上一页\"],\"aJTbXX\":[\"Oper 密码\"],\"aQryQv\":[\"模式已存在\"],\"aW9pLN\":[\"頻道允許的最大使用者數，留空表示無限制。\"],\"ah4fmZ\":[\"同时显示来自 YouTube、Vimeo、SoundCloud 及类似知名服务的预览。\"],\"aifXak\":[\"此頻道沒有媒體\"],\"ap2zBz\":[\"宽松\"],\"az8lvo\":[\"关\"],\"azXSNo\":[\"展开成员列表\"],\"azdliB\":[\"登录账户\"],\"b26wlF\":[\"她/她的\"],\"bD/+Ei\":[\"严格\"],\"bQ6BJn\":[\"設定詳細的洪水保護規則。每條規則指定要監控的活動類型以及超過閾值時採取的操作。\"],\"beV7+y\":[\"使用者將收到加入 \",[\"channelName\"],\" 的邀請。\"],\"bk84cH\":[\"离开消息\"],\"bkHdLj\":[\"添加 IRC 服务器\"],\"bmQLn5\":[\"新增規則\"],\"bwRvnp\":[\"操作\"],\"c8+EVZ\":[\"已验证账户\"],\"cGYUlD\":[\"未加载任何媒体预览。\"],\"cLF98o\":[\"顯示評論 (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"没有可用用户\"],\"cSgpoS\":[\"置顶私聊\"],\"cde3ce\":[\"发消息给 <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"複製格式化輸出\"],\"cl/A5J\":[\"欢迎来到 \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"！\"],\"cnGeoo\":[\"删除\"],\"coPLXT\":[\"我們不在伺服器上儲存您的 IRC 通訊\"],\"crYH/6\":[\"SoundCloud 播放器\"],\"d3sis4\":[\"添加服务器\"],\"d9aN5k\":[\"将 \",[\"username\"],\" 移出频道\"],\"dEgA5A\":[\"取消\"],\"dGi1We\":[\"取消置顶此私信对话\"],\"dJVuyC\":[\"離開了 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"至\"],\"dXqxlh\":[\"<0>⚠️ 安全风险！</0> 此连接可能容易遭受窃听或中间人攻击。\"],\"da9Q/R\":[\"已更改频道模式\"],\"dhJN3N\":[\"顯示評論\"],\"dj2xTE\":[\"关闭通知\"],\"dpCzmC\":[\"洪水保護設定\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"是否在新标签页中打开此链接？\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"编辑\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"上傳圖片或提供含可選 \",[\"size\"],\" 替換的 URL\"],\"edBbee\":[\"通过 hostmask 封禁 \",[\"username\"],\"（阻止其从相同 IP/主机重新加入）\"],\"ekfzWq\":[\"使用者設定\"],\"elPDWs\":[\"自定义您的 IRC 客户端体验\"],\"eu2osY\":[\"<0>💡 建议：</0> 仅在您信任此服务器并了解相关风险的情况下继续操作。避免通过此连接共享敏感信息或密码。\"],\"euEhbr\":[\"點擊加入 \",[\"channel\"]],\"ez3vLd\":[\"啟用多行輸入\"],\"f0J5Ki\":[\"服务器之间的通信可能使用未加密的连接\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"警告用户\"],\"fDOLLd\":[\"未找到頻道。\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"匿名分析：\"],\"fq1GF9\":[\"顯示使用者中斷伺服器連線的事件\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"此伺服器僅支援一種連線類型\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"忽略清單\"],\"gNzMrk\":[\"目前頭像\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"輸入暱稱...\"],\"gz6UQ3\":[\"最大化\"],\"h6razj\":[\"排除頻道名稱遮罩\"],\"hG6jnw\":[\"未设置主题\"],\"hG89Ed\":[\"圖片\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"端口\"],\"ha+Bz5\":[\"例如：100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"數量\"],\"hzdLuQ\":[\"只有獲得發言權或更高權限的使用者才能發言\"],\"i0qMbr\":[\"主页\"],\"iDNBZe\":[\"通知\"],\"iH8pgl\":[\"返回\"],\"iL9SZg\":[\"封禁用户（按昵称）\"],\"iNt+3c\":[\"返回图片\"],\"iQvi+a\":[\"不再提醒我此服务器的低链路安全问题\"],\"iSLIjg\":[\"連線\"],\"iWXkHH\":[\"半管理员\"],\"iZeTtp\":[\"服务器地址\"],\"idD8Ev\":[\"已儲存\"],\"iivqkW\":[\"登入時間\"],\"ij+Elv\":[\"图片预览\"],\"ilIWp7\":[\"切换通知\"],\"iuaqvB\":[\"使用 * 作為萬用字元。範例：baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"机器人\"],\"j2DGR0\":[\"依主機遮罩封禁\"],\"jA4uoI\":[\"話題：\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"原因（可选）\"],\"jUV7CU\":[\"上傳頭像\"],\"jW5Uwh\":[\"控制載入的外部媒體量。關閉 / 安全 / 可信來源 / 所有內容。\"],\"jXzms5\":[\"附件选项\"],\"jZlrte\":[\"颜色\"],\"jfC/xh\":[\"聯絡方式\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"載入較早的訊息\"],\"k3ID0F\":[\"筛选成员…\"],\"k65gsE\":[\"深入查看\"],\"k7Zgob\":[\"取消连接\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"未找到邀請\"],\"kCLEPU\":[\"已連線至\"],\"kF5LKb\":[\"已忽略的模式：\"],\"kGeOx/\":[\"加入 \",[\"0\"]],\"kITKr8\":[\"正在載入頻道模式...\"],\"kPpPsw\":[\"您是 IRC Operator\"],\"kWJmRL\":[\"您\"],\"kfcRb0\":[\"头像\"],\"kjMqSj\":[\"複製 JSON\"],\"krViRy\":[\"點擊以 JSON 格式複製\"],\"ks71ra\":[\"例外\"],\"kw4lRv\":[\"頻道半操作員\"],\"kxgIRq\":[\"选择或添加频道以开始使用。\"],\"ky6dWe\":[\"头像预览\"],\"l+GxCv\":[\"正在載入頻道...\"],\"l+IUVW\":[\"帳戶 \",[\"account\"],\" 驗證成功：\",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"other\":[\"已重新連線 \",[\"reconnectCount\"],\" 次\"]}]],\"l5jmzx\":[[\"0\"],\" 和 \",[\"1\"],\" 正在输入...\"],\"lHy8N5\":[\"正在載入更多頻道...\"],\"lbpf14\":[\"加入 \",[\"value\"]],\"lfFsZ4\":[\"頻道\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"帳戶名稱\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"上传图片\"],\"loQxaJ\":[\"我回来了\"],\"lvfaxv\":[\"首頁\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"新增\"],\"m8flAk\":[\"預覽（尚未上傳）\"],\"mEPxTp\":[\"<0>⚠️ 请注意！</0> 仅打开来自可信来源的链接。恶意链接可能危害您的安全或隐私。\"],\"mHGdhG\":[\"伺服器資訊\"],\"mHS8lb\":[\"发消息到 #\",[\"0\"]],\"mMYBD9\":[\"寬泛 – 更廣的保護範圍\"],\"mTGsPd\":[\"頻道主題\"],\"mU8j6O\":[\"禁止外部訊息 (+n)\"],\"mZp8FL\":[\"自動回退至單行\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"評論 (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"用户已认证\"],\"mwtcGl\":[\"关闭评论\"],\"mzI/c+\":[\"下载\"],\"n3fGRk\":[\"由 \",[\"0\"],\" 設定\"],\"nE9jsU\":[\"寬鬆 – 較弱的保護\"],\"nNflMD\":[\"离开频道\"],\"nPXkBi\":[\"正在載入 WHOIS 資料...\"],\"nQnxxF\":[\"发消息到 #\",[\"0\"],\"（Shift+Enter 换行）\"],\"nWMRxa\":[\"取消置顶\"],\"nkC032\":[\"无洪水防护配置\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"向 \",[\"username\"],\" 发送警告消息\"],\"o9ylQi\":[\"搜尋 GIF 以開始\"],\"oFGkER\":[\"服务器通知\"],\"oOi11l\":[\"滚动到底部\"],\"oQEzQR\":[\"新私訊\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"在线\"],\"oal760\":[\"服务器链路可能遭受中间人攻击\"],\"oeqmmJ\":[\"受信任来源\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"默认\"],\"p0Z69r\":[\"模式不能為空\"],\"p1KgtK\":[\"音频加载失败\"],\"p59pEv\":[\"更多詳情\"],\"p7sRI6\":[\"讓其他人知道您正在輸入\"],\"pBm1od\":[\"秘密频道\"],\"pNmiXx\":[\"所有伺服器的預設暱稱\"],\"pUUo9G\":[\"主機名：\"],\"pVGPmz\":[\"账户密码\"],\"peNE68\":[\"永久\"],\"plhHQt\":[\"無資料\"],\"pm6+q5\":[\"安全警告\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"其他資訊\"],\"q0cR4S\":[\"現在稱為 **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"頻道不會出現在 LIST 或 NAMES 指令中\"],\"qLpTm/\":[\"移除表情 \",[\"emoji\"]],\"qVkGWK\":[\"置顶\"],\"qY8wNa\":[\"主页\"],\"qb0xJ7\":[\"萬用字元：* 符合任意字元，? 符合單一字元。範例：nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"頻道金鑰 (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"无限制\"],\"r1W2AS\":[\"檔案託管圖片\"],\"rIPR2O\":[\"主題設定時間早於（分鐘前）\"],\"rMMSYo\":[\"最大長度為 \",[\"0\"]],\"rWtzQe\":[\"網路已分裂並重新連接。✅\"],\"rYG2u6\":[\"请稍候...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"预览\"],\"rjGI/Q\":[\"隐私\"],\"rk8iDX\":[\"正在載入 GIF...\"],\"rn6SBY\":[\"取消靜音\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"已被踢出频道\"],\"s8cATI\":[\"加入了 \",[\"channelName\"]],\"sCO9ue\":[\"与 <0>\",[\"serverName\"],\"</0> 的连接存在以下安全问题：\"],\"sGH11W\":[\"伺服器\"],\"sHI1H+\":[\"現在稱為 **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" 邀請您加入 \",[\"channel\"]],\"sby+1/\":[\"點擊複製\"],\"sfN25C\":[\"您的真實姓名或全名\"],\"sliuzR\":[\"打开链接\"],\"sqrO9R\":[\"自訂提及\"],\"sr6RdJ\":[\"Shift+Enter 換行\"],\"swrCpB\":[\"頻道已由 \",[\"user\"],\" 從 \",[\"oldName\"],\" 重新命名為 \",[\"newName\"],[\"0\"]],\"sxkWRg\":[\"進階\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"移除\"],\"t47eHD\":[\"您在此伺服器上的唯一識別碼\"],\"tAkAh0\":[\"含可選 \",[\"size\"],\" 替換的 URL。範例：https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"显示或隐藏频道列表侧栏\"],\"tfDRzk\":[\"保存\"],\"tiBsJk\":[\"離開了 \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"退出了 (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"暱稱 {nick} 已被使用，正在用 {newNick} 重試\"],\"u0a8B4\":[\"以 IRC 操作員身分進行管理存取認證\"],\"u0rWFU\":[\"建立時間晚於（分鐘前）\"],\"u72w3t\":[\"要忽略的使用者和模式\"],\"u7jc2L\":[\"退出了\"],\"uAQUqI\":[\"状态\"],\"uB85T3\":[\"儲存失敗：\",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC 伺服器：\"],\"usSSr/\":[\"缩放级别\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"軟體：\"],\"vE8kb+\":[\"Shift+Enter 換行（Enter 傳送）\"],\"vERlcd\":[\"个人资料\"],\"vK0RL8\":[\"無主題\"],\"vSJd18\":[\"影片\"],\"vXIe7J\":[\"语言\"],\"vaHYxN\":[\"真实姓名\"],\"vhjbKr\":[\"离开\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" 客戶端\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"值無效\"],\"wFjjxZ\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"未找到封禁例外\"],\"wPrGnM\":[\"頻道管理員\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"顯示使用者加入或離開頻道的事件\"],\"whqZ9r\":[\"要醒目提示的額外詞語或短語\"],\"wm7RV4\":[\"通知聲音\"],\"wz/Yoq\":[\"您的消息在服务器之间转发时可能被截获\"],\"xCJdfg\":[\"清除\"],\"xUHRTR\":[\"連線時自動以操作員身分認證\"],\"xWHwwQ\":[\"封禁\"],\"xYilR2\":[\"媒体\"],\"xceQrO\":[\"仅支持安全的 WebSocket 连接\"],\"xdtXa+\":[\"頻道名稱\"],\"xfXC7q\":[\"文字頻道\"],\"xlCYOE\":[\"正在載入更多訊息...\"],\"xlhswE\":[\"最小值為 \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"添加词语或短语...\"],\"xuRqRq\":[\"用戶端限制 (+l)\"],\"xwF+7J\":[[\"0\"],\" 正在输入...\"],\"yNeucF\":[\"此伺服器不支援擴充個人資料元資料（IRCv3 METADATA 擴充功能）。頭像、顯示名稱和狀態等欄位不可用。\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"頻道頭像\"],\"yQE2r9\":[\"載入中\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper 使用者名稱\"],\"yYOzWD\":[\"日志\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC 操作員密碼\"],\"ygCKqB\":[\"停止\"],\"ymDxJx\":[\"IRC 操作員使用者名稱\"],\"yrpRsQ\":[\"依名稱排序\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"关闭\"],\"zJw+jA\":[\"設定模式：\",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"输入 oper 用户名\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -1 +1 @@
-/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"无效的模式格式，请使用 nick!user@host 格式（允许通配符 *）\"],\"+6NQQA\":[\"通用支持频道\"],\"+6NyRG\":[\"客户端\"],\"+K0AvT\":[\"断开连接\"],\"+cyFdH\":[\"标记为离开时的默认消息\"],\"+mVPqU\":[\"在消息中渲染 Markdown 格式\"],\"+vqCJH\":[\"您的账户认证用户名\"],\"+yPBXI\":[\"选择文件\"],\"+zy2Nq\":[\"类型\"],\"/09cao\":[\"链路安全级别较低（级别 \",[\"securityLevel\"],\"）\"],\"/3BQ4J\":[\"频道外的用户无法发送消息\"],\"/6BzZF\":[\"切换成员列表\"],\"/TNOPk\":[\"用户已离开\"],\"/XQgft\":[\"发现\"],\"/cF7Rs\":[\"音量\"],\"/dqduX\":[\"下一页\"],\"/fc3q4\":[\"所有内容\"],\"/kISDh\":[\"启用通知声音\"],\"/n04sB\":[\"踢出\"],\"/rTz0M\":[\"音频\"],\"/rfkZe\":[\"提及和消息时播放声音\"],\"0/0ZGA\":[\"频道名称掩码\"],\"0D6j7U\":[\"了解更多自定义规则 →\"],\"0XsHcR\":[\"踢出用户\"],\"0ZpE//\":[\"按用户数排序\"],\"0bEPwz\":[\"设置为离开\"],\"0dGkPt\":[\"展开频道列表\"],\"0gS7M5\":[\"显示名称\"],\"0kS+M8\":[\"示例网络\"],\"0rgoY7\":[\"仅连接您选择的服务器\"],\"0wdd7X\":[\"加入\"],\"0wkVYx\":[\"私信\"],\"111uHX\":[\"链接预览\"],\"196EG4\":[\"删除私聊\"],\"1DSr1i\":[\"注册账户\"],\"1O/24y\":[\"切换频道列表\"],\"1VPJJ2\":[\"外部链接警告\"],\"1ZC/dv\":[\"没有未读提及或消息\"],\"1pO1zi\":[\"服务器名称为必填项\"],\"1uwfzQ\":[\"查看频道主题\"],\"268g7c\":[\"输入显示名称\"],\"2FOFq1\":[\"网络上的服务器管理员可能读取您的消息\"],\"2FYpfJ\":[\"更多\"],\"2HF1Y2\":[[\"inviter\"],\" 邀请了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"2I70QL\":[\"查看用户资料信息\"],\"2QYdmE\":[\"用户：\"],\"2QpEjG\":[\"已离开\"],\"2YE223\":[\"发消息到 #\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"2bimFY\":[\"使用服务器密码\"],\"2iTmdZ\":[\"本地存储：\"],\"2odkwe\":[\"严格 – 更强的保护\"],\"2uDhbA\":[\"输入要邀请的用户名\"],\"2ygf/L\":[\"← 返回\"],\"2zEgxj\":[\"搜索 GIF...\"],\"3RdPhl\":[\"重命名频道\"],\"3THokf\":[\"有发言权的用户\"],\"3TSz9S\":[\"最小化\"],\"3jBDvM\":[\"频道显示名称\"],\"3ryuFU\":[\"可选的崩溃报告以改善应用\"],\"3uBF/8\":[\"关闭查看器\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"输入账户名称...\"],\"4/Rr0R\":[\"邀请用户加入当前频道\"],\"4EZrJN\":[\"规则\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"洪水配置文件 (+F)\"],\"4RZQRK\":[\"你在做什么?\"],\"4hfTrB\":[\"昵称\"],\"4n99LO\":[\"已在 \",[\"0\"]],\"4t6vMV\":[\"短消息自动切换到单行\"],\"4vsHmf\":[\"时间（分钟）\"],\"5+INAX\":[\"高亮提及您的消息\"],\"5R5Pv/\":[\"Oper 用户名\"],\"678PKt\":[\"网络名称\"],\"6Aih4U\":[\"离线\"],\"6CO3WE\":[\"加入频道需要密码，留空可移除密钥。\"],\"6HhMs3\":[\"退出消息\"],\"6V3Ea3\":[\"已复制\"],\"6lGV3K\":[\"收起\"],\"6yFOEi\":[\"输入 oper 密码...\"],\"7+IHTZ\":[\"未选择文件\"],\"73hrRi\":[\"nick!user@host（例如：spam*!*@*, *!*@badhost.com）\"],\"7QkKyN\":[\"发送私信\"],\"7U1W7c\":[\"非常宽松\"],\"7Y1YQj\":[\"真实姓名：\"],\"7YHArF\":[\"— 在查看器中打开\"],\"7fjnVl\":[\"搜索用户...\"],\"7jL88x\":[\"删除此消息？此操作无法撤销。\"],\"7nGhhM\":[\"您在想什么？\"],\"7sEpu1\":[\"成员 — \",[\"0\"]],\"7sNhEz\":[\"用户名\"],\"8H0Q+x\":[\"了解更多配置文件 →\"],\"8Phu0A\":[\"显示用户更改昵称的事件\"],\"8XTG9e\":[\"输入 oper 密码\"],\"8XsV2J\":[\"重新发送\"],\"8ZsakT\":[\"密码\"],\"8kR84m\":[\"您即将打开一个外部链接：\"],\"8lCgih\":[\"删除规则\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8wRzac\":[[\"joinCount\",\"plural\",{\"other\":[\"已加入 \",[\"joinCount\"],\" 次\"]}]],\"9BMLnJ\":[\"重新连接服务器\"],\"9OEgyT\":[\"添加表情\"],\"9PQ8m2\":[\"G-Line（全局封禁）\"],\"9Qs99X\":[\"邮箱：\"],\"9QupBP\":[\"删除规则\"],\"9bG48P\":[\"发送中\"],\"9f5f0u\":[\"有隐私问题？联系我们：\"],\"9unqs3\":[\"离开：\"],\"9v3hwv\":[\"未找到服务器。\"],\"9zb2WA\":[\"连接中\"],\"A1taO8\":[\"搜索\"],\"A2adVi\":[\"发送正在输入通知\"],\"A9Rhec\":[\"频道名称\"],\"AWOSPo\":[\"放大\"],\"AXSpEQ\":[\"连接时获取 Oper\"],\"AeXO77\":[\"账户\"],\"AhNP40\":[\"跳转\"],\"Ai2U7L\":[\"主机\"],\"AjBQnf\":[\"已更改昵称\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"取消回复\"],\"ApSx0O\":[\"找到 \",[\"0\"],\" 条匹配\\\"\",[\"searchQuery\"],\"\\\"的消息\"],\"AxPAXW\":[\"未找到结果\"],\"AyNqAB\":[\"在聊天中显示所有服务器事件\"],\"B/QqGw\":[\"暂时离开\"],\"B8AaMI\":[\"此字段为必填项\"],\"BA2c49\":[\"服务器不支持高级 LIST 筛选\"],\"BDKt3I\":[[\"0\"],\"、\",[\"1\"],\"、\",[\"2\"],\" 以及另外 \",[\"3\"],\" 人正在输入...\"],\"BGul2A\":[\"您有未保存的更改。确定要关闭而不保存吗？\"],\"BIf9fi\":[\"您的状态消息\"],\"BZz3md\":[\"您的个人网站\"],\"Bgm/H7\":[\"允许输入多行文本\"],\"BiQIl1\":[\"置顶此私信对话\"],\"BlNZZ2\":[\"点击跳转到消息\"],\"Bowq3c\":[\"只有管理员可以更改频道主题\"],\"Btozzp\":[\"此图片已过期\"],\"Bycfjm\":[\"总计：\",[\"0\"]],\"C6IBQc\":[\"复制完整 JSON\"],\"C9L9wL\":[\"数据收集\"],\"CDq4wC\":[\"管理用户\"],\"CHVRxG\":[\"发消息给 @\",[\"0\"],\"（Shift+Enter 换行）\"],\"CN9zdR\":[\"Oper 用户名和密码为必填项\"],\"CW3sYa\":[\"添加表情 \",[\"emoji\"]],\"CaAkqd\":[\"显示退出事件\"],\"CbvaYj\":[\"按昵称封禁\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"选择频道\"],\"CsekCi\":[\"普通\"],\"D+NlUC\":[\"系统\"],\"D28t6+\":[\"已加入并退出\"],\"DB8zMK\":[\"应用\"],\"DBcWHr\":[\"自定义通知音频文件\"],\"DTy9Xw\":[\"媒体预览\"],\"Dj4pSr\":[\"选择一个安全密码\"],\"Du+zn+\":[\"搜索中...\"],\"Du2T2f\":[\"未找到设置\"],\"DwsSVQ\":[\"应用筛选并刷新\"],\"E3W/zd\":[\"默认昵称\"],\"E6nRW7\":[\"复制链接\"],\"E703RG\":[\"模式：\"],\"EAeu1Z\":[\"发送邀请\"],\"EFKJQT\":[\"设置\"],\"EGPQBv\":[\"自定义洪水规则 (+f)\"],\"ELik0r\":[\"查看完整隐私政策\"],\"EPbeC2\":[\"查看或编辑频道主题\"],\"EQCDNT\":[\"输入 oper 用户名...\"],\"EUvulZ\":[\"找到 1 条匹配\\\"\",[\"searchQuery\"],\"\\\"的消息\"],\"EatZYJ\":[\"下一张图片\"],\"EdQY6l\":[\"无\"],\"EnqLYU\":[\"搜索服务器...\"],\"F0OKMc\":[\"编辑服务器\"],\"F6Int2\":[\"启用高亮\"],\"FDoLyE\":[\"最大用户数\"],\"FUU/hZ\":[\"控制聊天中加载的外部媒体数量。\"],\"Fdp03t\":[\"开启\"],\"FfPWR0\":[\"弹窗\"],\"FjkaiT\":[\"缩小\"],\"FlqOE9\":[\"这意味着：\"],\"FolHNl\":[\"管理您的账户和身份验证\"],\"Fp2Dif\":[\"已退出服务器\"],\"G5KmCc\":[\"GZ-Line（全局 Z-Line）\"],\"GDs0lz\":[\"<0>风险：</0> 敏感信息（消息、私人对话、身份验证详情）可能会暴露给网络管理员或位于 IRC 服务器之间的攻击者。\"],\"GR+2I3\":[\"添加邀请掩码（例如 nick!*@*, *!*@host.com）\"],\"GRLyMU\":[\"关闭弹出的服务器通知\"],\"GlHnXw\":[\"昵称修改失败: \",[\"error\"],\" \",[\"0\"]],\"GswZF3\":[\"预览：\"],\"GtmO8/\":[\"来自\"],\"GtuHUQ\":[\"在服务器上重命名此频道，所有用户都会看到新名称。\"],\"GuGfFX\":[\"切换搜索\"],\"GxkJXS\":[\"上传中...\"],\"GzbwnK\":[\"已加入频道\"],\"GzsUDB\":[\"扩展资料\"],\"H/PnT8\":[\"插入表情\"],\"H6Izzl\":[\"您的首选颜色代码\"],\"H9jIv+\":[\"显示加入/离开\"],\"HAKBY9\":[\"上传文件\"],\"HdE1If\":[\"频道\"],\"Hk4AW9\":[\"您的首选显示名称\"],\"HmHDk7\":[\"选择成员\"],\"HrQzPU\":[[\"networkName\"],\" 上的频道\"],\"I2tXQ5\":[\"发消息给 @\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"I6bw/h\":[\"封禁用户\"],\"I92Z+b\":[\"启用通知\"],\"I9D72S\":[\"您确定要删除此消息吗？此操作无法撤销。\"],\"IA+1wo\":[\"显示用户被踢出频道的事件\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"信息：\"],\"IUwGEM\":[\"保存更改\"],\"IVeGK6\":[[\"0\"],\"、\",[\"1\"],\" 和 \",[\"2\"],\" 正在输入...\"],\"IgrLD/\":[\"暂停\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"回复\"],\"IoHMnl\":[\"最大值为 \",[\"0\"]],\"IvMj+0\":[\"管理员\"],\"J28zul\":[\"正在连接...\"],\"J5T9NW\":[\"用户信息\"],\"J8Y5+z\":[\"哎呀！网络分裂！⚠️\"],\"JBHkBA\":[\"已离开频道\"],\"JCwL0Q\":[\"输入原因（可选）\"],\"JFciKP\":[\"切换\"],\"JXGkhG\":[\"更改频道名称（仅限管理员）\"],\"JcD7qf\":[\"更多操作\"],\"JdkA+c\":[\"隐秘 (+s)\"],\"Jmu12l\":[\"服务器频道\"],\"JvQ++s\":[\"启用 Markdown\"],\"K2jwh/\":[\"无可用 WHOIS 数据\"],\"KAXSwC\":[\"语音权限\"],\"KDfTdX\":[\"删除消息\"],\"KKBlUU\":[\"嵌入\"],\"KM0pLb\":[\"欢迎来到本频道！\"],\"KR6W2h\":[\"取消忽略用户\"],\"KV+Bi1\":[\"仅限邀请 (+i)\"],\"KdCtwE\":[\"在重置计数器之前监控洪水活动的秒数\"],\"Kkezga\":[\"服务器密码\"],\"KsiQ/8\":[\"用户必须受邀才能加入频道\"],\"L+gB/D\":[\"频道信息\"],\"LC1a7n\":[\"IRC 服务器报告其服务器间链路的安全级别较低。这意味着当您的消息在网络中的 IRC 服务器之间转发时，可能未经过妥善加密，或者 SSL/TLS 证书未被正确验证。\"],\"LNfLR5\":[\"显示踢出事件\"],\"LQb0W/\":[\"显示所有事件\"],\"LU7/yA\":[\"显示用的别名，可包含空格、表情和特殊字符。真实频道名（\",[\"channelName\"],\"）仍用于 IRC 命令。\"],\"LUb9O7\":[\"需要有效的服务器端口\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"隐私政策\"],\"LcuSDR\":[\"管理您的个人资料信息和元数据\"],\"LqLS9B\":[\"显示昵称变更\"],\"LsDQt2\":[\"频道设置\"],\"LtI9AS\":[\"频道所有者\"],\"LuNhhL\":[\"对此消息做出了回应\"],\"M/AZNG\":[\"头像图片 URL\"],\"M/WIer\":[\"发送消息\"],\"M8er/5\":[\"名称：\"],\"MHk+7g\":[\"上一张图片\"],\"MRorGe\":[\"私信用户\"],\"MVbSGP\":[\"时间窗口（秒）\"],\"MkpcsT\":[\"您的消息和设置存储在本地设备上\"],\"N/hDSy\":[\"标记为机器人——通常为 'on' 或空\"],\"N7TQbE\":[\"邀请用户加入 \",[\"channelName\"]],\"NCca/o\":[\"输入默认昵称...\"],\"Nqs6B9\":[\"显示所有外部媒体。任何 URL 都可能向未知服务器发送请求。\"],\"Nt+9O7\":[\"使用 WebSocket 而非原始 TCP\"],\"NxIHzc\":[\"踢出用户\"],\"O+v/cL\":[\"浏览服务器上的所有频道\"],\"ODwSCk\":[\"发送 GIF\"],\"OGQ5kK\":[\"配置通知声音和高亮提示\"],\"OIPt1Z\":[\"显示或隐藏成员列表侧栏\"],\"OKSNq/\":[\"非常严格\"],\"ONWvwQ\":[\"上传\"],\"OVKoQO\":[\"您的账户认证密码\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - 将 IRC 带入未来\"],\"OhCpra\":[\"设置主题…\"],\"OkltoQ\":[\"通过昵称封禁 \",[\"username\"],\"（阻止其使用相同昵称重新加入）\"],\"P+t/Te\":[\"无其他数据\"],\"P42Wcc\":[\"安全\"],\"PD38l0\":[\"频道头像预览\"],\"PD9mEt\":[\"输入消息...\"],\"PPqfdA\":[\"打开频道配置设置\"],\"PSCjfZ\":[\"此频道显示的主题，所有用户均可查看。\"],\"PZCecv\":[\"PDF 预览\"],\"PeLgsC\":[[\"c\",\"plural\",{\"other\":[[\"c\"],\" 次\"]}]],\"PguS2C\":[\"添加例外掩码（例如 nick!*@*, *!*@host.com）\"],\"Pil5Ty\":[\"显示 \",[\"displayedChannelsCount\"],\"，共 \",[\"0\"],\" 个频道\"],\"PqhVlJ\":[\"封禁用户（按 Hostmask）\"],\"Q+chwU\":[\"用户名：\"],\"Q6hhn8\":[\"偏好设置\"],\"QF4a34\":[\"请输入用户名\"],\"QGqSZ2\":[\"颜色与格式\"],\"QJQd1J\":[\"编辑资料\"],\"QSzGDE\":[\"闲置\"],\"QUlny5\":[\"欢迎来到 \",[\"0\"],\"！\"],\"Qoq+GP\":[\"阅读更多\"],\"QuSkCF\":[\"筛选频道...\"],\"QwUrDZ\":[\"将话题更改为：\",[\"topic\"]],\"R0UH07\":[\"第 \",[\"0\"],\" 张，共 \",[\"1\"],\" 张\"],\"R7SsBE\":[\"静音\"],\"R8rf1X\":[\"点击设置主题\"],\"RArB3D\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"]],\"RI3cWd\":[\"使用 ObsidianIRC 探索 IRC 的世界\"],\"RMMaN5\":[\"受管理 (+m)\"],\"RWw9Lg\":[\"关闭窗口\"],\"RZ2BuZ\":[\"账户 \",[\"account\"],\" 注册需要验证：\",[\"message\"]],\"RySp6q\":[\"隐藏评论\"],\"SPKQTd\":[\"昵称为必填项\"],\"SPVjfj\":[\"留空将默认显示\\\"无原因\\\"\"],\"SQKPvQ\":[\"邀请用户\"],\"SkZcl+\":[\"选择预定义的洪水保护配置文件。这些配置文件为不同使用场景提供均衡的保护设置。\"],\"Slr+3C\":[\"最小用户数\"],\"Spnlre\":[\"您邀请了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"T/ckN5\":[\"在查看器中打开\"],\"T91vKp\":[\"播放\"],\"TV2Wdu\":[\"了解我们如何处理您的数据并保护您的隐私。\"],\"TgFpwD\":[\"正在应用...\"],\"TkzSFB\":[\"无更改\"],\"TtserG\":[\"输入真实姓名\"],\"Ttz9J1\":[\"输入密码...\"],\"Tz0i8g\":[\"设置\"],\"U3pytU\":[\"管理员\"],\"UDb2YD\":[\"添加表情\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"保存设置\"],\"UV5hLB\":[\"未找到封禁\"],\"Uaj3Nd\":[\"状态消息\"],\"Ue3uny\":[\"默认（无配置文件）\"],\"UkARhe\":[\"普通 – 标准保护\"],\"Umn7Cj\":[\"暂无评论，成为第一个吧！\"],\"UtUIRh\":[[\"0\"],\" 条旧消息\"],\"UwzP+U\":[\"安全连接\"],\"V0/A4O\":[\"频道所有者\"],\"V4qgxE\":[\"创建时间早于（分钟前）\"],\"V8yTm6\":[\"清除搜索\"],\"VJMMyz\":[\"ObsidianIRC - 将 IRC 带入未来\"],\"VJScHU\":[\"原因\"],\"VLsmVV\":[\"静音通知\"],\"VbyRUy\":[\"评论\"],\"Vmx0mQ\":[\"设置者：\"],\"VqnIZz\":[\"查看我们的隐私政策和数据使用规范\"],\"VrMygG\":[\"最小长度为 \",[\"0\"]],\"VrnTui\":[\"您的代词，显示在个人资料中\"],\"W8E3qn\":[\"已验证账户\"],\"WAakm9\":[\"删除频道\"],\"WFxTHC\":[\"添加封禁掩码（例如 nick!*@*, *!*@host.com）\"],\"WN1g9F\":[\"服务器地址为必填项\"],\"WRYdXW\":[\"音频进度\"],\"WUOH5B\":[\"忽略用户\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"other\":[\"显示另外 \",[\"1\"],\" 个项目\"]}]],\"Weq9zb\":[\"常规\"],\"Wfj7Sk\":[\"开启或关闭通知声音\"],\"Wm7gbG\":[\"GitHub：\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"用户资料\"],\"X6S3lt\":[\"搜索设置、频道、服务器...\"],\"XEHan5\":[\"仍然继续\"],\"XI1+wb\":[\"格式无效\"],\"XIXeuC\":[\"发消息给 @\",[\"0\"]],\"XMS+k4\":[\"发起私信\"],\"XWgxXq\":[\"相册\"],\"Xd7+IT\":[\"取消置顶私聊\"],\"Xm/s+u\":[\"显示\"],\"Xp2n93\":[\"显示来自服务器受信任文件主机的媒体。不会向外部服务发送任何请求。\"],\"XvjC4F\":[\"正在保存...\"],\"Y/qryO\":[\"未找到与搜索条件匹配的用户\"],\"YAqRpI\":[\"账户 \",[\"account\"],\" 注册成功：\",[\"message\"]],\"YEfzvP\":[\"受保护主题 (+t)\"],\"YQOn6a\":[\"收起成员列表\"],\"YRCoE9\":[\"频道操作员\"],\"YURQaF\":[\"查看资料\"],\"YdBSvr\":[\"控制媒体显示和外部内容\"],\"Yj6U3V\":[\"无中央服务器：\"],\"YjvpGx\":[\"代词\"],\"YqH4l4\":[\"无密钥\"],\"YyUPpV\":[\"账户：\"],\"ZJSWfw\":[\"断开服务器时显示的消息\"],\"ZR1dJ4\":[\"邀请\"],\"ZdWg0V\":[\"在浏览器中打开\"],\"ZhRBbl\":[\"搜索消息…\"],\"Zmcu3y\":[\"高级筛选\"],\"a2/8e5\":[\"主题设置时间晚于（分钟前）\"],\"aHKcKc\":[\"上一页\"],\"aJTbXX\":[\"Oper 密码\"],\"aQryQv\":[\"模式已存在\"],\"aW9pLN\":[\"频道允许的最大用户数，留空表示无限制。\"],\"ah4fmZ\":[\"同时显示来自 YouTube、Vimeo、SoundCloud 及类似知名服务的预览。\"],\"aifXak\":[\"此频道没有媒体\"],\"ap2zBz\":[\"宽松\"],\"az8lvo\":[\"关\"],\"azXSNo\":[\"展开成员列表\"],\"azdliB\":[\"登录账户\"],\"b26wlF\":[\"她/她的\"],\"bD/+Ei\":[\"严格\"],\"bQ6BJn\":[\"配置详细的洪水保护规则。每条规则指定要监控的活动类型以及超过阈值时采取的操作。\"],\"beV7+y\":[\"用户将收到加入 \",[\"channelName\"],\" 的邀请。\"],\"bk84cH\":[\"离开消息\"],\"bkHdLj\":[\"添加 IRC 服务器\"],\"bmQLn5\":[\"添加规则\"],\"bwRvnp\":[\"操作\"],\"c8+EVZ\":[\"已验证账户\"],\"cGYUlD\":[\"未加载任何媒体预览。\"],\"cLF98o\":[\"显示评论 (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"没有可用用户\"],\"cSgpoS\":[\"置顶私聊\"],\"cde3ce\":[\"发消息给 <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"复制格式化输出\"],\"cl/A5J\":[\"欢迎来到 \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"！\"],\"cnGeoo\":[\"删除\"],\"coPLXT\":[\"我们不在服务器上存储您的 IRC 通信\"],\"crYH/6\":[\"SoundCloud 播放器\"],\"d3sis4\":[\"添加服务器\"],\"d9aN5k\":[\"将 \",[\"username\"],\" 移出频道\"],\"dEgA5A\":[\"取消\"],\"dGi1We\":[\"取消置顶此私信对话\"],\"dJVuyC\":[\"离开了 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"至\"],\"dXqxlh\":[\"<0>⚠️ 安全风险！</0> 此连接可能容易遭受窃听或中间人攻击。\"],\"da9Q/R\":[\"已更改频道模式\"],\"dhJN3N\":[\"显示评论\"],\"dj2xTE\":[\"关闭通知\"],\"dpCzmC\":[\"洪水保护设置\"],\"e9dQpT\":[\"是否在新标签页中打开此链接？\"],\"ePK91l\":[\"编辑\"],\"eYBDuB\":[\"上传图片或提供带可选 \",[\"size\"],\" 替换的 URL\"],\"edBbee\":[\"通过 hostmask 封禁 \",[\"username\"],\"（阻止其从相同 IP/主机重新加入）\"],\"ekfzWq\":[\"用户设置\"],\"elPDWs\":[\"自定义您的 IRC 客户端体验\"],\"eu2osY\":[\"<0>💡 建议：</0> 仅在您信任此服务器并了解相关风险的情况下继续操作。避免通过此连接共享敏感信息或密码。\"],\"euEhbr\":[\"点击加入 \",[\"channel\"]],\"ez3vLd\":[\"启用多行输入\"],\"f0J5Ki\":[\"服务器之间的通信可能使用未加密的连接\"],\"f9BHJk\":[\"警告用户\"],\"fDOLLd\":[\"未找到频道。\"],\"ffzDkB\":[\"匿名分析：\"],\"fq1GF9\":[\"显示用户断开服务器连接的事件\"],\"gEF57C\":[\"此服务器仅支持一种连接类型\"],\"gJuLUI\":[\"忽略列表\"],\"gNzMrk\":[\"当前头像\"],\"gjPWyO\":[\"输入昵称...\"],\"gz6UQ3\":[\"最大化\"],\"h6razj\":[\"排除频道名称掩码\"],\"hG6jnw\":[\"未设置主题\"],\"hG89Ed\":[\"图片\"],\"hZ6znB\":[\"端口\"],\"ha+Bz5\":[\"例如：100:1440\"],\"hehnjM\":[\"数量\"],\"hzdLuQ\":[\"只有获得发言权或更高权限的用户才能发言\"],\"i0qMbr\":[\"主页\"],\"iDNBZe\":[\"通知\"],\"iH8pgl\":[\"返回\"],\"iL9SZg\":[\"封禁用户（按昵称）\"],\"iNt+3c\":[\"返回图片\"],\"iQvi+a\":[\"不再提醒我此服务器的低链路安全问题\"],\"iSLIjg\":[\"连接\"],\"iWXkHH\":[\"半管理员\"],\"iZeTtp\":[\"服务器地址\"],\"idD8Ev\":[\"已保存\"],\"iivqkW\":[\"登录时间\"],\"ij+Elv\":[\"图片预览\"],\"ilIWp7\":[\"切换通知\"],\"iuaqvB\":[\"用 * 作通配符。示例：baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"机器人\"],\"j2DGR0\":[\"按主机掩码封禁\"],\"jA4uoI\":[\"话题：\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"原因（可选）\"],\"jUV7CU\":[\"上传头像\"],\"jW5Uwh\":[\"控制加载的外部媒体量。关闭 / 安全 / 可信来源 / 所有内容。\"],\"jXzms5\":[\"附件选项\"],\"jZlrte\":[\"颜色\"],\"jfC/xh\":[\"联系方式\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"加载旧消息\"],\"k3ID0F\":[\"筛选成员…\"],\"k65gsE\":[\"深入查看\"],\"k7Zgob\":[\"取消连接\"],\"kAVx5h\":[\"未找到邀请\"],\"kCLEPU\":[\"已连接到\"],\"kF5LKb\":[\"已忽略的模式：\"],\"kGeOx/\":[\"加入 \",[\"0\"]],\"kITKr8\":[\"正在加载频道模式...\"],\"kPpPsw\":[\"您是 IRC Operator\"],\"kWJmRL\":[\"您\"],\"kfcRb0\":[\"头像\"],\"kjMqSj\":[\"复制 JSON\"],\"krViRy\":[\"点击以 JSON 格式复制\"],\"ks71ra\":[\"例外\"],\"kw4lRv\":[\"频道半操作员\"],\"kxgIRq\":[\"选择或添加频道以开始使用。\"],\"ky6dWe\":[\"头像预览\"],\"l+GxCv\":[\"正在加载频道...\"],\"l+IUVW\":[\"账户 \",[\"account\"],\" 验证成功：\",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"other\":[\"已重新连接 \",[\"reconnectCount\"],\" 次\"]}]],\"l5jmzx\":[[\"0\"],\" 和 \",[\"1\"],\" 正在输入...\"],\"lHy8N5\":[\"正在加载更多频道...\"],\"lbpf14\":[\"加入 \",[\"value\"]],\"lfFsZ4\":[\"频道\"],\"lkNdiH\":[\"账户名称\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"上传图片\"],\"loQxaJ\":[\"我回来了\"],\"lvfaxv\":[\"主页\"],\"m16xKo\":[\"添加\"],\"m8flAk\":[\"预览（尚未上传）\"],\"mEPxTp\":[\"<0>⚠️ 请注意！</0> 仅打开来自可信来源的链接。恶意链接可能危害您的安全或隐私。\"],\"mHGdhG\":[\"服务器信息\"],\"mHS8lb\":[\"发消息到 #\",[\"0\"]],\"mMYBD9\":[\"宽泛 – 更广的保护范围\"],\"mTGsPd\":[\"频道主题\"],\"mU8j6O\":[\"禁止外部消息 (+n)\"],\"mZp8FL\":[\"自动回退到单行\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"评论 (\",[\"commentCount\"],\")\"],\"mvyLSy\":[\"用户已认证\"],\"mwtcGl\":[\"关闭评论\"],\"mzI/c+\":[\"下载\"],\"n3fGRk\":[\"由 \",[\"0\"],\" 设置\"],\"nE9jsU\":[\"宽松 – 较弱的保护\"],\"nNflMD\":[\"离开频道\"],\"nPXkBi\":[\"正在加载 WHOIS 数据...\"],\"nQnxxF\":[\"发消息到 #\",[\"0\"],\"（Shift+Enter 换行）\"],\"nWMRxa\":[\"取消置顶\"],\"nkC032\":[\"无洪水防护配置\"],\"o69z4d\":[\"向 \",[\"username\"],\" 发送警告消息\"],\"o9ylQi\":[\"搜索 GIF 以开始\"],\"oFGkER\":[\"服务器通知\"],\"oOi11l\":[\"滚动到底部\"],\"oQEzQR\":[\"新私信\"],\"oXOSPE\":[\"在线\"],\"oal760\":[\"服务器链路可能遭受中间人攻击\"],\"oeqmmJ\":[\"受信任来源\"],\"ovBPCi\":[\"默认\"],\"p0Z69r\":[\"模式不能为空\"],\"p1KgtK\":[\"音频加载失败\"],\"p59pEv\":[\"更多详情\"],\"p7sRI6\":[\"让其他人知道您正在输入\"],\"pBm1od\":[\"秘密频道\"],\"pNmiXx\":[\"所有服务器的默认昵称\"],\"pUUo9G\":[\"主机名：\"],\"pVGPmz\":[\"账户密码\"],\"peNE68\":[\"永久\"],\"plhHQt\":[\"无数据\"],\"pm6+q5\":[\"安全警告\"],\"pn5qSs\":[\"附加信息\"],\"q0cR4S\":[\"现在称为 **\",[\"newNick\"],\"**\"],\"qFcunY\":[\"频道不会出现在 LIST 或 NAMES 命令中\"],\"qLpTm/\":[\"移除表情 \",[\"emoji\"]],\"qVkGWK\":[\"置顶\"],\"qY8wNa\":[\"主页\"],\"qb0xJ7\":[\"通配符：* 匹配任意字符，? 匹配单个字符。示例：nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"频道密钥 (+k)\"],\"qtoOYG\":[\"无限制\"],\"r1W2AS\":[\"文件托管图片\"],\"rIPR2O\":[\"主题设置时间早于（分钟前）\"],\"rMMSYo\":[\"最大长度为 \",[\"0\"]],\"rWtzQe\":[\"网络已分裂并重新连接。✅\"],\"rYG2u6\":[\"请稍候...\"],\"rdUucN\":[\"预览\"],\"rjGI/Q\":[\"隐私\"],\"rk8iDX\":[\"正在加载 GIF...\"],\"rn6SBY\":[\"取消静音\"],\"s/UKqq\":[\"已被踢出频道\"],\"s8cATI\":[\"加入了 \",[\"channelName\"]],\"sCO9ue\":[\"与 <0>\",[\"serverName\"],\"</0> 的连接存在以下安全问题：\"],\"sGH11W\":[\"服务器\"],\"sHI1H+\":[\"现在称为 **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" 邀请您加入 \",[\"channel\"]],\"sby+1/\":[\"点击复制\"],\"sfN25C\":[\"您的真实姓名或全名\"],\"sliuzR\":[\"打开链接\"],\"sqrO9R\":[\"自定义提及\"],\"sr6RdJ\":[\"Shift+Enter 换行\"],\"swrCpB\":[\"频道已由 \",[\"user\"],\" 从 \",[\"oldName\"],\" 重命名为 \",[\"newName\"],[\"0\"]],\"sxkWRg\":[\"高级\"],\"t/YqKh\":[\"移除\"],\"t47eHD\":[\"您在此服务器上的唯一标识符\"],\"tAkAh0\":[\"可选 \",[\"size\"],\" 替换的 URL。示例：https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tXLJS3\":[\"显示或隐藏频道列表侧栏\"],\"tfDRzk\":[\"保存\"],\"tiBsJk\":[\"离开了 \",[\"channelName\"]],\"tt4/UD\":[\"退出了 (\",[\"reason\"],\")\"],\"u0TcnO\":[\"昵称 {nick} 已被使用，正在用 {newNick} 重试\"],\"u0a8B4\":[\"以 IRC 运营商身份进行管理访问认证\"],\"u0rWFU\":[\"创建时间晚于（分钟前）\"],\"u72w3t\":[\"要忽略的用户和模式\"],\"u7jc2L\":[\"退出了\"],\"uAQUqI\":[\"状态\"],\"uB85T3\":[\"保存失败：\",[\"msg\"]],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC 服务器：\"],\"usSSr/\":[\"缩放级别\"],\"v7uvcf\":[\"软件：\"],\"vE8kb+\":[\"Shift+Enter 换行（Enter 发送）\"],\"vERlcd\":[\"个人资料\"],\"vK0RL8\":[\"无主题\"],\"vSJd18\":[\"视频\"],\"vXIe7J\":[\"语言\"],\"vaHYxN\":[\"真实姓名\"],\"vhjbKr\":[\"离开\"],\"w4NYox\":[[\"title\"],\" 客户端\"],\"w8xQRx\":[\"值无效\"],\"wFjjxZ\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"未找到封禁例外\"],\"wPrGnM\":[\"频道管理员\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"显示用户加入或离开频道的事件\"],\"whqZ9r\":[\"要高亮的额外词语或短语\"],\"wm7RV4\":[\"通知声音\"],\"wz/Yoq\":[\"您的消息在服务器之间转发时可能被截获\"],\"xCJdfg\":[\"清除\"],\"xUHRTR\":[\"连接时自动以操作员身份认证\"],\"xWHwwQ\":[\"封禁\"],\"xYilR2\":[\"媒体\"],\"xceQrO\":[\"仅支持安全的 WebSocket 连接\"],\"xdtXa+\":[\"频道名称\"],\"xfXC7q\":[\"文字频道\"],\"xlCYOE\":[\"正在加载更多消息...\"],\"xlhswE\":[\"最小值为 \",[\"0\"]],\"xq97Ci\":[\"添加词语或短语...\"],\"xuRqRq\":[\"客户端限制 (+l)\"],\"xwF+7J\":[[\"0\"],\" 正在输入...\"],\"yNeucF\":[\"此服务器不支持扩展个人资料元数据（IRCv3 METADATA 扩展）。头像、显示名称和状态等字段不可用。\"],\"yPlrca\":[\"频道头像\"],\"yQE2r9\":[\"加载中\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper 用户名\"],\"yYOzWD\":[\"日志\"],\"yfx9Re\":[\"IRC 运营商密码\"],\"ygCKqB\":[\"停止\"],\"ymDxJx\":[\"IRC 运营商用户名\"],\"yrpRsQ\":[\"按名称排序\"],\"yz7wBu\":[\"关闭\"],\"zJw+jA\":[\"设置模式：\",[\"0\"]],\"zebeLu\":[\"输入 oper 用户名\"],\"zpr0Bw\":[\"GZ-Line\"]}");
\ No newline at end of file
+/*eslint-disable*/export const messages=JSON.parse("{\"+5VMrz\":[\"无效的模式格式，请使用 nick!user@host 格式（允许通配符 *）\"],\"+6NQQA\":[\"通用支持频道\"],\"+6NyRG\":[\"客户端\"],\"+K0AvT\":[\"断开连接\"],\"+cyFdH\":[\"标记为离开时的默认消息\"],\"+mVPqU\":[\"在消息中渲染 Markdown 格式\"],\"+r52UY\":[\"Image Quality\"],\"+vqCJH\":[\"您的账户认证用户名\"],\"+yPBXI\":[\"选择文件\"],\"+zy2Nq\":[\"类型\"],\"/09cao\":[\"链路安全级别较低（级别 \",[\"securityLevel\"],\"）\"],\"/3BQ4J\":[\"频道外的用户无法发送消息\"],\"/6BzZF\":[\"切换成员列表\"],\"/KNSzH\":[\"After 30 minutes\"],\"/TNOPk\":[\"用户已离开\"],\"/XQgft\":[\"发现\"],\"/cF7Rs\":[\"音量\"],\"/dqduX\":[\"下一页\"],\"/fc3q4\":[\"所有内容\"],\"/kISDh\":[\"启用通知声音\"],\"/n04sB\":[\"踢出\"],\"/rTz0M\":[\"音频\"],\"/rfkZe\":[\"提及和消息时播放声音\"],\"0/0ZGA\":[\"频道名称掩码\"],\"0D6j7U\":[\"了解更多自定义规则 →\"],\"0XsHcR\":[\"踢出用户\"],\"0ZpE//\":[\"按用户数排序\"],\"0bEPwz\":[\"设置为离开\"],\"0dGkPt\":[\"展开频道列表\"],\"0gS7M5\":[\"显示名称\"],\"0kS+M8\":[\"示例网络\"],\"0rgoY7\":[\"仅连接您选择的服务器\"],\"0wdd7X\":[\"加入\"],\"0wkVYx\":[\"私信\"],\"111uHX\":[\"链接预览\"],\"196EG4\":[\"删除私聊\"],\"1DSr1i\":[\"注册账户\"],\"1O/24y\":[\"切换频道列表\"],\"1TNIig\":[\"Open\"],\"1VPJJ2\":[\"外部链接警告\"],\"1ZC/dv\":[\"没有未读提及或消息\"],\"1pO1zi\":[\"服务器名称为必填项\"],\"1uwfzQ\":[\"查看频道主题\"],\"268g7c\":[\"输入显示名称\"],\"2BTSK9\":[\"Switch Profile\"],\"2FOFq1\":[\"网络上的服务器管理员可能读取您的消息\"],\"2FYpfJ\":[\"更多\"],\"2HF1Y2\":[[\"inviter\"],\" 邀请了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"2I70QL\":[\"查看用户资料信息\"],\"2QYdmE\":[\"用户：\"],\"2QpEjG\":[\"已离开\"],\"2YE223\":[\"发消息到 #\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"2ZqpRB\":[\"Turn off\"],\"2bimFY\":[\"使用服务器密码\"],\"2iTmdZ\":[\"本地存储：\"],\"2m1Skm\":[\"Lock when idle\"],\"2odkwe\":[\"严格 – 更强的保护\"],\"2uDhbA\":[\"输入要邀请的用户名\"],\"2ygf/L\":[\"← 返回\"],\"2zEgxj\":[\"搜索 GIF...\"],\"38gup8\":[\"(current)\"],\"3LDeGC\":[\"Show a title, description and image under links that arrive without one. The app fetches each page itself, at most once a day, so linked sites can see your IP address.\"],\"3RdPhl\":[\"重命名频道\"],\"3THokf\":[\"有发言权的用户\"],\"3TSz9S\":[\"最小化\"],\"3iCQAo\":[\"Remove host\"],\"3jBDvM\":[\"频道显示名称\"],\"3ryuFU\":[\"可选的崩溃报告以改善应用\"],\"3uBF/8\":[\"关闭查看器\"],\"3uwW8F\":[\"https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"3xf8Kz\":[\"输入账户名称...\"],\"4/Rr0R\":[\"邀请用户加入当前频道\"],\"46g2OM\":[\"No downloads yet.\"],\"4EZrJN\":[\"规则\"],\"4JJtW9\":[\"#overflow\"],\"4NqeT4\":[\"洪水配置文件 (+F)\"],\"4RZQRK\":[\"你在做什么?\"],\"4hfTrB\":[\"昵称\"],\"4n99LO\":[\"已在 \",[\"0\"]],\"4t6vMV\":[\"短消息自动切换到单行\"],\"4vsHmf\":[\"时间（分钟）\"],\"5+INAX\":[\"高亮提及您的消息\"],\"5Oi8Ux\":[\"Enter your passphrase to continue.\"],\"5R5Pv/\":[\"Oper 用户名\"],\"678PKt\":[\"网络名称\"],\"68H+aB\":[\"files.corp, *.intranet, 192.168.1.0/24\"],\"6Aih4U\":[\"离线\"],\"6CO3WE\":[\"加入频道需要密码，留空可移除密钥。\"],\"6HhMs3\":[\"退出消息\"],\"6V3Ea3\":[\"已复制\"],\"6gRgw8\":[\"Retry\"],\"6lGV3K\":[\"收起\"],\"6lk9iN\":[\"Failed: \",[\"error\"]],\"6yFOEi\":[\"输入 oper 密码...\"],\"7+IHTZ\":[\"未选择文件\"],\"73hrRi\":[\"nick!user@host（例如：spam*!*@*, *!*@badhost.com）\"],\"7QkKyN\":[\"发送私信\"],\"7U1W7c\":[\"非常宽松\"],\"7Y1YQj\":[\"真实姓名：\"],\"7YHArF\":[\"— 在查看器中打开\"],\"7fjnVl\":[\"搜索用户...\"],\"7jL88x\":[\"删除此消息？此操作无法撤销。\"],\"7l15X4\":[\"failed\"],\"7nGhhM\":[\"您在想什么？\"],\"7sEpu1\":[\"成员 — \",[\"0\"]],\"7sNhEz\":[\"用户名\"],\"8H0Q+x\":[\"了解更多配置文件 →\"],\"8Phu0A\":[\"显示用户更改昵称的事件\"],\"8XTG9e\":[\"输入 oper 密码\"],\"8XsV2J\":[\"重新发送\"],\"8ZsakT\":[\"密码\"],\"8kR84m\":[\"您即将打开一个外部链接：\"],\"8lCgih\":[\"删除规则\"],\"8p/xVT\":[[\"0\",\"plural\",{\"one\":[[\"1\"]],\"other\":[[\"2\"]]}]],\"8sqxA/\":[\"Delete profile \\\"\",[\"name\"],\"\\\" with all its networks, logs and history?\"],\"8wRzac\":[[\"joinCount\",\"plural\",{\"other\":[\"已加入 \",[\"joinCount\"],\" 次\"]}]],\"9BMLnJ\":[\"重新连接服务器\"],\"9JQ2r3\":[[\"count\",\"plural\",{\"one\":[\"Uploading 1 file\"],\"other\":[\"Uploading \",[\"count\"],\" files\"]}]],\"9OEgyT\":[\"添加表情\"],\"9PQ8m2\":[\"G-Line（全局封禁）\"],\"9Qs99X\":[\"邮箱：\"],\"9QupBP\":[\"删除规则\"],\"9bG48P\":[\"发送中\"],\"9f5f0u\":[\"有隐私问题？联系我们：\"],\"9unqs3\":[\"离开：\"],\"9v3hwv\":[\"未找到服务器。\"],\"9wjhYZ\":[\"ObsidianIRC is locked\"],\"9zb2WA\":[\"连接中\"],\"A1taO8\":[\"搜索\"],\"A2adVi\":[\"发送正在输入通知\"],\"A9Rhec\":[\"频道名称\"],\"AWOSPo\":[\"放大\"],\"AXSpEQ\":[\"连接时获取 Oper\"],\"AeXO77\":[\"账户\"],\"AhNP40\":[\"跳转\"],\"Ai2U7L\":[\"主机\"],\"AjBQnf\":[\"已更改昵称\"],\"AmXVh6\":[\"https://example.com/avatar.png\"],\"AnRu/j\":[\"取消回复\"],\"ApSx0O\":[\"找到 \",[\"0\"],\" 条匹配\\\"\",[\"searchQuery\"],\"\\\"的消息\"],\"AxPAXW\":[\"未找到结果\"],\"AyNqAB\":[\"在聊天中显示所有服务器事件\"],\"B/QqGw\":[\"暂时离开\"],\"B8AaMI\":[\"此字段为必填项\"],\"B9zD9S\":[\"New passphrase\"],\"BA2c49\":[\"服务器不支持高级 LIST 筛选\"],\"BDKt3I\":[[\"0\"],\"、\",[\"1\"],\"、\",[\"2\"],\" 以及另外 \",[\"3\"],\" 人正在输入...\"],\"BGul2A\":[\"您有未保存的更改。确定要关闭而不保存吗？\"],\"BIf9fi\":[\"您的状态消息\"],\"BZz3md\":[\"您的个人网站\"],\"Bgm/H7\":[\"允许输入多行文本\"],\"BiQIl1\":[\"置顶此私信对话\"],\"BiUDE0\":[\"Lock now; connections stay up\"],\"BlNZZ2\":[\"点击跳转到消息\"],\"Bowq3c\":[\"只有管理员可以更改频道主题\"],\"Btozzp\":[\"此图片已过期\"],\"Bycfjm\":[\"总计：\",[\"0\"]],\"BzYaaf\":[\"App Lock Settings\"],\"C6IBQc\":[\"复制完整 JSON\"],\"C9L9wL\":[\"数据收集\"],\"CDq4wC\":[\"管理用户\"],\"CHVRxG\":[\"发消息给 @\",[\"0\"],\"（Shift+Enter 换行）\"],\"CN9zdR\":[\"Oper 用户名和密码为必填项\"],\"CW3sYa\":[\"添加表情 \",[\"emoji\"]],\"CaAkqd\":[\"显示退出事件\"],\"CbvaYj\":[\"按昵称封禁\"],\"CcK+Ft\":[\"PDF\"],\"Ce8q3L\":[\"选择频道\"],\"CjRyOH\":[\"metadata\"],\"CsekCi\":[\"普通\"],\"D+NlUC\":[\"系统\"],\"D28t6+\":[\"已加入并退出\"],\"DB8zMK\":[\"应用\"],\"DBcWHr\":[\"自定义通知音频文件\"],\"DPfwMq\":[\"Done\"],\"DTy9Xw\":[\"媒体预览\"],\"Dj4pSr\":[\"选择一个安全密码\"],\"Dq+zgV\":[\"Repeat passphrase\"],\"Du+zn+\":[\"搜索中...\"],\"Du2T2f\":[\"未找到设置\"],\"DwsSVQ\":[\"应用筛选并刷新\"],\"E3W/zd\":[\"默认昵称\"],\"E6nRW7\":[\"复制链接\"],\"E703RG\":[\"模式：\"],\"EAeu1Z\":[\"发送邀请\"],\"EFKJQT\":[\"设置\"],\"EGPQBv\":[\"自定义洪水规则 (+f)\"],\"ELik0r\":[\"查看完整隐私政策\"],\"EPbeC2\":[\"查看或编辑频道主题\"],\"EQCDNT\":[\"输入 oper 用户名...\"],\"ESSqi0\":[\"Download every image, video and file linked here\"],\"EUvulZ\":[\"找到 1 条匹配\\\"\",[\"searchQuery\"],\"\\\"的消息\"],\"EatZYJ\":[\"下一张图片\"],\"EdQY6l\":[\"无\"],\"EnqLYU\":[\"搜索服务器...\"],\"F0OKMc\":[\"编辑服务器\"],\"F6Int2\":[\"启用高亮\"],\"F7ijSe\":[\"Filter commands, e.g. PRIVMSG 001 -PING\"],\"FDoLyE\":[\"最大用户数\"],\"FUU/hZ\":[\"控制聊天中加载的外部媒体数量。\"],\"Fdp03t\":[\"开启\"],\"FfPWR0\":[\"弹窗\"],\"FjkaiT\":[\"缩小\"],\"FlQt90\":[\"Resized \",[\"0\"],\" → \",[\"1\"]],\"FlqOE9\":[\"这意味着：\"],\"FolHNl\":[\"管理您的账户和身份验证\"],\"Fp2Dif\":[\"已退出服务器\"],\"G5KmCc\":[\"GZ-Line（全局 Z-Line）\"],\"GDs0lz\":[\"<0>风险：</0> 敏感信息（消息、私人对话、身份验证详情）可能会暴露给网络管理员或位于 IRC 服务器之间的攻击者。\"],\"GR+2I3\":[\"添加邀请掩码（例如 nick!*@*, *!*@host.com）\"],\"GRLyMU\":[\"关闭弹出的服务器通知\"],\"GlHnXw\":[\"昵称修改失败: \",[\"error\"],\" \",[\"0\"]],\"GqdcyX\":[\"Paused · \",[\"size\"]],\"GswZF3\":[\"预览：\"],\"GtmO8/\":[\"来自\"],\"GtuHUQ\":[\"在服务器上重命名此频道，所有用户都会看到新名称。\"],\"GuGfFX\":[\"切换搜索\"],\"GxkJXS\":[\"上传中...\"],\"GzbwnK\":[\"已加入频道\"],\"GzsUDB\":[\"扩展资料\"],\"H/PnT8\":[\"插入表情\"],\"H6Izzl\":[\"您的首选颜色代码\"],\"H9jIv+\":[\"显示加入/离开\"],\"HAKBY9\":[\"上传文件\"],\"HdE1If\":[\"频道\"],\"Hk4AW9\":[\"您的首选显示名称\"],\"HmHDk7\":[\"选择成员\"],\"HrQzPU\":[[\"networkName\"],\" 上的频道\"],\"I2tXQ5\":[\"发消息给 @\",[\"0\"],\"（Enter 换行，Shift+Enter 发送）\"],\"I6bw/h\":[\"封禁用户\"],\"I8bqQO\":[\"Pause, resume and find downloaded files\"],\"I92Z+b\":[\"启用通知\"],\"I9D72S\":[\"您确定要删除此消息吗？此操作无法撤销。\"],\"IA+1wo\":[\"显示用户被踢出频道的事件\"],\"IDwkJx\":[\"IRC Operator\"],\"ILlU+s\":[\"信息：\"],\"IUwGEM\":[\"保存更改\"],\"IVeGK6\":[[\"0\"],\"、\",[\"1\"],\" 和 \",[\"2\"],\" 正在输入...\"],\"IgrLD/\":[\"暂停\"],\"Im6JED\":[\"WHISPER\"],\"ImOQa9\":[\"回复\"],\"IoHMnl\":[\"最大值为 \",[\"0\"]],\"IvMj+0\":[\"管理员\"],\"J28zul\":[\"正在连接...\"],\"J5T9NW\":[\"用户信息\"],\"J8Y5+z\":[\"哎呀！网络分裂！⚠️\"],\"JBHkBA\":[\"已离开频道\"],\"JCwL0Q\":[\"输入原因（可选）\"],\"JFciKP\":[\"切换\"],\"JTPqcx\":[\"Fetch Link Previews\"],\"JXGkhG\":[\"更改频道名称（仅限管理员）\"],\"JcD7qf\":[\"更多操作\"],\"JdkA+c\":[\"隐秘 (+s)\"],\"Jmu12l\":[\"服务器频道\"],\"JsxcGC\":[\"unknown error\"],\"JvQ++s\":[\"启用 Markdown\"],\"K0TMF9\":[\"Domains that link previews are never fetched from, including their subdomains\"],\"K2jwh/\":[\"无可用 WHOIS 数据\"],\"KAXSwC\":[\"语音权限\"],\"KDfTdX\":[\"删除消息\"],\"KKBlUU\":[\"嵌入\"],\"KM0pLb\":[\"欢迎来到本频道！\"],\"KR6W2h\":[\"取消忽略用户\"],\"KV+Bi1\":[\"仅限邀请 (+i)\"],\"KdCtwE\":[\"在重置计数器之前监控洪水活动的秒数\"],\"Kkezga\":[\"服务器密码\"],\"KsiQ/8\":[\"用户必须受邀才能加入频道\"],\"KwkrLQ\":[\"Host already exists\"],\"L+gB/D\":[\"频道信息\"],\"LC1a7n\":[\"IRC 服务器报告其服务器间链路的安全级别较低。这意味着当您的消息在网络中的 IRC 服务器之间转发时，可能未经过妥善加密，或者 SSL/TLS 证书未被正确验证。\"],\"LNfLR5\":[\"显示踢出事件\"],\"LQb0W/\":[\"显示所有事件\"],\"LU7/yA\":[\"显示用的别名，可包含空格、表情和特殊字符。真实频道名（\",[\"channelName\"],\"）仍用于 IRC 命令。\"],\"LUb9O7\":[\"需要有效的服务器端口\"],\"Lb+BUl\":[\"https://example.com/avatar.jpg\"],\"LcET2C\":[\"隐私政策\"],\"LcuSDR\":[\"管理您的个人资料信息和元数据\"],\"LqLS9B\":[\"显示昵称变更\"],\"LsDQt2\":[\"频道设置\"],\"LtI9AS\":[\"频道所有者\"],\"LuNhhL\":[\"对此消息做出了回应\"],\"M/AZNG\":[\"头像图片 URL\"],\"M/WIer\":[\"发送消息\"],\"M1UW1E\":[\"After 5 minutes\"],\"M8er/5\":[\"名称：\"],\"MHk+7g\":[\"上一张图片\"],\"MRorGe\":[\"私信用户\"],\"MVbSGP\":[\"时间窗口（秒）\"],\"MkpcsT\":[\"您的消息和设置存储在本地设备上\"],\"MzsrPH\":[\"Lock App\"],\"N/hDSy\":[\"标记为机器人——通常为 'on' 或空\"],\"N7TQbE\":[\"邀请用户加入 \",[\"channelName\"]],\"NCca/o\":[\"输入默认昵称...\"],\"NUKYfg\":[\"Show in folder\"],\"Nqs6B9\":[\"显示所有外部媒体。任何 URL 都可能向未知服务器发送请求。\"],\"Nt+9O7\":[\"使用 WebSocket 而非原始 TCP\"],\"NxIHzc\":[\"踢出用户\"],\"O+v/cL\":[\"浏览服务器上的所有频道\"],\"ODwSCk\":[\"发送 GIF\"],\"OGQ5kK\":[\"配置通知声音和高亮提示\"],\"OIPt1Z\":[\"显示或隐藏成员列表侧栏\"],\"OKSNq/\":[\"非常严格\"],\"ONWvwQ\":[\"上传\"],\"OVKoQO\":[\"您的账户认证密码\"],\"ObsidianIRC - Bringing IRC to the future\":[\"ObsidianIRC - 将 IRC 带入未来\"],\"OhCpra\":[\"设置主题…\"],\"OkltoQ\":[\"通过昵称封禁 \",[\"username\"],\"（阻止其使用相同昵称重新加入）\"],\"P+t/Te\":[\"无其他数据\"],\"P42Wcc\":[\"安全\"],\"PD38l0\":[\"频道头像预览\"],\"PD9mEt\":[\"输入消息...\"],\"PLV8Io\":[\"Ask where to save each download\"],\"PPqfdA\":[\"打开频道配置设置\"],\"PPw1qj\":[\"example.com, tracker.example\"],\"PSCjfZ\":[\"此频道显示的主题，所有用户均可查看。\"],\"PZCecv\":[\"PDF 预览\"],\"PeLgsC\":[[\"c\",\"plural\",{\"other\":[[\"c\"],\" 次\"]}]],\"PguS2C\":[\"添加例外掩码（例如 nick!*@*, *!*@host.com）\"],\"Pil5Ty\":[\"显示 \",[\"displayedChannelsCount\"],\"，共 \",[\"0\"],\" 个频道\"],\"PqhVlJ\":[\"封禁用户（按 Hostmask）\"],\"Q+chwU\":[\"用户名：\"],\"Q6hhn8\":[\"偏好设置\"],\"Q7xfEj\":[\"done\"],\"QF4a34\":[\"请输入用户名\"],\"QGqSZ2\":[\"颜色与格式\"],\"QJQd1J\":[\"编辑资料\"],\"QSzGDE\":[\"闲置\"],\"QUlny5\":[\"欢迎来到 \",[\"0\"],\"！\"],\"QX6gK4\":[\"Change passphrase\"],\"Qoq+GP\":[\"阅读更多\"],\"QuSkCF\":[\"筛选频道...\"],\"QwUrDZ\":[\"将话题更改为：\",[\"topic\"]],\"R0UH07\":[\"第 \",[\"0\"],\" 张，共 \",[\"1\"],\" 张\"],\"R7SsBE\":[\"静音\"],\"R8rf1X\":[\"点击设置主题\"],\"RArB3D\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"]],\"RC9Tef\":[\"Passphrase changed.\"],\"RI3cWd\":[\"使用 ObsidianIRC 探索 IRC 的世界\"],\"RMMaN5\":[\"受管理 (+m)\"],\"RWw9Lg\":[\"关闭窗口\"],\"RZ2BuZ\":[\"账户 \",[\"account\"],\" 注册需要验证：\",[\"message\"]],\"RySp6q\":[\"隐藏评论\"],\"SN1Um5\":[\"The app lock is on. Raw logs were stopped.\"],\"SPKQTd\":[\"昵称为必填项\"],\"SPVjfj\":[\"留空将默认显示\\\"无原因\\\"\"],\"SQKPvQ\":[\"邀请用户\"],\"SXGR8p\":[\"Always ask\"],\"SkOWk2\":[\"New profile name\"],\"SkZcl+\":[\"选择预定义的洪水保护配置文件。这些配置文件为不同使用场景提供均衡的保护设置。\"],\"Slr+3C\":[\"最小用户数\"],\"Spnlre\":[\"您邀请了 \",[\"target\"],\" 加入 \",[\"channel\"]],\"T/ckN5\":[\"在查看器中打开\"],\"T91vKp\":[\"播放\"],\"TV2Wdu\":[\"了解我们如何处理您的数据并保护您的隐私。\"],\"TgFpwD\":[\"正在应用...\"],\"TgZOXj\":[\"Choose folder\"],\"TkzSFB\":[\"无更改\"],\"TtserG\":[\"输入真实姓名\"],\"Ttz9J1\":[\"输入密码...\"],\"Tz0i8g\":[\"设置\"],\"U3pytU\":[\"管理员\"],\"UDb2YD\":[\"添加表情\"],\"UE4KO5\":[\"*channel*\"],\"UGT5vp\":[\"保存设置\"],\"UV5hLB\":[\"未找到封禁\"],\"UX8s3F\":[\"Ask which profile to use at startup\"],\"Uaj3Nd\":[\"状态消息\"],\"Ue3uny\":[\"默认（无配置文件）\"],\"UkARhe\":[\"普通 – 标准保护\"],\"Umn7Cj\":[\"暂无评论，成为第一个吧！\"],\"UtUIRh\":[[\"0\"],\" 条旧消息\"],\"UwzP+U\":[\"安全连接\"],\"Uy4iQO\":[\"Side by side\"],\"V0/A4O\":[\"频道所有者\"],\"V4qgxE\":[\"创建时间早于（分钟前）\"],\"V8yTm6\":[\"清除搜索\"],\"VAOn4r\":[\"Unlock\"],\"VHPcrL\":[\"Unlocking…\"],\"VJMMyz\":[\"ObsidianIRC - 将 IRC 带入未来\"],\"VJScHU\":[\"原因\"],\"VJWeaG\":[\"Cancel download\"],\"VLsmVV\":[\"静音通知\"],\"VbyRUy\":[\"评论\"],\"VeG1K5\":[\"Remove Photo Metadata\"],\"Vmx0mQ\":[\"设置者：\"],\"VqnIZz\":[\"查看我们的隐私政策和数据使用规范\"],\"VrMygG\":[\"最小长度为 \",[\"0\"]],\"VrnTui\":[\"您的代词，显示在个人资料中\"],\"W8E3qn\":[\"已验证账户\"],\"WAakm9\":[\"删除频道\"],\"WFxTHC\":[\"添加封禁掩码（例如 nick!*@*, *!*@host.com）\"],\"WN1g9F\":[\"服务器地址为必填项\"],\"WRYdXW\":[\"音频进度\"],\"WUOH5B\":[\"忽略用户\"],\"WWEXnZ\":[[\"0\",\"plural\",{\"other\":[\"显示另外 \",[\"1\"],\" 个项目\"]}]],\"Weq9zb\":[\"常规\"],\"Wfj7Sk\":[\"开启或关闭通知声音\"],\"Wm7gbG\":[\"GitHub：\"],\"WyeHWY\":[\"*spam*\"],\"WzMCru\":[\"用户资料\"],\"X6S3lt\":[\"搜索设置、频道、服务器...\"],\"XEHan5\":[\"仍然继续\"],\"XI1+wb\":[\"格式无效\"],\"XIXeuC\":[\"发消息给 @\",[\"0\"]],\"XMS+k4\":[\"发起私信\"],\"XWgxXq\":[\"相册\"],\"Xd7+IT\":[\"取消置顶私聊\"],\"XklovM\":[\"Working…\"],\"Xm/s+u\":[\"显示\"],\"Xp2n93\":[\"显示来自服务器受信任文件主机的媒体。不会向外部服务发送任何请求。\"],\"XvjC4F\":[\"正在保存...\"],\"Y/qryO\":[\"未找到与搜索条件匹配的用户\"],\"Y65WrD\":[\"Run this profile next to the current one\"],\"YAqRpI\":[\"账户 \",[\"account\"],\" 注册成功：\",[\"message\"]],\"YEfzvP\":[\"受保护主题 (+t)\"],\"YQOn6a\":[\"收起成员列表\"],\"YRCoE9\":[\"频道操作员\"],\"YURQaF\":[\"查看资料\"],\"YdBSvr\":[\"控制媒体显示和外部内容\"],\"Yj6U3V\":[\"无中央服务器：\"],\"YjvpGx\":[\"代词\"],\"YqH4l4\":[\"无密钥\"],\"YyUPpV\":[\"账户：\"],\"ZJSWfw\":[\"断开服务器时显示的消息\"],\"ZR1dJ4\":[\"邀请\"],\"ZdWg0V\":[\"在浏览器中打开\"],\"ZhRBbl\":[\"搜索消息…\"],\"Zmcu3y\":[\"高级筛选\"],\"a2/8e5\":[\"主题设置时间晚于（分钟前）\"],\"aG8sc/\":[\"the date taken\"],\"aHKcKc\":[\"上一页\"],\"aJTbXX\":[\"Oper 密码\"],\"aQryQv\":[\"模式已存在\"],\"aW9pLN\":[\"频道允许的最大用户数，留空表示无限制。\"],\"ah4fmZ\":[\"同时显示来自 YouTube、Vimeo、SoundCloud 及类似知名服务的预览。\"],\"aifXak\":[\"此频道没有媒体\"],\"ap2zBz\":[\"宽松\"],\"az8lvo\":[\"关\"],\"azXSNo\":[\"展开成员列表\"],\"azdliB\":[\"登录账户\"],\"b26wlF\":[\"她/她的\"],\"bD/+Ei\":[\"严格\"],\"bQ6BJn\":[\"配置详细的洪水保护规则。每条规则指定要监控的活动类型以及超过阈值时采取的操作。\"],\"beV7+y\":[\"用户将收到加入 \",[\"channelName\"],\" 的邀请。\"],\"bk84cH\":[\"离开消息\"],\"bkHdLj\":[\"添加 IRC 服务器\"],\"bmQLn5\":[\"添加规则\"],\"bwRvnp\":[\"操作\"],\"c8+EVZ\":[\"已验证账户\"],\"cGYUlD\":[\"未加载任何媒体预览。\"],\"cLF98o\":[\"显示评论 (\",[\"commentCount\"],\")\"],\"cLKIDO\":[\"没有可用用户\"],\"cSgpoS\":[\"置顶私聊\"],\"cde3ce\":[\"发消息给 <0>\",[\"0\"],\"</0>\"],\"chQsxg\":[\"复制格式化输出\"],\"cl/A5J\":[\"欢迎来到 \",[\"__DEFAULT_IRC_SERVER_NAME__\"],\"！\"],\"cnGeoo\":[\"删除\"],\"coPLXT\":[\"我们不在服务器上存储您的 IRC 通信\"],\"crYH/6\":[\"SoundCloud 播放器\"],\"d3sis4\":[\"添加服务器\"],\"d9aN5k\":[\"将 \",[\"username\"],\" 移出频道\"],\"dEgA5A\":[\"取消\"],\"dGi1We\":[\"取消置顶此私信对话\"],\"dJVuyC\":[\"离开了 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"dMtLDE\":[\"至\"],\"dXqxlh\":[\"<0>⚠️ 安全风险！</0> 此连接可能容易遭受窃听或中间人攻击。\"],\"da9Q/R\":[\"已更改频道模式\"],\"dhJN3N\":[\"显示评论\"],\"dj2xTE\":[\"关闭通知\"],\"dpCzmC\":[\"洪水保护设置\"],\"dqK/Ng\":[\"After 1 hour\"],\"e9dQpT\":[\"是否在新标签页中打开此链接？\"],\"eKNJ3g\":[\"Current passphrase\"],\"ePK91l\":[\"编辑\"],\"eRX4FR\":[\"Scale down images whose longest side is larger than this before uploading them. 0 uploads them at full size.\"],\"eYBDuB\":[\"上传图片或提供带可选 \",[\"size\"],\" 替换的 URL\"],\"edBbee\":[\"通过 hostmask 封禁 \",[\"username\"],\"（阻止其从相同 IP/主机重新加入）\"],\"ekfzWq\":[\"用户设置\"],\"elPDWs\":[\"自定义您的 IRC 客户端体验\"],\"eu2osY\":[\"<0>💡 建议：</0> 仅在您信任此服务器并了解相关风险的情况下继续操作。避免通过此连接共享敏感信息或密码。\"],\"euEhbr\":[\"点击加入 \",[\"channel\"]],\"ez3vLd\":[\"启用多行输入\"],\"f0J5Ki\":[\"服务器之间的通信可能使用未加密的连接\"],\"f7Wbcm\":[\"Use unix:///path/to/socket to connect to a local bouncer or server on Linux and macOS\"],\"f9BHJk\":[\"警告用户\"],\"fDOLLd\":[\"未找到频道。\"],\"fK7YOw\":[\"Downloads\"],\"fZqzEv\":[\"Clear finished\"],\"ffzDkB\":[\"匿名分析：\"],\"fq1GF9\":[\"显示用户断开服务器连接的事件\"],\"gCqS7x\":[\"Passphrase (at least 8 characters)\"],\"gEF57C\":[\"此服务器仅支持一种连接类型\"],\"gGOtek\":[\"After 15 minutes\"],\"gJuLUI\":[\"忽略列表\"],\"gNzMrk\":[\"当前头像\"],\"gg3jCm\":[\"Maximum Image Size\"],\"gjPWyO\":[\"输入昵称...\"],\"gz6UQ3\":[\"最大化\"],\"h6razj\":[\"排除频道名称掩码\"],\"hG6jnw\":[\"未设置主题\"],\"hG89Ed\":[\"图片\"],\"hYgDIe\":[\"Create\"],\"hZ6znB\":[\"端口\"],\"ha+Bz5\":[\"例如：100:1440\"],\"he3ygx\":[\"Copy\"],\"hehnjM\":[\"数量\"],\"hzdLuQ\":[\"只有获得发言权或更高权限的用户才能发言\"],\"i0qMbr\":[\"主页\"],\"iDNBZe\":[\"通知\"],\"iH8pgl\":[\"返回\"],\"iL9SZg\":[\"封禁用户（按昵称）\"],\"iNt+3c\":[\"返回图片\"],\"iQvi+a\":[\"不再提醒我此服务器的低链路安全问题\"],\"iSLIjg\":[\"连接\"],\"iWXkHH\":[\"半管理员\"],\"iZeTtp\":[\"服务器地址\"],\"idD8Ev\":[\"已保存\"],\"iivqkW\":[\"登录时间\"],\"ij+Elv\":[\"图片预览\"],\"ilIWp7\":[\"切换通知\"],\"iuaqvB\":[\"用 * 作通配符。示例：baduser!*@*, *!*@spammer.com, troll*!*@*\"],\"ixkTse\":[\"机器人\"],\"j2DGR0\":[\"按主机掩码封禁\"],\"jA4uoI\":[\"话题：\"],\"jLXxGK\":[\"https://example.com\"],\"jPSk57\":[\"原因（可选）\"],\"jUV7CU\":[\"上传头像\"],\"jW5Uwh\":[\"控制加载的外部媒体量。关闭 / 安全 / 可信来源 / 所有内容。\"],\"jXzms5\":[\"附件选项\"],\"jZlrte\":[\"颜色\"],\"jfC/xh\":[\"联系方式\"],\"jywMpv\":[\"#new-channel-name\"],\"k112DD\":[\"加载旧消息\"],\"k3ID0F\":[\"筛选成员…\"],\"k65gsE\":[\"深入查看\"],\"k7Zgob\":[\"取消连接\"],\"k99j0U\":[\"Cancel upload\"],\"kAVx5h\":[\"未找到邀请\"],\"kCLEPU\":[\"已连接到\"],\"kF5LKb\":[\"已忽略的模式：\"],\"kGeOx/\":[\"加入 \",[\"0\"]],\"kITKr8\":[\"正在加载频道模式...\"],\"kPpPsw\":[\"您是 IRC Operator\"],\"kWJmRL\":[\"您\"],\"kfcRb0\":[\"头像\"],\"kjMqSj\":[\"复制 JSON\"],\"krViRy\":[\"点击以 JSON 格式复制\"],\"ks71ra\":[\"例外\"],\"kw4lRv\":[\"频道半操作员\"],\"kxgIRq\":[\"选择或添加频道以开始使用。\"],\"ky6dWe\":[\"头像预览\"],\"l+GxCv\":[\"正在加载频道...\"],\"l+IUVW\":[\"账户 \",[\"account\"],\" 验证成功：\",[\"message\"]],\"l/siQz\":[[\"reconnectCount\",\"plural\",{\"other\":[\"已重新连接 \",[\"reconnectCount\"],\" 次\"]}]],\"l5jmzx\":[[\"0\"],\" 和 \",[\"1\"],\" 正在输入...\"],\"lHy8N5\":[\"正在加载更多频道...\"],\"lbpf14\":[\"加入 \",[\"value\"]],\"lfFsZ4\":[\"频道\"],\"ljBDOp\":[\"Open another profile or run one side by side\"],\"lkNdiH\":[\"账户名称\"],\"ln500L\":[\"ObsidianIRC\"],\"lnCMdg\":[\"上传图片\"],\"loQxaJ\":[\"我回来了\"],\"lvfaxv\":[\"主页\"],\"lyPX20\":[[\"0\"],\" images contain \",[\"details\"],\". Remove it before uploading?\"],\"m16xKo\":[\"添加\"],\"m8flAk\":[\"预览（尚未上传）\"],\"mEPxTp\":[\"<0>⚠️ 请注意！</0> 仅打开来自可信来源的链接。恶意链接可能危害您的安全或隐私。\"],\"mHGdhG\":[\"服务器信息\"],\"mHS8lb\":[\"发消息到 #\",[\"0\"]],\"mMYBD9\":[\"宽泛 – 更广的保护范围\"],\"mTGsPd\":[\"频道主题\"],\"mU8j6O\":[\"禁止外部消息 (+n)\"],\"mZp8FL\":[\"自动回退到单行\"],\"mdQu8G\":[\"YourNickname\"],\"miSSBQ\":[\"评论 (\",[\"commentCount\"],\")\"],\"mp8e4d\":[\"Lock now\"],\"mvyLSy\":[\"用户已认证\"],\"mwtcGl\":[\"关闭评论\"],\"mzI/c+\":[\"下载\"],\"n3fGRk\":[\"由 \",[\"0\"],\" 设置\"],\"nE9jsU\":[\"宽松 – 较弱的保护\"],\"nNflMD\":[\"离开频道\"],\"nPXkBi\":[\"正在加载 WHOIS 数据...\"],\"nQnxxF\":[\"发消息到 #\",[\"0\"],\"（Shift+Enter 换行）\"],\"nWMRxa\":[\"取消置顶\"],\"nkC032\":[\"无洪水防护配置\"],\"nrF9rx\":[\"Remove from list\"],\"o69z4d\":[\"向 \",[\"username\"],\" 发送警告消息\"],\"o9ylQi\":[\"搜索 GIF 以开始\"],\"oFGkER\":[\"服务器通知\"],\"oOi11l\":[\"滚动到底部\"],\"oQEzQR\":[\"新私信\"],\"oRfg6o\":[\"Turn on app lock\"],\"oXOSPE\":[\"在线\"],\"oal760\":[\"服务器链路可能遭受中间人攻击\"],\"oeqmmJ\":[\"受信任来源\"],\"olbLIL\":[\"The app lock is off.\"],\"ovBPCi\":[\"默认\"],\"p0Z69r\":[\"模式不能为空\"],\"p1KgtK\":[\"音频加载失败\"],\"p59pEv\":[\"更多详情\"],\"p7sRI6\":[\"让其他人知道您正在输入\"],\"pBm1od\":[\"秘密频道\"],\"pNmiXx\":[\"所有服务器的默认昵称\"],\"pUUo9G\":[\"主机名：\"],\"pVGPmz\":[\"账户密码\"],\"peNE68\":[\"永久\"],\"plhHQt\":[\"无数据\"],\"pm6+q5\":[\"安全警告\"],\"pm91z4\":[\"camera details\"],\"pn5qSs\":[\"附加信息\"],\"q0cR4S\":[\"现在称为 **\",[\"newNick\"],\"**\"],\"q4TC37\":[\"This image contains \",[\"details\"],\". Remove it before uploading?\"],\"q9EqoO\":[\"Simultaneous downloads\"],\"qFcunY\":[\"频道不会出现在 LIST 或 NAMES 命令中\"],\"qLpTm/\":[\"移除表情 \",[\"emoji\"]],\"qVkGWK\":[\"置顶\"],\"qY8wNa\":[\"主页\"],\"qb0xJ7\":[\"通配符：* 匹配任意字符，? 匹配单个字符。示例：nick!*@*, *!*@host.com, *!*user@*\"],\"qhzpRq\":[\"频道密钥 (+k)\"],\"qqeAJM\":[\"Never\"],\"qtoOYG\":[\"无限制\"],\"r1W2AS\":[\"文件托管图片\"],\"rIPR2O\":[\"主题设置时间早于（分钟前）\"],\"rMMSYo\":[\"最大长度为 \",[\"0\"]],\"rWtzQe\":[\"网络已分裂并重新连接。✅\"],\"rYG2u6\":[\"请稍候...\"],\"rcwVQd\":[\"resizing\"],\"rdUucN\":[\"预览\"],\"rjGI/Q\":[\"隐私\"],\"rk8iDX\":[\"正在加载 GIF...\"],\"rn6SBY\":[\"取消静音\"],\"rnbuvh\":[[\"skipped\"],\" lines skipped because the console fell behind\"],\"s/UKqq\":[\"已被踢出频道\"],\"s8cATI\":[\"加入了 \",[\"channelName\"]],\"sCO9ue\":[\"与 <0>\",[\"serverName\"],\"</0> 的连接存在以下安全问题：\"],\"sGH11W\":[\"服务器\"],\"sHI1H+\":[\"现在称为 **\",[\"newNick\"],\"**\"],\"sJyV04\":[[\"inviter\"],\" 邀请您加入 \",[\"channel\"]],\"sby+1/\":[\"点击复制\"],\"sfN25C\":[\"您的真实姓名或全名\"],\"sliuzR\":[\"打开链接\"],\"sqrO9R\":[\"自定义提及\"],\"sr6RdJ\":[\"Shift+Enter 换行\"],\"swrCpB\":[\"频道已由 \",[\"user\"],\" 从 \",[\"oldName\"],\" 重命名为 \",[\"newName\"],[\"0\"]],\"sxkWRg\":[\"高级\"],\"syWzbL\":[\"Passphrase\"],\"t/YqKh\":[\"移除\"],\"t47eHD\":[\"您在此服务器上的唯一标识符\"],\"tAkAh0\":[\"可选 \",[\"size\"],\" 替换的 URL。示例：https://example.com/avatar/\",[\"size\"],\"/channel.jpg\"],\"tSLCCT\":[\"Local Network Hosts\"],\"tXLJS3\":[\"显示或隐藏频道列表侧栏\"],\"tfDRzk\":[\"保存\"],\"tiBsJk\":[\"离开了 \",[\"channelName\"]],\"tqLxW+\":[\"Encrypt saved data with a passphrase\"],\"tt4/UD\":[\"退出了 (\",[\"reason\"],\")\"],\"txO8j2\":[\"JPEG and WebP quality for images that are resized or too large for the server\"],\"u0TcnO\":[\"昵称 {nick} 已被使用，正在用 {newNick} 重试\"],\"u0a8B4\":[\"以 IRC 运营商身份进行管理访问认证\"],\"u0rWFU\":[\"创建时间晚于（分钟前）\"],\"u72w3t\":[\"要忽略的用户和模式\"],\"u7jc2L\":[\"退出了\"],\"uAQUqI\":[\"状态\"],\"uB85T3\":[\"保存失败：\",[\"msg\"]],\"uUehLT\":[\"Waiting\"],\"uV3DOL\":[\"G-Line\"],\"uW3lLI\":[\"IRC 服务器：\"],\"usSSr/\":[\"缩放级别\"],\"v39wLo\":[\"Resume\"],\"v7uvcf\":[\"软件：\"],\"vE8kb+\":[\"Shift+Enter 换行（Enter 发送）\"],\"vERlcd\":[\"个人资料\"],\"vK0RL8\":[\"无主题\"],\"vSJd18\":[\"视频\"],\"vXIe7J\":[\"语言\"],\"vaHYxN\":[\"真实姓名\"],\"vhjbKr\":[\"离开\"],\"vjAXZK\":[\"Repeat new passphrase\"],\"vqY/7o\":[\"Link Preview Blocklist\"],\"w4NYox\":[[\"title\"],\" 客户端\"],\"w8O3ho\":[\"Choose a profile\"],\"w8xQRx\":[\"值无效\"],\"wFjjxZ\":[\"被 \",[\"username\"],\" 踢出 \",[\"channelName\"],\" (\",[\"reason\"],\")\"],\"wGjaGl\":[\"未找到封禁例外\"],\"wPrGnM\":[\"频道管理员\"],\"wRkP2d\":[\"GIF\"],\"wbm86v\":[\"显示用户加入或离开频道的事件\"],\"whqZ9r\":[\"要高亮的额外词语或短语\"],\"wm7RV4\":[\"通知声音\"],\"wz/Yoq\":[\"您的消息在服务器之间转发时可能被截获\"],\"xCJdfg\":[\"清除\"],\"xUHRTR\":[\"连接时自动以操作员身份认证\"],\"xWHwwQ\":[\"封禁\"],\"xYilR2\":[\"媒体\"],\"xceQrO\":[\"仅支持安全的 WebSocket 连接\"],\"xdtXa+\":[\"频道名称\"],\"xfXC7q\":[\"文字频道\"],\"xlCYOE\":[\"正在加载更多消息...\"],\"xlhswE\":[\"最小值为 \",[\"0\"]],\"xotGHN\":[\"Save All Media in Channel\"],\"xq97Ci\":[\"添加词语或短语...\"],\"xuRqRq\":[\"客户端限制 (+l)\"],\"xwF+7J\":[[\"0\"],\" 正在输入...\"],\"yNeucF\":[\"此服务器不支持扩展个人资料元数据（IRCv3 METADATA 扩展）。头像、显示名称和状态等字段不可用。\"],\"yOIBd1\":[\"Remove location, camera and other EXIF, XMP and IPTC data from JPEG, PNG, WebP and HEIC images before uploading them\"],\"yPlrca\":[\"频道头像\"],\"yQE2r9\":[\"加载中\"],\"ySU+JY\":[\"your@email.com\"],\"yTX1Rt\":[\"Oper 用户名\"],\"yYOzWD\":[\"日志\"],\"yf36Lv\":[\"Hosts on your local network that downloads and link previews may reach. Links to local and private addresses are blocked otherwise.\"],\"yfx9Re\":[\"IRC 运营商密码\"],\"ygCKqB\":[\"停止\"],\"ymDxJx\":[\"IRC 运营商用户名\"],\"yrpRsQ\":[\"按名称排序\"],\"ytCTfj\":[\"App lock\"],\"yz7wBu\":[\"关闭\"],\"zJw+jA\":[\"设置模式：\",[\"0\"]],\"zcrte4\":[\"Encrypts saved passwords, chat logs and message history with a passphrase. While locked you stay connected, but nothing new is saved until you unlock. A forgotten passphrase can't be recovered.\"],\"zebeLu\":[\"输入 oper 用户名\"],\"zpr0Bw\":[\"GZ-Line\"],\"zy/uad\":[\"location data\"]}");
\ No newline at end of file
@@ -6,6 +6,7 @@ import ReactDOM from "react-dom/client";
 import { pdfjs } from "react-pdf";
 import { BrowserRouter } from "react-router-dom";
 import App from "./App";
+import RawConsole from "./components/RawConsole";
 import "./index.css";
 import { registerHostedServiceWorker } from "./lib/registerServiceWorker";
 
@@ -83,15 +84,25 @@ async function loadCatalog(locale: string) {
   }
 }
 
+// Secondary Tauri windows load the same bundle with a query parameter and
+// must not boot the full client (which would auto-connect to every server).
+const rawConsoleLabel = new URLSearchParams(window.location.search).get(
+  "rawConsole",
+);
+
 (async () => {
   const locale = await resolveLocale();
   await loadCatalog(locale);
   ReactDOM.createRoot(rootElement).render(
     <React.StrictMode>
       <I18nProvider i18n={i18n}>
-        <BrowserRouter>
-          <App />
-        </BrowserRouter>
+        {rawConsoleLabel ? (
+          <RawConsole label={rawConsoleLabel} />
+        ) : (
+          <BrowserRouter>
+            <App />
+          </BrowserRouter>
+        )}
       </I18nProvider>
     </React.StrictMode>,
   );
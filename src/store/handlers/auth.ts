@@ -2,6 +2,8 @@ import { t } from "@lingui/core/macro";
 import { v4 as uuidv4 } from "uuid";
 import type { StoreApi } from "zustand";
 import ircClient from "../../lib/ircClient";
+import { freshOauthTokens } from "../../lib/oauth";
+import { isTauri } from "../../lib/platformUtils";
 import {
   type ScramState,
   sasl as saslChunk,
@@ -74,6 +76,39 @@ function pickBearer(oauth: ServerOAuthConfig): string | undefined {
   return oauth.idToken ?? oauth.accessToken;
 }
 
+// Desktop: swap a held OAuth token that is about to expire for a fresh one
+// before it goes out in AUTHENTICATE. On failure the old token is sent
+// anyway and the server's 904 tells the user.
+async function renewOauth(serverId: string): Promise<void> {
+  if (!isTauri()) return;
+  const servers = storage.servers.load();
+  const oauth = getActiveOauth(servers.find((s) => s.id === serverId));
+  if (!oauth) return;
+  try {
+    const fresh = await freshOauthTokens(serverId, oauth);
+    if (!fresh.refreshed) return;
+    storage.servers.save(
+      servers.map((s) =>
+        s.id === serverId
+          ? {
+              ...s,
+              oauth: {
+                ...oauth,
+                accessToken: fresh.accessToken,
+                // Refreshes often carry no id token; keeping the old one
+                // would make pickBearer send it after it expired
+                idToken: fresh.idToken,
+                tokenExpiresAt: fresh.expiresAt,
+              },
+            }
+          : s,
+      ),
+    );
+  } catch (error) {
+    console.warn("OAuth token refresh failed:", error);
+  }
+}
+
 const sessions = new Map<string, SaslSession>();
 
 function chooseMechanism(
@@ -121,7 +156,7 @@ function clearSession(serverId: string) {
 }
 
 export function registerAuthHandlers(store: StoreApi<AppState>): void {
-  ircClient.on("CAP_ACKNOWLEDGED", ({ serverId, key, capabilities }) => {
+  ircClient.on("CAP_ACKNOWLEDGED", async ({ serverId, key, capabilities }) => {
     if (capabilities?.startsWith("draft/metadata")) {
       const currentSubs =
         store.getState().metadataSubscriptions[serverId] || [];
@@ -144,6 +179,8 @@ export function registerAuthHandlers(store: StoreApi<AppState>): void {
     }
     if (key !== "sasl") return;
 
+    await renewOauth(serverId);
+
     // Pick mechanism up-front so the AUTHENTICATE event handler knows what
     // to do when the server says "+".
     const servers = storage.servers.load();
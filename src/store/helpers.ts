@@ -20,6 +20,10 @@ export function generateDeterministicId(
  * Normalize host for comparison (extract hostname from URL or return as-is)
  */
 export function normalizeHost(host: string): string {
+  if (host.startsWith("unix://")) {
+    // A socket path has no hostname; the whole URL identifies the server
+    return host;
+  }
   if (host.includes("://")) {
     // Extract hostname from URL format
     const withoutProtocol = host.replace(/^(irc|ircs|wss):\/\//, "");
@@ -45,6 +45,10 @@ function generateDeterministicId(serverId: string, name: string): string {
 
 // Helper function to normalize host for comparison (extract hostname from URL or return as-is)
 function normalizeHost(host: string): string {
+  if (host.startsWith("unix://")) {
+    // A socket path has no hostname; the whole URL identifies the server
+    return host;
+  }
   if (host.includes("://")) {
     // Extract hostname from URL format
     const withoutProtocol = host.replace(/^(irc|ircs|wss):\/\//, "");
@@ -53,6 +57,18 @@ function normalizeHost(host: string): string {
   return host;
 }
 
+/** Whether a saved server is the network named by `network` (name or host) */
+export function isSavedNetwork(
+  server: ServerConfig,
+  network: string,
+): boolean {
+  const wanted = network.toLowerCase();
+  return (
+    server.name?.toLowerCase() === wanted ||
+    normalizeHost(server.host).toLowerCase() === wanted
+  );
+}
+
 // Helper function to ensure host is in URL format
 function ensureUrlFormat(host: string, port: number): string {
   if (host.includes("://")) {
@@ -408,6 +424,9 @@ interface UIState {
   twoFactorSettingsServerId: string | null;
   isSettingsModalOpen: boolean;
   isQuickActionsOpen: boolean;
+  isProfilePickerOpen: boolean;
+  isAppLockSettingsOpen: boolean;
+  isDownloadsOpen: boolean;
   isDarkMode: boolean;
   isNarrowView: boolean;
   isMobileMenuOpen: boolean;
@@ -785,7 +804,7 @@ export interface AppState {
   reorderPrivateChats: (serverId: string, privateChatIds: string[]) => void;
   markChannelAsRead: (serverId: string, channelId: string) => void;
   reorderChannels: (serverId: string, channelIds: string[]) => void;
-  connectToSavedServers: () => void; // New action to load servers from localStorage
+  connectToSavedServers: (only?: string[]) => void; // New action to load servers from localStorage; `only` limits it to saved networks with these names or hosts
   reconnectServer: (serverId: string) => Promise<void>; // Reconnect to an existing server
   deleteServer: (serverId: string) => void; // New action to delete a server
   updateServer: (serverId: string, config: Partial<ServerConfig>) => void; // Update server configuration
@@ -798,6 +817,9 @@ export interface AppState {
   toggleEditServerModal: (isOpen?: boolean, serverId?: string | null) => void;
   toggleSettingsModal: (isOpen?: boolean) => void;
   toggleQuickActions: (isOpen?: boolean) => void;
+  toggleProfilePicker: (isOpen?: boolean) => void;
+  toggleAppLockSettings: (isOpen?: boolean) => void;
+  toggleDownloads: (isOpen?: boolean) => void;
   requestChatInputFocus: () => void;
   clearChatInputFocus: () => void;
   toggleUserProfileModal: (isOpen?: boolean) => void;
@@ -994,6 +1016,9 @@ const useStore = create<AppState>((set, get) => ({
     editServerId: null,
     isSettingsModalOpen: false,
     isQuickActionsOpen: false,
+    isProfilePickerOpen: false,
+    isAppLockSettingsOpen: false,
+    isDownloadsOpen: false,
     isDarkMode: true,
     isNarrowView:
       typeof window !== "undefined"
@@ -1067,6 +1092,12 @@ const useStore = create<AppState>((set, get) => ({
     autoFallbackToSingleLine: true,
     // Media settings
     mediaVisibilityLevel: 1 as MediaVisibilityLevel,
+    // Upload settings
+    imageMetadataMode: "always",
+    uploadMaxDimension: 0,
+    uploadImageQuality: 85,
+    // Link preview settings
+    fetchLinkPreviews: false,
     // Markdown settings
     enableMarkdownRendering: false,
     // Status messages
@@ -1143,6 +1174,7 @@ const useStore = create<AppState>((set, get) => ({
         saslPassword,
         existingSavedServer?.id, // Pass the saved server ID if it exists
         oauthBearerEnabled,
+        existingSavedServer?.nativeWebSocket,
       );
 
       // Save server to localStorage
@@ -1181,6 +1213,7 @@ const useStore = create<AppState>((set, get) => ({
         operOnConnect: savedServer?.operOnConnect,
         skipLocalhostWarning: savedServer?.skipLocalhostWarning,
         skipLinkSecurityWarning: savedServer?.skipLinkSecurityWarning,
+        nativeWebSocket: savedServer?.nativeWebSocket,
         // Preserve existing addedAt timestamp or set current time for new servers
         addedAt: savedServer?.addedAt || Date.now(),
         oauth: mergedOauth ?? savedServer?.oauth,
@@ -2742,17 +2775,21 @@ const useStore = create<AppState>((set, get) => ({
     });
   },
 
-  connectToSavedServers: async () => {
+  connectToSavedServers: async (only?: string[]) => {
     const state = get();
-    if (state.hasConnectedToSavedServers) {
-      return; // Already connected, don't do it again
+    if (!only) {
+      if (state.hasConnectedToSavedServers) {
+        return; // Already connected, don't do it again
+      }
+      set({ hasConnectedToSavedServers: true });
     }
 
-    set({ hasConnectedToSavedServers: true });
-
     runPendingMigrations();
 
-    const savedServers = loadSavedServers();
+    const savedServers = loadSavedServers().filter(
+      (server) =>
+        !only || only.some((network) => isSavedNetwork(server, network)),
+    );
     const connectionPromises = [];
 
     for (const savedServer of savedServers) {
@@ -2778,6 +2815,16 @@ const useStore = create<AppState>((set, get) => ({
           normalizeHost(s.host) === normalizeHost(urlHost) && s.port === port,
       );
 
+      // Networks named on the command line may already be up or on their way
+      if (
+        only &&
+        (existingServer?.isConnected ||
+          existingServer?.connectionState === "connecting" ||
+          existingServer?.connectionState === "reconnecting")
+      ) {
+        continue;
+      }
+
       if (!existingServer) {
         // Add server to store with connecting state
         const connectingServer: Server = {
@@ -3020,6 +3067,36 @@ const useStore = create<AppState>((set, get) => ({
     }));
   },
 
+  toggleProfilePicker: (isOpen) => {
+    set((state) => ({
+      ui: {
+        ...state.ui,
+        isProfilePickerOpen:
+          isOpen !== undefined ? isOpen : !state.ui.isProfilePickerOpen,
+      },
+    }));
+  },
+
+  toggleAppLockSettings: (isOpen) => {
+    set((state) => ({
+      ui: {
+        ...state.ui,
+        isAppLockSettingsOpen:
+          isOpen !== undefined ? isOpen : !state.ui.isAppLockSettingsOpen,
+      },
+    }));
+  },
+
+  toggleDownloads: (isOpen) => {
+    set((state) => ({
+      ui: {
+        ...state.ui,
+        isDownloadsOpen:
+          isOpen !== undefined ? isOpen : !state.ui.isDownloadsOpen,
+      },
+    }));
+  },
+
   requestChatInputFocus: () => {
     set((state) => ({
       ui: { ...state.ui, shouldFocusChatInput: true },
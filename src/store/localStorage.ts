@@ -1,3 +1,4 @@
+import { extractSecrets, mergeSecrets } from "../lib/secrets";
 import type { ServerConfig } from "../types";
 import type {
   ChannelOrderMap,
@@ -20,11 +21,14 @@ const KEYS = {
 export const servers = {
   load: (): ServerConfig[] => {
     const data = JSON.parse(localStorage.getItem(KEYS.SERVERS) || "[]");
-    return [...data].sort((a, b) => (a.addedAt || 0) - (b.addedAt || 0));
+    return mergeSecrets(data).sort(
+      (a, b) => (a.addedAt || 0) - (b.addedAt || 0),
+    );
   },
 
+  // Passwords and tokens go to the OS keychain once initSecrets has run
   save: (servers: ServerConfig[]) => {
-    localStorage.setItem(KEYS.SERVERS, JSON.stringify(servers));
+    localStorage.setItem(KEYS.SERVERS, JSON.stringify(extractSecrets(servers)));
   },
 };
 
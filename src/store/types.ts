@@ -30,6 +30,9 @@ export type PinnedPrivateChatsMap = Record<
 
 export type ChannelOrderMap = Record<string, string[]>;
 
+/** Whether uploads lose their image metadata (Tauri only). */
+export type ImageMetadataMode = "always" | "ask" | "never";
+
 export interface GlobalSettings {
   enableNotifications: boolean;
   notificationSound: string;
@@ -51,6 +54,10 @@ export interface GlobalSettings {
   multilineOnShiftEnter: boolean;
   autoFallbackToSingleLine: boolean;
   mediaVisibilityLevel: MediaVisibilityLevel;
+  imageMetadataMode: ImageMetadataMode;
+  uploadMaxDimension: number;
+  uploadImageQuality: number;
+  fetchLinkPreviews: boolean;
   enableMarkdownRendering: boolean;
   awayMessage: string;
   quitMessage: string;
@@ -118,6 +118,9 @@ export interface ServerConfig {
     | "EXTERNAL";
   skipLinkSecurityWarning?: boolean;
   skipLocalhostWarning?: boolean;
+  // Desktop only: connect wss:// servers through the native socket layer
+  // instead of the webview's WebSocket
+  nativeWebSocket?: boolean;
   operUsername?: string;
   operPassword?: string;
   operOnConnect?: boolean;
@@ -188,6 +188,10 @@ describe("Metadata Display Features", () => {
         multilineOnShiftEnter: true,
         autoFallbackToSingleLine: true,
         mediaVisibilityLevel: 3,
+        imageMetadataMode: "always",
+        uploadMaxDimension: 0,
+        uploadImageQuality: 85,
+        fetchLinkPreviews: false,
         enableMarkdownRendering: false,
         awayMessage: "",
         quitMessage: "ObsidianIRC - Bringing IRC to the future",
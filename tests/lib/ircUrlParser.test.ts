@@ -1,11 +1,22 @@
-import { describe, expect, it } from "vitest";
+import { invoke } from "@tauri-apps/api/core";
+import { describe, expect, it, vi } from "vitest";
 import {
   constructIrcUrl,
   isValidIrcUrl,
   normalizeChannelName,
   type ParsedIrcUrl,
   parseIrcUrl,
+  resolveIrcUrl,
 } from "../../src/lib/ircUrlParser";
+import { isTauri } from "../../src/lib/platformUtils";
+
+vi.mock("@tauri-apps/api/core", () => ({
+  invoke: vi.fn(),
+}));
+
+vi.mock("../../src/lib/platformUtils", () => ({
+  isTauri: vi.fn(() => false),
+}));
 
 describe("ircUrlParser", () => {
   describe("parseIrcUrl", () => {
@@ -284,4 +295,53 @@ describe("ircUrlParser", () => {
       });
     });
   });
+
+  describe("resolveIrcUrl", () => {
+    it("uses the TypeScript parser in the browser", async () => {
+      vi.mocked(isTauri).mockReturnValue(false);
+      const result = await resolveIrcUrl("ircs://irc.libera.chat/");
+
+      expect(result.port).toBe(443);
+      expect(invoke).not.toHaveBeenCalled();
+    });
+
+    it("uses the native parser on Tauri", async () => {
+      vi.mocked(isTauri).mockReturnValue(true);
+      vi.mocked(invoke).mockResolvedValueOnce({
+        scheme: "ircs",
+        host: "irc.libera.chat",
+        port: 6697,
+        path: "",
+        nick: null,
+        password: null,
+        channels: ["#test"],
+        queryNick: null,
+        key: null,
+        needpass: false,
+        needkey: false,
+      });
+
+      const result = await resolveIrcUrl("ircs://irc.libera.chat/test).");
+
+      expect(invoke).toHaveBeenCalledWith("parse_irc_url", {
+        url: "ircs://irc.libera.chat/test",
+      });
+      expect(result).toEqual({
+        host: "irc.libera.chat",
+        port: 6697,
+        scheme: "ircs",
+        channels: ["#test"],
+        nick: "user",
+        password: undefined,
+      });
+    });
+
+    it("falls back when the native parser rejects the URL", async () => {
+      vi.mocked(isTauri).mockReturnValue(true);
+      vi.mocked(invoke).mockRejectedValueOnce("Invalid port: x");
+      const result = await resolveIrcUrl("irc://example.net/");
+
+      expect(result.host).toBe("example.net");
+    });
+  });
 });